@@ -691,6 +691,7 @@ async fn test_multipart_upload(
     {
         let options = PreSignedRequestOption {
             expires_in: Duration::from_secs(60 * 30),
+            ..Default::default()
         };
         let presigned_multipart_put = part_req2.get_presigned_url(region, credentials, &options);
         println!("presigned multipart put: {:#?}", presigned_multipart_put);
@@ -1205,6 +1206,7 @@ async fn test_get_object_with_expired_presigned_url(
     };
     let opt = PreSignedRequestOption {
         expires_in: ::std::time::Duration::from_secs(1),
+        ..Default::default()
     };
     let presigned_url = req.get_presigned_url(region, credentials, &opt);
     ::std::thread::sleep(::std::time::Duration::from_secs(2));
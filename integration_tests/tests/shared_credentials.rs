@@ -0,0 +1,94 @@
+#![cfg(all(feature = "s3", feature = "dynamodb"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::FutureExt;
+use rusoto_core::credential::{
+    AutoRefreshingProvider, AwsCredentials, CredentialsError, ProvideAwsCredentials,
+};
+use rusoto_core::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpResponse};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{ByteStream, Region};
+use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListTablesInput};
+use rusoto_s3::{HeadBucketRequest, S3Client, S3};
+
+/// Counts the credentials it issues.
+#[derive(Default)]
+struct Issuer(AtomicUsize);
+
+#[async_trait]
+impl ProvideAwsCredentials for Issuer {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let issued = self.0.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(AwsCredentials::new(
+            format!("AKID{}", issued),
+            "secret",
+            None,
+            Some(Utc::now() + chrono::Duration::hours(1)),
+        ))
+    }
+}
+
+/// Answers every request successfully, recording the credential scope it was signed with.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl DispatchSignedRequest for Recorder {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        _timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let authorization = String::from_utf8(request.headers()["authorization"][0].clone())
+            .expect("authorization");
+        let credential = authorization
+            .split("Credential=")
+            .nth(1)
+            .and_then(|rest| rest.split('/').next())
+            .expect("credential")
+            .to_owned();
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", request.service, credential));
+        let body = match request.service.as_str() {
+            "dynamodb" => r#"{"TableNames": []}"#,
+            _ => "",
+        };
+        futures::future::ready(Ok(HttpResponse {
+            status: http::StatusCode::OK,
+            body: ByteStream::from(body.as_bytes().to_vec()),
+            headers: Default::default(),
+        }))
+        .boxed()
+    }
+}
+
+#[tokio::test]
+async fn one_auto_refreshing_provider_feeds_several_clients() {
+    let provider = Arc::new(AutoRefreshingProvider::new(Issuer::default()).unwrap());
+    let recorder = Recorder::default();
+    let s3 = S3Client::new_with(recorder.clone(), provider.clone(), Region::UsEast1);
+    let dynamodb = DynamoDbClient::new_with(recorder.clone(), provider.clone(), Region::UsEast1);
+
+    s3.head_bucket(HeadBucketRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    dynamodb
+        .list_tables(ListTablesInput::default())
+        .await
+        .unwrap();
+
+    assert_eq!(provider.get_ref().0.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec!["s3 AKID1", "dynamodb AKID1"]
+    );
+}
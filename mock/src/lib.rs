@@ -33,17 +33,21 @@
 //! ```
 #![deny(missing_docs)]
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::FutureExt;
 use http::{header::HeaderName, HeaderMap, StatusCode};
 use rusoto_core::credential::{AwsCredentials, ProvideAwsCredentials};
 use rusoto_core::request::HttpResponse;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timeout::{TimeoutError, TimeoutKind};
 use rusoto_core::{ByteStream, DispatchSignedRequest, HttpDispatchError};
 use serde::Serialize;
+use tokio::time;
 
 /// Provides a set of credentials that always resolve
 /// successfully
@@ -72,6 +76,9 @@ pub struct MockRequestDispatcher {
     body: Vec<u8>,
     headers: HeaderMap<String>,
     request_checker: Option<Box<dyn Fn(&SignedRequest) + Send + Sync>>,
+    recorder: RequestRecorder,
+    delay: Option<Duration>,
+    truncated_body: Option<usize>,
 }
 
 enum RequestOutcome {
@@ -110,6 +117,12 @@ impl MockRequestDispatcher {
         self
     }
 
+    /// Mocks a binary response body, e.g. an event stream
+    pub fn with_binary_body(mut self, body: &[u8]) -> MockRequestDispatcher {
+        self.body = body.to_vec();
+        self
+    }
+
     /// Mocks the json serialized response body what would be
     /// returned from AWS
     pub fn with_json_body<B>(mut self, body: B) -> MockRequestDispatcher
@@ -136,29 +149,129 @@ impl MockRequestDispatcher {
             .insert(key.parse::<HeaderName>().unwrap(), value.into());
         self
     }
+
+    /// Delays the response, or the dispatch error, by `delay` of tokio's clock, so tests can
+    /// pause and advance it
+    ///
+    /// When the request's timeout is shorter than the delay, the request fails with a
+    /// timeout error once the timeout elapsed, like with an `HttpClient`.
+    pub fn with_delay(mut self, delay: Duration) -> MockRequestDispatcher {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Mocks the connection closing after the first `len` bytes of the response body, so
+    /// that reading the body fails
+    pub fn with_truncated_body(mut self, len: usize) -> MockRequestDispatcher {
+        self.truncated_body = Some(len);
+        self
+    }
+
+    /// Returns a handle to the requests this dispatcher receives, which stays usable after
+    /// the dispatcher moved into a client
+    pub fn recorder(&self) -> RequestRecorder {
+        self.recorder.clone()
+    }
+
+    fn response_body(&self) -> ByteStream {
+        match self.truncated_body {
+            None => ByteStream::from(self.body.clone()),
+            Some(len) => {
+                let received = Bytes::from(self.body[..len.min(self.body.len())].to_vec());
+                ByteStream::new(futures::stream::iter(vec![
+                    Ok(received),
+                    Err(io::Error::new(
+                        io::ErrorKind::ConnectionReset,
+                        "connection closed before the response body was complete",
+                    )),
+                ]))
+            }
+        }
+    }
 }
 
 impl DispatchSignedRequest for MockRequestDispatcher {
     fn dispatch(
         &self,
         request: SignedRequest,
-        _timeout: Option<Duration>,
+        timeout: Option<Duration>,
     ) -> rusoto_core::request::DispatchSignedRequestFuture {
         if self.request_checker.is_some() {
             self.request_checker.as_ref().unwrap()(&request);
         }
-        match self.outcome {
-            RequestOutcome::Performed(ref status) => futures::future::ready(Ok(HttpResponse {
+        self.recorder.record(&request);
+        let result = match self.outcome {
+            RequestOutcome::Performed(ref status) => Ok(HttpResponse {
                 status: *status,
-                body: ByteStream::from(self.body.clone()),
+                body: self.response_body(),
                 headers: self.headers.clone(),
-            }))
+            }),
+            RequestOutcome::Failed(ref error) => Err(error.clone()),
+        };
+        match self.delay {
+            None => futures::future::ready(result).boxed(),
+            Some(delay) => async move {
+                match timeout {
+                    Some(timeout) if timeout < delay => {
+                        time::delay_for(timeout).await;
+                        Err(TimeoutError::new(TimeoutKind::Operation, timeout).into())
+                    }
+                    _ => {
+                        time::delay_for(delay).await;
+                        result
+                    }
+                }
+            }
             .boxed(),
-            RequestOutcome::Failed(ref error) => futures::future::ready(Err(error.clone())).boxed(),
         }
     }
 }
 
+/// The requests received by a mock dispatcher, in the order they arrived
+///
+/// Clones share the same requests. Streaming payloads that can't be replayed are recorded
+/// without their payload.
+#[derive(Clone, Debug, Default)]
+pub struct RequestRecorder {
+    requests: Arc<Mutex<Vec<SignedRequest>>>,
+}
+
+impl RequestRecorder {
+    /// Returns the requests received so far
+    pub fn requests(&self) -> MutexGuard<'_, Vec<SignedRequest>> {
+        self.requests.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Returns the number of requests received so far
+    pub fn len(&self) -> usize {
+        self.requests().len()
+    }
+
+    /// Returns whether no request was received yet
+    pub fn is_empty(&self) -> bool {
+        self.requests().is_empty()
+    }
+
+    fn record(&self, request: &SignedRequest) {
+        let copy = request.try_clone().unwrap_or_else(|_| SignedRequest {
+            method: request.method.clone(),
+            service: request.service.clone(),
+            region: request.region.clone(),
+            path: request.path.clone(),
+            headers: request.headers.clone(),
+            params: request.params.clone(),
+            scheme: request.scheme.clone(),
+            hostname: request.hostname.clone(),
+            payload: None,
+            canonical_query_string: request.canonical_query_string.clone(),
+            canonical_uri: request.canonical_uri.clone(),
+            operation: request.operation.clone(),
+            request_compression: request.request_compression,
+        });
+        self.requests().push(copy);
+    }
+}
+
 /// An interface for producing response body content
 pub trait ReadMockResponse {
     /// Return a response body string for a given directory and file name
@@ -185,11 +298,30 @@ impl ReadMockResponse for MockResponseReader {
 }
 
 /// Returns sequential mock API responses consuming a collection of MockRequestDispatch
+///
+/// Each response's request checker is applied to the request it answers. Receiving more
+/// requests than there are responses panics.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_mock::{MockRequestDispatcher, MultipleMockRequestDispatcher};
+///
+/// let dispatcher = MultipleMockRequestDispatcher::new(vec![
+///     MockRequestDispatcher::with_status(500),
+///     MockRequestDispatcher::with_status(200)
+///         .with_request_checker(|request| assert_eq!(request.method, "POST")),
+/// ]);
+/// let requests = dispatcher.recorder();
+/// // let client = rusoto_sqs::SqsClient::new_with(dispatcher, ...);
+/// assert!(requests.is_empty());
+/// ```
 pub struct MultipleMockRequestDispatcher<I>
 where
     I: Iterator<Item = MockRequestDispatcher>,
 {
-    iterator: I,
+    iterator: Mutex<I>,
+    recorder: RequestRecorder,
 }
 
 impl<I> MultipleMockRequestDispatcher<I>
@@ -203,9 +335,16 @@ where
         C: IntoIterator<IntoIter = I>,
     {
         MultipleMockRequestDispatcher {
-            iterator: collection.into_iter(),
+            iterator: Mutex::new(collection.into_iter()),
+            recorder: RequestRecorder::default(),
         }
     }
+
+    /// Returns a handle to the requests this dispatcher receives, which stays usable after
+    /// the dispatcher moved into a client
+    pub fn recorder(&self) -> RequestRecorder {
+        self.recorder.clone()
+    }
 }
 
 impl<I> DispatchSignedRequest for MultipleMockRequestDispatcher<I>
@@ -217,13 +356,123 @@ where
         request: SignedRequest,
         _timeout: Option<Duration>,
     ) -> rusoto_core::request::DispatchSignedRequestFuture {
-        // Unsafe code required to increment iterator due to non-mutable &self
-        unsafe {
-            (*(self as *const Self as *mut Self))
-                .iterator
-                .next()
-                .unwrap()
-                .dispatch(request, _timeout)
+        self.recorder.record(&request);
+        let next = self
+            .iterator
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .next();
+        match next {
+            Some(dispatcher) => dispatcher.dispatch(request, _timeout),
+            None => panic!(
+                "MultipleMockRequestDispatcher received request {} ({} {}), but no more responses were scripted",
+                self.recorder.len(),
+                request.method,
+                request.path
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+
+    fn request(path: &str) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, path);
+        request.set_payload(Some(format!("Action={}", path)));
+        request
+    }
+
+    #[test]
+    fn dispatches_responses_in_order_and_records_requests() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(500)
+                .with_request_checker(|request| assert_eq!(request.path, "/first")),
+            MockRequestDispatcher::with_dispatch_error(HttpDispatchError::new(
+                "connection reset".to_owned(),
+            )),
+            MockRequestDispatcher::with_status(200).with_body("done"),
+        ]);
+        let recorder = dispatcher.recorder();
+
+        let first = block_on(dispatcher.dispatch(request("/first"), None)).unwrap();
+        let second = block_on(dispatcher.dispatch(request("/second"), None));
+        let third = block_on(dispatcher.dispatch(request("/third"), None)).unwrap();
+
+        assert_eq!(first.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(second.is_err());
+        assert_eq!(third.status, StatusCode::OK);
+        let requests = recorder.requests();
+        let paths: Vec<_> = requests.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths, vec!["/first", "/second", "/third"]);
+        match requests[2].payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => assert_eq!(&body[..], b"Action=/third"),
+            _ => panic!("expected a buffered payload"),
         }
     }
+
+    #[tokio::test]
+    async fn delays_responses_on_tokios_clock() {
+        time::pause();
+        let dispatcher =
+            MultipleMockRequestDispatcher::new(vec![MockRequestDispatcher::with_status(503)
+                .with_header("Retry-After", "1")
+                .with_delay(Duration::from_secs(5))]);
+
+        let response = dispatcher.dispatch(request("/slow"), None);
+        futures::pin_mut!(response);
+        assert!(futures::poll!(response.as_mut()).is_pending());
+        time::advance(Duration::from_secs(5)).await;
+        let response = response.await.unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers.get("retry-after").unwrap(), "1");
+    }
+
+    #[tokio::test]
+    async fn times_out_when_delayed_past_the_timeout() {
+        time::pause();
+        let dispatcher =
+            MockRequestDispatcher::with_status(200).with_delay(Duration::from_secs(60));
+
+        let response = dispatcher.dispatch(request("/slow"), Some(Duration::from_secs(1)));
+        futures::pin_mut!(response);
+        assert!(futures::poll!(response.as_mut()).is_pending());
+        time::advance(Duration::from_secs(1)).await;
+        let err = match response.await {
+            Err(err) => err,
+            Ok(response) => panic!("expected a timeout, got {}", response.status),
+        };
+
+        assert_eq!(
+            err.timeout().map(TimeoutError::kind),
+            Some(TimeoutKind::Operation)
+        );
+    }
+
+    #[tokio::test]
+    async fn truncated_bodies_fail_to_read() {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body("<Response>complete</Response>")
+            .with_truncated_body(10);
+
+        let mut response = dispatcher.dispatch(request("/"), None).await.unwrap();
+        let err = response.buffer().await.unwrap_err();
+
+        assert!(err.to_string().contains("connection closed"), "{}", err);
+    }
+
+    #[test]
+    #[should_panic(expected = "received request 2 (POST /second), but no more responses")]
+    fn panics_on_unscripted_requests() {
+        let dispatcher =
+            MultipleMockRequestDispatcher::new(vec![MockRequestDispatcher::with_status(200)]);
+
+        block_on(dispatcher.dispatch(request("/first"), None)).unwrap();
+        drop(dispatcher.dispatch(request("/second"), None));
+    }
 }
@@ -1,15 +1,30 @@
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use crate::clock_skew::{self, ClockSkew};
 
 use crate::credential::{
     Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
 };
-use crate::encoding::ContentEncoding;
+use crate::encoding::{ContentEncoding, RequestCompression};
+use crate::endpoint::{
+    DefaultEndpointResolver, Endpoint, EndpointError, EndpointResolver, EndpointVariants,
+    StsRegionalEndpoints,
+};
+use crate::instrument::RequestSpan;
+use crate::interceptor::{Interceptor, InterceptorError};
+use crate::metrics::{ErrorClassification, MetricsObserver, Outcome, RequestMetrics};
+use crate::region::Region;
 use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
+use crate::retry::{
+    self, AdaptiveRetry, AdaptiveRetryStats, RetryMode, RetryPolicy, AMZ_SDK_REQUEST,
+};
 use crate::signature::SignedRequest;
+use crate::ByteStream;
 
 use async_trait::async_trait;
 use lazy_static::lazy_static;
+use log::debug;
 use tokio::time;
 
 lazy_static! {
@@ -21,6 +36,15 @@ lazy_static! {
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    endpoint_resolver: Option<Arc<dyn EndpointResolver + Send + Sync>>,
+    endpoint_variants: EndpointVariants,
+    sts_regional_endpoints: StsRegionalEndpoints,
+    clock_skew: ClockSkew,
+    retry_policy: RetryPolicy,
+    adaptive_retry: Arc<Mutex<AdaptiveRetry>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics_observer: Option<Arc<dyn MetricsObserver>>,
+    request_compression: RequestCompression,
 }
 
 impl Client {
@@ -28,7 +52,7 @@ impl Client {
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+            return Client::from_inner(inner);
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
@@ -39,7 +63,7 @@ impl Client {
             content_encoding: Default::default(),
         });
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client::from_inner(inner)
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -53,9 +77,7 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     /// Create a client from a request dispatcher without a credentials provider. The client will
@@ -72,9 +94,7 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     #[cfg(feature = "encoding")]
@@ -93,17 +113,475 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding,
         };
+        Client::from_inner(Arc::new(inner))
+    }
+
+    fn from_inner(inner: Arc<dyn SignAndDispatch + Send + Sync>) -> Self {
         Client {
-            inner: Arc::new(inner),
+            inner,
+            endpoint_resolver: None,
+            endpoint_variants: EndpointVariants::from_env(),
+            sts_regional_endpoints: StsRegionalEndpoints::from_env(),
+            clock_skew: ClockSkew::default(),
+            retry_policy: RetryPolicy::from_env(),
+            adaptive_retry: Arc::new(Mutex::new(AdaptiveRetry::new())),
+            interceptors: Vec::new(),
+            metrics_observer: None,
+            request_compression: RequestCompression::from_env(),
+        }
+    }
+
+    /// Use `resolver` to choose the endpoint of every request sent by this client, instead of
+    /// the `AWS_ENDPOINT_URL` environment variables and the endpoint of the request's region.
+    pub fn with_endpoint_resolver<R>(mut self, resolver: R) -> Self
+    where
+        R: EndpointResolver + Send + Sync + 'static,
+    {
+        self.endpoint_resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Send requests to FIPS endpoints, overriding `AWS_USE_FIPS_ENDPOINT` and the
+    /// `use_fips_endpoint` profile key. Requests to services without a FIPS endpoint in their
+    /// region fail. Ignored for `Region::Custom` and when an endpoint resolver or
+    /// `AWS_ENDPOINT_URL` is set.
+    pub fn with_fips_endpoint(mut self, use_fips_endpoint: bool) -> Self {
+        self.endpoint_variants.fips = use_fips_endpoint;
+        self
+    }
+
+    /// Send requests to dual-stack (IPv4 and IPv6) endpoints, overriding
+    /// `AWS_USE_DUALSTACK_ENDPOINT` and the `use_dualstack_endpoint` profile key. Requests to
+    /// services without a dual-stack endpoint in their region fail. Ignored for
+    /// `Region::Custom` and when an endpoint resolver or `AWS_ENDPOINT_URL` is set.
+    pub fn with_dualstack_endpoint(mut self, use_dualstack_endpoint: bool) -> Self {
+        self.endpoint_variants.dualstack = use_dualstack_endpoint;
+        self
+    }
+
+    /// The endpoint variants this client sends requests to, from `with_fips_endpoint`,
+    /// `with_dualstack_endpoint` or the environment.
+    pub fn endpoint_variants(&self) -> EndpointVariants {
+        self.endpoint_variants
+    }
+
+    /// Send STS requests to regional or legacy global endpoints as `sts_regional_endpoints`
+    /// says, overriding `AWS_STS_REGIONAL_ENDPOINTS` and the `sts_regional_endpoints` profile
+    /// key. Ignored for `Region::Custom`, with endpoint variants, and when an endpoint
+    /// resolver or `AWS_ENDPOINT_URL` is set.
+    pub fn with_sts_regional_endpoints(
+        mut self,
+        sts_regional_endpoints: StsRegionalEndpoints,
+    ) -> Self {
+        self.sts_regional_endpoints = sts_regional_endpoints;
+        self
+    }
+
+    /// Whether this client sends STS requests to regional or legacy global endpoints, from
+    /// `with_sts_regional_endpoints` or the environment.
+    pub fn sts_regional_endpoints(&self) -> StsRegionalEndpoints {
+        self.sts_regional_endpoints
+    }
+
+    /// Retry requests that failed with a transient error according to `retry_policy`, instead
+    /// of `RetryPolicy::from_env()`, which only sends requests once unless a retry mode is set
+    /// in the environment. See the `retry` module for which errors are retried.
+    ///
+    /// The returned client starts with a full retry quota and an unlimited send rate, which it
+    /// shares with its clones.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.adaptive_retry = Arc::new(Mutex::new(AdaptiveRetry::new()));
+        self
+    }
+
+    /// The policy this client retries failed requests with.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The current retry quota and send rate of this client if its retry policy is in
+    /// `RetryMode::Adaptive`, e.g. to export them as metrics.
+    pub fn adaptive_retry_stats(&self) -> Option<AdaptiveRetryStats> {
+        match self.retry_policy.mode() {
+            RetryMode::Adaptive => Some(self.adaptive_retry.lock().unwrap().stats()),
+            RetryMode::Standard => None,
         }
     }
 
+    /// Run `interceptor` around every attempt of the requests this client sends, after the
+    /// interceptors added before it. Clones made afterwards run it too.
+    pub fn add_interceptor(&mut self, interceptor: Box<dyn Interceptor>) {
+        self.interceptors.push(Arc::from(interceptor));
+    }
+
+    /// Call `observer` around every attempt of the requests this client sends, see the
+    /// `metrics` module. Wrap it in an `Arc` to share it with other clients.
+    pub fn with_metrics_observer<O>(mut self, observer: O) -> Self
+    where
+        O: MetricsObserver + 'static,
+    {
+        self.metrics_observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Compress the bodies of requests to operations that accept compressed bodies according
+    /// to `request_compression`, instead of `RequestCompression::from_env()`, e.g.
+    /// `RequestCompression::disabled()` to send them as they are while debugging.
+    pub fn with_request_compression(mut self, request_compression: RequestCompression) -> Self {
+        self.request_compression = request_compression;
+        self
+    }
+
+    /// How this client compresses the bodies of requests.
+    pub fn request_compression(&self) -> &RequestCompression {
+        &self.request_compression
+    }
+
     /// Fetch credentials, sign the request and dispatch it.
     pub async fn sign_and_dispatch(
+        &self,
+        mut request: SignedRequest,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        self.resolve_endpoint(&mut request)
+            .map_err(SignAndDispatchError::Endpoint)?;
+        self.sign_and_dispatch_resolved(request).await
+    }
+
+    /// Fetch credentials, sign the request and dispatch it to its current scheme and hostname,
+    /// without resolving its endpoint. Use this after `resolve_endpoint` when the endpoint
+    /// needs adjusting per request, e.g. to address an S3 bucket by hostname.
+    pub async fn sign_and_dispatch_resolved(
+        &self,
+        mut request: SignedRequest,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        self.request_compression.compress(&mut request);
+        let span = RequestSpan::new(&request);
+        let metrics = self
+            .metrics_observer
+            .as_ref()
+            .map(|observer| RequestMetrics::new(observer.clone(), &request));
+        let result = span
+            .run(self.dispatch_with_retries(request, &span, metrics.as_ref()))
+            .await;
+        if let Some(metrics) = metrics {
+            metrics.complete();
+        }
+        result
+    }
+
+    /// Sign and dispatch the request, retrying it according to the retry policy.
+    async fn dispatch_with_retries(
+        &self,
+        mut request: SignedRequest,
+        span: &RequestSpan,
+        metrics: Option<&RequestMetrics>,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        let max_attempts = self.retry_policy.max_attempts();
+        if max_attempts == 1 {
+            let start = Instant::now();
+            let result = self
+                .dispatch_correcting_clock_skew(request, 1, span, metrics)
+                .await;
+            if let Some(metrics) = metrics {
+                metrics.attempt_end(1, outcome(&result, None), start.elapsed());
+            }
+            return result;
+        }
+        let adaptive = match self.retry_policy.mode() {
+            RetryMode::Adaptive => Some(&self.adaptive_retry),
+            RetryMode::Standard => None,
+        };
+        let mut attempt = 1;
+        loop {
+            // Keep an unsigned copy to sign again for the next attempt.
+            let next = if attempt < max_attempts {
+                request.try_clone().ok()
+            } else {
+                None
+            };
+            request.remove_header(AMZ_SDK_REQUEST);
+            request.add_header(
+                AMZ_SDK_REQUEST,
+                &format!("attempt={}; max={}", attempt, max_attempts),
+            );
+            if let Some(state) = adaptive {
+                let wait = state.lock().unwrap().acquire_send();
+                if wait > Duration::from_secs(0) {
+                    time::delay_for(wait).await;
+                }
+            }
+            let start = Instant::now();
+            let result = self
+                .dispatch_correcting_clock_skew(request, attempt, span, metrics)
+                .await;
+            let (result, reason) = classify(result).await;
+            if let Some(metrics) = metrics {
+                let outcome = outcome(&result, reason.as_ref());
+                metrics.attempt_end(attempt, outcome, start.elapsed());
+            }
+            if let Some(state) = adaptive {
+                let mut state = state.lock().unwrap();
+                state.record_response(matches!(reason, Some(ref r) if r.throttling));
+                if reason.is_none() && matches!(result, Ok(ref r) if r.status.is_success()) {
+                    state.release(attempt > 1);
+                }
+            }
+            let (reason, next) = match (reason, next) {
+                (Some(reason), Some(next)) => (reason, next),
+                _ => return result,
+            };
+            if let Some(state) = adaptive {
+                if !state.lock().unwrap().acquire_retry() {
+                    debug!(
+                        "Not retrying {} {}, the retry quota is exhausted: {}",
+                        next.method, next.path, reason.message
+                    );
+                    return result;
+                }
+            }
+            let delay = match self.retry_policy.delay_after(attempt, reason.retry_after) {
+                Some(delay) => delay,
+                None => {
+                    debug!(
+                        "Not retrying {} {}, the response asks to wait {:?}: {}",
+                        next.method, next.path, reason.retry_after, reason.message
+                    );
+                    return result;
+                }
+            };
+            debug!(
+                "Retrying {} {} in {:?}, attempt {} of {} failed: {}",
+                next.method, next.path, delay, attempt, max_attempts, reason.message
+            );
+            span.record_retry(attempt, delay, &reason.message);
+            time::delay_for(delay).await;
+            request = next;
+            attempt += 1;
+        }
+    }
+
+    /// Sign and dispatch one attempt of a request like `intercept_and_dispatch`. When the
+    /// response rejects the request for the time it was signed at, the clock skew of this
+    /// client is corrected from the server time in the response, and the request is sent
+    /// again once, signed at the corrected time like all requests after it.
+    async fn dispatch_correcting_clock_skew(
         &self,
         request: SignedRequest,
+        attempt: u32,
+        span: &RequestSpan,
+        metrics: Option<&RequestMetrics>,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        self.inner.sign_and_dispatch(request, None).await
+        // Keep an unsigned copy to sign again at the corrected time.
+        let corrected = request.try_clone().ok();
+        let result = self
+            .intercept_and_dispatch(request, attempt, span, metrics)
+            .await;
+        let (mut response, corrected) = match (result, corrected) {
+            (Ok(response), Some(corrected))
+                if clock_skew::is_clock_skew_status(response.status) =>
+            {
+                (response, corrected)
+            }
+            (result, _) => return result,
+        };
+        let buffered = response
+            .buffer()
+            .await
+            .map_err(SignAndDispatchError::Dispatch)?;
+        if !self.clock_skew.correct(&buffered) {
+            return Ok(HttpResponse {
+                status: buffered.status,
+                body: ByteStream::from(buffered.body.to_vec()),
+                headers: buffered.headers,
+            });
+        }
+        debug!(
+            "Sending {} {} again, the clock is off by {}ms",
+            corrected.method,
+            corrected.path,
+            self.clock_skew.offset_millis()
+        );
+        self.intercept_and_dispatch(corrected, attempt, span, metrics)
+            .await
+    }
+
+    /// Sign and dispatch one attempt of a request, running the interceptors around it.
+    async fn intercept_and_dispatch(
+        &self,
+        mut request: SignedRequest,
+        attempt: u32,
+        span: &RequestSpan,
+        metrics: Option<&RequestMetrics>,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        span.record_attempt(attempt);
+        if let Some(metrics) = metrics {
+            metrics.attempt_start(attempt);
+        }
+        for interceptor in &self.interceptors {
+            interceptor
+                .before_sign(&mut request, attempt)
+                .map_err(SignAndDispatchError::Interceptor)?;
+        }
+        let result = self
+            .inner
+            .sign_and_dispatch(request, None, metrics, &self.clock_skew)
+            .await;
+        for interceptor in &self.interceptors {
+            interceptor
+                .after_dispatch(result.as_ref(), attempt)
+                .map_err(SignAndDispatchError::Interceptor)?;
+        }
+        result
+    }
+
+    /// Set the scheme and hostname of `request` to the endpoint this client sends it to, as
+    /// `sign_and_dispatch` does for every request. Requests whose endpoint is the default one
+    /// for their region are left unchanged. STS requests sent to the legacy global endpoint
+    /// also get the region they are signed for, `us-east-1`.
+    pub fn resolve_endpoint(&self, request: &mut SignedRequest) -> Result<(), EndpointError> {
+        let mut signing_region = None;
+        let endpoint = match (&self.endpoint_resolver, &request.region) {
+            (Some(resolver), region) => Some(resolver.resolve(&request.service, region)),
+            // Without a resolver the request already has the endpoint of its region, so it only
+            // changes if one is configured in the environment or a variant is enabled.
+            (None, Region::Custom { .. }) => None,
+            (None, region) => match DefaultEndpointResolver::from_env(&request.service) {
+                Some(endpoint) => Some(endpoint),
+                None if self.endpoint_variants == EndpointVariants::default() => {
+                    match request.service.as_str() {
+                        "sts" => self.sts_regional_endpoints.global_endpoint(region).map(
+                            |(endpoint, region)| {
+                                signing_region = Some(region);
+                                endpoint
+                            },
+                        ),
+                        _ => None,
+                    }
+                }
+                None => Some(Endpoint::for_region_with_variants(
+                    &request.service,
+                    region,
+                    self.endpoint_variants,
+                )?),
+            },
+        };
+        if let Some(endpoint) = endpoint {
+            request.scheme = Some(endpoint.scheme);
+            request.hostname = Some(endpoint.hostname);
+        }
+        if let Some(region) = signing_region {
+            request.region = region;
+        }
+        Ok(())
+    }
+}
+
+/// Why a request is retried.
+struct RetryReason {
+    message: String,
+    throttling: bool,
+    /// The delay the response asked for with `Retry-After`.
+    retry_after: Option<Duration>,
+}
+
+impl RetryReason {
+    fn new<S: Into<String>>(message: S) -> Self {
+        RetryReason {
+            message: message.into(),
+            throttling: false,
+            retry_after: None,
+        }
+    }
+}
+
+/// Returns `result`, and why the request that produced it should be retried, if it should be.
+/// The bodies of client error responses are buffered to read their error code.
+async fn classify(
+    result: Result<HttpResponse, SignAndDispatchError>,
+) -> (
+    Result<HttpResponse, SignAndDispatchError>,
+    Option<RetryReason>,
+) {
+    let mut response = match result {
+        Ok(response) => response,
+        Err(SignAndDispatchError::Dispatch(err)) => {
+            let reason = RetryReason::new(err.to_string());
+            return (Err(SignAndDispatchError::Dispatch(err)), Some(reason));
+        }
+        Err(err) => return (Err(err), None),
+    };
+    if retry::is_retryable_status(response.status) {
+        let reason = RetryReason {
+            throttling: response.status == http::StatusCode::TOO_MANY_REQUESTS,
+            retry_after: retry::retry_after(&response.headers),
+            ..RetryReason::new(format!("HTTP {}", response.status))
+        };
+        return (Ok(response), Some(reason));
+    }
+    if !response.status.is_client_error() {
+        return (Ok(response), None);
+    }
+    let buffered = match response.buffer().await {
+        Ok(buffered) => buffered,
+        Err(err) => {
+            let reason = RetryReason::new(err.to_string());
+            return (Err(SignAndDispatchError::Dispatch(err)), Some(reason));
+        }
+    };
+    let reason = match retry::error_code(&buffered) {
+        Some(code) if retry::is_retryable_error_code(&code) => Some(RetryReason {
+            throttling: retry::is_throttling_error_code(&code),
+            retry_after: retry::retry_after(&buffered.headers),
+            message: code,
+        }),
+        _ => None,
+    };
+    let response = HttpResponse {
+        status: buffered.status,
+        body: ByteStream::from(buffered.body.to_vec()),
+        headers: buffered.headers,
+    };
+    (Ok(response), reason)
+}
+
+/// How the attempt that produced `result` ended, given why it should be retried, if it should.
+fn outcome(
+    result: &Result<HttpResponse, SignAndDispatchError>,
+    reason: Option<&RetryReason>,
+) -> Outcome {
+    let response = match *result {
+        Ok(ref response) => response,
+        Err(ref err) => {
+            let classification = match *err {
+                SignAndDispatchError::Credentials(_) => ErrorClassification::Credentials,
+                SignAndDispatchError::Dispatch(ref err) if err.timeout().is_some() => {
+                    ErrorClassification::Timeout
+                }
+                SignAndDispatchError::Dispatch(_) => ErrorClassification::Dispatch,
+                SignAndDispatchError::Endpoint(_) | SignAndDispatchError::Interceptor(_) => {
+                    ErrorClassification::Rejected
+                }
+            };
+            return Outcome {
+                status: None,
+                classification,
+            };
+        }
+    };
+    let status = response.status;
+    let classification = match reason {
+        Some(reason) if reason.throttling => ErrorClassification::Throttling,
+        Some(_) => ErrorClassification::Transient,
+        None if status.is_success() || status.is_redirection() => ErrorClassification::Success,
+        None if status == http::StatusCode::TOO_MANY_REQUESTS => ErrorClassification::Throttling,
+        None if retry::is_retryable_status(status) => ErrorClassification::Transient,
+        None if status.is_client_error() => ErrorClassification::Client,
+        None => ErrorClassification::Server,
+    };
+    Outcome {
+        status: Some(status),
+        classification,
     }
 }
 
@@ -114,6 +592,10 @@ pub enum SignAndDispatchError {
     Credentials(CredentialsError),
     /// Error was due to http dispatch
     Dispatch(HttpDispatchError),
+    /// Error was due to the endpoint not being available for the enabled variants
+    Endpoint(EndpointError),
+    /// Error was returned by an interceptor
+    Interceptor(InterceptorError),
 }
 
 #[async_trait]
@@ -122,6 +604,8 @@ trait SignAndDispatch {
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        metrics: Option<&RequestMetrics>,
+        clock_skew: &ClockSkew,
     ) -> Result<HttpResponse, SignAndDispatchError>;
 }
 
@@ -145,6 +629,8 @@ async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
+    metrics: Option<&RequestMetrics>,
+    clock_skew: &ClockSkew,
 ) -> Result<HttpResponse, SignAndDispatchError>
 where
     P: ProvideAwsCredentials + Send + Sync + 'static,
@@ -152,21 +638,23 @@ where
 {
     client.content_encoding.encode(&mut request);
     if let Some(provider) = client.credentials_provider {
+        let start = Instant::now();
         let credentials = if let Some(to) = timeout {
             time::timeout(to, provider.credentials())
                 .await
-                .map_err(|_| CredentialsError {
-                    message: "Timeout getting credentials".to_owned(),
-                })
+                .map_err(|_| CredentialsError::new("Timeout getting credentials"))
                 .and_then(std::convert::identity)
         } else {
             provider.credentials().await
+        };
+        if let Some(metrics) = metrics {
+            metrics.credentials(credentials.is_ok(), start.elapsed());
         }
-        .map_err(SignAndDispatchError::Credentials)?;
+        let credentials = credentials.map_err(SignAndDispatchError::Credentials)?;
         if credentials.is_anonymous() {
             request.complement();
         } else {
-            request.sign(&credentials);
+            request.sign_at(&credentials, clock_skew.now());
         }
     } else {
         request.complement();
@@ -188,8 +676,10 @@ where
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        metrics: Option<&RequestMetrics>,
+        clock_skew: &ClockSkew,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+        sign_and_dispatch(self.clone(), request, timeout, metrics, clock_skew).await
     }
 }
 
@@ -199,3 +689,712 @@ fn client_is_send_and_sync() {
 
     is_send_and_sync::<Client>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::AwsCredentials;
+    use crate::endpoint::Endpoint;
+    use crate::metrics::RequestInfo;
+    use crate::request::DispatchSignedRequestFuture;
+    use crate::ByteStream;
+    use futures::FutureExt;
+    use std::time::SystemTime;
+
+    /// Records the host of each request it dispatches.
+    #[derive(Clone, Default)]
+    struct HostRecorder(Arc<Mutex<Vec<String>>>);
+
+    impl DispatchSignedRequest for HostRecorder {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let url = format!("{}://{}", request.scheme(), request.hostname());
+            let host = String::from_utf8(request.headers()["host"][0].clone()).unwrap();
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} host={}", url, host));
+            futures::future::ready(Ok(HttpResponse {
+                status: http::StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers: Default::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn endpoint_resolver_sets_request_and_signed_host() {
+        let recorder = HostRecorder::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, recorder.clone()).with_endpoint_resolver(
+            |service: &str, region: &Region| match service {
+                "s3" => Endpoint::from_url("http://minio:9000"),
+                _ => Endpoint::for_region(service, region),
+            },
+        );
+
+        for service in &["s3", "sqs"] {
+            let request = SignedRequest::new("GET", service, &Region::UsWest2, "/");
+            client.sign_and_dispatch(request).await.unwrap();
+        }
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![
+                "http://minio:9000 host=minio:9000",
+                "https://sqs.us-west-2.amazonaws.com host=sqs.us-west-2.amazonaws.com",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn clients_share_boxed_and_shared_providers() {
+        let recorder = HostRecorder::default();
+        let credentials: Arc<dyn ProvideAwsCredentials + Send + Sync> = Arc::new(
+            StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+        );
+        let boxed: Box<dyn ProvideAwsCredentials + Send + Sync> = Box::new(credentials.clone());
+        let clients = vec![
+            Client::new_with(credentials.clone(), recorder.clone()),
+            Client::new_with(credentials, recorder.clone()),
+            Client::new_with(boxed, recorder.clone()),
+        ];
+
+        for client in &clients {
+            let request = SignedRequest::new("GET", "sqs", &Region::UsWest2, "/");
+            client.sign_and_dispatch(request).await.unwrap();
+        }
+        assert_eq!(recorder.0.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn endpoint_variants_set_request_host() {
+        let recorder = HostRecorder::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, recorder.clone())
+            .with_fips_endpoint(true)
+            .with_dualstack_endpoint(false);
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+        let request = SignedRequest::new("GET", "s3", &Region::EuWest1, "/");
+        let err = match client.sign_and_dispatch(request).await {
+            Ok(_) => panic!("request to a missing FIPS endpoint succeeded"),
+            Err(err) => err,
+        };
+
+        assert_eq!(
+            err,
+            SignAndDispatchError::Endpoint(EndpointError::new(
+                "s3 does not have a FIPS endpoint in eu-west-1"
+            ))
+        );
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec!["https://s3-fips.us-east-1.amazonaws.com host=s3-fips.us-east-1.amazonaws.com"]
+        );
+    }
+
+    #[tokio::test]
+    async fn sts_regional_endpoints_set_request_host_and_signing_region() {
+        let recorder = HostRecorder::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, recorder.clone())
+            .with_fips_endpoint(false)
+            .with_dualstack_endpoint(false)
+            .with_sts_regional_endpoints(StsRegionalEndpoints::Regional);
+
+        let mut request = SignedRequest::new("POST", "sts", &Region::EuWest1, "/");
+        client.resolve_endpoint(&mut request).unwrap();
+        assert_eq!(request.hostname(), "sts.eu-west-1.amazonaws.com");
+        assert_eq!(request.region, Region::EuWest1);
+
+        let client = client.with_sts_regional_endpoints(StsRegionalEndpoints::Legacy);
+        let mut request = SignedRequest::new("POST", "sts", &Region::EuWest1, "/");
+        client.resolve_endpoint(&mut request).unwrap();
+        assert_eq!(request.hostname(), "sts.amazonaws.com");
+        assert_eq!(request.region, Region::UsEast1);
+
+        for region in &[Region::ApEast1, Region::EuWest1] {
+            let request = SignedRequest::new("POST", "sts", region, "/");
+            client.sign_and_dispatch(request).await.unwrap();
+        }
+        let request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![
+                "https://sts.ap-east-1.amazonaws.com host=sts.ap-east-1.amazonaws.com",
+                "https://sts.amazonaws.com host=sts.amazonaws.com",
+                "https://sqs.eu-west-1.amazonaws.com host=sqs.eu-west-1.amazonaws.com",
+            ]
+        );
+    }
+
+    /// Rejects the first request it dispatches for its signing time, with a `Date` header 10
+    /// minutes ahead, and answers the others, recording when every request was signed.
+    #[derive(Clone, Default)]
+    struct SkewedClockDispatcher(Arc<Mutex<Vec<SystemTime>>>);
+
+    impl DispatchSignedRequest for SkewedClockDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let date = String::from_utf8(request.headers()["x-amz-date"][0].clone()).unwrap();
+            let mut signed = self.0.lock().unwrap();
+            signed.push(clock_skew::parse_iso8601(&date).unwrap());
+            let mut headers = http::HeaderMap::<String>::default();
+            let (status, body) = if signed.len() == 1 {
+                let server_time = SystemTime::now() + Duration::from_secs(600);
+                headers.insert("date", httpdate::fmt_http_date(server_time));
+                (
+                    http::StatusCode::FORBIDDEN,
+                    r#"{"__type": "InvalidSignatureException", "message": "Signature not yet current"}"#,
+                )
+            } else {
+                (http::StatusCode::OK, "{}")
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status,
+                body: ByteStream::from(body.as_bytes().to_vec()),
+                headers,
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn clock_skew_errors_are_corrected_and_sent_again_once() {
+        let dispatcher = SkewedClockDispatcher::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher.clone())
+            .with_retry_policy(RetryPolicy::new().with_max_attempts(1));
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, http::StatusCode::OK);
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let now = SystemTime::now();
+        let signed = dispatcher.0.lock().unwrap();
+        assert_eq!(signed.len(), 3);
+        let ahead = |time: SystemTime| match time.duration_since(now) {
+            Ok(ahead) => ahead.as_secs() as i64,
+            Err(behind) => -(behind.duration().as_secs() as i64),
+        };
+        assert!(ahead(signed[0]).abs() < 5, "{:?}", signed[0]);
+        for corrected in &signed[1..] {
+            assert!((590..=605).contains(&ahead(*corrected)), "{:?}", corrected);
+        }
+    }
+
+    /// A response status and body, or a dispatch error message.
+    type ScriptedResponse = Result<(u16, &'static str), &'static str>;
+
+    /// Answers each request with the next of a scripted sequence of responses, recording the
+    /// `amz-sdk-request` header of every attempt.
+    #[derive(Clone, Default)]
+    struct ScriptedDispatcher {
+        responses: Arc<Mutex<Vec<ScriptedResponse>>>,
+        attempts: Arc<Mutex<Vec<String>>>,
+        retry_after: Option<&'static str>,
+    }
+
+    impl ScriptedDispatcher {
+        fn new(mut responses: Vec<ScriptedResponse>) -> Self {
+            responses.reverse();
+            ScriptedDispatcher {
+                responses: Arc::new(Mutex::new(responses)),
+                attempts: Default::default(),
+                retry_after: None,
+            }
+        }
+
+        /// Adds a `Retry-After` header to every response.
+        fn with_retry_after(mut self, retry_after: &'static str) -> Self {
+            self.retry_after = Some(retry_after);
+            self
+        }
+    }
+
+    impl DispatchSignedRequest for ScriptedDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let attempt = String::from_utf8(request.headers()[AMZ_SDK_REQUEST][0].clone());
+            self.attempts.lock().unwrap().push(attempt.unwrap());
+            let mut headers = http::HeaderMap::<String>::default();
+            if let Some(retry_after) = self.retry_after {
+                headers.insert("retry-after", retry_after.to_owned());
+            }
+            let response = match self.responses.lock().unwrap().pop().unwrap() {
+                Ok((status, body)) => Ok(HttpResponse {
+                    status: http::StatusCode::from_u16(status).unwrap(),
+                    body: ByteStream::from(body.as_bytes().to_vec()),
+                    headers,
+                }),
+                Err(message) => Err(HttpDispatchError::new(message.to_owned())),
+            };
+            futures::future::ready(response).boxed()
+        }
+    }
+
+    /// A client retrying with ceilings of 1, 2, 4 and 4 milliseconds, recording the delays.
+    fn retrying_client(dispatcher: ScriptedDispatcher) -> (Client, Arc<Mutex<Vec<Duration>>>) {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded = delays.clone();
+        let policy = RetryPolicy::new()
+            .with_max_attempts(5)
+            .with_base_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(4))
+            .with_jitter(move |ceiling| {
+                recorded.lock().unwrap().push(ceiling);
+                ceiling
+            });
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher).with_retry_policy(policy);
+        (client, delays)
+    }
+
+    fn millis(delays: &[u64]) -> Vec<Duration> {
+        delays.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors_with_backoff() {
+        let dispatcher = ScriptedDispatcher::new(vec![
+            Err("connection reset"),
+            Ok((503, "")),
+            Ok((
+                400,
+                r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException"}"#,
+            )),
+            Ok((200, "done")),
+        ]);
+        let (client, delays) = retrying_client(dispatcher.clone());
+
+        let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "done");
+        assert_eq!(*delays.lock().unwrap(), millis(&[1, 2, 4]));
+        assert_eq!(
+            *dispatcher.attempts.lock().unwrap(),
+            vec![
+                "attempt=1; max=5",
+                "attempt=2; max=5",
+                "attempt=3; max=5",
+                "attempt=4; max=5",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let dispatcher = ScriptedDispatcher::new(vec![Ok((500, "")); 5]);
+        let (client, delays) = retrying_client(dispatcher.clone());
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, http::StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(*delays.lock().unwrap(), millis(&[1, 2, 4, 4]));
+        assert_eq!(dispatcher.attempts.lock().unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_errors() {
+        let body = r#"{"__type":"ValidationException","message":"bad"}"#;
+        let dispatcher = ScriptedDispatcher::new(vec![Ok((400, body))]);
+        let (client, delays) = retrying_client(dispatcher.clone());
+
+        let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+        // The buffered body is handed back intact.
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), body);
+        assert!(delays.lock().unwrap().is_empty());
+        assert_eq!(dispatcher.attempts.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn honors_retry_after() {
+        let dispatcher =
+            ScriptedDispatcher::new(vec![Ok((429, "")), Ok((200, ""))]).with_retry_after("0");
+        let (client, delays) = retrying_client(dispatcher.clone());
+
+        let request = SignedRequest::new("GET", "apigateway", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, http::StatusCode::OK);
+        assert_eq!(*delays.lock().unwrap(), millis(&[1]));
+
+        // Waiting a second is longer than the maximum delay of 4 milliseconds.
+        let dispatcher =
+            ScriptedDispatcher::new(vec![Ok((503, "")), Ok((200, ""))]).with_retry_after("1");
+        let (client, _) = retrying_client(dispatcher.clone());
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, http::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(dispatcher.attempts.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn adaptive_mode_shares_quota_and_rate_between_clones() {
+        let dispatcher = ScriptedDispatcher::new(vec![
+            Ok((400, "<Error><Code>SlowDown</Code></Error>")),
+            Ok((200, "")),
+            Ok((503, "")),
+            Ok((503, "")),
+        ]);
+        let (client, _) = retrying_client(dispatcher.clone());
+        let policy = client
+            .retry_policy()
+            .clone()
+            .with_max_attempts(2)
+            .with_mode(RetryMode::Adaptive);
+        let client = client.with_retry_policy(policy);
+        let clone = client.clone();
+        assert_eq!(
+            client.adaptive_retry_stats().unwrap().available_tokens,
+            retry::RETRY_QUOTA_CAPACITY
+        );
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+        // The retry that succeeded was refunded.
+        let stats = clone.adaptive_retry_stats().unwrap();
+        assert_eq!(stats.available_tokens, retry::RETRY_QUOTA_CAPACITY);
+        assert!(stats.send_rate.is_some());
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = clone.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, http::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            client.adaptive_retry_stats().unwrap().available_tokens,
+            retry::RETRY_QUOTA_CAPACITY - retry::RETRY_COST
+        );
+        assert_eq!(dispatcher.attempts.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn standard_mode_has_no_adaptive_stats() {
+        let (client, _) = retrying_client(ScriptedDispatcher::default());
+        assert_eq!(client.adaptive_retry_stats(), None);
+    }
+
+    /// Records span and event fields as `name=value` strings.
+    #[cfg(feature = "tracing")]
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    #[cfg(feature = "tracing")]
+    struct Fields<'a>(&'a mut String);
+
+    #[cfg(feature = "tracing")]
+    impl<'a> tracing::field::Visit for Fields<'a> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            use std::fmt::Write;
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Capture {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut line = format!("span {}", span.metadata().name());
+            span.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+            tracing::span::Id::from_u64(1)
+        }
+        fn record(&self, _: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            let mut line = "record".to_owned();
+            values.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+        }
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut line = "event".to_owned();
+            event.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn requests_run_in_spans_recording_retries() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let dispatcher = ScriptedDispatcher::new(vec![Ok((503, "")), Ok((200, ""))]);
+        let (client, _) = retrying_client(dispatcher);
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("GetObject");
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let lines = capture.0.lock().unwrap().clone();
+        assert!(
+            lines[0].starts_with("span aws.request otel.name=aws.s3.GetObject"),
+            "{:?}",
+            lines
+        );
+        assert!(lines[0].contains("aws.region=\"us-east-1\""), "{:?}", lines);
+        let retries: Vec<_> = lines
+            .iter()
+            .filter(|line| line.contains("retrying request"))
+            .collect();
+        assert_eq!(retries.len(), 1, "{:?}", lines);
+        assert!(retries[0].contains("reason=\"HTTP 503"), "{:?}", lines);
+        assert!(
+            lines.contains(&"record aws.attempts=2".to_owned()),
+            "{:?}",
+            lines
+        );
+        assert!(
+            lines.contains(&"record http.status_code=200".to_owned()),
+            "{:?}",
+            lines
+        );
+        // Neither the credentials nor the signature are recorded.
+        assert!(
+            lines
+                .iter()
+                .all(|line| !line.contains("secret") && !line.contains("Signature")),
+            "{:?}",
+            lines
+        );
+    }
+
+    /// Records the hooks it runs in, and fails those configured to.
+    struct RecordingInterceptor {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+        fail_before_sign: bool,
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn before_sign(
+            &self,
+            request: &mut SignedRequest,
+            attempt: u32,
+        ) -> Result<(), InterceptorError> {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} before_sign {}", self.name, attempt));
+            request.add_header("x-intercepted-by", self.name);
+            if self.fail_before_sign {
+                return Err(InterceptorError::new(format!("{} refused", self.name)));
+            }
+            Ok(())
+        }
+
+        fn after_dispatch(
+            &self,
+            result: Result<&HttpResponse, &SignAndDispatchError>,
+            attempt: u32,
+        ) -> Result<(), InterceptorError> {
+            let outcome = match result {
+                Ok(response) => response.status.as_u16().to_string(),
+                Err(err) => format!("{:?}", err),
+            };
+            self.events.lock().unwrap().push(format!(
+                "{} after_dispatch {} {}",
+                self.name, attempt, outcome
+            ));
+            Ok(())
+        }
+    }
+
+    fn recording_interceptor(
+        name: &'static str,
+        events: &Arc<Mutex<Vec<String>>>,
+    ) -> Box<RecordingInterceptor> {
+        Box::new(RecordingInterceptor {
+            name,
+            events: events.clone(),
+            fail_before_sign: false,
+        })
+    }
+
+    #[tokio::test]
+    async fn interceptors_run_in_order_for_every_attempt() {
+        let dispatcher = ScriptedDispatcher::new(vec![Ok((503, "")), Ok((200, ""))]);
+        let (mut client, _) = retrying_client(dispatcher);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        client.add_interceptor(recording_interceptor("first", &events));
+        client.add_interceptor(recording_interceptor("second", &events));
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, http::StatusCode::OK);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "first before_sign 1",
+                "second before_sign 1",
+                "first after_dispatch 1 503",
+                "second after_dispatch 1 503",
+                "first before_sign 2",
+                "second before_sign 2",
+                "first after_dispatch 2 200",
+                "second after_dispatch 2 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn interceptors_can_end_requests() {
+        let recorder = HostRecorder::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut client = Client::new_with(credentials, recorder.clone());
+        let events = Arc::new(Mutex::new(Vec::new()));
+        client.add_interceptor(Box::new(RecordingInterceptor {
+            fail_before_sign: true,
+            ..*recording_interceptor("first", &events)
+        }));
+        client.add_interceptor(recording_interceptor("second", &events));
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        let err = match client.sign_and_dispatch(request).await {
+            Ok(_) => panic!("request refused by an interceptor was sent"),
+            Err(err) => err,
+        };
+
+        assert_eq!(
+            err,
+            SignAndDispatchError::Interceptor(InterceptorError::new("first refused"))
+        );
+        assert_eq!(*events.lock().unwrap(), vec!["first before_sign 1"]);
+        assert!(recorder.0.lock().unwrap().is_empty());
+    }
+
+    /// Records the calls it gets, as strings.
+    #[derive(Clone, Default)]
+    struct RecordingObserver(Arc<Mutex<Vec<String>>>);
+
+    impl MetricsObserver for RecordingObserver {
+        fn on_attempt_start(&self, request: &RequestInfo<'_>, attempt: u32) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} start {}", request, attempt));
+        }
+
+        fn on_attempt_end(
+            &self,
+            request: &RequestInfo<'_>,
+            attempt: u32,
+            outcome: &Outcome,
+            _duration: Duration,
+        ) {
+            let status = outcome.status.map(|status| status.as_u16()).unwrap_or(0);
+            self.0.lock().unwrap().push(format!(
+                "{} end {} {} {}",
+                request, attempt, status, outcome.classification
+            ));
+        }
+
+        fn on_request_complete(
+            &self,
+            request: &RequestInfo<'_>,
+            attempts: u32,
+            outcome: &Outcome,
+            _duration: Duration,
+        ) {
+            self.0.lock().unwrap().push(format!(
+                "{} complete {} {}",
+                request, attempts, outcome.classification
+            ));
+        }
+
+        fn on_credentials(&self, request: &RequestInfo<'_>, success: bool, _duration: Duration) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} credentials {}", request, success));
+        }
+    }
+
+    #[tokio::test]
+    async fn metrics_observers_see_every_attempt() {
+        let dispatcher =
+            ScriptedDispatcher::new(vec![Ok((503, "")), Err("connection reset"), Ok((404, ""))]);
+        let (client, _) = retrying_client(dispatcher);
+        let observer = RecordingObserver::default();
+        let client = client.with_metrics_observer(observer.clone());
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("GetObject");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            *observer.0.lock().unwrap(),
+            vec![
+                "s3.GetObject start 1",
+                "s3.GetObject credentials true",
+                "s3.GetObject end 1 503 transient",
+                "s3.GetObject start 2",
+                "s3.GetObject credentials true",
+                "s3.GetObject end 2 0 dispatch",
+                "s3.GetObject start 3",
+                "s3.GetObject credentials true",
+                "s3.GetObject end 3 404 client",
+                "s3.GetObject complete 3 client",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics_observers_see_credential_failures() {
+        let dispatcher = ScriptedDispatcher::new(vec![]);
+        let observer = RecordingObserver::default();
+        let client = Client::new_with(FailingCredentials, dispatcher.clone())
+            .with_metrics_observer(observer.clone());
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.add_param("Action", "SendMessage");
+        assert!(client.sign_and_dispatch(request).await.is_err());
+
+        assert!(dispatcher.attempts.lock().unwrap().is_empty());
+        assert_eq!(
+            *observer.0.lock().unwrap(),
+            vec![
+                "sqs.SendMessage start 1",
+                "sqs.SendMessage credentials false",
+                "sqs.SendMessage end 1 0 credentials",
+                "sqs.SendMessage complete 1 credentials",
+            ]
+        );
+    }
+
+    struct FailingCredentials;
+
+    #[async_trait]
+    impl ProvideAwsCredentials for FailingCredentials {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            Err(CredentialsError::new("no credentials"))
+        }
+    }
+}
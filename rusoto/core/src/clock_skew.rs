@@ -0,0 +1,220 @@
+//! Correction of the skew between the local clock and the clock of AWS services.
+//!
+//! Services reject requests signed more than a few minutes before or after their own time.
+//! When a response rejects a request for that reason, the offset between the server time it
+//! reports and the local time is kept and added to the time of every request signed later.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use http::StatusCode;
+
+use crate::request::BufferedHttpResponse;
+use crate::retry;
+
+/// Error codes of requests rejected for being signed at a time too far from the server time.
+const CLOCK_SKEW_ERROR_CODES: &[&str] = &[
+    "AuthFailure",
+    "InvalidSignatureException",
+    "RequestExpired",
+    "RequestInTheFuture",
+    "RequestTimeTooSkewed",
+    "SignatureDoesNotMatch",
+];
+
+/// The smallest change of the offset, in milliseconds, worth sending a request again for.
+/// Smaller changes come from the one second precision of the `Date` header and the time the
+/// response took to arrive, and don't explain a rejected signature.
+const MIN_CORRECTION_MILLIS: i64 = 60_000;
+
+/// The offset of a client's clock, shared by all its clones.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClockSkew(Arc<AtomicI64>);
+
+impl ClockSkew {
+    /// The milliseconds the server clock is ahead of the local one, negative if it's behind.
+    pub(crate) fn offset_millis(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// The current time corrected for the skew.
+    pub(crate) fn now(&self) -> SystemTime {
+        let now = SystemTime::now();
+        let offset = self.offset_millis();
+        if offset >= 0 {
+            now + Duration::from_millis(offset as u64)
+        } else {
+            now - Duration::from_millis(offset.unsigned_abs())
+        }
+    }
+
+    /// Corrects the skew if `response` rejected a request for its signing time and reports a
+    /// server time far enough from the corrected local time. Returns whether it did, in which
+    /// case the request is worth sending again.
+    pub(crate) fn correct(&self, response: &BufferedHttpResponse) -> bool {
+        match retry::error_code(response) {
+            Some(ref code) if is_clock_skew_error_code(code) => {}
+            _ => return false,
+        }
+        let server_time = match server_time(response) {
+            Some(server_time) => server_time,
+            None => return false,
+        };
+        let offset = millis_between(SystemTime::now(), server_time);
+        if (offset - self.offset_millis()).abs() < MIN_CORRECTION_MILLIS {
+            return false;
+        }
+        self.0.store(offset, Ordering::Relaxed);
+        true
+    }
+}
+
+/// Whether responses with `status` may reject a request for its signing time.
+pub(crate) fn is_clock_skew_status(status: StatusCode) -> bool {
+    status == StatusCode::BAD_REQUEST
+        || status == StatusCode::UNAUTHORIZED
+        || status == StatusCode::FORBIDDEN
+}
+
+/// Whether `code` is the error code of a request rejected for its signing time, or for a
+/// signature that may be wrong because of it.
+pub(crate) fn is_clock_skew_error_code(code: &str) -> bool {
+    CLOCK_SKEW_ERROR_CODES.contains(&code)
+}
+
+/// The server time of a response, from its `Date` header, or otherwise from the time in its
+/// error message: the `<ServerTime>` of S3, or the time in parentheses of messages like
+/// `Signature expired: 20200101T000000Z is now earlier than 20200101T001000Z (20200101T000500Z
+/// - 5 min.)`, which is the server time less 5 minutes.
+fn server_time(response: &BufferedHttpResponse) -> Option<SystemTime> {
+    if let Some(date) = response.headers.get("date") {
+        if let Ok(date) = httpdate::parse_http_date(date.trim()) {
+            return Some(date);
+        }
+    }
+    let body = response.body_as_str();
+    if let Some(start) = body.find("<ServerTime>") {
+        let start = start + "<ServerTime>".len();
+        let end = start + body[start..].find("</ServerTime>")?;
+        return parse_iso8601(body[start..end].trim());
+    }
+    let reported = &body[body.rfind('(')? + 1..];
+    let time = parse_iso8601(reported.split_whitespace().next()?)?;
+    let shift = Duration::from_secs(5 * 60);
+    if reported.contains("- 5 min.") {
+        Some(time + shift)
+    } else if reported.contains("+ 5 min.") {
+        Some(time - shift)
+    } else {
+        Some(time)
+    }
+}
+
+/// Parses UTC times like `2020-01-01T00:05:00.000Z` or `20200101T000500Z`.
+pub(crate) fn parse_iso8601(time: &str) -> Option<SystemTime> {
+    let digits: String = time
+        .trim_end_matches('Z')
+        .split('.')
+        .next()?
+        .chars()
+        .filter(|c| *c != '-' && *c != ':')
+        .collect();
+    if digits.len() != 15 || digits.as_bytes()[8] != b'T' {
+        return None;
+    }
+    let field = |range: std::ops::Range<usize>| digits.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (field(0..4)?, field(4..6)?, field(6..8)?);
+    let (hour, minute, second) = (field(9..11)?, field(11..13)?, field(13..15)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second;
+    if seconds < 0 {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(seconds as u64))
+}
+
+/// The days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The milliseconds from `from` to `to`, negative if `to` is earlier.
+fn millis_between(from: SystemTime, to: SystemTime) -> i64 {
+    match to.duration_since(from) {
+        Ok(ahead) => ahead.as_millis() as i64,
+        Err(behind) => -(behind.duration().as_millis() as i64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    fn response(date: Option<SystemTime>, body: &str) -> BufferedHttpResponse {
+        let mut headers = HeaderMap::<String>::default();
+        if let Some(date) = date {
+            headers.insert("date", httpdate::fmt_http_date(date));
+        }
+        BufferedHttpResponse {
+            status: StatusCode::FORBIDDEN,
+            body: Bytes::from(body.to_owned()),
+            headers,
+        }
+    }
+
+    #[test]
+    fn parses_iso8601_times() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_577_837_100);
+        assert_eq!(parse_iso8601("2020-01-01T00:05:00Z"), Some(time));
+        assert_eq!(parse_iso8601("2020-01-01T00:05:00.123Z"), Some(time));
+        assert_eq!(parse_iso8601("20200101T000500Z"), Some(time));
+        assert_eq!(parse_iso8601("2020-13-01T00:05:00Z"), None);
+        assert_eq!(parse_iso8601("yesterday"), None);
+    }
+
+    #[test]
+    fn reads_server_times_from_error_messages() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_577_837_100);
+        let s3 = response(
+            None,
+            "<Error><Code>RequestTimeTooSkewed</Code>\
+             <RequestTime>20191231T000000Z</RequestTime>\
+             <ServerTime>2020-01-01T00:05:00Z</ServerTime></Error>",
+        );
+        assert_eq!(server_time(&s3), Some(time));
+        let expired = response(
+            None,
+            r#"{"__type": "InvalidSignatureException", "message": "Signature expired: 20191231T000000Z is now earlier than 20200101T000000Z (20200101T000500Z - 5 min.)"}"#,
+        );
+        assert_eq!(server_time(&expired), Some(time + Duration::from_secs(300)));
+        let dated = response(Some(time), "{}");
+        assert_eq!(server_time(&dated), Some(time));
+    }
+
+    #[test]
+    fn corrects_only_skew_errors_far_from_the_local_time() {
+        let skew = ClockSkew::default();
+        let ahead = SystemTime::now() + Duration::from_secs(600);
+        let body = r#"{"__type": "InvalidSignatureException", "message": "Signature expired"}"#;
+
+        assert!(!skew.correct(&response(Some(ahead), r#"{"__type": "AccessDenied"}"#)));
+        assert!(!skew.correct(&response(Some(SystemTime::now()), body)));
+        assert_eq!(skew.offset_millis(), 0);
+
+        assert!(skew.correct(&response(Some(ahead), body)));
+        let offset = skew.offset_millis();
+        assert!((598_000..=601_000).contains(&offset), "{}", offset);
+        assert!(!skew.correct(&response(Some(ahead), body)));
+        assert!(skew.now() > SystemTime::now() + Duration::from_secs(590));
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+use std::env;
+
+use crate::credential::ProfileProvider;
+use crate::instrument::operation_name;
 use crate::signature::SignedRequest;
-#[cfg(feature = "encoding")]
 use crate::signature::SignedRequestPayload;
 #[cfg(feature = "encoding")]
 use flate2::{write::GzEncoder, Compression};
@@ -9,6 +13,155 @@ use std::io::Write;
 // Default compression level for gzip defined same as flate2
 pub const DEFAULT_GZIP_COMPRESSION_LEVEL: u32 = 6;
 
+/// The size of the smallest bodies a `RequestCompression` compresses by default, in bytes.
+pub const DEFAULT_MIN_COMPRESSION_SIZE: usize = 10 * 1024;
+
+/// The largest minimum size a `RequestCompression` accepts from the environment, in bytes.
+const MAX_MIN_COMPRESSION_SIZE: usize = 10 * 1024 * 1024;
+
+const AWS_DISABLE_REQUEST_COMPRESSION: &str = "AWS_DISABLE_REQUEST_COMPRESSION";
+const DISABLE_REQUEST_COMPRESSION: &str = "disable_request_compression";
+const AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES: &str = "AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES";
+const REQUEST_MIN_COMPRESSION_SIZE_BYTES: &str = "request_min_compression_size_bytes";
+
+/// How a `Client` compresses the bodies of requests to API operations that accept gzip
+/// compressed bodies, like CloudWatch's `PutMetricData`, set with
+/// `Client::with_request_compression`.
+///
+/// Bodies of at least the minimum size, `DEFAULT_MIN_COMPRESSION_SIZE` unless set otherwise,
+/// are gzip compressed and sent with `Content-Encoding: gzip`, and the compressed bytes are
+/// signed. Streamed bodies, and bodies that already have a `Content-Encoding`, are sent as
+/// they are. Compression needs the `encoding` feature: without it, no body is compressed.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::encoding::RequestCompression;
+///
+/// // Compress bodies from 64 KiB, but send those of `PutMetricData` as they are.
+/// let compression = RequestCompression::new()
+///     .with_min_size(64 * 1024)
+///     .with_operation("PutMetricData", false);
+/// assert_eq!(compression.min_size(), 64 * 1024);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestCompression {
+    disabled: bool,
+    min_size: usize,
+    operations: HashMap<String, bool>,
+}
+
+impl RequestCompression {
+    /// Compresses the bodies of at least `DEFAULT_MIN_COMPRESSION_SIZE` bytes of operations
+    /// that accept compressed bodies.
+    pub fn new() -> Self {
+        RequestCompression {
+            disabled: false,
+            min_size: DEFAULT_MIN_COMPRESSION_SIZE,
+            operations: HashMap::new(),
+        }
+    }
+
+    /// Never compresses bodies, e.g. to read them while debugging.
+    pub fn disabled() -> Self {
+        RequestCompression {
+            disabled: true,
+            ..RequestCompression::new()
+        }
+    }
+
+    /// Returns `new()`, or `disabled()` if `AWS_DISABLE_REQUEST_COMPRESSION` or the
+    /// `disable_request_compression` profile key is `true`, with the minimum size in
+    /// `AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES` or the `request_min_compression_size_bytes`
+    /// profile key, if set to at most 10 MiB. This is the setting of new clients.
+    pub fn from_env() -> Self {
+        let setting = |var: &str, key: &str| {
+            env::var(var)
+                .ok()
+                .or_else(|| ProfileProvider::setting(key).ok().and_then(|value| value))
+        };
+        let disabled = setting(AWS_DISABLE_REQUEST_COMPRESSION, DISABLE_REQUEST_COMPRESSION)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"));
+        let min_size = setting(
+            AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES,
+            REQUEST_MIN_COMPRESSION_SIZE_BYTES,
+        )
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|size| *size <= MAX_MIN_COMPRESSION_SIZE)
+        .unwrap_or(DEFAULT_MIN_COMPRESSION_SIZE);
+        RequestCompression {
+            disabled,
+            min_size,
+            operations: HashMap::new(),
+        }
+    }
+
+    /// Sets the size of the smallest bodies to compress, in bytes.
+    pub fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Compresses the bodies of requests to `operation`, e.g. `PutMetricData`, if `compress`
+    /// is true, even if the operation isn't known to accept compressed bodies, or never
+    /// compresses them otherwise.
+    pub fn with_operation<S>(mut self, operation: S, compress: bool) -> Self
+    where
+        S: Into<String>,
+    {
+        self.operations.insert(operation.into(), compress);
+        self
+    }
+
+    /// The size of the smallest bodies this compresses, in bytes.
+    pub fn min_size(&self) -> usize {
+        self.min_size
+    }
+
+    /// Compresses the body of `request` if it should be.
+    pub(crate) fn compress(&self, request: &mut SignedRequest) {
+        if self.applies_to(request) {
+            #[cfg(feature = "encoding")]
+            {
+                if let Some(SignedRequestPayload::Buffer(ref payload)) = request.payload {
+                    let compressed = gzip(payload, DEFAULT_GZIP_COMPRESSION_LEVEL);
+                    request.payload = Some(SignedRequestPayload::Buffer(compressed.into()));
+                    request.add_header("Content-Encoding", "gzip");
+                }
+            }
+        }
+    }
+
+    fn applies_to(&self, request: &SignedRequest) -> bool {
+        let size = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref payload)) => payload.len(),
+            _ => return false,
+        };
+        if self.disabled || size < self.min_size || request.headers.contains_key("content-encoding")
+        {
+            return false;
+        }
+        operation_name(request)
+            .and_then(|operation| self.operations.get(&operation).cloned())
+            .unwrap_or(request.request_compression)
+    }
+}
+
+impl Default for RequestCompression {
+    fn default() -> Self {
+        RequestCompression::new()
+    }
+}
+
+#[cfg(feature = "encoding")]
+fn gzip(payload: &[u8], level: u32) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::<u8>::new(), Compression::new(level));
+    encoder
+        .write_all(payload)
+        .expect("Request payload was not written to encoder.");
+    encoder.finish().expect("Failed to finish compression.")
+}
+
 #[derive(Debug, Clone)]
 pub enum ContentEncoding {
     /// Indicates the identity function (i.e., no compression or modification)
@@ -50,13 +203,11 @@ impl ContentEncoding {
                                 return;
                             }
                         }
-                        let mut encoder =
-                            GzEncoder::new(Vec::<u8>::new(), Compression::new(*level));
-                        encoder
-                            .write_all(payload)
-                            .expect("Request payload was not written to encoder.");
-                        let payload_compressed =
-                            encoder.finish().expect("Failed to finish compression.");
+                        // e.g. compressed already by the client's `RequestCompression`
+                        if request.headers.contains_key("content-encoding") {
+                            return;
+                        }
+                        let payload_compressed = gzip(payload, *level);
 
                         // Don't compress if payload length isn't decreased
                         if payload.len() < payload_compressed.len() {
@@ -75,3 +226,121 @@ impl ContentEncoding {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+
+    fn request(operation: &str, size: usize, compressible: bool) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        request.set_operation(operation);
+        request.set_payload(Some(vec![b'a'; size]));
+        request.set_request_compression(compressible);
+        request
+    }
+
+    #[test]
+    fn compresses_large_bodies_of_operations_accepting_them() {
+        let compression = RequestCompression::new();
+        assert!(compression.applies_to(&request("PutMetricData", 10 * 1024, true)));
+        assert!(!compression.applies_to(&request("PutMetricData", 10 * 1024 - 1, true)));
+        assert!(!compression.applies_to(&request("GetMetricData", 10 * 1024, false)));
+
+        let mut encoded = request("PutMetricData", 10 * 1024, true);
+        encoded.add_header("Content-Encoding", "identity");
+        assert!(!compression.applies_to(&encoded));
+
+        let mut streamed = request("PutMetricData", 0, true);
+        streamed.set_payload_stream(crate::ByteStream::from(vec![b'a'; 10 * 1024]));
+        assert!(!compression.applies_to(&streamed));
+
+        assert!(!RequestCompression::disabled().applies_to(&request(
+            "PutMetricData",
+            10 * 1024,
+            true
+        )));
+    }
+
+    #[test]
+    fn operation_overrides_take_precedence() {
+        let compression = RequestCompression::new()
+            .with_min_size(0)
+            .with_operation("PutMetricData", false)
+            .with_operation("GetMetricData", true);
+        assert!(!compression.applies_to(&request("PutMetricData", 1, true)));
+        assert!(compression.applies_to(&request("GetMetricData", 1, false)));
+        assert!(compression.applies_to(&request("PutDashboard", 1, true)));
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn compressed_bodies_are_signed() {
+        use std::io::Read;
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        use flate2::read::GzDecoder;
+        use futures::FutureExt;
+
+        use crate::credential::{AwsCredentials, StaticProvider};
+        use crate::request::{DispatchSignedRequestFuture, HttpResponse};
+        use crate::{ByteStream, Client, DispatchSignedRequest};
+
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Option<SignedRequest>>>);
+
+        impl DispatchSignedRequest for Recorder {
+            fn dispatch(
+                &self,
+                request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                *self.0.lock().unwrap() = Some(request);
+                futures::future::ready(Ok(HttpResponse {
+                    status: http::StatusCode::OK,
+                    body: ByteStream::from(Vec::new()),
+                    headers: Default::default(),
+                }))
+                .boxed()
+            }
+        }
+
+        let recorder = Recorder::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, recorder.clone())
+            .with_request_compression(RequestCompression::new());
+        client
+            .sign_and_dispatch(request("PutMetricData", 20 * 1024, true))
+            .await
+            .unwrap();
+
+        let sent = recorder.0.lock().unwrap().take().unwrap();
+        assert_eq!(sent.headers["content-encoding"], vec![b"gzip".to_vec()]);
+        let compressed = match sent.payload {
+            Some(SignedRequestPayload::Buffer(ref payload)) => payload.to_vec(),
+            ref other => panic!("unexpected payload: {:?}", other),
+        };
+        let mut body = Vec::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_end(&mut body)
+            .unwrap();
+        assert_eq!(body, vec![b'a'; 20 * 1024]);
+
+        let mut expected = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        expected.set_payload(Some(compressed));
+        expected.sign(&AwsCredentials::new("key", "secret", None, None));
+        assert_eq!(
+            sent.headers["x-amz-content-sha256"],
+            expected.headers["x-amz-content-sha256"]
+        );
+
+        let client = client.with_request_compression(RequestCompression::disabled());
+        client
+            .sign_and_dispatch(request("PutMetricData", 20 * 1024, true))
+            .await
+            .unwrap();
+        let sent = recorder.0.lock().unwrap().take().unwrap();
+        assert!(!sent.headers.contains_key("content-encoding"));
+    }
+}
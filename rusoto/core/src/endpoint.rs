@@ -0,0 +1,573 @@
+//! Resolution of the endpoint a request is sent to.
+//!
+//! By default requests go to the endpoint derived from their service and region, unless the
+//! `AWS_ENDPOINT_URL_<SERVICE>` or `AWS_ENDPOINT_URL` environment variable is set. A custom
+//! `EndpointResolver` can be given to `Client::with_endpoint_resolver` to choose endpoints per
+//! service, e.g. to send S3 requests to MinIO and DynamoDB requests to DynamoDB Local.
+//!
+//! FIPS and dual-stack (IPv6) endpoints are used when enabled with
+//! `Client::with_fips_endpoint` and `Client::with_dualstack_endpoint`, the
+//! `AWS_USE_FIPS_ENDPOINT` and `AWS_USE_DUALSTACK_ENDPOINT` environment variables, or the
+//! `use_fips_endpoint` and `use_dualstack_endpoint` keys of the current profile.
+//!
+//! STS requests go to the endpoint of their region, e.g. `sts.eu-west-1.amazonaws.com`
+//! signed for `eu-west-1`. Setting `AWS_STS_REGIONAL_ENDPOINTS` or the
+//! `sts_regional_endpoints` profile key to `legacy`, or calling
+//! `Client::with_sts_regional_endpoints`, sends requests in the regions that used the global
+//! endpoint to `sts.amazonaws.com` instead, signed for `us-east-1`.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+
+use crate::credential::ProfileProvider;
+use crate::region::Region;
+use crate::signature::SignedRequest;
+
+const AWS_ENDPOINT_URL: &str = "AWS_ENDPOINT_URL";
+const AWS_USE_FIPS_ENDPOINT: &str = "AWS_USE_FIPS_ENDPOINT";
+const AWS_USE_DUALSTACK_ENDPOINT: &str = "AWS_USE_DUALSTACK_ENDPOINT";
+const USE_FIPS_ENDPOINT: &str = "use_fips_endpoint";
+const USE_DUALSTACK_ENDPOINT: &str = "use_dualstack_endpoint";
+const AWS_STS_REGIONAL_ENDPOINTS: &str = "AWS_STS_REGIONAL_ENDPOINTS";
+const STS_REGIONAL_ENDPOINTS: &str = "sts_regional_endpoints";
+
+/// Services with FIPS endpoints of the form `<service>-fips.<region>.amazonaws.com`.
+const FIPS_SERVICES: &[&str] = &[
+    "acm",
+    "apigateway",
+    "cloudformation",
+    "cloudtrail",
+    "codebuild",
+    "codecommit",
+    "codedeploy",
+    "codepipeline",
+    "dynamodb",
+    "ec2",
+    "ecs",
+    "elasticache",
+    "elasticloadbalancing",
+    "elasticmapreduce",
+    "events",
+    "firehose",
+    "glacier",
+    "kinesis",
+    "kms",
+    "lambda",
+    "logs",
+    "monitoring",
+    "rds",
+    "redshift",
+    "s3",
+    "secretsmanager",
+    "sns",
+    "sqs",
+    "ssm",
+    "states",
+    "sts",
+    "swf",
+];
+
+/// Services with dual-stack endpoints. S3 uses `s3.dualstack.<region>.amazonaws.com`, the
+/// others `<service>.<region>.api.aws`.
+const DUALSTACK_SERVICES: &[&str] = &["ec2", "s3"];
+
+/// Regions whose STS requests go to the global endpoint with `StsRegionalEndpoints::Legacy`.
+const LEGACY_GLOBAL_STS_REGIONS: &[&str] = &[
+    "ap-northeast-1",
+    "ap-south-1",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ca-central-1",
+    "eu-central-1",
+    "eu-north-1",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "sa-east-1",
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+];
+
+/// The scheme and host a request is sent to.
+///
+/// The endpoint is used both for the HTTP request and for the `host` header that is signed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    /// The URL scheme, `http` or `https`.
+    pub scheme: String,
+    /// The host name, including the port if it isn't the default one for the scheme.
+    pub hostname: String,
+}
+
+impl Endpoint {
+    /// Creates an endpoint from a URL such as `http://localhost:9000`. The scheme defaults to
+    /// `https`, and any path after the host is ignored.
+    pub fn from_url(url: &str) -> Endpoint {
+        let url = url.trim();
+        let (scheme, rest) = match url.find("://") {
+            Some(pos) => (url[..pos].to_ascii_lowercase(), &url[pos + 3..]),
+            None => ("https".to_owned(), url),
+        };
+        let hostname = rest.split('/').next().unwrap_or_default().to_owned();
+        Endpoint { scheme, hostname }
+    }
+
+    /// Returns the endpoint rusoto uses for `service` in `region` when no endpoint is
+    /// configured.
+    pub fn for_region(service: &str, region: &Region) -> Endpoint {
+        let request = SignedRequest::new("GET", service, region, "/");
+        Endpoint {
+            scheme: request.scheme(),
+            hostname: request.hostname(),
+        }
+    }
+}
+
+impl Endpoint {
+    /// Returns the endpoint for `service` in `region` with the given variants. Returns an error
+    /// if the service has no such endpoint in the region. The endpoint of a `Region::Custom` is
+    /// returned as is.
+    pub fn for_region_with_variants(
+        service: &str,
+        region: &Region,
+        variants: EndpointVariants,
+    ) -> Result<Endpoint, EndpointError> {
+        if let Region::Custom { .. } = *region {
+            return Ok(Endpoint::for_region(service, region));
+        }
+        if !variants.fips && !variants.dualstack {
+            return Ok(Endpoint::for_region(service, region));
+        }
+        let name = region.name();
+        let unsupported = |variant: &str| {
+            Err(EndpointError::new(format!(
+                "{} does not have a {} endpoint in {}",
+                service, variant, name
+            )))
+        };
+        if variants.fips && !(FIPS_SERVICES.contains(&service) && fips_region(region)) {
+            return unsupported("FIPS");
+        }
+        if variants.dualstack && !DUALSTACK_SERVICES.contains(&service) {
+            return unsupported("dual-stack");
+        }
+        let suffix = match *region {
+            Region::CnNorth1 | Region::CnNorthwest1 => "amazonaws.com.cn",
+            _ => "amazonaws.com",
+        };
+        let fips = if variants.fips { "-fips" } else { "" };
+        let hostname = match (service, variants.dualstack) {
+            ("s3", true) => format!("s3{}.dualstack.{}.{}", fips, name, suffix),
+            (_, true) => {
+                if suffix != "amazonaws.com" {
+                    return unsupported("dual-stack");
+                }
+                format!("{}{}.{}.api.aws", service, fips, name)
+            }
+            (_, false) => format!("{}{}.{}.{}", service, fips, name, suffix),
+        };
+        Ok(Endpoint {
+            scheme: "https".to_owned(),
+            hostname,
+        })
+    }
+}
+
+/// Regions with FIPS endpoints.
+fn fips_region(region: &Region) -> bool {
+    matches!(
+        *region,
+        Region::UsEast1
+            | Region::UsEast2
+            | Region::UsWest1
+            | Region::UsWest2
+            | Region::UsGovEast1
+            | Region::UsGovWest1
+            | Region::CaCentral1
+    )
+}
+
+/// The endpoint variants a client sends its requests to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EndpointVariants {
+    /// Use endpoints that use FIPS 140-2 validated cryptographic modules.
+    pub fips: bool,
+    /// Use endpoints reachable over both IPv4 and IPv6.
+    pub dualstack: bool,
+}
+
+impl EndpointVariants {
+    /// Returns the variants enabled with the `AWS_USE_FIPS_ENDPOINT` and
+    /// `AWS_USE_DUALSTACK_ENDPOINT` environment variables, or otherwise with the
+    /// `use_fips_endpoint` and `use_dualstack_endpoint` keys of the current profile in the
+    /// AWS config file. Values other than `true` and `false` are ignored.
+    pub fn from_env() -> EndpointVariants {
+        EndpointVariants {
+            fips: flag_from_env(AWS_USE_FIPS_ENDPOINT, USE_FIPS_ENDPOINT),
+            dualstack: flag_from_env(AWS_USE_DUALSTACK_ENDPOINT, USE_DUALSTACK_ENDPOINT),
+        }
+    }
+}
+
+fn flag_from_env(var: &str, key: &str) -> bool {
+    env::var(var)
+        .ok()
+        .and_then(|value| parse_flag(&value))
+        .or_else(|| {
+            ProfileProvider::setting(key)
+                .ok()
+                .and_then(|value| value)
+                .and_then(|value| parse_flag(&value))
+        })
+        .unwrap_or(false)
+}
+
+fn parse_flag(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Whether STS requests go to the endpoint of their region or, in the regions that used it
+/// before regional endpoints existed, to the global `sts.amazonaws.com` endpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StsRegionalEndpoints {
+    /// Send requests in the regions that used the global endpoint to `sts.amazonaws.com`,
+    /// signed for `us-east-1`.
+    Legacy,
+    /// Send requests to the endpoint of their region, signed for that region.
+    #[default]
+    Regional,
+}
+
+impl StsRegionalEndpoints {
+    /// Returns the setting of the `AWS_STS_REGIONAL_ENDPOINTS` environment variable, or
+    /// otherwise of the `sts_regional_endpoints` key of the current profile in the AWS config
+    /// file, either `legacy` or `regional`. Defaults to `Regional`, ignoring other values.
+    pub fn from_env() -> StsRegionalEndpoints {
+        env::var(AWS_STS_REGIONAL_ENDPOINTS)
+            .ok()
+            .and_then(|value| parse_sts_regional_endpoints(&value))
+            .or_else(|| {
+                ProfileProvider::setting(STS_REGIONAL_ENDPOINTS)
+                    .ok()
+                    .and_then(|value| value)
+                    .and_then(|value| parse_sts_regional_endpoints(&value))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the endpoint and signing region of STS requests in `region`, unless they are
+    /// those of the region itself.
+    pub fn global_endpoint(self, region: &Region) -> Option<(Endpoint, Region)> {
+        match (self, region) {
+            (_, Region::Custom { .. }) => None,
+            (StsRegionalEndpoints::Legacy, _)
+                if LEGACY_GLOBAL_STS_REGIONS.contains(&region.name()) =>
+            {
+                Some((
+                    Endpoint {
+                        scheme: "https".to_owned(),
+                        hostname: "sts.amazonaws.com".to_owned(),
+                    },
+                    Region::UsEast1,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_sts_regional_endpoints(value: &str) -> Option<StsRegionalEndpoints> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "legacy" => Some(StsRegionalEndpoints::Legacy),
+        "regional" => Some(StsRegionalEndpoints::Regional),
+        _ => None,
+    }
+}
+
+/// An error returned when a service has no endpoint for the requested variants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointError {
+    /// The reason the endpoint could not be resolved.
+    pub message: String,
+}
+
+impl EndpointError {
+    /// Creates an `EndpointError` with the given message.
+    pub fn new<S>(message: S) -> EndpointError
+    where
+        S: ToString,
+    {
+        EndpointError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for EndpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for EndpointError {}
+
+/// Chooses the endpoint requests for a service are sent to.
+pub trait EndpointResolver {
+    /// Returns the endpoint for `service` (the name the service is signed with, e.g. `s3` or
+    /// `dynamodb`) in `region`.
+    fn resolve(&self, service: &str, region: &Region) -> Endpoint;
+}
+
+impl<F> EndpointResolver for F
+where
+    F: Fn(&str, &Region) -> Endpoint,
+{
+    fn resolve(&self, service: &str, region: &Region) -> Endpoint {
+        self(service, region)
+    }
+}
+
+/// The `EndpointResolver` used when none is configured.
+///
+/// Uses the URL in `AWS_ENDPOINT_URL_<SERVICE>` if that is set, where `<SERVICE>` is the
+/// service name in upper case with dashes replaced by underscores (e.g.
+/// `AWS_ENDPOINT_URL_DYNAMODB`), then the URL in `AWS_ENDPOINT_URL`, and otherwise the
+/// endpoint derived from the service and region. The environment is not consulted for
+/// `Region::Custom`, whose endpoint is always used.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultEndpointResolver;
+
+impl DefaultEndpointResolver {
+    /// Returns the endpoint configured in the environment for `service`, if any.
+    pub fn from_env(service: &str) -> Option<Endpoint> {
+        let service_var = format!(
+            "{}_{}",
+            AWS_ENDPOINT_URL,
+            service.to_ascii_uppercase().replace('-', "_")
+        );
+        env::var(service_var)
+            .or_else(|_| env::var(AWS_ENDPOINT_URL))
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .map(|url| Endpoint::from_url(&url))
+    }
+}
+
+impl EndpointResolver for DefaultEndpointResolver {
+    fn resolve(&self, service: &str, region: &Region) -> Endpoint {
+        match *region {
+            Region::Custom { .. } => None,
+            _ => DefaultEndpointResolver::from_env(service),
+        }
+        .unwrap_or_else(|| Endpoint::for_region(service, region))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    lazy_static::lazy_static! {
+        static ref ENV_MUTEX: Mutex<()> = Mutex::new(());
+    }
+
+    fn endpoint(service: &str, region: Region, fips: bool, dualstack: bool) -> String {
+        Endpoint::for_region_with_variants(service, &region, EndpointVariants { fips, dualstack })
+            .map(|endpoint| endpoint.hostname)
+            .unwrap_or_else(|err| err.to_string())
+    }
+
+    fn with_env<F: FnOnce()>(vars: &[(&str, &str)], f: F) {
+        let _guard = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, value) in vars {
+            env::set_var(name, value);
+        }
+        f();
+        for (name, _) in vars {
+            env::remove_var(name);
+        }
+    }
+
+    #[test]
+    fn endpoint_from_url() {
+        assert_eq!(
+            Endpoint::from_url("http://localhost:9000/"),
+            Endpoint {
+                scheme: "http".to_owned(),
+                hostname: "localhost:9000".to_owned()
+            }
+        );
+        assert_eq!(
+            Endpoint::from_url("minio.internal"),
+            Endpoint {
+                scheme: "https".to_owned(),
+                hostname: "minio.internal".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn default_resolver_uses_region_endpoint() {
+        with_env(&[], || {
+            let endpoint = DefaultEndpointResolver.resolve("dynamodb", &Region::EuWest1);
+            assert_eq!(endpoint.scheme, "https");
+            assert_eq!(endpoint.hostname, "dynamodb.eu-west-1.amazonaws.com");
+        });
+    }
+
+    #[test]
+    fn default_resolver_prefers_service_specific_variable() {
+        with_env(
+            &[
+                ("AWS_ENDPOINT_URL", "https://everything.example.com"),
+                ("AWS_ENDPOINT_URL_DYNAMODB", "http://localhost:8000"),
+            ],
+            || {
+                let dynamodb = DefaultEndpointResolver.resolve("dynamodb", &Region::UsEast1);
+                assert_eq!(dynamodb, Endpoint::from_url("http://localhost:8000"));
+                let sqs = DefaultEndpointResolver.resolve("sqs", &Region::UsEast1);
+                assert_eq!(sqs, Endpoint::from_url("https://everything.example.com"));
+            },
+        );
+    }
+
+    #[test]
+    fn default_resolver_keeps_custom_region_endpoint() {
+        with_env(
+            &[("AWS_ENDPOINT_URL", "https://everything.example.com")],
+            || {
+                let region = Region::Custom {
+                    name: "local".to_owned(),
+                    endpoint: "http://localhost:4566".to_owned(),
+                };
+                let endpoint = DefaultEndpointResolver.resolve("s3", &region);
+                assert_eq!(endpoint, Endpoint::from_url("http://localhost:4566"));
+            },
+        );
+    }
+
+    #[test]
+    fn fips_and_dualstack_endpoints() {
+        assert_eq!(
+            endpoint("s3", Region::UsEast1, true, false),
+            "s3-fips.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint("sts", Region::UsWest2, true, false),
+            "sts-fips.us-west-2.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint("s3", Region::UsWest2, false, true),
+            "s3.dualstack.us-west-2.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint("s3", Region::UsEast2, true, true),
+            "s3-fips.dualstack.us-east-2.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint("ec2", Region::EuWest1, false, true),
+            "ec2.eu-west-1.api.aws"
+        );
+        assert_eq!(
+            endpoint("s3", Region::CnNorth1, false, true),
+            "s3.dualstack.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            endpoint("sqs", Region::EuWest1, false, false),
+            "sqs.eu-west-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn unsupported_variants_are_errors() {
+        assert_eq!(
+            endpoint("sts", Region::EuWest1, true, false),
+            "sts does not have a FIPS endpoint in eu-west-1"
+        );
+        assert_eq!(
+            endpoint("iam", Region::UsEast1, true, false),
+            "iam does not have a FIPS endpoint in us-east-1"
+        );
+        assert_eq!(
+            endpoint("sqs", Region::UsEast1, false, true),
+            "sqs does not have a dual-stack endpoint in us-east-1"
+        );
+    }
+
+    #[test]
+    fn custom_region_ignores_variants() {
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:4566".to_owned(),
+        };
+        assert_eq!(endpoint("sqs", region, true, true), "localhost:4566");
+    }
+
+    #[test]
+    fn sts_regional_endpoints_from_env() {
+        with_env(&[("AWS_CONFIG_FILE", "/nonexistent/config")], || {
+            assert_eq!(
+                StsRegionalEndpoints::from_env(),
+                StsRegionalEndpoints::Regional
+            );
+        });
+        with_env(
+            &[
+                ("AWS_STS_REGIONAL_ENDPOINTS", "Legacy"),
+                ("AWS_CONFIG_FILE", "/nonexistent/config"),
+            ],
+            || {
+                assert_eq!(
+                    StsRegionalEndpoints::from_env(),
+                    StsRegionalEndpoints::Legacy
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn legacy_sts_endpoints_are_global_in_former_global_regions() {
+        let legacy = StsRegionalEndpoints::Legacy;
+        assert_eq!(
+            legacy.global_endpoint(&Region::EuWest1),
+            Some((Endpoint::from_url("sts.amazonaws.com"), Region::UsEast1))
+        );
+        assert_eq!(legacy.global_endpoint(&Region::ApEast1), None);
+        assert_eq!(
+            StsRegionalEndpoints::Regional.global_endpoint(&Region::EuWest1),
+            None
+        );
+        assert_eq!(
+            Endpoint::for_region("sts", &Region::EuWest1).hostname,
+            "sts.eu-west-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn variants_from_env() {
+        with_env(
+            &[
+                ("AWS_USE_FIPS_ENDPOINT", "TRUE"),
+                ("AWS_USE_DUALSTACK_ENDPOINT", "false"),
+                ("AWS_CONFIG_FILE", "/nonexistent/config"),
+            ],
+            || {
+                assert_eq!(
+                    EndpointVariants::from_env(),
+                    EndpointVariants {
+                        fips: true,
+                        dualstack: false
+                    }
+                );
+            },
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::credential::CredentialsError;
 
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
+use super::timeout::TimeoutError;
 use crate::client::SignAndDispatchError;
 
 /// Generic error type returned by all rusoto requests.
@@ -25,6 +26,9 @@ pub enum RusotoError<E> {
     Unknown(BufferedHttpResponse),
     /// An error occurred when attempting to run a future as blocking
     Blocking,
+    /// The request, or reading its response, took longer than one of the timeouts of the
+    /// `HttpClient`.
+    Timeout(TimeoutError),
 }
 
 /// Result carrying a generic `RusotoError`.
@@ -54,7 +58,10 @@ impl<E> From<CredentialsError> for RusotoError<E> {
 
 impl<E> From<HttpDispatchError> for RusotoError<E> {
     fn from(err: HttpDispatchError) -> Self {
-        RusotoError::HttpDispatch(err)
+        match err.timeout() {
+            Some(timeout) => RusotoError::Timeout(timeout.clone()),
+            None => RusotoError::HttpDispatch(err),
+        }
     }
 }
 
@@ -63,13 +70,15 @@ impl<E> From<SignAndDispatchError> for RusotoError<E> {
         match err {
             SignAndDispatchError::Credentials(e) => Self::from(e),
             SignAndDispatchError::Dispatch(e) => Self::from(e),
+            SignAndDispatchError::Endpoint(e) => RusotoError::Validation(e.to_string()),
+            SignAndDispatchError::Interceptor(e) => RusotoError::Validation(e.to_string()),
         }
     }
 }
 
 impl<E> From<io::Error> for RusotoError<E> {
     fn from(err: io::Error) -> Self {
-        RusotoError::HttpDispatch(HttpDispatchError::from(err))
+        Self::from(HttpDispatchError::from(err))
     }
 }
 
@@ -88,6 +97,7 @@ impl<E: Error + 'static> fmt::Display for RusotoError<E> {
                 cause.body_as_str()
             ),
             RusotoError::Blocking => write!(f, "Failed to run blocking future"),
+            RusotoError::Timeout(ref err) => write!(f, "{}", err),
         }
     }
 }
@@ -98,6 +108,7 @@ impl<E: Error + 'static> Error for RusotoError<E> {
             RusotoError::Service(ref err) => Some(err),
             RusotoError::Credentials(ref err) => Some(err),
             RusotoError::HttpDispatch(ref err) => Some(err),
+            RusotoError::Timeout(ref err) => Some(err),
             _ => None,
         }
     }
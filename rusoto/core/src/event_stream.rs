@@ -5,12 +5,14 @@ use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 use std::pin::Pin;
 
+use bytes::Bytes;
 use crc32fast::Hasher;
 use futures::task::{Context, Poll};
 use futures::Stream;
+use http::{HeaderMap, StatusCode};
 
 use crate::error::RusotoError;
-use crate::request::HttpResponse;
+use crate::request::{BufferedHttpResponse, HttpResponse};
 use crate::stream::ByteStream;
 
 #[doc(hidden)]
@@ -234,14 +236,42 @@ impl<'a> EventStreamMessage<'a> {
     pub fn get_header(&self, name: &str) -> Option<&EventStreamHeader<'a>> {
         self.headers.iter().find(|h| h.name == name)
     }
+
+    fn string_header(&self, name: &str) -> Option<&'a str> {
+        match self.get_header(name)?.value {
+            EventStreamHeaderValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The error an `error` message reports, as a response to the request of the stream.
+    fn into_error<E>(self, status: StatusCode) -> RusotoError<E> {
+        let mut headers = HeaderMap::<String>::default();
+        if let Some(code) = self.string_header(":error-code") {
+            headers.insert("x-amzn-errortype", code.to_owned());
+        }
+        let message = self.string_header(":error-message").unwrap_or_default();
+        RusotoError::Unknown(BufferedHttpResponse {
+            status,
+            body: Bytes::copy_from_slice(message.as_bytes()),
+            headers,
+        })
+    }
 }
 
 /// Event Stream decoder
 ///
 /// This struct implements `futures::Stream` and decodes events of type `T` from a streaming HTTP body.
+///
+/// Each message's CRCs are checked, and messages split across chunks of the body are buffered
+/// until they're complete. Messages of the `exception` type are decoded into the variant of `T`
+/// named by their `:exception-type` header. Messages of the `error` type end the stream with a
+/// `RusotoError::Unknown`, whose response has the message's `:error-code` as its
+/// `x-amzn-errortype` header and its `:error-message` as its body.
 #[derive(Debug)]
 pub struct EventStream<T> {
     response_body: Option<Pin<Box<ByteStream>>>,
+    status: StatusCode,
     buf: Vec<u8>,
     _phantom: std::marker::PhantomData<T>,
 }
@@ -251,12 +281,14 @@ impl<T: DeserializeEvent + Unpin> EventStream<T> {
     pub fn new(response: HttpResponse) -> EventStream<T> {
         EventStream {
             response_body: Some(Box::pin(response.body)),
+            status: response.status,
             buf: Vec::with_capacity(512),
             _phantom: PhantomData {},
         }
     }
 
-    fn pop_event(buf: &mut Vec<u8>) -> Result<Option<T>, RusotoError<()>> {
+    #[allow(clippy::result_large_err)]
+    fn pop_event(buf: &mut Vec<u8>, status: StatusCode) -> Result<Option<T>, RusotoError<()>> {
         loop {
             let mut reader: &[u8] = &buf;
             let initial_size = reader.len();
@@ -267,6 +299,10 @@ impl<T: DeserializeEvent + Unpin> EventStream<T> {
             };
             log::trace!("Parsed event stream event: {:?}", event_msg);
 
+            if event_msg.string_header(":message-type") == Some("error") {
+                return Err(event_msg.into_error(status));
+            }
+
             let event_type_header = event_msg
                 .get_header(":event-type")
                 .or_else(|| event_msg.get_header(":exception-type"))
@@ -302,8 +338,10 @@ impl<T: DeserializeEvent + Unpin> EventStream<T> {
         }
     }
 
+    /// Ends the stream after an error, which may have left it broken.
     fn drop_response_body(&mut self) {
         self.response_body = None;
+        self.buf.clear();
     }
 }
 
@@ -313,59 +351,53 @@ impl<T: DeserializeEvent + Unpin> futures::Stream for EventStream<T> {
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let projection = self.get_mut();
 
-        // First try to use the buffer
-        match Self::pop_event(&mut projection.buf) {
-            Ok(Some(event)) => return Poll::Ready(Some(Ok(event))),
-            Ok(None) => {}
-            Err(err) => {
-                projection.drop_response_body();
-                return Poll::Ready(Some(Err(err)));
-            }
-        };
+        loop {
+            // First try to use the buffer
+            match Self::pop_event(&mut projection.buf, projection.status) {
+                Ok(Some(event)) => return Poll::Ready(Some(Ok(event))),
+                Ok(None) => {}
+                Err(err) => {
+                    projection.drop_response_body();
+                    return Poll::Ready(Some(Err(err)));
+                }
+            };
 
-        // Otherwise, see if new data has arrived from the network
-        let chunk_option = match &mut projection.response_body {
-            // We still maintain the stream, poll it and return if nothing is available
-            Some(body) => futures::ready!(Stream::poll_next(body.as_mut(), cx)),
+            // Otherwise, see if new data has arrived from the network
+            let chunk_option = match &mut projection.response_body {
+                // We still maintain the stream, poll it and return if nothing is available
+                Some(body) => futures::ready!(Stream::poll_next(body.as_mut(), cx)),
 
-            // We dropped the stream because we encountered an error.
-            // This means that the stream is potentially broken and so we end it.
-            None => return Poll::Ready(None),
-        };
-        match chunk_option {
-            // We received an http body chunk
-            Some(Ok(byte_chunk)) => {
-                log::trace!("Got event stream bytes: {:?}", byte_chunk);
-
-                projection.buf.extend(byte_chunk);
-
-                let parsed_event = match Self::pop_event(&mut projection.buf) {
-                    Ok(None) => return Poll::Pending,
-                    Ok(Some(item)) => Ok(item),
-                    Err(err) => {
-                        projection.drop_response_body();
-                        Err(err)
-                    }
-                };
-                Poll::Ready(Some(parsed_event))
-            }
+                // We dropped the stream because we encountered an error.
+                // This means that the stream is potentially broken and so we end it.
+                None => return Poll::Ready(None),
+            };
+            match chunk_option {
+                // We received an http body chunk, which may complete an event or only part of
+                // one, so try the buffer again
+                Some(Ok(byte_chunk)) => {
+                    log::trace!("Got event stream bytes: {:?}", byte_chunk);
 
-            // Something went wrong with the network connection
-            Some(Err(e)) => {
-                projection.drop_response_body();
-                Poll::Ready(Some(Err(RusotoError::from(e))))
-            }
+                    projection.buf.extend(byte_chunk);
+                }
 
-            // The underlying stream is closed
-            None => {
-                projection.drop_response_body();
+                // Something went wrong with the network connection
+                Some(Err(e)) => {
+                    projection.drop_response_body();
+                    return Poll::Ready(Some(Err(RusotoError::from(e))));
+                }
 
-                if !projection.buf.is_empty() {
-                    Poll::Ready(Some(Err(RusotoError::ParseError(
-                        "Event stream closed with incomplete data remaining".to_string(),
-                    ))))
-                } else {
-                    Poll::Ready(None)
+                // The underlying stream is closed
+                None => {
+                    let incomplete = !projection.buf.is_empty();
+                    projection.drop_response_body();
+
+                    return if incomplete {
+                        Poll::Ready(Some(Err(RusotoError::ParseError(
+                            "Event stream closed with incomplete data remaining".to_string(),
+                        ))))
+                    } else {
+                        Poll::Ready(None)
+                    };
                 }
             }
         }
@@ -375,6 +407,7 @@ impl<T: DeserializeEvent + Unpin> futures::Stream for EventStream<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::StreamExt;
 
     #[test]
     fn parse_initial_response() {
@@ -488,4 +521,115 @@ mod tests {
             Err(EventStreamParseError::InvalidData(_))
         ));
     }
+
+    #[derive(Debug, PartialEq)]
+    struct TestEvent(String, Vec<u8>);
+
+    impl DeserializeEvent for TestEvent {
+        fn deserialize_event(event_type: &str, data: &[u8]) -> Result<Self, RusotoError<()>> {
+            Ok(TestEvent(event_type.to_owned(), data.to_vec()))
+        }
+    }
+
+    /// Encodes a message with string headers.
+    fn message(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend_from_slice(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend_from_slice(value.as_bytes());
+        }
+        let total_length = 12 + encoded_headers.len() + payload.len() + 4;
+        let mut message = Vec::new();
+        message.extend_from_slice(&(total_length as u32).to_be_bytes());
+        message.extend_from_slice(&(encoded_headers.len() as u32).to_be_bytes());
+        let prelude_crc = crc32fast::hash(&message);
+        message.extend_from_slice(&prelude_crc.to_be_bytes());
+        message.extend_from_slice(&encoded_headers);
+        message.extend_from_slice(payload);
+        let message_crc = crc32fast::hash(&message);
+        message.extend_from_slice(&message_crc.to_be_bytes());
+        message
+    }
+
+    fn event(event_type: &str, payload: &[u8]) -> Vec<u8> {
+        message(
+            &[(":message-type", "event"), (":event-type", event_type)],
+            payload,
+        )
+    }
+
+    /// Decodes `body`, received in chunks of `chunk_size` bytes.
+    fn decode(body: &[u8], chunk_size: usize) -> Vec<Result<TestEvent, RusotoError<()>>> {
+        let chunks: Vec<Result<Bytes, std::io::Error>> = body
+            .chunks(chunk_size)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+        let response = HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::new(futures::stream::iter(chunks)),
+            headers: HeaderMap::default(),
+        };
+        futures::executor::block_on(EventStream::<TestEvent>::new(response).collect())
+    }
+
+    #[test]
+    fn decodes_events_split_across_chunks() {
+        let mut body = event("initial-response", b"{}");
+        body.extend(event("Records", b"first"));
+        body.extend(event("Records", b"second"));
+
+        for chunk_size in &[1, 7, body.len()] {
+            let events: Vec<_> = decode(&body, *chunk_size)
+                .into_iter()
+                .map(Result::unwrap)
+                .collect();
+            assert_eq!(
+                events,
+                vec![
+                    TestEvent("Records".to_owned(), b"first".to_vec()),
+                    TestEvent("Records".to_owned(), b"second".to_vec()),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn error_messages_end_the_stream() {
+        let mut body = event("Records", b"first");
+        body.extend(message(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InternalError"),
+                (":error-message", "We encountered an internal error."),
+            ],
+            b"",
+        ));
+        body.extend(event("Records", b"second"));
+
+        let mut events = decode(&body, 5).into_iter();
+        assert_eq!(
+            events.next().unwrap().unwrap(),
+            TestEvent("Records".to_owned(), b"first".to_vec())
+        );
+        match events.next() {
+            Some(Err(RusotoError::Unknown(response))) => {
+                assert_eq!(response.headers["x-amzn-errortype"], "InternalError");
+                assert_eq!(response.body_as_str(), "We encountered an internal error.");
+            }
+            other => panic!("expected the error message, got {:?}", other),
+        }
+        assert!(events.next().is_none());
+    }
+
+    #[test]
+    fn truncated_streams_fail() {
+        let body = event("Records", b"first");
+
+        let events = decode(&body[..body.len() - 1], 4);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Err(RusotoError::ParseError(_))));
+    }
 }
@@ -0,0 +1,186 @@
+//! The spans of the `tracing` feature, which do nothing without it, and the operation names
+//! they and `MetricsObserver`s report.
+//!
+//! Every request a `Client` sends, retries included, runs in an `aws.request` span. Span
+//! names can't vary, so its `otel.name` field names the operation, e.g. `aws.s3.GetObject`.
+//! The span records the service, operation, region, number of attempts, response status,
+//! request IDs and duration, and an event for each retry. Headers and bodies aren't recorded,
+//! so neither are credentials or other secrets in them.
+
+use crate::signature::{SignedRequest, SignedRequestPayload};
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use self::disabled::*;
+#[cfg(feature = "tracing")]
+pub(crate) use self::enabled::*;
+
+/// The name of the operation of `request`: the one the service crate set, or the one
+/// in the target header of JSON requests or the `Action` parameter of query requests.
+pub(crate) fn operation_name(request: &SignedRequest) -> Option<String> {
+    if let Some(ref operation) = request.operation {
+        return Some(operation.clone());
+    }
+    let header = |name: &str| {
+        request
+            .headers
+            .get(name)
+            .and_then(|values| values.first())
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+    };
+    if let Some(target) = header("x-amz-target") {
+        return target.rsplit('.').next().map(str::to_owned);
+    }
+    if let Some(Some(action)) = request.params.get("Action") {
+        return Some(action.clone());
+    }
+    match (header("content-type"), &request.payload) {
+        (Some(ref content_type), Some(SignedRequestPayload::Buffer(ref body)))
+            if content_type.starts_with("application/x-www-form-urlencoded") =>
+        {
+            body.split(|byte| *byte == b'&').find_map(|pair| {
+                pair.strip_prefix(b"Action=")
+                    .map(|action| String::from_utf8_lossy(action).into_owned())
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod enabled {
+    use std::future::Future;
+    use std::time::{Duration, Instant};
+
+    use tracing::field::Empty;
+    use tracing::{Instrument, Span};
+
+    use super::operation_name;
+    use crate::client::SignAndDispatchError;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+
+    /// The span of a request.
+    pub(crate) struct RequestSpan(Span);
+
+    impl RequestSpan {
+        pub(crate) fn new(request: &SignedRequest) -> RequestSpan {
+            let operation = operation_name(request).unwrap_or_default();
+            let name = if operation.is_empty() {
+                format!("aws.{}", request.service)
+            } else {
+                format!("aws.{}.{}", request.service, operation)
+            };
+            RequestSpan(tracing::info_span!(
+                "aws.request",
+                otel.name = %name,
+                aws.service = %request.service,
+                aws.operation = %operation,
+                aws.region = request.region.name(),
+                aws.attempts = Empty,
+                http.status_code = Empty,
+                aws.request_id = Empty,
+                aws.extended_request_id = Empty,
+                duration_ms = Empty,
+            ))
+        }
+
+        /// Runs `dispatch` in the span, and records its result.
+        pub(crate) async fn run<F>(&self, dispatch: F) -> F::Output
+        where
+            F: Future<Output = Result<HttpResponse, SignAndDispatchError>>,
+        {
+            let start = Instant::now();
+            let result = dispatch.instrument(self.0.clone()).await;
+            let span = &self.0;
+            match result {
+                Ok(ref response) => {
+                    span.record("http.status_code", response.status.as_u16());
+                    let request_id = response
+                        .headers
+                        .get("x-amz-request-id")
+                        .or_else(|| response.headers.get("x-amzn-requestid"));
+                    if let Some(request_id) = request_id {
+                        span.record("aws.request_id", request_id.as_str());
+                    }
+                    if let Some(extended_request_id) = response.headers.get("x-amz-id-2") {
+                        span.record("aws.extended_request_id", extended_request_id.as_str());
+                    }
+                }
+                Err(ref err) => tracing::info!(parent: span, error = ?err, "request failed"),
+            }
+            span.record("duration_ms", start.elapsed().as_millis() as u64);
+            result
+        }
+
+        /// Records the number of the attempt being sent.
+        pub(crate) fn record_attempt(&self, attempt: u32) {
+            self.0.record("aws.attempts", attempt);
+        }
+
+        /// Records a retry as an event of the span.
+        pub(crate) fn record_retry(&self, attempt: u32, delay: Duration, reason: &str) {
+            tracing::info!(
+                parent: &self.0,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                reason,
+                "retrying request"
+            );
+        }
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod disabled {
+    use std::future::Future;
+    use std::time::Duration;
+
+    use crate::client::SignAndDispatchError;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+
+    /// The span of a request, which is no span without the `tracing` feature.
+    pub(crate) struct RequestSpan;
+
+    impl RequestSpan {
+        pub(crate) fn new(_request: &SignedRequest) -> RequestSpan {
+            RequestSpan
+        }
+
+        pub(crate) async fn run<F>(&self, dispatch: F) -> F::Output
+        where
+            F: Future<Output = Result<HttpResponse, SignAndDispatchError>>,
+        {
+            dispatch.await
+        }
+
+        pub(crate) fn record_attempt(&self, _attempt: u32) {}
+
+        pub(crate) fn record_retry(&self, _attempt: u32, _delay: Duration, _reason: &str) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::Region;
+
+    #[test]
+    fn operation_names_come_from_the_request() {
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert_eq!(operation_name(&request), None);
+        request.set_operation("GetObject");
+        assert_eq!(operation_name(&request).as_deref(), Some("GetObject"));
+
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
+        assert_eq!(operation_name(&request).as_deref(), Some("GetItem"));
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        request.set_payload(Some(
+            "AWSAccountIds.member.1=1&Action=AddPermission&Version=1",
+        ));
+        assert_eq!(operation_name(&request).as_deref(), Some("AddPermission"));
+    }
+}
@@ -0,0 +1,84 @@
+//! Hooks into every request a `Client` sends.
+//!
+//! Interceptors added with `Client::add_interceptor` see each attempt of a request before it
+//! is signed, and its response or error after it was dispatched, e.g. to add headers, log or
+//! collect metrics. They run in the order they were added, and a failing interceptor ends the
+//! request with its error, without running the interceptors after it.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::request::HttpResponse;
+use crate::signature::SignedRequest;
+
+pub use crate::client::SignAndDispatchError;
+
+/// Hooks run by a `Client` around each attempt of a request.
+///
+/// Attempts are numbered from 1, retries of a request get the following numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::interceptor::{Interceptor, InterceptorError};
+/// use rusoto_core::signature::SignedRequest;
+///
+/// /// Tags every request with the name of the application.
+/// struct UserAgentSuffix(&'static str);
+///
+/// impl Interceptor for UserAgentSuffix {
+///     fn before_sign(
+///         &self,
+///         request: &mut SignedRequest,
+///         _attempt: u32,
+///     ) -> Result<(), InterceptorError> {
+///         request.add_header("x-app-name", self.0);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called with the request of an attempt before it is signed. An error ends the request
+    /// without dispatching it.
+    fn before_sign(
+        &self,
+        _request: &mut SignedRequest,
+        _attempt: u32,
+    ) -> Result<(), InterceptorError> {
+        Ok(())
+    }
+
+    /// Called with the response or error of an attempt, before deciding whether to retry it.
+    /// An error ends the request instead of returning the response or retrying.
+    fn after_dispatch(
+        &self,
+        _result: Result<&HttpResponse, &SignAndDispatchError>,
+        _attempt: u32,
+    ) -> Result<(), InterceptorError> {
+        Ok(())
+    }
+}
+
+/// The error an `Interceptor` ends a request with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterceptorError {
+    /// The reason the interceptor ended the request.
+    pub message: String,
+}
+
+impl InterceptorError {
+    /// Creates an error ending a request for the reason given in `message`.
+    pub fn new<S: Into<String>>(message: S) -> InterceptorError {
+        InterceptorError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for InterceptorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for InterceptorError {}
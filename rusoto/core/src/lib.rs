@@ -23,30 +23,50 @@
 use hyper_rustls as tls;
 #[cfg(feature = "native-tls")]
 use hyper_tls as tls;
+#[cfg(feature = "native-tls")]
+use hyper_tls::native_tls;
+#[cfg(feature = "rustls")]
+use rustls_crate as rustls;
 
 mod client;
+mod clock_skew;
 mod error;
+mod instrument;
 mod stream;
 
 pub mod event_stream;
+pub mod interceptor;
+pub mod metrics;
+pub mod pagination;
 pub mod param;
+pub mod proxy;
 #[doc(hidden)]
 pub mod region;
 pub mod request;
+pub mod retry;
 #[doc(hidden)]
 pub mod signature;
+pub mod timeout;
+pub mod waiter;
 
 #[doc(hidden)]
 pub use crate::client::Client;
 #[doc(hidden)]
 pub mod encoding;
+pub mod endpoint;
 #[doc(hidden)]
 pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
+pub use crate::endpoint::{
+    DefaultEndpointResolver, Endpoint, EndpointError, EndpointResolver, EndpointVariants,
+    StsRegionalEndpoints,
+};
 pub use crate::error::{RusotoError, RusotoResult};
+pub use crate::proto::xml::error::XmlErrorMessage;
 pub use crate::region::Region;
 pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
+pub use crate::retry::{RetryMode, RetryPolicy};
 pub use crate::stream::ByteStream;
 pub use rusoto_credential as credential;
@@ -0,0 +1,264 @@
+//! Observing the requests a `Client` sends, e.g. to export their latency and errors as
+//! metrics.
+//!
+//! A `MetricsObserver` set with `Client::with_metrics_observer` is called for every attempt of
+//! every request, and once a request completed, with its service, operation and how it ended.
+//! Clients without an observer don't collect anything.
+//!
+//! The credentials a request is signed with are fetched during its attempts, and reported
+//! with `on_credentials`. Credential providers calling STS send their requests with STS
+//! clients of their own, set an observer on those clients to see the STS operations.
+//!
+//! # Examples
+//!
+//! Counting requests and throttled attempts per operation:
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use std::sync::Mutex;
+//! use std::time::Duration;
+//!
+//! use rusoto_core::metrics::{ErrorClassification, MetricsObserver, Outcome, RequestInfo};
+//!
+//! #[derive(Default)]
+//! struct Counters(Mutex<HashMap<String, (u64, u64)>>);
+//!
+//! impl MetricsObserver for Counters {
+//!     fn on_attempt_end(
+//!         &self,
+//!         request: &RequestInfo<'_>,
+//!         _attempt: u32,
+//!         outcome: &Outcome,
+//!         _duration: Duration,
+//!     ) {
+//!         if outcome.classification == ErrorClassification::Throttling {
+//!             let mut counters = self.0.lock().unwrap();
+//!             counters.entry(request.to_string()).or_default().1 += 1;
+//!         }
+//!     }
+//!
+//!     fn on_request_complete(
+//!         &self,
+//!         request: &RequestInfo<'_>,
+//!         _attempts: u32,
+//!         _outcome: &Outcome,
+//!         _duration: Duration,
+//!     ) {
+//!         let mut counters = self.0.lock().unwrap();
+//!         counters.entry(request.to_string()).or_default().0 += 1;
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use http::StatusCode;
+
+use crate::instrument::operation_name;
+use crate::signature::SignedRequest;
+
+/// Called by a `Client` around the requests it sends.
+///
+/// Every method has an empty default implementation. They're called on the task sending the
+/// request, so they should return quickly.
+pub trait MetricsObserver: Send + Sync {
+    /// Called before an attempt of a request is signed and sent. Attempts are numbered from 1,
+    /// those after the first are retries.
+    fn on_attempt_start(&self, _request: &RequestInfo<'_>, _attempt: u32) {}
+
+    /// Called with how an attempt ended, and how long it took to get and sign with
+    /// credentials, send it and receive the response headers.
+    fn on_attempt_end(
+        &self,
+        _request: &RequestInfo<'_>,
+        _attempt: u32,
+        _outcome: &Outcome,
+        _duration: Duration,
+    ) {
+    }
+
+    /// Called once per request, with the number of attempts sent, the outcome of the last
+    /// one and how long the request took, retry delays included.
+    fn on_request_complete(
+        &self,
+        _request: &RequestInfo<'_>,
+        _attempts: u32,
+        _outcome: &Outcome,
+        _duration: Duration,
+    ) {
+    }
+
+    /// Called when an attempt got credentials from the client's credentials provider, or
+    /// failed to, with how long that took. Long durations are refreshes of the credentials.
+    fn on_credentials(&self, _request: &RequestInfo<'_>, _success: bool, _duration: Duration) {}
+}
+
+impl<O: MetricsObserver + ?Sized> MetricsObserver for Arc<O> {
+    fn on_attempt_start(&self, request: &RequestInfo<'_>, attempt: u32) {
+        (**self).on_attempt_start(request, attempt)
+    }
+
+    fn on_attempt_end(
+        &self,
+        request: &RequestInfo<'_>,
+        attempt: u32,
+        outcome: &Outcome,
+        duration: Duration,
+    ) {
+        (**self).on_attempt_end(request, attempt, outcome, duration)
+    }
+
+    fn on_request_complete(
+        &self,
+        request: &RequestInfo<'_>,
+        attempts: u32,
+        outcome: &Outcome,
+        duration: Duration,
+    ) {
+        (**self).on_request_complete(request, attempts, outcome, duration)
+    }
+
+    fn on_credentials(&self, request: &RequestInfo<'_>, success: bool, duration: Duration) {
+        (**self).on_credentials(request, success, duration)
+    }
+}
+
+/// The request a `MetricsObserver` is called for. Displays as `service.Operation`, e.g.
+/// `s3.GetObject`, or just the service if the operation isn't known.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestInfo<'a> {
+    /// The signing name of the service, e.g. `s3`.
+    pub service: &'a str,
+    /// The name of the API operation, e.g. `GetObject`, if known.
+    pub operation: Option<&'a str>,
+    /// The name of the region the request is sent to.
+    pub region: &'a str,
+}
+
+impl<'a> fmt::Display for RequestInfo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operation {
+            Some(operation) => write!(f, "{}.{}", self.service, operation),
+            None => f.write_str(self.service),
+        }
+    }
+}
+
+/// How an attempt or request ended.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Outcome {
+    /// The status of the response, if one was received.
+    pub status: Option<StatusCode>,
+    /// Whether the attempt succeeded, or what kind of error it failed with.
+    pub classification: ErrorClassification,
+}
+
+/// What kind of error an attempt failed with.
+///
+/// Error codes in response bodies, like the `ThrottlingException` of a 400 response, are only
+/// recognized if the client retries requests, since responses aren't read otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorClassification {
+    /// The response has a success or redirect status.
+    Success,
+    /// The service throttled the request.
+    Throttling,
+    /// The service failed with an error that is retried, like an HTTP 503.
+    Transient,
+    /// The service rejected the request with another client error status.
+    Client,
+    /// The service failed with another server error status.
+    Server,
+    /// The request couldn't be sent, or no response was received.
+    Dispatch,
+    /// Sending the request, or receiving the response, timed out.
+    Timeout,
+    /// No credentials could be fetched to sign the request with.
+    Credentials,
+    /// An interceptor ended the request before it was sent.
+    Rejected,
+}
+
+impl ErrorClassification {
+    /// Returns the name of the classification in snake case, e.g. `throttling`, to label
+    /// metrics with.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorClassification::Success => "success",
+            ErrorClassification::Throttling => "throttling",
+            ErrorClassification::Transient => "transient",
+            ErrorClassification::Client => "client",
+            ErrorClassification::Server => "server",
+            ErrorClassification::Dispatch => "dispatch",
+            ErrorClassification::Timeout => "timeout",
+            ErrorClassification::Credentials => "credentials",
+            ErrorClassification::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for ErrorClassification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A request being observed, only created for clients with an observer.
+pub(crate) struct RequestMetrics {
+    observer: Arc<dyn MetricsObserver>,
+    service: String,
+    operation: Option<String>,
+    region: String,
+    start: Instant,
+    /// The number and outcome of the last attempt that ended.
+    last: Mutex<Option<(u32, Outcome)>>,
+}
+
+impl RequestMetrics {
+    pub(crate) fn new(observer: Arc<dyn MetricsObserver>, request: &SignedRequest) -> Self {
+        RequestMetrics {
+            observer,
+            service: request.service.clone(),
+            operation: operation_name(request),
+            region: request.region.name().to_owned(),
+            start: Instant::now(),
+            last: Mutex::new(None),
+        }
+    }
+
+    fn info(&self) -> RequestInfo<'_> {
+        RequestInfo {
+            service: &self.service,
+            operation: self.operation.as_deref(),
+            region: &self.region,
+        }
+    }
+
+    pub(crate) fn attempt_start(&self, attempt: u32) {
+        self.observer.on_attempt_start(&self.info(), attempt);
+    }
+
+    pub(crate) fn attempt_end(&self, attempt: u32, outcome: Outcome, duration: Duration) {
+        *self.last.lock().unwrap() = Some((attempt, outcome));
+        self.observer
+            .on_attempt_end(&self.info(), attempt, &outcome, duration);
+    }
+
+    pub(crate) fn credentials(&self, success: bool, duration: Duration) {
+        self.observer
+            .on_credentials(&self.info(), success, duration);
+    }
+
+    pub(crate) fn complete(&self) {
+        if let Some((attempts, outcome)) = *self.last.lock().unwrap() {
+            self.observer.on_request_complete(
+                &self.info(),
+                attempts,
+                &outcome,
+                self.start.elapsed(),
+            );
+        }
+    }
+}
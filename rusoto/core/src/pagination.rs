@@ -0,0 +1,133 @@
+//! Streams of the pages of paginated operations.
+//!
+//! Service crates build their `*_pages` and `*_stream` methods on `paginate`.
+
+use std::future::Future;
+
+use futures::stream::{self, Stream, TryStreamExt};
+
+use crate::RusotoError;
+
+/// Streams the pages of a paginated operation, starting with the response to `request`.
+///
+/// `fetch` sends a request, and `next` returns the request for the page after the one it
+/// was given, or `None` once that was the last. The stream ends after the first error.
+///
+/// Pages are only requested while the stream is polled, and nothing runs in the background,
+/// so dropping the stream cancels the request in flight.
+pub fn paginate<R, P, E, F, Fut, N>(
+    request: R,
+    fetch: F,
+    next: N,
+) -> impl Stream<Item = Result<P, RusotoError<E>>>
+where
+    R: Clone,
+    F: FnMut(R) -> Fut,
+    Fut: Future<Output = Result<P, RusotoError<E>>>,
+    N: FnMut(R, &P) -> Option<R>,
+{
+    stream::unfold(
+        (Some(request), fetch, next),
+        |(request, mut fetch, mut next)| async move {
+            let request = request?;
+            match fetch(request.clone()).await {
+                Ok(page) => {
+                    let following = next(request, &page);
+                    Some((Ok(page), (following, fetch, next)))
+                }
+                Err(err) => Some((Err(err), (None, fetch, next))),
+            }
+        },
+    )
+}
+
+/// Flattens a stream of pages into a stream of the items `items` takes from each page.
+pub fn flatten_pages<P, T, E, S, I>(
+    pages: S,
+    mut items: I,
+) -> impl Stream<Item = Result<T, RusotoError<E>>>
+where
+    S: Stream<Item = Result<P, RusotoError<E>>>,
+    I: FnMut(P) -> Vec<T>,
+{
+    pages
+        .map_ok(move |page| stream::iter(items(page).into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::cell::RefCell;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Request(Option<u32>);
+
+    fn next(_: Request, page: &(Vec<u32>, Option<u32>)) -> Option<Request> {
+        page.1.map(|token| Request(Some(token)))
+    }
+
+    #[tokio::test]
+    async fn follows_tokens_until_the_last_page() {
+        let sent = RefCell::new(Vec::new());
+        let pages = paginate(
+            Request(None),
+            |request: Request| {
+                sent.borrow_mut().push(request.clone());
+                async move {
+                    Ok::<_, RusotoError<()>>(match request.0 {
+                        None => (vec![1, 2], Some(1)),
+                        Some(1) => (vec![3], Some(2)),
+                        _ => (vec![], None),
+                    })
+                }
+            },
+            next,
+        );
+
+        let items: Vec<u32> = flatten_pages(pages, |page| page.0)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(
+            *sent.borrow(),
+            vec![Request(None), Request(Some(1)), Request(Some(2))]
+        );
+    }
+
+    #[tokio::test]
+    async fn ends_after_an_error() {
+        let pages = paginate(
+            Request(None),
+            |_| async { Err::<(Vec<u32>, Option<u32>), _>(RusotoError::Service(())) },
+            |_, _| panic!("no next request after an error"),
+        );
+
+        let results: Vec<_> = pages.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn requests_pages_only_when_polled() {
+        let sent = RefCell::new(0);
+        let pages = paginate(
+            Request(None),
+            |_| {
+                *sent.borrow_mut() += 1;
+                async { Ok::<_, RusotoError<()>>((vec![1], Some(1))) }
+            },
+            |request, _| Some(request),
+        );
+        futures::pin_mut!(pages);
+
+        assert_eq!(*sent.borrow(), 0);
+        pages.next().await.unwrap().unwrap();
+        pages.next().await.unwrap().unwrap();
+        assert_eq!(*sent.borrow(), 2);
+    }
+}
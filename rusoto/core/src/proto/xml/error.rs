@@ -1,5 +1,11 @@
+use std::fmt;
+use std::ops::Deref;
+
 use log::*;
 
+use crate::error::AWS_REQUEST_ID_HEADER;
+use crate::request::BufferedHttpResponse;
+
 use super::util::{characters, end_element, peek_at_name, skip_tree, start_element, string_field};
 use super::util::{Next, Peek, XmlParseError};
 
@@ -55,3 +61,179 @@ impl XmlErrorDeserializer {
         Ok(obj)
     }
 }
+
+/// The message of an error parsed from an XML error response, with the ID the service gave
+/// the failed request, to quote in support cases.
+///
+/// It derefs to and displays as the message, so it can mostly be used like the `String` it
+/// replaces.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::XmlErrorMessage;
+///
+/// let message = XmlErrorMessage::new("Queue does not exist")
+///     .with_request_id("c6104cbe-af31-11e0-8154-cbc7ccf896c7");
+/// assert_eq!(message, "Queue does not exist");
+/// assert_eq!(message.request_id(), Some("c6104cbe-af31-11e0-8154-cbc7ccf896c7"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct XmlErrorMessage {
+    message: String,
+    request_id: Option<String>,
+}
+
+impl XmlErrorMessage {
+    /// An error message without a request ID.
+    pub fn new<S: Into<String>>(message: S) -> XmlErrorMessage {
+        XmlErrorMessage {
+            message: message.into(),
+            request_id: None,
+        }
+    }
+
+    /// Sets the ID of the failed request.
+    pub fn with_request_id<S: Into<String>>(mut self, request_id: S) -> XmlErrorMessage {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// The error `message` of `response`, with the request ID of its `x-amzn-RequestId` or
+    /// `x-amz-request-id` header, or else of the `RequestId` element of its body.
+    pub fn from_response(message: String, response: &BufferedHttpResponse) -> XmlErrorMessage {
+        XmlErrorMessage {
+            message,
+            request_id: response_request_id(response),
+        }
+    }
+
+    /// Returns the message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the ID of the failed request, if the response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+}
+
+fn response_request_id(response: &BufferedHttpResponse) -> Option<String> {
+    let header = response
+        .headers
+        .get(AWS_REQUEST_ID_HEADER)
+        .or_else(|| response.headers.get("x-amz-request-id"));
+    if let Some(request_id) = header {
+        return Some(request_id.to_owned());
+    }
+    let body = response.body_as_str();
+    let start = body.find("<RequestId>")? + "<RequestId>".len();
+    let end = start + body[start..].find("</RequestId>")?;
+    Some(body[start..end].trim().to_owned()).filter(|request_id| !request_id.is_empty())
+}
+
+impl fmt::Display for XmlErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Deref for XmlErrorMessage {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.message
+    }
+}
+
+impl AsRef<str> for XmlErrorMessage {
+    fn as_ref(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<String> for XmlErrorMessage {
+    fn from(message: String) -> XmlErrorMessage {
+        XmlErrorMessage::new(message)
+    }
+}
+
+impl<'a> From<&'a str> for XmlErrorMessage {
+    fn from(message: &'a str) -> XmlErrorMessage {
+        XmlErrorMessage::new(message)
+    }
+}
+
+impl From<XmlErrorMessage> for String {
+    fn from(message: XmlErrorMessage) -> String {
+        message.message
+    }
+}
+
+impl PartialEq<str> for XmlErrorMessage {
+    fn eq(&self, other: &str) -> bool {
+        self.message == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for XmlErrorMessage {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.message == *other
+    }
+}
+
+impl PartialEq<String> for XmlErrorMessage {
+    fn eq(&self, other: &String) -> bool {
+        self.message == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn response(body: &str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status: TryFrom::try_from(400).unwrap(),
+            body: body.to_owned().into(),
+            headers: Default::default(),
+        }
+    }
+
+    #[test]
+    fn request_ids_are_read_from_headers_before_bodies() {
+        let body = "<ErrorResponse><Error><Code>ExpiredToken</Code></Error>\
+                    <RequestId>4e5c1f6a-2d3b-4c8e-9f0a-1b2c3d4e5f60</RequestId></ErrorResponse>";
+        let message = XmlErrorMessage::from_response("expired".to_owned(), &response(body));
+        assert_eq!(message, "expired");
+        assert_eq!(
+            message.request_id(),
+            Some("4e5c1f6a-2d3b-4c8e-9f0a-1b2c3d4e5f60")
+        );
+
+        let mut with_header = response(body);
+        with_header.headers.insert(
+            "x-amzn-requestid",
+            "c6104cbe-af31-11e0-8154-cbc7ccf896c7".to_owned(),
+        );
+        let message = XmlErrorMessage::from_response("expired".to_owned(), &with_header);
+        assert_eq!(
+            message.request_id(),
+            Some("c6104cbe-af31-11e0-8154-cbc7ccf896c7")
+        );
+
+        let mut s3 = response("<Error><Code>NoSuchKey</Code></Error>");
+        s3.headers
+            .insert("x-amz-request-id", "4442587FB7D0A2F9".to_owned());
+        let message = XmlErrorMessage::from_response("missing".to_owned(), &s3);
+        assert_eq!(message.request_id(), Some("4442587FB7D0A2F9"));
+
+        let message = XmlErrorMessage::from_response(
+            "missing".to_owned(),
+            &response("<Error><RequestId> </RequestId></Error>"),
+        );
+        assert_eq!(message.request_id(), None);
+    }
+}
@@ -265,18 +265,29 @@ pub async fn parse_response<T, E>(
 where
     T: Default,
 {
-    let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-    if xml_response.body.is_empty() {
+    let xml_response = response.buffer().await?;
+    Ok(parse_payload(&xml_response.body, deserialize)?)
+}
+
+/// Deserializes an XML document, e.g. the payload of an event stream message, with
+/// `deserialize` called with the name of its root element. An empty document is
+/// deserialized as `T::default()`.
+pub fn parse_payload<T>(
+    payload: &[u8],
+    deserialize: fn(&str, &mut XmlResponse<'_>) -> Result<T, XmlParseError>,
+) -> Result<T, XmlParseError>
+where
+    T: Default,
+{
+    if payload.is_empty() {
         Ok(T::default())
     } else {
-        let reader = EventReader::new_with_config(
-            xml_response.body.as_ref(),
-            ParserConfig::new().trim_whitespace(false),
-        );
+        let reader =
+            EventReader::new_with_config(payload, ParserConfig::new().trim_whitespace(false));
         let mut stack = XmlResponse::new(reader.into_iter().peekable());
         let _start_document = stack.next();
         let actual_tag_name = peek_at_name(&mut stack)?;
-        Ok(deserialize(&actual_tag_name, &mut stack)?)
+        deserialize(&actual_tag_name, &mut stack)
     }
 }
 
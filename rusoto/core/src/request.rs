@@ -16,6 +16,7 @@ use std::time::Duration;
 use bytes::{Bytes, BytesMut};
 use futures::{FutureExt, StreamExt};
 use http::header::{HeaderName, HeaderValue};
+use http::uri::Authority;
 use http::{HeaderMap, Method, StatusCode};
 use hyper::client::connect::Connect;
 use hyper::client::Builder as HyperBuilder;
@@ -28,8 +29,15 @@ use tokio::time;
 use log::Level::Debug;
 use log::*;
 
+#[cfg(feature = "native-tls")]
+use crate::native_tls;
+use crate::proxy::ProxyConfig;
+use crate::proxy::ProxyConnector;
+#[cfg(feature = "rustls")]
+use crate::rustls;
 use crate::signature::SignedRequest;
 use crate::stream::ByteStream;
+use crate::timeout::{self, TimedBody, TimeoutError, TimeoutKind};
 use crate::tls::HttpsConnector;
 
 // Pulls in the statically generated rustc version.
@@ -100,8 +108,9 @@ impl HttpResponse {
     pub async fn buffer(&mut self) -> Result<BufferedHttpResponse, HttpDispatchError> {
         let mut bytes = BytesMut::new();
         while let Some(try_chunk) = self.body.next().await {
-            let chunk = try_chunk.map_err(|e| HttpDispatchError {
-                message: format!("Error obtaining body: {}", e),
+            let chunk = try_chunk.map_err(|e| match TimeoutError::from_io(&e) {
+                Some(timeout) => HttpDispatchError::from(timeout),
+                None => HttpDispatchError::new(format!("Error obtaining body: {}", e)),
             })?;
             bytes.extend(chunk);
         }
@@ -112,7 +121,12 @@ impl HttpResponse {
         })
     }
 
-    async fn from_hyper(hyper_response: HyperResponse<Body>) -> HttpResponse {
+    async fn from_hyper(
+        hyper_response: HyperResponse<Body>,
+        read_timeout: Option<Duration>,
+        operation_timeout: Option<Duration>,
+        started: time::Instant,
+    ) -> HttpResponse {
         let status = hyper_response.status();
         let headers = hyper_response
             .headers()
@@ -131,10 +145,21 @@ impl HttpResponse {
             })
         });
 
+        let body = if read_timeout.is_some() || operation_timeout.is_some() {
+            ByteStream::new(TimedBody::new(
+                body,
+                read_timeout,
+                operation_timeout,
+                started,
+            ))
+        } else {
+            ByteStream::new(body)
+        };
+
         HttpResponse {
             status,
             headers,
-            body: ByteStream::new(body),
+            body,
         }
     }
 }
@@ -143,12 +168,30 @@ impl HttpResponse {
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
     message: String,
+    timeout: Option<TimeoutError>,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError {
+            message,
+            timeout: None,
+        }
+    }
+
+    /// The timeout that elapsed, if the request failed because it timed out.
+    pub fn timeout(&self) -> Option<&TimeoutError> {
+        self.timeout.as_ref()
+    }
+}
+
+impl From<TimeoutError> for HttpDispatchError {
+    fn from(err: TimeoutError) -> HttpDispatchError {
+        HttpDispatchError {
+            message: err.to_string(),
+            timeout: Some(err),
+        }
     }
 }
 
@@ -162,16 +205,15 @@ impl fmt::Display for HttpDispatchError {
 
 impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
-        }
+        HttpDispatchError::new(err.to_string())
     }
 }
 
 impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
+        match TimeoutError::from_io(&err) {
+            Some(timeout) => HttpDispatchError::from(timeout),
+            None => HttpDispatchError::new(err.to_string()),
         }
     }
 }
@@ -210,33 +252,93 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
     }
 }
 
+type DefaultConnector = HttpsConnector<ProxyConnector>;
+
 /// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
+pub struct HttpClient<C = DefaultConnector> {
     inner: HyperClient<C, Body>,
     local_agent: Option<String>,
+    timeouts: Timeouts,
+    proxy: ProxyConfig,
+}
+
+/// The timeouts of an `HttpConfig`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Timeouts {
+    connect: Option<Duration>,
+    read: Option<Duration>,
+    operation: Option<Duration>,
 }
 
 impl HttpClient {
     /// Create a tls-enabled http client.
+    ///
+    /// It sends requests through the proxies named by the environment, see the `proxy`
+    /// module.
     pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
-
-        Ok(Self::from_connector(connector))
+        Self::new_with_config(HttpConfig::new())
     }
 
     /// Create a tls-enabled http client.
+    ///
+    /// It sends requests through the proxies of `config`, or those named by the environment
+    /// if it has none, see the `proxy` module.
+    ///
+    /// With the `rustls` feature it trusts the root certificates of the platform, and fails
+    /// if none can be loaded.
     pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
         #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
+        let client = {
+            let tls = native_tls::TlsConnector::new().map_err(|err| TlsError {
+                message: format!("Couldn't create NativeTlsClient: {}", err),
+            })?;
+            Self::with_native_tls(tls, config)
+        };
 
         #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
+        let client = Self::with_tls_config(default_tls_config()?, config);
 
-        Ok(Self::from_connector_with_config(connector, config))
+        Ok(client)
+    }
+
+    /// Create an http client connecting to HTTPS endpoints with `tls`, e.g. to trust a
+    /// private CA or present a client certificate.
+    ///
+    /// Like `new_with_config`, it sends requests through the proxies of `config`, or those
+    /// named by the environment if it has none.
+    #[cfg(feature = "native-tls")]
+    pub fn with_native_tls(tls: native_tls::TlsConnector, mut config: HttpConfig) -> Self {
+        let proxy = config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(config.connect_timeout);
+        let connector = HttpsConnector::from((
+            ProxyConnector::new(http, proxy.clone()),
+            tokio_tls::TlsConnector::from(tls),
+        ));
+
+        let mut client = Self::from_connector_with_config(connector, config);
+        client.proxy = proxy;
+        client
+    }
+
+    /// Create an http client connecting to HTTPS endpoints with `tls_config`, e.g. to trust
+    /// a private CA or present a client certificate.
+    ///
+    /// Like `new_with_config`, it sends requests through the proxies of `config`, or those
+    /// named by the environment if it has none.
+    #[cfg(feature = "rustls")]
+    pub fn with_tls_config(tls_config: rustls::ClientConfig, mut config: HttpConfig) -> Self {
+        let proxy = config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(config.connect_timeout);
+        let connector =
+            HttpsConnector::from((ProxyConnector::new(http, proxy.clone()), tls_config));
+
+        let mut client = Self::from_connector_with_config(connector, config);
+        client.proxy = proxy;
+        client
     }
 
     /// Sets a local agent that is prepended to the default HTTP
@@ -246,21 +348,54 @@ impl HttpClient {
     }
 }
 
+/// The TLS configuration of `hyper_rustls::HttpsConnector::new`, trusting the root
+/// certificates of the platform.
+#[cfg(feature = "rustls")]
+fn default_tls_config() -> Result<rustls::ClientConfig, TlsError> {
+    let mut tls_config = rustls::ClientConfig::new();
+    tls_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    tls_config.root_store = match rustls_native_certs::load_native_certs() {
+        Ok(store) => store,
+        Err((Some(store), err)) => {
+            warn!("Couldn't load all native root certificates: {}", err);
+            store
+        }
+        Err((None, err)) => {
+            return Err(TlsError {
+                message: format!("Couldn't load native root certificates: {}", err),
+            })
+        }
+    };
+    tls_config.ct_logs = Some(&ct_logs::LOGS);
+    Ok(tls_config)
+}
+
 impl<C> HttpClient<C>
 where
     C: Connect + Clone + Send + Sync,
 {
     /// Allows for a custom connector to be used with the HttpClient
+    ///
+    /// Any `hyper` connector works, e.g. a `hyper_tls` or `hyper_rustls` `HttpsConnector`
+    /// with its own TLS configuration. Requests sent through it get the same headers, timing
+    /// and retries around them as with the connector of `HttpClient::new`.
     pub fn from_connector(connector: C) -> Self {
         let inner = HyperClient::builder().build(connector);
         HttpClient {
             inner,
             local_agent: None,
+            timeouts: Timeouts::default(),
+            proxy: ProxyConfig::new(),
         }
     }
 
     /// Allows for a custom connector to be used with the HttpClient
     /// with extra configuration options
+    ///
+    /// The connect timeout of `config` is only used to recognize connect timeouts of
+    /// `connector`, which has to enforce it itself, e.g. with
+    /// `HttpConnector::set_connect_timeout`. Its proxies are ignored, wrap `connector` in a
+    /// `ProxyConnector` to use them.
     pub fn from_connector_with_config(connector: C, config: HttpConfig) -> Self {
         let mut builder = HyperClient::builder();
         config
@@ -271,6 +406,12 @@ where
         HttpClient {
             inner,
             local_agent: None,
+            timeouts: Timeouts {
+                connect: config.connect_timeout,
+                read: config.read_timeout,
+                operation: config.operation_timeout,
+            },
+            proxy: ProxyConfig::new(),
         }
     }
 
@@ -280,13 +421,21 @@ where
         HttpClient {
             inner,
             local_agent: None,
+            timeouts: Timeouts::default(),
+            proxy: ProxyConfig::new(),
         }
     }
 }
 
 /// Configuration options for the HTTP Client
+///
+/// No timeouts are set by default. See the `timeout` module for how timeouts surface.
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    operation_timeout: Option<Duration>,
+    proxy: Option<ProxyConfig>,
 }
 
 impl HttpConfig {
@@ -294,6 +443,10 @@ impl HttpConfig {
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
+            connect_timeout: None,
+            read_timeout: None,
+            operation_timeout: None,
+            proxy: None,
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -302,6 +455,32 @@ impl HttpConfig {
     pub fn read_buf_size(&mut self, sz: usize) {
         self.read_buf_size = Some(sz);
     }
+
+    /// Sets how long connecting to an endpoint may take.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long to wait for the next chunk of a response body.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long a request may take, from sending it until its response body is read.
+    /// It can be replaced for some requests with `timeout::with_operation_timeout`.
+    pub fn operation_timeout(mut self, timeout: Duration) -> Self {
+        self.operation_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the proxies to send requests through, instead of those named by the environment.
+    /// `ProxyConfig::new()` sends requests directly.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
 }
 
 impl Default for HttpConfig {
@@ -315,11 +494,14 @@ async fn http_client_dispatch<'a, C>(
     client: HyperClient<C, Body>,
     request: SignedRequest,
     timeout: Option<Duration>,
+    timeouts: Timeouts,
     user_agent: HeaderValue,
+    proxy_authorization: Option<HeaderValue>,
 ) -> Result<HttpResponse, HttpDispatchError>
 where
     C: Connect + Send + Sync + Clone + 'static,
 {
+    let started = time::Instant::now();
     let hyper_method = match request.method().as_ref() {
         "POST" => Method::POST,
         "PUT" => Method::PUT,
@@ -327,9 +509,10 @@ where
         "GET" => Method::GET,
         "HEAD" => Method::HEAD,
         v => {
-            return Err(HttpDispatchError {
-                message: format!("Unsupported HTTP verb {}", v),
-            });
+            return Err(HttpDispatchError::new(format!(
+                "Unsupported HTTP verb {}",
+                v
+            )));
         }
     };
 
@@ -339,24 +522,31 @@ where
         let header_name = match h.0.parse::<HeaderName>() {
             Ok(name) => name,
             Err(err) => {
-                return Err(HttpDispatchError {
-                    message: format!("error parsing header name: {}", err),
-                });
+                return Err(HttpDispatchError::new(format!(
+                    "error parsing header name: {}",
+                    err
+                )));
             }
         };
         for v in h.1.iter() {
             let header_value = match HeaderValue::from_bytes(v) {
                 Ok(value) => value,
                 Err(err) => {
-                    return Err(HttpDispatchError {
-                        message: format!("error parsing header value: {}", err),
-                    });
+                    return Err(HttpDispatchError::new(format!(
+                        "error parsing header value: {}",
+                        err
+                    )));
                 }
             };
             hyper_headers.append(&header_name, header_value);
         }
     }
 
+    // Proxies forwarding plain HTTP requests only see credentials sent along with them.
+    if let Some(authorization) = proxy_authorization {
+        hyper_headers.insert("proxy-authorization", authorization);
+    }
+
     // Add a default user-agent header if one is not already present.
     if !hyper_headers.contains_key("user-agent") {
         hyper_headers.insert("user-agent", user_agent);
@@ -390,29 +580,50 @@ where
         http_request_builder.body(Body::empty())
     };
 
-    let mut http_request = try_http_request.map_err(|err| HttpDispatchError {
-        message: format!("error building request: {}", err),
-    })?;
+    let mut http_request = try_http_request
+        .map_err(|err| HttpDispatchError::new(format!("error building request: {}", err)))?;
 
     *http_request.headers_mut() = hyper_headers;
 
     let f = client.request(http_request);
 
-    let try_resp = match timeout {
+    // `timeout` bounds the wait for the response headers, the operation timeout the request
+    // including its response body.
+    let operation_timeout = timeout::operation_timeout_override().unwrap_or(timeouts.operation);
+    let headers_timeout = match (timeout, operation_timeout) {
+        (Some(timeout), Some(operation)) => Some(timeout.min(operation)),
+        (timeout, operation) => timeout.or(operation),
+    };
+    let try_resp = match headers_timeout {
         None => f.await,
         Some(duration) => match time::timeout(duration, f).await {
-            Err(_e) => {
-                return Err(HttpDispatchError {
-                    message: "Timeout while dispatching request".to_owned(),
-                })
-            }
+            Err(_e) => return Err(TimeoutError::new(TimeoutKind::Operation, duration).into()),
             Ok(try_req) => try_req,
         },
     };
-    let resp = try_resp.map_err(|e| HttpDispatchError {
-        message: format!("Error during dispatch: {}", e),
+    let resp = try_resp.map_err(|e| match connect_timeout(&e, timeouts.connect) {
+        Some(timeout) => HttpDispatchError::from(timeout),
+        None => HttpDispatchError::new(format!("Error during dispatch: {}", e)),
     })?;
-    Ok(HttpResponse::from_hyper(resp).await)
+    Ok(HttpResponse::from_hyper(resp, timeouts.read, operation_timeout, started).await)
+}
+
+/// The connect timeout error `err` is, if it's a connection attempt timing out.
+fn connect_timeout(err: &HyperError, timeout: Option<Duration>) -> Option<TimeoutError> {
+    let timeout = timeout?;
+    if !err.is_connect() {
+        return None;
+    }
+    let mut source = err.source();
+    while let Some(cause) = source {
+        match cause.downcast_ref::<IoError>() {
+            Some(io_err) if io_err.kind() == io::ErrorKind::TimedOut => {
+                return Some(TimeoutError::new(TimeoutKind::Connect, timeout));
+            }
+            _ => source = cause.source(),
+        }
+    }
+    None
 }
 
 impl<C> DispatchSignedRequest for HttpClient<C>
@@ -430,8 +641,29 @@ where
             .map(|agent| format!("{} {}", agent, *DEFAULT_USER_AGENT).parse())
             .unwrap_or_else(|| DEFAULT_USER_AGENT.parse())
             .expect("failed to parse user-agent string");
+        let proxy_authorization = if request.scheme() == "http" {
+            let hostname = request.hostname();
+            let host = hostname
+                .parse::<Authority>()
+                .map(|authority| authority.host().to_owned())
+                .unwrap_or(hostname);
+            self.proxy
+                .proxy_for("http", &host)
+                .and_then(|proxy| proxy.authorization())
+                .and_then(|authorization| authorization.parse().ok())
+        } else {
+            None
+        };
 
-        http_client_dispatch::<C>(self.inner.clone(), request, timeout, user_agent).boxed()
+        http_client_dispatch::<C>(
+            self.inner.clone(),
+            request,
+            timeout,
+            self.timeouts,
+            user_agent,
+            proxy_authorization,
+        )
+        .boxed()
     }
 }
 
@@ -512,4 +744,133 @@ mod tests {
         let error = HttpDispatchError::from(io_error);
         assert_eq!(error.to_string(), "my error message")
     }
+
+    /// Serves one connection on a local port, writing `response` and then stalling.
+    async fn stalling_server(response: &'static str) -> Region {
+        use tokio::io::AsyncWriteExt;
+
+        let mut listener = tokio::net::TcpListener::bind((std::net::Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            socket.write_all(response.as_bytes()).await.unwrap();
+            futures::future::pending::<()>().await;
+        });
+        Region::Custom {
+            name: "local".to_owned(),
+            endpoint,
+        }
+    }
+
+    /// Answers HTTPS requests on a local port with a certificate for `localhost` signed by
+    /// itself.
+    #[cfg(feature = "native-tls")]
+    async fn tls_server() -> Region {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let identity = native_tls::Identity::from_pkcs12(
+            include_bytes!("../test_resources/localhost.p12"),
+            "rusoto",
+        )
+        .unwrap();
+        let acceptor =
+            tokio_tls::TlsAcceptor::from(native_tls::TlsAcceptor::new(identity).unwrap());
+        let mut listener = tokio::net::TcpListener::bind((std::net::Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let endpoint = format!(
+            "https://localhost:{}",
+            listener.local_addr().unwrap().port()
+        );
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    if let Ok(mut stream) = acceptor.accept(socket).await {
+                        let mut request = [0; 1024];
+                        let _ = stream.read(&mut request).await;
+                        let _ = stream
+                            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok")
+                            .await;
+                    }
+                });
+            }
+        });
+        Region::Custom {
+            name: "local".to_owned(),
+            endpoint,
+        }
+    }
+
+    #[cfg(feature = "native-tls")]
+    #[tokio::test]
+    async fn with_native_tls_trusts_custom_roots() {
+        let region = tls_server().await;
+        let request = || SignedRequest::new("GET", "s3", &region, "/");
+        let config = || HttpConfig::new().proxy(ProxyConfig::new());
+
+        let client = HttpClient::new_with_config(config()).unwrap();
+        assert!(client.dispatch(request(), None).await.is_err());
+
+        let root =
+            native_tls::Certificate::from_pem(include_bytes!("../test_resources/localhost.pem"))
+                .unwrap();
+        let tls = native_tls::TlsConnector::builder()
+            .add_root_certificate(root)
+            .build()
+            .unwrap();
+        let client = HttpClient::with_native_tls(tls, config());
+        let mut response = client.dispatch(request(), None).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "ok");
+    }
+
+    #[tokio::test]
+    async fn operation_timeout_bounds_unanswered_requests() {
+        let region = stalling_server("").await;
+        let timeout = Duration::from_millis(50);
+        let config = HttpConfig::default().operation_timeout(timeout);
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(), config);
+
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        let err = client.dispatch(request, None).await.err().unwrap();
+        assert_eq!(
+            err.timeout(),
+            Some(&TimeoutError::new(TimeoutKind::Operation, timeout))
+        );
+        assert!(matches!(
+            crate::RusotoError::<()>::from(err),
+            crate::RusotoError::Timeout(_)
+        ));
+
+        let shorter = Duration::from_millis(10);
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        let dispatch = client.dispatch(request, None);
+        let err = timeout::with_operation_timeout(Some(shorter), dispatch)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.timeout().map(TimeoutError::duration), Some(shorter));
+    }
+
+    #[tokio::test]
+    async fn read_timeout_applies_to_the_response_body() {
+        let region = stalling_server("HTTP/1.1 200 OK\r\ncontent-length: 10\r\n\r\nabc").await;
+        let timeout = Duration::from_millis(50);
+        let config = HttpConfig::default().read_timeout(timeout);
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(), config);
+
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        let mut response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let err = response.buffer().await.err().unwrap();
+        assert_eq!(
+            err.timeout(),
+            Some(&TimeoutError::new(TimeoutKind::Read, timeout))
+        );
+    }
 }
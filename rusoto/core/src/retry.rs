@@ -0,0 +1,825 @@
+//! Retrying of requests that failed with a transient error.
+//!
+//! A `Client` sends every request once unless it's given a `RetryPolicy` with
+//! `Client::with_retry_policy`. With a policy, requests that fail with a connection error, an
+//! HTTP 500, 502, 503 or 504 response, or a throttling or request timeout error code (e.g.
+//! `ThrottlingException` or `ProvisionedThroughputExceededException`) are sent again after an
+//! exponentially growing, jittered delay, until they succeed or run out of attempts.
+//!
+//! HTTP 429 responses and throttling error codes are retried too. A `Retry-After` header on a
+//! retried response is the minimum delay before the next attempt; responses asking to wait
+//! longer than the policy's maximum delay aren't retried.
+//!
+//! Every attempt carries an `amz-sdk-request: attempt=<n>; max=<max>` header, so dispatchers
+//! wrapping the `HttpClient` can see which attempt they're sending. Requests with a payload
+//! stream that can't be replayed are only sent once.
+//!
+//! In `RetryMode::Adaptive` a client additionally limits how often it retries with a retry
+//! quota, and how fast it sends requests with a rate limiter that slows down after throttling
+//! errors, like the adaptive retry mode of the newer AWS SDKs. The mode can be selected with
+//! the `AWS_RETRY_MODE` environment variable or the `retry_mode` key of the current profile,
+//! see `RetryPolicy::from_env`.
+
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use http::{HeaderMap, StatusCode};
+
+use crate::credential::ProfileProvider;
+use crate::request::BufferedHttpResponse;
+use crate::RusotoError;
+
+const AWS_RETRY_MODE: &str = "AWS_RETRY_MODE";
+const RETRY_MODE: &str = "retry_mode";
+
+/// The header carrying the attempt number of a request and the maximum number of attempts.
+pub const AMZ_SDK_REQUEST: &str = "amz-sdk-request";
+
+/// The default maximum number of attempts of a `RetryPolicy`, including the first one.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(20);
+
+/// Error codes of transient errors other than throttling, which are retried.
+const TRANSIENT_ERROR_CODES: &[&str] = &["RequestTimeout", "RequestTimeoutException"];
+
+/// Error codes of throttling errors, which are retried and after which an adaptive client
+/// slows down. The same list as the other AWS SDKs, including service-specific codes.
+const THROTTLING_ERROR_CODES: &[&str] = &[
+    "BandwidthLimitExceeded",
+    "EC2ThrottledException",
+    "LimitExceededException",
+    "PriorRequestNotComplete",
+    "ProvisionedThroughputExceededException",
+    "RequestLimitExceeded",
+    "RequestThrottled",
+    "RequestThrottledException",
+    "SlowDown",
+    "ThrottledException",
+    "Throttling",
+    "ThrottlingException",
+    "TooManyRequestsException",
+    "TransactionInProgressException",
+];
+
+/// The number of tokens in the retry quota of an adaptive `Client`.
+pub const RETRY_QUOTA_CAPACITY: u32 = 500;
+
+/// The number of tokens a retry takes from the retry quota.
+pub const RETRY_COST: u32 = 5;
+
+/// The number of tokens a request that succeeds on its first attempt returns to the quota.
+pub const NO_RETRY_INCREMENT: u32 = 1;
+
+/// How a `RetryPolicy` paces retries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryMode {
+    /// Retry with exponential backoff.
+    Standard,
+    /// Retry with exponential backoff while the client's retry quota lasts, and limit the rate
+    /// requests are sent at after throttling errors. All requests sent through one `Client`,
+    /// its clones and the service clients created from it, share the quota and the rate.
+    Adaptive,
+}
+
+impl RetryMode {
+    /// Returns the mode set with the `AWS_RETRY_MODE` environment variable, or otherwise with
+    /// the `retry_mode` key of the current profile in the AWS config file. `legacy` is treated
+    /// as `standard`, and other unknown values are ignored.
+    pub fn from_env() -> Option<RetryMode> {
+        env::var(AWS_RETRY_MODE)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .or_else(|| {
+                ProfileProvider::setting(RETRY_MODE)
+                    .ok()
+                    .and_then(|value| value)
+                    .and_then(|value| value.parse().ok())
+            })
+    }
+}
+
+impl FromStr for RetryMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<RetryMode, ()> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "legacy" | "standard" => Ok(RetryMode::Standard),
+            "adaptive" => Ok(RetryMode::Adaptive),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How many times, and how long apart, a `Client` sends requests that failed with a transient
+/// error.
+///
+/// The delay after attempt `n` is chosen by the jitter function from zero up to
+/// `min(max_delay, base_delay * 2^(n - 1))`. The default jitter picks it uniformly at random
+/// ("full jitter"), which spreads out the retries of clients throttled at the same time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rusoto_core::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::new()
+///     .with_max_attempts(5)
+///     .with_base_delay(Duration::from_millis(50))
+///     .with_max_delay(Duration::from_secs(5));
+/// assert!(policy.delay(3) <= Duration::from_millis(200));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    mode: RetryMode,
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: Arc<dyn Fn(Duration) -> Duration + Send + Sync>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to `DEFAULT_MAX_ATTEMPTS` attempts, with a base delay of
+    /// 100 milliseconds and a maximum delay of 20 seconds.
+    pub fn new() -> Self {
+        RetryPolicy {
+            mode: RetryMode::Standard,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            jitter: Arc::new(full_jitter),
+        }
+    }
+
+    /// Creates a policy sending every request only once.
+    pub fn never() -> Self {
+        RetryPolicy::new().with_max_attempts(1)
+    }
+
+    /// Creates a policy like `new` in `RetryMode::Adaptive`.
+    pub fn adaptive() -> Self {
+        RetryPolicy::new().with_mode(RetryMode::Adaptive)
+    }
+
+    /// Returns the default policy in the mode selected by `RetryMode::from_env`, or
+    /// `RetryPolicy::never()` if no mode is selected. This is the policy of new clients.
+    pub fn from_env() -> Self {
+        match RetryMode::from_env() {
+            Some(mode) => RetryPolicy::new().with_mode(mode),
+            None => RetryPolicy::never(),
+        }
+    }
+
+    /// Sets how retries are paced.
+    pub fn with_mode(mut self, mode: RetryMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// How retries are paced.
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    /// Sets the maximum number of attempts, including the first one. Values below 1 are
+    /// treated as 1.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay ceiling after the first attempt, which doubles with every attempt.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the highest delay ceiling between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the function choosing the delay between two attempts from the exponential backoff
+    /// ceiling. Returning the ceiling itself disables jitter, which is useful in tests.
+    pub fn with_jitter<F>(mut self, jitter: F) -> Self
+    where
+        F: Fn(Duration) -> Duration + Send + Sync + 'static,
+    {
+        self.jitter = Arc::new(jitter);
+        self
+    }
+
+    /// The maximum number of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay before retrying a request whose `attempt`th attempt (starting at 1) failed.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let ceiling = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+        (self.jitter)(ceiling)
+    }
+
+    /// The delay before retrying a request whose `attempt`th attempt failed with a response
+    /// asking to wait `retry_after`: the longer of that and the backoff `delay`. Returns `None`
+    /// if the response asks to wait longer than the maximum delay, so the request isn't retried.
+    pub(crate) fn delay_after(
+        &self,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        let delay = self.delay(attempt);
+        match retry_after {
+            Some(retry_after) if retry_after > self.max_delay => None,
+            Some(retry_after) => Some(delay.max(retry_after)),
+            None => Some(delay),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("mode", &self.mode)
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .finish()
+    }
+}
+
+/// Picks a delay uniformly at random from zero up to `ceiling`.
+fn full_jitter(ceiling: Duration) -> Duration {
+    // Every `RandomState` is seeded differently, which is random enough to spread out retries
+    // without depending on a random number generator.
+    let random = RandomState::new().build_hasher().finish();
+    let nanos = ceiling.as_nanos().min(u128::from(u64::MAX - 1)) as u64;
+    Duration::from_nanos(random % (nanos + 1))
+}
+
+/// Whether a response with `status` is retried regardless of its error code.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || matches!(status.as_u16(), 500 | 502 | 503 | 504)
+}
+
+/// Whether a response with the error code `code` is retried.
+pub(crate) fn is_retryable_error_code(code: &str) -> bool {
+    TRANSIENT_ERROR_CODES.contains(&code) || is_throttling_error_code(code)
+}
+
+/// Whether a response with the error code `code` is a throttling error.
+pub(crate) fn is_throttling_error_code(code: &str) -> bool {
+    THROTTLING_ERROR_CODES.contains(&code)
+}
+
+/// Whether `err` is a throttling error: an HTTP 429 response, or an error with one of the
+/// throttling error codes the AWS SDKs retry, like `ThrottlingException`,
+/// `TooManyRequestsException` or `ProvisionedThroughputExceededException`.
+///
+/// Service errors are recognized by the name of their variant, which is the error code
+/// without its `Exception` suffix, e.g. `DescribeTableError::Throttling`.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_core::retry::is_throttling_error;
+/// use rusoto_core::RusotoError;
+///
+/// #[derive(Debug)]
+/// enum PutItemError {
+///     ProvisionedThroughputExceeded(String),
+///     ResourceNotFound(String),
+/// }
+///
+/// let throttled = PutItemError::ProvisionedThroughputExceeded("slow down".to_owned());
+/// assert!(is_throttling_error(&RusotoError::Service(throttled)));
+/// let not_found = PutItemError::ResourceNotFound("no table".to_owned());
+/// assert!(!is_throttling_error(&RusotoError::Service(not_found)));
+/// ```
+pub fn is_throttling_error<E: fmt::Debug>(err: &RusotoError<E>) -> bool {
+    match err {
+        RusotoError::Service(err) => {
+            let debug = format!("{:?}", err);
+            let variant = debug
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap_or("");
+            THROTTLING_ERROR_CODES
+                .iter()
+                .any(|code| *code == variant || code.trim_end_matches("Exception") == variant)
+        }
+        RusotoError::Unknown(response) => {
+            response.status == StatusCode::TOO_MANY_REQUESTS
+                || matches!(error_code(response), Some(ref code) if is_throttling_error_code(code))
+        }
+        _ => false,
+    }
+}
+
+/// The delay a response asks for in its `Retry-After` header, given either in seconds or as
+/// an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap<String>) -> Option<Duration> {
+    let value = headers.get("retry-after")?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// Extracts the error code of an error response, from the `x-amzn-ErrorType` header, the
+/// `__type` or `code` field of a JSON body, or the `Code` element of an XML body.
+pub(crate) fn error_code(response: &BufferedHttpResponse) -> Option<String> {
+    if let Some(error_type) = response.headers.get("x-amzn-errortype") {
+        return Some(strip_error_type(error_type.split(':').next().unwrap_or("")));
+    }
+    if let Ok(serde_json::Value::Object(body)) =
+        serde_json::from_slice::<serde_json::Value>(&response.body)
+    {
+        return ["__type", "code", "Code"]
+            .iter()
+            .filter_map(|field| body.get(*field).and_then(|value| value.as_str()))
+            .next()
+            .map(strip_error_type);
+    }
+    let body = response.body_as_str();
+    let start = body.find("<Code>")? + "<Code>".len();
+    let end = start + body[start..].find("</Code>")?;
+    Some(body[start..end].trim().to_owned())
+}
+
+/// Removes the namespace from error types like
+/// `com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException`.
+fn strip_error_type(error_type: &str) -> String {
+    error_type
+        .rsplit('#')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_owned()
+}
+
+/// The retry quota and send rate of an adaptive `Client`, returned by
+/// `Client::adaptive_retry_stats` for metrics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdaptiveRetryStats {
+    /// Tokens left in the retry quota.
+    pub available_tokens: u32,
+    /// The capacity of the retry quota.
+    pub capacity: u32,
+    /// The rate, in requests per second, the client limits itself to, or `None` if it hasn't
+    /// been throttled yet.
+    pub send_rate: Option<f64>,
+    /// The rate, in requests per second, the client has recently been sending requests at.
+    pub measured_send_rate: f64,
+}
+
+/// The state an adaptive `Client` shares between its requests.
+pub(crate) struct AdaptiveRetry {
+    epoch: Instant,
+    available_tokens: u32,
+    limiter: RateLimiter,
+}
+
+impl AdaptiveRetry {
+    pub(crate) fn new() -> Self {
+        AdaptiveRetry {
+            epoch: Instant::now(),
+            available_tokens: RETRY_QUOTA_CAPACITY,
+            limiter: RateLimiter::new(0.0),
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.epoch.elapsed().as_secs_f64()
+    }
+
+    /// Reserves a slot to send a request in, returning how long to wait before sending it.
+    pub(crate) fn acquire_send(&mut self) -> Duration {
+        let now = self.now();
+        self.limiter.acquire(now)
+    }
+
+    /// Updates the send rate after a response, or a dispatch error, was received.
+    pub(crate) fn record_response(&mut self, throttling: bool) {
+        let now = self.now();
+        self.limiter.update(now, throttling);
+    }
+
+    /// Takes the cost of a retry from the quota. Returns false, and takes nothing, if the quota
+    /// has too few tokens left.
+    pub(crate) fn acquire_retry(&mut self) -> bool {
+        if self.available_tokens < RETRY_COST {
+            return false;
+        }
+        self.available_tokens -= RETRY_COST;
+        true
+    }
+
+    /// Returns tokens to the quota after a request succeeded: the cost of its last retry if it
+    /// was retried, and `NO_RETRY_INCREMENT` otherwise.
+    pub(crate) fn release(&mut self, retried: bool) {
+        let tokens = if retried {
+            RETRY_COST
+        } else {
+            NO_RETRY_INCREMENT
+        };
+        self.available_tokens = (self.available_tokens + tokens).min(RETRY_QUOTA_CAPACITY);
+    }
+
+    pub(crate) fn stats(&self) -> AdaptiveRetryStats {
+        AdaptiveRetryStats {
+            available_tokens: self.available_tokens,
+            capacity: RETRY_QUOTA_CAPACITY,
+            send_rate: if self.limiter.enabled {
+                Some(self.limiter.fill_rate)
+            } else {
+                None
+            },
+            measured_send_rate: self.limiter.measured_tx_rate,
+        }
+    }
+}
+
+// Constants of the CUBIC rate limiter used by the adaptive retry mode of the AWS SDKs.
+const BETA: f64 = 0.7;
+const SCALE_CONSTANT: f64 = 0.4;
+const SMOOTH: f64 = 0.8;
+const MIN_FILL_RATE: f64 = 0.5;
+const MIN_CAPACITY: f64 = 1.0;
+
+/// A token bucket whose fill rate follows the CUBIC congestion control algorithm: it drops to
+/// `BETA` times the measured send rate on throttling, and then grows back along a cubic curve
+/// that flattens around the rate that was throttled. Times are in seconds.
+///
+/// The bucket is only used once a request was throttled; until then requests aren't delayed.
+struct RateLimiter {
+    fill_rate: f64,
+    max_capacity: f64,
+    current_capacity: f64,
+    last_timestamp: Option<f64>,
+    enabled: bool,
+    measured_tx_rate: f64,
+    last_tx_rate_bucket: f64,
+    request_count: u64,
+    last_max_rate: f64,
+    last_throttle_time: f64,
+    time_window: f64,
+}
+
+impl RateLimiter {
+    fn new(now: f64) -> Self {
+        RateLimiter {
+            fill_rate: 0.0,
+            max_capacity: 0.0,
+            current_capacity: 0.0,
+            last_timestamp: None,
+            enabled: false,
+            measured_tx_rate: 0.0,
+            last_tx_rate_bucket: now.floor(),
+            request_count: 0,
+            last_max_rate: 0.0,
+            last_throttle_time: now,
+            time_window: 0.0,
+        }
+    }
+
+    /// Takes a token for a request, returning how long to wait for it if the bucket is empty.
+    fn acquire(&mut self, now: f64) -> Duration {
+        if !self.enabled {
+            return Duration::from_secs(0);
+        }
+        self.refill(now);
+        let wait = if self.current_capacity < 1.0 {
+            (1.0 - self.current_capacity) / self.fill_rate
+        } else {
+            0.0
+        };
+        // Taking the token now reserves it, so concurrent requests queue up behind each other.
+        self.current_capacity -= 1.0;
+        Duration::from_secs_f64(wait)
+    }
+
+    fn refill(&mut self, now: f64) {
+        if let Some(last_timestamp) = self.last_timestamp {
+            let fill_amount = (now - last_timestamp) * self.fill_rate;
+            self.current_capacity = (self.current_capacity + fill_amount).min(self.max_capacity);
+        }
+        self.last_timestamp = Some(now);
+    }
+
+    /// Adjusts the fill rate after a response, which was a throttling error if `throttling`.
+    fn update(&mut self, now: f64, throttling: bool) {
+        self.update_measured_rate(now);
+        let calculated_rate = if throttling {
+            let rate_to_use = if self.enabled {
+                self.measured_tx_rate.min(self.fill_rate)
+            } else {
+                self.measured_tx_rate
+            };
+            self.last_max_rate = rate_to_use;
+            self.calculate_time_window();
+            self.last_throttle_time = now;
+            self.enabled = true;
+            rate_to_use * BETA
+        } else {
+            self.calculate_time_window();
+            self.cubic_success(now)
+        };
+        let new_rate = calculated_rate.min(2.0 * self.measured_tx_rate);
+        self.refill(now);
+        self.fill_rate = new_rate.max(MIN_FILL_RATE);
+        self.max_capacity = new_rate.max(MIN_CAPACITY);
+        self.current_capacity = self.current_capacity.min(self.max_capacity);
+    }
+
+    /// Measures the send rate in half-second buckets, smoothed exponentially.
+    fn update_measured_rate(&mut self, now: f64) {
+        let time_bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if time_bucket > self.last_tx_rate_bucket {
+            let current_rate = self.request_count as f64 / (time_bucket - self.last_tx_rate_bucket);
+            self.measured_tx_rate = current_rate * SMOOTH + self.measured_tx_rate * (1.0 - SMOOTH);
+            self.request_count = 0;
+            self.last_tx_rate_bucket = time_bucket;
+        }
+    }
+
+    fn calculate_time_window(&mut self) {
+        self.time_window = (self.last_max_rate * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+    }
+
+    fn cubic_success(&self, now: f64) -> f64 {
+        let dt = now - self.last_throttle_time;
+        SCALE_CONSTANT * (dt - self.time_window).powi(3) + self.last_max_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &str) -> BufferedHttpResponse {
+        let mut header_map = HeaderMap::<String>::default();
+        for (name, value) in headers {
+            header_map.insert(
+                http::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                (*value).to_owned(),
+            );
+        }
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: body.to_owned().into(),
+            headers: header_map,
+        }
+    }
+
+    #[test]
+    fn delays_grow_exponentially_up_to_max_delay() {
+        let policy = RetryPolicy::new()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500))
+            .with_jitter(|ceiling| ceiling);
+        let delays: Vec<_> = (1..=5).map(|attempt| policy.delay(attempt)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500, 500]
+                .iter()
+                .map(|ms| Duration::from_millis(*ms))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(policy.delay(1000), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn full_jitter_stays_below_ceiling() {
+        let policy = RetryPolicy::new();
+        for attempt in 1..=10 {
+            let ceiling = (DEFAULT_BASE_DELAY * (1 << (attempt - 1))).min(DEFAULT_MAX_DELAY);
+            assert!(policy.delay(attempt) <= ceiling);
+        }
+        assert_eq!(full_jitter(Duration::from_secs(0)), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn max_attempts_is_at_least_one() {
+        assert_eq!(RetryPolicy::new().max_attempts(), DEFAULT_MAX_ATTEMPTS);
+        assert_eq!(RetryPolicy::never().max_attempts(), 1);
+        assert_eq!(RetryPolicy::new().with_max_attempts(0).max_attempts(), 1);
+    }
+
+    #[test]
+    fn extracts_error_codes() {
+        let cases = [
+            (
+                response(
+                    400,
+                    &[("x-amzn-ErrorType", "ThrottlingException:http://internal/")],
+                    "{}",
+                ),
+                Some("ThrottlingException"),
+            ),
+            (
+                response(
+                    400,
+                    &[],
+                    r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"slow down"}"#,
+                ),
+                Some("ProvisionedThroughputExceededException"),
+            ),
+            (
+                response(
+                    400,
+                    &[],
+                    "<Error><Code>RequestTimeout</Code><Message>Idle</Message></Error>",
+                ),
+                Some("RequestTimeout"),
+            ),
+            (response(400, &[], "not an error document"), None),
+        ];
+        for (response, code) in cases.iter() {
+            assert_eq!(error_code(response).as_deref(), *code, "{:?}", response);
+        }
+    }
+
+    #[test]
+    fn classifies_retryable_errors() {
+        for status in &[429, 500, 502, 503, 504] {
+            assert!(is_retryable_status(StatusCode::from_u16(*status).unwrap()));
+        }
+        for status in &[400, 403, 404, 501] {
+            assert!(!is_retryable_status(StatusCode::from_u16(*status).unwrap()));
+        }
+        assert!(is_retryable_error_code("ThrottlingException"));
+        assert!(is_retryable_error_code("RequestTimeout"));
+        assert!(is_retryable_error_code("LimitExceededException"));
+        assert!(!is_retryable_error_code("ValidationException"));
+        assert!(is_throttling_error_code("TransactionInProgressException"));
+        assert!(!is_throttling_error_code("RequestTimeout"));
+    }
+
+    #[derive(Debug)]
+    enum TestError {
+        Throttling(String),
+        TooManyRequests(String),
+        Validation(String),
+    }
+
+    #[test]
+    fn recognizes_throttling_errors() {
+        let service = |err| is_throttling_error(&RusotoError::Service(err));
+        assert!(service(TestError::Throttling("rate exceeded".to_owned())));
+        assert!(service(TestError::TooManyRequests("slow down".to_owned())));
+        assert!(!service(TestError::Validation("Throttling".to_owned())));
+
+        let unknown = |status, body| {
+            is_throttling_error::<TestError>(&RusotoError::Unknown(response(status, &[], body)))
+        };
+        assert!(unknown(429, ""));
+        assert!(unknown(400, r#"{"__type":"SlowDown"}"#));
+        assert!(!unknown(400, r#"{"__type":"ValidationException"}"#));
+        assert!(!is_throttling_error::<TestError>(&RusotoError::Blocking));
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::<String>::default();
+            headers.insert("Retry-After", value.to_owned());
+            headers
+        };
+        assert_eq!(retry_after(&headers("3")), Some(Duration::from_secs(3)));
+        assert_eq!(
+            retry_after(&headers("Sun, 06 Nov 1994 08:49:37 GMT")),
+            Some(Duration::from_secs(0))
+        );
+        let later = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(120));
+        let delay = retry_after(&headers(&later)).unwrap();
+        assert!(delay > Duration::from_secs(110) && delay <= Duration::from_secs(120));
+        assert_eq!(retry_after(&headers("soon")), None);
+        assert_eq!(retry_after(&HeaderMap::<String>::default()), None);
+    }
+
+    #[test]
+    fn retry_after_is_a_minimum_delay() {
+        let policy = RetryPolicy::new()
+            .with_max_delay(Duration::from_secs(10))
+            .with_jitter(|ceiling| ceiling);
+        assert_eq!(
+            policy.delay_after(1, None),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay_after(1, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.delay_after(1, Some(Duration::from_millis(10))),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(policy.delay_after(1, Some(Duration::from_secs(11))), None);
+    }
+
+    #[test]
+    fn parses_retry_modes() {
+        assert_eq!("adaptive".parse(), Ok(RetryMode::Adaptive));
+        assert_eq!(" Standard ".parse(), Ok(RetryMode::Standard));
+        assert_eq!("legacy".parse(), Ok(RetryMode::Standard));
+        assert_eq!("other".parse::<RetryMode>(), Err(()));
+    }
+
+    #[test]
+    fn retry_quota_charges_retries_and_refunds_successes() {
+        let mut state = AdaptiveRetry::new();
+        let retries = (0..).take_while(|_| state.acquire_retry()).count();
+        assert_eq!(retries as u32, RETRY_QUOTA_CAPACITY / RETRY_COST);
+        assert_eq!(state.stats().available_tokens, 0);
+
+        state.release(false);
+        assert_eq!(state.stats().available_tokens, NO_RETRY_INCREMENT);
+        state.release(true);
+        state.release(true);
+        assert_eq!(
+            state.stats().available_tokens,
+            NO_RETRY_INCREMENT + 2 * RETRY_COST
+        );
+        assert!(state.acquire_retry());
+
+        let mut state = AdaptiveRetry::new();
+        state.release(true);
+        assert_eq!(state.stats().available_tokens, RETRY_QUOTA_CAPACITY);
+    }
+
+    #[test]
+    fn rate_limiter_is_disabled_until_throttled() {
+        let mut limiter = RateLimiter::new(0.0);
+        for i in 0..100 {
+            let now = f64::from(i) * 0.01;
+            assert_eq!(limiter.acquire(now), Duration::from_secs(0));
+            limiter.update(now, false);
+        }
+        assert!(!limiter.enabled);
+    }
+
+    #[test]
+    fn rate_limiter_follows_cubic_curve() {
+        let mut limiter = RateLimiter::new(0.0);
+        // Send 10 requests per second for 5 seconds, then get throttled.
+        for i in 0..50 {
+            limiter.update(f64::from(i) * 0.1, false);
+        }
+        limiter.update(5.0, true);
+        assert!(limiter.enabled);
+        let measured = limiter.last_max_rate;
+        assert!((measured - 10.0).abs() < 0.5, "{}", measured);
+        assert!((limiter.fill_rate - measured * BETA).abs() < 1e-9);
+
+        // The rate recovers to the throttled rate after the time window.
+        let window = (measured * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+        assert!((limiter.cubic_success(5.0) - measured * BETA).abs() < 1e-9);
+        assert!((limiter.cubic_success(5.0 + window) - measured).abs() < 1e-9);
+        assert!(limiter.cubic_success(5.0 + 2.0 * window) > measured);
+    }
+
+    #[test]
+    fn rate_limiter_delays_requests_beyond_fill_rate() {
+        let mut limiter = RateLimiter::new(0.0);
+        limiter.enabled = true;
+        limiter.fill_rate = 2.0;
+        limiter.max_capacity = 2.0;
+        limiter.current_capacity = 1.0;
+        limiter.last_timestamp = Some(0.0);
+
+        let waits: Vec<_> = (0..4).map(|_| limiter.acquire(0.0)).collect();
+        assert_eq!(
+            waits,
+            vec![
+                Duration::from_secs(0),
+                Duration::from_millis(500),
+                Duration::from_millis(1000),
+                Duration::from_millis(1500),
+            ]
+        );
+    }
+}
@@ -1,2 +1,3 @@
 // moved to rusoto_signature
 pub use rusoto_signature::signature::*;
+pub use rusoto_signature::presigned_post::*;
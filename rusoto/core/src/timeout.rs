@@ -0,0 +1,254 @@
+//! Timeouts of requests sent by an `HttpClient`.
+//!
+//! An `HttpClient` made with an `HttpConfig` bounds how long connecting, waiting for each chunk
+//! of a response body and a whole request may take:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use rusoto_core::{HttpClient, HttpConfig};
+//!
+//! let config = HttpConfig::default()
+//!     .connect_timeout(Duration::from_secs(3))
+//!     .read_timeout(Duration::from_secs(10))
+//!     .operation_timeout(Duration::from_secs(30));
+//! let http_client = HttpClient::new_with_config(config).unwrap();
+//! ```
+//!
+//! Requests that time out fail with `RusotoError::Timeout`, including when the timeout elapses
+//! while their response body is read. The operation timeout can be replaced for the requests
+//! of one call with `with_operation_timeout`.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::Stream;
+use tokio::time::{self, Delay, Instant};
+
+tokio::task_local! {
+    static OPERATION_TIMEOUT: Option<Duration>;
+}
+
+/// Which timeout of an `HttpConfig` elapsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutKind {
+    /// Connecting to the endpoint took too long.
+    Connect,
+    /// No data of the response body arrived for too long.
+    Read,
+    /// The request, including reading its response body, took too long.
+    Operation,
+}
+
+impl fmt::Display for TimeoutKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            TimeoutKind::Connect => "connect",
+            TimeoutKind::Read => "read",
+            TimeoutKind::Operation => "operation",
+        })
+    }
+}
+
+/// A request, or reading its response, took longer than one of its timeouts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeoutError {
+    kind: TimeoutKind,
+    duration: Duration,
+}
+
+impl TimeoutError {
+    /// Creates an error for the timeout `kind` of `duration` elapsing.
+    pub fn new(kind: TimeoutKind, duration: Duration) -> TimeoutError {
+        TimeoutError { kind, duration }
+    }
+
+    /// Which timeout elapsed.
+    pub fn kind(&self) -> TimeoutKind {
+        self.kind
+    }
+
+    /// The duration of the timeout that elapsed.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Extracts the timeout error an `io::Error` from a response body stream carries.
+    pub(crate) fn from_io(err: &io::Error) -> Option<TimeoutError> {
+        if err.kind() != io::ErrorKind::TimedOut {
+            return None;
+        }
+        err.get_ref()
+            .and_then(|inner| inner.downcast_ref::<TimeoutError>())
+            .cloned()
+    }
+
+    fn into_io(self) -> io::Error {
+        io::Error::new(io::ErrorKind::TimedOut, self)
+    }
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} timeout of {:?} elapsed", self.kind, self.duration)
+    }
+}
+
+impl Error for TimeoutError {}
+
+/// Runs `future`, replacing the operation timeout of the `HttpClient` for the requests it
+/// sends with `timeout`. `None` sends them without an operation timeout, e.g. for a
+/// `select_object_content` call streaming results for longer than other calls may take.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::timeout::with_operation_timeout;
+///
+/// # async fn example() {
+/// # let select_object_content = async {};
+/// // e.g. `s3.select_object_content(request)`
+/// let output = with_operation_timeout(None, select_object_content).await;
+/// # }
+/// ```
+pub async fn with_operation_timeout<F: Future>(timeout: Option<Duration>, future: F) -> F::Output {
+    OPERATION_TIMEOUT.scope(timeout, future).await
+}
+
+/// The operation timeout set with `with_operation_timeout` around the current task, if any.
+pub(crate) fn operation_timeout_override() -> Option<Option<Duration>> {
+    OPERATION_TIMEOUT.try_with(|timeout| *timeout).ok()
+}
+
+/// A response body that fails with a `TimeoutError` when no chunk arrives within the read
+/// timeout, or the operation deadline passes.
+pub(crate) struct TimedBody<S> {
+    body: S,
+    idle: Option<(Delay, Duration)>,
+    deadline: Option<(Delay, Duration)>,
+    timed_out: bool,
+}
+
+/// The duration of `timer`, if it elapsed.
+fn passed(timer: &mut Option<(Delay, Duration)>, cx: &mut Context<'_>) -> Option<Duration> {
+    let (delay, duration) = timer.as_mut()?;
+    match Pin::new(delay).poll(cx) {
+        Poll::Ready(()) => Some(*duration),
+        Poll::Pending => None,
+    }
+}
+
+impl<S> TimedBody<S> {
+    /// Wraps `body`, whose operation started at `started` and may take `operation_timeout`.
+    pub(crate) fn new(
+        body: S,
+        read_timeout: Option<Duration>,
+        operation_timeout: Option<Duration>,
+        started: Instant,
+    ) -> Self {
+        TimedBody {
+            body,
+            idle: read_timeout.map(|timeout| (time::delay_for(timeout), timeout)),
+            deadline: operation_timeout
+                .map(|timeout| (time::delay_until(started + timeout), timeout)),
+            timed_out: false,
+        }
+    }
+}
+
+impl<S> Stream for TimedBody<S>
+where
+    S: Stream<Item = Result<Bytes, io::Error>> + Unpin,
+{
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.timed_out {
+            return Poll::Ready(None);
+        }
+        if let Poll::Ready(chunk) = Pin::new(&mut this.body).poll_next(cx) {
+            if let Some((idle, timeout)) = this.idle.as_mut() {
+                idle.reset(Instant::now() + *timeout);
+            }
+            return Poll::Ready(chunk);
+        }
+        let elapsed = if let Some(timeout) = passed(&mut this.deadline, cx) {
+            TimeoutError::new(TimeoutKind::Operation, timeout)
+        } else if let Some(timeout) = passed(&mut this.idle, cx) {
+            TimeoutError::new(TimeoutKind::Read, timeout)
+        } else {
+            return Poll::Pending;
+        };
+        this.timed_out = true;
+        Poll::Ready(Some(Err(elapsed.into_io())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn chunk(data: &'static str) -> Result<Bytes, io::Error> {
+        Ok(Bytes::from_static(data.as_bytes()))
+    }
+
+    #[tokio::test]
+    async fn read_timeout_resets_with_every_chunk() {
+        let body =
+            futures::stream::iter(vec![chunk("a"), chunk("b")]).chain(futures::stream::pending());
+        let timeout = Duration::from_millis(20);
+        let mut timed = TimedBody::new(Box::pin(body), Some(timeout), None, Instant::now());
+
+        assert_eq!(timed.next().await.unwrap().unwrap(), "a");
+        assert_eq!(timed.next().await.unwrap().unwrap(), "b");
+        let err = timed.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            TimeoutError::from_io(&err),
+            Some(TimeoutError::new(TimeoutKind::Read, timeout))
+        );
+        assert!(timed.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn operation_deadline_covers_the_body() {
+        let body = futures::stream::iter(vec![chunk("a")]).chain(futures::stream::pending());
+        let timeout = Duration::from_millis(20);
+        let started = Instant::now() - Duration::from_millis(15);
+        let mut timed = TimedBody::new(
+            Box::pin(body),
+            Some(Duration::from_secs(10)),
+            Some(timeout),
+            started,
+        );
+
+        assert_eq!(timed.next().await.unwrap().unwrap(), "a");
+        let err = timed.next().await.unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "operation timeout of 20ms elapsed");
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn scopes_operation_timeout_overrides() {
+        assert_eq!(operation_timeout_override(), None);
+        let inside = with_operation_timeout(None, async { operation_timeout_override() }).await;
+        assert_eq!(inside, Some(None));
+        let timeout = Some(Duration::from_secs(1));
+        let inside = with_operation_timeout(timeout, async { operation_timeout_override() }).await;
+        assert_eq!(inside, Some(timeout));
+    }
+
+    #[test]
+    fn only_timed_out_io_errors_carry_timeouts() {
+        let err = TimeoutError::new(TimeoutKind::Connect, Duration::from_secs(1));
+        assert_eq!(TimeoutError::from_io(&err.clone().into_io()), Some(err));
+        let other = io::Error::new(io::ErrorKind::TimedOut, "timed out");
+        assert_eq!(TimeoutError::from_io(&other), None);
+    }
+}
@@ -0,0 +1,295 @@
+//! Polling an operation until a resource reaches a desired state.
+//!
+//! Service crates build their `wait_until_*` methods on `wait`, which sends a request, hands
+//! the response to a matcher deciding whether the wait succeeded, failed or should go on, and
+//! polls again after a delay until a `WaiterConfig`'s attempts or maximum wait run out.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use http::StatusCode;
+use tokio::time;
+
+use crate::retry;
+use crate::RusotoError;
+
+/// What a matcher made of one response of a waiter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WaiterState<T> {
+    /// The resource reached the desired state, the wait is over.
+    Success(T),
+    /// The resource isn't in the desired state yet, poll again.
+    Retry,
+    /// The resource is in a state it won't leave for the desired one, e.g. a stack that
+    /// rolled back. Carries a description of that state.
+    Failure(String),
+}
+
+/// How often, and for how long, a waiter polls.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rusoto_core::waiter::WaiterConfig;
+///
+/// let config = WaiterConfig::new(Duration::from_secs(5), 20)
+///     .with_max_wait(Duration::from_secs(60));
+/// assert_eq!(config.max_attempts(), 20);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaiterConfig {
+    delay: Duration,
+    max_attempts: u32,
+    max_wait: Option<Duration>,
+}
+
+impl WaiterConfig {
+    /// Creates a config polling up to `max_attempts` times, `delay` apart.
+    pub fn new(delay: Duration, max_attempts: u32) -> Self {
+        WaiterConfig {
+            delay,
+            max_attempts: max_attempts.max(1),
+            max_wait: None,
+        }
+    }
+
+    /// Sets the delay between polls.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the maximum number of polls, at least one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Gives up once waiting for the next poll would take longer than `max_wait` overall,
+    /// even if attempts are left.
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// The delay between polls.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// The maximum number of polls.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The maximum time to wait overall, if any.
+    pub fn max_wait(&self) -> Option<Duration> {
+        self.max_wait
+    }
+}
+
+/// Why a waiter didn't see the resource reach the desired state.
+#[derive(Debug, PartialEq)]
+pub enum WaiterError<E> {
+    /// The resource didn't reach the desired state within the attempts or the maximum wait.
+    Timeout {
+        /// The number of polls made.
+        attempts: u32,
+        /// The time spent waiting.
+        elapsed: Duration,
+    },
+    /// The resource reached a state it won't leave for the desired one.
+    Failure(String),
+    /// A poll failed with an error none of the waiter's matchers expect.
+    Request(RusotoError<E>),
+}
+
+impl<E: Error + 'static> fmt::Display for WaiterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WaiterError::Timeout { attempts, elapsed } => write!(
+                f,
+                "Resource didn't reach the desired state after {} attempts over {:?}",
+                attempts, elapsed
+            ),
+            WaiterError::Failure(ref state) => {
+                write!(f, "Resource reached a failure state: {}", state)
+            }
+            WaiterError::Request(ref err) => write!(f, "Waiter request failed: {}", err),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for WaiterError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            WaiterError::Request(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Polls with `poll` until `matcher` accepts a response as success or failure, or `config`
+/// runs out of attempts or time.
+///
+/// `matcher` gets every response, including errors, so that e.g. a 404 can mean "retry"
+/// while waiting for a resource to exist or "success" while waiting for it to be deleted.
+/// Errors it hands back are returned as `WaiterError::Request`.
+pub async fn wait<T, R, E, F, Fut, M>(
+    config: &WaiterConfig,
+    mut poll: F,
+    mut matcher: M,
+) -> Result<R, WaiterError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RusotoError<E>>>,
+    M: FnMut(Result<T, RusotoError<E>>) -> Result<WaiterState<R>, RusotoError<E>>,
+{
+    let started = Instant::now();
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match matcher(poll().await).map_err(WaiterError::Request)? {
+            WaiterState::Success(output) => return Ok(output),
+            WaiterState::Failure(state) => return Err(WaiterError::Failure(state)),
+            WaiterState::Retry => {}
+        }
+        let elapsed = started.elapsed();
+        let out_of_time =
+            matches!(config.max_wait, Some(max_wait) if elapsed + config.delay > max_wait);
+        if attempts >= config.max_attempts || out_of_time {
+            return Err(WaiterError::Timeout { attempts, elapsed });
+        }
+        time::delay_for(config.delay).await;
+    }
+}
+
+/// The HTTP status of an error response, for matchers.
+pub fn error_status<E>(err: &RusotoError<E>) -> Option<StatusCode> {
+    match *err {
+        RusotoError::Unknown(ref response) => Some(response.status),
+        _ => None,
+    }
+}
+
+/// The error code of an error response that wasn't parsed into a service error, e.g.
+/// `InvalidInstanceID.NotFound`, for matchers.
+pub fn error_code<E>(err: &RusotoError<E>) -> Option<String> {
+    match *err {
+        RusotoError::Unknown(ref response) => retry::error_code(response),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use crate::request::BufferedHttpResponse;
+    use std::cell::RefCell;
+
+    fn config(max_attempts: u32) -> WaiterConfig {
+        WaiterConfig::new(Duration::from_millis(1), max_attempts)
+    }
+
+    fn response(status: u16, body: &str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: body.as_bytes().to_vec().into(),
+            headers: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_until_success() {
+        let polled = RefCell::new(0);
+        let result = wait(
+            &config(5),
+            || {
+                *polled.borrow_mut() += 1;
+                let count = *polled.borrow();
+                async move { Ok::<_, RusotoError<()>>(count) }
+            },
+            |count| match count? {
+                3 => Ok(WaiterState::Success("done")),
+                _ => Ok(WaiterState::Retry),
+            },
+        )
+        .await;
+
+        assert_eq!(result, Ok("done"));
+        assert_eq!(*polled.borrow(), 3);
+    }
+
+    #[tokio::test]
+    async fn times_out_after_max_attempts() {
+        let result = wait(
+            &config(2),
+            || async { Ok::<_, RusotoError<()>>(()) },
+            |_| Ok(WaiterState::<()>::Retry),
+        )
+        .await;
+
+        match result {
+            Err(WaiterError::Timeout { attempts, .. }) => assert_eq!(attempts, 2),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn times_out_before_exceeding_max_wait() {
+        let config =
+            WaiterConfig::new(Duration::from_secs(60), 10).with_max_wait(Duration::from_secs(30));
+
+        let result = wait(
+            &config,
+            || async { Ok::<_, RusotoError<()>>(()) },
+            |_| Ok(WaiterState::<()>::Retry),
+        )
+        .await;
+
+        match result {
+            Err(WaiterError::Timeout { attempts, .. }) => assert_eq!(attempts, 1),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn distinguishes_failure_states_from_request_errors() {
+        let failed = wait(
+            &config(5),
+            || async { Ok::<_, RusotoError<()>>("ROLLBACK_COMPLETE") },
+            |state| Ok(WaiterState::<()>::Failure(state?.to_owned())),
+        )
+        .await;
+        assert_eq!(
+            failed,
+            Err(WaiterError::Failure("ROLLBACK_COMPLETE".to_owned()))
+        );
+
+        let errored = wait(
+            &config(5),
+            || async { Err::<(), _>(RusotoError::Service(())) },
+            |result| result.map(|_| WaiterState::<()>::Retry),
+        )
+        .await;
+        assert_eq!(errored, Err(WaiterError::Request(RusotoError::Service(()))));
+    }
+
+    #[test]
+    fn extracts_error_codes_and_statuses() {
+        let err = RusotoError::<()>::Unknown(response(
+            400,
+            "<Response><Errors><Error><Code>InvalidInstanceID.NotFound</Code></Error></Errors></Response>",
+        ));
+        assert_eq!(
+            error_code(&err).as_deref(),
+            Some("InvalidInstanceID.NotFound")
+        );
+        assert_eq!(error_status(&err), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(error_code(&RusotoError::Service(())), None);
+    }
+}
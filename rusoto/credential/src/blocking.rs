@@ -0,0 +1,69 @@
+//! Getting credentials synchronously, for programs that don't run an async runtime.
+//!
+//! Requires the `blocking` feature.
+
+use tokio::runtime::{Builder, Handle};
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Gets credentials from `provider`, driving it to completion on a new single-threaded
+/// runtime that is shut down afterwards.
+///
+/// Fails instead of blocking when called from within a tokio runtime, where it would stall
+/// the runtime's other tasks; await `provider.credentials()` there instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_credential::{blocking, StaticProvider};
+///
+/// let provider = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+/// let credentials = blocking::credentials(&provider).unwrap();
+/// assert_eq!(credentials.aws_access_key_id(), "key");
+/// ```
+pub fn credentials<P>(provider: &P) -> Result<AwsCredentials, CredentialsError>
+where
+    P: ProvideAwsCredentials + ?Sized,
+{
+    if Handle::try_current().is_ok() {
+        return Err(CredentialsError::new(
+            "blocking::credentials can't be called from within a tokio runtime, \
+             await the provider's credentials() instead",
+        ));
+    }
+    let mut runtime = Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()
+        .map_err(|err| {
+            CredentialsError::new(format!(
+                "Couldn't start a runtime to get credentials: {}",
+                err
+            ))
+        })?;
+    runtime.block_on(provider.credentials())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaticProvider;
+
+    #[test]
+    fn gets_credentials_without_a_runtime() {
+        let provider = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+
+        let credentials = credentials(&provider).unwrap();
+
+        assert_eq!(credentials.aws_secret_access_key(), "secret");
+    }
+
+    #[tokio::test]
+    async fn fails_within_a_runtime() {
+        let provider = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+
+        let err = credentials(&provider).unwrap_err();
+
+        assert!(err.message.contains("from within a tokio runtime"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Provides credentials obtained by application code, e.g. from a vault or a broker API.
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt};
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+type Callback =
+    dyn Fn() -> BoxFuture<'static, Result<AwsCredentials, CredentialsError>> + Send + Sync;
+
+/// Provides AWS credentials returned by a callback, called on every fetch.
+///
+/// Wrap it in an `AutoRefreshingProvider` to call the callback only when the credentials it
+/// returned last expire, according to their `expires_at`. Clones share the callback.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use rusoto_credential::{AutoRefreshingProvider, AwsCredentials, CallbackProvider};
+///
+/// let provider = AutoRefreshingProvider::new(CallbackProvider::new(|| async {
+///     // e.g. ask a broker for credentials valid for 15 minutes
+///     let expires_at = Utc::now() + Duration::minutes(15);
+///     Ok(AwsCredentials::new("key", "secret", None, Some(expires_at)))
+/// }))
+/// .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct CallbackProvider {
+    callback: Arc<Callback>,
+}
+
+impl CallbackProvider {
+    /// Creates a provider returning the credentials of the futures `callback` returns.
+    pub fn new<F, Fut>(callback: F) -> CallbackProvider
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AwsCredentials, CredentialsError>> + Send + 'static,
+    {
+        CallbackProvider {
+            callback: Arc::new(move || callback().boxed()),
+        }
+    }
+}
+
+impl fmt::Debug for CallbackProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackProvider").finish()
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for CallbackProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        (self.callback)().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::*;
+    use crate::{AutoRefreshingProvider, Clock};
+
+    #[derive(Debug, Clone)]
+    struct FakeClock(Arc<Mutex<DateTime<Utc>>>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn expiry_of_returned_credentials_drives_refreshes() {
+        let start = Utc::now();
+        let clock = FakeClock(Arc::new(Mutex::new(start)));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CallbackProvider::new({
+            let calls = calls.clone();
+            move || {
+                let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                let expires_at = start + Duration::minutes(15) * call as i32;
+                async move {
+                    Ok(AwsCredentials::new(
+                        format!("key-{}", call),
+                        "secret",
+                        None,
+                        Some(expires_at),
+                    ))
+                }
+            }
+        });
+        let provider = AutoRefreshingProvider::new(provider.clone())
+            .unwrap()
+            .with_clock(clock.clone());
+
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key-1");
+        *clock.0.lock().unwrap() = start + Duration::minutes(10);
+        provider.credentials().await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        *clock.0.lock().unwrap() = start + Duration::minutes(15);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key-2");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn callback_errors_are_returned() {
+        let provider =
+            CallbackProvider::new(|| async { Err(CredentialsError::new("vault is sealed")) });
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(err.message, "vault is sealed");
+    }
+
+    #[test]
+    fn is_send_sync_and_clone() {
+        fn is_send_sync<T: Send + Sync + Clone>() {}
+        is_send_sync::<CallbackProvider>();
+    }
+}
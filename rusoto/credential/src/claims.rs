@@ -27,3 +27,22 @@ pub const AUDIENCE: &str = "aud";
 /// that was passed in the `AssumeRoleWithWebIdentity` request.
 /// For SAML this is the value of the Issuer element of the SAML assertion.
 pub const ISSUER: &str = "iss";
+
+/// Key used in the claims map for the ARN of the assumed role session.
+///
+/// Returned by `AssumeRole` as the `Arn` of the `AssumedRoleUser`, e.g.
+/// `arn:aws:sts::123456789012:assumed-role/demo/session`. This is the principal that requests
+/// signed with the credentials are made as.
+pub const ASSUMED_ROLE_ARN: &str = "assumed_role_arn";
+
+/// Key used in the claims map for the ID of the assumed role session.
+///
+/// Returned by `AssumeRole` as the `AssumedRoleId` of the `AssumedRoleUser`, made of the role
+/// ID and the role session name, e.g. `AROA3XFRBF535PLBIFPI4:session`.
+pub const ASSUMED_ROLE_ID: &str = "assumed_role_id";
+
+/// Key used in the claims map for the source identity of the assumed role session.
+///
+/// Only present if a source identity was set when assuming the role, or carried over from
+/// the session the role was assumed with.
+pub const SOURCE_IDENTITY: &str = "source_identity";
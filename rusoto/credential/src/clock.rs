@@ -0,0 +1,21 @@
+//! Provides the current time to expiry checks, so that tests can control it.
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time, used by `AutoRefreshingProvider` to decide whether cached
+/// credentials need a refresh.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, which `AutoRefreshingProvider` uses by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -1,8 +1,11 @@
 //! The Credentials provider to read from a task's IAM Role.
 
+use std::fs;
+use std::net::{IpAddr, Ipv6Addr};
 use std::time::Duration;
 
 use async_trait::async_trait;
+use hyper::header::AUTHORIZATION;
 use hyper::{Body, Request};
 
 use crate::request::HttpClient;
@@ -19,6 +22,9 @@ const AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: &str = "AWS_CONTAINER_CREDENTIALS_
 // environment variables, but they are used by the Java, Go, JavaScript and the Python SDKs.
 const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
 const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN";
+const AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE";
+// Address of the EKS Pod Identity agent when the pod uses IPv6.
+const AWS_EKS_POD_IDENTITY_IPV6: Ipv6Addr = Ipv6Addr::new(0xfd00, 0x0ec2, 0, 0, 0, 0, 0, 0x23);
 
 /// Provides AWS credentials from a task's IAM role.
 ///
@@ -31,8 +37,13 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 /// URL stored in the environment variable ```AWS_CONTAINER_CREDENTIALS_RELATIVE_URI```, which will
 /// be used to obtain the AWS credentials. If that environment variable is not set, rusoto will use
 /// the URL set in environment variable ```AWS_CONTAINER_CREDENTIALS_FULL_URI``` to obtain AWS
-/// credentials and will (optionally) also set the ```Authorization``` header to the value of
-/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// credentials and will (optionally) also set the ```Authorization``` header to the contents of
+/// the file named by environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE``` or, if
+/// that is not set, to the value of environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// The token file is read again on every request, so it can be rotated while the process runs.
+///
+/// A full URI must use `https`, or `http` with a loopback or link-local host (such as the
+/// `169.254.170.23` endpoint of the EKS Pod Identity agent). Any other URI is rejected.
 ///
 /// # Example
 ///
@@ -76,21 +87,15 @@ impl Default for ContainerProvider {
 #[async_trait]
 impl ProvideAwsCredentials for ContainerProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let req = request_from_env_vars().map_err(|err| CredentialsError {
-            message: format!(
-                "Could not get request from environment: {}",
-                err.to_string()
-            ),
+        let req = request_from_env_vars().map_err(|err| {
+            CredentialsError::new(format!("Could not get request from environment: {}", err))
         })?;
         let resp = self
             .client
             .request(req, self.timeout)
             .await
-            .map_err(|err| CredentialsError {
-                message: format!(
-                    "Could not get credentials from container: {}",
-                    err.to_string()
-                ),
+            .map_err(|err| {
+                CredentialsError::new(format!("Could not get credentials from container: {}", err))
             })?;
         parse_credentials_from_aws_service(&resp)
     }
@@ -104,10 +109,11 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
         None => match non_empty_env_var(AWS_CONTAINER_CREDENTIALS_FULL_URI) {
             Some(ref uri) => {
                 let mut request = new_request(uri, AWS_CONTAINER_CREDENTIALS_FULL_URI)?;
-                if let Some(token) = non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN) {
+                validate_full_uri(&request)?;
+                if let Some(token) = authorization_token()? {
                     match token.parse() {
                         Ok(parsed_token) => {
-                            request.headers_mut().insert("authorization", parsed_token);
+                            request.headers_mut().insert(AUTHORIZATION, parsed_token);
                         }
                         Err(err) => {
                             return Err(CredentialsError::new(format!(
@@ -127,6 +133,50 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
     }
 }
 
+fn authorization_token() -> Result<Option<String>, CredentialsError> {
+    match non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE) {
+        Some(path) => {
+            let token = fs::read_to_string(&path).map_err(|err| {
+                CredentialsError::new(format!(
+                    "Could not read authorization token file '{}' from environment variable '{}': {}",
+                    path, AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, err
+                ))
+            })?;
+            Ok(Some(token.trim().to_owned()).filter(|token| !token.is_empty()))
+        }
+        None => Ok(non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN)),
+    }
+}
+
+fn validate_full_uri(request: &Request<Body>) -> Result<(), CredentialsError> {
+    let uri = request.uri();
+    let allowed = match uri.scheme_str() {
+        Some("https") => true,
+        Some("http") => matches!(uri.host(), Some(host) if is_allowed_http_host(host)),
+        _ => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(CredentialsError::new(format!(
+            "URI '{}' from environment variable '{}' must use https, or http with a loopback or link-local host",
+            uri, AWS_CONTAINER_CREDENTIALS_FULL_URI
+        )))
+    }
+}
+
+fn is_allowed_http_host(host: &str) -> bool {
+    if host.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => ip.is_loopback() || ip.is_link_local(),
+        Ok(IpAddr::V6(ip)) => ip.is_loopback() || ip == AWS_EKS_POD_IDENTITY_IPV6,
+        Err(_) => false,
+    }
+}
+
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
     Request::get(uri).body(Body::empty()).map_err(|error| {
         CredentialsError::new(format!(
@@ -141,6 +191,8 @@ mod tests {
     use super::*;
     use crate::test_utils::lock_env;
     use std::env;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
 
     #[test]
     fn request_from_relative_uri() {
@@ -227,4 +279,77 @@ mod tests {
         assert_eq!(request.uri().to_string(), url);
         assert_eq!(request.headers().contains_key("authorization"), false);
     }
+
+    #[test]
+    fn request_from_full_uri_with_token_file() {
+        let url = "http://169.254.170.23/v1/credentials";
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "  file-token ").unwrap();
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "env-token");
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, file.path());
+        let first = request_from_env_vars();
+        std::fs::write(file.path(), "rotated-token\n").unwrap();
+        let second = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        assert_eq!(first.unwrap().headers()[AUTHORIZATION], "file-token");
+        assert_eq!(second.unwrap().headers()[AUTHORIZATION], "rotated-token");
+    }
+
+    #[test]
+    fn error_from_missing_token_file() {
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "http://localhost/xxx");
+        env::set_var(
+            AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE,
+            "/nonexistent/rusoto/token",
+        );
+        let result = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        let err = result.unwrap_err();
+        assert!(err.message.contains("/nonexistent/rusoto/token"), "{}", err);
+    }
+
+    #[test]
+    fn full_uri_host_validation() {
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        let accepted = [
+            "http://localhost/xxx",
+            "http://127.0.0.1:8080/xxx",
+            "http://169.254.170.2/xxx",
+            "http://169.254.170.23/v1/credentials",
+            "http://[::1]/xxx",
+            "http://[fd00:ec2::23]/v1/credentials",
+            "https://credentials.example.com/xxx",
+        ];
+        let rejected = [
+            "http://credentials.example.com/xxx",
+            "http://10.0.0.1/xxx",
+            "http://[fd00::1]/xxx",
+            "ftp://localhost/xxx",
+        ];
+        let mut results = Vec::new();
+        for url in accepted.iter().chain(rejected.iter()) {
+            env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
+            results.push((url, request_from_env_vars()));
+        }
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        for (url, result) in results {
+            if accepted.contains(url) {
+                assert!(result.is_ok(), "{} should be accepted", url);
+            } else {
+                let err = result.unwrap_err();
+                assert!(err.message.contains(url), "{}", err);
+            }
+        }
+    }
 }
@@ -25,6 +25,9 @@ use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCrede
 ///   Expiration time in RFC 3339 format (e.g. `1996-12-19T16:39:57-08:00`). If unset, credentials
 ///   won't expire.
 ///
+/// The environment is read again on every call to `credentials`, so when an expiration is set an
+/// `AutoRefreshingProvider` will pick up credentials that were rotated in the environment.
+///
 /// # Example
 ///
 /// ```rust
@@ -66,6 +69,9 @@ impl Default for EnvironmentProvider {
 impl EnvironmentProvider {
     /// Create an EnvironmentProvider with a non-standard variable prefix.
     ///
+    /// The prefix replaces `AWS` in all four variables, including the session token and the
+    /// credential expiration.
+    ///
     /// ```rust
     /// use std::future::Future;
     /// use rusoto_credential::{EnvironmentProvider, ProvideAwsCredentials};
@@ -264,7 +270,7 @@ mod tests {
         env::remove_var(AWS_CREDENTIAL_EXPIRATION);
         assert!(result.is_err());
         assert!(match &result.err() {
-            &Some(CredentialsError { ref message }) => message.starts_with(E_INVALID_EXPIRATION),
+            &Some(CredentialsError { ref message, .. }) => message.starts_with(E_INVALID_EXPIRATION),
             _ => false,
         });
     }
@@ -337,4 +343,74 @@ mod tests {
         assert_eq!(creds.token(), &Some("token".to_string()));
         assert_eq!(creds.expires_at(), &Some(now));
     }
+
+    #[tokio::test]
+    async fn empty_expiration_does_not_expire() {
+        let _guard = lock_env();
+        env::set_var(AWS_ACCESS_KEY_ID, "id");
+        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
+        env::set_var(AWS_CREDENTIAL_EXPIRATION, "");
+        let result = EnvironmentProvider::default().credentials().await;
+        env::remove_var(AWS_ACCESS_KEY_ID);
+        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
+        assert_eq!(result.unwrap().expires_at(), &None);
+    }
+
+    #[tokio::test]
+    async fn alternative_prefix_bad_expiration() {
+        let _guard = lock_env();
+        env::set_var("MYAPP_ACCESS_KEY_ID", "id");
+        env::set_var("MYAPP_SECRET_ACCESS_KEY", "secret");
+        env::set_var("MYAPP_CREDENTIAL_EXPIRATION", "2018-04-21 01:13");
+        // The standard variable must not be consulted for a prefixed provider.
+        env::set_var(AWS_CREDENTIAL_EXPIRATION, "2018-04-21T01:13:02Z");
+        let result = EnvironmentProvider::with_prefix("MYAPP")
+            .credentials()
+            .await;
+        env::remove_var("MYAPP_ACCESS_KEY_ID");
+        env::remove_var("MYAPP_SECRET_ACCESS_KEY");
+        env::remove_var("MYAPP_CREDENTIAL_EXPIRATION");
+        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
+        let err = result.unwrap_err();
+        assert!(
+            err.message
+                .starts_with("Invalid MYAPP_CREDENTIAL_EXPIRATION in environment"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn environment_is_read_on_every_call() {
+        let _guard = lock_env();
+        let provider = EnvironmentProvider::default();
+        env::set_var(AWS_ACCESS_KEY_ID, "id");
+        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
+        env::set_var(AWS_SESSION_TOKEN, "token");
+        env::set_var(AWS_CREDENTIAL_EXPIRATION, "2018-04-21T01:13:02Z");
+        let first = provider.credentials().await;
+        env::set_var(AWS_ACCESS_KEY_ID, "rotated-id");
+        env::set_var(AWS_SESSION_TOKEN, "rotated-token");
+        env::set_var(AWS_CREDENTIAL_EXPIRATION, "2018-04-21T02:13:02Z");
+        let second = provider.credentials().await;
+        env::remove_var(AWS_ACCESS_KEY_ID);
+        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        env::remove_var(AWS_SESSION_TOKEN);
+        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
+
+        let first = first.unwrap();
+        assert_eq!(first.aws_access_key_id(), "id");
+        assert_eq!(
+            first.expires_at().unwrap().to_rfc3339(),
+            "2018-04-21T01:13:02+00:00"
+        );
+        let second = second.unwrap();
+        assert_eq!(second.aws_access_key_id(), "rotated-id");
+        assert_eq!(second.token(), &Some("rotated-token".to_string()));
+        assert_eq!(
+            second.expires_at().unwrap().to_rfc3339(),
+            "2018-04-21T02:13:02+00:00"
+        );
+    }
 }
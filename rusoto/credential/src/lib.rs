@@ -7,18 +7,26 @@
 
 //! Types for loading and managing AWS access credentials for API requests.
 
+pub use crate::callback::CallbackProvider;
+pub use crate::clock::{Clock, SystemClock};
 pub use crate::container::ContainerProvider;
 pub use crate::environment::EnvironmentProvider;
 pub use crate::instance_metadata::InstanceMetadataProvider;
+pub use crate::or_else::OrElseProvider;
 pub use crate::profile::ProfileProvider;
 pub use crate::secrets::Secret;
 pub use crate::static_provider::StaticProvider;
 pub use crate::variable::Variable;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod callback;
 pub mod claims;
+mod clock;
 mod container;
 mod environment;
 mod instance_metadata;
+mod or_else;
 mod profile;
 mod request;
 mod secrets;
@@ -28,13 +36,16 @@ pub(crate) mod test_utils;
 mod variable;
 
 use async_trait::async_trait;
+use std::collections::hash_map::RandomState;
 use std::collections::BTreeMap;
+use std::convert::TryFrom;
 use std::env::{var as env_var, VarError};
 use std::error::Error;
 use std::fmt;
+use std::hash::{BuildHasher, Hasher};
 use std::io::Error as IoError;
 use std::string::FromUtf8Error;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use chrono::{DateTime, Duration as ChronoDuration, ParseError, Utc};
@@ -118,15 +129,20 @@ impl AwsCredentials {
         &self.token
     }
 
-    /// Determine whether or not the credentials are expired.
-    fn credentials_are_expired(&self) -> bool {
+    /// Determine whether the credentials expire within `buffer` from now and should be
+    /// refreshed. Credentials that have already expired always need a refresh, and credentials
+    /// without an expiry time never do.
+    pub fn needs_refresh(&self, buffer: Duration) -> bool {
+        self.needs_refresh_at(Utc::now(), buffer)
+    }
+
+    fn needs_refresh_at(&self, now: DateTime<Utc>, buffer: Duration) -> bool {
         match self.expires_at {
-            Some(ref e) =>
-            // This is a rough hack to hopefully avoid someone requesting creds then sitting on them
-            // before issuing the request:
-            {
-                *e < Utc::now() + ChronoDuration::seconds(20)
-            }
+            Some(ref e) => match ChronoDuration::from_std(buffer) {
+                Ok(buffer) => *e <= now + buffer,
+                // A buffer too large for chrono covers any expiry time.
+                Err(_) => true,
+            },
             None => false,
         }
     }
@@ -157,11 +173,13 @@ impl fmt::Debug for AwsCredentials {
 /// Represents an Error that has occured during the fetching Credentials Phase.
 ///
 /// This generally is an error message from one of our underlying libraries, however
-/// we wrap it up with this type so we can export one single error type.
-#[derive(Clone, Debug, PartialEq)]
+/// we wrap it up with this type so we can export one single error type. The underlying
+/// error, if kept with `with_source`, is returned by `Error::source`.
+#[derive(Clone, Debug)]
 pub struct CredentialsError {
     /// The underlying error message for the credentials error.
     pub message: String,
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 
 impl CredentialsError {
@@ -174,8 +192,26 @@ impl CredentialsError {
     {
         CredentialsError {
             message: message.to_string(),
+            source: None,
         }
     }
+
+    /// Keeps `source` as the error this one was caused by, e.g. to tell apart the reasons a
+    /// provider failed for.
+    pub fn with_source<E>(mut self, source: E) -> CredentialsError
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.source = Some(Arc::new(source));
+        self
+    }
+}
+
+/// Errors are equal if their messages are, whatever their sources.
+impl PartialEq for CredentialsError {
+    fn eq(&self, other: &CredentialsError) -> bool {
+        self.message == other.message
+    }
 }
 
 impl fmt::Display for CredentialsError {
@@ -184,7 +220,13 @@ impl fmt::Display for CredentialsError {
     }
 }
 
-impl Error for CredentialsError {}
+impl Error for CredentialsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
 
 impl From<ParseError> for CredentialsError {
     fn from(err: ParseError) -> CredentialsError {
@@ -229,23 +271,65 @@ pub trait ProvideAwsCredentials {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError>;
 }
 
+/// Shares a provider, e.g. an `AutoRefreshingProvider` and its cache, between several clients.
+#[async_trait]
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for Arc<P> {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        P::credentials(self).await
+    }
+}
+
+/// Allows a provider picked at runtime, as a `Box<dyn ProvideAwsCredentials + Send + Sync>`.
+#[async_trait]
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for Box<P> {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        P::credentials(self).await
+    }
+}
+
 #[async_trait]
-impl<P: ProvideAwsCredentials + Send + Sync> ProvideAwsCredentials for Arc<P> {
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for &P {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         P::credentials(self).await
     }
 }
 
+/// The default time before their expiry at which `AutoRefreshingProvider` refreshes
+/// credentials, so that they aren't used after expiring while a request is in flight.
+pub const DEFAULT_REFRESH_BUFFER: Duration = Duration::from_secs(20);
+
+/// Credentials cached by `AutoRefreshingProvider`, with the refresh jitter picked for them.
+type CachedCredentials = Result<(AwsCredentials, Duration), CredentialsError>;
+
+/// The callback `AutoRefreshingProvider` calls with fetched credentials expiring within the
+/// refresh buffer.
+#[derive(Clone)]
+struct ExpiryWarning(Arc<dyn Fn(&AwsCredentials) + Send + Sync>);
+
+impl fmt::Debug for ExpiryWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ExpiryWarning")
+    }
+}
+
 /// Wrapper for `ProvideAwsCredentials` that caches the credentials returned by the
 /// wrapped provider.  Each time the credentials are accessed, they are checked to see if
-/// they have expired, in which case they are retrieved from the wrapped provider again.
+/// they expire within the refresh buffer, in which case they are retrieved from the wrapped
+/// provider again.
 ///
 /// In order to access the wrapped provider, for instance to set a timeout, the `get_ref`
 /// and `get_mut` methods can be used.
 #[derive(Debug, Clone)]
 pub struct AutoRefreshingProvider<P: ProvideAwsCredentials + 'static> {
     credentials_provider: P,
-    current_credentials: Arc<Mutex<Option<Result<AwsCredentials, CredentialsError>>>>,
+    current_credentials: Arc<Mutex<Option<CachedCredentials>>>,
+    last_credentials: Arc<RwLock<Option<AwsCredentials>>>,
+    refresh_buffer: Duration,
+    refresh_jitter: Duration,
+    /// The state of the random number generator picking the jitter.
+    jitter_state: Arc<std::sync::Mutex<u64>>,
+    clock: Arc<dyn Clock>,
+    on_expiry_warning: Option<ExpiryWarning>,
 }
 
 impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
@@ -254,9 +338,84 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
         Ok(AutoRefreshingProvider {
             credentials_provider: provider,
             current_credentials: Arc::new(Mutex::new(None)),
+            last_credentials: Arc::new(RwLock::new(None)),
+            refresh_buffer: DEFAULT_REFRESH_BUFFER,
+            refresh_jitter: Duration::from_secs(0),
+            // Every `RandomState` is seeded differently, so providers pick different jitters.
+            jitter_state: Arc::new(std::sync::Mutex::new(
+                RandomState::new().build_hasher().finish(),
+            )),
+            clock: Arc::new(SystemClock),
+            on_expiry_warning: None,
         })
     }
 
+    /// Set how long before their expiry cached credentials are refreshed. Defaults to
+    /// `DEFAULT_REFRESH_BUFFER`.
+    pub fn with_refresh_buffer(mut self, buffer: Duration) -> Self {
+        self.refresh_buffer = buffer;
+        self
+    }
+
+    /// Refresh cached credentials up to `jitter` before the refresh buffer, by an amount
+    /// picked at random for each credentials fetched. This spreads out the refreshes of many
+    /// processes that fetched credentials with the same expiry at the same time. Defaults to
+    /// no jitter.
+    pub fn with_refresh_jitter(mut self, jitter: Duration) -> Self {
+        self.refresh_jitter = jitter;
+        self
+    }
+
+    /// Seed the random choice of the refresh jitter, e.g. to make it repeatable in tests.
+    /// Defaults to a different seed for every provider.
+    pub fn with_refresh_jitter_seed(mut self, seed: u64) -> Self {
+        self.jitter_state = Arc::new(std::sync::Mutex::new(seed));
+        self
+    }
+
+    /// Picks the jitter of freshly fetched credentials, from zero up to `refresh_jitter`.
+    fn next_jitter(&self) -> Duration {
+        let max = u64::try_from(self.refresh_jitter.as_nanos()).unwrap_or(u64::MAX);
+        if max == 0 {
+            return Duration::from_secs(0);
+        }
+        let mut state = match self.jitter_state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        Duration::from_nanos(splitmix64(&mut state) % max.saturating_add(1))
+    }
+
+    /// Set the clock whose time cached credentials are checked against. Defaults to
+    /// `SystemClock`.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set a callback called with freshly fetched credentials that already expire within the
+    /// refresh buffer, e.g. because STS issued them for a shorter duration than requested.
+    /// It's called once per fetch, on the task fetching the credentials, and should return
+    /// quickly.
+    pub fn with_expiry_warning<F>(mut self, on_expiry_warning: F) -> Self
+    where
+        F: Fn(&AwsCredentials) + Send + Sync + 'static,
+    {
+        self.on_expiry_warning = Some(ExpiryWarning(Arc::new(on_expiry_warning)));
+        self
+    }
+
+    /// Get the credentials most recently fetched from the wrapped provider, if any.
+    ///
+    /// This never refreshes the credentials or waits for a refresh in progress, so the
+    /// returned credentials may have expired. Check `expires_at` if that matters.
+    pub fn current(&self) -> Option<AwsCredentials> {
+        match self.last_credentials.read() {
+            Ok(last) => last.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
     /// Get a shared reference to the wrapped provider.
     pub fn get_ref(&self) -> &P {
         &self.credentials_provider
@@ -271,25 +430,60 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
     }
 }
 
-#[async_trait]
-impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
-    for AutoRefreshingProvider<P>
-{
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+impl<P: ProvideAwsCredentials + Send + Sync + 'static> AutoRefreshingProvider<P> {
+    /// Fetch credentials from the wrapped provider if the cached ones expire within the
+    /// refresh buffer, or if there are none yet.
+    ///
+    /// Returns whether credentials were fetched, e.g. for a background task refreshing them
+    /// ahead of requests to log the refreshes. Concurrent calls, and calls to `credentials`,
+    /// wait for a fetch in progress rather than fetching again. A failed fetch is cached and
+    /// returned like from `credentials`.
+    pub async fn refresh_if_needed(&self) -> Result<bool, CredentialsError> {
+        self.cached_or_fetched().await.map(|(_, fetched)| fetched)
+    }
+
+    /// Fetch credentials from the wrapped provider even if the cached ones are still fresh,
+    /// e.g. because a service rejected them as expired, and cache them instead.
+    ///
+    /// A cached failure is discarded too. Calls to `credentials` made meanwhile wait for the
+    /// fetch.
+    pub async fn refresh(&self) -> Result<AwsCredentials, CredentialsError> {
+        self.current_credentials.lock().await.take();
+        self.cached_or_fetched().await.map(|(creds, _)| creds)
+    }
+
+    /// Returns the cached credentials if they're still fresh, or fetches new ones, along with
+    /// whether they were fetched.
+    async fn cached_or_fetched(&self) -> Result<(AwsCredentials, bool), CredentialsError> {
         loop {
             let mut guard = self.current_credentials.lock().await;
             match guard.as_ref() {
                 // no result from the future yet, let's keep using it
                 None => {
                     let res = self.credentials_provider.credentials().await;
-                    *guard = Some(res);
+                    if let Ok(ref creds) = res {
+                        match self.last_credentials.write() {
+                            Ok(mut last) => *last = Some(creds.clone()),
+                            Err(poisoned) => *poisoned.into_inner() = Some(creds.clone()),
+                        }
+                        if let Some(ExpiryWarning(ref on_expiry_warning)) = self.on_expiry_warning {
+                            if creds.needs_refresh_at(self.clock.now(), self.refresh_buffer) {
+                                on_expiry_warning(creds);
+                            }
+                        }
+                    }
+                    // Fresh credentials are returned even if they expire within the refresh
+                    // buffer, so a buffer longer than their lifetime can't refresh forever.
+                    *guard = Some(res.clone().map(|creds| (creds, self.next_jitter())));
+                    return res.map(|creds| (creds, true));
                 }
                 Some(Err(e)) => return Err(e.clone()),
-                Some(Ok(creds)) => {
-                    if creds.credentials_are_expired() {
+                Some(Ok((creds, jitter))) => {
+                    let buffer = self.refresh_buffer.saturating_add(*jitter);
+                    if creds.needs_refresh_at(self.clock.now(), buffer) {
                         *guard = None;
                     } else {
-                        return Ok(creds.clone());
+                        return Ok((creds.clone(), false));
                     };
                 }
             }
@@ -297,6 +491,24 @@ impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
     }
 }
 
+#[async_trait]
+impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
+    for AutoRefreshingProvider<P>
+{
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        self.cached_or_fetched().await.map(|(creds, _)| creds)
+    }
+}
+
+/// Advances `state` and returns the next number of the SplitMix64 generator.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
 /// Wraps a `ChainProvider` in an `AutoRefreshingProvider`.
 ///
 /// The underlying `ChainProvider` checks multiple sources for credentials, and the `AutoRefreshingProvider`
@@ -336,12 +548,20 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
 /// 1. Environment variables: `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`
 /// 2. `credential_process` command in the AWS config file, usually located at `~/.aws/config`.
 /// 3. AWS credentials file. Usually located at `~/.aws/credentials`.
-/// 4. IAM instance profile. Will only work if running on an EC2 instance with an instance profile/role.
+/// 4. Container credentials: `AWS_CONTAINER_CREDENTIALS_RELATIVE_URI` (ECS) or
+///    `AWS_CONTAINER_CREDENTIALS_FULL_URI` (e.g. EKS Pod Identity), see `ContainerProvider`.
+/// 5. IAM instance profile. Will only work if running on an EC2 instance with an instance profile/role.
 ///
 /// If the sources are exhausted without finding credentials, an error is returned.
 ///
-/// The provider has a default timeout of 30 seconds. While it should work well for most setups,
-/// you can change the timeout using the `set_timeout` method.
+/// The container provider has a default timeout of 30 seconds and the instance metadata provider
+/// a default timeout of 1 second per request. While they should work well for most setups, you
+/// can change the timeout of both using the `set_timeout` method.
+///
+/// Each source in turn gets up to 30 seconds to provide credentials, after which the chain
+/// moves on to the next one. `with_provider_timeout` shortens this, e.g. so that a CLI fails
+/// fast where packets to the instance metadata service are dropped. The error returned when no
+/// source provided credentials says why each of them failed.
 ///
 /// # Example
 ///
@@ -353,6 +573,8 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
 /// let mut provider = ChainProvider::new();
 /// // you can overwrite the default timeout like this:
 /// provider.set_timeout(Duration::from_secs(60));
+/// // and give up on each source after 2 seconds:
+/// let provider = provider.with_provider_timeout(Duration::from_secs(2));
 /// ```
 ///
 /// # Warning
@@ -370,36 +592,88 @@ pub struct ChainProvider {
     instance_metadata_provider: InstanceMetadataProvider,
     container_provider: ContainerProvider,
     profile_provider: Option<ProfileProvider>,
+    provider_timeout: Duration,
 }
 
+/// How long `ChainProvider` waits for each of its sources by default.
+const DEFAULT_PROVIDER_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl ChainProvider {
     /// Set the timeout on the provider to the specified duration.
     pub fn set_timeout(&mut self, duration: Duration) {
         self.instance_metadata_provider.set_timeout(duration);
         self.container_provider.set_timeout(duration);
     }
+
+    /// Returns the provider giving up on each of its sources, the environment, the profile,
+    /// the container and the instance metadata, after `timeout`, and trying the next one.
+    /// Defaults to 30 seconds.
+    pub fn with_provider_timeout(mut self, timeout: Duration) -> Self {
+        self.provider_timeout = timeout;
+        self
+    }
+}
+
+/// Gets credentials from the `source` of a `ChainProvider`, adding why it failed to
+/// `failures` if it did.
+async fn chain_source_credentials<P: ProvideAwsCredentials>(
+    source: &str,
+    provider: &P,
+    timeout: Duration,
+    failures: &mut Vec<String>,
+) -> Option<AwsCredentials> {
+    match tokio::time::timeout(timeout, provider.credentials()).await {
+        Ok(Ok(creds)) => Some(creds),
+        Ok(Err(err)) => {
+            failures.push(format!("{}: {}", source, err));
+            None
+        }
+        Err(_) => {
+            failures.push(format!("{}: timed out after {:?}", source, timeout));
+            None
+        }
+    }
 }
 
 async fn chain_provider_credentials(
     provider: ChainProvider,
 ) -> Result<AwsCredentials, CredentialsError> {
-    if let Ok(creds) = provider.environment_provider.credentials().await {
+    let timeout = provider.provider_timeout;
+    let mut failures = Vec::new();
+    let environment = &provider.environment_provider;
+    if let Some(creds) =
+        chain_source_credentials("environment", environment, timeout, &mut failures).await
+    {
         return Ok(creds);
     }
     if let Some(ref profile_provider) = provider.profile_provider {
-        if let Ok(creds) = profile_provider.credentials().await {
+        if let Some(creds) =
+            chain_source_credentials("profile", profile_provider, timeout, &mut failures).await
+        {
             return Ok(creds);
         }
     }
-    if let Ok(creds) = provider.container_provider.credentials().await {
+    let container = &provider.container_provider;
+    if let Some(creds) =
+        chain_source_credentials("container", container, timeout, &mut failures).await
+    {
         return Ok(creds);
     }
-    if let Ok(creds) = provider.instance_metadata_provider.credentials().await {
+    let instance_metadata = &provider.instance_metadata_provider;
+    if let Some(creds) = chain_source_credentials(
+        "instance metadata",
+        instance_metadata,
+        timeout,
+        &mut failures,
+    )
+    .await
+    {
         return Ok(creds);
     }
-    Err(CredentialsError::new(
-        "Couldn't find AWS credentials in environment, credentials file, or IAM role.",
-    ))
+    Err(CredentialsError::new(format!(
+        "Couldn't find AWS credentials in environment, credentials file, or IAM role. ({})",
+        failures.join("; ")
+    )))
 }
 
 #[async_trait]
@@ -417,6 +691,7 @@ impl ChainProvider {
             profile_provider: ProfileProvider::new().ok(),
             instance_metadata_provider: InstanceMetadataProvider::new(),
             container_provider: ContainerProvider::new(),
+            provider_timeout: DEFAULT_PROVIDER_TIMEOUT,
         }
     }
 
@@ -427,6 +702,7 @@ impl ChainProvider {
             profile_provider: Some(profile_provider),
             instance_metadata_provider: InstanceMetadataProvider::new(),
             container_provider: ContainerProvider::new(),
+            provider_timeout: DEFAULT_PROVIDER_TIMEOUT,
         }
     }
 }
@@ -468,6 +744,62 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn credentials_errors_keep_their_source() {
+        let err = CredentialsError::new("no credentials")
+            .with_source(IoError::new(std::io::ErrorKind::NotFound, "missing file"));
+
+        assert_eq!(err.source().unwrap().to_string(), "missing file");
+        assert_eq!(err, CredentialsError::new("no credentials"));
+        assert!(CredentialsError::new("no credentials").source().is_none());
+    }
+
+    #[tokio::test]
+    async fn chain_provider_times_out_each_source() {
+        let _guard = lock_env();
+        // Accepts connections but never answers, like a firewall dropping packets.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/credentials", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let _connections: Vec<_> = listener.incoming().collect();
+        });
+        for name in &[
+            "AWS_ACCESS_KEY_ID",
+            "AWS_SECRET_ACCESS_KEY",
+            "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+        ] {
+            std::env::remove_var(name);
+        }
+        std::env::set_var("AWS_CONTAINER_CREDENTIALS_FULL_URI", &url);
+        std::env::set_var("AWS_EC2_METADATA_DISABLED", "true");
+
+        let mut provider = ChainProvider::with_profile_provider(
+            ProfileProvider::with_configuration("tests/sample-data/no_such_file", "default"),
+        );
+        provider.set_timeout(Duration::from_secs(60));
+        let provider = provider.with_provider_timeout(Duration::from_millis(200));
+        let start = std::time::Instant::now();
+        let result = provider.credentials().await;
+        let elapsed = start.elapsed();
+
+        std::env::remove_var("AWS_CONTAINER_CREDENTIALS_FULL_URI");
+        std::env::remove_var("AWS_EC2_METADATA_DISABLED");
+        let err = result.unwrap_err();
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+        assert!(
+            err.message.contains("container: timed out after 200ms"),
+            "{}",
+            err.message
+        );
+        assert!(err.message.contains("environment: "), "{}", err.message);
+        assert!(err.message.contains("profile: "), "{}", err.message);
+        assert!(
+            err.message.contains("instance metadata: "),
+            "{}",
+            err.message
+        );
+    }
+
     #[test]
     fn default_empty_credentials_are_considered_anonymous() {
         assert!(AwsCredentials::default().is_anonymous())
@@ -487,6 +819,296 @@ mod tests {
         is_send_and_sync::<DefaultCredentialsProvider>();
     }
 
+    #[tokio::test]
+    async fn auto_refreshing_provider_current_returns_cached_credentials() {
+        let provider =
+            AutoRefreshingProvider::new(StaticProvider::new_minimal("key".into(), "secret".into()))
+                .unwrap();
+        assert!(provider.current().is_none());
+
+        let creds = provider.credentials().await.unwrap();
+        let current = provider.current().expect("cached credentials");
+        assert_eq!(current.aws_access_key_id(), creds.aws_access_key_id());
+        assert_eq!(
+            current.aws_secret_access_key(),
+            creds.aws_secret_access_key()
+        );
+
+        // current() doesn't wait for the lock held while refreshing.
+        let _refreshing = provider.current_credentials.lock().await;
+        assert_eq!(provider.current().unwrap().aws_access_key_id(), "key");
+    }
+
+    #[test]
+    fn needs_refresh_at_buffer_edge() {
+        let now = Utc::now();
+        let buffer = Duration::from_secs(60);
+        let expiring_at = |offset: i64| {
+            AwsCredentials::new(
+                "key",
+                "secret",
+                None,
+                Some(now + ChronoDuration::seconds(offset)),
+            )
+        };
+
+        assert!(!expiring_at(61).needs_refresh_at(now, buffer));
+        assert!(expiring_at(60).needs_refresh_at(now, buffer));
+        assert!(expiring_at(59).needs_refresh_at(now, buffer));
+    }
+
+    #[test]
+    fn needs_refresh_for_expired_credentials() {
+        let now = Utc::now();
+        let expired = AwsCredentials::new(
+            "key",
+            "secret",
+            None,
+            Some(now - ChronoDuration::seconds(1)),
+        );
+        assert!(expired.needs_refresh_at(now, Duration::from_secs(0)));
+        assert!(expired.needs_refresh(Duration::from_secs(0)));
+        assert!(expired.needs_refresh(Duration::from_secs(u64::MAX)));
+
+        let permanent = AwsCredentials::new("key", "secret", None, None);
+        assert!(!permanent.needs_refresh(Duration::from_secs(u64::MAX)));
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_uses_refresh_buffer() {
+        struct Expiring(std::sync::atomic::AtomicUsize);
+
+        #[async_trait]
+        impl ProvideAwsCredentials for Expiring {
+            async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let expires_at = Utc::now() + ChronoDuration::seconds(120);
+                Ok(AwsCredentials::new("key", "secret", None, Some(expires_at)))
+            }
+        }
+
+        let provider = AutoRefreshingProvider::new(Expiring(Default::default())).unwrap();
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+        assert_eq!(
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+
+        let provider = provider.with_refresh_buffer(Duration::from_secs(300));
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+        assert_eq!(
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            3
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    struct FakeClock(Arc<std::sync::Mutex<DateTime<Utc>>>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    /// Counts its fetches of credentials expiring at a fixed time.
+    struct Counting(std::sync::atomic::AtomicUsize, DateTime<Utc>);
+
+    #[async_trait]
+    impl ProvideAwsCredentials for Counting {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(AwsCredentials::new("key", "secret", None, Some(self.1)))
+        }
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_at_buffer_boundary_of_clock() {
+        let start = Utc::now();
+        let expires_at = start + ChronoDuration::seconds(3600);
+        let clock = FakeClock(Arc::new(std::sync::Mutex::new(start)));
+        let provider = AutoRefreshingProvider::new(Counting(Default::default(), expires_at))
+            .unwrap()
+            .with_clock(clock.clone());
+        let fetches = || {
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+        let buffer = ChronoDuration::from_std(DEFAULT_REFRESH_BUFFER).unwrap();
+
+        provider.credentials().await.unwrap();
+        *clock.0.lock().unwrap() = expires_at - buffer - ChronoDuration::seconds(1);
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 1);
+
+        *clock.0.lock().unwrap() = expires_at - buffer;
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 2);
+
+        *clock.0.lock().unwrap() = expires_at + ChronoDuration::seconds(1);
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 3);
+    }
+
+    #[tokio::test]
+    async fn shared_and_boxed_providers_provide_credentials() {
+        async fn fetch<P: ProvideAwsCredentials>(provider: P) -> AwsCredentials {
+            provider.credentials().await.unwrap()
+        }
+
+        let expires_at = Utc::now() + ChronoDuration::seconds(3600);
+        let provider = Arc::new(
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at)).unwrap(),
+        );
+        let boxed: Box<dyn ProvideAwsCredentials + Send + Sync> = Box::new(provider.clone());
+        let shared: Arc<dyn ProvideAwsCredentials + Send + Sync> = provider.clone();
+
+        fetch(provider.clone()).await;
+        fetch(boxed).await;
+        fetch(shared).await;
+        fetch(&*provider).await;
+        assert_eq!(
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_fresh_credentials_on_demand() {
+        let expires_at = Utc::now() + ChronoDuration::seconds(3600);
+        let provider =
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at)).unwrap();
+        let fetches = || {
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+
+        provider.credentials().await.unwrap();
+        provider.refresh().await.unwrap();
+        assert_eq!(fetches(), 2);
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_if_needed_once() {
+        let expires_at = Utc::now() + ChronoDuration::seconds(3600);
+        let provider =
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at)).unwrap();
+        let fetches = || {
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+
+        assert!(provider.refresh_if_needed().await.unwrap());
+        assert!(!provider.refresh_if_needed().await.unwrap());
+        assert_eq!(fetches(), 1);
+
+        // Concurrent calls fetch only once, too.
+        let provider =
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at)).unwrap();
+        let (first, second) =
+            futures::join!(provider.refresh_if_needed(), provider.refresh_if_needed());
+        assert!(first.unwrap() ^ second.unwrap());
+        assert_eq!(
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_within_jitter_window() {
+        let start = Utc::now();
+        let expires_at = start + ChronoDuration::seconds(3600);
+        let clock = FakeClock(Arc::new(std::sync::Mutex::new(start)));
+        let jittered = |seed| {
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at))
+                .unwrap()
+                .with_refresh_jitter(Duration::from_secs(300))
+                .with_refresh_jitter_seed(seed)
+                .with_clock(clock.clone())
+        };
+        // A provider with the same seed picks the same jitter.
+        let jitter = jittered(42).next_jitter();
+        assert!(jitter <= Duration::from_secs(300));
+        assert_eq!(jitter, jittered(42).next_jitter());
+        assert_ne!(jitter, jittered(43).next_jitter());
+
+        let provider = jittered(42);
+        let fetches = || {
+            provider
+                .get_ref()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+        let refresh_at =
+            expires_at - ChronoDuration::from_std(DEFAULT_REFRESH_BUFFER + jitter).unwrap();
+
+        provider.credentials().await.unwrap();
+        *clock.0.lock().unwrap() = refresh_at - ChronoDuration::seconds(1);
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 1);
+
+        *clock.0.lock().unwrap() = refresh_at;
+        provider.credentials().await.unwrap();
+        assert_eq!(fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_warns_of_short_lived_credentials() {
+        let start = Utc::now();
+        let clock = FakeClock(Arc::new(std::sync::Mutex::new(start)));
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let warned = warnings.clone();
+        let provider = |expires_at| {
+            let warned = warned.clone();
+            AutoRefreshingProvider::new(Counting(Default::default(), expires_at))
+                .unwrap()
+                .with_refresh_buffer(Duration::from_secs(300))
+                .with_clock(clock.clone())
+                .with_expiry_warning(move |creds: &AwsCredentials| {
+                    warned.lock().unwrap().push(creds.expires_at().unwrap())
+                })
+        };
+
+        let long_lived = provider(start + ChronoDuration::seconds(3600));
+        long_lived.credentials().await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        let expires_at = start + ChronoDuration::seconds(120);
+        let short_lived = provider(expires_at);
+        short_lived.credentials().await.unwrap();
+        assert_eq!(*warnings.lock().unwrap(), vec![expires_at]);
+    }
+
+    #[test]
+    fn auto_refreshing_provider_has_no_jitter_by_default() {
+        let provider =
+            AutoRefreshingProvider::new(StaticProvider::new_minimal("key".into(), "secret".into()))
+                .unwrap();
+        assert_eq!(provider.next_jitter(), Duration::from_secs(0));
+    }
+
     #[tokio::test]
     async fn profile_provider_finds_right_credentials_in_file() {
         let _guard = lock_env();
@@ -0,0 +1,121 @@
+//! Provides credentials from a primary provider, falling back to another one if it fails.
+use async_trait::async_trait;
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Provides AWS credentials from a primary provider or, if it fails, from a fallback provider.
+///
+/// Unlike the `ChainProvider`, which tries a fixed list of sources, it combines any two
+/// providers, e.g. to prefer an assume role provider but fall back to a session token provider
+/// during MFA outages. Both providers are tried on every fetch: wrap it in an
+/// `AutoRefreshingProvider` to cache the credentials it returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_credential::{EnvironmentProvider, OrElseProvider, StaticProvider};
+///
+/// let provider = OrElseProvider::new(
+///     EnvironmentProvider::default(),
+///     StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrElseProvider<A, B> {
+    primary: A,
+    fallback: B,
+}
+
+impl<A, B> OrElseProvider<A, B> {
+    /// Creates a provider trying `primary` first and `fallback` if `primary` fails.
+    pub fn new(primary: A, fallback: B) -> OrElseProvider<A, B> {
+        OrElseProvider { primary, fallback }
+    }
+
+    /// Returns the primary provider.
+    pub fn primary(&self) -> &A {
+        &self.primary
+    }
+
+    /// Returns the fallback provider.
+    pub fn fallback(&self) -> &B {
+        &self.fallback
+    }
+}
+
+#[async_trait]
+impl<A, B> ProvideAwsCredentials for OrElseProvider<A, B>
+where
+    A: ProvideAwsCredentials + Send + Sync,
+    B: ProvideAwsCredentials + Send + Sync,
+{
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let primary_err = match self.primary.credentials().await {
+            Ok(credentials) => return Ok(credentials),
+            Err(err) => err,
+        };
+        self.fallback.credentials().await.map_err(|err| {
+            CredentialsError::new(format!(
+                "Couldn't get credentials from the fallback provider: {}, \
+                 after the primary provider failed: {}",
+                err.message, primary_err.message
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::StaticProvider;
+
+    #[derive(Debug)]
+    struct Failing(&'static str, AtomicUsize);
+
+    impl Failing {
+        fn new(message: &'static str) -> Failing {
+            Failing(message, AtomicUsize::new(0))
+        }
+    }
+
+    #[async_trait]
+    impl ProvideAwsCredentials for Failing {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            self.1.fetch_add(1, Ordering::SeqCst);
+            Err(CredentialsError::new(self.0))
+        }
+    }
+
+    fn static_provider(key: &str) -> StaticProvider {
+        StaticProvider::new_minimal(key.to_owned(), "secret".to_owned())
+    }
+
+    #[tokio::test]
+    async fn primary_credentials_are_preferred() {
+        let provider = OrElseProvider::new(static_provider("primary"), Failing::new("unused"));
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "primary");
+        assert_eq!(provider.fallback().1.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_the_primary_fails() {
+        let provider = OrElseProvider::new(Failing::new("MFA outage"), static_provider("fallback"));
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "fallback");
+        assert_eq!(provider.primary().1.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn errors_of_both_providers_are_reported() {
+        let provider = OrElseProvider::new(Failing::new("MFA outage"), Failing::new("no token"));
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(
+            err.message,
+            "Couldn't get credentials from the fallback provider: no token, \
+             after the primary provider failed: MFA outage"
+        );
+    }
+}
@@ -74,8 +74,8 @@ impl ProfileProvider {
     /// Create a new `ProfileProvider` for the default credentials file path using
     /// the given profile.
     pub fn with_default_credentials<P>(profile: P) -> Result<ProfileProvider, CredentialsError>
-        where
-            P: Into<String>,
+    where
+        P: Into<String>,
     {
         let profile_location = ProfileProvider::default_profile_location()?;
         Ok(ProfileProvider {
@@ -90,12 +90,19 @@ impl ProfileProvider {
     ///
     /// For a the ful region resolution chain, use the `Default` impl for `rusoto_core::Region`
     pub fn region() -> Result<Option<String>, CredentialsError> {
+        ProfileProvider::setting(REGION)
+    }
+
+    /// Attempts to resolve the value of `key` in the current default profile from
+    /// `~/.aws/config` or the file associated with the `AWS_CONFIG_FILE` environment variable,
+    /// e.g. `use_fips_endpoint`. An `Option` is returned as the key may not be set.
+    pub fn setting(key: &str) -> Result<Option<String>, CredentialsError> {
         let location = ProfileProvider::default_config_location();
         location.map(|location| {
             parse_config_file(&location).and_then(|config| {
                 config
                     .get(&ProfileProvider::default_profile_name())
-                    .and_then(|props| props.get(REGION))
+                    .and_then(|props| props.get(key))
                     .map(std::borrow::ToOwned::to_owned)
             })
         })
@@ -105,13 +112,12 @@ impl ProfileProvider {
     /// config file path (`ProfileProvider.file_path`) and profile (`ProfileProvider.profile`).
     /// As these fields do not require a region field to be defined, an `Option` type is returned
     pub fn region_from_profile(&self) -> Result<Option<String>, CredentialsError> {
-        Ok(
-            parse_config_file(&self.file_path).and_then(|config| {
-                config
-                    .get(&self.profile)
-                    .and_then(|props| props.get(REGION))
-                    .map(std::borrow::ToOwned::to_owned)
-            }))
+        Ok(parse_config_file(&self.file_path).and_then(|config| {
+            config
+                .get(&self.profile)
+                .and_then(|props| props.get(REGION))
+                .map(std::borrow::ToOwned::to_owned)
+        }))
     }
 
     /// Default config file location:
@@ -736,16 +742,25 @@ mod tests {
 
     #[test]
     fn region_from_profile() {
-        let provider = ProfileProvider::with_configuration(
-            "tests/sample-data/multiple_profile_config",
-            "foo",
-        );
+        let provider =
+            ProfileProvider::with_configuration("tests/sample-data/multiple_profile_config", "foo");
         let maybe_region = provider.region_from_profile().unwrap();
 
+        assert_eq!(maybe_region, Some("us-east-3".to_string()));
+    }
+
+    #[test]
+    fn setting_from_default_profile() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/multiple_profile_config");
+        env::set_var(AWS_PROFILE, "bar");
         assert_eq!(
-            maybe_region,
-            Some("us-east-3".to_string())
+            ProfileProvider::setting("output").unwrap(),
+            Some("json".to_owned())
         );
+        assert_eq!(ProfileProvider::setting("use_fips_endpoint").unwrap(), None);
+        env::remove_var(AWS_PROFILE);
+        env::remove_var(AWS_CONFIG_FILE);
     }
 
     #[test]
@@ -756,10 +771,6 @@ mod tests {
         );
         let maybe_region = provider.region_from_profile().unwrap();
 
-        assert_eq!(
-            maybe_region,
-            None
-        );
+        assert_eq!(maybe_region, None);
     }
-
 }
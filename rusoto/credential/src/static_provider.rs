@@ -1,7 +1,7 @@
 //! Provides a way to create static/programmatically generated AWS Credentials.
 //! For those who can't get them from an environment, or a file.
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
 
@@ -40,6 +40,23 @@ impl StaticProvider {
         }
     }
 
+    /// Creates a Static Provider of anonymous credentials, with which clients send requests
+    /// without signing them, e.g. to read objects of public S3 buckets. No credentials need to
+    /// be configured on the machine.
+    pub fn anonymous() -> StaticProvider {
+        StaticProvider::from(AwsCredentials::default())
+    }
+
+    /// Makes the credentials of this Static Provider expire at `expires_at`, e.g. for
+    /// short-lived credentials obtained out-of-band. This replaces the validity set with
+    /// `valid_for`. An `AutoRefreshingProvider` wrapping the provider fetches its credentials
+    /// again after they expire, so use a `CallbackProvider` to get new ones.
+    pub fn with_expiration(mut self, expires_at: DateTime<Utc>) -> StaticProvider {
+        self.credentials.expires_at = Some(expires_at);
+        self.valid_for = None;
+        self
+    }
+
     /// Gets the AWS Access Key ID for this Static Provider.
     pub fn get_aws_access_key_id(&self) -> &str {
         &self.credentials.key
@@ -70,7 +87,9 @@ impl StaticProvider {
 impl ProvideAwsCredentials for StaticProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         let mut creds = self.credentials.clone();
-        creds.expires_at = self.valid_for.map(|v| Utc::now() + Duration::seconds(v));
+        if let Some(valid_for) = self.valid_for {
+            creds.expires_at = Some(Utc::now() + Duration::seconds(valid_for));
+        }
         Ok(creds)
     }
 }
@@ -95,6 +114,37 @@ mod tests {
 
     use quickcheck_macros::quickcheck;
 
+    #[tokio::test]
+    async fn anonymous_provider_credentials_are_anonymous() {
+        use crate::Anonymous;
+
+        let credentials = StaticProvider::anonymous().credentials().await.unwrap();
+        assert!(credentials.is_anonymous());
+        assert_eq!(credentials.expires_at(), &None);
+    }
+
+    #[tokio::test]
+    async fn static_provider_with_expiration() {
+        let expires_at = Utc::now() + Duration::minutes(15);
+        let provider = StaticProvider::new(
+            "fake-key".to_owned(),
+            "fake-secret".to_owned(),
+            None,
+            Some(300),
+        )
+        .with_expiration(expires_at);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.expires_at(), &Some(expires_at));
+        assert_eq!(*provider.is_valid_for(), None);
+
+        let credentials = AwsCredentials::new("key", "secret", None, Some(expires_at));
+        let credentials = StaticProvider::from(credentials)
+            .credentials()
+            .await
+            .unwrap();
+        assert_eq!(credentials.expires_at(), &Some(expires_at));
+    }
+
     #[test]
     fn static_provider_impl_from_for_awscredentials() {
         let provider = StaticProvider::from(AwsCredentials::default());
@@ -1779,6 +1779,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1790,13 +1791,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateAnalyzerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAnalyzerError::from_response(response))
         }
     }
@@ -1813,6 +1814,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1824,12 +1826,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateArchiveRuleError::from_response(response))
         }
     }
@@ -1847,6 +1849,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1861,12 +1864,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAnalyzerError::from_response(response))
         }
     }
@@ -1885,6 +1888,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1899,12 +1903,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteArchiveRuleError::from_response(response))
         }
     }
@@ -1918,6 +1922,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1931,13 +1936,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAnalyzedResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAnalyzedResourceError::from_response(response))
         }
     }
@@ -1954,6 +1959,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1962,13 +1968,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAnalyzerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAnalyzerError::from_response(response))
         }
     }
@@ -1986,6 +1992,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1994,13 +2001,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetArchiveRuleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetArchiveRuleError::from_response(response))
         }
     }
@@ -2014,6 +2021,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/finding/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2026,13 +2034,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFindingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFindingError::from_response(response))
         }
     }
@@ -2046,6 +2054,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2057,13 +2066,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAnalyzedResourcesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAnalyzedResourcesError::from_response(response))
         }
     }
@@ -2077,6 +2086,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2097,13 +2107,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAnalyzersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAnalyzersError::from_response(response))
         }
     }
@@ -2120,6 +2130,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListArchiveRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2137,13 +2148,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListArchiveRulesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListArchiveRulesError::from_response(response))
         }
     }
@@ -2157,6 +2168,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2168,13 +2180,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFindingsError::from_response(response))
         }
     }
@@ -2188,6 +2200,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2196,13 +2209,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -2216,6 +2229,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/resource/scan";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("StartResourceScan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2227,12 +2241,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartResourceScanError::from_response(response))
         }
     }
@@ -2246,6 +2260,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2257,13 +2272,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -2278,6 +2293,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2292,13 +2308,13 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -2316,6 +2332,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2327,12 +2344,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateArchiveRuleError::from_response(response))
         }
     }
@@ -2346,6 +2363,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2357,12 +2375,12 @@ impl AccessAnalyzer for AccessAnalyzerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFindingsError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl AcmPcaClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2090,7 +2090,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, CreateCertificateAuthorityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCertificateAuthorityResponse, _>()
     }
@@ -2118,7 +2118,7 @@ impl AcmPca for AcmPcaClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCertificateAuthorityAuditReportResponse, _>()
     }
@@ -2189,7 +2189,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, DescribeCertificateAuthorityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCertificateAuthorityResponse, _>()
     }
@@ -2217,7 +2217,7 @@ impl AcmPca for AcmPcaClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCertificateAuthorityAuditReportResponse, _>()
     }
@@ -2236,7 +2236,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, GetCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCertificateResponse, _>()
     }
 
@@ -2263,7 +2263,7 @@ impl AcmPca for AcmPcaClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCertificateAuthorityCertificateResponse, _>()
     }
@@ -2283,7 +2283,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, GetCertificateAuthorityCsrError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCertificateAuthorityCsrResponse, _>()
     }
@@ -2325,7 +2325,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, IssueCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IssueCertificateResponse, _>()
     }
 
@@ -2344,7 +2344,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, ListCertificateAuthoritiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCertificateAuthoritiesResponse, _>()
     }
@@ -2363,7 +2363,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, ListPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPermissionsResponse, _>()
     }
 
@@ -2381,7 +2381,7 @@ impl AcmPca for AcmPcaClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
     }
 
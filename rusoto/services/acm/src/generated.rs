@@ -41,7 +41,7 @@ impl AcmClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1390,7 +1390,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, DescribeCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCertificateResponse, _>()
     }
 
@@ -1408,7 +1408,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, ExportCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ExportCertificateResponse, _>()
     }
 
@@ -1426,7 +1426,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, GetCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCertificateResponse, _>()
     }
 
@@ -1444,7 +1444,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, ImportCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportCertificateResponse, _>()
     }
 
@@ -1462,7 +1462,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, ListCertificatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCertificatesResponse, _>()
     }
 
@@ -1480,7 +1480,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, ListTagsForCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTagsForCertificateResponse, _>()
     }
@@ -1536,7 +1536,7 @@ impl Acm for AcmClient {
             .sign_and_dispatch(request, RequestCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RequestCertificateResponse, _>()
     }
 
@@ -41,7 +41,7 @@ impl AlexaForBusinessClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7429,7 +7429,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ApproveSkillError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ApproveSkillResponse, _>()
     }
 
@@ -7453,7 +7453,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, AssociateContactWithAddressBookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateContactWithAddressBookResponse, _>()
     }
@@ -7481,7 +7481,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateDeviceWithNetworkProfileResponse, _>()
     }
@@ -7500,7 +7500,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, AssociateDeviceWithRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateDeviceWithRoomResponse, _>()
     }
@@ -7523,7 +7523,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, AssociateSkillGroupWithRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateSkillGroupWithRoomResponse, _>()
     }
@@ -7546,7 +7546,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, AssociateSkillWithSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateSkillWithSkillGroupResponse, _>()
     }
@@ -7565,7 +7565,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, AssociateSkillWithUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateSkillWithUsersResponse, _>()
     }
@@ -7584,7 +7584,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateAddressBookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAddressBookResponse, _>()
     }
 
@@ -7606,7 +7606,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateBusinessReportScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateBusinessReportScheduleResponse, _>()
     }
@@ -7625,7 +7625,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateConferenceProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateConferenceProviderResponse, _>()
     }
@@ -7644,7 +7644,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateContactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateContactResponse, _>()
     }
 
@@ -7662,7 +7662,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateGatewayGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGatewayGroupResponse, _>()
     }
 
@@ -7680,7 +7680,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateNetworkProfileResponse, _>()
     }
@@ -7699,7 +7699,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProfileResponse, _>()
     }
 
@@ -7717,7 +7717,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRoomResponse, _>()
     }
 
@@ -7735,7 +7735,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSkillGroupResponse, _>()
     }
 
@@ -7753,7 +7753,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, CreateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResponse, _>()
     }
 
@@ -7771,7 +7771,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteAddressBookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAddressBookResponse, _>()
     }
 
@@ -7793,7 +7793,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteBusinessReportScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteBusinessReportScheduleResponse, _>()
     }
@@ -7812,7 +7812,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteConferenceProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteConferenceProviderResponse, _>()
     }
@@ -7831,7 +7831,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteContactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteContactResponse, _>()
     }
 
@@ -7849,7 +7849,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDeviceResponse, _>()
     }
 
@@ -7867,7 +7867,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteDeviceUsageDataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteDeviceUsageDataResponse, _>()
     }
@@ -7886,7 +7886,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteGatewayGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGatewayGroupResponse, _>()
     }
 
@@ -7904,7 +7904,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteNetworkProfileResponse, _>()
     }
@@ -7923,7 +7923,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProfileResponse, _>()
     }
 
@@ -7941,7 +7941,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRoomResponse, _>()
     }
 
@@ -7959,7 +7959,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteRoomSkillParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRoomSkillParameterResponse, _>()
     }
@@ -7978,7 +7978,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteSkillAuthorizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSkillAuthorizationResponse, _>()
     }
@@ -7997,7 +7997,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSkillGroupResponse, _>()
     }
 
@@ -8015,7 +8015,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DeleteUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserResponse, _>()
     }
 
@@ -8042,7 +8042,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateContactFromAddressBookResponse, _>()
     }
@@ -8065,7 +8065,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DisassociateDeviceFromRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateDeviceFromRoomResponse, _>()
     }
@@ -8090,7 +8090,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DisassociateSkillFromSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateSkillFromSkillGroupResponse, _>()
     }
@@ -8113,7 +8113,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DisassociateSkillFromUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateSkillFromUsersResponse, _>()
     }
@@ -8138,7 +8138,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, DisassociateSkillGroupFromRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateSkillGroupFromRoomResponse, _>()
     }
@@ -8158,7 +8158,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ForgetSmartHomeAppliancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ForgetSmartHomeAppliancesResponse, _>()
     }
@@ -8177,7 +8177,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetAddressBookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAddressBookResponse, _>()
     }
 
@@ -8193,7 +8193,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetConferencePreferenceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetConferencePreferenceResponse, _>()
     }
@@ -8212,7 +8212,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetConferenceProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetConferenceProviderResponse, _>()
     }
@@ -8231,7 +8231,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetContactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetContactResponse, _>()
     }
 
@@ -8249,7 +8249,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResponse, _>()
     }
 
@@ -8267,7 +8267,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGatewayResponse, _>()
     }
 
@@ -8285,7 +8285,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetGatewayGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGatewayGroupResponse, _>()
     }
 
@@ -8305,7 +8305,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetInvitationConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetInvitationConfigurationResponse, _>()
     }
@@ -8324,7 +8324,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetNetworkProfileResponse, _>()
     }
 
@@ -8342,7 +8342,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetProfileResponse, _>()
     }
 
@@ -8360,7 +8360,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()
     }
 
@@ -8378,7 +8378,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetRoomSkillParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRoomSkillParameterResponse, _>()
     }
@@ -8397,7 +8397,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, GetSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSkillGroupResponse, _>()
     }
 
@@ -8419,7 +8419,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListBusinessReportSchedulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListBusinessReportSchedulesResponse, _>()
     }
@@ -8438,7 +8438,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListConferenceProvidersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListConferenceProvidersResponse, _>()
     }
@@ -8457,7 +8457,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListDeviceEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeviceEventsResponse, _>()
     }
 
@@ -8475,7 +8475,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListGatewayGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGatewayGroupsResponse, _>()
     }
 
@@ -8493,7 +8493,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListGatewaysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGatewaysResponse, _>()
     }
 
@@ -8511,7 +8511,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListSkillsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSkillsResponse, _>()
     }
 
@@ -8530,7 +8530,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListSkillsStoreCategoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSkillsStoreCategoriesResponse, _>()
     }
@@ -8555,7 +8555,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListSkillsStoreSkillsByCategoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSkillsStoreSkillsByCategoryResponse, _>()
     }
@@ -8574,7 +8574,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListSmartHomeAppliancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSmartHomeAppliancesResponse, _>()
     }
@@ -8593,7 +8593,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
     }
 
@@ -8611,7 +8611,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, PutConferencePreferenceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutConferencePreferenceResponse, _>()
     }
@@ -8634,7 +8634,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, PutInvitationConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutInvitationConfigurationResponse, _>()
     }
@@ -8653,7 +8653,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, PutRoomSkillParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutRoomSkillParameterResponse, _>()
     }
@@ -8672,7 +8672,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, PutSkillAuthorizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutSkillAuthorizationResponse, _>()
     }
@@ -8691,7 +8691,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, RegisterAVSDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterAVSDeviceResponse, _>()
     }
 
@@ -8709,7 +8709,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, RejectSkillError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RejectSkillResponse, _>()
     }
 
@@ -8727,7 +8727,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, ResolveRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveRoomResponse, _>()
     }
 
@@ -8745,7 +8745,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, RevokeInvitationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RevokeInvitationResponse, _>()
     }
 
@@ -8763,7 +8763,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchAddressBooksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchAddressBooksResponse, _>()
     }
 
@@ -8781,7 +8781,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchContactsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchContactsResponse, _>()
     }
 
@@ -8799,7 +8799,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchDevicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchDevicesResponse, _>()
     }
 
@@ -8817,7 +8817,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchNetworkProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SearchNetworkProfilesResponse, _>()
     }
@@ -8836,7 +8836,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchProfilesResponse, _>()
     }
 
@@ -8854,7 +8854,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchRoomsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchRoomsResponse, _>()
     }
 
@@ -8872,7 +8872,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchSkillGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchSkillGroupsResponse, _>()
     }
 
@@ -8890,7 +8890,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SearchUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchUsersResponse, _>()
     }
 
@@ -8908,7 +8908,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SendAnnouncementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendAnnouncementResponse, _>()
     }
 
@@ -8926,7 +8926,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, SendInvitationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendInvitationResponse, _>()
     }
 
@@ -8944,7 +8944,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, StartDeviceSyncError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartDeviceSyncResponse, _>()
     }
 
@@ -8971,7 +8971,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartSmartHomeApplianceDiscoveryResponse, _>()
     }
@@ -8990,7 +8990,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -9008,7 +9008,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -9026,7 +9026,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateAddressBookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAddressBookResponse, _>()
     }
 
@@ -9048,7 +9048,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateBusinessReportScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateBusinessReportScheduleResponse, _>()
     }
@@ -9067,7 +9067,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateConferenceProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateConferenceProviderResponse, _>()
     }
@@ -9086,7 +9086,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateContactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateContactResponse, _>()
     }
 
@@ -9104,7 +9104,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeviceResponse, _>()
     }
 
@@ -9122,7 +9122,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGatewayResponse, _>()
     }
 
@@ -9140,7 +9140,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateGatewayGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGatewayGroupResponse, _>()
     }
 
@@ -9158,7 +9158,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateNetworkProfileResponse, _>()
     }
@@ -9177,7 +9177,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateProfileResponse, _>()
     }
 
@@ -9195,7 +9195,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateRoomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRoomResponse, _>()
     }
 
@@ -9213,7 +9213,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
             .sign_and_dispatch(request, UpdateSkillGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSkillGroupResponse, _>()
     }
 }
@@ -3870,6 +3870,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3881,13 +3882,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAppError::from_response(response))
         }
     }
@@ -3901,6 +3902,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3912,13 +3914,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBackendEnvironmentResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBackendEnvironmentError::from_response(response))
         }
     }
@@ -3932,6 +3934,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3943,13 +3946,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBranchResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBranchError::from_response(response))
         }
     }
@@ -3967,6 +3970,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3978,13 +3982,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentError::from_response(response))
         }
     }
@@ -3998,6 +4002,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4009,13 +4014,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDomainAssociationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDomainAssociationError::from_response(response))
         }
     }
@@ -4029,6 +4034,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4040,13 +4046,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateWebhookResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateWebhookError::from_response(response))
         }
     }
@@ -4060,6 +4066,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4068,13 +4075,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DeleteAppResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAppError::from_response(response))
         }
     }
@@ -4092,6 +4099,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4100,13 +4108,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteBackendEnvironmentResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackendEnvironmentError::from_response(response))
         }
     }
@@ -4124,6 +4132,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4132,13 +4141,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteBranchResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBranchError::from_response(response))
         }
     }
@@ -4156,6 +4165,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4164,13 +4174,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDomainAssociationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDomainAssociationError::from_response(response))
         }
     }
@@ -4189,6 +4199,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4197,13 +4208,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DeleteJobResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteJobError::from_response(response))
         }
     }
@@ -4217,6 +4228,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4225,13 +4237,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteWebhookResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteWebhookError::from_response(response))
         }
     }
@@ -4245,6 +4257,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/accesslogs", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("GenerateAccessLogs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4256,13 +4269,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GenerateAccessLogsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GenerateAccessLogsError::from_response(response))
         }
     }
@@ -4276,6 +4289,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4284,13 +4298,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetAppResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAppError::from_response(response))
         }
     }
@@ -4304,6 +4318,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/artifacts/{artifact_id}", artifact_id = input.artifact_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetArtifactUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4312,13 +4327,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetArtifactUrlResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetArtifactUrlError::from_response(response))
         }
     }
@@ -4336,6 +4351,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4344,13 +4360,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackendEnvironmentResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackendEnvironmentError::from_response(response))
         }
     }
@@ -4368,6 +4384,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4376,13 +4393,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetBranchResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBranchError::from_response(response))
         }
     }
@@ -4400,6 +4417,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4408,13 +4426,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDomainAssociationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainAssociationError::from_response(response))
         }
     }
@@ -4433,6 +4451,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4441,13 +4460,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetJobError::from_response(response))
         }
     }
@@ -4461,6 +4480,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4469,13 +4489,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetWebhookResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetWebhookError::from_response(response))
         }
     }
@@ -4489,6 +4509,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListApps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4506,13 +4527,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAppsError::from_response(response))
         }
     }
@@ -4531,6 +4552,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListArtifacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4548,13 +4570,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListArtifactsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListArtifactsError::from_response(response))
         }
     }
@@ -4568,6 +4590,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBackendEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4588,13 +4611,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackendEnvironmentsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackendEnvironmentsError::from_response(response))
         }
     }
@@ -4608,6 +4631,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBranches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4625,13 +4649,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBranchesResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBranchesError::from_response(response))
         }
     }
@@ -4645,6 +4669,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListDomainAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4662,13 +4687,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDomainAssociationsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDomainAssociationsError::from_response(response))
         }
     }
@@ -4686,6 +4711,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4703,13 +4729,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsError::from_response(response))
         }
     }
@@ -4723,6 +4749,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4731,13 +4758,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -4751,6 +4778,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListWebhooks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4768,13 +4796,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListWebhooksResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListWebhooksError::from_response(response))
         }
     }
@@ -4792,6 +4820,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4803,13 +4832,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartDeploymentResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartDeploymentError::from_response(response))
         }
     }
@@ -4827,6 +4856,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4838,13 +4868,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<StartJobResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartJobError::from_response(response))
         }
     }
@@ -4863,6 +4893,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("StopJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4871,13 +4902,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopJobError::from_response(response))
         }
     }
@@ -4891,6 +4922,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4902,13 +4934,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -4922,6 +4954,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4936,13 +4969,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -4956,6 +4989,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4967,13 +5001,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UpdateAppResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAppError::from_response(response))
         }
     }
@@ -4991,6 +5025,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5002,13 +5037,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateBranchResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateBranchError::from_response(response))
         }
     }
@@ -5026,6 +5061,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5037,13 +5073,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDomainAssociationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDomainAssociationError::from_response(response))
         }
     }
@@ -5057,6 +5093,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5068,13 +5105,13 @@ impl Amplify for AmplifyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateWebhookResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateWebhookError::from_response(response))
         }
     }
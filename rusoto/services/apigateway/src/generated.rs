@@ -10517,6 +10517,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10528,12 +10529,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApiKeyError::from_response(response))
         }
     }
@@ -10550,6 +10551,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10561,13 +10563,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAuthorizerError::from_response(response))
         }
     }
@@ -10584,6 +10586,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10595,13 +10598,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBasePathMappingError::from_response(response))
         }
     }
@@ -10618,6 +10621,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10629,13 +10633,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentError::from_response(response))
         }
     }
@@ -10651,6 +10655,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10662,13 +10667,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationPart, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDocumentationPartError::from_response(response))
         }
     }
@@ -10684,6 +10689,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10695,13 +10701,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationVersion, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDocumentationVersionError::from_response(response))
         }
     }
@@ -10715,6 +10721,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10726,13 +10733,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDomainNameError::from_response(response))
         }
     }
@@ -10749,6 +10756,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10760,12 +10768,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateModelError::from_response(response))
         }
     }
@@ -10782,6 +10790,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10793,13 +10802,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RequestValidator, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRequestValidatorError::from_response(response))
         }
     }
@@ -10817,6 +10826,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10828,13 +10838,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateResourceError::from_response(response))
         }
     }
@@ -10848,6 +10858,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10859,13 +10870,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRestApiError::from_response(response))
         }
     }
@@ -10882,6 +10893,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10893,12 +10905,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateStageError::from_response(response))
         }
     }
@@ -10912,6 +10924,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10923,13 +10936,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateUsagePlanError::from_response(response))
         }
     }
@@ -10946,6 +10959,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10957,13 +10971,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateUsagePlanKeyError::from_response(response))
         }
     }
@@ -10977,6 +10991,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10988,13 +11003,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVpcLinkError::from_response(response))
         }
     }
@@ -11008,6 +11023,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11016,12 +11032,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApiKeyError::from_response(response))
         }
     }
@@ -11039,6 +11055,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11047,12 +11064,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAuthorizerError::from_response(response))
         }
     }
@@ -11070,6 +11087,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11078,12 +11096,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBasePathMappingError::from_response(response))
         }
     }
@@ -11100,6 +11118,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11108,12 +11127,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteClientCertificateError::from_response(response))
         }
     }
@@ -11131,6 +11150,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11139,12 +11159,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDeploymentError::from_response(response))
         }
     }
@@ -11161,6 +11181,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11169,12 +11190,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDocumentationPartError::from_response(response))
         }
     }
@@ -11191,6 +11212,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11199,12 +11221,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDocumentationVersionError::from_response(response))
         }
     }
@@ -11221,6 +11243,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11229,12 +11252,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDomainNameError::from_response(response))
         }
     }
@@ -11252,6 +11275,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11260,12 +11284,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGatewayResponseError::from_response(response))
         }
     }
@@ -11284,6 +11308,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11292,12 +11317,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntegrationError::from_response(response))
         }
     }
@@ -11311,6 +11336,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11319,12 +11345,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntegrationResponseError::from_response(response))
         }
     }
@@ -11343,6 +11369,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11351,12 +11378,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMethodError::from_response(response))
         }
     }
@@ -11370,6 +11397,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11378,12 +11406,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMethodResponseError::from_response(response))
         }
     }
@@ -11401,6 +11429,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11409,12 +11438,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteModelError::from_response(response))
         }
     }
@@ -11432,6 +11461,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11440,12 +11470,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRequestValidatorError::from_response(response))
         }
     }
@@ -11463,6 +11493,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11471,12 +11502,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteResourceError::from_response(response))
         }
     }
@@ -11490,6 +11521,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11498,12 +11530,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRestApiError::from_response(response))
         }
     }
@@ -11521,6 +11553,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11529,12 +11562,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteStageError::from_response(response))
         }
     }
@@ -11551,6 +11584,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11559,12 +11593,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUsagePlanError::from_response(response))
         }
     }
@@ -11582,6 +11616,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11590,12 +11625,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUsagePlanKeyError::from_response(response))
         }
     }
@@ -11609,6 +11644,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11617,12 +11653,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVpcLinkError::from_response(response))
         }
     }
@@ -11640,6 +11676,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageAuthorizersCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11648,12 +11685,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(FlushStageAuthorizersCacheError::from_response(response))
         }
     }
@@ -11671,6 +11708,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11679,12 +11717,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(FlushStageCacheError::from_response(response))
         }
     }
@@ -11698,6 +11736,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("GenerateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11709,13 +11748,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ClientCertificate, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GenerateClientCertificateError::from_response(response))
         }
     }
@@ -11726,6 +11765,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11734,13 +11774,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAccountError::from_response(response))
         }
     }
@@ -11754,6 +11794,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11768,12 +11809,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiKeyError::from_response(response))
         }
     }
@@ -11787,6 +11828,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11813,13 +11855,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ApiKeys, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiKeysError::from_response(response))
         }
     }
@@ -11837,6 +11879,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11845,13 +11888,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAuthorizerError::from_response(response))
         }
     }
@@ -11868,6 +11911,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11885,13 +11929,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Authorizers, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAuthorizersError::from_response(response))
         }
     }
@@ -11909,6 +11953,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11917,13 +11962,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBasePathMappingError::from_response(response))
         }
     }
@@ -11940,6 +11985,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11957,13 +12003,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BasePathMappings, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBasePathMappingsError::from_response(response))
         }
     }
@@ -11980,6 +12026,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11988,13 +12035,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ClientCertificate, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetClientCertificateError::from_response(response))
         }
     }
@@ -12008,6 +12055,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12025,13 +12073,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ClientCertificates, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetClientCertificatesError::from_response(response))
         }
     }
@@ -12049,6 +12097,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12065,13 +12114,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentError::from_response(response))
         }
     }
@@ -12088,6 +12137,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12105,13 +12155,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentsError::from_response(response))
         }
     }
@@ -12128,6 +12178,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12136,13 +12187,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationPart, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentationPartError::from_response(response))
         }
     }
@@ -12158,6 +12209,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12187,13 +12239,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationParts, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentationPartsError::from_response(response))
         }
     }
@@ -12210,6 +12262,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12218,13 +12271,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationVersion, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentationVersionError::from_response(response))
         }
     }
@@ -12240,6 +12293,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12257,13 +12311,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationVersions, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentationVersionsError::from_response(response))
         }
     }
@@ -12280,6 +12334,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12288,13 +12343,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainNameError::from_response(response))
         }
     }
@@ -12308,6 +12363,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12325,13 +12381,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DomainNames, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainNamesError::from_response(response))
         }
     }
@@ -12350,6 +12406,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accepts.as_ref());
@@ -12367,7 +12424,7 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = ExportResponse::default();
             result.body = Some(response.body);
@@ -12377,7 +12434,7 @@ impl ApiGateway for ApiGatewayClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetExportError::from_response(response))
         }
     }
@@ -12395,6 +12452,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12403,13 +12461,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGatewayResponseError::from_response(response))
         }
     }
@@ -12426,6 +12484,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12443,13 +12502,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GatewayResponses, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGatewayResponsesError::from_response(response))
         }
     }
@@ -12468,6 +12527,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12476,13 +12536,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationError::from_response(response))
         }
     }
@@ -12496,6 +12556,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12504,13 +12565,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<IntegrationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationResponseError::from_response(response))
         }
     }
@@ -12529,6 +12590,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12537,12 +12599,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMethodError::from_response(response))
         }
     }
@@ -12556,6 +12618,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12564,13 +12627,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMethodResponseError::from_response(response))
         }
     }
@@ -12585,6 +12648,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12599,12 +12663,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelError::from_response(response))
         }
     }
@@ -12622,6 +12686,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12630,13 +12695,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Template, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelTemplateError::from_response(response))
         }
     }
@@ -12653,6 +12718,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12670,12 +12736,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Models, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelsError::from_response(response))
         }
     }
@@ -12693,6 +12759,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12701,13 +12768,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RequestValidator, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRequestValidatorError::from_response(response))
         }
     }
@@ -12724,6 +12791,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12741,13 +12809,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RequestValidators, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRequestValidatorsError::from_response(response))
         }
     }
@@ -12765,6 +12833,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12781,13 +12850,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourceError::from_response(response))
         }
     }
@@ -12804,6 +12873,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12826,13 +12896,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Resources, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourcesError::from_response(response))
         }
     }
@@ -12846,6 +12916,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12854,13 +12925,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRestApiError::from_response(response))
         }
     }
@@ -12874,6 +12945,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12891,13 +12963,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApis, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRestApisError::from_response(response))
         }
     }
@@ -12913,6 +12985,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdk");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12929,7 +13002,7 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = SdkResponse::default();
             result.body = Some(response.body);
@@ -12939,7 +13012,7 @@ impl ApiGateway for ApiGatewayClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSdkError::from_response(response))
         }
     }
@@ -12952,6 +13025,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12960,13 +13034,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<SdkType, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSdkTypeError::from_response(response))
         }
     }
@@ -12979,6 +13053,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/sdktypes";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12996,13 +13071,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<SdkTypes, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSdkTypesError::from_response(response))
         }
     }
@@ -13017,6 +13092,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13025,12 +13101,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetStageError::from_response(response))
         }
     }
@@ -13047,6 +13123,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13061,12 +13138,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Stages, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetStagesError::from_response(response))
         }
     }
@@ -13077,6 +13154,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13094,12 +13172,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Tags, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetTagsError::from_response(response))
         }
     }
@@ -13113,6 +13191,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13135,12 +13214,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUsageError::from_response(response))
         }
     }
@@ -13157,6 +13236,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13165,13 +13245,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUsagePlanError::from_response(response))
         }
     }
@@ -13189,6 +13269,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13197,13 +13278,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUsagePlanKeyError::from_response(response))
         }
     }
@@ -13220,6 +13301,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13240,13 +13322,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKeys, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUsagePlanKeysError::from_response(response))
         }
     }
@@ -13260,6 +13342,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13280,13 +13363,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlans, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUsagePlansError::from_response(response))
         }
     }
@@ -13300,6 +13383,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13308,13 +13392,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVpcLinkError::from_response(response))
         }
     }
@@ -13328,6 +13412,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13345,13 +13430,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<VpcLinks, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVpcLinksError::from_response(response))
         }
     }
@@ -13365,6 +13450,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13384,13 +13470,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ApiKeyIds, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ImportApiKeysError::from_response(response))
         }
     }
@@ -13406,6 +13492,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13426,13 +13513,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationPartIds, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ImportDocumentationPartsError::from_response(response))
         }
     }
@@ -13446,6 +13533,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13469,13 +13557,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ImportRestApiError::from_response(response))
         }
     }
@@ -13493,6 +13581,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13504,13 +13593,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutGatewayResponseError::from_response(response))
         }
     }
@@ -13529,6 +13618,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13540,13 +13630,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutIntegrationError::from_response(response))
         }
     }
@@ -13560,6 +13650,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13571,13 +13662,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<IntegrationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutIntegrationResponseError::from_response(response))
         }
     }
@@ -13596,6 +13687,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13607,12 +13699,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutMethodError::from_response(response))
         }
     }
@@ -13626,6 +13718,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13637,13 +13730,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutMethodResponseError::from_response(response))
         }
     }
@@ -13657,6 +13750,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13682,13 +13776,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutRestApiError::from_response(response))
         }
     }
@@ -13702,6 +13796,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13713,12 +13808,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -13736,6 +13831,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13747,13 +13843,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TestInvokeAuthorizerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TestInvokeAuthorizerError::from_response(response))
         }
     }
@@ -13772,6 +13868,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13783,13 +13880,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TestInvokeMethodResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TestInvokeMethodError::from_response(response))
         }
     }
@@ -13803,6 +13900,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13817,12 +13915,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -13836,6 +13934,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13847,13 +13946,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAccountError::from_response(response))
         }
     }
@@ -13867,6 +13966,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13878,12 +13978,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApiKeyError::from_response(response))
         }
     }
@@ -13901,6 +14001,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13912,13 +14013,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAuthorizerError::from_response(response))
         }
     }
@@ -13936,6 +14037,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13947,13 +14049,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateBasePathMappingError::from_response(response))
         }
     }
@@ -13970,6 +14072,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13981,13 +14084,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ClientCertificate, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateClientCertificateError::from_response(response))
         }
     }
@@ -14005,6 +14108,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14016,13 +14120,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeploymentError::from_response(response))
         }
     }
@@ -14039,6 +14143,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14050,13 +14155,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationPart, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDocumentationPartError::from_response(response))
         }
     }
@@ -14073,6 +14178,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14084,13 +14190,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DocumentationVersion, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDocumentationVersionError::from_response(response))
         }
     }
@@ -14107,6 +14213,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14118,13 +14225,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDomainNameError::from_response(response))
         }
     }
@@ -14142,6 +14249,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14153,13 +14261,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGatewayResponseError::from_response(response))
         }
     }
@@ -14178,6 +14286,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14189,13 +14298,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIntegrationError::from_response(response))
         }
     }
@@ -14209,6 +14318,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14220,13 +14330,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<IntegrationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIntegrationResponseError::from_response(response))
         }
     }
@@ -14245,6 +14355,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14256,12 +14367,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMethodError::from_response(response))
         }
     }
@@ -14275,6 +14386,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14286,13 +14398,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMethodResponseError::from_response(response))
         }
     }
@@ -14310,6 +14422,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14321,12 +14434,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateModelError::from_response(response))
         }
     }
@@ -14344,6 +14457,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14355,13 +14469,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RequestValidator, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRequestValidatorError::from_response(response))
         }
     }
@@ -14379,6 +14493,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14390,13 +14505,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateResourceError::from_response(response))
         }
     }
@@ -14410,6 +14525,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14421,13 +14537,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRestApiError::from_response(response))
         }
     }
@@ -14445,6 +14561,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14456,12 +14573,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateStageError::from_response(response))
         }
     }
@@ -14479,6 +14596,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14490,12 +14608,12 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUsageError::from_response(response))
         }
     }
@@ -14512,6 +14630,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14523,13 +14642,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUsagePlanError::from_response(response))
         }
     }
@@ -14543,6 +14662,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14554,13 +14674,13 @@ impl ApiGateway for ApiGatewayClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVpcLinkError::from_response(response))
         }
     }
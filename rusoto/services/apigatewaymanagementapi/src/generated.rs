@@ -286,6 +286,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -294,12 +295,12 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConnectionError::from_response(response))
         }
     }
@@ -316,6 +317,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -324,13 +326,13 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConnectionError::from_response(response))
         }
     }
@@ -347,6 +349,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("PostToConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.data.to_owned());
@@ -358,12 +361,12 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PostToConnectionError::from_response(response))
         }
     }
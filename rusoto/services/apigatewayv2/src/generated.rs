@@ -7382,6 +7382,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7393,13 +7394,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApiError::from_response(response))
         }
     }
@@ -7416,6 +7417,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7427,13 +7429,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApiMappingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApiMappingError::from_response(response))
         }
     }
@@ -7447,6 +7449,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7458,13 +7461,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateAuthorizerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAuthorizerError::from_response(response))
         }
     }
@@ -7478,6 +7481,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7489,13 +7493,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentError::from_response(response))
         }
     }
@@ -7509,6 +7513,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7520,13 +7525,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDomainNameResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDomainNameError::from_response(response))
         }
     }
@@ -7540,6 +7545,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7551,13 +7557,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIntegrationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIntegrationError::from_response(response))
         }
     }
@@ -7576,6 +7582,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7587,13 +7594,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIntegrationResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIntegrationResponseError::from_response(response))
         }
     }
@@ -7607,6 +7614,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7618,13 +7626,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateModelError::from_response(response))
         }
     }
@@ -7638,6 +7646,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7649,13 +7658,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRouteResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRouteError::from_response(response))
         }
     }
@@ -7673,6 +7682,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7684,13 +7694,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRouteResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRouteResponseError::from_response(response))
         }
     }
@@ -7704,6 +7714,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7715,13 +7726,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateStageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateStageError::from_response(response))
         }
     }
@@ -7735,6 +7746,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7746,13 +7758,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVpcLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVpcLinkError::from_response(response))
         }
     }
@@ -7770,6 +7782,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAccessLogSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7778,12 +7791,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAccessLogSettingsError::from_response(response))
         }
     }
@@ -7794,6 +7807,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7802,12 +7816,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApiError::from_response(response))
         }
     }
@@ -7825,6 +7839,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7833,12 +7848,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApiMappingError::from_response(response))
         }
     }
@@ -7856,6 +7871,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7864,12 +7880,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAuthorizerError::from_response(response))
         }
     }
@@ -7883,6 +7899,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/cors", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteCorsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7891,12 +7908,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteCorsConfigurationError::from_response(response))
         }
     }
@@ -7914,6 +7931,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7922,12 +7940,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDeploymentError::from_response(response))
         }
     }
@@ -7944,6 +7962,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7952,12 +7971,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDomainNameError::from_response(response))
         }
     }
@@ -7975,6 +7994,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7983,12 +8003,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntegrationError::from_response(response))
         }
     }
@@ -8002,6 +8022,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8010,12 +8031,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntegrationResponseError::from_response(response))
         }
     }
@@ -8033,6 +8054,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8041,12 +8063,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteModelError::from_response(response))
         }
     }
@@ -8064,6 +8086,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8072,12 +8095,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRouteError::from_response(response))
         }
     }
@@ -8096,6 +8119,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteRequestParameter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8104,12 +8128,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRouteRequestParameterError::from_response(response))
         }
     }
@@ -8128,6 +8152,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8136,12 +8161,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRouteResponseError::from_response(response))
         }
     }
@@ -8160,6 +8185,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8168,12 +8194,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRouteSettingsError::from_response(response))
         }
     }
@@ -8191,6 +8217,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8199,12 +8226,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteStageError::from_response(response))
         }
     }
@@ -8221,6 +8248,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8229,13 +8257,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteVpcLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVpcLinkError::from_response(response))
         }
     }
@@ -8252,6 +8280,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("ExportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8273,14 +8302,14 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = ExportApiResponse::default();
             result.body = Some(response.body);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ExportApiError::from_response(response))
         }
     }
@@ -8294,6 +8323,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8302,13 +8332,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiError::from_response(response))
         }
     }
@@ -8326,6 +8356,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8334,13 +8365,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetApiMappingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiMappingError::from_response(response))
         }
     }
@@ -8357,6 +8388,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8374,13 +8406,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetApiMappingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiMappingsError::from_response(response))
         }
     }
@@ -8394,6 +8426,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8411,13 +8444,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetApisResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApisError::from_response(response))
         }
     }
@@ -8435,6 +8468,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8443,13 +8477,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAuthorizerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAuthorizerError::from_response(response))
         }
     }
@@ -8463,6 +8497,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8480,13 +8515,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAuthorizersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAuthorizersError::from_response(response))
         }
     }
@@ -8504,6 +8539,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8512,13 +8548,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentError::from_response(response))
         }
     }
@@ -8532,6 +8568,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8549,13 +8586,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeploymentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentsError::from_response(response))
         }
     }
@@ -8572,6 +8609,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8580,13 +8618,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDomainNameResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainNameError::from_response(response))
         }
     }
@@ -8600,6 +8638,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8617,13 +8656,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDomainNamesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainNamesError::from_response(response))
         }
     }
@@ -8641,6 +8680,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8649,13 +8689,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntegrationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationError::from_response(response))
         }
     }
@@ -8669,6 +8709,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8677,13 +8718,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntegrationResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationResponseError::from_response(response))
         }
     }
@@ -8701,6 +8742,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8718,13 +8760,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntegrationResponsesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationResponsesError::from_response(response))
         }
     }
@@ -8738,6 +8780,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8755,13 +8798,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntegrationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntegrationsError::from_response(response))
         }
     }
@@ -8779,6 +8822,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8787,13 +8831,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelError::from_response(response))
         }
     }
@@ -8811,6 +8855,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8819,13 +8864,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetModelTemplateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelTemplateError::from_response(response))
         }
     }
@@ -8839,6 +8884,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8856,13 +8902,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetModelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetModelsError::from_response(response))
         }
     }
@@ -8880,6 +8926,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8888,13 +8935,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetRouteResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRouteError::from_response(response))
         }
     }
@@ -8913,6 +8960,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8921,13 +8969,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRouteResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRouteResponseError::from_response(response))
         }
     }
@@ -8945,6 +8993,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8962,13 +9011,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRouteResponsesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRouteResponsesError::from_response(response))
         }
     }
@@ -8982,6 +9031,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8999,13 +9049,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRoutesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRoutesError::from_response(response))
         }
     }
@@ -9023,6 +9073,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9031,13 +9082,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetStageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetStageError::from_response(response))
         }
     }
@@ -9051,6 +9102,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9068,13 +9120,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetStagesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetStagesError::from_response(response))
         }
     }
@@ -9088,6 +9140,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9096,13 +9149,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetTagsError::from_response(response))
         }
     }
@@ -9119,6 +9172,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9127,13 +9181,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVpcLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVpcLinkError::from_response(response))
         }
     }
@@ -9147,6 +9201,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9164,13 +9219,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVpcLinksResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVpcLinksError::from_response(response))
         }
     }
@@ -9184,6 +9239,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9204,13 +9260,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ImportApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ImportApiError::from_response(response))
         }
     }
@@ -9224,6 +9280,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ReimportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9244,13 +9301,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ReimportApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ReimportApiError::from_response(response))
         }
     }
@@ -9264,6 +9321,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9275,13 +9333,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -9295,6 +9353,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9309,12 +9368,12 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -9328,6 +9387,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9339,13 +9399,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApiError::from_response(response))
         }
     }
@@ -9363,6 +9423,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9374,13 +9435,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateApiMappingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApiMappingError::from_response(response))
         }
     }
@@ -9398,6 +9459,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9409,13 +9471,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateAuthorizerResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAuthorizerError::from_response(response))
         }
     }
@@ -9433,6 +9495,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9444,13 +9507,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeploymentError::from_response(response))
         }
     }
@@ -9467,6 +9530,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9478,13 +9542,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDomainNameResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDomainNameError::from_response(response))
         }
     }
@@ -9502,6 +9566,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9513,13 +9578,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateIntegrationResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIntegrationError::from_response(response))
         }
     }
@@ -9534,6 +9599,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9545,13 +9611,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateIntegrationResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIntegrationResponseError::from_response(response))
         }
     }
@@ -9569,6 +9635,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9580,13 +9647,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateModelError::from_response(response))
         }
     }
@@ -9604,6 +9671,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9615,13 +9683,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRouteResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRouteError::from_response(response))
         }
     }
@@ -9640,6 +9708,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9651,13 +9720,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRouteResponseResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRouteResponseError::from_response(response))
         }
     }
@@ -9675,6 +9744,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9686,13 +9756,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateStageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateStageError::from_response(response))
         }
     }
@@ -9709,6 +9779,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9720,13 +9791,13 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVpcLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVpcLinkError::from_response(response))
         }
     }
@@ -2786,6 +2786,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2797,13 +2798,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApplicationError::from_response(response))
         }
     }
@@ -2820,6 +2821,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2831,13 +2833,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigurationProfile, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConfigurationProfileError::from_response(response))
         }
     }
@@ -2851,6 +2853,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2862,13 +2865,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeploymentStrategy, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentStrategyError::from_response(response))
         }
     }
@@ -2885,6 +2888,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2896,13 +2900,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateEnvironmentError::from_response(response))
         }
     }
@@ -2917,6 +2921,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateHostedConfigurationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.content.to_owned());
@@ -2934,7 +2939,7 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = HostedConfigurationVersion::default();
             result.content = Some(response.body);
@@ -2950,7 +2955,7 @@ impl AppConfig for AppConfigClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateHostedConfigurationVersionError::from_response(
                 response,
             ))
@@ -2969,6 +2974,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2977,12 +2983,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApplicationError::from_response(response))
         }
     }
@@ -3000,6 +3006,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3008,12 +3015,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConfigurationProfileError::from_response(response))
         }
     }
@@ -3030,6 +3037,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3038,12 +3046,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDeploymentStrategyError::from_response(response))
         }
     }
@@ -3061,6 +3069,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3069,12 +3078,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteEnvironmentError::from_response(response))
         }
     }
@@ -3088,6 +3097,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteHostedConfigurationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3096,12 +3106,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteHostedConfigurationVersionError::from_response(
                 response,
             ))
@@ -3120,6 +3130,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3128,13 +3139,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApplicationError::from_response(response))
         }
     }
@@ -3153,6 +3164,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3168,7 +3180,7 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = Configuration::default();
             result.content = Some(response.body);
@@ -3178,7 +3190,7 @@ impl AppConfig for AppConfigClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConfigurationError::from_response(response))
         }
     }
@@ -3196,6 +3208,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3204,13 +3217,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigurationProfile, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConfigurationProfileError::from_response(response))
         }
     }
@@ -3224,6 +3237,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3232,13 +3246,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentError::from_response(response))
         }
     }
@@ -3255,6 +3269,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3263,13 +3278,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeploymentStrategy, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentStrategyError::from_response(response))
         }
     }
@@ -3287,6 +3302,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3295,13 +3311,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetEnvironmentError::from_response(response))
         }
     }
@@ -3315,6 +3331,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetHostedConfigurationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3323,7 +3340,7 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = HostedConfigurationVersion::default();
             result.content = Some(response.body);
@@ -3339,7 +3356,7 @@ impl AppConfig for AppConfigClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetHostedConfigurationVersionError::from_response(response))
         }
     }
@@ -3353,6 +3370,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3370,13 +3388,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Applications, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListApplicationsError::from_response(response))
         }
     }
@@ -3393,6 +3411,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListConfigurationProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3410,13 +3429,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigurationProfiles, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListConfigurationProfilesError::from_response(response))
         }
     }
@@ -3430,6 +3449,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeploymentStrategies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3447,13 +3467,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeploymentStrategies, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeploymentStrategiesError::from_response(response))
         }
     }
@@ -3471,6 +3491,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3488,13 +3509,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeploymentsError::from_response(response))
         }
     }
@@ -3511,6 +3532,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3528,13 +3550,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Environments, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEnvironmentsError::from_response(response))
         }
     }
@@ -3549,6 +3571,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListHostedConfigurationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3566,13 +3589,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<HostedConfigurationVersions, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListHostedConfigurationVersionsError::from_response(
                 response,
             ))
@@ -3588,6 +3611,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3596,13 +3620,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ResourceTags, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3620,6 +3644,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3631,13 +3656,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartDeploymentError::from_response(response))
         }
     }
@@ -3651,6 +3676,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("StopDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3659,13 +3685,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopDeploymentError::from_response(response))
         }
     }
@@ -3679,6 +3705,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3690,12 +3717,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3709,6 +3736,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3723,12 +3751,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3745,6 +3773,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3756,13 +3785,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApplicationError::from_response(response))
         }
     }
@@ -3780,6 +3809,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3791,13 +3821,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigurationProfile, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateConfigurationProfileError::from_response(response))
         }
     }
@@ -3814,6 +3844,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3825,13 +3856,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeploymentStrategy, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeploymentStrategyError::from_response(response))
         }
     }
@@ -3849,6 +3880,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3860,13 +3892,13 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateEnvironmentError::from_response(response))
         }
     }
@@ -3880,6 +3912,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/validators", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("ValidateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3892,12 +3925,12 @@ impl AppConfig for AppConfigClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ValidateConfigurationError::from_response(response))
         }
     }
@@ -46,7 +46,7 @@ impl ApplicationAutoScalingClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1304,7 +1304,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DeleteScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteScalingPolicyResponse, _>()
     }
 
@@ -1325,7 +1325,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DeleteScheduledActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteScheduledActionResponse, _>()
     }
@@ -1347,7 +1347,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DeregisterScalableTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterScalableTargetResponse, _>()
     }
@@ -1369,7 +1369,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DescribeScalableTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalableTargetsResponse, _>()
     }
@@ -1392,7 +1392,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DescribeScalingActivitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalingActivitiesResponse, _>()
     }
@@ -1414,7 +1414,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DescribeScalingPoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalingPoliciesResponse, _>()
     }
@@ -1436,7 +1436,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, DescribeScheduledActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScheduledActionsResponse, _>()
     }
@@ -1455,7 +1455,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, PutScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutScalingPolicyResponse, _>()
     }
 
@@ -1473,7 +1473,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, PutScheduledActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutScheduledActionResponse, _>()
     }
 
@@ -1494,7 +1494,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .sign_and_dispatch(request, RegisterScalableTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterScalableTargetResponse, _>()
     }
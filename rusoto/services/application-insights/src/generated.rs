@@ -46,7 +46,7 @@ impl ApplicationInsightsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2309,7 +2309,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, CreateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationResponse, _>()
     }
 
@@ -2327,7 +2327,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, CreateComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateComponentResponse, _>()
     }
 
@@ -2345,7 +2345,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, CreateLogPatternError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLogPatternResponse, _>()
     }
 
@@ -2363,7 +2363,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DeleteApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteApplicationResponse, _>()
     }
 
@@ -2381,7 +2381,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DeleteComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteComponentResponse, _>()
     }
 
@@ -2399,7 +2399,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DeleteLogPatternError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLogPatternResponse, _>()
     }
 
@@ -2420,7 +2420,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeApplicationResponse, _>()
     }
 
@@ -2438,7 +2438,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeComponentResponse, _>()
     }
 
@@ -2462,7 +2462,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeComponentConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeComponentConfigurationResponse, _>()
     }
@@ -2490,7 +2490,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeComponentConfigurationRecommendationResponse, _>()
     }
@@ -2509,7 +2509,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeLogPatternError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLogPatternResponse, _>()
     }
 
@@ -2530,7 +2530,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeObservationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeObservationResponse, _>()
     }
 
@@ -2548,7 +2548,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeProblemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProblemResponse, _>()
     }
 
@@ -2570,7 +2570,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, DescribeProblemObservationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProblemObservationsResponse, _>()
     }
@@ -2589,7 +2589,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationsResponse, _>()
     }
 
@@ -2607,7 +2607,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListComponentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListComponentsResponse, _>()
     }
 
@@ -2628,7 +2628,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListConfigurationHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListConfigurationHistoryResponse, _>()
     }
@@ -2647,7 +2647,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListLogPatternSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLogPatternSetsResponse, _>()
     }
 
@@ -2665,7 +2665,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListLogPatternsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLogPatternsResponse, _>()
     }
 
@@ -2683,7 +2683,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListProblemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProblemsResponse, _>()
     }
 
@@ -2704,7 +2704,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2722,7 +2722,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2740,7 +2740,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2758,7 +2758,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, UpdateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 
@@ -2776,7 +2776,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, UpdateComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateComponentResponse, _>()
     }
 
@@ -2798,7 +2798,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, UpdateComponentConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateComponentConfigurationResponse, _>()
     }
@@ -2817,7 +2817,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
             .sign_and_dispatch(request, UpdateLogPatternError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateLogPatternResponse, _>()
     }
 }
@@ -6051,6 +6051,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6068,13 +6069,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGatewayRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGatewayRouteError::from_response(response))
         }
     }
@@ -6095,6 +6096,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6106,13 +6108,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMeshOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMeshError::from_response(response))
         }
     }
@@ -6135,6 +6137,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6152,13 +6155,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRouteError::from_response(response))
         }
     }
@@ -6182,6 +6185,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6199,13 +6203,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVirtualGatewayOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVirtualGatewayError::from_response(response))
         }
     }
@@ -6244,6 +6248,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6261,13 +6266,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVirtualNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVirtualNodeError::from_response(response))
         }
     }
@@ -6292,6 +6297,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6309,13 +6315,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVirtualRouterOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVirtualRouterError::from_response(response))
         }
     }
@@ -6340,6 +6346,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6357,13 +6364,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVirtualServiceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVirtualServiceError::from_response(response))
         }
     }
@@ -6377,6 +6384,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6391,13 +6399,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGatewayRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGatewayRouteError::from_response(response))
         }
     }
@@ -6415,6 +6423,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6423,13 +6432,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMeshOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMeshError::from_response(response))
         }
     }
@@ -6448,6 +6457,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6462,13 +6472,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRouteError::from_response(response))
         }
     }
@@ -6487,6 +6497,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6501,13 +6512,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteVirtualGatewayOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVirtualGatewayError::from_response(response))
         }
     }
@@ -6529,6 +6540,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6543,13 +6555,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteVirtualNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVirtualNodeError::from_response(response))
         }
     }
@@ -6571,6 +6583,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6585,13 +6598,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteVirtualRouterOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVirtualRouterError::from_response(response))
         }
     }
@@ -6609,6 +6622,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6623,13 +6637,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteVirtualServiceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVirtualServiceError::from_response(response))
         }
     }
@@ -6643,6 +6657,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6657,13 +6672,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeGatewayRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeGatewayRouteError::from_response(response))
         }
     }
@@ -6677,6 +6692,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6691,13 +6707,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeMeshOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeMeshError::from_response(response))
         }
     }
@@ -6716,6 +6732,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6730,13 +6747,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRouteError::from_response(response))
         }
     }
@@ -6754,6 +6771,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6768,13 +6786,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVirtualGatewayOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeVirtualGatewayError::from_response(response))
         }
     }
@@ -6792,6 +6810,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6806,13 +6825,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVirtualNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeVirtualNodeError::from_response(response))
         }
     }
@@ -6830,6 +6849,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6844,13 +6864,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVirtualRouterOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeVirtualRouterError::from_response(response))
         }
     }
@@ -6868,6 +6888,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6882,13 +6903,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVirtualServiceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeVirtualServiceError::from_response(response))
         }
     }
@@ -6907,6 +6928,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListGatewayRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6927,13 +6949,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGatewayRoutesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGatewayRoutesError::from_response(response))
         }
     }
@@ -6947,6 +6969,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListMeshes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6964,13 +6987,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMeshesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMeshesError::from_response(response))
         }
     }
@@ -6988,6 +7011,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7008,13 +7032,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRoutesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRoutesError::from_response(response))
         }
     }
@@ -7028,6 +7052,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tags";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7046,13 +7071,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -7069,6 +7094,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualGateways");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7089,13 +7115,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVirtualGatewaysOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVirtualGatewaysError::from_response(response))
         }
     }
@@ -7112,6 +7138,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7132,13 +7159,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVirtualNodesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVirtualNodesError::from_response(response))
         }
     }
@@ -7155,6 +7182,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualRouters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7175,13 +7203,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVirtualRoutersOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVirtualRoutersError::from_response(response))
         }
     }
@@ -7198,6 +7226,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualServices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7218,13 +7247,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVirtualServicesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVirtualServicesError::from_response(response))
         }
     }
@@ -7241,6 +7270,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7256,13 +7286,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -7276,6 +7306,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/untag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7291,13 +7322,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -7312,6 +7343,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7329,13 +7361,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGatewayRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGatewayRouteError::from_response(response))
         }
     }
@@ -7349,6 +7381,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7360,13 +7393,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateMeshOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMeshError::from_response(response))
         }
     }
@@ -7385,6 +7418,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7402,13 +7436,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRouteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRouteError::from_response(response))
         }
     }
@@ -7426,6 +7460,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7443,13 +7478,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVirtualGatewayOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVirtualGatewayError::from_response(response))
         }
     }
@@ -7467,6 +7502,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7484,13 +7520,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVirtualNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVirtualNodeError::from_response(response))
         }
     }
@@ -7508,6 +7544,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7525,13 +7562,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVirtualRouterOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVirtualRouterError::from_response(response))
         }
     }
@@ -7549,6 +7586,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7566,13 +7604,13 @@ impl AppMesh for AppMeshClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVirtualServiceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVirtualServiceError::from_response(response))
         }
     }
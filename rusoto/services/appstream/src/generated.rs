@@ -42,7 +42,7 @@ impl AppStreamClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4467,7 +4467,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, AssociateFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateFleetResult, _>()
     }
 
@@ -4488,7 +4488,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, BatchAssociateUserStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchAssociateUserStackResult, _>()
     }
@@ -4511,7 +4511,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, BatchDisassociateUserStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDisassociateUserStackResult, _>()
     }
@@ -4530,7 +4530,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CopyImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CopyImageResponse, _>()
     }
 
@@ -4551,7 +4551,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateDirectoryConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDirectoryConfigResult, _>()
     }
 
@@ -4569,7 +4569,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFleetResult, _>()
     }
 
@@ -4587,7 +4587,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateImageBuilderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateImageBuilderResult, _>()
     }
 
@@ -4611,7 +4611,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateImageBuilderStreamingURLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateImageBuilderStreamingURLResult, _>()
     }
@@ -4630,7 +4630,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateStackResult, _>()
     }
 
@@ -4648,7 +4648,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateStreamingURLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateStreamingURLResult, _>()
     }
 
@@ -4668,7 +4668,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateUsageReportSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateUsageReportSubscriptionResult, _>()
     }
@@ -4687,7 +4687,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, CreateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResult, _>()
     }
 
@@ -4708,7 +4708,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteDirectoryConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDirectoryConfigResult, _>()
     }
 
@@ -4726,7 +4726,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFleetResult, _>()
     }
 
@@ -4744,7 +4744,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteImageResult, _>()
     }
 
@@ -4762,7 +4762,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteImageBuilderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteImageBuilderResult, _>()
     }
 
@@ -4783,7 +4783,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteImagePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteImagePermissionsResult, _>()
     }
@@ -4802,7 +4802,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteStackResult, _>()
     }
 
@@ -4822,7 +4822,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteUsageReportSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteUsageReportSubscriptionResult, _>()
     }
@@ -4841,7 +4841,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DeleteUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserResult, _>()
     }
 
@@ -4862,7 +4862,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeDirectoryConfigsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDirectoryConfigsResult, _>()
     }
@@ -4881,7 +4881,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeFleetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFleetsResult, _>()
     }
 
@@ -4902,7 +4902,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeImageBuildersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeImageBuildersResult, _>()
     }
 
@@ -4923,7 +4923,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeImagePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeImagePermissionsResult, _>()
     }
@@ -4942,7 +4942,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeImagesResult, _>()
     }
 
@@ -4960,7 +4960,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSessionsResult, _>()
     }
 
@@ -4978,7 +4978,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeStacksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStacksResult, _>()
     }
 
@@ -5005,7 +5005,7 @@ impl AppStream for AppStreamClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeUsageReportSubscriptionsResult, _>()
     }
@@ -5028,7 +5028,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeUserStackAssociationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeUserStackAssociationsResult, _>()
     }
@@ -5047,7 +5047,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DescribeUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUsersResult, _>()
     }
 
@@ -5065,7 +5065,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DisableUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableUserResult, _>()
     }
 
@@ -5083,7 +5083,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, DisassociateFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisassociateFleetResult, _>()
     }
 
@@ -5101,7 +5101,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, EnableUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableUserResult, _>()
     }
 
@@ -5119,7 +5119,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, ExpireSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ExpireSessionResult, _>()
     }
 
@@ -5140,7 +5140,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, ListAssociatedFleetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAssociatedFleetsResult, _>()
     }
 
@@ -5161,7 +5161,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, ListAssociatedStacksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAssociatedStacksResult, _>()
     }
 
@@ -5182,7 +5182,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -5200,7 +5200,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, StartFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartFleetResult, _>()
     }
 
@@ -5218,7 +5218,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, StartImageBuilderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartImageBuilderResult, _>()
     }
 
@@ -5236,7 +5236,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, StopFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopFleetResult, _>()
     }
 
@@ -5254,7 +5254,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, StopImageBuilderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopImageBuilderResult, _>()
     }
 
@@ -5272,7 +5272,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -5290,7 +5290,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -5311,7 +5311,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, UpdateDirectoryConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDirectoryConfigResult, _>()
     }
 
@@ -5329,7 +5329,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, UpdateFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFleetResult, _>()
     }
 
@@ -5350,7 +5350,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, UpdateImagePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateImagePermissionsResult, _>()
     }
@@ -5369,7 +5369,7 @@ impl AppStream for AppStreamClient {
             .sign_and_dispatch(request, UpdateStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStackResult, _>()
     }
 }
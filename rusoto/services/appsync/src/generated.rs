@@ -4142,6 +4142,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4153,13 +4154,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApiCacheResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApiCacheError::from_response(response))
         }
     }
@@ -4173,6 +4174,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4184,13 +4186,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApiKeyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApiKeyError::from_response(response))
         }
     }
@@ -4204,6 +4206,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4215,13 +4218,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDataSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDataSourceError::from_response(response))
         }
     }
@@ -4235,6 +4238,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4246,13 +4250,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFunctionError::from_response(response))
         }
     }
@@ -4266,6 +4270,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4277,13 +4282,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGraphqlApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGraphqlApiError::from_response(response))
         }
     }
@@ -4301,6 +4306,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4312,13 +4318,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateResolverResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateResolverError::from_response(response))
         }
     }
@@ -4332,6 +4338,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4343,13 +4350,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTypeError::from_response(response))
         }
     }
@@ -4363,6 +4370,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4371,13 +4379,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteApiCacheResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApiCacheError::from_response(response))
         }
     }
@@ -4395,6 +4403,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4403,13 +4412,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteApiKeyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApiKeyError::from_response(response))
         }
     }
@@ -4427,6 +4436,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4435,13 +4445,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDataSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDataSourceError::from_response(response))
         }
     }
@@ -4459,6 +4469,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4467,13 +4478,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFunctionError::from_response(response))
         }
     }
@@ -4487,6 +4498,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4495,13 +4507,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGraphqlApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGraphqlApiError::from_response(response))
         }
     }
@@ -4520,6 +4532,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4528,13 +4541,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteResolverResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteResolverError::from_response(response))
         }
     }
@@ -4552,6 +4565,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4560,13 +4574,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTypeError::from_response(response))
         }
     }
@@ -4580,6 +4594,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/FlushCache", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("FlushApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4588,13 +4603,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FlushApiCacheResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(FlushApiCacheError::from_response(response))
         }
     }
@@ -4608,6 +4623,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4616,13 +4632,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetApiCacheResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApiCacheError::from_response(response))
         }
     }
@@ -4640,6 +4656,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4648,13 +4665,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDataSourceError::from_response(response))
         }
     }
@@ -4672,6 +4689,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4680,13 +4698,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionError::from_response(response))
         }
     }
@@ -4700,6 +4718,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4708,13 +4727,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGraphqlApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGraphqlApiError::from_response(response))
         }
     }
@@ -4728,6 +4747,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetIntrospectionSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4743,14 +4763,14 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetIntrospectionSchemaResponse::default();
             result.schema = Some(response.body);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntrospectionSchemaError::from_response(response))
         }
     }
@@ -4769,6 +4789,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4777,13 +4798,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetResolverResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResolverError::from_response(response))
         }
     }
@@ -4797,6 +4818,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetSchemaCreationStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4805,13 +4827,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSchemaCreationStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSchemaCreationStatusError::from_response(response))
         }
     }
@@ -4829,6 +4851,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4841,13 +4864,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetTypeError::from_response(response))
         }
     }
@@ -4861,6 +4884,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4878,13 +4902,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListApiKeysResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListApiKeysError::from_response(response))
         }
     }
@@ -4898,6 +4922,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4915,13 +4940,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataSourcesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataSourcesError::from_response(response))
         }
     }
@@ -4935,6 +4960,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4952,13 +4978,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFunctionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFunctionsError::from_response(response))
         }
     }
@@ -4972,6 +4998,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListGraphqlApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4989,13 +5016,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGraphqlApisResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGraphqlApisError::from_response(response))
         }
     }
@@ -5013,6 +5040,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolvers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5030,13 +5058,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListResolversResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListResolversError::from_response(response))
         }
     }
@@ -5054,6 +5082,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolversByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5071,13 +5100,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListResolversByFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListResolversByFunctionError::from_response(response))
         }
     }
@@ -5091,6 +5120,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5099,13 +5129,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -5119,6 +5149,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5137,13 +5168,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTypesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTypesError::from_response(response))
         }
     }
@@ -5157,6 +5188,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("StartSchemaCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5168,13 +5200,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartSchemaCreationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartSchemaCreationError::from_response(response))
         }
     }
@@ -5188,6 +5220,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5199,13 +5232,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -5219,6 +5252,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5233,13 +5267,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -5253,6 +5287,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches/update", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5264,13 +5299,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateApiCacheResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApiCacheError::from_response(response))
         }
     }
@@ -5288,6 +5323,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5299,13 +5335,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateApiKeyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApiKeyError::from_response(response))
         }
     }
@@ -5323,6 +5359,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5334,13 +5371,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSourceError::from_response(response))
         }
     }
@@ -5358,6 +5395,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5369,13 +5407,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFunctionError::from_response(response))
         }
     }
@@ -5389,6 +5427,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5400,13 +5439,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGraphqlApiResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGraphqlApiError::from_response(response))
         }
     }
@@ -5425,6 +5464,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5436,13 +5476,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateResolverResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateResolverError::from_response(response))
         }
     }
@@ -5460,6 +5500,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5471,13 +5512,13 @@ impl AppSync for AppSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateTypeError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl AthenaClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2498,7 +2498,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, BatchGetNamedQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetNamedQueryOutput, _>()
     }
 
@@ -2516,7 +2516,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, BatchGetQueryExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetQueryExecutionOutput, _>()
     }
@@ -2535,7 +2535,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, CreateDataCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDataCatalogOutput, _>()
     }
 
@@ -2553,7 +2553,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, CreateNamedQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateNamedQueryOutput, _>()
     }
 
@@ -2571,7 +2571,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, CreateWorkGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkGroupOutput, _>()
     }
 
@@ -2589,7 +2589,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, DeleteDataCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDataCatalogOutput, _>()
     }
 
@@ -2607,7 +2607,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, DeleteNamedQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamedQueryOutput, _>()
     }
 
@@ -2625,7 +2625,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, DeleteWorkGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkGroupOutput, _>()
     }
 
@@ -2643,7 +2643,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetDataCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDataCatalogOutput, _>()
     }
 
@@ -2661,7 +2661,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDatabaseOutput, _>()
     }
 
@@ -2679,7 +2679,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetNamedQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetNamedQueryOutput, _>()
     }
 
@@ -2697,7 +2697,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetQueryExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetQueryExecutionOutput, _>()
     }
 
@@ -2715,7 +2715,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetQueryResultsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetQueryResultsOutput, _>()
     }
 
@@ -2733,7 +2733,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetTableMetadataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTableMetadataOutput, _>()
     }
 
@@ -2751,7 +2751,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, GetWorkGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWorkGroupOutput, _>()
     }
 
@@ -2769,7 +2769,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListDataCatalogsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDataCatalogsOutput, _>()
     }
 
@@ -2787,7 +2787,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListDatabasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDatabasesOutput, _>()
     }
 
@@ -2805,7 +2805,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListNamedQueriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListNamedQueriesOutput, _>()
     }
 
@@ -2823,7 +2823,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListQueryExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListQueryExecutionsOutput, _>()
     }
 
@@ -2841,7 +2841,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListTableMetadataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTableMetadataOutput, _>()
     }
 
@@ -2859,7 +2859,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -2877,7 +2877,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, ListWorkGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWorkGroupsOutput, _>()
     }
 
@@ -2895,7 +2895,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, StartQueryExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartQueryExecutionOutput, _>()
     }
 
@@ -2913,7 +2913,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, StopQueryExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopQueryExecutionOutput, _>()
     }
 
@@ -2931,7 +2931,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -2949,7 +2949,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 
@@ -2967,7 +2967,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, UpdateDataCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDataCatalogOutput, _>()
     }
 
@@ -2985,7 +2985,7 @@ impl Athena for AthenaClient {
             .sign_and_dispatch(request, UpdateWorkGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkGroupOutput, _>()
     }
 }
@@ -42,7 +42,7 @@ impl AutoscalingPlansClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -868,7 +868,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             .sign_and_dispatch(request, CreateScalingPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateScalingPlanResponse, _>()
     }
 
@@ -889,7 +889,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             .sign_and_dispatch(request, DeleteScalingPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteScalingPlanResponse, _>()
     }
 
@@ -911,7 +911,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             .sign_and_dispatch(request, DescribeScalingPlanResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalingPlanResourcesResponse, _>()
     }
@@ -933,7 +933,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             .sign_and_dispatch(request, DescribeScalingPlansError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalingPlansResponse, _>()
     }
@@ -961,7 +961,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetScalingPlanResourceForecastDataResponse, _>()
     }
@@ -983,7 +983,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
             .sign_and_dispatch(request, UpdateScalingPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateScalingPlanResponse, _>()
     }
 }
@@ -52,7 +52,7 @@ impl AutoscalingClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7461,9 +7461,9 @@ impl XmlStringUserDataDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AttachInstancesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl AttachInstancesError {
@@ -7476,12 +7476,14 @@ impl AttachInstancesError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(AttachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
-                            AttachInstancesError::ServiceLinkedRoleFailure(parsed_error.message),
+                            AttachInstancesError::ServiceLinkedRoleFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7498,6 +7500,14 @@ impl AttachInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachInstancesError::ResourceContentionFault(ref cause) => cause.request_id(),
+            AttachInstancesError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachInstancesError {
     #[allow(unused_variables)]
@@ -7513,9 +7523,9 @@ impl Error for AttachInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl AttachLoadBalancerTargetGroupsError {
@@ -7531,14 +7541,14 @@ impl AttachLoadBalancerTargetGroupsError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             AttachLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
                             AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7556,6 +7566,18 @@ impl AttachLoadBalancerTargetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachLoadBalancerTargetGroupsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+            AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AttachLoadBalancerTargetGroupsError {
     #[allow(unused_variables)]
@@ -7575,9 +7597,9 @@ impl Error for AttachLoadBalancerTargetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl AttachLoadBalancersError {
@@ -7590,13 +7612,15 @@ impl AttachLoadBalancersError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            AttachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                            AttachLoadBalancersError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
                             AttachLoadBalancersError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7614,6 +7638,14 @@ impl AttachLoadBalancersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachLoadBalancersError::ResourceContentionFault(ref cause) => cause.request_id(),
+            AttachLoadBalancersError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachLoadBalancersError {
     #[allow(unused_variables)]
@@ -7629,7 +7661,7 @@ impl Error for AttachLoadBalancersError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteScheduledActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl BatchDeleteScheduledActionError {
@@ -7645,7 +7677,7 @@ impl BatchDeleteScheduledActionError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             BatchDeleteScheduledActionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7663,6 +7695,15 @@ impl BatchDeleteScheduledActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchDeleteScheduledActionError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for BatchDeleteScheduledActionError {
     #[allow(unused_variables)]
@@ -7679,11 +7720,11 @@ impl Error for BatchDeleteScheduledActionError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchPutScheduledUpdateGroupActionError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
-    AlreadyExistsFault(String),
+    AlreadyExistsFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl BatchPutScheduledUpdateGroupActionError {
@@ -7699,21 +7740,21 @@ impl BatchPutScheduledUpdateGroupActionError {
                     "AlreadyExists" => {
                         return RusotoError::Service(
                             BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             BatchPutScheduledUpdateGroupActionError::LimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7731,6 +7772,21 @@ impl BatchPutScheduledUpdateGroupActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            BatchPutScheduledUpdateGroupActionError::LimitExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for BatchPutScheduledUpdateGroupActionError {
     #[allow(unused_variables)]
@@ -7753,11 +7809,11 @@ impl Error for BatchPutScheduledUpdateGroupActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CancelInstanceRefreshError {
     /// <p>The request failed because an active instance refresh for the specified Auto Scaling group was not found. </p>
-    ActiveInstanceRefreshNotFoundFault(String),
+    ActiveInstanceRefreshNotFoundFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl CancelInstanceRefreshError {
@@ -7771,19 +7827,21 @@ impl CancelInstanceRefreshError {
                     "ActiveInstanceRefreshNotFound" => {
                         return RusotoError::Service(
                             CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            CancelInstanceRefreshError::LimitExceededFault(parsed_error.message),
+                            CancelInstanceRefreshError::LimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             CancelInstanceRefreshError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7801,6 +7859,17 @@ impl CancelInstanceRefreshError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CancelInstanceRefreshError::LimitExceededFault(ref cause) => cause.request_id(),
+            CancelInstanceRefreshError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CancelInstanceRefreshError {
     #[allow(unused_variables)]
@@ -7821,7 +7890,7 @@ impl Error for CancelInstanceRefreshError {}
 #[derive(Debug, PartialEq)]
 pub enum CompleteLifecycleActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl CompleteLifecycleActionError {
@@ -7835,7 +7904,7 @@ impl CompleteLifecycleActionError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             CompleteLifecycleActionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7853,6 +7922,13 @@ impl CompleteLifecycleActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CompleteLifecycleActionError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CompleteLifecycleActionError {
     #[allow(unused_variables)]
@@ -7869,13 +7945,13 @@ impl Error for CompleteLifecycleActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateAutoScalingGroupError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
-    AlreadyExistsFault(String),
+    AlreadyExistsFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl CreateAutoScalingGroupError {
@@ -7888,25 +7964,29 @@ impl CreateAutoScalingGroupError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(
-                            CreateAutoScalingGroupError::AlreadyExistsFault(parsed_error.message),
+                            CreateAutoScalingGroupError::AlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            CreateAutoScalingGroupError::LimitExceededFault(parsed_error.message),
+                            CreateAutoScalingGroupError::LimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             CreateAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
                             CreateAutoScalingGroupError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7924,6 +8004,16 @@ impl CreateAutoScalingGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateAutoScalingGroupError::AlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateAutoScalingGroupError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateAutoScalingGroupError::ResourceContentionFault(ref cause) => cause.request_id(),
+            CreateAutoScalingGroupError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateAutoScalingGroupError {
     #[allow(unused_variables)]
@@ -7945,11 +8035,11 @@ impl Error for CreateAutoScalingGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLaunchConfigurationError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
-    AlreadyExistsFault(String),
+    AlreadyExistsFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl CreateLaunchConfigurationError {
@@ -7963,21 +8053,21 @@ impl CreateLaunchConfigurationError {
                     "AlreadyExists" => {
                         return RusotoError::Service(
                             CreateLaunchConfigurationError::AlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             CreateLaunchConfigurationError::LimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             CreateLaunchConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7995,6 +8085,17 @@ impl CreateLaunchConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLaunchConfigurationError::AlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateLaunchConfigurationError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateLaunchConfigurationError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateLaunchConfigurationError {
     #[allow(unused_variables)]
@@ -8013,13 +8114,13 @@ impl Error for CreateLaunchConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateOrUpdateTagsError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
-    AlreadyExistsFault(String),
+    AlreadyExistsFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
 }
 
 impl CreateOrUpdateTagsError {
@@ -8032,22 +8133,24 @@ impl CreateOrUpdateTagsError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CreateOrUpdateTagsError::AlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateOrUpdateTagsError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            CreateOrUpdateTagsError::ResourceContentionFault(parsed_error.message),
+                            CreateOrUpdateTagsError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(CreateOrUpdateTagsError::ResourceInUseFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8064,6 +8167,16 @@ impl CreateOrUpdateTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateOrUpdateTagsError::AlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateOrUpdateTagsError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateOrUpdateTagsError::ResourceContentionFault(ref cause) => cause.request_id(),
+            CreateOrUpdateTagsError::ResourceInUseFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateOrUpdateTagsError {
     #[allow(unused_variables)]
@@ -8081,11 +8194,11 @@ impl Error for CreateOrUpdateTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
-    ScalingActivityInProgressFault(String),
+    ScalingActivityInProgressFault(XmlErrorMessage),
 }
 
 impl DeleteAutoScalingGroupError {
@@ -8099,19 +8212,21 @@ impl DeleteAutoScalingGroupError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DeleteAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(
-                            DeleteAutoScalingGroupError::ResourceInUseFault(parsed_error.message),
+                            DeleteAutoScalingGroupError::ResourceInUseFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ScalingActivityInProgress" => {
                         return RusotoError::Service(
                             DeleteAutoScalingGroupError::ScalingActivityInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8129,6 +8244,17 @@ impl DeleteAutoScalingGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAutoScalingGroupError::ResourceContentionFault(ref cause) => cause.request_id(),
+            DeleteAutoScalingGroupError::ResourceInUseFault(ref cause) => cause.request_id(),
+            DeleteAutoScalingGroupError::ScalingActivityInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteAutoScalingGroupError {
     #[allow(unused_variables)]
@@ -8149,9 +8275,9 @@ impl Error for DeleteAutoScalingGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLaunchConfigurationError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
 }
 
 impl DeleteLaunchConfigurationError {
@@ -8165,14 +8291,14 @@ impl DeleteLaunchConfigurationError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DeleteLaunchConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(
                             DeleteLaunchConfigurationError::ResourceInUseFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8190,6 +8316,16 @@ impl DeleteLaunchConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLaunchConfigurationError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteLaunchConfigurationError::ResourceInUseFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteLaunchConfigurationError {
     #[allow(unused_variables)]
@@ -8207,7 +8343,7 @@ impl Error for DeleteLaunchConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLifecycleHookError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DeleteLifecycleHookError {
@@ -8220,7 +8356,9 @@ impl DeleteLifecycleHookError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            DeleteLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                            DeleteLifecycleHookError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8237,6 +8375,13 @@ impl DeleteLifecycleHookError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLifecycleHookError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteLifecycleHookError {
     #[allow(unused_variables)]
@@ -8251,7 +8396,7 @@ impl Error for DeleteLifecycleHookError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationConfigurationError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DeleteNotificationConfigurationError {
@@ -8267,7 +8412,7 @@ impl DeleteNotificationConfigurationError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DeleteNotificationConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8285,6 +8430,15 @@ impl DeleteNotificationConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteNotificationConfigurationError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -8301,9 +8455,9 @@ impl Error for DeleteNotificationConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl DeletePolicyError {
@@ -8316,12 +8470,12 @@ impl DeletePolicyError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(DeletePolicyError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(DeletePolicyError::ServiceLinkedRoleFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8338,6 +8492,14 @@ impl DeletePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePolicyError::ResourceContentionFault(ref cause) => cause.request_id(),
+            DeletePolicyError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeletePolicyError {
     #[allow(unused_variables)]
@@ -8353,7 +8515,7 @@ impl Error for DeletePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DeleteScheduledActionError {
@@ -8367,7 +8529,7 @@ impl DeleteScheduledActionError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DeleteScheduledActionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8385,6 +8547,13 @@ impl DeleteScheduledActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteScheduledActionError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteScheduledActionError {
     #[allow(unused_variables)]
@@ -8401,9 +8570,9 @@ impl Error for DeleteScheduledActionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
 }
 
 impl DeleteTagsError {
@@ -8416,12 +8585,12 @@ impl DeleteTagsError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(DeleteTagsError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(DeleteTagsError::ResourceInUseFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8438,6 +8607,14 @@ impl DeleteTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTagsError::ResourceContentionFault(ref cause) => cause.request_id(),
+            DeleteTagsError::ResourceInUseFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteTagsError {
     #[allow(unused_variables)]
@@ -8453,7 +8630,7 @@ impl Error for DeleteTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountLimitsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeAccountLimitsError {
@@ -8467,7 +8644,7 @@ impl DescribeAccountLimitsError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeAccountLimitsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8485,6 +8662,13 @@ impl DescribeAccountLimitsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAccountLimitsError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
@@ -8501,7 +8685,7 @@ impl Error for DescribeAccountLimitsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAdjustmentTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeAdjustmentTypesError {
@@ -8515,7 +8699,7 @@ impl DescribeAdjustmentTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeAdjustmentTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8533,6 +8717,13 @@ impl DescribeAdjustmentTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAdjustmentTypesError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAdjustmentTypesError {
     #[allow(unused_variables)]
@@ -8549,9 +8740,9 @@ impl Error for DescribeAdjustmentTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingGroupsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeAutoScalingGroupsError {
@@ -8564,13 +8755,15 @@ impl DescribeAutoScalingGroupsError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
-                            DescribeAutoScalingGroupsError::InvalidNextToken(parsed_error.message),
+                            DescribeAutoScalingGroupsError::InvalidNextToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeAutoScalingGroupsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8588,6 +8781,16 @@ impl DescribeAutoScalingGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAutoScalingGroupsError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeAutoScalingGroupsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeAutoScalingGroupsError {
     #[allow(unused_variables)]
@@ -8605,9 +8808,9 @@ impl Error for DescribeAutoScalingGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingInstancesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeAutoScalingInstancesError {
@@ -8623,14 +8826,14 @@ impl DescribeAutoScalingInstancesError {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
                             DescribeAutoScalingInstancesError::InvalidNextToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeAutoScalingInstancesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8648,6 +8851,16 @@ impl DescribeAutoScalingInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAutoScalingInstancesError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeAutoScalingInstancesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeAutoScalingInstancesError {
     #[allow(unused_variables)]
@@ -8667,7 +8880,7 @@ impl Error for DescribeAutoScalingInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingNotificationTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeAutoScalingNotificationTypesError {
@@ -8683,7 +8896,7 @@ impl DescribeAutoScalingNotificationTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeAutoScalingNotificationTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8701,6 +8914,15 @@ impl DescribeAutoScalingNotificationTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAutoScalingNotificationTypesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeAutoScalingNotificationTypesError {
     #[allow(unused_variables)]
@@ -8717,9 +8939,9 @@ impl Error for DescribeAutoScalingNotificationTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceRefreshesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeInstanceRefreshesError {
@@ -8732,13 +8954,15 @@ impl DescribeInstanceRefreshesError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
-                            DescribeInstanceRefreshesError::InvalidNextToken(parsed_error.message),
+                            DescribeInstanceRefreshesError::InvalidNextToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeInstanceRefreshesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8756,6 +8980,16 @@ impl DescribeInstanceRefreshesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeInstanceRefreshesError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeInstanceRefreshesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeInstanceRefreshesError {
     #[allow(unused_variables)]
@@ -8773,9 +9007,9 @@ impl Error for DescribeInstanceRefreshesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLaunchConfigurationsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeLaunchConfigurationsError {
@@ -8791,14 +9025,14 @@ impl DescribeLaunchConfigurationsError {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
                             DescribeLaunchConfigurationsError::InvalidNextToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeLaunchConfigurationsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8816,6 +9050,16 @@ impl DescribeLaunchConfigurationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLaunchConfigurationsError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeLaunchConfigurationsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLaunchConfigurationsError {
     #[allow(unused_variables)]
@@ -8835,7 +9079,7 @@ impl Error for DescribeLaunchConfigurationsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLifecycleHookTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeLifecycleHookTypesError {
@@ -8851,7 +9095,7 @@ impl DescribeLifecycleHookTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeLifecycleHookTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8869,6 +9113,15 @@ impl DescribeLifecycleHookTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLifecycleHookTypesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLifecycleHookTypesError {
     #[allow(unused_variables)]
@@ -8885,7 +9138,7 @@ impl Error for DescribeLifecycleHookTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLifecycleHooksError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeLifecycleHooksError {
@@ -8899,7 +9152,7 @@ impl DescribeLifecycleHooksError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeLifecycleHooksError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8917,6 +9170,13 @@ impl DescribeLifecycleHooksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLifecycleHooksError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLifecycleHooksError {
     #[allow(unused_variables)]
@@ -8933,7 +9193,7 @@ impl Error for DescribeLifecycleHooksError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancerTargetGroupsError {
@@ -8949,7 +9209,7 @@ impl DescribeLoadBalancerTargetGroupsError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8967,6 +9227,15 @@ impl DescribeLoadBalancerTargetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancerTargetGroupsError {
     #[allow(unused_variables)]
@@ -8983,7 +9252,7 @@ impl Error for DescribeLoadBalancerTargetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancersError {
@@ -8997,7 +9266,7 @@ impl DescribeLoadBalancersError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeLoadBalancersError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9015,6 +9284,13 @@ impl DescribeLoadBalancersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancersError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancersError {
     #[allow(unused_variables)]
@@ -9031,7 +9307,7 @@ impl Error for DescribeLoadBalancersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeMetricCollectionTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeMetricCollectionTypesError {
@@ -9047,7 +9323,7 @@ impl DescribeMetricCollectionTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeMetricCollectionTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9065,6 +9341,15 @@ impl DescribeMetricCollectionTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeMetricCollectionTypesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeMetricCollectionTypesError {
     #[allow(unused_variables)]
@@ -9081,9 +9366,9 @@ impl Error for DescribeMetricCollectionTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotificationConfigurationsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeNotificationConfigurationsError {
@@ -9099,14 +9384,14 @@ impl DescribeNotificationConfigurationsError {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
                             DescribeNotificationConfigurationsError::InvalidNextToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeNotificationConfigurationsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9124,6 +9409,18 @@ impl DescribeNotificationConfigurationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeNotificationConfigurationsError::InvalidNextToken(ref cause) => {
+                cause.request_id()
+            }
+            DescribeNotificationConfigurationsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeNotificationConfigurationsError {
     #[allow(unused_variables)]
@@ -9143,11 +9440,11 @@ impl Error for DescribeNotificationConfigurationsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribePoliciesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl DescribePoliciesError {
@@ -9160,17 +9457,21 @@ impl DescribePoliciesError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(DescribePoliciesError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            DescribePoliciesError::ResourceContentionFault(parsed_error.message),
+                            DescribePoliciesError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
-                            DescribePoliciesError::ServiceLinkedRoleFailure(parsed_error.message),
+                            DescribePoliciesError::ServiceLinkedRoleFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9187,6 +9488,15 @@ impl DescribePoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribePoliciesError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribePoliciesError::ResourceContentionFault(ref cause) => cause.request_id(),
+            DescribePoliciesError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribePoliciesError {
     #[allow(unused_variables)]
@@ -9203,9 +9513,9 @@ impl Error for DescribePoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingActivitiesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeScalingActivitiesError {
@@ -9218,13 +9528,15 @@ impl DescribeScalingActivitiesError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
-                            DescribeScalingActivitiesError::InvalidNextToken(parsed_error.message),
+                            DescribeScalingActivitiesError::InvalidNextToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeScalingActivitiesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9242,6 +9554,16 @@ impl DescribeScalingActivitiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeScalingActivitiesError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeScalingActivitiesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeScalingActivitiesError {
     #[allow(unused_variables)]
@@ -9259,7 +9581,7 @@ impl Error for DescribeScalingActivitiesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingProcessTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeScalingProcessTypesError {
@@ -9275,7 +9597,7 @@ impl DescribeScalingProcessTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeScalingProcessTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9293,6 +9615,15 @@ impl DescribeScalingProcessTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeScalingProcessTypesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeScalingProcessTypesError {
     #[allow(unused_variables)]
@@ -9309,9 +9640,9 @@ impl Error for DescribeScalingProcessTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledActionsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeScheduledActionsError {
@@ -9324,13 +9655,15 @@ impl DescribeScheduledActionsError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(
-                            DescribeScheduledActionsError::InvalidNextToken(parsed_error.message),
+                            DescribeScheduledActionsError::InvalidNextToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeScheduledActionsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9348,6 +9681,14 @@ impl DescribeScheduledActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeScheduledActionsError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeScheduledActionsError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeScheduledActionsError {
     #[allow(unused_variables)]
@@ -9365,9 +9706,9 @@ impl Error for DescribeScheduledActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeTagsError {
@@ -9380,12 +9721,12 @@ impl DescribeTagsError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(DescribeTagsError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(DescribeTagsError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9402,6 +9743,14 @@ impl DescribeTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTagsError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeTagsError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTagsError {
     #[allow(unused_variables)]
@@ -9417,7 +9766,7 @@ impl Error for DescribeTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTerminationPolicyTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DescribeTerminationPolicyTypesError {
@@ -9433,7 +9782,7 @@ impl DescribeTerminationPolicyTypesError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DescribeTerminationPolicyTypesError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9451,6 +9800,15 @@ impl DescribeTerminationPolicyTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTerminationPolicyTypesError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeTerminationPolicyTypesError {
     #[allow(unused_variables)]
@@ -9467,7 +9825,7 @@ impl Error for DescribeTerminationPolicyTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachInstancesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DetachInstancesError {
@@ -9480,7 +9838,7 @@ impl DetachInstancesError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(DetachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9497,6 +9855,13 @@ impl DetachInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachInstancesError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetachInstancesError {
     #[allow(unused_variables)]
@@ -9511,7 +9876,7 @@ impl Error for DetachInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DetachLoadBalancerTargetGroupsError {
@@ -9527,7 +9892,7 @@ impl DetachLoadBalancerTargetGroupsError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DetachLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9545,6 +9910,15 @@ impl DetachLoadBalancerTargetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachLoadBalancerTargetGroupsError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DetachLoadBalancerTargetGroupsError {
     #[allow(unused_variables)]
@@ -9561,7 +9935,7 @@ impl Error for DetachLoadBalancerTargetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DetachLoadBalancersError {
@@ -9574,7 +9948,9 @@ impl DetachLoadBalancersError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            DetachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                            DetachLoadBalancersError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9591,6 +9967,13 @@ impl DetachLoadBalancersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachLoadBalancersError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetachLoadBalancersError {
     #[allow(unused_variables)]
@@ -9605,7 +9988,7 @@ impl Error for DetachLoadBalancersError {}
 #[derive(Debug, PartialEq)]
 pub enum DisableMetricsCollectionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl DisableMetricsCollectionError {
@@ -9619,7 +10002,7 @@ impl DisableMetricsCollectionError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             DisableMetricsCollectionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9637,6 +10020,13 @@ impl DisableMetricsCollectionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisableMetricsCollectionError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DisableMetricsCollectionError {
     #[allow(unused_variables)]
@@ -9653,7 +10043,7 @@ impl Error for DisableMetricsCollectionError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableMetricsCollectionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl EnableMetricsCollectionError {
@@ -9667,7 +10057,7 @@ impl EnableMetricsCollectionError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             EnableMetricsCollectionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9685,6 +10075,13 @@ impl EnableMetricsCollectionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableMetricsCollectionError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for EnableMetricsCollectionError {
     #[allow(unused_variables)]
@@ -9701,7 +10098,7 @@ impl Error for EnableMetricsCollectionError {}
 #[derive(Debug, PartialEq)]
 pub enum EnterStandbyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl EnterStandbyError {
@@ -9714,7 +10111,7 @@ impl EnterStandbyError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(EnterStandbyError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9731,6 +10128,13 @@ impl EnterStandbyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnterStandbyError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for EnterStandbyError {
     #[allow(unused_variables)]
@@ -9745,9 +10149,9 @@ impl Error for EnterStandbyError {}
 #[derive(Debug, PartialEq)]
 pub enum ExecutePolicyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
-    ScalingActivityInProgressFault(String),
+    ScalingActivityInProgressFault(XmlErrorMessage),
 }
 
 impl ExecutePolicyError {
@@ -9760,13 +10164,13 @@ impl ExecutePolicyError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(ExecutePolicyError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ScalingActivityInProgress" => {
                         return RusotoError::Service(
                             ExecutePolicyError::ScalingActivityInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9784,6 +10188,14 @@ impl ExecutePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ExecutePolicyError::ResourceContentionFault(ref cause) => cause.request_id(),
+            ExecutePolicyError::ScalingActivityInProgressFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ExecutePolicyError {
     #[allow(unused_variables)]
@@ -9799,7 +10211,7 @@ impl Error for ExecutePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ExitStandbyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl ExitStandbyError {
@@ -9812,7 +10224,7 @@ impl ExitStandbyError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(ExitStandbyError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9829,6 +10241,13 @@ impl ExitStandbyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ExitStandbyError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ExitStandbyError {
     #[allow(unused_variables)]
@@ -9843,9 +10262,9 @@ impl Error for ExitStandbyError {}
 #[derive(Debug, PartialEq)]
 pub enum PutLifecycleHookError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl PutLifecycleHookError {
@@ -9858,12 +10277,14 @@ impl PutLifecycleHookError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutLifecycleHookError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            PutLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                            PutLifecycleHookError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9880,6 +10301,14 @@ impl PutLifecycleHookError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutLifecycleHookError::LimitExceededFault(ref cause) => cause.request_id(),
+            PutLifecycleHookError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutLifecycleHookError {
     #[allow(unused_variables)]
@@ -9895,11 +10324,11 @@ impl Error for PutLifecycleHookError {}
 #[derive(Debug, PartialEq)]
 pub enum PutNotificationConfigurationError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl PutNotificationConfigurationError {
@@ -9915,21 +10344,21 @@ impl PutNotificationConfigurationError {
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             PutNotificationConfigurationError::LimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             PutNotificationConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
                             PutNotificationConfigurationError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9947,6 +10376,19 @@ impl PutNotificationConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutNotificationConfigurationError::LimitExceededFault(ref cause) => cause.request_id(),
+            PutNotificationConfigurationError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+            PutNotificationConfigurationError::ServiceLinkedRoleFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PutNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -9969,11 +10411,11 @@ impl Error for PutNotificationConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum PutScalingPolicyError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl PutScalingPolicyError {
@@ -9986,17 +10428,21 @@ impl PutScalingPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutScalingPolicyError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            PutScalingPolicyError::ResourceContentionFault(parsed_error.message),
+                            PutScalingPolicyError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
-                            PutScalingPolicyError::ServiceLinkedRoleFailure(parsed_error.message),
+                            PutScalingPolicyError::ServiceLinkedRoleFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10013,6 +10459,15 @@ impl PutScalingPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutScalingPolicyError::LimitExceededFault(ref cause) => cause.request_id(),
+            PutScalingPolicyError::ResourceContentionFault(ref cause) => cause.request_id(),
+            PutScalingPolicyError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutScalingPolicyError {
     #[allow(unused_variables)]
@@ -10029,11 +10484,11 @@ impl Error for PutScalingPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum PutScheduledUpdateGroupActionError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
-    AlreadyExistsFault(String),
+    AlreadyExistsFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl PutScheduledUpdateGroupActionError {
@@ -10049,21 +10504,21 @@ impl PutScheduledUpdateGroupActionError {
                     "AlreadyExists" => {
                         return RusotoError::Service(
                             PutScheduledUpdateGroupActionError::AlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             PutScheduledUpdateGroupActionError::LimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             PutScheduledUpdateGroupActionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10081,6 +10536,17 @@ impl PutScheduledUpdateGroupActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutScheduledUpdateGroupActionError::AlreadyExistsFault(ref cause) => cause.request_id(),
+            PutScheduledUpdateGroupActionError::LimitExceededFault(ref cause) => cause.request_id(),
+            PutScheduledUpdateGroupActionError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PutScheduledUpdateGroupActionError {
     #[allow(unused_variables)]
@@ -10103,7 +10569,7 @@ impl Error for PutScheduledUpdateGroupActionError {}
 #[derive(Debug, PartialEq)]
 pub enum RecordLifecycleActionHeartbeatError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl RecordLifecycleActionHeartbeatError {
@@ -10119,7 +10585,7 @@ impl RecordLifecycleActionHeartbeatError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             RecordLifecycleActionHeartbeatError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10137,6 +10603,15 @@ impl RecordLifecycleActionHeartbeatError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RecordLifecycleActionHeartbeatError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RecordLifecycleActionHeartbeatError {
     #[allow(unused_variables)]
@@ -10153,9 +10628,9 @@ impl Error for RecordLifecycleActionHeartbeatError {}
 #[derive(Debug, PartialEq)]
 pub enum ResumeProcessesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
 }
 
 impl ResumeProcessesError {
@@ -10168,12 +10643,12 @@ impl ResumeProcessesError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(ResumeProcessesError::ResourceContentionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(ResumeProcessesError::ResourceInUseFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10190,6 +10665,14 @@ impl ResumeProcessesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResumeProcessesError::ResourceContentionFault(ref cause) => cause.request_id(),
+            ResumeProcessesError::ResourceInUseFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResumeProcessesError {
     #[allow(unused_variables)]
@@ -10205,9 +10688,9 @@ impl Error for ResumeProcessesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetDesiredCapacityError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
-    ScalingActivityInProgressFault(String),
+    ScalingActivityInProgressFault(XmlErrorMessage),
 }
 
 impl SetDesiredCapacityError {
@@ -10220,13 +10703,15 @@ impl SetDesiredCapacityError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            SetDesiredCapacityError::ResourceContentionFault(parsed_error.message),
+                            SetDesiredCapacityError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ScalingActivityInProgress" => {
                         return RusotoError::Service(
                             SetDesiredCapacityError::ScalingActivityInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10244,6 +10729,16 @@ impl SetDesiredCapacityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetDesiredCapacityError::ResourceContentionFault(ref cause) => cause.request_id(),
+            SetDesiredCapacityError::ScalingActivityInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for SetDesiredCapacityError {
     #[allow(unused_variables)]
@@ -10261,7 +10756,7 @@ impl Error for SetDesiredCapacityError {}
 #[derive(Debug, PartialEq)]
 pub enum SetInstanceHealthError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl SetInstanceHealthError {
@@ -10274,7 +10769,9 @@ impl SetInstanceHealthError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            SetInstanceHealthError::ResourceContentionFault(parsed_error.message),
+                            SetInstanceHealthError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10291,6 +10788,13 @@ impl SetInstanceHealthError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetInstanceHealthError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetInstanceHealthError {
     #[allow(unused_variables)]
@@ -10305,9 +10809,9 @@ impl Error for SetInstanceHealthError {}
 #[derive(Debug, PartialEq)]
 pub enum SetInstanceProtectionError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl SetInstanceProtectionError {
@@ -10320,13 +10824,15 @@ impl SetInstanceProtectionError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            SetInstanceProtectionError::LimitExceededFault(parsed_error.message),
+                            SetInstanceProtectionError::LimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             SetInstanceProtectionError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10344,6 +10850,14 @@ impl SetInstanceProtectionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetInstanceProtectionError::LimitExceededFault(ref cause) => cause.request_id(),
+            SetInstanceProtectionError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetInstanceProtectionError {
     #[allow(unused_variables)]
@@ -10361,11 +10875,11 @@ impl Error for SetInstanceProtectionError {}
 #[derive(Debug, PartialEq)]
 pub enum StartInstanceRefreshError {
     /// <p>The request failed because an active instance refresh operation already exists for the specified Auto Scaling group.</p>
-    InstanceRefreshInProgressFault(String),
+    InstanceRefreshInProgressFault(XmlErrorMessage),
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/APIReference/API_DescribeAccountLimits.html">DescribeAccountLimits</a> in the <i>Amazon EC2 Auto Scaling API Reference</i>.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
 }
 
 impl StartInstanceRefreshError {
@@ -10379,19 +10893,19 @@ impl StartInstanceRefreshError {
                     "InstanceRefreshInProgress" => {
                         return RusotoError::Service(
                             StartInstanceRefreshError::InstanceRefreshInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(StartInstanceRefreshError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceContention" => {
                         return RusotoError::Service(
                             StartInstanceRefreshError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10409,6 +10923,17 @@ impl StartInstanceRefreshError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartInstanceRefreshError::InstanceRefreshInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+            StartInstanceRefreshError::LimitExceededFault(ref cause) => cause.request_id(),
+            StartInstanceRefreshError::ResourceContentionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartInstanceRefreshError {
     #[allow(unused_variables)]
@@ -10427,9 +10952,9 @@ impl Error for StartInstanceRefreshError {}
 #[derive(Debug, PartialEq)]
 pub enum SuspendProcessesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because the resource is in use.</p>
-    ResourceInUseFault(String),
+    ResourceInUseFault(XmlErrorMessage),
 }
 
 impl SuspendProcessesError {
@@ -10442,12 +10967,14 @@ impl SuspendProcessesError {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
                         return RusotoError::Service(
-                            SuspendProcessesError::ResourceContentionFault(parsed_error.message),
+                            SuspendProcessesError::ResourceContentionFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(SuspendProcessesError::ResourceInUseFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10464,6 +10991,14 @@ impl SuspendProcessesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SuspendProcessesError::ResourceContentionFault(ref cause) => cause.request_id(),
+            SuspendProcessesError::ResourceInUseFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SuspendProcessesError {
     #[allow(unused_variables)]
@@ -10479,9 +11014,9 @@ impl Error for SuspendProcessesError {}
 #[derive(Debug, PartialEq)]
 pub enum TerminateInstanceInAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
-    ScalingActivityInProgressFault(String),
+    ScalingActivityInProgressFault(XmlErrorMessage),
 }
 
 impl TerminateInstanceInAutoScalingGroupError {
@@ -10497,13 +11032,13 @@ impl TerminateInstanceInAutoScalingGroupError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScalingActivityInProgress" => return RusotoError::Service(
                         TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -10520,6 +11055,18 @@ impl TerminateInstanceInAutoScalingGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(ref cause) => {
+                cause.request_id()
+            }
+            TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for TerminateInstanceInAutoScalingGroupError {
     #[allow(unused_variables)]
@@ -10539,11 +11086,11 @@ impl Error for TerminateInstanceInAutoScalingGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
-    ResourceContentionFault(String),
+    ResourceContentionFault(XmlErrorMessage),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
-    ScalingActivityInProgressFault(String),
+    ScalingActivityInProgressFault(XmlErrorMessage),
     /// <p>The service-linked role is not yet ready for use.</p>
-    ServiceLinkedRoleFailure(String),
+    ServiceLinkedRoleFailure(XmlErrorMessage),
 }
 
 impl UpdateAutoScalingGroupError {
@@ -10557,21 +11104,21 @@ impl UpdateAutoScalingGroupError {
                     "ResourceContention" => {
                         return RusotoError::Service(
                             UpdateAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScalingActivityInProgress" => {
                         return RusotoError::Service(
                             UpdateAutoScalingGroupError::ScalingActivityInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
                         return RusotoError::Service(
                             UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10589,6 +11136,17 @@ impl UpdateAutoScalingGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateAutoScalingGroupError::ResourceContentionFault(ref cause) => cause.request_id(),
+            UpdateAutoScalingGroupError::ScalingActivityInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+            UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateAutoScalingGroupError {
     #[allow(unused_variables)]
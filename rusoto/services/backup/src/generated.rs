@@ -5072,6 +5072,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5083,13 +5084,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBackupPlanOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBackupPlanError::from_response(response))
         }
     }
@@ -5106,6 +5107,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5117,13 +5119,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBackupSelectionOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBackupSelectionError::from_response(response))
         }
     }
@@ -5140,6 +5142,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5151,13 +5154,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBackupVaultOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBackupVaultError::from_response(response))
         }
     }
@@ -5174,6 +5177,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5182,13 +5186,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteBackupPlanOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackupPlanError::from_response(response))
         }
     }
@@ -5206,6 +5210,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5214,12 +5219,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackupSelectionError::from_response(response))
         }
     }
@@ -5236,6 +5241,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5244,12 +5250,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackupVaultError::from_response(response))
         }
     }
@@ -5266,6 +5272,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5274,12 +5281,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackupVaultAccessPolicyError::from_response(response))
         }
     }
@@ -5296,6 +5303,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5304,12 +5312,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBackupVaultNotificationsError::from_response(response))
         }
     }
@@ -5327,6 +5335,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5335,12 +5344,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRecoveryPointError::from_response(response))
         }
     }
@@ -5357,6 +5366,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5365,13 +5375,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeBackupJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeBackupJobError::from_response(response))
         }
     }
@@ -5388,6 +5398,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5396,13 +5407,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeBackupVaultOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeBackupVaultError::from_response(response))
         }
     }
@@ -5416,6 +5427,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/copy-jobs/{copy_job_id}", copy_job_id = input.copy_job_id);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5424,13 +5436,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeCopyJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeCopyJobError::from_response(response))
         }
     }
@@ -5447,6 +5459,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeProtectedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5455,13 +5468,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeProtectedResourceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeProtectedResourceError::from_response(response))
         }
     }
@@ -5479,6 +5492,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5487,13 +5501,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRecoveryPointOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRecoveryPointError::from_response(response))
         }
     }
@@ -5506,6 +5520,7 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRegionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5514,13 +5529,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRegionSettingsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRegionSettingsError::from_response(response))
         }
     }
@@ -5537,6 +5552,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5545,13 +5561,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRestoreJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRestoreJobError::from_response(response))
         }
     }
@@ -5568,6 +5584,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ExportBackupPlanTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5576,13 +5593,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ExportBackupPlanTemplateOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ExportBackupPlanTemplateError::from_response(response))
         }
     }
@@ -5599,6 +5616,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5613,13 +5631,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupPlanOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupPlanError::from_response(response))
         }
     }
@@ -5633,6 +5651,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/json/toPlan";
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromJSON");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5644,13 +5663,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupPlanFromJSONOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupPlanFromJSONError::from_response(response))
         }
     }
@@ -5667,6 +5686,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5675,13 +5695,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupPlanFromTemplateOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupPlanFromTemplateError::from_response(response))
         }
     }
@@ -5699,6 +5719,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5707,13 +5728,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupSelectionOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupSelectionError::from_response(response))
         }
     }
@@ -5731,6 +5752,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5739,13 +5761,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupVaultAccessPolicyOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupVaultAccessPolicyError::from_response(response))
         }
     }
@@ -5763,6 +5785,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5771,13 +5794,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBackupVaultNotificationsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBackupVaultNotificationsError::from_response(response))
         }
     }
@@ -5794,6 +5817,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}/restore-metadata", backup_vault_name = input.backup_vault_name, recovery_point_arn = input.recovery_point_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetRecoveryPointRestoreMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5802,13 +5826,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRecoveryPointRestoreMetadataOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRecoveryPointRestoreMetadataError::from_response(
                 response,
             ))
@@ -5823,6 +5847,7 @@ impl Backup for BackupClient {
         let request_uri = "/supported-resource-types";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetSupportedResourceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5831,13 +5856,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSupportedResourceTypesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSupportedResourceTypesError::from_response(response))
         }
     }
@@ -5851,6 +5876,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5889,13 +5915,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupJobsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupJobsError::from_response(response))
         }
     }
@@ -5909,6 +5935,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/plans";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5926,13 +5953,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupPlanTemplatesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupPlanTemplatesError::from_response(response))
         }
     }
@@ -5949,6 +5976,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5966,13 +5994,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupPlanVersionsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupPlanVersionsError::from_response(response))
         }
     }
@@ -5986,6 +6014,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6006,13 +6035,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupPlansOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupPlansError::from_response(response))
         }
     }
@@ -6029,6 +6058,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupSelections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6046,13 +6076,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupSelectionsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupSelectionsError::from_response(response))
         }
     }
@@ -6066,6 +6096,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-vaults/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6083,13 +6114,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBackupVaultsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBackupVaultsError::from_response(response))
         }
     }
@@ -6103,6 +6134,7 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListCopyJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6141,13 +6173,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListCopyJobsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListCopyJobsError::from_response(response))
         }
     }
@@ -6161,6 +6193,7 @@ impl Backup for BackupClient {
         let request_uri = "/resources/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListProtectedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6178,13 +6211,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProtectedResourcesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProtectedResourcesError::from_response(response))
         }
     }
@@ -6204,6 +6237,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6236,13 +6270,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRecoveryPointsByBackupVaultOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRecoveryPointsByBackupVaultError::from_response(
                 response,
             ))
@@ -6262,6 +6296,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6279,13 +6314,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRecoveryPointsByResourceOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRecoveryPointsByResourceError::from_response(response))
         }
     }
@@ -6299,6 +6334,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRestoreJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6328,13 +6364,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRestoreJobsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRestoreJobsError::from_response(response))
         }
     }
@@ -6348,6 +6384,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}/", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6365,13 +6402,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsError::from_response(response))
         }
     }
@@ -6388,6 +6425,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6399,12 +6437,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutBackupVaultAccessPolicyError::from_response(response))
         }
     }
@@ -6421,6 +6459,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6432,12 +6471,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutBackupVaultNotificationsError::from_response(response))
         }
     }
@@ -6451,6 +6490,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6462,13 +6502,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartBackupJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartBackupJobError::from_response(response))
         }
     }
@@ -6482,6 +6522,7 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6493,13 +6534,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartCopyJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartCopyJobError::from_response(response))
         }
     }
@@ -6513,6 +6554,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6524,13 +6566,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartRestoreJobOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartRestoreJobError::from_response(response))
         }
     }
@@ -6547,6 +6589,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("StopBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6555,12 +6598,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopBackupJobError::from_response(response))
         }
     }
@@ -6574,6 +6617,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6585,12 +6629,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -6604,6 +6648,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/untag/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6615,12 +6660,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -6637,6 +6682,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6648,13 +6694,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateBackupPlanOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateBackupPlanError::from_response(response))
         }
     }
@@ -6673,6 +6719,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRecoveryPointLifecycle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6684,13 +6731,13 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRecoveryPointLifecycleOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRecoveryPointLifecycleError::from_response(response))
         }
     }
@@ -6704,6 +6751,7 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRegionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6715,12 +6763,12 @@ impl Backup for BackupClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRegionSettingsError::from_response(response))
         }
     }
@@ -2052,6 +2052,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2063,13 +2064,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelJobError::from_response(response))
         }
     }
@@ -2083,6 +2084,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2094,13 +2096,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateComputeEnvironmentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateComputeEnvironmentError::from_response(response))
         }
     }
@@ -2114,6 +2116,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2125,13 +2128,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobQueueResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateJobQueueError::from_response(response))
         }
     }
@@ -2145,6 +2148,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2156,13 +2160,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteComputeEnvironmentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteComputeEnvironmentError::from_response(response))
         }
     }
@@ -2176,6 +2180,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2187,13 +2192,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteJobQueueResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteJobQueueError::from_response(response))
         }
     }
@@ -2207,6 +2212,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeregisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2218,13 +2224,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeregisterJobDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeregisterJobDefinitionError::from_response(response))
         }
     }
@@ -2239,6 +2245,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeComputeEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2250,13 +2257,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeComputeEnvironmentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeComputeEnvironmentsError::from_response(response))
         }
     }
@@ -2270,6 +2277,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2281,13 +2289,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeJobDefinitionsError::from_response(response))
         }
     }
@@ -2301,6 +2309,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2312,13 +2321,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobQueuesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeJobQueuesError::from_response(response))
         }
     }
@@ -2332,6 +2341,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2343,13 +2353,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeJobsError::from_response(response))
         }
     }
@@ -2363,6 +2373,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2374,13 +2385,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListJobsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsError::from_response(response))
         }
     }
@@ -2394,6 +2405,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("RegisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2405,13 +2417,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterJobDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterJobDefinitionError::from_response(response))
         }
     }
@@ -2425,6 +2437,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("SubmitJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2436,13 +2449,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SubmitJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SubmitJobError::from_response(response))
         }
     }
@@ -2456,6 +2469,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TerminateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2467,13 +2481,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TerminateJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TerminateJobError::from_response(response))
         }
     }
@@ -2487,6 +2501,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2498,13 +2513,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateComputeEnvironmentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateComputeEnvironmentError::from_response(response))
         }
     }
@@ -2518,6 +2533,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2529,13 +2545,13 @@ impl Batch for BatchClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateJobQueueResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateJobQueueError::from_response(response))
         }
     }
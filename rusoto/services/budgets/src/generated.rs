@@ -41,7 +41,7 @@ impl BudgetsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1599,7 +1599,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, CreateBudgetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBudgetResponse, _>()
     }
 
@@ -1617,7 +1617,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, CreateNotificationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateNotificationResponse, _>()
     }
 
@@ -1635,7 +1635,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, CreateSubscriberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSubscriberResponse, _>()
     }
 
@@ -1653,7 +1653,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DeleteBudgetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBudgetResponse, _>()
     }
 
@@ -1671,7 +1671,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DeleteNotificationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteNotificationResponse, _>()
     }
 
@@ -1689,7 +1689,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DeleteSubscriberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSubscriberResponse, _>()
     }
 
@@ -1707,7 +1707,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DescribeBudgetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBudgetResponse, _>()
     }
 
@@ -1734,7 +1734,7 @@ impl Budgets for BudgetsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeBudgetPerformanceHistoryResponse, _>()
     }
@@ -1753,7 +1753,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DescribeBudgetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBudgetsResponse, _>()
     }
 
@@ -1777,7 +1777,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, DescribeNotificationsForBudgetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeNotificationsForBudgetResponse, _>()
     }
@@ -1805,7 +1805,7 @@ impl Budgets for BudgetsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSubscribersForNotificationResponse, _>()
     }
@@ -1824,7 +1824,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, UpdateBudgetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateBudgetResponse, _>()
     }
 
@@ -1842,7 +1842,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, UpdateNotificationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateNotificationResponse, _>()
     }
 
@@ -1860,7 +1860,7 @@ impl Budgets for BudgetsClient {
             .sign_and_dispatch(request, UpdateSubscriberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriberResponse, _>()
     }
 }
@@ -41,7 +41,7 @@ impl CostExplorerClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3273,7 +3273,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, CreateCostCategoryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCostCategoryDefinitionResponse, _>()
     }
@@ -3296,7 +3296,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, DeleteCostCategoryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteCostCategoryDefinitionResponse, _>()
     }
@@ -3321,7 +3321,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, DescribeCostCategoryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCostCategoryDefinitionResponse, _>()
     }
@@ -3340,7 +3340,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetCostAndUsageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCostAndUsageResponse, _>()
     }
 
@@ -3362,7 +3362,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetCostAndUsageWithResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCostAndUsageWithResourcesResponse, _>()
     }
@@ -3381,7 +3381,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetCostForecastError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCostForecastResponse, _>()
     }
 
@@ -3399,7 +3399,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetDimensionValuesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDimensionValuesResponse, _>()
     }
 
@@ -3420,7 +3420,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetReservationCoverageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetReservationCoverageResponse, _>()
     }
@@ -3448,7 +3448,7 @@ impl CostExplorer for CostExplorerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetReservationPurchaseRecommendationResponse, _>()
     }
@@ -3471,7 +3471,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetReservationUtilizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetReservationUtilizationResponse, _>()
     }
@@ -3494,7 +3494,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetRightsizingRecommendationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRightsizingRecommendationResponse, _>()
     }
@@ -3516,7 +3516,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetSavingsPlansCoverageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSavingsPlansCoverageResponse, _>()
     }
@@ -3544,7 +3544,7 @@ impl CostExplorer for CostExplorerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSavingsPlansPurchaseRecommendationResponse, _>()
     }
@@ -3567,7 +3567,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetSavingsPlansUtilizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSavingsPlansUtilizationResponse, _>()
     }
@@ -3595,7 +3595,7 @@ impl CostExplorer for CostExplorerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSavingsPlansUtilizationDetailsResponse, _>()
     }
@@ -3614,7 +3614,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
     }
 
@@ -3632,7 +3632,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, GetUsageForecastError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetUsageForecastResponse, _>()
     }
 
@@ -3654,7 +3654,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, ListCostCategoryDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCostCategoryDefinitionsResponse, _>()
     }
@@ -3677,7 +3677,7 @@ impl CostExplorer for CostExplorerClient {
             .sign_and_dispatch(request, UpdateCostCategoryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateCostCategoryDefinitionResponse, _>()
     }
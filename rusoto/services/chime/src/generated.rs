@@ -13331,6 +13331,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumberWithUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13346,13 +13347,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociatePhoneNumberWithUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociatePhoneNumberWithUserError::from_response(response))
         }
     }
@@ -13372,6 +13373,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13387,13 +13389,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociatePhoneNumbersWithVoiceConnectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociatePhoneNumbersWithVoiceConnectorError::from_response(
                 response,
             ))
@@ -13415,6 +13417,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13430,14 +13433,14 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociatePhoneNumbersWithVoiceConnectorGroupResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociatePhoneNumbersWithVoiceConnectorGroupError::from_response(response))
         }
     }
@@ -13454,6 +13457,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociateSigninDelegateGroupsWithAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13469,13 +13473,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateSigninDelegateGroupsWithAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateSigninDelegateGroupsWithAccountError::from_response(response))
         }
     }
@@ -13492,6 +13496,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13507,13 +13512,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchCreateAttendeeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchCreateAttendeeError::from_response(response))
         }
     }
@@ -13532,6 +13537,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13547,13 +13553,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchCreateRoomMembershipResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchCreateRoomMembershipError::from_response(response))
         }
     }
@@ -13567,6 +13573,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchDeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13582,13 +13589,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchDeletePhoneNumberResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchDeletePhoneNumberError::from_response(response))
         }
     }
@@ -13605,6 +13612,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchSuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13620,13 +13628,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchSuspendUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchSuspendUserError::from_response(response))
         }
     }
@@ -13643,6 +13651,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUnsuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13658,13 +13667,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUnsuspendUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchUnsuspendUserError::from_response(response))
         }
     }
@@ -13678,6 +13687,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13693,13 +13703,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUpdatePhoneNumberResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchUpdatePhoneNumberError::from_response(response))
         }
     }
@@ -13716,6 +13726,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13727,13 +13738,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUpdateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchUpdateUserError::from_response(response))
         }
     }
@@ -13747,6 +13758,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13758,13 +13770,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAccountError::from_response(response))
         }
     }
@@ -13781,6 +13793,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13792,13 +13805,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateAttendeeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAttendeeError::from_response(response))
         }
     }
@@ -13812,6 +13825,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13823,13 +13837,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBotError::from_response(response))
         }
     }
@@ -13843,6 +13857,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13854,13 +13869,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMeetingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMeetingError::from_response(response))
         }
     }
@@ -13875,6 +13890,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeetingWithAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13890,13 +13906,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMeetingWithAttendeesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMeetingWithAttendeesError::from_response(response))
         }
     }
@@ -13910,6 +13926,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreatePhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13921,13 +13938,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePhoneNumberOrderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePhoneNumberOrderError::from_response(response))
         }
     }
@@ -13944,6 +13961,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13955,13 +13973,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateProxySessionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateProxySessionError::from_response(response))
         }
     }
@@ -13978,6 +13996,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13989,13 +14008,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRoomResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRoomError::from_response(response))
         }
     }
@@ -14013,6 +14032,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14024,13 +14044,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRoomMembershipResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRoomMembershipError::from_response(response))
         }
     }
@@ -14047,6 +14067,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14062,13 +14083,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateUserError::from_response(response))
         }
     }
@@ -14082,6 +14103,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14093,13 +14115,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVoiceConnectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVoiceConnectorError::from_response(response))
         }
     }
@@ -14114,6 +14136,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14125,13 +14148,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVoiceConnectorGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVoiceConnectorGroupError::from_response(response))
         }
     }
@@ -14145,6 +14168,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14153,13 +14177,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAccountError::from_response(response))
         }
     }
@@ -14177,6 +14201,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14185,12 +14210,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAttendeeError::from_response(response))
         }
     }
@@ -14208,6 +14233,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14216,12 +14242,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteEventsConfigurationError::from_response(response))
         }
     }
@@ -14235,6 +14261,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14243,12 +14270,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMeetingError::from_response(response))
         }
     }
@@ -14265,6 +14292,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14273,12 +14301,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePhoneNumberError::from_response(response))
         }
     }
@@ -14296,6 +14324,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14304,12 +14333,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteProxySessionError::from_response(response))
         }
     }
@@ -14327,6 +14356,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14335,12 +14365,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRoomError::from_response(response))
         }
     }
@@ -14359,6 +14389,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14367,12 +14398,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRoomMembershipError::from_response(response))
         }
     }
@@ -14389,6 +14420,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14397,12 +14429,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorError::from_response(response))
         }
     }
@@ -14419,6 +14451,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14427,12 +14460,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorEmergencyCallingConfigurationError::from_response(response))
         }
     }
@@ -14449,6 +14482,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14457,12 +14491,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorGroupError::from_response(response))
         }
     }
@@ -14479,6 +14513,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14487,12 +14522,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorOriginationError::from_response(
                 response,
             ))
@@ -14511,6 +14546,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14519,12 +14555,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorProxyError::from_response(response))
         }
     }
@@ -14541,6 +14577,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14549,12 +14586,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorStreamingConfigurationError::from_response(response))
         }
     }
@@ -14571,6 +14608,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14579,12 +14617,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorTerminationError::from_response(
                 response,
             ))
@@ -14603,6 +14641,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14618,12 +14657,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVoiceConnectorTerminationCredentialsError::from_response(response))
         }
     }
@@ -14644,6 +14683,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumberFromUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14656,13 +14696,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociatePhoneNumberFromUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociatePhoneNumberFromUserError::from_response(
                 response,
             ))
@@ -14684,6 +14724,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14699,13 +14740,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociatePhoneNumbersFromVoiceConnectorError::from_response(response))
         }
     }
@@ -14725,6 +14766,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14740,14 +14782,14 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorGroupResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociatePhoneNumbersFromVoiceConnectorGroupError::from_response(response))
         }
     }
@@ -14764,6 +14806,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociateSigninDelegateGroupsFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14779,13 +14822,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateSigninDelegateGroupsFromAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateSigninDelegateGroupsFromAccountError::from_response(response))
         }
     }
@@ -14799,6 +14842,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14807,13 +14851,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAccountError::from_response(response))
         }
     }
@@ -14830,6 +14874,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14838,13 +14883,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAccountSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAccountSettingsError::from_response(response))
         }
     }
@@ -14862,6 +14907,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14870,13 +14916,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAttendeeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAttendeeError::from_response(response))
         }
     }
@@ -14894,6 +14940,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14902,13 +14949,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotError::from_response(response))
         }
     }
@@ -14926,6 +14973,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14934,13 +14982,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetEventsConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetEventsConfigurationError::from_response(response))
         }
     }
@@ -14953,6 +15001,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14961,13 +15010,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGlobalSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGlobalSettingsError::from_response(response))
         }
     }
@@ -14981,6 +15030,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14989,13 +15039,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMeetingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMeetingError::from_response(response))
         }
     }
@@ -15012,6 +15062,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15020,13 +15071,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPhoneNumberResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPhoneNumberError::from_response(response))
         }
     }
@@ -15043,6 +15094,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15051,13 +15103,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPhoneNumberOrderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPhoneNumberOrderError::from_response(response))
         }
     }
@@ -15070,6 +15122,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15078,13 +15131,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPhoneNumberSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPhoneNumberSettingsError::from_response(response))
         }
     }
@@ -15102,6 +15155,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15110,13 +15164,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetProxySessionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetProxySessionError::from_response(response))
         }
     }
@@ -15133,6 +15187,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15141,13 +15196,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRetentionSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRetentionSettingsError::from_response(response))
         }
     }
@@ -15165,6 +15220,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15173,13 +15229,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRoomError::from_response(response))
         }
     }
@@ -15197,6 +15253,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15205,13 +15262,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUserError::from_response(response))
         }
     }
@@ -15229,6 +15286,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15237,13 +15295,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetUserSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUserSettingsError::from_response(response))
         }
     }
@@ -15260,6 +15318,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15268,13 +15327,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorError::from_response(response))
         }
     }
@@ -15294,6 +15353,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15302,14 +15362,14 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorEmergencyCallingConfigurationResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorEmergencyCallingConfigurationError::from_response(response))
         }
     }
@@ -15326,6 +15386,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15334,13 +15395,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorGroupError::from_response(response))
         }
     }
@@ -15360,6 +15421,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15368,13 +15430,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorLoggingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorLoggingConfigurationError::from_response(
                 response,
             ))
@@ -15394,6 +15456,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15402,13 +15465,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorOriginationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorOriginationError::from_response(response))
         }
     }
@@ -15425,6 +15488,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15433,13 +15497,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorProxyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorProxyError::from_response(response))
         }
     }
@@ -15459,6 +15523,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15467,13 +15532,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorStreamingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorStreamingConfigurationError::from_response(
                 response,
             ))
@@ -15493,6 +15558,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15501,13 +15567,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorTerminationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorTerminationError::from_response(response))
         }
     }
@@ -15527,6 +15593,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTerminationHealth");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15535,13 +15602,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVoiceConnectorTerminationHealthResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVoiceConnectorTerminationHealthError::from_response(
                 response,
             ))
@@ -15560,6 +15627,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("InviteUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -15575,13 +15643,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InviteUsersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InviteUsersError::from_response(response))
         }
     }
@@ -15595,6 +15663,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15618,13 +15687,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAccountsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAccountsError::from_response(response))
         }
     }
@@ -15642,6 +15711,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendeeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15650,13 +15720,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAttendeeTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAttendeeTagsError::from_response(response))
         }
     }
@@ -15673,6 +15743,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15690,13 +15761,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAttendeesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAttendeesError::from_response(response))
         }
     }
@@ -15710,6 +15781,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15727,13 +15799,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBotsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBotsError::from_response(response))
         }
     }
@@ -15747,6 +15819,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetingTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15755,13 +15828,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMeetingTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMeetingTagsError::from_response(response))
         }
     }
@@ -15775,6 +15848,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15792,13 +15866,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMeetingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMeetingsError::from_response(response))
         }
     }
@@ -15812,6 +15886,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumberOrders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15829,13 +15904,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPhoneNumberOrdersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPhoneNumberOrdersError::from_response(response))
         }
     }
@@ -15849,6 +15924,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15878,13 +15954,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPhoneNumbersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPhoneNumbersError::from_response(response))
         }
     }
@@ -15901,6 +15977,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListProxySessions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15921,13 +15998,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProxySessionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProxySessionsError::from_response(response))
         }
     }
@@ -15945,6 +16022,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRoomMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15962,13 +16040,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRoomMembershipsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRoomMembershipsError::from_response(response))
         }
     }
@@ -15985,6 +16063,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRooms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16005,13 +16084,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRoomsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRoomsError::from_response(response))
         }
     }
@@ -16025,6 +16104,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16037,13 +16117,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -16060,6 +16140,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16083,13 +16164,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListUsersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListUsersError::from_response(response))
         }
     }
@@ -16103,6 +16184,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16120,13 +16202,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVoiceConnectorGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVoiceConnectorGroupsError::from_response(response))
         }
     }
@@ -16146,6 +16228,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -16154,13 +16237,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVoiceConnectorTerminationCredentialsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVoiceConnectorTerminationCredentialsError::from_response(response))
         }
     }
@@ -16174,6 +16257,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16191,13 +16275,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVoiceConnectorsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVoiceConnectorsError::from_response(response))
         }
     }
@@ -16215,6 +16299,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("LogoutUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16227,13 +16312,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<LogoutUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(LogoutUserError::from_response(response))
         }
     }
@@ -16251,6 +16336,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16262,13 +16348,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutEventsConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutEventsConfigurationError::from_response(response))
         }
     }
@@ -16285,6 +16371,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16296,13 +16383,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutRetentionSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutRetentionSettingsError::from_response(response))
         }
     }
@@ -16322,6 +16409,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16333,14 +16421,14 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorEmergencyCallingConfigurationResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorEmergencyCallingConfigurationError::from_response(response))
         }
     }
@@ -16360,6 +16448,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16371,13 +16460,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorLoggingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorLoggingConfigurationError::from_response(
                 response,
             ))
@@ -16397,6 +16486,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16408,13 +16498,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorOriginationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorOriginationError::from_response(response))
         }
     }
@@ -16431,6 +16521,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16442,13 +16533,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorProxyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorProxyError::from_response(response))
         }
     }
@@ -16468,6 +16559,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16479,13 +16571,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorStreamingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorStreamingConfigurationError::from_response(
                 response,
             ))
@@ -16505,6 +16597,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16516,13 +16609,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorTerminationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorTerminationError::from_response(response))
         }
     }
@@ -16539,6 +16632,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16554,12 +16648,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutVoiceConnectorTerminationCredentialsError::from_response(
                 response,
             ))
@@ -16581,6 +16675,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RedactConversationMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16593,13 +16688,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RedactConversationMessageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RedactConversationMessageError::from_response(response))
         }
     }
@@ -16618,6 +16713,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RedactRoomMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16630,13 +16726,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RedactRoomMessageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RedactRoomMessageError::from_response(response))
         }
     }
@@ -16654,6 +16750,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RegenerateSecurityToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16666,13 +16763,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegenerateSecurityTokenResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegenerateSecurityTokenError::from_response(response))
         }
     }
@@ -16690,6 +16787,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("ResetPersonalPIN");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16702,13 +16800,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ResetPersonalPINResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ResetPersonalPINError::from_response(response))
         }
     }
@@ -16725,6 +16823,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RestorePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16737,13 +16836,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RestorePhoneNumberResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RestorePhoneNumberError::from_response(response))
         }
     }
@@ -16758,6 +16857,7 @@ impl Chime for ChimeClient {
         let request_uri = "/search";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("SearchAvailablePhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16791,13 +16891,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SearchAvailablePhoneNumbersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SearchAvailablePhoneNumbersError::from_response(response))
         }
     }
@@ -16815,6 +16915,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16830,12 +16931,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagAttendeeError::from_response(response))
         }
     }
@@ -16849,6 +16950,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16864,12 +16966,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagMeetingError::from_response(response))
         }
     }
@@ -16883,6 +16985,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16898,12 +17001,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -16921,6 +17024,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16936,12 +17040,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagAttendeeError::from_response(response))
         }
     }
@@ -16955,6 +17059,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16970,12 +17075,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagMeetingError::from_response(response))
         }
     }
@@ -16989,6 +17094,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17004,12 +17110,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -17023,6 +17129,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17034,13 +17141,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAccountError::from_response(response))
         }
     }
@@ -17057,6 +17164,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17068,13 +17176,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateAccountSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAccountSettingsError::from_response(response))
         }
     }
@@ -17092,6 +17200,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17103,13 +17212,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateBotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateBotError::from_response(response))
         }
     }
@@ -17123,6 +17232,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17134,12 +17244,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGlobalSettingsError::from_response(response))
         }
     }
@@ -17156,6 +17266,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17167,13 +17278,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePhoneNumberResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePhoneNumberError::from_response(response))
         }
     }
@@ -17187,6 +17298,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17198,12 +17310,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePhoneNumberSettingsError::from_response(response))
         }
     }
@@ -17221,6 +17333,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17232,13 +17345,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateProxySessionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateProxySessionError::from_response(response))
         }
     }
@@ -17256,6 +17369,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17267,13 +17381,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRoomResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRoomError::from_response(response))
         }
     }
@@ -17292,6 +17406,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17303,13 +17418,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRoomMembershipResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRoomMembershipError::from_response(response))
         }
     }
@@ -17327,6 +17442,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17338,13 +17454,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUserError::from_response(response))
         }
     }
@@ -17362,6 +17478,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17373,12 +17490,12 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUserSettingsError::from_response(response))
         }
     }
@@ -17395,6 +17512,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17406,13 +17524,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVoiceConnectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVoiceConnectorError::from_response(response))
         }
     }
@@ -17430,6 +17548,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17441,13 +17560,13 @@ impl Chime for ChimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateVoiceConnectorGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateVoiceConnectorGroupError::from_response(response))
         }
     }
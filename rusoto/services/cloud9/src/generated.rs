@@ -41,7 +41,7 @@ impl Cloud9Client {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1452,7 +1452,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, CreateEnvironmentEC2Error::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEnvironmentEC2Result, _>()
     }
 
@@ -1474,7 +1474,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, CreateEnvironmentMembershipError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateEnvironmentMembershipResult, _>()
     }
@@ -1496,7 +1496,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, DeleteEnvironmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteEnvironmentResult, _>()
     }
 
@@ -1518,7 +1518,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, DeleteEnvironmentMembershipError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteEnvironmentMembershipResult, _>()
     }
@@ -1543,7 +1543,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, DescribeEnvironmentMembershipsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEnvironmentMembershipsResult, _>()
     }
@@ -1565,7 +1565,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, DescribeEnvironmentStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEnvironmentStatusResult, _>()
     }
@@ -1587,7 +1587,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, DescribeEnvironmentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEnvironmentsResult, _>()
     }
 
@@ -1608,7 +1608,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, ListEnvironmentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEnvironmentsResult, _>()
     }
 
@@ -1629,7 +1629,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -1650,7 +1650,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -1671,7 +1671,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -1692,7 +1692,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, UpdateEnvironmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEnvironmentResult, _>()
     }
 
@@ -1714,7 +1714,7 @@ impl Cloud9 for Cloud9Client {
             .sign_and_dispatch(request, UpdateEnvironmentMembershipError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateEnvironmentMembershipResult, _>()
     }
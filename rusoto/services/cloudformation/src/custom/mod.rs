@@ -1,2 +1,4 @@
+mod waiter;
+
 #[cfg(test)]
 mod custom_tests;
@@ -0,0 +1,284 @@
+use std::time::Duration;
+
+use rusoto_core::waiter::{error_code, wait, WaiterConfig, WaiterError, WaiterState};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    CloudFormation, CloudFormationClient, DescribeStacksError, DescribeStacksInput,
+    DescribeStacksOutput,
+};
+
+/// The delay and attempts of the CloudFormation waiters unless they're given a config.
+fn default_config() -> WaiterConfig {
+    WaiterConfig::new(Duration::from_secs(30), 120)
+}
+
+/// Succeeds once all stacks are in `desired`, and fails once any is in one of `failures`.
+fn match_statuses(
+    output: DescribeStacksOutput,
+    desired: &str,
+    failures: &[&str],
+) -> WaiterState<DescribeStacksOutput> {
+    let stacks = output.stacks.as_deref().unwrap_or_default();
+    if let Some(stack) = stacks
+        .iter()
+        .find(|stack| failures.contains(&stack.stack_status.as_str()))
+    {
+        return WaiterState::Failure(match stack.stack_status_reason {
+            Some(ref reason) => format!(
+                "stack {} is {}: {}",
+                stack.stack_name, stack.stack_status, reason
+            ),
+            None => format!("stack {} is {}", stack.stack_name, stack.stack_status),
+        });
+    }
+    if !stacks.is_empty() && stacks.iter().all(|stack| stack.stack_status == desired) {
+        WaiterState::Success(output)
+    } else {
+        WaiterState::Retry
+    }
+}
+
+/// Whether `err` says the stack doesn't exist.
+fn is_validation_error<E>(err: &RusotoError<E>) -> bool {
+    error_code(err).as_deref() == Some("ValidationError")
+}
+
+// Matchers hand back the `RusotoError`s they don't expect.
+#[allow(clippy::result_large_err)]
+impl CloudFormationClient {
+    /// Polls `describe_stacks` until all stacks it describes are created, and returns the
+    /// last response.
+    ///
+    /// Fails once any stack failed to create, rolled back or was deleted. Errors, including
+    /// the `ValidationError` of a stack that doesn't exist, end the wait. Without a `config`
+    /// this polls every 30 seconds, up to 120 times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_cloudformation::{CloudFormationClient, DescribeStacksInput};
+    /// use rusoto_core::Region;
+    ///
+    /// # async fn example() {
+    /// let cloudformation = CloudFormationClient::new(Region::UsEast1);
+    /// let input = DescribeStacksInput {
+    ///     stack_name: Some("my-stack".to_owned()),
+    ///     ..Default::default()
+    /// };
+    /// cloudformation
+    ///     .wait_until_stack_create_complete(input, None)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn wait_until_stack_create_complete(
+        &self,
+        input: DescribeStacksInput,
+        config: Option<WaiterConfig>,
+    ) -> Result<DescribeStacksOutput, WaiterError<DescribeStacksError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_stacks(input.clone()),
+            |result| {
+                result.map(|output| {
+                    match_statuses(
+                        output,
+                        "CREATE_COMPLETE",
+                        &[
+                            "CREATE_FAILED",
+                            "DELETE_COMPLETE",
+                            "DELETE_FAILED",
+                            "ROLLBACK_FAILED",
+                            "ROLLBACK_COMPLETE",
+                        ],
+                    )
+                })
+            },
+        )
+        .await
+    }
+
+    /// Polls `describe_stacks` until all stacks it describes are updated, see
+    /// `wait_until_stack_create_complete`.
+    ///
+    /// Fails once any stack failed to update or rolled back.
+    pub async fn wait_until_stack_update_complete(
+        &self,
+        input: DescribeStacksInput,
+        config: Option<WaiterConfig>,
+    ) -> Result<DescribeStacksOutput, WaiterError<DescribeStacksError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_stacks(input.clone()),
+            |result| {
+                result.map(|output| {
+                    match_statuses(
+                        output,
+                        "UPDATE_COMPLETE",
+                        &[
+                            "UPDATE_FAILED",
+                            "UPDATE_ROLLBACK_FAILED",
+                            "UPDATE_ROLLBACK_COMPLETE",
+                        ],
+                    )
+                })
+            },
+        )
+        .await
+    }
+
+    /// Polls `describe_stacks` until all stacks it describes are deleted, see
+    /// `wait_until_stack_create_complete`.
+    ///
+    /// A stack that doesn't exist anymore (`ValidationError`) is deleted too. Fails once any
+    /// stack failed to delete, or is rolling back an update.
+    pub async fn wait_until_stack_delete_complete(
+        &self,
+        input: DescribeStacksInput,
+        config: Option<WaiterConfig>,
+    ) -> Result<(), WaiterError<DescribeStacksError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_stacks(input.clone()),
+            |result| match result {
+                Ok(output) => Ok(
+                    match match_statuses(
+                        output,
+                        "DELETE_COMPLETE",
+                        &[
+                            "DELETE_FAILED",
+                            "CREATE_FAILED",
+                            "ROLLBACK_FAILED",
+                            "UPDATE_ROLLBACK_IN_PROGRESS",
+                            "UPDATE_ROLLBACK_FAILED",
+                            "UPDATE_ROLLBACK_COMPLETE",
+                        ],
+                    ) {
+                        WaiterState::Success(_) => WaiterState::Success(()),
+                        WaiterState::Retry => WaiterState::Retry,
+                        WaiterState::Failure(state) => WaiterState::Failure(state),
+                    },
+                ),
+                Err(ref err) if is_validation_error(err) => Ok(WaiterState::Success(())),
+                Err(err) => Err(err),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn quick(max_attempts: u32) -> Option<WaiterConfig> {
+        Some(WaiterConfig::new(Duration::from_millis(1), max_attempts))
+    }
+
+    fn input() -> DescribeStacksInput {
+        DescribeStacksInput {
+            stack_name: Some("stack".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn described(status: &str, reason: Option<&str>) -> MockRequestDispatcher {
+        let reason = reason
+            .map(|reason| format!("<StackStatusReason>{}</StackStatusReason>", reason))
+            .unwrap_or_default();
+        MockRequestDispatcher::with_status(200).with_body(&format!(
+            r#"<DescribeStacksResponse xmlns="http://cloudformation.amazonaws.com/doc/2010-05-15/">
+  <DescribeStacksResult>
+    <Stacks>
+      <member>
+        <StackName>stack</StackName>
+        <CreationTime>2020-01-01T00:00:00Z</CreationTime>
+        <StackStatus>{}</StackStatus>{}
+      </member>
+    </Stacks>
+  </DescribeStacksResult>
+  <ResponseMetadata><RequestId>ad4c8bf2-9167-11e2-991c-f387408da2bf</RequestId></ResponseMetadata>
+</DescribeStacksResponse>"#,
+            status, reason
+        ))
+    }
+
+    fn does_not_exist() -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(400).with_body(
+            r#"<ErrorResponse xmlns="http://cloudformation.amazonaws.com/doc/2010-05-15/">
+  <Error><Type>Sender</Type><Code>ValidationError</Code><Message>Stack with id stack does not exist</Message></Error>
+</ErrorResponse>"#,
+        )
+    }
+
+    #[tokio::test]
+    async fn create_complete_waits_while_in_progress() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            described("CREATE_IN_PROGRESS", None),
+            described("CREATE_COMPLETE", None),
+        ]);
+        let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let output = client
+            .wait_until_stack_create_complete(input(), quick(3))
+            .await
+            .unwrap();
+
+        assert_eq!(output.stacks.unwrap()[0].stack_status, "CREATE_COMPLETE");
+    }
+
+    #[tokio::test]
+    async fn create_complete_fails_after_a_rollback() {
+        let mock = described(
+            "ROLLBACK_COMPLETE",
+            Some("The following resource(s) failed"),
+        );
+        let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let result = client
+            .wait_until_stack_create_complete(input(), quick(3))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(WaiterError::Failure(
+                "stack stack is ROLLBACK_COMPLETE: The following resource(s) failed".to_owned()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_complete_succeeds_once_the_stack_is_gone() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            described("DELETE_IN_PROGRESS", None),
+            does_not_exist(),
+        ]);
+        let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        client
+            .wait_until_stack_delete_complete(input(), quick(3))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_complete_fails_on_missing_stacks() {
+        let mock = does_not_exist();
+        let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        match client
+            .wait_until_stack_update_complete(input(), quick(3))
+            .await
+        {
+            Err(WaiterError::Request(RusotoError::Unknown(response))) => {
+                assert_eq!(response.status.as_u16(), 400)
+            }
+            other => panic!("expected a request error, got {:?}", other),
+        }
+    }
+}
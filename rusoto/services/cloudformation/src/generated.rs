@@ -52,7 +52,7 @@ impl CloudFormationClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -9350,7 +9350,7 @@ impl VisibilityDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum CancelUpdateStackError {
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl CancelUpdateStackError {
@@ -9363,7 +9363,7 @@ impl CancelUpdateStackError {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(CancelUpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9380,6 +9380,13 @@ impl CancelUpdateStackError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CancelUpdateStackError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CancelUpdateStackError {
     #[allow(unused_variables)]
@@ -9394,7 +9401,7 @@ impl Error for CancelUpdateStackError {}
 #[derive(Debug, PartialEq)]
 pub enum ContinueUpdateRollbackError {
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl ContinueUpdateRollbackError {
@@ -9407,7 +9414,9 @@ impl ContinueUpdateRollbackError {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(
-                            ContinueUpdateRollbackError::TokenAlreadyExists(parsed_error.message),
+                            ContinueUpdateRollbackError::TokenAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9424,6 +9433,13 @@ impl ContinueUpdateRollbackError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ContinueUpdateRollbackError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ContinueUpdateRollbackError {
     #[allow(unused_variables)]
@@ -9438,11 +9454,11 @@ impl Error for ContinueUpdateRollbackError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateChangeSetError {
     /// <p>The resource with the name requested already exists.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>The template contains resources with capabilities that weren't specified in the Capabilities parameter.</p>
-    InsufficientCapabilities(String),
+    InsufficientCapabilities(XmlErrorMessage),
     /// <p>The quota for the resource has already been reached.</p> <p>For information on resource and stack limitations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateChangeSetError {
@@ -9455,17 +9471,19 @@ impl CreateChangeSetError {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
                         return RusotoError::Service(CreateChangeSetError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientCapabilitiesException" => {
                         return RusotoError::Service(
-                            CreateChangeSetError::InsufficientCapabilities(parsed_error.message),
+                            CreateChangeSetError::InsufficientCapabilities(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(CreateChangeSetError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9482,6 +9500,15 @@ impl CreateChangeSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateChangeSetError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateChangeSetError::InsufficientCapabilities(ref cause) => cause.request_id(),
+            CreateChangeSetError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateChangeSetError {
     #[allow(unused_variables)]
@@ -9498,13 +9525,13 @@ impl Error for CreateChangeSetError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStackError {
     /// <p>The resource with the name requested already exists.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>The template contains resources with capabilities that weren't specified in the Capabilities parameter.</p>
-    InsufficientCapabilities(String),
+    InsufficientCapabilities(XmlErrorMessage),
     /// <p>The quota for the resource has already been reached.</p> <p>For information on resource and stack limitations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateStackError {
@@ -9517,22 +9544,22 @@ impl CreateStackError {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
                         return RusotoError::Service(CreateStackError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientCapabilitiesException" => {
                         return RusotoError::Service(CreateStackError::InsufficientCapabilities(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(CreateStackError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(CreateStackError::TokenAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9549,6 +9576,16 @@ impl CreateStackError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStackError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateStackError::InsufficientCapabilities(ref cause) => cause.request_id(),
+            CreateStackError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateStackError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateStackError {
     #[allow(unused_variables)]
@@ -9566,17 +9603,17 @@ impl Error for CreateStackError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>The quota for the resource has already been reached.</p> <p>For information on resource and stack limitations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The specified operation ID already exists.</p>
-    OperationIdAlreadyExists(String),
+    OperationIdAlreadyExists(XmlErrorMessage),
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
-    StaleRequest(String),
+    StaleRequest(XmlErrorMessage),
 }
 
 impl CreateStackInstancesError {
@@ -9589,34 +9626,36 @@ impl CreateStackInstancesError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(CreateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(CreateStackInstancesError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationIdAlreadyExistsException" => {
                         return RusotoError::Service(
                             CreateStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressException" => {
                         return RusotoError::Service(
-                            CreateStackInstancesError::OperationInProgress(parsed_error.message),
+                            CreateStackInstancesError::OperationInProgress(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(CreateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleRequestException" => {
                         return RusotoError::Service(CreateStackInstancesError::StaleRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9633,6 +9672,18 @@ impl CreateStackInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStackInstancesError::InvalidOperation(ref cause) => cause.request_id(),
+            CreateStackInstancesError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateStackInstancesError::OperationIdAlreadyExists(ref cause) => cause.request_id(),
+            CreateStackInstancesError::OperationInProgress(ref cause) => cause.request_id(),
+            CreateStackInstancesError::StackSetNotFound(ref cause) => cause.request_id(),
+            CreateStackInstancesError::StaleRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateStackInstancesError {
     #[allow(unused_variables)]
@@ -9654,11 +9705,11 @@ impl Error for CreateStackInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStackSetError {
     /// <p>The specified resource exists, but has been changed.</p>
-    CreatedButModified(String),
+    CreatedButModified(XmlErrorMessage),
     /// <p>The quota for the resource has already been reached.</p> <p>For information on resource and stack limitations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The specified name is already in use.</p>
-    NameAlreadyExists(String),
+    NameAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateStackSetError {
@@ -9671,17 +9722,17 @@ impl CreateStackSetError {
                 match &parsed_error.code[..] {
                     "CreatedButModifiedException" => {
                         return RusotoError::Service(CreateStackSetError::CreatedButModified(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(CreateStackSetError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NameAlreadyExistsException" => {
                         return RusotoError::Service(CreateStackSetError::NameAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9698,6 +9749,15 @@ impl CreateStackSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStackSetError::CreatedButModified(ref cause) => cause.request_id(),
+            CreateStackSetError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateStackSetError::NameAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateStackSetError {
     #[allow(unused_variables)]
@@ -9714,7 +9774,7 @@ impl Error for CreateStackSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteChangeSetError {
     /// <p>The specified change set can't be used to update the stack. For example, the change set status might be <code>CREATE_IN_PROGRESS</code>, or the stack status might be <code>UPDATE_IN_PROGRESS</code>.</p>
-    InvalidChangeSetStatus(String),
+    InvalidChangeSetStatus(XmlErrorMessage),
 }
 
 impl DeleteChangeSetError {
@@ -9727,7 +9787,7 @@ impl DeleteChangeSetError {
                 match &parsed_error.code[..] {
                     "InvalidChangeSetStatus" => {
                         return RusotoError::Service(DeleteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9744,6 +9804,13 @@ impl DeleteChangeSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteChangeSetError::InvalidChangeSetStatus(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteChangeSetError {
     #[allow(unused_variables)]
@@ -9758,7 +9825,7 @@ impl Error for DeleteChangeSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackError {
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl DeleteStackError {
@@ -9771,7 +9838,7 @@ impl DeleteStackError {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(DeleteStackError::TokenAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9788,6 +9855,13 @@ impl DeleteStackError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteStackError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteStackError {
     #[allow(unused_variables)]
@@ -9802,15 +9876,15 @@ impl Error for DeleteStackError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>The specified operation ID already exists.</p>
-    OperationIdAlreadyExists(String),
+    OperationIdAlreadyExists(XmlErrorMessage),
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
-    StaleRequest(String),
+    StaleRequest(XmlErrorMessage),
 }
 
 impl DeleteStackInstancesError {
@@ -9823,29 +9897,31 @@ impl DeleteStackInstancesError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(DeleteStackInstancesError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationIdAlreadyExistsException" => {
                         return RusotoError::Service(
                             DeleteStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressException" => {
                         return RusotoError::Service(
-                            DeleteStackInstancesError::OperationInProgress(parsed_error.message),
+                            DeleteStackInstancesError::OperationInProgress(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(DeleteStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleRequestException" => {
                         return RusotoError::Service(DeleteStackInstancesError::StaleRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9862,6 +9938,17 @@ impl DeleteStackInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteStackInstancesError::InvalidOperation(ref cause) => cause.request_id(),
+            DeleteStackInstancesError::OperationIdAlreadyExists(ref cause) => cause.request_id(),
+            DeleteStackInstancesError::OperationInProgress(ref cause) => cause.request_id(),
+            DeleteStackInstancesError::StackSetNotFound(ref cause) => cause.request_id(),
+            DeleteStackInstancesError::StaleRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteStackInstancesError {
     #[allow(unused_variables)]
@@ -9882,9 +9969,9 @@ impl Error for DeleteStackInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackSetError {
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>You can't yet delete this stack set, because it still contains one or more stack instances. Delete all stack instances from the stack set before deleting the stack set.</p>
-    StackSetNotEmpty(String),
+    StackSetNotEmpty(XmlErrorMessage),
 }
 
 impl DeleteStackSetError {
@@ -9897,12 +9984,12 @@ impl DeleteStackSetError {
                 match &parsed_error.code[..] {
                     "OperationInProgressException" => {
                         return RusotoError::Service(DeleteStackSetError::OperationInProgress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StackSetNotEmptyException" => {
                         return RusotoError::Service(DeleteStackSetError::StackSetNotEmpty(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9919,6 +10006,14 @@ impl DeleteStackSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteStackSetError::OperationInProgress(ref cause) => cause.request_id(),
+            DeleteStackSetError::StackSetNotEmpty(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteStackSetError {
     #[allow(unused_variables)]
@@ -9934,9 +10029,9 @@ impl Error for DeleteStackSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
-    TypeNotFound(String),
+    TypeNotFound(XmlErrorMessage),
 }
 
 impl DeregisterTypeError {
@@ -9949,12 +10044,12 @@ impl DeregisterTypeError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(DeregisterTypeError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TypeNotFoundException" => {
                         return RusotoError::Service(DeregisterTypeError::TypeNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9971,6 +10066,14 @@ impl DeregisterTypeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeregisterTypeError::CFNRegistry(ref cause) => cause.request_id(),
+            DeregisterTypeError::TypeNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeregisterTypeError {
     #[allow(unused_variables)]
@@ -10008,6 +10111,11 @@ impl DescribeAccountLimitsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
@@ -10020,7 +10128,7 @@ impl Error for DescribeAccountLimitsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeChangeSetError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
-    ChangeSetNotFound(String),
+    ChangeSetNotFound(XmlErrorMessage),
 }
 
 impl DescribeChangeSetError {
@@ -10033,7 +10141,7 @@ impl DescribeChangeSetError {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
                         return RusotoError::Service(DescribeChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10050,6 +10158,13 @@ impl DescribeChangeSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeChangeSetError::ChangeSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeChangeSetError {
     #[allow(unused_variables)]
@@ -10088,6 +10203,11 @@ impl DescribeStackDriftDetectionStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStackDriftDetectionStatusError {
     #[allow(unused_variables)]
@@ -10122,6 +10242,11 @@ impl DescribeStackEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStackEventsError {
     #[allow(unused_variables)]
@@ -10134,9 +10259,9 @@ impl Error for DescribeStackEventsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackInstanceError {
     /// <p>The specified stack instance doesn't exist.</p>
-    StackInstanceNotFound(String),
+    StackInstanceNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl DescribeStackInstanceError {
@@ -10149,12 +10274,14 @@ impl DescribeStackInstanceError {
                 match &parsed_error.code[..] {
                     "StackInstanceNotFoundException" => {
                         return RusotoError::Service(
-                            DescribeStackInstanceError::StackInstanceNotFound(parsed_error.message),
+                            DescribeStackInstanceError::StackInstanceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(DescribeStackInstanceError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10171,6 +10298,14 @@ impl DescribeStackInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeStackInstanceError::StackInstanceNotFound(ref cause) => cause.request_id(),
+            DescribeStackInstanceError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeStackInstanceError {
     #[allow(unused_variables)]
@@ -10208,6 +10343,11 @@ impl DescribeStackResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStackResourceError {
     #[allow(unused_variables)]
@@ -10244,6 +10384,11 @@ impl DescribeStackResourceDriftsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStackResourceDriftsError {
     #[allow(unused_variables)]
@@ -10278,6 +10423,11 @@ impl DescribeStackResourcesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStackResourcesError {
     #[allow(unused_variables)]
@@ -10290,7 +10440,7 @@ impl Error for DescribeStackResourcesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackSetError {
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl DescribeStackSetError {
@@ -10303,7 +10453,7 @@ impl DescribeStackSetError {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(DescribeStackSetError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10320,6 +10470,13 @@ impl DescribeStackSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeStackSetError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeStackSetError {
     #[allow(unused_variables)]
@@ -10334,9 +10491,9 @@ impl Error for DescribeStackSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackSetOperationError {
     /// <p>The specified ID refers to an operation that doesn't exist.</p>
-    OperationNotFound(String),
+    OperationNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl DescribeStackSetOperationError {
@@ -10349,12 +10506,16 @@ impl DescribeStackSetOperationError {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
                         return RusotoError::Service(
-                            DescribeStackSetOperationError::OperationNotFound(parsed_error.message),
+                            DescribeStackSetOperationError::OperationNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(
-                            DescribeStackSetOperationError::StackSetNotFound(parsed_error.message),
+                            DescribeStackSetOperationError::StackSetNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10371,6 +10532,14 @@ impl DescribeStackSetOperationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeStackSetOperationError::OperationNotFound(ref cause) => cause.request_id(),
+            DescribeStackSetOperationError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeStackSetOperationError {
     #[allow(unused_variables)]
@@ -10408,6 +10577,11 @@ impl DescribeStacksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStacksError {
     #[allow(unused_variables)]
@@ -10420,9 +10594,9 @@ impl Error for DescribeStacksError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
-    TypeNotFound(String),
+    TypeNotFound(XmlErrorMessage),
 }
 
 impl DescribeTypeError {
@@ -10435,12 +10609,12 @@ impl DescribeTypeError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(DescribeTypeError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TypeNotFoundException" => {
                         return RusotoError::Service(DescribeTypeError::TypeNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10457,6 +10631,14 @@ impl DescribeTypeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTypeError::CFNRegistry(ref cause) => cause.request_id(),
+            DescribeTypeError::TypeNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTypeError {
     #[allow(unused_variables)]
@@ -10472,7 +10654,7 @@ impl Error for DescribeTypeError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTypeRegistrationError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
 }
 
 impl DescribeTypeRegistrationError {
@@ -10485,7 +10667,7 @@ impl DescribeTypeRegistrationError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(DescribeTypeRegistrationError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10502,6 +10684,13 @@ impl DescribeTypeRegistrationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTypeRegistrationError::CFNRegistry(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTypeRegistrationError {
     #[allow(unused_variables)]
@@ -10538,6 +10727,11 @@ impl DetectStackDriftError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetectStackDriftError {
     #[allow(unused_variables)]
@@ -10572,6 +10766,11 @@ impl DetectStackResourceDriftError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetectStackResourceDriftError {
     #[allow(unused_variables)]
@@ -10584,11 +10783,11 @@ impl Error for DetectStackResourceDriftError {}
 #[derive(Debug, PartialEq)]
 pub enum DetectStackSetDriftError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl DetectStackSetDriftError {
@@ -10601,17 +10800,17 @@ impl DetectStackSetDriftError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(DetectStackSetDriftError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationInProgressException" => {
                         return RusotoError::Service(DetectStackSetDriftError::OperationInProgress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(DetectStackSetDriftError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10628,6 +10827,15 @@ impl DetectStackSetDriftError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetectStackSetDriftError::InvalidOperation(ref cause) => cause.request_id(),
+            DetectStackSetDriftError::OperationInProgress(ref cause) => cause.request_id(),
+            DetectStackSetDriftError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetectStackSetDriftError {
     #[allow(unused_variables)]
@@ -10666,6 +10874,11 @@ impl EstimateTemplateCostError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EstimateTemplateCostError {
     #[allow(unused_variables)]
@@ -10678,13 +10891,13 @@ impl Error for EstimateTemplateCostError {}
 #[derive(Debug, PartialEq)]
 pub enum ExecuteChangeSetError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
-    ChangeSetNotFound(String),
+    ChangeSetNotFound(XmlErrorMessage),
     /// <p>The template contains resources with capabilities that weren't specified in the Capabilities parameter.</p>
-    InsufficientCapabilities(String),
+    InsufficientCapabilities(XmlErrorMessage),
     /// <p>The specified change set can't be used to update the stack. For example, the change set status might be <code>CREATE_IN_PROGRESS</code>, or the stack status might be <code>UPDATE_IN_PROGRESS</code>.</p>
-    InvalidChangeSetStatus(String),
+    InvalidChangeSetStatus(XmlErrorMessage),
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl ExecuteChangeSetError {
@@ -10697,22 +10910,24 @@ impl ExecuteChangeSetError {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
                         return RusotoError::Service(ExecuteChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientCapabilitiesException" => {
                         return RusotoError::Service(
-                            ExecuteChangeSetError::InsufficientCapabilities(parsed_error.message),
+                            ExecuteChangeSetError::InsufficientCapabilities(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidChangeSetStatus" => {
                         return RusotoError::Service(ExecuteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(ExecuteChangeSetError::TokenAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10729,6 +10944,16 @@ impl ExecuteChangeSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ExecuteChangeSetError::ChangeSetNotFound(ref cause) => cause.request_id(),
+            ExecuteChangeSetError::InsufficientCapabilities(ref cause) => cause.request_id(),
+            ExecuteChangeSetError::InvalidChangeSetStatus(ref cause) => cause.request_id(),
+            ExecuteChangeSetError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ExecuteChangeSetError {
     #[allow(unused_variables)]
@@ -10768,6 +10993,11 @@ impl GetStackPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetStackPolicyError {
     #[allow(unused_variables)]
@@ -10780,7 +11010,7 @@ impl Error for GetStackPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
-    ChangeSetNotFound(String),
+    ChangeSetNotFound(XmlErrorMessage),
 }
 
 impl GetTemplateError {
@@ -10793,7 +11023,7 @@ impl GetTemplateError {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
                         return RusotoError::Service(GetTemplateError::ChangeSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10810,6 +11040,13 @@ impl GetTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTemplateError::ChangeSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetTemplateError {
     #[allow(unused_variables)]
@@ -10824,7 +11061,7 @@ impl Error for GetTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateSummaryError {
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl GetTemplateSummaryError {
@@ -10837,7 +11074,7 @@ impl GetTemplateSummaryError {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(GetTemplateSummaryError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10854,6 +11091,13 @@ impl GetTemplateSummaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTemplateSummaryError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetTemplateSummaryError {
     #[allow(unused_variables)]
@@ -10890,6 +11134,11 @@ impl ListChangeSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListChangeSetsError {
     #[allow(unused_variables)]
@@ -10924,6 +11173,11 @@ impl ListExportsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListExportsError {
     #[allow(unused_variables)]
@@ -10958,6 +11212,11 @@ impl ListImportsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListImportsError {
     #[allow(unused_variables)]
@@ -10970,7 +11229,7 @@ impl Error for ListImportsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListStackInstancesError {
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl ListStackInstancesError {
@@ -10983,7 +11242,7 @@ impl ListStackInstancesError {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(ListStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11000,6 +11259,13 @@ impl ListStackInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListStackInstancesError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListStackInstancesError {
     #[allow(unused_variables)]
@@ -11036,6 +11302,11 @@ impl ListStackResourcesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListStackResourcesError {
     #[allow(unused_variables)]
@@ -11048,9 +11319,9 @@ impl Error for ListStackResourcesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListStackSetOperationResultsError {
     /// <p>The specified ID refers to an operation that doesn't exist.</p>
-    OperationNotFound(String),
+    OperationNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl ListStackSetOperationResultsError {
@@ -11066,14 +11337,14 @@ impl ListStackSetOperationResultsError {
                     "OperationNotFoundException" => {
                         return RusotoError::Service(
                             ListStackSetOperationResultsError::OperationNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(
                             ListStackSetOperationResultsError::StackSetNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11091,6 +11362,14 @@ impl ListStackSetOperationResultsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListStackSetOperationResultsError::OperationNotFound(ref cause) => cause.request_id(),
+            ListStackSetOperationResultsError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListStackSetOperationResultsError {
     #[allow(unused_variables)]
@@ -11110,7 +11389,7 @@ impl Error for ListStackSetOperationResultsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListStackSetOperationsError {
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl ListStackSetOperationsError {
@@ -11123,7 +11402,7 @@ impl ListStackSetOperationsError {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(ListStackSetOperationsError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11140,6 +11419,13 @@ impl ListStackSetOperationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListStackSetOperationsError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListStackSetOperationsError {
     #[allow(unused_variables)]
@@ -11176,6 +11462,11 @@ impl ListStackSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListStackSetsError {
     #[allow(unused_variables)]
@@ -11210,6 +11501,11 @@ impl ListStacksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListStacksError {
     #[allow(unused_variables)]
@@ -11222,7 +11518,7 @@ impl Error for ListStacksError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTypeRegistrationsError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
 }
 
 impl ListTypeRegistrationsError {
@@ -11235,7 +11531,7 @@ impl ListTypeRegistrationsError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(ListTypeRegistrationsError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11252,6 +11548,13 @@ impl ListTypeRegistrationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTypeRegistrationsError::CFNRegistry(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTypeRegistrationsError {
     #[allow(unused_variables)]
@@ -11266,7 +11569,7 @@ impl Error for ListTypeRegistrationsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTypeVersionsError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
 }
 
 impl ListTypeVersionsError {
@@ -11279,7 +11582,7 @@ impl ListTypeVersionsError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(ListTypeVersionsError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11296,6 +11599,13 @@ impl ListTypeVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTypeVersionsError::CFNRegistry(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTypeVersionsError {
     #[allow(unused_variables)]
@@ -11310,7 +11620,7 @@ impl Error for ListTypeVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTypesError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
 }
 
 impl ListTypesError {
@@ -11323,7 +11633,7 @@ impl ListTypesError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(ListTypesError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11340,6 +11650,13 @@ impl ListTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTypesError::CFNRegistry(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTypesError {
     #[allow(unused_variables)]
@@ -11354,9 +11671,9 @@ impl Error for ListTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum RecordHandlerProgressError {
     /// <p>Error reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. CloudFormation does not return this error to users.</p>
-    InvalidStateTransition(String),
+    InvalidStateTransition(XmlErrorMessage),
     /// <p>Error reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. CloudFormation does not return this error to users.</p>
-    OperationStatusCheckFailed(String),
+    OperationStatusCheckFailed(XmlErrorMessage),
 }
 
 impl RecordHandlerProgressError {
@@ -11370,14 +11687,14 @@ impl RecordHandlerProgressError {
                     "InvalidStateTransition" => {
                         return RusotoError::Service(
                             RecordHandlerProgressError::InvalidStateTransition(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ConditionalCheckFailed" => {
                         return RusotoError::Service(
                             RecordHandlerProgressError::OperationStatusCheckFailed(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11395,6 +11712,14 @@ impl RecordHandlerProgressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RecordHandlerProgressError::InvalidStateTransition(ref cause) => cause.request_id(),
+            RecordHandlerProgressError::OperationStatusCheckFailed(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RecordHandlerProgressError {
     #[allow(unused_variables)]
@@ -11412,7 +11737,7 @@ impl Error for RecordHandlerProgressError {}
 #[derive(Debug, PartialEq)]
 pub enum RegisterTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
 }
 
 impl RegisterTypeError {
@@ -11425,7 +11750,7 @@ impl RegisterTypeError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(RegisterTypeError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11442,6 +11767,13 @@ impl RegisterTypeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RegisterTypeError::CFNRegistry(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RegisterTypeError {
     #[allow(unused_variables)]
@@ -11478,6 +11810,11 @@ impl SetStackPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetStackPolicyError {
     #[allow(unused_variables)]
@@ -11490,9 +11827,9 @@ impl Error for SetStackPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum SetTypeDefaultVersionError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
-    CFNRegistry(String),
+    CFNRegistry(XmlErrorMessage),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
-    TypeNotFound(String),
+    TypeNotFound(XmlErrorMessage),
 }
 
 impl SetTypeDefaultVersionError {
@@ -11505,12 +11842,12 @@ impl SetTypeDefaultVersionError {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
                         return RusotoError::Service(SetTypeDefaultVersionError::CFNRegistry(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TypeNotFoundException" => {
                         return RusotoError::Service(SetTypeDefaultVersionError::TypeNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11527,6 +11864,14 @@ impl SetTypeDefaultVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetTypeDefaultVersionError::CFNRegistry(ref cause) => cause.request_id(),
+            SetTypeDefaultVersionError::TypeNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetTypeDefaultVersionError {
     #[allow(unused_variables)]
@@ -11564,6 +11909,11 @@ impl SignalResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SignalResourceError {
     #[allow(unused_variables)]
@@ -11576,11 +11926,11 @@ impl Error for SignalResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum StopStackSetOperationError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>The specified ID refers to an operation that doesn't exist.</p>
-    OperationNotFound(String),
+    OperationNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
 }
 
 impl StopStackSetOperationError {
@@ -11593,17 +11943,17 @@ impl StopStackSetOperationError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(StopStackSetOperationError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationNotFoundException" => {
                         return RusotoError::Service(StopStackSetOperationError::OperationNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(StopStackSetOperationError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11620,6 +11970,15 @@ impl StopStackSetOperationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopStackSetOperationError::InvalidOperation(ref cause) => cause.request_id(),
+            StopStackSetOperationError::OperationNotFound(ref cause) => cause.request_id(),
+            StopStackSetOperationError::StackSetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopStackSetOperationError {
     #[allow(unused_variables)]
@@ -11636,9 +11995,9 @@ impl Error for StopStackSetOperationError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackError {
     /// <p>The template contains resources with capabilities that weren't specified in the Capabilities parameter.</p>
-    InsufficientCapabilities(String),
+    InsufficientCapabilities(XmlErrorMessage),
     /// <p>A client request token already exists.</p>
-    TokenAlreadyExists(String),
+    TokenAlreadyExists(XmlErrorMessage),
 }
 
 impl UpdateStackError {
@@ -11651,12 +12010,12 @@ impl UpdateStackError {
                 match &parsed_error.code[..] {
                     "InsufficientCapabilitiesException" => {
                         return RusotoError::Service(UpdateStackError::InsufficientCapabilities(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TokenAlreadyExistsException" => {
                         return RusotoError::Service(UpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11673,6 +12032,14 @@ impl UpdateStackError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateStackError::InsufficientCapabilities(ref cause) => cause.request_id(),
+            UpdateStackError::TokenAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateStackError {
     #[allow(unused_variables)]
@@ -11688,17 +12055,17 @@ impl Error for UpdateStackError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>The specified operation ID already exists.</p>
-    OperationIdAlreadyExists(String),
+    OperationIdAlreadyExists(XmlErrorMessage),
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>The specified stack instance doesn't exist.</p>
-    StackInstanceNotFound(String),
+    StackInstanceNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
-    StaleRequest(String),
+    StaleRequest(XmlErrorMessage),
 }
 
 impl UpdateStackInstancesError {
@@ -11711,34 +12078,38 @@ impl UpdateStackInstancesError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(UpdateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationIdAlreadyExistsException" => {
                         return RusotoError::Service(
                             UpdateStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressException" => {
                         return RusotoError::Service(
-                            UpdateStackInstancesError::OperationInProgress(parsed_error.message),
+                            UpdateStackInstancesError::OperationInProgress(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackInstanceNotFoundException" => {
                         return RusotoError::Service(
-                            UpdateStackInstancesError::StackInstanceNotFound(parsed_error.message),
+                            UpdateStackInstancesError::StackInstanceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(UpdateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleRequestException" => {
                         return RusotoError::Service(UpdateStackInstancesError::StaleRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11755,6 +12126,18 @@ impl UpdateStackInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateStackInstancesError::InvalidOperation(ref cause) => cause.request_id(),
+            UpdateStackInstancesError::OperationIdAlreadyExists(ref cause) => cause.request_id(),
+            UpdateStackInstancesError::OperationInProgress(ref cause) => cause.request_id(),
+            UpdateStackInstancesError::StackInstanceNotFound(ref cause) => cause.request_id(),
+            UpdateStackInstancesError::StackSetNotFound(ref cause) => cause.request_id(),
+            UpdateStackInstancesError::StaleRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateStackInstancesError {
     #[allow(unused_variables)]
@@ -11776,17 +12159,17 @@ impl Error for UpdateStackInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackSetError {
     /// <p>The specified operation isn't valid.</p>
-    InvalidOperation(String),
+    InvalidOperation(XmlErrorMessage),
     /// <p>The specified operation ID already exists.</p>
-    OperationIdAlreadyExists(String),
+    OperationIdAlreadyExists(XmlErrorMessage),
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>The specified stack instance doesn't exist.</p>
-    StackInstanceNotFound(String),
+    StackInstanceNotFound(XmlErrorMessage),
     /// <p>The specified stack set doesn't exist.</p>
-    StackSetNotFound(String),
+    StackSetNotFound(XmlErrorMessage),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
-    StaleRequest(String),
+    StaleRequest(XmlErrorMessage),
 }
 
 impl UpdateStackSetError {
@@ -11799,32 +12182,32 @@ impl UpdateStackSetError {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
                         return RusotoError::Service(UpdateStackSetError::InvalidOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationIdAlreadyExistsException" => {
                         return RusotoError::Service(UpdateStackSetError::OperationIdAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationInProgressException" => {
                         return RusotoError::Service(UpdateStackSetError::OperationInProgress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StackInstanceNotFoundException" => {
                         return RusotoError::Service(UpdateStackSetError::StackInstanceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StackSetNotFoundException" => {
                         return RusotoError::Service(UpdateStackSetError::StackSetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleRequestException" => {
                         return RusotoError::Service(UpdateStackSetError::StaleRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11841,6 +12224,18 @@ impl UpdateStackSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateStackSetError::InvalidOperation(ref cause) => cause.request_id(),
+            UpdateStackSetError::OperationIdAlreadyExists(ref cause) => cause.request_id(),
+            UpdateStackSetError::OperationInProgress(ref cause) => cause.request_id(),
+            UpdateStackSetError::StackInstanceNotFound(ref cause) => cause.request_id(),
+            UpdateStackSetError::StackSetNotFound(ref cause) => cause.request_id(),
+            UpdateStackSetError::StaleRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateStackSetError {
     #[allow(unused_variables)]
@@ -11884,6 +12279,11 @@ impl UpdateTerminationProtectionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateTerminationProtectionError {
     #[allow(unused_variables)]
@@ -11918,6 +12318,11 @@ impl ValidateTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ValidateTemplateError {
     #[allow(unused_variables)]
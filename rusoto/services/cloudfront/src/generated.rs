@@ -45,7 +45,7 @@ impl CloudFrontClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -8169,15 +8169,15 @@ impl ViewerProtocolPolicySerializer {
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFrontOriginAccessIdentityError {
     /// <p>If the <code>CallerReference</code> is a value you already sent in a previous request to create an identity but the content of the <code>CloudFrontOriginAccessIdentityConfig</code> is different from the original request, CloudFront returns a <code>CloudFrontOriginAccessIdentityAlreadyExists</code> error. </p>
-    CloudFrontOriginAccessIdentityAlreadyExists(String),
+    CloudFrontOriginAccessIdentityAlreadyExists(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of origin access identities allowed.</p>
-    TooManyCloudFrontOriginAccessIdentities(String),
+    TooManyCloudFrontOriginAccessIdentities(XmlErrorMessage),
 }
 
 impl CreateCloudFrontOriginAccessIdentityError {
@@ -8190,7 +8190,7 @@ impl CreateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message)),_ => {}
+                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8204,6 +8204,17 @@ impl CreateCloudFrontOriginAccessIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(ref cause) => cause.request_id(),
+            CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateCloudFrontOriginAccessIdentityError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateCloudFrontOriginAccessIdentityError::MissingBody(ref cause) => cause.request_id(),
+            CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCloudFrontOriginAccessIdentityError {
     #[allow(unused_variables)]
@@ -8222,93 +8233,93 @@ impl Error for CreateCloudFrontOriginAccessIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>The caller reference you attempted to create the distribution with is associated with another distribution.</p>
-    DistributionAlreadyExists(String),
+    DistributionAlreadyExists(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption can't be associated with the specified cache behavior.</p>
-    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(String),
+    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The default root object file name is too big or contains an invalid character.</p>
-    InvalidDefaultRootObject(String),
+    InvalidDefaultRootObject(XmlErrorMessage),
     /// <p>An invalid error code was specified.</p>
-    InvalidErrorCode(String),
+    InvalidErrorCode(XmlErrorMessage),
     /// <p>Your request contains forward cookies option which doesn't match with the expectation for the <code>whitelisted</code> list of cookie names. Either list of cookie names has been specified when not allowed or list of cookie names is missing when expected.</p>
-    InvalidForwardCookies(String),
+    InvalidForwardCookies(XmlErrorMessage),
     /// <p>The specified geo restriction parameter is not valid.</p>
-    InvalidGeoRestrictionParameter(String),
+    InvalidGeoRestrictionParameter(XmlErrorMessage),
     /// <p>The headers specified are not valid for an Amazon S3 origin.</p>
-    InvalidHeadersForS3Origin(String),
+    InvalidHeadersForS3Origin(XmlErrorMessage),
     /// <p>The specified Lambda function association is invalid.</p>
-    InvalidLambdaFunctionAssociation(String),
+    InvalidLambdaFunctionAssociation(XmlErrorMessage),
     /// <p>The location code specified is not valid.</p>
-    InvalidLocationCode(String),
+    InvalidLocationCode(XmlErrorMessage),
     /// <p>The minimum protocol version specified is not valid.</p>
-    InvalidMinimumProtocolVersion(String),
+    InvalidMinimumProtocolVersion(XmlErrorMessage),
     /// <p>The Amazon S3 origin server specified does not refer to a valid Amazon S3 bucket.</p>
-    InvalidOrigin(String),
+    InvalidOrigin(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>The keep alive timeout specified for the origin is not valid.</p>
-    InvalidOriginKeepaliveTimeout(String),
+    InvalidOriginKeepaliveTimeout(XmlErrorMessage),
     /// <p>The read timeout specified for the origin is not valid.</p>
-    InvalidOriginReadTimeout(String),
+    InvalidOriginReadTimeout(XmlErrorMessage),
     /// <p>You cannot specify SSLv3 as the minimum protocol version if you only want to support only clients that support Server Name Indication (SNI).</p>
-    InvalidProtocolSettings(String),
+    InvalidProtocolSettings(XmlErrorMessage),
     /// <p>The query string parameters specified are not valid.</p>
-    InvalidQueryStringParameters(String),
+    InvalidQueryStringParameters(XmlErrorMessage),
     /// <p>The relative path is too big, is not URL-encoded, or does not begin with a slash (/).</p>
-    InvalidRelativePath(String),
+    InvalidRelativePath(XmlErrorMessage),
     /// <p>This operation requires the HTTPS protocol. Ensure that you specify the HTTPS protocol in your request, or omit the <code>RequiredProtocols</code> element from your distribution configuration.</p>
-    InvalidRequiredProtocol(String),
+    InvalidRequiredProtocol(XmlErrorMessage),
     /// <p>A response code is not valid.</p>
-    InvalidResponseCode(String),
+    InvalidResponseCode(XmlErrorMessage),
     /// <p>The TTL order specified is not valid.</p>
-    InvalidTTLOrder(String),
+    InvalidTTLOrder(XmlErrorMessage),
     /// <p>A viewer certificate specified is not valid.</p>
-    InvalidViewerCertificate(String),
+    InvalidViewerCertificate(XmlErrorMessage),
     /// <p>A web ACL ID specified is not valid. To specify a web ACL created using the latest version of AWS WAF, use the ACL ARN, for example <code>arn:aws:wafv2:us-east-1:123456789012:global/webacl/ExampleWebACL/473e64fd-f30b-4765-81a0-62ad96dd167a</code>. To specify a web ACL created using AWS WAF Classic, use the ACL ID, for example <code>473e64fd-f30b-4765-81a0-62ad96dd167a</code>.</p>
-    InvalidWebACLId(String),
+    InvalidWebACLId(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>No origin exists with the specified <code>Origin Id</code>. </p>
-    NoSuchOrigin(String),
+    NoSuchOrigin(XmlErrorMessage),
     /// <p>You cannot create more cache behaviors for the distribution.</p>
-    TooManyCacheBehaviors(String),
+    TooManyCacheBehaviors(XmlErrorMessage),
     /// <p>You cannot create anymore custom SSL/TLS certificates.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
     /// <p>Your request contains more cookie names in the whitelist than are allowed per cache behavior.</p>
-    TooManyCookieNamesInWhiteList(String),
+    TooManyCookieNamesInWhiteList(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyDistributionCNAMEs(String),
+    TooManyDistributionCNAMEs(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of distributions allowed.</p>
-    TooManyDistributions(String),
+    TooManyDistributions(XmlErrorMessage),
     /// <p>The maximum number of distributions have been associated with the specified configuration for field-level encryption.</p>
-    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(String),
+    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>Processing your request would cause the maximum number of distributions with Lambda function associations per owner to be exceeded.</p>
-    TooManyDistributionsWithLambdaAssociations(String),
+    TooManyDistributionsWithLambdaAssociations(XmlErrorMessage),
     /// <p>Your request contains too many headers in forwarded values.</p>
-    TooManyHeadersInForwardedValues(String),
+    TooManyHeadersInForwardedValues(XmlErrorMessage),
     /// <p>Your request contains more Lambda function associations than are allowed per distribution.</p>
-    TooManyLambdaFunctionAssociations(String),
+    TooManyLambdaFunctionAssociations(XmlErrorMessage),
     /// <p>Your request contains too many origin custom headers.</p>
-    TooManyOriginCustomHeaders(String),
+    TooManyOriginCustomHeaders(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of origin groups allowed.</p>
-    TooManyOriginGroupsPerDistribution(String),
+    TooManyOriginGroupsPerDistribution(XmlErrorMessage),
     /// <p>You cannot create more origins for the distribution.</p>
-    TooManyOrigins(String),
+    TooManyOrigins(XmlErrorMessage),
     /// <p>Your request contains too many query string parameters.</p>
-    TooManyQueryStringParameters(String),
+    TooManyQueryStringParameters(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl CreateDistributionError {
@@ -8319,7 +8330,7 @@ impl CreateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(CreateDistributionError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionError::CNAMEAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionError::DistributionAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateDistributionError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionError::InvalidDefaultRootObject(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionError::InvalidErrorCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionError::InvalidForwardCookies(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionError::InvalidGeoRestrictionParameter(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionError::InvalidHeadersForS3Origin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionError::InvalidLambdaFunctionAssociation(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionError::InvalidLocationCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionError::InvalidMinimumProtocolVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOrigin" => return RusotoError::Service(CreateDistributionError::InvalidOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionError::InvalidOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginKeepaliveTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginReadTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionError::InvalidProtocolSettings(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionError::InvalidQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionError::InvalidRelativePath(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionError::InvalidRequiredProtocol(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionError::InvalidResponseCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionError::InvalidTTLOrder(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionError::InvalidViewerCertificate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionError::InvalidWebACLId(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(CreateDistributionError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionError::NoSuchOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionError::TooManyCacheBehaviors(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCertificates" => return RusotoError::Service(CreateDistributionError::TooManyCertificates(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionError::TooManyCookieNamesInWhiteList(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionError::TooManyDistributionCNAMEs(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributions" => return RusotoError::Service(CreateDistributionError::TooManyDistributions(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionError::TooManyHeadersInForwardedValues(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionError::TooManyLambdaFunctionAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionError::TooManyOriginCustomHeaders(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionError::TooManyOriginGroupsPerDistribution(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOrigins" => return RusotoError::Service(CreateDistributionError::TooManyOrigins(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionError::TooManyQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionError::TooManyTrustedSigners(XmlErrorMessage::from_response(parsed_error.message, &res))),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedSignerDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8333,6 +8344,56 @@ impl CreateDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            CreateDistributionError::CNAMEAlreadyExists(ref cause) => cause.request_id(),
+            CreateDistributionError::DistributionAlreadyExists(ref cause) => cause.request_id(),
+            CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(ref cause) => cause.request_id(),
+            CreateDistributionError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidDefaultRootObject(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidErrorCode(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidForwardCookies(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidGeoRestrictionParameter(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidHeadersForS3Origin(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidLambdaFunctionAssociation(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidLocationCode(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidMinimumProtocolVersion(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidOrigin(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidOriginAccessIdentity(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidOriginKeepaliveTimeout(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidOriginReadTimeout(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidProtocolSettings(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidQueryStringParameters(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidRelativePath(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidRequiredProtocol(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidResponseCode(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidTTLOrder(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidViewerCertificate(ref cause) => cause.request_id(),
+            CreateDistributionError::InvalidWebACLId(ref cause) => cause.request_id(),
+            CreateDistributionError::MissingBody(ref cause) => cause.request_id(),
+            CreateDistributionError::NoSuchFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            CreateDistributionError::NoSuchOrigin(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyCacheBehaviors(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyCertificates(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyCookieNamesInWhiteList(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyDistributionCNAMEs(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyDistributions(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyDistributionsWithLambdaAssociations(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyHeadersInForwardedValues(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyLambdaFunctionAssociations(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyOriginCustomHeaders(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyOriginGroupsPerDistribution(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyOrigins(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyQueryStringParameters(ref cause) => cause.request_id(),
+            CreateDistributionError::TooManyTrustedSigners(ref cause) => cause.request_id(),
+            CreateDistributionError::TrustedSignerDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDistributionError {
     #[allow(unused_variables)]
@@ -8390,95 +8451,95 @@ impl Error for CreateDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionWithTagsError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>The caller reference you attempted to create the distribution with is associated with another distribution.</p>
-    DistributionAlreadyExists(String),
+    DistributionAlreadyExists(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption can't be associated with the specified cache behavior.</p>
-    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(String),
+    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The default root object file name is too big or contains an invalid character.</p>
-    InvalidDefaultRootObject(String),
+    InvalidDefaultRootObject(XmlErrorMessage),
     /// <p>An invalid error code was specified.</p>
-    InvalidErrorCode(String),
+    InvalidErrorCode(XmlErrorMessage),
     /// <p>Your request contains forward cookies option which doesn't match with the expectation for the <code>whitelisted</code> list of cookie names. Either list of cookie names has been specified when not allowed or list of cookie names is missing when expected.</p>
-    InvalidForwardCookies(String),
+    InvalidForwardCookies(XmlErrorMessage),
     /// <p>The specified geo restriction parameter is not valid.</p>
-    InvalidGeoRestrictionParameter(String),
+    InvalidGeoRestrictionParameter(XmlErrorMessage),
     /// <p>The headers specified are not valid for an Amazon S3 origin.</p>
-    InvalidHeadersForS3Origin(String),
+    InvalidHeadersForS3Origin(XmlErrorMessage),
     /// <p>The specified Lambda function association is invalid.</p>
-    InvalidLambdaFunctionAssociation(String),
+    InvalidLambdaFunctionAssociation(XmlErrorMessage),
     /// <p>The location code specified is not valid.</p>
-    InvalidLocationCode(String),
+    InvalidLocationCode(XmlErrorMessage),
     /// <p>The minimum protocol version specified is not valid.</p>
-    InvalidMinimumProtocolVersion(String),
+    InvalidMinimumProtocolVersion(XmlErrorMessage),
     /// <p>The Amazon S3 origin server specified does not refer to a valid Amazon S3 bucket.</p>
-    InvalidOrigin(String),
+    InvalidOrigin(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>The keep alive timeout specified for the origin is not valid.</p>
-    InvalidOriginKeepaliveTimeout(String),
+    InvalidOriginKeepaliveTimeout(XmlErrorMessage),
     /// <p>The read timeout specified for the origin is not valid.</p>
-    InvalidOriginReadTimeout(String),
+    InvalidOriginReadTimeout(XmlErrorMessage),
     /// <p>You cannot specify SSLv3 as the minimum protocol version if you only want to support only clients that support Server Name Indication (SNI).</p>
-    InvalidProtocolSettings(String),
+    InvalidProtocolSettings(XmlErrorMessage),
     /// <p>The query string parameters specified are not valid.</p>
-    InvalidQueryStringParameters(String),
+    InvalidQueryStringParameters(XmlErrorMessage),
     /// <p>The relative path is too big, is not URL-encoded, or does not begin with a slash (/).</p>
-    InvalidRelativePath(String),
+    InvalidRelativePath(XmlErrorMessage),
     /// <p>This operation requires the HTTPS protocol. Ensure that you specify the HTTPS protocol in your request, or omit the <code>RequiredProtocols</code> element from your distribution configuration.</p>
-    InvalidRequiredProtocol(String),
+    InvalidRequiredProtocol(XmlErrorMessage),
     /// <p>A response code is not valid.</p>
-    InvalidResponseCode(String),
+    InvalidResponseCode(XmlErrorMessage),
     /// <p>The TTL order specified is not valid.</p>
-    InvalidTTLOrder(String),
+    InvalidTTLOrder(XmlErrorMessage),
     /// <p>The tagging specified is not valid.</p>
-    InvalidTagging(String),
+    InvalidTagging(XmlErrorMessage),
     /// <p>A viewer certificate specified is not valid.</p>
-    InvalidViewerCertificate(String),
+    InvalidViewerCertificate(XmlErrorMessage),
     /// <p>A web ACL ID specified is not valid. To specify a web ACL created using the latest version of AWS WAF, use the ACL ARN, for example <code>arn:aws:wafv2:us-east-1:123456789012:global/webacl/ExampleWebACL/473e64fd-f30b-4765-81a0-62ad96dd167a</code>. To specify a web ACL created using AWS WAF Classic, use the ACL ID, for example <code>473e64fd-f30b-4765-81a0-62ad96dd167a</code>.</p>
-    InvalidWebACLId(String),
+    InvalidWebACLId(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>No origin exists with the specified <code>Origin Id</code>. </p>
-    NoSuchOrigin(String),
+    NoSuchOrigin(XmlErrorMessage),
     /// <p>You cannot create more cache behaviors for the distribution.</p>
-    TooManyCacheBehaviors(String),
+    TooManyCacheBehaviors(XmlErrorMessage),
     /// <p>You cannot create anymore custom SSL/TLS certificates.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
     /// <p>Your request contains more cookie names in the whitelist than are allowed per cache behavior.</p>
-    TooManyCookieNamesInWhiteList(String),
+    TooManyCookieNamesInWhiteList(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyDistributionCNAMEs(String),
+    TooManyDistributionCNAMEs(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of distributions allowed.</p>
-    TooManyDistributions(String),
+    TooManyDistributions(XmlErrorMessage),
     /// <p>The maximum number of distributions have been associated with the specified configuration for field-level encryption.</p>
-    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(String),
+    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>Processing your request would cause the maximum number of distributions with Lambda function associations per owner to be exceeded.</p>
-    TooManyDistributionsWithLambdaAssociations(String),
+    TooManyDistributionsWithLambdaAssociations(XmlErrorMessage),
     /// <p>Your request contains too many headers in forwarded values.</p>
-    TooManyHeadersInForwardedValues(String),
+    TooManyHeadersInForwardedValues(XmlErrorMessage),
     /// <p>Your request contains more Lambda function associations than are allowed per distribution.</p>
-    TooManyLambdaFunctionAssociations(String),
+    TooManyLambdaFunctionAssociations(XmlErrorMessage),
     /// <p>Your request contains too many origin custom headers.</p>
-    TooManyOriginCustomHeaders(String),
+    TooManyOriginCustomHeaders(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of origin groups allowed.</p>
-    TooManyOriginGroupsPerDistribution(String),
+    TooManyOriginGroupsPerDistribution(XmlErrorMessage),
     /// <p>You cannot create more origins for the distribution.</p>
-    TooManyOrigins(String),
+    TooManyOrigins(XmlErrorMessage),
     /// <p>Your request contains too many query string parameters.</p>
-    TooManyQueryStringParameters(String),
+    TooManyQueryStringParameters(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl CreateDistributionWithTagsError {
@@ -8491,7 +8552,7 @@ impl CreateDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTagging(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionWithTagsError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(CreateDistributionWithTagsError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::CNAMEAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::DistributionAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionWithTagsError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidDefaultRootObject(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidErrorCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidForwardCookies(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLocationCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidProtocolSettings(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRelativePath(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRequiredProtocol(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidResponseCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTTLOrder(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTagging" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTagging(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidViewerCertificate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidWebACLId(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(CreateDistributionWithTagsError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCacheBehaviors(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCertificates" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCertificates(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributions" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributions(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOrigins" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOrigins(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyTrustedSigners(XmlErrorMessage::from_response(parsed_error.message, &res))),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8505,6 +8566,57 @@ impl CreateDistributionWithTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDistributionWithTagsError::AccessDenied(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::CNAMEAlreadyExists(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::DistributionAlreadyExists(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidDefaultRootObject(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidErrorCode(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidForwardCookies(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidHeadersForS3Origin(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidLocationCode(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidOrigin(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidOriginAccessIdentity(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidOriginReadTimeout(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidProtocolSettings(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidQueryStringParameters(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidRelativePath(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidRequiredProtocol(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidResponseCode(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidTTLOrder(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidTagging(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidViewerCertificate(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::InvalidWebACLId(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::MissingBody(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::NoSuchOrigin(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyCacheBehaviors(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyCertificates(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyDistributionCNAMEs(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyDistributions(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyOriginCustomHeaders(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyOrigins(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyQueryStringParameters(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TooManyTrustedSigners(ref cause) => cause.request_id(),
+            CreateDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDistributionWithTagsError {
     #[allow(unused_variables)]
@@ -8563,21 +8675,21 @@ impl Error for CreateDistributionWithTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateFieldLevelEncryptionConfigError {
     /// <p>The specified configuration for field-level encryption already exists.</p>
-    FieldLevelEncryptionConfigAlreadyExists(String),
+    FieldLevelEncryptionConfigAlreadyExists(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
     /// <p>No profile specified for the field-level encryption query argument.</p>
-    QueryArgProfileEmpty(String),
+    QueryArgProfileEmpty(XmlErrorMessage),
     /// <p>The maximum number of configurations for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionConfigs(String),
+    TooManyFieldLevelEncryptionConfigs(XmlErrorMessage),
     /// <p>The maximum number of content type profiles for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionContentTypeProfiles(String),
+    TooManyFieldLevelEncryptionContentTypeProfiles(XmlErrorMessage),
     /// <p>The maximum number of query arg profiles for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionQueryArgProfiles(String),
+    TooManyFieldLevelEncryptionQueryArgProfiles(XmlErrorMessage),
 }
 
 impl CreateFieldLevelEncryptionConfigError {
@@ -8590,7 +8702,7 @@ impl CreateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(XmlErrorMessage::from_response(parsed_error.message, &res))),"QueryArgProfileEmpty" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8604,6 +8716,20 @@ impl CreateFieldLevelEncryptionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateFieldLevelEncryptionConfigError {
     #[allow(unused_variables)]
@@ -8625,21 +8751,21 @@ impl Error for CreateFieldLevelEncryptionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateFieldLevelEncryptionProfileError {
     /// <p>The specified profile for field-level encryption already exists.</p>
-    FieldLevelEncryptionProfileAlreadyExists(String),
+    FieldLevelEncryptionProfileAlreadyExists(XmlErrorMessage),
     /// <p>The maximum size of a profile for field-level encryption was exceeded.</p>
-    FieldLevelEncryptionProfileSizeExceeded(String),
+    FieldLevelEncryptionProfileSizeExceeded(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
     /// <p>The maximum number of encryption entities for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionEncryptionEntities(String),
+    TooManyFieldLevelEncryptionEncryptionEntities(XmlErrorMessage),
     /// <p>The maximum number of field patterns for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionFieldPatterns(String),
+    TooManyFieldLevelEncryptionFieldPatterns(XmlErrorMessage),
     /// <p>The maximum number of profiles for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionProfiles(String),
+    TooManyFieldLevelEncryptionProfiles(XmlErrorMessage),
 }
 
 impl CreateFieldLevelEncryptionProfileError {
@@ -8652,7 +8778,7 @@ impl CreateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchPublicKey" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8666,6 +8792,20 @@ impl CreateFieldLevelEncryptionProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(ref cause) => cause.request_id(),
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateFieldLevelEncryptionProfileError {
     #[allow(unused_variables)]
@@ -8687,19 +8827,19 @@ impl Error for CreateFieldLevelEncryptionProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateInvalidationError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>Invalidation batch specified is too large.</p>
-    BatchTooLarge(String),
+    BatchTooLarge(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of allowable InProgress invalidation batch requests, or invalidation objects.</p>
-    TooManyInvalidationsInProgress(String),
+    TooManyInvalidationsInProgress(XmlErrorMessage),
 }
 
 impl CreateInvalidationError {
@@ -8712,38 +8852,40 @@ impl CreateInvalidationError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(CreateInvalidationError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "BatchTooLarge" => {
                         return RusotoError::Service(CreateInvalidationError::BatchTooLarge(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InconsistentQuantities" => {
                         return RusotoError::Service(
-                            CreateInvalidationError::InconsistentQuantities(parsed_error.message),
+                            CreateInvalidationError::InconsistentQuantities(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(CreateInvalidationError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingBody" => {
                         return RusotoError::Service(CreateInvalidationError::MissingBody(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(CreateInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyInvalidationsInProgress" => {
                         return RusotoError::Service(
                             CreateInvalidationError::TooManyInvalidationsInProgress(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8761,6 +8903,21 @@ impl CreateInvalidationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateInvalidationError::AccessDenied(ref cause) => cause.request_id(),
+            CreateInvalidationError::BatchTooLarge(ref cause) => cause.request_id(),
+            CreateInvalidationError::InconsistentQuantities(ref cause) => cause.request_id(),
+            CreateInvalidationError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateInvalidationError::MissingBody(ref cause) => cause.request_id(),
+            CreateInvalidationError::NoSuchDistribution(ref cause) => cause.request_id(),
+            CreateInvalidationError::TooManyInvalidationsInProgress(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateInvalidationError {
     #[allow(unused_variables)]
@@ -8783,11 +8940,11 @@ impl Error for CreateInvalidationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePublicKeyError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The specified public key already exists.</p>
-    PublicKeyAlreadyExists(String),
+    PublicKeyAlreadyExists(XmlErrorMessage),
     /// <p>The maximum number of public keys for field-level encryption have been created. To create a new public key, delete one of the existing keys.</p>
-    TooManyPublicKeys(String),
+    TooManyPublicKeys(XmlErrorMessage),
 }
 
 impl CreatePublicKeyError {
@@ -8800,17 +8957,17 @@ impl CreatePublicKeyError {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
                         return RusotoError::Service(CreatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PublicKeyAlreadyExists" => {
                         return RusotoError::Service(CreatePublicKeyError::PublicKeyAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyPublicKeys" => {
                         return RusotoError::Service(CreatePublicKeyError::TooManyPublicKeys(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8827,6 +8984,15 @@ impl CreatePublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePublicKeyError::InvalidArgument(ref cause) => cause.request_id(),
+            CreatePublicKeyError::PublicKeyAlreadyExists(ref cause) => cause.request_id(),
+            CreatePublicKeyError::TooManyPublicKeys(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePublicKeyError {
     #[allow(unused_variables)]
@@ -8843,29 +9009,29 @@ impl Error for CreatePublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamingDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The Amazon S3 origin server specified does not refer to a valid Amazon S3 bucket.</p>
-    InvalidOrigin(String),
+    InvalidOrigin(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The caller reference you attempted to create the streaming distribution with is associated with another distribution</p>
-    StreamingDistributionAlreadyExists(String),
+    StreamingDistributionAlreadyExists(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyStreamingDistributionCNAMEs(String),
+    TooManyStreamingDistributionCNAMEs(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of streaming distributions allowed.</p>
-    TooManyStreamingDistributions(String),
+    TooManyStreamingDistributions(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl CreateStreamingDistributionError {
@@ -8880,77 +9046,83 @@ impl CreateStreamingDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            CreateStreamingDistributionError::AccessDenied(parsed_error.message),
+                            CreateStreamingDistributionError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "CNAMEAlreadyExists" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::CNAMEAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InconsistentQuantities" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::InconsistentQuantities(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(
-                            CreateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                            CreateStreamingDistributionError::InvalidArgument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidOrigin" => {
                         return RusotoError::Service(
-                            CreateStreamingDistributionError::InvalidOrigin(parsed_error.message),
+                            CreateStreamingDistributionError::InvalidOrigin(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::InvalidOriginAccessIdentity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MissingBody" => {
                         return RusotoError::Service(CreateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StreamingDistributionAlreadyExists" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::StreamingDistributionAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyStreamingDistributions" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::TooManyStreamingDistributions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTrustedSigners" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::TooManyTrustedSigners(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
                         return RusotoError::Service(
                             CreateStreamingDistributionError::TrustedSignerDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8968,6 +9140,38 @@ impl CreateStreamingDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStreamingDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            CreateStreamingDistributionError::CNAMEAlreadyExists(ref cause) => cause.request_id(),
+            CreateStreamingDistributionError::InconsistentQuantities(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateStreamingDistributionError::InvalidOrigin(ref cause) => cause.request_id(),
+            CreateStreamingDistributionError::InvalidOriginAccessIdentity(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::MissingBody(ref cause) => cause.request_id(),
+            CreateStreamingDistributionError::StreamingDistributionAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::TooManyStreamingDistributions(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::TooManyTrustedSigners(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionError::TrustedSignerDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateStreamingDistributionError {
     #[allow(unused_variables)]
@@ -9009,31 +9213,31 @@ impl Error for CreateStreamingDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamingDistributionWithTagsError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The Amazon S3 origin server specified does not refer to a valid Amazon S3 bucket.</p>
-    InvalidOrigin(String),
+    InvalidOrigin(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>The tagging specified is not valid.</p>
-    InvalidTagging(String),
+    InvalidTagging(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The caller reference you attempted to create the streaming distribution with is associated with another distribution</p>
-    StreamingDistributionAlreadyExists(String),
+    StreamingDistributionAlreadyExists(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyStreamingDistributionCNAMEs(String),
+    TooManyStreamingDistributionCNAMEs(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of streaming distributions allowed.</p>
-    TooManyStreamingDistributions(String),
+    TooManyStreamingDistributions(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl CreateStreamingDistributionWithTagsError {
@@ -9046,7 +9250,7 @@ impl CreateStreamingDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message)),"StreamingDistributionAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message)),"TooManyStreamingDistributionCNAMEs" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message)),"TooManyStreamingDistributions" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"CNAMEAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOrigin" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTagging" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidTagging(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"StreamingDistributionAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyStreamingDistributionCNAMEs" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyStreamingDistributions" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyTrustedSigners" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(XmlErrorMessage::from_response(parsed_error.message, &res))),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9060,6 +9264,47 @@ impl CreateStreamingDistributionWithTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStreamingDistributionWithTagsError::AccessDenied(ref cause) => cause.request_id(),
+            CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::InconsistentQuantities(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::InvalidArgument(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::InvalidOrigin(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::InvalidTagging(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::MissingBody(ref cause) => cause.request_id(),
+            CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(
+                ref cause,
+            ) => cause.request_id(),
+            CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(
+                ref cause,
+            ) => cause.request_id(),
+            CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(ref cause) => {
+                cause.request_id()
+            }
+            CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateStreamingDistributionWithTagsError {
     #[allow(unused_variables)]
@@ -9112,15 +9357,15 @@ impl Error for CreateStreamingDistributionWithTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The Origin Access Identity specified is already in use.</p>
-    CloudFrontOriginAccessIdentityInUse(String),
+    CloudFrontOriginAccessIdentityInUse(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified origin access identity does not exist.</p>
-    NoSuchCloudFrontOriginAccessIdentity(String),
+    NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl DeleteCloudFrontOriginAccessIdentityError {
@@ -9133,7 +9378,7 @@ impl DeleteCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidIfMatchVersion" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"PreconditionFailed" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9147,6 +9392,27 @@ impl DeleteCloudFrontOriginAccessIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCloudFrontOriginAccessIdentityError::AccessDenied(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteCloudFrontOriginAccessIdentityError {
     #[allow(unused_variables)]
@@ -9175,15 +9441,15 @@ impl Error for DeleteCloudFrontOriginAccessIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified CloudFront distribution is not disabled. You must disable the distribution before you can delete it.</p>
-    DistributionNotDisabled(String),
+    DistributionNotDisabled(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl DeleteDistributionError {
@@ -9196,27 +9462,31 @@ impl DeleteDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(DeleteDistributionError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DistributionNotDisabled" => {
                         return RusotoError::Service(
-                            DeleteDistributionError::DistributionNotDisabled(parsed_error.message),
+                            DeleteDistributionError::DistributionNotDisabled(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(
-                            DeleteDistributionError::InvalidIfMatchVersion(parsed_error.message),
+                            DeleteDistributionError::InvalidIfMatchVersion(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(DeleteDistributionError::NoSuchDistribution(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(DeleteDistributionError::PreconditionFailed(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9233,6 +9503,17 @@ impl DeleteDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            DeleteDistributionError::DistributionNotDisabled(ref cause) => cause.request_id(),
+            DeleteDistributionError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            DeleteDistributionError::NoSuchDistribution(ref cause) => cause.request_id(),
+            DeleteDistributionError::PreconditionFailed(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDistributionError {
     #[allow(unused_variables)]
@@ -9251,15 +9532,15 @@ impl Error for DeleteDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption is in use.</p>
-    FieldLevelEncryptionConfigInUse(String),
+    FieldLevelEncryptionConfigInUse(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl DeleteFieldLevelEncryptionConfigError {
@@ -9275,35 +9556,35 @@ impl DeleteFieldLevelEncryptionConfigError {
                     "AccessDenied" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionConfigError::AccessDenied(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "FieldLevelEncryptionConfigInUse" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionConfigError::PreconditionFailed(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9321,6 +9602,25 @@ impl DeleteFieldLevelEncryptionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteFieldLevelEncryptionConfigError::AccessDenied(ref cause) => cause.request_id(),
+            DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(ref cause) => {
+                cause.request_id()
+            }
+            DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(ref cause) => {
+                cause.request_id()
+            }
+            DeleteFieldLevelEncryptionConfigError::PreconditionFailed(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteFieldLevelEncryptionConfigError {
     #[allow(unused_variables)]
@@ -9349,15 +9649,15 @@ impl Error for DeleteFieldLevelEncryptionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption is in use.</p>
-    FieldLevelEncryptionProfileInUse(String),
+    FieldLevelEncryptionProfileInUse(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl DeleteFieldLevelEncryptionProfileError {
@@ -9373,31 +9673,31 @@ impl DeleteFieldLevelEncryptionProfileError {
                     "AccessDenied" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionProfileError::AccessDenied(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "FieldLevelEncryptionProfileInUse" => return RusotoError::Service(
                         DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(
                         DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "PreconditionFailed" => {
                         return RusotoError::Service(
                             DeleteFieldLevelEncryptionProfileError::PreconditionFailed(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9415,6 +9715,25 @@ impl DeleteFieldLevelEncryptionProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteFieldLevelEncryptionProfileError::AccessDenied(ref cause) => cause.request_id(),
+            DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(ref cause) => {
+                cause.request_id()
+            }
+            DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteFieldLevelEncryptionProfileError::PreconditionFailed(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteFieldLevelEncryptionProfileError {
     #[allow(unused_variables)]
@@ -9443,15 +9762,15 @@ impl Error for DeleteFieldLevelEncryptionProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePublicKeyError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>The specified public key is in use. </p>
-    PublicKeyInUse(String),
+    PublicKeyInUse(XmlErrorMessage),
 }
 
 impl DeletePublicKeyError {
@@ -9464,27 +9783,27 @@ impl DeletePublicKeyError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(DeletePublicKeyError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(DeletePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchPublicKey" => {
                         return RusotoError::Service(DeletePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(DeletePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PublicKeyInUse" => {
                         return RusotoError::Service(DeletePublicKeyError::PublicKeyInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9501,6 +9820,17 @@ impl DeletePublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePublicKeyError::AccessDenied(ref cause) => cause.request_id(),
+            DeletePublicKeyError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            DeletePublicKeyError::NoSuchPublicKey(ref cause) => cause.request_id(),
+            DeletePublicKeyError::PreconditionFailed(ref cause) => cause.request_id(),
+            DeletePublicKeyError::PublicKeyInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeletePublicKeyError {
     #[allow(unused_variables)]
@@ -9519,15 +9849,15 @@ impl Error for DeletePublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamingDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified streaming distribution does not exist.</p>
-    NoSuchStreamingDistribution(String),
+    NoSuchStreamingDistribution(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>The specified CloudFront distribution is not disabled. You must disable the distribution before you can delete it.</p>
-    StreamingDistributionNotDisabled(String),
+    StreamingDistributionNotDisabled(XmlErrorMessage),
 }
 
 impl DeleteStreamingDistributionError {
@@ -9542,34 +9872,36 @@ impl DeleteStreamingDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            DeleteStreamingDistributionError::AccessDenied(parsed_error.message),
+                            DeleteStreamingDistributionError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(
                             DeleteStreamingDistributionError::InvalidIfMatchVersion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchStreamingDistribution" => {
                         return RusotoError::Service(
                             DeleteStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(
                             DeleteStreamingDistributionError::PreconditionFailed(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StreamingDistributionNotDisabled" => {
                         return RusotoError::Service(
                             DeleteStreamingDistributionError::StreamingDistributionNotDisabled(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9587,6 +9919,23 @@ impl DeleteStreamingDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteStreamingDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            DeleteStreamingDistributionError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            DeleteStreamingDistributionError::NoSuchStreamingDistribution(ref cause) => {
+                cause.request_id()
+            }
+            DeleteStreamingDistributionError::PreconditionFailed(ref cause) => cause.request_id(),
+            DeleteStreamingDistributionError::StreamingDistributionNotDisabled(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteStreamingDistributionError {
     #[allow(unused_variables)]
@@ -9613,9 +9962,9 @@ impl Error for DeleteStreamingDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified origin access identity does not exist.</p>
-    NoSuchCloudFrontOriginAccessIdentity(String),
+    NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage),
 }
 
 impl GetCloudFrontOriginAccessIdentityError {
@@ -9628,7 +9977,7 @@ impl GetCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9642,6 +9991,16 @@ impl GetCloudFrontOriginAccessIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetCloudFrontOriginAccessIdentityError::AccessDenied(ref cause) => cause.request_id(),
+            GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetCloudFrontOriginAccessIdentityError {
     #[allow(unused_variables)]
@@ -9661,9 +10020,9 @@ impl Error for GetCloudFrontOriginAccessIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFrontOriginAccessIdentityConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified origin access identity does not exist.</p>
-    NoSuchCloudFrontOriginAccessIdentity(String),
+    NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage),
 }
 
 impl GetCloudFrontOriginAccessIdentityConfigError {
@@ -9676,7 +10035,7 @@ impl GetCloudFrontOriginAccessIdentityConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9690,6 +10049,18 @@ impl GetCloudFrontOriginAccessIdentityConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(ref cause) => {
+                cause.request_id()
+            }
+            GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetCloudFrontOriginAccessIdentityConfigError {
     #[allow(unused_variables)]
@@ -9709,9 +10080,9 @@ impl Error for GetCloudFrontOriginAccessIdentityConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
 }
 
 impl GetDistributionError {
@@ -9724,12 +10095,12 @@ impl GetDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetDistributionError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(GetDistributionError::NoSuchDistribution(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9746,6 +10117,14 @@ impl GetDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            GetDistributionError::NoSuchDistribution(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetDistributionError {
     #[allow(unused_variables)]
@@ -9761,9 +10140,9 @@ impl Error for GetDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
 }
 
 impl GetDistributionConfigError {
@@ -9776,12 +10155,14 @@ impl GetDistributionConfigError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetDistributionConfigError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(
-                            GetDistributionConfigError::NoSuchDistribution(parsed_error.message),
+                            GetDistributionConfigError::NoSuchDistribution(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9798,6 +10179,14 @@ impl GetDistributionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetDistributionConfigError::AccessDenied(ref cause) => cause.request_id(),
+            GetDistributionConfigError::NoSuchDistribution(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetDistributionConfigError {
     #[allow(unused_variables)]
@@ -9813,9 +10202,9 @@ impl Error for GetDistributionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
 }
 
 impl GetFieldLevelEncryptionError {
@@ -9828,13 +10217,13 @@ impl GetFieldLevelEncryptionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetFieldLevelEncryptionError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
                         return RusotoError::Service(
                             GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9852,6 +10241,16 @@ impl GetFieldLevelEncryptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetFieldLevelEncryptionError::AccessDenied(ref cause) => cause.request_id(),
+            GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetFieldLevelEncryptionError {
     #[allow(unused_variables)]
@@ -9869,9 +10268,9 @@ impl Error for GetFieldLevelEncryptionError {}
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
 }
 
 impl GetFieldLevelEncryptionConfigError {
@@ -9886,13 +10285,15 @@ impl GetFieldLevelEncryptionConfigError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            GetFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message),
+                            GetFieldLevelEncryptionConfigError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
                         return RusotoError::Service(
                             GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9910,6 +10311,16 @@ impl GetFieldLevelEncryptionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetFieldLevelEncryptionConfigError::AccessDenied(ref cause) => cause.request_id(),
+            GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetFieldLevelEncryptionConfigError {
     #[allow(unused_variables)]
@@ -9927,9 +10338,9 @@ impl Error for GetFieldLevelEncryptionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
 }
 
 impl GetFieldLevelEncryptionProfileError {
@@ -9944,13 +10355,15 @@ impl GetFieldLevelEncryptionProfileError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            GetFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message),
+                            GetFieldLevelEncryptionProfileError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchFieldLevelEncryptionProfile" => {
                         return RusotoError::Service(
                             GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9968,6 +10381,16 @@ impl GetFieldLevelEncryptionProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetFieldLevelEncryptionProfileError::AccessDenied(ref cause) => cause.request_id(),
+            GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetFieldLevelEncryptionProfileError {
     #[allow(unused_variables)]
@@ -9985,9 +10408,9 @@ impl Error for GetFieldLevelEncryptionProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionProfileConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
 }
 
 impl GetFieldLevelEncryptionProfileConfigError {
@@ -10000,7 +10423,7 @@ impl GetFieldLevelEncryptionProfileConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -10014,6 +10437,18 @@ impl GetFieldLevelEncryptionProfileConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetFieldLevelEncryptionProfileConfigError::AccessDenied(ref cause) => {
+                cause.request_id()
+            }
+            GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetFieldLevelEncryptionProfileConfigError {
     #[allow(unused_variables)]
@@ -10033,11 +10468,11 @@ impl Error for GetFieldLevelEncryptionProfileConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetInvalidationError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
     /// <p>The specified invalidation does not exist.</p>
-    NoSuchInvalidation(String),
+    NoSuchInvalidation(XmlErrorMessage),
 }
 
 impl GetInvalidationError {
@@ -10050,17 +10485,17 @@ impl GetInvalidationError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetInvalidationError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(GetInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchInvalidation" => {
                         return RusotoError::Service(GetInvalidationError::NoSuchInvalidation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10077,6 +10512,15 @@ impl GetInvalidationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetInvalidationError::AccessDenied(ref cause) => cause.request_id(),
+            GetInvalidationError::NoSuchDistribution(ref cause) => cause.request_id(),
+            GetInvalidationError::NoSuchInvalidation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetInvalidationError {
     #[allow(unused_variables)]
@@ -10093,9 +10537,9 @@ impl Error for GetInvalidationError {}
 #[derive(Debug, PartialEq)]
 pub enum GetPublicKeyError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
 }
 
 impl GetPublicKeyError {
@@ -10108,12 +10552,12 @@ impl GetPublicKeyError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetPublicKeyError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchPublicKey" => {
                         return RusotoError::Service(GetPublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10130,6 +10574,14 @@ impl GetPublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetPublicKeyError::AccessDenied(ref cause) => cause.request_id(),
+            GetPublicKeyError::NoSuchPublicKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetPublicKeyError {
     #[allow(unused_variables)]
@@ -10145,9 +10597,9 @@ impl Error for GetPublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetPublicKeyConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
 }
 
 impl GetPublicKeyConfigError {
@@ -10160,12 +10612,12 @@ impl GetPublicKeyConfigError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetPublicKeyConfigError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchPublicKey" => {
                         return RusotoError::Service(GetPublicKeyConfigError::NoSuchPublicKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10182,6 +10634,14 @@ impl GetPublicKeyConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetPublicKeyConfigError::AccessDenied(ref cause) => cause.request_id(),
+            GetPublicKeyConfigError::NoSuchPublicKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetPublicKeyConfigError {
     #[allow(unused_variables)]
@@ -10197,9 +10657,9 @@ impl Error for GetPublicKeyConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetStreamingDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified streaming distribution does not exist.</p>
-    NoSuchStreamingDistribution(String),
+    NoSuchStreamingDistribution(XmlErrorMessage),
 }
 
 impl GetStreamingDistributionError {
@@ -10212,13 +10672,13 @@ impl GetStreamingDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(GetStreamingDistributionError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchStreamingDistribution" => {
                         return RusotoError::Service(
                             GetStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10236,6 +10696,16 @@ impl GetStreamingDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetStreamingDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            GetStreamingDistributionError::NoSuchStreamingDistribution(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetStreamingDistributionError {
     #[allow(unused_variables)]
@@ -10253,9 +10723,9 @@ impl Error for GetStreamingDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum GetStreamingDistributionConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified streaming distribution does not exist.</p>
-    NoSuchStreamingDistribution(String),
+    NoSuchStreamingDistribution(XmlErrorMessage),
 }
 
 impl GetStreamingDistributionConfigError {
@@ -10270,13 +10740,15 @@ impl GetStreamingDistributionConfigError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            GetStreamingDistributionConfigError::AccessDenied(parsed_error.message),
+                            GetStreamingDistributionConfigError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchStreamingDistribution" => {
                         return RusotoError::Service(
                             GetStreamingDistributionConfigError::NoSuchStreamingDistribution(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10294,6 +10766,16 @@ impl GetStreamingDistributionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetStreamingDistributionConfigError::AccessDenied(ref cause) => cause.request_id(),
+            GetStreamingDistributionConfigError::NoSuchStreamingDistribution(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetStreamingDistributionConfigError {
     #[allow(unused_variables)]
@@ -10311,7 +10793,7 @@ impl Error for GetStreamingDistributionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum ListCloudFrontOriginAccessIdentitiesError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListCloudFrontOriginAccessIdentitiesError {
@@ -10327,7 +10809,7 @@ impl ListCloudFrontOriginAccessIdentitiesError {
                     "InvalidArgument" => {
                         return RusotoError::Service(
                             ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10345,6 +10827,15 @@ impl ListCloudFrontOriginAccessIdentitiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListCloudFrontOriginAccessIdentitiesError {
     #[allow(unused_variables)]
@@ -10361,7 +10852,7 @@ impl Error for ListCloudFrontOriginAccessIdentitiesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListDistributionsError {
@@ -10374,7 +10865,7 @@ impl ListDistributionsError {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
                         return RusotoError::Service(ListDistributionsError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10391,6 +10882,13 @@ impl ListDistributionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDistributionsError::InvalidArgument(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDistributionsError {
     #[allow(unused_variables)]
@@ -10405,9 +10903,9 @@ impl Error for ListDistributionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByWebACLIdError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>A web ACL ID specified is not valid. To specify a web ACL created using the latest version of AWS WAF, use the ACL ARN, for example <code>arn:aws:wafv2:us-east-1:123456789012:global/webacl/ExampleWebACL/473e64fd-f30b-4765-81a0-62ad96dd167a</code>. To specify a web ACL created using AWS WAF Classic, use the ACL ID, for example <code>473e64fd-f30b-4765-81a0-62ad96dd167a</code>.</p>
-    InvalidWebACLId(String),
+    InvalidWebACLId(XmlErrorMessage),
 }
 
 impl ListDistributionsByWebACLIdError {
@@ -10422,12 +10920,16 @@ impl ListDistributionsByWebACLIdError {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
                         return RusotoError::Service(
-                            ListDistributionsByWebACLIdError::InvalidArgument(parsed_error.message),
+                            ListDistributionsByWebACLIdError::InvalidArgument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidWebACLId" => {
                         return RusotoError::Service(
-                            ListDistributionsByWebACLIdError::InvalidWebACLId(parsed_error.message),
+                            ListDistributionsByWebACLIdError::InvalidWebACLId(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10444,6 +10946,14 @@ impl ListDistributionsByWebACLIdError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDistributionsByWebACLIdError::InvalidArgument(ref cause) => cause.request_id(),
+            ListDistributionsByWebACLIdError::InvalidWebACLId(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDistributionsByWebACLIdError {
     #[allow(unused_variables)]
@@ -10459,7 +10969,7 @@ impl Error for ListDistributionsByWebACLIdError {}
 #[derive(Debug, PartialEq)]
 pub enum ListFieldLevelEncryptionConfigsError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListFieldLevelEncryptionConfigsError {
@@ -10475,7 +10985,7 @@ impl ListFieldLevelEncryptionConfigsError {
                     "InvalidArgument" => {
                         return RusotoError::Service(
                             ListFieldLevelEncryptionConfigsError::InvalidArgument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10493,6 +11003,13 @@ impl ListFieldLevelEncryptionConfigsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListFieldLevelEncryptionConfigsError::InvalidArgument(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListFieldLevelEncryptionConfigsError {
     #[allow(unused_variables)]
@@ -10509,7 +11026,7 @@ impl Error for ListFieldLevelEncryptionConfigsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListFieldLevelEncryptionProfilesError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListFieldLevelEncryptionProfilesError {
@@ -10525,7 +11042,7 @@ impl ListFieldLevelEncryptionProfilesError {
                     "InvalidArgument" => {
                         return RusotoError::Service(
                             ListFieldLevelEncryptionProfilesError::InvalidArgument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10543,6 +11060,13 @@ impl ListFieldLevelEncryptionProfilesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListFieldLevelEncryptionProfilesError::InvalidArgument(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListFieldLevelEncryptionProfilesError {
     #[allow(unused_variables)]
@@ -10559,11 +11083,11 @@ impl Error for ListFieldLevelEncryptionProfilesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListInvalidationsError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
 }
 
 impl ListInvalidationsError {
@@ -10576,17 +11100,17 @@ impl ListInvalidationsError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(ListInvalidationsError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(ListInvalidationsError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDistribution" => {
                         return RusotoError::Service(ListInvalidationsError::NoSuchDistribution(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10603,6 +11127,15 @@ impl ListInvalidationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListInvalidationsError::AccessDenied(ref cause) => cause.request_id(),
+            ListInvalidationsError::InvalidArgument(ref cause) => cause.request_id(),
+            ListInvalidationsError::NoSuchDistribution(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListInvalidationsError {
     #[allow(unused_variables)]
@@ -10619,7 +11152,7 @@ impl Error for ListInvalidationsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPublicKeysError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListPublicKeysError {
@@ -10632,7 +11165,7 @@ impl ListPublicKeysError {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
                         return RusotoError::Service(ListPublicKeysError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10649,6 +11182,13 @@ impl ListPublicKeysError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPublicKeysError::InvalidArgument(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPublicKeysError {
     #[allow(unused_variables)]
@@ -10663,7 +11203,7 @@ impl Error for ListPublicKeysError {}
 #[derive(Debug, PartialEq)]
 pub enum ListStreamingDistributionsError {
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
 }
 
 impl ListStreamingDistributionsError {
@@ -10678,7 +11218,9 @@ impl ListStreamingDistributionsError {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
                         return RusotoError::Service(
-                            ListStreamingDistributionsError::InvalidArgument(parsed_error.message),
+                            ListStreamingDistributionsError::InvalidArgument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10695,6 +11237,13 @@ impl ListStreamingDistributionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListStreamingDistributionsError::InvalidArgument(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListStreamingDistributionsError {
     #[allow(unused_variables)]
@@ -10709,13 +11258,13 @@ impl Error for ListStreamingDistributionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The tagging specified is not valid.</p>
-    InvalidTagging(String),
+    InvalidTagging(XmlErrorMessage),
     /// <p>A resource that was specified is not valid.</p>
-    NoSuchResource(String),
+    NoSuchResource(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -10728,22 +11277,22 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(ListTagsForResourceError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(ListTagsForResourceError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagging" => {
                         return RusotoError::Service(ListTagsForResourceError::InvalidTagging(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchResource" => {
                         return RusotoError::Service(ListTagsForResourceError::NoSuchResource(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10760,6 +11309,16 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::AccessDenied(ref cause) => cause.request_id(),
+            ListTagsForResourceError::InvalidArgument(ref cause) => cause.request_id(),
+            ListTagsForResourceError::InvalidTagging(ref cause) => cause.request_id(),
+            ListTagsForResourceError::NoSuchResource(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -10777,13 +11336,13 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The tagging specified is not valid.</p>
-    InvalidTagging(String),
+    InvalidTagging(XmlErrorMessage),
     /// <p>A resource that was specified is not valid.</p>
-    NoSuchResource(String),
+    NoSuchResource(XmlErrorMessage),
 }
 
 impl TagResourceError {
@@ -10796,22 +11355,22 @@ impl TagResourceError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(TagResourceError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(TagResourceError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagging" => {
                         return RusotoError::Service(TagResourceError::InvalidTagging(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchResource" => {
                         return RusotoError::Service(TagResourceError::NoSuchResource(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10828,6 +11387,16 @@ impl TagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TagResourceError::AccessDenied(ref cause) => cause.request_id(),
+            TagResourceError::InvalidArgument(ref cause) => cause.request_id(),
+            TagResourceError::InvalidTagging(ref cause) => cause.request_id(),
+            TagResourceError::NoSuchResource(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TagResourceError {
     #[allow(unused_variables)]
@@ -10845,13 +11414,13 @@ impl Error for TagResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The tagging specified is not valid.</p>
-    InvalidTagging(String),
+    InvalidTagging(XmlErrorMessage),
     /// <p>A resource that was specified is not valid.</p>
-    NoSuchResource(String),
+    NoSuchResource(XmlErrorMessage),
 }
 
 impl UntagResourceError {
@@ -10864,22 +11433,22 @@ impl UntagResourceError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(UntagResourceError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(UntagResourceError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagging" => {
                         return RusotoError::Service(UntagResourceError::InvalidTagging(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchResource" => {
                         return RusotoError::Service(UntagResourceError::NoSuchResource(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10896,6 +11465,16 @@ impl UntagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UntagResourceError::AccessDenied(ref cause) => cause.request_id(),
+            UntagResourceError::InvalidArgument(ref cause) => cause.request_id(),
+            UntagResourceError::InvalidTagging(ref cause) => cause.request_id(),
+            UntagResourceError::NoSuchResource(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UntagResourceError {
     #[allow(unused_variables)]
@@ -10913,21 +11492,21 @@ impl Error for UntagResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified origin access identity does not exist.</p>
-    NoSuchCloudFrontOriginAccessIdentity(String),
+    NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl UpdateCloudFrontOriginAccessIdentityError {
@@ -10940,7 +11519,7 @@ impl UpdateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalUpdate" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"PreconditionFailed" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -10954,6 +11533,34 @@ impl UpdateCloudFrontOriginAccessIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateCloudFrontOriginAccessIdentityError::AccessDenied(ref cause) => {
+                cause.request_id()
+            }
+            UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(ref cause) => {
+                cause.request_id()
+            }
+            UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(ref cause) => {
+                cause.request_id()
+            }
+            UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(ref cause) => {
+                cause.request_id()
+            }
+            UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            UpdateCloudFrontOriginAccessIdentityError::MissingBody(ref cause) => cause.request_id(),
+            UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(
+                ref cause,
+            ) => cause.request_id(),
+            UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateCloudFrontOriginAccessIdentityError {
     #[allow(unused_variables)]
@@ -10991,93 +11598,93 @@ impl Error for UpdateCloudFrontOriginAccessIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption can't be associated with the specified cache behavior.</p>
-    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(String),
+    IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The default root object file name is too big or contains an invalid character.</p>
-    InvalidDefaultRootObject(String),
+    InvalidDefaultRootObject(XmlErrorMessage),
     /// <p>An invalid error code was specified.</p>
-    InvalidErrorCode(String),
+    InvalidErrorCode(XmlErrorMessage),
     /// <p>Your request contains forward cookies option which doesn't match with the expectation for the <code>whitelisted</code> list of cookie names. Either list of cookie names has been specified when not allowed or list of cookie names is missing when expected.</p>
-    InvalidForwardCookies(String),
+    InvalidForwardCookies(XmlErrorMessage),
     /// <p>The specified geo restriction parameter is not valid.</p>
-    InvalidGeoRestrictionParameter(String),
+    InvalidGeoRestrictionParameter(XmlErrorMessage),
     /// <p>The headers specified are not valid for an Amazon S3 origin.</p>
-    InvalidHeadersForS3Origin(String),
+    InvalidHeadersForS3Origin(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified Lambda function association is invalid.</p>
-    InvalidLambdaFunctionAssociation(String),
+    InvalidLambdaFunctionAssociation(XmlErrorMessage),
     /// <p>The location code specified is not valid.</p>
-    InvalidLocationCode(String),
+    InvalidLocationCode(XmlErrorMessage),
     /// <p>The minimum protocol version specified is not valid.</p>
-    InvalidMinimumProtocolVersion(String),
+    InvalidMinimumProtocolVersion(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>The keep alive timeout specified for the origin is not valid.</p>
-    InvalidOriginKeepaliveTimeout(String),
+    InvalidOriginKeepaliveTimeout(XmlErrorMessage),
     /// <p>The read timeout specified for the origin is not valid.</p>
-    InvalidOriginReadTimeout(String),
+    InvalidOriginReadTimeout(XmlErrorMessage),
     /// <p>The query string parameters specified are not valid.</p>
-    InvalidQueryStringParameters(String),
+    InvalidQueryStringParameters(XmlErrorMessage),
     /// <p>The relative path is too big, is not URL-encoded, or does not begin with a slash (/).</p>
-    InvalidRelativePath(String),
+    InvalidRelativePath(XmlErrorMessage),
     /// <p>This operation requires the HTTPS protocol. Ensure that you specify the HTTPS protocol in your request, or omit the <code>RequiredProtocols</code> element from your distribution configuration.</p>
-    InvalidRequiredProtocol(String),
+    InvalidRequiredProtocol(XmlErrorMessage),
     /// <p>A response code is not valid.</p>
-    InvalidResponseCode(String),
+    InvalidResponseCode(XmlErrorMessage),
     /// <p>The TTL order specified is not valid.</p>
-    InvalidTTLOrder(String),
+    InvalidTTLOrder(XmlErrorMessage),
     /// <p>A viewer certificate specified is not valid.</p>
-    InvalidViewerCertificate(String),
+    InvalidViewerCertificate(XmlErrorMessage),
     /// <p>A web ACL ID specified is not valid. To specify a web ACL created using the latest version of AWS WAF, use the ACL ARN, for example <code>arn:aws:wafv2:us-east-1:123456789012:global/webacl/ExampleWebACL/473e64fd-f30b-4765-81a0-62ad96dd167a</code>. To specify a web ACL created using AWS WAF Classic, use the ACL ID, for example <code>473e64fd-f30b-4765-81a0-62ad96dd167a</code>.</p>
-    InvalidWebACLId(String),
+    InvalidWebACLId(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified distribution does not exist.</p>
-    NoSuchDistribution(String),
+    NoSuchDistribution(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>No origin exists with the specified <code>Origin Id</code>. </p>
-    NoSuchOrigin(String),
+    NoSuchOrigin(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>You cannot create more cache behaviors for the distribution.</p>
-    TooManyCacheBehaviors(String),
+    TooManyCacheBehaviors(XmlErrorMessage),
     /// <p>You cannot create anymore custom SSL/TLS certificates.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
     /// <p>Your request contains more cookie names in the whitelist than are allowed per cache behavior.</p>
-    TooManyCookieNamesInWhiteList(String),
+    TooManyCookieNamesInWhiteList(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyDistributionCNAMEs(String),
+    TooManyDistributionCNAMEs(XmlErrorMessage),
     /// <p>The maximum number of distributions have been associated with the specified configuration for field-level encryption.</p>
-    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(String),
+    TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>Processing your request would cause the maximum number of distributions with Lambda function associations per owner to be exceeded.</p>
-    TooManyDistributionsWithLambdaAssociations(String),
+    TooManyDistributionsWithLambdaAssociations(XmlErrorMessage),
     /// <p>Your request contains too many headers in forwarded values.</p>
-    TooManyHeadersInForwardedValues(String),
+    TooManyHeadersInForwardedValues(XmlErrorMessage),
     /// <p>Your request contains more Lambda function associations than are allowed per distribution.</p>
-    TooManyLambdaFunctionAssociations(String),
+    TooManyLambdaFunctionAssociations(XmlErrorMessage),
     /// <p>Your request contains too many origin custom headers.</p>
-    TooManyOriginCustomHeaders(String),
+    TooManyOriginCustomHeaders(XmlErrorMessage),
     /// <p>Processing your request would cause you to exceed the maximum number of origin groups allowed.</p>
-    TooManyOriginGroupsPerDistribution(String),
+    TooManyOriginGroupsPerDistribution(XmlErrorMessage),
     /// <p>You cannot create more origins for the distribution.</p>
-    TooManyOrigins(String),
+    TooManyOrigins(XmlErrorMessage),
     /// <p>Your request contains too many query string parameters.</p>
-    TooManyQueryStringParameters(String),
+    TooManyQueryStringParameters(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateDistributionError {
@@ -11088,7 +11695,7 @@ impl UpdateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateDistributionError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(UpdateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(UpdateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(UpdateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(UpdateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(UpdateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(UpdateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(UpdateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(UpdateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateDistributionError::MissingBody(parsed_error.message)),"NoSuchDistribution" => return RusotoError::Service(UpdateDistributionError::NoSuchDistribution(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(UpdateDistributionError::NoSuchOrigin(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateDistributionError::PreconditionFailed(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(UpdateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(UpdateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(UpdateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(UpdateDistributionError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"CNAMEAlreadyExists" => return RusotoError::Service(UpdateDistributionError::CNAMEAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalUpdate" => return RusotoError::Service(UpdateDistributionError::IllegalUpdate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(UpdateDistributionError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(UpdateDistributionError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidDefaultRootObject" => return RusotoError::Service(UpdateDistributionError::InvalidDefaultRootObject(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidErrorCode" => return RusotoError::Service(UpdateDistributionError::InvalidErrorCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidForwardCookies" => return RusotoError::Service(UpdateDistributionError::InvalidForwardCookies(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGeoRestrictionParameter" => return RusotoError::Service(UpdateDistributionError::InvalidGeoRestrictionParameter(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidHeadersForS3Origin" => return RusotoError::Service(UpdateDistributionError::InvalidHeadersForS3Origin(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateDistributionError::InvalidIfMatchVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(UpdateDistributionError::InvalidLambdaFunctionAssociation(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidLocationCode" => return RusotoError::Service(UpdateDistributionError::InvalidLocationCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidMinimumProtocolVersion" => return RusotoError::Service(UpdateDistributionError::InvalidMinimumProtocolVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginAccessIdentity" => return RusotoError::Service(UpdateDistributionError::InvalidOriginAccessIdentity(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidOriginReadTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginReadTimeout(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::InvalidQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRelativePath" => return RusotoError::Service(UpdateDistributionError::InvalidRelativePath(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRequiredProtocol" => return RusotoError::Service(UpdateDistributionError::InvalidRequiredProtocol(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidResponseCode" => return RusotoError::Service(UpdateDistributionError::InvalidResponseCode(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTTLOrder" => return RusotoError::Service(UpdateDistributionError::InvalidTTLOrder(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidViewerCertificate" => return RusotoError::Service(UpdateDistributionError::InvalidViewerCertificate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidWebACLId" => return RusotoError::Service(UpdateDistributionError::InvalidWebACLId(XmlErrorMessage::from_response(parsed_error.message, &res))),"MissingBody" => return RusotoError::Service(UpdateDistributionError::MissingBody(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchDistribution" => return RusotoError::Service(UpdateDistributionError::NoSuchDistribution(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchOrigin" => return RusotoError::Service(UpdateDistributionError::NoSuchOrigin(XmlErrorMessage::from_response(parsed_error.message, &res))),"PreconditionFailed" => return RusotoError::Service(UpdateDistributionError::PreconditionFailed(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCacheBehaviors" => return RusotoError::Service(UpdateDistributionError::TooManyCacheBehaviors(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCertificates" => return RusotoError::Service(UpdateDistributionError::TooManyCertificates(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(UpdateDistributionError::TooManyCookieNamesInWhiteList(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionCNAMEs" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionCNAMEs(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyHeadersInForwardedValues" => return RusotoError::Service(UpdateDistributionError::TooManyHeadersInForwardedValues(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyLambdaFunctionAssociations(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginCustomHeaders" => return RusotoError::Service(UpdateDistributionError::TooManyOriginCustomHeaders(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyOrigins" => return RusotoError::Service(UpdateDistributionError::TooManyOrigins(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::TooManyQueryStringParameters(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyTrustedSigners" => return RusotoError::Service(UpdateDistributionError::TooManyTrustedSigners(XmlErrorMessage::from_response(parsed_error.message, &res))),"TrustedSignerDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedSignerDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11102,6 +11709,56 @@ impl UpdateDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            UpdateDistributionError::CNAMEAlreadyExists(ref cause) => cause.request_id(),
+            UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(ref cause) => cause.request_id(),
+            UpdateDistributionError::IllegalUpdate(ref cause) => cause.request_id(),
+            UpdateDistributionError::InconsistentQuantities(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidArgument(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidDefaultRootObject(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidErrorCode(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidForwardCookies(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidGeoRestrictionParameter(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidHeadersForS3Origin(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidLambdaFunctionAssociation(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidLocationCode(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidMinimumProtocolVersion(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidOriginAccessIdentity(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidOriginKeepaliveTimeout(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidOriginReadTimeout(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidQueryStringParameters(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidRelativePath(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidRequiredProtocol(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidResponseCode(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidTTLOrder(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidViewerCertificate(ref cause) => cause.request_id(),
+            UpdateDistributionError::InvalidWebACLId(ref cause) => cause.request_id(),
+            UpdateDistributionError::MissingBody(ref cause) => cause.request_id(),
+            UpdateDistributionError::NoSuchDistribution(ref cause) => cause.request_id(),
+            UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            UpdateDistributionError::NoSuchOrigin(ref cause) => cause.request_id(),
+            UpdateDistributionError::PreconditionFailed(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyCacheBehaviors(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyCertificates(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyCookieNamesInWhiteList(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyDistributionCNAMEs(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyHeadersInForwardedValues(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyLambdaFunctionAssociations(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyOriginCustomHeaders(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyOriginGroupsPerDistribution(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyOrigins(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyQueryStringParameters(ref cause) => cause.request_id(),
+            UpdateDistributionError::TooManyTrustedSigners(ref cause) => cause.request_id(),
+            UpdateDistributionError::TrustedSignerDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateDistributionError {
     #[allow(unused_variables)]
@@ -11159,27 +11816,27 @@ impl Error for UpdateDistributionError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionConfig(String),
+    NoSuchFieldLevelEncryptionConfig(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>No profile specified for the field-level encryption query argument.</p>
-    QueryArgProfileEmpty(String),
+    QueryArgProfileEmpty(XmlErrorMessage),
     /// <p>The maximum number of content type profiles for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionContentTypeProfiles(String),
+    TooManyFieldLevelEncryptionContentTypeProfiles(XmlErrorMessage),
     /// <p>The maximum number of query arg profiles for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionQueryArgProfiles(String),
+    TooManyFieldLevelEncryptionQueryArgProfiles(XmlErrorMessage),
 }
 
 impl UpdateFieldLevelEncryptionConfigError {
@@ -11192,7 +11849,7 @@ impl UpdateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(XmlErrorMessage::from_response(parsed_error.message, &res))),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(XmlErrorMessage::from_response(parsed_error.message, &res))),"QueryArgProfileEmpty" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11206,6 +11863,23 @@ impl UpdateFieldLevelEncryptionConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateFieldLevelEncryptionConfigError::AccessDenied(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::IllegalUpdate(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::InvalidArgument(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::PreconditionFailed(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateFieldLevelEncryptionConfigError {
     #[allow(unused_variables)]
@@ -11230,29 +11904,29 @@ impl Error for UpdateFieldLevelEncryptionConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption already exists.</p>
-    FieldLevelEncryptionProfileAlreadyExists(String),
+    FieldLevelEncryptionProfileAlreadyExists(XmlErrorMessage),
     /// <p>The maximum size of a profile for field-level encryption was exceeded.</p>
-    FieldLevelEncryptionProfileSizeExceeded(String),
+    FieldLevelEncryptionProfileSizeExceeded(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
-    NoSuchFieldLevelEncryptionProfile(String),
+    NoSuchFieldLevelEncryptionProfile(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>The maximum number of encryption entities for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionEncryptionEntities(String),
+    TooManyFieldLevelEncryptionEncryptionEntities(XmlErrorMessage),
     /// <p>The maximum number of field patterns for field-level encryption have been created.</p>
-    TooManyFieldLevelEncryptionFieldPatterns(String),
+    TooManyFieldLevelEncryptionFieldPatterns(XmlErrorMessage),
 }
 
 impl UpdateFieldLevelEncryptionProfileError {
@@ -11265,7 +11939,7 @@ impl UpdateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message)),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::AccessDenied(XmlErrorMessage::from_response(parsed_error.message, &res))),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(XmlErrorMessage::from_response(parsed_error.message, &res))),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(XmlErrorMessage::from_response(parsed_error.message, &res))),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchPublicKey" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(XmlErrorMessage::from_response(parsed_error.message, &res))),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11279,6 +11953,24 @@ impl UpdateFieldLevelEncryptionProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateFieldLevelEncryptionProfileError::AccessDenied(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::IllegalUpdate(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::InvalidArgument(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::PreconditionFailed(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(ref cause) => cause.request_id(),
+            UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateFieldLevelEncryptionProfileError {
     #[allow(unused_variables)]
@@ -11304,19 +11996,19 @@ impl Error for UpdateFieldLevelEncryptionProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdatePublicKeyError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>You can't change the value of a public key.</p>
-    CannotChangeImmutablePublicKeyFields(String),
+    CannotChangeImmutablePublicKeyFields(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The specified public key doesn't exist.</p>
-    NoSuchPublicKey(String),
+    NoSuchPublicKey(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
 }
 
 impl UpdatePublicKeyError {
@@ -11329,39 +12021,39 @@ impl UpdatePublicKeyError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(UpdatePublicKeyError::AccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CannotChangeImmutablePublicKeyFields" => {
                         return RusotoError::Service(
                             UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IllegalUpdate" => {
                         return RusotoError::Service(UpdatePublicKeyError::IllegalUpdate(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(UpdatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(UpdatePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchPublicKey" => {
                         return RusotoError::Service(UpdatePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(UpdatePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11378,6 +12070,21 @@ impl UpdatePublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdatePublicKeyError::AccessDenied(ref cause) => cause.request_id(),
+            UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(ref cause) => {
+                cause.request_id()
+            }
+            UpdatePublicKeyError::IllegalUpdate(ref cause) => cause.request_id(),
+            UpdatePublicKeyError::InvalidArgument(ref cause) => cause.request_id(),
+            UpdatePublicKeyError::InvalidIfMatchVersion(ref cause) => cause.request_id(),
+            UpdatePublicKeyError::NoSuchPublicKey(ref cause) => cause.request_id(),
+            UpdatePublicKeyError::PreconditionFailed(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdatePublicKeyError {
     #[allow(unused_variables)]
@@ -11400,31 +12107,31 @@ impl Error for UpdatePublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateStreamingDistributionError {
     /// <p>Access denied.</p>
-    AccessDenied(String),
+    AccessDenied(XmlErrorMessage),
     /// <p>The CNAME specified is already defined for CloudFront.</p>
-    CNAMEAlreadyExists(String),
+    CNAMEAlreadyExists(XmlErrorMessage),
     /// <p>Origin and <code>CallerReference</code> cannot be updated. </p>
-    IllegalUpdate(String),
+    IllegalUpdate(XmlErrorMessage),
     /// <p>The value of <code>Quantity</code> and the size of <code>Items</code> don't match.</p>
-    InconsistentQuantities(String),
+    InconsistentQuantities(XmlErrorMessage),
     /// <p>An argument is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The <code>If-Match</code> version is missing or not valid for the distribution.</p>
-    InvalidIfMatchVersion(String),
+    InvalidIfMatchVersion(XmlErrorMessage),
     /// <p>The origin access identity is not valid or doesn't exist.</p>
-    InvalidOriginAccessIdentity(String),
+    InvalidOriginAccessIdentity(XmlErrorMessage),
     /// <p>This operation requires a body. Ensure that the body is present and the <code>Content-Type</code> header is set.</p>
-    MissingBody(String),
+    MissingBody(XmlErrorMessage),
     /// <p>The specified streaming distribution does not exist.</p>
-    NoSuchStreamingDistribution(String),
+    NoSuchStreamingDistribution(XmlErrorMessage),
     /// <p>The precondition given in one or more of the request header fields evaluated to <code>false</code>.</p>
-    PreconditionFailed(String),
+    PreconditionFailed(XmlErrorMessage),
     /// <p>Your request contains more CNAMEs than are allowed per distribution.</p>
-    TooManyStreamingDistributionCNAMEs(String),
+    TooManyStreamingDistributionCNAMEs(XmlErrorMessage),
     /// <p>Your request contains more trusted signers than are allowed per distribution.</p>
-    TooManyTrustedSigners(String),
+    TooManyTrustedSigners(XmlErrorMessage),
     /// <p>One or more of your trusted signers don't exist.</p>
-    TrustedSignerDoesNotExist(String),
+    TrustedSignerDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateStreamingDistributionError {
@@ -11439,84 +12146,90 @@ impl UpdateStreamingDistributionError {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
                         return RusotoError::Service(
-                            UpdateStreamingDistributionError::AccessDenied(parsed_error.message),
+                            UpdateStreamingDistributionError::AccessDenied(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "CNAMEAlreadyExists" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::CNAMEAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IllegalUpdate" => {
                         return RusotoError::Service(
-                            UpdateStreamingDistributionError::IllegalUpdate(parsed_error.message),
+                            UpdateStreamingDistributionError::IllegalUpdate(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InconsistentQuantities" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::InconsistentQuantities(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(
-                            UpdateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                            UpdateStreamingDistributionError::InvalidArgument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidIfMatchVersion" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::InvalidIfMatchVersion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::InvalidOriginAccessIdentity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MissingBody" => {
                         return RusotoError::Service(UpdateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchStreamingDistribution" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PreconditionFailed" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::PreconditionFailed(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTrustedSigners" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::TooManyTrustedSigners(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
                         return RusotoError::Service(
                             UpdateStreamingDistributionError::TrustedSignerDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11534,6 +12247,39 @@ impl UpdateStreamingDistributionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateStreamingDistributionError::AccessDenied(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::CNAMEAlreadyExists(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::IllegalUpdate(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::InconsistentQuantities(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::InvalidArgument(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::InvalidIfMatchVersion(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::InvalidOriginAccessIdentity(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::MissingBody(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::NoSuchStreamingDistribution(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::PreconditionFailed(ref cause) => cause.request_id(),
+            UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::TooManyTrustedSigners(ref cause) => {
+                cause.request_id()
+            }
+            UpdateStreamingDistributionError::TrustedSignerDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateStreamingDistributionError {
     #[allow(unused_variables)]
@@ -11944,6 +12690,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCloudFrontOriginAccessIdentity");
 
         let mut writer = EventWriter::new(Vec::new());
         CloudFrontOriginAccessIdentityConfigSerializer::serialize(
@@ -11983,6 +12730,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistribution");
 
         let mut writer = EventWriter::new(Vec::new());
         DistributionConfigSerializer::serialize(
@@ -12017,6 +12765,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistributionWithTags");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -12056,6 +12805,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionConfigSerializer::serialize(
@@ -12095,6 +12845,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionProfile");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionProfileConfigSerializer::serialize(
@@ -12134,6 +12885,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateInvalidation");
 
         let mut writer = EventWriter::new(Vec::new());
         InvalidationBatchSerializer::serialize(
@@ -12166,6 +12918,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreatePublicKey");
 
         let mut writer = EventWriter::new(Vec::new());
         PublicKeyConfigSerializer::serialize(
@@ -12200,6 +12953,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistribution");
 
         let mut writer = EventWriter::new(Vec::new());
         StreamingDistributionConfigSerializer::serialize(
@@ -12236,6 +12990,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistributionWithTags");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -12281,6 +13036,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCloudFrontOriginAccessIdentity");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12304,6 +13060,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12324,6 +13081,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionConfig");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12350,6 +13108,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionProfile");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12373,6 +13132,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeletePublicKey");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12393,6 +13153,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteStreamingDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12419,6 +13180,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentity");
 
         let mut response = self
             .sign_and_dispatch(
@@ -12452,6 +13214,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentityConfig");
 
         let mut response = self
             .sign_and_dispatch(
@@ -12482,6 +13245,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistribution");
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionError::from_response)
@@ -12506,6 +13270,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionConfigError::from_response)
@@ -12530,6 +13295,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryption");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionError::from_response)
@@ -12558,6 +13324,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionConfigError::from_response)
@@ -12588,6 +13355,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfile");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionProfileError::from_response)
@@ -12618,6 +13386,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfileConfig");
 
         let mut response = self
             .sign_and_dispatch(
@@ -12652,6 +13421,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetInvalidation");
 
         let mut response = self
             .sign_and_dispatch(request, GetInvalidationError::from_response)
@@ -12676,6 +13446,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKey");
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyError::from_response)
@@ -12700,6 +13471,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKeyConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyConfigError::from_response)
@@ -12724,6 +13496,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistribution");
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionError::from_response)
@@ -12754,6 +13527,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistributionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionConfigError::from_response)
@@ -12781,6 +13555,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCloudFrontOriginAccessIdentities");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12820,6 +13595,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12857,6 +13633,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByWebACLId");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12893,6 +13670,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12929,6 +13707,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionProfiles");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12968,6 +13747,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListInvalidations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13001,6 +13781,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListPublicKeys");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13035,6 +13816,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListStreamingDistributions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13068,6 +13850,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13096,6 +13879,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("TagResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13122,6 +13906,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UntagResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13154,6 +13939,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCloudFrontOriginAccessIdentity");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13194,6 +13980,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13234,6 +14021,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionConfig");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13277,6 +14065,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionProfile");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13314,6 +14103,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdatePublicKey");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13352,6 +14142,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateStreamingDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -41,7 +41,7 @@ impl CloudHsmClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1650,7 +1650,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, AddTagsToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResponse, _>()
     }
 
@@ -1668,7 +1668,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, CreateHapgError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHapgResponse, _>()
     }
 
@@ -1686,7 +1686,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, CreateHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHsmResponse, _>()
     }
 
@@ -1704,7 +1704,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, CreateLunaClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLunaClientResponse, _>()
     }
 
@@ -1722,7 +1722,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DeleteHapgError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHapgResponse, _>()
     }
 
@@ -1740,7 +1740,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DeleteHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHsmResponse, _>()
     }
 
@@ -1758,7 +1758,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DeleteLunaClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLunaClientResponse, _>()
     }
 
@@ -1776,7 +1776,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DescribeHapgError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeHapgResponse, _>()
     }
 
@@ -1794,7 +1794,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DescribeHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeHsmResponse, _>()
     }
 
@@ -1812,7 +1812,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, DescribeLunaClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLunaClientResponse, _>()
     }
 
@@ -1830,7 +1830,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, GetConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConfigResponse, _>()
     }
 
@@ -1846,7 +1846,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ListAvailableZonesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAvailableZonesResponse, _>()
     }
 
@@ -1864,7 +1864,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ListHapgsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListHapgsResponse, _>()
     }
 
@@ -1882,7 +1882,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ListHsmsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListHsmsResponse, _>()
     }
 
@@ -1900,7 +1900,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ListLunaClientsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLunaClientsResponse, _>()
     }
 
@@ -1921,7 +1921,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -1939,7 +1939,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ModifyHapgError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyHapgResponse, _>()
     }
 
@@ -1957,7 +1957,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ModifyHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyHsmResponse, _>()
     }
 
@@ -1975,7 +1975,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, ModifyLunaClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyLunaClientResponse, _>()
     }
 
@@ -1996,7 +1996,7 @@ impl CloudHsm for CloudHsmClient {
             .sign_and_dispatch(request, RemoveTagsFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveTagsFromResourceResponse, _>()
     }
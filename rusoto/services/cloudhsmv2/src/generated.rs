@@ -42,7 +42,7 @@ impl CloudHsmv2Client {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1498,7 +1498,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, CopyBackupToRegionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CopyBackupToRegionResponse, _>()
     }
 
@@ -1516,7 +1516,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, CreateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
     }
 
@@ -1534,7 +1534,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, CreateHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHsmResponse, _>()
     }
 
@@ -1552,7 +1552,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, DeleteBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
     }
 
@@ -1570,7 +1570,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, DeleteClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
     }
 
@@ -1588,7 +1588,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, DeleteHsmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHsmResponse, _>()
     }
 
@@ -1606,7 +1606,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, DescribeBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
     }
 
@@ -1624,7 +1624,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, DescribeClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeClustersResponse, _>()
     }
 
@@ -1642,7 +1642,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, InitializeClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<InitializeClusterResponse, _>()
     }
 
@@ -1660,7 +1660,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
     }
 
@@ -1678,7 +1678,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, RestoreBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RestoreBackupResponse, _>()
     }
 
@@ -1696,7 +1696,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -1714,7 +1714,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
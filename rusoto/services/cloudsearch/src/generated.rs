@@ -52,7 +52,7 @@ impl CloudSearchClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4092,11 +4092,11 @@ impl WordDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum BuildSuggestersError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl BuildSuggestersError {
@@ -4109,17 +4109,17 @@ impl BuildSuggestersError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(BuildSuggestersError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(BuildSuggestersError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(BuildSuggestersError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4136,6 +4136,15 @@ impl BuildSuggestersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BuildSuggestersError::Base(ref cause) => cause.request_id(),
+            BuildSuggestersError::Internal(ref cause) => cause.request_id(),
+            BuildSuggestersError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for BuildSuggestersError {
     #[allow(unused_variables)]
@@ -4152,11 +4161,11 @@ impl Error for BuildSuggestersError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateDomainError {
@@ -4168,16 +4177,18 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(CreateDomainError::Base(parsed_error.message))
+                        return RusotoError::Service(CreateDomainError::Base(
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
+                        ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(CreateDomainError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateDomainError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4194,6 +4205,15 @@ impl CreateDomainError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDomainError::Base(ref cause) => cause.request_id(),
+            CreateDomainError::Internal(ref cause) => cause.request_id(),
+            CreateDomainError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDomainError {
     #[allow(unused_variables)]
@@ -4210,15 +4230,15 @@ impl Error for CreateDomainError {}
 #[derive(Debug, PartialEq)]
 pub enum DefineAnalysisSchemeError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DefineAnalysisSchemeError {
@@ -4231,27 +4251,27 @@ impl DefineAnalysisSchemeError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DefineAnalysisSchemeError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DefineAnalysisSchemeError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DefineAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DefineAnalysisSchemeError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DefineAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4268,6 +4288,17 @@ impl DefineAnalysisSchemeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DefineAnalysisSchemeError::Base(ref cause) => cause.request_id(),
+            DefineAnalysisSchemeError::Internal(ref cause) => cause.request_id(),
+            DefineAnalysisSchemeError::InvalidType(ref cause) => cause.request_id(),
+            DefineAnalysisSchemeError::LimitExceeded(ref cause) => cause.request_id(),
+            DefineAnalysisSchemeError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DefineAnalysisSchemeError {
     #[allow(unused_variables)]
@@ -4286,15 +4317,15 @@ impl Error for DefineAnalysisSchemeError {}
 #[derive(Debug, PartialEq)]
 pub enum DefineExpressionError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DefineExpressionError {
@@ -4307,27 +4338,27 @@ impl DefineExpressionError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DefineExpressionError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DefineExpressionError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DefineExpressionError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DefineExpressionError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DefineExpressionError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4344,6 +4375,17 @@ impl DefineExpressionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DefineExpressionError::Base(ref cause) => cause.request_id(),
+            DefineExpressionError::Internal(ref cause) => cause.request_id(),
+            DefineExpressionError::InvalidType(ref cause) => cause.request_id(),
+            DefineExpressionError::LimitExceeded(ref cause) => cause.request_id(),
+            DefineExpressionError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DefineExpressionError {
     #[allow(unused_variables)]
@@ -4362,15 +4404,15 @@ impl Error for DefineExpressionError {}
 #[derive(Debug, PartialEq)]
 pub enum DefineIndexFieldError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DefineIndexFieldError {
@@ -4383,27 +4425,27 @@ impl DefineIndexFieldError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DefineIndexFieldError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DefineIndexFieldError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DefineIndexFieldError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DefineIndexFieldError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DefineIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4420,6 +4462,17 @@ impl DefineIndexFieldError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DefineIndexFieldError::Base(ref cause) => cause.request_id(),
+            DefineIndexFieldError::Internal(ref cause) => cause.request_id(),
+            DefineIndexFieldError::InvalidType(ref cause) => cause.request_id(),
+            DefineIndexFieldError::LimitExceeded(ref cause) => cause.request_id(),
+            DefineIndexFieldError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DefineIndexFieldError {
     #[allow(unused_variables)]
@@ -4438,15 +4491,15 @@ impl Error for DefineIndexFieldError {}
 #[derive(Debug, PartialEq)]
 pub enum DefineSuggesterError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DefineSuggesterError {
@@ -4459,27 +4512,27 @@ impl DefineSuggesterError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DefineSuggesterError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DefineSuggesterError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DefineSuggesterError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DefineSuggesterError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DefineSuggesterError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4496,6 +4549,17 @@ impl DefineSuggesterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DefineSuggesterError::Base(ref cause) => cause.request_id(),
+            DefineSuggesterError::Internal(ref cause) => cause.request_id(),
+            DefineSuggesterError::InvalidType(ref cause) => cause.request_id(),
+            DefineSuggesterError::LimitExceeded(ref cause) => cause.request_id(),
+            DefineSuggesterError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DefineSuggesterError {
     #[allow(unused_variables)]
@@ -4514,13 +4578,13 @@ impl Error for DefineSuggesterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnalysisSchemeError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteAnalysisSchemeError {
@@ -4533,22 +4597,22 @@ impl DeleteAnalysisSchemeError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DeleteAnalysisSchemeError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DeleteAnalysisSchemeError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DeleteAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4565,6 +4629,16 @@ impl DeleteAnalysisSchemeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAnalysisSchemeError::Base(ref cause) => cause.request_id(),
+            DeleteAnalysisSchemeError::Internal(ref cause) => cause.request_id(),
+            DeleteAnalysisSchemeError::InvalidType(ref cause) => cause.request_id(),
+            DeleteAnalysisSchemeError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAnalysisSchemeError {
     #[allow(unused_variables)]
@@ -4582,9 +4656,9 @@ impl Error for DeleteAnalysisSchemeError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
 }
 
 impl DeleteDomainError {
@@ -4596,11 +4670,13 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteDomainError::Base(parsed_error.message))
+                        return RusotoError::Service(DeleteDomainError::Base(
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
+                        ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DeleteDomainError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4617,6 +4693,14 @@ impl DeleteDomainError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDomainError::Base(ref cause) => cause.request_id(),
+            DeleteDomainError::Internal(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDomainError {
     #[allow(unused_variables)]
@@ -4632,13 +4716,13 @@ impl Error for DeleteDomainError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteExpressionError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteExpressionError {
@@ -4651,22 +4735,22 @@ impl DeleteExpressionError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DeleteExpressionError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DeleteExpressionError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DeleteExpressionError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteExpressionError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4683,6 +4767,16 @@ impl DeleteExpressionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteExpressionError::Base(ref cause) => cause.request_id(),
+            DeleteExpressionError::Internal(ref cause) => cause.request_id(),
+            DeleteExpressionError::InvalidType(ref cause) => cause.request_id(),
+            DeleteExpressionError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteExpressionError {
     #[allow(unused_variables)]
@@ -4700,13 +4794,13 @@ impl Error for DeleteExpressionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteIndexFieldError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteIndexFieldError {
@@ -4719,22 +4813,22 @@ impl DeleteIndexFieldError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DeleteIndexFieldError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DeleteIndexFieldError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DeleteIndexFieldError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4751,6 +4845,16 @@ impl DeleteIndexFieldError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteIndexFieldError::Base(ref cause) => cause.request_id(),
+            DeleteIndexFieldError::Internal(ref cause) => cause.request_id(),
+            DeleteIndexFieldError::InvalidType(ref cause) => cause.request_id(),
+            DeleteIndexFieldError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteIndexFieldError {
     #[allow(unused_variables)]
@@ -4768,13 +4872,13 @@ impl Error for DeleteIndexFieldError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSuggesterError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteSuggesterError {
@@ -4787,22 +4891,22 @@ impl DeleteSuggesterError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DeleteSuggesterError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DeleteSuggesterError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DeleteSuggesterError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteSuggesterError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4819,6 +4923,16 @@ impl DeleteSuggesterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSuggesterError::Base(ref cause) => cause.request_id(),
+            DeleteSuggesterError::Internal(ref cause) => cause.request_id(),
+            DeleteSuggesterError::InvalidType(ref cause) => cause.request_id(),
+            DeleteSuggesterError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteSuggesterError {
     #[allow(unused_variables)]
@@ -4836,11 +4950,11 @@ impl Error for DeleteSuggesterError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnalysisSchemesError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeAnalysisSchemesError {
@@ -4853,17 +4967,19 @@ impl DescribeAnalysisSchemesError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeAnalysisSchemesError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeAnalysisSchemesError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
-                            DescribeAnalysisSchemesError::ResourceNotFound(parsed_error.message),
+                            DescribeAnalysisSchemesError::ResourceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4880,6 +4996,15 @@ impl DescribeAnalysisSchemesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAnalysisSchemesError::Base(ref cause) => cause.request_id(),
+            DescribeAnalysisSchemesError::Internal(ref cause) => cause.request_id(),
+            DescribeAnalysisSchemesError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAnalysisSchemesError {
     #[allow(unused_variables)]
@@ -4896,17 +5021,17 @@ impl Error for DescribeAnalysisSchemesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAvailabilityOptionsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>The request was rejected because it attempted an operation which is not enabled.</p>
-    DisabledOperation(String),
+    DisabledOperation(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeAvailabilityOptionsError {
@@ -4921,35 +5046,37 @@ impl DescribeAvailabilityOptionsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeAvailabilityOptionsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DisabledAction" => {
                         return RusotoError::Service(
                             DescribeAvailabilityOptionsError::DisabledOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeAvailabilityOptionsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(DescribeAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            DescribeAvailabilityOptionsError::LimitExceeded(parsed_error.message),
+                            DescribeAvailabilityOptionsError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
                             DescribeAvailabilityOptionsError::ResourceNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4967,6 +5094,18 @@ impl DescribeAvailabilityOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAvailabilityOptionsError::Base(ref cause) => cause.request_id(),
+            DescribeAvailabilityOptionsError::DisabledOperation(ref cause) => cause.request_id(),
+            DescribeAvailabilityOptionsError::Internal(ref cause) => cause.request_id(),
+            DescribeAvailabilityOptionsError::InvalidType(ref cause) => cause.request_id(),
+            DescribeAvailabilityOptionsError::LimitExceeded(ref cause) => cause.request_id(),
+            DescribeAvailabilityOptionsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAvailabilityOptionsError {
     #[allow(unused_variables)]
@@ -4988,15 +5127,15 @@ impl Error for DescribeAvailabilityOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainEndpointOptionsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>The request was rejected because it attempted an operation which is not enabled.</p>
-    DisabledOperation(String),
+    DisabledOperation(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeDomainEndpointOptionsError {
@@ -5011,30 +5150,32 @@ impl DescribeDomainEndpointOptionsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeDomainEndpointOptionsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DisabledAction" => {
                         return RusotoError::Service(
                             DescribeDomainEndpointOptionsError::DisabledOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            DescribeDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                            DescribeDomainEndpointOptionsError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
                             DescribeDomainEndpointOptionsError::ResourceNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5052,6 +5193,17 @@ impl DescribeDomainEndpointOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDomainEndpointOptionsError::Base(ref cause) => cause.request_id(),
+            DescribeDomainEndpointOptionsError::DisabledOperation(ref cause) => cause.request_id(),
+            DescribeDomainEndpointOptionsError::Internal(ref cause) => cause.request_id(),
+            DescribeDomainEndpointOptionsError::LimitExceeded(ref cause) => cause.request_id(),
+            DescribeDomainEndpointOptionsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDomainEndpointOptionsError {
     #[allow(unused_variables)]
@@ -5074,9 +5226,9 @@ impl Error for DescribeDomainEndpointOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
 }
 
 impl DescribeDomainsError {
@@ -5089,12 +5241,12 @@ impl DescribeDomainsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeDomainsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeDomainsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5111,6 +5263,14 @@ impl DescribeDomainsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDomainsError::Base(ref cause) => cause.request_id(),
+            DescribeDomainsError::Internal(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDomainsError {
     #[allow(unused_variables)]
@@ -5126,11 +5286,11 @@ impl Error for DescribeDomainsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeExpressionsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeExpressionsError {
@@ -5143,17 +5303,17 @@ impl DescribeExpressionsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeExpressionsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeExpressionsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DescribeExpressionsError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5170,6 +5330,15 @@ impl DescribeExpressionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeExpressionsError::Base(ref cause) => cause.request_id(),
+            DescribeExpressionsError::Internal(ref cause) => cause.request_id(),
+            DescribeExpressionsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeExpressionsError {
     #[allow(unused_variables)]
@@ -5186,11 +5355,11 @@ impl Error for DescribeExpressionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeIndexFieldsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeIndexFieldsError {
@@ -5203,17 +5372,17 @@ impl DescribeIndexFieldsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeIndexFieldsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeIndexFieldsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DescribeIndexFieldsError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5230,6 +5399,15 @@ impl DescribeIndexFieldsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeIndexFieldsError::Base(ref cause) => cause.request_id(),
+            DescribeIndexFieldsError::Internal(ref cause) => cause.request_id(),
+            DescribeIndexFieldsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeIndexFieldsError {
     #[allow(unused_variables)]
@@ -5246,11 +5424,11 @@ impl Error for DescribeIndexFieldsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingParametersError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeScalingParametersError {
@@ -5263,17 +5441,19 @@ impl DescribeScalingParametersError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeScalingParametersError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeScalingParametersError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
-                            DescribeScalingParametersError::ResourceNotFound(parsed_error.message),
+                            DescribeScalingParametersError::ResourceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5290,6 +5470,15 @@ impl DescribeScalingParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeScalingParametersError::Base(ref cause) => cause.request_id(),
+            DescribeScalingParametersError::Internal(ref cause) => cause.request_id(),
+            DescribeScalingParametersError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeScalingParametersError {
     #[allow(unused_variables)]
@@ -5306,11 +5495,11 @@ impl Error for DescribeScalingParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceAccessPoliciesError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeServiceAccessPoliciesError {
@@ -5325,18 +5514,18 @@ impl DescribeServiceAccessPoliciesError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeServiceAccessPoliciesError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
                             DescribeServiceAccessPoliciesError::ResourceNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5354,6 +5543,15 @@ impl DescribeServiceAccessPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeServiceAccessPoliciesError::Base(ref cause) => cause.request_id(),
+            DescribeServiceAccessPoliciesError::Internal(ref cause) => cause.request_id(),
+            DescribeServiceAccessPoliciesError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeServiceAccessPoliciesError {
     #[allow(unused_variables)]
@@ -5372,11 +5570,11 @@ impl Error for DescribeServiceAccessPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeSuggestersError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DescribeSuggestersError {
@@ -5389,17 +5587,17 @@ impl DescribeSuggestersError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(DescribeSuggestersError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(DescribeSuggestersError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(DescribeSuggestersError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5416,6 +5614,15 @@ impl DescribeSuggestersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeSuggestersError::Base(ref cause) => cause.request_id(),
+            DescribeSuggestersError::Internal(ref cause) => cause.request_id(),
+            DescribeSuggestersError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeSuggestersError {
     #[allow(unused_variables)]
@@ -5432,11 +5639,11 @@ impl Error for DescribeSuggestersError {}
 #[derive(Debug, PartialEq)]
 pub enum IndexDocumentsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl IndexDocumentsError {
@@ -5449,17 +5656,17 @@ impl IndexDocumentsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(IndexDocumentsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(IndexDocumentsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(IndexDocumentsError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5476,6 +5683,15 @@ impl IndexDocumentsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            IndexDocumentsError::Base(ref cause) => cause.request_id(),
+            IndexDocumentsError::Internal(ref cause) => cause.request_id(),
+            IndexDocumentsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for IndexDocumentsError {
     #[allow(unused_variables)]
@@ -5492,7 +5708,7 @@ impl Error for IndexDocumentsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDomainNamesError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
 }
 
 impl ListDomainNamesError {
@@ -5505,7 +5721,7 @@ impl ListDomainNamesError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(ListDomainNamesError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5522,6 +5738,13 @@ impl ListDomainNamesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDomainNamesError::Base(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDomainNamesError {
     #[allow(unused_variables)]
@@ -5536,19 +5759,19 @@ impl Error for ListDomainNamesError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateAvailabilityOptionsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>The request was rejected because it attempted an operation which is not enabled.</p>
-    DisabledOperation(String),
+    DisabledOperation(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>The request was rejected because it has invalid parameters.</p>
-    Validation(String),
+    Validation(XmlErrorMessage),
 }
 
 impl UpdateAvailabilityOptionsError {
@@ -5561,37 +5784,41 @@ impl UpdateAvailabilityOptionsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(UpdateAvailabilityOptionsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DisabledAction" => {
                         return RusotoError::Service(
-                            UpdateAvailabilityOptionsError::DisabledOperation(parsed_error.message),
+                            UpdateAvailabilityOptionsError::DisabledOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalException" => {
                         return RusotoError::Service(UpdateAvailabilityOptionsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(UpdateAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateAvailabilityOptionsError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
-                            UpdateAvailabilityOptionsError::ResourceNotFound(parsed_error.message),
+                            UpdateAvailabilityOptionsError::ResourceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ValidationException" => {
                         return RusotoError::Service(UpdateAvailabilityOptionsError::Validation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5608,6 +5835,19 @@ impl UpdateAvailabilityOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateAvailabilityOptionsError::Base(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::DisabledOperation(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::Internal(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::InvalidType(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::ResourceNotFound(ref cause) => cause.request_id(),
+            UpdateAvailabilityOptionsError::Validation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateAvailabilityOptionsError {
     #[allow(unused_variables)]
@@ -5628,19 +5868,19 @@ impl Error for UpdateAvailabilityOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainEndpointOptionsError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>The request was rejected because it attempted an operation which is not enabled.</p>
-    DisabledOperation(String),
+    DisabledOperation(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>The request was rejected because it has invalid parameters.</p>
-    Validation(String),
+    Validation(XmlErrorMessage),
 }
 
 impl UpdateDomainEndpointOptionsError {
@@ -5655,41 +5895,43 @@ impl UpdateDomainEndpointOptionsError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(UpdateDomainEndpointOptionsError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DisabledAction" => {
                         return RusotoError::Service(
                             UpdateDomainEndpointOptionsError::DisabledOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalException" => {
                         return RusotoError::Service(UpdateDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(UpdateDomainEndpointOptionsError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            UpdateDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                            UpdateDomainEndpointOptionsError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
                             UpdateDomainEndpointOptionsError::ResourceNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ValidationException" => {
                         return RusotoError::Service(UpdateDomainEndpointOptionsError::Validation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5706,6 +5948,19 @@ impl UpdateDomainEndpointOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateDomainEndpointOptionsError::Base(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::DisabledOperation(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::Internal(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::InvalidType(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::ResourceNotFound(ref cause) => cause.request_id(),
+            UpdateDomainEndpointOptionsError::Validation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateDomainEndpointOptionsError {
     #[allow(unused_variables)]
@@ -5728,15 +5983,15 @@ impl Error for UpdateDomainEndpointOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateScalingParametersError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl UpdateScalingParametersError {
@@ -5749,27 +6004,29 @@ impl UpdateScalingParametersError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(UpdateScalingParametersError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(UpdateScalingParametersError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(UpdateScalingParametersError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateScalingParametersError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
-                            UpdateScalingParametersError::ResourceNotFound(parsed_error.message),
+                            UpdateScalingParametersError::ResourceNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5786,6 +6043,17 @@ impl UpdateScalingParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateScalingParametersError::Base(ref cause) => cause.request_id(),
+            UpdateScalingParametersError::Internal(ref cause) => cause.request_id(),
+            UpdateScalingParametersError::InvalidType(ref cause) => cause.request_id(),
+            UpdateScalingParametersError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateScalingParametersError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateScalingParametersError {
     #[allow(unused_variables)]
@@ -5804,15 +6072,15 @@ impl Error for UpdateScalingParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceAccessPoliciesError {
     /// <p>An error occurred while processing the request.</p>
-    Base(String),
+    Base(XmlErrorMessage),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
-    Internal(String),
+    Internal(XmlErrorMessage),
     /// <p>The request was rejected because it specified an invalid type definition.</p>
-    InvalidType(String),
+    InvalidType(XmlErrorMessage),
     /// <p>The request was rejected because a resource limit has already been met.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl UpdateServiceAccessPoliciesError {
@@ -5827,28 +6095,30 @@ impl UpdateServiceAccessPoliciesError {
                 match &parsed_error.code[..] {
                     "BaseException" => {
                         return RusotoError::Service(UpdateServiceAccessPoliciesError::Base(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalException" => {
                         return RusotoError::Service(UpdateServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidType" => {
                         return RusotoError::Service(UpdateServiceAccessPoliciesError::InvalidType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            UpdateServiceAccessPoliciesError::LimitExceeded(parsed_error.message),
+                            UpdateServiceAccessPoliciesError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(
                             UpdateServiceAccessPoliciesError::ResourceNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5866,6 +6136,17 @@ impl UpdateServiceAccessPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateServiceAccessPoliciesError::Base(ref cause) => cause.request_id(),
+            UpdateServiceAccessPoliciesError::Internal(ref cause) => cause.request_id(),
+            UpdateServiceAccessPoliciesError::InvalidType(ref cause) => cause.request_id(),
+            UpdateServiceAccessPoliciesError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateServiceAccessPoliciesError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateServiceAccessPoliciesError {
     #[allow(unused_variables)]
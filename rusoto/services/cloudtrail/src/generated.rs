@@ -41,7 +41,7 @@ impl CloudTrailClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2546,7 +2546,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, AddTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsResponse, _>()
     }
 
@@ -2567,7 +2567,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, CreateTrailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTrailResponse, _>()
     }
 
@@ -2588,7 +2588,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, DeleteTrailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrailResponse, _>()
     }
 
@@ -2609,7 +2609,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, DescribeTrailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrailsResponse, _>()
     }
 
@@ -2630,7 +2630,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, GetEventSelectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetEventSelectorsResponse, _>()
     }
 
@@ -2651,7 +2651,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, GetInsightSelectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInsightSelectorsResponse, _>()
     }
 
@@ -2672,7 +2672,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, GetTrailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTrailResponse, _>()
     }
 
@@ -2693,7 +2693,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, GetTrailStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTrailStatusResponse, _>()
     }
 
@@ -2714,7 +2714,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, ListPublicKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPublicKeysResponse, _>()
     }
 
@@ -2735,7 +2735,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
     }
 
@@ -2756,7 +2756,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, ListTrailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTrailsResponse, _>()
     }
 
@@ -2777,7 +2777,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, LookupEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<LookupEventsResponse, _>()
     }
 
@@ -2798,7 +2798,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, PutEventSelectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutEventSelectorsResponse, _>()
     }
 
@@ -2819,7 +2819,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, PutInsightSelectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutInsightSelectorsResponse, _>()
     }
 
@@ -2840,7 +2840,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, RemoveTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsResponse, _>()
     }
 
@@ -2861,7 +2861,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, StartLoggingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartLoggingResponse, _>()
     }
 
@@ -2882,7 +2882,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, StopLoggingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopLoggingResponse, _>()
     }
 
@@ -2903,7 +2903,7 @@ impl CloudTrail for CloudTrailClient {
             .sign_and_dispatch(request, UpdateTrailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrailResponse, _>()
     }
 }
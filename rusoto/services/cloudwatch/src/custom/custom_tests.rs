@@ -65,3 +65,19 @@ async fn should_serialize_complex_metric_data_params() {
     let response = client.put_metric_data(request).await.unwrap();
     println!("{:#?}", response);
 }
+
+#[tokio::test]
+async fn put_metric_data_accepts_request_compression() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(|request: &SignedRequest| {
+            assert!(request.request_compression);
+        });
+    let request = PutMetricDataInput {
+        namespace: "TestNamespace".to_string(),
+        ..Default::default()
+    };
+
+    let client = CloudWatchClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.put_metric_data(request).await.unwrap();
+}
@@ -52,7 +52,7 @@ impl CloudWatchClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4717,7 +4717,7 @@ impl ValuesSerializer {
 #[derive(Debug, PartialEq)]
 pub enum DeleteAlarmsError {
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteAlarmsError {
@@ -4730,7 +4730,7 @@ impl DeleteAlarmsError {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteAlarmsError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4747,6 +4747,13 @@ impl DeleteAlarmsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAlarmsError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAlarmsError {
     #[allow(unused_variables)]
@@ -4761,13 +4768,13 @@ impl Error for DeleteAlarmsError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnomalyDetectorError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl DeleteAnomalyDetectorError {
@@ -4780,24 +4787,28 @@ impl DeleteAnomalyDetectorError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(
-                            DeleteAnomalyDetectorError::InternalServiceFault(parsed_error.message),
+                            DeleteAnomalyDetectorError::InternalServiceFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DeleteAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                            DeleteAnomalyDetectorError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
                             DeleteAnomalyDetectorError::MissingRequiredParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(DeleteAnomalyDetectorError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4814,6 +4825,16 @@ impl DeleteAnomalyDetectorError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAnomalyDetectorError::InternalServiceFault(ref cause) => cause.request_id(),
+            DeleteAnomalyDetectorError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteAnomalyDetectorError::MissingRequiredParameter(ref cause) => cause.request_id(),
+            DeleteAnomalyDetectorError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAnomalyDetectorError {
     #[allow(unused_variables)]
@@ -4833,11 +4854,11 @@ impl Error for DeleteAnomalyDetectorError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDashboardsError {
     /// <p>The specified dashboard does not exist.</p>
-    DashboardNotFoundError(String),
+    DashboardNotFoundError(XmlErrorMessage),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DeleteDashboardsError {
@@ -4850,17 +4871,17 @@ impl DeleteDashboardsError {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
                         return RusotoError::Service(DeleteDashboardsError::DashboardNotFoundError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalServiceError" => {
                         return RusotoError::Service(DeleteDashboardsError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(DeleteDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4877,6 +4898,15 @@ impl DeleteDashboardsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDashboardsError::DashboardNotFoundError(ref cause) => cause.request_id(),
+            DeleteDashboardsError::InternalServiceFault(ref cause) => cause.request_id(),
+            DeleteDashboardsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDashboardsError {
     #[allow(unused_variables)]
@@ -4893,9 +4923,9 @@ impl Error for DeleteDashboardsError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl DeleteInsightRulesError {
@@ -4908,12 +4938,16 @@ impl DeleteInsightRulesError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DeleteInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            DeleteInsightRulesError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
-                            DeleteInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                            DeleteInsightRulesError::MissingRequiredParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4930,6 +4964,14 @@ impl DeleteInsightRulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteInsightRulesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteInsightRulesError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteInsightRulesError {
     #[allow(unused_variables)]
@@ -4945,7 +4987,7 @@ impl Error for DeleteInsightRulesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlarmHistoryError {
     /// <p>The next token specified is invalid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
 }
 
 impl DescribeAlarmHistoryError {
@@ -4958,7 +5000,7 @@ impl DescribeAlarmHistoryError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(DescribeAlarmHistoryError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4975,6 +5017,13 @@ impl DescribeAlarmHistoryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAlarmHistoryError::InvalidNextToken(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAlarmHistoryError {
     #[allow(unused_variables)]
@@ -4989,7 +5038,7 @@ impl Error for DescribeAlarmHistoryError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlarmsError {
     /// <p>The next token specified is invalid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
 }
 
 impl DescribeAlarmsError {
@@ -5002,7 +5051,7 @@ impl DescribeAlarmsError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(DescribeAlarmsError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5019,6 +5068,13 @@ impl DescribeAlarmsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAlarmsError::InvalidNextToken(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAlarmsError {
     #[allow(unused_variables)]
@@ -5055,6 +5111,11 @@ impl DescribeAlarmsForMetricError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAlarmsForMetricError {
     #[allow(unused_variables)]
@@ -5067,11 +5128,11 @@ impl Error for DescribeAlarmsForMetricError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnomalyDetectorsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The next token specified is invalid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeAnomalyDetectorsError {
@@ -5085,19 +5146,21 @@ impl DescribeAnomalyDetectorsError {
                     "InternalServiceError" => {
                         return RusotoError::Service(
                             DescribeAnomalyDetectorsError::InternalServiceFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidNextToken" => {
                         return RusotoError::Service(
-                            DescribeAnomalyDetectorsError::InvalidNextToken(parsed_error.message),
+                            DescribeAnomalyDetectorsError::InvalidNextToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeAnomalyDetectorsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5115,6 +5178,15 @@ impl DescribeAnomalyDetectorsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAnomalyDetectorsError::InternalServiceFault(ref cause) => cause.request_id(),
+            DescribeAnomalyDetectorsError::InvalidNextToken(ref cause) => cause.request_id(),
+            DescribeAnomalyDetectorsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAnomalyDetectorsError {
     #[allow(unused_variables)]
@@ -5135,7 +5207,7 @@ impl Error for DescribeAnomalyDetectorsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeInsightRulesError {
     /// <p>The next token specified is invalid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
 }
 
 impl DescribeInsightRulesError {
@@ -5148,7 +5220,7 @@ impl DescribeInsightRulesError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(DescribeInsightRulesError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5165,6 +5237,13 @@ impl DescribeInsightRulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeInsightRulesError::InvalidNextToken(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeInsightRulesError {
     #[allow(unused_variables)]
@@ -5201,6 +5280,11 @@ impl DisableAlarmActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableAlarmActionsError {
     #[allow(unused_variables)]
@@ -5213,9 +5297,9 @@ impl Error for DisableAlarmActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DisableInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl DisableInsightRulesError {
@@ -5228,13 +5312,15 @@ impl DisableInsightRulesError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DisableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            DisableInsightRulesError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
                             DisableInsightRulesError::MissingRequiredParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5252,6 +5338,14 @@ impl DisableInsightRulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisableInsightRulesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DisableInsightRulesError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DisableInsightRulesError {
     #[allow(unused_variables)]
@@ -5289,6 +5383,11 @@ impl EnableAlarmActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableAlarmActionsError {
     #[allow(unused_variables)]
@@ -5301,11 +5400,11 @@ impl Error for EnableAlarmActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The operation exceeded one or more limits.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl EnableInsightRulesError {
@@ -5318,17 +5417,21 @@ impl EnableInsightRulesError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            EnableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            EnableInsightRulesError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(EnableInsightRulesError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
-                            EnableInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                            EnableInsightRulesError::MissingRequiredParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5345,6 +5448,15 @@ impl EnableInsightRulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableInsightRulesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            EnableInsightRulesError::LimitExceeded(ref cause) => cause.request_id(),
+            EnableInsightRulesError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for EnableInsightRulesError {
     #[allow(unused_variables)]
@@ -5361,11 +5473,11 @@ impl Error for EnableInsightRulesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetDashboardError {
     /// <p>The specified dashboard does not exist.</p>
-    DashboardNotFoundError(String),
+    DashboardNotFoundError(XmlErrorMessage),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl GetDashboardError {
@@ -5378,17 +5490,17 @@ impl GetDashboardError {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
                         return RusotoError::Service(GetDashboardError::DashboardNotFoundError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalServiceError" => {
                         return RusotoError::Service(GetDashboardError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(GetDashboardError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5405,6 +5517,15 @@ impl GetDashboardError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetDashboardError::DashboardNotFoundError(ref cause) => cause.request_id(),
+            GetDashboardError::InternalServiceFault(ref cause) => cause.request_id(),
+            GetDashboardError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetDashboardError {
     #[allow(unused_variables)]
@@ -5421,11 +5542,11 @@ impl Error for GetDashboardError {}
 #[derive(Debug, PartialEq)]
 pub enum GetInsightRuleReportError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl GetInsightRuleReportError {
@@ -5438,19 +5559,21 @@ impl GetInsightRuleReportError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            GetInsightRuleReportError::InvalidParameterValue(parsed_error.message),
+                            GetInsightRuleReportError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
                             GetInsightRuleReportError::MissingRequiredParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(GetInsightRuleReportError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5467,6 +5590,15 @@ impl GetInsightRuleReportError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetInsightRuleReportError::InvalidParameterValue(ref cause) => cause.request_id(),
+            GetInsightRuleReportError::MissingRequiredParameter(ref cause) => cause.request_id(),
+            GetInsightRuleReportError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetInsightRuleReportError {
     #[allow(unused_variables)]
@@ -5485,7 +5617,7 @@ impl Error for GetInsightRuleReportError {}
 #[derive(Debug, PartialEq)]
 pub enum GetMetricDataError {
     /// <p>The next token specified is invalid.</p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
 }
 
 impl GetMetricDataError {
@@ -5498,7 +5630,7 @@ impl GetMetricDataError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(GetMetricDataError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5515,6 +5647,13 @@ impl GetMetricDataError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetMetricDataError::InvalidNextToken(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetMetricDataError {
     #[allow(unused_variables)]
@@ -5529,13 +5668,13 @@ impl Error for GetMetricDataError {}
 #[derive(Debug, PartialEq)]
 pub enum GetMetricStatisticsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>Parameters were used together that cannot be used together.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl GetMetricStatisticsError {
@@ -5548,25 +5687,29 @@ impl GetMetricStatisticsError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(
-                            GetMetricStatisticsError::InternalServiceFault(parsed_error.message),
+                            GetMetricStatisticsError::InternalServiceFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             GetMetricStatisticsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            GetMetricStatisticsError::InvalidParameterValue(parsed_error.message),
+                            GetMetricStatisticsError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
                             GetMetricStatisticsError::MissingRequiredParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5584,6 +5727,16 @@ impl GetMetricStatisticsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetMetricStatisticsError::InternalServiceFault(ref cause) => cause.request_id(),
+            GetMetricStatisticsError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            GetMetricStatisticsError::InvalidParameterValue(ref cause) => cause.request_id(),
+            GetMetricStatisticsError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetMetricStatisticsError {
     #[allow(unused_variables)]
@@ -5625,6 +5778,11 @@ impl GetMetricWidgetImageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetMetricWidgetImageError {
     #[allow(unused_variables)]
@@ -5637,9 +5795,9 @@ impl Error for GetMetricWidgetImageError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDashboardsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ListDashboardsError {
@@ -5652,12 +5810,12 @@ impl ListDashboardsError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(ListDashboardsError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(ListDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5674,6 +5832,14 @@ impl ListDashboardsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDashboardsError::InternalServiceFault(ref cause) => cause.request_id(),
+            ListDashboardsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDashboardsError {
     #[allow(unused_variables)]
@@ -5689,9 +5855,9 @@ impl Error for ListDashboardsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListMetricsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ListMetricsError {
@@ -5704,12 +5870,12 @@ impl ListMetricsError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(ListMetricsError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(ListMetricsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5726,6 +5892,14 @@ impl ListMetricsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListMetricsError::InternalServiceFault(ref cause) => cause.request_id(),
+            ListMetricsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListMetricsError {
     #[allow(unused_variables)]
@@ -5741,11 +5915,11 @@ impl Error for ListMetricsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -5758,17 +5932,21 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::InternalServiceFault(parsed_error.message),
+                            ListTagsForResourceError::InternalServiceFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::InvalidParameterValue(parsed_error.message),
+                            ListTagsForResourceError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5785,6 +5963,15 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::InternalServiceFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::InvalidParameterValue(ref cause) => cause.request_id(),
+            ListTagsForResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -5801,13 +5988,13 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum PutAnomalyDetectorError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The operation exceeded one or more limits.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl PutAnomalyDetectorError {
@@ -5820,22 +6007,26 @@ impl PutAnomalyDetectorError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(PutAnomalyDetectorError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            PutAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                            PutAnomalyDetectorError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(PutAnomalyDetectorError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(
-                            PutAnomalyDetectorError::MissingRequiredParameter(parsed_error.message),
+                            PutAnomalyDetectorError::MissingRequiredParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5852,6 +6043,16 @@ impl PutAnomalyDetectorError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutAnomalyDetectorError::InternalServiceFault(ref cause) => cause.request_id(),
+            PutAnomalyDetectorError::InvalidParameterValue(ref cause) => cause.request_id(),
+            PutAnomalyDetectorError::LimitExceeded(ref cause) => cause.request_id(),
+            PutAnomalyDetectorError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutAnomalyDetectorError {
     #[allow(unused_variables)]
@@ -5869,7 +6070,7 @@ impl Error for PutAnomalyDetectorError {}
 #[derive(Debug, PartialEq)]
 pub enum PutCompositeAlarmError {
     /// <p>The quota for alarms for this customer has already been reached.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
 }
 
 impl PutCompositeAlarmError {
@@ -5882,7 +6083,7 @@ impl PutCompositeAlarmError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutCompositeAlarmError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5899,6 +6100,13 @@ impl PutCompositeAlarmError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutCompositeAlarmError::LimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutCompositeAlarmError {
     #[allow(unused_variables)]
@@ -5913,9 +6121,9 @@ impl Error for PutCompositeAlarmError {}
 #[derive(Debug, PartialEq)]
 pub enum PutDashboardError {
     /// <p>Some part of the dashboard data is invalid.</p>
-    DashboardInvalidInputError(String),
+    DashboardInvalidInputError(XmlErrorMessage),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
 }
 
 impl PutDashboardError {
@@ -5928,12 +6136,12 @@ impl PutDashboardError {
                 match &parsed_error.code[..] {
                     "InvalidParameterInput" => {
                         return RusotoError::Service(PutDashboardError::DashboardInvalidInputError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalServiceError" => {
                         return RusotoError::Service(PutDashboardError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5950,6 +6158,14 @@ impl PutDashboardError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutDashboardError::DashboardInvalidInputError(ref cause) => cause.request_id(),
+            PutDashboardError::InternalServiceFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutDashboardError {
     #[allow(unused_variables)]
@@ -5965,11 +6181,11 @@ impl Error for PutDashboardError {}
 #[derive(Debug, PartialEq)]
 pub enum PutInsightRuleError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The operation exceeded one or more limits.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl PutInsightRuleError {
@@ -5982,17 +6198,17 @@ impl PutInsightRuleError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(PutInsightRuleError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededException" => {
                         return RusotoError::Service(PutInsightRuleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(PutInsightRuleError::MissingRequiredParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6009,6 +6225,15 @@ impl PutInsightRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutInsightRuleError::InvalidParameterValue(ref cause) => cause.request_id(),
+            PutInsightRuleError::LimitExceeded(ref cause) => cause.request_id(),
+            PutInsightRuleError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutInsightRuleError {
     #[allow(unused_variables)]
@@ -6025,7 +6250,7 @@ impl Error for PutInsightRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum PutMetricAlarmError {
     /// <p>The quota for alarms for this customer has already been reached.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
 }
 
 impl PutMetricAlarmError {
@@ -6038,7 +6263,7 @@ impl PutMetricAlarmError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutMetricAlarmError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6055,6 +6280,13 @@ impl PutMetricAlarmError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutMetricAlarmError::LimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutMetricAlarmError {
     #[allow(unused_variables)]
@@ -6069,13 +6301,13 @@ impl Error for PutMetricAlarmError {}
 #[derive(Debug, PartialEq)]
 pub enum PutMetricDataError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>Parameters were used together that cannot be used together.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>An input parameter that is required is missing.</p>
-    MissingRequiredParameter(String),
+    MissingRequiredParameter(XmlErrorMessage),
 }
 
 impl PutMetricDataError {
@@ -6088,22 +6320,24 @@ impl PutMetricDataError {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
                         return RusotoError::Service(PutMetricDataError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            PutMetricDataError::InvalidParameterCombination(parsed_error.message),
+                            PutMetricDataError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(PutMetricDataError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(PutMetricDataError::MissingRequiredParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6120,6 +6354,16 @@ impl PutMetricDataError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutMetricDataError::InternalServiceFault(ref cause) => cause.request_id(),
+            PutMetricDataError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            PutMetricDataError::InvalidParameterValue(ref cause) => cause.request_id(),
+            PutMetricDataError::MissingRequiredParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutMetricDataError {
     #[allow(unused_variables)]
@@ -6137,9 +6381,9 @@ impl Error for PutMetricDataError {}
 #[derive(Debug, PartialEq)]
 pub enum SetAlarmStateError {
     /// <p>Data was not syntactically valid JSON.</p>
-    InvalidFormatFault(String),
+    InvalidFormatFault(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl SetAlarmStateError {
@@ -6152,12 +6396,12 @@ impl SetAlarmStateError {
                 match &parsed_error.code[..] {
                     "InvalidFormat" => {
                         return RusotoError::Service(SetAlarmStateError::InvalidFormatFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(SetAlarmStateError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6174,6 +6418,14 @@ impl SetAlarmStateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetAlarmStateError::InvalidFormatFault(ref cause) => cause.request_id(),
+            SetAlarmStateError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetAlarmStateError {
     #[allow(unused_variables)]
@@ -6189,13 +6441,13 @@ impl Error for SetAlarmStateError {}
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
     /// <p>More than one process tried to modify a resource at the same time.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl TagResourceError {
@@ -6208,22 +6460,22 @@ impl TagResourceError {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
                         return RusotoError::Service(TagResourceError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalServiceError" => {
                         return RusotoError::Service(TagResourceError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(TagResourceError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6240,6 +6492,16 @@ impl TagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TagResourceError::ConcurrentModification(ref cause) => cause.request_id(),
+            TagResourceError::InternalServiceFault(ref cause) => cause.request_id(),
+            TagResourceError::InvalidParameterValue(ref cause) => cause.request_id(),
+            TagResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TagResourceError {
     #[allow(unused_variables)]
@@ -6257,13 +6519,13 @@ impl Error for TagResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
     /// <p>More than one process tried to modify a resource at the same time.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
-    InternalServiceFault(String),
+    InternalServiceFault(XmlErrorMessage),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The named resource does not exist.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
 }
 
 impl UntagResourceError {
@@ -6276,22 +6538,22 @@ impl UntagResourceError {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
                         return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalServiceError" => {
                         return RusotoError::Service(UntagResourceError::InternalServiceFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(UntagResourceError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6308,6 +6570,16 @@ impl UntagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UntagResourceError::ConcurrentModification(ref cause) => cause.request_id(),
+            UntagResourceError::InternalServiceFault(ref cause) => cause.request_id(),
+            UntagResourceError::InvalidParameterValue(ref cause) => cause.request_id(),
+            UntagResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UntagResourceError {
     #[allow(unused_variables)]
@@ -7289,6 +7561,7 @@ impl CloudWatch for CloudWatchClient {
         PutMetricDataInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        request.set_request_compression(true);
 
         let response = self
             .sign_and_dispatch(request, PutMetricDataError::from_response)
@@ -41,7 +41,7 @@ impl CodeBuildClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3498,7 +3498,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, BatchDeleteBuildsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDeleteBuildsOutput, _>()
     }
 
@@ -3516,7 +3516,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, BatchGetBuildsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetBuildsOutput, _>()
     }
 
@@ -3534,7 +3534,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, BatchGetProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetProjectsOutput, _>()
     }
 
@@ -3552,7 +3552,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, BatchGetReportGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetReportGroupsOutput, _>()
     }
 
@@ -3570,7 +3570,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, BatchGetReportsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetReportsOutput, _>()
     }
 
@@ -3588,7 +3588,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, CreateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectOutput, _>()
     }
 
@@ -3606,7 +3606,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, CreateReportGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateReportGroupOutput, _>()
     }
 
@@ -3624,7 +3624,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, CreateWebhookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWebhookOutput, _>()
     }
 
@@ -3642,7 +3642,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectOutput, _>()
     }
 
@@ -3660,7 +3660,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteReportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteReportOutput, _>()
     }
 
@@ -3678,7 +3678,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteReportGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteReportGroupOutput, _>()
     }
 
@@ -3696,7 +3696,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteResourcePolicyOutput, _>()
     }
 
@@ -3714,7 +3714,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteSourceCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSourceCredentialsOutput, _>()
     }
@@ -3733,7 +3733,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DeleteWebhookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebhookOutput, _>()
     }
 
@@ -3751,7 +3751,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, DescribeTestCasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTestCasesOutput, _>()
     }
 
@@ -3769,7 +3769,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, GetResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourcePolicyOutput, _>()
     }
 
@@ -3787,7 +3787,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ImportSourceCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ImportSourceCredentialsOutput, _>()
     }
@@ -3806,7 +3806,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, InvalidateProjectCacheError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<InvalidateProjectCacheOutput, _>()
     }
@@ -3825,7 +3825,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListBuildsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBuildsOutput, _>()
     }
 
@@ -3843,7 +3843,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListBuildsForProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBuildsForProjectOutput, _>()
     }
 
@@ -3863,7 +3863,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListCuratedEnvironmentImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCuratedEnvironmentImagesOutput, _>()
     }
@@ -3882,7 +3882,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsOutput, _>()
     }
 
@@ -3900,7 +3900,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListReportGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListReportGroupsOutput, _>()
     }
 
@@ -3918,7 +3918,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListReportsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListReportsOutput, _>()
     }
 
@@ -3939,7 +3939,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListReportsForReportGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListReportsForReportGroupOutput, _>()
     }
@@ -3958,7 +3958,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListSharedProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSharedProjectsOutput, _>()
     }
 
@@ -3976,7 +3976,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListSharedReportGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSharedReportGroupsOutput, _>()
     }
@@ -3993,7 +3993,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, ListSourceCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSourceCredentialsOutput, _>()
     }
 
@@ -4011,7 +4011,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, PutResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourcePolicyOutput, _>()
     }
 
@@ -4029,7 +4029,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, StartBuildError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartBuildOutput, _>()
     }
 
@@ -4047,7 +4047,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, StopBuildError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopBuildOutput, _>()
     }
 
@@ -4065,7 +4065,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, UpdateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectOutput, _>()
     }
 
@@ -4083,7 +4083,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, UpdateReportGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateReportGroupOutput, _>()
     }
 
@@ -4101,7 +4101,7 @@ impl CodeBuild for CodeBuildClient {
             .sign_and_dispatch(request, UpdateWebhookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebhookOutput, _>()
     }
 }
@@ -41,7 +41,7 @@ impl CodeCommitClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -15088,7 +15088,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchAssociateApprovalRuleTemplateWithRepositoriesOutput, _>()
     }
@@ -15111,7 +15111,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, BatchDescribeMergeConflictsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDescribeMergeConflictsOutput, _>()
     }
@@ -15139,7 +15139,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput, _>()
     }
@@ -15158,7 +15158,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, BatchGetCommitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetCommitsOutput, _>()
     }
 
@@ -15176,7 +15176,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, BatchGetRepositoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetRepositoriesOutput, _>()
     }
 
@@ -15198,7 +15198,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreateApprovalRuleTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateApprovalRuleTemplateOutput, _>()
     }
@@ -15234,7 +15234,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreateCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCommitOutput, _>()
     }
 
@@ -15252,7 +15252,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreatePullRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePullRequestOutput, _>()
     }
 
@@ -15274,7 +15274,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreatePullRequestApprovalRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePullRequestApprovalRuleOutput, _>()
     }
@@ -15293,7 +15293,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreateRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRepositoryOutput, _>()
     }
 
@@ -15315,7 +15315,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, CreateUnreferencedMergeCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateUnreferencedMergeCommitOutput, _>()
     }
@@ -15338,7 +15338,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeleteApprovalRuleTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApprovalRuleTemplateOutput, _>()
     }
@@ -15357,7 +15357,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeleteBranchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBranchOutput, _>()
     }
 
@@ -15375,7 +15375,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeleteCommentContentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteCommentContentOutput, _>()
     }
 
@@ -15393,7 +15393,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeleteFileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFileOutput, _>()
     }
 
@@ -15415,7 +15415,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeletePullRequestApprovalRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeletePullRequestApprovalRuleOutput, _>()
     }
@@ -15434,7 +15434,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DeleteRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRepositoryOutput, _>()
     }
 
@@ -15452,7 +15452,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DescribeMergeConflictsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMergeConflictsOutput, _>()
     }
@@ -15474,7 +15474,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, DescribePullRequestEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePullRequestEventsOutput, _>()
     }
@@ -15525,7 +15525,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<EvaluatePullRequestApprovalRulesOutput, _>()
     }
@@ -15547,7 +15547,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetApprovalRuleTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetApprovalRuleTemplateOutput, _>()
     }
@@ -15566,7 +15566,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetBlobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetBlobOutput, _>()
     }
 
@@ -15584,7 +15584,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetBranchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetBranchOutput, _>()
     }
 
@@ -15602,7 +15602,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetCommentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCommentOutput, _>()
     }
 
@@ -15620,7 +15620,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetCommentReactionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCommentReactionsOutput, _>()
     }
 
@@ -15642,7 +15642,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetCommentsForComparedCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCommentsForComparedCommitOutput, _>()
     }
@@ -15664,7 +15664,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetCommentsForPullRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCommentsForPullRequestOutput, _>()
     }
@@ -15683,7 +15683,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCommitOutput, _>()
     }
 
@@ -15701,7 +15701,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetDifferencesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDifferencesOutput, _>()
     }
 
@@ -15719,7 +15719,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetFileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFileOutput, _>()
     }
 
@@ -15737,7 +15737,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetFolderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFolderOutput, _>()
     }
 
@@ -15755,7 +15755,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetMergeCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMergeCommitOutput, _>()
     }
 
@@ -15773,7 +15773,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetMergeConflictsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMergeConflictsOutput, _>()
     }
 
@@ -15791,7 +15791,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetMergeOptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMergeOptionsOutput, _>()
     }
 
@@ -15809,7 +15809,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetPullRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPullRequestOutput, _>()
     }
 
@@ -15831,7 +15831,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetPullRequestApprovalStatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetPullRequestApprovalStatesOutput, _>()
     }
@@ -15854,7 +15854,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetPullRequestOverrideStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetPullRequestOverrideStateOutput, _>()
     }
@@ -15873,7 +15873,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRepositoryOutput, _>()
     }
 
@@ -15891,7 +15891,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, GetRepositoryTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRepositoryTriggersOutput, _>()
     }
 
@@ -15912,7 +15912,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, ListApprovalRuleTemplatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListApprovalRuleTemplatesOutput, _>()
     }
@@ -15940,7 +15940,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssociatedApprovalRuleTemplatesForRepositoryOutput, _>()
     }
@@ -15959,7 +15959,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, ListBranchesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBranchesOutput, _>()
     }
 
@@ -15977,7 +15977,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, ListPullRequestsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPullRequestsOutput, _>()
     }
 
@@ -15995,7 +15995,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, ListRepositoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRepositoriesOutput, _>()
     }
 
@@ -16022,7 +16022,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRepositoriesForApprovalRuleTemplateOutput, _>()
     }
@@ -16041,7 +16041,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -16063,7 +16063,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergeBranchesByFastForwardError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergeBranchesByFastForwardOutput, _>()
     }
@@ -16082,7 +16082,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergeBranchesBySquashError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<MergeBranchesBySquashOutput, _>()
     }
 
@@ -16103,7 +16103,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergeBranchesByThreeWayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergeBranchesByThreeWayOutput, _>()
     }
@@ -16126,7 +16126,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergePullRequestByFastForwardError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergePullRequestByFastForwardOutput, _>()
     }
@@ -16148,7 +16148,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergePullRequestBySquashError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergePullRequestBySquashOutput, _>()
     }
@@ -16171,7 +16171,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, MergePullRequestByThreeWayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergePullRequestByThreeWayOutput, _>()
     }
@@ -16217,7 +16217,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, PostCommentForComparedCommitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PostCommentForComparedCommitOutput, _>()
     }
@@ -16239,7 +16239,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, PostCommentForPullRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PostCommentForPullRequestOutput, _>()
     }
@@ -16258,7 +16258,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, PostCommentReplyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PostCommentReplyOutput, _>()
     }
 
@@ -16293,7 +16293,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, PutFileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutFileOutput, _>()
     }
 
@@ -16311,7 +16311,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, PutRepositoryTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRepositoryTriggersOutput, _>()
     }
 
@@ -16346,7 +16346,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, TestRepositoryTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<TestRepositoryTriggersOutput, _>()
     }
@@ -16391,7 +16391,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateApprovalRuleTemplateContentOutput, _>()
     }
@@ -16419,7 +16419,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateApprovalRuleTemplateDescriptionOutput, _>()
     }
@@ -16444,7 +16444,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, UpdateApprovalRuleTemplateNameError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateApprovalRuleTemplateNameOutput, _>()
     }
@@ -16463,7 +16463,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, UpdateCommentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCommentOutput, _>()
     }
 
@@ -16507,7 +16507,7 @@ impl CodeCommit for CodeCommitClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdatePullRequestApprovalRuleContentOutput, _>()
     }
@@ -16550,7 +16550,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, UpdatePullRequestDescriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdatePullRequestDescriptionOutput, _>()
     }
@@ -16572,7 +16572,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, UpdatePullRequestStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdatePullRequestStatusOutput, _>()
     }
@@ -16591,7 +16591,7 @@ impl CodeCommit for CodeCommitClient {
             .sign_and_dispatch(request, UpdatePullRequestTitleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdatePullRequestTitleOutput, _>()
     }
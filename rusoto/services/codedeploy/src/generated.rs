@@ -41,7 +41,7 @@ impl CodeDeployClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6792,7 +6792,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetApplicationRevisionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetApplicationRevisionsOutput, _>()
     }
@@ -6811,7 +6811,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetApplicationsOutput, _>()
     }
 
@@ -6832,7 +6832,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetDeploymentGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetDeploymentGroupsOutput, _>()
     }
@@ -6855,7 +6855,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetDeploymentInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetDeploymentInstancesOutput, _>()
     }
@@ -6877,7 +6877,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetDeploymentTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetDeploymentTargetsOutput, _>()
     }
@@ -6896,7 +6896,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetDeploymentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetDeploymentsOutput, _>()
     }
 
@@ -6918,7 +6918,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, BatchGetOnPremisesInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetOnPremisesInstancesOutput, _>()
     }
@@ -6954,7 +6954,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, CreateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationOutput, _>()
     }
 
@@ -6972,7 +6972,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, CreateDeploymentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDeploymentOutput, _>()
     }
 
@@ -6990,7 +6990,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, CreateDeploymentConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDeploymentConfigOutput, _>()
     }
@@ -7009,7 +7009,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, CreateDeploymentGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDeploymentGroupOutput, _>()
     }
 
@@ -7061,7 +7061,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, DeleteDeploymentGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDeploymentGroupOutput, _>()
     }
 
@@ -7082,7 +7082,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, DeleteGitHubAccountTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteGitHubAccountTokenOutput, _>()
     }
@@ -7105,7 +7105,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, DeleteResourcesByExternalIdError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteResourcesByExternalIdOutput, _>()
     }
@@ -7144,7 +7144,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetApplicationOutput, _>()
     }
 
@@ -7162,7 +7162,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetApplicationRevisionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetApplicationRevisionOutput, _>()
     }
@@ -7181,7 +7181,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetDeploymentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentOutput, _>()
     }
 
@@ -7199,7 +7199,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetDeploymentConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentConfigOutput, _>()
     }
 
@@ -7217,7 +7217,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetDeploymentGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentGroupOutput, _>()
     }
 
@@ -7235,7 +7235,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetDeploymentInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentInstanceOutput, _>()
     }
 
@@ -7253,7 +7253,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetDeploymentTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentTargetOutput, _>()
     }
 
@@ -7271,7 +7271,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, GetOnPremisesInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOnPremisesInstanceOutput, _>()
     }
 
@@ -7292,7 +7292,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListApplicationRevisionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListApplicationRevisionsOutput, _>()
     }
@@ -7311,7 +7311,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationsOutput, _>()
     }
 
@@ -7329,7 +7329,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListDeploymentConfigsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeploymentConfigsOutput, _>()
     }
 
@@ -7347,7 +7347,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListDeploymentGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeploymentGroupsOutput, _>()
     }
 
@@ -7368,7 +7368,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListDeploymentInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDeploymentInstancesOutput, _>()
     }
@@ -7387,7 +7387,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListDeploymentTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeploymentTargetsOutput, _>()
     }
 
@@ -7405,7 +7405,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListDeploymentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeploymentsOutput, _>()
     }
 
@@ -7427,7 +7427,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListGitHubAccountTokenNamesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListGitHubAccountTokenNamesOutput, _>()
     }
@@ -7449,7 +7449,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListOnPremisesInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListOnPremisesInstancesOutput, _>()
     }
@@ -7468,7 +7468,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -7495,7 +7495,7 @@ impl CodeDeploy for CodeDeployClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutLifecycleEventHookExecutionStatusOutput, _>()
     }
@@ -7600,7 +7600,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, StopDeploymentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopDeploymentOutput, _>()
     }
 
@@ -7618,7 +7618,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -7636,7 +7636,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 
@@ -7671,7 +7671,7 @@ impl CodeDeploy for CodeDeployClient {
             .sign_and_dispatch(request, UpdateDeploymentGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeploymentGroupOutput, _>()
     }
 }
@@ -1287,6 +1287,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("AssociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1298,13 +1299,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateRepositoryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateRepositoryError::from_response(response))
         }
     }
@@ -1322,6 +1323,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeCodeReview");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1330,13 +1332,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeCodeReviewResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeCodeReviewError::from_response(response))
         }
     }
@@ -1357,6 +1359,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1372,13 +1375,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRecommendationFeedbackResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRecommendationFeedbackError::from_response(response))
         }
     }
@@ -1399,6 +1402,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRepositoryAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1407,13 +1411,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRepositoryAssociationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRepositoryAssociationError::from_response(response))
         }
     }
@@ -1431,6 +1435,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DisassociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1439,13 +1444,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateRepositoryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateRepositoryError::from_response(response))
         }
     }
@@ -1460,6 +1465,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListCodeReviews");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1493,13 +1499,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListCodeReviewsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListCodeReviewsError::from_response(response))
         }
     }
@@ -1518,6 +1524,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1545,13 +1552,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRecommendationFeedbackResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRecommendationFeedbackError::from_response(response))
         }
     }
@@ -1569,6 +1576,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1586,13 +1594,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRecommendationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRecommendationsError::from_response(response))
         }
     }
@@ -1608,6 +1616,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRepositoryAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1645,13 +1654,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRepositoryAssociationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRepositoryAssociationsError::from_response(response))
         }
     }
@@ -1667,6 +1676,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("PutRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1678,13 +1688,13 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutRecommendationFeedbackResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutRecommendationFeedbackError::from_response(response))
         }
     }
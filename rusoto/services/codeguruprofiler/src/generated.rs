@@ -2018,6 +2018,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("AddNotificationChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2029,13 +2030,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddNotificationChannelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddNotificationChannelsError::from_response(response))
         }
     }
@@ -2053,6 +2054,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("BatchGetFrameMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2079,13 +2081,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchGetFrameMetricDataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchGetFrameMetricDataError::from_response(response))
         }
     }
@@ -2103,6 +2105,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ConfigureAgent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2114,13 +2117,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigureAgentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ConfigureAgentError::from_response(response))
         }
     }
@@ -2135,6 +2138,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("CreateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2150,13 +2154,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateProfilingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateProfilingGroupError::from_response(response))
         }
     }
@@ -2174,6 +2178,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DeleteProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2182,13 +2187,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteProfilingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteProfilingGroupError::from_response(response))
         }
     }
@@ -2206,6 +2211,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DescribeProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2214,13 +2220,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeProfilingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeProfilingGroupError::from_response(response))
         }
     }
@@ -2238,6 +2244,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetFindingsReportAccountSummary");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2258,13 +2265,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFindingsReportAccountSummaryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFindingsReportAccountSummaryError::from_response(
                 response,
             ))
@@ -2285,6 +2292,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetNotificationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2293,13 +2301,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetNotificationConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetNotificationConfigurationError::from_response(response))
         }
     }
@@ -2317,6 +2325,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2325,13 +2334,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPolicyError::from_response(response))
         }
     }
@@ -2349,6 +2358,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accept.as_ref());
@@ -2373,7 +2383,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetProfileResponse::default();
             result.profile = response.body;
@@ -2384,7 +2394,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetProfileError::from_response(response))
         }
     }
@@ -2402,6 +2412,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2418,13 +2429,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRecommendationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRecommendationsError::from_response(response))
         }
     }
@@ -2442,6 +2453,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListFindingsReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2464,13 +2476,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFindingsReportsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFindingsReportsError::from_response(response))
         }
     }
@@ -2488,6 +2500,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfileTimes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2511,13 +2524,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProfileTimesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProfileTimesError::from_response(response))
         }
     }
@@ -2532,6 +2545,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfilingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2552,13 +2566,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProfilingGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProfilingGroupsError::from_response(response))
         }
     }
@@ -2576,6 +2590,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PostAgentProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.agent_profile.to_owned());
@@ -2593,13 +2608,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PostAgentProfileResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PostAgentProfileError::from_response(response))
         }
     }
@@ -2618,6 +2633,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PutPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2629,13 +2645,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutPermissionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutPermissionError::from_response(response))
         }
     }
@@ -2655,6 +2671,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("RemoveNotificationChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2663,13 +2680,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RemoveNotificationChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveNotificationChannelError::from_response(response))
         }
     }
@@ -2688,6 +2705,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2700,13 +2718,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RemovePermissionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemovePermissionError::from_response(response))
         }
     }
@@ -2721,6 +2739,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("SubmitFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2732,13 +2751,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SubmitFeedbackResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SubmitFeedbackError::from_response(response))
         }
     }
@@ -2756,6 +2775,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("UpdateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2767,13 +2787,13 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateProfilingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateProfilingGroupError::from_response(response))
         }
     }
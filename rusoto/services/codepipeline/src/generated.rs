@@ -42,7 +42,7 @@ impl CodePipelineClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3925,7 +3925,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, AcknowledgeJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AcknowledgeJobOutput, _>()
     }
 
@@ -3946,7 +3946,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, AcknowledgeThirdPartyJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AcknowledgeThirdPartyJobOutput, _>()
     }
@@ -3968,7 +3968,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, CreateCustomActionTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCustomActionTypeOutput, _>()
     }
@@ -3987,7 +3987,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, CreatePipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePipelineOutput, _>()
     }
 
@@ -4042,7 +4042,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, DeleteWebhookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebhookOutput, _>()
     }
 
@@ -4066,7 +4066,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, DeregisterWebhookWithThirdPartyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterWebhookWithThirdPartyOutput, _>()
     }
@@ -4125,7 +4125,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, GetJobDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobDetailsOutput, _>()
     }
 
@@ -4143,7 +4143,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, GetPipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineOutput, _>()
     }
 
@@ -4161,7 +4161,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, GetPipelineExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineExecutionOutput, _>()
     }
 
@@ -4179,7 +4179,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, GetPipelineStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineStateOutput, _>()
     }
 
@@ -4200,7 +4200,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, GetThirdPartyJobDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetThirdPartyJobDetailsOutput, _>()
     }
@@ -4219,7 +4219,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListActionExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListActionExecutionsOutput, _>()
     }
 
@@ -4237,7 +4237,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListActionTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListActionTypesOutput, _>()
     }
 
@@ -4258,7 +4258,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListPipelineExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPipelineExecutionsOutput, _>()
     }
@@ -4277,7 +4277,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListPipelinesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPipelinesOutput, _>()
     }
 
@@ -4295,7 +4295,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -4313,7 +4313,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, ListWebhooksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWebhooksOutput, _>()
     }
 
@@ -4331,7 +4331,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, PollForJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PollForJobsOutput, _>()
     }
 
@@ -4352,7 +4352,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, PollForThirdPartyJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PollForThirdPartyJobsOutput, _>()
     }
 
@@ -4370,7 +4370,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, PutActionRevisionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutActionRevisionOutput, _>()
     }
 
@@ -4388,7 +4388,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, PutApprovalResultError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutApprovalResultOutput, _>()
     }
 
@@ -4480,7 +4480,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, PutWebhookError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutWebhookOutput, _>()
     }
 
@@ -4502,7 +4502,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, RegisterWebhookWithThirdPartyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterWebhookWithThirdPartyOutput, _>()
     }
@@ -4521,7 +4521,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, RetryStageExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RetryStageExecutionOutput, _>()
     }
 
@@ -4542,7 +4542,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, StartPipelineExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartPipelineExecutionOutput, _>()
     }
@@ -4564,7 +4564,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, StopPipelineExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopPipelineExecutionOutput, _>()
     }
 
@@ -4582,7 +4582,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -4600,7 +4600,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 
@@ -4618,7 +4618,7 @@ impl CodePipeline for CodePipelineClient {
             .sign_and_dispatch(request, UpdatePipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePipelineOutput, _>()
     }
 }
@@ -46,7 +46,7 @@ impl CodeStarConnectionsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -862,7 +862,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, CreateConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateConnectionOutput, _>()
     }
 
@@ -883,7 +883,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, CreateHostError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHostOutput, _>()
     }
 
@@ -904,7 +904,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, DeleteConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteConnectionOutput, _>()
     }
 
@@ -925,7 +925,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, DeleteHostError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHostOutput, _>()
     }
 
@@ -946,7 +946,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, GetConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionOutput, _>()
     }
 
@@ -967,7 +967,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, GetHostError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetHostOutput, _>()
     }
 
@@ -988,7 +988,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, ListConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListConnectionsOutput, _>()
     }
 
@@ -1009,7 +1009,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, ListHostsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListHostsOutput, _>()
     }
 
@@ -1030,7 +1030,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -1051,7 +1051,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -1072,7 +1072,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 }
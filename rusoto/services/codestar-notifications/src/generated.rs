@@ -1047,6 +1047,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("CreateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1058,13 +1059,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateNotificationRuleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateNotificationRuleError::from_response(response))
         }
     }
@@ -1079,6 +1080,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1090,13 +1092,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteNotificationRuleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteNotificationRuleError::from_response(response))
         }
     }
@@ -1111,6 +1113,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1122,13 +1125,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteTargetResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTargetError::from_response(response))
         }
     }
@@ -1143,6 +1146,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1154,13 +1158,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeNotificationRuleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeNotificationRuleError::from_response(response))
         }
     }
@@ -1175,6 +1179,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListEventTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1186,13 +1191,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEventTypesResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEventTypesError::from_response(response))
         }
     }
@@ -1207,6 +1212,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListNotificationRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1218,13 +1224,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListNotificationRulesResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListNotificationRulesError::from_response(response))
         }
     }
@@ -1239,6 +1245,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1250,13 +1257,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -1271,6 +1278,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1282,13 +1290,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTargetsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTargetsError::from_response(response))
         }
     }
@@ -1303,6 +1311,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Subscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1314,13 +1323,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<SubscribeResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SubscribeError::from_response(response))
         }
     }
@@ -1335,6 +1344,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1346,13 +1356,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -1367,6 +1377,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Unsubscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1378,13 +1389,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UnsubscribeResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UnsubscribeError::from_response(response))
         }
     }
@@ -1399,6 +1410,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1410,13 +1422,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -1431,6 +1443,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UpdateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1442,13 +1455,13 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateNotificationRuleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateNotificationRuleError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl CodeStarClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1682,7 +1682,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, AssociateTeamMemberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateTeamMemberResult, _>()
     }
 
@@ -1700,7 +1700,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, CreateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResult, _>()
     }
 
@@ -1718,7 +1718,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, CreateUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserProfileResult, _>()
     }
 
@@ -1736,7 +1736,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, DeleteProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectResult, _>()
     }
 
@@ -1754,7 +1754,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, DeleteUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserProfileResult, _>()
     }
 
@@ -1772,7 +1772,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, DescribeProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProjectResult, _>()
     }
 
@@ -1790,7 +1790,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, DescribeUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserProfileResult, _>()
     }
 
@@ -1808,7 +1808,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, DisassociateTeamMemberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateTeamMemberResult, _>()
     }
@@ -1827,7 +1827,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, ListProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsResult, _>()
     }
 
@@ -1845,7 +1845,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, ListResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourcesResult, _>()
     }
 
@@ -1863,7 +1863,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, ListTagsForProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForProjectResult, _>()
     }
 
@@ -1881,7 +1881,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, ListTeamMembersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTeamMembersResult, _>()
     }
 
@@ -1899,7 +1899,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, ListUserProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUserProfilesResult, _>()
     }
 
@@ -1917,7 +1917,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, TagProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagProjectResult, _>()
     }
 
@@ -1935,7 +1935,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, UntagProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagProjectResult, _>()
     }
 
@@ -1953,7 +1953,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, UpdateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectResult, _>()
     }
 
@@ -1971,7 +1971,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, UpdateTeamMemberError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTeamMemberResult, _>()
     }
 
@@ -1989,7 +1989,7 @@ impl CodeStar for CodeStarClient {
             .sign_and_dispatch(request, UpdateUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserProfileResult, _>()
     }
 }
@@ -42,7 +42,7 @@ impl CognitoIdentityClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2243,7 +2243,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, CreateIdentityPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
     }
 
@@ -2261,7 +2261,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, DeleteIdentitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteIdentitiesResponse, _>()
     }
 
@@ -2299,7 +2299,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, DescribeIdentityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityDescription, _>()
     }
 
@@ -2320,7 +2320,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, DescribeIdentityPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
     }
 
@@ -2342,7 +2342,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, GetCredentialsForIdentityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCredentialsForIdentityResponse, _>()
     }
@@ -2358,7 +2358,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, GetIdError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetIdResponse, _>()
     }
 
@@ -2379,7 +2379,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, GetIdentityPoolRolesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetIdentityPoolRolesResponse, _>()
     }
@@ -2398,7 +2398,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, GetOpenIdTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOpenIdTokenResponse, _>()
     }
 
@@ -2425,7 +2425,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetOpenIdTokenForDeveloperIdentityResponse, _>()
     }
@@ -2444,7 +2444,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, ListIdentitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIdentitiesResponse, _>()
     }
 
@@ -2465,7 +2465,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, ListIdentityPoolsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIdentityPoolsResponse, _>()
     }
 
@@ -2486,7 +2486,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2507,7 +2507,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, LookupDeveloperIdentityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<LookupDeveloperIdentityResponse, _>()
     }
@@ -2529,7 +2529,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, MergeDeveloperIdentitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<MergeDeveloperIdentitiesResponse, _>()
     }
@@ -2568,7 +2568,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2623,7 +2623,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2644,7 +2644,7 @@ impl CognitoIdentity for CognitoIdentityClient {
             .sign_and_dispatch(request, UpdateIdentityPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
     }
 }
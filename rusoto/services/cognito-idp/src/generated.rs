@@ -41,7 +41,7 @@ impl CognitoIdentityProviderClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -12777,7 +12777,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AddCustomAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddCustomAttributesResponse, _>()
     }
 
@@ -12818,7 +12818,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminConfirmSignUpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminConfirmSignUpResponse, _>()
     }
 
@@ -12839,7 +12839,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminCreateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminCreateUserResponse, _>()
     }
 
@@ -12881,7 +12881,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminDeleteUserAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminDeleteUserAttributesResponse, _>()
     }
@@ -12904,7 +12904,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminDisableProviderForUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminDisableProviderForUserResponse, _>()
     }
@@ -12926,7 +12926,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminDisableUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminDisableUserResponse, _>()
     }
 
@@ -12947,7 +12947,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminEnableUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminEnableUserResponse, _>()
     }
 
@@ -12988,7 +12988,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminGetDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminGetDeviceResponse, _>()
     }
 
@@ -13009,7 +13009,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminGetUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminGetUserResponse, _>()
     }
 
@@ -13030,7 +13030,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminInitiateAuthError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminInitiateAuthResponse, _>()
     }
 
@@ -13051,7 +13051,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminLinkProviderForUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminLinkProviderForUserResponse, _>()
     }
@@ -13073,7 +13073,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminListDevicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdminListDevicesResponse, _>()
     }
 
@@ -13094,7 +13094,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminListGroupsForUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminListGroupsForUserResponse, _>()
     }
@@ -13116,7 +13116,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminListUserAuthEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminListUserAuthEventsResponse, _>()
     }
@@ -13158,7 +13158,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminResetUserPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminResetUserPasswordResponse, _>()
     }
@@ -13181,7 +13181,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminRespondToAuthChallengeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminRespondToAuthChallengeResponse, _>()
     }
@@ -13204,7 +13204,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminSetUserMFAPreferenceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminSetUserMFAPreferenceResponse, _>()
     }
@@ -13226,7 +13226,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminSetUserPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminSetUserPasswordResponse, _>()
     }
@@ -13248,7 +13248,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminSetUserSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminSetUserSettingsResponse, _>()
     }
@@ -13271,7 +13271,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminUpdateAuthEventFeedbackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminUpdateAuthEventFeedbackResponse, _>()
     }
@@ -13293,7 +13293,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminUpdateDeviceStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminUpdateDeviceStatusResponse, _>()
     }
@@ -13316,7 +13316,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminUpdateUserAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminUpdateUserAttributesResponse, _>()
     }
@@ -13338,7 +13338,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AdminUserGlobalSignOutError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AdminUserGlobalSignOutResponse, _>()
     }
@@ -13360,7 +13360,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, AssociateSoftwareTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateSoftwareTokenResponse, _>()
     }
@@ -13382,7 +13382,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ChangePasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ChangePasswordResponse, _>()
     }
 
@@ -13403,7 +13403,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ConfirmDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ConfirmDeviceResponse, _>()
     }
 
@@ -13424,7 +13424,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ConfirmForgotPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ConfirmForgotPasswordResponse, _>()
     }
@@ -13446,7 +13446,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ConfirmSignUpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ConfirmSignUpResponse, _>()
     }
 
@@ -13467,7 +13467,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGroupResponse, _>()
     }
 
@@ -13488,7 +13488,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateIdentityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateIdentityProviderResponse, _>()
     }
@@ -13510,7 +13510,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateResourceServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateResourceServerResponse, _>()
     }
@@ -13532,7 +13532,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateUserImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserImportJobResponse, _>()
     }
 
@@ -13553,7 +13553,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateUserPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserPoolResponse, _>()
     }
 
@@ -13574,7 +13574,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateUserPoolClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateUserPoolClientResponse, _>()
     }
@@ -13596,7 +13596,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, CreateUserPoolDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateUserPoolDomainResponse, _>()
     }
@@ -13698,7 +13698,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DeleteUserAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteUserAttributesResponse, _>()
     }
@@ -13760,7 +13760,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DeleteUserPoolDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteUserPoolDomainResponse, _>()
     }
@@ -13782,7 +13782,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeIdentityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeIdentityProviderResponse, _>()
     }
@@ -13804,7 +13804,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeResourceServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeResourceServerResponse, _>()
     }
@@ -13827,7 +13827,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeRiskConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRiskConfigurationResponse, _>()
     }
@@ -13849,7 +13849,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeUserImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeUserImportJobResponse, _>()
     }
@@ -13871,7 +13871,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeUserPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserPoolResponse, _>()
     }
 
@@ -13892,7 +13892,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeUserPoolClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeUserPoolClientResponse, _>()
     }
@@ -13914,7 +13914,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, DescribeUserPoolDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeUserPoolDomainResponse, _>()
     }
@@ -13956,7 +13956,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ForgotPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ForgotPasswordResponse, _>()
     }
 
@@ -13977,7 +13977,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetCSVHeaderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCSVHeaderResponse, _>()
     }
 
@@ -13998,7 +13998,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResponse, _>()
     }
 
@@ -14016,7 +14016,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGroupResponse, _>()
     }
 
@@ -14040,7 +14040,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetIdentityProviderByIdentifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetIdentityProviderByIdentifierResponse, _>()
     }
@@ -14062,7 +14062,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetSigningCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSigningCertificateResponse, _>()
     }
@@ -14084,7 +14084,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetUICustomizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetUICustomizationResponse, _>()
     }
 
@@ -14102,7 +14102,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()
     }
 
@@ -14129,7 +14129,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetUserAttributeVerificationCodeResponse, _>()
     }
@@ -14151,7 +14151,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GetUserPoolMfaConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetUserPoolMfaConfigResponse, _>()
     }
@@ -14173,7 +14173,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, GlobalSignOutError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GlobalSignOutResponse, _>()
     }
 
@@ -14194,7 +14194,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, InitiateAuthError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<InitiateAuthResponse, _>()
     }
 
@@ -14215,7 +14215,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListDevicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDevicesResponse, _>()
     }
 
@@ -14236,7 +14236,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGroupsResponse, _>()
     }
 
@@ -14257,7 +14257,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListIdentityProvidersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListIdentityProvidersResponse, _>()
     }
@@ -14279,7 +14279,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListResourceServersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourceServersResponse, _>()
     }
 
@@ -14300,7 +14300,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -14321,7 +14321,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListUserImportJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUserImportJobsResponse, _>()
     }
 
@@ -14342,7 +14342,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListUserPoolClientsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUserPoolClientsResponse, _>()
     }
 
@@ -14363,7 +14363,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListUserPoolsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUserPoolsResponse, _>()
     }
 
@@ -14384,7 +14384,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUsersResponse, _>()
     }
 
@@ -14405,7 +14405,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ListUsersInGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUsersInGroupResponse, _>()
     }
 
@@ -14426,7 +14426,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, ResendConfirmationCodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ResendConfirmationCodeResponse, _>()
     }
@@ -14448,7 +14448,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, RespondToAuthChallengeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RespondToAuthChallengeResponse, _>()
     }
@@ -14470,7 +14470,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SetRiskConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SetRiskConfigurationResponse, _>()
     }
@@ -14492,7 +14492,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SetUICustomizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SetUICustomizationResponse, _>()
     }
 
@@ -14513,7 +14513,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SetUserMFAPreferenceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SetUserMFAPreferenceResponse, _>()
     }
@@ -14535,7 +14535,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SetUserPoolMfaConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SetUserPoolMfaConfigResponse, _>()
     }
@@ -14557,7 +14557,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SetUserSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SetUserSettingsResponse, _>()
     }
 
@@ -14575,7 +14575,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, SignUpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SignUpResponse, _>()
     }
 
@@ -14596,7 +14596,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, StartUserImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartUserImportJobResponse, _>()
     }
 
@@ -14617,7 +14617,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, StopUserImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopUserImportJobResponse, _>()
     }
 
@@ -14638,7 +14638,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -14659,7 +14659,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -14680,7 +14680,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateAuthEventFeedbackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateAuthEventFeedbackResponse, _>()
     }
@@ -14702,7 +14702,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateDeviceStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeviceStatusResponse, _>()
     }
 
@@ -14723,7 +14723,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGroupResponse, _>()
     }
 
@@ -14744,7 +14744,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateIdentityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateIdentityProviderResponse, _>()
     }
@@ -14766,7 +14766,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateResourceServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateResourceServerResponse, _>()
     }
@@ -14788,7 +14788,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateUserAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateUserAttributesResponse, _>()
     }
@@ -14810,7 +14810,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateUserPoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserPoolResponse, _>()
     }
 
@@ -14831,7 +14831,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateUserPoolClientError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateUserPoolClientResponse, _>()
     }
@@ -14853,7 +14853,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, UpdateUserPoolDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateUserPoolDomainResponse, _>()
     }
@@ -14875,7 +14875,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, VerifySoftwareTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VerifySoftwareTokenResponse, _>()
     }
 
@@ -14896,7 +14896,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
             .sign_and_dispatch(request, VerifyUserAttributeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyUserAttributeResponse, _>()
     }
 }
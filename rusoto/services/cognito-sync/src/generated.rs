@@ -1876,6 +1876,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("BulkPublish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1884,13 +1885,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BulkPublishResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BulkPublishError::from_response(response))
         }
     }
@@ -1909,6 +1910,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1917,13 +1919,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDatasetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDatasetError::from_response(response))
         }
     }
@@ -1942,6 +1944,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1950,13 +1953,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDatasetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDatasetError::from_response(response))
         }
     }
@@ -1974,6 +1977,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1982,13 +1986,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeIdentityPoolUsageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeIdentityPoolUsageError::from_response(response))
         }
     }
@@ -2006,6 +2010,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2014,13 +2019,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeIdentityUsageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeIdentityUsageError::from_response(response))
         }
     }
@@ -2037,6 +2042,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetBulkPublishDetails");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2045,13 +2051,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBulkPublishDetailsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBulkPublishDetailsError::from_response(response))
         }
     }
@@ -2068,6 +2074,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2076,13 +2083,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCognitoEventsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCognitoEventsError::from_response(response))
         }
     }
@@ -2100,6 +2107,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2108,13 +2116,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIdentityPoolConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIdentityPoolConfigurationError::from_response(response))
         }
     }
@@ -2132,6 +2140,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2149,13 +2158,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDatasetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDatasetsError::from_response(response))
         }
     }
@@ -2169,6 +2178,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2186,13 +2196,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListIdentityPoolUsageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListIdentityPoolUsageError::from_response(response))
         }
     }
@@ -2206,6 +2216,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2229,13 +2240,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRecordsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRecordsError::from_response(response))
         }
     }
@@ -2253,6 +2264,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("RegisterDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2264,13 +2276,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterDeviceError::from_response(response))
         }
     }
@@ -2287,6 +2299,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2298,12 +2311,12 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SetCognitoEventsError::from_response(response))
         }
     }
@@ -2321,6 +2334,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2332,13 +2346,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SetIdentityPoolConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SetIdentityPoolConfigurationError::from_response(response))
         }
     }
@@ -2352,6 +2366,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SubscribeToDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2360,13 +2375,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SubscribeToDatasetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SubscribeToDatasetError::from_response(response))
         }
     }
@@ -2380,6 +2395,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UnsubscribeFromDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2388,13 +2404,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UnsubscribeFromDatasetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UnsubscribeFromDatasetError::from_response(response))
         }
     }
@@ -2413,6 +2429,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UpdateRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2425,13 +2442,13 @@ impl CognitoSync for CognitoSyncClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRecordsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRecordsError::from_response(response))
         }
     }
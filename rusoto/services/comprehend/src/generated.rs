@@ -41,7 +41,7 @@ impl ComprehendClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5949,7 +5949,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, BatchDetectDominantLanguageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDetectDominantLanguageResponse, _>()
     }
@@ -5968,7 +5968,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, BatchDetectEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDetectEntitiesResponse, _>()
     }
 
@@ -5986,7 +5986,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, BatchDetectKeyPhrasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDetectKeyPhrasesResponse, _>()
     }
@@ -6005,7 +6005,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, BatchDetectSentimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDetectSentimentResponse, _>()
     }
@@ -6024,7 +6024,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, BatchDetectSyntaxError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDetectSyntaxResponse, _>()
     }
 
@@ -6042,7 +6042,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ClassifyDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ClassifyDocumentResponse, _>()
     }
 
@@ -6063,7 +6063,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, CreateDocumentClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDocumentClassifierResponse, _>()
     }
@@ -6082,7 +6082,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, CreateEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointResponse, _>()
     }
 
@@ -6100,7 +6100,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, CreateEntityRecognizerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateEntityRecognizerResponse, _>()
     }
@@ -6122,7 +6122,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DeleteDocumentClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteDocumentClassifierResponse, _>()
     }
@@ -6141,7 +6141,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DeleteEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteEndpointResponse, _>()
     }
 
@@ -6159,7 +6159,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DeleteEntityRecognizerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteEntityRecognizerResponse, _>()
     }
@@ -6187,7 +6187,7 @@ impl Comprehend for ComprehendClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDocumentClassificationJobResponse, _>()
     }
@@ -6210,7 +6210,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeDocumentClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDocumentClassifierResponse, _>()
     }
@@ -6238,7 +6238,7 @@ impl Comprehend for ComprehendClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDominantLanguageDetectionJobResponse, _>()
     }
@@ -6257,7 +6257,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEndpointResponse, _>()
     }
 
@@ -6279,7 +6279,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeEntitiesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEntitiesDetectionJobResponse, _>()
     }
@@ -6301,7 +6301,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeEntityRecognizerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEntityRecognizerResponse, _>()
     }
@@ -6326,7 +6326,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeKeyPhrasesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeKeyPhrasesDetectionJobResponse, _>()
     }
@@ -6351,7 +6351,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeSentimentDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSentimentDetectionJobResponse, _>()
     }
@@ -6374,7 +6374,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DescribeTopicsDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTopicsDetectionJobResponse, _>()
     }
@@ -6393,7 +6393,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DetectDominantLanguageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DetectDominantLanguageResponse, _>()
     }
@@ -6412,7 +6412,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DetectEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectEntitiesResponse, _>()
     }
 
@@ -6430,7 +6430,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DetectKeyPhrasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectKeyPhrasesResponse, _>()
     }
 
@@ -6448,7 +6448,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DetectSentimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectSentimentResponse, _>()
     }
 
@@ -6466,7 +6466,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, DetectSyntaxError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectSyntaxResponse, _>()
     }
 
@@ -6490,7 +6490,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListDocumentClassificationJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDocumentClassificationJobsResponse, _>()
     }
@@ -6512,7 +6512,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListDocumentClassifiersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDocumentClassifiersResponse, _>()
     }
@@ -6540,7 +6540,7 @@ impl Comprehend for ComprehendClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDominantLanguageDetectionJobsResponse, _>()
     }
@@ -6559,7 +6559,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointsResponse, _>()
     }
 
@@ -6581,7 +6581,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListEntitiesDetectionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListEntitiesDetectionJobsResponse, _>()
     }
@@ -6600,7 +6600,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListEntityRecognizersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListEntityRecognizersResponse, _>()
     }
@@ -6623,7 +6623,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListKeyPhrasesDetectionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListKeyPhrasesDetectionJobsResponse, _>()
     }
@@ -6646,7 +6646,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListSentimentDetectionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSentimentDetectionJobsResponse, _>()
     }
@@ -6665,7 +6665,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -6686,7 +6686,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, ListTopicsDetectionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTopicsDetectionJobsResponse, _>()
     }
@@ -6711,7 +6711,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StartDocumentClassificationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDocumentClassificationJobResponse, _>()
     }
@@ -6739,7 +6739,7 @@ impl Comprehend for ComprehendClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDominantLanguageDetectionJobResponse, _>()
     }
@@ -6762,7 +6762,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StartEntitiesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartEntitiesDetectionJobResponse, _>()
     }
@@ -6785,7 +6785,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StartKeyPhrasesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartKeyPhrasesDetectionJobResponse, _>()
     }
@@ -6808,7 +6808,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StartSentimentDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartSentimentDetectionJobResponse, _>()
     }
@@ -6830,7 +6830,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StartTopicsDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartTopicsDetectionJobResponse, _>()
     }
@@ -6858,7 +6858,7 @@ impl Comprehend for ComprehendClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopDominantLanguageDetectionJobResponse, _>()
     }
@@ -6880,7 +6880,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StopEntitiesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopEntitiesDetectionJobResponse, _>()
     }
@@ -6903,7 +6903,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StopKeyPhrasesDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopKeyPhrasesDetectionJobResponse, _>()
     }
@@ -6926,7 +6926,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StopSentimentDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopSentimentDetectionJobResponse, _>()
     }
@@ -6951,7 +6951,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StopTrainingDocumentClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopTrainingDocumentClassifierResponse, _>()
     }
@@ -6974,7 +6974,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, StopTrainingEntityRecognizerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopTrainingEntityRecognizerResponse, _>()
     }
@@ -6993,7 +6993,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -7011,7 +7011,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -7029,7 +7029,7 @@ impl Comprehend for ComprehendClient {
             .sign_and_dispatch(request, UpdateEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointResponse, _>()
     }
 }
@@ -42,7 +42,7 @@ impl ComprehendMedicalClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2380,7 +2380,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DescribeEntitiesDetectionV2JobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEntitiesDetectionV2JobResponse, _>()
     }
@@ -2403,7 +2403,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DescribeICD10CMInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeICD10CMInferenceJobResponse, _>()
     }
@@ -2425,7 +2425,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DescribePHIDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePHIDetectionJobResponse, _>()
     }
@@ -2448,7 +2448,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DescribeRxNormInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRxNormInferenceJobResponse, _>()
     }
@@ -2467,7 +2467,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DetectEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectEntitiesResponse, _>()
     }
 
@@ -2488,7 +2488,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DetectEntitiesV2Error::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectEntitiesV2Response, _>()
     }
 
@@ -2506,7 +2506,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, DetectPHIError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectPHIResponse, _>()
     }
 
@@ -2524,7 +2524,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, InferICD10CMError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<InferICD10CMResponse, _>()
     }
 
@@ -2542,7 +2542,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, InferRxNormError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<InferRxNormResponse, _>()
     }
 
@@ -2564,7 +2564,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, ListEntitiesDetectionV2JobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListEntitiesDetectionV2JobsResponse, _>()
     }
@@ -2586,7 +2586,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, ListICD10CMInferenceJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListICD10CMInferenceJobsResponse, _>()
     }
@@ -2608,7 +2608,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, ListPHIDetectionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPHIDetectionJobsResponse, _>()
     }
@@ -2630,7 +2630,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, ListRxNormInferenceJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRxNormInferenceJobsResponse, _>()
     }
@@ -2653,7 +2653,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StartEntitiesDetectionV2JobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartEntitiesDetectionV2JobResponse, _>()
     }
@@ -2675,7 +2675,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StartICD10CMInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartICD10CMInferenceJobResponse, _>()
     }
@@ -2697,7 +2697,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StartPHIDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartPHIDetectionJobResponse, _>()
     }
@@ -2719,7 +2719,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StartRxNormInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartRxNormInferenceJobResponse, _>()
     }
@@ -2742,7 +2742,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StopEntitiesDetectionV2JobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopEntitiesDetectionV2JobResponse, _>()
     }
@@ -2764,7 +2764,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StopICD10CMInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopICD10CMInferenceJobResponse, _>()
     }
@@ -2786,7 +2786,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StopPHIDetectionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopPHIDetectionJobResponse, _>()
     }
 
@@ -2807,7 +2807,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .sign_and_dispatch(request, StopRxNormInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopRxNormInferenceJobResponse, _>()
     }
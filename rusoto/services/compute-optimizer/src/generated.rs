@@ -42,7 +42,7 @@ impl ComputeOptimizerClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1725,7 +1725,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRecommendationExportJobsResponse, _>()
     }
@@ -1753,7 +1753,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExportAutoScalingGroupRecommendationsResponse, _>()
     }
@@ -1781,7 +1781,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExportEC2InstanceRecommendationsResponse, _>()
     }
@@ -1809,7 +1809,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAutoScalingGroupRecommendationsResponse, _>()
     }
@@ -1834,7 +1834,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .sign_and_dispatch(request, GetEC2InstanceRecommendationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetEC2InstanceRecommendationsResponse, _>()
     }
@@ -1862,7 +1862,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetEC2RecommendationProjectedMetricsResponse, _>()
     }
@@ -1882,7 +1882,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .sign_and_dispatch(request, GetEnrollmentStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetEnrollmentStatusResponse, _>()
     }
 
@@ -1904,7 +1904,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .sign_and_dispatch(request, GetRecommendationSummariesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRecommendationSummariesResponse, _>()
     }
@@ -1926,7 +1926,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .sign_and_dispatch(request, UpdateEnrollmentStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateEnrollmentStatusResponse, _>()
     }
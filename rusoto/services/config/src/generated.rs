@@ -41,7 +41,7 @@ impl ConfigServiceClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -8293,7 +8293,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, BatchGetAggregateResourceConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetAggregateResourceConfigResponse, _>()
     }
@@ -8312,7 +8312,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, BatchGetResourceConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetResourceConfigResponse, _>()
     }
@@ -8445,7 +8445,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DeleteEvaluationResultsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteEvaluationResultsResponse, _>()
     }
@@ -8533,7 +8533,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DeleteRemediationConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRemediationConfigurationResponse, _>()
     }
@@ -8556,7 +8556,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DeleteRemediationExceptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRemediationExceptionsResponse, _>()
     }
@@ -8612,7 +8612,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DeliverConfigSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeliverConfigSnapshotResponse, _>()
     }
@@ -8640,7 +8640,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAggregateComplianceByConfigRulesResponse, _>()
     }
@@ -8668,7 +8668,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAggregationAuthorizationsResponse, _>()
     }
@@ -8693,7 +8693,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeComplianceByConfigRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeComplianceByConfigRuleResponse, _>()
     }
@@ -8716,7 +8716,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeComplianceByResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeComplianceByResourceResponse, _>()
     }
@@ -8744,7 +8744,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigRuleEvaluationStatusResponse, _>()
     }
@@ -8763,7 +8763,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeConfigRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeConfigRulesResponse, _>()
     }
 
@@ -8790,7 +8790,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigurationAggregatorSourcesStatusResponse, _>()
     }
@@ -8818,7 +8818,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigurationAggregatorsResponse, _>()
     }
@@ -8846,7 +8846,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigurationRecorderStatusResponse, _>()
     }
@@ -8871,7 +8871,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeConfigurationRecordersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigurationRecordersResponse, _>()
     }
@@ -8899,7 +8899,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConformancePackComplianceResponse, _>()
     }
@@ -8924,7 +8924,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeConformancePackStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConformancePackStatusResponse, _>()
     }
@@ -8946,7 +8946,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeConformancePacksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConformancePacksResponse, _>()
     }
@@ -8971,7 +8971,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeDeliveryChannelStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDeliveryChannelStatusResponse, _>()
     }
@@ -8993,7 +8993,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeDeliveryChannelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDeliveryChannelsResponse, _>()
     }
@@ -9021,7 +9021,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationConfigRuleStatusesResponse, _>()
     }
@@ -9046,7 +9046,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeOrganizationConfigRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationConfigRulesResponse, _>()
     }
@@ -9074,7 +9074,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationConformancePackStatusesResponse, _>()
     }
@@ -9102,7 +9102,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationConformancePacksResponse, _>()
     }
@@ -9130,7 +9130,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePendingAggregationRequestsResponse, _>()
     }
@@ -9158,7 +9158,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRemediationConfigurationsResponse, _>()
     }
@@ -9183,7 +9183,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeRemediationExceptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRemediationExceptionsResponse, _>()
     }
@@ -9211,7 +9211,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRemediationExecutionStatusResponse, _>()
     }
@@ -9236,7 +9236,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, DescribeRetentionConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRetentionConfigurationsResponse, _>()
     }
@@ -9264,7 +9264,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAggregateComplianceDetailsByConfigRuleResponse, _>()
     }
@@ -9292,7 +9292,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAggregateConfigRuleComplianceSummaryResponse, _>()
     }
@@ -9320,7 +9320,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAggregateDiscoveredResourceCountsResponse, _>()
     }
@@ -9343,7 +9343,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, GetAggregateResourceConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAggregateResourceConfigResponse, _>()
     }
@@ -9371,7 +9371,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetComplianceDetailsByConfigRuleResponse, _>()
     }
@@ -9396,7 +9396,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, GetComplianceDetailsByResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetComplianceDetailsByResourceResponse, _>()
     }
@@ -9422,7 +9422,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetComplianceSummaryByConfigRuleResponse, _>()
     }
@@ -9450,7 +9450,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetComplianceSummaryByResourceTypeResponse, _>()
     }
@@ -9478,7 +9478,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetConformancePackComplianceDetailsResponse, _>()
     }
@@ -9506,7 +9506,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetConformancePackComplianceSummaryResponse, _>()
     }
@@ -9529,7 +9529,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, GetDiscoveredResourceCountsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDiscoveredResourceCountsResponse, _>()
     }
@@ -9557,7 +9557,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetOrganizationConfigRuleDetailedStatusResponse, _>()
     }
@@ -9585,7 +9585,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetOrganizationConformancePackDetailedStatusResponse, _>()
     }
@@ -9607,7 +9607,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, GetResourceConfigHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetResourceConfigHistoryResponse, _>()
     }
@@ -9635,7 +9635,7 @@ impl ConfigService for ConfigServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAggregateDiscoveredResourcesResponse, _>()
     }
@@ -9657,7 +9657,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, ListDiscoveredResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDiscoveredResourcesResponse, _>()
     }
@@ -9676,7 +9676,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -9698,7 +9698,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutAggregationAuthorizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutAggregationAuthorizationResponse, _>()
     }
@@ -9738,7 +9738,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutConfigurationAggregatorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutConfigurationAggregatorResponse, _>()
     }
@@ -9777,7 +9777,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutConformancePackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutConformancePackResponse, _>()
     }
 
@@ -9812,7 +9812,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutEvaluationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutEvaluationsResponse, _>()
     }
 
@@ -9834,7 +9834,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutOrganizationConfigRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutOrganizationConfigRuleResponse, _>()
     }
@@ -9859,7 +9859,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutOrganizationConformancePackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutOrganizationConformancePackResponse, _>()
     }
@@ -9882,7 +9882,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutRemediationConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutRemediationConfigurationsResponse, _>()
     }
@@ -9904,7 +9904,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutRemediationExceptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutRemediationExceptionsResponse, _>()
     }
@@ -9944,7 +9944,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, PutRetentionConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutRetentionConfigurationResponse, _>()
     }
@@ -9969,7 +9969,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, SelectAggregateResourceConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SelectAggregateResourceConfigResponse, _>()
     }
@@ -9988,7 +9988,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, SelectResourceConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SelectResourceConfigResponse, _>()
     }
@@ -10011,7 +10011,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, StartConfigRulesEvaluationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartConfigRulesEvaluationResponse, _>()
     }
@@ -10054,7 +10054,7 @@ impl ConfigService for ConfigServiceClient {
             .sign_and_dispatch(request, StartRemediationExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartRemediationExecutionResponse, _>()
     }
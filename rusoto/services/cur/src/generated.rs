@@ -41,7 +41,7 @@ impl CostAndUsageReportClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -380,7 +380,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
             .sign_and_dispatch(request, DeleteReportDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteReportDefinitionResponse, _>()
     }
@@ -403,7 +403,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
             .sign_and_dispatch(request, DescribeReportDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReportDefinitionsResponse, _>()
     }
@@ -425,7 +425,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
             .sign_and_dispatch(request, ModifyReportDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyReportDefinitionResponse, _>()
     }
@@ -447,7 +447,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
             .sign_and_dispatch(request, PutReportDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutReportDefinitionResponse, _>()
     }
 }
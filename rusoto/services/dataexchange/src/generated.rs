@@ -2405,6 +2405,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2413,12 +2414,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelJobError::from_response(response))
         }
     }
@@ -2432,6 +2433,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2443,13 +2445,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDataSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDataSetError::from_response(response))
         }
     }
@@ -2463,6 +2465,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2474,13 +2477,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateJobError::from_response(response))
         }
     }
@@ -2497,6 +2500,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2508,13 +2512,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRevisionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRevisionError::from_response(response))
         }
     }
@@ -2533,6 +2537,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2541,12 +2546,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAssetError::from_response(response))
         }
     }
@@ -2563,6 +2568,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2571,12 +2577,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDataSetError::from_response(response))
         }
     }
@@ -2594,6 +2600,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2602,12 +2609,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRevisionError::from_response(response))
         }
     }
@@ -2626,6 +2633,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2634,13 +2642,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAssetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAssetError::from_response(response))
         }
     }
@@ -2657,6 +2665,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2665,13 +2674,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDataSetError::from_response(response))
         }
     }
@@ -2685,6 +2694,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2693,13 +2703,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetJobError::from_response(response))
         }
     }
@@ -2717,6 +2727,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2725,13 +2736,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRevisionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRevisionError::from_response(response))
         }
     }
@@ -2748,6 +2759,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSetRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2765,13 +2777,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataSetRevisionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataSetRevisionsError::from_response(response))
         }
     }
@@ -2785,6 +2797,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2805,13 +2818,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataSetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataSetsError::from_response(response))
         }
     }
@@ -2825,6 +2838,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2848,13 +2862,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListJobsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsError::from_response(response))
         }
     }
@@ -2872,6 +2886,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListRevisionAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2889,13 +2904,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRevisionAssetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRevisionAssetsError::from_response(response))
         }
     }
@@ -2909,6 +2924,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2917,13 +2933,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -2937,6 +2953,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2945,13 +2962,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartJobError::from_response(response))
         }
     }
@@ -2965,6 +2982,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2976,12 +2994,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -2995,6 +3013,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3009,12 +3028,12 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3033,6 +3052,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3044,13 +3064,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateAssetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAssetError::from_response(response))
         }
     }
@@ -3067,6 +3087,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3078,13 +3099,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSetError::from_response(response))
         }
     }
@@ -3102,6 +3123,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3113,13 +3135,13 @@ impl DataExchange for DataExchangeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRevisionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRevisionError::from_response(response))
         }
     }
@@ -42,7 +42,7 @@ impl DataPipelineClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1843,7 +1843,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, ActivatePipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivatePipelineOutput, _>()
     }
 
@@ -1861,7 +1861,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, AddTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
     }
 
@@ -1879,7 +1879,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, CreatePipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePipelineOutput, _>()
     }
 
@@ -1897,7 +1897,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, DeactivatePipelineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeactivatePipelineOutput, _>()
     }
 
@@ -1932,7 +1932,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, DescribeObjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeObjectsOutput, _>()
     }
 
@@ -1950,7 +1950,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, DescribePipelinesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePipelinesOutput, _>()
     }
 
@@ -1968,7 +1968,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, EvaluateExpressionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EvaluateExpressionOutput, _>()
     }
 
@@ -1986,7 +1986,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, GetPipelineDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineDefinitionOutput, _>()
     }
 
@@ -2004,7 +2004,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, ListPipelinesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPipelinesOutput, _>()
     }
 
@@ -2022,7 +2022,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, PollForTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PollForTaskOutput, _>()
     }
 
@@ -2040,7 +2040,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, PutPipelineDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutPipelineDefinitionOutput, _>()
     }
 
@@ -2058,7 +2058,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, QueryObjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<QueryObjectsOutput, _>()
     }
 
@@ -2076,7 +2076,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, RemoveTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsOutput, _>()
     }
 
@@ -2094,7 +2094,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, ReportTaskProgressError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ReportTaskProgressOutput, _>()
     }
 
@@ -2112,7 +2112,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, ReportTaskRunnerHeartbeatError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ReportTaskRunnerHeartbeatOutput, _>()
     }
@@ -2145,7 +2145,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, SetTaskStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SetTaskStatusOutput, _>()
     }
 
@@ -2164,7 +2164,7 @@ impl DataPipeline for DataPipelineClient {
             .sign_and_dispatch(request, ValidatePipelineDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ValidatePipelineDefinitionOutput, _>()
     }
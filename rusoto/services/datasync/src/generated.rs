@@ -41,7 +41,7 @@ impl DataSyncClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2518,7 +2518,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CancelTaskExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelTaskExecutionResponse, _>()
     }
 
@@ -2536,7 +2536,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateAgentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAgentResponse, _>()
     }
 
@@ -2554,7 +2554,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateLocationEfsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLocationEfsResponse, _>()
     }
 
@@ -2572,7 +2572,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateLocationFsxWindowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateLocationFsxWindowsResponse, _>()
     }
@@ -2591,7 +2591,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateLocationNfsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLocationNfsResponse, _>()
     }
 
@@ -2609,7 +2609,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateLocationS3Error::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLocationS3Response, _>()
     }
 
@@ -2627,7 +2627,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateLocationSmbError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLocationSmbResponse, _>()
     }
 
@@ -2645,7 +2645,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, CreateTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTaskResponse, _>()
     }
 
@@ -2663,7 +2663,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DeleteAgentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAgentResponse, _>()
     }
 
@@ -2681,7 +2681,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DeleteLocationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLocationResponse, _>()
     }
 
@@ -2699,7 +2699,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DeleteTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTaskResponse, _>()
     }
 
@@ -2717,7 +2717,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeAgentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAgentResponse, _>()
     }
 
@@ -2735,7 +2735,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeLocationEfsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLocationEfsResponse, _>()
     }
 
@@ -2754,7 +2754,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeLocationFsxWindowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeLocationFsxWindowsResponse, _>()
     }
@@ -2773,7 +2773,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeLocationNfsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLocationNfsResponse, _>()
     }
 
@@ -2791,7 +2791,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeLocationS3Error::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLocationS3Response, _>()
     }
 
@@ -2809,7 +2809,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeLocationSmbError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLocationSmbResponse, _>()
     }
 
@@ -2827,7 +2827,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTaskResponse, _>()
     }
 
@@ -2845,7 +2845,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, DescribeTaskExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTaskExecutionResponse, _>()
     }
@@ -2864,7 +2864,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, ListAgentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAgentsResponse, _>()
     }
 
@@ -2882,7 +2882,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, ListLocationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLocationsResponse, _>()
     }
 
@@ -2900,7 +2900,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2918,7 +2918,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, ListTaskExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTaskExecutionsResponse, _>()
     }
 
@@ -2936,7 +2936,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, ListTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTasksResponse, _>()
     }
 
@@ -2954,7 +2954,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, StartTaskExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartTaskExecutionResponse, _>()
     }
 
@@ -2972,7 +2972,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2990,7 +2990,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -3008,7 +3008,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, UpdateAgentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAgentResponse, _>()
     }
 
@@ -3026,7 +3026,7 @@ impl DataSync for DataSyncClient {
             .sign_and_dispatch(request, UpdateTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskResponse, _>()
     }
 }
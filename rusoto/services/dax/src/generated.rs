@@ -41,7 +41,7 @@ impl DynamodbAcceleratorClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2641,7 +2641,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, CreateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
     }
 
@@ -2659,7 +2659,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, CreateParameterGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateParameterGroupResponse, _>()
     }
@@ -2678,7 +2678,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, CreateSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSubnetGroupResponse, _>()
     }
 
@@ -2697,7 +2697,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DecreaseReplicationFactorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DecreaseReplicationFactorResponse, _>()
     }
@@ -2716,7 +2716,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DeleteClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
     }
 
@@ -2734,7 +2734,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DeleteParameterGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteParameterGroupResponse, _>()
     }
@@ -2753,7 +2753,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DeleteSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSubnetGroupResponse, _>()
     }
 
@@ -2771,7 +2771,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeClustersResponse, _>()
     }
 
@@ -2790,7 +2790,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeDefaultParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDefaultParametersResponse, _>()
     }
@@ -2809,7 +2809,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
     }
 
@@ -2827,7 +2827,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeParameterGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeParameterGroupsResponse, _>()
     }
@@ -2846,7 +2846,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeParametersResponse, _>()
     }
 
@@ -2864,7 +2864,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, DescribeSubnetGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSubnetGroupsResponse, _>()
     }
@@ -2884,7 +2884,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, IncreaseReplicationFactorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<IncreaseReplicationFactorResponse, _>()
     }
@@ -2903,7 +2903,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
     }
 
@@ -2921,7 +2921,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, RebootNodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RebootNodeResponse, _>()
     }
 
@@ -2939,7 +2939,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2957,7 +2957,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2975,7 +2975,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, UpdateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateClusterResponse, _>()
     }
 
@@ -2993,7 +2993,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, UpdateParameterGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateParameterGroupResponse, _>()
     }
@@ -3012,7 +3012,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
             .sign_and_dispatch(request, UpdateSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubnetGroupResponse, _>()
     }
 }
@@ -918,6 +918,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("PUT", "detective", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -930,12 +931,12 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AcceptInvitationError::from_response(response))
         }
     }
@@ -946,6 +947,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -956,13 +958,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGraphResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGraphError::from_response(response))
         }
     }
@@ -976,6 +978,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -988,13 +991,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMembersError::from_response(response))
         }
     }
@@ -1008,6 +1011,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1020,12 +1024,12 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGraphError::from_response(response))
         }
     }
@@ -1039,6 +1043,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1051,13 +1056,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMembersError::from_response(response))
         }
     }
@@ -1071,6 +1076,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/membership/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DisassociateMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1083,12 +1089,12 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateMembershipError::from_response(response))
         }
     }
@@ -1102,6 +1108,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/get";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1114,13 +1121,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMembersError::from_response(response))
         }
     }
@@ -1134,6 +1141,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graphs/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListGraphs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1146,13 +1154,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGraphsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGraphsError::from_response(response))
         }
     }
@@ -1166,6 +1174,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitations/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1178,13 +1187,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInvitationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInvitationsError::from_response(response))
         }
     }
@@ -1198,6 +1207,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1210,13 +1220,13 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMembersError::from_response(response))
         }
     }
@@ -1230,6 +1240,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1242,12 +1253,12 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RejectInvitationError::from_response(response))
         }
     }
@@ -1261,6 +1272,7 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/member/monitoringstate";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1273,12 +1285,12 @@ impl Detective for DetectiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartMonitoringMemberError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl DeviceFarmClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7664,7 +7664,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateDevicePoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDevicePoolResult, _>()
     }
 
@@ -7682,7 +7682,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateInstanceProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateInstanceProfileResult, _>()
     }
 
@@ -7700,7 +7700,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateNetworkProfileResult, _>()
     }
 
@@ -7718,7 +7718,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResult, _>()
     }
 
@@ -7739,7 +7739,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateRemoteAccessSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRemoteAccessSessionResult, _>()
     }
@@ -7758,7 +7758,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateTestGridProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTestGridProjectResult, _>()
     }
 
@@ -7776,7 +7776,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateTestGridUrlError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTestGridUrlResult, _>()
     }
 
@@ -7794,7 +7794,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUploadResult, _>()
     }
 
@@ -7815,7 +7815,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, CreateVPCEConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateVPCEConfigurationResult, _>()
     }
@@ -7834,7 +7834,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteDevicePoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDevicePoolResult, _>()
     }
 
@@ -7852,7 +7852,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteInstanceProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteInstanceProfileResult, _>()
     }
 
@@ -7870,7 +7870,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteNetworkProfileResult, _>()
     }
 
@@ -7888,7 +7888,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectResult, _>()
     }
 
@@ -7909,7 +7909,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteRemoteAccessSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRemoteAccessSessionResult, _>()
     }
@@ -7928,7 +7928,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRunResult, _>()
     }
 
@@ -7946,7 +7946,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteTestGridProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTestGridProjectResult, _>()
     }
 
@@ -7964,7 +7964,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteUploadResult, _>()
     }
 
@@ -7985,7 +7985,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, DeleteVPCEConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteVPCEConfigurationResult, _>()
     }
@@ -8002,7 +8002,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetAccountSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAccountSettingsResult, _>()
     }
 
@@ -8020,7 +8020,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetDeviceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResult, _>()
     }
 
@@ -8038,7 +8038,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetDeviceInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceInstanceResult, _>()
     }
 
@@ -8056,7 +8056,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetDevicePoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDevicePoolResult, _>()
     }
 
@@ -8078,7 +8078,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetDevicePoolCompatibilityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDevicePoolCompatibilityResult, _>()
     }
@@ -8097,7 +8097,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetInstanceProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceProfileResult, _>()
     }
 
@@ -8115,7 +8115,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()
     }
 
@@ -8133,7 +8133,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetNetworkProfileResult, _>()
     }
 
@@ -8151,7 +8151,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetOfferingStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOfferingStatusResult, _>()
     }
 
@@ -8169,7 +8169,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetProjectResult, _>()
     }
 
@@ -8187,7 +8187,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetRemoteAccessSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRemoteAccessSessionResult, _>()
     }
@@ -8206,7 +8206,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRunResult, _>()
     }
 
@@ -8224,7 +8224,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetSuiteError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSuiteResult, _>()
     }
 
@@ -8242,7 +8242,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetTestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTestResult, _>()
     }
 
@@ -8260,7 +8260,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetTestGridProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTestGridProjectResult, _>()
     }
 
@@ -8278,7 +8278,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetTestGridSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTestGridSessionResult, _>()
     }
 
@@ -8296,7 +8296,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetUploadResult, _>()
     }
 
@@ -8314,7 +8314,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, GetVPCEConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetVPCEConfigurationResult, _>()
     }
 
@@ -8336,7 +8336,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, InstallToRemoteAccessSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<InstallToRemoteAccessSessionResult, _>()
     }
@@ -8355,7 +8355,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListArtifactsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListArtifactsResult, _>()
     }
 
@@ -8373,7 +8373,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListDeviceInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeviceInstancesResult, _>()
     }
 
@@ -8391,7 +8391,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListDevicePoolsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDevicePoolsResult, _>()
     }
 
@@ -8409,7 +8409,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListDevicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDevicesResult, _>()
     }
 
@@ -8427,7 +8427,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListInstanceProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInstanceProfilesResult, _>()
     }
 
@@ -8445,7 +8445,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
     }
 
@@ -8463,7 +8463,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListNetworkProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListNetworkProfilesResult, _>()
     }
 
@@ -8481,7 +8481,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListOfferingPromotionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListOfferingPromotionsResult, _>()
     }
@@ -8503,7 +8503,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListOfferingTransactionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListOfferingTransactionsResult, _>()
     }
@@ -8522,7 +8522,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListOfferingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListOfferingsResult, _>()
     }
 
@@ -8540,7 +8540,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsResult, _>()
     }
 
@@ -8561,7 +8561,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListRemoteAccessSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRemoteAccessSessionsResult, _>()
     }
@@ -8580,7 +8580,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRunsResult, _>()
     }
 
@@ -8598,7 +8598,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListSamplesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSamplesResult, _>()
     }
 
@@ -8616,7 +8616,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListSuitesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSuitesResult, _>()
     }
 
@@ -8634,7 +8634,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -8652,7 +8652,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTestGridProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTestGridProjectsResult, _>()
     }
 
@@ -8674,7 +8674,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTestGridSessionActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTestGridSessionActionsResult, _>()
     }
@@ -8697,7 +8697,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTestGridSessionArtifactsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTestGridSessionArtifactsResult, _>()
     }
@@ -8716,7 +8716,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTestGridSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTestGridSessionsResult, _>()
     }
 
@@ -8734,7 +8734,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListTestsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTestsResult, _>()
     }
 
@@ -8752,7 +8752,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListUniqueProblemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUniqueProblemsResult, _>()
     }
 
@@ -8770,7 +8770,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListUploadsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUploadsResult, _>()
     }
 
@@ -8788,7 +8788,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ListVPCEConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListVPCEConfigurationsResult, _>()
     }
@@ -8807,7 +8807,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, PurchaseOfferingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PurchaseOfferingResult, _>()
     }
 
@@ -8825,7 +8825,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, RenewOfferingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RenewOfferingResult, _>()
     }
 
@@ -8843,7 +8843,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, ScheduleRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ScheduleRunResult, _>()
     }
 
@@ -8861,7 +8861,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, StopJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()
     }
 
@@ -8882,7 +8882,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, StopRemoteAccessSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopRemoteAccessSessionResult, _>()
     }
@@ -8901,7 +8901,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, StopRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopRunResult, _>()
     }
 
@@ -8919,7 +8919,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -8937,7 +8937,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -8955,7 +8955,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateDeviceInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeviceInstanceResult, _>()
     }
 
@@ -8973,7 +8973,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateDevicePoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDevicePoolResult, _>()
     }
 
@@ -8991,7 +8991,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateInstanceProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateInstanceProfileResult, _>()
     }
 
@@ -9009,7 +9009,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateNetworkProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateNetworkProfileResult, _>()
     }
 
@@ -9027,7 +9027,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectResult, _>()
     }
 
@@ -9045,7 +9045,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateTestGridProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTestGridProjectResult, _>()
     }
 
@@ -9063,7 +9063,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUploadResult, _>()
     }
 
@@ -9084,7 +9084,7 @@ impl DeviceFarm for DeviceFarmClient {
             .sign_and_dispatch(request, UpdateVPCEConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateVPCEConfigurationResult, _>()
     }
@@ -42,7 +42,7 @@ impl DirectConnectClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5100,7 +5100,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AcceptDirectConnectGatewayAssociationProposalResult, _>()
     }
@@ -5125,7 +5125,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5143,7 +5143,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AllocateHostedConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5164,7 +5164,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AllocatePrivateVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 
@@ -5185,7 +5185,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AllocatePublicVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 
@@ -5209,7 +5209,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AllocateTransitVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AllocateTransitVirtualInterfaceResult, _>()
     }
@@ -5228,7 +5228,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AssociateConnectionWithLagError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5246,7 +5246,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AssociateHostedConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5264,7 +5264,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, AssociateVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 
@@ -5282,7 +5282,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, ConfirmConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ConfirmConnectionResponse, _>()
     }
 
@@ -5306,7 +5306,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, ConfirmPrivateVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ConfirmPrivateVirtualInterfaceResponse, _>()
     }
@@ -5331,7 +5331,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, ConfirmPublicVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ConfirmPublicVirtualInterfaceResponse, _>()
     }
@@ -5356,7 +5356,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, ConfirmTransitVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ConfirmTransitVirtualInterfaceResponse, _>()
     }
@@ -5375,7 +5375,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateBGPPeerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBGPPeerResponse, _>()
     }
 
@@ -5393,7 +5393,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5412,7 +5412,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateDirectConnectGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDirectConnectGatewayResult, _>()
     }
@@ -5440,7 +5440,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDirectConnectGatewayAssociationResult, _>()
     }
@@ -5468,7 +5468,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDirectConnectGatewayAssociationProposalResult, _>()
     }
@@ -5487,7 +5487,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateInterconnectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Interconnect, _>()
     }
 
@@ -5505,7 +5505,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateLagError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
     }
 
@@ -5526,7 +5526,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreatePrivateVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 
@@ -5547,7 +5547,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreatePublicVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 
@@ -5569,7 +5569,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, CreateTransitVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateTransitVirtualInterfaceResult, _>()
     }
@@ -5588,7 +5588,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteBGPPeerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBGPPeerResponse, _>()
     }
 
@@ -5606,7 +5606,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -5625,7 +5625,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteDirectConnectGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteDirectConnectGatewayResult, _>()
     }
@@ -5653,7 +5653,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteDirectConnectGatewayAssociationResult, _>()
     }
@@ -5681,7 +5681,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteDirectConnectGatewayAssociationProposalResult, _>()
     }
@@ -5700,7 +5700,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteInterconnectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteInterconnectResponse, _>()
     }
 
@@ -5718,7 +5718,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteLagError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
     }
 
@@ -5736,7 +5736,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DeleteVirtualInterfaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteVirtualInterfaceResponse, _>()
     }
@@ -5755,7 +5755,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeConnectionLoaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConnectionLoaResponse, _>()
     }
@@ -5774,7 +5774,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
     }
 
@@ -5798,7 +5798,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
     }
 
@@ -5825,7 +5825,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDirectConnectGatewayAssociationProposalsResult, _>()
     }
@@ -5853,7 +5853,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDirectConnectGatewayAssociationsResult, _>()
     }
@@ -5881,7 +5881,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDirectConnectGatewayAttachmentsResult, _>()
     }
@@ -5904,7 +5904,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeDirectConnectGatewaysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDirectConnectGatewaysResult, _>()
     }
@@ -5923,7 +5923,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeHostedConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
     }
 
@@ -5941,7 +5941,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeInterconnectLoaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInterconnectLoaResponse, _>()
     }
@@ -5960,7 +5960,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeInterconnectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Interconnects, _>()
     }
 
@@ -5978,7 +5978,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeLagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Lags, _>()
     }
 
@@ -5996,7 +5996,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeLoaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Loa, _>()
     }
 
@@ -6010,7 +6010,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeLocationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Locations, _>()
     }
 
@@ -6028,7 +6028,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsResponse, _>()
     }
 
@@ -6044,7 +6044,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeVirtualGatewaysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualGateways, _>()
     }
 
@@ -6062,7 +6062,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DescribeVirtualInterfacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterfaces, _>()
     }
 
@@ -6083,7 +6083,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, DisassociateConnectionFromLagError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
     }
 
@@ -6107,7 +6107,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, ListVirtualInterfaceTestHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListVirtualInterfaceTestHistoryResponse, _>()
     }
@@ -6126,7 +6126,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, StartBgpFailoverTestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartBgpFailoverTestResponse, _>()
     }
@@ -6145,7 +6145,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, StopBgpFailoverTestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopBgpFailoverTestResponse, _>()
     }
 
@@ -6163,7 +6163,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -6181,7 +6181,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -6208,7 +6208,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDirectConnectGatewayAssociationResult, _>()
     }
@@ -6227,7 +6227,7 @@ impl DirectConnect for DirectConnectClient {
             .sign_and_dispatch(request, UpdateLagError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
     }
 
@@ -6251,7 +6251,7 @@ impl DirectConnect for DirectConnectClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 }
@@ -41,7 +41,7 @@ impl DiscoveryClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2997,7 +2997,7 @@ impl Discovery for DiscoveryClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateConfigurationItemsToApplicationResponse, _>()
     }
@@ -3019,7 +3019,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, BatchDeleteImportDataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDeleteImportDataResponse, _>()
     }
@@ -3041,7 +3041,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, CreateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationResponse, _>()
     }
 
@@ -3059,7 +3059,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, CreateTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTagsResponse, _>()
     }
 
@@ -3080,7 +3080,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DeleteApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteApplicationsResponse, _>()
     }
 
@@ -3098,7 +3098,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DeleteTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsResponse, _>()
     }
 
@@ -3119,7 +3119,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeAgentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAgentsResponse, _>()
     }
 
@@ -3140,7 +3140,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConfigurationsResponse, _>()
     }
@@ -3163,7 +3163,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeContinuousExportsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeContinuousExportsResponse, _>()
     }
@@ -3186,7 +3186,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeExportConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeExportConfigurationsResponse, _>()
     }
@@ -3208,7 +3208,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeExportTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeExportTasksResponse, _>()
     }
 
@@ -3229,7 +3229,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeImportTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeImportTasksResponse, _>()
     }
 
@@ -3250,7 +3250,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, DescribeTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsResponse, _>()
     }
 
@@ -3277,7 +3277,7 @@ impl Discovery for DiscoveryClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateConfigurationItemsFromApplicationResponse, _>()
     }
@@ -3297,7 +3297,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, ExportConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExportConfigurationsResponse, _>()
     }
@@ -3317,7 +3317,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, GetDiscoverySummaryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDiscoverySummaryResponse, _>()
     }
 
@@ -3338,7 +3338,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, ListConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListConfigurationsResponse, _>()
     }
 
@@ -3359,7 +3359,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, ListServerNeighborsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServerNeighborsResponse, _>()
     }
 
@@ -3378,7 +3378,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StartContinuousExportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartContinuousExportResponse, _>()
     }
@@ -3403,7 +3403,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StartDataCollectionByAgentIdsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDataCollectionByAgentIdsResponse, _>()
     }
@@ -3425,7 +3425,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StartExportTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartExportTaskResponse, _>()
     }
 
@@ -3446,7 +3446,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StartImportTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartImportTaskResponse, _>()
     }
 
@@ -3467,7 +3467,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StopContinuousExportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopContinuousExportResponse, _>()
     }
@@ -3490,7 +3490,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, StopDataCollectionByAgentIdsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopDataCollectionByAgentIdsResponse, _>()
     }
@@ -3512,7 +3512,7 @@ impl Discovery for DiscoveryClient {
             .sign_and_dispatch(request, UpdateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
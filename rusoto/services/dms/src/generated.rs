@@ -41,7 +41,7 @@ impl DatabaseMigrationServiceClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5545,7 +5545,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, AddTagsToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResponse, _>()
     }
 
@@ -5569,7 +5569,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ApplyPendingMaintenanceActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ApplyPendingMaintenanceActionResponse, _>()
     }
@@ -5588,7 +5588,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, CreateEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointResponse, _>()
     }
 
@@ -5606,7 +5606,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, CreateEventSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateEventSubscriptionResponse, _>()
     }
@@ -5629,7 +5629,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, CreateReplicationInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateReplicationInstanceResponse, _>()
     }
@@ -5652,7 +5652,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, CreateReplicationSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateReplicationSubnetGroupResponse, _>()
     }
@@ -5671,7 +5671,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, CreateReplicationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateReplicationTaskResponse, _>()
     }
@@ -5690,7 +5690,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteCertificateResponse, _>()
     }
 
@@ -5708,7 +5708,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteConnectionResponse, _>()
     }
 
@@ -5726,7 +5726,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteEndpointResponse, _>()
     }
 
@@ -5744,7 +5744,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteEventSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteEventSubscriptionResponse, _>()
     }
@@ -5767,7 +5767,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteReplicationInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteReplicationInstanceResponse, _>()
     }
@@ -5790,7 +5790,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteReplicationSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteReplicationSubnetGroupResponse, _>()
     }
@@ -5809,7 +5809,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DeleteReplicationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteReplicationTaskResponse, _>()
     }
@@ -5830,7 +5830,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeAccountAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAccountAttributesResponse, _>()
     }
@@ -5849,7 +5849,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeCertificatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCertificatesResponse, _>()
     }
@@ -5868,7 +5868,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeConnectionsResponse, _>()
     }
 
@@ -5886,7 +5886,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeEndpointTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEndpointTypesResponse, _>()
     }
@@ -5905,7 +5905,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEndpointsResponse, _>()
     }
 
@@ -5923,7 +5923,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeEventCategoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventCategoriesResponse, _>()
     }
@@ -5946,7 +5946,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeEventSubscriptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventSubscriptionsResponse, _>()
     }
@@ -5965,7 +5965,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
     }
 
@@ -5992,7 +5992,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrderableReplicationInstancesResponse, _>()
     }
@@ -6020,7 +6020,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePendingMaintenanceActionsResponse, _>()
     }
@@ -6043,7 +6043,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeRefreshSchemasStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRefreshSchemasStatusResponse, _>()
     }
@@ -6071,7 +6071,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReplicationInstanceTaskLogsResponse, _>()
     }
@@ -6094,7 +6094,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeReplicationInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReplicationInstancesResponse, _>()
     }
@@ -6119,7 +6119,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeReplicationSubnetGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReplicationSubnetGroupsResponse, _>()
     }
@@ -6147,7 +6147,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReplicationTaskAssessmentResultsResponse, _>()
     }
@@ -6169,7 +6169,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeReplicationTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReplicationTasksResponse, _>()
     }
@@ -6188,7 +6188,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeSchemasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSchemasResponse, _>()
     }
 
@@ -6206,7 +6206,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, DescribeTableStatisticsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTableStatisticsResponse, _>()
     }
@@ -6225,7 +6225,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ImportCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportCertificateResponse, _>()
     }
 
@@ -6243,7 +6243,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -6261,7 +6261,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ModifyEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyEndpointResponse, _>()
     }
 
@@ -6279,7 +6279,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ModifyEventSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyEventSubscriptionResponse, _>()
     }
@@ -6302,7 +6302,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ModifyReplicationInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyReplicationInstanceResponse, _>()
     }
@@ -6325,7 +6325,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ModifyReplicationSubnetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyReplicationSubnetGroupResponse, _>()
     }
@@ -6344,7 +6344,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ModifyReplicationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyReplicationTaskResponse, _>()
     }
@@ -6367,7 +6367,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, RebootReplicationInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RebootReplicationInstanceResponse, _>()
     }
@@ -6386,7 +6386,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, RefreshSchemasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RefreshSchemasResponse, _>()
     }
 
@@ -6404,7 +6404,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, ReloadTablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ReloadTablesResponse, _>()
     }
 
@@ -6422,7 +6422,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, RemoveTagsFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveTagsFromResourceResponse, _>()
     }
@@ -6441,7 +6441,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, StartReplicationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartReplicationTaskResponse, _>()
     }
@@ -6466,7 +6466,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, StartReplicationTaskAssessmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartReplicationTaskAssessmentResponse, _>()
     }
@@ -6485,7 +6485,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, StopReplicationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopReplicationTaskResponse, _>()
     }
 
@@ -6503,7 +6503,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
             .sign_and_dispatch(request, TestConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TestConnectionResponse, _>()
     }
 }
@@ -52,7 +52,7 @@ impl DocdbClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5823,11 +5823,11 @@ impl VpcSecurityGroupMembershipListDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl AddTagsToResourceError {
@@ -5840,17 +5840,23 @@ impl AddTagsToResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5867,6 +5873,15 @@ impl AddTagsToResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsToResourceError {
     #[allow(unused_variables)]
@@ -5883,11 +5898,11 @@ impl Error for AddTagsToResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -5903,21 +5918,21 @@ impl ApplyPendingMaintenanceActionError {
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5935,6 +5950,21 @@ impl ApplyPendingMaintenanceActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ApplyPendingMaintenanceActionError {
     #[allow(unused_variables)]
@@ -5957,11 +5987,11 @@ impl Error for ApplyPendingMaintenanceActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
     /// <p>A parameter group with the same name already exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>This request would cause you to exceed the allowed number of parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterParameterGroupError {
@@ -5977,21 +6007,21 @@ impl CopyDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6009,6 +6039,21 @@ impl CopyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -6031,17 +6076,17 @@ impl Error for CopyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
     /// <p>You already have a cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>An error occurred when accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterSnapshotError {
@@ -6055,42 +6100,42 @@ impl CopyDBClusterSnapshotError {
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6108,6 +6153,24 @@ impl CopyDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -6139,35 +6202,35 @@ impl Error for CopyDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
     /// <p>You already have a cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster can't be created because you have reached the maximum allowed quota of clusters.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>There is not enough storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available. </p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The subnet group can't be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred when accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterError {
@@ -6180,85 +6243,101 @@ impl CreateDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBClusterError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -6275,6 +6354,33 @@ impl CreateDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterError {
     #[allow(unused_variables)]
@@ -6311,9 +6417,9 @@ impl Error for CreateDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
     /// <p>A parameter group with the same name already exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>This request would cause you to exceed the allowed number of parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterParameterGroupError {
@@ -6329,14 +6435,14 @@ impl CreateDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6354,6 +6460,18 @@ impl CreateDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -6373,15 +6491,15 @@ impl Error for CreateDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>You already have a cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterSnapshotError {
@@ -6395,35 +6513,35 @@ impl CreateDBClusterSnapshotError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6441,6 +6559,25 @@ impl CreateDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -6469,35 +6606,35 @@ impl Error for CreateDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
     /// <p>The specified CIDR IP or Amazon EC2 security group isn't authorized for the specified security group.</p> <p>Amazon DocumentDB also might not be authorized to perform necessary actions on your behalf using IAM.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>You already have a instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred when accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the specified <code>StorageType</code> can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl CreateDBInstanceError {
@@ -6510,90 +6647,102 @@ impl CreateDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::InstanceQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6611,6 +6760,31 @@ impl CreateDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InstanceQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBInstanceError {
     #[allow(unused_variables)]
@@ -6651,15 +6825,15 @@ impl Error for CreateDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> is already being used by an existing subnet group. </p>
-    DBSubnetGroupAlreadyExistsFault(String),
+    DBSubnetGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>Subnets in the subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of subnet groups.</p>
-    DBSubnetGroupQuotaExceededFault(String),
+    DBSubnetGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of subnets in a subnet group.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
 }
 
 impl CreateDBSubnetGroupError {
@@ -6673,34 +6847,34 @@ impl CreateDBSubnetGroupError {
                     "DBSubnetGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6717,6 +6891,23 @@ impl CreateDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -6743,15 +6934,15 @@ impl Error for CreateDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>You already have a cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterError {
@@ -6764,31 +6955,35 @@ impl DeleteDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBClusterError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -6805,6 +7000,21 @@ impl DeleteDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterError {
     #[allow(unused_variables)]
@@ -6827,9 +7037,9 @@ impl Error for DeleteDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterParameterGroupError {
@@ -6845,14 +7055,14 @@ impl DeleteDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6870,6 +7080,18 @@ impl DeleteDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -6889,9 +7111,9 @@ impl Error for DeleteDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterSnapshotError {
@@ -6905,14 +7127,14 @@ impl DeleteDBClusterSnapshotError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -6930,6 +7152,18 @@ impl DeleteDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -6949,15 +7183,15 @@ impl Error for DeleteDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> is already being used by an existing snapshot. </p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBInstanceError {
@@ -6970,31 +7204,37 @@ impl DeleteDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            DeleteDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7011,6 +7251,17 @@ impl DeleteDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBInstanceError {
     #[allow(unused_variables)]
@@ -7031,11 +7282,11 @@ impl Error for DeleteDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The subnet group can't be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p> The subnet isn't in the <i>available</i> state. </p>
-    InvalidDBSubnetStateFault(String),
+    InvalidDBSubnetStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBSubnetGroupError {
@@ -7049,21 +7300,21 @@ impl DeleteDBSubnetGroupError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7081,6 +7332,17 @@ impl DeleteDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -7103,7 +7365,7 @@ impl Error for DeleteDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificatesError {
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeCertificatesError {
@@ -7117,7 +7379,7 @@ impl DescribeCertificatesError {
                     "CertificateNotFound" => {
                         return RusotoError::Service(
                             DescribeCertificatesError::CertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7135,6 +7397,13 @@ impl DescribeCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCertificatesError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeCertificatesError {
     #[allow(unused_variables)]
@@ -7151,7 +7420,7 @@ impl Error for DescribeCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParameterGroupsError {
@@ -7167,7 +7436,7 @@ impl DescribeDBClusterParameterGroupsError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7185,6 +7454,15 @@ impl DescribeDBClusterParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParameterGroupsError {
     #[allow(unused_variables)]
@@ -7201,7 +7479,7 @@ impl Error for DescribeDBClusterParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParametersError {
@@ -7217,7 +7495,7 @@ impl DescribeDBClusterParametersError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7235,6 +7513,15 @@ impl DescribeDBClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParametersError {
     #[allow(unused_variables)]
@@ -7251,7 +7538,7 @@ impl Error for DescribeDBClusterParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotAttributesError {
@@ -7266,7 +7553,7 @@ impl DescribeDBClusterSnapshotAttributesError {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -7283,6 +7570,15 @@ impl DescribeDBClusterSnapshotAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     #[allow(unused_variables)]
@@ -7299,7 +7595,7 @@ impl Error for DescribeDBClusterSnapshotAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotsError {
@@ -7315,7 +7611,7 @@ impl DescribeDBClusterSnapshotsError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7333,6 +7629,15 @@ impl DescribeDBClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -7349,7 +7654,7 @@ impl Error for DescribeDBClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClustersError {
@@ -7362,7 +7667,9 @@ impl DescribeDBClustersError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                            DescribeDBClustersError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7379,6 +7686,13 @@ impl DescribeDBClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBClustersError {
     #[allow(unused_variables)]
@@ -7415,6 +7729,11 @@ impl DescribeDBEngineVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeDBEngineVersionsError {
     #[allow(unused_variables)]
@@ -7427,7 +7746,7 @@ impl Error for DescribeDBEngineVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBInstancesError {
@@ -7440,7 +7759,9 @@ impl DescribeDBInstancesError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                            DescribeDBInstancesError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7457,6 +7778,13 @@ impl DescribeDBInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBInstancesError {
     #[allow(unused_variables)]
@@ -7471,7 +7799,7 @@ impl Error for DescribeDBInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSubnetGroupsError {
@@ -7485,7 +7813,7 @@ impl DescribeDBSubnetGroupsError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7503,6 +7831,15 @@ impl DescribeDBSubnetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBSubnetGroupsError {
     #[allow(unused_variables)]
@@ -7543,6 +7880,11 @@ impl DescribeEngineDefaultClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     #[allow(unused_variables)]
@@ -7577,6 +7919,11 @@ impl DescribeEventCategoriesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
@@ -7611,6 +7958,11 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -7647,6 +7999,11 @@ impl DescribeOrderableDBInstanceOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     #[allow(unused_variables)]
@@ -7659,7 +8016,7 @@ impl Error for DescribeOrderableDBInstanceOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -7675,7 +8032,7 @@ impl DescribePendingMaintenanceActionsError {
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7693,6 +8050,15 @@ impl DescribePendingMaintenanceActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribePendingMaintenanceActionsError {
     #[allow(unused_variables)]
@@ -7709,11 +8075,11 @@ impl Error for DescribePendingMaintenanceActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl FailoverDBClusterError {
@@ -7726,20 +8092,22 @@ impl FailoverDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            FailoverDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7757,6 +8125,15 @@ impl FailoverDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for FailoverDBClusterError {
     #[allow(unused_variables)]
@@ -7775,11 +8152,11 @@ impl Error for FailoverDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -7792,17 +8169,23 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7819,6 +8202,15 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -7835,27 +8227,27 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
     /// <p>You already have a cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The subnet group can't be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterError {
@@ -7868,63 +8260,75 @@ impl ModifyDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7941,6 +8345,25 @@ impl ModifyDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterError {
     #[allow(unused_variables)]
@@ -7971,9 +8394,9 @@ impl Error for ModifyDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterParameterGroupError {
@@ -7989,14 +8412,14 @@ impl ModifyDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8014,6 +8437,18 @@ impl ModifyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -8033,11 +8468,11 @@ impl Error for ModifyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with. </p>
-    SharedSnapshotQuotaExceededFault(String),
+    SharedSnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterSnapshotAttributeError {
@@ -8053,19 +8488,19 @@ impl ModifyDBClusterSnapshotAttributeError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8083,6 +8518,21 @@ impl ModifyDBClusterSnapshotAttributeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -8105,31 +8555,31 @@ impl Error for ModifyDBClusterSnapshotAttributeError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
     /// <p>The specified CIDR IP or Amazon EC2 security group isn't authorized for the specified security group.</p> <p>Amazon DocumentDB also might not be authorized to perform necessary actions on your behalf using IAM.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
     /// <p>You already have a instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The upgrade failed because a resource that the depends on can't be modified.</p>
-    DBUpgradeDependencyFailureFault(String),
+    DBUpgradeDependencyFailureFault(XmlErrorMessage),
     /// <p>The specified instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the specified <code>StorageType</code> can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl ModifyDBInstanceError {
@@ -8142,84 +8592,92 @@ impl ModifyDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::CertificateNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8237,6 +8695,29 @@ impl ModifyDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBInstanceError {
     #[allow(unused_variables)]
@@ -8277,15 +8758,15 @@ impl Error for ModifyDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
     /// <p>Subnets in the subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed number of subnets in a subnet group.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet is already in use in the Availability Zone.</p>
-    SubnetAlreadyInUse(String),
+    SubnetAlreadyInUse(XmlErrorMessage),
 }
 
 impl ModifyDBSubnetGroupError {
@@ -8299,32 +8780,32 @@ impl ModifyDBSubnetGroupError {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetAlreadyInUse" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8341,6 +8822,19 @@ impl ModifyDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -8365,9 +8859,9 @@ impl Error for ModifyDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl RebootDBInstanceError {
@@ -8380,13 +8874,15 @@ impl RebootDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            RebootDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8404,6 +8900,14 @@ impl RebootDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RebootDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebootDBInstanceError {
     #[allow(unused_variables)]
@@ -8419,11 +8923,11 @@ impl Error for RebootDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveTagsFromResourceError {
@@ -8437,21 +8941,21 @@ impl RemoveTagsFromResourceError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8469,6 +8973,15 @@ impl RemoveTagsFromResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsFromResourceError {
     #[allow(unused_variables)]
@@ -8491,9 +9004,9 @@ impl Error for RemoveTagsFromResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetDBClusterParameterGroupError {
@@ -8509,14 +9022,14 @@ impl ResetDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8534,6 +9047,18 @@ impl ResetDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -8553,33 +9078,33 @@ impl Error for ResetDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
     /// <p>You already have a cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p>The cluster can't be created because you have reached the maximum allowed quota of clusters.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster doesn't have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is not enough storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available. </p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The state of the snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>You cannot restore from a virtual private cloud (VPC) backup to a non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred when accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterFromSnapshotError {
@@ -8595,94 +9120,96 @@ impl RestoreDBClusterFromSnapshotError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8700,6 +9227,50 @@ impl RestoreDBClusterFromSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterFromSnapshotError {
     #[allow(unused_variables)]
@@ -8753,35 +9324,35 @@ impl Error for RestoreDBClusterFromSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
     /// <p>You already have a cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster can't be created because you have reached the maximum allowed quota of clusters.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster doesn't have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is not enough storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available. </p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The provided value isn't a valid cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The state of the snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>You cannot restore from a virtual private cloud (VPC) backup to a non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The subnet group doesn't cover all Availability Zones after it is created because of changes that were made.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred when accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterToPointInTimeError {
@@ -8797,101 +9368,103 @@ impl RestoreDBClusterToPointInTimeError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8909,6 +9482,55 @@ impl RestoreDBClusterToPointInTimeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterToPointInTimeError {
     #[allow(unused_variables)]
@@ -8965,11 +9587,11 @@ impl Error for RestoreDBClusterToPointInTimeError {}
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StartDBClusterError {
@@ -8982,17 +9604,21 @@ impl StartDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9009,6 +9635,15 @@ impl StartDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartDBClusterError {
     #[allow(unused_variables)]
@@ -9025,11 +9660,11 @@ impl Error for StartDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster isn't in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p> The specified instance isn't in the <i>available</i> state. </p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StopDBClusterError {
@@ -9042,17 +9677,21 @@ impl StopDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9069,6 +9708,15 @@ impl StopDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopDBClusterError {
     #[allow(unused_variables)]
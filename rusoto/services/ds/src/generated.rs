@@ -41,7 +41,7 @@ impl DirectoryServiceClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5961,7 +5961,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, AcceptSharedDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AcceptSharedDirectoryResult, _>()
     }
 
@@ -5979,7 +5979,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, AddIpRoutesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddIpRoutesResult, _>()
     }
 
@@ -6000,7 +6000,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, AddTagsToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResult, _>()
     }
 
@@ -6021,7 +6021,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CancelSchemaExtensionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelSchemaExtensionResult, _>()
     }
 
@@ -6039,7 +6039,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ConnectDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ConnectDirectoryResult, _>()
     }
 
@@ -6057,7 +6057,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAliasResult, _>()
     }
 
@@ -6075,7 +6075,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateComputerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateComputerResult, _>()
     }
 
@@ -6097,7 +6097,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateConditionalForwarderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateConditionalForwarderResult, _>()
     }
@@ -6116,7 +6116,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDirectoryResult, _>()
     }
 
@@ -6137,7 +6137,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateLogSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLogSubscriptionResult, _>()
     }
 
@@ -6158,7 +6158,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateMicrosoftADError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateMicrosoftADResult, _>()
     }
 
@@ -6176,7 +6176,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSnapshotResult, _>()
     }
 
@@ -6194,7 +6194,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, CreateTrustError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTrustResult, _>()
     }
 
@@ -6216,7 +6216,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeleteConditionalForwarderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteConditionalForwarderResult, _>()
     }
@@ -6235,7 +6235,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeleteDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDirectoryResult, _>()
     }
 
@@ -6256,7 +6256,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeleteLogSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLogSubscriptionResult, _>()
     }
 
@@ -6274,7 +6274,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeleteSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSnapshotResult, _>()
     }
 
@@ -6292,7 +6292,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeleteTrustError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrustResult, _>()
     }
 
@@ -6313,7 +6313,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeregisterCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeregisterCertificateResult, _>()
     }
 
@@ -6334,7 +6334,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DeregisterEventTopicError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeregisterEventTopicResult, _>()
     }
 
@@ -6355,7 +6355,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCertificateResult, _>()
     }
 
@@ -6377,7 +6377,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeConditionalForwardersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeConditionalForwardersResult, _>()
     }
@@ -6399,7 +6399,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeDirectoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDirectoriesResult, _>()
     }
 
@@ -6420,7 +6420,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeDomainControllersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDomainControllersResult, _>()
     }
@@ -6442,7 +6442,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeEventTopicsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventTopicsResult, _>()
     }
 
@@ -6463,7 +6463,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeLDAPSSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLDAPSSettingsResult, _>()
     }
 
@@ -6484,7 +6484,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeSharedDirectoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSharedDirectoriesResult, _>()
     }
@@ -6506,7 +6506,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSnapshotsResult, _>()
     }
 
@@ -6524,7 +6524,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DescribeTrustsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrustsResult, _>()
     }
 
@@ -6542,7 +6542,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DisableLDAPSError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableLDAPSResult, _>()
     }
 
@@ -6560,7 +6560,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DisableRadiusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableRadiusResult, _>()
     }
 
@@ -6578,7 +6578,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, DisableSsoError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableSsoResult, _>()
     }
 
@@ -6596,7 +6596,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, EnableLDAPSError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableLDAPSResult, _>()
     }
 
@@ -6614,7 +6614,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, EnableRadiusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableRadiusResult, _>()
     }
 
@@ -6632,7 +6632,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, EnableSsoError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableSsoResult, _>()
     }
 
@@ -6651,7 +6651,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, GetDirectoryLimitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDirectoryLimitsResult, _>()
     }
 
@@ -6672,7 +6672,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, GetSnapshotLimitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSnapshotLimitsResult, _>()
     }
 
@@ -6690,7 +6690,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ListCertificatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCertificatesResult, _>()
     }
 
@@ -6708,7 +6708,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ListIpRoutesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIpRoutesResult, _>()
     }
 
@@ -6729,7 +6729,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ListLogSubscriptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLogSubscriptionsResult, _>()
     }
 
@@ -6750,7 +6750,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ListSchemaExtensionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSchemaExtensionsResult, _>()
     }
 
@@ -6771,7 +6771,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResult, _>()
     }
 
@@ -6792,7 +6792,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RegisterCertificateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterCertificateResult, _>()
     }
 
@@ -6813,7 +6813,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RegisterEventTopicError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterEventTopicResult, _>()
     }
 
@@ -6834,7 +6834,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RejectSharedDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RejectSharedDirectoryResult, _>()
     }
 
@@ -6852,7 +6852,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RemoveIpRoutesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RemoveIpRoutesResult, _>()
     }
 
@@ -6873,7 +6873,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RemoveTagsFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveTagsFromResourceResult, _>()
     }
@@ -6895,7 +6895,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ResetUserPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResetUserPasswordResult, _>()
     }
 
@@ -6916,7 +6916,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, RestoreFromSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RestoreFromSnapshotResult, _>()
     }
 
@@ -6934,7 +6934,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, ShareDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ShareDirectoryResult, _>()
     }
 
@@ -6955,7 +6955,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, StartSchemaExtensionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartSchemaExtensionResult, _>()
     }
 
@@ -6973,7 +6973,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, UnshareDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UnshareDirectoryResult, _>()
     }
 
@@ -6995,7 +6995,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, UpdateConditionalForwarderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateConditionalForwarderResult, _>()
     }
@@ -7020,7 +7020,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, UpdateNumberOfDomainControllersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateNumberOfDomainControllersResult, _>()
     }
@@ -7039,7 +7039,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, UpdateRadiusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRadiusResult, _>()
     }
 
@@ -7057,7 +7057,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, UpdateTrustError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrustResult, _>()
     }
 
@@ -7075,7 +7075,7 @@ impl DirectoryService for DirectoryServiceClient {
             .sign_and_dispatch(request, VerifyTrustError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyTrustResult, _>()
     }
 }
@@ -0,0 +1,448 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::mem;
+
+use rusoto_core::retry::RetryPolicy;
+use rusoto_core::RusotoError;
+use tokio::time;
+
+use super::paginate::backoff_when_throttled;
+use crate::generated::{
+    AttributeValue, BatchGetItemError, BatchGetItemInput, BatchWriteItemError, BatchWriteItemInput,
+    DynamoDb, DynamoDbClient, KeysAndAttributes, WriteRequest,
+};
+
+/// The most requests `batch_write_item` accepts at once.
+const MAX_BATCH_WRITE_ITEMS: usize = 25;
+/// The most keys `batch_get_item` accepts at once.
+const MAX_BATCH_GET_KEYS: usize = 100;
+
+/// An item, or the key of one.
+type Item = HashMap<String, AttributeValue>;
+
+/// What's left after `batch_write_item_with_retries` wrote all the items it could.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchWriteOutcome {
+    /// The requests, by table name, that were still unprocessed after the last attempt.
+    pub unprocessed_items: HashMap<String, Vec<WriteRequest>>,
+}
+
+impl BatchWriteOutcome {
+    /// Whether all requests were processed.
+    pub fn is_complete(&self) -> bool {
+        self.unprocessed_items.is_empty()
+    }
+}
+
+/// The items `batch_get_item_with_retries` read, and the keys it couldn't.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchGetOutcome {
+    /// The items read, by table name, in no particular order.
+    pub responses: HashMap<String, Vec<Item>>,
+    /// The keys, by table name, that were still unprocessed after the last attempt.
+    pub unprocessed_keys: HashMap<String, KeysAndAttributes>,
+}
+
+impl BatchGetOutcome {
+    /// Whether all keys were read.
+    pub fn is_complete(&self) -> bool {
+        self.unprocessed_keys.is_empty()
+    }
+}
+
+impl DynamoDbClient {
+    /// Writes any number of items with `batch_write_item`, in batches of 25 requests.
+    ///
+    /// The unprocessed items of every batch are sent again after the delays of `policy`,
+    /// until they're processed or the batch was sent `policy.max_attempts()` times. Batches
+    /// that were throttled are retried the same way. Requests still unprocessed are returned
+    /// in the outcome, while an error ends the call, leaving the batches after the failed
+    /// one unwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::retry::RetryPolicy;
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{BatchWriteItemInput, DynamoDbClient, WriteRequest};
+    /// # use std::collections::HashMap;
+    ///
+    /// # async fn example(requests: Vec<WriteRequest>) {
+    /// let dynamodb = DynamoDbClient::new(Region::UsEast1);
+    /// let mut request_items = HashMap::new();
+    /// request_items.insert("my-table".to_owned(), requests);
+    /// let input = BatchWriteItemInput {
+    ///     request_items,
+    ///     ..Default::default()
+    /// };
+    /// let outcome = dynamodb
+    ///     .batch_write_item_with_retries(input, RetryPolicy::new())
+    ///     .await
+    ///     .unwrap();
+    /// assert!(outcome.is_complete(), "{:?}", outcome.unprocessed_items);
+    /// # }
+    /// ```
+    pub async fn batch_write_item_with_retries(
+        &self,
+        mut input: BatchWriteItemInput,
+        policy: RetryPolicy,
+    ) -> Result<BatchWriteOutcome, RusotoError<BatchWriteItemError>> {
+        let request_items = mem::take(&mut input.request_items);
+        let mut outcome = BatchWriteOutcome::default();
+        for batch in chunks(request_items, MAX_BATCH_WRITE_ITEMS) {
+            let mut pending = batch;
+            let mut attempt = 1;
+            loop {
+                let batch_input = BatchWriteItemInput {
+                    request_items: pending,
+                    ..input.clone()
+                };
+                let output = backoff_when_throttled(policy.clone(), batch_input, |input| {
+                    self.batch_write_item(input)
+                })
+                .await?;
+                pending = without_empty(output.unprocessed_items.unwrap_or_default());
+                if pending.is_empty() {
+                    break;
+                }
+                if attempt >= policy.max_attempts() {
+                    merge(&mut outcome.unprocessed_items, pending);
+                    break;
+                }
+                time::delay_for(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Reads any number of items with `batch_get_item`, in batches of 100 keys, retrying
+    /// unprocessed keys like `batch_write_item_with_retries`.
+    ///
+    /// The attributes, projection and consistency of each table's `KeysAndAttributes` apply
+    /// to all its batches.
+    pub async fn batch_get_item_with_retries(
+        &self,
+        mut input: BatchGetItemInput,
+        policy: RetryPolicy,
+    ) -> Result<BatchGetOutcome, RusotoError<BatchGetItemError>> {
+        let mut templates = HashMap::new();
+        let mut keys = HashMap::new();
+        for (table, mut keys_and_attributes) in mem::take(&mut input.request_items) {
+            keys.insert(table.clone(), mem::take(&mut keys_and_attributes.keys));
+            templates.insert(table, keys_and_attributes);
+        }
+
+        let with_template = |batch: HashMap<String, Vec<Item>>| -> HashMap<_, _> {
+            batch
+                .into_iter()
+                .map(|(table, keys)| {
+                    let keys_and_attributes = KeysAndAttributes {
+                        keys,
+                        ..templates[&table].clone()
+                    };
+                    (table, keys_and_attributes)
+                })
+                .collect()
+        };
+
+        let mut outcome = BatchGetOutcome::default();
+        for batch in chunks(keys, MAX_BATCH_GET_KEYS) {
+            let mut pending = batch;
+            let mut attempt = 1;
+            loop {
+                let batch_input = BatchGetItemInput {
+                    request_items: with_template(pending),
+                    ..input.clone()
+                };
+                let output = backoff_when_throttled(policy.clone(), batch_input, |input| {
+                    self.batch_get_item(input)
+                })
+                .await?;
+                merge(&mut outcome.responses, output.responses.unwrap_or_default());
+                // Only the keys are taken from the response, retries are sent with the rest
+                // of the table's `KeysAndAttributes` as given.
+                pending = without_empty(
+                    output
+                        .unprocessed_keys
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|(table, _)| templates.contains_key(table))
+                        .map(|(table, keys_and_attributes)| (table, keys_and_attributes.keys))
+                        .collect(),
+                );
+                if pending.is_empty() {
+                    break;
+                }
+                if attempt >= policy.max_attempts() {
+                    for (table, keys_and_attributes) in with_template(pending) {
+                        match outcome.unprocessed_keys.entry(table) {
+                            Entry::Occupied(mut entry) => {
+                                entry.get_mut().keys.extend(keys_and_attributes.keys)
+                            }
+                            Entry::Vacant(entry) => {
+                                entry.insert(keys_and_attributes);
+                            }
+                        }
+                    }
+                    break;
+                }
+                time::delay_for(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+        }
+        Ok(outcome)
+    }
+}
+
+/// Splits the values of `by_table` into batches of at most `size` values in total. Tables
+/// are batched in the order of their names.
+fn chunks<T>(by_table: HashMap<String, Vec<T>>, size: usize) -> Vec<HashMap<String, Vec<T>>> {
+    let mut tables: Vec<_> = by_table.into_iter().collect();
+    tables.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut batches = Vec::new();
+    let mut batch: HashMap<_, Vec<T>> = HashMap::new();
+    let mut len = 0;
+    for (table, values) in tables {
+        for value in values {
+            if len == size {
+                batches.push(mem::take(&mut batch));
+                len = 0;
+            }
+            batch.entry(table.clone()).or_default().push(value);
+            len += 1;
+        }
+    }
+    if len > 0 {
+        batches.push(batch);
+    }
+    batches
+}
+
+fn without_empty<T>(by_table: HashMap<String, Vec<T>>) -> HashMap<String, Vec<T>> {
+    by_table
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .collect()
+}
+
+fn merge<T>(into: &mut HashMap<String, Vec<T>>, by_table: HashMap<String, Vec<T>>) {
+    for (table, values) in without_empty(by_table) {
+        into.entry(table).or_default().extend(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::PutRequest;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    /// Responds with `body` to a request for the items or keys of `table` with the ids
+    /// `expected`.
+    fn expecting(
+        table: &'static str,
+        field: &'static str,
+        expected: Vec<&str>,
+        body: Value,
+    ) -> MockRequestDispatcher {
+        let expected: Vec<String> = expected.into_iter().map(str::to_owned).collect();
+        MockRequestDispatcher::with_status(200)
+            .with_body(&body.to_string())
+            .with_request_checker(move |request| {
+                let sent: Value = match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref body)) => {
+                        serde_json::from_slice(body).unwrap()
+                    }
+                    _ => panic!("expected a buffered request body"),
+                };
+                let sent = &sent["RequestItems"][table];
+                let ids: Vec<_> = match field {
+                    "Keys" => {
+                        assert_eq!(sent["ConsistentRead"], true);
+                        sent["Keys"].as_array().unwrap().iter().collect()
+                    }
+                    _ => sent
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|request| &request["PutRequest"]["Item"])
+                        .collect(),
+                };
+                let ids: Vec<_> = ids
+                    .into_iter()
+                    .map(|item| item["id"]["S"].as_str().unwrap().to_owned())
+                    .collect();
+                assert_eq!(ids, expected);
+            })
+    }
+
+    fn item(id: &str) -> Item {
+        let mut item = HashMap::new();
+        item.insert(
+            "id".to_owned(),
+            AttributeValue {
+                s: Some(id.to_owned()),
+                ..Default::default()
+            },
+        );
+        item
+    }
+
+    fn put(id: &str) -> WriteRequest {
+        WriteRequest {
+            put_request: Some(PutRequest { item: item(id) }),
+            ..Default::default()
+        }
+    }
+
+    fn quick_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new()
+            .with_max_attempts(max_attempts)
+            .with_base_delay(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn chunks_fill_batches_across_tables() {
+        let mut by_table = HashMap::new();
+        by_table.insert("b".to_owned(), vec![4, 5, 6]);
+        by_table.insert("a".to_owned(), vec![1, 2, 3]);
+
+        let batches = chunks(by_table, 4);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0]["a"], vec![1, 2, 3]);
+        assert_eq!(batches[0]["b"], vec![4]);
+        assert_eq!(batches[1].len(), 1);
+        assert_eq!(batches[1]["b"], vec![5, 6]);
+    }
+
+    #[tokio::test]
+    async fn batch_writes_retry_unprocessed_items() {
+        let ids: Vec<String> = (0..27).map(|i| format!("{:02}", i)).collect();
+        let first: Vec<&str> = ids[..25].iter().map(String::as_str).collect();
+        let unprocessed = |ids: &[&str]| {
+            let requests: Vec<_> = ids
+                .iter()
+                .map(|id| json!({"PutRequest": {"Item": {"id": {"S": id}}}}))
+                .collect();
+            json!({ "UnprocessedItems": { "table": requests } })
+        };
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting("table", "Items", first, unprocessed(&["03", "07"])),
+            expecting("table", "Items", vec!["03", "07"], unprocessed(&["07"])),
+            expecting(
+                "table",
+                "Items",
+                vec!["07"],
+                json!({"UnprocessedItems": {}}),
+            ),
+            expecting("table", "Items", vec!["25", "26"], json!({})),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut request_items = HashMap::new();
+        request_items.insert("table".to_owned(), ids.iter().map(|id| put(id)).collect());
+        let input = BatchWriteItemInput {
+            request_items,
+            ..Default::default()
+        };
+
+        let outcome = client
+            .batch_write_item_with_retries(input, quick_retries(3))
+            .await
+            .unwrap();
+
+        assert!(outcome.is_complete(), "{:?}", outcome);
+    }
+
+    #[tokio::test]
+    async fn batch_writes_return_items_left_after_the_last_attempt() {
+        let unprocessed = json!({
+            "UnprocessedItems": {"table": [{"PutRequest": {"Item": {"id": {"S": "b"}}}}]}
+        });
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting("table", "Items", vec!["a", "b"], unprocessed.clone()),
+            expecting("table", "Items", vec!["b"], unprocessed),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut request_items = HashMap::new();
+        request_items.insert("table".to_owned(), vec![put("a"), put("b")]);
+        let input = BatchWriteItemInput {
+            request_items,
+            ..Default::default()
+        };
+
+        let outcome = client
+            .batch_write_item_with_retries(input, quick_retries(2))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.unprocessed_items["table"], vec![put("b")]);
+    }
+
+    #[tokio::test]
+    async fn batch_gets_merge_responses_of_retries() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting(
+                "table",
+                "Keys",
+                vec!["a", "b", "c"],
+                json!({
+                    "Responses": {"table": [{"id": {"S": "a"}, "n": {"N": "1"}}]},
+                    "UnprocessedKeys": {
+                        "table": {"Keys": [{"id": {"S": "b"}}, {"id": {"S": "c"}}]}
+                    }
+                }),
+            ),
+            expecting(
+                "table",
+                "Keys",
+                vec!["b", "c"],
+                json!({
+                    "Responses": {"table": [{"id": {"S": "c"}, "n": {"N": "3"}}]},
+                    "UnprocessedKeys": {"table": {"Keys": [{"id": {"S": "b"}}]}}
+                }),
+            ),
+            expecting(
+                "table",
+                "Keys",
+                vec!["b"],
+                json!({"Responses": {"table": [{"id": {"S": "b"}, "n": {"N": "2"}}]}}),
+            ),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut request_items = HashMap::new();
+        request_items.insert(
+            "table".to_owned(),
+            KeysAndAttributes {
+                keys: vec![item("a"), item("b"), item("c")],
+                consistent_read: Some(true),
+                ..Default::default()
+            },
+        );
+        let input = BatchGetItemInput {
+            request_items,
+            ..Default::default()
+        };
+
+        let outcome = client
+            .batch_get_item_with_retries(input, quick_retries(3))
+            .await
+            .unwrap();
+
+        assert!(outcome.is_complete(), "{:?}", outcome);
+        let mut numbers: Vec<_> = outcome.responses["table"]
+            .iter()
+            .map(|item| item["n"].n.clone().unwrap())
+            .collect();
+        numbers.sort();
+        assert_eq!(numbers, vec!["1", "2", "3"]);
+    }
+}
@@ -0,0 +1,1263 @@
+//! Converting between Rust values and DynamoDB items with serde.
+//!
+//! `to_item` serializes a struct or map into the attributes of an item, and `from_item`
+//! deserializes them back. Values map to attribute values as follows:
+//!
+//! * `bool` to `BOOL`.
+//! * Integers and floats to `N`. Integers are written exactly, floats with the fewest digits
+//!   that parse back to the same float; NaN and infinite floats can't be serialized. Numbers
+//!   are parsed as the type they're deserialized into, so a `u64` above 2<sup>53</sup> round
+//!   trips exactly, and deserializing a number that doesn't fit the type fails.
+//! * Strings and `char`s to `S`.
+//! * Bytes, e.g. a `bytes::Bytes`, to `B`. Note that `Vec<u8>` serializes as a list of numbers
+//!   unless its field is marked with `#[serde(with = "serde_bytes")]` or similar.
+//! * `None` is left out of structs and maps, and is `NULL` in lists. Missing attributes and
+//!   `NULL`s deserialize as `None`. `()` and unit structs are `NULL`.
+//! * Sequences and tuples to `L`, structs and maps to `M`. Map keys must be strings, `char`s
+//!   or integers.
+//! * `StringSet`, `NumberSet` and `BinarySet` to `SS`, `NS` and `BS`. DynamoDB doesn't allow
+//!   empty sets, so serializing one fails. Sets also deserialize into `Vec`s.
+//! * Unit enum variants to `S` with the name of the variant, other variants to `M` with the
+//!   name of the variant as the only key, like `serde_json` does.
+//!
+//! DynamoDB stores numbers with up to 38 digits of precision, and doesn't keep the sign of
+//! zero, so some `i128`, `u128` and float values aren't read back like they were written.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusoto_dynamodb::item::{from_item, to_item, StringSet};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//!     email: Option<String>,
+//!     tags: StringSet,
+//! }
+//!
+//! let user = User {
+//!     id: 9_007_199_254_740_993,
+//!     name: "Ferris".to_owned(),
+//!     email: None,
+//!     tags: StringSet(vec!["admin".to_owned()]),
+//! };
+//!
+//! let item = to_item(&user).unwrap();
+//! assert_eq!(item["id"].n.as_deref(), Some("9007199254740993"));
+//! assert!(!item.contains_key("email"));
+//! assert_eq!(from_item::<User>(item).unwrap(), user);
+//! ```
+
+use std::collections::{hash_map, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use bytes::Bytes;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::ser::{self, Impossible, Serialize};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+use crate::generated::AttributeValue;
+
+/// Serializes `value`, a struct or a map, into the attributes of an item.
+pub fn to_item<T>(value: &T) -> Result<HashMap<String, AttributeValue>, ItemError>
+where
+    T: Serialize + ?Sized,
+{
+    match to_attribute_value(value)? {
+        AttributeValue { m: Some(item), .. } => Ok(item),
+        _ => Err(ItemError::new(
+            "only structs and maps can be serialized as items",
+        )),
+    }
+}
+
+/// Serializes `value` into an attribute value.
+pub fn to_attribute_value<T>(value: &T) -> Result<AttributeValue, ItemError>
+where
+    T: Serialize + ?Sized,
+{
+    Ok(value.serialize(Serializer)?.unwrap_or_else(null))
+}
+
+/// Deserializes a `T` from the attributes of an item.
+pub fn from_item<T>(item: HashMap<String, AttributeValue>) -> Result<T, ItemError>
+where
+    T: DeserializeOwned,
+{
+    from_attribute_value(AttributeValue {
+        m: Some(item),
+        ..Default::default()
+    })
+}
+
+/// Deserializes a `T` from an attribute value.
+pub fn from_attribute_value<T>(value: AttributeValue) -> Result<T, ItemError>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(Deserializer(value))
+}
+
+/// The error converting between a value and an item failed with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemError {
+    /// Why the value couldn't be converted.
+    pub message: String,
+}
+
+impl ItemError {
+    fn new<S: Into<String>>(message: S) -> ItemError {
+        ItemError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ItemError {}
+
+impl ser::Error for ItemError {
+    fn custom<T: fmt::Display>(msg: T) -> ItemError {
+        ItemError::new(msg.to_string())
+    }
+}
+
+impl de::Error for ItemError {
+    fn custom<T: fmt::Display>(msg: T) -> ItemError {
+        ItemError::new(msg.to_string())
+    }
+}
+
+// The names sets serialize as newtype structs with, for `Serializer` to tell them apart from
+// lists.
+const STRING_SET: &str = "$rusoto_dynamodb::item::StringSet";
+const NUMBER_SET: &str = "$rusoto_dynamodb::item::NumberSet";
+const BINARY_SET: &str = "$rusoto_dynamodb::item::BinarySet";
+
+/// A string set, serialized as an `SS` attribute, and as a sequence by other serializers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StringSet(pub Vec<String>);
+
+/// A number set, serialized as an `NS` attribute, and as a sequence by other serializers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NumberSet<T>(pub Vec<T>);
+
+/// A binary set, serialized as a `BS` attribute, and as a sequence by other serializers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BinarySet(pub Vec<Bytes>);
+
+impl Serialize for StringSet {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(STRING_SET, &self.0)
+    }
+}
+
+impl<T: Serialize> Serialize for NumberSet<T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(NUMBER_SET, &self.0)
+    }
+}
+
+impl Serialize for BinarySet {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(BINARY_SET, &self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for StringSet {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(StringSet)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NumberSet<T> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(NumberSet)
+    }
+}
+
+impl<'de> Deserialize<'de> for BinarySet {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(BinarySet)
+    }
+}
+
+fn null() -> AttributeValue {
+    AttributeValue {
+        null: Some(true),
+        ..Default::default()
+    }
+}
+
+fn number(n: String) -> AttributeValue {
+    AttributeValue {
+        n: Some(n),
+        ..Default::default()
+    }
+}
+
+fn string(s: String) -> AttributeValue {
+    AttributeValue {
+        s: Some(s),
+        ..Default::default()
+    }
+}
+
+fn map(m: HashMap<String, AttributeValue>) -> AttributeValue {
+    AttributeValue {
+        m: Some(m),
+        ..Default::default()
+    }
+}
+
+/// The map an enum variant with fields serializes as.
+fn variant_map(variant: &str, value: AttributeValue) -> AttributeValue {
+    let mut values = HashMap::with_capacity(1);
+    values.insert(variant.to_owned(), value);
+    map(values)
+}
+
+fn float<F: fmt::Display>(value: F, finite: bool) -> Result<Option<AttributeValue>, ItemError> {
+    if !finite {
+        return Err(ItemError::new(format!(
+            "DynamoDB numbers can't be {}",
+            value
+        )));
+    }
+    Ok(Some(number(value.to_string())))
+}
+
+/// Converts the list serialized for the set called `name` into the set.
+fn set(name: &str, list: Option<AttributeValue>) -> Result<Option<AttributeValue>, ItemError> {
+    let values = match list {
+        Some(AttributeValue {
+            l: Some(values), ..
+        }) => values,
+        _ => return Err(ItemError::new("sets must be serialized from sequences")),
+    };
+    if values.is_empty() {
+        return Err(ItemError::new("DynamoDB sets can't be empty"));
+    }
+    let element = |value: AttributeValue| match name {
+        STRING_SET => value.s,
+        NUMBER_SET => value.n,
+        _ => None,
+    };
+    let mut set = AttributeValue::default();
+    match name {
+        BINARY_SET => {
+            let values: Option<Vec<Bytes>> = values.into_iter().map(|value| value.b).collect();
+            set.bs = Some(values.ok_or_else(|| ItemError::new("binary sets can only hold bytes"))?);
+        }
+        STRING_SET => {
+            let values: Option<Vec<String>> = values.into_iter().map(element).collect();
+            set.ss =
+                Some(values.ok_or_else(|| ItemError::new("string sets can only hold strings"))?);
+        }
+        _ => {
+            let values: Option<Vec<String>> = values.into_iter().map(element).collect();
+            set.ns =
+                Some(values.ok_or_else(|| ItemError::new("number sets can only hold numbers"))?);
+        }
+    }
+    Ok(Some(set))
+}
+
+/// Serializes values into attribute values, `None` into `Ok(None)` for it to be left out of
+/// maps.
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeVariant<SerializeList>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeVariant<SerializeMap>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, ItemError> {
+        Ok(Some(AttributeValue {
+            bool: Some(v),
+            ..Default::default()
+        }))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, ItemError> {
+        Ok(Some(number(v.to_string())))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, ItemError> {
+        float(v, v.is_finite())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, ItemError> {
+        float(v, v.is_finite())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, ItemError> {
+        Ok(Some(string(v.to_string())))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, ItemError> {
+        Ok(Some(string(v.to_owned())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, ItemError> {
+        Ok(Some(AttributeValue {
+            b: Some(Bytes::copy_from_slice(v)),
+            ..Default::default()
+        }))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, ItemError> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, ItemError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(null()))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, ItemError> {
+        Ok(Some(null()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, ItemError> {
+        Ok(Some(string(variant.to_owned())))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, ItemError> {
+        match name {
+            STRING_SET | NUMBER_SET | BINARY_SET => set(name, value.serialize(self)?),
+            _ => value.serialize(self),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, ItemError> {
+        Ok(Some(variant_map(variant, to_attribute_value(value)?)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, ItemError> {
+        Ok(SerializeList(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, ItemError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, ItemError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, ItemError> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, ItemError> {
+        Ok(SerializeMap {
+            values: HashMap::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap, ItemError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, ItemError> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+/// Serializes the elements of a list.
+struct SerializeList(Vec<AttributeValue>);
+
+impl SerializeList {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.0.push(to_attribute_value(value)?);
+        Ok(())
+    }
+
+    fn into_list(self) -> AttributeValue {
+        AttributeValue {
+            l: Some(self.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(self.into_list()))
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(self.into_list()))
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(self.into_list()))
+    }
+}
+
+/// Serializes the entries of a map, leaving out `None`s.
+struct SerializeMap {
+    values: HashMap<String, AttributeValue>,
+    key: Option<String>,
+}
+
+impl SerializeMap {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<(), ItemError> {
+        if let Some(value) = value.serialize(Serializer)? {
+            self.values.insert(key, value);
+        }
+        Ok(())
+    }
+
+    fn into_map(self) -> AttributeValue {
+        map(self.values)
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ItemError> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ItemError::new("map value serialized before its key"))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(self.into_map()))
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ItemError> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(self.into_map()))
+    }
+}
+
+/// Serializes the fields of an enum variant, as the only entry of a map keyed by the variant.
+struct SerializeVariant<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeList> {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(variant_map(self.variant, self.inner.into_list())))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeMap> {
+    type Ok = Option<AttributeValue>;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ItemError> {
+        self.inner.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, ItemError> {
+        Ok(Some(variant_map(self.variant, self.inner.into_map())))
+    }
+}
+
+/// Serializes map keys, which must be strings, `char`s or integers.
+struct KeySerializer;
+
+fn key_must_be_a_string() -> ItemError {
+    ItemError::new("map keys must be strings, chars or integers")
+}
+
+macro_rules! serialize_key {
+    ($($method:ident: $ty:ty,)*) => {$(
+        fn $method(self, v: $ty) -> Result<String, ItemError> {
+            Ok(v.to_string())
+        }
+    )*};
+}
+
+macro_rules! reject_key {
+    ($($method:ident: $ty:ty,)*) => {$(
+        fn $method(self, _v: $ty) -> Result<String, ItemError> {
+            Err(key_must_be_a_string())
+        }
+    )*};
+}
+
+impl ser::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = ItemError;
+    type SerializeSeq = Impossible<String, ItemError>;
+    type SerializeTuple = Impossible<String, ItemError>;
+    type SerializeTupleStruct = Impossible<String, ItemError>;
+    type SerializeTupleVariant = Impossible<String, ItemError>;
+    type SerializeMap = Impossible<String, ItemError>;
+    type SerializeStruct = Impossible<String, ItemError>;
+    type SerializeStructVariant = Impossible<String, ItemError>;
+
+    serialize_key! {
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    reject_key! {
+        serialize_bool: bool,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_bytes: &[u8],
+    }
+
+    fn serialize_none(self) -> Result<String, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, ItemError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, ItemError> {
+        Ok(variant.to_owned())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, ItemError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, ItemError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, ItemError> {
+        Err(key_must_be_a_string())
+    }
+}
+
+/// The value of an attribute value, whichever of its fields holds it.
+enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Binary(Bytes),
+    List(Vec<AttributeValue>),
+    Map(HashMap<String, AttributeValue>),
+}
+
+impl Value {
+    fn of(value: AttributeValue) -> Result<Value, ItemError> {
+        let AttributeValue {
+            b,
+            bool,
+            bs,
+            l,
+            m,
+            n,
+            ns,
+            null,
+            s,
+            ss,
+        } = value;
+        let value = if let Some(s) = s {
+            Value::String(s)
+        } else if let Some(n) = n {
+            Value::Number(n)
+        } else if let Some(b) = b {
+            Value::Binary(b)
+        } else if let Some(bool) = bool {
+            Value::Bool(bool)
+        } else if let Some(m) = m {
+            Value::Map(m)
+        } else if let Some(l) = l {
+            Value::List(l)
+        } else if let Some(ss) = ss {
+            Value::List(ss.into_iter().map(string).collect())
+        } else if let Some(ns) = ns {
+            Value::List(ns.into_iter().map(number).collect())
+        } else if let Some(bs) = bs {
+            let binary = |b| AttributeValue {
+                b: Some(b),
+                ..Default::default()
+            };
+            Value::List(bs.into_iter().map(binary).collect())
+        } else if null == Some(true) {
+            Value::Null
+        } else {
+            return Err(ItemError::new("attribute value has no value"));
+        };
+        Ok(value)
+    }
+}
+
+fn parse_number<T: FromStr>(n: &str, expected: &str) -> Result<T, ItemError> {
+    n.trim()
+        .parse()
+        .map_err(|_| ItemError::new(format!("expected {}, got the number {}", expected, n)))
+}
+
+/// Deserializes values from an attribute value.
+struct Deserializer(AttributeValue);
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident: $ty:ident,)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+            match self.0.n {
+                Some(ref n) => visitor.$visit(parse_number::<$ty>(n, stringify!($ty))?),
+                None => self.deserialize_any(visitor),
+            }
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = ItemError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        match Value::of(self.0)? {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::Number(n) => {
+                if let Ok(v) = n.parse::<u64>() {
+                    visitor.visit_u64(v)
+                } else if let Ok(v) = n.parse::<i64>() {
+                    visitor.visit_i64(v)
+                } else {
+                    visitor.visit_f64(parse_number(&n, "a number")?)
+                }
+            }
+            Value::String(s) => visitor.visit_string(s),
+            Value::Binary(b) => visitor.visit_byte_buf(b.to_vec()),
+            Value::List(l) => visitor.visit_seq(SeqDeserializer(l.into_iter())),
+            Value::Map(m) => visitor.visit_map(MapDeserializer {
+                entries: m.into_iter(),
+                value: None,
+            }),
+        }
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        if self.0.null == Some(true) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        match Value::of(self.0)? {
+            Value::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Value::Map(m) if m.len() == 1 => {
+                let (variant, value) = m.into_iter().next().unwrap();
+                visitor.visit_enum(EnumDeserializer { variant, value })
+            }
+            _ => Err(ItemError::new(
+                "enums must be deserialized from strings or maps with a single key",
+            )),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqDeserializer(std::vec::IntoIter<AttributeValue>);
+
+impl<'de> SeqAccess<'de> for SeqDeserializer {
+    type Error = ItemError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ItemError> {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(Deserializer(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct MapDeserializer {
+    entries: hash_map::IntoIter<String, AttributeValue>,
+    value: Option<AttributeValue>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer {
+    type Error = ItemError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ItemError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(KeyDeserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, ItemError> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| ItemError::new("map value deserialized before its key"))?;
+        seed.deserialize(Deserializer(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// Deserializes map keys, parsing them if integers are expected.
+struct KeyDeserializer(String);
+
+macro_rules! deserialize_key {
+    ($($method:ident => $visit:ident: $ty:ident,)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+            visitor.$visit(parse_number::<$ty>(&self.0, stringify!($ty))?)
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = ItemError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_key! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Deserializes an enum variant from the only entry of a map.
+struct EnumDeserializer {
+    variant: String,
+    value: AttributeValue,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = ItemError;
+    type Variant = Deserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Deserializer), ItemError> {
+        let variant = seed.deserialize(KeyDeserializer(self.variant))?;
+        Ok((variant, Deserializer(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+    type Error = ItemError;
+
+    fn unit_variant(self) -> Result<(), ItemError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, ItemError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(i32, i32),
+        Rectangle { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Everything {
+        flag: bool,
+        small: i8,
+        big: u64,
+        huge: i128,
+        ratio: f64,
+        letter: char,
+        name: String,
+        data: Bytes,
+        missing: Option<String>,
+        present: Option<u32>,
+        unit: (),
+        list: Vec<Option<String>>,
+        pair: (u8, String),
+        nested: BTreeMap<String, Vec<u16>>,
+        by_id: BTreeMap<u32, String>,
+        names: StringSet,
+        scores: NumberSet<i64>,
+        blobs: BinarySet,
+        shapes: Vec<Shape>,
+    }
+
+    fn everything() -> Everything {
+        let mut nested = BTreeMap::new();
+        nested.insert("primes".to_owned(), vec![2, 3, 5]);
+        nested.insert("empty".to_owned(), vec![]);
+        let mut by_id = BTreeMap::new();
+        by_id.insert(7, "seven".to_owned());
+        Everything {
+            flag: true,
+            small: -8,
+            big: u64::MAX,
+            huge: i128::MIN,
+            ratio: 0.1,
+            letter: 'λ',
+            name: "Ferris".to_owned(),
+            data: Bytes::from_static(b"\x00\xff"),
+            missing: None,
+            present: Some(42),
+            unit: (),
+            list: vec![Some("a".to_owned()), None],
+            pair: (1, "one".to_owned()),
+            nested,
+            by_id,
+            names: StringSet(vec!["a".to_owned(), "b".to_owned()]),
+            scores: NumberSet(vec![-1, 10]),
+            blobs: BinarySet(vec![Bytes::from_static(b"blob")]),
+            shapes: vec![
+                Shape::Point,
+                Shape::Circle(1.5),
+                Shape::Line(-1, 1),
+                Shape::Rectangle {
+                    width: 2,
+                    height: 3,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trips_every_kind_of_value() {
+        let item = to_item(&everything()).unwrap();
+
+        assert_eq!(item["flag"].bool, Some(true));
+        assert_eq!(item["big"].n.as_deref(), Some("18446744073709551615"));
+        assert_eq!(
+            item["huge"].n.as_deref(),
+            Some("-170141183460469231731687303715884105728")
+        );
+        assert_eq!(item["ratio"].n.as_deref(), Some("0.1"));
+        assert_eq!(item["letter"].s.as_deref(), Some("λ"));
+        assert_eq!(item["data"].b, Some(Bytes::from_static(b"\x00\xff")));
+        assert!(!item.contains_key("missing"));
+        assert_eq!(item["unit"].null, Some(true));
+        assert_eq!(item["list"].l.as_ref().unwrap()[1].null, Some(true));
+        assert_eq!(
+            item["by_id"].m.as_ref().unwrap()["7"].s.as_deref(),
+            Some("seven")
+        );
+        assert_eq!(item["names"].ss, Some(vec!["a".to_owned(), "b".to_owned()]));
+        assert_eq!(
+            item["scores"].ns,
+            Some(vec!["-1".to_owned(), "10".to_owned()])
+        );
+        assert_eq!(item["blobs"].bs, Some(vec![Bytes::from_static(b"blob")]));
+        let shapes = item["shapes"].l.as_ref().unwrap();
+        assert_eq!(shapes[0].s.as_deref(), Some("Point"));
+        assert_eq!(
+            shapes[1].m.as_ref().unwrap()["Circle"].n.as_deref(),
+            Some("1.5")
+        );
+
+        assert_eq!(from_item::<Everything>(item).unwrap(), everything());
+    }
+
+    #[test]
+    fn deserializes_null_and_missing_attributes_as_none() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Optional {
+            a: Option<String>,
+            b: Option<String>,
+        }
+        let mut item = HashMap::new();
+        item.insert("a".to_owned(), null());
+
+        let optional: Optional = from_item(item).unwrap();
+
+        assert_eq!(optional, Optional { a: None, b: None });
+    }
+
+    #[test]
+    fn sets_deserialize_into_vecs() {
+        let value = AttributeValue {
+            ns: Some(vec!["1".to_owned(), "2.5".to_owned()]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            from_attribute_value::<Vec<f64>>(value).unwrap(),
+            vec![1.0, 2.5]
+        );
+    }
+
+    #[test]
+    fn rejects_values_dynamodb_cannot_store() {
+        assert!(to_attribute_value(&f64::NAN).is_err());
+        assert!(to_attribute_value(&f32::INFINITY).is_err());
+        assert!(to_attribute_value(&StringSet(vec![])).is_err());
+        assert!(to_item(&"not a map").is_err());
+
+        let mut by_pair = HashMap::new();
+        by_pair.insert((1, 2), "pair");
+        let err = to_item(&by_pair).unwrap_err();
+        assert_eq!(err.message, "map keys must be strings, chars or integers");
+    }
+
+    #[test]
+    fn rejects_numbers_that_do_not_fit() {
+        let err = from_attribute_value::<u8>(number("256".to_owned())).unwrap_err();
+        assert_eq!(err.message, "expected u8, got the number 256");
+        assert!(from_attribute_value::<i64>(number("1.5".to_owned())).is_err());
+        assert!(from_attribute_value::<u32>(string("1".to_owned())).is_err());
+    }
+
+    #[test]
+    fn keeps_the_sign_of_zero() {
+        let value = to_attribute_value(&-0.0f64).unwrap();
+
+        assert_eq!(value.n.as_deref(), Some("-0"));
+        assert!(from_attribute_value::<f64>(value)
+            .unwrap()
+            .is_sign_negative());
+    }
+
+    #[quickcheck]
+    fn round_trips_integers(a: u64, b: i64, c: u128, d: i128) -> bool {
+        let value = (a, b, c, d);
+        from_attribute_value::<(u64, i64, u128, i128)>(to_attribute_value(&value).unwrap()).unwrap()
+            == value
+    }
+
+    #[quickcheck]
+    fn round_trips_floats(a: f64, b: f32) -> bool {
+        if !a.is_finite() || !b.is_finite() {
+            return to_attribute_value(&(a, b)).is_err();
+        }
+        let (x, y): (f64, f32) =
+            from_attribute_value(to_attribute_value(&(a, b)).unwrap()).unwrap();
+        x.to_bits() == a.to_bits() && y.to_bits() == b.to_bits()
+    }
+
+    #[quickcheck]
+    fn round_trips_maps_of_strings(map: HashMap<String, Vec<Option<String>>>) -> bool {
+        from_item::<HashMap<String, Vec<Option<String>>>>(to_item(&map).unwrap()).unwrap() == map
+    }
+}
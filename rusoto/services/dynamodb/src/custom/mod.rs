@@ -1,2 +1,9 @@
+pub mod item;
+mod batch;
+mod paginate;
+
+pub use self::batch::{BatchGetOutcome, BatchWriteOutcome};
+pub use self::paginate::ItemStreamOptions;
+
 #[cfg(test)]
 mod custom_tests;
@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+
+use futures::stream::{self, Stream, StreamExt};
+use rusoto_core::pagination::{flatten_pages, paginate};
+use rusoto_core::retry::{is_throttling_error, RetryPolicy};
+use rusoto_core::RusotoError;
+use tokio::time;
+
+use crate::generated::{
+    AttributeValue, DynamoDb, DynamoDbClient, QueryError, QueryInput, QueryOutput, ScanError,
+    ScanInput, ScanOutput,
+};
+
+/// An item returned by `scan` or `query`.
+type Item = HashMap<String, AttributeValue>;
+
+/// How the streams of `scan_stream_with`, `query_stream_with` and `parallel_scan_stream` read
+/// items.
+///
+/// By default they read all items, and retry pages that were throttled, e.g. with a
+/// `ProvisionedThroughputExceededException`, with the backoff of `RetryPolicy::new()`.
+#[derive(Clone, Debug)]
+pub struct ItemStreamOptions {
+    max_items: Option<usize>,
+    throttle_retry_policy: RetryPolicy,
+}
+
+impl ItemStreamOptions {
+    /// Creates options reading all items, and retrying throttled pages.
+    pub fn new() -> Self {
+        ItemStreamOptions {
+            max_items: None,
+            throttle_retry_policy: RetryPolicy::new(),
+        }
+    }
+
+    /// Ends the stream after `max_items` items in total, across pages.
+    ///
+    /// The `limit` of the input still limits the items evaluated per page, and pages are
+    /// requested with a `limit` of at most the number of items left to read.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Sets the policy retrying pages that were throttled. Only its maximum number of
+    /// attempts and its delays are used, `RetryPolicy::never()` fails the stream with the
+    /// first throttling error.
+    ///
+    /// These retries come on top of those of the client's own retry policy.
+    pub fn with_throttle_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.throttle_retry_policy = policy;
+        self
+    }
+}
+
+impl Default for ItemStreamOptions {
+    fn default() -> Self {
+        ItemStreamOptions::new()
+    }
+}
+
+impl DynamoDbClient {
+    /// Streams the pages of `scan`, starting with the response to `input` and following
+    /// `last_evaluated_key`.
+    ///
+    /// Pages are only requested while the stream is polled, dropping it cancels the request
+    /// in flight. The stream ends after the first error.
+    pub fn scan_pages(
+        &self,
+        input: ScanInput,
+    ) -> impl Stream<Item = Result<ScanOutput, RusotoError<ScanError>>> + '_ {
+        paginate(
+            input,
+            move |input| self.scan(input),
+            |input, page: &ScanOutput| {
+                next_start_key(&page.last_evaluated_key).map(|key| ScanInput {
+                    exclusive_start_key: Some(key),
+                    ..input
+                })
+            },
+        )
+    }
+
+    /// Streams the items of all pages of `scan`, see `scan_pages`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDbClient, ScanInput};
+    ///
+    /// # async fn example() {
+    /// let dynamodb = DynamoDbClient::new(Region::UsEast1);
+    /// let input = ScanInput {
+    ///     table_name: "my-table".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let items = dynamodb.scan_stream(input);
+    /// futures::pin_mut!(items);
+    /// while let Some(item) = items.try_next().await.unwrap() {
+    ///     println!("{:?}", item);
+    /// }
+    /// # }
+    /// ```
+    pub fn scan_stream(
+        &self,
+        input: ScanInput,
+    ) -> impl Stream<Item = Result<Item, RusotoError<ScanError>>> + '_ {
+        flatten_pages(self.scan_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// Streams the pages of `query`, starting with the response to `input` and following
+    /// `last_evaluated_key`, see `scan_pages`.
+    pub fn query_pages(
+        &self,
+        input: QueryInput,
+    ) -> impl Stream<Item = Result<QueryOutput, RusotoError<QueryError>>> + '_ {
+        paginate(
+            input,
+            move |input| self.query(input),
+            |input, page: &QueryOutput| {
+                next_start_key(&page.last_evaluated_key).map(|key| QueryInput {
+                    exclusive_start_key: Some(key),
+                    ..input
+                })
+            },
+        )
+    }
+
+    /// Streams the items of all pages of `query`, see `query_pages`.
+    pub fn query_stream(
+        &self,
+        input: QueryInput,
+    ) -> impl Stream<Item = Result<Item, RusotoError<QueryError>>> + '_ {
+        flatten_pages(self.query_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// Streams the items of all pages of `scan` like `scan_stream`, but up to the maximum
+    /// number of items of `options`, and backing off when throttled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDbClient, ItemStreamOptions, ScanInput};
+    ///
+    /// # async fn example() {
+    /// let dynamodb = DynamoDbClient::new(Region::UsEast1);
+    /// let input = ScanInput {
+    ///     table_name: "my-table".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let options = ItemStreamOptions::new().with_max_items(1000);
+    /// let items: Vec<_> = dynamodb
+    ///     .scan_stream_with(input, options)
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn scan_stream_with(
+        &self,
+        input: ScanInput,
+        options: ItemStreamOptions,
+    ) -> impl Stream<Item = Result<Item, RusotoError<ScanError>>> + '_ {
+        item_stream(input, options, move |input| self.scan(input))
+    }
+
+    /// Streams the items of all pages of `query` like `query_stream`, but up to the maximum
+    /// number of items of `options`, and backing off when throttled, see `scan_stream_with`.
+    pub fn query_stream_with(
+        &self,
+        input: QueryInput,
+        options: ItemStreamOptions,
+    ) -> impl Stream<Item = Result<Item, RusotoError<QueryError>>> + '_ {
+        item_stream(input, options, move |input| self.query(input))
+    }
+
+    /// Scans `total_segments` segments of the table in parallel, streaming their items in the
+    /// order they arrive, see `scan_stream_with`.
+    ///
+    /// The `segment` and `total_segments` of `input` are overwritten. The maximum number of
+    /// items of `options` is the total of all segments, and the stream ends after the first
+    /// error of any segment.
+    pub fn parallel_scan_stream(
+        &self,
+        input: ScanInput,
+        total_segments: i64,
+        options: ItemStreamOptions,
+    ) -> impl Stream<Item = Result<Item, RusotoError<ScanError>>> + '_ {
+        let max_items = options.max_items.unwrap_or(usize::MAX);
+        let segments = (0..total_segments.max(1)).map(|segment| {
+            let input = ScanInput {
+                segment: Some(segment),
+                total_segments: Some(total_segments.max(1)),
+                ..input.clone()
+            };
+            self.scan_stream_with(input, options.clone()).boxed()
+        });
+        take_until_error(stream::select_all(segments)).take(max_items)
+    }
+}
+
+/// The inputs of `scan` and `query`, which are paginated the same way.
+trait PageInput: Clone {
+    fn limit(&mut self) -> &mut Option<i64>;
+    fn exclusive_start_key(&mut self) -> &mut Option<Item>;
+}
+
+/// The outputs of `scan` and `query`.
+trait Page {
+    fn items(&self) -> &Option<Vec<Item>>;
+    fn last_evaluated_key(&self) -> &Option<Item>;
+    fn into_items(self) -> Vec<Item>;
+}
+
+macro_rules! paginated {
+    ($input:ty, $output:ty) => {
+        impl PageInput for $input {
+            fn limit(&mut self) -> &mut Option<i64> {
+                &mut self.limit
+            }
+
+            fn exclusive_start_key(&mut self) -> &mut Option<Item> {
+                &mut self.exclusive_start_key
+            }
+        }
+
+        impl Page for $output {
+            fn items(&self) -> &Option<Vec<Item>> {
+                &self.items
+            }
+
+            fn last_evaluated_key(&self) -> &Option<Item> {
+                &self.last_evaluated_key
+            }
+
+            fn into_items(self) -> Vec<Item> {
+                self.items.unwrap_or_default()
+            }
+        }
+    };
+}
+
+paginated!(ScanInput, ScanOutput);
+paginated!(QueryInput, QueryOutput);
+
+/// Streams the items of the pages `fetch` returns, following `last_evaluated_key` as
+/// configured by `options`.
+fn item_stream<I, P, E, F, Fut>(
+    mut input: I,
+    options: ItemStreamOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<Item, RusotoError<E>>>
+where
+    I: PageInput,
+    P: Page,
+    E: fmt::Debug,
+    F: FnMut(I) -> Fut + Clone,
+    Fut: Future<Output = Result<P, RusotoError<E>>>,
+{
+    let mut remaining = options.max_items;
+    if let Some(max_items) = remaining {
+        cap_limit(&mut input, max_items);
+    }
+    let policy = options.throttle_retry_policy;
+    let pages = paginate(
+        input,
+        move |input| backoff_when_throttled(policy.clone(), input, fetch.clone()),
+        move |mut input, page: &P| {
+            let key = next_start_key(page.last_evaluated_key())?;
+            if let Some(ref mut remaining) = remaining {
+                *remaining = remaining.saturating_sub(page.items().as_ref().map_or(0, Vec::len));
+                if *remaining == 0 {
+                    return None;
+                }
+                cap_limit(&mut input, *remaining);
+            }
+            *input.exclusive_start_key() = Some(key);
+            Some(input)
+        },
+    );
+    flatten_pages(pages, Page::into_items).take(options.max_items.unwrap_or(usize::MAX))
+}
+
+/// Limits the items evaluated for the next page to `remaining`.
+fn cap_limit<I: PageInput>(input: &mut I, remaining: usize) {
+    let remaining = remaining.min(i64::MAX as usize) as i64;
+    let limit = input.limit();
+    *limit = Some(limit.map_or(remaining, |limit| limit.min(remaining)));
+}
+
+/// Fetches the page for `input`, retrying it after the delays of `policy` while it's
+/// throttled.
+pub(crate) async fn backoff_when_throttled<I, P, E, F, Fut>(
+    policy: RetryPolicy,
+    input: I,
+    mut fetch: F,
+) -> Result<P, RusotoError<E>>
+where
+    I: Clone,
+    E: fmt::Debug,
+    F: FnMut(I) -> Fut,
+    Fut: Future<Output = Result<P, RusotoError<E>>>,
+{
+    let mut attempt = 1;
+    loop {
+        match fetch(input.clone()).await {
+            Err(ref err) if attempt < policy.max_attempts() && is_throttling_error(err) => {
+                time::delay_for(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Ends `items` after its first error.
+fn take_until_error<T, E, S>(items: S) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    let mut failed = false;
+    items.take_while(move |item| {
+        let done = failed;
+        failed = item.is_err();
+        futures::future::ready(!done)
+    })
+}
+
+/// The key to start the next page at, unless the last page was read.
+fn next_start_key(last_evaluated_key: &Option<Item>) -> Option<Item> {
+    last_evaluated_key
+        .as_ref()
+        .filter(|key| !key.is_empty())
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    fn sent(request: &SignedRequest) -> Value {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => serde_json::from_slice(body).unwrap(),
+            _ => panic!("expected a buffered request body"),
+        }
+    }
+
+    fn expecting_start_key(start_key: Value, body: Value) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(&body.to_string())
+            .with_request_checker(move |request| {
+                let sent = sent(request);
+                assert_eq!(sent["TableName"], "table");
+                assert_eq!(sent["ExclusiveStartKey"], start_key);
+            })
+    }
+
+    fn throttled() -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(400).with_body(
+            &json!({
+                "__type": "com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException",
+                "message": "slow down"
+            })
+            .to_string(),
+        )
+    }
+
+    fn ids(items: Vec<Item>) -> Vec<String> {
+        items
+            .into_iter()
+            .map(|mut item| item.remove("id").unwrap().s.unwrap())
+            .collect()
+    }
+
+    fn quick_retries() -> ItemStreamOptions {
+        ItemStreamOptions::new().with_throttle_retry_policy(
+            RetryPolicy::new()
+                .with_max_attempts(3)
+                .with_base_delay(Duration::from_millis(1)),
+        )
+    }
+
+    #[tokio::test]
+    async fn scan_streams_items_across_pages() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting_start_key(
+                Value::Null,
+                json!({
+                    "Items": [{"id": {"S": "a"}}, {"id": {"S": "b"}}],
+                    "LastEvaluatedKey": {"id": {"S": "b"}}
+                }),
+            ),
+            expecting_start_key(
+                json!({"id": {"S": "b"}}),
+                json!({"Items": [{"id": {"S": "c"}}], "LastEvaluatedKey": {}}),
+            ),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = ScanInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        };
+
+        let ids: Vec<_> = client
+            .scan_stream(input)
+            .map_ok(|mut item| item.remove("id").unwrap().s.unwrap())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn query_pages_end_without_last_evaluated_key() {
+        let mock = MultipleMockRequestDispatcher::new(vec![expecting_start_key(
+            Value::Null,
+            json!({"Items": [], "Count": 0}),
+        )]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = QueryInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        };
+
+        let pages: Vec<_> = client.query_pages(input).try_collect().await.unwrap();
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].count, Some(0));
+    }
+
+    #[tokio::test]
+    async fn item_streams_back_off_when_throttled() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            throttled(),
+            throttled(),
+            expecting_start_key(
+                Value::Null,
+                json!({"Items": [{"id": {"S": "a"}}], "LastEvaluatedKey": {"id": {"S": "a"}}}),
+            ),
+            throttled(),
+            expecting_start_key(
+                json!({"id": {"S": "a"}}),
+                json!({"Items": [{"id": {"S": "b"}}]}),
+            ),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = QueryInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        };
+
+        let items = client
+            .query_stream_with(input, quick_retries())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids(items), vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn item_streams_fail_when_throttled_too_often() {
+        let mock = MultipleMockRequestDispatcher::new(vec![throttled(), throttled(), throttled()]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = ScanInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        };
+
+        let result: Result<Vec<_>, _> = client
+            .scan_stream_with(input, quick_retries())
+            .try_collect()
+            .await;
+
+        match result {
+            Err(RusotoError::Service(ScanError::ProvisionedThroughputExceeded(_))) => {}
+            other => panic!("expected a throttling error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn item_streams_stop_at_max_items() {
+        let page = |limit: i64, body: Value| {
+            MockRequestDispatcher::with_status(200)
+                .with_body(&body.to_string())
+                .with_request_checker(move |request| assert_eq!(sent(request)["Limit"], limit))
+        };
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            page(
+                3,
+                json!({
+                    "Items": [{"id": {"S": "a"}}, {"id": {"S": "b"}}],
+                    "LastEvaluatedKey": {"id": {"S": "b"}}
+                }),
+            ),
+            page(
+                1,
+                json!({"Items": [{"id": {"S": "c"}}], "LastEvaluatedKey": {"id": {"S": "c"}}}),
+            ),
+        ]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = ScanInput {
+            table_name: "table".to_owned(),
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        let items = client
+            .scan_stream_with(input, ItemStreamOptions::new().with_max_items(3))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids(items), vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn parallel_scans_combine_their_segments() {
+        let segment = |id: &str| {
+            MockRequestDispatcher::with_status(200)
+                .with_body(&json!({"Items": [{"id": {"S": id}}]}).to_string())
+                .with_request_checker(|request| {
+                    let sent = sent(request);
+                    assert_eq!(sent["TotalSegments"], 2);
+                    assert!(sent["Segment"] == 0 || sent["Segment"] == 1);
+                })
+        };
+        let mock = MultipleMockRequestDispatcher::new(vec![segment("a"), segment("b")]);
+        let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let input = ScanInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        };
+
+        let mut ids = ids(client
+            .parallel_scan_stream(input, 2, ItemStreamOptions::new())
+            .try_collect()
+            .await
+            .unwrap());
+        ids.sort();
+
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+}
@@ -41,7 +41,7 @@ impl DynamoDbClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5500,7 +5500,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, BatchGetItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetItemOutput, _>()
     }
 
@@ -5518,7 +5518,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, BatchWriteItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchWriteItemOutput, _>()
     }
 
@@ -5536,7 +5536,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, CreateBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupOutput, _>()
     }
 
@@ -5554,7 +5554,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, CreateGlobalTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGlobalTableOutput, _>()
     }
 
@@ -5572,7 +5572,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, CreateTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTableOutput, _>()
     }
 
@@ -5590,7 +5590,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DeleteBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupOutput, _>()
     }
 
@@ -5608,7 +5608,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DeleteItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteItemOutput, _>()
     }
 
@@ -5626,7 +5626,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DeleteTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTableOutput, _>()
     }
 
@@ -5644,7 +5644,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupOutput, _>()
     }
 
@@ -5665,7 +5665,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeContinuousBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeContinuousBackupsOutput, _>()
     }
@@ -5688,7 +5688,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeContributorInsightsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeContributorInsightsOutput, _>()
     }
@@ -5705,7 +5705,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEndpointsResponse, _>()
     }
 
@@ -5723,7 +5723,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeGlobalTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeGlobalTableOutput, _>()
     }
 
@@ -5745,7 +5745,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeGlobalTableSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGlobalTableSettingsOutput, _>()
     }
@@ -5762,7 +5762,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeLimitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLimitsOutput, _>()
     }
 
@@ -5780,7 +5780,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTableOutput, _>()
     }
 
@@ -5804,7 +5804,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeTableReplicaAutoScalingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTableReplicaAutoScalingOutput, _>()
     }
@@ -5823,7 +5823,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, DescribeTimeToLiveError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTimeToLiveOutput, _>()
     }
 
@@ -5841,7 +5841,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, GetItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetItemOutput, _>()
     }
 
@@ -5859,7 +5859,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ListBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBackupsOutput, _>()
     }
 
@@ -5877,7 +5877,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ListContributorInsightsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListContributorInsightsOutput, _>()
     }
@@ -5896,7 +5896,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ListGlobalTablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGlobalTablesOutput, _>()
     }
 
@@ -5914,7 +5914,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ListTablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTablesOutput, _>()
     }
 
@@ -5932,7 +5932,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ListTagsOfResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOfResourceOutput, _>()
     }
 
@@ -5950,7 +5950,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, PutItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutItemOutput, _>()
     }
 
@@ -5965,7 +5965,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, QueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()
     }
 
@@ -5983,7 +5983,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, RestoreTableFromBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RestoreTableFromBackupOutput, _>()
     }
@@ -6005,7 +6005,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, RestoreTableToPointInTimeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RestoreTableToPointInTimeOutput, _>()
     }
@@ -6021,7 +6021,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, ScanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()
     }
 
@@ -6056,7 +6056,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, TransactGetItemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TransactGetItemsOutput, _>()
     }
 
@@ -6074,7 +6074,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, TransactWriteItemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TransactWriteItemsOutput, _>()
     }
 
@@ -6109,7 +6109,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateContinuousBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateContinuousBackupsOutput, _>()
     }
@@ -6131,7 +6131,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateContributorInsightsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateContributorInsightsOutput, _>()
     }
@@ -6150,7 +6150,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateGlobalTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGlobalTableOutput, _>()
     }
 
@@ -6171,7 +6171,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateGlobalTableSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateGlobalTableSettingsOutput, _>()
     }
@@ -6190,7 +6190,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateItemOutput, _>()
     }
 
@@ -6208,7 +6208,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTableOutput, _>()
     }
 
@@ -6230,7 +6230,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateTableReplicaAutoScalingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateTableReplicaAutoScalingOutput, _>()
     }
@@ -6249,7 +6249,7 @@ impl DynamoDb for DynamoDbClient {
             .sign_and_dispatch(request, UpdateTimeToLiveError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTimeToLiveOutput, _>()
     }
 }
@@ -42,7 +42,7 @@ impl DynamoDbStreamsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -659,7 +659,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
             .sign_and_dispatch(request, DescribeStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStreamOutput, _>()
     }
 
@@ -677,7 +677,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
             .sign_and_dispatch(request, GetRecordsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRecordsOutput, _>()
     }
 
@@ -695,7 +695,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
             .sign_and_dispatch(request, GetShardIteratorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetShardIteratorOutput, _>()
     }
 
@@ -713,7 +713,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
             .sign_and_dispatch(request, ListStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
     }
 }
@@ -787,6 +787,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation("CompleteSnapshot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header(
@@ -809,13 +810,13 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CompleteSnapshotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CompleteSnapshotError::from_response(response))
         }
     }
@@ -833,6 +834,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("GetSnapshotBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -845,7 +847,7 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetSnapshotBlockResponse::default();
             result.block_data = Some(response.body);
@@ -859,7 +861,7 @@ impl Ebs for EbsClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSnapshotBlockError::from_response(response))
         }
     }
@@ -876,6 +878,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListChangedBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -899,13 +902,13 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListChangedBlocksResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListChangedBlocksError::from_response(response))
         }
     }
@@ -922,6 +925,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListSnapshotBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -942,13 +946,13 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSnapshotBlocksResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSnapshotBlocksError::from_response(response))
         }
     }
@@ -966,6 +970,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ebs", &self.region, &request_uri);
+        request.set_operation("PutSnapshotBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.block_data.to_owned());
@@ -984,7 +989,7 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutSnapshotBlockResponse, _>()?;
             result.checksum = response.headers.remove("x-amz-Checksum");
@@ -992,7 +997,7 @@ impl Ebs for EbsClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutSnapshotBlockError::from_response(response))
         }
     }
@@ -1006,6 +1011,7 @@ impl Ebs for EbsClient {
         let request_uri = "/snapshots";
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation("StartSnapshot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1017,13 +1023,13 @@ impl Ebs for EbsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartSnapshotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartSnapshotError::from_response(response))
         }
     }
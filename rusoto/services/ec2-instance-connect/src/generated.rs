@@ -46,7 +46,7 @@ impl Ec2InstanceConnectClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -207,7 +207,7 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
             .sign_and_dispatch(request, SendSSHPublicKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendSSHPublicKeyResponse, _>()
     }
 }
@@ -1,3 +1,5 @@
+mod paginate;
+
 /// `Filter` structure is a bit verbose to set up, and since it is used in many places
 /// `filter!` macro provides an ergononic way of creating it.
 /// `filter!` expects its arguments to be `ToString`.
@@ -73,3 +75,4 @@ macro_rules! filter {
         }
     }
 }
+mod waiter;
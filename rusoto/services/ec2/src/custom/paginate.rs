@@ -0,0 +1,126 @@
+use futures::Stream;
+use rusoto_core::pagination::{flatten_pages, paginate};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    DescribeInstancesError, DescribeInstancesRequest, DescribeInstancesResult, Ec2, Ec2Client,
+    Reservation,
+};
+
+impl Ec2Client {
+    /// Streams the pages of `describe_instances`, starting with the response to `request` and
+    /// following `next_token`.
+    ///
+    /// Pages are only requested while the stream is polled, dropping it cancels the request
+    /// in flight. The stream ends after the first error.
+    pub fn describe_instances_pages(
+        &self,
+        request: DescribeInstancesRequest,
+    ) -> impl Stream<Item = Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>>> + '_
+    {
+        paginate(
+            request,
+            move |request| self.describe_instances(request),
+            |request, page: &DescribeInstancesResult| match page.next_token {
+                Some(ref token) if !token.is_empty() => Some(DescribeInstancesRequest {
+                    next_token: Some(token.clone()),
+                    ..request
+                }),
+                _ => None,
+            },
+        )
+    }
+
+    /// Streams the reservations of all pages of `describe_instances`, see
+    /// `describe_instances_pages`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use rusoto_core::Region;
+    /// use rusoto_ec2::{DescribeInstancesRequest, Ec2Client};
+    ///
+    /// # async fn example() {
+    /// let ec2 = Ec2Client::new(Region::UsEast1);
+    /// let reservations = ec2.describe_instances_stream(DescribeInstancesRequest::default());
+    /// futures::pin_mut!(reservations);
+    /// while let Some(reservation) = reservations.try_next().await.unwrap() {
+    ///     for instance in reservation.instances.unwrap_or_default() {
+    ///         println!("{:?}", instance.instance_id);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn describe_instances_stream(
+        &self,
+        request: DescribeInstancesRequest,
+    ) -> impl Stream<Item = Result<Reservation, RusotoError<DescribeInstancesError>>> + '_ {
+        flatten_pages(self.describe_instances_pages(request), |page| {
+            page.reservations.unwrap_or_default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use std::collections::HashMap;
+
+    fn page(reservation_ids: &[&str], next_token: Option<&str>) -> String {
+        let reservations: String = reservation_ids
+            .iter()
+            .map(|id| format!("<item><reservationId>{}</reservationId></item>", id))
+            .collect();
+        let next = next_token
+            .map(|token| format!("<nextToken>{}</nextToken>", token))
+            .unwrap_or_default();
+        format!(
+            r#"<DescribeInstancesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+  <requestId>8f7724cf-496f-496e-8fe3-example</requestId>
+  <reservationSet>{}</reservationSet>
+  {}
+</DescribeInstancesResponse>"#,
+            reservations, next
+        )
+    }
+
+    fn expecting_token(token: Option<&'static str>, body: &str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(body)
+            .with_request_checker(move |request| {
+                let params: HashMap<String, String> = match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref body)) => {
+                        serde_urlencoded::from_bytes(body).expect("urlencoded request body")
+                    }
+                    _ => panic!("expected a buffered request body"),
+                };
+                assert_eq!(params["Action"], "DescribeInstances");
+                assert_eq!(params.get("NextToken").map(String::as_str), token);
+            })
+    }
+
+    #[tokio::test]
+    async fn streams_reservations_across_pages() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting_token(None, &page(&["r-1"], Some("token-1"))),
+            expecting_token(Some("token-1"), &page(&["r-2", "r-3"], None)),
+        ]);
+        let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let ids: Vec<_> = client
+            .describe_instances_stream(DescribeInstancesRequest::default())
+            .map_ok(|reservation| reservation.reservation_id.unwrap())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec!["r-1", "r-2", "r-3"]);
+    }
+}
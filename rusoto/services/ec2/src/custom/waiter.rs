@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use rusoto_core::waiter::{error_code, wait, WaiterConfig, WaiterError, WaiterState};
+
+use crate::generated::{
+    DescribeInstancesError, DescribeInstancesRequest, DescribeInstancesResult, Ec2, Ec2Client,
+};
+
+/// The delay and attempts of the EC2 waiters unless they're given a config.
+fn default_config() -> WaiterConfig {
+    WaiterConfig::new(Duration::from_secs(15), 40)
+}
+
+/// The state names of all instances in `result`.
+fn instance_states(result: &DescribeInstancesResult) -> Vec<&str> {
+    result
+        .reservations
+        .iter()
+        .flatten()
+        .flat_map(|reservation| reservation.instances.iter().flatten())
+        .map(|instance| {
+            instance
+                .state
+                .as_ref()
+                .and_then(|state| state.name.as_deref())
+                .unwrap_or("")
+        })
+        .collect()
+}
+
+/// Succeeds once all instances are in `desired`, and fails once any is in one of `failures`.
+fn match_states(
+    result: DescribeInstancesResult,
+    desired: &str,
+    failures: &[&str],
+) -> WaiterState<DescribeInstancesResult> {
+    let states = instance_states(&result);
+    if let Some(state) = states.iter().find(|state| failures.contains(state)) {
+        return WaiterState::Failure(format!("an instance is {}", state));
+    }
+    if !states.is_empty() && states.iter().all(|state| *state == desired) {
+        WaiterState::Success(result)
+    } else {
+        WaiterState::Retry
+    }
+}
+
+// Matchers hand back the `RusotoError`s they don't expect.
+#[allow(clippy::result_large_err)]
+impl Ec2Client {
+    /// Polls `describe_instances` until all instances it describes are running, and returns
+    /// the last response.
+    ///
+    /// Fails once any instance is shutting down, terminated or stopping. Instances that don't
+    /// exist yet (`InvalidInstanceID.NotFound`) are waited for. Without a `config` this polls
+    /// every 15 seconds, up to 40 times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_ec2::{DescribeInstancesRequest, Ec2Client};
+    ///
+    /// # async fn example() {
+    /// let ec2 = Ec2Client::new(Region::UsEast1);
+    /// let request = DescribeInstancesRequest {
+    ///     instance_ids: Some(vec!["i-1234567890abcdef0".to_owned()]),
+    ///     ..Default::default()
+    /// };
+    /// ec2.wait_until_instance_running(request, None).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn wait_until_instance_running(
+        &self,
+        request: DescribeInstancesRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<DescribeInstancesResult, WaiterError<DescribeInstancesError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_instances(request.clone()),
+            |result| match result {
+                Ok(result) => Ok(match_states(
+                    result,
+                    "running",
+                    &["shutting-down", "terminated", "stopping"],
+                )),
+                Err(ref err)
+                    if error_code(err).as_deref() == Some("InvalidInstanceID.NotFound") =>
+                {
+                    Ok(WaiterState::Retry)
+                }
+                Err(err) => Err(err),
+            },
+        )
+        .await
+    }
+
+    /// Polls `describe_instances` until all instances it describes are stopped, see
+    /// `wait_until_instance_running`.
+    ///
+    /// Fails once any instance is pending or terminated.
+    pub async fn wait_until_instance_stopped(
+        &self,
+        request: DescribeInstancesRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<DescribeInstancesResult, WaiterError<DescribeInstancesError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_instances(request.clone()),
+            |result| {
+                result.map(|result| match_states(result, "stopped", &["pending", "terminated"]))
+            },
+        )
+        .await
+    }
+
+    /// Polls `describe_instances` until all instances it describes are terminated, see
+    /// `wait_until_instance_running`.
+    ///
+    /// Fails once any instance is pending or stopping.
+    pub async fn wait_until_instance_terminated(
+        &self,
+        request: DescribeInstancesRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<DescribeInstancesResult, WaiterError<DescribeInstancesError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.describe_instances(request.clone()),
+            |result| {
+                result.map(|result| match_states(result, "terminated", &["pending", "stopping"]))
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::{Region, RusotoError};
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn quick(max_attempts: u32) -> Option<WaiterConfig> {
+        Some(WaiterConfig::new(Duration::from_millis(1), max_attempts))
+    }
+
+    fn described(states: &[&str]) -> MockRequestDispatcher {
+        let instances: String = states
+            .iter()
+            .map(|state| {
+                format!(
+                    "<item><instanceId>i-1</instanceId><instanceState><name>{}</name></instanceState></item>",
+                    state
+                )
+            })
+            .collect();
+        MockRequestDispatcher::with_status(200).with_body(&format!(
+            r#"<DescribeInstancesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+  <reservationSet><item><reservationId>r-1</reservationId><instancesSet>{}</instancesSet></item></reservationSet>
+</DescribeInstancesResponse>"#,
+            instances
+        ))
+    }
+
+    fn not_found() -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(400).with_body(
+            "<Response><Errors><Error><Code>InvalidInstanceID.NotFound</Code>\
+             <Message>The instance ID 'i-1' does not exist</Message></Error></Errors></Response>",
+        )
+    }
+
+    #[tokio::test]
+    async fn instance_running_waits_for_pending_and_missing_instances() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            not_found(),
+            described(&["pending", "running"]),
+            described(&["running", "running"]),
+        ]);
+        let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let result = client
+            .wait_until_instance_running(DescribeInstancesRequest::default(), quick(3))
+            .await
+            .unwrap();
+
+        assert_eq!(instance_states(&result), vec!["running", "running"]);
+    }
+
+    #[tokio::test]
+    async fn instance_running_fails_once_an_instance_terminates() {
+        let mock = MultipleMockRequestDispatcher::new(vec![described(&["running", "terminated"])]);
+        let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let result = client
+            .wait_until_instance_running(DescribeInstancesRequest::default(), quick(3))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(WaiterError::Failure("an instance is terminated".to_owned()))
+        );
+    }
+
+    #[tokio::test]
+    async fn instance_stopped_fails_on_missing_instances() {
+        let mock = not_found();
+        let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let result = client
+            .wait_until_instance_stopped(DescribeInstancesRequest::default(), quick(3))
+            .await;
+
+        match result {
+            Err(WaiterError::Request(RusotoError::Unknown(_))) => {}
+            other => panic!("expected a request error, got {:?}", other),
+        }
+    }
+}
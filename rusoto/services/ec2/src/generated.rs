@@ -52,7 +52,7 @@ impl Ec2Client {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -66459,6 +66459,11 @@ impl AcceptReservedInstancesExchangeQuoteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AcceptReservedInstancesExchangeQuoteError {
     #[allow(unused_variables)]
@@ -66496,6 +66501,11 @@ impl AcceptTransitGatewayPeeringAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AcceptTransitGatewayPeeringAttachmentError {
     #[allow(unused_variables)]
@@ -66533,6 +66543,11 @@ impl AcceptTransitGatewayVpcAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AcceptTransitGatewayVpcAttachmentError {
     #[allow(unused_variables)]
@@ -66570,6 +66585,11 @@ impl AcceptVpcEndpointConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AcceptVpcEndpointConnectionsError {
     #[allow(unused_variables)]
@@ -66607,6 +66627,11 @@ impl AcceptVpcPeeringConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AcceptVpcPeeringConnectionError {
     #[allow(unused_variables)]
@@ -66642,6 +66667,11 @@ impl AdvertiseByoipCidrError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AdvertiseByoipCidrError {
     #[allow(unused_variables)]
@@ -66677,6 +66707,11 @@ impl AllocateAddressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AllocateAddressError {
     #[allow(unused_variables)]
@@ -66712,6 +66747,11 @@ impl AllocateHostsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AllocateHostsError {
     #[allow(unused_variables)]
@@ -66749,6 +66789,11 @@ impl ApplySecurityGroupsToClientVpnTargetNetworkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ApplySecurityGroupsToClientVpnTargetNetworkError {
     #[allow(unused_variables)]
@@ -66784,6 +66829,11 @@ impl AssignIpv6AddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssignIpv6AddressesError {
     #[allow(unused_variables)]
@@ -66819,6 +66869,11 @@ impl AssignPrivateIpAddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssignPrivateIpAddressesError {
     #[allow(unused_variables)]
@@ -66854,6 +66909,11 @@ impl AssociateAddressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateAddressError {
     #[allow(unused_variables)]
@@ -66891,6 +66951,11 @@ impl AssociateClientVpnTargetNetworkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateClientVpnTargetNetworkError {
     #[allow(unused_variables)]
@@ -66926,6 +66991,11 @@ impl AssociateDhcpOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateDhcpOptionsError {
     #[allow(unused_variables)]
@@ -66963,6 +67033,11 @@ impl AssociateIamInstanceProfileError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateIamInstanceProfileError {
     #[allow(unused_variables)]
@@ -66998,6 +67073,11 @@ impl AssociateRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateRouteTableError {
     #[allow(unused_variables)]
@@ -67033,6 +67113,11 @@ impl AssociateSubnetCidrBlockError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateSubnetCidrBlockError {
     #[allow(unused_variables)]
@@ -67070,6 +67155,11 @@ impl AssociateTransitGatewayMulticastDomainError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateTransitGatewayMulticastDomainError {
     #[allow(unused_variables)]
@@ -67107,6 +67197,11 @@ impl AssociateTransitGatewayRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateTransitGatewayRouteTableError {
     #[allow(unused_variables)]
@@ -67142,6 +67237,11 @@ impl AssociateVpcCidrBlockError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AssociateVpcCidrBlockError {
     #[allow(unused_variables)]
@@ -67177,6 +67277,11 @@ impl AttachClassicLinkVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AttachClassicLinkVpcError {
     #[allow(unused_variables)]
@@ -67212,6 +67317,11 @@ impl AttachInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AttachInternetGatewayError {
     #[allow(unused_variables)]
@@ -67247,6 +67357,11 @@ impl AttachNetworkInterfaceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AttachNetworkInterfaceError {
     #[allow(unused_variables)]
@@ -67282,6 +67397,11 @@ impl AttachVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AttachVolumeError {
     #[allow(unused_variables)]
@@ -67317,6 +67437,11 @@ impl AttachVpnGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AttachVpnGatewayError {
     #[allow(unused_variables)]
@@ -67352,6 +67477,11 @@ impl AuthorizeClientVpnIngressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AuthorizeClientVpnIngressError {
     #[allow(unused_variables)]
@@ -67389,6 +67519,11 @@ impl AuthorizeSecurityGroupEgressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AuthorizeSecurityGroupEgressError {
     #[allow(unused_variables)]
@@ -67426,6 +67561,11 @@ impl AuthorizeSecurityGroupIngressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for AuthorizeSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -67461,6 +67601,11 @@ impl BundleInstanceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for BundleInstanceError {
     #[allow(unused_variables)]
@@ -67496,6 +67641,11 @@ impl CancelBundleTaskError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelBundleTaskError {
     #[allow(unused_variables)]
@@ -67531,6 +67681,11 @@ impl CancelCapacityReservationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelCapacityReservationError {
     #[allow(unused_variables)]
@@ -67566,6 +67721,11 @@ impl CancelConversionTaskError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelConversionTaskError {
     #[allow(unused_variables)]
@@ -67601,6 +67761,11 @@ impl CancelExportTaskError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelExportTaskError {
     #[allow(unused_variables)]
@@ -67636,6 +67801,11 @@ impl CancelImportTaskError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelImportTaskError {
     #[allow(unused_variables)]
@@ -67673,6 +67843,11 @@ impl CancelReservedInstancesListingError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelReservedInstancesListingError {
     #[allow(unused_variables)]
@@ -67710,6 +67885,11 @@ impl EC2CancelSpotFleetRequestsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EC2CancelSpotFleetRequestsError {
     #[allow(unused_variables)]
@@ -67747,6 +67927,11 @@ impl CancelSpotInstanceRequestsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CancelSpotInstanceRequestsError {
     #[allow(unused_variables)]
@@ -67782,6 +67967,11 @@ impl ConfirmProductInstanceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ConfirmProductInstanceError {
     #[allow(unused_variables)]
@@ -67817,6 +68007,11 @@ impl CopyFpgaImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CopyFpgaImageError {
     #[allow(unused_variables)]
@@ -67852,6 +68047,11 @@ impl CopyImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CopyImageError {
     #[allow(unused_variables)]
@@ -67887,6 +68087,11 @@ impl CopySnapshotError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CopySnapshotError {
     #[allow(unused_variables)]
@@ -67922,6 +68127,11 @@ impl CreateCapacityReservationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateCapacityReservationError {
     #[allow(unused_variables)]
@@ -67957,6 +68167,11 @@ impl CreateClientVpnEndpointError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateClientVpnEndpointError {
     #[allow(unused_variables)]
@@ -67992,6 +68207,11 @@ impl CreateClientVpnRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateClientVpnRouteError {
     #[allow(unused_variables)]
@@ -68027,6 +68247,11 @@ impl CreateCustomerGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateCustomerGatewayError {
     #[allow(unused_variables)]
@@ -68062,6 +68287,11 @@ impl CreateDefaultSubnetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateDefaultSubnetError {
     #[allow(unused_variables)]
@@ -68097,6 +68327,11 @@ impl CreateDefaultVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateDefaultVpcError {
     #[allow(unused_variables)]
@@ -68132,6 +68367,11 @@ impl CreateDhcpOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateDhcpOptionsError {
     #[allow(unused_variables)]
@@ -68169,6 +68409,11 @@ impl CreateEgressOnlyInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateEgressOnlyInternetGatewayError {
     #[allow(unused_variables)]
@@ -68204,6 +68449,11 @@ impl CreateFleetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateFleetError {
     #[allow(unused_variables)]
@@ -68239,6 +68489,11 @@ impl CreateFlowLogsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateFlowLogsError {
     #[allow(unused_variables)]
@@ -68274,6 +68529,11 @@ impl CreateFpgaImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateFpgaImageError {
     #[allow(unused_variables)]
@@ -68309,6 +68569,11 @@ impl CreateImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateImageError {
     #[allow(unused_variables)]
@@ -68344,6 +68609,11 @@ impl CreateInstanceExportTaskError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateInstanceExportTaskError {
     #[allow(unused_variables)]
@@ -68379,6 +68649,11 @@ impl CreateInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateInternetGatewayError {
     #[allow(unused_variables)]
@@ -68414,6 +68689,11 @@ impl CreateKeyPairError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateKeyPairError {
     #[allow(unused_variables)]
@@ -68449,6 +68729,11 @@ impl CreateLaunchTemplateError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateLaunchTemplateError {
     #[allow(unused_variables)]
@@ -68486,6 +68771,11 @@ impl CreateLaunchTemplateVersionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateLaunchTemplateVersionError {
     #[allow(unused_variables)]
@@ -68521,6 +68811,11 @@ impl CreateLocalGatewayRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateLocalGatewayRouteError {
     #[allow(unused_variables)]
@@ -68558,6 +68853,11 @@ impl CreateLocalGatewayRouteTableVpcAssociationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateLocalGatewayRouteTableVpcAssociationError {
     #[allow(unused_variables)]
@@ -68593,6 +68893,11 @@ impl CreateManagedPrefixListError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateManagedPrefixListError {
     #[allow(unused_variables)]
@@ -68628,6 +68933,11 @@ impl CreateNatGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateNatGatewayError {
     #[allow(unused_variables)]
@@ -68663,6 +68973,11 @@ impl CreateNetworkAclError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateNetworkAclError {
     #[allow(unused_variables)]
@@ -68698,6 +69013,11 @@ impl CreateNetworkAclEntryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateNetworkAclEntryError {
     #[allow(unused_variables)]
@@ -68733,6 +69053,11 @@ impl CreateNetworkInterfaceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateNetworkInterfaceError {
     #[allow(unused_variables)]
@@ -68770,6 +69095,11 @@ impl CreateNetworkInterfacePermissionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateNetworkInterfacePermissionError {
     #[allow(unused_variables)]
@@ -68805,6 +69135,11 @@ impl CreatePlacementGroupError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreatePlacementGroupError {
     #[allow(unused_variables)]
@@ -68842,6 +69177,11 @@ impl CreateReservedInstancesListingError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateReservedInstancesListingError {
     #[allow(unused_variables)]
@@ -68877,6 +69217,11 @@ impl CreateRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateRouteError {
     #[allow(unused_variables)]
@@ -68912,6 +69257,11 @@ impl CreateRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateRouteTableError {
     #[allow(unused_variables)]
@@ -68947,6 +69297,11 @@ impl CreateSecurityGroupError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateSecurityGroupError {
     #[allow(unused_variables)]
@@ -68982,6 +69337,11 @@ impl CreateSnapshotError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateSnapshotError {
     #[allow(unused_variables)]
@@ -69017,6 +69377,11 @@ impl CreateSnapshotsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateSnapshotsError {
     #[allow(unused_variables)]
@@ -69054,6 +69419,11 @@ impl CreateSpotDatafeedSubscriptionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateSpotDatafeedSubscriptionError {
     #[allow(unused_variables)]
@@ -69089,6 +69459,11 @@ impl CreateSubnetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateSubnetError {
     #[allow(unused_variables)]
@@ -69124,6 +69499,11 @@ impl CreateTagsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTagsError {
     #[allow(unused_variables)]
@@ -69159,6 +69539,11 @@ impl CreateTrafficMirrorFilterError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTrafficMirrorFilterError {
     #[allow(unused_variables)]
@@ -69196,6 +69581,11 @@ impl CreateTrafficMirrorFilterRuleError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTrafficMirrorFilterRuleError {
     #[allow(unused_variables)]
@@ -69233,6 +69623,11 @@ impl CreateTrafficMirrorSessionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTrafficMirrorSessionError {
     #[allow(unused_variables)]
@@ -69268,6 +69663,11 @@ impl CreateTrafficMirrorTargetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTrafficMirrorTargetError {
     #[allow(unused_variables)]
@@ -69303,6 +69703,11 @@ impl CreateTransitGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayError {
     #[allow(unused_variables)]
@@ -69340,6 +69745,11 @@ impl CreateTransitGatewayMulticastDomainError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayMulticastDomainError {
     #[allow(unused_variables)]
@@ -69377,6 +69787,11 @@ impl CreateTransitGatewayPeeringAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayPeeringAttachmentError {
     #[allow(unused_variables)]
@@ -69412,6 +69827,11 @@ impl CreateTransitGatewayRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayRouteError {
     #[allow(unused_variables)]
@@ -69449,6 +69869,11 @@ impl CreateTransitGatewayRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayRouteTableError {
     #[allow(unused_variables)]
@@ -69486,6 +69911,11 @@ impl CreateTransitGatewayVpcAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateTransitGatewayVpcAttachmentError {
     #[allow(unused_variables)]
@@ -69521,6 +69951,11 @@ impl CreateVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVolumeError {
     #[allow(unused_variables)]
@@ -69556,6 +69991,11 @@ impl CreateVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpcError {
     #[allow(unused_variables)]
@@ -69591,6 +70031,11 @@ impl CreateVpcEndpointError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpcEndpointError {
     #[allow(unused_variables)]
@@ -69628,6 +70073,11 @@ impl CreateVpcEndpointConnectionNotificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpcEndpointConnectionNotificationError {
     #[allow(unused_variables)]
@@ -69665,6 +70115,11 @@ impl CreateVpcEndpointServiceConfigurationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpcEndpointServiceConfigurationError {
     #[allow(unused_variables)]
@@ -69702,6 +70157,11 @@ impl CreateVpcPeeringConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpcPeeringConnectionError {
     #[allow(unused_variables)]
@@ -69737,6 +70197,11 @@ impl CreateVpnConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpnConnectionError {
     #[allow(unused_variables)]
@@ -69772,6 +70237,11 @@ impl CreateVpnConnectionRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpnConnectionRouteError {
     #[allow(unused_variables)]
@@ -69807,6 +70277,11 @@ impl CreateVpnGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateVpnGatewayError {
     #[allow(unused_variables)]
@@ -69842,6 +70317,11 @@ impl DeleteClientVpnEndpointError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteClientVpnEndpointError {
     #[allow(unused_variables)]
@@ -69877,6 +70357,11 @@ impl DeleteClientVpnRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteClientVpnRouteError {
     #[allow(unused_variables)]
@@ -69912,6 +70397,11 @@ impl DeleteCustomerGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteCustomerGatewayError {
     #[allow(unused_variables)]
@@ -69947,6 +70437,11 @@ impl DeleteDhcpOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteDhcpOptionsError {
     #[allow(unused_variables)]
@@ -69984,6 +70479,11 @@ impl DeleteEgressOnlyInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteEgressOnlyInternetGatewayError {
     #[allow(unused_variables)]
@@ -70019,6 +70519,11 @@ impl DeleteFleetsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteFleetsError {
     #[allow(unused_variables)]
@@ -70054,6 +70559,11 @@ impl DeleteFlowLogsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteFlowLogsError {
     #[allow(unused_variables)]
@@ -70089,6 +70599,11 @@ impl DeleteFpgaImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteFpgaImageError {
     #[allow(unused_variables)]
@@ -70124,6 +70639,11 @@ impl DeleteInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteInternetGatewayError {
     #[allow(unused_variables)]
@@ -70159,6 +70679,11 @@ impl DeleteKeyPairError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteKeyPairError {
     #[allow(unused_variables)]
@@ -70194,6 +70719,11 @@ impl DeleteLaunchTemplateError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteLaunchTemplateError {
     #[allow(unused_variables)]
@@ -70231,6 +70761,11 @@ impl DeleteLaunchTemplateVersionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteLaunchTemplateVersionsError {
     #[allow(unused_variables)]
@@ -70266,6 +70801,11 @@ impl DeleteLocalGatewayRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteLocalGatewayRouteError {
     #[allow(unused_variables)]
@@ -70303,6 +70843,11 @@ impl DeleteLocalGatewayRouteTableVpcAssociationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteLocalGatewayRouteTableVpcAssociationError {
     #[allow(unused_variables)]
@@ -70338,6 +70883,11 @@ impl DeleteManagedPrefixListError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteManagedPrefixListError {
     #[allow(unused_variables)]
@@ -70373,6 +70923,11 @@ impl DeleteNatGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteNatGatewayError {
     #[allow(unused_variables)]
@@ -70408,6 +70963,11 @@ impl DeleteNetworkAclError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteNetworkAclError {
     #[allow(unused_variables)]
@@ -70443,6 +71003,11 @@ impl DeleteNetworkAclEntryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteNetworkAclEntryError {
     #[allow(unused_variables)]
@@ -70478,6 +71043,11 @@ impl DeleteNetworkInterfaceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteNetworkInterfaceError {
     #[allow(unused_variables)]
@@ -70515,6 +71085,11 @@ impl DeleteNetworkInterfacePermissionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteNetworkInterfacePermissionError {
     #[allow(unused_variables)]
@@ -70550,6 +71125,11 @@ impl DeletePlacementGroupError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeletePlacementGroupError {
     #[allow(unused_variables)]
@@ -70587,6 +71167,11 @@ impl DeleteQueuedReservedInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteQueuedReservedInstancesError {
     #[allow(unused_variables)]
@@ -70622,6 +71207,11 @@ impl DeleteRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteRouteError {
     #[allow(unused_variables)]
@@ -70657,6 +71247,11 @@ impl DeleteRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteRouteTableError {
     #[allow(unused_variables)]
@@ -70692,6 +71287,11 @@ impl DeleteSecurityGroupError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteSecurityGroupError {
     #[allow(unused_variables)]
@@ -70727,6 +71327,11 @@ impl DeleteSnapshotError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteSnapshotError {
     #[allow(unused_variables)]
@@ -70764,6 +71369,11 @@ impl DeleteSpotDatafeedSubscriptionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteSpotDatafeedSubscriptionError {
     #[allow(unused_variables)]
@@ -70799,6 +71409,11 @@ impl DeleteSubnetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteSubnetError {
     #[allow(unused_variables)]
@@ -70834,6 +71449,11 @@ impl DeleteTagsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTagsError {
     #[allow(unused_variables)]
@@ -70869,6 +71489,11 @@ impl DeleteTrafficMirrorFilterError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTrafficMirrorFilterError {
     #[allow(unused_variables)]
@@ -70906,6 +71531,11 @@ impl DeleteTrafficMirrorFilterRuleError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTrafficMirrorFilterRuleError {
     #[allow(unused_variables)]
@@ -70943,6 +71573,11 @@ impl DeleteTrafficMirrorSessionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTrafficMirrorSessionError {
     #[allow(unused_variables)]
@@ -70978,6 +71613,11 @@ impl DeleteTrafficMirrorTargetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTrafficMirrorTargetError {
     #[allow(unused_variables)]
@@ -71013,6 +71653,11 @@ impl DeleteTransitGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayError {
     #[allow(unused_variables)]
@@ -71050,6 +71695,11 @@ impl DeleteTransitGatewayMulticastDomainError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayMulticastDomainError {
     #[allow(unused_variables)]
@@ -71087,6 +71737,11 @@ impl DeleteTransitGatewayPeeringAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayPeeringAttachmentError {
     #[allow(unused_variables)]
@@ -71122,6 +71777,11 @@ impl DeleteTransitGatewayRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayRouteError {
     #[allow(unused_variables)]
@@ -71159,6 +71819,11 @@ impl DeleteTransitGatewayRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayRouteTableError {
     #[allow(unused_variables)]
@@ -71196,6 +71861,11 @@ impl DeleteTransitGatewayVpcAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTransitGatewayVpcAttachmentError {
     #[allow(unused_variables)]
@@ -71231,6 +71901,11 @@ impl DeleteVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVolumeError {
     #[allow(unused_variables)]
@@ -71266,6 +71941,11 @@ impl DeleteVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpcError {
     #[allow(unused_variables)]
@@ -71303,6 +71983,11 @@ impl DeleteVpcEndpointConnectionNotificationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpcEndpointConnectionNotificationsError {
     #[allow(unused_variables)]
@@ -71340,6 +72025,11 @@ impl DeleteVpcEndpointServiceConfigurationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpcEndpointServiceConfigurationsError {
     #[allow(unused_variables)]
@@ -71375,6 +72065,11 @@ impl DeleteVpcEndpointsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpcEndpointsError {
     #[allow(unused_variables)]
@@ -71412,6 +72107,11 @@ impl DeleteVpcPeeringConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpcPeeringConnectionError {
     #[allow(unused_variables)]
@@ -71447,6 +72147,11 @@ impl DeleteVpnConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpnConnectionError {
     #[allow(unused_variables)]
@@ -71482,6 +72187,11 @@ impl DeleteVpnConnectionRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpnConnectionRouteError {
     #[allow(unused_variables)]
@@ -71517,6 +72227,11 @@ impl DeleteVpnGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVpnGatewayError {
     #[allow(unused_variables)]
@@ -71552,6 +72267,11 @@ impl DeprovisionByoipCidrError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeprovisionByoipCidrError {
     #[allow(unused_variables)]
@@ -71587,6 +72307,11 @@ impl DeregisterImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeregisterImageError {
     #[allow(unused_variables)]
@@ -71624,6 +72349,11 @@ impl DeregisterInstanceEventNotificationAttributesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeregisterInstanceEventNotificationAttributesError {
     #[allow(unused_variables)]
@@ -71661,6 +72391,11 @@ impl DeregisterTransitGatewayMulticastGroupMembersError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeregisterTransitGatewayMulticastGroupMembersError {
     #[allow(unused_variables)]
@@ -71698,6 +72433,11 @@ impl DeregisterTransitGatewayMulticastGroupSourcesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeregisterTransitGatewayMulticastGroupSourcesError {
     #[allow(unused_variables)]
@@ -71733,6 +72473,11 @@ impl DescribeAccountAttributesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
@@ -71768,6 +72513,11 @@ impl DescribeAddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAddressesError {
     #[allow(unused_variables)]
@@ -71803,6 +72553,11 @@ impl DescribeAggregateIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAggregateIdFormatError {
     #[allow(unused_variables)]
@@ -71838,6 +72593,11 @@ impl DescribeAvailabilityZonesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAvailabilityZonesError {
     #[allow(unused_variables)]
@@ -71873,6 +72633,11 @@ impl DescribeBundleTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeBundleTasksError {
     #[allow(unused_variables)]
@@ -71908,6 +72673,11 @@ impl DescribeByoipCidrsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeByoipCidrsError {
     #[allow(unused_variables)]
@@ -71945,6 +72715,11 @@ impl DescribeCapacityReservationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeCapacityReservationsError {
     #[allow(unused_variables)]
@@ -71982,6 +72757,11 @@ impl DescribeClassicLinkInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClassicLinkInstancesError {
     #[allow(unused_variables)]
@@ -72019,6 +72799,11 @@ impl DescribeClientVpnAuthorizationRulesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClientVpnAuthorizationRulesError {
     #[allow(unused_variables)]
@@ -72056,6 +72841,11 @@ impl DescribeClientVpnConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClientVpnConnectionsError {
     #[allow(unused_variables)]
@@ -72093,6 +72883,11 @@ impl DescribeClientVpnEndpointsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClientVpnEndpointsError {
     #[allow(unused_variables)]
@@ -72128,6 +72923,11 @@ impl DescribeClientVpnRoutesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClientVpnRoutesError {
     #[allow(unused_variables)]
@@ -72165,6 +72965,11 @@ impl DescribeClientVpnTargetNetworksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClientVpnTargetNetworksError {
     #[allow(unused_variables)]
@@ -72200,6 +73005,11 @@ impl DescribeCoipPoolsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeCoipPoolsError {
     #[allow(unused_variables)]
@@ -72235,6 +73045,11 @@ impl DescribeConversionTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeConversionTasksError {
     #[allow(unused_variables)]
@@ -72270,6 +73085,11 @@ impl DescribeCustomerGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeCustomerGatewaysError {
     #[allow(unused_variables)]
@@ -72305,6 +73125,11 @@ impl DescribeDhcpOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeDhcpOptionsError {
     #[allow(unused_variables)]
@@ -72342,6 +73167,11 @@ impl DescribeEgressOnlyInternetGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEgressOnlyInternetGatewaysError {
     #[allow(unused_variables)]
@@ -72377,6 +73207,11 @@ impl DescribeElasticGpusError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeElasticGpusError {
     #[allow(unused_variables)]
@@ -72412,6 +73247,11 @@ impl DescribeExportImageTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeExportImageTasksError {
     #[allow(unused_variables)]
@@ -72447,6 +73287,11 @@ impl DescribeExportTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeExportTasksError {
     #[allow(unused_variables)]
@@ -72484,6 +73329,11 @@ impl DescribeFastSnapshotRestoresError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFastSnapshotRestoresError {
     #[allow(unused_variables)]
@@ -72519,6 +73369,11 @@ impl DescribeFleetHistoryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFleetHistoryError {
     #[allow(unused_variables)]
@@ -72554,6 +73409,11 @@ impl DescribeFleetInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFleetInstancesError {
     #[allow(unused_variables)]
@@ -72589,6 +73449,11 @@ impl DescribeFleetsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFleetsError {
     #[allow(unused_variables)]
@@ -72624,6 +73489,11 @@ impl DescribeFlowLogsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFlowLogsError {
     #[allow(unused_variables)]
@@ -72661,6 +73531,11 @@ impl DescribeFpgaImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFpgaImageAttributeError {
     #[allow(unused_variables)]
@@ -72696,6 +73571,11 @@ impl DescribeFpgaImagesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeFpgaImagesError {
     #[allow(unused_variables)]
@@ -72733,6 +73613,11 @@ impl DescribeHostReservationOfferingsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeHostReservationOfferingsError {
     #[allow(unused_variables)]
@@ -72768,6 +73653,11 @@ impl DescribeHostReservationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeHostReservationsError {
     #[allow(unused_variables)]
@@ -72803,6 +73693,11 @@ impl DescribeHostsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeHostsError {
     #[allow(unused_variables)]
@@ -72840,6 +73735,11 @@ impl DescribeIamInstanceProfileAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeIamInstanceProfileAssociationsError {
     #[allow(unused_variables)]
@@ -72875,6 +73775,11 @@ impl DescribeIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeIdFormatError {
     #[allow(unused_variables)]
@@ -72910,6 +73815,11 @@ impl DescribeIdentityIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeIdentityIdFormatError {
     #[allow(unused_variables)]
@@ -72945,6 +73855,11 @@ impl DescribeImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeImageAttributeError {
     #[allow(unused_variables)]
@@ -72980,6 +73895,11 @@ impl DescribeImagesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeImagesError {
     #[allow(unused_variables)]
@@ -73015,6 +73935,11 @@ impl DescribeImportImageTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeImportImageTasksError {
     #[allow(unused_variables)]
@@ -73052,6 +73977,11 @@ impl DescribeImportSnapshotTasksError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeImportSnapshotTasksError {
     #[allow(unused_variables)]
@@ -73087,6 +74017,11 @@ impl DescribeInstanceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceAttributeError {
     #[allow(unused_variables)]
@@ -73124,6 +74059,11 @@ impl DescribeInstanceCreditSpecificationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceCreditSpecificationsError {
     #[allow(unused_variables)]
@@ -73161,6 +74101,11 @@ impl DescribeInstanceEventNotificationAttributesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceEventNotificationAttributesError {
     #[allow(unused_variables)]
@@ -73196,6 +74141,11 @@ impl DescribeInstanceStatusError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceStatusError {
     #[allow(unused_variables)]
@@ -73233,6 +74183,11 @@ impl DescribeInstanceTypeOfferingsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceTypeOfferingsError {
     #[allow(unused_variables)]
@@ -73268,6 +74223,11 @@ impl DescribeInstanceTypesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstanceTypesError {
     #[allow(unused_variables)]
@@ -73303,6 +74263,11 @@ impl DescribeInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInstancesError {
     #[allow(unused_variables)]
@@ -73338,6 +74303,11 @@ impl DescribeInternetGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeInternetGatewaysError {
     #[allow(unused_variables)]
@@ -73373,6 +74343,11 @@ impl DescribeIpv6PoolsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeIpv6PoolsError {
     #[allow(unused_variables)]
@@ -73408,6 +74383,11 @@ impl DescribeKeyPairsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeKeyPairsError {
     #[allow(unused_variables)]
@@ -73445,6 +74425,11 @@ impl DescribeLaunchTemplateVersionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLaunchTemplateVersionsError {
     #[allow(unused_variables)]
@@ -73480,6 +74465,11 @@ impl DescribeLaunchTemplatesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLaunchTemplatesError {
     #[allow(unused_variables)]
@@ -73517,6 +74507,11 @@ impl DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError {
     #[allow(unused_variables)]
@@ -73554,6 +74549,11 @@ impl DescribeLocalGatewayRouteTableVpcAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewayRouteTableVpcAssociationsError {
     #[allow(unused_variables)]
@@ -73591,6 +74591,11 @@ impl DescribeLocalGatewayRouteTablesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewayRouteTablesError {
     #[allow(unused_variables)]
@@ -73628,6 +74633,11 @@ impl DescribeLocalGatewayVirtualInterfaceGroupsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewayVirtualInterfaceGroupsError {
     #[allow(unused_variables)]
@@ -73665,6 +74675,11 @@ impl DescribeLocalGatewayVirtualInterfacesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewayVirtualInterfacesError {
     #[allow(unused_variables)]
@@ -73700,6 +74715,11 @@ impl DescribeLocalGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeLocalGatewaysError {
     #[allow(unused_variables)]
@@ -73737,6 +74757,11 @@ impl DescribeManagedPrefixListsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeManagedPrefixListsError {
     #[allow(unused_variables)]
@@ -73772,6 +74797,11 @@ impl DescribeMovingAddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeMovingAddressesError {
     #[allow(unused_variables)]
@@ -73807,6 +74837,11 @@ impl DescribeNatGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeNatGatewaysError {
     #[allow(unused_variables)]
@@ -73842,6 +74877,11 @@ impl DescribeNetworkAclsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeNetworkAclsError {
     #[allow(unused_variables)]
@@ -73879,6 +74919,11 @@ impl DescribeNetworkInterfaceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeNetworkInterfaceAttributeError {
     #[allow(unused_variables)]
@@ -73916,6 +74961,11 @@ impl DescribeNetworkInterfacePermissionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeNetworkInterfacePermissionsError {
     #[allow(unused_variables)]
@@ -73951,6 +75001,11 @@ impl DescribeNetworkInterfacesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeNetworkInterfacesError {
     #[allow(unused_variables)]
@@ -73986,6 +75041,11 @@ impl DescribePlacementGroupsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribePlacementGroupsError {
     #[allow(unused_variables)]
@@ -74021,6 +75081,11 @@ impl DescribePrefixListsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribePrefixListsError {
     #[allow(unused_variables)]
@@ -74056,6 +75121,11 @@ impl DescribePrincipalIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribePrincipalIdFormatError {
     #[allow(unused_variables)]
@@ -74091,6 +75161,11 @@ impl DescribePublicIpv4PoolsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribePublicIpv4PoolsError {
     #[allow(unused_variables)]
@@ -74126,6 +75201,11 @@ impl DescribeRegionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeRegionsError {
     #[allow(unused_variables)]
@@ -74161,6 +75241,11 @@ impl DescribeReservedInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeReservedInstancesError {
     #[allow(unused_variables)]
@@ -74198,6 +75283,11 @@ impl DescribeReservedInstancesListingsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeReservedInstancesListingsError {
     #[allow(unused_variables)]
@@ -74235,6 +75325,11 @@ impl DescribeReservedInstancesModificationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeReservedInstancesModificationsError {
     #[allow(unused_variables)]
@@ -74272,6 +75367,11 @@ impl DescribeReservedInstancesOfferingsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeReservedInstancesOfferingsError {
     #[allow(unused_variables)]
@@ -74307,6 +75407,11 @@ impl DescribeRouteTablesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeRouteTablesError {
     #[allow(unused_variables)]
@@ -74344,6 +75449,11 @@ impl DescribeScheduledInstanceAvailabilityError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeScheduledInstanceAvailabilityError {
     #[allow(unused_variables)]
@@ -74381,6 +75491,11 @@ impl DescribeScheduledInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeScheduledInstancesError {
     #[allow(unused_variables)]
@@ -74418,6 +75533,11 @@ impl DescribeSecurityGroupReferencesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSecurityGroupReferencesError {
     #[allow(unused_variables)]
@@ -74453,6 +75573,11 @@ impl DescribeSecurityGroupsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSecurityGroupsError {
     #[allow(unused_variables)]
@@ -74488,6 +75613,11 @@ impl DescribeSnapshotAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -74523,6 +75653,11 @@ impl DescribeSnapshotsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSnapshotsError {
     #[allow(unused_variables)]
@@ -74560,6 +75695,11 @@ impl DescribeSpotDatafeedSubscriptionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotDatafeedSubscriptionError {
     #[allow(unused_variables)]
@@ -74597,6 +75737,11 @@ impl DescribeSpotFleetInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotFleetInstancesError {
     #[allow(unused_variables)]
@@ -74634,6 +75779,11 @@ impl DescribeSpotFleetRequestHistoryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotFleetRequestHistoryError {
     #[allow(unused_variables)]
@@ -74669,6 +75819,11 @@ impl DescribeSpotFleetRequestsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotFleetRequestsError {
     #[allow(unused_variables)]
@@ -74706,6 +75861,11 @@ impl DescribeSpotInstanceRequestsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotInstanceRequestsError {
     #[allow(unused_variables)]
@@ -74741,6 +75901,11 @@ impl DescribeSpotPriceHistoryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSpotPriceHistoryError {
     #[allow(unused_variables)]
@@ -74778,6 +75943,11 @@ impl DescribeStaleSecurityGroupsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStaleSecurityGroupsError {
     #[allow(unused_variables)]
@@ -74813,6 +75983,11 @@ impl DescribeSubnetsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSubnetsError {
     #[allow(unused_variables)]
@@ -74848,6 +76023,11 @@ impl DescribeTagsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTagsError {
     #[allow(unused_variables)]
@@ -74885,6 +76065,11 @@ impl DescribeTrafficMirrorFiltersError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTrafficMirrorFiltersError {
     #[allow(unused_variables)]
@@ -74922,6 +76107,11 @@ impl DescribeTrafficMirrorSessionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTrafficMirrorSessionsError {
     #[allow(unused_variables)]
@@ -74959,6 +76149,11 @@ impl DescribeTrafficMirrorTargetsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTrafficMirrorTargetsError {
     #[allow(unused_variables)]
@@ -74996,6 +76191,11 @@ impl DescribeTransitGatewayAttachmentsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewayAttachmentsError {
     #[allow(unused_variables)]
@@ -75033,6 +76233,11 @@ impl DescribeTransitGatewayMulticastDomainsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewayMulticastDomainsError {
     #[allow(unused_variables)]
@@ -75070,6 +76275,11 @@ impl DescribeTransitGatewayPeeringAttachmentsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewayPeeringAttachmentsError {
     #[allow(unused_variables)]
@@ -75107,6 +76317,11 @@ impl DescribeTransitGatewayRouteTablesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewayRouteTablesError {
     #[allow(unused_variables)]
@@ -75144,6 +76359,11 @@ impl DescribeTransitGatewayVpcAttachmentsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewayVpcAttachmentsError {
     #[allow(unused_variables)]
@@ -75179,6 +76399,11 @@ impl DescribeTransitGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeTransitGatewaysError {
     #[allow(unused_variables)]
@@ -75214,6 +76439,11 @@ impl DescribeVolumeAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVolumeAttributeError {
     #[allow(unused_variables)]
@@ -75249,6 +76479,11 @@ impl DescribeVolumeStatusError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVolumeStatusError {
     #[allow(unused_variables)]
@@ -75284,6 +76519,11 @@ impl DescribeVolumesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVolumesError {
     #[allow(unused_variables)]
@@ -75321,6 +76561,11 @@ impl DescribeVolumesModificationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVolumesModificationsError {
     #[allow(unused_variables)]
@@ -75356,6 +76601,11 @@ impl DescribeVpcAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcAttributeError {
     #[allow(unused_variables)]
@@ -75391,6 +76641,11 @@ impl DescribeVpcClassicLinkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcClassicLinkError {
     #[allow(unused_variables)]
@@ -75428,6 +76683,11 @@ impl DescribeVpcClassicLinkDnsSupportError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcClassicLinkDnsSupportError {
     #[allow(unused_variables)]
@@ -75465,6 +76725,11 @@ impl DescribeVpcEndpointConnectionNotificationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointConnectionNotificationsError {
     #[allow(unused_variables)]
@@ -75502,6 +76767,11 @@ impl DescribeVpcEndpointConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointConnectionsError {
     #[allow(unused_variables)]
@@ -75539,6 +76809,11 @@ impl DescribeVpcEndpointServiceConfigurationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointServiceConfigurationsError {
     #[allow(unused_variables)]
@@ -75576,6 +76851,11 @@ impl DescribeVpcEndpointServicePermissionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointServicePermissionsError {
     #[allow(unused_variables)]
@@ -75613,6 +76893,11 @@ impl DescribeVpcEndpointServicesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointServicesError {
     #[allow(unused_variables)]
@@ -75648,6 +76933,11 @@ impl DescribeVpcEndpointsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcEndpointsError {
     #[allow(unused_variables)]
@@ -75685,6 +76975,11 @@ impl DescribeVpcPeeringConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcPeeringConnectionsError {
     #[allow(unused_variables)]
@@ -75720,6 +77015,11 @@ impl DescribeVpcsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpcsError {
     #[allow(unused_variables)]
@@ -75755,6 +77055,11 @@ impl DescribeVpnConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpnConnectionsError {
     #[allow(unused_variables)]
@@ -75790,6 +77095,11 @@ impl DescribeVpnGatewaysError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeVpnGatewaysError {
     #[allow(unused_variables)]
@@ -75825,6 +77135,11 @@ impl DetachClassicLinkVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetachClassicLinkVpcError {
     #[allow(unused_variables)]
@@ -75860,6 +77175,11 @@ impl DetachInternetGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetachInternetGatewayError {
     #[allow(unused_variables)]
@@ -75895,6 +77215,11 @@ impl DetachNetworkInterfaceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetachNetworkInterfaceError {
     #[allow(unused_variables)]
@@ -75930,6 +77255,11 @@ impl DetachVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetachVolumeError {
     #[allow(unused_variables)]
@@ -75965,6 +77295,11 @@ impl DetachVpnGatewayError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DetachVpnGatewayError {
     #[allow(unused_variables)]
@@ -76002,6 +77337,11 @@ impl DisableEbsEncryptionByDefaultError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableEbsEncryptionByDefaultError {
     #[allow(unused_variables)]
@@ -76039,6 +77379,11 @@ impl DisableFastSnapshotRestoresError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableFastSnapshotRestoresError {
     #[allow(unused_variables)]
@@ -76076,6 +77421,11 @@ impl DisableTransitGatewayRouteTablePropagationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableTransitGatewayRouteTablePropagationError {
     #[allow(unused_variables)]
@@ -76113,6 +77463,11 @@ impl DisableVgwRoutePropagationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableVgwRoutePropagationError {
     #[allow(unused_variables)]
@@ -76148,6 +77503,11 @@ impl DisableVpcClassicLinkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableVpcClassicLinkError {
     #[allow(unused_variables)]
@@ -76185,6 +77545,11 @@ impl DisableVpcClassicLinkDnsSupportError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisableVpcClassicLinkDnsSupportError {
     #[allow(unused_variables)]
@@ -76220,6 +77585,11 @@ impl DisassociateAddressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateAddressError {
     #[allow(unused_variables)]
@@ -76257,6 +77627,11 @@ impl DisassociateClientVpnTargetNetworkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateClientVpnTargetNetworkError {
     #[allow(unused_variables)]
@@ -76294,6 +77669,11 @@ impl DisassociateIamInstanceProfileError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateIamInstanceProfileError {
     #[allow(unused_variables)]
@@ -76329,6 +77709,11 @@ impl DisassociateRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateRouteTableError {
     #[allow(unused_variables)]
@@ -76366,6 +77751,11 @@ impl DisassociateSubnetCidrBlockError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateSubnetCidrBlockError {
     #[allow(unused_variables)]
@@ -76403,6 +77793,11 @@ impl DisassociateTransitGatewayMulticastDomainError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateTransitGatewayMulticastDomainError {
     #[allow(unused_variables)]
@@ -76440,6 +77835,11 @@ impl DisassociateTransitGatewayRouteTableError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateTransitGatewayRouteTableError {
     #[allow(unused_variables)]
@@ -76475,6 +77875,11 @@ impl DisassociateVpcCidrBlockError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DisassociateVpcCidrBlockError {
     #[allow(unused_variables)]
@@ -76512,6 +77917,11 @@ impl EnableEbsEncryptionByDefaultError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableEbsEncryptionByDefaultError {
     #[allow(unused_variables)]
@@ -76549,6 +77959,11 @@ impl EnableFastSnapshotRestoresError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableFastSnapshotRestoresError {
     #[allow(unused_variables)]
@@ -76586,6 +78001,11 @@ impl EnableTransitGatewayRouteTablePropagationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableTransitGatewayRouteTablePropagationError {
     #[allow(unused_variables)]
@@ -76621,6 +78041,11 @@ impl EnableVgwRoutePropagationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableVgwRoutePropagationError {
     #[allow(unused_variables)]
@@ -76656,6 +78081,11 @@ impl EnableVolumeIOError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableVolumeIOError {
     #[allow(unused_variables)]
@@ -76691,6 +78121,11 @@ impl EnableVpcClassicLinkError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableVpcClassicLinkError {
     #[allow(unused_variables)]
@@ -76728,6 +78163,11 @@ impl EnableVpcClassicLinkDnsSupportError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for EnableVpcClassicLinkDnsSupportError {
     #[allow(unused_variables)]
@@ -76765,6 +78205,11 @@ impl ExportClientVpnClientCertificateRevocationListError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ExportClientVpnClientCertificateRevocationListError {
     #[allow(unused_variables)]
@@ -76802,6 +78247,11 @@ impl ExportClientVpnClientConfigurationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ExportClientVpnClientConfigurationError {
     #[allow(unused_variables)]
@@ -76837,6 +78287,11 @@ impl ExportImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ExportImageError {
     #[allow(unused_variables)]
@@ -76874,6 +78329,11 @@ impl ExportTransitGatewayRoutesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ExportTransitGatewayRoutesError {
     #[allow(unused_variables)]
@@ -76911,6 +78371,11 @@ impl GetAssociatedIpv6PoolCidrsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetAssociatedIpv6PoolCidrsError {
     #[allow(unused_variables)]
@@ -76948,6 +78413,11 @@ impl GetCapacityReservationUsageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetCapacityReservationUsageError {
     #[allow(unused_variables)]
@@ -76983,6 +78453,11 @@ impl GetCoipPoolUsageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetCoipPoolUsageError {
     #[allow(unused_variables)]
@@ -77018,6 +78493,11 @@ impl GetConsoleOutputError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetConsoleOutputError {
     #[allow(unused_variables)]
@@ -77053,6 +78533,11 @@ impl GetConsoleScreenshotError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetConsoleScreenshotError {
     #[allow(unused_variables)]
@@ -77090,6 +78575,11 @@ impl GetDefaultCreditSpecificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetDefaultCreditSpecificationError {
     #[allow(unused_variables)]
@@ -77125,6 +78615,11 @@ impl GetEbsDefaultKmsKeyIdError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetEbsDefaultKmsKeyIdError {
     #[allow(unused_variables)]
@@ -77160,6 +78655,11 @@ impl GetEbsEncryptionByDefaultError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetEbsEncryptionByDefaultError {
     #[allow(unused_variables)]
@@ -77197,6 +78697,11 @@ impl GetHostReservationPurchasePreviewError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetHostReservationPurchasePreviewError {
     #[allow(unused_variables)]
@@ -77232,6 +78737,11 @@ impl GetLaunchTemplateDataError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetLaunchTemplateDataError {
     #[allow(unused_variables)]
@@ -77269,6 +78779,11 @@ impl GetManagedPrefixListAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetManagedPrefixListAssociationsError {
     #[allow(unused_variables)]
@@ -77306,6 +78821,11 @@ impl GetManagedPrefixListEntriesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetManagedPrefixListEntriesError {
     #[allow(unused_variables)]
@@ -77341,6 +78861,11 @@ impl GetPasswordDataError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetPasswordDataError {
     #[allow(unused_variables)]
@@ -77378,6 +78903,11 @@ impl GetReservedInstancesExchangeQuoteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetReservedInstancesExchangeQuoteError {
     #[allow(unused_variables)]
@@ -77415,6 +78945,11 @@ impl GetTransitGatewayAttachmentPropagationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetTransitGatewayAttachmentPropagationsError {
     #[allow(unused_variables)]
@@ -77452,6 +78987,11 @@ impl GetTransitGatewayMulticastDomainAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetTransitGatewayMulticastDomainAssociationsError {
     #[allow(unused_variables)]
@@ -77489,6 +79029,11 @@ impl GetTransitGatewayRouteTableAssociationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetTransitGatewayRouteTableAssociationsError {
     #[allow(unused_variables)]
@@ -77526,6 +79071,11 @@ impl GetTransitGatewayRouteTablePropagationsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetTransitGatewayRouteTablePropagationsError {
     #[allow(unused_variables)]
@@ -77563,6 +79113,11 @@ impl ImportClientVpnClientCertificateRevocationListError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportClientVpnClientCertificateRevocationListError {
     #[allow(unused_variables)]
@@ -77598,6 +79153,11 @@ impl ImportImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportImageError {
     #[allow(unused_variables)]
@@ -77633,6 +79193,11 @@ impl ImportInstanceError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportInstanceError {
     #[allow(unused_variables)]
@@ -77668,6 +79233,11 @@ impl ImportKeyPairError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportKeyPairError {
     #[allow(unused_variables)]
@@ -77703,6 +79273,11 @@ impl ImportSnapshotError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportSnapshotError {
     #[allow(unused_variables)]
@@ -77738,6 +79313,11 @@ impl ImportVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ImportVolumeError {
     #[allow(unused_variables)]
@@ -77775,6 +79355,11 @@ impl ModifyAvailabilityZoneGroupError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyAvailabilityZoneGroupError {
     #[allow(unused_variables)]
@@ -77810,6 +79395,11 @@ impl ModifyCapacityReservationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyCapacityReservationError {
     #[allow(unused_variables)]
@@ -77845,6 +79435,11 @@ impl ModifyClientVpnEndpointError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyClientVpnEndpointError {
     #[allow(unused_variables)]
@@ -77882,6 +79477,11 @@ impl ModifyDefaultCreditSpecificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyDefaultCreditSpecificationError {
     #[allow(unused_variables)]
@@ -77917,6 +79517,11 @@ impl ModifyEbsDefaultKmsKeyIdError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyEbsDefaultKmsKeyIdError {
     #[allow(unused_variables)]
@@ -77952,6 +79557,11 @@ impl ModifyFleetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyFleetError {
     #[allow(unused_variables)]
@@ -77987,6 +79597,11 @@ impl ModifyFpgaImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyFpgaImageAttributeError {
     #[allow(unused_variables)]
@@ -78022,6 +79637,11 @@ impl ModifyHostsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyHostsError {
     #[allow(unused_variables)]
@@ -78057,6 +79677,11 @@ impl ModifyIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyIdFormatError {
     #[allow(unused_variables)]
@@ -78092,6 +79717,11 @@ impl ModifyIdentityIdFormatError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyIdentityIdFormatError {
     #[allow(unused_variables)]
@@ -78127,6 +79757,11 @@ impl ModifyImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyImageAttributeError {
     #[allow(unused_variables)]
@@ -78162,6 +79797,11 @@ impl ModifyInstanceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstanceAttributeError {
     #[allow(unused_variables)]
@@ -78199,6 +79839,11 @@ impl ModifyInstanceCapacityReservationAttributesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstanceCapacityReservationAttributesError {
     #[allow(unused_variables)]
@@ -78236,6 +79881,11 @@ impl ModifyInstanceCreditSpecificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstanceCreditSpecificationError {
     #[allow(unused_variables)]
@@ -78273,6 +79923,11 @@ impl ModifyInstanceEventStartTimeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstanceEventStartTimeError {
     #[allow(unused_variables)]
@@ -78310,6 +79965,11 @@ impl ModifyInstanceMetadataOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstanceMetadataOptionsError {
     #[allow(unused_variables)]
@@ -78345,6 +80005,11 @@ impl ModifyInstancePlacementError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyInstancePlacementError {
     #[allow(unused_variables)]
@@ -78380,6 +80045,11 @@ impl ModifyLaunchTemplateError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyLaunchTemplateError {
     #[allow(unused_variables)]
@@ -78415,6 +80085,11 @@ impl ModifyManagedPrefixListError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyManagedPrefixListError {
     #[allow(unused_variables)]
@@ -78452,6 +80127,11 @@ impl ModifyNetworkInterfaceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyNetworkInterfaceAttributeError {
     #[allow(unused_variables)]
@@ -78487,6 +80167,11 @@ impl ModifyReservedInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyReservedInstancesError {
     #[allow(unused_variables)]
@@ -78522,6 +80207,11 @@ impl ModifySnapshotAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifySnapshotAttributeError {
     #[allow(unused_variables)]
@@ -78557,6 +80247,11 @@ impl ModifySpotFleetRequestError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifySpotFleetRequestError {
     #[allow(unused_variables)]
@@ -78592,6 +80287,11 @@ impl ModifySubnetAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifySubnetAttributeError {
     #[allow(unused_variables)]
@@ -78629,6 +80329,11 @@ impl ModifyTrafficMirrorFilterNetworkServicesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyTrafficMirrorFilterNetworkServicesError {
     #[allow(unused_variables)]
@@ -78666,6 +80371,11 @@ impl ModifyTrafficMirrorFilterRuleError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyTrafficMirrorFilterRuleError {
     #[allow(unused_variables)]
@@ -78703,6 +80413,11 @@ impl ModifyTrafficMirrorSessionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyTrafficMirrorSessionError {
     #[allow(unused_variables)]
@@ -78740,6 +80455,11 @@ impl ModifyTransitGatewayVpcAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyTransitGatewayVpcAttachmentError {
     #[allow(unused_variables)]
@@ -78775,6 +80495,11 @@ impl ModifyVolumeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVolumeError {
     #[allow(unused_variables)]
@@ -78810,6 +80535,11 @@ impl ModifyVolumeAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVolumeAttributeError {
     #[allow(unused_variables)]
@@ -78845,6 +80575,11 @@ impl ModifyVpcAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcAttributeError {
     #[allow(unused_variables)]
@@ -78880,6 +80615,11 @@ impl ModifyVpcEndpointError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcEndpointError {
     #[allow(unused_variables)]
@@ -78917,6 +80657,11 @@ impl ModifyVpcEndpointConnectionNotificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcEndpointConnectionNotificationError {
     #[allow(unused_variables)]
@@ -78954,6 +80699,11 @@ impl ModifyVpcEndpointServiceConfigurationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcEndpointServiceConfigurationError {
     #[allow(unused_variables)]
@@ -78991,6 +80741,11 @@ impl ModifyVpcEndpointServicePermissionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcEndpointServicePermissionsError {
     #[allow(unused_variables)]
@@ -79028,6 +80783,11 @@ impl ModifyVpcPeeringConnectionOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcPeeringConnectionOptionsError {
     #[allow(unused_variables)]
@@ -79063,6 +80823,11 @@ impl ModifyVpcTenancyError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpcTenancyError {
     #[allow(unused_variables)]
@@ -79098,6 +80863,11 @@ impl ModifyVpnConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpnConnectionError {
     #[allow(unused_variables)]
@@ -79135,6 +80905,11 @@ impl ModifyVpnTunnelCertificateError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpnTunnelCertificateError {
     #[allow(unused_variables)]
@@ -79170,6 +80945,11 @@ impl ModifyVpnTunnelOptionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ModifyVpnTunnelOptionsError {
     #[allow(unused_variables)]
@@ -79205,6 +80985,11 @@ impl MonitorInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for MonitorInstancesError {
     #[allow(unused_variables)]
@@ -79240,6 +81025,11 @@ impl MoveAddressToVpcError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for MoveAddressToVpcError {
     #[allow(unused_variables)]
@@ -79275,6 +81065,11 @@ impl ProvisionByoipCidrError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ProvisionByoipCidrError {
     #[allow(unused_variables)]
@@ -79310,6 +81105,11 @@ impl PurchaseHostReservationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PurchaseHostReservationError {
     #[allow(unused_variables)]
@@ -79347,6 +81147,11 @@ impl PurchaseReservedInstancesOfferingError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PurchaseReservedInstancesOfferingError {
     #[allow(unused_variables)]
@@ -79384,6 +81189,11 @@ impl PurchaseScheduledInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PurchaseScheduledInstancesError {
     #[allow(unused_variables)]
@@ -79419,6 +81229,11 @@ impl RebootInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RebootInstancesError {
     #[allow(unused_variables)]
@@ -79454,6 +81269,11 @@ impl RegisterImageError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RegisterImageError {
     #[allow(unused_variables)]
@@ -79491,6 +81311,11 @@ impl RegisterInstanceEventNotificationAttributesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RegisterInstanceEventNotificationAttributesError {
     #[allow(unused_variables)]
@@ -79528,6 +81353,11 @@ impl RegisterTransitGatewayMulticastGroupMembersError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RegisterTransitGatewayMulticastGroupMembersError {
     #[allow(unused_variables)]
@@ -79565,6 +81395,11 @@ impl RegisterTransitGatewayMulticastGroupSourcesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RegisterTransitGatewayMulticastGroupSourcesError {
     #[allow(unused_variables)]
@@ -79602,6 +81437,11 @@ impl RejectTransitGatewayPeeringAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RejectTransitGatewayPeeringAttachmentError {
     #[allow(unused_variables)]
@@ -79639,6 +81479,11 @@ impl RejectTransitGatewayVpcAttachmentError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RejectTransitGatewayVpcAttachmentError {
     #[allow(unused_variables)]
@@ -79676,6 +81521,11 @@ impl RejectVpcEndpointConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RejectVpcEndpointConnectionsError {
     #[allow(unused_variables)]
@@ -79713,6 +81563,11 @@ impl RejectVpcPeeringConnectionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RejectVpcPeeringConnectionError {
     #[allow(unused_variables)]
@@ -79748,6 +81603,11 @@ impl ReleaseAddressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReleaseAddressError {
     #[allow(unused_variables)]
@@ -79783,6 +81643,11 @@ impl ReleaseHostsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReleaseHostsError {
     #[allow(unused_variables)]
@@ -79820,6 +81685,11 @@ impl ReplaceIamInstanceProfileAssociationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceIamInstanceProfileAssociationError {
     #[allow(unused_variables)]
@@ -79857,6 +81727,11 @@ impl ReplaceNetworkAclAssociationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceNetworkAclAssociationError {
     #[allow(unused_variables)]
@@ -79892,6 +81767,11 @@ impl ReplaceNetworkAclEntryError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceNetworkAclEntryError {
     #[allow(unused_variables)]
@@ -79927,6 +81807,11 @@ impl ReplaceRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceRouteError {
     #[allow(unused_variables)]
@@ -79964,6 +81849,11 @@ impl ReplaceRouteTableAssociationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceRouteTableAssociationError {
     #[allow(unused_variables)]
@@ -80001,6 +81891,11 @@ impl ReplaceTransitGatewayRouteError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReplaceTransitGatewayRouteError {
     #[allow(unused_variables)]
@@ -80036,6 +81931,11 @@ impl ReportInstanceStatusError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ReportInstanceStatusError {
     #[allow(unused_variables)]
@@ -80071,6 +81971,11 @@ impl RequestSpotFleetError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RequestSpotFleetError {
     #[allow(unused_variables)]
@@ -80106,6 +82011,11 @@ impl RequestSpotInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RequestSpotInstancesError {
     #[allow(unused_variables)]
@@ -80141,6 +82051,11 @@ impl ResetEbsDefaultKmsKeyIdError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetEbsDefaultKmsKeyIdError {
     #[allow(unused_variables)]
@@ -80176,6 +82091,11 @@ impl ResetFpgaImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetFpgaImageAttributeError {
     #[allow(unused_variables)]
@@ -80211,6 +82131,11 @@ impl ResetImageAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetImageAttributeError {
     #[allow(unused_variables)]
@@ -80246,6 +82171,11 @@ impl ResetInstanceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetInstanceAttributeError {
     #[allow(unused_variables)]
@@ -80283,6 +82213,11 @@ impl ResetNetworkInterfaceAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetNetworkInterfaceAttributeError {
     #[allow(unused_variables)]
@@ -80318,6 +82253,11 @@ impl ResetSnapshotAttributeError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ResetSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -80353,6 +82293,11 @@ impl RestoreAddressToClassicError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RestoreAddressToClassicError {
     #[allow(unused_variables)]
@@ -80390,6 +82335,11 @@ impl RestoreManagedPrefixListVersionError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RestoreManagedPrefixListVersionError {
     #[allow(unused_variables)]
@@ -80425,6 +82375,11 @@ impl RevokeClientVpnIngressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RevokeClientVpnIngressError {
     #[allow(unused_variables)]
@@ -80460,6 +82415,11 @@ impl RevokeSecurityGroupEgressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RevokeSecurityGroupEgressError {
     #[allow(unused_variables)]
@@ -80497,6 +82457,11 @@ impl RevokeSecurityGroupIngressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RevokeSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -80532,6 +82497,11 @@ impl RunInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RunInstancesError {
     #[allow(unused_variables)]
@@ -80567,6 +82537,11 @@ impl RunScheduledInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RunScheduledInstancesError {
     #[allow(unused_variables)]
@@ -80602,6 +82577,11 @@ impl SearchLocalGatewayRoutesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SearchLocalGatewayRoutesError {
     #[allow(unused_variables)]
@@ -80639,6 +82619,11 @@ impl SearchTransitGatewayMulticastGroupsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SearchTransitGatewayMulticastGroupsError {
     #[allow(unused_variables)]
@@ -80676,6 +82661,11 @@ impl SearchTransitGatewayRoutesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SearchTransitGatewayRoutesError {
     #[allow(unused_variables)]
@@ -80711,6 +82701,11 @@ impl SendDiagnosticInterruptError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SendDiagnosticInterruptError {
     #[allow(unused_variables)]
@@ -80746,6 +82741,11 @@ impl StartInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for StartInstancesError {
     #[allow(unused_variables)]
@@ -80783,6 +82783,11 @@ impl StartVpcEndpointServicePrivateDnsVerificationError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for StartVpcEndpointServicePrivateDnsVerificationError {
     #[allow(unused_variables)]
@@ -80818,6 +82823,11 @@ impl StopInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for StopInstancesError {
     #[allow(unused_variables)]
@@ -80855,6 +82865,11 @@ impl TerminateClientVpnConnectionsError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for TerminateClientVpnConnectionsError {
     #[allow(unused_variables)]
@@ -80890,6 +82905,11 @@ impl TerminateInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for TerminateInstancesError {
     #[allow(unused_variables)]
@@ -80925,6 +82945,11 @@ impl UnassignIpv6AddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UnassignIpv6AddressesError {
     #[allow(unused_variables)]
@@ -80962,6 +82987,11 @@ impl UnassignPrivateIpAddressesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UnassignPrivateIpAddressesError {
     #[allow(unused_variables)]
@@ -80997,6 +83027,11 @@ impl UnmonitorInstancesError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UnmonitorInstancesError {
     #[allow(unused_variables)]
@@ -81034,6 +83069,11 @@ impl UpdateSecurityGroupRuleDescriptionsEgressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateSecurityGroupRuleDescriptionsEgressError {
     #[allow(unused_variables)]
@@ -81071,6 +83111,11 @@ impl UpdateSecurityGroupRuleDescriptionsIngressError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateSecurityGroupRuleDescriptionsIngressError {
     #[allow(unused_variables)]
@@ -81106,6 +83151,11 @@ impl WithdrawByoipCidrError {
         xml_util::start_element("Errors", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for WithdrawByoipCidrError {
     #[allow(unused_variables)]
@@ -42,7 +42,7 @@ impl EcrClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3154,7 +3154,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, BatchCheckLayerAvailabilityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchCheckLayerAvailabilityResponse, _>()
     }
@@ -3176,7 +3176,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, BatchDeleteImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDeleteImageResponse, _>()
     }
 
@@ -3197,7 +3197,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, BatchGetImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetImageResponse, _>()
     }
 
@@ -3218,7 +3218,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, CompleteLayerUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CompleteLayerUploadResponse, _>()
     }
 
@@ -3239,7 +3239,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, CreateRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRepositoryResponse, _>()
     }
 
@@ -3260,7 +3260,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DeleteLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteLifecyclePolicyResponse, _>()
     }
@@ -3282,7 +3282,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DeleteRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRepositoryResponse, _>()
     }
 
@@ -3303,7 +3303,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DeleteRepositoryPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRepositoryPolicyResponse, _>()
     }
@@ -3326,7 +3326,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DescribeImageScanFindingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeImageScanFindingsResponse, _>()
     }
@@ -3348,7 +3348,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DescribeImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeImagesResponse, _>()
     }
 
@@ -3369,7 +3369,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, DescribeRepositoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRepositoriesResponse, _>()
     }
@@ -3391,7 +3391,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, GetAuthorizationTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAuthorizationTokenResponse, _>()
     }
@@ -3413,7 +3413,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, GetDownloadUrlForLayerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDownloadUrlForLayerResponse, _>()
     }
@@ -3435,7 +3435,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, GetLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLifecyclePolicyResponse, _>()
     }
 
@@ -3457,7 +3457,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, GetLifecyclePolicyPreviewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLifecyclePolicyPreviewResponse, _>()
     }
@@ -3479,7 +3479,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, GetRepositoryPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRepositoryPolicyResponse, _>()
     }
 
@@ -3500,7 +3500,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, InitiateLayerUploadError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<InitiateLayerUploadResponse, _>()
     }
 
@@ -3521,7 +3521,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, ListImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListImagesResponse, _>()
     }
 
@@ -3542,7 +3542,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3563,7 +3563,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, PutImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutImageResponse, _>()
     }
 
@@ -3587,7 +3587,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, PutImageScanningConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutImageScanningConfigurationResponse, _>()
     }
@@ -3609,7 +3609,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, PutImageTagMutabilityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutImageTagMutabilityResponse, _>()
     }
@@ -3631,7 +3631,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, PutLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutLifecyclePolicyResponse, _>()
     }
 
@@ -3652,7 +3652,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, SetRepositoryPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SetRepositoryPolicyResponse, _>()
     }
 
@@ -3673,7 +3673,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, StartImageScanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartImageScanResponse, _>()
     }
 
@@ -3695,7 +3695,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, StartLifecyclePolicyPreviewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartLifecyclePolicyPreviewResponse, _>()
     }
@@ -3717,7 +3717,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3738,7 +3738,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -3759,7 +3759,7 @@ impl Ecr for EcrClient {
             .sign_and_dispatch(request, UploadLayerPartError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UploadLayerPartResponse, _>()
     }
 }
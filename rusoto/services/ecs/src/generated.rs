@@ -41,7 +41,7 @@ impl EcsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6546,7 +6546,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, CreateCapacityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCapacityProviderResponse, _>()
     }
@@ -6568,7 +6568,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, CreateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
     }
 
@@ -6589,7 +6589,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, CreateServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateServiceResponse, _>()
     }
 
@@ -6610,7 +6610,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, CreateTaskSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTaskSetResponse, _>()
     }
 
@@ -6631,7 +6631,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteAccountSettingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteAccountSettingResponse, _>()
     }
@@ -6653,7 +6653,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAttributesResponse, _>()
     }
 
@@ -6674,7 +6674,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteCapacityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteCapacityProviderResponse, _>()
     }
@@ -6696,7 +6696,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
     }
 
@@ -6717,7 +6717,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteServiceResponse, _>()
     }
 
@@ -6738,7 +6738,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeleteTaskSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTaskSetResponse, _>()
     }
 
@@ -6760,7 +6760,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeregisterContainerInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterContainerInstanceResponse, _>()
     }
@@ -6782,7 +6782,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DeregisterTaskDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterTaskDefinitionResponse, _>()
     }
@@ -6805,7 +6805,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeCapacityProvidersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCapacityProvidersResponse, _>()
     }
@@ -6827,7 +6827,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeClustersResponse, _>()
     }
 
@@ -6849,7 +6849,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeContainerInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeContainerInstancesResponse, _>()
     }
@@ -6871,7 +6871,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServicesResponse, _>()
     }
 
@@ -6892,7 +6892,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeTaskDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTaskDefinitionResponse, _>()
     }
@@ -6914,7 +6914,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeTaskSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTaskSetsResponse, _>()
     }
 
@@ -6935,7 +6935,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DescribeTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTasksResponse, _>()
     }
 
@@ -6956,7 +6956,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, DiscoverPollEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DiscoverPollEndpointResponse, _>()
     }
@@ -6978,7 +6978,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListAccountSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAccountSettingsResponse, _>()
     }
 
@@ -6999,7 +6999,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAttributesResponse, _>()
     }
 
@@ -7020,7 +7020,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListClustersResponse, _>()
     }
 
@@ -7041,7 +7041,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListContainerInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListContainerInstancesResponse, _>()
     }
@@ -7063,7 +7063,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
     }
 
@@ -7084,7 +7084,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -7106,7 +7106,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListTaskDefinitionFamiliesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTaskDefinitionFamiliesResponse, _>()
     }
@@ -7128,7 +7128,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListTaskDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTaskDefinitionsResponse, _>()
     }
 
@@ -7149,7 +7149,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, ListTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTasksResponse, _>()
     }
 
@@ -7170,7 +7170,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, PutAccountSettingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutAccountSettingResponse, _>()
     }
 
@@ -7191,7 +7191,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, PutAccountSettingDefaultError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutAccountSettingDefaultResponse, _>()
     }
@@ -7213,7 +7213,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, PutAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutAttributesResponse, _>()
     }
 
@@ -7235,7 +7235,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, PutClusterCapacityProvidersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutClusterCapacityProvidersResponse, _>()
     }
@@ -7258,7 +7258,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, RegisterContainerInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterContainerInstanceResponse, _>()
     }
@@ -7280,7 +7280,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, RegisterTaskDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterTaskDefinitionResponse, _>()
     }
@@ -7299,7 +7299,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, RunTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RunTaskResponse, _>()
     }
 
@@ -7320,7 +7320,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, StartTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartTaskResponse, _>()
     }
 
@@ -7341,7 +7341,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, StopTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopTaskResponse, _>()
     }
 
@@ -7363,7 +7363,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, SubmitAttachmentStateChangesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SubmitAttachmentStateChangesResponse, _>()
     }
@@ -7386,7 +7386,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, SubmitContainerStateChangeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SubmitContainerStateChangeResponse, _>()
     }
@@ -7408,7 +7408,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, SubmitTaskStateChangeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SubmitTaskStateChangeResponse, _>()
     }
@@ -7430,7 +7430,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -7451,7 +7451,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -7472,7 +7472,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateClusterSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateClusterSettingsResponse, _>()
     }
@@ -7494,7 +7494,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateContainerAgentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateContainerAgentResponse, _>()
     }
@@ -7519,7 +7519,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateContainerInstancesStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateContainerInstancesStateResponse, _>()
     }
@@ -7541,7 +7541,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
     }
 
@@ -7563,7 +7563,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateServicePrimaryTaskSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateServicePrimaryTaskSetResponse, _>()
     }
@@ -7585,7 +7585,7 @@ impl Ecs for EcsClient {
             .sign_and_dispatch(request, UpdateTaskSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskSetResponse, _>()
     }
 }
@@ -2382,6 +2382,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2393,13 +2394,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AccessPointDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAccessPointError::from_response(response))
         }
     }
@@ -2414,6 +2415,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2425,13 +2427,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FileSystemDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFileSystemError::from_response(response))
         }
     }
@@ -2446,6 +2448,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2457,13 +2460,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<MountTargetDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMountTargetError::from_response(response))
         }
     }
@@ -2481,6 +2484,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2492,12 +2496,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTagsError::from_response(response))
         }
     }
@@ -2515,6 +2519,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2523,12 +2528,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAccessPointError::from_response(response))
         }
     }
@@ -2546,6 +2551,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2554,12 +2560,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFileSystemError::from_response(response))
         }
     }
@@ -2577,6 +2583,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2585,12 +2592,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFileSystemPolicyError::from_response(response))
         }
     }
@@ -2608,6 +2615,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2616,12 +2624,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMountTargetError::from_response(response))
         }
     }
@@ -2639,6 +2647,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2650,12 +2659,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTagsError::from_response(response))
         }
     }
@@ -2670,6 +2679,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeAccessPoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2693,13 +2703,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAccessPointsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAccessPointsError::from_response(response))
         }
     }
@@ -2717,6 +2727,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeBackupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2725,13 +2736,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BackupPolicyDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeBackupPolicyError::from_response(response))
         }
     }
@@ -2749,6 +2760,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2757,13 +2769,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FileSystemPolicyDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFileSystemPolicyError::from_response(response))
         }
     }
@@ -2778,6 +2790,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystems");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2801,13 +2814,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFileSystemsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFileSystemsError::from_response(response))
         }
     }
@@ -2826,6 +2839,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2834,13 +2848,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<LifecycleConfigurationDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeLifecycleConfigurationError::from_response(response))
         }
     }
@@ -2861,6 +2875,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2869,13 +2884,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeMountTargetSecurityGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeMountTargetSecurityGroupsError::from_response(
                 response,
             ))
@@ -2892,6 +2907,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2918,13 +2934,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeMountTargetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeMountTargetsError::from_response(response))
         }
     }
@@ -2942,6 +2958,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2959,13 +2976,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeTagsError::from_response(response))
         }
     }
@@ -2983,6 +3000,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3000,13 +3018,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3024,6 +3042,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ModifyMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3035,12 +3054,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ModifyMountTargetSecurityGroupsError::from_response(
                 response,
             ))
@@ -3060,6 +3079,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutBackupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3071,13 +3091,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BackupPolicyDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutBackupPolicyError::from_response(response))
         }
     }
@@ -3095,6 +3115,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3106,13 +3127,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FileSystemPolicyDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutFileSystemPolicyError::from_response(response))
         }
     }
@@ -3131,6 +3152,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3142,13 +3164,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<LifecycleConfigurationDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutLifecycleConfigurationError::from_response(response))
         }
     }
@@ -3166,6 +3188,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3177,12 +3200,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3200,6 +3223,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3214,12 +3238,12 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3237,6 +3261,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UpdateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3248,13 +3273,13 @@ impl Efs for EfsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FileSystemDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFileSystemError::from_response(response))
         }
     }
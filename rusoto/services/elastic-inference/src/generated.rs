@@ -595,6 +595,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("DescribeAcceleratorOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -607,13 +608,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAcceleratorOfferingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAcceleratorOfferingsError::from_response(response))
         }
     }
@@ -627,6 +628,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("GET", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("DescribeAcceleratorTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -637,13 +639,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAcceleratorTypesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAcceleratorTypesError::from_response(response))
         }
     }
@@ -658,6 +660,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("DescribeAccelerators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -670,13 +673,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAcceleratorsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAcceleratorsError::from_response(response))
         }
     }
@@ -691,6 +694,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("GET", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -701,13 +705,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -722,6 +726,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -734,13 +739,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -755,6 +760,7 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastic-inference", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -771,13 +777,13 @@ impl ElasticInference for ElasticInferenceClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
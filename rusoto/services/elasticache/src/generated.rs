@@ -52,7 +52,7 @@ impl ElastiCacheClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -8929,13 +8929,13 @@ impl UpdateActionsMessageDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
-    InvalidARNFault(String),
+    InvalidARNFault(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
-    TagQuotaPerResourceExceeded(String),
+    TagQuotaPerResourceExceeded(XmlErrorMessage),
 }
 
 impl AddTagsToResourceError {
@@ -8948,23 +8948,25 @@ impl AddTagsToResourceError {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::CacheClusterNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::CacheClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidARN" => {
                         return RusotoError::Service(AddTagsToResourceError::InvalidARNFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(AddTagsToResourceError::SnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagQuotaPerResourceExceeded" => {
                         return RusotoError::Service(
                             AddTagsToResourceError::TagQuotaPerResourceExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8982,6 +8984,16 @@ impl AddTagsToResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsToResourceError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::InvalidARNFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::TagQuotaPerResourceExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsToResourceError {
     #[allow(unused_variables)]
@@ -9001,15 +9013,15 @@ impl Error for AddTagsToResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeCacheSecurityGroupIngressError {
     /// <p>The specified Amazon EC2 security group is already authorized for the specified cache security group.</p>
-    AuthorizationAlreadyExistsFault(String),
+    AuthorizationAlreadyExistsFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache security group does not allow deletion.</p>
-    InvalidCacheSecurityGroupStateFault(String),
+    InvalidCacheSecurityGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl AuthorizeCacheSecurityGroupIngressError {
@@ -9022,7 +9034,7 @@ impl AuthorizeCacheSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"InvalidCacheSecurityGroupState" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"CacheSecurityGroupNotFound" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidCacheSecurityGroupState" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9036,6 +9048,27 @@ impl AuthorizeCacheSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AuthorizeCacheSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -9064,9 +9097,9 @@ impl Error for AuthorizeCacheSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchApplyUpdateActionError {
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The service update doesn't exist</p>
-    ServiceUpdateNotFoundFault(String),
+    ServiceUpdateNotFoundFault(XmlErrorMessage),
 }
 
 impl BatchApplyUpdateActionError {
@@ -9080,14 +9113,14 @@ impl BatchApplyUpdateActionError {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             BatchApplyUpdateActionError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
                         return RusotoError::Service(
                             BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9105,6 +9138,16 @@ impl BatchApplyUpdateActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchApplyUpdateActionError::InvalidParameterValue(ref cause) => cause.request_id(),
+            BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for BatchApplyUpdateActionError {
     #[allow(unused_variables)]
@@ -9122,9 +9165,9 @@ impl Error for BatchApplyUpdateActionError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchStopUpdateActionError {
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The service update doesn't exist</p>
-    ServiceUpdateNotFoundFault(String),
+    ServiceUpdateNotFoundFault(XmlErrorMessage),
 }
 
 impl BatchStopUpdateActionError {
@@ -9137,13 +9180,15 @@ impl BatchStopUpdateActionError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            BatchStopUpdateActionError::InvalidParameterValue(parsed_error.message),
+                            BatchStopUpdateActionError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
                         return RusotoError::Service(
                             BatchStopUpdateActionError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9161,6 +9206,14 @@ impl BatchStopUpdateActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchStopUpdateActionError::InvalidParameterValue(ref cause) => cause.request_id(),
+            BatchStopUpdateActionError::ServiceUpdateNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for BatchStopUpdateActionError {
     #[allow(unused_variables)]
@@ -9178,11 +9231,11 @@ impl Error for BatchStopUpdateActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CompleteMigrationError {
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The designated replication group is not available for data migration.</p>
-    ReplicationGroupNotUnderMigrationFault(String),
+    ReplicationGroupNotUnderMigrationFault(XmlErrorMessage),
 }
 
 impl CompleteMigrationError {
@@ -9196,21 +9249,21 @@ impl CompleteMigrationError {
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             CompleteMigrationError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CompleteMigrationError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotUnderMigrationFault" => {
                         return RusotoError::Service(
                             CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9228,6 +9281,19 @@ impl CompleteMigrationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CompleteMigrationError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CompleteMigrationError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CompleteMigrationError {
     #[allow(unused_variables)]
@@ -9250,17 +9316,17 @@ impl Error for CompleteMigrationError {}
 #[derive(Debug, PartialEq)]
 pub enum CopySnapshotError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The current state of the snapshot does not allow the requested operation to occur.</p>
-    InvalidSnapshotStateFault(String),
+    InvalidSnapshotStateFault(XmlErrorMessage),
     /// <p>You already have a snapshot with the given name.</p>
-    SnapshotAlreadyExistsFault(String),
+    SnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopySnapshotError {
@@ -9273,32 +9339,34 @@ impl CopySnapshotError {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            CopySnapshotError::InvalidParameterCombination(parsed_error.message),
+                            CopySnapshotError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(CopySnapshotError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSnapshotState" => {
                         return RusotoError::Service(CopySnapshotError::InvalidSnapshotStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(CopySnapshotError::SnapshotAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(CopySnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotQuotaExceededFault" => {
                         return RusotoError::Service(CopySnapshotError::SnapshotQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9315,6 +9383,18 @@ impl CopySnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopySnapshotError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            CopySnapshotError::InvalidParameterValue(ref cause) => cause.request_id(),
+            CopySnapshotError::InvalidSnapshotStateFault(ref cause) => cause.request_id(),
+            CopySnapshotError::SnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            CopySnapshotError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+            CopySnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopySnapshotError {
     #[allow(unused_variables)]
@@ -9334,33 +9414,33 @@ impl Error for CopySnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheClusterError {
     /// <p>You already have a cluster with the given identifier.</p>
-    CacheClusterAlreadyExistsFault(String),
+    CacheClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
-    CacheSubnetGroupNotFoundFault(String),
+    CacheSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
-    ClusterQuotaForCustomerExceededFault(String),
+    ClusterQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes in a single cluster.</p>
-    NodeQuotaForClusterExceededFault(String),
+    NodeQuotaForClusterExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
-    TagQuotaPerResourceExceeded(String),
+    TagQuotaPerResourceExceeded(XmlErrorMessage),
 }
 
 impl CreateCacheClusterError {
@@ -9374,96 +9454,98 @@ impl CreateCacheClusterError {
                     "CacheClusterAlreadyExists" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::CacheClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            CreateCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            CreateCacheClusterError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagQuotaPerResourceExceeded" => {
                         return RusotoError::Service(
                             CreateCacheClusterError::TagQuotaPerResourceExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9481,6 +9563,42 @@ impl CreateCacheClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCacheClusterError::CacheClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::CacheSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            CreateCacheClusterError::InvalidParameterValue(ref cause) => cause.request_id(),
+            CreateCacheClusterError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateCacheClusterError::NodeQuotaForClusterExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheClusterError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateCacheClusterError::TagQuotaPerResourceExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCacheClusterError {
     #[allow(unused_variables)]
@@ -9534,15 +9652,15 @@ impl Error for CreateCacheClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheParameterGroupError {
     /// <p>A cache parameter group with the requested name already exists.</p>
-    CacheParameterGroupAlreadyExistsFault(String),
+    CacheParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum number of cache security groups.</p>
-    CacheParameterGroupQuotaExceededFault(String),
+    CacheParameterGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The current state of the cache parameter group does not allow the requested operation to occur.</p>
-    InvalidCacheParameterGroupStateFault(String),
+    InvalidCacheParameterGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl CreateCacheParameterGroupError {
@@ -9556,35 +9674,35 @@ impl CreateCacheParameterGroupError {
                     "CacheParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
                         return RusotoError::Service(
                             CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             CreateCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             CreateCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9602,6 +9720,25 @@ impl CreateCacheParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheParameterGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheParameterGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCacheParameterGroupError {
     #[allow(unused_variables)]
@@ -9630,13 +9767,13 @@ impl Error for CreateCacheParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheSecurityGroupError {
     /// <p>A cache security group with the specified name already exists.</p>
-    CacheSecurityGroupAlreadyExistsFault(String),
+    CacheSecurityGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache security groups.</p>
-    CacheSecurityGroupQuotaExceededFault(String),
+    CacheSecurityGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl CreateCacheSecurityGroupError {
@@ -9650,28 +9787,28 @@ impl CreateCacheSecurityGroupError {
                     "CacheSecurityGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "QuotaExceeded.CacheSecurityGroup" => {
                         return RusotoError::Service(
                             CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             CreateCacheSecurityGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             CreateCacheSecurityGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9689,6 +9826,22 @@ impl CreateCacheSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSecurityGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSecurityGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCacheSecurityGroupError {
     #[allow(unused_variables)]
@@ -9714,13 +9867,13 @@ impl Error for CreateCacheSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheSubnetGroupError {
     /// <p>The requested cache subnet group name is already in use by an existing cache subnet group.</p>
-    CacheSubnetGroupAlreadyExistsFault(String),
+    CacheSubnetGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache subnet groups.</p>
-    CacheSubnetGroupQuotaExceededFault(String),
+    CacheSubnetGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of subnets in a cache subnet group.</p>
-    CacheSubnetQuotaExceededFault(String),
+    CacheSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>An invalid subnet identifier was specified.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
 }
 
 impl CreateCacheSubnetGroupError {
@@ -9734,27 +9887,27 @@ impl CreateCacheSubnetGroupError {
                     "CacheSubnetGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSubnetGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9771,6 +9924,22 @@ impl CreateCacheSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateCacheSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCacheSubnetGroupError {
     #[allow(unused_variables)]
@@ -9794,15 +9963,15 @@ impl Error for CreateCacheSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalReplicationGroupError {
     /// <p>The Global Datastore name already exists.</p>
-    GlobalReplicationGroupAlreadyExistsFault(String),
+    GlobalReplicationGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified service linked role (SLR) was not found.</p>
-    ServiceLinkedRoleNotFoundFault(String),
+    ServiceLinkedRoleNotFoundFault(XmlErrorMessage),
 }
 
 impl CreateGlobalReplicationGroupError {
@@ -9817,34 +9986,34 @@ impl CreateGlobalReplicationGroupError {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupAlreadyExistsFault" => return RusotoError::Service(
                         CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             CreateGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
                         return RusotoError::Service(
                             CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9862,6 +10031,27 @@ impl CreateGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateGlobalReplicationGroupError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+            CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -9890,39 +10080,39 @@ impl Error for CreateGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationGroupError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
-    CacheSubnetGroupNotFoundFault(String),
+    CacheSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
-    ClusterQuotaForCustomerExceededFault(String),
+    ClusterQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum allowed number of node groups (shards) in a single replication group. The default maximum is 90</p>
-    NodeGroupsPerReplicationGroupQuotaExceededFault(String),
+    NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes in a single cluster.</p>
-    NodeQuotaForClusterExceededFault(String),
+    NodeQuotaForClusterExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group already exists.</p>
-    ReplicationGroupAlreadyExistsFault(String),
+    ReplicationGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
-    TagQuotaPerResourceExceeded(String),
+    TagQuotaPerResourceExceeded(XmlErrorMessage),
 }
 
 impl CreateReplicationGroupError {
@@ -9933,7 +10123,7 @@ impl CreateReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CacheClusterNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message)),"CacheParameterGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"CacheSubnetGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message)),"ClusterQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message)),"GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InsufficientCacheClusterCapacity" => return RusotoError::Service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterValue(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForClusterExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupAlreadyExists" => return RusotoError::Service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message)),"TagQuotaPerResourceExceeded" => return RusotoError::Service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message)),_ => {}
+                                    "CacheClusterNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheClusterNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"CacheParameterGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"CacheSecurityGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"CacheSubnetGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ClusterQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InsufficientCacheClusterCapacity" => return RusotoError::Service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidCacheClusterState" => return RusotoError::Service(CreateReplicationGroupError::InvalidCacheClusterStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidVPCNetworkStateFault" => return RusotoError::Service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"NodeQuotaForClusterExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReplicationGroupAlreadyExists" => return RusotoError::Service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"TagQuotaPerResourceExceeded" => return RusotoError::Service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9947,6 +10137,59 @@ impl CreateReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateReplicationGroupError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateReplicationGroupError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::InvalidCacheClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+            CreateReplicationGroupError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateReplicationGroupError::NodeQuotaForClusterExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateReplicationGroupError::TagQuotaPerResourceExceeded(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateReplicationGroupError {
     #[allow(unused_variables)]
@@ -10009,23 +10252,23 @@ impl Error for CreateReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>You already have a snapshot with the given name.</p>
-    SnapshotAlreadyExistsFault(String),
+    SnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>You attempted one of the following operations:</p> <ul> <li> <p>Creating a snapshot of a Redis cluster running on a <code>cache.t1.micro</code> cache node.</p> </li> <li> <p>Creating a snapshot of a cluster that is running Memcached rather than Redis.</p> </li> </ul> <p>Neither of these are supported by ElastiCache.</p>
-    SnapshotFeatureNotSupportedFault(String),
+    SnapshotFeatureNotSupportedFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateSnapshotError {
@@ -10038,55 +10281,63 @@ impl CreateSnapshotError {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
-                            CreateSnapshotError::CacheClusterNotFoundFault(parsed_error.message),
+                            CreateSnapshotError::CacheClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             CreateSnapshotError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            CreateSnapshotError::InvalidParameterCombination(parsed_error.message),
+                            CreateSnapshotError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(CreateSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             CreateSnapshotError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateSnapshotError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            CreateSnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
+                            CreateSnapshotError::SnapshotAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
                         return RusotoError::Service(
                             CreateSnapshotError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
                         return RusotoError::Service(
-                            CreateSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                            CreateSnapshotError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10103,6 +10354,21 @@ impl CreateSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateSnapshotError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            CreateSnapshotError::InvalidParameterValue(ref cause) => cause.request_id(),
+            CreateSnapshotError::InvalidReplicationGroupStateFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::SnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::SnapshotFeatureNotSupportedFault(ref cause) => cause.request_id(),
+            CreateSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateSnapshotError {
     #[allow(unused_variables)]
@@ -10129,13 +10395,13 @@ impl Error for CreateSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DecreaseNodeGroupsInGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DecreaseNodeGroupsInGlobalReplicationGroupError {
@@ -10148,7 +10414,7 @@ impl DecreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -10162,6 +10428,16 @@ impl DecreaseNodeGroupsInGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => cause.request_id(),
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DecreaseNodeGroupsInGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -10179,29 +10455,29 @@ impl Error for DecreaseNodeGroupsInGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DecreaseReplicaCountError {
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
-    ClusterQuotaForCustomerExceededFault(String),
+    ClusterQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The operation was not performed because no changes were required.</p>
-    NoOperationFault(String),
+    NoOperationFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum allowed number of node groups (shards) in a single replication group. The default maximum is 90</p>
-    NodeGroupsPerReplicationGroupQuotaExceededFault(String),
+    NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified service linked role (SLR) was not found.</p>
-    ServiceLinkedRoleNotFoundFault(String),
+    ServiceLinkedRoleNotFoundFault(XmlErrorMessage),
 }
 
 impl DecreaseReplicaCountError {
@@ -10215,78 +10491,80 @@ impl DecreaseReplicaCountError {
                     "ClusterQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
+                            DecreaseReplicaCountError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoOperationFault" => {
                         return RusotoError::Service(DecreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
                         DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "NodeQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
                         return RusotoError::Service(
                             DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10304,6 +10582,40 @@ impl DecreaseReplicaCountError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::InvalidCacheClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DecreaseReplicaCountError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DecreaseReplicaCountError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            DecreaseReplicaCountError::NoOperationFault(ref cause) => cause.request_id(),
+            DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DecreaseReplicaCountError {
     #[allow(unused_variables)]
@@ -10349,19 +10661,19 @@ impl Error for DecreaseReplicaCountError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>You already have a snapshot with the given name.</p>
-    SnapshotAlreadyExistsFault(String),
+    SnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>You attempted one of the following operations:</p> <ul> <li> <p>Creating a snapshot of a Redis cluster running on a <code>cache.t1.micro</code> cache node.</p> </li> <li> <p>Creating a snapshot of a cluster that is running Memcached rather than Redis.</p> </li> </ul> <p>Neither of these are supported by ElastiCache.</p>
-    SnapshotFeatureNotSupportedFault(String),
+    SnapshotFeatureNotSupportedFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteCacheClusterError {
@@ -10375,47 +10687,49 @@ impl DeleteCacheClusterError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DeleteCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            DeleteCacheClusterError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::SnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
                         return RusotoError::Service(
                             DeleteCacheClusterError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10433,6 +10747,21 @@ impl DeleteCacheClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCacheClusterError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteCacheClusterError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+            DeleteCacheClusterError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DeleteCacheClusterError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteCacheClusterError::SnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheClusterError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteCacheClusterError {
     #[allow(unused_variables)]
@@ -10463,13 +10792,13 @@ impl Error for DeleteCacheClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache parameter group does not allow the requested operation to occur.</p>
-    InvalidCacheParameterGroupStateFault(String),
+    InvalidCacheParameterGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DeleteCacheParameterGroupError {
@@ -10483,28 +10812,28 @@ impl DeleteCacheParameterGroupError {
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DeleteCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DeleteCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10522,6 +10851,22 @@ impl DeleteCacheParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheParameterGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheParameterGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteCacheParameterGroupError {
     #[allow(unused_variables)]
@@ -10547,13 +10892,13 @@ impl Error for DeleteCacheParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheSecurityGroupError {
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache security group does not allow deletion.</p>
-    InvalidCacheSecurityGroupStateFault(String),
+    InvalidCacheSecurityGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DeleteCacheSecurityGroupError {
@@ -10567,28 +10912,28 @@ impl DeleteCacheSecurityGroupError {
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
                         return RusotoError::Service(
                             DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DeleteCacheSecurityGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DeleteCacheSecurityGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10606,6 +10951,22 @@ impl DeleteCacheSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheSecurityGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCacheSecurityGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteCacheSecurityGroupError {
     #[allow(unused_variables)]
@@ -10631,9 +10992,9 @@ impl Error for DeleteCacheSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheSubnetGroupError {
     /// <p>The requested cache subnet group is currently in use.</p>
-    CacheSubnetGroupInUse(String),
+    CacheSubnetGroupInUse(XmlErrorMessage),
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
-    CacheSubnetGroupNotFoundFault(String),
+    CacheSubnetGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteCacheSubnetGroupError {
@@ -10647,14 +11008,14 @@ impl DeleteCacheSubnetGroupError {
                     "CacheSubnetGroupInUse" => {
                         return RusotoError::Service(
                             DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10672,6 +11033,16 @@ impl DeleteCacheSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(ref cause) => cause.request_id(),
+            DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteCacheSubnetGroupError {
     #[allow(unused_variables)]
@@ -10689,11 +11060,11 @@ impl Error for DeleteCacheSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DeleteGlobalReplicationGroupError {
@@ -10709,19 +11080,19 @@ impl DeleteGlobalReplicationGroupError {
                     "GlobalReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
                         DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DeleteGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10739,6 +11110,21 @@ impl DeleteGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteGlobalReplicationGroupError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -10761,19 +11147,19 @@ impl Error for DeleteGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationGroupError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>You already have a snapshot with the given name.</p>
-    SnapshotAlreadyExistsFault(String),
+    SnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>You attempted one of the following operations:</p> <ul> <li> <p>Creating a snapshot of a Redis cluster running on a <code>cache.t1.micro</code> cache node.</p> </li> <li> <p>Creating a snapshot of a cluster that is running Memcached rather than Redis.</p> </li> </ul> <p>Neither of these are supported by ElastiCache.</p>
-    SnapshotFeatureNotSupportedFault(String),
+    SnapshotFeatureNotSupportedFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteReplicationGroupError {
@@ -10787,49 +11173,49 @@ impl DeleteReplicationGroupError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::SnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
                         return RusotoError::Service(
                             DeleteReplicationGroupError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10847,6 +11233,31 @@ impl DeleteReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteReplicationGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteReplicationGroupError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReplicationGroupError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReplicationGroupError::SnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReplicationGroupError::SnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteReplicationGroupError {
     #[allow(unused_variables)]
@@ -10879,13 +11290,13 @@ impl Error for DeleteReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The current state of the snapshot does not allow the requested operation to occur.</p>
-    InvalidSnapshotStateFault(String),
+    InvalidSnapshotStateFault(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteSnapshotError {
@@ -10898,22 +11309,26 @@ impl DeleteSnapshotError {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            DeleteSnapshotError::InvalidParameterCombination(parsed_error.message),
+                            DeleteSnapshotError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(DeleteSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSnapshotState" => {
                         return RusotoError::Service(
-                            DeleteSnapshotError::InvalidSnapshotStateFault(parsed_error.message),
+                            DeleteSnapshotError::InvalidSnapshotStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(DeleteSnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10930,6 +11345,16 @@ impl DeleteSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSnapshotError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DeleteSnapshotError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteSnapshotError::InvalidSnapshotStateFault(ref cause) => cause.request_id(),
+            DeleteSnapshotError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteSnapshotError {
     #[allow(unused_variables)]
@@ -10947,11 +11372,11 @@ impl Error for DeleteSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheClustersError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeCacheClustersError {
@@ -10965,20 +11390,22 @@ impl DescribeCacheClustersError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             DescribeCacheClustersError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeCacheClustersError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DescribeCacheClustersError::InvalidParameterValue(parsed_error.message),
+                            DescribeCacheClustersError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10995,6 +11422,17 @@ impl DescribeCacheClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCacheClustersError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeCacheClustersError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheClustersError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeCacheClustersError {
     #[allow(unused_variables)]
@@ -11039,6 +11477,11 @@ impl DescribeCacheEngineVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeCacheEngineVersionsError {
     #[allow(unused_variables)]
@@ -11051,11 +11494,11 @@ impl Error for DescribeCacheEngineVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheParameterGroupsError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeCacheParameterGroupsError {
@@ -11071,21 +11514,21 @@ impl DescribeCacheParameterGroupsError {
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeCacheParameterGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeCacheParameterGroupsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11103,6 +11546,21 @@ impl DescribeCacheParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheParameterGroupsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheParameterGroupsError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeCacheParameterGroupsError {
     #[allow(unused_variables)]
@@ -11125,11 +11583,11 @@ impl Error for DescribeCacheParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheParametersError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeCacheParametersError {
@@ -11143,21 +11601,21 @@ impl DescribeCacheParametersError {
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeCacheParametersError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeCacheParametersError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeCacheParametersError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11175,6 +11633,19 @@ impl DescribeCacheParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCacheParametersError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheParametersError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheParametersError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeCacheParametersError {
     #[allow(unused_variables)]
@@ -11197,11 +11668,11 @@ impl Error for DescribeCacheParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheSecurityGroupsError {
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeCacheSecurityGroupsError {
@@ -11217,21 +11688,21 @@ impl DescribeCacheSecurityGroupsError {
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeCacheSecurityGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeCacheSecurityGroupsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11249,6 +11720,21 @@ impl DescribeCacheSecurityGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheSecurityGroupsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeCacheSecurityGroupsError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeCacheSecurityGroupsError {
     #[allow(unused_variables)]
@@ -11271,7 +11757,7 @@ impl Error for DescribeCacheSecurityGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheSubnetGroupsError {
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
-    CacheSubnetGroupNotFoundFault(String),
+    CacheSubnetGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeCacheSubnetGroupsError {
@@ -11285,7 +11771,7 @@ impl DescribeCacheSubnetGroupsError {
                     "CacheSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11303,6 +11789,15 @@ impl DescribeCacheSubnetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeCacheSubnetGroupsError {
     #[allow(unused_variables)]
@@ -11319,9 +11814,9 @@ impl Error for DescribeCacheSubnetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultParametersError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeEngineDefaultParametersError {
@@ -11337,14 +11832,14 @@ impl DescribeEngineDefaultParametersError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeEngineDefaultParametersError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeEngineDefaultParametersError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11362,6 +11857,18 @@ impl DescribeEngineDefaultParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEngineDefaultParametersError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeEngineDefaultParametersError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEngineDefaultParametersError {
     #[allow(unused_variables)]
@@ -11381,9 +11888,9 @@ impl Error for DescribeEngineDefaultParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeEventsError {
@@ -11396,12 +11903,14 @@ impl DescribeEventsError {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            DescribeEventsError::InvalidParameterCombination(parsed_error.message),
+                            DescribeEventsError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11418,6 +11927,14 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEventsError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DescribeEventsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -11433,11 +11950,11 @@ impl Error for DescribeEventsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalReplicationGroupsError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeGlobalReplicationGroupsError {
@@ -11452,20 +11969,20 @@ impl DescribeGlobalReplicationGroupsError {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(
                         DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeGlobalReplicationGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeGlobalReplicationGroupsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11483,6 +12000,21 @@ impl DescribeGlobalReplicationGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            DescribeGlobalReplicationGroupsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeGlobalReplicationGroupsError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeGlobalReplicationGroupsError {
     #[allow(unused_variables)]
@@ -11505,11 +12037,11 @@ impl Error for DescribeGlobalReplicationGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationGroupsError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReplicationGroupsError {
@@ -11523,21 +12055,21 @@ impl DescribeReplicationGroupsError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeReplicationGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeReplicationGroupsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11555,6 +12087,19 @@ impl DescribeReplicationGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReplicationGroupsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReplicationGroupsError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeReplicationGroupsError {
     #[allow(unused_variables)]
@@ -11577,11 +12122,11 @@ impl Error for DescribeReplicationGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedCacheNodesError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested reserved cache node was not found.</p>
-    ReservedCacheNodeNotFoundFault(String),
+    ReservedCacheNodeNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReservedCacheNodesError {
@@ -11597,21 +12142,21 @@ impl DescribeReservedCacheNodesError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeReservedCacheNodesError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeReservedCacheNodesError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedCacheNodeNotFound" => {
                         return RusotoError::Service(
                             DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11629,6 +12174,19 @@ impl DescribeReservedCacheNodesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedCacheNodesError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedCacheNodesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeReservedCacheNodesError {
     #[allow(unused_variables)]
@@ -11651,11 +12209,11 @@ impl Error for DescribeReservedCacheNodesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedCacheNodesOfferingsError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested cache node offering does not exist.</p>
-    ReservedCacheNodesOfferingNotFoundFault(String),
+    ReservedCacheNodesOfferingNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReservedCacheNodesOfferingsError {
@@ -11668,7 +12226,7 @@ impl DescribeReservedCacheNodesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11682,6 +12240,21 @@ impl DescribeReservedCacheNodesOfferingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeReservedCacheNodesOfferingsError {
     #[allow(unused_variables)]
@@ -11704,11 +12277,11 @@ impl Error for DescribeReservedCacheNodesOfferingsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceUpdatesError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The service update doesn't exist</p>
-    ServiceUpdateNotFoundFault(String),
+    ServiceUpdateNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeServiceUpdatesError {
@@ -11722,21 +12295,21 @@ impl DescribeServiceUpdatesError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeServiceUpdatesError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             DescribeServiceUpdatesError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11754,6 +12327,19 @@ impl DescribeServiceUpdatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeServiceUpdatesError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeServiceUpdatesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeServiceUpdatesError {
     #[allow(unused_variables)]
@@ -11774,13 +12360,13 @@ impl Error for DescribeServiceUpdatesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotsError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeSnapshotsError {
@@ -11793,24 +12379,26 @@ impl DescribeSnapshotsError {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
-                            DescribeSnapshotsError::CacheClusterNotFoundFault(parsed_error.message),
+                            DescribeSnapshotsError::CacheClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeSnapshotsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(DescribeSnapshotsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(DescribeSnapshotsError::SnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11827,6 +12415,16 @@ impl DescribeSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeSnapshotsError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeSnapshotsError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            DescribeSnapshotsError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DescribeSnapshotsError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeSnapshotsError {
     #[allow(unused_variables)]
@@ -11846,9 +12444,9 @@ impl Error for DescribeSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeUpdateActionsError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DescribeUpdateActionsError {
@@ -11862,13 +12460,15 @@ impl DescribeUpdateActionsError {
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             DescribeUpdateActionsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            DescribeUpdateActionsError::InvalidParameterValue(parsed_error.message),
+                            DescribeUpdateActionsError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -11885,6 +12485,16 @@ impl DescribeUpdateActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeUpdateActionsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DescribeUpdateActionsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeUpdateActionsError {
     #[allow(unused_variables)]
@@ -11902,13 +12512,13 @@ impl Error for DescribeUpdateActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DisassociateGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl DisassociateGlobalReplicationGroupError {
@@ -11921,7 +12531,7 @@ impl DisassociateGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11935,6 +12545,24 @@ impl DisassociateGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            DisassociateGlobalReplicationGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            DisassociateGlobalReplicationGroupError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DisassociateGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -11960,13 +12588,13 @@ impl Error for DisassociateGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum FailoverGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl FailoverGlobalReplicationGroupError {
@@ -11979,7 +12607,7 @@ impl FailoverGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11993,6 +12621,24 @@ impl FailoverGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            FailoverGlobalReplicationGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            FailoverGlobalReplicationGroupError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for FailoverGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -12018,11 +12664,11 @@ impl Error for FailoverGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum IncreaseNodeGroupsInGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl IncreaseNodeGroupsInGlobalReplicationGroupError {
@@ -12035,7 +12681,7 @@ impl IncreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -12049,6 +12695,15 @@ impl IncreaseNodeGroupsInGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => cause.request_id(),
+            IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for IncreaseNodeGroupsInGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -12065,29 +12720,29 @@ impl Error for IncreaseNodeGroupsInGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum IncreaseReplicaCountError {
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
-    ClusterQuotaForCustomerExceededFault(String),
+    ClusterQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The KMS key supplied is not valid.</p>
-    InvalidKMSKeyFault(String),
+    InvalidKMSKeyFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The operation was not performed because no changes were required.</p>
-    NoOperationFault(String),
+    NoOperationFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum allowed number of node groups (shards) in a single replication group. The default maximum is 90</p>
-    NodeGroupsPerReplicationGroupQuotaExceededFault(String),
+    NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl IncreaseReplicaCountError {
@@ -12101,76 +12756,78 @@ impl IncreaseReplicaCountError {
                     "ClusterQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidKMSKeyFault" => {
                         return RusotoError::Service(IncreaseReplicaCountError::InvalidKMSKeyFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
+                            IncreaseReplicaCountError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoOperationFault" => {
                         return RusotoError::Service(IncreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
                         IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "NodeQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             IncreaseReplicaCountError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12188,6 +12845,38 @@ impl IncreaseReplicaCountError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            IncreaseReplicaCountError::InvalidCacheClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            IncreaseReplicaCountError::InvalidKMSKeyFault(ref cause) => cause.request_id(),
+            IncreaseReplicaCountError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            IncreaseReplicaCountError::InvalidParameterValue(ref cause) => cause.request_id(),
+            IncreaseReplicaCountError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            IncreaseReplicaCountError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            IncreaseReplicaCountError::NoOperationFault(ref cause) => cause.request_id(),
+            IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            IncreaseReplicaCountError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for IncreaseReplicaCountError {
     #[allow(unused_variables)]
@@ -12231,13 +12920,13 @@ impl Error for IncreaseReplicaCountError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAllowedNodeTypeModificationsError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl ListAllowedNodeTypeModificationsError {
@@ -12253,28 +12942,28 @@ impl ListAllowedNodeTypeModificationsError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             ListAllowedNodeTypeModificationsError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             ListAllowedNodeTypeModificationsError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12292,6 +12981,24 @@ impl ListAllowedNodeTypeModificationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ListAllowedNodeTypeModificationsError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            ListAllowedNodeTypeModificationsError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+            ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListAllowedNodeTypeModificationsError {
     #[allow(unused_variables)]
@@ -12317,11 +13024,11 @@ impl Error for ListAllowedNodeTypeModificationsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
-    InvalidARNFault(String),
+    InvalidARNFault(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -12335,18 +13042,20 @@ impl ListTagsForResourceError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             ListTagsForResourceError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidARN" => {
                         return RusotoError::Service(ListTagsForResourceError::InvalidARNFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::SnapshotNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::SnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12363,6 +13072,15 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::InvalidARNFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -12381,27 +13099,27 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The current state of the cache security group does not allow deletion.</p>
-    InvalidCacheSecurityGroupStateFault(String),
+    InvalidCacheSecurityGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes in a single cluster.</p>
-    NodeQuotaForClusterExceededFault(String),
+    NodeQuotaForClusterExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
 }
 
 impl ModifyCacheClusterError {
@@ -12415,75 +13133,77 @@ impl ModifyCacheClusterError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            ModifyCacheClusterError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12501,6 +13221,35 @@ impl ModifyCacheClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyCacheClusterError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyCacheClusterError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheClusterError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+            ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheClusterError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            ModifyCacheClusterError::InvalidParameterValue(ref cause) => cause.request_id(),
+            ModifyCacheClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyCacheClusterError::NodeQuotaForClusterExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyCacheClusterError {
     #[allow(unused_variables)]
@@ -12543,15 +13292,15 @@ impl Error for ModifyCacheClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache parameter group does not allow the requested operation to occur.</p>
-    InvalidCacheParameterGroupStateFault(String),
+    InvalidCacheParameterGroupStateFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ModifyCacheParameterGroupError {
@@ -12565,35 +13314,35 @@ impl ModifyCacheParameterGroupError {
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
                         return RusotoError::Service(
                             ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             ModifyCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             ModifyCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12611,6 +13360,25 @@ impl ModifyCacheParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheParameterGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheParameterGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyCacheParameterGroupError {
     #[allow(unused_variables)]
@@ -12639,13 +13407,13 @@ impl Error for ModifyCacheParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheSubnetGroupError {
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
-    CacheSubnetGroupNotFoundFault(String),
+    CacheSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of subnets in a cache subnet group.</p>
-    CacheSubnetQuotaExceededFault(String),
+    CacheSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>An invalid subnet identifier was specified.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The requested subnet is being used by another cache subnet group.</p>
-    SubnetInUse(String),
+    SubnetInUse(XmlErrorMessage),
 }
 
 impl ModifyCacheSubnetGroupError {
@@ -12659,25 +13427,25 @@ impl ModifyCacheSubnetGroupError {
                     "CacheSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetInUse" => {
                         return RusotoError::Service(ModifyCacheSubnetGroupError::SubnetInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12694,6 +13462,20 @@ impl ModifyCacheSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCacheSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyCacheSubnetGroupError::SubnetInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyCacheSubnetGroupError {
     #[allow(unused_variables)]
@@ -12715,11 +13497,11 @@ impl Error for ModifyCacheSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ModifyGlobalReplicationGroupError {
@@ -12735,19 +13517,19 @@ impl ModifyGlobalReplicationGroupError {
                     "GlobalReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
                         ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             ModifyGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12765,6 +13547,21 @@ impl ModifyGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            ModifyGlobalReplicationGroupError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -12787,33 +13584,33 @@ impl Error for ModifyGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationGroupError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The current state of the cache security group does not allow deletion.</p>
-    InvalidCacheSecurityGroupStateFault(String),
+    InvalidCacheSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The KMS key supplied is not valid.</p>
-    InvalidKMSKeyFault(String),
+    InvalidKMSKeyFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes in a single cluster.</p>
-    NodeQuotaForClusterExceededFault(String),
+    NodeQuotaForClusterExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyReplicationGroupError {
@@ -12827,96 +13624,98 @@ impl ModifyReplicationGroupError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidKMSKeyFault" => {
                         return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidKMSKeyFault(parsed_error.message),
+                            ModifyReplicationGroupError::InvalidKMSKeyFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12934,6 +13733,48 @@ impl ModifyReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyReplicationGroupError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InvalidCacheClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InvalidKMSKeyFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+            ModifyReplicationGroupError::InvalidReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyReplicationGroupError::ReplicationGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyReplicationGroupError {
     #[allow(unused_variables)]
@@ -12985,25 +13826,25 @@ impl Error for ModifyReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationGroupShardConfigurationError {
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
-    InsufficientCacheClusterCapacityFault(String),
+    InsufficientCacheClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The KMS key supplied is not valid.</p>
-    InvalidKMSKeyFault(String),
+    InvalidKMSKeyFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The VPC network is in an invalid state.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the maximum allowed number of node groups (shards) in a single replication group. The default maximum is 90</p>
-    NodeGroupsPerReplicationGroupQuotaExceededFault(String),
+    NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
-    NodeQuotaForCustomerExceededFault(String),
+    NodeQuotaForCustomerExceededFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyReplicationGroupShardConfigurationError {
@@ -13016,7 +13857,7 @@ impl ModifyReplicationGroupShardConfigurationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InsufficientCacheClusterCapacity" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidKMSKeyFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message)),"InvalidReplicationGroupState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupNotFoundFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message)),_ => {}
+                                    "InsufficientCacheClusterCapacity" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidCacheClusterState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidKMSKeyFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterCombination" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidReplicationGroupState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidVPCNetworkStateFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReplicationGroupNotFoundFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -13030,6 +13871,22 @@ impl ModifyReplicationGroupShardConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(ref cause) => cause.request_id(),
+            ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyReplicationGroupShardConfigurationError {
     #[allow(unused_variables)]
@@ -13053,15 +13910,15 @@ impl Error for ModifyReplicationGroupShardConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedCacheNodesOfferingError {
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>You already have a reservation with the given identifier.</p>
-    ReservedCacheNodeAlreadyExistsFault(String),
+    ReservedCacheNodeAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request cannot be processed because it would exceed the user's cache node quota.</p>
-    ReservedCacheNodeQuotaExceededFault(String),
+    ReservedCacheNodeQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested cache node offering does not exist.</p>
-    ReservedCacheNodesOfferingNotFoundFault(String),
+    ReservedCacheNodesOfferingNotFoundFault(XmlErrorMessage),
 }
 
 impl PurchaseReservedCacheNodesOfferingError {
@@ -13074,7 +13931,7 @@ impl PurchaseReservedCacheNodesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodeAlreadyExists" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message)),"ReservedCacheNodeQuotaExceeded" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedCacheNodeAlreadyExists" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedCacheNodeQuotaExceeded" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -13088,6 +13945,27 @@ impl PurchaseReservedCacheNodesOfferingError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PurchaseReservedCacheNodesOfferingError {
     #[allow(unused_variables)]
@@ -13116,11 +13994,11 @@ impl Error for PurchaseReservedCacheNodesOfferingError {}
 #[derive(Debug, PartialEq)]
 pub enum RebalanceSlotsInGlobalReplicationGroupError {
     /// <p>The Global Datastore does not exist</p>
-    GlobalReplicationGroupNotFoundFault(String),
+    GlobalReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl RebalanceSlotsInGlobalReplicationGroupError {
@@ -13133,7 +14011,7 @@ impl RebalanceSlotsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidParameterValue" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -13147,6 +14025,15 @@ impl RebalanceSlotsInGlobalReplicationGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => cause.request_id(),
+            RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebalanceSlotsInGlobalReplicationGroupError {
     #[allow(unused_variables)]
@@ -13163,9 +14050,9 @@ impl Error for RebalanceSlotsInGlobalReplicationGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RebootCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
 }
 
 impl RebootCacheClusterError {
@@ -13179,14 +14066,14 @@ impl RebootCacheClusterError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             RebootCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
                             RebootCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13204,6 +14091,14 @@ impl RebootCacheClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebootCacheClusterError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            RebootCacheClusterError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebootCacheClusterError {
     #[allow(unused_variables)]
@@ -13221,13 +14116,13 @@ impl Error for RebootCacheClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
-    CacheClusterNotFoundFault(String),
+    CacheClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
-    InvalidARNFault(String),
+    InvalidARNFault(XmlErrorMessage),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
-    SnapshotNotFoundFault(String),
+    SnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The requested tag was not found on this resource.</p>
-    TagNotFoundFault(String),
+    TagNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveTagsFromResourceError {
@@ -13241,25 +14136,25 @@ impl RemoveTagsFromResourceError {
                     "CacheClusterNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::CacheClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidARN" => {
                         return RusotoError::Service(RemoveTagsFromResourceError::InvalidARNFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::SnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagNotFound" => {
                         return RusotoError::Service(RemoveTagsFromResourceError::TagNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13276,6 +14171,16 @@ impl RemoveTagsFromResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsFromResourceError::CacheClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::InvalidARNFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::SnapshotNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::TagNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsFromResourceError {
     #[allow(unused_variables)]
@@ -13295,15 +14200,15 @@ impl Error for RemoveTagsFromResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
-    CacheParameterGroupNotFoundFault(String),
+    CacheParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache parameter group does not allow the requested operation to occur.</p>
-    InvalidCacheParameterGroupStateFault(String),
+    InvalidCacheParameterGroupStateFault(XmlErrorMessage),
     /// <p>The Global Datastore is not available or in primary-only state.</p>
-    InvalidGlobalReplicationGroupStateFault(String),
+    InvalidGlobalReplicationGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ResetCacheParameterGroupError {
@@ -13317,35 +14222,35 @@ impl ResetCacheParameterGroupError {
                     "CacheParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
                         return RusotoError::Service(
                             ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
                         return RusotoError::Service(
                             ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             ResetCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             ResetCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13363,6 +14268,25 @@ impl ResetCacheParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetCacheParameterGroupError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            ResetCacheParameterGroupError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResetCacheParameterGroupError {
     #[allow(unused_variables)]
@@ -13391,15 +14315,15 @@ impl Error for ResetCacheParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RevokeCacheSecurityGroupIngressError {
     /// <p>The specified Amazon EC2 security group is not authorized for the specified cache security group.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
-    CacheSecurityGroupNotFoundFault(String),
+    CacheSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The current state of the cache security group does not allow deletion.</p>
-    InvalidCacheSecurityGroupStateFault(String),
+    InvalidCacheSecurityGroupStateFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl RevokeCacheSecurityGroupIngressError {
@@ -13415,33 +14339,33 @@ impl RevokeCacheSecurityGroupIngressError {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => return RusotoError::Service(
                         RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
                             RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
                             RevokeCacheSecurityGroupIngressError::InvalidParameterValue(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13459,6 +14383,27 @@ impl RevokeCacheSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(ref cause) => {
+                cause.request_id()
+            }
+            RevokeCacheSecurityGroupIngressError::InvalidParameterValue(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RevokeCacheSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -13487,13 +14432,13 @@ impl Error for RevokeCacheSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum StartMigrationError {
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The targeted replication group is not available. </p>
-    ReplicationGroupAlreadyUnderMigrationFault(String),
+    ReplicationGroupAlreadyUnderMigrationFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl StartMigrationError {
@@ -13506,27 +14451,27 @@ impl StartMigrationError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(StartMigrationError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             StartMigrationError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupAlreadyUnderMigrationFault" => {
                         return RusotoError::Service(
                             StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
                             StartMigrationError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13544,6 +14489,18 @@ impl StartMigrationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartMigrationError::InvalidParameterValue(ref cause) => cause.request_id(),
+            StartMigrationError::InvalidReplicationGroupStateFault(ref cause) => cause.request_id(),
+            StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(ref cause) => {
+                cause.request_id()
+            }
+            StartMigrationError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartMigrationError {
     #[allow(unused_variables)]
@@ -13565,23 +14522,23 @@ impl Error for StartMigrationError {}
 #[derive(Debug, PartialEq)]
 pub enum TestFailoverError {
     /// <p>The customer has exceeded the allowed rate of API calls.</p>
-    APICallRateForCustomerExceededFault(String),
+    APICallRateForCustomerExceededFault(XmlErrorMessage),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
-    InvalidCacheClusterStateFault(String),
+    InvalidCacheClusterStateFault(XmlErrorMessage),
     /// <p>The KMS key supplied is not valid.</p>
-    InvalidKMSKeyFault(String),
+    InvalidKMSKeyFault(XmlErrorMessage),
     /// <p>Two or more incompatible parameters were specified.</p>
-    InvalidParameterCombination(String),
+    InvalidParameterCombination(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
-    InvalidReplicationGroupStateFault(String),
+    InvalidReplicationGroupStateFault(XmlErrorMessage),
     /// <p>The node group specified by the <code>NodeGroupId</code> parameter could not be found. Please verify that the node group exists and that you spelled the <code>NodeGroupId</code> value correctly.</p>
-    NodeGroupNotFoundFault(String),
+    NodeGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified replication group does not exist.</p>
-    ReplicationGroupNotFoundFault(String),
+    ReplicationGroupNotFoundFault(XmlErrorMessage),
     /// <p>The <code>TestFailover</code> action is not available.</p>
-    TestFailoverNotAvailableFault(String),
+    TestFailoverNotAvailableFault(XmlErrorMessage),
 }
 
 impl TestFailoverError {
@@ -13595,50 +14552,58 @@ impl TestFailoverError {
                     "APICallRateForCustomerExceeded" => {
                         return RusotoError::Service(
                             TestFailoverError::APICallRateForCustomerExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
                         return RusotoError::Service(
-                            TestFailoverError::InvalidCacheClusterStateFault(parsed_error.message),
+                            TestFailoverError::InvalidCacheClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidKMSKeyFault" => {
                         return RusotoError::Service(TestFailoverError::InvalidKMSKeyFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterCombination" => {
                         return RusotoError::Service(
-                            TestFailoverError::InvalidParameterCombination(parsed_error.message),
+                            TestFailoverError::InvalidParameterCombination(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(TestFailoverError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidReplicationGroupState" => {
                         return RusotoError::Service(
                             TestFailoverError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NodeGroupNotFoundFault" => {
                         return RusotoError::Service(TestFailoverError::NodeGroupNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ReplicationGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            TestFailoverError::ReplicationGroupNotFoundFault(parsed_error.message),
+                            TestFailoverError::ReplicationGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TestFailoverNotAvailableFault" => {
                         return RusotoError::Service(
-                            TestFailoverError::TestFailoverNotAvailableFault(parsed_error.message),
+                            TestFailoverError::TestFailoverNotAvailableFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -13655,6 +14620,21 @@ impl TestFailoverError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TestFailoverError::APICallRateForCustomerExceededFault(ref cause) => cause.request_id(),
+            TestFailoverError::InvalidCacheClusterStateFault(ref cause) => cause.request_id(),
+            TestFailoverError::InvalidKMSKeyFault(ref cause) => cause.request_id(),
+            TestFailoverError::InvalidParameterCombination(ref cause) => cause.request_id(),
+            TestFailoverError::InvalidParameterValue(ref cause) => cause.request_id(),
+            TestFailoverError::InvalidReplicationGroupStateFault(ref cause) => cause.request_id(),
+            TestFailoverError::NodeGroupNotFoundFault(ref cause) => cause.request_id(),
+            TestFailoverError::ReplicationGroupNotFoundFault(ref cause) => cause.request_id(),
+            TestFailoverError::TestFailoverNotAvailableFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TestFailoverError {
     #[allow(unused_variables)]
@@ -52,7 +52,7 @@ impl ElasticBeanstalkClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7720,7 +7720,7 @@ impl VirtualizationTypeDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AbortEnvironmentUpdateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl AbortEnvironmentUpdateError {
@@ -7734,7 +7734,7 @@ impl AbortEnvironmentUpdateError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             AbortEnvironmentUpdateError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7752,6 +7752,13 @@ impl AbortEnvironmentUpdateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AbortEnvironmentUpdateError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AbortEnvironmentUpdateError {
     #[allow(unused_variables)]
@@ -7768,9 +7775,9 @@ impl Error for AbortEnvironmentUpdateError {}
 #[derive(Debug, PartialEq)]
 pub enum ApplyEnvironmentManagedActionError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>Cannot modify the managed action in its current state.</p>
-    ManagedActionInvalidState(String),
+    ManagedActionInvalidState(XmlErrorMessage),
 }
 
 impl ApplyEnvironmentManagedActionError {
@@ -7786,14 +7793,14 @@ impl ApplyEnvironmentManagedActionError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             ApplyEnvironmentManagedActionError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ManagedActionInvalidStateException" => {
                         return RusotoError::Service(
                             ApplyEnvironmentManagedActionError::ManagedActionInvalidState(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7811,6 +7818,18 @@ impl ApplyEnvironmentManagedActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ApplyEnvironmentManagedActionError::ElasticBeanstalkService(ref cause) => {
+                cause.request_id()
+            }
+            ApplyEnvironmentManagedActionError::ManagedActionInvalidState(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ApplyEnvironmentManagedActionError {
     #[allow(unused_variables)]
@@ -7830,7 +7849,7 @@ impl Error for ApplyEnvironmentManagedActionError {}
 #[derive(Debug, PartialEq)]
 pub enum AssociateEnvironmentOperationsRoleError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl AssociateEnvironmentOperationsRoleError {
@@ -7846,7 +7865,7 @@ impl AssociateEnvironmentOperationsRoleError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             AssociateEnvironmentOperationsRoleError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7864,6 +7883,15 @@ impl AssociateEnvironmentOperationsRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AssociateEnvironmentOperationsRoleError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AssociateEnvironmentOperationsRoleError {
     #[allow(unused_variables)]
@@ -7902,6 +7930,11 @@ impl CheckDNSAvailabilityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CheckDNSAvailabilityError {
     #[allow(unused_variables)]
@@ -7914,9 +7947,9 @@ impl Error for CheckDNSAvailabilityError {}
 #[derive(Debug, PartialEq)]
 pub enum ComposeEnvironmentsError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of environments.</p>
-    TooManyEnvironments(String),
+    TooManyEnvironments(XmlErrorMessage),
 }
 
 impl ComposeEnvironmentsError {
@@ -7929,12 +7962,14 @@ impl ComposeEnvironmentsError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            ComposeEnvironmentsError::InsufficientPrivileges(parsed_error.message),
+                            ComposeEnvironmentsError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyEnvironmentsException" => {
                         return RusotoError::Service(ComposeEnvironmentsError::TooManyEnvironments(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7951,6 +7986,14 @@ impl ComposeEnvironmentsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ComposeEnvironmentsError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            ComposeEnvironmentsError::TooManyEnvironments(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ComposeEnvironmentsError {
     #[allow(unused_variables)]
@@ -7966,7 +8009,7 @@ impl Error for ComposeEnvironmentsError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
     /// <p>The specified account has reached its limit of applications.</p>
-    TooManyApplications(String),
+    TooManyApplications(XmlErrorMessage),
 }
 
 impl CreateApplicationError {
@@ -7979,7 +8022,7 @@ impl CreateApplicationError {
                 match &parsed_error.code[..] {
                     "TooManyApplicationsException" => {
                         return RusotoError::Service(CreateApplicationError::TooManyApplications(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7996,6 +8039,13 @@ impl CreateApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateApplicationError::TooManyApplications(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateApplicationError {
     #[allow(unused_variables)]
@@ -8010,15 +8060,15 @@ impl Error for CreateApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationVersionError {
     /// <p>AWS CodeBuild is not available in the specified region.</p>
-    CodeBuildNotInServiceRegion(String),
+    CodeBuildNotInServiceRegion(XmlErrorMessage),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p><p>The specified S3 bucket does not belong to the S3 region in which the service is running. The following regions are supported:</p> <ul> <li> <p>IAD/us-east-1</p> </li> <li> <p>PDX/us-west-2</p> </li> <li> <p>DUB/eu-west-1</p> </li> </ul></p>
-    S3LocationNotInServiceRegion(String),
+    S3LocationNotInServiceRegion(XmlErrorMessage),
     /// <p>The specified account has reached its limit of application versions.</p>
-    TooManyApplicationVersions(String),
+    TooManyApplicationVersions(XmlErrorMessage),
     /// <p>The specified account has reached its limit of applications.</p>
-    TooManyApplications(String),
+    TooManyApplications(XmlErrorMessage),
 }
 
 impl CreateApplicationVersionError {
@@ -8032,35 +8082,35 @@ impl CreateApplicationVersionError {
                     "CodeBuildNotInServiceRegionException" => {
                         return RusotoError::Service(
                             CreateApplicationVersionError::CodeBuildNotInServiceRegion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             CreateApplicationVersionError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
                         return RusotoError::Service(
                             CreateApplicationVersionError::S3LocationNotInServiceRegion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyApplicationVersionsException" => {
                         return RusotoError::Service(
                             CreateApplicationVersionError::TooManyApplicationVersions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyApplicationsException" => {
                         return RusotoError::Service(
                             CreateApplicationVersionError::TooManyApplications(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8078,6 +8128,23 @@ impl CreateApplicationVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateApplicationVersionError::CodeBuildNotInServiceRegion(ref cause) => {
+                cause.request_id()
+            }
+            CreateApplicationVersionError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            CreateApplicationVersionError::S3LocationNotInServiceRegion(ref cause) => {
+                cause.request_id()
+            }
+            CreateApplicationVersionError::TooManyApplicationVersions(ref cause) => {
+                cause.request_id()
+            }
+            CreateApplicationVersionError::TooManyApplications(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateApplicationVersionError {
     #[allow(unused_variables)]
@@ -8104,11 +8171,11 @@ impl Error for CreateApplicationVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationTemplateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
     /// <p>The specified account has reached its limit of configuration templates.</p>
-    TooManyConfigurationTemplates(String),
+    TooManyConfigurationTemplates(XmlErrorMessage),
 }
 
 impl CreateConfigurationTemplateError {
@@ -8124,19 +8191,21 @@ impl CreateConfigurationTemplateError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             CreateConfigurationTemplateError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
                         return RusotoError::Service(
-                            CreateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                            CreateConfigurationTemplateError::TooManyBuckets(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyConfigurationTemplatesException" => {
                         return RusotoError::Service(
                             CreateConfigurationTemplateError::TooManyConfigurationTemplates(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8154,6 +8223,19 @@ impl CreateConfigurationTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateConfigurationTemplateError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationTemplateError::TooManyBuckets(ref cause) => cause.request_id(),
+            CreateConfigurationTemplateError::TooManyConfigurationTemplates(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateConfigurationTemplateError {
     #[allow(unused_variables)]
@@ -8174,9 +8256,9 @@ impl Error for CreateConfigurationTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of environments.</p>
-    TooManyEnvironments(String),
+    TooManyEnvironments(XmlErrorMessage),
 }
 
 impl CreateEnvironmentError {
@@ -8189,12 +8271,14 @@ impl CreateEnvironmentError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            CreateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            CreateEnvironmentError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyEnvironmentsException" => {
                         return RusotoError::Service(CreateEnvironmentError::TooManyEnvironments(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8211,6 +8295,14 @@ impl CreateEnvironmentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateEnvironmentError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            CreateEnvironmentError::TooManyEnvironments(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateEnvironmentError {
     #[allow(unused_variables)]
@@ -8226,11 +8318,11 @@ impl Error for CreateEnvironmentError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of allowed platforms associated with the account.</p>
-    TooManyPlatforms(String),
+    TooManyPlatforms(XmlErrorMessage),
 }
 
 impl CreatePlatformVersionError {
@@ -8244,20 +8336,20 @@ impl CreatePlatformVersionError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             CreatePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             CreatePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyPlatformsException" => {
                         return RusotoError::Service(CreatePlatformVersionError::TooManyPlatforms(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8274,6 +8366,15 @@ impl CreatePlatformVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePlatformVersionError::ElasticBeanstalkService(ref cause) => cause.request_id(),
+            CreatePlatformVersionError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            CreatePlatformVersionError::TooManyPlatforms(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePlatformVersionError {
     #[allow(unused_variables)]
@@ -8292,11 +8393,11 @@ impl Error for CreatePlatformVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateStorageLocationError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account does not have a subscription to Amazon S3.</p>
-    S3SubscriptionRequired(String),
+    S3SubscriptionRequired(XmlErrorMessage),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl CreateStorageLocationError {
@@ -8310,20 +8411,20 @@ impl CreateStorageLocationError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             CreateStorageLocationError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "S3SubscriptionRequiredException" => {
                         return RusotoError::Service(
                             CreateStorageLocationError::S3SubscriptionRequired(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
                         return RusotoError::Service(CreateStorageLocationError::TooManyBuckets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8340,6 +8441,15 @@ impl CreateStorageLocationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateStorageLocationError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            CreateStorageLocationError::S3SubscriptionRequired(ref cause) => cause.request_id(),
+            CreateStorageLocationError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateStorageLocationError {
     #[allow(unused_variables)]
@@ -8356,7 +8466,7 @@ impl Error for CreateStorageLocationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
 }
 
 impl DeleteApplicationError {
@@ -8369,7 +8479,7 @@ impl DeleteApplicationError {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
                         return RusotoError::Service(DeleteApplicationError::OperationInProgress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8386,6 +8496,13 @@ impl DeleteApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteApplicationError::OperationInProgress(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteApplicationError {
     #[allow(unused_variables)]
@@ -8400,13 +8517,13 @@ impl Error for DeleteApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationVersionError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p><p>The specified S3 bucket does not belong to the S3 region in which the service is running. The following regions are supported:</p> <ul> <li> <p>IAD/us-east-1</p> </li> <li> <p>PDX/us-west-2</p> </li> <li> <p>DUB/eu-west-1</p> </li> </ul></p>
-    S3LocationNotInServiceRegion(String),
+    S3LocationNotInServiceRegion(XmlErrorMessage),
     /// <p>Unable to delete the Amazon S3 source bundle associated with the application version. The application version was deleted successfully.</p>
-    SourceBundleDeletion(String),
+    SourceBundleDeletion(XmlErrorMessage),
 }
 
 impl DeleteApplicationVersionError {
@@ -8420,28 +8537,28 @@ impl DeleteApplicationVersionError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DeleteApplicationVersionError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
                         return RusotoError::Service(
                             DeleteApplicationVersionError::OperationInProgress(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
                         return RusotoError::Service(
                             DeleteApplicationVersionError::S3LocationNotInServiceRegion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SourceBundleDeletionFailure" => {
                         return RusotoError::Service(
                             DeleteApplicationVersionError::SourceBundleDeletion(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8459,6 +8576,18 @@ impl DeleteApplicationVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteApplicationVersionError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            DeleteApplicationVersionError::OperationInProgress(ref cause) => cause.request_id(),
+            DeleteApplicationVersionError::S3LocationNotInServiceRegion(ref cause) => {
+                cause.request_id()
+            }
+            DeleteApplicationVersionError::SourceBundleDeletion(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteApplicationVersionError {
     #[allow(unused_variables)]
@@ -8482,7 +8611,7 @@ impl Error for DeleteApplicationVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationTemplateError {
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
 }
 
 impl DeleteConfigurationTemplateError {
@@ -8498,7 +8627,7 @@ impl DeleteConfigurationTemplateError {
                     "OperationInProgressFailure" => {
                         return RusotoError::Service(
                             DeleteConfigurationTemplateError::OperationInProgress(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8516,6 +8645,13 @@ impl DeleteConfigurationTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteConfigurationTemplateError::OperationInProgress(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteConfigurationTemplateError {
     #[allow(unused_variables)]
@@ -8556,6 +8692,11 @@ impl DeleteEnvironmentConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteEnvironmentConfigurationError {
     #[allow(unused_variables)]
@@ -8568,13 +8709,13 @@ impl Error for DeleteEnvironmentConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>You cannot delete the platform version because there are still environments running on it.</p>
-    PlatformVersionStillReferenced(String),
+    PlatformVersionStillReferenced(XmlErrorMessage),
 }
 
 impl DeletePlatformVersionError {
@@ -8588,26 +8729,28 @@ impl DeletePlatformVersionError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DeletePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DeletePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
                         return RusotoError::Service(
-                            DeletePlatformVersionError::OperationInProgress(parsed_error.message),
+                            DeletePlatformVersionError::OperationInProgress(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "PlatformVersionStillReferencedException" => {
                         return RusotoError::Service(
                             DeletePlatformVersionError::PlatformVersionStillReferenced(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8625,6 +8768,18 @@ impl DeletePlatformVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePlatformVersionError::ElasticBeanstalkService(ref cause) => cause.request_id(),
+            DeletePlatformVersionError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            DeletePlatformVersionError::OperationInProgress(ref cause) => cause.request_id(),
+            DeletePlatformVersionError::PlatformVersionStillReferenced(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeletePlatformVersionError {
     #[allow(unused_variables)]
@@ -8646,7 +8801,7 @@ impl Error for DeletePlatformVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl DescribeAccountAttributesError {
@@ -8660,7 +8815,7 @@ impl DescribeAccountAttributesError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DescribeAccountAttributesError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8678,6 +8833,13 @@ impl DescribeAccountAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeAccountAttributesError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
@@ -8718,6 +8880,11 @@ impl DescribeApplicationVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeApplicationVersionsError {
     #[allow(unused_variables)]
@@ -8752,6 +8919,11 @@ impl DescribeApplicationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeApplicationsError {
     #[allow(unused_variables)]
@@ -8764,7 +8936,7 @@ impl Error for DescribeApplicationsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationOptionsError {
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl DescribeConfigurationOptionsError {
@@ -8779,7 +8951,9 @@ impl DescribeConfigurationOptionsError {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
                         return RusotoError::Service(
-                            DescribeConfigurationOptionsError::TooManyBuckets(parsed_error.message),
+                            DescribeConfigurationOptionsError::TooManyBuckets(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8796,6 +8970,13 @@ impl DescribeConfigurationOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeConfigurationOptionsError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeConfigurationOptionsError {
     #[allow(unused_variables)]
@@ -8810,7 +8991,7 @@ impl Error for DescribeConfigurationOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationSettingsError {
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl DescribeConfigurationSettingsError {
@@ -8826,7 +9007,7 @@ impl DescribeConfigurationSettingsError {
                     "TooManyBucketsException" => {
                         return RusotoError::Service(
                             DescribeConfigurationSettingsError::TooManyBuckets(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8844,6 +9025,13 @@ impl DescribeConfigurationSettingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeConfigurationSettingsError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeConfigurationSettingsError {
     #[allow(unused_variables)]
@@ -8858,9 +9046,9 @@ impl Error for DescribeConfigurationSettingsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentHealthError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>One or more input parameters is not valid. Please correct the input parameters and try the operation again.</p>
-    InvalidRequest(String),
+    InvalidRequest(XmlErrorMessage),
 }
 
 impl DescribeEnvironmentHealthError {
@@ -8874,13 +9062,15 @@ impl DescribeEnvironmentHealthError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DescribeEnvironmentHealthError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRequestException" => {
                         return RusotoError::Service(
-                            DescribeEnvironmentHealthError::InvalidRequest(parsed_error.message),
+                            DescribeEnvironmentHealthError::InvalidRequest(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8897,6 +9087,16 @@ impl DescribeEnvironmentHealthError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEnvironmentHealthError::ElasticBeanstalkService(ref cause) => {
+                cause.request_id()
+            }
+            DescribeEnvironmentHealthError::InvalidRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeEnvironmentHealthError {
     #[allow(unused_variables)]
@@ -8914,7 +9114,7 @@ impl Error for DescribeEnvironmentHealthError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentManagedActionHistoryError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
 }
 
 impl DescribeEnvironmentManagedActionHistoryError {
@@ -8930,7 +9130,7 @@ impl DescribeEnvironmentManagedActionHistoryError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8948,6 +9148,15 @@ impl DescribeEnvironmentManagedActionHistoryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEnvironmentManagedActionHistoryError {
     #[allow(unused_variables)]
@@ -8964,7 +9173,7 @@ impl Error for DescribeEnvironmentManagedActionHistoryError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentManagedActionsError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
 }
 
 impl DescribeEnvironmentManagedActionsError {
@@ -8980,7 +9189,7 @@ impl DescribeEnvironmentManagedActionsError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8998,6 +9207,15 @@ impl DescribeEnvironmentManagedActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEnvironmentManagedActionsError {
     #[allow(unused_variables)]
@@ -9014,7 +9232,7 @@ impl Error for DescribeEnvironmentManagedActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentResourcesError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl DescribeEnvironmentResourcesError {
@@ -9030,7 +9248,7 @@ impl DescribeEnvironmentResourcesError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DescribeEnvironmentResourcesError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9048,6 +9266,15 @@ impl DescribeEnvironmentResourcesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEnvironmentResourcesError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEnvironmentResourcesError {
     #[allow(unused_variables)]
@@ -9086,6 +9313,11 @@ impl DescribeEnvironmentsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEnvironmentsError {
     #[allow(unused_variables)]
@@ -9120,6 +9352,11 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -9132,9 +9369,9 @@ impl Error for DescribeEventsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancesHealthError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>One or more input parameters is not valid. Please correct the input parameters and try the operation again.</p>
-    InvalidRequest(String),
+    InvalidRequest(XmlErrorMessage),
 }
 
 impl DescribeInstancesHealthError {
@@ -9148,13 +9385,13 @@ impl DescribeInstancesHealthError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DescribeInstancesHealthError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRequestException" => {
                         return RusotoError::Service(DescribeInstancesHealthError::InvalidRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9171,6 +9408,14 @@ impl DescribeInstancesHealthError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeInstancesHealthError::ElasticBeanstalkService(ref cause) => cause.request_id(),
+            DescribeInstancesHealthError::InvalidRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeInstancesHealthError {
     #[allow(unused_variables)]
@@ -9188,9 +9433,9 @@ impl Error for DescribeInstancesHealthError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl DescribePlatformVersionError {
@@ -9204,14 +9449,14 @@ impl DescribePlatformVersionError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             DescribePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DescribePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9229,6 +9474,14 @@ impl DescribePlatformVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribePlatformVersionError::ElasticBeanstalkService(ref cause) => cause.request_id(),
+            DescribePlatformVersionError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribePlatformVersionError {
     #[allow(unused_variables)]
@@ -9248,7 +9501,7 @@ impl Error for DescribePlatformVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum DisassociateEnvironmentOperationsRoleError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl DisassociateEnvironmentOperationsRoleError {
@@ -9264,7 +9517,7 @@ impl DisassociateEnvironmentOperationsRoleError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9282,6 +9535,15 @@ impl DisassociateEnvironmentOperationsRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DisassociateEnvironmentOperationsRoleError {
     #[allow(unused_variables)]
@@ -9322,6 +9584,11 @@ impl ListAvailableSolutionStacksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListAvailableSolutionStacksError {
     #[allow(unused_variables)]
@@ -9356,6 +9623,11 @@ impl ListPlatformBranchesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListPlatformBranchesError {
     #[allow(unused_variables)]
@@ -9368,9 +9640,9 @@ impl Error for ListPlatformBranchesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPlatformVersionsError {
     /// <p>A generic service exception has occurred.</p>
-    ElasticBeanstalkService(String),
+    ElasticBeanstalkService(XmlErrorMessage),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl ListPlatformVersionsError {
@@ -9384,13 +9656,15 @@ impl ListPlatformVersionsError {
                     "ElasticBeanstalkServiceException" => {
                         return RusotoError::Service(
                             ListPlatformVersionsError::ElasticBeanstalkService(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            ListPlatformVersionsError::InsufficientPrivileges(parsed_error.message),
+                            ListPlatformVersionsError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9407,6 +9681,14 @@ impl ListPlatformVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPlatformVersionsError::ElasticBeanstalkService(ref cause) => cause.request_id(),
+            ListPlatformVersionsError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPlatformVersionsError {
     #[allow(unused_variables)]
@@ -9422,11 +9704,11 @@ impl Error for ListPlatformVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>A resource doesn't exist for the specified Amazon Resource Name (ARN).</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>The type of the specified Amazon Resource Name (ARN) isn't supported for this operation.</p>
-    ResourceTypeNotSupported(String),
+    ResourceTypeNotSupported(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -9439,18 +9721,20 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::InsufficientPrivileges(parsed_error.message),
+                            ListTagsForResourceError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceTypeNotSupportedException" => {
                         return RusotoError::Service(
                             ListTagsForResourceError::ResourceTypeNotSupported(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9468,6 +9752,15 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            ListTagsForResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+            ListTagsForResourceError::ResourceTypeNotSupported(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -9484,7 +9777,7 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum RebuildEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl RebuildEnvironmentError {
@@ -9497,7 +9790,9 @@ impl RebuildEnvironmentError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            RebuildEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            RebuildEnvironmentError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9514,6 +9809,13 @@ impl RebuildEnvironmentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebuildEnvironmentError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebuildEnvironmentError {
     #[allow(unused_variables)]
@@ -9550,6 +9852,11 @@ impl RequestEnvironmentInfoError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RequestEnvironmentInfoError {
     #[allow(unused_variables)]
@@ -9584,6 +9891,11 @@ impl RestartAppServerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RestartAppServerError {
     #[allow(unused_variables)]
@@ -9618,6 +9930,11 @@ impl RetrieveEnvironmentInfoError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RetrieveEnvironmentInfoError {
     #[allow(unused_variables)]
@@ -9652,6 +9969,11 @@ impl SwapEnvironmentCNAMEsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SwapEnvironmentCNAMEsError {
     #[allow(unused_variables)]
@@ -9664,7 +9986,7 @@ impl Error for SwapEnvironmentCNAMEsError {}
 #[derive(Debug, PartialEq)]
 pub enum TerminateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl TerminateEnvironmentError {
@@ -9677,7 +9999,9 @@ impl TerminateEnvironmentError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            TerminateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            TerminateEnvironmentError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9694,6 +10018,13 @@ impl TerminateEnvironmentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TerminateEnvironmentError::InsufficientPrivileges(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TerminateEnvironmentError {
     #[allow(unused_variables)]
@@ -9730,6 +10061,11 @@ impl UpdateApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateApplicationError {
     #[allow(unused_variables)]
@@ -9742,7 +10078,7 @@ impl Error for UpdateApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationResourceLifecycleError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
 }
 
 impl UpdateApplicationResourceLifecycleError {
@@ -9758,7 +10094,7 @@ impl UpdateApplicationResourceLifecycleError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             UpdateApplicationResourceLifecycleError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9776,6 +10112,15 @@ impl UpdateApplicationResourceLifecycleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateApplicationResourceLifecycleError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateApplicationResourceLifecycleError {
     #[allow(unused_variables)]
@@ -9814,6 +10159,11 @@ impl UpdateApplicationVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateApplicationVersionError {
     #[allow(unused_variables)]
@@ -9826,9 +10176,9 @@ impl Error for UpdateApplicationVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationTemplateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl UpdateConfigurationTemplateError {
@@ -9844,13 +10194,15 @@ impl UpdateConfigurationTemplateError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             UpdateConfigurationTemplateError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
                         return RusotoError::Service(
-                            UpdateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                            UpdateConfigurationTemplateError::TooManyBuckets(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9867,6 +10219,16 @@ impl UpdateConfigurationTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateConfigurationTemplateError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+            UpdateConfigurationTemplateError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateConfigurationTemplateError {
     #[allow(unused_variables)]
@@ -9884,9 +10246,9 @@ impl Error for UpdateConfigurationTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl UpdateEnvironmentError {
@@ -9899,12 +10261,14 @@ impl UpdateEnvironmentError {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
-                            UpdateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            UpdateEnvironmentError::InsufficientPrivileges(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyBucketsException" => {
                         return RusotoError::Service(UpdateEnvironmentError::TooManyBuckets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9921,6 +10285,14 @@ impl UpdateEnvironmentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateEnvironmentError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            UpdateEnvironmentError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateEnvironmentError {
     #[allow(unused_variables)]
@@ -9936,15 +10308,15 @@ impl Error for UpdateEnvironmentError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateTagsForResourceError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
-    OperationInProgress(String),
+    OperationInProgress(XmlErrorMessage),
     /// <p>A resource doesn't exist for the specified Amazon Resource Name (ARN).</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>The type of the specified Amazon Resource Name (ARN) isn't supported for this operation.</p>
-    ResourceTypeNotSupported(String),
+    ResourceTypeNotSupported(XmlErrorMessage),
     /// <p>The number of tags in the resource would exceed the number of tags that each resource can have.</p> <p>To calculate this, the operation considers both the number of tags the resource already has and the tags this operation would add if it succeeded.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
 }
 
 impl UpdateTagsForResourceError {
@@ -9958,30 +10330,32 @@ impl UpdateTagsForResourceError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             UpdateTagsForResourceError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
                         return RusotoError::Service(
-                            UpdateTagsForResourceError::OperationInProgress(parsed_error.message),
+                            UpdateTagsForResourceError::OperationInProgress(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceNotFoundException" => {
                         return RusotoError::Service(UpdateTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceTypeNotSupportedException" => {
                         return RusotoError::Service(
                             UpdateTagsForResourceError::ResourceTypeNotSupported(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTagsException" => {
                         return RusotoError::Service(UpdateTagsForResourceError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9998,6 +10372,17 @@ impl UpdateTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateTagsForResourceError::InsufficientPrivileges(ref cause) => cause.request_id(),
+            UpdateTagsForResourceError::OperationInProgress(ref cause) => cause.request_id(),
+            UpdateTagsForResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+            UpdateTagsForResourceError::ResourceTypeNotSupported(ref cause) => cause.request_id(),
+            UpdateTagsForResourceError::TooManyTags(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateTagsForResourceError {
     #[allow(unused_variables)]
@@ -10018,9 +10403,9 @@ impl Error for UpdateTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ValidateConfigurationSettingsError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
-    InsufficientPrivileges(String),
+    InsufficientPrivileges(XmlErrorMessage),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
-    TooManyBuckets(String),
+    TooManyBuckets(XmlErrorMessage),
 }
 
 impl ValidateConfigurationSettingsError {
@@ -10036,14 +10421,14 @@ impl ValidateConfigurationSettingsError {
                     "InsufficientPrivilegesException" => {
                         return RusotoError::Service(
                             ValidateConfigurationSettingsError::InsufficientPrivileges(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
                         return RusotoError::Service(
                             ValidateConfigurationSettingsError::TooManyBuckets(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10061,6 +10446,16 @@ impl ValidateConfigurationSettingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ValidateConfigurationSettingsError::InsufficientPrivileges(ref cause) => {
+                cause.request_id()
+            }
+            ValidateConfigurationSettingsError::TooManyBuckets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ValidateConfigurationSettingsError {
     #[allow(unused_variables)]
@@ -2454,6 +2454,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2462,13 +2463,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelJobError::from_response(response))
         }
     }
@@ -2483,6 +2484,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2494,13 +2496,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateJobError::from_response(response))
         }
     }
@@ -2515,6 +2517,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2526,13 +2529,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePipelineError::from_response(response))
         }
     }
@@ -2547,6 +2550,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2558,13 +2562,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePresetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePresetError::from_response(response))
         }
     }
@@ -2579,6 +2583,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2587,13 +2592,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePipelineError::from_response(response))
         }
     }
@@ -2608,6 +2613,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2616,13 +2622,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePresetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePresetError::from_response(response))
         }
     }
@@ -2640,6 +2646,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2657,13 +2664,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListJobsByPipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsByPipelineError::from_response(response))
         }
     }
@@ -2678,6 +2685,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2695,13 +2703,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListJobsByStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsByStatusError::from_response(response))
         }
     }
@@ -2716,6 +2724,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2733,13 +2742,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPipelinesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPipelinesError::from_response(response))
         }
     }
@@ -2754,6 +2763,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPresets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2771,13 +2781,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPresetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPresetsError::from_response(response))
         }
     }
@@ -2792,6 +2802,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2800,13 +2811,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ReadJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ReadJobError::from_response(response))
         }
     }
@@ -2821,6 +2832,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2829,13 +2841,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ReadPipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ReadPipelineError::from_response(response))
         }
     }
@@ -2850,6 +2862,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2858,13 +2871,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ReadPresetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ReadPresetError::from_response(response))
         }
     }
@@ -2879,6 +2892,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("TestRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2890,13 +2904,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TestRoleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TestRoleError::from_response(response))
         }
     }
@@ -2911,6 +2925,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2922,13 +2937,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePipelineError::from_response(response))
         }
     }
@@ -2944,6 +2959,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2955,13 +2971,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePipelineNotificationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePipelineNotificationsError::from_response(response))
         }
     }
@@ -2976,6 +2992,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2987,13 +3004,13 @@ impl Ets for EtsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePipelineStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePipelineStatusError::from_response(response))
         }
     }
@@ -52,7 +52,7 @@ impl ElbClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4114,11 +4114,11 @@ impl VPCIdDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>A tag key was specified more than once.</p>
-    DuplicateTagKeys(String),
+    DuplicateTagKeys(XmlErrorMessage),
     /// <p>The quota for the number of tags that can be assigned to a load balancer has been reached.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
 }
 
 impl AddTagsError {
@@ -4131,17 +4131,17 @@ impl AddTagsError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(AddTagsError::AccessPointNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DuplicateTagKeys" => {
                         return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTags" => {
                         return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4158,6 +4158,15 @@ impl AddTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsError::AccessPointNotFound(ref cause) => cause.request_id(),
+            AddTagsError::DuplicateTagKeys(ref cause) => cause.request_id(),
+            AddTagsError::TooManyTags(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsError {
     #[allow(unused_variables)]
@@ -4174,11 +4183,11 @@ impl Error for AddTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum ApplySecurityGroupsToLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>One or more of the specified security groups do not exist.</p>
-    InvalidSecurityGroup(String),
+    InvalidSecurityGroup(XmlErrorMessage),
 }
 
 impl ApplySecurityGroupsToLoadBalancerError {
@@ -4194,21 +4203,21 @@ impl ApplySecurityGroupsToLoadBalancerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSecurityGroup" => {
                         return RusotoError::Service(
                             ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4226,6 +4235,21 @@ impl ApplySecurityGroupsToLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ApplySecurityGroupsToLoadBalancerError {
     #[allow(unused_variables)]
@@ -4248,13 +4272,13 @@ impl Error for ApplySecurityGroupsToLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancerToSubnetsError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified VPC has no associated Internet gateway.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>One or more of the specified subnets do not exist.</p>
-    SubnetNotFound(String),
+    SubnetNotFound(XmlErrorMessage),
 }
 
 impl AttachLoadBalancerToSubnetsError {
@@ -4270,25 +4294,29 @@ impl AttachLoadBalancerToSubnetsError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             AttachLoadBalancerToSubnetsError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::InvalidSubnet(parsed_error.message),
+                            AttachLoadBalancerToSubnetsError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubnetNotFound" => {
                         return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::SubnetNotFound(parsed_error.message),
+                            AttachLoadBalancerToSubnetsError::SubnetNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4305,6 +4333,18 @@ impl AttachLoadBalancerToSubnetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachLoadBalancerToSubnetsError::AccessPointNotFound(ref cause) => cause.request_id(),
+            AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            AttachLoadBalancerToSubnetsError::InvalidSubnet(ref cause) => cause.request_id(),
+            AttachLoadBalancerToSubnetsError::SubnetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachLoadBalancerToSubnetsError {
     #[allow(unused_variables)]
@@ -4326,7 +4366,7 @@ impl Error for AttachLoadBalancerToSubnetsError {}
 #[derive(Debug, PartialEq)]
 pub enum ConfigureHealthCheckError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
 }
 
 impl ConfigureHealthCheckError {
@@ -4339,7 +4379,9 @@ impl ConfigureHealthCheckError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
-                            ConfigureHealthCheckError::AccessPointNotFound(parsed_error.message),
+                            ConfigureHealthCheckError::AccessPointNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4356,6 +4398,13 @@ impl ConfigureHealthCheckError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ConfigureHealthCheckError::AccessPointNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ConfigureHealthCheckError {
     #[allow(unused_variables)]
@@ -4370,13 +4419,13 @@ impl Error for ConfigureHealthCheckError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateAppCookieStickinessPolicyError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>A policy with the specified name already exists for this load balancer.</p>
-    DuplicatePolicyName(String),
+    DuplicatePolicyName(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
-    TooManyPolicies(String),
+    TooManyPolicies(XmlErrorMessage),
 }
 
 impl CreateAppCookieStickinessPolicyError {
@@ -4392,28 +4441,28 @@ impl CreateAppCookieStickinessPolicyError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             CreateAppCookieStickinessPolicyError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
                         return RusotoError::Service(
                             CreateAppCookieStickinessPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyPolicies" => {
                         return RusotoError::Service(
                             CreateAppCookieStickinessPolicyError::TooManyPolicies(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4431,6 +4480,22 @@ impl CreateAppCookieStickinessPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateAppCookieStickinessPolicyError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            CreateAppCookieStickinessPolicyError::DuplicatePolicyName(ref cause) => {
+                cause.request_id()
+            }
+            CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            CreateAppCookieStickinessPolicyError::TooManyPolicies(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateAppCookieStickinessPolicyError {
     #[allow(unused_variables)]
@@ -4456,13 +4521,13 @@ impl Error for CreateAppCookieStickinessPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLBCookieStickinessPolicyError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>A policy with the specified name already exists for this load balancer.</p>
-    DuplicatePolicyName(String),
+    DuplicatePolicyName(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
-    TooManyPolicies(String),
+    TooManyPolicies(XmlErrorMessage),
 }
 
 impl CreateLBCookieStickinessPolicyError {
@@ -4478,28 +4543,28 @@ impl CreateLBCookieStickinessPolicyError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             CreateLBCookieStickinessPolicyError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
                         return RusotoError::Service(
                             CreateLBCookieStickinessPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyPolicies" => {
                         return RusotoError::Service(
                             CreateLBCookieStickinessPolicyError::TooManyPolicies(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4517,6 +4582,22 @@ impl CreateLBCookieStickinessPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLBCookieStickinessPolicyError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            CreateLBCookieStickinessPolicyError::DuplicatePolicyName(ref cause) => {
+                cause.request_id()
+            }
+            CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            CreateLBCookieStickinessPolicyError::TooManyPolicies(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLBCookieStickinessPolicyError {
     #[allow(unused_variables)]
@@ -4542,29 +4623,29 @@ impl Error for CreateLBCookieStickinessPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerError {
     /// <p>The specified ARN does not refer to a valid SSL certificate in AWS Identity and Access Management (IAM) or AWS Certificate Manager (ACM). Note that if you recently uploaded the certificate to IAM, this error might indicate that the certificate is not fully available yet.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>The specified load balancer name already exists for this account.</p>
-    DuplicateAccessPointName(String),
+    DuplicateAccessPointName(XmlErrorMessage),
     /// <p>A tag key was specified more than once.</p>
-    DuplicateTagKeys(String),
+    DuplicateTagKeys(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified value for the schema is not valid. You can only specify a scheme for load balancers in a VPC.</p>
-    InvalidScheme(String),
+    InvalidScheme(XmlErrorMessage),
     /// <p>One or more of the specified security groups do not exist.</p>
-    InvalidSecurityGroup(String),
+    InvalidSecurityGroup(XmlErrorMessage),
     /// <p>The specified VPC has no associated Internet gateway.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>One or more of the specified subnets do not exist.</p>
-    SubnetNotFound(String),
+    SubnetNotFound(XmlErrorMessage),
     /// <p>The quota for the number of load balancers has been reached.</p>
-    TooManyAccessPoints(String),
+    TooManyAccessPoints(XmlErrorMessage),
     /// <p>The quota for the number of tags that can be assigned to a load balancer has been reached.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
     /// <p>The specified protocol or signature version is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl CreateLoadBalancerError {
@@ -4577,64 +4658,68 @@ impl CreateLoadBalancerError {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
                         return RusotoError::Service(CreateLoadBalancerError::CertificateNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DuplicateLoadBalancerName" => {
                         return RusotoError::Service(
-                            CreateLoadBalancerError::DuplicateAccessPointName(parsed_error.message),
+                            CreateLoadBalancerError::DuplicateAccessPointName(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DuplicateTagKeys" => {
                         return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidScheme" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurityGroup" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationNotPermitted" => {
                         return RusotoError::Service(
-                            CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            CreateLoadBalancerError::OperationNotPermitted(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubnetNotFound" => {
                         return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyLoadBalancers" => {
                         return RusotoError::Service(CreateLoadBalancerError::TooManyAccessPoints(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTags" => {
                         return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(CreateLoadBalancerError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4651,6 +4736,24 @@ impl CreateLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLoadBalancerError::CertificateNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::DuplicateAccessPointName(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::DuplicateTagKeys(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidScheme(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidSecurityGroup(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::OperationNotPermitted(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::SubnetNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::TooManyAccessPoints(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::TooManyTags(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLoadBalancerError {
     #[allow(unused_variables)]
@@ -4678,15 +4781,15 @@ impl Error for CreateLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerListenersError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified ARN does not refer to a valid SSL certificate in AWS Identity and Access Management (IAM) or AWS Certificate Manager (ACM). Note that if you recently uploaded the certificate to IAM, this error might indicate that the certificate is not fully available yet.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>A listener already exists for the specified load balancer name and port, but with a different instance port, protocol, or SSL certificate.</p>
-    DuplicateListener(String),
+    DuplicateListener(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified protocol or signature version is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl CreateLoadBalancerListenersError {
@@ -4702,35 +4805,35 @@ impl CreateLoadBalancerListenersError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             CreateLoadBalancerListenersError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(
                             CreateLoadBalancerListenersError::CertificateNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicateListener" => {
                         return RusotoError::Service(
                             CreateLoadBalancerListenersError::DuplicateListener(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateLoadBalancerListenersError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(
                             CreateLoadBalancerListenersError::UnsupportedProtocol(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4748,6 +4851,19 @@ impl CreateLoadBalancerListenersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLoadBalancerListenersError::AccessPointNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerListenersError::CertificateNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerListenersError::DuplicateListener(ref cause) => cause.request_id(),
+            CreateLoadBalancerListenersError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            CreateLoadBalancerListenersError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLoadBalancerListenersError {
     #[allow(unused_variables)]
@@ -4776,15 +4892,15 @@ impl Error for CreateLoadBalancerListenersError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerPolicyError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>A policy with the specified name already exists for this load balancer.</p>
-    DuplicatePolicyName(String),
+    DuplicatePolicyName(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>One or more of the specified policy types do not exist.</p>
-    PolicyTypeNotFound(String),
+    PolicyTypeNotFound(XmlErrorMessage),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
-    TooManyPolicies(String),
+    TooManyPolicies(XmlErrorMessage),
 }
 
 impl CreateLoadBalancerPolicyError {
@@ -4798,32 +4914,36 @@ impl CreateLoadBalancerPolicyError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             CreateLoadBalancerPolicyError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
                         return RusotoError::Service(
                             CreateLoadBalancerPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateLoadBalancerPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PolicyTypeNotFound" => {
                         return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::PolicyTypeNotFound(parsed_error.message),
+                            CreateLoadBalancerPolicyError::PolicyTypeNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyPolicies" => {
                         return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::TooManyPolicies(parsed_error.message),
+                            CreateLoadBalancerPolicyError::TooManyPolicies(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4840,6 +4960,19 @@ impl CreateLoadBalancerPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLoadBalancerPolicyError::AccessPointNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerPolicyError::DuplicatePolicyName(ref cause) => cause.request_id(),
+            CreateLoadBalancerPolicyError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            CreateLoadBalancerPolicyError::PolicyTypeNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerPolicyError::TooManyPolicies(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLoadBalancerPolicyError {
     #[allow(unused_variables)]
@@ -4882,6 +5015,11 @@ impl DeleteLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteLoadBalancerError {
     #[allow(unused_variables)]
@@ -4894,7 +5032,7 @@ impl Error for DeleteLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerListenersError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
 }
 
 impl DeleteLoadBalancerListenersError {
@@ -4910,7 +5048,7 @@ impl DeleteLoadBalancerListenersError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DeleteLoadBalancerListenersError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4928,6 +5066,13 @@ impl DeleteLoadBalancerListenersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLoadBalancerListenersError::AccessPointNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteLoadBalancerListenersError {
     #[allow(unused_variables)]
@@ -4944,9 +5089,9 @@ impl Error for DeleteLoadBalancerListenersError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerPolicyError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
 }
 
 impl DeleteLoadBalancerPolicyError {
@@ -4960,14 +5105,14 @@ impl DeleteLoadBalancerPolicyError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DeleteLoadBalancerPolicyError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -4985,6 +5130,16 @@ impl DeleteLoadBalancerPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLoadBalancerPolicyError::AccessPointNotFound(ref cause) => cause.request_id(),
+            DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteLoadBalancerPolicyError {
     #[allow(unused_variables)]
@@ -5002,9 +5157,9 @@ impl Error for DeleteLoadBalancerPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeregisterInstancesFromLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified endpoint is not valid.</p>
-    InvalidEndPoint(String),
+    InvalidEndPoint(XmlErrorMessage),
 }
 
 impl DeregisterInstancesFromLoadBalancerError {
@@ -5020,14 +5175,14 @@ impl DeregisterInstancesFromLoadBalancerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInstance" => {
                         return RusotoError::Service(
                             DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5045,6 +5200,18 @@ impl DeregisterInstancesFromLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeregisterInstancesFromLoadBalancerError {
     #[allow(unused_variables)]
@@ -5086,6 +5253,11 @@ impl DescribeAccountLimitsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
@@ -5098,9 +5270,9 @@ impl Error for DescribeAccountLimitsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceHealthError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified endpoint is not valid.</p>
-    InvalidEndPoint(String),
+    InvalidEndPoint(XmlErrorMessage),
 }
 
 impl DescribeInstanceHealthError {
@@ -5113,12 +5285,14 @@ impl DescribeInstanceHealthError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
-                            DescribeInstanceHealthError::AccessPointNotFound(parsed_error.message),
+                            DescribeInstanceHealthError::AccessPointNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInstance" => {
                         return RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5135,6 +5309,14 @@ impl DescribeInstanceHealthError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeInstanceHealthError::AccessPointNotFound(ref cause) => cause.request_id(),
+            DescribeInstanceHealthError::InvalidEndPoint(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeInstanceHealthError {
     #[allow(unused_variables)]
@@ -5150,9 +5332,9 @@ impl Error for DescribeInstanceHealthError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified load balancer attribute does not exist.</p>
-    LoadBalancerAttributeNotFound(String),
+    LoadBalancerAttributeNotFound(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancerAttributesError {
@@ -5168,14 +5350,14 @@ impl DescribeLoadBalancerAttributesError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerAttributesError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5193,6 +5375,18 @@ impl DescribeLoadBalancerAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancerAttributesError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancerAttributesError {
     #[allow(unused_variables)]
@@ -5212,9 +5406,9 @@ impl Error for DescribeLoadBalancerAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerPoliciesError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>One or more of the specified policies do not exist.</p>
-    PolicyNotFound(String),
+    PolicyNotFound(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancerPoliciesError {
@@ -5230,13 +5424,15 @@ impl DescribeLoadBalancerPoliciesError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerPoliciesError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PolicyNotFound" => {
                         return RusotoError::Service(
-                            DescribeLoadBalancerPoliciesError::PolicyNotFound(parsed_error.message),
+                            DescribeLoadBalancerPoliciesError::PolicyNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5253,6 +5449,14 @@ impl DescribeLoadBalancerPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancerPoliciesError::AccessPointNotFound(ref cause) => cause.request_id(),
+            DescribeLoadBalancerPoliciesError::PolicyNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancerPoliciesError {
     #[allow(unused_variables)]
@@ -5270,7 +5474,7 @@ impl Error for DescribeLoadBalancerPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerPolicyTypesError {
     /// <p>One or more of the specified policy types do not exist.</p>
-    PolicyTypeNotFound(String),
+    PolicyTypeNotFound(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancerPolicyTypesError {
@@ -5286,7 +5490,7 @@ impl DescribeLoadBalancerPolicyTypesError {
                     "PolicyTypeNotFound" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5304,6 +5508,15 @@ impl DescribeLoadBalancerPolicyTypesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancerPolicyTypesError {
     #[allow(unused_variables)]
@@ -5320,9 +5533,9 @@ impl Error for DescribeLoadBalancerPolicyTypesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>A request made by Elastic Load Balancing to another service exceeds the maximum request rate permitted for your account.</p>
-    DependencyThrottle(String),
+    DependencyThrottle(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancersError {
@@ -5335,12 +5548,16 @@ impl DescribeLoadBalancersError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
-                            DescribeLoadBalancersError::AccessPointNotFound(parsed_error.message),
+                            DescribeLoadBalancersError::AccessPointNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DependencyThrottle" => {
                         return RusotoError::Service(
-                            DescribeLoadBalancersError::DependencyThrottle(parsed_error.message),
+                            DescribeLoadBalancersError::DependencyThrottle(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -5357,6 +5574,14 @@ impl DescribeLoadBalancersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancersError::AccessPointNotFound(ref cause) => cause.request_id(),
+            DescribeLoadBalancersError::DependencyThrottle(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancersError {
     #[allow(unused_variables)]
@@ -5372,7 +5597,7 @@ impl Error for DescribeLoadBalancersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
 }
 
 impl DescribeTagsError {
@@ -5385,7 +5610,7 @@ impl DescribeTagsError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(DescribeTagsError::AccessPointNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5402,6 +5627,13 @@ impl DescribeTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTagsError::AccessPointNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTagsError {
     #[allow(unused_variables)]
@@ -5416,9 +5648,9 @@ impl Error for DescribeTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancerFromSubnetsError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
 }
 
 impl DetachLoadBalancerFromSubnetsError {
@@ -5434,14 +5666,14 @@ impl DetachLoadBalancerFromSubnetsError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DetachLoadBalancerFromSubnetsError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5459,6 +5691,18 @@ impl DetachLoadBalancerFromSubnetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachLoadBalancerFromSubnetsError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DetachLoadBalancerFromSubnetsError {
     #[allow(unused_variables)]
@@ -5478,9 +5722,9 @@ impl Error for DetachLoadBalancerFromSubnetsError {}
 #[derive(Debug, PartialEq)]
 pub enum DisableAvailabilityZonesForLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
 }
 
 impl DisableAvailabilityZonesForLoadBalancerError {
@@ -5496,13 +5740,13 @@ impl DisableAvailabilityZonesForLoadBalancerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => return RusotoError::Service(
                         DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -5519,6 +5763,18 @@ impl DisableAvailabilityZonesForLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DisableAvailabilityZonesForLoadBalancerError {
     #[allow(unused_variables)]
@@ -5538,7 +5794,7 @@ impl Error for DisableAvailabilityZonesForLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableAvailabilityZonesForLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
 }
 
 impl EnableAvailabilityZonesForLoadBalancerError {
@@ -5554,7 +5810,7 @@ impl EnableAvailabilityZonesForLoadBalancerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5572,6 +5828,15 @@ impl EnableAvailabilityZonesForLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for EnableAvailabilityZonesForLoadBalancerError {
     #[allow(unused_variables)]
@@ -5588,11 +5853,11 @@ impl Error for EnableAvailabilityZonesForLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified load balancer attribute does not exist.</p>
-    LoadBalancerAttributeNotFound(String),
+    LoadBalancerAttributeNotFound(XmlErrorMessage),
 }
 
 impl ModifyLoadBalancerAttributesError {
@@ -5608,21 +5873,21 @@ impl ModifyLoadBalancerAttributesError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             ModifyLoadBalancerAttributesError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
                         return RusotoError::Service(
                             ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5640,6 +5905,19 @@ impl ModifyLoadBalancerAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyLoadBalancerAttributesError::AccessPointNotFound(ref cause) => cause.request_id(),
+            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyLoadBalancerAttributesError {
     #[allow(unused_variables)]
@@ -5662,9 +5940,9 @@ impl Error for ModifyLoadBalancerAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum RegisterInstancesWithLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified endpoint is not valid.</p>
-    InvalidEndPoint(String),
+    InvalidEndPoint(XmlErrorMessage),
 }
 
 impl RegisterInstancesWithLoadBalancerError {
@@ -5680,14 +5958,14 @@ impl RegisterInstancesWithLoadBalancerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             RegisterInstancesWithLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInstance" => {
                         return RusotoError::Service(
                             RegisterInstancesWithLoadBalancerError::InvalidEndPoint(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5705,6 +5983,18 @@ impl RegisterInstancesWithLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RegisterInstancesWithLoadBalancerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            RegisterInstancesWithLoadBalancerError::InvalidEndPoint(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RegisterInstancesWithLoadBalancerError {
     #[allow(unused_variables)]
@@ -5724,7 +6014,7 @@ impl Error for RegisterInstancesWithLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
 }
 
 impl RemoveTagsError {
@@ -5737,7 +6027,7 @@ impl RemoveTagsError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(RemoveTagsError::AccessPointNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -5754,6 +6044,13 @@ impl RemoveTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsError::AccessPointNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsError {
     #[allow(unused_variables)]
@@ -5768,15 +6065,15 @@ impl Error for RemoveTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerListenerSSLCertificateError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The specified ARN does not refer to a valid SSL certificate in AWS Identity and Access Management (IAM) or AWS Certificate Manager (ACM). Note that if you recently uploaded the certificate to IAM, this error might indicate that the certificate is not fully available yet.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The load balancer does not have a listener configured at the specified port.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified protocol or signature version is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl SetLoadBalancerListenerSSLCertificateError {
@@ -5792,35 +6089,35 @@ impl SetLoadBalancerListenerSSLCertificateError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ListenerNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(
                             SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5838,6 +6135,27 @@ impl SetLoadBalancerListenerSSLCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for SetLoadBalancerListenerSSLCertificateError {
     #[allow(unused_variables)]
@@ -5866,11 +6184,11 @@ impl Error for SetLoadBalancerListenerSSLCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerPoliciesForBackendServerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>One or more of the specified policies do not exist.</p>
-    PolicyNotFound(String),
+    PolicyNotFound(XmlErrorMessage),
 }
 
 impl SetLoadBalancerPoliciesForBackendServerError {
@@ -5886,19 +6204,19 @@ impl SetLoadBalancerPoliciesForBackendServerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => return RusotoError::Service(
                         SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "PolicyNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5916,6 +6234,21 @@ impl SetLoadBalancerPoliciesForBackendServerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(
+                ref cause,
+            ) => cause.request_id(),
+            SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for SetLoadBalancerPoliciesForBackendServerError {
     #[allow(unused_variables)]
@@ -5938,13 +6271,13 @@ impl Error for SetLoadBalancerPoliciesForBackendServerError {}
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerPoliciesOfListenerError {
     /// <p>The specified load balancer does not exist.</p>
-    AccessPointNotFound(String),
+    AccessPointNotFound(XmlErrorMessage),
     /// <p>The requested configuration change is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The load balancer does not have a listener configured at the specified port.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>One or more of the specified policies do not exist.</p>
-    PolicyNotFound(String),
+    PolicyNotFound(XmlErrorMessage),
 }
 
 impl SetLoadBalancerPoliciesOfListenerError {
@@ -5960,28 +6293,28 @@ impl SetLoadBalancerPoliciesOfListenerError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ListenerNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PolicyNotFound" => {
                         return RusotoError::Service(
                             SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -5999,6 +6332,22 @@ impl SetLoadBalancerPoliciesOfListenerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(ref cause) => {
+                cause.request_id()
+            }
+            SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetLoadBalancerPoliciesOfListenerError {
     #[allow(unused_variables)]
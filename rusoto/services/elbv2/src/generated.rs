@@ -52,7 +52,7 @@ impl ElbClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6302,11 +6302,11 @@ impl ZoneNameDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddListenerCertificatesError {
     /// <p>The specified certificate does not exist.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of certificates per load balancer.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
 }
 
 impl AddListenerCertificatesError {
@@ -6319,17 +6319,23 @@ impl AddListenerCertificatesError {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
                         return RusotoError::Service(
-                            AddListenerCertificatesError::CertificateNotFound(parsed_error.message),
+                            AddListenerCertificatesError::CertificateNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ListenerNotFound" => {
                         return RusotoError::Service(
-                            AddListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                            AddListenerCertificatesError::ListenerNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyCertificates" => {
                         return RusotoError::Service(
-                            AddListenerCertificatesError::TooManyCertificates(parsed_error.message),
+                            AddListenerCertificatesError::TooManyCertificates(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -6346,6 +6352,15 @@ impl AddListenerCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddListenerCertificatesError::CertificateNotFound(ref cause) => cause.request_id(),
+            AddListenerCertificatesError::ListenerNotFound(ref cause) => cause.request_id(),
+            AddListenerCertificatesError::TooManyCertificates(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddListenerCertificatesError {
     #[allow(unused_variables)]
@@ -6362,13 +6377,13 @@ impl Error for AddListenerCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
     /// <p>A tag key was specified more than once.</p>
-    DuplicateTagKeys(String),
+    DuplicateTagKeys(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
 }
 
 impl AddTagsError {
@@ -6381,22 +6396,22 @@ impl AddTagsError {
                 match &parsed_error.code[..] {
                     "DuplicateTagKeys" => {
                         return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(AddTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(AddTagsError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTags" => {
                         return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6413,6 +6428,16 @@ impl AddTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsError::DuplicateTagKeys(ref cause) => cause.request_id(),
+            AddTagsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            AddTagsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            AddTagsError::TooManyTags(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsError {
     #[allow(unused_variables)]
@@ -6430,39 +6455,39 @@ impl Error for AddTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateListenerError {
     /// <p>The specified ALPN policy is not supported.</p>
-    ALPNPolicyNotSupported(String),
+    ALPNPolicyNotSupported(XmlErrorMessage),
     /// <p>The specified certificate does not exist.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>A listener with the specified port already exists.</p>
-    DuplicateListener(String),
+    DuplicateListener(XmlErrorMessage),
     /// <p>The specified configuration is not valid with this protocol.</p>
-    IncompatibleProtocols(String),
+    IncompatibleProtocols(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The requested action is not valid.</p>
-    InvalidLoadBalancerAction(String),
+    InvalidLoadBalancerAction(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified SSL policy does not exist.</p>
-    SSLPolicyNotFound(String),
+    SSLPolicyNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of load balancers per target group.</p>
-    TargetGroupAssociationLimit(String),
+    TargetGroupAssociationLimit(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of actions per rule.</p>
-    TooManyActions(String),
+    TooManyActions(XmlErrorMessage),
     /// <p>You've reached the limit on the number of certificates per load balancer.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
     /// <p>You've reached the limit on the number of listeners per load balancer.</p>
-    TooManyListeners(String),
+    TooManyListeners(XmlErrorMessage),
     /// <p>You've reached the limit on the number of times a target can be registered with a load balancer.</p>
-    TooManyRegistrationsForTargetId(String),
+    TooManyRegistrationsForTargetId(XmlErrorMessage),
     /// <p>You've reached the limit on the number of targets.</p>
-    TooManyTargets(String),
+    TooManyTargets(XmlErrorMessage),
     /// <p>You've reached the limit on the number of unique target groups per load balancer across all listeners. If a target group is used by multiple actions for a load balancer, it is counted as only one use.</p>
-    TooManyUniqueTargetGroupsPerLoadBalancer(String),
+    TooManyUniqueTargetGroupsPerLoadBalancer(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl CreateListenerError {
@@ -6475,91 +6500,97 @@ impl CreateListenerError {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
                         return RusotoError::Service(CreateListenerError::ALPNPolicyNotSupported(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(CreateListenerError::CertificateNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DuplicateListener" => {
                         return RusotoError::Service(CreateListenerError::DuplicateListener(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "IncompatibleProtocols" => {
                         return RusotoError::Service(CreateListenerError::IncompatibleProtocols(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
-                            CreateListenerError::InvalidConfigurationRequest(parsed_error.message),
+                            CreateListenerError::InvalidConfigurationRequest(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidLoadBalancerAction" => {
                         return RusotoError::Service(
-                            CreateListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                            CreateListenerError::InvalidLoadBalancerAction(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(CreateListenerError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SSLPolicyNotFound" => {
                         return RusotoError::Service(CreateListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupAssociationLimit" => {
                         return RusotoError::Service(
-                            CreateListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                            CreateListenerError::TargetGroupAssociationLimit(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(CreateListenerError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyActions" => {
                         return RusotoError::Service(CreateListenerError::TooManyActions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyCertificates" => {
                         return RusotoError::Service(CreateListenerError::TooManyCertificates(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyListeners" => {
                         return RusotoError::Service(CreateListenerError::TooManyListeners(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRegistrationsForTargetId" => {
                         return RusotoError::Service(
                             CreateListenerError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTargets" => {
                         return RusotoError::Service(CreateListenerError::TooManyTargets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
                         return RusotoError::Service(
                             CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(CreateListenerError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6576,6 +6607,31 @@ impl CreateListenerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateListenerError::ALPNPolicyNotSupported(ref cause) => cause.request_id(),
+            CreateListenerError::CertificateNotFound(ref cause) => cause.request_id(),
+            CreateListenerError::DuplicateListener(ref cause) => cause.request_id(),
+            CreateListenerError::IncompatibleProtocols(ref cause) => cause.request_id(),
+            CreateListenerError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            CreateListenerError::InvalidLoadBalancerAction(ref cause) => cause.request_id(),
+            CreateListenerError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            CreateListenerError::SSLPolicyNotFound(ref cause) => cause.request_id(),
+            CreateListenerError::TargetGroupAssociationLimit(ref cause) => cause.request_id(),
+            CreateListenerError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyActions(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyCertificates(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyListeners(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyRegistrationsForTargetId(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyTargets(ref cause) => cause.request_id(),
+            CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(ref cause) => {
+                cause.request_id()
+            }
+            CreateListenerError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateListenerError {
     #[allow(unused_variables)]
@@ -6610,31 +6666,31 @@ impl Error for CreateListenerError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerError {
     /// <p>The specified allocation ID does not exist.</p>
-    AllocationIdNotFound(String),
+    AllocationIdNotFound(XmlErrorMessage),
     /// <p>The specified Availability Zone is not supported.</p>
-    AvailabilityZoneNotSupported(String),
+    AvailabilityZoneNotSupported(XmlErrorMessage),
     /// <p>A load balancer with the specified name already exists.</p>
-    DuplicateLoadBalancerName(String),
+    DuplicateLoadBalancerName(XmlErrorMessage),
     /// <p>A tag key was specified more than once.</p>
-    DuplicateTagKeys(String),
+    DuplicateTagKeys(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The requested scheme is not valid.</p>
-    InvalidScheme(String),
+    InvalidScheme(XmlErrorMessage),
     /// <p>The specified security group does not exist.</p>
-    InvalidSecurityGroup(String),
+    InvalidSecurityGroup(XmlErrorMessage),
     /// <p>The specified subnet is out of available addresses.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>A specified resource is in use.</p>
-    ResourceInUse(String),
+    ResourceInUse(XmlErrorMessage),
     /// <p>The specified subnet does not exist.</p>
-    SubnetNotFound(String),
+    SubnetNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of load balancers for your AWS account.</p>
-    TooManyLoadBalancers(String),
+    TooManyLoadBalancers(XmlErrorMessage),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
 }
 
 impl CreateLoadBalancerError {
@@ -6647,73 +6703,75 @@ impl CreateLoadBalancerError {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
                         return RusotoError::Service(CreateLoadBalancerError::AllocationIdNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AvailabilityZoneNotSupported" => {
                         return RusotoError::Service(
                             CreateLoadBalancerError::AvailabilityZoneNotSupported(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicateLoadBalancerName" => {
                         return RusotoError::Service(
                             CreateLoadBalancerError::DuplicateLoadBalancerName(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DuplicateTagKeys" => {
                         return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidScheme" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurityGroup" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationNotPermitted" => {
                         return RusotoError::Service(
-                            CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            CreateLoadBalancerError::OperationNotPermitted(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(CreateLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetNotFound" => {
                         return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyLoadBalancers" => {
                         return RusotoError::Service(CreateLoadBalancerError::TooManyLoadBalancers(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTags" => {
                         return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6730,6 +6788,25 @@ impl CreateLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLoadBalancerError::AllocationIdNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::AvailabilityZoneNotSupported(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::DuplicateLoadBalancerName(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::DuplicateTagKeys(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidScheme(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidSecurityGroup(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::OperationNotPermitted(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::ResourceInUse(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::SubnetNotFound(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::TooManyLoadBalancers(ref cause) => cause.request_id(),
+            CreateLoadBalancerError::TooManyTags(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLoadBalancerError {
     #[allow(unused_variables)]
@@ -6760,33 +6837,33 @@ impl Error for CreateLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateRuleError {
     /// <p>The specified configuration is not valid with this protocol.</p>
-    IncompatibleProtocols(String),
+    IncompatibleProtocols(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The requested action is not valid.</p>
-    InvalidLoadBalancerAction(String),
+    InvalidLoadBalancerAction(XmlErrorMessage),
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified priority is in use.</p>
-    PriorityInUse(String),
+    PriorityInUse(XmlErrorMessage),
     /// <p>You've reached the limit on the number of load balancers per target group.</p>
-    TargetGroupAssociationLimit(String),
+    TargetGroupAssociationLimit(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of actions per rule.</p>
-    TooManyActions(String),
+    TooManyActions(XmlErrorMessage),
     /// <p>You've reached the limit on the number of times a target can be registered with a load balancer.</p>
-    TooManyRegistrationsForTargetId(String),
+    TooManyRegistrationsForTargetId(XmlErrorMessage),
     /// <p>You've reached the limit on the number of rules per load balancer.</p>
-    TooManyRules(String),
+    TooManyRules(XmlErrorMessage),
     /// <p>You've reached the limit on the number of target groups for your AWS account.</p>
-    TooManyTargetGroups(String),
+    TooManyTargetGroups(XmlErrorMessage),
     /// <p>You've reached the limit on the number of targets.</p>
-    TooManyTargets(String),
+    TooManyTargets(XmlErrorMessage),
     /// <p>You've reached the limit on the number of unique target groups per load balancer across all listeners. If a target group is used by multiple actions for a load balancer, it is counted as only one use.</p>
-    TooManyUniqueTargetGroupsPerLoadBalancer(String),
+    TooManyUniqueTargetGroupsPerLoadBalancer(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl CreateRuleError {
@@ -6799,74 +6876,76 @@ impl CreateRuleError {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
                         return RusotoError::Service(CreateRuleError::IncompatibleProtocols(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(CreateRuleError::InvalidConfigurationRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidLoadBalancerAction" => {
                         return RusotoError::Service(CreateRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ListenerNotFound" => {
                         return RusotoError::Service(CreateRuleError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorityInUse" => {
                         return RusotoError::Service(CreateRuleError::PriorityInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupAssociationLimit" => {
                         return RusotoError::Service(CreateRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(CreateRuleError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyActions" => {
                         return RusotoError::Service(CreateRuleError::TooManyActions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRegistrationsForTargetId" => {
                         return RusotoError::Service(
-                            CreateRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                            CreateRuleError::TooManyRegistrationsForTargetId(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyRules" => {
                         return RusotoError::Service(CreateRuleError::TooManyRules(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTargetGroups" => {
                         return RusotoError::Service(CreateRuleError::TooManyTargetGroups(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTargets" => {
                         return RusotoError::Service(CreateRuleError::TooManyTargets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
                         return RusotoError::Service(
                             CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(CreateRuleError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6883,6 +6962,28 @@ impl CreateRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateRuleError::IncompatibleProtocols(ref cause) => cause.request_id(),
+            CreateRuleError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            CreateRuleError::InvalidLoadBalancerAction(ref cause) => cause.request_id(),
+            CreateRuleError::ListenerNotFound(ref cause) => cause.request_id(),
+            CreateRuleError::PriorityInUse(ref cause) => cause.request_id(),
+            CreateRuleError::TargetGroupAssociationLimit(ref cause) => cause.request_id(),
+            CreateRuleError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyActions(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyRegistrationsForTargetId(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyRules(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyTargetGroups(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyTargets(ref cause) => cause.request_id(),
+            CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(ref cause) => {
+                cause.request_id()
+            }
+            CreateRuleError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateRuleError {
     #[allow(unused_variables)]
@@ -6912,11 +7013,11 @@ impl Error for CreateRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTargetGroupError {
     /// <p>A target group with the specified name already exists.</p>
-    DuplicateTargetGroupName(String),
+    DuplicateTargetGroupName(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>You've reached the limit on the number of target groups for your AWS account.</p>
-    TooManyTargetGroups(String),
+    TooManyTargetGroups(XmlErrorMessage),
 }
 
 impl CreateTargetGroupError {
@@ -6929,19 +7030,21 @@ impl CreateTargetGroupError {
                 match &parsed_error.code[..] {
                     "DuplicateTargetGroupName" => {
                         return RusotoError::Service(
-                            CreateTargetGroupError::DuplicateTargetGroupName(parsed_error.message),
+                            CreateTargetGroupError::DuplicateTargetGroupName(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             CreateTargetGroupError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTargetGroups" => {
                         return RusotoError::Service(CreateTargetGroupError::TooManyTargetGroups(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -6958,6 +7061,15 @@ impl CreateTargetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTargetGroupError::DuplicateTargetGroupName(ref cause) => cause.request_id(),
+            CreateTargetGroupError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            CreateTargetGroupError::TooManyTargetGroups(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTargetGroupError {
     #[allow(unused_variables)]
@@ -6976,7 +7088,7 @@ impl Error for CreateTargetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteListenerError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
 }
 
 impl DeleteListenerError {
@@ -6989,7 +7101,7 @@ impl DeleteListenerError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(DeleteListenerError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7006,6 +7118,13 @@ impl DeleteListenerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteListenerError::ListenerNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteListenerError {
     #[allow(unused_variables)]
@@ -7020,11 +7139,11 @@ impl Error for DeleteListenerError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>A specified resource is in use.</p>
-    ResourceInUse(String),
+    ResourceInUse(XmlErrorMessage),
 }
 
 impl DeleteLoadBalancerError {
@@ -7037,17 +7156,19 @@ impl DeleteLoadBalancerError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(DeleteLoadBalancerError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationNotPermitted" => {
                         return RusotoError::Service(
-                            DeleteLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            DeleteLoadBalancerError::OperationNotPermitted(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ResourceInUse" => {
                         return RusotoError::Service(DeleteLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7064,6 +7185,15 @@ impl DeleteLoadBalancerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLoadBalancerError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            DeleteLoadBalancerError::OperationNotPermitted(ref cause) => cause.request_id(),
+            DeleteLoadBalancerError::ResourceInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteLoadBalancerError {
     #[allow(unused_variables)]
@@ -7080,9 +7210,9 @@ impl Error for DeleteLoadBalancerError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleError {
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
 }
 
 impl DeleteRuleError {
@@ -7095,12 +7225,12 @@ impl DeleteRuleError {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
                         return RusotoError::Service(DeleteRuleError::OperationNotPermitted(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(DeleteRuleError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7117,6 +7247,14 @@ impl DeleteRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteRuleError::OperationNotPermitted(ref cause) => cause.request_id(),
+            DeleteRuleError::RuleNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteRuleError {
     #[allow(unused_variables)]
@@ -7132,7 +7270,7 @@ impl Error for DeleteRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTargetGroupError {
     /// <p>A specified resource is in use.</p>
-    ResourceInUse(String),
+    ResourceInUse(XmlErrorMessage),
 }
 
 impl DeleteTargetGroupError {
@@ -7145,7 +7283,7 @@ impl DeleteTargetGroupError {
                 match &parsed_error.code[..] {
                     "ResourceInUse" => {
                         return RusotoError::Service(DeleteTargetGroupError::ResourceInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7162,6 +7300,13 @@ impl DeleteTargetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTargetGroupError::ResourceInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteTargetGroupError {
     #[allow(unused_variables)]
@@ -7176,9 +7321,9 @@ impl Error for DeleteTargetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTargetsError {
     /// <p>The specified target does not exist, is not in the same VPC as the target group, or has an unsupported instance type.</p>
-    InvalidTarget(String),
+    InvalidTarget(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl DeregisterTargetsError {
@@ -7191,12 +7336,12 @@ impl DeregisterTargetsError {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
                         return RusotoError::Service(DeregisterTargetsError::InvalidTarget(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(DeregisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7213,6 +7358,14 @@ impl DeregisterTargetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeregisterTargetsError::InvalidTarget(ref cause) => cause.request_id(),
+            DeregisterTargetsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeregisterTargetsError {
     #[allow(unused_variables)]
@@ -7250,6 +7403,11 @@ impl DescribeAccountLimitsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
@@ -7262,7 +7420,7 @@ impl Error for DescribeAccountLimitsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeListenerCertificatesError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
 }
 
 impl DescribeListenerCertificatesError {
@@ -7278,7 +7436,7 @@ impl DescribeListenerCertificatesError {
                     "ListenerNotFound" => {
                         return RusotoError::Service(
                             DescribeListenerCertificatesError::ListenerNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7296,6 +7454,13 @@ impl DescribeListenerCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeListenerCertificatesError::ListenerNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeListenerCertificatesError {
     #[allow(unused_variables)]
@@ -7312,11 +7477,11 @@ impl Error for DescribeListenerCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeListenersError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl DescribeListenersError {
@@ -7329,17 +7494,17 @@ impl DescribeListenersError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(DescribeListenersError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(DescribeListenersError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(DescribeListenersError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7356,6 +7521,15 @@ impl DescribeListenersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeListenersError::ListenerNotFound(ref cause) => cause.request_id(),
+            DescribeListenersError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            DescribeListenersError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeListenersError {
     #[allow(unused_variables)]
@@ -7372,7 +7546,7 @@ impl Error for DescribeListenersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancerAttributesError {
@@ -7388,7 +7562,7 @@ impl DescribeLoadBalancerAttributesError {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             DescribeLoadBalancerAttributesError::LoadBalancerNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7406,6 +7580,15 @@ impl DescribeLoadBalancerAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancerAttributesError::LoadBalancerNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancerAttributesError {
     #[allow(unused_variables)]
@@ -7422,7 +7605,7 @@ impl Error for DescribeLoadBalancerAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
 }
 
 impl DescribeLoadBalancersError {
@@ -7435,7 +7618,9 @@ impl DescribeLoadBalancersError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
-                            DescribeLoadBalancersError::LoadBalancerNotFound(parsed_error.message),
+                            DescribeLoadBalancersError::LoadBalancerNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7452,6 +7637,13 @@ impl DescribeLoadBalancersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoadBalancersError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLoadBalancersError {
     #[allow(unused_variables)]
@@ -7466,11 +7658,11 @@ impl Error for DescribeLoadBalancersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeRulesError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl DescribeRulesError {
@@ -7483,17 +7675,17 @@ impl DescribeRulesError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(DescribeRulesError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(DescribeRulesError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(DescribeRulesError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7510,6 +7702,15 @@ impl DescribeRulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeRulesError::ListenerNotFound(ref cause) => cause.request_id(),
+            DescribeRulesError::RuleNotFound(ref cause) => cause.request_id(),
+            DescribeRulesError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeRulesError {
     #[allow(unused_variables)]
@@ -7526,7 +7727,7 @@ impl Error for DescribeRulesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeSSLPoliciesError {
     /// <p>The specified SSL policy does not exist.</p>
-    SSLPolicyNotFound(String),
+    SSLPolicyNotFound(XmlErrorMessage),
 }
 
 impl DescribeSSLPoliciesError {
@@ -7539,7 +7740,7 @@ impl DescribeSSLPoliciesError {
                 match &parsed_error.code[..] {
                     "SSLPolicyNotFound" => {
                         return RusotoError::Service(DescribeSSLPoliciesError::SSLPolicyNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7556,6 +7757,13 @@ impl DescribeSSLPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeSSLPoliciesError::SSLPolicyNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeSSLPoliciesError {
     #[allow(unused_variables)]
@@ -7570,13 +7778,13 @@ impl Error for DescribeSSLPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl DescribeTagsError {
@@ -7589,22 +7797,22 @@ impl DescribeTagsError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(DescribeTagsError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(DescribeTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(DescribeTagsError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(DescribeTagsError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7621,6 +7829,16 @@ impl DescribeTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTagsError::ListenerNotFound(ref cause) => cause.request_id(),
+            DescribeTagsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            DescribeTagsError::RuleNotFound(ref cause) => cause.request_id(),
+            DescribeTagsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTagsError {
     #[allow(unused_variables)]
@@ -7638,7 +7856,7 @@ impl Error for DescribeTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetGroupAttributesError {
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl DescribeTargetGroupAttributesError {
@@ -7654,7 +7872,7 @@ impl DescribeTargetGroupAttributesError {
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeTargetGroupAttributesError::TargetGroupNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7672,6 +7890,15 @@ impl DescribeTargetGroupAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTargetGroupAttributesError::TargetGroupNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeTargetGroupAttributesError {
     #[allow(unused_variables)]
@@ -7688,9 +7915,9 @@ impl Error for DescribeTargetGroupAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetGroupsError {
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl DescribeTargetGroupsError {
@@ -7703,12 +7930,16 @@ impl DescribeTargetGroupsError {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
-                            DescribeTargetGroupsError::LoadBalancerNotFound(parsed_error.message),
+                            DescribeTargetGroupsError::LoadBalancerNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(
-                            DescribeTargetGroupsError::TargetGroupNotFound(parsed_error.message),
+                            DescribeTargetGroupsError::TargetGroupNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7725,6 +7956,14 @@ impl DescribeTargetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTargetGroupsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            DescribeTargetGroupsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTargetGroupsError {
     #[allow(unused_variables)]
@@ -7740,11 +7979,11 @@ impl Error for DescribeTargetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetHealthError {
     /// <p>The health of the specified targets could not be retrieved due to an internal error.</p>
-    HealthUnavailable(String),
+    HealthUnavailable(XmlErrorMessage),
     /// <p>The specified target does not exist, is not in the same VPC as the target group, or has an unsupported instance type.</p>
-    InvalidTarget(String),
+    InvalidTarget(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl DescribeTargetHealthError {
@@ -7757,17 +7996,19 @@ impl DescribeTargetHealthError {
                 match &parsed_error.code[..] {
                     "HealthUnavailable" => {
                         return RusotoError::Service(DescribeTargetHealthError::HealthUnavailable(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTarget" => {
                         return RusotoError::Service(DescribeTargetHealthError::InvalidTarget(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(
-                            DescribeTargetHealthError::TargetGroupNotFound(parsed_error.message),
+                            DescribeTargetHealthError::TargetGroupNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -7784,6 +8025,15 @@ impl DescribeTargetHealthError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTargetHealthError::HealthUnavailable(ref cause) => cause.request_id(),
+            DescribeTargetHealthError::InvalidTarget(ref cause) => cause.request_id(),
+            DescribeTargetHealthError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTargetHealthError {
     #[allow(unused_variables)]
@@ -7800,39 +8050,39 @@ impl Error for DescribeTargetHealthError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyListenerError {
     /// <p>The specified ALPN policy is not supported.</p>
-    ALPNPolicyNotSupported(String),
+    ALPNPolicyNotSupported(XmlErrorMessage),
     /// <p>The specified certificate does not exist.</p>
-    CertificateNotFound(String),
+    CertificateNotFound(XmlErrorMessage),
     /// <p>A listener with the specified port already exists.</p>
-    DuplicateListener(String),
+    DuplicateListener(XmlErrorMessage),
     /// <p>The specified configuration is not valid with this protocol.</p>
-    IncompatibleProtocols(String),
+    IncompatibleProtocols(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The requested action is not valid.</p>
-    InvalidLoadBalancerAction(String),
+    InvalidLoadBalancerAction(XmlErrorMessage),
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified SSL policy does not exist.</p>
-    SSLPolicyNotFound(String),
+    SSLPolicyNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of load balancers per target group.</p>
-    TargetGroupAssociationLimit(String),
+    TargetGroupAssociationLimit(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of actions per rule.</p>
-    TooManyActions(String),
+    TooManyActions(XmlErrorMessage),
     /// <p>You've reached the limit on the number of certificates per load balancer.</p>
-    TooManyCertificates(String),
+    TooManyCertificates(XmlErrorMessage),
     /// <p>You've reached the limit on the number of listeners per load balancer.</p>
-    TooManyListeners(String),
+    TooManyListeners(XmlErrorMessage),
     /// <p>You've reached the limit on the number of times a target can be registered with a load balancer.</p>
-    TooManyRegistrationsForTargetId(String),
+    TooManyRegistrationsForTargetId(XmlErrorMessage),
     /// <p>You've reached the limit on the number of targets.</p>
-    TooManyTargets(String),
+    TooManyTargets(XmlErrorMessage),
     /// <p>You've reached the limit on the number of unique target groups per load balancer across all listeners. If a target group is used by multiple actions for a load balancer, it is counted as only one use.</p>
-    TooManyUniqueTargetGroupsPerLoadBalancer(String),
+    TooManyUniqueTargetGroupsPerLoadBalancer(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl ModifyListenerError {
@@ -7845,91 +8095,97 @@ impl ModifyListenerError {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
                         return RusotoError::Service(ModifyListenerError::ALPNPolicyNotSupported(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(ModifyListenerError::CertificateNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DuplicateListener" => {
                         return RusotoError::Service(ModifyListenerError::DuplicateListener(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "IncompatibleProtocols" => {
                         return RusotoError::Service(ModifyListenerError::IncompatibleProtocols(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
-                            ModifyListenerError::InvalidConfigurationRequest(parsed_error.message),
+                            ModifyListenerError::InvalidConfigurationRequest(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidLoadBalancerAction" => {
                         return RusotoError::Service(
-                            ModifyListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                            ModifyListenerError::InvalidLoadBalancerAction(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ListenerNotFound" => {
                         return RusotoError::Service(ModifyListenerError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SSLPolicyNotFound" => {
                         return RusotoError::Service(ModifyListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupAssociationLimit" => {
                         return RusotoError::Service(
-                            ModifyListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                            ModifyListenerError::TargetGroupAssociationLimit(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(ModifyListenerError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyActions" => {
                         return RusotoError::Service(ModifyListenerError::TooManyActions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyCertificates" => {
                         return RusotoError::Service(ModifyListenerError::TooManyCertificates(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyListeners" => {
                         return RusotoError::Service(ModifyListenerError::TooManyListeners(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRegistrationsForTargetId" => {
                         return RusotoError::Service(
                             ModifyListenerError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTargets" => {
                         return RusotoError::Service(ModifyListenerError::TooManyTargets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
                         return RusotoError::Service(
                             ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(ModifyListenerError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7946,6 +8202,31 @@ impl ModifyListenerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyListenerError::ALPNPolicyNotSupported(ref cause) => cause.request_id(),
+            ModifyListenerError::CertificateNotFound(ref cause) => cause.request_id(),
+            ModifyListenerError::DuplicateListener(ref cause) => cause.request_id(),
+            ModifyListenerError::IncompatibleProtocols(ref cause) => cause.request_id(),
+            ModifyListenerError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            ModifyListenerError::InvalidLoadBalancerAction(ref cause) => cause.request_id(),
+            ModifyListenerError::ListenerNotFound(ref cause) => cause.request_id(),
+            ModifyListenerError::SSLPolicyNotFound(ref cause) => cause.request_id(),
+            ModifyListenerError::TargetGroupAssociationLimit(ref cause) => cause.request_id(),
+            ModifyListenerError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyActions(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyCertificates(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyListeners(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyRegistrationsForTargetId(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyTargets(ref cause) => cause.request_id(),
+            ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(ref cause) => {
+                cause.request_id()
+            }
+            ModifyListenerError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyListenerError {
     #[allow(unused_variables)]
@@ -7980,9 +8261,9 @@ impl Error for ModifyListenerError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyLoadBalancerAttributesError {
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
 }
 
 impl ModifyLoadBalancerAttributesError {
@@ -7998,14 +8279,14 @@ impl ModifyLoadBalancerAttributesError {
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(
                             ModifyLoadBalancerAttributesError::LoadBalancerNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8023,6 +8304,18 @@ impl ModifyLoadBalancerAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            ModifyLoadBalancerAttributesError::LoadBalancerNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyLoadBalancerAttributesError {
     #[allow(unused_variables)]
@@ -8042,27 +8335,27 @@ impl Error for ModifyLoadBalancerAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyRuleError {
     /// <p>The specified configuration is not valid with this protocol.</p>
-    IncompatibleProtocols(String),
+    IncompatibleProtocols(XmlErrorMessage),
     /// <p>The requested action is not valid.</p>
-    InvalidLoadBalancerAction(String),
+    InvalidLoadBalancerAction(XmlErrorMessage),
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of load balancers per target group.</p>
-    TargetGroupAssociationLimit(String),
+    TargetGroupAssociationLimit(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of actions per rule.</p>
-    TooManyActions(String),
+    TooManyActions(XmlErrorMessage),
     /// <p>You've reached the limit on the number of times a target can be registered with a load balancer.</p>
-    TooManyRegistrationsForTargetId(String),
+    TooManyRegistrationsForTargetId(XmlErrorMessage),
     /// <p>You've reached the limit on the number of targets.</p>
-    TooManyTargets(String),
+    TooManyTargets(XmlErrorMessage),
     /// <p>You've reached the limit on the number of unique target groups per load balancer across all listeners. If a target group is used by multiple actions for a load balancer, it is counted as only one use.</p>
-    TooManyUniqueTargetGroupsPerLoadBalancer(String),
+    TooManyUniqueTargetGroupsPerLoadBalancer(XmlErrorMessage),
     /// <p>The specified protocol is not supported.</p>
-    UnsupportedProtocol(String),
+    UnsupportedProtocol(XmlErrorMessage),
 }
 
 impl ModifyRuleError {
@@ -8075,59 +8368,61 @@ impl ModifyRuleError {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
                         return RusotoError::Service(ModifyRuleError::IncompatibleProtocols(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidLoadBalancerAction" => {
                         return RusotoError::Service(ModifyRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "OperationNotPermitted" => {
                         return RusotoError::Service(ModifyRuleError::OperationNotPermitted(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(ModifyRuleError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupAssociationLimit" => {
                         return RusotoError::Service(ModifyRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(ModifyRuleError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyActions" => {
                         return RusotoError::Service(ModifyRuleError::TooManyActions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRegistrationsForTargetId" => {
                         return RusotoError::Service(
-                            ModifyRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                            ModifyRuleError::TooManyRegistrationsForTargetId(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TooManyTargets" => {
                         return RusotoError::Service(ModifyRuleError::TooManyTargets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
                         return RusotoError::Service(
                             ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
                         return RusotoError::Service(ModifyRuleError::UnsupportedProtocol(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8144,6 +8439,25 @@ impl ModifyRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyRuleError::IncompatibleProtocols(ref cause) => cause.request_id(),
+            ModifyRuleError::InvalidLoadBalancerAction(ref cause) => cause.request_id(),
+            ModifyRuleError::OperationNotPermitted(ref cause) => cause.request_id(),
+            ModifyRuleError::RuleNotFound(ref cause) => cause.request_id(),
+            ModifyRuleError::TargetGroupAssociationLimit(ref cause) => cause.request_id(),
+            ModifyRuleError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            ModifyRuleError::TooManyActions(ref cause) => cause.request_id(),
+            ModifyRuleError::TooManyRegistrationsForTargetId(ref cause) => cause.request_id(),
+            ModifyRuleError::TooManyTargets(ref cause) => cause.request_id(),
+            ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(ref cause) => {
+                cause.request_id()
+            }
+            ModifyRuleError::UnsupportedProtocol(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyRuleError {
     #[allow(unused_variables)]
@@ -8170,9 +8484,9 @@ impl Error for ModifyRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyTargetGroupError {
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl ModifyTargetGroupError {
@@ -8186,13 +8500,13 @@ impl ModifyTargetGroupError {
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             ModifyTargetGroupError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(ModifyTargetGroupError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8209,6 +8523,14 @@ impl ModifyTargetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyTargetGroupError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            ModifyTargetGroupError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyTargetGroupError {
     #[allow(unused_variables)]
@@ -8226,9 +8548,9 @@ impl Error for ModifyTargetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyTargetGroupAttributesError {
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
 }
 
 impl ModifyTargetGroupAttributesError {
@@ -8244,14 +8566,14 @@ impl ModifyTargetGroupAttributesError {
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             ModifyTargetGroupAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyTargetGroupAttributesError::TargetGroupNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8269,6 +8591,16 @@ impl ModifyTargetGroupAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyTargetGroupAttributesError::InvalidConfigurationRequest(ref cause) => {
+                cause.request_id()
+            }
+            ModifyTargetGroupAttributesError::TargetGroupNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyTargetGroupAttributesError {
     #[allow(unused_variables)]
@@ -8288,13 +8620,13 @@ impl Error for ModifyTargetGroupAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum RegisterTargetsError {
     /// <p>The specified target does not exist, is not in the same VPC as the target group, or has an unsupported instance type.</p>
-    InvalidTarget(String),
+    InvalidTarget(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of times a target can be registered with a load balancer.</p>
-    TooManyRegistrationsForTargetId(String),
+    TooManyRegistrationsForTargetId(XmlErrorMessage),
     /// <p>You've reached the limit on the number of targets.</p>
-    TooManyTargets(String),
+    TooManyTargets(XmlErrorMessage),
 }
 
 impl RegisterTargetsError {
@@ -8307,24 +8639,24 @@ impl RegisterTargetsError {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
                         return RusotoError::Service(RegisterTargetsError::InvalidTarget(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(RegisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRegistrationsForTargetId" => {
                         return RusotoError::Service(
                             RegisterTargetsError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTargets" => {
                         return RusotoError::Service(RegisterTargetsError::TooManyTargets(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8341,6 +8673,16 @@ impl RegisterTargetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RegisterTargetsError::InvalidTarget(ref cause) => cause.request_id(),
+            RegisterTargetsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            RegisterTargetsError::TooManyRegistrationsForTargetId(ref cause) => cause.request_id(),
+            RegisterTargetsError::TooManyTargets(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RegisterTargetsError {
     #[allow(unused_variables)]
@@ -8360,9 +8702,9 @@ impl Error for RegisterTargetsError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveListenerCertificatesError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
 }
 
 impl RemoveListenerCertificatesError {
@@ -8377,13 +8719,15 @@ impl RemoveListenerCertificatesError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(
-                            RemoveListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                            RemoveListenerCertificatesError::ListenerNotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "OperationNotPermitted" => {
                         return RusotoError::Service(
                             RemoveListenerCertificatesError::OperationNotPermitted(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8401,6 +8745,14 @@ impl RemoveListenerCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveListenerCertificatesError::ListenerNotFound(ref cause) => cause.request_id(),
+            RemoveListenerCertificatesError::OperationNotPermitted(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveListenerCertificatesError {
     #[allow(unused_variables)]
@@ -8418,15 +8770,15 @@ impl Error for RemoveListenerCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
     /// <p>The specified listener does not exist.</p>
-    ListenerNotFound(String),
+    ListenerNotFound(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
     /// <p>The specified target group does not exist.</p>
-    TargetGroupNotFound(String),
+    TargetGroupNotFound(XmlErrorMessage),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
-    TooManyTags(String),
+    TooManyTags(XmlErrorMessage),
 }
 
 impl RemoveTagsError {
@@ -8439,27 +8791,27 @@ impl RemoveTagsError {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
                         return RusotoError::Service(RemoveTagsError::ListenerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(RemoveTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(RemoveTagsError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TargetGroupNotFound" => {
                         return RusotoError::Service(RemoveTagsError::TargetGroupNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyTags" => {
                         return RusotoError::Service(RemoveTagsError::TooManyTags(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8476,6 +8828,17 @@ impl RemoveTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsError::ListenerNotFound(ref cause) => cause.request_id(),
+            RemoveTagsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            RemoveTagsError::RuleNotFound(ref cause) => cause.request_id(),
+            RemoveTagsError::TargetGroupNotFound(ref cause) => cause.request_id(),
+            RemoveTagsError::TooManyTags(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsError {
     #[allow(unused_variables)]
@@ -8494,11 +8857,11 @@ impl Error for RemoveTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum SetIpAddressTypeError {
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified subnet is out of available addresses.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
 }
 
 impl SetIpAddressTypeError {
@@ -8512,18 +8875,18 @@ impl SetIpAddressTypeError {
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             SetIpAddressTypeError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(SetIpAddressTypeError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(SetIpAddressTypeError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8540,6 +8903,15 @@ impl SetIpAddressTypeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetIpAddressTypeError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            SetIpAddressTypeError::InvalidSubnet(ref cause) => cause.request_id(),
+            SetIpAddressTypeError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetIpAddressTypeError {
     #[allow(unused_variables)]
@@ -8556,11 +8928,11 @@ impl Error for SetIpAddressTypeError {}
 #[derive(Debug, PartialEq)]
 pub enum SetRulePrioritiesError {
     /// <p>This operation is not allowed.</p>
-    OperationNotPermitted(String),
+    OperationNotPermitted(XmlErrorMessage),
     /// <p>The specified priority is in use.</p>
-    PriorityInUse(String),
+    PriorityInUse(XmlErrorMessage),
     /// <p>The specified rule does not exist.</p>
-    RuleNotFound(String),
+    RuleNotFound(XmlErrorMessage),
 }
 
 impl SetRulePrioritiesError {
@@ -8573,17 +8945,17 @@ impl SetRulePrioritiesError {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
                         return RusotoError::Service(SetRulePrioritiesError::OperationNotPermitted(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorityInUse" => {
                         return RusotoError::Service(SetRulePrioritiesError::PriorityInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleNotFound" => {
                         return RusotoError::Service(SetRulePrioritiesError::RuleNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8600,6 +8972,15 @@ impl SetRulePrioritiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetRulePrioritiesError::OperationNotPermitted(ref cause) => cause.request_id(),
+            SetRulePrioritiesError::PriorityInUse(ref cause) => cause.request_id(),
+            SetRulePrioritiesError::RuleNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetRulePrioritiesError {
     #[allow(unused_variables)]
@@ -8616,11 +8997,11 @@ impl Error for SetRulePrioritiesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetSecurityGroupsError {
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified security group does not exist.</p>
-    InvalidSecurityGroup(String),
+    InvalidSecurityGroup(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
 }
 
 impl SetSecurityGroupsError {
@@ -8634,18 +9015,18 @@ impl SetSecurityGroupsError {
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(
                             SetSecurityGroupsError::InvalidConfigurationRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSecurityGroup" => {
                         return RusotoError::Service(SetSecurityGroupsError::InvalidSecurityGroup(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(SetSecurityGroupsError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8662,6 +9043,15 @@ impl SetSecurityGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetSecurityGroupsError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            SetSecurityGroupsError::InvalidSecurityGroup(ref cause) => cause.request_id(),
+            SetSecurityGroupsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetSecurityGroupsError {
     #[allow(unused_variables)]
@@ -8680,17 +9070,17 @@ impl Error for SetSecurityGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum SetSubnetsError {
     /// <p>The specified allocation ID does not exist.</p>
-    AllocationIdNotFound(String),
+    AllocationIdNotFound(XmlErrorMessage),
     /// <p>The specified Availability Zone is not supported.</p>
-    AvailabilityZoneNotSupported(String),
+    AvailabilityZoneNotSupported(XmlErrorMessage),
     /// <p>The requested configuration is not valid.</p>
-    InvalidConfigurationRequest(String),
+    InvalidConfigurationRequest(XmlErrorMessage),
     /// <p>The specified subnet is out of available addresses.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The specified load balancer does not exist.</p>
-    LoadBalancerNotFound(String),
+    LoadBalancerNotFound(XmlErrorMessage),
     /// <p>The specified subnet does not exist.</p>
-    SubnetNotFound(String),
+    SubnetNotFound(XmlErrorMessage),
 }
 
 impl SetSubnetsError {
@@ -8703,32 +9093,32 @@ impl SetSubnetsError {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
                         return RusotoError::Service(SetSubnetsError::AllocationIdNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AvailabilityZoneNotSupported" => {
                         return RusotoError::Service(SetSubnetsError::AvailabilityZoneNotSupported(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidConfigurationRequest" => {
                         return RusotoError::Service(SetSubnetsError::InvalidConfigurationRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(SetSubnetsError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LoadBalancerNotFound" => {
                         return RusotoError::Service(SetSubnetsError::LoadBalancerNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetNotFound" => {
                         return RusotoError::Service(SetSubnetsError::SubnetNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8745,6 +9135,18 @@ impl SetSubnetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetSubnetsError::AllocationIdNotFound(ref cause) => cause.request_id(),
+            SetSubnetsError::AvailabilityZoneNotSupported(ref cause) => cause.request_id(),
+            SetSubnetsError::InvalidConfigurationRequest(ref cause) => cause.request_id(),
+            SetSubnetsError::InvalidSubnet(ref cause) => cause.request_id(),
+            SetSubnetsError::LoadBalancerNotFound(ref cause) => cause.request_id(),
+            SetSubnetsError::SubnetNotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetSubnetsError {
     #[allow(unused_variables)]
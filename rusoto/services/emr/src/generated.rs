@@ -42,7 +42,7 @@ impl EmrClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4069,7 +4069,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, AddInstanceFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddInstanceFleetOutput, _>()
     }
 
@@ -4087,7 +4087,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, AddInstanceGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddInstanceGroupsOutput, _>()
     }
 
@@ -4105,7 +4105,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, AddJobFlowStepsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddJobFlowStepsOutput, _>()
     }
 
@@ -4123,7 +4123,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, AddTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
     }
 
@@ -4141,7 +4141,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, CancelStepsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelStepsOutput, _>()
     }
 
@@ -4163,7 +4163,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, CreateSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSecurityConfigurationOutput, _>()
     }
@@ -4186,7 +4186,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, DeleteSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSecurityConfigurationOutput, _>()
     }
@@ -4205,7 +4205,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, DescribeClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeClusterOutput, _>()
     }
 
@@ -4223,7 +4223,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, DescribeJobFlowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeJobFlowsOutput, _>()
     }
 
@@ -4245,7 +4245,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, DescribeSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSecurityConfigurationOutput, _>()
     }
@@ -4264,7 +4264,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, DescribeStepError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStepOutput, _>()
     }
 
@@ -4289,7 +4289,7 @@ impl Emr for EmrClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetBlockPublicAccessConfigurationOutput, _>()
     }
@@ -4308,7 +4308,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, GetManagedScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetManagedScalingPolicyOutput, _>()
     }
@@ -4327,7 +4327,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListBootstrapActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBootstrapActionsOutput, _>()
     }
 
@@ -4345,7 +4345,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListClustersOutput, _>()
     }
 
@@ -4363,7 +4363,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListInstanceFleetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInstanceFleetsOutput, _>()
     }
 
@@ -4381,7 +4381,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListInstanceGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInstanceGroupsOutput, _>()
     }
 
@@ -4399,7 +4399,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInstancesOutput, _>()
     }
 
@@ -4421,7 +4421,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListSecurityConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSecurityConfigurationsOutput, _>()
     }
@@ -4440,7 +4440,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ListStepsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListStepsOutput, _>()
     }
 
@@ -4458,7 +4458,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, ModifyClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyClusterOutput, _>()
     }
 
@@ -4510,7 +4510,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, PutAutoScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutAutoScalingPolicyOutput, _>()
     }
 
@@ -4537,7 +4537,7 @@ impl Emr for EmrClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutBlockPublicAccessConfigurationOutput, _>()
     }
@@ -4556,7 +4556,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, PutManagedScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutManagedScalingPolicyOutput, _>()
     }
@@ -4575,7 +4575,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, RemoveAutoScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveAutoScalingPolicyOutput, _>()
     }
@@ -4598,7 +4598,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, RemoveManagedScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveManagedScalingPolicyOutput, _>()
     }
@@ -4617,7 +4617,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, RemoveTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsOutput, _>()
     }
 
@@ -4635,7 +4635,7 @@ impl Emr for EmrClient {
             .sign_and_dispatch(request, RunJobFlowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RunJobFlowOutput, _>()
     }
 
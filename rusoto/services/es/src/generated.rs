@@ -4317,6 +4317,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation("AcceptInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4325,13 +4326,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AcceptInboundCrossClusterSearchConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AcceptInboundCrossClusterSearchConnectionError::from_response(response))
         }
     }
@@ -4342,6 +4343,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AddTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4353,12 +4355,12 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddTagsError::from_response(response))
         }
     }
@@ -4376,6 +4378,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AssociatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4384,13 +4387,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociatePackageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociatePackageError::from_response(response))
         }
     }
@@ -4407,6 +4410,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/cancel";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CancelElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4418,13 +4422,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelElasticsearchServiceSoftwareUpdateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelElasticsearchServiceSoftwareUpdateError::from_response(response))
         }
     }
@@ -4439,6 +4443,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4450,13 +4455,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateElasticsearchDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateElasticsearchDomainError::from_response(response))
         }
     }
@@ -4473,6 +4478,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateOutboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4484,13 +4490,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateOutboundCrossClusterSearchConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateOutboundCrossClusterSearchConnectionError::from_response(response))
         }
     }
@@ -4504,6 +4510,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4515,13 +4522,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePackageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePackageError::from_response(response))
         }
     }
@@ -4539,6 +4546,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4547,13 +4555,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteElasticsearchDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteElasticsearchDomainError::from_response(response))
         }
     }
@@ -4566,6 +4574,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/role";
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchServiceRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4574,12 +4583,12 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteElasticsearchServiceRoleError::from_response(response))
         }
     }
@@ -4599,6 +4608,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4607,13 +4617,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInboundCrossClusterSearchConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInboundCrossClusterSearchConnectionError::from_response(response))
         }
     }
@@ -4633,6 +4643,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteOutboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4641,13 +4652,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteOutboundCrossClusterSearchConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteOutboundCrossClusterSearchConnectionError::from_response(response))
         }
     }
@@ -4664,6 +4675,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeletePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4672,13 +4684,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePackageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePackageError::from_response(response))
         }
     }
@@ -4696,6 +4708,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4704,13 +4717,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeElasticsearchDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeElasticsearchDomainError::from_response(response))
         }
     }
@@ -4730,6 +4743,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4738,13 +4752,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeElasticsearchDomainConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeElasticsearchDomainConfigError::from_response(
                 response,
             ))
@@ -4761,6 +4775,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain-info";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomains");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4772,13 +4787,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeElasticsearchDomainsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeElasticsearchDomainsError::from_response(response))
         }
     }
@@ -4799,6 +4814,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchInstanceTypeLimits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4813,13 +4829,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeElasticsearchInstanceTypeLimitsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeElasticsearchInstanceTypeLimitsError::from_response(
                 response,
             ))
@@ -4838,6 +4854,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/inboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeInboundCrossClusterSearchConnections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4849,14 +4866,14 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeInboundCrossClusterSearchConnectionsResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeInboundCrossClusterSearchConnectionsError::from_response(response))
         }
     }
@@ -4873,6 +4890,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeOutboundCrossClusterSearchConnections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4884,14 +4902,14 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeOutboundCrossClusterSearchConnectionsResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeOutboundCrossClusterSearchConnectionsError::from_response(response))
         }
     }
@@ -4905,6 +4923,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages/describe";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribePackages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4916,13 +4935,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribePackagesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribePackagesError::from_response(response))
         }
     }
@@ -4939,6 +4958,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstanceOfferings";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstanceOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4959,14 +4979,14 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeReservedElasticsearchInstanceOfferingsResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeReservedElasticsearchInstanceOfferingsError::from_response(response))
         }
     }
@@ -4983,6 +5003,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstances";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5003,13 +5024,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeReservedElasticsearchInstancesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeReservedElasticsearchInstancesError::from_response(
                 response,
             ))
@@ -5029,6 +5050,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DissociatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5037,13 +5059,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DissociatePackageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DissociatePackageError::from_response(response))
         }
     }
@@ -5060,6 +5082,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/compatibleVersions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetCompatibleElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5074,13 +5097,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCompatibleElasticsearchVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCompatibleElasticsearchVersionsError::from_response(
                 response,
             ))
@@ -5099,6 +5122,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeHistory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5116,13 +5140,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetUpgradeHistoryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUpgradeHistoryError::from_response(response))
         }
     }
@@ -5139,6 +5163,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5147,13 +5172,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetUpgradeStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUpgradeStatusError::from_response(response))
         }
     }
@@ -5166,6 +5191,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/domain";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5174,13 +5200,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDomainNamesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDomainNamesError::from_response(response))
         }
     }
@@ -5197,6 +5223,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainsForPackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5214,13 +5241,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDomainsForPackageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDomainsForPackageError::from_response(response))
         }
     }
@@ -5240,6 +5267,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5260,13 +5288,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListElasticsearchInstanceTypesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListElasticsearchInstanceTypesError::from_response(response))
         }
     }
@@ -5281,6 +5309,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/versions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5298,13 +5327,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListElasticsearchVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListElasticsearchVersionsError::from_response(response))
         }
     }
@@ -5321,6 +5350,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListPackagesForDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5338,13 +5368,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPackagesForDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPackagesForDomainError::from_response(response))
         }
     }
@@ -5358,6 +5388,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags/";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5370,13 +5401,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsError::from_response(response))
         }
     }
@@ -5393,6 +5424,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("PurchaseReservedElasticsearchInstanceOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5404,14 +5436,14 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PurchaseReservedElasticsearchInstanceOfferingResponse, _>(
             )?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PurchaseReservedElasticsearchInstanceOfferingError::from_response(response))
         }
     }
@@ -5431,6 +5463,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation("RejectInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5439,13 +5472,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RejectInboundCrossClusterSearchConnectionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RejectInboundCrossClusterSearchConnectionError::from_response(response))
         }
     }
@@ -5459,6 +5492,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags-removal";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("RemoveTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5470,12 +5504,12 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveTagsError::from_response(response))
         }
     }
@@ -5492,6 +5526,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("StartElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5503,13 +5538,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartElasticsearchServiceSoftwareUpdateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartElasticsearchServiceSoftwareUpdateError::from_response(
                 response,
             ))
@@ -5531,6 +5566,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpdateElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5542,13 +5578,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateElasticsearchDomainConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateElasticsearchDomainConfigError::from_response(
                 response,
             ))
@@ -5565,6 +5601,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/upgradeDomain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpgradeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5576,13 +5613,13 @@ impl Es for EsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpgradeElasticsearchDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpgradeElasticsearchDomainError::from_response(response))
         }
     }
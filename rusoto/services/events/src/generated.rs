@@ -41,7 +41,7 @@ impl EventBridgeClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2940,7 +2940,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, CreateEventBusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEventBusResponse, _>()
     }
 
@@ -2958,7 +2958,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, CreatePartnerEventSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePartnerEventSourceResponse, _>()
     }
@@ -3045,7 +3045,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, DescribeEventBusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventBusResponse, _>()
     }
 
@@ -3063,7 +3063,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, DescribeEventSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventSourceResponse, _>()
     }
 
@@ -3082,7 +3082,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, DescribePartnerEventSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePartnerEventSourceResponse, _>()
     }
@@ -3101,7 +3101,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, DescribeRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeRuleResponse, _>()
     }
 
@@ -3153,7 +3153,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListEventBusesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEventBusesResponse, _>()
     }
 
@@ -3171,7 +3171,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListEventSourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEventSourcesResponse, _>()
     }
 
@@ -3192,7 +3192,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListPartnerEventSourceAccountsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPartnerEventSourceAccountsResponse, _>()
     }
@@ -3211,7 +3211,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListPartnerEventSourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPartnerEventSourcesResponse, _>()
     }
@@ -3230,7 +3230,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListRuleNamesByTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRuleNamesByTargetResponse, _>()
     }
@@ -3249,7 +3249,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRulesResponse, _>()
     }
 
@@ -3267,7 +3267,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3285,7 +3285,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, ListTargetsByRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTargetsByRuleResponse, _>()
     }
 
@@ -3303,7 +3303,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, PutEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutEventsResponse, _>()
     }
 
@@ -3321,7 +3321,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, PutPartnerEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutPartnerEventsResponse, _>()
     }
 
@@ -3356,7 +3356,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, PutRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRuleResponse, _>()
     }
 
@@ -3374,7 +3374,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, PutTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutTargetsResponse, _>()
     }
 
@@ -3409,7 +3409,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, RemoveTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RemoveTargetsResponse, _>()
     }
 
@@ -3427,7 +3427,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3445,7 +3445,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, TestEventPatternError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TestEventPatternResponse, _>()
     }
 
@@ -3463,7 +3463,7 @@ impl EventBridge for EventBridgeClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
@@ -41,7 +41,7 @@ impl KinesisFirehoseClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2293,7 +2293,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, CreateDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDeliveryStreamOutput, _>()
     }
 
@@ -2311,7 +2311,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, DeleteDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDeliveryStreamOutput, _>()
     }
 
@@ -2329,7 +2329,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, DescribeDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDeliveryStreamOutput, _>()
     }
@@ -2348,7 +2348,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, ListDeliveryStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDeliveryStreamsOutput, _>()
     }
 
@@ -2369,7 +2369,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, ListTagsForDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTagsForDeliveryStreamOutput, _>()
     }
@@ -2388,7 +2388,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, PutRecordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
     }
 
@@ -2406,7 +2406,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, PutRecordBatchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRecordBatchOutput, _>()
     }
 
@@ -2428,7 +2428,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, StartDeliveryStreamEncryptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDeliveryStreamEncryptionOutput, _>()
     }
@@ -2451,7 +2451,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, StopDeliveryStreamEncryptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopDeliveryStreamEncryptionOutput, _>()
     }
@@ -2470,7 +2470,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, TagDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagDeliveryStreamOutput, _>()
     }
 
@@ -2488,7 +2488,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, UntagDeliveryStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagDeliveryStreamOutput, _>()
     }
 
@@ -2506,7 +2506,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
             .sign_and_dispatch(request, UpdateDestinationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDestinationOutput, _>()
     }
 }
@@ -41,7 +41,7 @@ impl FmsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1590,7 +1590,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, GetAdminAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAdminAccountResponse, _>()
     }
 
@@ -1608,7 +1608,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, GetComplianceDetailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetComplianceDetailResponse, _>()
     }
 
@@ -1624,7 +1624,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, GetNotificationChannelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetNotificationChannelResponse, _>()
     }
@@ -1643,7 +1643,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, GetPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPolicyResponse, _>()
     }
 
@@ -1661,7 +1661,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, GetProtectionStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetProtectionStatusResponse, _>()
     }
 
@@ -1679,7 +1679,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, ListComplianceStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListComplianceStatusResponse, _>()
     }
@@ -1698,7 +1698,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, ListMemberAccountsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListMemberAccountsResponse, _>()
     }
 
@@ -1716,7 +1716,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, ListPoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPoliciesResponse, _>()
     }
 
@@ -1734,7 +1734,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -1769,7 +1769,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, PutPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutPolicyResponse, _>()
     }
 
@@ -1787,7 +1787,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -1805,7 +1805,7 @@ impl Fms for FmsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
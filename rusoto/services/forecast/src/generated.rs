@@ -41,7 +41,7 @@ impl ForecastClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2941,7 +2941,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreateDatasetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetResponse, _>()
     }
 
@@ -2959,7 +2959,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreateDatasetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetGroupResponse, _>()
     }
 
@@ -2977,7 +2977,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreateDatasetImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDatasetImportJobResponse, _>()
     }
@@ -2996,7 +2996,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreateForecastError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateForecastResponse, _>()
     }
 
@@ -3014,7 +3014,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreateForecastExportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateForecastExportJobResponse, _>()
     }
@@ -3033,7 +3033,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, CreatePredictorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePredictorResponse, _>()
     }
 
@@ -3153,7 +3153,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribeDatasetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDatasetResponse, _>()
     }
 
@@ -3171,7 +3171,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribeDatasetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDatasetGroupResponse, _>()
     }
@@ -3190,7 +3190,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribeDatasetImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDatasetImportJobResponse, _>()
     }
@@ -3209,7 +3209,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribeForecastError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeForecastResponse, _>()
     }
 
@@ -3228,7 +3228,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribeForecastExportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeForecastExportJobResponse, _>()
     }
@@ -3247,7 +3247,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, DescribePredictorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePredictorResponse, _>()
     }
 
@@ -3265,7 +3265,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, GetAccuracyMetricsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAccuracyMetricsResponse, _>()
     }
 
@@ -3283,7 +3283,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListDatasetGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetGroupsResponse, _>()
     }
 
@@ -3301,7 +3301,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListDatasetImportJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDatasetImportJobsResponse, _>()
     }
@@ -3320,7 +3320,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListDatasetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetsResponse, _>()
     }
 
@@ -3338,7 +3338,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListForecastExportJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListForecastExportJobsResponse, _>()
     }
@@ -3357,7 +3357,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListForecastsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListForecastsResponse, _>()
     }
 
@@ -3375,7 +3375,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListPredictorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPredictorsResponse, _>()
     }
 
@@ -3393,7 +3393,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3411,7 +3411,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3429,7 +3429,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -3447,7 +3447,7 @@ impl Forecast for ForecastClient {
             .sign_and_dispatch(request, UpdateDatasetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDatasetGroupResponse, _>()
     }
 }
@@ -42,7 +42,7 @@ impl FraudDetectorClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3005,7 +3005,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, BatchCreateVariableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchCreateVariableResult, _>()
     }
 
@@ -3023,7 +3023,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, BatchGetVariableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetVariableResult, _>()
     }
 
@@ -3044,7 +3044,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, CreateDetectorVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDetectorVersionResult, _>()
     }
 
@@ -3065,7 +3065,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, CreateModelVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateModelVersionResult, _>()
     }
 
@@ -3083,7 +3083,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, CreateRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleResult, _>()
     }
 
@@ -3101,7 +3101,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, CreateVariableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateVariableResult, _>()
     }
 
@@ -3119,7 +3119,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DeleteDetectorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDetectorResult, _>()
     }
 
@@ -3140,7 +3140,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DeleteDetectorVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDetectorVersionResult, _>()
     }
 
@@ -3158,7 +3158,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DeleteEventError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteEventResult, _>()
     }
 
@@ -3179,7 +3179,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DeleteRuleVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleVersionResult, _>()
     }
 
@@ -3197,7 +3197,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DescribeDetectorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDetectorResult, _>()
     }
 
@@ -3218,7 +3218,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, DescribeModelVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeModelVersionsResult, _>()
     }
 
@@ -3239,7 +3239,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetDetectorVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDetectorVersionResult, _>()
     }
 
@@ -3257,7 +3257,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetDetectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDetectorsResult, _>()
     }
 
@@ -3278,7 +3278,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetExternalModelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetExternalModelsResult, _>()
     }
 
@@ -3296,7 +3296,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetModelVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetModelVersionResult, _>()
     }
 
@@ -3314,7 +3314,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetModelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetModelsResult, _>()
     }
 
@@ -3332,7 +3332,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetOutcomesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOutcomesResult, _>()
     }
 
@@ -3350,7 +3350,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetPredictionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPredictionResult, _>()
     }
 
@@ -3368,7 +3368,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRulesResult, _>()
     }
 
@@ -3386,7 +3386,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, GetVariablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetVariablesResult, _>()
     }
 
@@ -3404,7 +3404,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, PutDetectorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutDetectorResult, _>()
     }
 
@@ -3422,7 +3422,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, PutExternalModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutExternalModelResult, _>()
     }
 
@@ -3440,7 +3440,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, PutModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutModelResult, _>()
     }
 
@@ -3458,7 +3458,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, PutOutcomeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutOutcomeResult, _>()
     }
 
@@ -3479,7 +3479,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateDetectorVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDetectorVersionResult, _>()
     }
 
@@ -3501,7 +3501,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateDetectorVersionMetadataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDetectorVersionMetadataResult, _>()
     }
@@ -3524,7 +3524,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateDetectorVersionStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDetectorVersionStatusResult, _>()
     }
@@ -3546,7 +3546,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateModelVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateModelVersionResult, _>()
     }
 
@@ -3567,7 +3567,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateRuleMetadataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleMetadataResult, _>()
     }
 
@@ -3588,7 +3588,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateRuleVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleVersionResult, _>()
     }
 
@@ -3606,7 +3606,7 @@ impl FraudDetector for FraudDetectorClient {
             .sign_and_dispatch(request, UpdateVariableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVariableResult, _>()
     }
 }
@@ -41,7 +41,7 @@ impl FsxClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2292,7 +2292,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, CancelDataRepositoryTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CancelDataRepositoryTaskResponse, _>()
     }
@@ -2311,7 +2311,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, CreateBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupResponse, _>()
     }
 
@@ -2332,7 +2332,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, CreateDataRepositoryTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDataRepositoryTaskResponse, _>()
     }
@@ -2354,7 +2354,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, CreateFileSystemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFileSystemResponse, _>()
     }
 
@@ -2376,7 +2376,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, CreateFileSystemFromBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateFileSystemFromBackupResponse, _>()
     }
@@ -2395,7 +2395,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, DeleteBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
     }
 
@@ -2416,7 +2416,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, DeleteFileSystemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFileSystemResponse, _>()
     }
 
@@ -2437,7 +2437,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, DescribeBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
     }
 
@@ -2459,7 +2459,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, DescribeDataRepositoryTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDataRepositoryTasksResponse, _>()
     }
@@ -2481,7 +2481,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, DescribeFileSystemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFileSystemsResponse, _>()
     }
 
@@ -2502,7 +2502,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2520,7 +2520,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2538,7 +2538,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2559,7 +2559,7 @@ impl Fsx for FsxClient {
             .sign_and_dispatch(request, UpdateFileSystemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFileSystemResponse, _>()
     }
 }
@@ -41,7 +41,7 @@ impl GameLiftClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -9411,7 +9411,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, AcceptMatchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AcceptMatchOutput, _>()
     }
 
@@ -9429,7 +9429,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ClaimGameServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ClaimGameServerOutput, _>()
     }
 
@@ -9447,7 +9447,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAliasOutput, _>()
     }
 
@@ -9465,7 +9465,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateBuildError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBuildOutput, _>()
     }
 
@@ -9483,7 +9483,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateFleetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFleetOutput, _>()
     }
 
@@ -9501,7 +9501,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGameServerGroupOutput, _>()
     }
 
@@ -9519,7 +9519,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateGameSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGameSessionOutput, _>()
     }
 
@@ -9537,7 +9537,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateGameSessionQueueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateGameSessionQueueOutput, _>()
     }
@@ -9559,7 +9559,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateMatchmakingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateMatchmakingConfigurationOutput, _>()
     }
@@ -9578,7 +9578,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateMatchmakingRuleSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateMatchmakingRuleSetOutput, _>()
     }
@@ -9597,7 +9597,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreatePlayerSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePlayerSessionOutput, _>()
     }
 
@@ -9615,7 +9615,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreatePlayerSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePlayerSessionsOutput, _>()
     }
 
@@ -9633,7 +9633,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateScriptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateScriptOutput, _>()
     }
 
@@ -9652,7 +9652,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateVpcPeeringAuthorizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateVpcPeeringAuthorizationOutput, _>()
     }
@@ -9672,7 +9672,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, CreateVpcPeeringConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateVpcPeeringConnectionOutput, _>()
     }
@@ -9742,7 +9742,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGameServerGroupOutput, _>()
     }
 
@@ -9760,7 +9760,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteGameSessionQueueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteGameSessionQueueOutput, _>()
     }
@@ -9782,7 +9782,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteMatchmakingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteMatchmakingConfigurationOutput, _>()
     }
@@ -9801,7 +9801,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteMatchmakingRuleSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteMatchmakingRuleSetOutput, _>()
     }
@@ -9855,7 +9855,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteVpcPeeringAuthorizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteVpcPeeringAuthorizationOutput, _>()
     }
@@ -9875,7 +9875,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DeleteVpcPeeringConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteVpcPeeringConnectionOutput, _>()
     }
@@ -9911,7 +9911,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAliasOutput, _>()
     }
 
@@ -9929,7 +9929,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeBuildError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBuildOutput, _>()
     }
 
@@ -9947,7 +9947,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeEC2InstanceLimitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEC2InstanceLimitsOutput, _>()
     }
@@ -9966,7 +9966,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeFleetAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeFleetAttributesOutput, _>()
     }
@@ -9985,7 +9985,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeFleetCapacityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFleetCapacityOutput, _>()
     }
 
@@ -10003,7 +10003,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeFleetEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFleetEventsOutput, _>()
     }
 
@@ -10021,7 +10021,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeFleetPortSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeFleetPortSettingsOutput, _>()
     }
@@ -10040,7 +10040,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeFleetUtilizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeFleetUtilizationOutput, _>()
     }
@@ -10059,7 +10059,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeGameServerOutput, _>()
     }
 
@@ -10077,7 +10077,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGameServerGroupOutput, _>()
     }
@@ -10097,7 +10097,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameSessionDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGameSessionDetailsOutput, _>()
     }
@@ -10117,7 +10117,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameSessionPlacementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGameSessionPlacementOutput, _>()
     }
@@ -10136,7 +10136,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameSessionQueuesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGameSessionQueuesOutput, _>()
     }
@@ -10155,7 +10155,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeGameSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeGameSessionsOutput, _>()
     }
 
@@ -10173,7 +10173,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeInstancesOutput, _>()
     }
 
@@ -10191,7 +10191,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeMatchmakingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeMatchmakingOutput, _>()
     }
 
@@ -10215,7 +10215,7 @@ impl GameLift for GameLiftClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMatchmakingConfigurationsOutput, _>()
     }
@@ -10235,7 +10235,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeMatchmakingRuleSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMatchmakingRuleSetsOutput, _>()
     }
@@ -10254,7 +10254,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribePlayerSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePlayerSessionsOutput, _>()
     }
@@ -10274,7 +10274,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeRuntimeConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRuntimeConfigurationOutput, _>()
     }
@@ -10293,7 +10293,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeScalingPoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeScalingPoliciesOutput, _>()
     }
@@ -10312,7 +10312,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeScriptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeScriptOutput, _>()
     }
 
@@ -10334,7 +10334,7 @@ impl GameLift for GameLiftClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeVpcPeeringAuthorizationsOutput, _>()
     }
@@ -10354,7 +10354,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, DescribeVpcPeeringConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeVpcPeeringConnectionsOutput, _>()
     }
@@ -10373,7 +10373,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, GetGameSessionLogUrlError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGameSessionLogUrlOutput, _>()
     }
 
@@ -10391,7 +10391,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, GetInstanceAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceAccessOutput, _>()
     }
 
@@ -10409,7 +10409,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListAliasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAliasesOutput, _>()
     }
 
@@ -10427,7 +10427,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListBuildsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBuildsOutput, _>()
     }
 
@@ -10445,7 +10445,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListFleetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFleetsOutput, _>()
     }
 
@@ -10463,7 +10463,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListGameServerGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGameServerGroupsOutput, _>()
     }
 
@@ -10481,7 +10481,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListGameServersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGameServersOutput, _>()
     }
 
@@ -10499,7 +10499,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListScriptsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListScriptsOutput, _>()
     }
 
@@ -10517,7 +10517,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -10535,7 +10535,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, PutScalingPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutScalingPolicyOutput, _>()
     }
 
@@ -10553,7 +10553,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, RegisterGameServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterGameServerOutput, _>()
     }
 
@@ -10571,7 +10571,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, RequestUploadCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RequestUploadCredentialsOutput, _>()
     }
@@ -10590,7 +10590,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ResolveAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveAliasOutput, _>()
     }
 
@@ -10608,7 +10608,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ResumeGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResumeGameServerGroupOutput, _>()
     }
 
@@ -10626,7 +10626,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, SearchGameSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchGameSessionsOutput, _>()
     }
 
@@ -10644,7 +10644,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StartFleetActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartFleetActionsOutput, _>()
     }
 
@@ -10662,7 +10662,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StartGameSessionPlacementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartGameSessionPlacementOutput, _>()
     }
@@ -10681,7 +10681,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StartMatchBackfillError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartMatchBackfillOutput, _>()
     }
 
@@ -10699,7 +10699,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StartMatchmakingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartMatchmakingOutput, _>()
     }
 
@@ -10717,7 +10717,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StopFleetActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopFleetActionsOutput, _>()
     }
 
@@ -10735,7 +10735,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StopGameSessionPlacementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopGameSessionPlacementOutput, _>()
     }
@@ -10754,7 +10754,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, StopMatchmakingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopMatchmakingOutput, _>()
     }
 
@@ -10772,7 +10772,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, SuspendGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SuspendGameServerGroupOutput, _>()
     }
@@ -10791,7 +10791,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -10809,7 +10809,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -10827,7 +10827,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAliasOutput, _>()
     }
 
@@ -10845,7 +10845,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateBuildError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateBuildOutput, _>()
     }
 
@@ -10863,7 +10863,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateFleetAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFleetAttributesOutput, _>()
     }
 
@@ -10881,7 +10881,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateFleetCapacityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFleetCapacityOutput, _>()
     }
 
@@ -10899,7 +10899,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateFleetPortSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateFleetPortSettingsOutput, _>()
     }
@@ -10918,7 +10918,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateGameServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGameServerOutput, _>()
     }
 
@@ -10936,7 +10936,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateGameServerGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGameServerGroupOutput, _>()
     }
 
@@ -10954,7 +10954,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateGameSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGameSessionOutput, _>()
     }
 
@@ -10972,7 +10972,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateGameSessionQueueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateGameSessionQueueOutput, _>()
     }
@@ -10994,7 +10994,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateMatchmakingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMatchmakingConfigurationOutput, _>()
     }
@@ -11014,7 +11014,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateRuntimeConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateRuntimeConfigurationOutput, _>()
     }
@@ -11033,7 +11033,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, UpdateScriptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateScriptOutput, _>()
     }
 
@@ -11052,7 +11052,7 @@ impl GameLift for GameLiftClient {
             .sign_and_dispatch(request, ValidateMatchmakingRuleSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ValidateMatchmakingRuleSetOutput, _>()
     }
@@ -3349,6 +3349,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3358,12 +3359,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AbortMultipartUploadError::from_response(response))
         }
     }
@@ -3381,6 +3382,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3390,12 +3392,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AbortVaultLockError::from_response(response))
         }
     }
@@ -3413,6 +3415,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("AddTagsToVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3429,12 +3432,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddTagsToVaultError::from_response(response))
         }
     }
@@ -3453,6 +3456,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3465,7 +3469,7 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ArchiveCreationOutput, _>()?;
             result.archive_id = response.headers.remove("x-amz-archive-id");
@@ -3474,7 +3478,7 @@ impl Glacier for GlacierClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CompleteMultipartUploadError::from_response(response))
         }
     }
@@ -3493,6 +3497,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3502,12 +3507,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CompleteVaultLockError::from_response(response))
         }
     }
@@ -3525,6 +3530,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("CreateVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3534,14 +3540,14 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVaultOutput, _>()?;
             result.location = response.headers.remove("Location");
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateVaultError::from_response(response))
         }
     }
@@ -3560,6 +3566,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3569,12 +3576,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteArchiveError::from_response(response))
         }
     }
@@ -3592,6 +3599,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3601,12 +3609,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVaultError::from_response(response))
         }
     }
@@ -3624,6 +3632,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3633,12 +3642,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVaultAccessPolicyError::from_response(response))
         }
     }
@@ -3656,6 +3665,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3665,12 +3675,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteVaultNotificationsError::from_response(response))
         }
     }
@@ -3689,6 +3699,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3698,13 +3709,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GlacierJobDescription, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeJobError::from_response(response))
         }
     }
@@ -3722,6 +3733,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3731,13 +3743,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVaultOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeVaultError::from_response(response))
         }
     }
@@ -3754,6 +3766,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3763,13 +3776,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataRetrievalPolicyOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDataRetrievalPolicyError::from_response(response))
         }
     }
@@ -3788,6 +3801,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetJobOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3799,7 +3813,7 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetJobOutputOutput::default();
             result.body = Some(response.body);
@@ -3812,7 +3826,7 @@ impl Glacier for GlacierClient {
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetJobOutputError::from_response(response))
         }
     }
@@ -3830,6 +3844,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3839,13 +3854,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultAccessPolicyOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVaultAccessPolicyError::from_response(response))
         }
     }
@@ -3863,6 +3878,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3872,13 +3888,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultLockOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVaultLockError::from_response(response))
         }
     }
@@ -3896,6 +3912,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3905,13 +3922,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultNotificationsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetVaultNotificationsError::from_response(response))
         }
     }
@@ -3929,6 +3946,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3941,7 +3959,7 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateJobOutput, _>()?;
             result.job_id = response.headers.remove("x-amz-job-id");
@@ -3950,7 +3968,7 @@ impl Glacier for GlacierClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InitiateJobError::from_response(response))
         }
     }
@@ -3968,6 +3986,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3983,7 +4002,7 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateMultipartUploadOutput, _>()?;
             result.location = response.headers.remove("Location");
@@ -3991,7 +4010,7 @@ impl Glacier for GlacierClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InitiateMultipartUploadError::from_response(response))
         }
     }
@@ -4009,6 +4028,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4021,14 +4041,14 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateVaultLockOutput, _>()?;
             result.lock_id = response.headers.remove("x-amz-lock-id");
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InitiateVaultLockError::from_response(response))
         }
     }
@@ -4046,6 +4066,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4070,13 +4091,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListJobsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListJobsError::from_response(response))
         }
     }
@@ -4094,6 +4115,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4112,13 +4134,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMultipartUploadsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMultipartUploadsError::from_response(response))
         }
     }
@@ -4137,6 +4159,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4155,13 +4178,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListPartsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPartsError::from_response(response))
         }
     }
@@ -4178,6 +4201,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4187,13 +4211,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProvisionedCapacityOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProvisionedCapacityError::from_response(response))
         }
     }
@@ -4211,6 +4235,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListTagsForVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4220,13 +4245,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForVaultOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForVaultError::from_response(response))
         }
     }
@@ -4240,6 +4265,7 @@ impl Glacier for GlacierClient {
         let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4258,13 +4284,13 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVaultsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVaultsError::from_response(response))
         }
     }
@@ -4282,6 +4308,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("PurchaseProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4291,14 +4318,14 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PurchaseProvisionedCapacityOutput, _>()?;
             result.capacity_id = response.headers.remove("x-amz-capacity-id");
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PurchaseProvisionedCapacityError::from_response(response))
         }
     }
@@ -4316,6 +4343,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("RemoveTagsFromVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4332,12 +4360,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveTagsFromVaultError::from_response(response))
         }
     }
@@ -4354,6 +4382,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4366,12 +4395,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SetDataRetrievalPolicyError::from_response(response))
         }
     }
@@ -4389,6 +4418,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4401,12 +4431,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SetVaultAccessPolicyError::from_response(response))
         }
     }
@@ -4424,6 +4454,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4436,12 +4467,12 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SetVaultNotificationsError::from_response(response))
         }
     }
@@ -4459,6 +4490,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4480,7 +4512,7 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ArchiveCreationOutput, _>()?;
             result.archive_id = response.headers.remove("x-amz-archive-id");
@@ -4489,7 +4521,7 @@ impl Glacier for GlacierClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UploadArchiveError::from_response(response))
         }
     }
@@ -4508,6 +4540,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadMultipartPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4526,14 +4559,14 @@ impl Glacier for GlacierClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UploadMultipartPartOutput, _>()?;
             result.checksum = response.headers.remove("x-amz-sha256-tree-hash");
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UploadMultipartPartError::from_response(response))
         }
     }
@@ -42,7 +42,7 @@ impl GlobalAcceleratorClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2387,7 +2387,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, AdvertiseByoipCidrError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AdvertiseByoipCidrResponse, _>()
     }
 
@@ -2408,7 +2408,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, CreateAcceleratorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAcceleratorResponse, _>()
     }
 
@@ -2429,7 +2429,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, CreateEndpointGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointGroupResponse, _>()
     }
 
@@ -2447,7 +2447,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, CreateListenerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateListenerResponse, _>()
     }
 
@@ -2525,7 +2525,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, DeprovisionByoipCidrError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeprovisionByoipCidrResponse, _>()
     }
@@ -2547,7 +2547,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, DescribeAcceleratorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAcceleratorResponse, _>()
     }
 
@@ -2571,7 +2571,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, DescribeAcceleratorAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAcceleratorAttributesResponse, _>()
     }
@@ -2593,7 +2593,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, DescribeEndpointGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEndpointGroupResponse, _>()
     }
@@ -2615,7 +2615,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, DescribeListenerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeListenerResponse, _>()
     }
 
@@ -2636,7 +2636,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ListAcceleratorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAcceleratorsResponse, _>()
     }
 
@@ -2654,7 +2654,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ListByoipCidrsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListByoipCidrsResponse, _>()
     }
 
@@ -2675,7 +2675,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ListEndpointGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointGroupsResponse, _>()
     }
 
@@ -2693,7 +2693,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ListListenersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListListenersResponse, _>()
     }
 
@@ -2714,7 +2714,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2735,7 +2735,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, ProvisionByoipCidrError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ProvisionByoipCidrResponse, _>()
     }
 
@@ -2753,7 +2753,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2771,7 +2771,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2792,7 +2792,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, UpdateAcceleratorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAcceleratorResponse, _>()
     }
 
@@ -2814,7 +2814,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, UpdateAcceleratorAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateAcceleratorAttributesResponse, _>()
     }
@@ -2836,7 +2836,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, UpdateEndpointGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointGroupResponse, _>()
     }
 
@@ -2854,7 +2854,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, UpdateListenerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateListenerResponse, _>()
     }
 
@@ -2875,7 +2875,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
             .sign_and_dispatch(request, WithdrawByoipCidrError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WithdrawByoipCidrResponse, _>()
     }
 }
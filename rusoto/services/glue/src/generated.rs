@@ -41,7 +41,7 @@ impl GlueClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -14490,7 +14490,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchCreatePartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchCreatePartitionResponse, _>()
     }
@@ -14509,7 +14509,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchDeleteConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDeleteConnectionResponse, _>()
     }
@@ -14528,7 +14528,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchDeletePartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDeletePartitionResponse, _>()
     }
@@ -14547,7 +14547,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchDeleteTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDeleteTableResponse, _>()
     }
 
@@ -14565,7 +14565,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchDeleteTableVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDeleteTableVersionResponse, _>()
     }
@@ -14584,7 +14584,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetCrawlersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetCrawlersResponse, _>()
     }
 
@@ -14602,7 +14602,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetDevEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGetDevEndpointsResponse, _>()
     }
@@ -14621,7 +14621,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetJobsResponse, _>()
     }
 
@@ -14639,7 +14639,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetPartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetPartitionResponse, _>()
     }
 
@@ -14657,7 +14657,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetTriggersResponse, _>()
     }
 
@@ -14675,7 +14675,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, BatchGetWorkflowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchGetWorkflowsResponse, _>()
     }
 
@@ -14693,7 +14693,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GlueBatchStopJobRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchStopJobRunResponse, _>()
     }
 
@@ -14711,7 +14711,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CancelMLTaskRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelMLTaskRunResponse, _>()
     }
 
@@ -14729,7 +14729,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateClassifierResponse, _>()
     }
 
@@ -14747,7 +14747,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateConnectionResponse, _>()
     }
 
@@ -14765,7 +14765,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCrawlerResponse, _>()
     }
 
@@ -14783,7 +14783,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDatabaseResponse, _>()
     }
 
@@ -14801,7 +14801,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateDevEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDevEndpointResponse, _>()
     }
 
@@ -14819,7 +14819,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResponse, _>()
     }
 
@@ -14837,7 +14837,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateMLTransformError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateMLTransformResponse, _>()
     }
 
@@ -14855,7 +14855,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreatePartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePartitionResponse, _>()
     }
 
@@ -14873,7 +14873,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateScriptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateScriptResponse, _>()
     }
 
@@ -14892,7 +14892,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSecurityConfigurationResponse, _>()
     }
@@ -14911,7 +14911,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTableResponse, _>()
     }
 
@@ -14929,7 +14929,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTriggerResponse, _>()
     }
 
@@ -14948,7 +14948,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateUserDefinedFunctionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateUserDefinedFunctionResponse, _>()
     }
@@ -14967,7 +14967,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, CreateWorkflowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkflowResponse, _>()
     }
 
@@ -14985,7 +14985,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteClassifierResponse, _>()
     }
 
@@ -15009,7 +15009,7 @@ impl Glue for GlueClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteColumnStatisticsForPartitionResponse, _>()
     }
@@ -15031,7 +15031,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteColumnStatisticsForTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteColumnStatisticsForTableResponse, _>()
     }
@@ -15050,7 +15050,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteConnectionResponse, _>()
     }
 
@@ -15068,7 +15068,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteCrawlerResponse, _>()
     }
 
@@ -15086,7 +15086,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDatabaseResponse, _>()
     }
 
@@ -15104,7 +15104,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteDevEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDevEndpointResponse, _>()
     }
 
@@ -15122,7 +15122,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteJobResponse, _>()
     }
 
@@ -15140,7 +15140,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteMLTransformError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteMLTransformResponse, _>()
     }
 
@@ -15158,7 +15158,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeletePartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeletePartitionResponse, _>()
     }
 
@@ -15176,7 +15176,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteResourcePolicyResponse, _>()
     }
@@ -15196,7 +15196,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSecurityConfigurationResponse, _>()
     }
@@ -15215,7 +15215,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTableResponse, _>()
     }
 
@@ -15233,7 +15233,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteTableVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTableVersionResponse, _>()
     }
 
@@ -15251,7 +15251,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTriggerResponse, _>()
     }
 
@@ -15270,7 +15270,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteUserDefinedFunctionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteUserDefinedFunctionResponse, _>()
     }
@@ -15289,7 +15289,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, DeleteWorkflowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkflowResponse, _>()
     }
 
@@ -15307,7 +15307,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetCatalogImportStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCatalogImportStatusResponse, _>()
     }
@@ -15326,7 +15326,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetClassifierResponse, _>()
     }
 
@@ -15344,7 +15344,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetClassifiersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetClassifiersResponse, _>()
     }
 
@@ -15365,7 +15365,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetColumnStatisticsForPartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetColumnStatisticsForPartitionResponse, _>()
     }
@@ -15385,7 +15385,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetColumnStatisticsForTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetColumnStatisticsForTableResponse, _>()
     }
@@ -15404,7 +15404,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionResponse, _>()
     }
 
@@ -15422,7 +15422,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetConnectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionsResponse, _>()
     }
 
@@ -15440,7 +15440,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCrawlerResponse, _>()
     }
 
@@ -15458,7 +15458,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetCrawlerMetricsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCrawlerMetricsResponse, _>()
     }
 
@@ -15476,7 +15476,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetCrawlersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCrawlersResponse, _>()
     }
 
@@ -15500,7 +15500,7 @@ impl Glue for GlueClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDataCatalogEncryptionSettingsResponse, _>()
     }
@@ -15519,7 +15519,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDatabaseResponse, _>()
     }
 
@@ -15537,7 +15537,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetDatabasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDatabasesResponse, _>()
     }
 
@@ -15555,7 +15555,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetDataflowGraphError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDataflowGraphResponse, _>()
     }
 
@@ -15573,7 +15573,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetDevEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDevEndpointResponse, _>()
     }
 
@@ -15591,7 +15591,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetDevEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDevEndpointsResponse, _>()
     }
 
@@ -15609,7 +15609,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()
     }
 
@@ -15627,7 +15627,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetJobBookmarkError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobBookmarkResponse, _>()
     }
 
@@ -15645,7 +15645,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetJobRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobRunResponse, _>()
     }
 
@@ -15663,7 +15663,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetJobRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobRunsResponse, _>()
     }
 
@@ -15681,7 +15681,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobsResponse, _>()
     }
 
@@ -15699,7 +15699,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetMLTaskRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMLTaskRunResponse, _>()
     }
 
@@ -15717,7 +15717,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetMLTaskRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMLTaskRunsResponse, _>()
     }
 
@@ -15735,7 +15735,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetMLTransformError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMLTransformResponse, _>()
     }
 
@@ -15753,7 +15753,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetMLTransformsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMLTransformsResponse, _>()
     }
 
@@ -15771,7 +15771,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetMappingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMappingResponse, _>()
     }
 
@@ -15789,7 +15789,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetPartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPartitionResponse, _>()
     }
 
@@ -15807,7 +15807,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetPartitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPartitionsResponse, _>()
     }
 
@@ -15825,7 +15825,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPlanResponse, _>()
     }
 
@@ -15843,7 +15843,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetResourcePoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourcePoliciesResponse, _>()
     }
 
@@ -15861,7 +15861,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourcePolicyResponse, _>()
     }
 
@@ -15879,7 +15879,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetSecurityConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSecurityConfigurationResponse, _>()
     }
@@ -15899,7 +15899,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetSecurityConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSecurityConfigurationsResponse, _>()
     }
@@ -15918,7 +15918,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTableResponse, _>()
     }
 
@@ -15936,7 +15936,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTableVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTableVersionResponse, _>()
     }
 
@@ -15954,7 +15954,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTableVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTableVersionsResponse, _>()
     }
 
@@ -15972,7 +15972,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTablesResponse, _>()
     }
 
@@ -15990,7 +15990,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
     }
 
@@ -16008,7 +16008,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTriggerResponse, _>()
     }
 
@@ -16026,7 +16026,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTriggersResponse, _>()
     }
 
@@ -16044,7 +16044,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetUserDefinedFunctionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetUserDefinedFunctionResponse, _>()
     }
@@ -16063,7 +16063,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetUserDefinedFunctionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetUserDefinedFunctionsResponse, _>()
     }
@@ -16082,7 +16082,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetWorkflowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowResponse, _>()
     }
 
@@ -16100,7 +16100,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetWorkflowRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowRunResponse, _>()
     }
 
@@ -16118,7 +16118,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetWorkflowRunPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetWorkflowRunPropertiesResponse, _>()
     }
@@ -16137,7 +16137,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, GetWorkflowRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowRunsResponse, _>()
     }
 
@@ -16155,7 +16155,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ImportCatalogToGlueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportCatalogToGlueResponse, _>()
     }
 
@@ -16173,7 +16173,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListCrawlersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCrawlersResponse, _>()
     }
 
@@ -16191,7 +16191,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListDevEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDevEndpointsResponse, _>()
     }
 
@@ -16209,7 +16209,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResponse, _>()
     }
 
@@ -16227,7 +16227,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListMLTransformsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListMLTransformsResponse, _>()
     }
 
@@ -16245,7 +16245,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListTriggersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTriggersResponse, _>()
     }
 
@@ -16263,7 +16263,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ListWorkflowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWorkflowsResponse, _>()
     }
 
@@ -16287,7 +16287,7 @@ impl Glue for GlueClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutDataCatalogEncryptionSettingsResponse, _>()
     }
@@ -16306,7 +16306,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, PutResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourcePolicyResponse, _>()
     }
 
@@ -16324,7 +16324,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, PutWorkflowRunPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutWorkflowRunPropertiesResponse, _>()
     }
@@ -16343,7 +16343,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, ResetJobBookmarkError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResetJobBookmarkResponse, _>()
     }
 
@@ -16361,7 +16361,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, SearchTablesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchTablesResponse, _>()
     }
 
@@ -16379,7 +16379,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartCrawlerResponse, _>()
     }
 
@@ -16397,7 +16397,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartCrawlerScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartCrawlerScheduleResponse, _>()
     }
@@ -16416,7 +16416,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartExportLabelsTaskRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartExportLabelsTaskRunResponse, _>()
     }
@@ -16435,7 +16435,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartImportLabelsTaskRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartImportLabelsTaskRunResponse, _>()
     }
@@ -16454,7 +16454,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartJobRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartJobRunResponse, _>()
     }
 
@@ -16472,7 +16472,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartMLEvaluationTaskRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartMLEvaluationTaskRunResponse, _>()
     }
@@ -16500,7 +16500,7 @@ impl Glue for GlueClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartMLLabelingSetGenerationTaskRunResponse, _>()
     }
@@ -16519,7 +16519,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartTriggerResponse, _>()
     }
 
@@ -16537,7 +16537,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StartWorkflowRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartWorkflowRunResponse, _>()
     }
 
@@ -16555,7 +16555,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StopCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopCrawlerResponse, _>()
     }
 
@@ -16573,7 +16573,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StopCrawlerScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopCrawlerScheduleResponse, _>()
     }
 
@@ -16591,7 +16591,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StopTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopTriggerResponse, _>()
     }
 
@@ -16609,7 +16609,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, StopWorkflowRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopWorkflowRunResponse, _>()
     }
 
@@ -16627,7 +16627,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -16645,7 +16645,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -16663,7 +16663,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateClassifierError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateClassifierResponse, _>()
     }
 
@@ -16687,7 +16687,7 @@ impl Glue for GlueClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateColumnStatisticsForPartitionResponse, _>()
     }
@@ -16709,7 +16709,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateColumnStatisticsForTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateColumnStatisticsForTableResponse, _>()
     }
@@ -16728,7 +16728,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateConnectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateConnectionResponse, _>()
     }
 
@@ -16746,7 +16746,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateCrawlerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCrawlerResponse, _>()
     }
 
@@ -16764,7 +16764,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateCrawlerScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateCrawlerScheduleResponse, _>()
     }
@@ -16783,7 +16783,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDatabaseResponse, _>()
     }
 
@@ -16801,7 +16801,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateDevEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDevEndpointResponse, _>()
     }
 
@@ -16819,7 +16819,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResponse, _>()
     }
 
@@ -16837,7 +16837,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateMLTransformError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateMLTransformResponse, _>()
     }
 
@@ -16855,7 +16855,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdatePartitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePartitionResponse, _>()
     }
 
@@ -16873,7 +16873,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateTableError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTableResponse, _>()
     }
 
@@ -16891,7 +16891,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateTriggerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTriggerResponse, _>()
     }
 
@@ -16910,7 +16910,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateUserDefinedFunctionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateUserDefinedFunctionResponse, _>()
     }
@@ -16929,7 +16929,7 @@ impl Glue for GlueClient {
             .sign_and_dispatch(request, UpdateWorkflowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkflowResponse, _>()
     }
 }
@@ -6990,6 +6990,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateRoleToGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7001,13 +7002,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateRoleToGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateRoleToGroupError::from_response(response))
         }
     }
@@ -7024,6 +7025,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateServiceRoleToAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7035,13 +7037,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateServiceRoleToAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateServiceRoleToAccountError::from_response(response))
         }
     }
@@ -7056,6 +7058,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7068,13 +7071,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConnectorDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConnectorDefinitionError::from_response(response))
         }
     }
@@ -7094,6 +7097,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7106,13 +7110,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConnectorDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConnectorDefinitionVersionError::from_response(
                 response,
             ))
@@ -7128,6 +7132,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7140,13 +7145,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCoreDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCoreDefinitionError::from_response(response))
         }
     }
@@ -7164,6 +7169,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7176,13 +7182,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCoreDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCoreDefinitionVersionError::from_response(response))
         }
     }
@@ -7199,6 +7205,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7211,13 +7218,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentError::from_response(response))
         }
     }
@@ -7231,6 +7238,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7243,13 +7251,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeviceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeviceDefinitionError::from_response(response))
         }
     }
@@ -7269,6 +7277,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7281,13 +7290,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeviceDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeviceDefinitionVersionError::from_response(response))
         }
     }
@@ -7301,6 +7310,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7313,13 +7323,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFunctionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFunctionDefinitionError::from_response(response))
         }
     }
@@ -7339,6 +7349,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7351,13 +7362,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFunctionDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFunctionDefinitionVersionError::from_response(
                 response,
             ))
@@ -7373,6 +7384,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7385,13 +7397,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupError::from_response(response))
         }
     }
@@ -7411,6 +7423,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("X-Amzn-Client-Token", input.amzn_client_token.as_ref());
@@ -7421,13 +7434,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupCertificateAuthorityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupCertificateAuthorityError::from_response(
                 response,
             ))
@@ -7446,6 +7459,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7458,13 +7472,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupVersionError::from_response(response))
         }
     }
@@ -7478,6 +7492,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7490,13 +7505,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateLoggerDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateLoggerDefinitionError::from_response(response))
         }
     }
@@ -7516,6 +7531,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7528,13 +7544,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateLoggerDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateLoggerDefinitionVersionError::from_response(response))
         }
     }
@@ -7548,6 +7564,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7560,13 +7577,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateResourceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateResourceDefinitionError::from_response(response))
         }
     }
@@ -7586,6 +7603,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7598,13 +7616,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateResourceDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateResourceDefinitionVersionError::from_response(
                 response,
             ))
@@ -7620,6 +7638,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/updates";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSoftwareUpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7632,13 +7651,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSoftwareUpdateJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSoftwareUpdateJobError::from_response(response))
         }
     }
@@ -7653,6 +7672,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7665,13 +7685,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSubscriptionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSubscriptionDefinitionError::from_response(response))
         }
     }
@@ -7691,6 +7711,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7703,13 +7724,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSubscriptionDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSubscriptionDefinitionVersionError::from_response(
                 response,
             ))
@@ -7729,6 +7750,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7737,13 +7759,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteConnectorDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConnectorDefinitionError::from_response(response))
         }
     }
@@ -7760,6 +7782,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7768,13 +7791,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteCoreDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteCoreDefinitionError::from_response(response))
         }
     }
@@ -7791,6 +7814,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7799,13 +7823,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDeviceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDeviceDefinitionError::from_response(response))
         }
     }
@@ -7822,6 +7846,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7830,13 +7855,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFunctionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFunctionDefinitionError::from_response(response))
         }
     }
@@ -7850,6 +7875,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7858,13 +7884,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGroupError::from_response(response))
         }
     }
@@ -7881,6 +7907,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7889,13 +7916,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteLoggerDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteLoggerDefinitionError::from_response(response))
         }
     }
@@ -7912,6 +7939,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7920,13 +7948,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteResourceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteResourceDefinitionError::from_response(response))
         }
     }
@@ -7944,6 +7972,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7952,13 +7981,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSubscriptionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSubscriptionDefinitionError::from_response(response))
         }
     }
@@ -7976,6 +8005,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateRoleFromGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7984,13 +8014,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateRoleFromGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateRoleFromGroupError::from_response(response))
         }
     }
@@ -8006,6 +8036,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateServiceRoleFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8014,13 +8045,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateServiceRoleFromAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateServiceRoleFromAccountError::from_response(
                 response,
             ))
@@ -8039,6 +8070,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetAssociatedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8047,13 +8079,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAssociatedRoleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAssociatedRoleError::from_response(response))
         }
     }
@@ -8070,6 +8102,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetBulkDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8078,13 +8111,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBulkDeploymentStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBulkDeploymentStatusError::from_response(response))
         }
     }
@@ -8101,6 +8134,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8109,13 +8143,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConnectivityInfoResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConnectivityInfoError::from_response(response))
         }
     }
@@ -8132,6 +8166,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8140,13 +8175,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConnectorDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConnectorDefinitionError::from_response(response))
         }
     }
@@ -8163,6 +8198,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8177,13 +8213,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConnectorDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConnectorDefinitionVersionError::from_response(response))
         }
     }
@@ -8200,6 +8236,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8208,13 +8245,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCoreDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCoreDefinitionError::from_response(response))
         }
     }
@@ -8228,6 +8265,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8236,13 +8274,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCoreDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCoreDefinitionVersionError::from_response(response))
         }
     }
@@ -8260,6 +8298,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8268,13 +8307,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeploymentStatusResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeploymentStatusError::from_response(response))
         }
     }
@@ -8291,6 +8330,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8299,13 +8339,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeviceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeviceDefinitionError::from_response(response))
         }
     }
@@ -8320,6 +8360,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8334,13 +8375,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeviceDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeviceDefinitionVersionError::from_response(response))
         }
     }
@@ -8357,6 +8398,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8365,13 +8407,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFunctionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionDefinitionError::from_response(response))
         }
     }
@@ -8386,6 +8428,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8400,13 +8443,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFunctionDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionDefinitionVersionError::from_response(response))
         }
     }
@@ -8420,6 +8463,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8428,13 +8472,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupError::from_response(response))
         }
     }
@@ -8453,6 +8497,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8461,13 +8506,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGroupCertificateAuthorityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupCertificateAuthorityError::from_response(response))
         }
     }
@@ -8487,6 +8532,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8495,13 +8541,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGroupCertificateConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupCertificateConfigurationError::from_response(
                 response,
             ))
@@ -8521,6 +8567,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8529,13 +8576,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGroupVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupVersionError::from_response(response))
         }
     }
@@ -8552,6 +8599,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8560,13 +8608,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLoggerDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLoggerDefinitionError::from_response(response))
         }
     }
@@ -8581,6 +8629,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8595,13 +8644,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLoggerDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLoggerDefinitionVersionError::from_response(response))
         }
     }
@@ -8618,6 +8667,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8626,13 +8676,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetResourceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourceDefinitionError::from_response(response))
         }
     }
@@ -8647,6 +8697,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8655,13 +8706,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetResourceDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourceDefinitionVersionError::from_response(response))
         }
     }
@@ -8674,6 +8725,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetServiceRoleForAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8682,13 +8734,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetServiceRoleForAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetServiceRoleForAccountError::from_response(response))
         }
     }
@@ -8706,6 +8758,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8714,13 +8767,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSubscriptionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSubscriptionDefinitionError::from_response(response))
         }
     }
@@ -8737,6 +8790,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8751,13 +8805,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSubscriptionDefinitionVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSubscriptionDefinitionVersionError::from_response(
                 response,
             ))
@@ -8779,6 +8833,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeploymentDetailedReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8796,13 +8851,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBulkDeploymentDetailedReportsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBulkDeploymentDetailedReportsError::from_response(
                 response,
             ))
@@ -8818,6 +8873,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8835,13 +8891,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBulkDeploymentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBulkDeploymentsError::from_response(response))
         }
     }
@@ -8861,6 +8917,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8878,13 +8935,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListConnectorDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListConnectorDefinitionVersionsError::from_response(
                 response,
             ))
@@ -8900,6 +8957,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8917,13 +8975,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListConnectorDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListConnectorDefinitionsError::from_response(response))
         }
     }
@@ -8941,6 +8999,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8958,13 +9017,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListCoreDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListCoreDefinitionVersionsError::from_response(response))
         }
     }
@@ -8978,6 +9037,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8995,13 +9055,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListCoreDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListCoreDefinitionsError::from_response(response))
         }
     }
@@ -9018,6 +9078,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9035,13 +9096,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeploymentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeploymentsError::from_response(response))
         }
     }
@@ -9059,6 +9120,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9076,13 +9138,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeviceDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeviceDefinitionVersionsError::from_response(response))
         }
     }
@@ -9096,6 +9158,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9113,13 +9176,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeviceDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeviceDefinitionsError::from_response(response))
         }
     }
@@ -9139,6 +9202,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9156,13 +9220,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFunctionDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFunctionDefinitionVersionsError::from_response(response))
         }
     }
@@ -9176,6 +9240,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9193,13 +9258,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFunctionDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFunctionDefinitionsError::from_response(response))
         }
     }
@@ -9219,6 +9284,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupCertificateAuthorities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9227,13 +9293,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupCertificateAuthoritiesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupCertificateAuthoritiesError::from_response(
                 response,
             ))
@@ -9252,6 +9318,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9269,13 +9336,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupVersionsError::from_response(response))
         }
     }
@@ -9289,6 +9356,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9306,13 +9374,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupsError::from_response(response))
         }
     }
@@ -9330,6 +9398,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9347,13 +9416,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListLoggerDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListLoggerDefinitionVersionsError::from_response(response))
         }
     }
@@ -9367,6 +9436,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9384,13 +9454,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListLoggerDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListLoggerDefinitionsError::from_response(response))
         }
     }
@@ -9410,6 +9480,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9427,13 +9498,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListResourceDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListResourceDefinitionVersionsError::from_response(response))
         }
     }
@@ -9447,6 +9518,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9464,13 +9536,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListResourceDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListResourceDefinitionsError::from_response(response))
         }
     }
@@ -9490,6 +9562,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9507,13 +9580,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSubscriptionDefinitionVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSubscriptionDefinitionVersionsError::from_response(
                 response,
             ))
@@ -9530,6 +9603,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9547,13 +9621,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSubscriptionDefinitionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSubscriptionDefinitionsError::from_response(response))
         }
     }
@@ -9567,6 +9641,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9575,13 +9650,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -9598,6 +9673,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("ResetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9610,13 +9686,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ResetDeploymentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ResetDeploymentsError::from_response(response))
         }
     }
@@ -9630,6 +9706,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("StartBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9642,13 +9719,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartBulkDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartBulkDeploymentError::from_response(response))
         }
     }
@@ -9665,6 +9742,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("StopBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9673,13 +9751,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopBulkDeploymentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopBulkDeploymentError::from_response(response))
         }
     }
@@ -9693,6 +9771,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9704,12 +9783,12 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -9723,6 +9802,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9737,12 +9817,12 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -9759,6 +9839,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9770,13 +9851,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateConnectivityInfoResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateConnectivityInfoError::from_response(response))
         }
     }
@@ -9794,6 +9875,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9805,13 +9887,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateConnectorDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateConnectorDefinitionError::from_response(response))
         }
     }
@@ -9828,6 +9910,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9839,13 +9922,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateCoreDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateCoreDefinitionError::from_response(response))
         }
     }
@@ -9862,6 +9945,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9873,13 +9957,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDeviceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeviceDefinitionError::from_response(response))
         }
     }
@@ -9896,6 +9980,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9907,13 +9992,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFunctionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFunctionDefinitionError::from_response(response))
         }
     }
@@ -9927,6 +10012,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9938,13 +10024,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGroupError::from_response(response))
         }
     }
@@ -9964,6 +10050,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9975,13 +10062,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGroupCertificateConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGroupCertificateConfigurationError::from_response(
                 response,
             ))
@@ -10000,6 +10087,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10011,13 +10099,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateLoggerDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateLoggerDefinitionError::from_response(response))
         }
     }
@@ -10034,6 +10122,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10045,13 +10134,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateResourceDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateResourceDefinitionError::from_response(response))
         }
     }
@@ -10069,6 +10158,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10080,13 +10170,13 @@ impl GreenGrass for GreenGrassClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateSubscriptionDefinitionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateSubscriptionDefinitionError::from_response(response))
         }
     }
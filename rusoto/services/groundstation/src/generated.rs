@@ -2451,6 +2451,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("CancelContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2459,13 +2460,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ContactIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelContactError::from_response(response))
         }
     }
@@ -2479,6 +2480,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2490,13 +2492,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConfigError::from_response(response))
         }
     }
@@ -2511,6 +2513,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2522,13 +2525,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDataflowEndpointGroupError::from_response(response))
         }
     }
@@ -2542,6 +2545,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2553,13 +2557,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<MissionProfileIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMissionProfileError::from_response(response))
         }
     }
@@ -2577,6 +2581,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2585,13 +2590,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConfigError::from_response(response))
         }
     }
@@ -2609,6 +2614,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2617,13 +2623,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDataflowEndpointGroupError::from_response(response))
         }
     }
@@ -2640,6 +2646,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2648,13 +2655,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<MissionProfileIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMissionProfileError::from_response(response))
         }
     }
@@ -2668,6 +2675,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("DescribeContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2676,13 +2684,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeContactResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeContactError::from_response(response))
         }
     }
@@ -2700,6 +2708,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2708,13 +2717,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConfigError::from_response(response))
         }
     }
@@ -2731,6 +2740,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2739,13 +2749,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataflowEndpointGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDataflowEndpointGroupError::from_response(response))
         }
     }
@@ -2759,6 +2769,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/minute-usage";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMinuteUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2770,13 +2781,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMinuteUsageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMinuteUsageError::from_response(response))
         }
     }
@@ -2793,6 +2804,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2801,13 +2813,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMissionProfileResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMissionProfileError::from_response(response))
         }
     }
@@ -2824,6 +2836,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetSatellite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2832,13 +2845,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSatelliteResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSatelliteError::from_response(response))
         }
     }
@@ -2852,6 +2865,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2869,13 +2883,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListConfigsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListConfigsError::from_response(response))
         }
     }
@@ -2889,6 +2903,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contacts";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListContacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2900,13 +2915,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListContactsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListContactsError::from_response(response))
         }
     }
@@ -2921,6 +2936,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListDataflowEndpointGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2938,13 +2954,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataflowEndpointGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataflowEndpointGroupsError::from_response(response))
         }
     }
@@ -2958,6 +2974,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/groundstation";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListGroundStations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2978,13 +2995,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroundStationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroundStationsError::from_response(response))
         }
     }
@@ -2998,6 +3015,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListMissionProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3015,13 +3033,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMissionProfilesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMissionProfilesError::from_response(response))
         }
     }
@@ -3035,6 +3053,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/satellite";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListSatellites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3052,13 +3071,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSatellitesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSatellitesError::from_response(response))
         }
     }
@@ -3072,6 +3091,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3080,13 +3100,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3100,6 +3120,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contact";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ReserveContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3111,13 +3132,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ContactIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ReserveContactError::from_response(response))
         }
     }
@@ -3131,6 +3152,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3142,13 +3164,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3162,6 +3184,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3176,13 +3199,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3200,6 +3223,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3211,13 +3235,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ConfigIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateConfigError::from_response(response))
         }
     }
@@ -3234,6 +3258,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3245,13 +3270,13 @@ impl GroundStation for GroundStationClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<MissionProfileIdResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMissionProfileError::from_response(response))
         }
     }
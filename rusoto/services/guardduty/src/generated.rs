@@ -4754,6 +4754,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4765,13 +4766,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AcceptInvitationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AcceptInvitationError::from_response(response))
         }
     }
@@ -4788,6 +4789,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ArchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4799,13 +4801,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ArchiveFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ArchiveFindingsError::from_response(response))
         }
     }
@@ -4819,6 +4821,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4830,13 +4833,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDetectorError::from_response(response))
         }
     }
@@ -4853,6 +4856,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4864,13 +4868,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFilterResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFilterError::from_response(response))
         }
     }
@@ -4887,6 +4891,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4898,13 +4903,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIPSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIPSetError::from_response(response))
         }
     }
@@ -4921,6 +4926,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4932,13 +4938,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMembersError::from_response(response))
         }
     }
@@ -4956,6 +4962,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4967,13 +4974,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePublishingDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePublishingDestinationError::from_response(response))
         }
     }
@@ -4990,6 +4997,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateSampleFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5001,13 +5009,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSampleFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSampleFindingsError::from_response(response))
         }
     }
@@ -5024,6 +5032,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5035,13 +5044,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateThreatIntelSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateThreatIntelSetError::from_response(response))
         }
     }
@@ -5055,6 +5064,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/decline";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5066,13 +5076,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeclineInvitationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeclineInvitationsError::from_response(response))
         }
     }
@@ -5086,6 +5096,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5094,13 +5105,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDetectorError::from_response(response))
         }
     }
@@ -5118,6 +5129,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5126,13 +5138,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFilterResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFilterError::from_response(response))
         }
     }
@@ -5150,6 +5162,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5158,13 +5171,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteIPSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIPSetError::from_response(response))
         }
     }
@@ -5178,6 +5191,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/delete";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5189,13 +5203,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInvitationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInvitationsError::from_response(response))
         }
     }
@@ -5212,6 +5226,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5223,13 +5238,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMembersError::from_response(response))
         }
     }
@@ -5248,6 +5263,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeletePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5256,13 +5272,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePublishingDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePublishingDestinationError::from_response(response))
         }
     }
@@ -5280,6 +5296,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5288,13 +5305,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteThreatIntelSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteThreatIntelSetError::from_response(response))
         }
     }
@@ -5314,6 +5331,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribeOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5322,13 +5340,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeOrganizationConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeOrganizationConfigurationError::from_response(
                 response,
             ))
@@ -5351,6 +5369,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5359,13 +5378,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribePublishingDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribePublishingDestinationError::from_response(response))
         }
     }
@@ -5382,6 +5401,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/disable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5393,13 +5413,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisableOrganizationAdminAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisableOrganizationAdminAccountError::from_response(
                 response,
             ))
@@ -5421,6 +5441,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5429,13 +5450,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateFromMasterAccountError::from_response(response))
         }
     }
@@ -5452,6 +5473,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5463,13 +5485,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateMembersError::from_response(response))
         }
     }
@@ -5486,6 +5508,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/enable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("EnableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5497,13 +5520,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EnableOrganizationAdminAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(EnableOrganizationAdminAccountError::from_response(response))
         }
     }
@@ -5517,6 +5540,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5525,13 +5549,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDetectorError::from_response(response))
         }
     }
@@ -5549,6 +5573,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5557,13 +5582,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFilterResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFilterError::from_response(response))
         }
     }
@@ -5580,6 +5605,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5591,13 +5617,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFindingsError::from_response(response))
         }
     }
@@ -5614,6 +5640,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindingsStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5625,13 +5652,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFindingsStatisticsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFindingsStatisticsError::from_response(response))
         }
     }
@@ -5649,6 +5676,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5657,13 +5685,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIPSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIPSetError::from_response(response))
         }
     }
@@ -5676,6 +5704,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/count";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5684,13 +5713,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetInvitationsCountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetInvitationsCountError::from_response(response))
         }
     }
@@ -5707,6 +5736,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5715,13 +5745,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMasterAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMasterAccountError::from_response(response))
         }
     }
@@ -5738,6 +5768,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5749,13 +5780,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMembersError::from_response(response))
         }
     }
@@ -5773,6 +5804,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5781,13 +5813,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetThreatIntelSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetThreatIntelSetError::from_response(response))
         }
     }
@@ -5804,6 +5836,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5815,13 +5848,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InviteMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InviteMembersError::from_response(response))
         }
     }
@@ -5835,6 +5868,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5852,13 +5886,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDetectorsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDetectorsError::from_response(response))
         }
     }
@@ -5875,6 +5909,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFilters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5892,13 +5927,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFiltersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFiltersError::from_response(response))
         }
     }
@@ -5915,6 +5950,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5926,13 +5962,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFindingsError::from_response(response))
         }
     }
@@ -5949,6 +5985,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListIPSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5966,13 +6003,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListIPSetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListIPSetsError::from_response(response))
         }
     }
@@ -5986,6 +6023,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6003,13 +6041,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInvitationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInvitationsError::from_response(response))
         }
     }
@@ -6026,6 +6064,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6046,13 +6085,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMembersError::from_response(response))
         }
     }
@@ -6069,6 +6108,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListOrganizationAdminAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6086,13 +6126,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListOrganizationAdminAccountsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListOrganizationAdminAccountsError::from_response(response))
         }
     }
@@ -6110,6 +6150,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListPublishingDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6127,13 +6168,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPublishingDestinationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPublishingDestinationsError::from_response(response))
         }
     }
@@ -6147,6 +6188,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6155,13 +6197,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -6178,6 +6220,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListThreatIntelSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6195,13 +6238,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListThreatIntelSetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListThreatIntelSetsError::from_response(response))
         }
     }
@@ -6218,6 +6261,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6229,13 +6273,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartMonitoringMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartMonitoringMembersError::from_response(response))
         }
     }
@@ -6252,6 +6296,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StopMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6263,13 +6308,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopMonitoringMembersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopMonitoringMembersError::from_response(response))
         }
     }
@@ -6283,6 +6328,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6294,13 +6340,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -6317,6 +6363,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UnarchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6328,13 +6375,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UnarchiveFindingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UnarchiveFindingsError::from_response(response))
         }
     }
@@ -6348,6 +6395,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6362,13 +6410,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -6382,6 +6430,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6393,13 +6442,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDetectorError::from_response(response))
         }
     }
@@ -6417,6 +6466,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6428,13 +6478,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFilterResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFilterError::from_response(response))
         }
     }
@@ -6451,6 +6501,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFindingsFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6462,13 +6513,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFindingsFeedbackResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFindingsFeedbackError::from_response(response))
         }
     }
@@ -6486,6 +6537,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6497,13 +6549,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateIPSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIPSetError::from_response(response))
         }
     }
@@ -6523,6 +6575,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6534,13 +6587,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateOrganizationConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateOrganizationConfigurationError::from_response(
                 response,
             ))
@@ -6561,6 +6614,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6572,13 +6626,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePublishingDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePublishingDestinationError::from_response(response))
         }
     }
@@ -6596,6 +6650,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6607,13 +6662,13 @@ impl GuardDuty for GuardDutyClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateThreatIntelSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateThreatIntelSetError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl AWSHealthClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1435,7 +1435,7 @@ impl AWSHealth for AWSHealthClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAffectedAccountsForOrganizationResponse, _>()
     }
@@ -1457,7 +1457,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeAffectedEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAffectedEntitiesResponse, _>()
     }
@@ -1485,7 +1485,7 @@ impl AWSHealth for AWSHealthClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAffectedEntitiesForOrganizationResponse, _>()
     }
@@ -1507,7 +1507,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEntityAggregatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEntityAggregatesResponse, _>()
     }
@@ -1526,7 +1526,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEventAggregatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventAggregatesResponse, _>()
     }
@@ -1545,7 +1545,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEventDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventDetailsResponse, _>()
     }
@@ -1573,7 +1573,7 @@ impl AWSHealth for AWSHealthClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventDetailsForOrganizationResponse, _>()
     }
@@ -1592,7 +1592,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEventTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventTypesResponse, _>()
     }
 
@@ -1610,7 +1610,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
     }
 
@@ -1634,7 +1634,7 @@ impl AWSHealth for AWSHealthClient {
             .sign_and_dispatch(request, DescribeEventsForOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventsForOrganizationResponse, _>()
     }
@@ -1660,7 +1660,7 @@ impl AWSHealth for AWSHealthClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeHealthServiceStatusForOrganizationResponse, _>()
     }
@@ -52,7 +52,7 @@ impl IamClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -11350,13 +11350,13 @@ impl VirtualMFADeviceListTypeDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddClientIDToOpenIDConnectProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl AddClientIDToOpenIDConnectProviderError {
@@ -11372,28 +11372,28 @@ impl AddClientIDToOpenIDConnectProviderError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             AddClientIDToOpenIDConnectProviderError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             AddClientIDToOpenIDConnectProviderError::LimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             AddClientIDToOpenIDConnectProviderError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             AddClientIDToOpenIDConnectProviderError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11411,6 +11411,18 @@ impl AddClientIDToOpenIDConnectProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddClientIDToOpenIDConnectProviderError::InvalidInput(ref cause) => cause.request_id(),
+            AddClientIDToOpenIDConnectProviderError::LimitExceeded(ref cause) => cause.request_id(),
+            AddClientIDToOpenIDConnectProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            AddClientIDToOpenIDConnectProviderError::ServiceFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AddClientIDToOpenIDConnectProviderError {
     #[allow(unused_variables)]
@@ -11436,15 +11448,15 @@ impl Error for AddClientIDToOpenIDConnectProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToInstanceProfileError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl AddRoleToInstanceProfileError {
@@ -11458,28 +11470,30 @@ impl AddRoleToInstanceProfileError {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
                             AddRoleToInstanceProfileError::EntityAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(AddRoleToInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(AddRoleToInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(AddRoleToInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
-                            AddRoleToInstanceProfileError::UnmodifiableEntity(parsed_error.message),
+                            AddRoleToInstanceProfileError::UnmodifiableEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -11496,6 +11510,17 @@ impl AddRoleToInstanceProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddRoleToInstanceProfileError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            AddRoleToInstanceProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            AddRoleToInstanceProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            AddRoleToInstanceProfileError::ServiceFailure(ref cause) => cause.request_id(),
+            AddRoleToInstanceProfileError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddRoleToInstanceProfileError {
     #[allow(unused_variables)]
@@ -11514,11 +11539,11 @@ impl Error for AddRoleToInstanceProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum AddUserToGroupError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl AddUserToGroupError {
@@ -11531,17 +11556,17 @@ impl AddUserToGroupError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(AddUserToGroupError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(AddUserToGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(AddUserToGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11558,6 +11583,15 @@ impl AddUserToGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddUserToGroupError::LimitExceeded(ref cause) => cause.request_id(),
+            AddUserToGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            AddUserToGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddUserToGroupError {
     #[allow(unused_variables)]
@@ -11574,15 +11608,15 @@ impl Error for AddUserToGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachGroupPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because AWS service role policies can only be attached to the service-linked role for that service.</p>
-    PolicyNotAttachable(String),
+    PolicyNotAttachable(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl AttachGroupPolicyError {
@@ -11595,27 +11629,27 @@ impl AttachGroupPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(AttachGroupPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(AttachGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(AttachGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyNotAttachable" => {
                         return RusotoError::Service(AttachGroupPolicyError::PolicyNotAttachable(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(AttachGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11632,6 +11666,17 @@ impl AttachGroupPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachGroupPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            AttachGroupPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            AttachGroupPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            AttachGroupPolicyError::PolicyNotAttachable(ref cause) => cause.request_id(),
+            AttachGroupPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachGroupPolicyError {
     #[allow(unused_variables)]
@@ -11650,17 +11695,17 @@ impl Error for AttachGroupPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachRolePolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because AWS service role policies can only be attached to the service-linked role for that service.</p>
-    PolicyNotAttachable(String),
+    PolicyNotAttachable(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl AttachRolePolicyError {
@@ -11673,32 +11718,32 @@ impl AttachRolePolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(AttachRolePolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(AttachRolePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(AttachRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyNotAttachable" => {
                         return RusotoError::Service(AttachRolePolicyError::PolicyNotAttachable(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(AttachRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(AttachRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11715,6 +11760,18 @@ impl AttachRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachRolePolicyError::InvalidInput(ref cause) => cause.request_id(),
+            AttachRolePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            AttachRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            AttachRolePolicyError::PolicyNotAttachable(ref cause) => cause.request_id(),
+            AttachRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+            AttachRolePolicyError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachRolePolicyError {
     #[allow(unused_variables)]
@@ -11734,15 +11791,15 @@ impl Error for AttachRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum AttachUserPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because AWS service role policies can only be attached to the service-linked role for that service.</p>
-    PolicyNotAttachable(String),
+    PolicyNotAttachable(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl AttachUserPolicyError {
@@ -11755,27 +11812,27 @@ impl AttachUserPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(AttachUserPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(AttachUserPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(AttachUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyNotAttachable" => {
                         return RusotoError::Service(AttachUserPolicyError::PolicyNotAttachable(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(AttachUserPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11792,6 +11849,17 @@ impl AttachUserPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AttachUserPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            AttachUserPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            AttachUserPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            AttachUserPolicyError::PolicyNotAttachable(ref cause) => cause.request_id(),
+            AttachUserPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AttachUserPolicyError {
     #[allow(unused_variables)]
@@ -11810,17 +11878,17 @@ impl Error for AttachUserPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ChangePasswordError {
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because the type of user for the transaction was incorrect.</p>
-    InvalidUserType(String),
+    InvalidUserType(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request was rejected because the provided password did not meet the requirements imposed by the account password policy.</p>
-    PasswordPolicyViolation(String),
+    PasswordPolicyViolation(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ChangePasswordError {
@@ -11834,33 +11902,33 @@ impl ChangePasswordError {
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
                             ChangePasswordError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidUserType" => {
                         return RusotoError::Service(ChangePasswordError::InvalidUserType(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(ChangePasswordError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ChangePasswordError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PasswordPolicyViolation" => {
                         return RusotoError::Service(ChangePasswordError::PasswordPolicyViolation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ChangePasswordError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11877,6 +11945,18 @@ impl ChangePasswordError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ChangePasswordError::EntityTemporarilyUnmodifiable(ref cause) => cause.request_id(),
+            ChangePasswordError::InvalidUserType(ref cause) => cause.request_id(),
+            ChangePasswordError::LimitExceeded(ref cause) => cause.request_id(),
+            ChangePasswordError::NoSuchEntity(ref cause) => cause.request_id(),
+            ChangePasswordError::PasswordPolicyViolation(ref cause) => cause.request_id(),
+            ChangePasswordError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ChangePasswordError {
     #[allow(unused_variables)]
@@ -11896,11 +11976,11 @@ impl Error for ChangePasswordError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateAccessKeyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateAccessKeyError {
@@ -11913,17 +11993,17 @@ impl CreateAccessKeyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateAccessKeyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreateAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateAccessKeyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11940,6 +12020,15 @@ impl CreateAccessKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateAccessKeyError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateAccessKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateAccessKeyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateAccessKeyError {
     #[allow(unused_variables)]
@@ -11956,11 +12045,11 @@ impl Error for CreateAccessKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateAccountAliasError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateAccountAliasError {
@@ -11973,17 +12062,17 @@ impl CreateAccountAliasError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateAccountAliasError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateAccountAliasError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateAccountAliasError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12000,6 +12089,15 @@ impl CreateAccountAliasError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateAccountAliasError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateAccountAliasError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateAccountAliasError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateAccountAliasError {
     #[allow(unused_variables)]
@@ -12016,13 +12114,13 @@ impl Error for CreateAccountAliasError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateGroupError {
@@ -12035,22 +12133,22 @@ impl CreateGroupError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateGroupError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateGroupError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreateGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12067,6 +12165,16 @@ impl CreateGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateGroupError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateGroupError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateGroupError {
     #[allow(unused_variables)]
@@ -12084,11 +12192,11 @@ impl Error for CreateGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceProfileError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateInstanceProfileError {
@@ -12101,17 +12209,19 @@ impl CreateInstanceProfileError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
-                            CreateInstanceProfileError::EntityAlreadyExists(parsed_error.message),
+                            CreateInstanceProfileError::EntityAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12128,6 +12238,15 @@ impl CreateInstanceProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateInstanceProfileError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateInstanceProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateInstanceProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateInstanceProfileError {
     #[allow(unused_variables)]
@@ -12144,15 +12263,15 @@ impl Error for CreateInstanceProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateLoginProfileError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request was rejected because the provided password did not meet the requirements imposed by the account password policy.</p>
-    PasswordPolicyViolation(String),
+    PasswordPolicyViolation(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateLoginProfileError {
@@ -12165,27 +12284,29 @@ impl CreateLoginProfileError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateLoginProfileError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateLoginProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreateLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PasswordPolicyViolation" => {
                         return RusotoError::Service(
-                            CreateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
+                            CreateLoginProfileError::PasswordPolicyViolation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateLoginProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12202,6 +12323,17 @@ impl CreateLoginProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateLoginProfileError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateLoginProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateLoginProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateLoginProfileError::PasswordPolicyViolation(ref cause) => cause.request_id(),
+            CreateLoginProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateLoginProfileError {
     #[allow(unused_variables)]
@@ -12220,13 +12352,13 @@ impl Error for CreateLoginProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateOpenIDConnectProviderError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateOpenIDConnectProviderError {
@@ -12242,23 +12374,29 @@ impl CreateOpenIDConnectProviderError {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
                             CreateOpenIDConnectProviderError::EntityAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::InvalidInput(parsed_error.message),
+                            CreateOpenIDConnectProviderError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::LimitExceeded(parsed_error.message),
+                            CreateOpenIDConnectProviderError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
+                            CreateOpenIDConnectProviderError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12275,6 +12413,16 @@ impl CreateOpenIDConnectProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateOpenIDConnectProviderError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateOpenIDConnectProviderError::InvalidInput(ref cause) => cause.request_id(),
+            CreateOpenIDConnectProviderError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateOpenIDConnectProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateOpenIDConnectProviderError {
     #[allow(unused_variables)]
@@ -12294,15 +12442,15 @@ impl Error for CreateOpenIDConnectProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreatePolicyError {
@@ -12315,27 +12463,27 @@ impl CreatePolicyError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreatePolicyError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreatePolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreatePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreatePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12352,6 +12500,17 @@ impl CreatePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePolicyError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreatePolicyError::InvalidInput(ref cause) => cause.request_id(),
+            CreatePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            CreatePolicyError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            CreatePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePolicyError {
     #[allow(unused_variables)]
@@ -12370,15 +12529,15 @@ impl Error for CreatePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyVersionError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreatePolicyVersionError {
@@ -12391,27 +12550,29 @@ impl CreatePolicyVersionError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(CreatePolicyVersionError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreatePolicyVersionError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
-                            CreatePolicyVersionError::MalformedPolicyDocument(parsed_error.message),
+                            CreatePolicyVersionError::MalformedPolicyDocument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreatePolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreatePolicyVersionError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12428,6 +12589,17 @@ impl CreatePolicyVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePolicyVersionError::InvalidInput(ref cause) => cause.request_id(),
+            CreatePolicyVersionError::LimitExceeded(ref cause) => cause.request_id(),
+            CreatePolicyVersionError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            CreatePolicyVersionError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreatePolicyVersionError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePolicyVersionError {
     #[allow(unused_variables)]
@@ -12446,17 +12618,17 @@ impl Error for CreatePolicyVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateRoleError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateRoleError {
@@ -12469,32 +12641,32 @@ impl CreateRoleError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(CreateRoleError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateRoleError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateRoleError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateRoleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(CreateRoleError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12511,6 +12683,18 @@ impl CreateRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateRoleError::ConcurrentModification(ref cause) => cause.request_id(),
+            CreateRoleError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateRoleError::InvalidInput(ref cause) => cause.request_id(),
+            CreateRoleError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateRoleError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            CreateRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateRoleError {
     #[allow(unused_variables)]
@@ -12530,13 +12714,13 @@ impl Error for CreateRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateSAMLProviderError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateSAMLProviderError {
@@ -12549,22 +12733,22 @@ impl CreateSAMLProviderError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateSAMLProviderError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateSAMLProviderError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12581,6 +12765,16 @@ impl CreateSAMLProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateSAMLProviderError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateSAMLProviderError::InvalidInput(ref cause) => cause.request_id(),
+            CreateSAMLProviderError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateSAMLProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateSAMLProviderError {
     #[allow(unused_variables)]
@@ -12598,13 +12792,13 @@ impl Error for CreateSAMLProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceLinkedRoleError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateServiceLinkedRoleError {
@@ -12617,22 +12811,22 @@ impl CreateServiceLinkedRoleError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(CreateServiceLinkedRoleError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateServiceLinkedRoleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreateServiceLinkedRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateServiceLinkedRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12649,6 +12843,16 @@ impl CreateServiceLinkedRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateServiceLinkedRoleError::InvalidInput(ref cause) => cause.request_id(),
+            CreateServiceLinkedRoleError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateServiceLinkedRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateServiceLinkedRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateServiceLinkedRoleError {
     #[allow(unused_variables)]
@@ -12666,11 +12870,11 @@ impl Error for CreateServiceLinkedRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceSpecificCredentialError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The specified service does not support service-specific credentials.</p>
-    ServiceNotSupported(String),
+    ServiceNotSupported(XmlErrorMessage),
 }
 
 impl CreateServiceSpecificCredentialError {
@@ -12686,21 +12890,21 @@ impl CreateServiceSpecificCredentialError {
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             CreateServiceSpecificCredentialError::LimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             CreateServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NotSupportedService" => {
                         return RusotoError::Service(
                             CreateServiceSpecificCredentialError::ServiceNotSupported(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12718,6 +12922,17 @@ impl CreateServiceSpecificCredentialError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateServiceSpecificCredentialError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateServiceSpecificCredentialError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateServiceSpecificCredentialError::ServiceNotSupported(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateServiceSpecificCredentialError {
     #[allow(unused_variables)]
@@ -12738,17 +12953,17 @@ impl Error for CreateServiceSpecificCredentialError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateUserError {
@@ -12761,32 +12976,32 @@ impl CreateUserError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(CreateUserError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(CreateUserError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateUserError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateUserError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(CreateUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12803,6 +13018,18 @@ impl CreateUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateUserError::ConcurrentModification(ref cause) => cause.request_id(),
+            CreateUserError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateUserError::InvalidInput(ref cause) => cause.request_id(),
+            CreateUserError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            CreateUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateUserError {
     #[allow(unused_variables)]
@@ -12822,11 +13049,11 @@ impl Error for CreateUserError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualMFADeviceError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl CreateVirtualMFADeviceError {
@@ -12839,17 +13066,19 @@ impl CreateVirtualMFADeviceError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
-                            CreateVirtualMFADeviceError::EntityAlreadyExists(parsed_error.message),
+                            CreateVirtualMFADeviceError::EntityAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateVirtualMFADeviceError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(CreateVirtualMFADeviceError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12866,6 +13095,15 @@ impl CreateVirtualMFADeviceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateVirtualMFADeviceError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            CreateVirtualMFADeviceError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateVirtualMFADeviceError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateVirtualMFADeviceError {
     #[allow(unused_variables)]
@@ -12882,13 +13120,13 @@ impl Error for CreateVirtualMFADeviceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeactivateMFADeviceError {
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeactivateMFADeviceError {
@@ -12902,23 +13140,23 @@ impl DeactivateMFADeviceError {
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
                             DeactivateMFADeviceError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeactivateMFADeviceError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeactivateMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeactivateMFADeviceError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12935,6 +13173,18 @@ impl DeactivateMFADeviceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeactivateMFADeviceError::EntityTemporarilyUnmodifiable(ref cause) => {
+                cause.request_id()
+            }
+            DeactivateMFADeviceError::LimitExceeded(ref cause) => cause.request_id(),
+            DeactivateMFADeviceError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeactivateMFADeviceError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeactivateMFADeviceError {
     #[allow(unused_variables)]
@@ -12954,11 +13204,11 @@ impl Error for DeactivateMFADeviceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccessKeyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteAccessKeyError {
@@ -12971,17 +13221,17 @@ impl DeleteAccessKeyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteAccessKeyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteAccessKeyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12998,6 +13248,15 @@ impl DeleteAccessKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAccessKeyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteAccessKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteAccessKeyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAccessKeyError {
     #[allow(unused_variables)]
@@ -13014,11 +13273,11 @@ impl Error for DeleteAccessKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountAliasError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteAccountAliasError {
@@ -13031,17 +13290,17 @@ impl DeleteAccountAliasError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteAccountAliasError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteAccountAliasError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteAccountAliasError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13058,6 +13317,15 @@ impl DeleteAccountAliasError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAccountAliasError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteAccountAliasError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteAccountAliasError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAccountAliasError {
     #[allow(unused_variables)]
@@ -13074,11 +13342,11 @@ impl Error for DeleteAccountAliasError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountPasswordPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteAccountPasswordPolicyError {
@@ -13093,17 +13361,23 @@ impl DeleteAccountPasswordPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
+                            DeleteAccountPasswordPolicyError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
+                            DeleteAccountPasswordPolicyError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
+                            DeleteAccountPasswordPolicyError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -13120,6 +13394,15 @@ impl DeleteAccountPasswordPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAccountPasswordPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteAccountPasswordPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteAccountPasswordPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAccountPasswordPolicyError {
     #[allow(unused_variables)]
@@ -13136,13 +13419,13 @@ impl Error for DeleteAccountPasswordPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteGroupError {
@@ -13155,22 +13438,22 @@ impl DeleteGroupError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteGroupError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteGroupError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13187,6 +13470,16 @@ impl DeleteGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteGroupError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteGroupError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteGroupError {
     #[allow(unused_variables)]
@@ -13204,11 +13497,11 @@ impl Error for DeleteGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteGroupPolicyError {
@@ -13221,17 +13514,17 @@ impl DeleteGroupPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13248,6 +13541,15 @@ impl DeleteGroupPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteGroupPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteGroupPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteGroupPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteGroupPolicyError {
     #[allow(unused_variables)]
@@ -13264,13 +13566,13 @@ impl Error for DeleteGroupPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceProfileError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteInstanceProfileError {
@@ -13283,22 +13585,22 @@ impl DeleteInstanceProfileError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteInstanceProfileError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13315,6 +13617,16 @@ impl DeleteInstanceProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteInstanceProfileError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteInstanceProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteInstanceProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteInstanceProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteInstanceProfileError {
     #[allow(unused_variables)]
@@ -13332,13 +13644,13 @@ impl Error for DeleteInstanceProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoginProfileError {
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteLoginProfileError {
@@ -13352,23 +13664,23 @@ impl DeleteLoginProfileError {
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
                             DeleteLoginProfileError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteLoginProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteLoginProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13385,6 +13697,16 @@ impl DeleteLoginProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteLoginProfileError::EntityTemporarilyUnmodifiable(ref cause) => cause.request_id(),
+            DeleteLoginProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteLoginProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteLoginProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteLoginProfileError {
     #[allow(unused_variables)]
@@ -13404,11 +13726,11 @@ impl Error for DeleteLoginProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteOpenIDConnectProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteOpenIDConnectProviderError {
@@ -13423,17 +13745,23 @@ impl DeleteOpenIDConnectProviderError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::InvalidInput(parsed_error.message),
+                            DeleteOpenIDConnectProviderError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::NoSuchEntity(parsed_error.message),
+                            DeleteOpenIDConnectProviderError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
+                            DeleteOpenIDConnectProviderError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -13450,6 +13778,15 @@ impl DeleteOpenIDConnectProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteOpenIDConnectProviderError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteOpenIDConnectProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteOpenIDConnectProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteOpenIDConnectProviderError {
     #[allow(unused_variables)]
@@ -13466,15 +13803,15 @@ impl Error for DeleteOpenIDConnectProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeletePolicyError {
@@ -13487,27 +13824,27 @@ impl DeletePolicyError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeletePolicyError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeletePolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeletePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeletePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeletePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13524,6 +13861,17 @@ impl DeletePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePolicyError::DeleteConflict(ref cause) => cause.request_id(),
+            DeletePolicyError::InvalidInput(ref cause) => cause.request_id(),
+            DeletePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeletePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeletePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeletePolicyError {
     #[allow(unused_variables)]
@@ -13542,15 +13890,15 @@ impl Error for DeletePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyVersionError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeletePolicyVersionError {
@@ -13563,27 +13911,27 @@ impl DeletePolicyVersionError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeletePolicyVersionError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeletePolicyVersionError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeletePolicyVersionError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeletePolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeletePolicyVersionError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13600,6 +13948,17 @@ impl DeletePolicyVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePolicyVersionError::DeleteConflict(ref cause) => cause.request_id(),
+            DeletePolicyVersionError::InvalidInput(ref cause) => cause.request_id(),
+            DeletePolicyVersionError::LimitExceeded(ref cause) => cause.request_id(),
+            DeletePolicyVersionError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeletePolicyVersionError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeletePolicyVersionError {
     #[allow(unused_variables)]
@@ -13618,17 +13977,17 @@ impl Error for DeletePolicyVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoleError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl DeleteRoleError {
@@ -13641,32 +14000,32 @@ impl DeleteRoleError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(DeleteRoleError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteRoleError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteRoleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(DeleteRoleError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13683,6 +14042,18 @@ impl DeleteRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteRoleError::ConcurrentModification(ref cause) => cause.request_id(),
+            DeleteRoleError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteRoleError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteRoleError::ServiceFailure(ref cause) => cause.request_id(),
+            DeleteRoleError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteRoleError {
     #[allow(unused_variables)]
@@ -13702,11 +14073,11 @@ impl Error for DeleteRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteRolePermissionsBoundaryError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl DeleteRolePermissionsBoundaryError {
@@ -13721,20 +14092,22 @@ impl DeleteRolePermissionsBoundaryError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            DeleteRolePermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                            DeleteRolePermissionsBoundaryError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             DeleteRolePermissionsBoundaryError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
                             DeleteRolePermissionsBoundaryError::UnmodifiableEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13752,6 +14125,15 @@ impl DeleteRolePermissionsBoundaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteRolePermissionsBoundaryError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteRolePermissionsBoundaryError::ServiceFailure(ref cause) => cause.request_id(),
+            DeleteRolePermissionsBoundaryError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteRolePermissionsBoundaryError {
     #[allow(unused_variables)]
@@ -13770,13 +14152,13 @@ impl Error for DeleteRolePermissionsBoundaryError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteRolePolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl DeleteRolePolicyError {
@@ -13789,22 +14171,22 @@ impl DeleteRolePolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteRolePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(DeleteRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13821,6 +14203,16 @@ impl DeleteRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteRolePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+            DeleteRolePolicyError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteRolePolicyError {
     #[allow(unused_variables)]
@@ -13838,13 +14230,13 @@ impl Error for DeleteRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSAMLProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteSAMLProviderError {
@@ -13857,22 +14249,22 @@ impl DeleteSAMLProviderError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(DeleteSAMLProviderError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13889,6 +14281,16 @@ impl DeleteSAMLProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSAMLProviderError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteSAMLProviderError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteSAMLProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteSAMLProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteSAMLProviderError {
     #[allow(unused_variables)]
@@ -13906,7 +14308,7 @@ impl Error for DeleteSAMLProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSSHPublicKeyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl DeleteSSHPublicKeyError {
@@ -13919,7 +14321,7 @@ impl DeleteSSHPublicKeyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13936,6 +14338,13 @@ impl DeleteSSHPublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSSHPublicKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteSSHPublicKeyError {
     #[allow(unused_variables)]
@@ -13950,13 +14359,13 @@ impl Error for DeleteSSHPublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteServerCertificateError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteServerCertificateError {
@@ -13969,22 +14378,22 @@ impl DeleteServerCertificateError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteServerCertificateError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteServerCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteServerCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14001,6 +14410,16 @@ impl DeleteServerCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteServerCertificateError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteServerCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteServerCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteServerCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteServerCertificateError {
     #[allow(unused_variables)]
@@ -14018,11 +14437,11 @@ impl Error for DeleteServerCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceLinkedRoleError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteServiceLinkedRoleError {
@@ -14035,17 +14454,17 @@ impl DeleteServiceLinkedRoleError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteServiceLinkedRoleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteServiceLinkedRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteServiceLinkedRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14062,6 +14481,15 @@ impl DeleteServiceLinkedRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteServiceLinkedRoleError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteServiceLinkedRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteServiceLinkedRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteServiceLinkedRoleError {
     #[allow(unused_variables)]
@@ -14078,7 +14506,7 @@ impl Error for DeleteServiceLinkedRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceSpecificCredentialError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl DeleteServiceSpecificCredentialError {
@@ -14094,7 +14522,7 @@ impl DeleteServiceSpecificCredentialError {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             DeleteServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14112,6 +14540,13 @@ impl DeleteServiceSpecificCredentialError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteServiceSpecificCredentialError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteServiceSpecificCredentialError {
     #[allow(unused_variables)]
@@ -14126,11 +14561,11 @@ impl Error for DeleteServiceSpecificCredentialError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSigningCertificateError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteSigningCertificateError {
@@ -14143,17 +14578,17 @@ impl DeleteSigningCertificateError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14170,6 +14605,15 @@ impl DeleteSigningCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSigningCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteSigningCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteSigningCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteSigningCertificateError {
     #[allow(unused_variables)]
@@ -14186,15 +14630,15 @@ impl Error for DeleteSigningCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteUserError {
@@ -14207,27 +14651,27 @@ impl DeleteUserError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(DeleteUserError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteUserError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteUserError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14244,6 +14688,17 @@ impl DeleteUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteUserError::ConcurrentModification(ref cause) => cause.request_id(),
+            DeleteUserError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteUserError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteUserError {
     #[allow(unused_variables)]
@@ -14262,9 +14717,9 @@ impl Error for DeleteUserError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPermissionsBoundaryError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteUserPermissionsBoundaryError {
@@ -14279,13 +14734,15 @@ impl DeleteUserPermissionsBoundaryError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            DeleteUserPermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                            DeleteUserPermissionsBoundaryError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             DeleteUserPermissionsBoundaryError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14303,6 +14760,14 @@ impl DeleteUserPermissionsBoundaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteUserPermissionsBoundaryError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteUserPermissionsBoundaryError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteUserPermissionsBoundaryError {
     #[allow(unused_variables)]
@@ -14318,11 +14783,11 @@ impl Error for DeleteUserPermissionsBoundaryError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteUserPolicyError {
@@ -14335,17 +14800,17 @@ impl DeleteUserPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteUserPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteUserPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14362,6 +14827,15 @@ impl DeleteUserPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteUserPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteUserPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteUserPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteUserPolicyError {
     #[allow(unused_variables)]
@@ -14378,13 +14852,13 @@ impl Error for DeleteUserPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualMFADeviceError {
     /// <p>The request was rejected because it attempted to delete a resource that has attached subordinate entities. The error message describes these entities.</p>
-    DeleteConflict(String),
+    DeleteConflict(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DeleteVirtualMFADeviceError {
@@ -14397,22 +14871,22 @@ impl DeleteVirtualMFADeviceError {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
                         return RusotoError::Service(DeleteVirtualMFADeviceError::DeleteConflict(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DeleteVirtualMFADeviceError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DeleteVirtualMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DeleteVirtualMFADeviceError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14429,6 +14903,16 @@ impl DeleteVirtualMFADeviceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteVirtualMFADeviceError::DeleteConflict(ref cause) => cause.request_id(),
+            DeleteVirtualMFADeviceError::LimitExceeded(ref cause) => cause.request_id(),
+            DeleteVirtualMFADeviceError::NoSuchEntity(ref cause) => cause.request_id(),
+            DeleteVirtualMFADeviceError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteVirtualMFADeviceError {
     #[allow(unused_variables)]
@@ -14446,13 +14930,13 @@ impl Error for DeleteVirtualMFADeviceError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachGroupPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DetachGroupPolicyError {
@@ -14465,22 +14949,22 @@ impl DetachGroupPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(DetachGroupPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DetachGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DetachGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DetachGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14497,6 +14981,16 @@ impl DetachGroupPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachGroupPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            DetachGroupPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DetachGroupPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DetachGroupPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetachGroupPolicyError {
     #[allow(unused_variables)]
@@ -14514,15 +15008,15 @@ impl Error for DetachGroupPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachRolePolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl DetachRolePolicyError {
@@ -14535,27 +15029,27 @@ impl DetachRolePolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(DetachRolePolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DetachRolePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DetachRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DetachRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(DetachRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14572,6 +15066,17 @@ impl DetachRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachRolePolicyError::InvalidInput(ref cause) => cause.request_id(),
+            DetachRolePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DetachRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DetachRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+            DetachRolePolicyError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetachRolePolicyError {
     #[allow(unused_variables)]
@@ -14590,13 +15095,13 @@ impl Error for DetachRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DetachUserPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl DetachUserPolicyError {
@@ -14609,22 +15114,22 @@ impl DetachUserPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(DetachUserPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(DetachUserPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(DetachUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(DetachUserPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14641,6 +15146,16 @@ impl DetachUserPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DetachUserPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            DetachUserPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            DetachUserPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            DetachUserPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DetachUserPolicyError {
     #[allow(unused_variables)]
@@ -14658,17 +15173,17 @@ impl Error for DetachUserPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableMFADeviceError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because the authentication code was not recognized. The error message describes the specific error.</p>
-    InvalidAuthenticationCode(String),
+    InvalidAuthenticationCode(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl EnableMFADeviceError {
@@ -14681,34 +15196,36 @@ impl EnableMFADeviceError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(EnableMFADeviceError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
                             EnableMFADeviceError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidAuthenticationCode" => {
                         return RusotoError::Service(
-                            EnableMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
+                            EnableMFADeviceError::InvalidAuthenticationCode(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(EnableMFADeviceError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(EnableMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(EnableMFADeviceError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14725,6 +15242,18 @@ impl EnableMFADeviceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableMFADeviceError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            EnableMFADeviceError::EntityTemporarilyUnmodifiable(ref cause) => cause.request_id(),
+            EnableMFADeviceError::InvalidAuthenticationCode(ref cause) => cause.request_id(),
+            EnableMFADeviceError::LimitExceeded(ref cause) => cause.request_id(),
+            EnableMFADeviceError::NoSuchEntity(ref cause) => cause.request_id(),
+            EnableMFADeviceError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for EnableMFADeviceError {
     #[allow(unused_variables)]
@@ -14746,9 +15275,9 @@ impl Error for EnableMFADeviceError {}
 #[derive(Debug, PartialEq)]
 pub enum GenerateCredentialReportError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GenerateCredentialReportError {
@@ -14761,12 +15290,12 @@ impl GenerateCredentialReportError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(GenerateCredentialReportError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GenerateCredentialReportError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14783,6 +15312,14 @@ impl GenerateCredentialReportError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GenerateCredentialReportError::LimitExceeded(ref cause) => cause.request_id(),
+            GenerateCredentialReportError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GenerateCredentialReportError {
     #[allow(unused_variables)]
@@ -14798,7 +15335,7 @@ impl Error for GenerateCredentialReportError {}
 #[derive(Debug, PartialEq)]
 pub enum GenerateOrganizationsAccessReportError {
     /// <p>The request failed because the maximum number of concurrent requests for this account are already running.</p>
-    ReportGenerationLimitExceeded(String),
+    ReportGenerationLimitExceeded(XmlErrorMessage),
 }
 
 impl GenerateOrganizationsAccessReportError {
@@ -14814,7 +15351,7 @@ impl GenerateOrganizationsAccessReportError {
                     "ReportGenerationLimitExceeded" => {
                         return RusotoError::Service(
                             GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14832,6 +15369,15 @@ impl GenerateOrganizationsAccessReportError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GenerateOrganizationsAccessReportError {
     #[allow(unused_variables)]
@@ -14848,9 +15394,9 @@ impl Error for GenerateOrganizationsAccessReportError {}
 #[derive(Debug, PartialEq)]
 pub enum GenerateServiceLastAccessedDetailsError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl GenerateServiceLastAccessedDetailsError {
@@ -14866,14 +15412,14 @@ impl GenerateServiceLastAccessedDetailsError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             GenerateServiceLastAccessedDetailsError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             GenerateServiceLastAccessedDetailsError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14891,6 +15437,14 @@ impl GenerateServiceLastAccessedDetailsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GenerateServiceLastAccessedDetailsError::InvalidInput(ref cause) => cause.request_id(),
+            GenerateServiceLastAccessedDetailsError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GenerateServiceLastAccessedDetailsError {
     #[allow(unused_variables)]
@@ -14932,6 +15486,11 @@ impl GetAccessKeyLastUsedError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetAccessKeyLastUsedError {
     #[allow(unused_variables)]
@@ -14944,7 +15503,7 @@ impl Error for GetAccessKeyLastUsedError {}
 #[derive(Debug, PartialEq)]
 pub enum GetAccountAuthorizationDetailsError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetAccountAuthorizationDetailsError {
@@ -14960,7 +15519,7 @@ impl GetAccountAuthorizationDetailsError {
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             GetAccountAuthorizationDetailsError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14978,6 +15537,13 @@ impl GetAccountAuthorizationDetailsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetAccountAuthorizationDetailsError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetAccountAuthorizationDetailsError {
     #[allow(unused_variables)]
@@ -14994,9 +15560,9 @@ impl Error for GetAccountAuthorizationDetailsError {}
 #[derive(Debug, PartialEq)]
 pub enum GetAccountPasswordPolicyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetAccountPasswordPolicyError {
@@ -15009,12 +15575,12 @@ impl GetAccountPasswordPolicyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetAccountPasswordPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetAccountPasswordPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15031,6 +15597,14 @@ impl GetAccountPasswordPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetAccountPasswordPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetAccountPasswordPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetAccountPasswordPolicyError {
     #[allow(unused_variables)]
@@ -15046,7 +15620,7 @@ impl Error for GetAccountPasswordPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSummaryError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetAccountSummaryError {
@@ -15059,7 +15633,7 @@ impl GetAccountSummaryError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(GetAccountSummaryError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15076,6 +15650,13 @@ impl GetAccountSummaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetAccountSummaryError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetAccountSummaryError {
     #[allow(unused_variables)]
@@ -15090,7 +15671,7 @@ impl Error for GetAccountSummaryError {}
 #[derive(Debug, PartialEq)]
 pub enum GetContextKeysForCustomPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl GetContextKeysForCustomPolicyError {
@@ -15105,7 +15686,9 @@ impl GetContextKeysForCustomPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            GetContextKeysForCustomPolicyError::InvalidInput(parsed_error.message),
+                            GetContextKeysForCustomPolicyError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -15122,6 +15705,13 @@ impl GetContextKeysForCustomPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetContextKeysForCustomPolicyError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetContextKeysForCustomPolicyError {
     #[allow(unused_variables)]
@@ -15136,9 +15726,9 @@ impl Error for GetContextKeysForCustomPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetContextKeysForPrincipalPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl GetContextKeysForPrincipalPolicyError {
@@ -15154,14 +15744,14 @@ impl GetContextKeysForPrincipalPolicyError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             GetContextKeysForPrincipalPolicyError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             GetContextKeysForPrincipalPolicyError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15179,9 +15769,17 @@ impl GetContextKeysForPrincipalPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
-}
-impl fmt::Display for GetContextKeysForPrincipalPolicyError {
-    #[allow(unused_variables)]
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetContextKeysForPrincipalPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            GetContextKeysForPrincipalPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
+}
+impl fmt::Display for GetContextKeysForPrincipalPolicyError {
+    #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetContextKeysForPrincipalPolicyError::InvalidInput(ref cause) => {
@@ -15198,13 +15796,13 @@ impl Error for GetContextKeysForPrincipalPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetCredentialReportError {
     /// <p>The request was rejected because the most recent credential report has expired. To generate a new credential report, use <a>GenerateCredentialReport</a>. For more information about credential report expiration, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/credential-reports.html">Getting Credential Reports</a> in the <i>IAM User Guide</i>.</p>
-    CredentialReportExpired(String),
+    CredentialReportExpired(XmlErrorMessage),
     /// <p>The request was rejected because the credential report does not exist. To generate a credential report, use <a>GenerateCredentialReport</a>.</p>
-    CredentialReportNotPresent(String),
+    CredentialReportNotPresent(XmlErrorMessage),
     /// <p>The request was rejected because the credential report is still being generated.</p>
-    CredentialReportNotReady(String),
+    CredentialReportNotReady(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetCredentialReportError {
@@ -15217,26 +15815,28 @@ impl GetCredentialReportError {
                 match &parsed_error.code[..] {
                     "ReportExpired" => {
                         return RusotoError::Service(
-                            GetCredentialReportError::CredentialReportExpired(parsed_error.message),
+                            GetCredentialReportError::CredentialReportExpired(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ReportNotPresent" => {
                         return RusotoError::Service(
                             GetCredentialReportError::CredentialReportNotPresent(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReportInProgress" => {
                         return RusotoError::Service(
                             GetCredentialReportError::CredentialReportNotReady(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetCredentialReportError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15253,6 +15853,16 @@ impl GetCredentialReportError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetCredentialReportError::CredentialReportExpired(ref cause) => cause.request_id(),
+            GetCredentialReportError::CredentialReportNotPresent(ref cause) => cause.request_id(),
+            GetCredentialReportError::CredentialReportNotReady(ref cause) => cause.request_id(),
+            GetCredentialReportError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetCredentialReportError {
     #[allow(unused_variables)]
@@ -15272,9 +15882,9 @@ impl Error for GetCredentialReportError {}
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetGroupError {
@@ -15287,12 +15897,12 @@ impl GetGroupError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15309,6 +15919,14 @@ impl GetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetGroupError {
     #[allow(unused_variables)]
@@ -15324,9 +15942,9 @@ impl Error for GetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum GetGroupPolicyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetGroupPolicyError {
@@ -15339,12 +15957,12 @@ impl GetGroupPolicyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15361,6 +15979,14 @@ impl GetGroupPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetGroupPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetGroupPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetGroupPolicyError {
     #[allow(unused_variables)]
@@ -15376,9 +16002,9 @@ impl Error for GetGroupPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceProfileError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetInstanceProfileError {
@@ -15391,12 +16017,12 @@ impl GetInstanceProfileError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15413,6 +16039,14 @@ impl GetInstanceProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetInstanceProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetInstanceProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetInstanceProfileError {
     #[allow(unused_variables)]
@@ -15428,9 +16062,9 @@ impl Error for GetInstanceProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum GetLoginProfileError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetLoginProfileError {
@@ -15443,12 +16077,12 @@ impl GetLoginProfileError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetLoginProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15465,6 +16099,14 @@ impl GetLoginProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetLoginProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetLoginProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetLoginProfileError {
     #[allow(unused_variables)]
@@ -15480,11 +16122,11 @@ impl Error for GetLoginProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum GetOpenIDConnectProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetOpenIDConnectProviderError {
@@ -15497,17 +16139,17 @@ impl GetOpenIDConnectProviderError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetOpenIDConnectProviderError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetOpenIDConnectProviderError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetOpenIDConnectProviderError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15524,6 +16166,15 @@ impl GetOpenIDConnectProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetOpenIDConnectProviderError::InvalidInput(ref cause) => cause.request_id(),
+            GetOpenIDConnectProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetOpenIDConnectProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetOpenIDConnectProviderError {
     #[allow(unused_variables)]
@@ -15540,7 +16191,7 @@ impl Error for GetOpenIDConnectProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum GetOrganizationsAccessReportError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl GetOrganizationsAccessReportError {
@@ -15555,7 +16206,9 @@ impl GetOrganizationsAccessReportError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            GetOrganizationsAccessReportError::NoSuchEntity(parsed_error.message),
+                            GetOrganizationsAccessReportError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -15572,6 +16225,13 @@ impl GetOrganizationsAccessReportError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetOrganizationsAccessReportError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetOrganizationsAccessReportError {
     #[allow(unused_variables)]
@@ -15586,11 +16246,11 @@ impl Error for GetOrganizationsAccessReportError {}
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetPolicyError {
@@ -15603,17 +16263,17 @@ impl GetPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15630,6 +16290,15 @@ impl GetPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            GetPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetPolicyError {
     #[allow(unused_variables)]
@@ -15646,11 +16315,11 @@ impl Error for GetPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyVersionError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetPolicyVersionError {
@@ -15663,17 +16332,17 @@ impl GetPolicyVersionError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetPolicyVersionError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetPolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetPolicyVersionError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15690,6 +16359,15 @@ impl GetPolicyVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetPolicyVersionError::InvalidInput(ref cause) => cause.request_id(),
+            GetPolicyVersionError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetPolicyVersionError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetPolicyVersionError {
     #[allow(unused_variables)]
@@ -15706,9 +16384,9 @@ impl Error for GetPolicyVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum GetRoleError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetRoleError {
@@ -15721,12 +16399,12 @@ impl GetRoleError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15743,6 +16421,14 @@ impl GetRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetRoleError {
     #[allow(unused_variables)]
@@ -15758,9 +16444,9 @@ impl Error for GetRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum GetRolePolicyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetRolePolicyError {
@@ -15773,12 +16459,12 @@ impl GetRolePolicyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15795,6 +16481,14 @@ impl GetRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetRolePolicyError {
     #[allow(unused_variables)]
@@ -15810,11 +16504,11 @@ impl Error for GetRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetSAMLProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetSAMLProviderError {
@@ -15827,17 +16521,17 @@ impl GetSAMLProviderError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetSAMLProviderError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15854,6 +16548,15 @@ impl GetSAMLProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetSAMLProviderError::InvalidInput(ref cause) => cause.request_id(),
+            GetSAMLProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetSAMLProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetSAMLProviderError {
     #[allow(unused_variables)]
@@ -15870,9 +16573,9 @@ impl Error for GetSAMLProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum GetSSHPublicKeyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request was rejected because the public key encoding format is unsupported or unrecognized.</p>
-    UnrecognizedPublicKeyEncoding(String),
+    UnrecognizedPublicKeyEncoding(XmlErrorMessage),
 }
 
 impl GetSSHPublicKeyError {
@@ -15885,13 +16588,13 @@ impl GetSSHPublicKeyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnrecognizedPublicKeyEncoding" => {
                         return RusotoError::Service(
                             GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15909,6 +16612,14 @@ impl GetSSHPublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetSSHPublicKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetSSHPublicKeyError {
     #[allow(unused_variables)]
@@ -15926,9 +16637,9 @@ impl Error for GetSSHPublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetServerCertificateError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetServerCertificateError {
@@ -15941,12 +16652,12 @@ impl GetServerCertificateError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetServerCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15963,6 +16674,14 @@ impl GetServerCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetServerCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetServerCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetServerCertificateError {
     #[allow(unused_variables)]
@@ -15978,9 +16697,9 @@ impl Error for GetServerCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLastAccessedDetailsError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl GetServiceLastAccessedDetailsError {
@@ -15995,12 +16714,16 @@ impl GetServiceLastAccessedDetailsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            GetServiceLastAccessedDetailsError::InvalidInput(parsed_error.message),
+                            GetServiceLastAccessedDetailsError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            GetServiceLastAccessedDetailsError::NoSuchEntity(parsed_error.message),
+                            GetServiceLastAccessedDetailsError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -16017,6 +16740,14 @@ impl GetServiceLastAccessedDetailsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetServiceLastAccessedDetailsError::InvalidInput(ref cause) => cause.request_id(),
+            GetServiceLastAccessedDetailsError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetServiceLastAccessedDetailsError {
     #[allow(unused_variables)]
@@ -16032,9 +16763,9 @@ impl Error for GetServiceLastAccessedDetailsError {}
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLastAccessedDetailsWithEntitiesError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl GetServiceLastAccessedDetailsWithEntitiesError {
@@ -16050,14 +16781,14 @@ impl GetServiceLastAccessedDetailsWithEntitiesError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16075,6 +16806,18 @@ impl GetServiceLastAccessedDetailsWithEntitiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput(ref cause) => {
+                cause.request_id()
+            }
+            GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetServiceLastAccessedDetailsWithEntitiesError {
     #[allow(unused_variables)]
@@ -16094,11 +16837,11 @@ impl Error for GetServiceLastAccessedDetailsWithEntitiesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLinkedRoleDeletionStatusError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetServiceLinkedRoleDeletionStatusError {
@@ -16114,21 +16857,21 @@ impl GetServiceLinkedRoleDeletionStatusError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             GetServiceLinkedRoleDeletionStatusError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             GetServiceLinkedRoleDeletionStatusError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             GetServiceLinkedRoleDeletionStatusError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16146,6 +16889,17 @@ impl GetServiceLinkedRoleDeletionStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetServiceLinkedRoleDeletionStatusError::InvalidInput(ref cause) => cause.request_id(),
+            GetServiceLinkedRoleDeletionStatusError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetServiceLinkedRoleDeletionStatusError::ServiceFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetServiceLinkedRoleDeletionStatusError {
     #[allow(unused_variables)]
@@ -16168,9 +16922,9 @@ impl Error for GetServiceLinkedRoleDeletionStatusError {}
 #[derive(Debug, PartialEq)]
 pub enum GetUserError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetUserError {
@@ -16183,12 +16937,12 @@ impl GetUserError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16205,6 +16959,14 @@ impl GetUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetUserError {
     #[allow(unused_variables)]
@@ -16220,9 +16982,9 @@ impl Error for GetUserError {}
 #[derive(Debug, PartialEq)]
 pub enum GetUserPolicyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl GetUserPolicyError {
@@ -16235,12 +16997,12 @@ impl GetUserPolicyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(GetUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(GetUserPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16257,6 +17019,14 @@ impl GetUserPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetUserPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            GetUserPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetUserPolicyError {
     #[allow(unused_variables)]
@@ -16272,9 +17042,9 @@ impl Error for GetUserPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAccessKeysError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListAccessKeysError {
@@ -16287,12 +17057,12 @@ impl ListAccessKeysError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListAccessKeysError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListAccessKeysError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16309,6 +17079,14 @@ impl ListAccessKeysError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAccessKeysError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListAccessKeysError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListAccessKeysError {
     #[allow(unused_variables)]
@@ -16324,7 +17102,7 @@ impl Error for ListAccessKeysError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAccountAliasesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListAccountAliasesError {
@@ -16337,7 +17115,7 @@ impl ListAccountAliasesError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListAccountAliasesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16354,6 +17132,13 @@ impl ListAccountAliasesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAccountAliasesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListAccountAliasesError {
     #[allow(unused_variables)]
@@ -16368,11 +17153,11 @@ impl Error for ListAccountAliasesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedGroupPoliciesError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListAttachedGroupPoliciesError {
@@ -16385,17 +17170,19 @@ impl ListAttachedGroupPoliciesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListAttachedGroupPoliciesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListAttachedGroupPoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            ListAttachedGroupPoliciesError::ServiceFailure(parsed_error.message),
+                            ListAttachedGroupPoliciesError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -16412,6 +17199,15 @@ impl ListAttachedGroupPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAttachedGroupPoliciesError::InvalidInput(ref cause) => cause.request_id(),
+            ListAttachedGroupPoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListAttachedGroupPoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListAttachedGroupPoliciesError {
     #[allow(unused_variables)]
@@ -16428,11 +17224,11 @@ impl Error for ListAttachedGroupPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedRolePoliciesError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListAttachedRolePoliciesError {
@@ -16445,17 +17241,17 @@ impl ListAttachedRolePoliciesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListAttachedRolePoliciesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListAttachedRolePoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListAttachedRolePoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16472,6 +17268,15 @@ impl ListAttachedRolePoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAttachedRolePoliciesError::InvalidInput(ref cause) => cause.request_id(),
+            ListAttachedRolePoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListAttachedRolePoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListAttachedRolePoliciesError {
     #[allow(unused_variables)]
@@ -16488,11 +17293,11 @@ impl Error for ListAttachedRolePoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedUserPoliciesError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListAttachedUserPoliciesError {
@@ -16505,17 +17310,17 @@ impl ListAttachedUserPoliciesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListAttachedUserPoliciesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListAttachedUserPoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListAttachedUserPoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16532,6 +17337,15 @@ impl ListAttachedUserPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListAttachedUserPoliciesError::InvalidInput(ref cause) => cause.request_id(),
+            ListAttachedUserPoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListAttachedUserPoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListAttachedUserPoliciesError {
     #[allow(unused_variables)]
@@ -16548,11 +17362,11 @@ impl Error for ListAttachedUserPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListEntitiesForPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListEntitiesForPolicyError {
@@ -16565,17 +17379,17 @@ impl ListEntitiesForPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListEntitiesForPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListEntitiesForPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListEntitiesForPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16592,6 +17406,15 @@ impl ListEntitiesForPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListEntitiesForPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            ListEntitiesForPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListEntitiesForPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListEntitiesForPolicyError {
     #[allow(unused_variables)]
@@ -16608,9 +17431,9 @@ impl Error for ListEntitiesForPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ListGroupPoliciesError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListGroupPoliciesError {
@@ -16623,12 +17446,12 @@ impl ListGroupPoliciesError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListGroupPoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListGroupPoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16645,6 +17468,14 @@ impl ListGroupPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListGroupPoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListGroupPoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListGroupPoliciesError {
     #[allow(unused_variables)]
@@ -16660,7 +17491,7 @@ impl Error for ListGroupPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListGroupsError {
@@ -16673,7 +17504,7 @@ impl ListGroupsError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListGroupsError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16690,6 +17521,13 @@ impl ListGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListGroupsError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListGroupsError {
     #[allow(unused_variables)]
@@ -16704,9 +17542,9 @@ impl Error for ListGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsForUserError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListGroupsForUserError {
@@ -16719,12 +17557,12 @@ impl ListGroupsForUserError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListGroupsForUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListGroupsForUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16741,6 +17579,14 @@ impl ListGroupsForUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListGroupsForUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListGroupsForUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListGroupsForUserError {
     #[allow(unused_variables)]
@@ -16756,7 +17602,7 @@ impl Error for ListGroupsForUserError {}
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceProfilesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListInstanceProfilesError {
@@ -16769,7 +17615,7 @@ impl ListInstanceProfilesError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListInstanceProfilesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16786,6 +17632,13 @@ impl ListInstanceProfilesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListInstanceProfilesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListInstanceProfilesError {
     #[allow(unused_variables)]
@@ -16800,9 +17653,9 @@ impl Error for ListInstanceProfilesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceProfilesForRoleError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListInstanceProfilesForRoleError {
@@ -16817,12 +17670,16 @@ impl ListInstanceProfilesForRoleError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            ListInstanceProfilesForRoleError::NoSuchEntity(parsed_error.message),
+                            ListInstanceProfilesForRoleError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            ListInstanceProfilesForRoleError::ServiceFailure(parsed_error.message),
+                            ListInstanceProfilesForRoleError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -16839,6 +17696,14 @@ impl ListInstanceProfilesForRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListInstanceProfilesForRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListInstanceProfilesForRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListInstanceProfilesForRoleError {
     #[allow(unused_variables)]
@@ -16854,9 +17719,9 @@ impl Error for ListInstanceProfilesForRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum ListMFADevicesError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListMFADevicesError {
@@ -16869,12 +17734,12 @@ impl ListMFADevicesError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListMFADevicesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListMFADevicesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16891,6 +17756,14 @@ impl ListMFADevicesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListMFADevicesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListMFADevicesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListMFADevicesError {
     #[allow(unused_variables)]
@@ -16906,7 +17779,7 @@ impl Error for ListMFADevicesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListOpenIDConnectProvidersError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListOpenIDConnectProvidersError {
@@ -16921,7 +17794,9 @@ impl ListOpenIDConnectProvidersError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            ListOpenIDConnectProvidersError::ServiceFailure(parsed_error.message),
+                            ListOpenIDConnectProvidersError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -16938,6 +17813,13 @@ impl ListOpenIDConnectProvidersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListOpenIDConnectProvidersError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListOpenIDConnectProvidersError {
     #[allow(unused_variables)]
@@ -16952,7 +17834,7 @@ impl Error for ListOpenIDConnectProvidersError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListPoliciesError {
@@ -16965,7 +17847,7 @@ impl ListPoliciesError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListPoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16982,6 +17864,13 @@ impl ListPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPoliciesError {
     #[allow(unused_variables)]
@@ -16996,9 +17885,9 @@ impl Error for ListPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesGrantingServiceAccessError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl ListPoliciesGrantingServiceAccessError {
@@ -17014,14 +17903,14 @@ impl ListPoliciesGrantingServiceAccessError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             ListPoliciesGrantingServiceAccessError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             ListPoliciesGrantingServiceAccessError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17039,6 +17928,14 @@ impl ListPoliciesGrantingServiceAccessError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPoliciesGrantingServiceAccessError::InvalidInput(ref cause) => cause.request_id(),
+            ListPoliciesGrantingServiceAccessError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPoliciesGrantingServiceAccessError {
     #[allow(unused_variables)]
@@ -17058,11 +17955,11 @@ impl Error for ListPoliciesGrantingServiceAccessError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPolicyVersionsError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListPolicyVersionsError {
@@ -17075,17 +17972,17 @@ impl ListPolicyVersionsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListPolicyVersionsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListPolicyVersionsError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListPolicyVersionsError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17102,6 +17999,15 @@ impl ListPolicyVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPolicyVersionsError::InvalidInput(ref cause) => cause.request_id(),
+            ListPolicyVersionsError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListPolicyVersionsError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPolicyVersionsError {
     #[allow(unused_variables)]
@@ -17118,9 +18024,9 @@ impl Error for ListPolicyVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListRolePoliciesError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListRolePoliciesError {
@@ -17133,12 +18039,12 @@ impl ListRolePoliciesError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListRolePoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListRolePoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17155,6 +18061,14 @@ impl ListRolePoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListRolePoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListRolePoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListRolePoliciesError {
     #[allow(unused_variables)]
@@ -17170,9 +18084,9 @@ impl Error for ListRolePoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListRoleTagsError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListRoleTagsError {
@@ -17185,12 +18099,12 @@ impl ListRoleTagsError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListRoleTagsError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListRoleTagsError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17207,6 +18121,14 @@ impl ListRoleTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListRoleTagsError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListRoleTagsError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListRoleTagsError {
     #[allow(unused_variables)]
@@ -17222,7 +18144,7 @@ impl Error for ListRoleTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListRolesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListRolesError {
@@ -17235,7 +18157,7 @@ impl ListRolesError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListRolesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17252,6 +18174,13 @@ impl ListRolesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListRolesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListRolesError {
     #[allow(unused_variables)]
@@ -17266,7 +18195,7 @@ impl Error for ListRolesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListSAMLProvidersError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListSAMLProvidersError {
@@ -17279,7 +18208,7 @@ impl ListSAMLProvidersError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListSAMLProvidersError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17296,6 +18225,13 @@ impl ListSAMLProvidersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListSAMLProvidersError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListSAMLProvidersError {
     #[allow(unused_variables)]
@@ -17310,7 +18246,7 @@ impl Error for ListSAMLProvidersError {}
 #[derive(Debug, PartialEq)]
 pub enum ListSSHPublicKeysError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl ListSSHPublicKeysError {
@@ -17323,7 +18259,7 @@ impl ListSSHPublicKeysError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListSSHPublicKeysError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17340,6 +18276,13 @@ impl ListSSHPublicKeysError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListSSHPublicKeysError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListSSHPublicKeysError {
     #[allow(unused_variables)]
@@ -17354,7 +18297,7 @@ impl Error for ListSSHPublicKeysError {}
 #[derive(Debug, PartialEq)]
 pub enum ListServerCertificatesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListServerCertificatesError {
@@ -17367,7 +18310,7 @@ impl ListServerCertificatesError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListServerCertificatesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17384,6 +18327,13 @@ impl ListServerCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListServerCertificatesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListServerCertificatesError {
     #[allow(unused_variables)]
@@ -17398,9 +18348,9 @@ impl Error for ListServerCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListServiceSpecificCredentialsError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The specified service does not support service-specific credentials.</p>
-    ServiceNotSupported(String),
+    ServiceNotSupported(XmlErrorMessage),
 }
 
 impl ListServiceSpecificCredentialsError {
@@ -17415,13 +18365,15 @@ impl ListServiceSpecificCredentialsError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            ListServiceSpecificCredentialsError::NoSuchEntity(parsed_error.message),
+                            ListServiceSpecificCredentialsError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NotSupportedService" => {
                         return RusotoError::Service(
                             ListServiceSpecificCredentialsError::ServiceNotSupported(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17439,6 +18391,16 @@ impl ListServiceSpecificCredentialsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListServiceSpecificCredentialsError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListServiceSpecificCredentialsError::ServiceNotSupported(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListServiceSpecificCredentialsError {
     #[allow(unused_variables)]
@@ -17456,9 +18418,9 @@ impl Error for ListServiceSpecificCredentialsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListSigningCertificatesError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListSigningCertificatesError {
@@ -17471,12 +18433,12 @@ impl ListSigningCertificatesError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListSigningCertificatesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListSigningCertificatesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17493,6 +18455,14 @@ impl ListSigningCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListSigningCertificatesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListSigningCertificatesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListSigningCertificatesError {
     #[allow(unused_variables)]
@@ -17508,9 +18478,9 @@ impl Error for ListSigningCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListUserPoliciesError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListUserPoliciesError {
@@ -17523,12 +18493,12 @@ impl ListUserPoliciesError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListUserPoliciesError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListUserPoliciesError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17545,6 +18515,14 @@ impl ListUserPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListUserPoliciesError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListUserPoliciesError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListUserPoliciesError {
     #[allow(unused_variables)]
@@ -17560,9 +18538,9 @@ impl Error for ListUserPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListUserTagsError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListUserTagsError {
@@ -17575,12 +18553,12 @@ impl ListUserTagsError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(ListUserTagsError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ListUserTagsError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17597,6 +18575,14 @@ impl ListUserTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListUserTagsError::NoSuchEntity(ref cause) => cause.request_id(),
+            ListUserTagsError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListUserTagsError {
     #[allow(unused_variables)]
@@ -17612,7 +18598,7 @@ impl Error for ListUserTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ListUsersError {
@@ -17625,7 +18611,7 @@ impl ListUsersError {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
                         return RusotoError::Service(ListUsersError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17642,6 +18628,13 @@ impl ListUsersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListUsersError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListUsersError {
     #[allow(unused_variables)]
@@ -17678,6 +18671,11 @@ impl ListVirtualMFADevicesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListVirtualMFADevicesError {
     #[allow(unused_variables)]
@@ -17690,13 +18688,13 @@ impl Error for ListVirtualMFADevicesError {}
 #[derive(Debug, PartialEq)]
 pub enum PutGroupPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl PutGroupPolicyError {
@@ -17709,22 +18707,22 @@ impl PutGroupPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(PutGroupPolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(PutGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(PutGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17741,6 +18739,16 @@ impl PutGroupPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutGroupPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            PutGroupPolicyError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            PutGroupPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            PutGroupPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutGroupPolicyError {
     #[allow(unused_variables)]
@@ -17758,15 +18766,15 @@ impl Error for PutGroupPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum PutRolePermissionsBoundaryError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because AWS service role policies can only be attached to the service-linked role for that service.</p>
-    PolicyNotAttachable(String),
+    PolicyNotAttachable(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl PutRolePermissionsBoundaryError {
@@ -17781,30 +18789,32 @@ impl PutRolePermissionsBoundaryError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(PutRolePermissionsBoundaryError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(PutRolePermissionsBoundaryError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyNotAttachable" => {
                         return RusotoError::Service(
                             PutRolePermissionsBoundaryError::PolicyNotAttachable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            PutRolePermissionsBoundaryError::ServiceFailure(parsed_error.message),
+                            PutRolePermissionsBoundaryError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
                             PutRolePermissionsBoundaryError::UnmodifiableEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17822,6 +18832,17 @@ impl PutRolePermissionsBoundaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutRolePermissionsBoundaryError::InvalidInput(ref cause) => cause.request_id(),
+            PutRolePermissionsBoundaryError::NoSuchEntity(ref cause) => cause.request_id(),
+            PutRolePermissionsBoundaryError::PolicyNotAttachable(ref cause) => cause.request_id(),
+            PutRolePermissionsBoundaryError::ServiceFailure(ref cause) => cause.request_id(),
+            PutRolePermissionsBoundaryError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutRolePermissionsBoundaryError {
     #[allow(unused_variables)]
@@ -17844,15 +18865,15 @@ impl Error for PutRolePermissionsBoundaryError {}
 #[derive(Debug, PartialEq)]
 pub enum PutRolePolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl PutRolePolicyError {
@@ -17865,27 +18886,27 @@ impl PutRolePolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutRolePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(PutRolePolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(PutRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(PutRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(PutRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17902,6 +18923,17 @@ impl PutRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutRolePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            PutRolePolicyError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            PutRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            PutRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+            PutRolePolicyError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutRolePolicyError {
     #[allow(unused_variables)]
@@ -17920,13 +18952,13 @@ impl Error for PutRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum PutUserPermissionsBoundaryError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because AWS service role policies can only be attached to the service-linked role for that service.</p>
-    PolicyNotAttachable(String),
+    PolicyNotAttachable(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl PutUserPermissionsBoundaryError {
@@ -17941,24 +18973,26 @@ impl PutUserPermissionsBoundaryError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(PutUserPermissionsBoundaryError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(PutUserPermissionsBoundaryError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyNotAttachable" => {
                         return RusotoError::Service(
                             PutUserPermissionsBoundaryError::PolicyNotAttachable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            PutUserPermissionsBoundaryError::ServiceFailure(parsed_error.message),
+                            PutUserPermissionsBoundaryError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -17975,6 +19009,16 @@ impl PutUserPermissionsBoundaryError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutUserPermissionsBoundaryError::InvalidInput(ref cause) => cause.request_id(),
+            PutUserPermissionsBoundaryError::NoSuchEntity(ref cause) => cause.request_id(),
+            PutUserPermissionsBoundaryError::PolicyNotAttachable(ref cause) => cause.request_id(),
+            PutUserPermissionsBoundaryError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutUserPermissionsBoundaryError {
     #[allow(unused_variables)]
@@ -17994,13 +19038,13 @@ impl Error for PutUserPermissionsBoundaryError {}
 #[derive(Debug, PartialEq)]
 pub enum PutUserPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl PutUserPolicyError {
@@ -18013,22 +19057,22 @@ impl PutUserPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(PutUserPolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(PutUserPolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(PutUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(PutUserPolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18045,6 +19089,16 @@ impl PutUserPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutUserPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            PutUserPolicyError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            PutUserPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            PutUserPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutUserPolicyError {
     #[allow(unused_variables)]
@@ -18062,11 +19116,11 @@ impl Error for PutUserPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveClientIDFromOpenIDConnectProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl RemoveClientIDFromOpenIDConnectProviderError {
@@ -18082,21 +19136,21 @@ impl RemoveClientIDFromOpenIDConnectProviderError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             RemoveClientIDFromOpenIDConnectProviderError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18114,6 +19168,21 @@ impl RemoveClientIDFromOpenIDConnectProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveClientIDFromOpenIDConnectProviderError::InvalidInput(ref cause) => {
+                cause.request_id()
+            }
+            RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity(ref cause) => {
+                cause.request_id()
+            }
+            RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveClientIDFromOpenIDConnectProviderError {
     #[allow(unused_variables)]
@@ -18136,13 +19205,13 @@ impl Error for RemoveClientIDFromOpenIDConnectProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromInstanceProfileError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl RemoveRoleFromInstanceProfileError {
@@ -18157,25 +19226,29 @@ impl RemoveRoleFromInstanceProfileError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::LimitExceeded(parsed_error.message),
+                            RemoveRoleFromInstanceProfileError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::NoSuchEntity(parsed_error.message),
+                            RemoveRoleFromInstanceProfileError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             RemoveRoleFromInstanceProfileError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
                             RemoveRoleFromInstanceProfileError::UnmodifiableEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18193,6 +19266,16 @@ impl RemoveRoleFromInstanceProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveRoleFromInstanceProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            RemoveRoleFromInstanceProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            RemoveRoleFromInstanceProfileError::ServiceFailure(ref cause) => cause.request_id(),
+            RemoveRoleFromInstanceProfileError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveRoleFromInstanceProfileError {
     #[allow(unused_variables)]
@@ -18212,11 +19295,11 @@ impl Error for RemoveRoleFromInstanceProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveUserFromGroupError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl RemoveUserFromGroupError {
@@ -18229,17 +19312,17 @@ impl RemoveUserFromGroupError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(RemoveUserFromGroupError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(RemoveUserFromGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(RemoveUserFromGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18256,6 +19339,15 @@ impl RemoveUserFromGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveUserFromGroupError::LimitExceeded(ref cause) => cause.request_id(),
+            RemoveUserFromGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            RemoveUserFromGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveUserFromGroupError {
     #[allow(unused_variables)]
@@ -18272,7 +19364,7 @@ impl Error for RemoveUserFromGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetServiceSpecificCredentialError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl ResetServiceSpecificCredentialError {
@@ -18287,7 +19379,9 @@ impl ResetServiceSpecificCredentialError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            ResetServiceSpecificCredentialError::NoSuchEntity(parsed_error.message),
+                            ResetServiceSpecificCredentialError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -18304,6 +19398,13 @@ impl ResetServiceSpecificCredentialError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetServiceSpecificCredentialError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResetServiceSpecificCredentialError {
     #[allow(unused_variables)]
@@ -18318,13 +19419,13 @@ impl Error for ResetServiceSpecificCredentialError {}
 #[derive(Debug, PartialEq)]
 pub enum ResyncMFADeviceError {
     /// <p>The request was rejected because the authentication code was not recognized. The error message describes the specific error.</p>
-    InvalidAuthenticationCode(String),
+    InvalidAuthenticationCode(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl ResyncMFADeviceError {
@@ -18337,22 +19438,24 @@ impl ResyncMFADeviceError {
                 match &parsed_error.code[..] {
                     "InvalidAuthenticationCode" => {
                         return RusotoError::Service(
-                            ResyncMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
+                            ResyncMFADeviceError::InvalidAuthenticationCode(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(ResyncMFADeviceError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(ResyncMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(ResyncMFADeviceError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18369,6 +19472,16 @@ impl ResyncMFADeviceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResyncMFADeviceError::InvalidAuthenticationCode(ref cause) => cause.request_id(),
+            ResyncMFADeviceError::LimitExceeded(ref cause) => cause.request_id(),
+            ResyncMFADeviceError::NoSuchEntity(ref cause) => cause.request_id(),
+            ResyncMFADeviceError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResyncMFADeviceError {
     #[allow(unused_variables)]
@@ -18386,13 +19499,13 @@ impl Error for ResyncMFADeviceError {}
 #[derive(Debug, PartialEq)]
 pub enum SetDefaultPolicyVersionError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl SetDefaultPolicyVersionError {
@@ -18405,22 +19518,22 @@ impl SetDefaultPolicyVersionError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(SetDefaultPolicyVersionError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(SetDefaultPolicyVersionError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(SetDefaultPolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(SetDefaultPolicyVersionError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18437,6 +19550,16 @@ impl SetDefaultPolicyVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetDefaultPolicyVersionError::InvalidInput(ref cause) => cause.request_id(),
+            SetDefaultPolicyVersionError::LimitExceeded(ref cause) => cause.request_id(),
+            SetDefaultPolicyVersionError::NoSuchEntity(ref cause) => cause.request_id(),
+            SetDefaultPolicyVersionError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetDefaultPolicyVersionError {
     #[allow(unused_variables)]
@@ -18454,7 +19577,7 @@ impl Error for SetDefaultPolicyVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum SetSecurityTokenServicePreferencesError {
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl SetSecurityTokenServicePreferencesError {
@@ -18470,7 +19593,7 @@ impl SetSecurityTokenServicePreferencesError {
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             SetSecurityTokenServicePreferencesError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18488,6 +19611,15 @@ impl SetSecurityTokenServicePreferencesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetSecurityTokenServicePreferencesError::ServiceFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for SetSecurityTokenServicePreferencesError {
     #[allow(unused_variables)]
@@ -18504,9 +19636,9 @@ impl Error for SetSecurityTokenServicePreferencesError {}
 #[derive(Debug, PartialEq)]
 pub enum SimulateCustomPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request failed because a provided policy could not be successfully evaluated. An additional detailed message indicates the source of the failure.</p>
-    PolicyEvaluation(String),
+    PolicyEvaluation(XmlErrorMessage),
 }
 
 impl SimulateCustomPolicyError {
@@ -18519,12 +19651,12 @@ impl SimulateCustomPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(SimulateCustomPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyEvaluation" => {
                         return RusotoError::Service(SimulateCustomPolicyError::PolicyEvaluation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18541,6 +19673,14 @@ impl SimulateCustomPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SimulateCustomPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            SimulateCustomPolicyError::PolicyEvaluation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SimulateCustomPolicyError {
     #[allow(unused_variables)]
@@ -18556,11 +19696,11 @@ impl Error for SimulateCustomPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum SimulatePrincipalPolicyError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request failed because a provided policy could not be successfully evaluated. An additional detailed message indicates the source of the failure.</p>
-    PolicyEvaluation(String),
+    PolicyEvaluation(XmlErrorMessage),
 }
 
 impl SimulatePrincipalPolicyError {
@@ -18573,17 +19713,19 @@ impl SimulatePrincipalPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(SimulatePrincipalPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(SimulatePrincipalPolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PolicyEvaluation" => {
                         return RusotoError::Service(
-                            SimulatePrincipalPolicyError::PolicyEvaluation(parsed_error.message),
+                            SimulatePrincipalPolicyError::PolicyEvaluation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -18600,6 +19742,15 @@ impl SimulatePrincipalPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SimulatePrincipalPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            SimulatePrincipalPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            SimulatePrincipalPolicyError::PolicyEvaluation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SimulatePrincipalPolicyError {
     #[allow(unused_variables)]
@@ -18616,15 +19767,15 @@ impl Error for SimulatePrincipalPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum TagRoleError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl TagRoleError {
@@ -18637,27 +19788,27 @@ impl TagRoleError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(TagRoleError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(TagRoleError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(TagRoleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(TagRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(TagRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18674,6 +19825,17 @@ impl TagRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TagRoleError::ConcurrentModification(ref cause) => cause.request_id(),
+            TagRoleError::InvalidInput(ref cause) => cause.request_id(),
+            TagRoleError::LimitExceeded(ref cause) => cause.request_id(),
+            TagRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            TagRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TagRoleError {
     #[allow(unused_variables)]
@@ -18692,15 +19854,15 @@ impl Error for TagRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum TagUserError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl TagUserError {
@@ -18713,27 +19875,27 @@ impl TagUserError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(TagUserError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(TagUserError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(TagUserError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(TagUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(TagUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18750,6 +19912,17 @@ impl TagUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TagUserError::ConcurrentModification(ref cause) => cause.request_id(),
+            TagUserError::InvalidInput(ref cause) => cause.request_id(),
+            TagUserError::LimitExceeded(ref cause) => cause.request_id(),
+            TagUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            TagUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TagUserError {
     #[allow(unused_variables)]
@@ -18768,11 +19941,11 @@ impl Error for TagUserError {}
 #[derive(Debug, PartialEq)]
 pub enum UntagRoleError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UntagRoleError {
@@ -18785,17 +19958,17 @@ impl UntagRoleError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(UntagRoleError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UntagRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UntagRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18812,6 +19985,15 @@ impl UntagRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UntagRoleError::ConcurrentModification(ref cause) => cause.request_id(),
+            UntagRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            UntagRoleError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UntagRoleError {
     #[allow(unused_variables)]
@@ -18828,11 +20010,11 @@ impl Error for UntagRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum UntagUserError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UntagUserError {
@@ -18845,17 +20027,17 @@ impl UntagUserError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(UntagUserError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UntagUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UntagUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18872,6 +20054,15 @@ impl UntagUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UntagUserError::ConcurrentModification(ref cause) => cause.request_id(),
+            UntagUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            UntagUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UntagUserError {
     #[allow(unused_variables)]
@@ -18888,11 +20079,11 @@ impl Error for UntagUserError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccessKeyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateAccessKeyError {
@@ -18905,17 +20096,17 @@ impl UpdateAccessKeyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateAccessKeyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateAccessKeyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18932,6 +20123,15 @@ impl UpdateAccessKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateAccessKeyError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateAccessKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateAccessKeyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateAccessKeyError {
     #[allow(unused_variables)]
@@ -18948,13 +20148,13 @@ impl Error for UpdateAccessKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountPasswordPolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateAccountPasswordPolicyError {
@@ -18969,24 +20169,30 @@ impl UpdateAccountPasswordPolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
+                            UpdateAccountPasswordPolicyError::LimitExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
                             UpdateAccountPasswordPolicyError::MalformedPolicyDocument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
+                            UpdateAccountPasswordPolicyError::NoSuchEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
+                            UpdateAccountPasswordPolicyError::ServiceFailure(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -19003,6 +20209,18 @@ impl UpdateAccountPasswordPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateAccountPasswordPolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateAccountPasswordPolicyError::MalformedPolicyDocument(ref cause) => {
+                cause.request_id()
+            }
+            UpdateAccountPasswordPolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateAccountPasswordPolicyError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateAccountPasswordPolicyError {
     #[allow(unused_variables)]
@@ -19022,15 +20240,15 @@ impl Error for UpdateAccountPasswordPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssumeRolePolicyError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl UpdateAssumeRolePolicyError {
@@ -19043,29 +20261,31 @@ impl UpdateAssumeRolePolicyError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateAssumeRolePolicyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
                             UpdateAssumeRolePolicyError::MalformedPolicyDocument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateAssumeRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateAssumeRolePolicyError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
-                            UpdateAssumeRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                            UpdateAssumeRolePolicyError::UnmodifiableEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -19082,6 +20302,17 @@ impl UpdateAssumeRolePolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateAssumeRolePolicyError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateAssumeRolePolicyError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            UpdateAssumeRolePolicyError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateAssumeRolePolicyError::ServiceFailure(ref cause) => cause.request_id(),
+            UpdateAssumeRolePolicyError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateAssumeRolePolicyError {
     #[allow(unused_variables)]
@@ -19102,13 +20333,13 @@ impl Error for UpdateAssumeRolePolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateGroupError {
@@ -19121,22 +20352,22 @@ impl UpdateGroupError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(UpdateGroupError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateGroupError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateGroupError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateGroupError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19153,6 +20384,16 @@ impl UpdateGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateGroupError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            UpdateGroupError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateGroupError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateGroupError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateGroupError {
     #[allow(unused_variables)]
@@ -19170,15 +20411,15 @@ impl Error for UpdateGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateLoginProfileError {
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request was rejected because the provided password did not meet the requirements imposed by the account password policy.</p>
-    PasswordPolicyViolation(String),
+    PasswordPolicyViolation(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateLoginProfileError {
@@ -19192,28 +20433,30 @@ impl UpdateLoginProfileError {
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
                             UpdateLoginProfileError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateLoginProfileError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PasswordPolicyViolation" => {
                         return RusotoError::Service(
-                            UpdateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
+                            UpdateLoginProfileError::PasswordPolicyViolation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateLoginProfileError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19230,6 +20473,17 @@ impl UpdateLoginProfileError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateLoginProfileError::EntityTemporarilyUnmodifiable(ref cause) => cause.request_id(),
+            UpdateLoginProfileError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateLoginProfileError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateLoginProfileError::PasswordPolicyViolation(ref cause) => cause.request_id(),
+            UpdateLoginProfileError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateLoginProfileError {
     #[allow(unused_variables)]
@@ -19250,11 +20504,11 @@ impl Error for UpdateLoginProfileError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateOpenIDConnectProviderThumbprintError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateOpenIDConnectProviderThumbprintError {
@@ -19270,21 +20524,21 @@ impl UpdateOpenIDConnectProviderThumbprintError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             UpdateOpenIDConnectProviderThumbprintError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(
                             UpdateOpenIDConnectProviderThumbprintError::ServiceFailure(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19302,6 +20556,21 @@ impl UpdateOpenIDConnectProviderThumbprintError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateOpenIDConnectProviderThumbprintError::InvalidInput(ref cause) => {
+                cause.request_id()
+            }
+            UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity(ref cause) => {
+                cause.request_id()
+            }
+            UpdateOpenIDConnectProviderThumbprintError::ServiceFailure(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateOpenIDConnectProviderThumbprintError {
     #[allow(unused_variables)]
@@ -19324,11 +20593,11 @@ impl Error for UpdateOpenIDConnectProviderThumbprintError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoleError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl UpdateRoleError {
@@ -19341,17 +20610,17 @@ impl UpdateRoleError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateRoleError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateRoleError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(UpdateRoleError::UnmodifiableEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19368,6 +20637,15 @@ impl UpdateRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateRoleError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateRoleError::ServiceFailure(ref cause) => cause.request_id(),
+            UpdateRoleError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateRoleError {
     #[allow(unused_variables)]
@@ -19384,11 +20662,11 @@ impl Error for UpdateRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoleDescriptionError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
     /// <p>The request was rejected because only the service that depends on the service-linked role can modify or delete the role on your behalf. The error message includes the name of the service that depends on this service-linked role. You must request the change through that service.</p>
-    UnmodifiableEntity(String),
+    UnmodifiableEntity(XmlErrorMessage),
 }
 
 impl UpdateRoleDescriptionError {
@@ -19401,17 +20679,19 @@ impl UpdateRoleDescriptionError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateRoleDescriptionError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateRoleDescriptionError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnmodifiableEntity" => {
                         return RusotoError::Service(
-                            UpdateRoleDescriptionError::UnmodifiableEntity(parsed_error.message),
+                            UpdateRoleDescriptionError::UnmodifiableEntity(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -19428,6 +20708,15 @@ impl UpdateRoleDescriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateRoleDescriptionError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateRoleDescriptionError::ServiceFailure(ref cause) => cause.request_id(),
+            UpdateRoleDescriptionError::UnmodifiableEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateRoleDescriptionError {
     #[allow(unused_variables)]
@@ -19444,13 +20733,13 @@ impl Error for UpdateRoleDescriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateSAMLProviderError {
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateSAMLProviderError {
@@ -19463,22 +20752,22 @@ impl UpdateSAMLProviderError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(UpdateSAMLProviderError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19495,6 +20784,16 @@ impl UpdateSAMLProviderError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateSAMLProviderError::InvalidInput(ref cause) => cause.request_id(),
+            UpdateSAMLProviderError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateSAMLProviderError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateSAMLProviderError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateSAMLProviderError {
     #[allow(unused_variables)]
@@ -19512,7 +20811,7 @@ impl Error for UpdateSAMLProviderError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateSSHPublicKeyError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl UpdateSSHPublicKeyError {
@@ -19525,7 +20824,7 @@ impl UpdateSSHPublicKeyError {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19542,6 +20841,13 @@ impl UpdateSSHPublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateSSHPublicKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateSSHPublicKeyError {
     #[allow(unused_variables)]
@@ -19556,13 +20862,13 @@ impl Error for UpdateSSHPublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateServerCertificateError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateServerCertificateError {
@@ -19575,22 +20881,24 @@ impl UpdateServerCertificateError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
-                            UpdateServerCertificateError::EntityAlreadyExists(parsed_error.message),
+                            UpdateServerCertificateError::EntityAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateServerCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateServerCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19607,6 +20915,16 @@ impl UpdateServerCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateServerCertificateError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            UpdateServerCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateServerCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateServerCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateServerCertificateError {
     #[allow(unused_variables)]
@@ -19624,7 +20942,7 @@ impl Error for UpdateServerCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceSpecificCredentialError {
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
 }
 
 impl UpdateServiceSpecificCredentialError {
@@ -19640,7 +20958,7 @@ impl UpdateServiceSpecificCredentialError {
                     "NoSuchEntity" => {
                         return RusotoError::Service(
                             UpdateServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19658,6 +20976,13 @@ impl UpdateServiceSpecificCredentialError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateServiceSpecificCredentialError::NoSuchEntity(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateServiceSpecificCredentialError {
     #[allow(unused_variables)]
@@ -19672,11 +20997,11 @@ impl Error for UpdateServiceSpecificCredentialError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateSigningCertificateError {
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateSigningCertificateError {
@@ -19689,17 +21014,17 @@ impl UpdateSigningCertificateError {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19716,6 +21041,15 @@ impl UpdateSigningCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateSigningCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateSigningCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateSigningCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateSigningCertificateError {
     #[allow(unused_variables)]
@@ -19732,17 +21066,17 @@ impl Error for UpdateSigningCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
     /// <p>The request was rejected because multiple requests to change this object were submitted simultaneously. Wait a few minutes and submit your request again.</p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because it referenced an entity that is temporarily unmodifiable, such as a user name that was deleted and then recreated. The error indicates that the request is likely to succeed if you try again after waiting several minutes. The error message describes the entity.</p>
-    EntityTemporarilyUnmodifiable(String),
+    EntityTemporarilyUnmodifiable(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UpdateUserError {
@@ -19755,32 +21089,34 @@ impl UpdateUserError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(UpdateUserError::ConcurrentModification(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(UpdateUserError::EntityAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EntityTemporarilyUnmodifiable" => {
                         return RusotoError::Service(
-                            UpdateUserError::EntityTemporarilyUnmodifiable(parsed_error.message),
+                            UpdateUserError::EntityTemporarilyUnmodifiable(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateUserError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UpdateUserError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UpdateUserError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19797,6 +21133,18 @@ impl UpdateUserError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateUserError::ConcurrentModification(ref cause) => cause.request_id(),
+            UpdateUserError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            UpdateUserError::EntityTemporarilyUnmodifiable(ref cause) => cause.request_id(),
+            UpdateUserError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateUserError::NoSuchEntity(ref cause) => cause.request_id(),
+            UpdateUserError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateUserError {
     #[allow(unused_variables)]
@@ -19816,15 +21164,15 @@ impl Error for UpdateUserError {}
 #[derive(Debug, PartialEq)]
 pub enum UploadSSHPublicKeyError {
     /// <p>The request was rejected because the SSH public key is already associated with the specified IAM user.</p>
-    DuplicateSSHPublicKey(String),
+    DuplicateSSHPublicKey(XmlErrorMessage),
     /// <p>The request was rejected because the public key is malformed or otherwise invalid.</p>
-    InvalidPublicKey(String),
+    InvalidPublicKey(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request was rejected because the public key encoding format is unsupported or unrecognized.</p>
-    UnrecognizedPublicKeyEncoding(String),
+    UnrecognizedPublicKeyEncoding(XmlErrorMessage),
 }
 
 impl UploadSSHPublicKeyError {
@@ -19837,28 +21185,30 @@ impl UploadSSHPublicKeyError {
                 match &parsed_error.code[..] {
                     "DuplicateSSHPublicKey" => {
                         return RusotoError::Service(
-                            UploadSSHPublicKeyError::DuplicateSSHPublicKey(parsed_error.message),
+                            UploadSSHPublicKeyError::DuplicateSSHPublicKey(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidPublicKey" => {
                         return RusotoError::Service(UploadSSHPublicKeyError::InvalidPublicKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UploadSSHPublicKeyError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UploadSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnrecognizedPublicKeyEncoding" => {
                         return RusotoError::Service(
                             UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19876,6 +21226,17 @@ impl UploadSSHPublicKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UploadSSHPublicKeyError::DuplicateSSHPublicKey(ref cause) => cause.request_id(),
+            UploadSSHPublicKeyError::InvalidPublicKey(ref cause) => cause.request_id(),
+            UploadSSHPublicKeyError::LimitExceeded(ref cause) => cause.request_id(),
+            UploadSSHPublicKeyError::NoSuchEntity(ref cause) => cause.request_id(),
+            UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UploadSSHPublicKeyError {
     #[allow(unused_variables)]
@@ -19896,15 +21257,15 @@ impl Error for UploadSSHPublicKeyError {}
 #[derive(Debug, PartialEq)]
 pub enum UploadServerCertificateError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because the public key certificate and the private key do not match.</p>
-    KeyPairMismatch(String),
+    KeyPairMismatch(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the certificate was malformed or expired. The error message describes the specific error.</p>
-    MalformedCertificate(String),
+    MalformedCertificate(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UploadServerCertificateError {
@@ -19917,29 +21278,31 @@ impl UploadServerCertificateError {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
-                            UploadServerCertificateError::EntityAlreadyExists(parsed_error.message),
+                            UploadServerCertificateError::EntityAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KeyPairMismatch" => {
                         return RusotoError::Service(UploadServerCertificateError::KeyPairMismatch(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UploadServerCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedCertificate" => {
                         return RusotoError::Service(
                             UploadServerCertificateError::MalformedCertificate(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UploadServerCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -19956,6 +21319,17 @@ impl UploadServerCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UploadServerCertificateError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            UploadServerCertificateError::KeyPairMismatch(ref cause) => cause.request_id(),
+            UploadServerCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            UploadServerCertificateError::MalformedCertificate(ref cause) => cause.request_id(),
+            UploadServerCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UploadServerCertificateError {
     #[allow(unused_variables)]
@@ -19974,19 +21348,19 @@ impl Error for UploadServerCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum UploadSigningCertificateError {
     /// <p>The request was rejected because the same certificate is associated with an IAM user in the account.</p>
-    DuplicateCertificate(String),
+    DuplicateCertificate(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
-    EntityAlreadyExists(String),
+    EntityAlreadyExists(XmlErrorMessage),
     /// <p>The request was rejected because the certificate is invalid.</p>
-    InvalidCertificate(String),
+    InvalidCertificate(XmlErrorMessage),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limitations. The error message describes the limit exceeded.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>The request was rejected because the certificate was malformed or expired. The error message describes the specific error.</p>
-    MalformedCertificate(String),
+    MalformedCertificate(XmlErrorMessage),
     /// <p>The request was rejected because it referenced a resource entity that does not exist. The error message describes the resource.</p>
-    NoSuchEntity(String),
+    NoSuchEntity(XmlErrorMessage),
     /// <p>The request processing has failed because of an unknown error, exception or failure.</p>
-    ServiceFailure(String),
+    ServiceFailure(XmlErrorMessage),
 }
 
 impl UploadSigningCertificateError {
@@ -20000,42 +21374,44 @@ impl UploadSigningCertificateError {
                     "DuplicateCertificate" => {
                         return RusotoError::Service(
                             UploadSigningCertificateError::DuplicateCertificate(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "EntityAlreadyExists" => {
                         return RusotoError::Service(
                             UploadSigningCertificateError::EntityAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidCertificate" => {
                         return RusotoError::Service(
-                            UploadSigningCertificateError::InvalidCertificate(parsed_error.message),
+                            UploadSigningCertificateError::InvalidCertificate(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UploadSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedCertificate" => {
                         return RusotoError::Service(
                             UploadSigningCertificateError::MalformedCertificate(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchEntity" => {
                         return RusotoError::Service(UploadSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ServiceFailure" => {
                         return RusotoError::Service(UploadSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -20052,6 +21428,19 @@ impl UploadSigningCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UploadSigningCertificateError::DuplicateCertificate(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::EntityAlreadyExists(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::InvalidCertificate(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::LimitExceeded(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::MalformedCertificate(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::NoSuchEntity(ref cause) => cause.request_id(),
+            UploadSigningCertificateError::ServiceFailure(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UploadSigningCertificateError {
     #[allow(unused_variables)]
@@ -5893,6 +5893,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CancelImageCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5904,13 +5905,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelImageCreationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelImageCreationError::from_response(response))
         }
     }
@@ -5924,6 +5925,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5935,13 +5937,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateComponentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateComponentError::from_response(response))
         }
     }
@@ -5958,6 +5960,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5969,13 +5972,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDistributionConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDistributionConfigurationError::from_response(
                 response,
             ))
@@ -5991,6 +5994,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6002,13 +6006,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateImageError::from_response(response))
         }
     }
@@ -6022,6 +6026,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6033,13 +6038,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImagePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateImagePipelineError::from_response(response))
         }
     }
@@ -6053,6 +6058,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImageRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6064,13 +6070,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImageRecipeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateImageRecipeError::from_response(response))
         }
     }
@@ -6087,6 +6093,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6098,13 +6105,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInfrastructureConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateInfrastructureConfigurationError::from_response(
                 response,
             ))
@@ -6120,6 +6127,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteComponent";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6135,13 +6143,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteComponentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteComponentError::from_response(response))
         }
     }
@@ -6158,6 +6166,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteDistributionConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6173,13 +6182,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDistributionConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDistributionConfigurationError::from_response(
                 response,
             ))
@@ -6195,6 +6204,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImage";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6207,13 +6217,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteImageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteImageError::from_response(response))
         }
     }
@@ -6227,6 +6237,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImagePipeline";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6239,13 +6250,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteImagePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteImagePipelineError::from_response(response))
         }
     }
@@ -6259,6 +6270,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImageRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6271,13 +6283,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteImageRecipeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteImageRecipeError::from_response(response))
         }
     }
@@ -6294,6 +6306,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6309,13 +6322,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInfrastructureConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInfrastructureConfigurationError::from_response(
                 response,
             ))
@@ -6331,6 +6344,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponent";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6346,13 +6360,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetComponentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetComponentError::from_response(response))
         }
     }
@@ -6366,6 +6380,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponentPolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6378,13 +6393,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetComponentPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetComponentPolicyError::from_response(response))
         }
     }
@@ -6399,6 +6414,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetDistributionConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6414,13 +6430,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDistributionConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDistributionConfigurationError::from_response(response))
         }
     }
@@ -6434,6 +6450,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImage";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6446,13 +6463,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImageError::from_response(response))
         }
     }
@@ -6466,6 +6483,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePipeline";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6478,13 +6496,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImagePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImagePipelineError::from_response(response))
         }
     }
@@ -6498,6 +6516,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6510,13 +6529,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImagePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImagePolicyError::from_response(response))
         }
     }
@@ -6530,6 +6549,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6542,13 +6562,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImageRecipeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImageRecipeError::from_response(response))
         }
     }
@@ -6562,6 +6582,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6574,13 +6595,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImageRecipePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImageRecipePolicyError::from_response(response))
         }
     }
@@ -6597,6 +6618,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6612,13 +6634,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetInfrastructureConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetInfrastructureConfigurationError::from_response(response))
         }
     }
@@ -6632,6 +6654,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ImportComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6643,13 +6666,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ImportComponentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ImportComponentError::from_response(response))
         }
     }
@@ -6664,6 +6687,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponentBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponentBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6675,13 +6699,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListComponentBuildVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListComponentBuildVersionsError::from_response(response))
         }
     }
@@ -6695,6 +6719,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponents";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6706,13 +6731,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListComponentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListComponentsError::from_response(response))
         }
     }
@@ -6729,6 +6754,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListDistributionConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListDistributionConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6740,13 +6766,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDistributionConfigurationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDistributionConfigurationsError::from_response(response))
         }
     }
@@ -6760,6 +6786,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6771,13 +6798,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListImageBuildVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListImageBuildVersionsError::from_response(response))
         }
     }
@@ -6791,6 +6818,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelineImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelineImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6802,13 +6830,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListImagePipelineImagesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListImagePipelineImagesError::from_response(response))
         }
     }
@@ -6822,6 +6850,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelines";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6833,13 +6862,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListImagePipelinesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListImagePipelinesError::from_response(response))
         }
     }
@@ -6853,6 +6882,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageRecipes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6864,13 +6894,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListImageRecipesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListImageRecipesError::from_response(response))
         }
     }
@@ -6884,6 +6914,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6895,13 +6926,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListImagesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListImagesError::from_response(response))
         }
     }
@@ -6918,6 +6949,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListInfrastructureConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListInfrastructureConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6929,13 +6961,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInfrastructureConfigurationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInfrastructureConfigurationsError::from_response(
                 response,
             ))
@@ -6951,6 +6983,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6959,13 +6992,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -6979,6 +7012,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutComponentPolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6990,13 +7024,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutComponentPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutComponentPolicyError::from_response(response))
         }
     }
@@ -7010,6 +7044,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImagePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7021,13 +7056,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutImagePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutImagePolicyError::from_response(response))
         }
     }
@@ -7041,6 +7076,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImageRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7052,13 +7088,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutImageRecipePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutImageRecipePolicyError::from_response(response))
         }
     }
@@ -7073,6 +7109,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("StartImagePipelineExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7084,13 +7121,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartImagePipelineExecutionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartImagePipelineExecutionError::from_response(response))
         }
     }
@@ -7104,6 +7141,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7115,13 +7153,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -7135,6 +7173,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7149,13 +7188,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -7172,6 +7211,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7183,13 +7223,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDistributionConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDistributionConfigurationError::from_response(
                 response,
             ))
@@ -7205,6 +7245,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7216,13 +7257,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateImagePipelineResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateImagePipelineError::from_response(response))
         }
     }
@@ -7239,6 +7280,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7250,13 +7292,13 @@ impl ImageBuilder for ImageBuilderClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInfrastructureConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateInfrastructureConfigurationError::from_response(
                 response,
             ))
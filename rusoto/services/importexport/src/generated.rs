@@ -52,7 +52,7 @@ impl ImportExportClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -859,17 +859,17 @@ impl WarningMessageDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum CancelJobError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
-    CanceledJobId(String),
+    CanceledJobId(XmlErrorMessage),
     /// <p>Indicates that the specified job has expired out of the system.</p>
-    ExpiredJobId(String),
+    ExpiredJobId(XmlErrorMessage),
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>The JOBID was missing, not found, or not associated with the AWS account.</p>
-    InvalidJobId(String),
+    InvalidJobId(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
     /// <p>AWS Import/Export cannot cancel the job</p>
-    UnableToCancelJobId(String),
+    UnableToCancelJobId(XmlErrorMessage),
 }
 
 impl CancelJobError {
@@ -882,32 +882,32 @@ impl CancelJobError {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
                         return RusotoError::Service(CancelJobError::CanceledJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ExpiredJobIdException" => {
                         return RusotoError::Service(CancelJobError::ExpiredJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(CancelJobError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidJobIdException" => {
                         return RusotoError::Service(CancelJobError::InvalidJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(CancelJobError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnableToCancelJobIdException" => {
                         return RusotoError::Service(CancelJobError::UnableToCancelJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -924,6 +924,18 @@ impl CancelJobError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CancelJobError::CanceledJobId(ref cause) => cause.request_id(),
+            CancelJobError::ExpiredJobId(ref cause) => cause.request_id(),
+            CancelJobError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            CancelJobError::InvalidJobId(ref cause) => cause.request_id(),
+            CancelJobError::InvalidVersion(ref cause) => cause.request_id(),
+            CancelJobError::UnableToCancelJobId(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CancelJobError {
     #[allow(unused_variables)]
@@ -943,37 +955,37 @@ impl Error for CancelJobError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
     /// <p>The account specified does not have the appropriate bucket permissions.</p>
-    BucketPermission(String),
+    BucketPermission(XmlErrorMessage),
     /// <p>Each account can create only a certain number of jobs per day. If you need to create more than this, please contact awsimportexport@amazon.com to explain your particular use case.</p>
-    CreateJobQuotaExceeded(String),
+    CreateJobQuotaExceeded(XmlErrorMessage),
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>The address specified in the manifest is invalid.</p>
-    InvalidAddress(String),
+    InvalidAddress(XmlErrorMessage),
     /// <p>One or more customs parameters was invalid. Please correct and resubmit.</p>
-    InvalidCustoms(String),
+    InvalidCustoms(XmlErrorMessage),
     /// <p>File system specified in export manifest is invalid.</p>
-    InvalidFileSystem(String),
+    InvalidFileSystem(XmlErrorMessage),
     /// <p>The JOBID was missing, not found, or not associated with the AWS account.</p>
-    InvalidJobId(String),
+    InvalidJobId(XmlErrorMessage),
     /// <p>One or more manifest fields was invalid. Please correct and resubmit.</p>
-    InvalidManifestField(String),
+    InvalidManifestField(XmlErrorMessage),
     /// <p>One or more parameters had an invalid value.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
     /// <p>Your manifest is not well-formed.</p>
-    MalformedManifest(String),
+    MalformedManifest(XmlErrorMessage),
     /// <p>One or more required customs parameters was missing from the manifest.</p>
-    MissingCustoms(String),
+    MissingCustoms(XmlErrorMessage),
     /// <p>One or more required fields were missing from the manifest file. Please correct and resubmit.</p>
-    MissingManifestField(String),
+    MissingManifestField(XmlErrorMessage),
     /// <p>One or more required parameters was missing from the request.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>Your manifest file contained buckets from multiple regions. A job is restricted to buckets from one region. Please correct and resubmit.</p>
-    MultipleRegions(String),
+    MultipleRegions(XmlErrorMessage),
     /// <p>The specified bucket does not exist. Create the specified bucket or change the manifest&#39;s bucket, exportBucket, or logBucket field to a bucket that the account, as specified by the manifest&#39;s Access Key ID, has write permissions to.</p>
-    NoSuchBucket(String),
+    NoSuchBucket(XmlErrorMessage),
 }
 
 impl CreateJobError {
@@ -986,82 +998,82 @@ impl CreateJobError {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
                         return RusotoError::Service(CreateJobError::BucketPermission(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CreateJobQuotaExceededException" => {
                         return RusotoError::Service(CreateJobError::CreateJobQuotaExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(CreateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAddressException" => {
                         return RusotoError::Service(CreateJobError::InvalidAddress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidCustomsException" => {
                         return RusotoError::Service(CreateJobError::InvalidCustoms(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidFileSystemException" => {
                         return RusotoError::Service(CreateJobError::InvalidFileSystem(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidJobIdException" => {
                         return RusotoError::Service(CreateJobError::InvalidJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidManifestFieldException" => {
                         return RusotoError::Service(CreateJobError::InvalidManifestField(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterException" => {
                         return RusotoError::Service(CreateJobError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(CreateJobError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedManifestException" => {
                         return RusotoError::Service(CreateJobError::MalformedManifest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingCustomsException" => {
                         return RusotoError::Service(CreateJobError::MissingCustoms(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingManifestFieldException" => {
                         return RusotoError::Service(CreateJobError::MissingManifestField(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameterException" => {
                         return RusotoError::Service(CreateJobError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MultipleRegionsException" => {
                         return RusotoError::Service(CreateJobError::MultipleRegions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchBucketException" => {
                         return RusotoError::Service(CreateJobError::NoSuchBucket(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1078,6 +1090,28 @@ impl CreateJobError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateJobError::BucketPermission(ref cause) => cause.request_id(),
+            CreateJobError::CreateJobQuotaExceeded(ref cause) => cause.request_id(),
+            CreateJobError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            CreateJobError::InvalidAddress(ref cause) => cause.request_id(),
+            CreateJobError::InvalidCustoms(ref cause) => cause.request_id(),
+            CreateJobError::InvalidFileSystem(ref cause) => cause.request_id(),
+            CreateJobError::InvalidJobId(ref cause) => cause.request_id(),
+            CreateJobError::InvalidManifestField(ref cause) => cause.request_id(),
+            CreateJobError::InvalidParameter(ref cause) => cause.request_id(),
+            CreateJobError::InvalidVersion(ref cause) => cause.request_id(),
+            CreateJobError::MalformedManifest(ref cause) => cause.request_id(),
+            CreateJobError::MissingCustoms(ref cause) => cause.request_id(),
+            CreateJobError::MissingManifestField(ref cause) => cause.request_id(),
+            CreateJobError::MissingParameter(ref cause) => cause.request_id(),
+            CreateJobError::MultipleRegions(ref cause) => cause.request_id(),
+            CreateJobError::NoSuchBucket(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateJobError {
     #[allow(unused_variables)]
@@ -1107,19 +1141,19 @@ impl Error for CreateJobError {}
 #[derive(Debug, PartialEq)]
 pub enum GetShippingLabelError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
-    CanceledJobId(String),
+    CanceledJobId(XmlErrorMessage),
     /// <p>Indicates that the specified job has expired out of the system.</p>
-    ExpiredJobId(String),
+    ExpiredJobId(XmlErrorMessage),
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>The address specified in the manifest is invalid.</p>
-    InvalidAddress(String),
+    InvalidAddress(XmlErrorMessage),
     /// <p>The JOBID was missing, not found, or not associated with the AWS account.</p>
-    InvalidJobId(String),
+    InvalidJobId(XmlErrorMessage),
     /// <p>One or more parameters had an invalid value.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
 }
 
 impl GetShippingLabelError {
@@ -1132,37 +1166,37 @@ impl GetShippingLabelError {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
                         return RusotoError::Service(GetShippingLabelError::CanceledJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ExpiredJobIdException" => {
                         return RusotoError::Service(GetShippingLabelError::ExpiredJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(GetShippingLabelError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAddressException" => {
                         return RusotoError::Service(GetShippingLabelError::InvalidAddress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidJobIdException" => {
                         return RusotoError::Service(GetShippingLabelError::InvalidJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterException" => {
                         return RusotoError::Service(GetShippingLabelError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(GetShippingLabelError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1179,6 +1213,19 @@ impl GetShippingLabelError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetShippingLabelError::CanceledJobId(ref cause) => cause.request_id(),
+            GetShippingLabelError::ExpiredJobId(ref cause) => cause.request_id(),
+            GetShippingLabelError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            GetShippingLabelError::InvalidAddress(ref cause) => cause.request_id(),
+            GetShippingLabelError::InvalidJobId(ref cause) => cause.request_id(),
+            GetShippingLabelError::InvalidParameter(ref cause) => cause.request_id(),
+            GetShippingLabelError::InvalidVersion(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetShippingLabelError {
     #[allow(unused_variables)]
@@ -1199,15 +1246,15 @@ impl Error for GetShippingLabelError {}
 #[derive(Debug, PartialEq)]
 pub enum GetStatusError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
-    CanceledJobId(String),
+    CanceledJobId(XmlErrorMessage),
     /// <p>Indicates that the specified job has expired out of the system.</p>
-    ExpiredJobId(String),
+    ExpiredJobId(XmlErrorMessage),
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>The JOBID was missing, not found, or not associated with the AWS account.</p>
-    InvalidJobId(String),
+    InvalidJobId(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
 }
 
 impl GetStatusError {
@@ -1220,27 +1267,27 @@ impl GetStatusError {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
                         return RusotoError::Service(GetStatusError::CanceledJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ExpiredJobIdException" => {
                         return RusotoError::Service(GetStatusError::ExpiredJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(GetStatusError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidJobIdException" => {
                         return RusotoError::Service(GetStatusError::InvalidJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(GetStatusError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1257,6 +1304,17 @@ impl GetStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetStatusError::CanceledJobId(ref cause) => cause.request_id(),
+            GetStatusError::ExpiredJobId(ref cause) => cause.request_id(),
+            GetStatusError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            GetStatusError::InvalidJobId(ref cause) => cause.request_id(),
+            GetStatusError::InvalidVersion(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetStatusError {
     #[allow(unused_variables)]
@@ -1275,11 +1333,11 @@ impl Error for GetStatusError {}
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>One or more parameters had an invalid value.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
 }
 
 impl ListJobsError {
@@ -1292,17 +1350,17 @@ impl ListJobsError {
                 match &parsed_error.code[..] {
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(ListJobsError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterException" => {
                         return RusotoError::Service(ListJobsError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(ListJobsError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1319,6 +1377,15 @@ impl ListJobsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListJobsError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            ListJobsError::InvalidParameter(ref cause) => cause.request_id(),
+            ListJobsError::InvalidVersion(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListJobsError {
     #[allow(unused_variables)]
@@ -1335,41 +1402,41 @@ impl Error for ListJobsError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobError {
     /// <p>The account specified does not have the appropriate bucket permissions.</p>
-    BucketPermission(String),
+    BucketPermission(XmlErrorMessage),
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
-    CanceledJobId(String),
+    CanceledJobId(XmlErrorMessage),
     /// <p>Indicates that the specified job has expired out of the system.</p>
-    ExpiredJobId(String),
+    ExpiredJobId(XmlErrorMessage),
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
-    InvalidAccessKeyId(String),
+    InvalidAccessKeyId(XmlErrorMessage),
     /// <p>The address specified in the manifest is invalid.</p>
-    InvalidAddress(String),
+    InvalidAddress(XmlErrorMessage),
     /// <p>One or more customs parameters was invalid. Please correct and resubmit.</p>
-    InvalidCustoms(String),
+    InvalidCustoms(XmlErrorMessage),
     /// <p>File system specified in export manifest is invalid.</p>
-    InvalidFileSystem(String),
+    InvalidFileSystem(XmlErrorMessage),
     /// <p>The JOBID was missing, not found, or not associated with the AWS account.</p>
-    InvalidJobId(String),
+    InvalidJobId(XmlErrorMessage),
     /// <p>One or more manifest fields was invalid. Please correct and resubmit.</p>
-    InvalidManifestField(String),
+    InvalidManifestField(XmlErrorMessage),
     /// <p>One or more parameters had an invalid value.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The client tool version is invalid.</p>
-    InvalidVersion(String),
+    InvalidVersion(XmlErrorMessage),
     /// <p>Your manifest is not well-formed.</p>
-    MalformedManifest(String),
+    MalformedManifest(XmlErrorMessage),
     /// <p>One or more required customs parameters was missing from the manifest.</p>
-    MissingCustoms(String),
+    MissingCustoms(XmlErrorMessage),
     /// <p>One or more required fields were missing from the manifest file. Please correct and resubmit.</p>
-    MissingManifestField(String),
+    MissingManifestField(XmlErrorMessage),
     /// <p>One or more required parameters was missing from the request.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>Your manifest file contained buckets from multiple regions. A job is restricted to buckets from one region. Please correct and resubmit.</p>
-    MultipleRegions(String),
+    MultipleRegions(XmlErrorMessage),
     /// <p>The specified bucket does not exist. Create the specified bucket or change the manifest&#39;s bucket, exportBucket, or logBucket field to a bucket that the account, as specified by the manifest&#39;s Access Key ID, has write permissions to.</p>
-    NoSuchBucket(String),
+    NoSuchBucket(XmlErrorMessage),
     /// <p>AWS Import/Export cannot update the job</p>
-    UnableToUpdateJobId(String),
+    UnableToUpdateJobId(XmlErrorMessage),
 }
 
 impl UpdateJobError {
@@ -1382,92 +1449,92 @@ impl UpdateJobError {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
                         return RusotoError::Service(UpdateJobError::BucketPermission(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CanceledJobIdException" => {
                         return RusotoError::Service(UpdateJobError::CanceledJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ExpiredJobIdException" => {
                         return RusotoError::Service(UpdateJobError::ExpiredJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAccessKeyIdException" => {
                         return RusotoError::Service(UpdateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidAddressException" => {
                         return RusotoError::Service(UpdateJobError::InvalidAddress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidCustomsException" => {
                         return RusotoError::Service(UpdateJobError::InvalidCustoms(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidFileSystemException" => {
                         return RusotoError::Service(UpdateJobError::InvalidFileSystem(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidJobIdException" => {
                         return RusotoError::Service(UpdateJobError::InvalidJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidManifestFieldException" => {
                         return RusotoError::Service(UpdateJobError::InvalidManifestField(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterException" => {
                         return RusotoError::Service(UpdateJobError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVersionException" => {
                         return RusotoError::Service(UpdateJobError::InvalidVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedManifestException" => {
                         return RusotoError::Service(UpdateJobError::MalformedManifest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingCustomsException" => {
                         return RusotoError::Service(UpdateJobError::MissingCustoms(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingManifestFieldException" => {
                         return RusotoError::Service(UpdateJobError::MissingManifestField(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameterException" => {
                         return RusotoError::Service(UpdateJobError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MultipleRegionsException" => {
                         return RusotoError::Service(UpdateJobError::MultipleRegions(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchBucketException" => {
                         return RusotoError::Service(UpdateJobError::NoSuchBucket(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnableToUpdateJobIdException" => {
                         return RusotoError::Service(UpdateJobError::UnableToUpdateJobId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1484,6 +1551,30 @@ impl UpdateJobError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateJobError::BucketPermission(ref cause) => cause.request_id(),
+            UpdateJobError::CanceledJobId(ref cause) => cause.request_id(),
+            UpdateJobError::ExpiredJobId(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidAccessKeyId(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidAddress(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidCustoms(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidFileSystem(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidJobId(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidManifestField(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidParameter(ref cause) => cause.request_id(),
+            UpdateJobError::InvalidVersion(ref cause) => cause.request_id(),
+            UpdateJobError::MalformedManifest(ref cause) => cause.request_id(),
+            UpdateJobError::MissingCustoms(ref cause) => cause.request_id(),
+            UpdateJobError::MissingManifestField(ref cause) => cause.request_id(),
+            UpdateJobError::MissingParameter(ref cause) => cause.request_id(),
+            UpdateJobError::MultipleRegions(ref cause) => cause.request_id(),
+            UpdateJobError::NoSuchBucket(ref cause) => cause.request_id(),
+            UpdateJobError::UnableToUpdateJobId(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateJobError {
     #[allow(unused_variables)]
@@ -41,7 +41,7 @@ impl InspectorClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3867,7 +3867,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, AddAttributesToFindingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddAttributesToFindingsResponse, _>()
     }
@@ -3886,7 +3886,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, CreateAssessmentTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateAssessmentTargetResponse, _>()
     }
@@ -3905,7 +3905,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, CreateAssessmentTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateAssessmentTemplateResponse, _>()
     }
@@ -3924,7 +3924,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, CreateExclusionsPreviewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateExclusionsPreviewResponse, _>()
     }
@@ -3943,7 +3943,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, CreateResourceGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateResourceGroupResponse, _>()
     }
 
@@ -4012,7 +4012,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeAssessmentRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAssessmentRunsResponse, _>()
     }
@@ -4032,7 +4032,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeAssessmentTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAssessmentTargetsResponse, _>()
     }
@@ -4055,7 +4055,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeAssessmentTemplatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAssessmentTemplatesResponse, _>()
     }
@@ -4078,7 +4078,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeCrossAccountAccessRoleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCrossAccountAccessRoleResponse, _>()
     }
@@ -4097,7 +4097,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeExclusionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeExclusionsResponse, _>()
     }
 
@@ -4115,7 +4115,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeFindingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFindingsResponse, _>()
     }
 
@@ -4133,7 +4133,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeResourceGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeResourceGroupsResponse, _>()
     }
@@ -4152,7 +4152,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, DescribeRulesPackagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRulesPackagesResponse, _>()
     }
@@ -4171,7 +4171,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, GetAssessmentReportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAssessmentReportResponse, _>()
     }
 
@@ -4189,7 +4189,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, GetExclusionsPreviewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetExclusionsPreviewResponse, _>()
     }
@@ -4208,7 +4208,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, GetTelemetryMetadataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetTelemetryMetadataResponse, _>()
     }
@@ -4227,7 +4227,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListAssessmentRunAgentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssessmentRunAgentsResponse, _>()
     }
@@ -4246,7 +4246,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListAssessmentRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAssessmentRunsResponse, _>()
     }
 
@@ -4264,7 +4264,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListAssessmentTargetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssessmentTargetsResponse, _>()
     }
@@ -4283,7 +4283,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListAssessmentTemplatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssessmentTemplatesResponse, _>()
     }
@@ -4302,7 +4302,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListEventSubscriptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListEventSubscriptionsResponse, _>()
     }
@@ -4321,7 +4321,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListExclusionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListExclusionsResponse, _>()
     }
 
@@ -4339,7 +4339,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListFindingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFindingsResponse, _>()
     }
 
@@ -4357,7 +4357,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListRulesPackagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRulesPackagesResponse, _>()
     }
 
@@ -4375,7 +4375,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -4393,7 +4393,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, PreviewAgentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PreviewAgentsResponse, _>()
     }
 
@@ -4435,7 +4435,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, RemoveAttributesFromFindingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveAttributesFromFindingsResponse, _>()
     }
@@ -4471,7 +4471,7 @@ impl Inspector for InspectorClient {
             .sign_and_dispatch(request, StartAssessmentRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartAssessmentRunResponse, _>()
     }
 
@@ -590,6 +590,7 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "iotdata", &self.region, &request_uri);
+        request.set_operation("DeleteThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -606,14 +607,14 @@ impl IotData for IotDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = DeleteThingShadowResponse::default();
             result.payload = response.body;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteThingShadowError::from_response(response))
         }
     }
@@ -627,6 +628,7 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("GetThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -643,14 +645,14 @@ impl IotData for IotDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetThingShadowResponse::default();
             result.payload = Some(response.body);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetThingShadowError::from_response(response))
         }
     }
@@ -667,6 +669,7 @@ impl IotData for IotDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("ListNamedShadowsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -686,13 +689,13 @@ impl IotData for IotDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListNamedShadowsForThingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListNamedShadowsForThingError::from_response(response))
         }
     }
@@ -703,6 +706,7 @@ impl IotData for IotDataClient {
         let request_uri = format!("/topics/{topic}", topic = input.topic);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("Publish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -725,12 +729,12 @@ impl IotData for IotDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PublishError::from_response(response))
         }
     }
@@ -744,6 +748,7 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("UpdateThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -762,14 +767,14 @@ impl IotData for IotDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = UpdateThingShadowResponse::default();
             result.payload = Some(response.body);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateThingShadowError::from_response(response))
         }
     }
@@ -596,6 +596,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -615,13 +616,13 @@ impl IotJobsData for IotJobsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobExecutionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeJobExecutionError::from_response(response))
         }
     }
@@ -635,6 +636,7 @@ impl IotJobsData for IotJobsDataClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("GetPendingJobExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -645,13 +647,13 @@ impl IotJobsData for IotJobsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPendingJobExecutionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPendingJobExecutionsError::from_response(response))
         }
     }
@@ -669,6 +671,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("StartNextPendingJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -681,13 +684,13 @@ impl IotJobsData for IotJobsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartNextPendingJobExecutionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartNextPendingJobExecutionError::from_response(response))
         }
     }
@@ -705,6 +708,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("UpdateJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -717,13 +721,13 @@ impl IotJobsData for IotJobsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateJobExecutionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateJobExecutionError::from_response(response))
         }
     }
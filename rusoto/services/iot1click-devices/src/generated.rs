@@ -1121,6 +1121,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("ClaimDevicesByClaimCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1131,13 +1132,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ClaimDevicesByClaimCodeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ClaimDevicesByClaimCodeError::from_response(response))
         }
     }
@@ -1152,6 +1153,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1162,13 +1164,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDeviceError::from_response(response))
         }
     }
@@ -1190,6 +1192,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("FinalizeDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1202,13 +1205,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FinalizeDeviceClaimResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(FinalizeDeviceClaimError::from_response(response))
         }
     }
@@ -1222,6 +1225,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDeviceMethods");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1232,13 +1236,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeviceMethodsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeviceMethodsError::from_response(response))
         }
     }
@@ -1260,6 +1264,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("InitiateDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1270,13 +1275,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateDeviceClaimResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InitiateDeviceClaimError::from_response(response))
         }
     }
@@ -1291,6 +1296,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("InvokeDeviceMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1303,13 +1309,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InvokeDeviceMethodResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InvokeDeviceMethodError::from_response(response))
         }
     }
@@ -1324,6 +1330,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDeviceEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1345,13 +1352,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeviceEventsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeviceEventsError::from_response(response))
         }
     }
@@ -1365,6 +1372,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1387,13 +1395,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDevicesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDevicesError::from_response(response))
         }
     }
@@ -1407,6 +1415,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1417,13 +1426,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -1438,6 +1447,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1450,12 +1460,12 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -1469,6 +1479,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UnclaimDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1479,13 +1490,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UnclaimDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UnclaimDeviceError::from_response(response))
         }
     }
@@ -1500,6 +1511,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1516,12 +1528,12 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -1536,6 +1548,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceState");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1548,13 +1561,13 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDeviceStateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeviceStateError::from_response(response))
         }
     }
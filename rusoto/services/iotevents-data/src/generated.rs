@@ -618,6 +618,7 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/inputs/messages";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -630,13 +631,13 @@ impl IotEventsData for IotEventsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchPutMessageResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchPutMessageError::from_response(response))
         }
     }
@@ -650,6 +651,7 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/detectors";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchUpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -662,13 +664,13 @@ impl IotEventsData for IotEventsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUpdateDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchUpdateDetectorError::from_response(response))
         }
     }
@@ -685,6 +687,7 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("DescribeDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -701,13 +704,13 @@ impl IotEventsData for IotEventsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDetectorResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDetectorError::from_response(response))
         }
     }
@@ -724,6 +727,7 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -746,13 +750,13 @@ impl IotEventsData for IotEventsDataClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDetectorsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDetectorsError::from_response(response))
         }
     }
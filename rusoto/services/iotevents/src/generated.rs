@@ -2109,6 +2109,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2120,13 +2121,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDetectorModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDetectorModelError::from_response(response))
         }
     }
@@ -2140,6 +2141,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2151,13 +2153,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateInputError::from_response(response))
         }
     }
@@ -2174,6 +2176,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2182,13 +2185,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDetectorModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDetectorModelError::from_response(response))
         }
     }
@@ -2202,6 +2205,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2210,13 +2214,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInputError::from_response(response))
         }
     }
@@ -2233,6 +2237,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2247,13 +2252,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDetectorModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDetectorModelError::from_response(response))
         }
     }
@@ -2267,6 +2272,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2275,13 +2281,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeInputError::from_response(response))
         }
     }
@@ -2294,6 +2300,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2302,13 +2309,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeLoggingOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeLoggingOptionsError::from_response(response))
         }
     }
@@ -2326,6 +2333,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModelVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2343,13 +2351,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDetectorModelVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDetectorModelVersionsError::from_response(response))
         }
     }
@@ -2363,6 +2371,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2380,13 +2389,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDetectorModelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDetectorModelsError::from_response(response))
         }
     }
@@ -2400,6 +2409,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2417,13 +2427,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInputsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInputsError::from_response(response))
         }
     }
@@ -2437,6 +2447,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2449,13 +2460,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -2469,6 +2480,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2480,12 +2492,12 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutLoggingOptionsError::from_response(response))
         }
     }
@@ -2499,6 +2511,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2514,13 +2527,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -2534,6 +2547,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2549,13 +2563,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -2572,6 +2586,7 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2583,13 +2598,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDetectorModelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDetectorModelError::from_response(response))
         }
     }
@@ -2603,6 +2618,7 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2614,13 +2630,13 @@ impl IotEvents for IotEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateInputError::from_response(response))
         }
     }
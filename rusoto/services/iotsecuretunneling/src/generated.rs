@@ -47,7 +47,7 @@ impl IoTSecureTunnelingClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -636,7 +636,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, CloseTunnelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CloseTunnelResponse, _>()
     }
 
@@ -654,7 +654,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, DescribeTunnelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTunnelResponse, _>()
     }
 
@@ -672,7 +672,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -690,7 +690,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, ListTunnelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTunnelsResponse, _>()
     }
 
@@ -708,7 +708,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, OpenTunnelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<OpenTunnelResponse, _>()
     }
 
@@ -726,7 +726,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -744,7 +744,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
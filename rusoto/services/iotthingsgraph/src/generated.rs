@@ -42,7 +42,7 @@ impl IotThingsGraphClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3260,7 +3260,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, AssociateEntityToThingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateEntityToThingResponse, _>()
     }
@@ -3282,7 +3282,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, CreateFlowTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFlowTemplateResponse, _>()
     }
 
@@ -3303,7 +3303,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, CreateSystemInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSystemInstanceResponse, _>()
     }
@@ -3325,7 +3325,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, CreateSystemTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSystemTemplateResponse, _>()
     }
@@ -3347,7 +3347,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeleteFlowTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFlowTemplateResponse, _>()
     }
 
@@ -3366,7 +3366,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeleteNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamespaceResponse, _>()
     }
 
@@ -3387,7 +3387,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeleteSystemInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSystemInstanceResponse, _>()
     }
@@ -3409,7 +3409,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeleteSystemTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSystemTemplateResponse, _>()
     }
@@ -3431,7 +3431,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeploySystemInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeploySystemInstanceResponse, _>()
     }
@@ -3453,7 +3453,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeprecateFlowTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeprecateFlowTemplateResponse, _>()
     }
@@ -3475,7 +3475,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DeprecateSystemTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeprecateSystemTemplateResponse, _>()
     }
@@ -3497,7 +3497,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DescribeNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeNamespaceResponse, _>()
     }
 
@@ -3519,7 +3519,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, DissociateEntityFromThingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DissociateEntityFromThingResponse, _>()
     }
@@ -3538,7 +3538,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetEntitiesResponse, _>()
     }
 
@@ -3559,7 +3559,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetFlowTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFlowTemplateResponse, _>()
     }
 
@@ -3580,7 +3580,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetFlowTemplateRevisionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetFlowTemplateRevisionsResponse, _>()
     }
@@ -3601,7 +3601,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetNamespaceDeletionStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetNamespaceDeletionStatusResponse, _>()
     }
@@ -3623,7 +3623,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetSystemInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSystemInstanceResponse, _>()
     }
 
@@ -3644,7 +3644,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetSystemTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSystemTemplateResponse, _>()
     }
 
@@ -3666,7 +3666,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetSystemTemplateRevisionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSystemTemplateRevisionsResponse, _>()
     }
@@ -3688,7 +3688,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, GetUploadStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetUploadStatusResponse, _>()
     }
 
@@ -3710,7 +3710,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, ListFlowExecutionMessagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListFlowExecutionMessagesResponse, _>()
     }
@@ -3732,7 +3732,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3753,7 +3753,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchEntitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchEntitiesResponse, _>()
     }
 
@@ -3774,7 +3774,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchFlowExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SearchFlowExecutionsResponse, _>()
     }
@@ -3796,7 +3796,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchFlowTemplatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchFlowTemplatesResponse, _>()
     }
 
@@ -3817,7 +3817,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchSystemInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SearchSystemInstancesResponse, _>()
     }
@@ -3839,7 +3839,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchSystemTemplatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SearchSystemTemplatesResponse, _>()
     }
@@ -3858,7 +3858,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, SearchThingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchThingsResponse, _>()
     }
 
@@ -3876,7 +3876,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3897,7 +3897,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, UndeploySystemInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UndeploySystemInstanceResponse, _>()
     }
@@ -3919,7 +3919,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -3940,7 +3940,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, UpdateFlowTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFlowTemplateResponse, _>()
     }
 
@@ -3961,7 +3961,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, UpdateSystemTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSystemTemplateResponse, _>()
     }
@@ -3983,7 +3983,7 @@ impl IotThingsGraph for IotThingsGraphClient {
             .sign_and_dispatch(request, UploadEntityDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UploadEntityDefinitionsResponse, _>()
     }
@@ -41,7 +41,7 @@ impl KendraClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3358,7 +3358,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, BatchDeleteDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchDeleteDocumentResponse, _>()
     }
 
@@ -3376,7 +3376,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, BatchPutDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchPutDocumentResponse, _>()
     }
 
@@ -3394,7 +3394,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, CreateDataSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDataSourceResponse, _>()
     }
 
@@ -3412,7 +3412,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, CreateFaqError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFaqResponse, _>()
     }
 
@@ -3430,7 +3430,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, CreateIndexError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateIndexResponse, _>()
     }
 
@@ -3499,7 +3499,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, DescribeDataSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDataSourceResponse, _>()
     }
 
@@ -3517,7 +3517,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, DescribeFaqError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFaqResponse, _>()
     }
 
@@ -3535,7 +3535,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, DescribeIndexError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeIndexResponse, _>()
     }
 
@@ -3556,7 +3556,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, ListDataSourceSyncJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDataSourceSyncJobsResponse, _>()
     }
@@ -3575,7 +3575,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, ListDataSourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDataSourcesResponse, _>()
     }
 
@@ -3593,7 +3593,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, ListFaqsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFaqsResponse, _>()
     }
 
@@ -3611,7 +3611,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, ListIndicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIndicesResponse, _>()
     }
 
@@ -3632,7 +3632,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3647,7 +3647,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, QueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<QueryResult, _>()
     }
 
@@ -3668,7 +3668,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, StartDataSourceSyncJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDataSourceSyncJobResponse, _>()
     }
@@ -3724,7 +3724,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3742,7 +3742,7 @@ impl Kendra for KendraClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -787,6 +787,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getClip";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetClip");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -798,7 +799,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetClipOutput::default();
             result.payload = Some(response.body);
@@ -807,7 +808,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetClipError::from_response(response))
         }
     }
@@ -822,6 +823,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getDASHStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDASHStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -833,13 +835,13 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDASHStreamingSessionURLOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDASHStreamingSessionURLError::from_response(response))
         }
     }
@@ -853,6 +855,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getHLSStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetHLSStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -864,13 +867,13 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetHLSStreamingSessionURLOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetHLSStreamingSessionURLError::from_response(response))
         }
     }
@@ -884,6 +887,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getMediaForFragmentList";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMediaForFragmentList");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -895,7 +899,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetMediaForFragmentListOutput::default();
             result.payload = Some(response.body);
@@ -904,7 +908,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMediaForFragmentListError::from_response(response))
         }
     }
@@ -918,6 +922,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/listFragments";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListFragments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -929,13 +934,13 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFragmentsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFragmentsError::from_response(response))
         }
     }
@@ -187,6 +187,7 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         let request_uri = "/getMedia";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMedia");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -198,7 +199,7 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetMediaOutput::default();
             result.payload = Some(response.body);
@@ -207,7 +208,7 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMediaError::from_response(response))
         }
     }
@@ -280,6 +280,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/get-ice-server-config";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetIceServerConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -291,13 +292,13 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIceServerConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIceServerConfigError::from_response(response))
         }
     }
@@ -311,6 +312,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/send-alexa-offer-to-master";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("SendAlexaOfferToMaster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -322,13 +324,13 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SendAlexaOfferToMasterResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SendAlexaOfferToMasterError::from_response(response))
         }
     }
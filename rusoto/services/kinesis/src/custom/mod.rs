@@ -1 +1,3 @@
+mod producer;
 
+pub use self::producer::{FailedRecord, KinesisProducer, ProducerOptions};
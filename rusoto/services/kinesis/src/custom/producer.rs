@@ -0,0 +1,595 @@
+//! A producer putting records into a stream in batches, see `KinesisProducer`.
+
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rusoto_core::retry::{is_throttling_error, RetryPolicy};
+use rusoto_core::RusotoError;
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
+
+use crate::generated::{Kinesis, PutRecordsError, PutRecordsInput, PutRecordsRequestEntry};
+
+/// The most records `put_records` accepts at once.
+const MAX_BATCH_RECORDS: usize = 500;
+/// The most bytes of data and partition keys `put_records` accepts at once.
+const MAX_BATCH_BYTES: usize = 5 * 1024 * 1024;
+/// The most bytes of data and partition key a single record may have.
+const MAX_RECORD_BYTES: usize = 1024 * 1024;
+
+type SharedKinesis = Arc<dyn Kinesis + Send + Sync>;
+
+/// When a `KinesisProducer` sends its buffered records, and how it retries them.
+#[derive(Clone, Debug)]
+pub struct ProducerOptions {
+    max_batch_records: usize,
+    max_batch_bytes: usize,
+    linger: Duration,
+    max_buffered_records: usize,
+    retry_policy: RetryPolicy,
+}
+
+impl ProducerOptions {
+    /// Options sending batches of up to 500 records and 5 MiB, at the latest 100 milliseconds
+    /// after their first record was put, with at most 10,000 records buffered, retried with
+    /// `RetryPolicy::new()`.
+    pub fn new() -> Self {
+        ProducerOptions {
+            max_batch_records: MAX_BATCH_RECORDS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            linger: Duration::from_millis(100),
+            max_buffered_records: 10_000,
+            retry_policy: RetryPolicy::new(),
+        }
+    }
+
+    /// Sends a batch once it has `max_batch_records` records, between 1 and 500.
+    pub fn with_max_batch_records(mut self, max_batch_records: usize) -> Self {
+        self.max_batch_records = max_batch_records.clamp(1, MAX_BATCH_RECORDS);
+        self
+    }
+
+    /// Sends a batch before it grows over `max_batch_bytes` of data and partition keys, at
+    /// most 5 MiB.
+    pub fn with_max_batch_bytes(mut self, max_batch_bytes: usize) -> Self {
+        self.max_batch_bytes = max_batch_bytes.clamp(1, MAX_BATCH_BYTES);
+        self
+    }
+
+    /// Sends a batch at the latest `linger` after its first record was put, even if it isn't
+    /// full.
+    pub fn with_linger(mut self, linger: Duration) -> Self {
+        self.linger = linger;
+        self
+    }
+
+    /// Makes `KinesisProducer::put` wait while `max_buffered_records` records, at least 1,
+    /// are put but not yet written or failed. Records are at most 1 MiB, so this also bounds
+    /// the memory the buffer takes.
+    pub fn with_max_buffered_records(mut self, max_buffered_records: usize) -> Self {
+        self.max_buffered_records = max_buffered_records.max(1);
+        self
+    }
+
+    /// Retries failed records with `policy`. Only its maximum number of attempts and its
+    /// delays are used.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+}
+
+impl Default for ProducerOptions {
+    fn default() -> Self {
+        ProducerOptions::new()
+    }
+}
+
+/// A record a `KinesisProducer` couldn't write to the stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedRecord {
+    /// The record as it was put.
+    pub record: PutRecordsRequestEntry,
+    /// Why the last attempt to write it failed.
+    pub reason: String,
+}
+
+impl fmt::Display for FailedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Failed to put record with partition key '{}': {}",
+            self.record.partition_key, self.reason
+        )
+    }
+}
+
+impl Error for FailedRecord {}
+
+/// Buffers records and puts them into a stream with `put_records`, in batches.
+///
+/// A batch is sent once it's full, `linger` after its first record was put, or on `flush`.
+/// Records `put_records` reports as failed, e.g. with `ProvisionedThroughputExceededException`
+/// on a hot shard, are sent again on their own, with the backoff of the retry policy; a batch
+/// failing as a whole is sent again if the error is a throttling, server or dispatch error.
+/// Records that still fail are returned by `take_failed_records` and `shutdown`.
+///
+/// Batches are sent one at a time, in the order their records were put, and a batch's retries
+/// end before the next batch is sent. Records with the same partition key are therefore
+/// written in order, except that a record sent in the same batch *after* a failed one may be
+/// written before the failed one is retried, which `put_records` doesn't allow to prevent.
+///
+/// `put` waits while `max_buffered_records` records are buffered or being sent, so producers
+/// can't queue records faster than the stream takes them. A producer must be created within a
+/// Tokio runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_kinesis::{KinesisClient, KinesisProducer, ProducerOptions, PutRecordsRequestEntry};
+///
+/// # async fn example() {
+/// let producer = KinesisProducer::new(
+///     KinesisClient::new(Region::UsEast1),
+///     "events",
+///     ProducerOptions::new(),
+/// );
+/// producer
+///     .put(PutRecordsRequestEntry {
+///         data: "hello".into(),
+///         partition_key: "user-1".to_owned(),
+///         ..Default::default()
+///     })
+///     .await;
+/// producer.flush().await;
+/// let failed = producer.shutdown().await;
+/// assert!(failed.is_empty(), "{:?}", failed);
+/// # }
+/// ```
+pub struct KinesisProducer {
+    commands: mpsc::UnboundedSender<Command>,
+    buffered: Arc<Semaphore>,
+    sender: JoinHandle<()>,
+    failed_records: Arc<Mutex<Vec<FailedRecord>>>,
+}
+
+enum Command {
+    Put(Pending),
+    Flush(oneshot::Sender<()>),
+}
+
+/// A record put but not yet written or failed, holding its room in the buffer.
+struct Pending {
+    record: PutRecordsRequestEntry,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl KinesisProducer {
+    /// Creates a producer putting records into the stream named `stream_name`.
+    pub fn new<C, S>(kinesis: C, stream_name: S, options: ProducerOptions) -> KinesisProducer
+    where
+        C: Kinesis + Send + Sync + 'static,
+        S: Into<String>,
+    {
+        let failed_records = Arc::new(Mutex::new(Vec::new()));
+        let (commands, received) = mpsc::unbounded_channel();
+        let buffered = Arc::new(Semaphore::new(options.max_buffered_records));
+        let sender = tokio::spawn(send_batches(
+            Arc::new(kinesis),
+            stream_name.into(),
+            options,
+            received,
+            failed_records.clone(),
+        ));
+        KinesisProducer {
+            commands,
+            buffered,
+            sender,
+            failed_records,
+        }
+    }
+
+    /// Buffers `record` to be put into the stream, waiting while the buffer is full.
+    ///
+    /// Records over 1 MiB of data and partition key fail right away.
+    pub async fn put(&self, record: PutRecordsRequestEntry) {
+        if record_size(&record) > MAX_RECORD_BYTES {
+            let reason = format!("record is larger than {} bytes", MAX_RECORD_BYTES);
+            push_failed(&self.failed_records, vec![FailedRecord { record, reason }]);
+            return;
+        }
+        let pending = Pending {
+            record,
+            _permit: self.buffered.clone().acquire_owned().await,
+        };
+        if let Err(mpsc::error::SendError(Command::Put(pending))) =
+            self.commands.send(Command::Put(pending))
+        {
+            let reason = "the producer stopped".to_owned();
+            push_failed(
+                &self.failed_records,
+                vec![FailedRecord {
+                    record: pending.record,
+                    reason,
+                }],
+            );
+        }
+    }
+
+    /// Sends the buffered records, and waits until all records put before are written or
+    /// failed.
+    pub async fn flush(&self) {
+        let (flushed, done) = oneshot::channel();
+        if self.commands.send(Command::Flush(flushed)).is_ok() {
+            // Only fails if the sending task panicked.
+            let _ = done.await;
+        }
+    }
+
+    /// Returns the records that failed since this was last called, without waiting for those
+    /// in progress.
+    pub fn take_failed_records(&self) -> Vec<FailedRecord> {
+        take_failed(&self.failed_records)
+    }
+
+    /// Sends the buffered records, waits until all records are written or failed, and returns
+    /// those that failed and weren't taken with `take_failed_records`.
+    pub async fn shutdown(self) -> Vec<FailedRecord> {
+        let KinesisProducer {
+            commands,
+            sender,
+            failed_records,
+            ..
+        } = self;
+        drop(commands);
+        // The task only ends early if it panicked, leaving its failures reported so far.
+        let _ = sender.await;
+        take_failed(&failed_records)
+    }
+}
+
+/// Receives the records put and sends them in batches, until the producer is dropped or shut
+/// down.
+async fn send_batches(
+    kinesis: SharedKinesis,
+    stream_name: String,
+    options: ProducerOptions,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    failed_records: Arc<Mutex<Vec<FailedRecord>>>,
+) {
+    let sender = BatchSender {
+        kinesis,
+        stream_name,
+        retry_policy: options.retry_policy.clone(),
+        failed_records,
+    };
+    let mut batch = Vec::new();
+    let mut batch_bytes = 0;
+    let mut deadline = None;
+    loop {
+        let received = match deadline {
+            Some(deadline) => time::timeout_at(deadline, commands.recv()).await.ok(),
+            None => Some(commands.recv().await),
+        };
+        match received {
+            Some(Some(Command::Put(pending))) => {
+                let size = record_size(&pending.record);
+                if !batch.is_empty() && batch_bytes + size > options.max_batch_bytes {
+                    sender.send(mem::take(&mut batch)).await;
+                }
+                if batch.is_empty() {
+                    batch_bytes = 0;
+                    deadline = Some(Instant::now() + options.linger);
+                }
+                batch.push(pending);
+                batch_bytes += size;
+                if batch.len() >= options.max_batch_records {
+                    sender.send(mem::take(&mut batch)).await;
+                    deadline = None;
+                }
+            }
+            Some(Some(Command::Flush(flushed))) => {
+                sender.send(mem::take(&mut batch)).await;
+                deadline = None;
+                let _ = flushed.send(());
+            }
+            // The batch lingered long enough.
+            None => {
+                sender.send(mem::take(&mut batch)).await;
+                deadline = None;
+            }
+            Some(None) => {
+                sender.send(batch).await;
+                return;
+            }
+        }
+    }
+}
+
+/// Sends batches of records to a stream, retrying those that fail.
+struct BatchSender {
+    kinesis: SharedKinesis,
+    stream_name: String,
+    retry_policy: RetryPolicy,
+    failed_records: Arc<Mutex<Vec<FailedRecord>>>,
+}
+
+impl BatchSender {
+    /// Puts `batch` into the stream, sending the records that failed again until they're
+    /// written or out of attempts.
+    async fn send(&self, mut batch: Vec<Pending>) {
+        let mut attempt = 1;
+        while !batch.is_empty() {
+            let input = PutRecordsInput {
+                records: batch.iter().map(|pending| pending.record.clone()).collect(),
+                stream_name: self.stream_name.clone(),
+            };
+            let failures: Vec<(Pending, String)> = match self.kinesis.put_records(input).await {
+                Ok(output) => {
+                    let mut results = output.records.into_iter();
+                    batch
+                        .into_iter()
+                        .filter_map(|pending| match results.next() {
+                            Some(result) => {
+                                let message = result.error_message.unwrap_or_default();
+                                result
+                                    .error_code
+                                    .map(|code| (pending, format!("{}: {}", code, message)))
+                            }
+                            None => Some((pending, "missing from the response".to_owned())),
+                        })
+                        .collect()
+                }
+                Err(ref err) if is_retryable(err) => batch
+                    .into_iter()
+                    .map(|pending| (pending, err.to_string()))
+                    .collect(),
+                Err(err) => {
+                    let reason = err.to_string();
+                    self.fail(batch.into_iter().map(|pending| (pending, reason.clone())));
+                    return;
+                }
+            };
+            if failures.is_empty() {
+                return;
+            }
+            if attempt >= self.retry_policy.max_attempts() {
+                self.fail(failures);
+                return;
+            }
+            time::delay_for(self.retry_policy.delay(attempt)).await;
+            attempt += 1;
+            batch = failures.into_iter().map(|(pending, _)| pending).collect();
+        }
+    }
+
+    fn fail<I>(&self, failures: I)
+    where
+        I: IntoIterator<Item = (Pending, String)>,
+    {
+        push_failed(
+            &self.failed_records,
+            failures
+                .into_iter()
+                .map(|(pending, reason)| FailedRecord {
+                    record: pending.record,
+                    reason,
+                })
+                .collect(),
+        );
+    }
+}
+
+/// Whether a failed `put_records` call is sent again.
+fn is_retryable(err: &RusotoError<PutRecordsError>) -> bool {
+    match *err {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(ref response) => response.status.is_server_error(),
+        ref err => is_throttling_error(err),
+    }
+}
+
+/// The size Kinesis counts for a record: its data and partition key.
+fn record_size(record: &PutRecordsRequestEntry) -> usize {
+    record.data.len() + record.partition_key.len()
+}
+
+fn push_failed(failed_records: &Mutex<Vec<FailedRecord>>, failed: Vec<FailedRecord>) {
+    match failed_records.lock() {
+        Ok(mut failed_records) => failed_records.extend(failed),
+        Err(poisoned) => poisoned.into_inner().extend(failed),
+    }
+}
+
+fn take_failed(failed_records: &Mutex<Vec<FailedRecord>>) -> Vec<FailedRecord> {
+    match failed_records.lock() {
+        Ok(mut failed) => mem::take(&mut *failed),
+        Err(poisoned) => mem::take(&mut *poisoned.into_inner()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::KinesisClient;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+        RequestRecorder,
+    };
+    use serde_json::{json, Value};
+
+    fn record(partition_key: &str, data: &str) -> PutRecordsRequestEntry {
+        PutRecordsRequestEntry {
+            data: data.to_owned().into(),
+            partition_key: partition_key.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// A `put_records` response, failing the records whose entry is `None` with
+    /// `ProvisionedThroughputExceededException`.
+    fn responding(results: &[Option<&str>]) -> MockRequestDispatcher {
+        let failed = results.iter().filter(|result| result.is_none()).count();
+        let records: Vec<Value> = results
+            .iter()
+            .map(|result| match *result {
+                Some(sequence_number) => {
+                    json!({"SequenceNumber": sequence_number, "ShardId": "shardId-000000000000"})
+                }
+                None => json!({
+                    "ErrorCode": "ProvisionedThroughputExceededException",
+                    "ErrorMessage": "Rate exceeded for shard shardId-000000000000",
+                }),
+            })
+            .collect();
+        MockRequestDispatcher::with_status(200)
+            .with_json_body(json!({"FailedRecordCount": failed, "Records": records}))
+    }
+
+    /// The partition keys of the records each `put_records` request sent.
+    fn sent_keys(recorder: &RequestRecorder) -> Vec<Vec<String>> {
+        recorder.requests().iter().map(request_keys).collect()
+    }
+
+    fn request_keys(request: &SignedRequest) -> Vec<String> {
+        let body: Value = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => serde_json::from_slice(body).unwrap(),
+            _ => panic!("expected a buffered request body"),
+        };
+        assert_eq!(body["StreamName"], "events");
+        body["Records"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|record| record["PartitionKey"].as_str().unwrap().to_owned())
+            .collect()
+    }
+
+    fn producer<I>(
+        dispatcher: MultipleMockRequestDispatcher<I>,
+        options: ProducerOptions,
+    ) -> KinesisProducer
+    where
+        I: Iterator<Item = MockRequestDispatcher> + Send + Sync + 'static,
+    {
+        let client = KinesisClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        KinesisProducer::new(client, "events", options)
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn retries_only_the_failed_records() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            responding(&[Some("1"), None, Some("2"), None]),
+            responding(&[Some("3"), Some("4")]),
+        ]);
+        let requests = dispatcher.recorder();
+        let producer = producer(
+            dispatcher,
+            ProducerOptions::new().with_retry_policy(fast_retries()),
+        );
+
+        producer.put(record("cold", "a")).await;
+        producer.put(record("hot", "b")).await;
+        producer.put(record("cold", "c")).await;
+        producer.put(record("hot", "d")).await;
+        producer.flush().await;
+
+        assert_eq!(
+            sent_keys(&requests),
+            vec![vec!["cold", "hot", "cold", "hot"], vec!["hot", "hot"]]
+        );
+        assert!(producer.shutdown().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn sends_full_batches_and_lingering_ones() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            responding(&[Some("1"), Some("2")]),
+            responding(&[Some("3")]),
+        ]);
+        let requests = dispatcher.recorder();
+        let producer = producer(
+            dispatcher,
+            ProducerOptions::new()
+                .with_max_batch_records(2)
+                .with_linger(Duration::from_millis(20)),
+        );
+
+        producer.put(record("a", "1")).await;
+        producer.put(record("b", "2")).await;
+        producer.put(record("c", "3")).await;
+        time::delay_for(Duration::from_millis(10)).await;
+        assert_eq!(sent_keys(&requests), vec![vec!["a", "b"]]);
+        time::delay_for(Duration::from_millis(100)).await;
+
+        assert_eq!(sent_keys(&requests), vec![vec!["a", "b"], vec!["c"]]);
+        assert!(producer.shutdown().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_records_that_keep_failing_or_are_too_large() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            responding(&[Some("1"), None]),
+            responding(&[None]),
+            responding(&[None]),
+        ]);
+        let requests = dispatcher.recorder();
+        let producer = producer(
+            dispatcher,
+            ProducerOptions::new().with_retry_policy(fast_retries()),
+        );
+
+        producer
+            .put(record("large", &"x".repeat(MAX_RECORD_BYTES)))
+            .await;
+        assert_eq!(
+            producer.take_failed_records()[0].record.partition_key,
+            "large"
+        );
+        producer.put(record("cold", "a")).await;
+        producer.put(record("hot", "b")).await;
+        let failed = producer.shutdown().await;
+
+        assert_eq!(requests.len(), 3);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].record, record("hot", "b"));
+        assert!(failed[0]
+            .reason
+            .starts_with("ProvisionedThroughputExceededException"));
+    }
+
+    #[tokio::test]
+    async fn retries_throttled_requests_as_a_whole() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(400).with_json_body(json!({
+                "__type": "ProvisionedThroughputExceededException",
+                "message": "Rate exceeded",
+            })),
+            responding(&[Some("1"), Some("2")]),
+        ]);
+        let requests = dispatcher.recorder();
+        let producer = producer(
+            dispatcher,
+            ProducerOptions::new().with_retry_policy(fast_retries()),
+        );
+
+        producer.put(record("a", "1")).await;
+        producer.put(record("b", "2")).await;
+
+        assert!(producer.shutdown().await.is_empty());
+        assert_eq!(sent_keys(&requests), vec![vec!["a", "b"]; 2]);
+    }
+}
@@ -41,7 +41,7 @@ impl KinesisClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2926,7 +2926,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, DescribeLimitsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLimitsOutput, _>()
     }
 
@@ -2944,7 +2944,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, DescribeStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStreamOutput, _>()
     }
 
@@ -2962,7 +2962,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, DescribeStreamConsumerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeStreamConsumerOutput, _>()
     }
@@ -2981,7 +2981,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, DescribeStreamSummaryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStreamSummaryOutput, _>()
     }
 
@@ -2999,7 +2999,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, DisableEnhancedMonitoringError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnhancedMonitoringOutput, _>()
     }
 
@@ -3017,7 +3017,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, EnableEnhancedMonitoringError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnhancedMonitoringOutput, _>()
     }
 
@@ -3035,7 +3035,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, GetRecordsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRecordsOutput, _>()
     }
 
@@ -3053,7 +3053,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, GetShardIteratorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetShardIteratorOutput, _>()
     }
 
@@ -3091,7 +3091,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, ListShardsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListShardsOutput, _>()
     }
 
@@ -3109,7 +3109,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, ListStreamConsumersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListStreamConsumersOutput, _>()
     }
 
@@ -3127,7 +3127,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, ListStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
     }
 
@@ -3145,7 +3145,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, ListTagsForStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForStreamOutput, _>()
     }
 
@@ -3180,7 +3180,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, PutRecordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
     }
 
@@ -3198,7 +3198,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, PutRecordsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRecordsOutput, _>()
     }
 
@@ -3216,7 +3216,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, RegisterStreamConsumerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterStreamConsumerOutput, _>()
     }
@@ -3321,7 +3321,7 @@ impl Kinesis for KinesisClient {
             .sign_and_dispatch(request, UpdateShardCountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateShardCountOutput, _>()
     }
 }
@@ -42,7 +42,7 @@ impl KinesisAnalyticsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2757,7 +2757,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()
     }
@@ -2779,7 +2779,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, AddApplicationInputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddApplicationInputResponse, _>()
     }
 
@@ -2806,7 +2806,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()
     }
@@ -2828,7 +2828,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, AddApplicationOutputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationOutputResponse, _>()
     }
@@ -2856,7 +2856,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationReferenceDataSourceResponse, _>()
     }
@@ -2878,7 +2878,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, CreateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationResponse, _>()
     }
 
@@ -2899,7 +2899,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, DeleteApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteApplicationResponse, _>()
     }
 
@@ -2926,7 +2926,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()
     }
@@ -2954,7 +2954,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>()
     }
@@ -2976,7 +2976,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, DeleteApplicationOutputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationOutputResponse, _>()
     }
@@ -3004,7 +3004,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()
     }
@@ -3026,7 +3026,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, DescribeApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeApplicationResponse, _>()
     }
 
@@ -3047,7 +3047,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, DiscoverInputSchemaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DiscoverInputSchemaResponse, _>()
     }
 
@@ -3065,7 +3065,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, ListApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationsResponse, _>()
     }
 
@@ -3086,7 +3086,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -3104,7 +3104,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, StartApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartApplicationResponse, _>()
     }
 
@@ -3122,7 +3122,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, StopApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopApplicationResponse, _>()
     }
 
@@ -3140,7 +3140,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -3158,7 +3158,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -3179,7 +3179,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
             .sign_and_dispatch(request, UpdateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
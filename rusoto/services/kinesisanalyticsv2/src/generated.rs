@@ -42,7 +42,7 @@ impl KinesisAnalyticsV2Client {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4134,7 +4134,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()
     }
@@ -4156,7 +4156,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, AddApplicationInputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddApplicationInputResponse, _>()
     }
 
@@ -4183,7 +4183,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()
     }
@@ -4205,7 +4205,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, AddApplicationOutputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationOutputResponse, _>()
     }
@@ -4233,7 +4233,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationReferenceDataSourceResponse, _>()
     }
@@ -4258,7 +4258,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, AddApplicationVpcConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddApplicationVpcConfigurationResponse, _>()
     }
@@ -4280,7 +4280,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, CreateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationResponse, _>()
     }
 
@@ -4302,7 +4302,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, CreateApplicationSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateApplicationSnapshotResponse, _>()
     }
@@ -4324,7 +4324,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DeleteApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteApplicationResponse, _>()
     }
 
@@ -4351,7 +4351,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()
     }
@@ -4379,7 +4379,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>()
     }
@@ -4401,7 +4401,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DeleteApplicationOutputError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationOutputResponse, _>()
     }
@@ -4429,7 +4429,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()
     }
@@ -4452,7 +4452,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DeleteApplicationSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationSnapshotResponse, _>()
     }
@@ -4480,7 +4480,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteApplicationVpcConfigurationResponse, _>()
     }
@@ -4502,7 +4502,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DescribeApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeApplicationResponse, _>()
     }
 
@@ -4524,7 +4524,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DescribeApplicationSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeApplicationSnapshotResponse, _>()
     }
@@ -4546,7 +4546,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, DiscoverInputSchemaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DiscoverInputSchemaResponse, _>()
     }
 
@@ -4567,7 +4567,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, ListApplicationSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListApplicationSnapshotsResponse, _>()
     }
@@ -4586,7 +4586,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, ListApplicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationsResponse, _>()
     }
 
@@ -4607,7 +4607,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -4625,7 +4625,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, StartApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartApplicationResponse, _>()
     }
 
@@ -4643,7 +4643,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, StopApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopApplicationResponse, _>()
     }
 
@@ -4661,7 +4661,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -4679,7 +4679,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -4700,7 +4700,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
             .sign_and_dispatch(request, UpdateApplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
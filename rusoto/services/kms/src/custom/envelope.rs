@@ -0,0 +1,504 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use bytes::Bytes;
+use openssl::rand::rand_bytes;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+use rusoto_core::RusotoError;
+
+use crate::{DecryptError, DecryptRequest, GenerateDataKeyError, GenerateDataKeyRequest, Kms};
+
+/// The version of the serialized form written by `EnvelopeCiphertext::to_bytes`.
+const FORMAT_VERSION: u8 = 1;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const DATA_KEY_LEN: usize = 32;
+
+/// The algorithm data is encrypted with locally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnvelopeAlgorithm {
+    /// AES-256 in Galois/Counter Mode, with a 96-bit nonce and a 128-bit tag.
+    Aes256Gcm,
+}
+
+impl EnvelopeAlgorithm {
+    fn id(self) -> u8 {
+        match self {
+            EnvelopeAlgorithm::Aes256Gcm => 1,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<EnvelopeAlgorithm> {
+        match id {
+            1 => Some(EnvelopeAlgorithm::Aes256Gcm),
+            _ => None,
+        }
+    }
+}
+
+/// Data encrypted by an `EnvelopeEncryptor`, with everything but the KMS key needed to
+/// decrypt it again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnvelopeCiphertext {
+    /// The algorithm the data is encrypted with.
+    pub algorithm: EnvelopeAlgorithm,
+    /// The data key, encrypted by KMS. It identifies the KMS key it's encrypted under.
+    pub encrypted_key: Bytes,
+    /// The nonce the data is encrypted with.
+    pub nonce: Vec<u8>,
+    /// The encrypted data, followed by the 16 byte authentication tag.
+    pub ciphertext: Vec<u8>,
+}
+
+impl EnvelopeCiphertext {
+    /// Serializes the envelope to a stable binary form, read back by `from_bytes`:
+    ///
+    /// - 1 byte format version, currently 1.
+    /// - 1 byte algorithm, 1 for AES-256-GCM.
+    /// - 2 bytes big endian length of the encrypted data key, then the key itself.
+    /// - 12 bytes nonce.
+    /// - The rest is the encrypted data and its authentication tag.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.header();
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Parses an envelope serialized by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<EnvelopeCiphertext, EnvelopeError> {
+        let malformed = |reason: &str| EnvelopeError::Malformed(reason.to_owned());
+        if bytes.len() < 4 {
+            return Err(malformed("too short for a header"));
+        }
+        if bytes[0] != FORMAT_VERSION {
+            return Err(EnvelopeError::Malformed(format!(
+                "unsupported format version {}",
+                bytes[0]
+            )));
+        }
+        let algorithm = EnvelopeAlgorithm::from_id(bytes[1]).ok_or_else(|| {
+            EnvelopeError::Malformed(format!("unsupported algorithm {}", bytes[1]))
+        })?;
+        let key_len = usize::from(u16::from_be_bytes([bytes[2], bytes[3]]));
+        let rest = &bytes[4..];
+        if rest.len() < key_len + NONCE_LEN + TAG_LEN {
+            return Err(malformed("truncated"));
+        }
+        let (encrypted_key, rest) = rest.split_at(key_len);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        Ok(EnvelopeCiphertext {
+            algorithm,
+            encrypted_key: Bytes::copy_from_slice(encrypted_key),
+            nonce: nonce.to_vec(),
+            ciphertext: ciphertext.to_vec(),
+        })
+    }
+
+    /// The serialized fields before the nonce, which are authenticated along with the data.
+    fn header(&self) -> Vec<u8> {
+        // Data keys are encrypted by KMS into blobs of a few hundred bytes at most.
+        let key_len = u16::try_from(self.encrypted_key.len()).unwrap_or(u16::MAX);
+        let mut header = Vec::with_capacity(4 + self.encrypted_key.len());
+        header.push(FORMAT_VERSION);
+        header.push(self.algorithm.id());
+        header.extend_from_slice(&key_len.to_be_bytes());
+        header.extend_from_slice(&self.encrypted_key);
+        header
+    }
+}
+
+/// Why an `EnvelopeEncryptor` failed to encrypt or decrypt.
+#[derive(Debug, PartialEq)]
+pub enum EnvelopeError {
+    /// KMS failed to generate a data key.
+    GenerateDataKey(Box<RusotoError<GenerateDataKeyError>>),
+    /// KMS failed to decrypt the data key, e.g. because the encryption context doesn't match.
+    Decrypt(Box<RusotoError<DecryptError>>),
+    /// KMS returned a data key that isn't 256 bits long.
+    InvalidDataKey(usize),
+    /// The serialized envelope couldn't be parsed.
+    Malformed(String),
+    /// Encrypting or decrypting the data failed, e.g. because it was tampered with.
+    Crypto(String),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EnvelopeError::GenerateDataKey(ref err) => {
+                write!(f, "Couldn't generate a data key: {}", err)
+            }
+            EnvelopeError::Decrypt(ref err) => write!(f, "Couldn't decrypt the data key: {}", err),
+            EnvelopeError::InvalidDataKey(len) => {
+                write!(f, "Expected a 32 byte data key from KMS, got {} bytes", len)
+            }
+            EnvelopeError::Malformed(ref reason) => write!(f, "Malformed envelope: {}", reason),
+            EnvelopeError::Crypto(ref reason) => {
+                write!(f, "Couldn't encrypt or decrypt the data: {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for EnvelopeError {}
+
+/// Encrypts data locally with AES-256-GCM under data keys generated by KMS.
+///
+/// `encrypt` asks KMS for a new data key, encrypts the data with it and keeps the data key
+/// only as encrypted by KMS in the returned `EnvelopeCiphertext`. `decrypt` has KMS decrypt
+/// the data key again, which needs no key id, so envelopes stay readable after the KMS key is
+/// rotated. The encryption context must be the same for both.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use rusoto_core::Region;
+/// use rusoto_kms::{EnvelopeCiphertext, EnvelopeEncryptor, KmsClient};
+///
+/// # async fn example() -> Result<(), rusoto_kms::EnvelopeError> {
+/// let encryptor = EnvelopeEncryptor::new(KmsClient::new(Region::UsEast1));
+/// let mut context = HashMap::new();
+/// context.insert("tenant".to_owned(), "acme".to_owned());
+///
+/// let envelope = encryptor.encrypt("alias/data", b"secret", &context).await?;
+/// let stored = envelope.to_bytes();
+///
+/// let envelope = EnvelopeCiphertext::from_bytes(&stored)?;
+/// assert_eq!(encryptor.decrypt(&envelope, &context).await?, b"secret");
+/// # Ok(())
+/// # }
+/// ```
+pub struct EnvelopeEncryptor<K> {
+    kms: K,
+}
+
+impl<K: Kms> EnvelopeEncryptor<K> {
+    /// Creates an encryptor calling `kms`.
+    pub fn new(kms: K) -> Self {
+        EnvelopeEncryptor { kms }
+    }
+
+    /// Encrypts `plaintext` under a new data key generated with the KMS key `key_id`, bound
+    /// to `encryption_context`.
+    pub async fn encrypt(
+        &self,
+        key_id: &str,
+        plaintext: &[u8],
+        encryption_context: &HashMap<String, String>,
+    ) -> Result<EnvelopeCiphertext, EnvelopeError> {
+        let data_key = self
+            .kms
+            .generate_data_key(GenerateDataKeyRequest {
+                key_id: key_id.to_owned(),
+                key_spec: Some("AES_256".to_owned()),
+                encryption_context: context(encryption_context),
+                ..Default::default()
+            })
+            .await
+            .map_err(|err| EnvelopeError::GenerateDataKey(Box::new(err)))?;
+        let key = data_key.plaintext.unwrap_or_default();
+        check_data_key(&key)?;
+
+        let mut envelope = EnvelopeCiphertext {
+            algorithm: EnvelopeAlgorithm::Aes256Gcm,
+            encrypted_key: data_key.ciphertext_blob.unwrap_or_default(),
+            nonce: vec![0; NONCE_LEN],
+            ciphertext: Vec::new(),
+        };
+        if envelope.encrypted_key.len() > usize::from(u16::MAX) {
+            return Err(EnvelopeError::Malformed(
+                "encrypted data key too long".to_owned(),
+            ));
+        }
+        rand_bytes(&mut envelope.nonce).map_err(crypto_error)?;
+        let mut tag = [0; TAG_LEN];
+        envelope.ciphertext = encrypt_aead(
+            Cipher::aes_256_gcm(),
+            &key,
+            Some(&envelope.nonce),
+            &envelope.header(),
+            plaintext,
+            &mut tag,
+        )
+        .map_err(crypto_error)?;
+        envelope.ciphertext.extend_from_slice(&tag);
+        Ok(envelope)
+    }
+
+    /// Decrypts `envelope`, having KMS decrypt its data key with `encryption_context`.
+    pub async fn decrypt(
+        &self,
+        envelope: &EnvelopeCiphertext,
+        encryption_context: &HashMap<String, String>,
+    ) -> Result<Vec<u8>, EnvelopeError> {
+        if envelope.nonce.len() != NONCE_LEN || envelope.ciphertext.len() < TAG_LEN {
+            return Err(EnvelopeError::Malformed(
+                "invalid nonce or ciphertext length".to_owned(),
+            ));
+        }
+        let data_key = self
+            .kms
+            .decrypt(DecryptRequest {
+                ciphertext_blob: envelope.encrypted_key.clone(),
+                encryption_context: context(encryption_context),
+                ..Default::default()
+            })
+            .await
+            .map_err(|err| EnvelopeError::Decrypt(Box::new(err)))?;
+        let key = data_key.plaintext.unwrap_or_default();
+        check_data_key(&key)?;
+
+        let (ciphertext, tag) = envelope
+            .ciphertext
+            .split_at(envelope.ciphertext.len() - TAG_LEN);
+        decrypt_aead(
+            Cipher::aes_256_gcm(),
+            &key,
+            Some(&envelope.nonce),
+            &envelope.header(),
+            ciphertext,
+            tag,
+        )
+        .map_err(crypto_error)
+    }
+}
+
+fn context(encryption_context: &HashMap<String, String>) -> Option<HashMap<String, String>> {
+    if encryption_context.is_empty() {
+        None
+    } else {
+        Some(encryption_context.clone())
+    }
+}
+
+fn check_data_key(key: &[u8]) -> Result<(), EnvelopeError> {
+    if key.len() == DATA_KEY_LEN {
+        Ok(())
+    } else {
+        Err(EnvelopeError::InvalidDataKey(key.len()))
+    }
+}
+
+fn crypto_error(err: openssl::error::ErrorStack) -> EnvelopeError {
+    EnvelopeError::Crypto(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KmsClient;
+    use rusoto_core::request::{DispatchSignedRequest, DispatchSignedRequestFuture};
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// A KMS "wrapping" data keys by recording them with the key version and context they
+    /// were generated with. The blob is the index of the record.
+    #[derive(Clone, Default)]
+    struct FakeKms(Arc<Mutex<FakeKmsState>>);
+
+    #[derive(Default)]
+    struct FakeKmsState {
+        key_version: u32,
+        data_keys: Vec<(String, Value, Vec<u8>)>,
+        decrypt_requests: Vec<Value>,
+    }
+
+    impl FakeKms {
+        fn rotate(&self) {
+            self.0.lock().unwrap().key_version += 1;
+        }
+    }
+
+    impl DispatchSignedRequest for FakeKms {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let body: Value = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref body)) => {
+                    serde_json::from_slice(body).unwrap()
+                }
+                _ => panic!("expected a buffered request body"),
+            };
+            let target = request.headers["x-amz-target"][0].clone();
+            let mut state = self.0.lock().unwrap();
+            let context = body
+                .get("EncryptionContext")
+                .cloned()
+                .unwrap_or(Value::Null);
+            let (status, response) = match String::from_utf8(target).unwrap().as_str() {
+                "TrentService.GenerateDataKey" => {
+                    assert_eq!(body["KeySpec"], "AES_256");
+                    let key_id =
+                        format!("{}#v{}", body["KeyId"].as_str().unwrap(), state.key_version);
+                    let data_key: Vec<u8> = (0..32)
+                        .map(|i| i * 7 + state.data_keys.len() as u8)
+                        .collect();
+                    state
+                        .data_keys
+                        .push((key_id.clone(), context, data_key.clone()));
+                    let blob = (state.data_keys.len() - 1).to_string();
+                    (
+                        200,
+                        json!({
+                            "KeyId": key_id,
+                            "Plaintext": base64::encode(&data_key),
+                            "CiphertextBlob": base64::encode(blob),
+                        }),
+                    )
+                }
+                "TrentService.Decrypt" => {
+                    state.decrypt_requests.push(body.clone());
+                    let blob = base64::decode(body["CiphertextBlob"].as_str().unwrap()).unwrap();
+                    let index: usize = String::from_utf8(blob).unwrap().parse().unwrap();
+                    let (ref key_id, ref expected_context, ref data_key) = state.data_keys[index];
+                    if *expected_context == context {
+                        (
+                            200,
+                            json!({ "KeyId": key_id, "Plaintext": base64::encode(data_key) }),
+                        )
+                    } else {
+                        (
+                            400,
+                            json!({ "__type": "InvalidCiphertextException", "message": "invalid" }),
+                        )
+                    }
+                }
+                target => panic!("unexpected {}", target),
+            };
+            MockRequestDispatcher::with_status(status)
+                .with_body(&response.to_string())
+                .dispatch(request, timeout)
+        }
+    }
+
+    fn encryptor(kms: &FakeKms) -> EnvelopeEncryptor<KmsClient> {
+        EnvelopeEncryptor::new(KmsClient::new_with(
+            kms.clone(),
+            MockCredentialsProvider,
+            Region::UsEast1,
+        ))
+    }
+
+    fn tenant(name: &str) -> HashMap<String, String> {
+        let mut context = HashMap::new();
+        context.insert("tenant".to_owned(), name.to_owned());
+        context
+    }
+
+    #[tokio::test]
+    async fn round_trips_through_the_serialized_form() {
+        let kms = FakeKms::default();
+        let encryptor = encryptor(&kms);
+
+        let envelope = encryptor
+            .encrypt("alias/data", b"attack at dawn", &tenant("acme"))
+            .await
+            .unwrap();
+        assert_eq!(envelope.algorithm, EnvelopeAlgorithm::Aes256Gcm);
+        assert_eq!(envelope.ciphertext.len(), b"attack at dawn".len() + TAG_LEN);
+        let bytes = envelope.to_bytes();
+        assert_eq!(&bytes[..4], &[1, 1, 0, 1]);
+        let parsed = EnvelopeCiphertext::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, envelope);
+
+        let plaintext = encryptor.decrypt(&parsed, &tenant("acme")).await.unwrap();
+        assert_eq!(plaintext, b"attack at dawn");
+    }
+
+    #[tokio::test]
+    async fn fails_with_another_encryption_context() {
+        let kms = FakeKms::default();
+        let encryptor = encryptor(&kms);
+        let envelope = encryptor
+            .encrypt("alias/data", b"attack at dawn", &tenant("acme"))
+            .await
+            .unwrap();
+
+        for context in [tenant("other"), HashMap::new()].iter() {
+            match encryptor.decrypt(&envelope, context).await {
+                Err(EnvelopeError::Decrypt(err)) => assert!(
+                    matches!(
+                        *err,
+                        RusotoError::Service(DecryptError::InvalidCiphertext(_))
+                    ),
+                    "{:?}",
+                    err
+                ),
+                other => panic!("decrypted with {:?}: {:?}", context, other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn decrypts_without_a_key_id_after_rotation() {
+        let kms = FakeKms::default();
+        let encryptor = encryptor(&kms);
+        let old = encryptor
+            .encrypt("alias/data", b"old", &HashMap::new())
+            .await
+            .unwrap();
+        kms.rotate();
+        let new = encryptor
+            .encrypt("alias/data", b"new", &HashMap::new())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            encryptor.decrypt(&old, &HashMap::new()).await.unwrap(),
+            b"old"
+        );
+        assert_eq!(
+            encryptor.decrypt(&new, &HashMap::new()).await.unwrap(),
+            b"new"
+        );
+        let state = kms.0.lock().unwrap();
+        assert_eq!(state.data_keys[0].0, "alias/data#v0");
+        assert_eq!(state.data_keys[1].0, "alias/data#v1");
+        assert!(state
+            .decrypt_requests
+            .iter()
+            .all(|request| request.get("KeyId").is_none()));
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_and_malformed_envelopes() {
+        let kms = FakeKms::default();
+        let encryptor = encryptor(&kms);
+        let envelope = encryptor
+            .encrypt("alias/data", b"attack at dawn", &HashMap::new())
+            .await
+            .unwrap();
+
+        let mut tampered = envelope.clone();
+        tampered.ciphertext[0] ^= 1;
+        match encryptor.decrypt(&tampered, &HashMap::new()).await {
+            Err(EnvelopeError::Crypto(_)) => {}
+            other => panic!("decrypted a tampered envelope: {:?}", other),
+        }
+
+        let bytes = envelope.to_bytes();
+        assert!(matches!(
+            EnvelopeCiphertext::from_bytes(&bytes[..bytes.len() - 20]),
+            Err(EnvelopeError::Malformed(_))
+        ));
+        let mut bytes = bytes;
+        bytes[0] = 2;
+        assert_eq!(
+            EnvelopeCiphertext::from_bytes(&bytes),
+            Err(EnvelopeError::Malformed(
+                "unsupported format version 2".to_owned()
+            ))
+        );
+    }
+}
@@ -1 +1,4 @@
-
+#[cfg(feature = "envelope-encryption")]
+mod envelope;
+#[cfg(feature = "envelope-encryption")]
+pub use self::envelope::{EnvelopeAlgorithm, EnvelopeCiphertext, EnvelopeEncryptor, EnvelopeError};
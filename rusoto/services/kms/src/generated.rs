@@ -41,7 +41,7 @@ impl KmsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4909,7 +4909,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, CancelKeyDeletionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelKeyDeletionResponse, _>()
     }
 
@@ -4927,7 +4927,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ConnectCustomKeyStoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ConnectCustomKeyStoreResponse, _>()
     }
@@ -4963,7 +4963,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, CreateCustomKeyStoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCustomKeyStoreResponse, _>()
     }
@@ -4982,7 +4982,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, CreateGrantError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGrantResponse, _>()
     }
 
@@ -5000,7 +5000,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, CreateKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateKeyResponse, _>()
     }
 
@@ -5018,7 +5018,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, DecryptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DecryptResponse, _>()
     }
 
@@ -5053,7 +5053,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, DeleteCustomKeyStoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteCustomKeyStoreResponse, _>()
     }
@@ -5089,7 +5089,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, DescribeCustomKeyStoresError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCustomKeyStoresResponse, _>()
     }
@@ -5108,7 +5108,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, DescribeKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeKeyResponse, _>()
     }
 
@@ -5160,7 +5160,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, DisconnectCustomKeyStoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisconnectCustomKeyStoreResponse, _>()
     }
@@ -5210,7 +5210,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, EncryptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EncryptResponse, _>()
     }
 
@@ -5228,7 +5228,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GenerateDataKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateDataKeyResponse, _>()
     }
 
@@ -5246,7 +5246,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GenerateDataKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateDataKeyPairResponse, _>()
     }
 
@@ -5273,7 +5273,7 @@ impl Kms for KmsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GenerateDataKeyPairWithoutPlaintextResponse, _>()
     }
@@ -5298,7 +5298,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GenerateDataKeyWithoutPlaintextError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GenerateDataKeyWithoutPlaintextResponse, _>()
     }
@@ -5317,7 +5317,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GenerateRandomError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateRandomResponse, _>()
     }
 
@@ -5335,7 +5335,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GetKeyPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPolicyResponse, _>()
     }
 
@@ -5353,7 +5353,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GetKeyRotationStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetKeyRotationStatusResponse, _>()
     }
@@ -5372,7 +5372,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GetParametersForImportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetParametersForImportResponse, _>()
     }
@@ -5391,7 +5391,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, GetPublicKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPublicKeyResponse, _>()
     }
 
@@ -5409,7 +5409,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ImportKeyMaterialError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportKeyMaterialResponse, _>()
     }
 
@@ -5427,7 +5427,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListAliasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAliasesResponse, _>()
     }
 
@@ -5445,7 +5445,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListGrantsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGrantsResponse, _>()
     }
 
@@ -5463,7 +5463,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListKeyPoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListKeyPoliciesResponse, _>()
     }
 
@@ -5481,7 +5481,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListKeysResponse, _>()
     }
 
@@ -5499,7 +5499,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListResourceTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourceTagsResponse, _>()
     }
 
@@ -5517,7 +5517,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ListRetirableGrantsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGrantsResponse, _>()
     }
 
@@ -5552,7 +5552,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ReEncryptError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ReEncryptResponse, _>()
     }
 
@@ -5604,7 +5604,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, ScheduleKeyDeletionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ScheduleKeyDeletionResponse, _>()
     }
 
@@ -5619,7 +5619,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, SignError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SignResponse, _>()
     }
 
@@ -5688,7 +5688,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, UpdateCustomKeyStoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateCustomKeyStoreResponse, _>()
     }
@@ -5724,7 +5724,7 @@ impl Kms for KmsClient {
             .sign_and_dispatch(request, VerifyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyResponse, _>()
     }
 }
@@ -42,7 +42,7 @@ impl LakeFormationClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1330,7 +1330,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, BatchGrantPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchGrantPermissionsResponse, _>()
     }
@@ -1349,7 +1349,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, BatchRevokePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchRevokePermissionsResponse, _>()
     }
@@ -1368,7 +1368,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, DeregisterResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeregisterResourceResponse, _>()
     }
 
@@ -1386,7 +1386,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, DescribeResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeResourceResponse, _>()
     }
 
@@ -1404,7 +1404,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, GetDataLakeSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDataLakeSettingsResponse, _>()
     }
 
@@ -1428,7 +1428,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, GetEffectivePermissionsForPathError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetEffectivePermissionsForPathResponse, _>()
     }
@@ -1447,7 +1447,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, GrantPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GrantPermissionsResponse, _>()
     }
 
@@ -1465,7 +1465,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, ListPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPermissionsResponse, _>()
     }
 
@@ -1483,7 +1483,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, ListResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourcesResponse, _>()
     }
 
@@ -1501,7 +1501,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, PutDataLakeSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutDataLakeSettingsResponse, _>()
     }
 
@@ -1519,7 +1519,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, RegisterResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterResourceResponse, _>()
     }
 
@@ -1537,7 +1537,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, RevokePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RevokePermissionsResponse, _>()
     }
 
@@ -1555,7 +1555,7 @@ impl LakeFormation for LakeFormationClient {
             .sign_and_dispatch(request, UpdateResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
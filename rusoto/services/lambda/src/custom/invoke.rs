@@ -0,0 +1,347 @@
+use std::error::Error;
+use std::fmt;
+
+use bytes::Bytes;
+use rusoto_core::RusotoError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_derive::Deserialize;
+
+use crate::generated::{InvocationRequest, InvokeError, Lambda, LambdaClient};
+
+/// Options of `LambdaClient::invoke_json`, besides the function and its payload.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InvokeJsonOptions {
+    qualifier: Option<String>,
+    client_context: Option<String>,
+    log_tail: bool,
+}
+
+impl InvokeJsonOptions {
+    /// Options invoking the unqualified function, without client context or logs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Invokes the version or alias `qualifier` of the function.
+    pub fn with_qualifier<S: Into<String>>(mut self, qualifier: S) -> Self {
+        self.qualifier = Some(qualifier.into());
+        self
+    }
+
+    /// Passes `client_context`, base64-encoded JSON, to the function.
+    pub fn with_client_context<S: Into<String>>(mut self, client_context: S) -> Self {
+        self.client_context = Some(client_context.into());
+        self
+    }
+
+    /// Requests the last 4 KB of the function's log, returned as `log_tail`.
+    pub fn with_log_tail(mut self) -> Self {
+        self.log_tail = true;
+        self
+    }
+}
+
+/// The response of a function invoked with `LambdaClient::invoke_json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvokeJsonOutput<T> {
+    /// The response the function returned.
+    pub payload: T,
+    /// The version of the function that was invoked.
+    pub executed_version: Option<String>,
+    /// The end of the function's log, if requested with `InvokeJsonOptions::with_log_tail`.
+    pub log_tail: Option<String>,
+}
+
+/// The error a function returned, as reported by Lambda.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctionError {
+    /// The `errorMessage` of the error payload, or the whole payload if it isn't a JSON
+    /// error object.
+    pub error_message: Option<String>,
+    /// The `errorType` of the error payload.
+    pub error_type: Option<String>,
+    /// The `stackTrace` of the error payload.
+    pub stack_trace: Vec<String>,
+    /// The error payload as returned.
+    pub payload: Bytes,
+    /// The end of the function's log, if requested with `InvokeJsonOptions::with_log_tail`.
+    pub log_tail: Option<String>,
+}
+
+impl fmt::Display for FunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.error_type, &self.error_message) {
+            (Some(error_type), Some(message)) => write!(f, "{}: {}", error_type, message),
+            (Some(error_type), None) => f.write_str(error_type),
+            (None, Some(message)) => f.write_str(message),
+            (None, None) => f.write_str("function failed without an error message"),
+        }
+    }
+}
+
+/// The error payload of a function error.
+#[derive(Deserialize)]
+struct ErrorPayload {
+    #[serde(rename = "errorMessage")]
+    error_message: Option<String>,
+    #[serde(rename = "errorType")]
+    error_type: Option<String>,
+    #[serde(rename = "stackTrace", default)]
+    stack_trace: Vec<String>,
+}
+
+impl FunctionError {
+    fn parse(payload: Bytes, log_tail: Option<String>) -> FunctionError {
+        match serde_json::from_slice::<ErrorPayload>(&payload) {
+            Ok(error) => FunctionError {
+                error_message: error.error_message,
+                error_type: error.error_type,
+                stack_trace: error.stack_trace,
+                payload,
+                log_tail,
+            },
+            Err(_) => FunctionError {
+                error_message: Some(String::from_utf8_lossy(&payload).into_owned())
+                    .filter(|message| !message.is_empty()),
+                payload,
+                log_tail,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Why `LambdaClient::invoke_json` failed.
+#[derive(Debug)]
+pub enum LambdaInvokeError {
+    /// The `invoke` call failed, e.g. because the function doesn't exist or the request
+    /// couldn't be sent.
+    Invoke(Box<RusotoError<InvokeError>>),
+    /// The request payload couldn't be serialized, or the response payload couldn't be
+    /// deserialized.
+    Json(serde_json::Error),
+    /// The function returned an error it handled, its `X-Amz-Function-Error` being `Handled`.
+    Handled(FunctionError),
+    /// The function failed with an error it didn't handle, or Lambda stopped it, e.g. on a
+    /// timeout.
+    Unhandled(FunctionError),
+}
+
+impl fmt::Display for LambdaInvokeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LambdaInvokeError::Invoke(ref err) => write!(f, "Failed to invoke function: {}", err),
+            LambdaInvokeError::Json(ref err) => write!(f, "Invalid JSON payload: {}", err),
+            LambdaInvokeError::Handled(ref err) => write!(f, "Function returned an error: {}", err),
+            LambdaInvokeError::Unhandled(ref err) => write!(f, "Function failed: {}", err),
+        }
+    }
+}
+
+impl Error for LambdaInvokeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            LambdaInvokeError::Invoke(ref err) => Some(&**err),
+            LambdaInvokeError::Json(ref err) => Some(err),
+            LambdaInvokeError::Handled(_) | LambdaInvokeError::Unhandled(_) => None,
+        }
+    }
+}
+
+impl From<RusotoError<InvokeError>> for LambdaInvokeError {
+    fn from(err: RusotoError<InvokeError>) -> Self {
+        LambdaInvokeError::Invoke(Box::new(err))
+    }
+}
+
+impl From<serde_json::Error> for LambdaInvokeError {
+    fn from(err: serde_json::Error) -> Self {
+        LambdaInvokeError::Json(err)
+    }
+}
+
+impl LambdaClient {
+    /// Invokes the function `function_name` synchronously with `payload` serialized as JSON,
+    /// and deserializes the JSON response.
+    ///
+    /// Unlike `invoke`, errors returned by the function fail the call, with
+    /// `LambdaInvokeError::Handled` or `LambdaInvokeError::Unhandled` holding the parsed error
+    /// payload. The log tail requested with `InvokeJsonOptions::with_log_tail` is returned
+    /// decoded, or as sent if it isn't valid base64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_lambda::{InvokeJsonOptions, LambdaClient};
+    /// use serde_json::{json, Value};
+    ///
+    /// # async fn example() -> Result<(), rusoto_lambda::LambdaInvokeError> {
+    /// let lambda = LambdaClient::new(Region::UsEast1);
+    /// let output = lambda
+    ///     .invoke_json::<_, Value>(
+    ///         "greeter",
+    ///         &json!({"name": "world"}),
+    ///         InvokeJsonOptions::new().with_log_tail(),
+    ///     )
+    ///     .await?;
+    /// println!("{} logged {:?}", output.payload, output.log_tail);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn invoke_json<Req, Resp>(
+        &self,
+        function_name: &str,
+        payload: &Req,
+        options: InvokeJsonOptions,
+    ) -> Result<InvokeJsonOutput<Resp>, LambdaInvokeError>
+    where
+        Req: Serialize + ?Sized,
+        Resp: DeserializeOwned,
+    {
+        let request = InvocationRequest {
+            function_name: function_name.to_owned(),
+            payload: Some(serde_json::to_vec(payload)?.into()),
+            qualifier: options.qualifier,
+            client_context: options.client_context,
+            log_type: if options.log_tail {
+                Some("Tail".to_owned())
+            } else {
+                None
+            },
+            invocation_type: Some("RequestResponse".to_owned()),
+        };
+        let response = self.invoke(request).await?;
+
+        let log_tail = response.log_result.map(|log| decode_log(&log));
+        let payload = response.payload.unwrap_or_default();
+        match response.function_error.as_deref() {
+            None => Ok(InvokeJsonOutput {
+                payload: serde_json::from_slice(&payload)?,
+                executed_version: response.executed_version,
+                log_tail,
+            }),
+            Some("Handled") => Err(LambdaInvokeError::Handled(FunctionError::parse(
+                payload, log_tail,
+            ))),
+            Some(_) => Err(LambdaInvokeError::Unhandled(FunctionError::parse(
+                payload, log_tail,
+            ))),
+        }
+    }
+}
+
+/// Decodes the base64 log tail Lambda returns.
+fn decode_log(log: &str) -> String {
+    match base64::decode(log) {
+        Ok(decoded) => String::from_utf8_lossy(&decoded).into_owned(),
+        Err(_) => log.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use serde_json::{json, Value};
+
+    fn client(dispatcher: MockRequestDispatcher) -> LambdaClient {
+        LambdaClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+    }
+
+    #[tokio::test]
+    async fn invoke_json_round_trips_payloads_and_decodes_logs() {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body(r#"{"greeting":"hello, world"}"#)
+            .with_header("X-Amz-Executed-Version", "3")
+            .with_header("X-Amz-Log-Result", &base64::encode("START RequestId: 1\n"))
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.path, "/2015-03-31/functions/greeter/invocations");
+                assert_eq!(request.headers["x-amz-log-type"], vec![b"Tail".to_vec()]);
+                match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref body)) => {
+                        assert_eq!(&body[..], br#"{"name":"world"}"#)
+                    }
+                    _ => panic!("expected a buffered payload"),
+                }
+            });
+
+        let output = client(dispatcher)
+            .invoke_json::<_, Value>(
+                "greeter",
+                &json!({"name": "world"}),
+                InvokeJsonOptions::new().with_log_tail(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(output.payload, json!({"greeting": "hello, world"}));
+        assert_eq!(output.executed_version.as_deref(), Some("3"));
+        assert_eq!(output.log_tail.as_deref(), Some("START RequestId: 1\n"));
+    }
+
+    #[tokio::test]
+    async fn invoke_json_fails_on_function_errors() {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body(
+                r#"{"errorMessage":"name is missing","errorType":"ValueError",
+                    "stackTrace":["  File \"handler.py\", line 3"]}"#,
+            )
+            .with_header("X-Amz-Function-Error", "Handled");
+        match client(dispatcher)
+            .invoke_json::<_, Value>("greeter", &json!({}), InvokeJsonOptions::new())
+            .await
+        {
+            Err(LambdaInvokeError::Handled(err)) => {
+                assert_eq!(err.error_type.as_deref(), Some("ValueError"));
+                assert_eq!(err.error_message.as_deref(), Some("name is missing"));
+                assert_eq!(err.stack_trace, vec!["  File \"handler.py\", line 3"]);
+                assert_eq!(err.to_string(), "ValueError: name is missing");
+            }
+            other => panic!("expected a handled error, got {:?}", other),
+        }
+
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body(r#"{"errorMessage":"Task timed out after 3.00 seconds"}"#)
+            .with_header("X-Amz-Function-Error", "Unhandled");
+        match client(dispatcher)
+            .invoke_json::<_, Value>("greeter", &json!({}), InvokeJsonOptions::new())
+            .await
+        {
+            Err(LambdaInvokeError::Unhandled(err)) => {
+                assert_eq!(err.error_type, None);
+                assert_eq!(
+                    err.error_message.as_deref(),
+                    Some("Task timed out after 3.00 seconds")
+                );
+            }
+            other => panic!("expected an unhandled error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn invoke_json_reports_invoke_and_payload_errors() {
+        let dispatcher = MockRequestDispatcher::with_status(404)
+            .with_body(r#"{"Type":"User","message":"Function not found"}"#)
+            .with_header("x-amzn-ErrorType", "ResourceNotFoundException");
+        let err = client(dispatcher)
+            .invoke_json::<_, Value>("missing", &json!({}), InvokeJsonOptions::new())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, LambdaInvokeError::Invoke(ref err) if matches!(**err, RusotoError::Service(InvokeError::ResourceNotFound(_)))),
+            "{:?}",
+            err
+        );
+
+        let dispatcher = MockRequestDispatcher::with_status(200).with_body("not json");
+        let err = client(dispatcher)
+            .invoke_json::<_, Value>("greeter", &json!({}), InvokeJsonOptions::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LambdaInvokeError::Json(_)), "{:?}", err);
+    }
+}
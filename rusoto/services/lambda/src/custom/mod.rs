@@ -1,2 +1,6 @@
+mod invoke;
+
+pub use self::invoke::{FunctionError, InvokeJsonOptions, InvokeJsonOutput, LambdaInvokeError};
+
 #[cfg(test)]
 mod custom_tests;
@@ -5288,6 +5288,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5305,13 +5306,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddLayerVersionPermissionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddLayerVersionPermissionError::from_response(response))
         }
     }
@@ -5328,6 +5329,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5345,13 +5347,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddPermissionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddPermissionError::from_response(response))
         }
     }
@@ -5368,6 +5370,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5379,13 +5382,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AliasConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAliasError::from_response(response))
         }
     }
@@ -5399,6 +5402,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5410,13 +5414,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EventSourceMappingConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateEventSourceMappingError::from_response(response))
         }
     }
@@ -5430,6 +5434,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5441,13 +5446,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFunctionError::from_response(response))
         }
     }
@@ -5465,6 +5470,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5473,12 +5479,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAliasError::from_response(response))
         }
     }
@@ -5495,6 +5501,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5503,13 +5510,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EventSourceMappingConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteEventSourceMappingError::from_response(response))
         }
     }
@@ -5526,6 +5533,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5540,12 +5548,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFunctionError::from_response(response))
         }
     }
@@ -5562,6 +5570,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5570,12 +5579,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFunctionConcurrencyError::from_response(response))
         }
     }
@@ -5592,6 +5601,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5606,12 +5616,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFunctionEventInvokeConfigError::from_response(
                 response,
             ))
@@ -5631,6 +5641,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5639,12 +5650,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteLayerVersionError::from_response(response))
         }
     }
@@ -5661,6 +5672,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5673,12 +5685,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteProvisionedConcurrencyConfigError::from_response(
                 response,
             ))
@@ -5693,6 +5705,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2016-08-19/account-settings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5701,13 +5714,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAccountSettingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAccountSettingsError::from_response(response))
         }
     }
@@ -5725,6 +5738,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5733,13 +5747,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AliasConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAliasError::from_response(response))
         }
     }
@@ -5756,6 +5770,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5764,13 +5779,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EventSourceMappingConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetEventSourceMappingError::from_response(response))
         }
     }
@@ -5787,6 +5802,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5801,13 +5817,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionError::from_response(response))
         }
     }
@@ -5824,6 +5840,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5832,13 +5849,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFunctionConcurrencyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionConcurrencyError::from_response(response))
         }
     }
@@ -5855,6 +5872,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5869,13 +5887,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionConfigurationError::from_response(response))
         }
     }
@@ -5892,6 +5910,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5906,13 +5925,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionEventInvokeConfig, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFunctionEventInvokeConfigError::from_response(response))
         }
     }
@@ -5930,6 +5949,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5938,13 +5958,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLayerVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLayerVersionError::from_response(response))
         }
     }
@@ -5958,6 +5978,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionByArn");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5971,13 +5992,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLayerVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLayerVersionByArnError::from_response(response))
         }
     }
@@ -5995,6 +6016,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6003,13 +6025,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLayerVersionPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLayerVersionPolicyError::from_response(response))
         }
     }
@@ -6026,6 +6048,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6040,13 +6063,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPolicyError::from_response(response))
         }
     }
@@ -6066,6 +6089,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6078,13 +6102,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetProvisionedConcurrencyConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetProvisionedConcurrencyConfigError::from_response(
                 response,
             ))
@@ -6103,6 +6127,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("Invoke");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = if let Some(ref payload) = input.payload {
@@ -6126,7 +6151,7 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = InvocationResponse::default();
             result.payload = Some(response.body);
@@ -6137,7 +6162,7 @@ impl Lambda for LambdaClient {
             result.status_code = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InvokeError::from_response(response))
         }
     }
@@ -6154,6 +6179,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("InvokeAsync");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.invoke_args.to_owned());
@@ -6165,14 +6191,14 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InvokeAsyncResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InvokeAsyncError::from_response(response))
         }
     }
@@ -6189,6 +6215,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6209,13 +6236,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAliasesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAliasesError::from_response(response))
         }
     }
@@ -6229,6 +6256,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListEventSourceMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6252,13 +6280,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEventSourceMappingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEventSourceMappingsError::from_response(response))
         }
     }
@@ -6278,6 +6306,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctionEventInvokeConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6295,13 +6324,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFunctionEventInvokeConfigsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFunctionEventInvokeConfigsError::from_response(response))
         }
     }
@@ -6315,6 +6344,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6338,13 +6368,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFunctionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFunctionsError::from_response(response))
         }
     }
@@ -6361,6 +6391,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayerVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6381,13 +6412,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListLayerVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListLayerVersionsError::from_response(response))
         }
     }
@@ -6401,6 +6432,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6421,13 +6453,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListLayersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListLayersError::from_response(response))
         }
     }
@@ -6447,6 +6479,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListProvisionedConcurrencyConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6465,13 +6498,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProvisionedConcurrencyConfigsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProvisionedConcurrencyConfigsError::from_response(
                 response,
             ))
@@ -6487,6 +6520,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6495,13 +6529,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsError::from_response(response))
         }
     }
@@ -6518,6 +6552,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListVersionsByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6535,13 +6570,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListVersionsByFunctionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListVersionsByFunctionError::from_response(response))
         }
     }
@@ -6558,6 +6593,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6569,13 +6605,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PublishLayerVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PublishLayerVersionError::from_response(response))
         }
     }
@@ -6592,6 +6628,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6603,13 +6640,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PublishVersionError::from_response(response))
         }
     }
@@ -6626,6 +6663,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6637,13 +6675,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Concurrency, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutFunctionConcurrencyError::from_response(response))
         }
     }
@@ -6660,6 +6698,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6677,13 +6716,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionEventInvokeConfig, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutFunctionEventInvokeConfigError::from_response(response))
         }
     }
@@ -6703,6 +6742,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6718,13 +6758,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutProvisionedConcurrencyConfigResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutProvisionedConcurrencyConfigError::from_response(
                 response,
             ))
@@ -6745,6 +6785,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemoveLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6759,12 +6800,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveLayerVersionPermissionError::from_response(response))
         }
     }
@@ -6782,6 +6823,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6799,12 +6841,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemovePermissionError::from_response(response))
         }
     }
@@ -6818,6 +6860,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6829,12 +6872,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -6848,6 +6891,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6862,12 +6906,12 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -6885,6 +6929,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6896,13 +6941,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AliasConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAliasError::from_response(response))
         }
     }
@@ -6919,6 +6964,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6930,13 +6976,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EventSourceMappingConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateEventSourceMappingError::from_response(response))
         }
     }
@@ -6953,6 +6999,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6964,13 +7011,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFunctionCodeError::from_response(response))
         }
     }
@@ -6987,6 +7034,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6998,13 +7046,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFunctionConfigurationError::from_response(response))
         }
     }
@@ -7021,6 +7069,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7038,13 +7087,13 @@ impl Lambda for LambdaClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionEventInvokeConfig, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFunctionEventInvokeConfigError::from_response(
                 response,
             ))
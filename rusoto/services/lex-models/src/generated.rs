@@ -4452,6 +4452,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4464,13 +4465,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateBotVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateBotVersionError::from_response(response))
         }
     }
@@ -4484,6 +4485,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4496,13 +4498,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIntentVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIntentVersionError::from_response(response))
         }
     }
@@ -4516,6 +4518,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4528,13 +4531,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSlotTypeVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSlotTypeVersionError::from_response(response))
         }
     }
@@ -4545,6 +4548,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4555,12 +4559,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBotError::from_response(response))
         }
     }
@@ -4578,6 +4582,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4588,12 +4593,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBotAliasError::from_response(response))
         }
     }
@@ -4612,6 +4617,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4622,12 +4628,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBotChannelAssociationError::from_response(response))
         }
     }
@@ -4645,6 +4651,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4655,12 +4662,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteBotVersionError::from_response(response))
         }
     }
@@ -4674,6 +4681,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4684,12 +4692,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntentError::from_response(response))
         }
     }
@@ -4707,6 +4715,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4717,12 +4726,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIntentVersionError::from_response(response))
         }
     }
@@ -4736,6 +4745,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4746,12 +4756,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSlotTypeError::from_response(response))
         }
     }
@@ -4769,6 +4779,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4779,12 +4790,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSlotTypeVersionError::from_response(response))
         }
     }
@@ -4802,6 +4813,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteUtterances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4812,12 +4824,12 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUtterancesError::from_response(response))
         }
     }
@@ -4835,6 +4847,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4845,13 +4858,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotError::from_response(response))
         }
     }
@@ -4869,6 +4882,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4879,13 +4893,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBotAliasResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotAliasError::from_response(response))
         }
     }
@@ -4899,6 +4913,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4921,13 +4936,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBotAliasesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotAliasesError::from_response(response))
         }
     }
@@ -4946,6 +4961,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4956,13 +4972,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBotChannelAssociationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotChannelAssociationError::from_response(response))
         }
     }
@@ -4981,6 +4997,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5003,13 +5020,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBotChannelAssociationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotChannelAssociationsError::from_response(response))
         }
     }
@@ -5023,6 +5040,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5042,13 +5060,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBotVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotVersionsError::from_response(response))
         }
     }
@@ -5062,6 +5080,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/bots/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5084,13 +5103,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetBotsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBotsError::from_response(response))
         }
     }
@@ -5104,6 +5123,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5114,13 +5134,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBuiltinIntentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBuiltinIntentError::from_response(response))
         }
     }
@@ -5134,6 +5154,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5159,13 +5180,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBuiltinIntentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBuiltinIntentsError::from_response(response))
         }
     }
@@ -5179,6 +5200,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5204,13 +5226,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBuiltinSlotTypesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBuiltinSlotTypesError::from_response(response))
         }
     }
@@ -5224,6 +5246,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/exports/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5241,13 +5264,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetExportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetExportError::from_response(response))
         }
     }
@@ -5261,6 +5284,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5271,13 +5295,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetImportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetImportError::from_response(response))
         }
     }
@@ -5295,6 +5319,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5305,13 +5330,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntentError::from_response(response))
         }
     }
@@ -5325,6 +5350,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5344,13 +5370,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntentVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntentVersionsError::from_response(response))
         }
     }
@@ -5364,6 +5390,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5386,13 +5413,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIntentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetIntentsError::from_response(response))
         }
     }
@@ -5410,6 +5437,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5420,13 +5448,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSlotTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSlotTypeError::from_response(response))
         }
     }
@@ -5440,6 +5468,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5459,13 +5488,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSlotTypeVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSlotTypeVersionsError::from_response(response))
         }
     }
@@ -5479,6 +5508,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5501,13 +5531,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSlotTypesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSlotTypesError::from_response(response))
         }
     }
@@ -5521,6 +5551,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetUtterancesView");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5539,13 +5570,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetUtterancesViewResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetUtterancesViewError::from_response(response))
         }
     }
@@ -5559,6 +5590,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5569,13 +5601,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -5589,6 +5621,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5601,13 +5634,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<PutBotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutBotError::from_response(response))
         }
     }
@@ -5625,6 +5658,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5637,13 +5671,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutBotAliasResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutBotAliasError::from_response(response))
         }
     }
@@ -5657,6 +5691,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5669,13 +5704,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutIntentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutIntentError::from_response(response))
         }
     }
@@ -5689,6 +5724,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5701,13 +5737,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutSlotTypeResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutSlotTypeError::from_response(response))
         }
     }
@@ -5721,6 +5757,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/imports/";
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("StartImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5733,13 +5770,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartImportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartImportError::from_response(response))
         }
     }
@@ -5753,6 +5790,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5765,13 +5803,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -5785,6 +5823,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5801,13 +5840,13 @@ impl LexModels for LexModelsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -845,6 +845,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -855,13 +856,13 @@ impl LexRuntime for LexRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSessionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSessionError::from_response(response))
         }
     }
@@ -880,6 +881,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -896,13 +898,13 @@ impl LexRuntime for LexRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSessionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSessionError::from_response(response))
         }
     }
@@ -921,6 +923,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -943,7 +946,7 @@ impl LexRuntime for LexRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = PostContentResponse::default();
             result.audio_stream = Some(response.body);
@@ -962,7 +965,7 @@ impl LexRuntime for LexRuntimeClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PostContentError::from_response(response))
         }
     }
@@ -981,6 +984,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostText");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -993,13 +997,13 @@ impl LexRuntime for LexRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PostTextResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PostTextError::from_response(response))
         }
     }
@@ -1018,6 +1022,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PutSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1031,7 +1036,7 @@ impl LexRuntime for LexRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = PutSessionResponse::default();
             result.audio_stream = Some(response.body);
@@ -1048,7 +1053,7 @@ impl LexRuntime for LexRuntimeClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutSessionError::from_response(response))
         }
     }
@@ -42,7 +42,7 @@ impl LicenseManagerClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2156,7 +2156,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, CreateLicenseConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateLicenseConfigurationResponse, _>()
     }
@@ -2179,7 +2179,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, DeleteLicenseConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteLicenseConfigurationResponse, _>()
     }
@@ -2198,7 +2198,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, GetLicenseConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLicenseConfigurationResponse, _>()
     }
@@ -2215,7 +2215,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, GetServiceSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetServiceSettingsResponse, _>()
     }
 
@@ -2242,7 +2242,7 @@ impl LicenseManager for LicenseManagerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssociationsForLicenseConfigurationResponse, _>()
     }
@@ -2270,7 +2270,7 @@ impl LicenseManager for LicenseManagerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListFailuresForLicenseConfigurationOperationsResponse, _>()
     }
@@ -2293,7 +2293,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, ListLicenseConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListLicenseConfigurationsResponse, _>()
     }
@@ -2321,7 +2321,7 @@ impl LicenseManager for LicenseManagerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListLicenseSpecificationsForResourceResponse, _>()
     }
@@ -2340,7 +2340,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, ListResourceInventoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResourceInventoryResponse, _>()
     }
@@ -2359,7 +2359,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2386,7 +2386,7 @@ impl LicenseManager for LicenseManagerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListUsageForLicenseConfigurationResponse, _>()
     }
@@ -2405,7 +2405,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2423,7 +2423,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2445,7 +2445,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, UpdateLicenseConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateLicenseConfigurationResponse, _>()
     }
@@ -2473,7 +2473,7 @@ impl LicenseManager for LicenseManagerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateLicenseSpecificationsForResourceResponse, _>()
     }
@@ -2492,7 +2492,7 @@ impl LicenseManager for LicenseManagerClient {
             .sign_and_dispatch(request, UpdateServiceSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateServiceSettingsResponse, _>()
     }
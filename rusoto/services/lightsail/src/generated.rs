@@ -41,7 +41,7 @@ impl LightsailClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -13715,7 +13715,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, AllocateStaticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AllocateStaticIpResult, _>()
     }
 
@@ -13733,7 +13733,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, AttachDiskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AttachDiskResult, _>()
     }
 
@@ -13755,7 +13755,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, AttachInstancesToLoadBalancerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AttachInstancesToLoadBalancerResult, _>()
     }
@@ -13783,7 +13783,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AttachLoadBalancerTlsCertificateResult, _>()
     }
@@ -13802,7 +13802,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, AttachStaticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AttachStaticIpResult, _>()
     }
 
@@ -13823,7 +13823,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CloseInstancePublicPortsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CloseInstancePublicPortsResult, _>()
     }
@@ -13842,7 +13842,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CopySnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CopySnapshotResult, _>()
     }
 
@@ -13863,7 +13863,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateCloudFormationStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCloudFormationStackResult, _>()
     }
@@ -13882,7 +13882,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateContactMethodError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateContactMethodResult, _>()
     }
 
@@ -13900,7 +13900,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateDiskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDiskResult, _>()
     }
 
@@ -13918,7 +13918,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateDiskFromSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDiskFromSnapshotResult, _>()
     }
@@ -13937,7 +13937,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateDiskSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDiskSnapshotResult, _>()
     }
 
@@ -13955,7 +13955,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainResult, _>()
     }
 
@@ -13973,7 +13973,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateDomainEntryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainEntryResult, _>()
     }
 
@@ -13991,7 +13991,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateInstanceSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateInstanceSnapshotResult, _>()
     }
@@ -14010,7 +14010,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateInstancesResult, _>()
     }
 
@@ -14032,7 +14032,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateInstancesFromSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateInstancesFromSnapshotResult, _>()
     }
@@ -14051,7 +14051,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateKeyPairResult, _>()
     }
 
@@ -14069,7 +14069,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateLoadBalancerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLoadBalancerResult, _>()
     }
 
@@ -14096,7 +14096,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateLoadBalancerTlsCertificateResult, _>()
     }
@@ -14118,7 +14118,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, CreateRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRelationalDatabaseResult, _>()
     }
@@ -14146,7 +14146,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRelationalDatabaseFromSnapshotResult, _>()
     }
@@ -14174,7 +14174,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRelationalDatabaseSnapshotResult, _>()
     }
@@ -14193,7 +14193,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteAlarmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAlarmResult, _>()
     }
 
@@ -14211,7 +14211,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteAutoSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAutoSnapshotResult, _>()
     }
 
@@ -14229,7 +14229,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteContactMethodError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteContactMethodResult, _>()
     }
 
@@ -14247,7 +14247,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteDiskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDiskResult, _>()
     }
 
@@ -14265,7 +14265,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteDiskSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDiskSnapshotResult, _>()
     }
 
@@ -14283,7 +14283,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDomainResult, _>()
     }
 
@@ -14301,7 +14301,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteDomainEntryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDomainEntryResult, _>()
     }
 
@@ -14319,7 +14319,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteInstanceResult, _>()
     }
 
@@ -14337,7 +14337,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteInstanceSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteInstanceSnapshotResult, _>()
     }
@@ -14356,7 +14356,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteKeyPairResult, _>()
     }
 
@@ -14374,7 +14374,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteKnownHostKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteKnownHostKeysResult, _>()
     }
 
@@ -14392,7 +14392,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteLoadBalancerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLoadBalancerResult, _>()
     }
 
@@ -14419,7 +14419,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteLoadBalancerTlsCertificateResult, _>()
     }
@@ -14441,7 +14441,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DeleteRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRelationalDatabaseResult, _>()
     }
@@ -14469,7 +14469,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRelationalDatabaseSnapshotResult, _>()
     }
@@ -14488,7 +14488,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DetachDiskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetachDiskResult, _>()
     }
 
@@ -14512,7 +14512,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DetachInstancesFromLoadBalancerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DetachInstancesFromLoadBalancerResult, _>()
     }
@@ -14531,7 +14531,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DetachStaticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetachStaticIpResult, _>()
     }
 
@@ -14549,7 +14549,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DisableAddOnError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableAddOnResult, _>()
     }
 
@@ -14565,7 +14565,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, DownloadDefaultKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DownloadDefaultKeyPairResult, _>()
     }
@@ -14584,7 +14584,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, EnableAddOnError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableAddOnResult, _>()
     }
 
@@ -14602,7 +14602,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, ExportSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ExportSnapshotResult, _>()
     }
 
@@ -14620,7 +14620,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetActiveNamesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetActiveNamesResult, _>()
     }
 
@@ -14638,7 +14638,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetAlarmsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAlarmsResult, _>()
     }
 
@@ -14656,7 +14656,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetAutoSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAutoSnapshotsResult, _>()
     }
 
@@ -14674,7 +14674,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetBlueprintsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetBlueprintsResult, _>()
     }
 
@@ -14692,7 +14692,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetBundlesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetBundlesResult, _>()
     }
 
@@ -14714,7 +14714,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetCloudFormationStackRecordsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCloudFormationStackRecordsResult, _>()
     }
@@ -14733,7 +14733,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetContactMethodsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetContactMethodsResult, _>()
     }
 
@@ -14751,7 +14751,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDiskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDiskResult, _>()
     }
 
@@ -14769,7 +14769,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDiskSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDiskSnapshotResult, _>()
     }
 
@@ -14787,7 +14787,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDiskSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDiskSnapshotsResult, _>()
     }
 
@@ -14805,7 +14805,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDisksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDisksResult, _>()
     }
 
@@ -14823,7 +14823,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDomainResult, _>()
     }
 
@@ -14841,7 +14841,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetDomainsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDomainsResult, _>()
     }
 
@@ -14862,7 +14862,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetExportSnapshotRecordsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetExportSnapshotRecordsResult, _>()
     }
@@ -14881,7 +14881,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceResult, _>()
     }
 
@@ -14902,7 +14902,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceAccessDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetInstanceAccessDetailsResult, _>()
     }
@@ -14921,7 +14921,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceMetricDataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceMetricDataResult, _>()
     }
 
@@ -14939,7 +14939,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstancePortStatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstancePortStatesResult, _>()
     }
 
@@ -14957,7 +14957,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceSnapshotResult, _>()
     }
 
@@ -14975,7 +14975,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceSnapshotsResult, _>()
     }
 
@@ -14993,7 +14993,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstanceStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceStateResult, _>()
     }
 
@@ -15011,7 +15011,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstancesResult, _>()
     }
 
@@ -15029,7 +15029,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPairResult, _>()
     }
 
@@ -15047,7 +15047,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetKeyPairsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPairsResult, _>()
     }
 
@@ -15065,7 +15065,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetLoadBalancerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLoadBalancerResult, _>()
     }
 
@@ -15086,7 +15086,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetLoadBalancerMetricDataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLoadBalancerMetricDataResult, _>()
     }
@@ -15111,7 +15111,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetLoadBalancerTlsCertificatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLoadBalancerTlsCertificatesResult, _>()
     }
@@ -15130,7 +15130,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetLoadBalancersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLoadBalancersResult, _>()
     }
 
@@ -15148,7 +15148,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetOperationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOperationResult, _>()
     }
 
@@ -15166,7 +15166,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetOperationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOperationsResult, _>()
     }
 
@@ -15187,7 +15187,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetOperationsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetOperationsForResourceResult, _>()
     }
@@ -15206,7 +15206,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRegionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRegionsResult, _>()
     }
 
@@ -15224,7 +15224,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRelationalDatabaseResult, _>()
     }
 
@@ -15248,7 +15248,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseBlueprintsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseBlueprintsResult, _>()
     }
@@ -15271,7 +15271,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseBundlesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseBundlesResult, _>()
     }
@@ -15294,7 +15294,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseEventsResult, _>()
     }
@@ -15319,7 +15319,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseLogEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseLogEventsResult, _>()
     }
@@ -15344,7 +15344,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseLogStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseLogStreamsResult, _>()
     }
@@ -15372,7 +15372,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseMasterUserPasswordResult, _>()
     }
@@ -15397,7 +15397,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseMetricDataError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseMetricDataResult, _>()
     }
@@ -15422,7 +15422,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseParametersResult, _>()
     }
@@ -15445,7 +15445,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseSnapshotResult, _>()
     }
@@ -15470,7 +15470,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabaseSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabaseSnapshotsResult, _>()
     }
@@ -15489,7 +15489,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetRelationalDatabasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRelationalDatabasesResult, _>()
     }
@@ -15508,7 +15508,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetStaticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetStaticIpResult, _>()
     }
 
@@ -15526,7 +15526,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, GetStaticIpsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetStaticIpsResult, _>()
     }
 
@@ -15544,7 +15544,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, ImportKeyPairError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportKeyPairResult, _>()
     }
 
@@ -15558,7 +15558,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, IsVpcPeeredError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IsVpcPeeredResult, _>()
     }
 
@@ -15576,7 +15576,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, OpenInstancePublicPortsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<OpenInstancePublicPortsResult, _>()
     }
@@ -15591,7 +15591,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, PeerVpcError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PeerVpcResult, _>()
     }
 
@@ -15609,7 +15609,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, PutAlarmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutAlarmResult, _>()
     }
 
@@ -15627,7 +15627,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, PutInstancePublicPortsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutInstancePublicPortsResult, _>()
     }
@@ -15646,7 +15646,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, RebootInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RebootInstanceResult, _>()
     }
 
@@ -15667,7 +15667,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, RebootRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RebootRelationalDatabaseResult, _>()
     }
@@ -15686,7 +15686,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, ReleaseStaticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ReleaseStaticIpResult, _>()
     }
 
@@ -15708,7 +15708,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, SendContactMethodVerificationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SendContactMethodVerificationResult, _>()
     }
@@ -15727,7 +15727,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, StartInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartInstanceResult, _>()
     }
 
@@ -15745,7 +15745,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, StartRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartRelationalDatabaseResult, _>()
     }
@@ -15764,7 +15764,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, StopInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopInstanceResult, _>()
     }
 
@@ -15782,7 +15782,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, StopRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopRelationalDatabaseResult, _>()
     }
@@ -15801,7 +15801,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResult, _>()
     }
 
@@ -15819,7 +15819,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, TestAlarmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TestAlarmResult, _>()
     }
 
@@ -15833,7 +15833,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, UnpeerVpcError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UnpeerVpcResult, _>()
     }
 
@@ -15851,7 +15851,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResult, _>()
     }
 
@@ -15869,7 +15869,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, UpdateDomainEntryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDomainEntryResult, _>()
     }
 
@@ -15891,7 +15891,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, UpdateLoadBalancerAttributeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateLoadBalancerAttributeResult, _>()
     }
@@ -15913,7 +15913,7 @@ impl Lightsail for LightsailClient {
             .sign_and_dispatch(request, UpdateRelationalDatabaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateRelationalDatabaseResult, _>()
     }
@@ -15941,7 +15941,7 @@ impl Lightsail for LightsailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateRelationalDatabaseParametersResult, _>()
     }
@@ -1 +1,3 @@
+mod writer;
 
+pub use self::writer::{CloudWatchLogsWriter, FailedLogEvent, WriterOptions};
@@ -0,0 +1,813 @@
+//! A writer putting log events into CloudWatch Logs in batches, see `CloudWatchLogsWriter`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rusoto_core::retry::{is_throttling_error, RetryPolicy};
+use rusoto_core::RusotoError;
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
+
+use crate::generated::{
+    CloudWatchLogs, CreateLogGroupError, CreateLogGroupRequest, CreateLogStreamError,
+    CreateLogStreamRequest, InputLogEvent, PutLogEventsError, PutLogEventsRequest,
+    RejectedLogEventsInfo,
+};
+
+/// The most events `put_log_events` accepts at once.
+const MAX_BATCH_EVENTS: usize = 10_000;
+/// The most bytes of events `put_log_events` accepts at once.
+const MAX_BATCH_BYTES: usize = 1_048_576;
+/// The bytes CloudWatch Logs counts for an event on top of its message.
+const EVENT_OVERHEAD_BYTES: usize = 26;
+/// The most bytes a single event may have, counting its overhead.
+const MAX_EVENT_BYTES: usize = 256 * 1024;
+/// The longest time, in milliseconds, the events of a `put_log_events` call may span.
+const MAX_BATCH_SPAN_MILLIS: i64 = 24 * 60 * 60 * 1000;
+
+type SharedLogs = Arc<dyn CloudWatchLogs + Send + Sync>;
+
+/// When a `CloudWatchLogsWriter` sends its buffered events, and how it recovers from errors.
+#[derive(Clone, Debug)]
+pub struct WriterOptions {
+    max_batch_events: usize,
+    max_batch_bytes: usize,
+    flush_interval: Duration,
+    max_buffered_events: usize,
+    create_missing: bool,
+    retry_policy: RetryPolicy,
+}
+
+impl WriterOptions {
+    /// Options sending the events of a stream once there are 10,000 of them or 1 MiB, and all
+    /// events at the latest 5 seconds after the first was put, with at most 100,000 events
+    /// buffered, without creating missing log groups or streams, retried with
+    /// `RetryPolicy::new()`.
+    pub fn new() -> Self {
+        WriterOptions {
+            max_batch_events: MAX_BATCH_EVENTS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            flush_interval: Duration::from_secs(5),
+            max_buffered_events: 100_000,
+            create_missing: false,
+            retry_policy: RetryPolicy::new(),
+        }
+    }
+
+    /// Sends the events of a stream once it has `max_batch_events`, between 1 and 10,000.
+    pub fn with_max_batch_events(mut self, max_batch_events: usize) -> Self {
+        self.max_batch_events = max_batch_events.clamp(1, MAX_BATCH_EVENTS);
+        self
+    }
+
+    /// Sends the events of a stream once they reach `max_batch_bytes`, between 256 KiB and
+    /// 1 MiB, counting 26 bytes per event on top of its message like CloudWatch Logs does.
+    pub fn with_max_batch_bytes(mut self, max_batch_bytes: usize) -> Self {
+        self.max_batch_bytes = max_batch_bytes.clamp(MAX_EVENT_BYTES, MAX_BATCH_BYTES);
+        self
+    }
+
+    /// Sends all buffered events at the latest `flush_interval` after the first of them was
+    /// put.
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// Makes `CloudWatchLogsWriter::put` wait while `max_buffered_events` events, at least 1,
+    /// are put but not yet written or failed.
+    pub fn with_max_buffered_events(mut self, max_buffered_events: usize) -> Self {
+        self.max_buffered_events = max_buffered_events.max(1);
+        self
+    }
+
+    /// Creates the log group and stream when `put_log_events` reports either missing, if
+    /// `create_missing` is set.
+    pub fn with_create_missing(mut self, create_missing: bool) -> Self {
+        self.create_missing = create_missing;
+        self
+    }
+
+    /// Retries throttled and failed calls with `policy`. Only its maximum number of attempts
+    /// and its delays are used.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+}
+
+impl Default for WriterOptions {
+    fn default() -> Self {
+        WriterOptions::new()
+    }
+}
+
+/// A log event a `CloudWatchLogsWriter` couldn't write.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedLogEvent {
+    /// The stream the event was put into.
+    pub log_stream_name: String,
+    /// The event as it was put.
+    pub event: InputLogEvent,
+    /// Why writing it failed.
+    pub reason: String,
+}
+
+impl fmt::Display for FailedLogEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Failed to write log event to stream '{}': {}",
+            self.log_stream_name, self.reason
+        )
+    }
+}
+
+impl Error for FailedLogEvent {}
+
+/// Buffers log events and writes them to the streams of a log group with `put_log_events`, in
+/// batches.
+///
+/// The events of a stream are sent once they reach the batch limits, and all events are sent
+/// `flush_interval` after the first buffered one was put, or on `flush`. Each batch is sorted
+/// by timestamp and split so it stays within the limits of `put_log_events`, including its 24
+/// hour span.
+///
+/// The writer keeps the sequence token of every stream. When CloudWatch Logs rejects one with
+/// `InvalidSequenceTokenException`, the batch is sent again with the token the error expects,
+/// and `DataAlreadyAcceptedException` counts as written. Throttled calls and server errors
+/// are retried with the backoff of the retry policy. Events that still fail, or that
+/// CloudWatch Logs rejects as too old, too new or expired, are returned by
+/// `take_failed_events` and `shutdown`.
+///
+/// `put` waits while `max_buffered_events` events are buffered or being sent. A writer must
+/// be created within a Tokio runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_logs::{CloudWatchLogsClient, CloudWatchLogsWriter, InputLogEvent, WriterOptions};
+///
+/// # async fn example() {
+/// let writer = CloudWatchLogsWriter::new(
+///     CloudWatchLogsClient::new(Region::UsEast1),
+///     "my-app",
+///     WriterOptions::new().with_create_missing(true),
+/// );
+/// writer
+///     .put(
+///         "web-1",
+///         InputLogEvent {
+///             message: "started".to_owned(),
+///             timestamp: 1_590_000_000_000,
+///         },
+///     )
+///     .await;
+/// let failed = writer.shutdown().await;
+/// assert!(failed.is_empty(), "{:?}", failed);
+/// # }
+/// ```
+pub struct CloudWatchLogsWriter {
+    commands: mpsc::UnboundedSender<Command>,
+    buffered: Arc<Semaphore>,
+    writer: JoinHandle<()>,
+    failed_events: Arc<Mutex<Vec<FailedLogEvent>>>,
+}
+
+enum Command {
+    Put(String, Pending),
+    Flush(oneshot::Sender<()>),
+}
+
+/// An event put but not yet written or failed, holding its room in the buffer.
+struct Pending {
+    event: InputLogEvent,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl CloudWatchLogsWriter {
+    /// Creates a writer putting events into the streams of the log group `log_group_name`.
+    pub fn new<C, S>(logs: C, log_group_name: S, options: WriterOptions) -> CloudWatchLogsWriter
+    where
+        C: CloudWatchLogs + Send + Sync + 'static,
+        S: Into<String>,
+    {
+        let failed_events = Arc::new(Mutex::new(Vec::new()));
+        let (commands, received) = mpsc::unbounded_channel();
+        let buffered = Arc::new(Semaphore::new(options.max_buffered_events));
+        let streams = StreamWriter {
+            logs: Arc::new(logs),
+            log_group_name: log_group_name.into(),
+            create_missing: options.create_missing,
+            retry_policy: options.retry_policy.clone(),
+            max_batch_events: options.max_batch_events,
+            max_batch_bytes: options.max_batch_bytes,
+            sequence_tokens: HashMap::new(),
+            failed_events: failed_events.clone(),
+        };
+        let writer = tokio::spawn(write_batches(streams, options, received));
+        CloudWatchLogsWriter {
+            commands,
+            buffered,
+            writer,
+            failed_events,
+        }
+    }
+
+    /// Buffers `event` to be written to the stream `log_stream_name`, waiting while the buffer
+    /// is full.
+    ///
+    /// Events over 256 KiB, counting 26 bytes on top of the message, fail right away.
+    pub async fn put(&self, log_stream_name: &str, event: InputLogEvent) {
+        if event_size(&event) > MAX_EVENT_BYTES {
+            let reason = format!("event is larger than {} bytes", MAX_EVENT_BYTES);
+            push_failed(&self.failed_events, log_stream_name, vec![event], &reason);
+            return;
+        }
+        let pending = Pending {
+            event,
+            _permit: self.buffered.clone().acquire_owned().await,
+        };
+        if let Err(mpsc::error::SendError(Command::Put(_, pending))) = self
+            .commands
+            .send(Command::Put(log_stream_name.to_owned(), pending))
+        {
+            let reason = "the writer stopped";
+            push_failed(
+                &self.failed_events,
+                log_stream_name,
+                vec![pending.event],
+                reason,
+            );
+        }
+    }
+
+    /// Sends the buffered events, and waits until all events put before are written or
+    /// failed.
+    pub async fn flush(&self) {
+        let (flushed, done) = oneshot::channel();
+        if self.commands.send(Command::Flush(flushed)).is_ok() {
+            // Only fails if the writing task panicked.
+            let _ = done.await;
+        }
+    }
+
+    /// Returns the events that failed since this was last called, without waiting for those
+    /// in progress.
+    pub fn take_failed_events(&self) -> Vec<FailedLogEvent> {
+        take_failed(&self.failed_events)
+    }
+
+    /// Sends the buffered events, waits until all events are written or failed, and returns
+    /// those that failed and weren't taken with `take_failed_events`.
+    pub async fn shutdown(self) -> Vec<FailedLogEvent> {
+        let CloudWatchLogsWriter {
+            commands,
+            writer,
+            failed_events,
+            ..
+        } = self;
+        drop(commands);
+        // The task only ends early if it panicked, leaving its failures reported so far.
+        let _ = writer.await;
+        take_failed(&failed_events)
+    }
+}
+
+/// The events buffered for a stream.
+#[derive(Default)]
+struct StreamBuffer {
+    events: Vec<Pending>,
+    bytes: usize,
+}
+
+/// Receives the events put and writes them in batches, until the writer is dropped or shut
+/// down.
+async fn write_batches(
+    mut streams: StreamWriter,
+    options: WriterOptions,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+) {
+    let mut buffers: HashMap<String, StreamBuffer> = HashMap::new();
+    let mut deadline = None;
+    loop {
+        let received = match deadline {
+            Some(deadline) => time::timeout_at(deadline, commands.recv()).await.ok(),
+            None => Some(commands.recv().await),
+        };
+        match received {
+            Some(Some(Command::Put(log_stream_name, pending))) => {
+                if deadline.is_none() {
+                    deadline = Some(Instant::now() + options.flush_interval);
+                }
+                let buffer = buffers.entry(log_stream_name.clone()).or_default();
+                buffer.bytes += event_size(&pending.event);
+                buffer.events.push(pending);
+                if buffer.events.len() >= options.max_batch_events
+                    || buffer.bytes >= options.max_batch_bytes
+                {
+                    let events = mem::take(&mut buffer.events);
+                    buffer.bytes = 0;
+                    streams.write(&log_stream_name, events).await;
+                }
+            }
+            Some(Some(Command::Flush(flushed))) => {
+                streams.write_all(&mut buffers).await;
+                deadline = None;
+                let _ = flushed.send(());
+            }
+            // The flush interval ran out.
+            None => {
+                streams.write_all(&mut buffers).await;
+                deadline = None;
+            }
+            Some(None) => {
+                streams.write_all(&mut buffers).await;
+                return;
+            }
+        }
+    }
+}
+
+/// Writes batches of events to the streams of a log group, keeping their sequence tokens.
+struct StreamWriter {
+    logs: SharedLogs,
+    log_group_name: String,
+    create_missing: bool,
+    retry_policy: RetryPolicy,
+    max_batch_events: usize,
+    max_batch_bytes: usize,
+    /// The sequence token of the next `put_log_events` call of every stream written, `None`
+    /// for a stream without events.
+    sequence_tokens: HashMap<String, Option<String>>,
+    failed_events: Arc<Mutex<Vec<FailedLogEvent>>>,
+}
+
+/// What to do after a failed `put_log_events` call.
+enum Recovery {
+    /// Send the batch again right away, e.g. with the sequence token the error expects.
+    Resend,
+    /// Send the batch again after the delay of the retry policy.
+    Retry,
+    /// The batch was written after all.
+    Written,
+    /// Fail the batch with the reason.
+    Fail(String),
+}
+
+impl StreamWriter {
+    async fn write_all(&mut self, buffers: &mut HashMap<String, StreamBuffer>) {
+        for (log_stream_name, buffer) in buffers.iter_mut() {
+            if !buffer.events.is_empty() {
+                let events = mem::take(&mut buffer.events);
+                buffer.bytes = 0;
+                self.write(log_stream_name, events).await;
+            }
+        }
+    }
+
+    /// Writes `events` to the stream, sorted by timestamp, in as many batches as the limits of
+    /// `put_log_events` require.
+    async fn write(&mut self, log_stream_name: &str, mut events: Vec<Pending>) {
+        events.sort_by_key(|pending| pending.event.timestamp);
+        for batch in batches(events, self.max_batch_events, self.max_batch_bytes) {
+            self.write_batch(log_stream_name, batch).await;
+        }
+    }
+
+    async fn write_batch(&mut self, log_stream_name: &str, batch: Vec<Pending>) {
+        let mut attempt = 1;
+        let mut created = false;
+        loop {
+            let request = PutLogEventsRequest {
+                log_events: batch.iter().map(|pending| pending.event.clone()).collect(),
+                log_group_name: self.log_group_name.clone(),
+                log_stream_name: log_stream_name.to_owned(),
+                sequence_token: self.sequence_tokens.get(log_stream_name).cloned().flatten(),
+            };
+            let recovery = match self.logs.put_log_events(request).await {
+                Ok(response) => {
+                    self.sequence_tokens
+                        .insert(log_stream_name.to_owned(), response.next_sequence_token);
+                    if let Some(ref rejected) = response.rejected_log_events_info {
+                        self.fail_rejected(log_stream_name, batch, rejected);
+                    }
+                    return;
+                }
+                Err(RusotoError::Service(PutLogEventsError::InvalidSequenceToken(message))) => {
+                    match sequence_token_in(&message) {
+                        Some(token) => {
+                            self.sequence_tokens
+                                .insert(log_stream_name.to_owned(), token);
+                            Recovery::Resend
+                        }
+                        None => Recovery::Fail(message),
+                    }
+                }
+                Err(RusotoError::Service(PutLogEventsError::DataAlreadyAccepted(message))) => {
+                    if let Some(token) = sequence_token_in(&message) {
+                        self.sequence_tokens
+                            .insert(log_stream_name.to_owned(), token);
+                    }
+                    Recovery::Written
+                }
+                Err(RusotoError::Service(PutLogEventsError::ResourceNotFound(_)))
+                    if self.create_missing && !created =>
+                {
+                    created = true;
+                    match self.create_missing(log_stream_name).await {
+                        Ok(()) => {
+                            self.sequence_tokens
+                                .insert(log_stream_name.to_owned(), None);
+                            Recovery::Resend
+                        }
+                        Err(reason) => Recovery::Fail(reason),
+                    }
+                }
+                Err(ref err) if is_retryable(err) => Recovery::Retry,
+                Err(err) => Recovery::Fail(err.to_string()),
+            };
+            match recovery {
+                Recovery::Written => return,
+                Recovery::Fail(reason) => {
+                    self.fail(log_stream_name, batch, &reason);
+                    return;
+                }
+                _ if attempt >= self.retry_policy.max_attempts() => {
+                    self.fail(
+                        log_stream_name,
+                        batch,
+                        "out of attempts to write the events",
+                    );
+                    return;
+                }
+                Recovery::Retry => time::delay_for(self.retry_policy.delay(attempt)).await,
+                Recovery::Resend => {}
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Creates the log group and stream, either of which may exist already.
+    async fn create_missing(&self, log_stream_name: &str) -> Result<(), String> {
+        let group = CreateLogGroupRequest {
+            log_group_name: self.log_group_name.clone(),
+            ..Default::default()
+        };
+        match self.logs.create_log_group(group).await {
+            Ok(()) | Err(RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(_))) => {}
+            Err(err) => return Err(format!("Failed to create the log group: {}", err)),
+        }
+        let stream = CreateLogStreamRequest {
+            log_group_name: self.log_group_name.clone(),
+            log_stream_name: log_stream_name.to_owned(),
+        };
+        match self.logs.create_log_stream(stream).await {
+            Ok(()) | Err(RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(_))) => {
+                Ok(())
+            }
+            Err(err) => Err(format!("Failed to create the log stream: {}", err)),
+        }
+    }
+
+    fn fail_rejected(
+        &self,
+        log_stream_name: &str,
+        batch: Vec<Pending>,
+        rejected: &RejectedLogEventsInfo,
+    ) {
+        let index = |index: Option<i64>| index.map(|index| index.max(0) as usize);
+        let too_old_end = index(rejected.too_old_log_event_end_index).unwrap_or(0);
+        let expired_end = index(rejected.expired_log_event_end_index).unwrap_or(0);
+        let too_new_start = index(rejected.too_new_log_event_start_index).unwrap_or(usize::MAX);
+        let mut too_old = Vec::new();
+        let mut expired = Vec::new();
+        let mut too_new = Vec::new();
+        for (index, pending) in batch.into_iter().enumerate() {
+            if index < expired_end {
+                expired.push(pending.event);
+            } else if index < too_old_end {
+                too_old.push(pending.event);
+            } else if index >= too_new_start {
+                too_new.push(pending.event);
+            }
+        }
+        for (events, reason) in [
+            (expired, "rejected as expired"),
+            (too_old, "rejected as too old"),
+            (too_new, "rejected as too new"),
+        ] {
+            if !events.is_empty() {
+                push_failed(&self.failed_events, log_stream_name, events, reason);
+            }
+        }
+    }
+
+    fn fail(&self, log_stream_name: &str, batch: Vec<Pending>, reason: &str) {
+        let events = batch.into_iter().map(|pending| pending.event).collect();
+        push_failed(&self.failed_events, log_stream_name, events, reason);
+    }
+}
+
+/// Splits events sorted by timestamp into batches of at most `max_events` events and
+/// `max_bytes`, spanning less than 24 hours.
+fn batches(events: Vec<Pending>, max_events: usize, max_bytes: usize) -> Vec<Vec<Pending>> {
+    let mut batches: Vec<Vec<Pending>> = Vec::new();
+    let mut batch_bytes = 0;
+    for pending in events {
+        let size = event_size(&pending.event);
+        let fits = batches.last().is_some_and(|batch| {
+            batch.len() < max_events
+                && batch_bytes + size <= max_bytes
+                && pending.event.timestamp - batch[0].event.timestamp < MAX_BATCH_SPAN_MILLIS
+        });
+        if fits {
+            batch_bytes += size;
+            batches.last_mut().unwrap().push(pending);
+        } else {
+            batch_bytes = size;
+            batches.push(vec![pending]);
+        }
+    }
+    batches
+}
+
+/// The size CloudWatch Logs counts for an event: its message and 26 bytes.
+fn event_size(event: &InputLogEvent) -> usize {
+    event.message.len() + EVENT_OVERHEAD_BYTES
+}
+
+/// Whether a failed `put_log_events` call is sent again after a delay.
+fn is_retryable(err: &RusotoError<PutLogEventsError>) -> bool {
+    match *err {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Service(PutLogEventsError::ServiceUnavailable(_)) => true,
+        RusotoError::Unknown(ref response) if response.status.is_server_error() => true,
+        ref err => is_throttling_error(err),
+    }
+}
+
+/// Reads the sequence token at the end of the message of an `InvalidSequenceTokenException`
+/// or `DataAlreadyAcceptedException`, e.g. "The next expected sequenceToken is: 4960..."; `null`
+/// for a stream without events.
+fn sequence_token_in(message: &str) -> Option<Option<String>> {
+    let (_, token) = message.rsplit_once(':')?;
+    match token.trim() {
+        "" => None,
+        "null" => Some(None),
+        token => Some(Some(token.to_owned())),
+    }
+}
+
+fn push_failed(
+    failed_events: &Mutex<Vec<FailedLogEvent>>,
+    log_stream_name: &str,
+    events: Vec<InputLogEvent>,
+    reason: &str,
+) {
+    let failed = events.into_iter().map(|event| FailedLogEvent {
+        log_stream_name: log_stream_name.to_owned(),
+        event,
+        reason: reason.to_owned(),
+    });
+    match failed_events.lock() {
+        Ok(mut failed_events) => failed_events.extend(failed),
+        Err(poisoned) => poisoned.into_inner().extend(failed),
+    }
+}
+
+fn take_failed(failed_events: &Mutex<Vec<FailedLogEvent>>) -> Vec<FailedLogEvent> {
+    match failed_events.lock() {
+        Ok(mut failed) => mem::take(&mut *failed),
+        Err(poisoned) => mem::take(&mut *poisoned.into_inner()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::CloudWatchLogsClient;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+        RequestRecorder,
+    };
+    use serde_json::{json, Value};
+
+    fn event(message: &str, timestamp: i64) -> InputLogEvent {
+        InputLogEvent {
+            message: message.to_owned(),
+            timestamp,
+        }
+    }
+
+    fn accepted(next_sequence_token: &str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_json_body(json!({ "nextSequenceToken": next_sequence_token }))
+    }
+
+    fn failing(error_type: &str, message: &str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(400)
+            .with_json_body(json!({"__type": error_type, "message": message}))
+    }
+
+    fn body(request: &SignedRequest) -> Value {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => serde_json::from_slice(body).unwrap(),
+            _ => panic!("expected a buffered request body"),
+        }
+    }
+
+    fn target(request: &SignedRequest) -> String {
+        String::from_utf8(request.headers["x-amz-target"][0].clone()).unwrap()
+    }
+
+    /// The sequence token and event messages of each `put_log_events` request sent.
+    fn sent_batches(recorder: &RequestRecorder) -> Vec<(Value, Vec<String>)> {
+        recorder
+            .requests()
+            .iter()
+            .filter(|request| target(request) == "Logs_20140328.PutLogEvents")
+            .map(|request| {
+                let body = body(request);
+                assert_eq!(body["logGroupName"], "app");
+                let messages = body["logEvents"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|event| event["message"].as_str().unwrap().to_owned())
+                    .collect();
+                (body["sequenceToken"].clone(), messages)
+            })
+            .collect()
+    }
+
+    fn writer<I>(
+        dispatcher: MultipleMockRequestDispatcher<I>,
+        options: WriterOptions,
+    ) -> CloudWatchLogsWriter
+    where
+        I: Iterator<Item = MockRequestDispatcher> + Send + Sync + 'static,
+    {
+        let client =
+            CloudWatchLogsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        CloudWatchLogsWriter::new(client, "app", options.with_retry_policy(fast_retries()))
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn reads_sequence_tokens_from_error_messages() {
+        assert_eq!(
+            sequence_token_in("The next expected sequenceToken is: 4960134"),
+            Some(Some("4960134".to_owned()))
+        );
+        assert_eq!(
+            sequence_token_in("The next expected sequenceToken is: null"),
+            Some(None)
+        );
+        assert_eq!(sequence_token_in("Rate exceeded"), None);
+    }
+
+    #[tokio::test]
+    async fn recovers_the_expected_sequence_token() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            failing(
+                "InvalidSequenceTokenException",
+                "The given sequenceToken is invalid. The next expected sequenceToken is: 100",
+            ),
+            accepted("101"),
+            accepted("102"),
+        ]);
+        let requests = dispatcher.recorder();
+        let writer = writer(dispatcher, WriterOptions::new());
+
+        writer.put("web", event("a", 1_000)).await;
+        writer.flush().await;
+        writer.put("web", event("b", 2_000)).await;
+        writer.flush().await;
+
+        assert_eq!(
+            sent_batches(&requests),
+            vec![
+                (Value::Null, vec!["a".to_owned()]),
+                (json!("100"), vec!["a".to_owned()]),
+                (json!("101"), vec!["b".to_owned()]),
+            ]
+        );
+        assert!(writer.shutdown().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn creates_missing_groups_and_streams() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            failing(
+                "ResourceNotFoundException",
+                "The specified log stream does not exist.",
+            ),
+            failing(
+                "ResourceAlreadyExistsException",
+                "The specified log group already exists",
+            ),
+            MockRequestDispatcher::with_status(200),
+            accepted("1"),
+        ]);
+        let requests = dispatcher.recorder();
+        let writer = writer(dispatcher, WriterOptions::new().with_create_missing(true));
+
+        writer.put("web", event("a", 1_000)).await;
+        let failed = writer.shutdown().await;
+
+        assert!(failed.is_empty(), "{:?}", failed);
+        let requests = requests.requests();
+        let targets: Vec<String> = requests.iter().map(target).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "Logs_20140328.PutLogEvents",
+                "Logs_20140328.CreateLogGroup",
+                "Logs_20140328.CreateLogStream",
+                "Logs_20140328.PutLogEvents",
+            ]
+        );
+        assert_eq!(body(&requests[2])["logStreamName"], "web");
+    }
+
+    #[tokio::test]
+    async fn sorts_and_splits_batches() {
+        let day = MAX_BATCH_SPAN_MILLIS;
+        let dispatcher =
+            MultipleMockRequestDispatcher::new(vec![accepted("1"), accepted("2"), accepted("3")]);
+        let requests = dispatcher.recorder();
+        let writer = writer(dispatcher, WriterOptions::new());
+
+        writer.put("web", event("late", 1_000 + day)).await;
+        writer.put("web", event("c", 3_000)).await;
+        writer.put("web", event("a", 1_000)).await;
+        writer.put("web", event("d", 4_000)).await;
+        writer.put("web", event("b", 2_000)).await;
+        writer.flush().await;
+
+        let batches: Vec<Vec<String>> = sent_batches(&requests)
+            .into_iter()
+            .map(|(_, messages)| messages)
+            .collect();
+        assert_eq!(
+            batches,
+            vec![vec!["a", "b", "c", "d"], vec!["late"]]
+                .into_iter()
+                .map(|batch| batch.into_iter().map(str::to_owned).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert!(writer.shutdown().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_rejected_events_and_retries_throttling() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            failing("ThrottlingException", "Rate exceeded"),
+            failing(
+                "DataAlreadyAcceptedException",
+                "The given batch of log events has already been accepted. \
+                 The next batch can be sent with sequenceToken: 7",
+            ),
+            MockRequestDispatcher::with_status(200).with_json_body(json!({
+                "nextSequenceToken": "8",
+                "rejectedLogEventsInfo": {"tooOldLogEventEndIndex": 1},
+            })),
+        ]);
+        let requests = dispatcher.recorder();
+        let writer = writer(dispatcher, WriterOptions::new());
+
+        writer.put("web", event("a", 1_000)).await;
+        writer.flush().await;
+        writer.put("web", event("old", 1)).await;
+        writer.put("web", event("b", 2_000)).await;
+        let failed = writer.shutdown().await;
+
+        let tokens: Vec<Value> = sent_batches(&requests)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect();
+        assert_eq!(tokens, vec![Value::Null, Value::Null, json!("7")]);
+        assert_eq!(
+            failed,
+            vec![FailedLogEvent {
+                log_stream_name: "web".to_owned(),
+                event: event("old", 1),
+                reason: "rejected as too old".to_owned(),
+            }]
+        );
+    }
+}
@@ -41,7 +41,7 @@ impl CloudWatchLogsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3724,7 +3724,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, CreateExportTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateExportTaskResponse, _>()
     }
 
@@ -3843,7 +3843,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DeleteQueryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteQueryDefinitionResponse, _>()
     }
@@ -3913,7 +3913,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeDestinationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDestinationsResponse, _>()
     }
@@ -3932,7 +3932,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeExportTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeExportTasksResponse, _>()
     }
 
@@ -3950,7 +3950,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeLogGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLogGroupsResponse, _>()
     }
 
@@ -3968,7 +3968,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeLogStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLogStreamsResponse, _>()
     }
 
@@ -3986,7 +3986,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeMetricFiltersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMetricFiltersResponse, _>()
     }
@@ -4005,7 +4005,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeQueriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeQueriesResponse, _>()
     }
 
@@ -4022,7 +4022,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeQueryDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeQueryDefinitionsResponse, _>()
     }
@@ -4041,7 +4041,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeResourcePoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeResourcePoliciesResponse, _>()
     }
@@ -4061,7 +4061,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, DescribeSubscriptionFiltersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSubscriptionFiltersResponse, _>()
     }
@@ -4097,7 +4097,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, FilterLogEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<FilterLogEventsResponse, _>()
     }
 
@@ -4115,7 +4115,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, GetLogEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLogEventsResponse, _>()
     }
 
@@ -4133,7 +4133,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, GetLogGroupFieldsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLogGroupFieldsResponse, _>()
     }
 
@@ -4151,7 +4151,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, GetLogRecordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLogRecordResponse, _>()
     }
 
@@ -4169,7 +4169,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, GetQueryResultsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetQueryResultsResponse, _>()
     }
 
@@ -4187,7 +4187,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, ListTagsLogGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsLogGroupResponse, _>()
     }
 
@@ -4205,7 +4205,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, PutDestinationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutDestinationResponse, _>()
     }
 
@@ -4240,7 +4240,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, PutLogEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutLogEventsResponse, _>()
     }
 
@@ -4274,7 +4274,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, PutQueryDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutQueryDefinitionResponse, _>()
     }
 
@@ -4292,7 +4292,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, PutResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourcePolicyResponse, _>()
     }
 
@@ -4344,7 +4344,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, StartQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartQueryResponse, _>()
     }
 
@@ -4362,7 +4362,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, StopQueryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopQueryResponse, _>()
     }
 
@@ -4397,7 +4397,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
             .sign_and_dispatch(request, TestMetricFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TestMetricFilterResponse, _>()
     }
 
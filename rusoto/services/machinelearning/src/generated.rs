@@ -42,7 +42,7 @@ impl MachineLearningClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3132,7 +3132,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, AddTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
     }
 
@@ -3150,7 +3150,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateBatchPredictionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBatchPredictionOutput, _>()
     }
 
@@ -3168,7 +3168,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateDataSourceFromRDSError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDataSourceFromRDSOutput, _>()
     }
@@ -3191,7 +3191,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateDataSourceFromRedshiftError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDataSourceFromRedshiftOutput, _>()
     }
@@ -3210,7 +3210,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateDataSourceFromS3Error::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDataSourceFromS3Output, _>()
     }
@@ -3229,7 +3229,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateEvaluationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEvaluationOutput, _>()
     }
 
@@ -3247,7 +3247,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateMLModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateMLModelOutput, _>()
     }
 
@@ -3265,7 +3265,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, CreateRealtimeEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRealtimeEndpointOutput, _>()
     }
@@ -3284,7 +3284,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteBatchPredictionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBatchPredictionOutput, _>()
     }
 
@@ -3302,7 +3302,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteDataSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDataSourceOutput, _>()
     }
 
@@ -3320,7 +3320,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteEvaluationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteEvaluationOutput, _>()
     }
 
@@ -3338,7 +3338,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteMLModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteMLModelOutput, _>()
     }
 
@@ -3356,7 +3356,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteRealtimeEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRealtimeEndpointOutput, _>()
     }
@@ -3375,7 +3375,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DeleteTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsOutput, _>()
     }
 
@@ -3393,7 +3393,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DescribeBatchPredictionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeBatchPredictionsOutput, _>()
     }
@@ -3412,7 +3412,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DescribeDataSourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDataSourcesOutput, _>()
     }
 
@@ -3430,7 +3430,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DescribeEvaluationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEvaluationsOutput, _>()
     }
 
@@ -3448,7 +3448,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DescribeMLModelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeMLModelsOutput, _>()
     }
 
@@ -3466,7 +3466,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, DescribeTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsOutput, _>()
     }
 
@@ -3484,7 +3484,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, GetBatchPredictionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetBatchPredictionOutput, _>()
     }
 
@@ -3502,7 +3502,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, GetDataSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDataSourceOutput, _>()
     }
 
@@ -3520,7 +3520,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, GetEvaluationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetEvaluationOutput, _>()
     }
 
@@ -3538,7 +3538,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, GetMLModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMLModelOutput, _>()
     }
 
@@ -3556,7 +3556,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, PredictError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PredictOutput, _>()
     }
 
@@ -3574,7 +3574,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, UpdateBatchPredictionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateBatchPredictionOutput, _>()
     }
 
@@ -3592,7 +3592,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, UpdateDataSourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDataSourceOutput, _>()
     }
 
@@ -3610,7 +3610,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, UpdateEvaluationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEvaluationOutput, _>()
     }
 
@@ -3628,7 +3628,7 @@ impl MachineLearning for MachineLearningClient {
             .sign_and_dispatch(request, UpdateMLModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateMLModelOutput, _>()
     }
 }
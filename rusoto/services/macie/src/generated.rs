@@ -41,7 +41,7 @@ impl MacieClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -693,7 +693,7 @@ impl Macie for MacieClient {
             .sign_and_dispatch(request, AssociateS3ResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateS3ResourcesResult, _>()
     }
 
@@ -728,7 +728,7 @@ impl Macie for MacieClient {
             .sign_and_dispatch(request, DisassociateS3ResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateS3ResourcesResult, _>()
     }
@@ -747,7 +747,7 @@ impl Macie for MacieClient {
             .sign_and_dispatch(request, ListMemberAccountsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListMemberAccountsResult, _>()
     }
 
@@ -765,7 +765,7 @@ impl Macie for MacieClient {
             .sign_and_dispatch(request, ListS3ResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListS3ResourcesResult, _>()
     }
 
@@ -783,7 +783,7 @@ impl Macie for MacieClient {
             .sign_and_dispatch(request, UpdateS3ResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateS3ResourcesResult, _>()
     }
 }
@@ -2467,6 +2467,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2478,13 +2479,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMemberOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMemberError::from_response(response))
         }
     }
@@ -2499,6 +2500,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2510,13 +2512,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateNetworkOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateNetworkError::from_response(response))
         }
     }
@@ -2535,6 +2537,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2546,13 +2549,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateNodeError::from_response(response))
         }
     }
@@ -2570,6 +2573,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2581,13 +2585,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateProposalOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateProposalError::from_response(response))
         }
     }
@@ -2606,6 +2610,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2614,13 +2619,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMemberOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMemberError::from_response(response))
         }
     }
@@ -2640,6 +2645,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2648,13 +2654,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteNodeError::from_response(response))
         }
     }
@@ -2673,6 +2679,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2681,13 +2688,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetMemberOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetMemberError::from_response(response))
         }
     }
@@ -2702,6 +2709,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2710,13 +2718,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetNetworkOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetNetworkError::from_response(response))
         }
     }
@@ -2736,6 +2744,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2744,13 +2753,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetNodeError::from_response(response))
         }
     }
@@ -2769,6 +2778,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2777,13 +2787,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetProposalOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetProposalError::from_response(response))
         }
     }
@@ -2798,6 +2808,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2815,13 +2826,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInvitationsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInvitationsError::from_response(response))
         }
     }
@@ -2839,6 +2850,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2865,13 +2877,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMembersOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMembersError::from_response(response))
         }
     }
@@ -2886,6 +2898,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2912,13 +2925,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListNetworksOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListNetworksError::from_response(response))
         }
     }
@@ -2937,6 +2950,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2957,13 +2971,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListNodesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListNodesError::from_response(response))
         }
     }
@@ -2982,6 +2996,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposalVotes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2999,13 +3014,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProposalVotesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProposalVotesError::from_response(response))
         }
     }
@@ -3023,6 +3038,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3040,13 +3056,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProposalsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProposalsError::from_response(response))
         }
     }
@@ -3064,6 +3080,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3072,13 +3089,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RejectInvitationOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RejectInvitationError::from_response(response))
         }
     }
@@ -3097,6 +3114,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("UpdateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3108,13 +3126,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateMemberOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMemberError::from_response(response))
         }
     }
@@ -3134,6 +3152,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("UpdateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3145,13 +3164,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateNodeOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateNodeError::from_response(response))
         }
     }
@@ -3170,6 +3189,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("VoteOnProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3181,13 +3201,13 @@ impl ManagedBlockchain for ManagedBlockchainClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<VoteOnProposalOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(VoteOnProposalError::from_response(response))
         }
     }
@@ -792,6 +792,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
 
         let mut request =
             SignedRequest::new("PATCH", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("CancelChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -807,13 +808,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelChangeSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelChangeSetError::from_response(response))
         }
     }
@@ -827,6 +828,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeChangeSet";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -842,13 +844,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeChangeSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeChangeSetError::from_response(response))
         }
     }
@@ -862,6 +864,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeEntity";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeEntity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -877,13 +880,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeEntityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeEntityError::from_response(response))
         }
     }
@@ -897,6 +900,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListChangeSets";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListChangeSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -909,13 +913,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListChangeSetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListChangeSetsError::from_response(response))
         }
     }
@@ -929,6 +933,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListEntities";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListEntities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -941,13 +946,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEntitiesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEntitiesError::from_response(response))
         }
     }
@@ -961,6 +966,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("StartChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -973,13 +979,13 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartChangeSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartChangeSetError::from_response(response))
         }
     }
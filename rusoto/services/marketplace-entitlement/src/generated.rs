@@ -43,7 +43,7 @@ impl MarketplaceEntitlementClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -242,7 +242,7 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
             .sign_and_dispatch(request, GetEntitlementsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetEntitlementsResult, _>()
     }
 }
@@ -46,7 +46,7 @@ impl MarketplaceCommerceAnalyticsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -274,7 +274,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
             .sign_and_dispatch(request, GenerateDataSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateDataSetResult, _>()
     }
 
@@ -295,7 +295,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
             .sign_and_dispatch(request, StartSupportDataExportError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartSupportDataExportResult, _>()
     }
@@ -2645,6 +2645,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/outputs", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowOutputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2656,13 +2657,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddFlowOutputsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddFlowOutputsError::from_response(response))
         }
     }
@@ -2676,6 +2677,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/source", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2687,13 +2689,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddFlowSourcesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddFlowSourcesError::from_response(response))
         }
     }
@@ -2710,6 +2712,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowVpcInterfaces");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2721,13 +2724,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddFlowVpcInterfacesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddFlowVpcInterfacesError::from_response(response))
         }
     }
@@ -2741,6 +2744,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("CreateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2752,13 +2756,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFlowError::from_response(response))
         }
     }
@@ -2772,6 +2776,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DeleteFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2780,13 +2785,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFlowError::from_response(response))
         }
     }
@@ -2800,6 +2805,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2808,13 +2814,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFlowError::from_response(response))
         }
     }
@@ -2831,6 +2837,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("GrantFlowEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2842,13 +2849,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GrantFlowEntitlementsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GrantFlowEntitlementsError::from_response(response))
         }
     }
@@ -2862,6 +2869,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/entitlements";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2879,13 +2887,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEntitlementsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEntitlementsError::from_response(response))
         }
     }
@@ -2899,6 +2907,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2916,13 +2925,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFlowsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFlowsError::from_response(response))
         }
     }
@@ -2936,6 +2945,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2944,13 +2954,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -2968,6 +2978,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2976,13 +2987,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RemoveFlowOutputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveFlowOutputError::from_response(response))
         }
     }
@@ -3000,6 +3011,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3008,13 +3020,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RemoveFlowSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveFlowSourceError::from_response(response))
         }
     }
@@ -3032,6 +3044,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowVpcInterface");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3040,13 +3053,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RemoveFlowVpcInterfaceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveFlowVpcInterfaceError::from_response(response))
         }
     }
@@ -3064,6 +3077,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RevokeFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3072,13 +3086,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RevokeFlowEntitlementResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RevokeFlowEntitlementError::from_response(response))
         }
     }
@@ -3092,6 +3106,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/start/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StartFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3100,13 +3115,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartFlowError::from_response(response))
         }
     }
@@ -3120,6 +3135,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/stop/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StopFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3128,13 +3144,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopFlowError::from_response(response))
         }
     }
@@ -3148,6 +3164,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3159,12 +3176,12 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3178,6 +3195,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3192,12 +3210,12 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3211,6 +3229,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3222,13 +3241,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFlowResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFlowError::from_response(response))
         }
     }
@@ -3246,6 +3265,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3257,13 +3277,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFlowEntitlementResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFlowEntitlementError::from_response(response))
         }
     }
@@ -3281,6 +3301,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3292,13 +3313,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFlowOutputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFlowOutputError::from_response(response))
         }
     }
@@ -3316,6 +3337,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3327,13 +3349,13 @@ impl MediaConnect for MediaConnectClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFlowSourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFlowSourceError::from_response(response))
         }
     }
@@ -9672,6 +9672,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchUpdateSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9683,13 +9684,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUpdateScheduleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchUpdateScheduleError::from_response(response))
         }
     }
@@ -9703,6 +9704,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9714,13 +9716,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateChannelError::from_response(response))
         }
     }
@@ -9734,6 +9736,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9745,13 +9748,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateInputError::from_response(response))
         }
     }
@@ -9765,6 +9768,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9776,13 +9780,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInputSecurityGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateInputSecurityGroupError::from_response(response))
         }
     }
@@ -9796,6 +9800,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9807,13 +9812,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMultiplexError::from_response(response))
         }
     }
@@ -9830,6 +9835,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9841,13 +9847,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMultiplexProgramResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateMultiplexProgramError::from_response(response))
         }
     }
@@ -9864,6 +9870,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9875,12 +9882,12 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTagsError::from_response(response))
         }
     }
@@ -9894,6 +9901,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9902,13 +9910,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteChannelError::from_response(response))
         }
     }
@@ -9922,6 +9930,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9930,13 +9939,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInputError::from_response(response))
         }
     }
@@ -9953,6 +9962,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9961,13 +9971,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteInputSecurityGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteInputSecurityGroupError::from_response(response))
         }
     }
@@ -9984,6 +9994,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9992,13 +10003,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMultiplexError::from_response(response))
         }
     }
@@ -10016,6 +10027,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10024,13 +10036,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMultiplexProgramResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteMultiplexProgramError::from_response(response))
         }
     }
@@ -10047,6 +10059,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10055,13 +10068,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteReservationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteReservationError::from_response(response))
         }
     }
@@ -10078,6 +10091,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10086,13 +10100,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteScheduleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteScheduleError::from_response(response))
         }
     }
@@ -10109,6 +10123,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10123,12 +10138,12 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTagsError::from_response(response))
         }
     }
@@ -10142,6 +10157,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10150,13 +10166,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeChannelError::from_response(response))
         }
     }
@@ -10170,6 +10186,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10178,13 +10195,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeInputError::from_response(response))
         }
     }
@@ -10201,6 +10218,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10209,13 +10227,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeInputDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeInputDeviceError::from_response(response))
         }
     }
@@ -10233,6 +10251,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10241,13 +10260,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeInputSecurityGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeInputSecurityGroupError::from_response(response))
         }
     }
@@ -10264,6 +10283,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10272,13 +10292,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeMultiplexError::from_response(response))
         }
     }
@@ -10296,6 +10316,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10304,13 +10325,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeMultiplexProgramResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeMultiplexProgramError::from_response(response))
         }
     }
@@ -10327,6 +10348,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10335,13 +10357,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeOfferingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeOfferingError::from_response(response))
         }
     }
@@ -10358,6 +10380,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10366,13 +10389,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeReservationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeReservationError::from_response(response))
         }
     }
@@ -10389,6 +10412,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10406,13 +10430,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeScheduleResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeScheduleError::from_response(response))
         }
     }
@@ -10426,6 +10450,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10443,13 +10468,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListChannelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListChannelsError::from_response(response))
         }
     }
@@ -10463,6 +10488,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputDevices";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10480,13 +10506,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInputDevicesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInputDevicesError::from_response(response))
         }
     }
@@ -10500,6 +10526,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10517,13 +10544,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInputSecurityGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInputSecurityGroupsError::from_response(response))
         }
     }
@@ -10537,6 +10564,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10554,13 +10582,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInputsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListInputsError::from_response(response))
         }
     }
@@ -10577,6 +10605,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexPrograms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10594,13 +10623,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMultiplexProgramsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMultiplexProgramsError::from_response(response))
         }
     }
@@ -10614,6 +10643,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10631,13 +10661,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMultiplexesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListMultiplexesError::from_response(response))
         }
     }
@@ -10651,6 +10681,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/offerings";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10698,13 +10729,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListOfferingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListOfferingsError::from_response(response))
         }
     }
@@ -10718,6 +10749,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/reservations";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListReservations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10759,13 +10791,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListReservationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListReservationsError::from_response(response))
         }
     }
@@ -10782,6 +10814,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10790,13 +10823,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -10813,6 +10846,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("PurchaseOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10824,13 +10858,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PurchaseOfferingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PurchaseOfferingError::from_response(response))
         }
     }
@@ -10847,6 +10881,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10855,13 +10890,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartChannelError::from_response(response))
         }
     }
@@ -10878,6 +10913,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10886,13 +10922,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartMultiplexError::from_response(response))
         }
     }
@@ -10909,6 +10945,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10917,13 +10954,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopChannelError::from_response(response))
         }
     }
@@ -10940,6 +10977,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10948,13 +10986,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopMultiplexError::from_response(response))
         }
     }
@@ -10968,6 +11006,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10979,13 +11018,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateChannelResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateChannelError::from_response(response))
         }
     }
@@ -11002,6 +11041,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannelClass");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11013,13 +11053,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateChannelClassResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateChannelClassError::from_response(response))
         }
     }
@@ -11033,6 +11073,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11044,13 +11085,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInputResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateInputError::from_response(response))
         }
     }
@@ -11067,6 +11108,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11078,13 +11120,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInputDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateInputDeviceError::from_response(response))
         }
     }
@@ -11101,6 +11143,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11112,13 +11155,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInputSecurityGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateInputSecurityGroupError::from_response(response))
         }
     }
@@ -11135,6 +11178,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11146,13 +11190,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateMultiplexResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMultiplexError::from_response(response))
         }
     }
@@ -11170,6 +11214,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11181,13 +11226,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateMultiplexProgramResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateMultiplexProgramError::from_response(response))
         }
     }
@@ -11204,6 +11249,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11215,13 +11261,13 @@ impl MediaLive for MediaLiveClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateReservationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateReservationError::from_response(response))
         }
     }
@@ -1963,6 +1963,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1974,13 +1975,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateAssetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateAssetError::from_response(response))
         }
     }
@@ -1996,6 +1997,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2007,13 +2009,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePackagingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePackagingConfigurationError::from_response(response))
         }
     }
@@ -2028,6 +2030,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2039,13 +2042,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePackagingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreatePackagingGroupError::from_response(response))
         }
     }
@@ -2060,6 +2063,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2068,13 +2072,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteAssetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteAssetError::from_response(response))
         }
     }
@@ -2090,6 +2094,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2098,13 +2103,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePackagingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePackagingConfigurationError::from_response(response))
         }
     }
@@ -2119,6 +2124,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2127,13 +2133,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePackagingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeletePackagingGroupError::from_response(response))
         }
     }
@@ -2147,6 +2153,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribeAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2155,13 +2162,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAssetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAssetError::from_response(response))
         }
     }
@@ -2178,6 +2185,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2186,13 +2194,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribePackagingConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribePackagingConfigurationError::from_response(response))
         }
     }
@@ -2206,6 +2214,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2214,13 +2223,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribePackagingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribePackagingGroupError::from_response(response))
         }
     }
@@ -2234,6 +2243,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/assets";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2254,13 +2264,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAssetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAssetsError::from_response(response))
         }
     }
@@ -2275,6 +2285,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_configurations";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2295,13 +2306,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPackagingConfigurationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPackagingConfigurationsError::from_response(response))
         }
     }
@@ -2315,6 +2326,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_groups";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2332,13 +2344,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListPackagingGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListPackagingGroupsError::from_response(response))
         }
     }
@@ -2352,6 +2364,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2360,13 +2373,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -2381,6 +2394,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2392,12 +2406,12 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -2412,6 +2426,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2426,12 +2441,12 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -2445,6 +2460,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("UpdatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2456,13 +2472,13 @@ impl MediaPackageVod for MediaPackageVodClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdatePackagingGroupResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdatePackagingGroupError::from_response(response))
         }
     }
@@ -41,7 +41,7 @@ impl MediaStoreClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1601,7 +1601,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, CreateContainerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateContainerOutput, _>()
     }
 
@@ -1619,7 +1619,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DeleteContainerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteContainerOutput, _>()
     }
 
@@ -1637,7 +1637,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DeleteContainerPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteContainerPolicyOutput, _>()
     }
 
@@ -1655,7 +1655,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DeleteCorsPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteCorsPolicyOutput, _>()
     }
 
@@ -1673,7 +1673,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DeleteLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteLifecyclePolicyOutput, _>()
     }
 
@@ -1691,7 +1691,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DeleteMetricPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteMetricPolicyOutput, _>()
     }
 
@@ -1709,7 +1709,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, DescribeContainerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeContainerOutput, _>()
     }
 
@@ -1727,7 +1727,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, GetContainerPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetContainerPolicyOutput, _>()
     }
 
@@ -1745,7 +1745,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, GetCorsPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCorsPolicyOutput, _>()
     }
 
@@ -1763,7 +1763,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, GetLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLifecyclePolicyOutput, _>()
     }
 
@@ -1781,7 +1781,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, GetMetricPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMetricPolicyOutput, _>()
     }
 
@@ -1799,7 +1799,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, ListContainersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListContainersOutput, _>()
     }
 
@@ -1817,7 +1817,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -1835,7 +1835,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, PutContainerPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutContainerPolicyOutput, _>()
     }
 
@@ -1853,7 +1853,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, PutCorsPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutCorsPolicyOutput, _>()
     }
 
@@ -1871,7 +1871,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, PutLifecyclePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutLifecyclePolicyOutput, _>()
     }
 
@@ -1889,7 +1889,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, PutMetricPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutMetricPolicyOutput, _>()
     }
 
@@ -1907,7 +1907,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, StartAccessLoggingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartAccessLoggingOutput, _>()
     }
 
@@ -1925,7 +1925,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, StopAccessLoggingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopAccessLoggingOutput, _>()
     }
 
@@ -1943,7 +1943,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -1961,7 +1961,7 @@ impl MediaStore for MediaStoreClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 }
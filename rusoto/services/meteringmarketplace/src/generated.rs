@@ -43,7 +43,7 @@ impl MarketplaceMeteringClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -553,7 +553,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
             .sign_and_dispatch(request, BatchMeterUsageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<BatchMeterUsageResult, _>()
     }
 
@@ -571,7 +571,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
             .sign_and_dispatch(request, MeterUsageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<MeterUsageResult, _>()
     }
 
@@ -589,7 +589,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
             .sign_and_dispatch(request, RegisterUsageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterUsageResult, _>()
     }
 
@@ -607,7 +607,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
             .sign_and_dispatch(request, ResolveCustomerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCustomerResult, _>()
     }
 }
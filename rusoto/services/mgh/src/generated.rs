@@ -41,7 +41,7 @@ impl MigrationHubClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2270,7 +2270,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, AssociateCreatedArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateCreatedArtifactResult, _>()
     }
@@ -2293,7 +2293,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, AssociateDiscoveredResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateDiscoveredResourceResult, _>()
     }
@@ -2313,7 +2313,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, CreateProgressUpdateStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateProgressUpdateStreamResult, _>()
     }
@@ -2333,7 +2333,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, DeleteProgressUpdateStreamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteProgressUpdateStreamResult, _>()
     }
@@ -2352,7 +2352,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, DescribeApplicationStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeApplicationStateResult, _>()
     }
@@ -2371,7 +2371,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, DescribeMigrationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeMigrationTaskResult, _>()
     }
 
@@ -2393,7 +2393,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, DisassociateCreatedArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateCreatedArtifactResult, _>()
     }
@@ -2418,7 +2418,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, DisassociateDiscoveredResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateDiscoveredResourceResult, _>()
     }
@@ -2437,7 +2437,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ImportMigrationTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportMigrationTaskResult, _>()
     }
 
@@ -2455,7 +2455,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ListApplicationStatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationStatesResult, _>()
     }
 
@@ -2473,7 +2473,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ListCreatedArtifactsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCreatedArtifactsResult, _>()
     }
 
@@ -2491,7 +2491,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ListDiscoveredResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDiscoveredResourcesResult, _>()
     }
@@ -2510,7 +2510,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ListMigrationTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListMigrationTasksResult, _>()
     }
 
@@ -2528,7 +2528,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, ListProgressUpdateStreamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListProgressUpdateStreamsResult, _>()
     }
@@ -2547,7 +2547,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, NotifyApplicationStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<NotifyApplicationStateResult, _>()
     }
@@ -2566,7 +2566,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, NotifyMigrationTaskStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<NotifyMigrationTaskStateResult, _>()
     }
@@ -2585,7 +2585,7 @@ impl MigrationHub for MigrationHubClient {
             .sign_and_dispatch(request, PutResourceAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourceAttributesResult, _>()
     }
 }
@@ -42,7 +42,7 @@ impl MigrationHubConfigClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -430,7 +430,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
             .sign_and_dispatch(request, CreateHomeRegionControlError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateHomeRegionControlResult, _>()
     }
@@ -453,7 +453,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
             .sign_and_dispatch(request, DescribeHomeRegionControlsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeHomeRegionControlsResult, _>()
     }
@@ -473,7 +473,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
             .sign_and_dispatch(request, GetHomeRegionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetHomeRegionResult, _>()
     }
 }
@@ -1000,6 +1000,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("CreateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1028,13 +1029,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateProjectResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateProjectError::from_response(response))
         }
     }
@@ -1049,6 +1050,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("DELETE", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1059,13 +1061,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteProjectResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteProjectError::from_response(response))
         }
     }
@@ -1080,6 +1082,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1090,13 +1093,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeBundleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeBundleError::from_response(response))
         }
     }
@@ -1111,6 +1114,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1128,13 +1132,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeProjectResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeProjectError::from_response(response))
         }
     }
@@ -1149,6 +1153,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1168,13 +1173,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ExportBundleResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ExportBundleError::from_response(response))
         }
     }
@@ -1189,6 +1194,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1199,13 +1205,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ExportProjectResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ExportProjectError::from_response(response))
         }
     }
@@ -1220,6 +1226,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListBundles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1239,13 +1246,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListBundlesResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListBundlesError::from_response(response))
         }
     }
@@ -1260,6 +1267,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListProjects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1279,13 +1287,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListProjectsResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListProjectsError::from_response(response))
         }
     }
@@ -1300,6 +1308,7 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1320,13 +1329,13 @@ impl Mobile for MobileClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateProjectResult, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateProjectError::from_response(response))
         }
     }
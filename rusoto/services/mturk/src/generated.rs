@@ -42,7 +42,7 @@ impl MechanicalTurkClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3400,7 +3400,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, AcceptQualificationRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AcceptQualificationRequestResponse, _>()
     }
@@ -3422,7 +3422,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ApproveAssignmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ApproveAssignmentResponse, _>()
     }
 
@@ -3449,7 +3449,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateQualificationWithWorkerResponse, _>()
     }
@@ -3477,7 +3477,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateAdditionalAssignmentsForHITResponse, _>()
     }
@@ -3496,7 +3496,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, CreateHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHITResponse, _>()
     }
 
@@ -3517,7 +3517,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, CreateHITTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHITTypeResponse, _>()
     }
 
@@ -3538,7 +3538,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, CreateHITWithHITTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateHITWithHITTypeResponse, _>()
     }
@@ -3560,7 +3560,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, CreateQualificationTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateQualificationTypeResponse, _>()
     }
@@ -3582,7 +3582,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, CreateWorkerBlockError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkerBlockResponse, _>()
     }
 
@@ -3600,7 +3600,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, DeleteHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHITResponse, _>()
     }
 
@@ -3621,7 +3621,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, DeleteQualificationTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteQualificationTypeResponse, _>()
     }
@@ -3643,7 +3643,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, DeleteWorkerBlockError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkerBlockResponse, _>()
     }
 
@@ -3670,7 +3670,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateQualificationFromWorkerResponse, _>()
     }
@@ -3690,7 +3690,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetAccountBalanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAccountBalanceResponse, _>()
     }
 
@@ -3711,7 +3711,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetAssignmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAssignmentResponse, _>()
     }
 
@@ -3732,7 +3732,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetFileUploadURLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFileUploadURLResponse, _>()
     }
 
@@ -3750,7 +3750,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetHITResponse, _>()
     }
 
@@ -3771,7 +3771,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetQualificationScoreError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetQualificationScoreResponse, _>()
     }
@@ -3793,7 +3793,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, GetQualificationTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetQualificationTypeResponse, _>()
     }
@@ -3815,7 +3815,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListAssignmentsForHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssignmentsForHITResponse, _>()
     }
@@ -3837,7 +3837,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListBonusPaymentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListBonusPaymentsResponse, _>()
     }
 
@@ -3855,7 +3855,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListHITsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListHITsResponse, _>()
     }
 
@@ -3877,7 +3877,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListHITsForQualificationTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListHITsForQualificationTypeResponse, _>()
     }
@@ -3900,7 +3900,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListQualificationRequestsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListQualificationRequestsResponse, _>()
     }
@@ -3922,7 +3922,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListQualificationTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListQualificationTypesResponse, _>()
     }
@@ -3947,7 +3947,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListReviewPolicyResultsForHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListReviewPolicyResultsForHITResponse, _>()
     }
@@ -3969,7 +3969,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListReviewableHITsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListReviewableHITsResponse, _>()
     }
 
@@ -3990,7 +3990,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, ListWorkerBlocksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWorkerBlocksResponse, _>()
     }
 
@@ -4017,7 +4017,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListWorkersWithQualificationTypeResponse, _>()
     }
@@ -4039,7 +4039,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, NotifyWorkersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<NotifyWorkersResponse, _>()
     }
 
@@ -4060,7 +4060,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, RejectAssignmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RejectAssignmentResponse, _>()
     }
 
@@ -4082,7 +4082,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, RejectQualificationRequestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RejectQualificationRequestResponse, _>()
     }
@@ -4101,7 +4101,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, SendBonusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendBonusResponse, _>()
     }
 
@@ -4123,7 +4123,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, SendTestEventNotificationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SendTestEventNotificationResponse, _>()
     }
@@ -4145,7 +4145,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, UpdateExpirationForHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateExpirationForHITResponse, _>()
     }
@@ -4167,7 +4167,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, UpdateHITReviewStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateHITReviewStatusResponse, _>()
     }
@@ -4189,7 +4189,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, UpdateHITTypeOfHITError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateHITTypeOfHITResponse, _>()
     }
 
@@ -4211,7 +4211,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, UpdateNotificationSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateNotificationSettingsResponse, _>()
     }
@@ -4233,7 +4233,7 @@ impl MechanicalTurk for MechanicalTurkClient {
             .sign_and_dispatch(request, UpdateQualificationTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateQualificationTypeResponse, _>()
     }
@@ -52,7 +52,7 @@ impl NeptuneClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -8540,13 +8540,13 @@ impl VpcSecurityGroupMembershipListDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified IAM role Amazon Resource Name (ARN) is already associated with the specified DB cluster.</p>
-    DBClusterRoleAlreadyExistsFault(String),
+    DBClusterRoleAlreadyExistsFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of IAM roles that can be associated with the specified DB cluster.</p>
-    DBClusterRoleQuotaExceededFault(String),
+    DBClusterRoleQuotaExceededFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl AddRoleToDBClusterError {
@@ -8559,27 +8559,29 @@ impl AddRoleToDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            AddRoleToDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8597,6 +8599,20 @@ impl AddRoleToDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddRoleToDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddRoleToDBClusterError {
     #[allow(unused_variables)]
@@ -8620,9 +8636,9 @@ impl Error for AddRoleToDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum AddSourceIdentifierToSubscriptionError {
     /// <p>The source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The designated subscription could not be found.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl AddSourceIdentifierToSubscriptionError {
@@ -8638,14 +8654,14 @@ impl AddSourceIdentifierToSubscriptionError {
                     "SourceNotFound" => {
                         return RusotoError::Service(
                             AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8663,6 +8679,18 @@ impl AddSourceIdentifierToSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AddSourceIdentifierToSubscriptionError {
     #[allow(unused_variables)]
@@ -8682,11 +8710,11 @@ impl Error for AddSourceIdentifierToSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl AddTagsToResourceError {
@@ -8699,17 +8727,23 @@ impl AddTagsToResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8726,6 +8760,15 @@ impl AddTagsToResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsToResourceError {
     #[allow(unused_variables)]
@@ -8742,7 +8785,7 @@ impl Error for AddTagsToResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -8758,7 +8801,7 @@ impl ApplyPendingMaintenanceActionError {
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8776,6 +8819,15 @@ impl ApplyPendingMaintenanceActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ApplyPendingMaintenanceActionError {
     #[allow(unused_variables)]
@@ -8792,11 +8844,11 @@ impl Error for ApplyPendingMaintenanceActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterParameterGroupError {
@@ -8812,21 +8864,21 @@ impl CopyDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8844,6 +8896,21 @@ impl CopyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -8866,17 +8933,17 @@ impl Error for CopyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
     /// <p>User already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>Error accessing KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterSnapshotError {
@@ -8890,42 +8957,42 @@ impl CopyDBClusterSnapshotError {
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8943,6 +9010,24 @@ impl CopyDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -8974,11 +9059,11 @@ impl Error for CopyDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBParameterGroupError {
@@ -8992,21 +9077,21 @@ impl CopyDBParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9024,6 +9109,21 @@ impl CopyDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CopyDBParameterGroupError {
     #[allow(unused_variables)]
@@ -9046,35 +9146,35 @@ impl Error for CopyDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
     /// <p>User already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBClusterParameterGroupName</i> does not refer to an existing DB Cluster parameter group.</p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>User attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You may be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it is in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>Error accessing KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterError {
@@ -9087,85 +9187,101 @@ impl CreateDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBClusterError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9182,6 +9298,33 @@ impl CreateDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterError {
     #[allow(unused_variables)]
@@ -9218,9 +9361,9 @@ impl Error for CreateDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterParameterGroupError {
@@ -9236,14 +9379,14 @@ impl CreateDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9261,6 +9404,18 @@ impl CreateDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -9280,15 +9435,15 @@ impl Error for CreateDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>User already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterSnapshotError {
@@ -9302,35 +9457,35 @@ impl CreateDBClusterSnapshotError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9348,6 +9503,25 @@ impl CreateDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -9376,41 +9550,41 @@ impl Error for CreateDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
     /// <p>Specified CIDRIP or EC2 security group is not authorized for the specified DB security group.</p> <p>Neptune may not also be authorized via IAM to perform necessary actions on your behalf.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>User already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSecurityGroupName</i> does not refer to an existing DB security group.</p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <i>Domain</i> does not refer to an existing Active Directory Domain.</p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>Specified DB instance class is not available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>Error accessing KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The designated option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p> <i>StorageType</i> specified cannot be associated with the DB Instance.</p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl CreateDBInstanceError {
@@ -9423,107 +9597,121 @@ impl CreateDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::InstanceQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9541,6 +9729,36 @@ impl CreateDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InstanceQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBInstanceError {
     #[allow(unused_variables)]
@@ -9586,9 +9804,9 @@ impl Error for CreateDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBParameterGroupError {
@@ -9602,14 +9820,14 @@ impl CreateDBParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9627,6 +9845,18 @@ impl CreateDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBParameterGroupError {
     #[allow(unused_variables)]
@@ -9646,15 +9876,15 @@ impl Error for CreateDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
     /// <p> <i>DBSubnetGroupName</i> is already used by an existing DB subnet group.</p>
-    DBSubnetGroupAlreadyExistsFault(String),
+    DBSubnetGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB subnet groups.</p>
-    DBSubnetGroupQuotaExceededFault(String),
+    DBSubnetGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of subnets in a DB subnet groups.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
 }
 
 impl CreateDBSubnetGroupError {
@@ -9668,34 +9898,34 @@ impl CreateDBSubnetGroupError {
                     "DBSubnetGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9712,6 +9942,23 @@ impl CreateDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -9738,19 +9985,19 @@ impl Error for CreateDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
     /// <p>You have exceeded the number of events you can subscribe to.</p>
-    EventSubscriptionQuotaExceededFault(String),
+    EventSubscriptionQuotaExceededFault(XmlErrorMessage),
     /// <p>The SNS topic is invalid.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>There is no SNS authorization.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>The ARN of the SNS topic could not be found.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>This subscription already exists.</p>
-    SubscriptionAlreadyExistFault(String),
+    SubscriptionAlreadyExistFault(XmlErrorMessage),
     /// <p>The designated subscription category could not be found.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
 }
 
 impl CreateEventSubscriptionError {
@@ -9764,47 +10011,49 @@ impl CreateEventSubscriptionError {
                     "EventSubscriptionQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SourceNotFound" => {
                         return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            CreateEventSubscriptionError::SourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9822,6 +10071,25 @@ impl CreateEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SourceNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateEventSubscriptionError {
     #[allow(unused_variables)]
@@ -9852,15 +10120,15 @@ impl Error for CreateEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>User already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterError {
@@ -9873,31 +10141,35 @@ impl DeleteDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBClusterError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9914,6 +10186,21 @@ impl DeleteDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterError {
     #[allow(unused_variables)]
@@ -9936,9 +10223,9 @@ impl Error for DeleteDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterParameterGroupError {
@@ -9954,14 +10241,14 @@ impl DeleteDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9979,6 +10266,18 @@ impl DeleteDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -9998,9 +10297,9 @@ impl Error for DeleteDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterSnapshotError {
@@ -10014,14 +10313,14 @@ impl DeleteDBClusterSnapshotError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10039,6 +10338,18 @@ impl DeleteDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -10058,15 +10369,15 @@ impl Error for DeleteDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSnapshotIdentifier</i> is already used by an existing snapshot.</p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBInstanceError {
@@ -10079,31 +10390,37 @@ impl DeleteDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            DeleteDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10120,6 +10437,17 @@ impl DeleteDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBInstanceError {
     #[allow(unused_variables)]
@@ -10140,9 +10468,9 @@ impl Error for DeleteDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBParameterGroupError {
@@ -10156,14 +10484,14 @@ impl DeleteDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10181,6 +10509,18 @@ impl DeleteDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBParameterGroupError {
     #[allow(unused_variables)]
@@ -10200,11 +10540,11 @@ impl Error for DeleteDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it is in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The DB subnet is not in the <i>available</i> state.</p>
-    InvalidDBSubnetStateFault(String),
+    InvalidDBSubnetStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBSubnetGroupError {
@@ -10218,21 +10558,21 @@ impl DeleteDBSubnetGroupError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10250,6 +10590,17 @@ impl DeleteDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -10272,9 +10623,9 @@ impl Error for DeleteDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
     /// <p>The event subscription is in an invalid state.</p>
-    InvalidEventSubscriptionStateFault(String),
+    InvalidEventSubscriptionStateFault(XmlErrorMessage),
     /// <p>The designated subscription could not be found.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteEventSubscriptionError {
@@ -10288,14 +10639,14 @@ impl DeleteEventSubscriptionError {
                     "InvalidEventSubscriptionState" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10313,6 +10664,18 @@ impl DeleteEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteEventSubscriptionError {
     #[allow(unused_variables)]
@@ -10332,7 +10695,7 @@ impl Error for DeleteEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParameterGroupsError {
@@ -10348,7 +10711,7 @@ impl DescribeDBClusterParameterGroupsError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10366,6 +10729,15 @@ impl DescribeDBClusterParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParameterGroupsError {
     #[allow(unused_variables)]
@@ -10382,7 +10754,7 @@ impl Error for DescribeDBClusterParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParametersError {
@@ -10398,7 +10770,7 @@ impl DescribeDBClusterParametersError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10416,6 +10788,15 @@ impl DescribeDBClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParametersError {
     #[allow(unused_variables)]
@@ -10432,7 +10813,7 @@ impl Error for DescribeDBClusterParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotAttributesError {
@@ -10447,7 +10828,7 @@ impl DescribeDBClusterSnapshotAttributesError {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -10464,6 +10845,15 @@ impl DescribeDBClusterSnapshotAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     #[allow(unused_variables)]
@@ -10480,7 +10870,7 @@ impl Error for DescribeDBClusterSnapshotAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotsError {
@@ -10496,7 +10886,7 @@ impl DescribeDBClusterSnapshotsError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10514,6 +10904,15 @@ impl DescribeDBClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -10530,7 +10929,7 @@ impl Error for DescribeDBClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClustersError {
@@ -10543,7 +10942,9 @@ impl DescribeDBClustersError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                            DescribeDBClustersError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10560,6 +10961,13 @@ impl DescribeDBClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBClustersError {
     #[allow(unused_variables)]
@@ -10596,6 +11004,11 @@ impl DescribeDBEngineVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeDBEngineVersionsError {
     #[allow(unused_variables)]
@@ -10608,7 +11021,7 @@ impl Error for DescribeDBEngineVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBInstancesError {
@@ -10621,7 +11034,9 @@ impl DescribeDBInstancesError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                            DescribeDBInstancesError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10638,6 +11053,13 @@ impl DescribeDBInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBInstancesError {
     #[allow(unused_variables)]
@@ -10652,7 +11074,7 @@ impl Error for DescribeDBInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParameterGroupsError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBParameterGroupsError {
@@ -10666,7 +11088,7 @@ impl DescribeDBParameterGroupsError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10684,6 +11106,15 @@ impl DescribeDBParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBParameterGroupsError {
     #[allow(unused_variables)]
@@ -10700,7 +11131,7 @@ impl Error for DescribeDBParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParametersError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBParametersError {
@@ -10714,7 +11145,7 @@ impl DescribeDBParametersError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10732,6 +11163,15 @@ impl DescribeDBParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBParametersError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBParametersError {
     #[allow(unused_variables)]
@@ -10748,7 +11188,7 @@ impl Error for DescribeDBParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSubnetGroupsError {
@@ -10762,7 +11202,7 @@ impl DescribeDBSubnetGroupsError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10780,6 +11220,15 @@ impl DescribeDBSubnetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBSubnetGroupsError {
     #[allow(unused_variables)]
@@ -10820,6 +11269,11 @@ impl DescribeEngineDefaultClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     #[allow(unused_variables)]
@@ -10856,6 +11310,11 @@ impl DescribeEngineDefaultParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEngineDefaultParametersError {
     #[allow(unused_variables)]
@@ -10890,6 +11349,11 @@ impl DescribeEventCategoriesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
@@ -10902,7 +11366,7 @@ impl Error for DescribeEventCategoriesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The designated subscription could not be found.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -10918,7 +11382,7 @@ impl DescribeEventSubscriptionsError {
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10936,6 +11400,15 @@ impl DescribeEventSubscriptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEventSubscriptionsError {
     #[allow(unused_variables)]
@@ -10974,6 +11447,11 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -11010,6 +11488,11 @@ impl DescribeOrderableDBInstanceOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     #[allow(unused_variables)]
@@ -11022,7 +11505,7 @@ impl Error for DescribeOrderableDBInstanceOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -11038,7 +11521,7 @@ impl DescribePendingMaintenanceActionsError {
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11056,6 +11539,15 @@ impl DescribePendingMaintenanceActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribePendingMaintenanceActionsError {
     #[allow(unused_variables)]
@@ -11072,9 +11564,9 @@ impl Error for DescribePendingMaintenanceActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeValidDBInstanceModificationsError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl DescribeValidDBInstanceModificationsError {
@@ -11090,14 +11582,14 @@ impl DescribeValidDBInstanceModificationsError {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11115,6 +11607,18 @@ impl DescribeValidDBInstanceModificationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeValidDBInstanceModificationsError {
     #[allow(unused_variables)]
@@ -11134,11 +11638,11 @@ impl Error for DescribeValidDBInstanceModificationsError {}
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl FailoverDBClusterError {
@@ -11151,20 +11655,22 @@ impl FailoverDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            FailoverDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11182,6 +11688,15 @@ impl FailoverDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for FailoverDBClusterError {
     #[allow(unused_variables)]
@@ -11200,11 +11715,11 @@ impl Error for FailoverDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -11217,17 +11732,23 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -11244,6 +11765,15 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -11260,27 +11790,27 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
     /// <p>User already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBClusterParameterGroupName</i> does not refer to an existing DB Cluster parameter group.</p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the DB security group does not allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it is in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterError {
@@ -11293,63 +11823,75 @@ impl ModifyDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -11366,6 +11908,25 @@ impl ModifyDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterError {
     #[allow(unused_variables)]
@@ -11396,9 +11957,9 @@ impl Error for ModifyDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterParameterGroupError {
@@ -11414,14 +11975,14 @@ impl ModifyDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11439,6 +12000,18 @@ impl ModifyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -11458,11 +12031,11 @@ impl Error for ModifyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with.</p>
-    SharedSnapshotQuotaExceededFault(String),
+    SharedSnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterSnapshotAttributeError {
@@ -11478,19 +12051,19 @@ impl ModifyDBClusterSnapshotAttributeError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11508,6 +12081,21 @@ impl ModifyDBClusterSnapshotAttributeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -11530,37 +12118,37 @@ impl Error for ModifyDBClusterSnapshotAttributeError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
     /// <p>Specified CIDRIP or EC2 security group is not authorized for the specified DB security group.</p> <p>Neptune may not also be authorized via IAM to perform necessary actions on your behalf.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <i>CertificateIdentifier</i> does not refer to an existing certificate.</p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
     /// <p>User already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSecurityGroupName</i> does not refer to an existing DB security group.</p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB upgrade failed because a resource the DB depends on could not be modified.</p>
-    DBUpgradeDependencyFailureFault(String),
+    DBUpgradeDependencyFailureFault(XmlErrorMessage),
     /// <p> <i>Domain</i> does not refer to an existing Active Directory Domain.</p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>Specified DB instance class is not available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the DB security group does not allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The designated option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p> <i>StorageType</i> specified cannot be associated with the DB Instance.</p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl ModifyDBInstanceError {
@@ -11573,101 +12161,111 @@ impl ModifyDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::CertificateNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11685,6 +12283,34 @@ impl ModifyDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBInstanceError {
     #[allow(unused_variables)]
@@ -11730,9 +12356,9 @@ impl Error for ModifyDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBParameterGroupError {
@@ -11746,14 +12372,14 @@ impl ModifyDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11771,6 +12397,18 @@ impl ModifyDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBParameterGroupError {
     #[allow(unused_variables)]
@@ -11790,15 +12428,15 @@ impl Error for ModifyDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed number of subnets in a DB subnet groups.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet is already in use in the Availability Zone.</p>
-    SubnetAlreadyInUse(String),
+    SubnetAlreadyInUse(XmlErrorMessage),
 }
 
 impl ModifyDBSubnetGroupError {
@@ -11812,32 +12450,32 @@ impl ModifyDBSubnetGroupError {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetAlreadyInUse" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -11854,6 +12492,19 @@ impl ModifyDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -11878,17 +12529,17 @@ impl Error for ModifyDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
     /// <p>You have exceeded the number of events you can subscribe to.</p>
-    EventSubscriptionQuotaExceededFault(String),
+    EventSubscriptionQuotaExceededFault(XmlErrorMessage),
     /// <p>The SNS topic is invalid.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>There is no SNS authorization.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>The ARN of the SNS topic could not be found.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The designated subscription category could not be found.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
     /// <p>The designated subscription could not be found.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyEventSubscriptionError {
@@ -11902,42 +12553,42 @@ impl ModifyEventSubscriptionError {
                     "EventSubscriptionQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11955,6 +12606,24 @@ impl ModifyEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyEventSubscriptionError {
     #[allow(unused_variables)]
@@ -11984,9 +12653,9 @@ impl Error for ModifyEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl PromoteReadReplicaDBClusterError {
@@ -12002,14 +12671,14 @@ impl PromoteReadReplicaDBClusterError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12027,6 +12696,18 @@ impl PromoteReadReplicaDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PromoteReadReplicaDBClusterError {
     #[allow(unused_variables)]
@@ -12046,9 +12727,9 @@ impl Error for PromoteReadReplicaDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl RebootDBInstanceError {
@@ -12061,13 +12742,15 @@ impl RebootDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            RebootDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12085,6 +12768,14 @@ impl RebootDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RebootDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebootDBInstanceError {
     #[allow(unused_variables)]
@@ -12100,11 +12791,11 @@ impl Error for RebootDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified IAM role Amazon Resource Name (ARN) is not associated with the specified DB cluster.</p>
-    DBClusterRoleNotFoundFault(String),
+    DBClusterRoleNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl RemoveRoleFromDBClusterError {
@@ -12118,21 +12809,21 @@ impl RemoveRoleFromDBClusterError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterRoleNotFound" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12150,6 +12841,19 @@ impl RemoveRoleFromDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveRoleFromDBClusterError {
     #[allow(unused_variables)]
@@ -12172,9 +12876,9 @@ impl Error for RemoveRoleFromDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveSourceIdentifierFromSubscriptionError {
     /// <p>The source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The designated subscription could not be found.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveSourceIdentifierFromSubscriptionError {
@@ -12190,14 +12894,14 @@ impl RemoveSourceIdentifierFromSubscriptionError {
                     "SourceNotFound" => {
                         return RusotoError::Service(
                             RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12215,6 +12919,18 @@ impl RemoveSourceIdentifierFromSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveSourceIdentifierFromSubscriptionError {
     #[allow(unused_variables)]
@@ -12234,11 +12950,11 @@ impl Error for RemoveSourceIdentifierFromSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveTagsFromResourceError {
@@ -12252,21 +12968,21 @@ impl RemoveTagsFromResourceError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12284,6 +13000,15 @@ impl RemoveTagsFromResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsFromResourceError {
     #[allow(unused_variables)]
@@ -12306,9 +13031,9 @@ impl Error for RemoveTagsFromResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetDBClusterParameterGroupError {
@@ -12324,14 +13049,14 @@ impl ResetDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12349,6 +13074,18 @@ impl ResetDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -12368,9 +13105,9 @@ impl Error for ResetDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetDBParameterGroupError {
@@ -12384,14 +13121,14 @@ impl ResetDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12409,6 +13146,18 @@ impl ResetDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetDBParameterGroupError {
     #[allow(unused_variables)]
@@ -12428,37 +13177,37 @@ impl Error for ResetDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
     /// <p>User already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBClusterParameterGroupName</i> does not refer to an existing DB Cluster parameter group.</p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>User attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster does not have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You may be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot does not allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>Cannot restore from vpc backup to non-vpc DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>Error accessing KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The designated option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterFromSnapshotError {
@@ -12474,108 +13223,110 @@ impl RestoreDBClusterFromSnapshotError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12593,6 +13344,56 @@ impl RestoreDBClusterFromSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterFromSnapshotError {
     #[allow(unused_variables)]
@@ -12652,39 +13453,39 @@ impl Error for RestoreDBClusterFromSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
     /// <p>User already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBClusterParameterGroupName</i> does not refer to an existing DB Cluster parameter group.</p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>User attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster does not have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You may be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot does not allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>Cannot restore from vpc backup to non-vpc DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>DB subnet group does not cover all Availability Zones after it is created because users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>Error accessing KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The designated option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterToPointInTimeError {
@@ -12700,113 +13501,115 @@ impl RestoreDBClusterToPointInTimeError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => return RusotoError::Service(
                         RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12824,6 +13627,61 @@ impl RestoreDBClusterToPointInTimeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterToPointInTimeError {
     #[allow(unused_variables)]
@@ -12886,11 +13744,11 @@ impl Error for RestoreDBClusterToPointInTimeError {}
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StartDBClusterError {
@@ -12903,17 +13761,21 @@ impl StartDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12930,6 +13792,15 @@ impl StartDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartDBClusterError {
     #[allow(unused_variables)]
@@ -12946,11 +13817,11 @@ impl Error for StartDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster is not in a valid state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StopDBClusterError {
@@ -12963,17 +13834,21 @@ impl StopDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12990,6 +13865,15 @@ impl StopDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopDBClusterError {
     #[allow(unused_variables)]
@@ -2926,6 +2926,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2937,13 +2938,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateCustomerGatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateCustomerGatewayError::from_response(response))
         }
     }
@@ -2960,6 +2961,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2971,13 +2973,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateLinkError::from_response(response))
         }
     }
@@ -2994,6 +2996,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3005,13 +3008,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeviceError::from_response(response))
         }
     }
@@ -3025,6 +3028,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3036,13 +3040,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGlobalNetworkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGlobalNetworkError::from_response(response))
         }
     }
@@ -3059,6 +3063,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3070,13 +3075,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateLinkError::from_response(response))
         }
     }
@@ -3093,6 +3098,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3104,13 +3110,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSiteResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSiteError::from_response(response))
         }
     }
@@ -3129,6 +3135,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3137,13 +3144,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDeviceError::from_response(response))
         }
     }
@@ -3161,6 +3168,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3169,13 +3177,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGlobalNetworkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGlobalNetworkError::from_response(response))
         }
     }
@@ -3194,6 +3202,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3202,13 +3211,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteLinkError::from_response(response))
         }
     }
@@ -3227,6 +3236,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3235,13 +3245,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSiteResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSiteError::from_response(response))
         }
     }
@@ -3256,6 +3266,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeregisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3264,13 +3275,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeregisterTransitGatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeregisterTransitGatewayError::from_response(response))
         }
     }
@@ -3284,6 +3295,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DescribeGlobalNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3306,13 +3318,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeGlobalNetworksResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeGlobalNetworksError::from_response(response))
         }
     }
@@ -3328,6 +3340,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3336,13 +3349,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateCustomerGatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateCustomerGatewayError::from_response(response))
         }
     }
@@ -3360,6 +3373,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3373,13 +3387,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateLinkError::from_response(response))
         }
     }
@@ -3399,6 +3413,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetCustomerGatewayAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3421,13 +3436,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCustomerGatewayAssociationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCustomerGatewayAssociationsError::from_response(response))
         }
     }
@@ -3444,6 +3459,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3469,13 +3485,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDevicesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDevicesError::from_response(response))
         }
     }
@@ -3492,6 +3508,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinkAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3515,13 +3532,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLinkAssociationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLinkAssociationsError::from_response(response))
         }
     }
@@ -3538,6 +3555,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3569,13 +3587,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLinksResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetLinksError::from_response(response))
         }
     }
@@ -3592,6 +3610,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3614,13 +3633,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSitesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetSitesError::from_response(response))
         }
     }
@@ -3640,6 +3659,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetTransitGatewayRegistrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3662,13 +3682,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetTransitGatewayRegistrationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetTransitGatewayRegistrationsError::from_response(response))
         }
     }
@@ -3682,6 +3702,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3690,13 +3711,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3713,6 +3734,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("RegisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3724,13 +3746,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterTransitGatewayResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterTransitGatewayError::from_response(response))
         }
     }
@@ -3744,6 +3766,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3755,13 +3778,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3776,6 +3799,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3790,13 +3814,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3814,6 +3838,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3825,13 +3850,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDeviceError::from_response(response))
         }
     }
@@ -3848,6 +3873,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3859,13 +3885,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGlobalNetworkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGlobalNetworkError::from_response(response))
         }
     }
@@ -3883,6 +3909,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3894,13 +3921,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateLinkResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateLinkError::from_response(response))
         }
     }
@@ -3918,6 +3945,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3929,13 +3957,13 @@ impl NetworkManager for NetworkManagerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateSiteResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateSiteError::from_response(response))
         }
     }
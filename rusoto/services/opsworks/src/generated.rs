@@ -41,7 +41,7 @@ impl OpsWorksClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6162,7 +6162,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CloneStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CloneStackResult, _>()
     }
 
@@ -6180,7 +6180,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()
     }
 
@@ -6198,7 +6198,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateDeploymentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDeploymentResult, _>()
     }
 
@@ -6216,7 +6216,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateInstanceResult, _>()
     }
 
@@ -6234,7 +6234,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateLayerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLayerResult, _>()
     }
 
@@ -6252,7 +6252,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateStackResult, _>()
     }
 
@@ -6270,7 +6270,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, CreateUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserProfileResult, _>()
     }
 
@@ -6455,7 +6455,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeAgentVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAgentVersionsResult, _>()
     }
 
@@ -6473,7 +6473,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeAppsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAppsResult, _>()
     }
 
@@ -6491,7 +6491,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeCommandsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCommandsResult, _>()
     }
 
@@ -6509,7 +6509,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeDeploymentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDeploymentsResult, _>()
     }
 
@@ -6527,7 +6527,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeEcsClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEcsClustersResult, _>()
     }
 
@@ -6545,7 +6545,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeElasticIpsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeElasticIpsResult, _>()
     }
 
@@ -6567,7 +6567,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeElasticLoadBalancersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeElasticLoadBalancersResult, _>()
     }
@@ -6586,7 +6586,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeInstancesResult, _>()
     }
 
@@ -6604,7 +6604,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeLayersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLayersResult, _>()
     }
 
@@ -6626,7 +6626,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeLoadBasedAutoScalingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeLoadBasedAutoScalingResult, _>()
     }
@@ -6643,7 +6643,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeMyUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeMyUserProfileResult, _>()
     }
 
@@ -6659,7 +6659,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeOperatingSystemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOperatingSystemsResponse, _>()
     }
@@ -6678,7 +6678,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePermissionsResult, _>()
     }
 
@@ -6696,7 +6696,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeRaidArraysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeRaidArraysResult, _>()
     }
 
@@ -6714,7 +6714,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeRdsDbInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeRdsDbInstancesResult, _>()
     }
@@ -6733,7 +6733,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeServiceErrorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServiceErrorsResult, _>()
     }
 
@@ -6760,7 +6760,7 @@ impl OpsWorks for OpsWorksClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeStackProvisioningParametersResult, _>()
     }
@@ -6779,7 +6779,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeStackSummaryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStackSummaryResult, _>()
     }
 
@@ -6797,7 +6797,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeStacksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStacksResult, _>()
     }
 
@@ -6819,7 +6819,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeTimeBasedAutoScalingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTimeBasedAutoScalingResult, _>()
     }
@@ -6838,7 +6838,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeUserProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserProfilesResult, _>()
     }
 
@@ -6856,7 +6856,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, DescribeVolumesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeVolumesResult, _>()
     }
 
@@ -6911,7 +6911,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, GetHostnameSuggestionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetHostnameSuggestionResult, _>()
     }
 
@@ -6929,7 +6929,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, GrantAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GrantAccessResult, _>()
     }
 
@@ -6947,7 +6947,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResult, _>()
     }
 
@@ -6982,7 +6982,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, RegisterEcsClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterEcsClusterResult, _>()
     }
 
@@ -7000,7 +7000,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, RegisterElasticIpError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterElasticIpResult, _>()
     }
 
@@ -7018,7 +7018,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, RegisterInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterInstanceResult, _>()
     }
 
@@ -7053,7 +7053,7 @@ impl OpsWorks for OpsWorksClient {
             .sign_and_dispatch(request, RegisterVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterVolumeResult, _>()
     }
 
@@ -41,7 +41,7 @@ impl OpsWorksCMClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1680,7 +1680,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, AssociateNodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateNodeResponse, _>()
     }
 
@@ -1698,7 +1698,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, CreateBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupResponse, _>()
     }
 
@@ -1716,7 +1716,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, CreateServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateServerResponse, _>()
     }
 
@@ -1734,7 +1734,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DeleteBackupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
     }
 
@@ -1752,7 +1752,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DeleteServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteServerResponse, _>()
     }
 
@@ -1772,7 +1772,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DescribeAccountAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAccountAttributesResponse, _>()
     }
@@ -1791,7 +1791,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DescribeBackupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
     }
 
@@ -1809,7 +1809,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DescribeEventsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
     }
 
@@ -1833,7 +1833,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DescribeNodeAssociationStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeNodeAssociationStatusResponse, _>()
     }
@@ -1852,7 +1852,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DescribeServersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServersResponse, _>()
     }
 
@@ -1870,7 +1870,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, DisassociateNodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisassociateNodeResponse, _>()
     }
 
@@ -1892,7 +1892,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, ExportServerEngineAttributeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExportServerEngineAttributeResponse, _>()
     }
@@ -1911,7 +1911,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -1929,7 +1929,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, RestoreServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RestoreServerResponse, _>()
     }
 
@@ -1947,7 +1947,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, StartMaintenanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartMaintenanceResponse, _>()
     }
 
@@ -1965,7 +1965,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -1983,7 +1983,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2001,7 +2001,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, UpdateServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServerResponse, _>()
     }
 
@@ -2023,7 +2023,7 @@ impl OpsWorksCM for OpsWorksCMClient {
             .sign_and_dispatch(request, UpdateServerEngineAttributesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateServerEngineAttributesResponse, _>()
     }
@@ -42,7 +42,7 @@ impl OrganizationsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6156,7 +6156,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, AcceptHandshakeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AcceptHandshakeResponse, _>()
     }
 
@@ -6191,7 +6191,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CancelHandshakeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelHandshakeResponse, _>()
     }
 
@@ -6209,7 +6209,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CreateAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAccountResponse, _>()
     }
 
@@ -6230,7 +6230,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CreateGovCloudAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateGovCloudAccountResponse, _>()
     }
@@ -6252,7 +6252,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CreateOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateOrganizationResponse, _>()
     }
 
@@ -6273,7 +6273,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CreateOrganizationalUnitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateOrganizationalUnitResponse, _>()
     }
@@ -6292,7 +6292,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, CreatePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePolicyResponse, _>()
     }
 
@@ -6310,7 +6310,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DeclineHandshakeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeclineHandshakeResponse, _>()
     }
 
@@ -6404,7 +6404,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAccountResponse, _>()
     }
 
@@ -6426,7 +6426,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeCreateAccountStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCreateAccountStatusResponse, _>()
     }
@@ -6448,7 +6448,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeEffectivePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEffectivePolicyResponse, _>()
     }
@@ -6470,7 +6470,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeHandshakeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeHandshakeResponse, _>()
     }
 
@@ -6489,7 +6489,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationResponse, _>()
     }
@@ -6512,7 +6512,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribeOrganizationalUnitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationalUnitResponse, _>()
     }
@@ -6531,7 +6531,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DescribePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePolicyResponse, _>()
     }
 
@@ -6589,7 +6589,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, DisablePolicyTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisablePolicyTypeResponse, _>()
     }
 
@@ -6628,7 +6628,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, EnableAllFeaturesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnableAllFeaturesResponse, _>()
     }
 
@@ -6646,7 +6646,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, EnablePolicyTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<EnablePolicyTypeResponse, _>()
     }
 
@@ -6668,7 +6668,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, InviteAccountToOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<InviteAccountToOrganizationResponse, _>()
     }
@@ -6712,7 +6712,7 @@ impl Organizations for OrganizationsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAWSServiceAccessForOrganizationResponse, _>()
     }
@@ -6731,7 +6731,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListAccountsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAccountsResponse, _>()
     }
 
@@ -6752,7 +6752,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListAccountsForParentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAccountsForParentResponse, _>()
     }
@@ -6771,7 +6771,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListChildrenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListChildrenResponse, _>()
     }
 
@@ -6792,7 +6792,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListCreateAccountStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCreateAccountStatusResponse, _>()
     }
@@ -6815,7 +6815,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListDelegatedAdministratorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDelegatedAdministratorsResponse, _>()
     }
@@ -6840,7 +6840,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListDelegatedServicesForAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDelegatedServicesForAccountResponse, _>()
     }
@@ -6862,7 +6862,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListHandshakesForAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListHandshakesForAccountResponse, _>()
     }
@@ -6887,7 +6887,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListHandshakesForOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListHandshakesForOrganizationResponse, _>()
     }
@@ -6915,7 +6915,7 @@ impl Organizations for OrganizationsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListOrganizationalUnitsForParentResponse, _>()
     }
@@ -6934,7 +6934,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListParentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListParentsResponse, _>()
     }
 
@@ -6952,7 +6952,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListPoliciesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPoliciesResponse, _>()
     }
 
@@ -6973,7 +6973,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListPoliciesForTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPoliciesForTargetResponse, _>()
     }
@@ -6992,7 +6992,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListRootsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRootsResponse, _>()
     }
 
@@ -7013,7 +7013,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -7034,7 +7034,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, ListTargetsForPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTargetsForPolicyResponse, _>()
     }
@@ -7147,7 +7147,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, UpdateOrganizationalUnitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateOrganizationalUnitResponse, _>()
     }
@@ -7166,7 +7166,7 @@ impl Organizations for OrganizationsClient {
             .sign_and_dispatch(request, UpdatePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePolicyResponse, _>()
     }
 }
@@ -609,6 +609,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("POST", "outposts", &self.region, &request_uri);
+        request.set_operation("CreateOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -620,13 +621,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateOutpostOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateOutpostError::from_response(response))
         }
     }
@@ -640,6 +641,7 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("DELETE", "outposts", &self.region, &request_uri);
+        request.set_operation("DeleteOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -648,13 +650,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteOutpostOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteOutpostError::from_response(response))
         }
     }
@@ -668,6 +670,7 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/sites/{site_id}", site_id = input.site_id);
 
         let mut request = SignedRequest::new("DELETE", "outposts", &self.region, &request_uri);
+        request.set_operation("DeleteSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -676,13 +679,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSiteOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSiteError::from_response(response))
         }
     }
@@ -696,6 +699,7 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -704,13 +708,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetOutpostOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetOutpostError::from_response(response))
         }
     }
@@ -727,6 +731,7 @@ impl Outposts for OutpostsClient {
         );
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpostInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -744,13 +749,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetOutpostInstanceTypesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetOutpostInstanceTypesError::from_response(response))
         }
     }
@@ -764,6 +769,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListOutposts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -781,13 +787,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListOutpostsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListOutpostsError::from_response(response))
         }
     }
@@ -801,6 +807,7 @@ impl Outposts for OutpostsClient {
         let request_uri = "/sites";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -818,13 +825,13 @@ impl Outposts for OutpostsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListSitesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSitesError::from_response(response))
         }
     }
@@ -143,6 +143,7 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/events";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
@@ -155,12 +156,12 @@ impl PersonalizeEvents for PersonalizeEventsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutEventsError::from_response(response))
         }
     }
@@ -240,6 +240,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/personalize-ranking";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetPersonalizedRanking");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -252,13 +253,13 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPersonalizedRankingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetPersonalizedRankingError::from_response(response))
         }
     }
@@ -272,6 +273,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/recommendations";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -284,13 +286,13 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRecommendationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRecommendationsError::from_response(response))
         }
     }
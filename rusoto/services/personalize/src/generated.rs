@@ -41,7 +41,7 @@ impl PersonalizeClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4262,7 +4262,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateBatchInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateBatchInferenceJobResponse, _>()
     }
@@ -4281,7 +4281,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateCampaignError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCampaignResponse, _>()
     }
 
@@ -4299,7 +4299,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateDatasetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetResponse, _>()
     }
 
@@ -4317,7 +4317,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateDatasetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetGroupResponse, _>()
     }
 
@@ -4335,7 +4335,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateDatasetImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateDatasetImportJobResponse, _>()
     }
@@ -4354,7 +4354,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateEventTrackerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEventTrackerResponse, _>()
     }
 
@@ -4372,7 +4372,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateFilterResponse, _>()
     }
 
@@ -4390,7 +4390,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateSchemaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSchemaResponse, _>()
     }
 
@@ -4408,7 +4408,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateSolutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSolutionResponse, _>()
     }
 
@@ -4426,7 +4426,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, CreateSolutionVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSolutionVersionResponse, _>()
     }
@@ -4564,7 +4564,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeAlgorithmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAlgorithmResponse, _>()
     }
 
@@ -4586,7 +4586,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeBatchInferenceJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeBatchInferenceJobResponse, _>()
     }
@@ -4605,7 +4605,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeCampaignError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCampaignResponse, _>()
     }
 
@@ -4623,7 +4623,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeDatasetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDatasetResponse, _>()
     }
 
@@ -4641,7 +4641,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeDatasetGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDatasetGroupResponse, _>()
     }
@@ -4660,7 +4660,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeDatasetImportJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDatasetImportJobResponse, _>()
     }
@@ -4679,7 +4679,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeEventTrackerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEventTrackerResponse, _>()
     }
@@ -4704,7 +4704,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeFeatureTransformationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeFeatureTransformationResponse, _>()
     }
@@ -4723,7 +4723,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeFilterResponse, _>()
     }
 
@@ -4741,7 +4741,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeRecipeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeRecipeResponse, _>()
     }
 
@@ -4759,7 +4759,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeSchemaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSchemaResponse, _>()
     }
 
@@ -4777,7 +4777,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeSolutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSolutionResponse, _>()
     }
 
@@ -4795,7 +4795,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, DescribeSolutionVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSolutionVersionResponse, _>()
     }
@@ -4814,7 +4814,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, GetSolutionMetricsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSolutionMetricsResponse, _>()
     }
 
@@ -4832,7 +4832,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListBatchInferenceJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListBatchInferenceJobsResponse, _>()
     }
@@ -4851,7 +4851,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListCampaignsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCampaignsResponse, _>()
     }
 
@@ -4869,7 +4869,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListDatasetGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetGroupsResponse, _>()
     }
 
@@ -4887,7 +4887,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListDatasetImportJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListDatasetImportJobsResponse, _>()
     }
@@ -4906,7 +4906,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListDatasetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetsResponse, _>()
     }
 
@@ -4924,7 +4924,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListEventTrackersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEventTrackersResponse, _>()
     }
 
@@ -4942,7 +4942,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListFiltersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFiltersResponse, _>()
     }
 
@@ -4960,7 +4960,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListRecipesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRecipesResponse, _>()
     }
 
@@ -4978,7 +4978,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListSchemasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSchemasResponse, _>()
     }
 
@@ -4996,7 +4996,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListSolutionVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSolutionVersionsResponse, _>()
     }
@@ -5015,7 +5015,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, ListSolutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSolutionsResponse, _>()
     }
 
@@ -5033,7 +5033,7 @@ impl Personalize for PersonalizeClient {
             .sign_and_dispatch(request, UpdateCampaignError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCampaignResponse, _>()
     }
 }
@@ -41,7 +41,7 @@ impl PerformanceInsightsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -437,7 +437,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
             .sign_and_dispatch(request, DescribeDimensionKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDimensionKeysResponse, _>()
     }
@@ -459,7 +459,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
             .sign_and_dispatch(request, GetResourceMetricsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourceMetricsResponse, _>()
     }
 }
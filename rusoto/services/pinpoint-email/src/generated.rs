@@ -4177,6 +4177,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4189,13 +4190,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConfigurationSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConfigurationSetError::from_response(response))
         }
     }
@@ -4215,6 +4216,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4227,13 +4229,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConfigurationSetEventDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateConfigurationSetEventDestinationError::from_response(
                 response,
             ))
@@ -4249,6 +4251,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4261,13 +4264,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDedicatedIpPoolResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDedicatedIpPoolError::from_response(response))
         }
     }
@@ -4284,6 +4287,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4296,13 +4300,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeliverabilityTestReportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeliverabilityTestReportError::from_response(response))
         }
     }
@@ -4316,6 +4320,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4328,13 +4333,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateEmailIdentityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateEmailIdentityError::from_response(response))
         }
     }
@@ -4351,6 +4356,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4361,13 +4367,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteConfigurationSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConfigurationSetError::from_response(response))
         }
     }
@@ -4384,6 +4390,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4394,13 +4401,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteConfigurationSetEventDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteConfigurationSetEventDestinationError::from_response(
                 response,
             ))
@@ -4419,6 +4426,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4429,13 +4437,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDedicatedIpPoolResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDedicatedIpPoolError::from_response(response))
         }
     }
@@ -4452,6 +4460,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4462,13 +4471,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteEmailIdentityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteEmailIdentityError::from_response(response))
         }
     }
@@ -4479,6 +4488,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4489,13 +4499,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetAccountResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetAccountError::from_response(response))
         }
     }
@@ -4509,6 +4519,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetBlacklistReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4525,13 +4536,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetBlacklistReportsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetBlacklistReportsError::from_response(response))
         }
     }
@@ -4548,6 +4559,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4558,13 +4570,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConfigurationSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConfigurationSetError::from_response(response))
         }
     }
@@ -4584,6 +4596,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4594,13 +4607,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConfigurationSetEventDestinationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetConfigurationSetEventDestinationsError::from_response(
                 response,
             ))
@@ -4616,6 +4629,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4626,13 +4640,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDedicatedIpResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDedicatedIpError::from_response(response))
         }
     }
@@ -4646,6 +4660,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4668,13 +4683,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDedicatedIpsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDedicatedIpsError::from_response(response))
         }
     }
@@ -4690,6 +4705,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityDashboardOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4700,13 +4716,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeliverabilityDashboardOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeliverabilityDashboardOptionsError::from_response(
                 response,
             ))
@@ -4726,6 +4742,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4736,13 +4753,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeliverabilityTestReportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDeliverabilityTestReportError::from_response(response))
         }
     }
@@ -4762,6 +4779,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainDeliverabilityCampaign");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4772,13 +4790,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDomainDeliverabilityCampaignResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainDeliverabilityCampaignError::from_response(
                 response,
             ))
@@ -4798,6 +4816,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainStatisticsReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4813,13 +4832,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDomainStatisticsReportResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDomainStatisticsReportError::from_response(response))
         }
     }
@@ -4836,6 +4855,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4846,13 +4866,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetEmailIdentityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetEmailIdentityError::from_response(response))
         }
     }
@@ -4866,6 +4886,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4885,13 +4906,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListConfigurationSetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListConfigurationSetsError::from_response(response))
         }
     }
@@ -4905,6 +4926,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDedicatedIpPools");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4924,13 +4946,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDedicatedIpPoolsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDedicatedIpPoolsError::from_response(response))
         }
     }
@@ -4947,6 +4969,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDeliverabilityTestReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4966,13 +4989,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeliverabilityTestReportsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeliverabilityTestReportsError::from_response(response))
         }
     }
@@ -4992,6 +5015,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDomainDeliverabilityCampaigns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5013,13 +5037,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDomainDeliverabilityCampaignsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDomainDeliverabilityCampaignsError::from_response(
                 response,
             ))
@@ -5035,6 +5059,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListEmailIdentities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5054,13 +5079,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEmailIdentitiesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListEmailIdentitiesError::from_response(response))
         }
     }
@@ -5074,6 +5099,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5088,13 +5114,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -5111,6 +5137,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5123,13 +5150,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutAccountDedicatedIpWarmupAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutAccountDedicatedIpWarmupAttributesError::from_response(
                 response,
             ))
@@ -5146,6 +5173,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSendingAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5158,13 +5186,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutAccountSendingAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutAccountSendingAttributesError::from_response(response))
         }
     }
@@ -5184,6 +5212,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5196,13 +5225,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutConfigurationSetDeliveryOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutConfigurationSetDeliveryOptionsError::from_response(
                 response,
             ))
@@ -5224,6 +5253,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetReputationOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5236,13 +5266,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutConfigurationSetReputationOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutConfigurationSetReputationOptionsError::from_response(
                 response,
             ))
@@ -5264,6 +5294,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSendingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5276,13 +5307,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutConfigurationSetSendingOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutConfigurationSetSendingOptionsError::from_response(
                 response,
             ))
@@ -5304,6 +5335,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetTrackingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5316,13 +5348,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutConfigurationSetTrackingOptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutConfigurationSetTrackingOptionsError::from_response(
                 response,
             ))
@@ -5338,6 +5370,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpInPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5350,13 +5383,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutDedicatedIpInPoolResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutDedicatedIpInPoolError::from_response(response))
         }
     }
@@ -5373,6 +5406,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5385,13 +5419,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutDedicatedIpWarmupAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutDedicatedIpWarmupAttributesError::from_response(response))
         }
     }
@@ -5408,6 +5442,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDeliverabilityDashboardOption");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5420,13 +5455,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutDeliverabilityDashboardOptionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutDeliverabilityDashboardOptionError::from_response(
                 response,
             ))
@@ -5448,6 +5483,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5460,13 +5496,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutEmailIdentityDkimAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutEmailIdentityDkimAttributesError::from_response(response))
         }
     }
@@ -5486,6 +5522,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityFeedbackAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5498,13 +5535,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutEmailIdentityFeedbackAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutEmailIdentityFeedbackAttributesError::from_response(
                 response,
             ))
@@ -5526,6 +5563,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityMailFromAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5538,13 +5576,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutEmailIdentityMailFromAttributesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutEmailIdentityMailFromAttributesError::from_response(
                 response,
             ))
@@ -5560,6 +5598,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("SendEmail");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5572,13 +5611,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SendEmailResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SendEmailError::from_response(response))
         }
     }
@@ -5592,6 +5631,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5604,13 +5644,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -5624,6 +5664,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5641,13 +5682,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -5664,6 +5705,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5676,13 +5718,13 @@ impl PinpointEmail for PinpointEmailClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateConfigurationSetEventDestinationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateConfigurationSetEventDestinationError::from_response(
                 response,
             ))
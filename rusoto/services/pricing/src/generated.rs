@@ -42,7 +42,7 @@ impl PricingClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -439,7 +439,7 @@ impl Pricing for PricingClient {
             .sign_and_dispatch(request, DescribeServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServicesResponse, _>()
     }
 
@@ -457,7 +457,7 @@ impl Pricing for PricingClient {
             .sign_and_dispatch(request, GetAttributeValuesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAttributeValuesResponse, _>()
     }
 
@@ -475,7 +475,7 @@ impl Pricing for PricingClient {
             .sign_and_dispatch(request, GetProductsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetProductsResponse, _>()
     }
 }
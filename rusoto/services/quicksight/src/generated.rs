@@ -11126,6 +11126,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("CancelIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11134,14 +11135,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelIngestionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelIngestionError::from_response(response))
         }
     }
@@ -11159,6 +11160,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11170,14 +11172,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDashboardError::from_response(response))
         }
     }
@@ -11194,6 +11196,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11205,14 +11208,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDataSetError::from_response(response))
         }
     }
@@ -11229,6 +11232,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11240,14 +11244,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDataSourceError::from_response(response))
         }
     }
@@ -11265,6 +11269,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11276,14 +11281,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupError::from_response(response))
         }
     }
@@ -11297,6 +11302,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11305,14 +11311,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupMembershipResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupMembershipError::from_response(response))
         }
     }
@@ -11331,6 +11337,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11342,14 +11349,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIAMPolicyAssignmentError::from_response(response))
         }
     }
@@ -11368,6 +11375,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11376,14 +11384,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateIngestionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateIngestionError::from_response(response))
         }
     }
@@ -11401,6 +11409,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11412,14 +11421,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTemplateError::from_response(response))
         }
     }
@@ -11438,6 +11447,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11449,14 +11459,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTemplateAliasError::from_response(response))
         }
     }
@@ -11474,6 +11484,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11485,14 +11496,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateThemeResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateThemeError::from_response(response))
         }
     }
@@ -11511,6 +11522,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11522,14 +11534,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateThemeAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateThemeAliasError::from_response(response))
         }
     }
@@ -11547,6 +11559,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11561,14 +11574,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDashboardError::from_response(response))
         }
     }
@@ -11586,6 +11599,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11594,14 +11608,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDataSetError::from_response(response))
         }
     }
@@ -11619,6 +11633,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11627,14 +11642,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDataSourceError::from_response(response))
         }
     }
@@ -11653,6 +11668,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11661,14 +11677,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGroupError::from_response(response))
         }
     }
@@ -11682,6 +11698,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11690,14 +11707,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGroupMembershipResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGroupMembershipError::from_response(response))
         }
     }
@@ -11712,6 +11729,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespace/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11720,14 +11738,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteIAMPolicyAssignmentError::from_response(response))
         }
     }
@@ -11745,6 +11763,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11759,14 +11778,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTemplateError::from_response(response))
         }
     }
@@ -11785,6 +11804,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11793,14 +11813,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteTemplateAliasError::from_response(response))
         }
     }
@@ -11818,6 +11838,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11832,14 +11853,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteThemeResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteThemeError::from_response(response))
         }
     }
@@ -11858,6 +11879,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11866,14 +11888,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteThemeAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteThemeAliasError::from_response(response))
         }
     }
@@ -11892,6 +11914,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11900,14 +11923,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUserError::from_response(response))
         }
     }
@@ -11926,6 +11949,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUserByPrincipalId");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11934,14 +11958,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteUserByPrincipalIdResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUserByPrincipalIdError::from_response(response))
         }
     }
@@ -11959,6 +11983,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11976,14 +12001,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDashboardError::from_response(response))
         }
     }
@@ -12002,6 +12027,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12010,14 +12036,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDashboardPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDashboardPermissionsError::from_response(response))
         }
     }
@@ -12035,6 +12061,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12043,14 +12070,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDataSetError::from_response(response))
         }
     }
@@ -12069,6 +12096,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12077,14 +12105,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDataSetPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDataSetPermissionsError::from_response(response))
         }
     }
@@ -12102,6 +12130,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12110,14 +12139,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDataSourceError::from_response(response))
         }
     }
@@ -12138,6 +12167,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12146,14 +12176,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDataSourcePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDataSourcePermissionsError::from_response(response))
         }
     }
@@ -12172,6 +12202,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12180,14 +12211,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeGroupError::from_response(response))
         }
     }
@@ -12202,6 +12233,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12210,14 +12242,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeIAMPolicyAssignmentError::from_response(response))
         }
     }
@@ -12236,6 +12268,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12244,14 +12277,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeIngestionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeIngestionError::from_response(response))
         }
     }
@@ -12269,6 +12302,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12286,14 +12320,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeTemplateError::from_response(response))
         }
     }
@@ -12312,6 +12346,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12320,14 +12355,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeTemplateAliasError::from_response(response))
         }
     }
@@ -12346,6 +12381,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12354,14 +12390,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeTemplatePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeTemplatePermissionsError::from_response(response))
         }
     }
@@ -12379,6 +12415,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12396,14 +12433,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeThemeResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeThemeError::from_response(response))
         }
     }
@@ -12422,6 +12459,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12430,14 +12468,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeThemeAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeThemeAliasError::from_response(response))
         }
     }
@@ -12455,6 +12493,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeThemePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12463,14 +12502,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeThemePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeThemePermissionsError::from_response(response))
         }
     }
@@ -12489,6 +12528,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12497,14 +12537,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeUserError::from_response(response))
         }
     }
@@ -12522,6 +12562,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("GetDashboardEmbedUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12546,14 +12587,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDashboardEmbedUrlResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDashboardEmbedUrlError::from_response(response))
         }
     }
@@ -12571,6 +12612,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboardVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12588,14 +12630,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDashboardVersionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDashboardVersionsError::from_response(response))
         }
     }
@@ -12612,6 +12654,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12629,14 +12672,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDashboardsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDashboardsError::from_response(response))
         }
     }
@@ -12653,6 +12696,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12670,14 +12714,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataSetsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataSetsError::from_response(response))
         }
     }
@@ -12694,6 +12738,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12711,14 +12756,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDataSourcesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDataSourcesError::from_response(response))
         }
     }
@@ -12737,6 +12782,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroupMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12754,14 +12800,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupMembershipsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupMembershipsError::from_response(response))
         }
     }
@@ -12779,6 +12825,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12796,14 +12843,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupsError::from_response(response))
         }
     }
@@ -12821,6 +12868,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12841,14 +12889,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListIAMPolicyAssignmentsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListIAMPolicyAssignmentsError::from_response(response))
         }
     }
@@ -12865,6 +12913,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/iam-policy-assignments", aws_account_id = input.aws_account_id, namespace = input.namespace, user_name = input.user_name);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignmentsForUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12882,14 +12931,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListIAMPolicyAssignmentsForUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListIAMPolicyAssignmentsForUserError::from_response(
                 response,
             ))
@@ -12909,6 +12958,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIngestions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12926,14 +12976,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListIngestionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListIngestionsError::from_response(response))
         }
     }
@@ -12950,6 +13000,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12958,14 +13009,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -12983,6 +13034,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13000,14 +13052,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTemplateAliasesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTemplateAliasesError::from_response(response))
         }
     }
@@ -13025,6 +13077,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13042,14 +13095,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTemplateVersionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTemplateVersionsError::from_response(response))
         }
     }
@@ -13066,6 +13119,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13083,14 +13137,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTemplatesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTemplatesError::from_response(response))
         }
     }
@@ -13108,6 +13162,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemeAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13125,14 +13180,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListThemeAliasesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListThemeAliasesError::from_response(response))
         }
     }
@@ -13150,6 +13205,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13167,14 +13223,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListThemeVersionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListThemeVersionsError::from_response(response))
         }
     }
@@ -13191,6 +13247,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13211,14 +13268,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListThemesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListThemesError::from_response(response))
         }
     }
@@ -13237,6 +13294,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUserGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13254,14 +13312,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListUserGroupsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListUserGroupsError::from_response(response))
         }
     }
@@ -13279,6 +13337,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13296,14 +13355,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListUsersResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListUsersError::from_response(response))
         }
     }
@@ -13321,6 +13380,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("RegisterUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13332,14 +13392,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterUserError::from_response(response))
         }
     }
@@ -13356,6 +13416,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("SearchDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13367,14 +13428,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SearchDashboardsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SearchDashboardsError::from_response(response))
         }
     }
@@ -13391,6 +13452,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13402,14 +13464,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -13426,6 +13488,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13440,14 +13503,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -13465,6 +13528,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13476,13 +13540,13 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDashboardResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDashboardError::from_response(response))
         }
     }
@@ -13501,6 +13565,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13512,14 +13577,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDashboardPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDashboardPermissionsError::from_response(response))
         }
     }
@@ -13541,6 +13606,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPublishedVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13549,14 +13615,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDashboardPublishedVersionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDashboardPublishedVersionError::from_response(
                 response,
             ))
@@ -13576,6 +13642,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13587,14 +13654,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSetError::from_response(response))
         }
     }
@@ -13612,6 +13679,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13623,14 +13691,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSetPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSetPermissionsError::from_response(response))
         }
     }
@@ -13648,6 +13716,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13659,14 +13728,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSourceError::from_response(response))
         }
     }
@@ -13685,6 +13754,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13696,14 +13766,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataSourcePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDataSourcePermissionsError::from_response(response))
         }
     }
@@ -13722,6 +13792,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13733,14 +13804,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGroupError::from_response(response))
         }
     }
@@ -13755,6 +13826,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13766,14 +13838,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIAMPolicyAssignmentError::from_response(response))
         }
     }
@@ -13791,6 +13863,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13802,14 +13875,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateTemplateError::from_response(response))
         }
     }
@@ -13828,6 +13901,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13839,14 +13913,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateTemplateAliasError::from_response(response))
         }
     }
@@ -13865,6 +13939,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13876,14 +13951,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateTemplatePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateTemplatePermissionsError::from_response(response))
         }
     }
@@ -13901,6 +13976,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13912,14 +13988,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateThemeResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateThemeError::from_response(response))
         }
     }
@@ -13938,6 +14014,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13949,14 +14026,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateThemeAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateThemeAliasError::from_response(response))
         }
     }
@@ -13974,6 +14051,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateThemePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13985,14 +14063,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateThemePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateThemePermissionsError::from_response(response))
         }
     }
@@ -14011,6 +14089,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14022,14 +14101,14 @@ impl Quicksight for QuicksightClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUserError::from_response(response))
         }
     }
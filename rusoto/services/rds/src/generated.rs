@@ -52,7 +52,7 @@ impl RdsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -18997,13 +18997,13 @@ impl WriteForwardingStatusDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified IAM role Amazon Resource Name (ARN) is already associated with the specified DB cluster.</p>
-    DBClusterRoleAlreadyExistsFault(String),
+    DBClusterRoleAlreadyExistsFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of IAM roles that can be associated with the specified DB cluster.</p>
-    DBClusterRoleQuotaExceededFault(String),
+    DBClusterRoleQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl AddRoleToDBClusterError {
@@ -19016,27 +19016,29 @@ impl AddRoleToDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            AddRoleToDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             AddRoleToDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19054,6 +19056,20 @@ impl AddRoleToDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddRoleToDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddRoleToDBClusterError {
     #[allow(unused_variables)]
@@ -19077,13 +19093,13 @@ impl Error for AddRoleToDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified <code>RoleArn</code> or <code>FeatureName</code> value is already associated with the DB instance.</p>
-    DBInstanceRoleAlreadyExistsFault(String),
+    DBInstanceRoleAlreadyExistsFault(XmlErrorMessage),
     /// <p>You can't associate any more AWS Identity and Access Management (IAM) roles with the DB instance because the quota has been reached.</p>
-    DBInstanceRoleQuotaExceededFault(String),
+    DBInstanceRoleQuotaExceededFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl AddRoleToDBInstanceError {
@@ -19096,27 +19112,29 @@ impl AddRoleToDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            AddRoleToDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            AddRoleToDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceRoleAlreadyExists" => {
                         return RusotoError::Service(
                             AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceRoleQuotaExceeded" => {
                         return RusotoError::Service(
                             AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             AddRoleToDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19134,6 +19152,20 @@ impl AddRoleToDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddRoleToDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            AddRoleToDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddRoleToDBInstanceError {
     #[allow(unused_variables)]
@@ -19157,9 +19189,9 @@ impl Error for AddRoleToDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum AddSourceIdentifierToSubscriptionError {
     /// <p>The requested source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The subscription name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl AddSourceIdentifierToSubscriptionError {
@@ -19175,14 +19207,14 @@ impl AddSourceIdentifierToSubscriptionError {
                     "SourceNotFound" => {
                         return RusotoError::Service(
                             AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19200,6 +19232,18 @@ impl AddSourceIdentifierToSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AddSourceIdentifierToSubscriptionError {
     #[allow(unused_variables)]
@@ -19219,15 +19263,15 @@ impl Error for AddSourceIdentifierToSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl AddTagsToResourceError {
@@ -19240,29 +19284,35 @@ impl AddTagsToResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(AddTagsToResourceError::DBProxyNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             AddTagsToResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            AddTagsToResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -19279,6 +19329,19 @@ impl AddTagsToResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            AddTagsToResourceError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AddTagsToResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddTagsToResourceError {
     #[allow(unused_variables)]
@@ -19299,11 +19362,11 @@ impl Error for AddTagsToResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -19319,21 +19382,21 @@ impl ApplyPendingMaintenanceActionError {
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19351,6 +19414,21 @@ impl ApplyPendingMaintenanceActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ApplyPendingMaintenanceActionError {
     #[allow(unused_variables)]
@@ -19373,13 +19451,13 @@ impl Error for ApplyPendingMaintenanceActionError {}
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeDBSecurityGroupIngressError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group is already authorized for the specified DB security group.</p>
-    AuthorizationAlreadyExistsFault(String),
+    AuthorizationAlreadyExistsFault(XmlErrorMessage),
     /// <p>The DB security group authorization quota has been reached.</p>
-    AuthorizationQuotaExceededFault(String),
+    AuthorizationQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl AuthorizeDBSecurityGroupIngressError {
@@ -19395,28 +19473,28 @@ impl AuthorizeDBSecurityGroupIngressError {
                     "AuthorizationAlreadyExists" => {
                         return RusotoError::Service(
                             AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
                         return RusotoError::Service(
                             AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19434,6 +19512,24 @@ impl AuthorizeDBSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AuthorizeDBSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -19459,9 +19555,9 @@ impl Error for AuthorizeDBSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum BacktrackDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl BacktrackDBClusterError {
@@ -19474,13 +19570,15 @@ impl BacktrackDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            BacktrackDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            BacktrackDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             BacktrackDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19498,6 +19596,14 @@ impl BacktrackDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BacktrackDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            BacktrackDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for BacktrackDBClusterError {
     #[allow(unused_variables)]
@@ -19515,9 +19621,9 @@ impl Error for BacktrackDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CancelExportTaskError {
     /// <p>The export task doesn't exist.</p>
-    ExportTaskNotFoundFault(String),
+    ExportTaskNotFoundFault(XmlErrorMessage),
     /// <p>You can't cancel an export task that has completed.</p>
-    InvalidExportTaskStateFault(String),
+    InvalidExportTaskStateFault(XmlErrorMessage),
 }
 
 impl CancelExportTaskError {
@@ -19530,13 +19636,15 @@ impl CancelExportTaskError {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
                         return RusotoError::Service(
-                            CancelExportTaskError::ExportTaskNotFoundFault(parsed_error.message),
+                            CancelExportTaskError::ExportTaskNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidExportTaskStateFault" => {
                         return RusotoError::Service(
                             CancelExportTaskError::InvalidExportTaskStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19554,6 +19662,14 @@ impl CancelExportTaskError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CancelExportTaskError::ExportTaskNotFoundFault(ref cause) => cause.request_id(),
+            CancelExportTaskError::InvalidExportTaskStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CancelExportTaskError {
     #[allow(unused_variables)]
@@ -19569,11 +19685,11 @@ impl Error for CancelExportTaskError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterParameterGroupError {
@@ -19589,21 +19705,21 @@ impl CopyDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19621,6 +19737,21 @@ impl CopyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -19643,17 +19774,17 @@ impl Error for CopyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
     /// <p>The user already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBClusterSnapshotError {
@@ -19667,42 +19798,42 @@ impl CopyDBClusterSnapshotError {
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19720,6 +19851,24 @@ impl CopyDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -19751,11 +19900,11 @@ impl Error for CopyDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBParameterGroupError {
@@ -19769,21 +19918,21 @@ impl CopyDBParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19801,6 +19950,21 @@ impl CopyDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CopyDBParameterGroupError {
     #[allow(unused_variables)]
@@ -19823,15 +19987,15 @@ impl Error for CopyDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyDBSnapshotError {
     /// <p> <code>DBSnapshotIdentifier</code> is already used by an existing snapshot. </p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyDBSnapshotError {
@@ -19844,27 +20008,33 @@ impl CopyDBSnapshotError {
                 match &parsed_error.code[..] {
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
-                            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(parsed_error.message),
+                            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(CopyDBSnapshotError::DBSnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
-                            CopyDBSnapshotError::InvalidDBSnapshotStateFault(parsed_error.message),
+                            CopyDBSnapshotError::InvalidDBSnapshotStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(CopyDBSnapshotError::KMSKeyNotAccessibleFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            CopyDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                            CopyDBSnapshotError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -19881,6 +20051,17 @@ impl CopyDBSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            CopyDBSnapshotError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+            CopyDBSnapshotError::InvalidDBSnapshotStateFault(ref cause) => cause.request_id(),
+            CopyDBSnapshotError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CopyDBSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyDBSnapshotError {
     #[allow(unused_variables)]
@@ -19899,11 +20080,11 @@ impl Error for CopyDBSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyOptionGroupError {
     /// <p>The option group you are trying to create already exists.</p>
-    OptionGroupAlreadyExistsFault(String),
+    OptionGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>The quota of 20 option groups was exceeded for this AWS account.</p>
-    OptionGroupQuotaExceededFault(String),
+    OptionGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CopyOptionGroupError {
@@ -19917,19 +20098,21 @@ impl CopyOptionGroupError {
                     "OptionGroupAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CopyOptionGroupError::OptionGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CopyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                            CopyOptionGroupError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
                         return RusotoError::Service(
                             CopyOptionGroupError::OptionGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -19947,6 +20130,15 @@ impl CopyOptionGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyOptionGroupError::OptionGroupAlreadyExistsFault(ref cause) => cause.request_id(),
+            CopyOptionGroupError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            CopyOptionGroupError::OptionGroupQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyOptionGroupError {
     #[allow(unused_variables)]
@@ -19967,11 +20159,11 @@ impl Error for CopyOptionGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomAvailabilityZoneError {
     /// <p> <code>CustomAvailabilityZoneName</code> is already used by an existing custom Availability Zone.</p>
-    CustomAvailabilityZoneAlreadyExistsFault(String),
+    CustomAvailabilityZoneAlreadyExistsFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of custom Availability Zones.</p>
-    CustomAvailabilityZoneQuotaExceededFault(String),
+    CustomAvailabilityZoneQuotaExceededFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
 }
 
 impl CreateCustomAvailabilityZoneError {
@@ -19986,18 +20178,18 @@ impl CreateCustomAvailabilityZoneError {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneAlreadyExists" => return RusotoError::Service(
                         CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "CustomAvailabilityZoneQuotaExceeded" => return RusotoError::Service(
                         CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20015,6 +20207,21 @@ impl CreateCustomAvailabilityZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateCustomAvailabilityZoneError {
     #[allow(unused_variables)]
@@ -20037,41 +20244,41 @@ impl Error for CreateCustomAvailabilityZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
     /// <p>The user already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing DB cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The user attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    GlobalClusterNotFoundFault(String),
+    GlobalClusterNotFoundFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p><p/></p>
-    InvalidGlobalClusterStateFault(String),
+    InvalidGlobalClusterStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterError {
@@ -20084,102 +20291,120 @@ impl CreateDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::DBClusterQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(CreateDBClusterError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "GlobalClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::GlobalClusterNotFoundFault(parsed_error.message),
+                            CreateDBClusterError::GlobalClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            CreateDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBClusterError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -20196,6 +20421,36 @@ impl CreateDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::GlobalClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidGlobalClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterError {
     #[allow(unused_variables)]
@@ -20237,17 +20492,17 @@ impl Error for CreateDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterEndpointError {
     /// <p>The specified custom endpoint can't be created because it already exists.</p>
-    DBClusterEndpointAlreadyExistsFault(String),
+    DBClusterEndpointAlreadyExistsFault(XmlErrorMessage),
     /// <p>The cluster already has the maximum number of custom endpoints.</p>
-    DBClusterEndpointQuotaExceededFault(String),
+    DBClusterEndpointQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterEndpointError {
@@ -20261,42 +20516,42 @@ impl CreateDBClusterEndpointError {
                     "DBClusterEndpointAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterEndpointQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             CreateDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20314,6 +20569,26 @@ impl CreateDBClusterEndpointError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterEndpointError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterEndpointError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterEndpointError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterEndpointError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterEndpointError {
     #[allow(unused_variables)]
@@ -20345,9 +20620,9 @@ impl Error for CreateDBClusterEndpointError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterParameterGroupError {
@@ -20363,14 +20638,14 @@ impl CreateDBClusterParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20388,6 +20663,18 @@ impl CreateDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -20407,15 +20694,15 @@ impl Error for CreateDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBClusterSnapshotError {
@@ -20429,35 +20716,35 @@ impl CreateDBClusterSnapshotError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20475,6 +20762,25 @@ impl CreateDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -20503,43 +20809,42 @@ impl Error for CreateDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
-
-    BackupPolicyNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
+    BackupPolicyNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl CreateDBInstanceError {
@@ -20552,112 +20857,128 @@ impl CreateDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::BackupPolicyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::InstanceQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            CreateDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            CreateDBInstanceError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            CreateDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20675,6 +20996,37 @@ impl CreateDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::BackupPolicyNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InstanceQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBInstanceError {
     #[allow(unused_variables)]
@@ -20721,43 +21073,43 @@ impl Error for CreateDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceReadReplicaError {
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p>The DBSubnetGroup shouldn't be specified while creating read replicas that lie in the same region as the source instance.</p>
-    DBSubnetGroupNotAllowedFault(String),
+    DBSubnetGroupNotAllowedFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The DBSubnetGroup doesn't belong to the same VPC as that of an existing cross-region read replica of the same source instance.</p>
-    InvalidDBSubnetGroupFault(String),
+    InvalidDBSubnetGroupFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl CreateDBInstanceReadReplicaError {
@@ -20773,131 +21125,133 @@ impl CreateDBInstanceReadReplicaError {
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotAllowedFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::DomainNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InvalidSubnet(parsed_error.message),
+                            CreateDBInstanceReadReplicaError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -20915,6 +21269,65 @@ impl CreateDBInstanceReadReplicaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBInstanceReadReplicaError {
     #[allow(unused_variables)]
@@ -20983,9 +21396,9 @@ impl Error for CreateDBInstanceReadReplicaError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
-    DBParameterGroupAlreadyExistsFault(String),
+    DBParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB parameter groups.</p>
-    DBParameterGroupQuotaExceededFault(String),
+    DBParameterGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBParameterGroupError {
@@ -20999,14 +21412,14 @@ impl CreateDBParameterGroupError {
                     "DBParameterGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21024,6 +21437,18 @@ impl CreateDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBParameterGroupError {
     #[allow(unused_variables)]
@@ -21043,11 +21468,11 @@ impl Error for CreateDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBProxyError {
     /// <p>The specified proxy name must be unique for all proxies owned by your AWS account in the specified AWS Region.</p>
-    DBProxyAlreadyExistsFault(String),
+    DBProxyAlreadyExistsFault(XmlErrorMessage),
     /// <p>Your AWS account already has the maximum number of proxies in the specified AWS Region.</p>
-    DBProxyQuotaExceededFault(String),
+    DBProxyQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
 }
 
 impl CreateDBProxyError {
@@ -21060,17 +21485,17 @@ impl CreateDBProxyError {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
                         return RusotoError::Service(CreateDBProxyError::DBProxyAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBProxyQuotaExceededFault" => {
                         return RusotoError::Service(CreateDBProxyError::DBProxyQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBProxyError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -21087,6 +21512,15 @@ impl CreateDBProxyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBProxyError::DBProxyAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBProxyError::DBProxyQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBProxyError::InvalidSubnet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBProxyError {
     #[allow(unused_variables)]
@@ -21103,11 +21537,11 @@ impl Error for CreateDBProxyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSecurityGroupError {
     /// <p> A DB security group with the name specified in <code>DBSecurityGroupName</code> already exists. </p>
-    DBSecurityGroupAlreadyExistsFault(String),
+    DBSecurityGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>A DB security group isn't allowed for this action.</p>
-    DBSecurityGroupNotSupportedFault(String),
+    DBSecurityGroupNotSupportedFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB security groups.</p>
-    DBSecurityGroupQuotaExceededFault(String),
+    DBSecurityGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBSecurityGroupError {
@@ -21121,21 +21555,21 @@ impl CreateDBSecurityGroupError {
                     "DBSecurityGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotSupported" => {
                         return RusotoError::Service(
                             CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "QuotaExceeded.DBSecurityGroup" => {
                         return RusotoError::Service(
                             CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21153,6 +21587,21 @@ impl CreateDBSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateDBSecurityGroupError {
     #[allow(unused_variables)]
@@ -21175,13 +21624,13 @@ impl Error for CreateDBSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSnapshotError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> is already used by an existing snapshot. </p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateDBSnapshotError {
@@ -21194,26 +21643,30 @@ impl CreateDBSnapshotError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            CreateDBSnapshotError::DBInstanceNotFoundFault(parsed_error.message),
+                            CreateDBSnapshotError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBSnapshotError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             CreateDBSnapshotError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            CreateDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                            CreateDBSnapshotError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -21230,6 +21683,16 @@ impl CreateDBSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBSnapshotError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            CreateDBSnapshotError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateDBSnapshotError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            CreateDBSnapshotError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBSnapshotError {
     #[allow(unused_variables)]
@@ -21249,15 +21712,15 @@ impl Error for CreateDBSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> is already used by an existing DB subnet group. </p>
-    DBSubnetGroupAlreadyExistsFault(String),
+    DBSubnetGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB subnet groups.</p>
-    DBSubnetGroupQuotaExceededFault(String),
+    DBSubnetGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of subnets in a DB subnet groups.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
 }
 
 impl CreateDBSubnetGroupError {
@@ -21271,34 +21734,34 @@ impl CreateDBSubnetGroupError {
                     "DBSubnetGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -21315,6 +21778,23 @@ impl CreateDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -21341,19 +21821,19 @@ impl Error for CreateDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
     /// <p>You have reached the maximum number of event subscriptions.</p>
-    EventSubscriptionQuotaExceededFault(String),
+    EventSubscriptionQuotaExceededFault(XmlErrorMessage),
     /// <p>SNS has responded that there is a problem with the SND topic specified.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>You do not have permission to publish to the SNS topic ARN.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>The SNS topic ARN does not exist.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The requested source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The supplied subscription name already exists.</p>
-    SubscriptionAlreadyExistFault(String),
+    SubscriptionAlreadyExistFault(XmlErrorMessage),
     /// <p>The supplied category does not exist.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
 }
 
 impl CreateEventSubscriptionError {
@@ -21367,47 +21847,49 @@ impl CreateEventSubscriptionError {
                     "EventSubscriptionQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SourceNotFound" => {
                         return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            CreateEventSubscriptionError::SourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21425,6 +21907,25 @@ impl CreateEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SourceNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateEventSubscriptionError {
     #[allow(unused_variables)]
@@ -21455,13 +21956,13 @@ impl Error for CreateEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    GlobalClusterAlreadyExistsFault(String),
+    GlobalClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p><p/></p>
-    GlobalClusterQuotaExceededFault(String),
+    GlobalClusterQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl CreateGlobalClusterError {
@@ -21474,27 +21975,29 @@ impl CreateGlobalClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateGlobalClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            CreateGlobalClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "GlobalClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateGlobalClusterError::GlobalClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "GlobalClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateGlobalClusterError::GlobalClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             CreateGlobalClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21512,6 +22015,20 @@ impl CreateGlobalClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateGlobalClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateGlobalClusterError::GlobalClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateGlobalClusterError::GlobalClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateGlobalClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateGlobalClusterError {
     #[allow(unused_variables)]
@@ -21535,9 +22052,9 @@ impl Error for CreateGlobalClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateOptionGroupError {
     /// <p>The option group you are trying to create already exists.</p>
-    OptionGroupAlreadyExistsFault(String),
+    OptionGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The quota of 20 option groups was exceeded for this AWS account.</p>
-    OptionGroupQuotaExceededFault(String),
+    OptionGroupQuotaExceededFault(XmlErrorMessage),
 }
 
 impl CreateOptionGroupError {
@@ -21551,14 +22068,14 @@ impl CreateOptionGroupError {
                     "OptionGroupAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateOptionGroupError::OptionGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateOptionGroupError::OptionGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21576,6 +22093,14 @@ impl CreateOptionGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateOptionGroupError::OptionGroupAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateOptionGroupError::OptionGroupQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateOptionGroupError {
     #[allow(unused_variables)]
@@ -21595,9 +22120,9 @@ impl Error for CreateOptionGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomAvailabilityZoneError {
     /// <p> <code>CustomAvailabilityZoneId</code> doesn't refer to an existing custom Availability Zone identifier.</p>
-    CustomAvailabilityZoneNotFoundFault(String),
+    CustomAvailabilityZoneNotFoundFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
 }
 
 impl DeleteCustomAvailabilityZoneError {
@@ -21613,14 +22138,14 @@ impl DeleteCustomAvailabilityZoneError {
                     "CustomAvailabilityZoneNotFound" => {
                         return RusotoError::Service(
                             DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21638,6 +22163,18 @@ impl DeleteCustomAvailabilityZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteCustomAvailabilityZoneError {
     #[allow(unused_variables)]
@@ -21657,15 +22194,15 @@ impl Error for DeleteCustomAvailabilityZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB cluster snapshot with the given identifier.</p>
-    DBClusterSnapshotAlreadyExistsFault(String),
+    DBClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterError {
@@ -21678,31 +22215,35 @@ impl DeleteDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBClusterError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -21719,6 +22260,21 @@ impl DeleteDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBClusterError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterError {
     #[allow(unused_variables)]
@@ -21741,11 +22297,11 @@ impl Error for DeleteDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterEndpointError {
     /// <p>The specified custom endpoint doesn't exist.</p>
-    DBClusterEndpointNotFoundFault(String),
+    DBClusterEndpointNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed on the endpoint while the endpoint is in this state.</p>
-    InvalidDBClusterEndpointStateFault(String),
+    InvalidDBClusterEndpointStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterEndpointError {
@@ -21759,21 +22315,21 @@ impl DeleteDBClusterEndpointError {
                     "DBClusterEndpointNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21791,6 +22347,21 @@ impl DeleteDBClusterEndpointError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterEndpointError {
     #[allow(unused_variables)]
@@ -21813,9 +22384,9 @@ impl Error for DeleteDBClusterEndpointError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterParameterGroupError {
@@ -21831,14 +22402,14 @@ impl DeleteDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21856,6 +22427,18 @@ impl DeleteDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -21875,9 +22458,9 @@ impl Error for DeleteDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBClusterSnapshotError {
@@ -21891,14 +22474,14 @@ impl DeleteDBClusterSnapshotError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -21916,6 +22499,18 @@ impl DeleteDBClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBClusterSnapshotError {
     #[allow(unused_variables)]
@@ -21935,17 +22530,17 @@ impl Error for DeleteDBClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
     /// <p>The quota for retained automated backups was exceeded. This prevents you from retaining any additional automated backups. The retained automated backups quota is the same as your DB Instance quota.</p>
-    DBInstanceAutomatedBackupQuotaExceededFault(String),
+    DBInstanceAutomatedBackupQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> is already used by an existing snapshot. </p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl DeleteDBInstanceError {
@@ -21959,37 +22554,43 @@ impl DeleteDBInstanceError {
                     "DBInstanceAutomatedBackupQuotaExceeded" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            DeleteDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            DeleteDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -22006,6 +22607,20 @@ impl DeleteDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBInstanceError {
     #[allow(unused_variables)]
@@ -22029,9 +22644,9 @@ impl Error for DeleteDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceAutomatedBackupError {
     /// <p>No automated backup for this DB instance was found.</p>
-    DBInstanceAutomatedBackupNotFoundFault(String),
+    DBInstanceAutomatedBackupNotFoundFault(XmlErrorMessage),
     /// <p>The automated backup is in an invalid state. For example, this automated backup is associated with an active instance. </p>
-    InvalidDBInstanceAutomatedBackupStateFault(String),
+    InvalidDBInstanceAutomatedBackupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBInstanceAutomatedBackupError {
@@ -22044,7 +22659,7 @@ impl DeleteDBInstanceAutomatedBackupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -22058,6 +22673,18 @@ impl DeleteDBInstanceAutomatedBackupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBInstanceAutomatedBackupError {
     #[allow(unused_variables)]
@@ -22077,9 +22704,9 @@ impl Error for DeleteDBInstanceAutomatedBackupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBParameterGroupError {
@@ -22093,14 +22720,14 @@ impl DeleteDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22118,6 +22745,18 @@ impl DeleteDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBParameterGroupError {
     #[allow(unused_variables)]
@@ -22137,9 +22776,9 @@ impl Error for DeleteDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBProxyError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBProxyError {
@@ -22152,12 +22791,12 @@ impl DeleteDBProxyError {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(DeleteDBProxyError::DBProxyNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(DeleteDBProxyError::InvalidDBProxyStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -22174,6 +22813,14 @@ impl DeleteDBProxyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBProxyError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBProxyError::InvalidDBProxyStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBProxyError {
     #[allow(unused_variables)]
@@ -22189,9 +22836,9 @@ impl Error for DeleteDBProxyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSecurityGroupError {
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBSecurityGroupError {
@@ -22205,14 +22852,14 @@ impl DeleteDBSecurityGroupError {
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22230,6 +22877,18 @@ impl DeleteDBSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteDBSecurityGroupError {
     #[allow(unused_variables)]
@@ -22249,9 +22908,9 @@ impl Error for DeleteDBSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSnapshotError {
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBSnapshotError {
@@ -22264,13 +22923,15 @@ impl DeleteDBSnapshotError {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            DeleteDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
+                            DeleteDBSnapshotError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             DeleteDBSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22288,6 +22949,14 @@ impl DeleteDBSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBSnapshotError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBSnapshotError::InvalidDBSnapshotStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBSnapshotError {
     #[allow(unused_variables)]
@@ -22303,11 +22972,11 @@ impl Error for DeleteDBSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p> The DB subnet isn't in the <i>available</i> state. </p>
-    InvalidDBSubnetStateFault(String),
+    InvalidDBSubnetStateFault(XmlErrorMessage),
 }
 
 impl DeleteDBSubnetGroupError {
@@ -22321,21 +22990,21 @@ impl DeleteDBSubnetGroupError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
                         return RusotoError::Service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22353,6 +23022,17 @@ impl DeleteDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -22375,9 +23055,9 @@ impl Error for DeleteDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
     /// <p>This error can occur if someone else is modifying a subscription. You should retry the action.</p>
-    InvalidEventSubscriptionStateFault(String),
+    InvalidEventSubscriptionStateFault(XmlErrorMessage),
     /// <p>The subscription name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteEventSubscriptionError {
@@ -22391,14 +23071,14 @@ impl DeleteEventSubscriptionError {
                     "InvalidEventSubscriptionState" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22416,6 +23096,18 @@ impl DeleteEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteEventSubscriptionError {
     #[allow(unused_variables)]
@@ -22435,9 +23127,9 @@ impl Error for DeleteEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteGlobalClusterError {
     /// <p><p/></p>
-    GlobalClusterNotFoundFault(String),
+    GlobalClusterNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    InvalidGlobalClusterStateFault(String),
+    InvalidGlobalClusterStateFault(XmlErrorMessage),
 }
 
 impl DeleteGlobalClusterError {
@@ -22451,14 +23143,14 @@ impl DeleteGlobalClusterError {
                     "GlobalClusterNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
                         return RusotoError::Service(
                             DeleteGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22476,6 +23168,16 @@ impl DeleteGlobalClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteGlobalClusterError::GlobalClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteGlobalClusterError::InvalidGlobalClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteGlobalClusterError {
     #[allow(unused_variables)]
@@ -22495,7 +23197,7 @@ impl Error for DeleteGlobalClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstallationMediaError {
     /// <p> <code>InstallationMediaID</code> doesn't refer to an existing installation medium.</p>
-    InstallationMediaNotFoundFault(String),
+    InstallationMediaNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteInstallationMediaError {
@@ -22509,7 +23211,7 @@ impl DeleteInstallationMediaError {
                     "InstallationMediaNotFound" => {
                         return RusotoError::Service(
                             DeleteInstallationMediaError::InstallationMediaNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22527,6 +23229,15 @@ impl DeleteInstallationMediaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteInstallationMediaError::InstallationMediaNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteInstallationMediaError {
     #[allow(unused_variables)]
@@ -22543,9 +23254,9 @@ impl Error for DeleteInstallationMediaError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteOptionGroupError {
     /// <p> The option group isn't in the <i>available</i> state. </p>
-    InvalidOptionGroupStateFault(String),
+    InvalidOptionGroupStateFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteOptionGroupError {
@@ -22559,13 +23270,15 @@ impl DeleteOptionGroupError {
                     "InvalidOptionGroupStateFault" => {
                         return RusotoError::Service(
                             DeleteOptionGroupError::InvalidOptionGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            DeleteOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                            DeleteOptionGroupError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -22582,6 +23295,14 @@ impl DeleteOptionGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteOptionGroupError::InvalidOptionGroupStateFault(ref cause) => cause.request_id(),
+            DeleteOptionGroupError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteOptionGroupError {
     #[allow(unused_variables)]
@@ -22599,13 +23320,13 @@ impl Error for DeleteOptionGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeregisterDBProxyTargetsError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified RDS DB instance or Aurora DB cluster isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetNotFoundFault(String),
+    DBProxyTargetNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl DeregisterDBProxyTargetsError {
@@ -22619,28 +23340,28 @@ impl DeregisterDBProxyTargetsError {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
                             DeregisterDBProxyTargetsError::DBProxyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
                         return RusotoError::Service(
                             DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(
                             DeregisterDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22658,6 +23379,22 @@ impl DeregisterDBProxyTargetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeregisterDBProxyTargetsError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeregisterDBProxyTargetsError::InvalidDBProxyStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeregisterDBProxyTargetsError {
     #[allow(unused_variables)]
@@ -22705,6 +23442,11 @@ impl DescribeAccountAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
@@ -22717,7 +23459,7 @@ impl Error for DescribeAccountAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificatesError {
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeCertificatesError {
@@ -22731,7 +23473,7 @@ impl DescribeCertificatesError {
                     "CertificateNotFound" => {
                         return RusotoError::Service(
                             DescribeCertificatesError::CertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22749,6 +23491,13 @@ impl DescribeCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCertificatesError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeCertificatesError {
     #[allow(unused_variables)]
@@ -22765,7 +23514,7 @@ impl Error for DescribeCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomAvailabilityZonesError {
     /// <p> <code>CustomAvailabilityZoneId</code> doesn't refer to an existing custom Availability Zone identifier.</p>
-    CustomAvailabilityZoneNotFoundFault(String),
+    CustomAvailabilityZoneNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeCustomAvailabilityZonesError {
@@ -22780,7 +23529,7 @@ impl DescribeCustomAvailabilityZonesError {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => return RusotoError::Service(
                         DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -22797,6 +23546,15 @@ impl DescribeCustomAvailabilityZonesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeCustomAvailabilityZonesError {
     #[allow(unused_variables)]
@@ -22813,9 +23571,9 @@ impl Error for DescribeCustomAvailabilityZonesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterBacktracksError {
     /// <p> <code>BacktrackIdentifier</code> doesn't refer to an existing backtrack. </p>
-    DBClusterBacktrackNotFoundFault(String),
+    DBClusterBacktrackNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterBacktracksError {
@@ -22831,14 +23589,14 @@ impl DescribeDBClusterBacktracksError {
                     "DBClusterBacktrackNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterBacktracksError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22856,6 +23614,18 @@ impl DescribeDBClusterBacktracksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeDBClusterBacktracksError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterBacktracksError {
     #[allow(unused_variables)]
@@ -22875,7 +23645,7 @@ impl Error for DescribeDBClusterBacktracksError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterEndpointsError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterEndpointsError {
@@ -22891,7 +23661,7 @@ impl DescribeDBClusterEndpointsError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterEndpointsError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22909,6 +23679,15 @@ impl DescribeDBClusterEndpointsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterEndpointsError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterEndpointsError {
     #[allow(unused_variables)]
@@ -22925,7 +23704,7 @@ impl Error for DescribeDBClusterEndpointsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParameterGroupsError {
@@ -22941,7 +23720,7 @@ impl DescribeDBClusterParameterGroupsError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -22959,6 +23738,15 @@ impl DescribeDBClusterParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParameterGroupsError {
     #[allow(unused_variables)]
@@ -22975,7 +23763,7 @@ impl Error for DescribeDBClusterParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterParametersError {
@@ -22991,7 +23779,7 @@ impl DescribeDBClusterParametersError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23009,6 +23797,15 @@ impl DescribeDBClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterParametersError {
     #[allow(unused_variables)]
@@ -23025,7 +23822,7 @@ impl Error for DescribeDBClusterParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotAttributesError {
@@ -23040,7 +23837,7 @@ impl DescribeDBClusterSnapshotAttributesError {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -23057,6 +23854,15 @@ impl DescribeDBClusterSnapshotAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     #[allow(unused_variables)]
@@ -23073,7 +23879,7 @@ impl Error for DescribeDBClusterSnapshotAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClusterSnapshotsError {
@@ -23089,7 +23895,7 @@ impl DescribeDBClusterSnapshotsError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23107,6 +23913,15 @@ impl DescribeDBClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -23123,7 +23938,7 @@ impl Error for DescribeDBClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBClustersError {
@@ -23136,7 +23951,9 @@ impl DescribeDBClustersError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                            DescribeDBClustersError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -23153,6 +23970,13 @@ impl DescribeDBClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBClustersError {
     #[allow(unused_variables)]
@@ -23189,6 +24013,11 @@ impl DescribeDBEngineVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeDBEngineVersionsError {
     #[allow(unused_variables)]
@@ -23201,7 +24030,7 @@ impl Error for DescribeDBEngineVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstanceAutomatedBackupsError {
     /// <p>No automated backup for this DB instance was found.</p>
-    DBInstanceAutomatedBackupNotFoundFault(String),
+    DBInstanceAutomatedBackupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBInstanceAutomatedBackupsError {
@@ -23214,7 +24043,7 @@ impl DescribeDBInstanceAutomatedBackupsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -23228,6 +24057,15 @@ impl DescribeDBInstanceAutomatedBackupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBInstanceAutomatedBackupsError {
     #[allow(unused_variables)]
@@ -23244,7 +24082,7 @@ impl Error for DescribeDBInstanceAutomatedBackupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBInstancesError {
@@ -23257,7 +24095,9 @@ impl DescribeDBInstancesError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                            DescribeDBInstancesError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -23274,6 +24114,13 @@ impl DescribeDBInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBInstancesError {
     #[allow(unused_variables)]
@@ -23288,7 +24135,7 @@ impl Error for DescribeDBInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBLogFilesError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBLogFilesError {
@@ -23301,7 +24148,9 @@ impl DescribeDBLogFilesError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            DescribeDBLogFilesError::DBInstanceNotFoundFault(parsed_error.message),
+                            DescribeDBLogFilesError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -23318,6 +24167,13 @@ impl DescribeDBLogFilesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBLogFilesError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBLogFilesError {
     #[allow(unused_variables)]
@@ -23332,7 +24188,7 @@ impl Error for DescribeDBLogFilesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParameterGroupsError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBParameterGroupsError {
@@ -23346,7 +24202,7 @@ impl DescribeDBParameterGroupsError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23364,6 +24220,15 @@ impl DescribeDBParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBParameterGroupsError {
     #[allow(unused_variables)]
@@ -23380,7 +24245,7 @@ impl Error for DescribeDBParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParametersError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBParametersError {
@@ -23394,7 +24259,7 @@ impl DescribeDBParametersError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23412,6 +24277,15 @@ impl DescribeDBParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBParametersError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBParametersError {
     #[allow(unused_variables)]
@@ -23428,7 +24302,7 @@ impl Error for DescribeDBParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxiesError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBProxiesError {
@@ -23441,7 +24315,7 @@ impl DescribeDBProxiesError {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(DescribeDBProxiesError::DBProxyNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -23458,6 +24332,13 @@ impl DescribeDBProxiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBProxiesError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBProxiesError {
     #[allow(unused_variables)]
@@ -23472,11 +24353,11 @@ impl Error for DescribeDBProxiesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxyTargetGroupsError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl DescribeDBProxyTargetGroupsError {
@@ -23492,21 +24373,21 @@ impl DescribeDBProxyTargetGroupsError {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23524,10 +24405,23 @@ impl DescribeDBProxyTargetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
-}
-impl fmt::Display for DescribeDBProxyTargetGroupsError {
-    #[allow(unused_variables)]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
+}
+impl fmt::Display for DescribeDBProxyTargetGroupsError {
+    #[allow(unused_variables)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
@@ -23546,13 +24440,13 @@ impl Error for DescribeDBProxyTargetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxyTargetsError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified RDS DB instance or Aurora DB cluster isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetNotFoundFault(String),
+    DBProxyTargetNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl DescribeDBProxyTargetsError {
@@ -23565,27 +24459,29 @@ impl DescribeDBProxyTargetsError {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
-                            DescribeDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
+                            DescribeDBProxyTargetsError::DBProxyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(
                             DescribeDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23603,6 +24499,20 @@ impl DescribeDBProxyTargetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBProxyTargetsError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeDBProxyTargetsError::InvalidDBProxyStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBProxyTargetsError {
     #[allow(unused_variables)]
@@ -23626,7 +24536,7 @@ impl Error for DescribeDBProxyTargetsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSecurityGroupsError {
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSecurityGroupsError {
@@ -23640,7 +24550,7 @@ impl DescribeDBSecurityGroupsError {
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23658,6 +24568,15 @@ impl DescribeDBSecurityGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBSecurityGroupsError {
     #[allow(unused_variables)]
@@ -23674,7 +24593,7 @@ impl Error for DescribeDBSecurityGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSnapshotAttributesError {
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSnapshotAttributesError {
@@ -23690,7 +24609,7 @@ impl DescribeDBSnapshotAttributesError {
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23708,6 +24627,15 @@ impl DescribeDBSnapshotAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBSnapshotAttributesError {
     #[allow(unused_variables)]
@@ -23724,7 +24652,7 @@ impl Error for DescribeDBSnapshotAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSnapshotsError {
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSnapshotsError {
@@ -23737,7 +24665,9 @@ impl DescribeDBSnapshotsError {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            DescribeDBSnapshotsError::DBSnapshotNotFoundFault(parsed_error.message),
+                            DescribeDBSnapshotsError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -23754,6 +24684,13 @@ impl DescribeDBSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSnapshotsError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeDBSnapshotsError {
     #[allow(unused_variables)]
@@ -23768,7 +24705,7 @@ impl Error for DescribeDBSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeDBSubnetGroupsError {
@@ -23782,7 +24719,7 @@ impl DescribeDBSubnetGroupsError {
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23800,6 +24737,15 @@ impl DescribeDBSubnetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeDBSubnetGroupsError {
     #[allow(unused_variables)]
@@ -23840,6 +24786,11 @@ impl DescribeEngineDefaultClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     #[allow(unused_variables)]
@@ -23876,6 +24827,11 @@ impl DescribeEngineDefaultParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEngineDefaultParametersError {
     #[allow(unused_variables)]
@@ -23910,6 +24866,11 @@ impl DescribeEventCategoriesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
@@ -23922,7 +24883,7 @@ impl Error for DescribeEventCategoriesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The subscription name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -23938,7 +24899,7 @@ impl DescribeEventSubscriptionsError {
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -23956,6 +24917,15 @@ impl DescribeEventSubscriptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEventSubscriptionsError {
     #[allow(unused_variables)]
@@ -23994,6 +24964,11 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -24006,7 +24981,7 @@ impl Error for DescribeEventsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportTasksError {
     /// <p>The export task doesn't exist.</p>
-    ExportTaskNotFoundFault(String),
+    ExportTaskNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeExportTasksError {
@@ -24019,7 +24994,9 @@ impl DescribeExportTasksError {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
                         return RusotoError::Service(
-                            DescribeExportTasksError::ExportTaskNotFoundFault(parsed_error.message),
+                            DescribeExportTasksError::ExportTaskNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -24036,6 +25013,13 @@ impl DescribeExportTasksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeExportTasksError::ExportTaskNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeExportTasksError {
     #[allow(unused_variables)]
@@ -24050,7 +25034,7 @@ impl Error for DescribeExportTasksError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalClustersError {
     /// <p><p/></p>
-    GlobalClusterNotFoundFault(String),
+    GlobalClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeGlobalClustersError {
@@ -24064,7 +25048,7 @@ impl DescribeGlobalClustersError {
                     "GlobalClusterNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeGlobalClustersError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24082,6 +25066,15 @@ impl DescribeGlobalClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeGlobalClustersError::GlobalClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeGlobalClustersError {
     #[allow(unused_variables)]
@@ -24098,7 +25091,7 @@ impl Error for DescribeGlobalClustersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstallationMediaError {
     /// <p> <code>InstallationMediaID</code> doesn't refer to an existing installation medium.</p>
-    InstallationMediaNotFoundFault(String),
+    InstallationMediaNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeInstallationMediaError {
@@ -24112,7 +25105,7 @@ impl DescribeInstallationMediaError {
                     "InstallationMediaNotFound" => {
                         return RusotoError::Service(
                             DescribeInstallationMediaError::InstallationMediaNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24130,6 +25123,15 @@ impl DescribeInstallationMediaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeInstallationMediaError::InstallationMediaNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeInstallationMediaError {
     #[allow(unused_variables)]
@@ -24170,6 +25172,11 @@ impl DescribeOptionGroupOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeOptionGroupOptionsError {
     #[allow(unused_variables)]
@@ -24182,7 +25189,7 @@ impl Error for DescribeOptionGroupOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeOptionGroupsError {
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeOptionGroupsError {
@@ -24196,7 +25203,7 @@ impl DescribeOptionGroupsError {
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeOptionGroupsError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24214,6 +25221,13 @@ impl DescribeOptionGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeOptionGroupsError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeOptionGroupsError {
     #[allow(unused_variables)]
@@ -24254,6 +25268,11 @@ impl DescribeOrderableDBInstanceOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     #[allow(unused_variables)]
@@ -24266,7 +25285,7 @@ impl Error for DescribeOrderableDBInstanceOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -24282,7 +25301,7 @@ impl DescribePendingMaintenanceActionsError {
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24300,6 +25319,15 @@ impl DescribePendingMaintenanceActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribePendingMaintenanceActionsError {
     #[allow(unused_variables)]
@@ -24316,7 +25344,7 @@ impl Error for DescribePendingMaintenanceActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedDBInstancesError {
     /// <p>The specified reserved DB Instance not found.</p>
-    ReservedDBInstanceNotFoundFault(String),
+    ReservedDBInstanceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReservedDBInstancesError {
@@ -24332,7 +25360,7 @@ impl DescribeReservedDBInstancesError {
                     "ReservedDBInstanceNotFound" => {
                         return RusotoError::Service(
                             DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24350,6 +25378,15 @@ impl DescribeReservedDBInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeReservedDBInstancesError {
     #[allow(unused_variables)]
@@ -24366,7 +25403,7 @@ impl Error for DescribeReservedDBInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedDBInstancesOfferingsError {
     /// <p>Specified offering does not exist.</p>
-    ReservedDBInstancesOfferingNotFoundFault(String),
+    ReservedDBInstancesOfferingNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReservedDBInstancesOfferingsError {
@@ -24379,7 +25416,7 @@ impl DescribeReservedDBInstancesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -24393,6 +25430,15 @@ impl DescribeReservedDBInstancesOfferingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeReservedDBInstancesOfferingsError {
     #[allow(unused_variables)]
@@ -24431,6 +25477,11 @@ impl DescribeSourceRegionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSourceRegionsError {
     #[allow(unused_variables)]
@@ -24443,9 +25494,9 @@ impl Error for DescribeSourceRegionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeValidDBInstanceModificationsError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl DescribeValidDBInstanceModificationsError {
@@ -24461,14 +25512,14 @@ impl DescribeValidDBInstanceModificationsError {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24486,6 +25537,18 @@ impl DescribeValidDBInstanceModificationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeValidDBInstanceModificationsError {
     #[allow(unused_variables)]
@@ -24505,9 +25568,9 @@ impl Error for DescribeValidDBInstanceModificationsError {}
 #[derive(Debug, PartialEq)]
 pub enum DownloadDBLogFilePortionError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>LogFileName</code> doesn't refer to an existing DB log file.</p>
-    DBLogFileNotFoundFault(String),
+    DBLogFileNotFoundFault(XmlErrorMessage),
 }
 
 impl DownloadDBLogFilePortionError {
@@ -24521,14 +25584,14 @@ impl DownloadDBLogFilePortionError {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             DownloadDBLogFilePortionError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBLogFileNotFoundFault" => {
                         return RusotoError::Service(
                             DownloadDBLogFilePortionError::DBLogFileNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24546,6 +25609,14 @@ impl DownloadDBLogFilePortionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DownloadDBLogFilePortionError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            DownloadDBLogFilePortionError::DBLogFileNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DownloadDBLogFilePortionError {
     #[allow(unused_variables)]
@@ -24565,11 +25636,11 @@ impl Error for DownloadDBLogFilePortionError {}
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl FailoverDBClusterError {
@@ -24582,20 +25653,22 @@ impl FailoverDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            FailoverDBClusterError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24613,6 +25686,15 @@ impl FailoverDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            FailoverDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for FailoverDBClusterError {
     #[allow(unused_variables)]
@@ -24631,9 +25713,9 @@ impl Error for FailoverDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ImportInstallationMediaError {
     /// <p> <code>CustomAvailabilityZoneId</code> doesn't refer to an existing custom Availability Zone identifier.</p>
-    CustomAvailabilityZoneNotFoundFault(String),
+    CustomAvailabilityZoneNotFoundFault(XmlErrorMessage),
     /// <p>The specified installation medium has already been imported.</p>
-    InstallationMediaAlreadyExistsFault(String),
+    InstallationMediaAlreadyExistsFault(XmlErrorMessage),
 }
 
 impl ImportInstallationMediaError {
@@ -24647,14 +25729,14 @@ impl ImportInstallationMediaError {
                     "CustomAvailabilityZoneNotFound" => {
                         return RusotoError::Service(
                             ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InstallationMediaAlreadyExists" => {
                         return RusotoError::Service(
                             ImportInstallationMediaError::InstallationMediaAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24672,6 +25754,18 @@ impl ImportInstallationMediaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ImportInstallationMediaError::InstallationMediaAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ImportInstallationMediaError {
     #[allow(unused_variables)]
@@ -24691,15 +25785,15 @@ impl Error for ImportInstallationMediaError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -24712,29 +25806,37 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBProxyNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBProxyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ListTagsForResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            ListTagsForResourceError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -24751,6 +25853,19 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            ListTagsForResourceError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ListTagsForResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -24771,7 +25886,7 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyCertificatesError {
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyCertificatesError {
@@ -24784,7 +25899,9 @@ impl ModifyCertificatesError {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
                         return RusotoError::Service(
-                            ModifyCertificatesError::CertificateNotFoundFault(parsed_error.message),
+                            ModifyCertificatesError::CertificateNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -24801,6 +25918,13 @@ impl ModifyCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyCertificatesError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyCertificatesError {
     #[allow(unused_variables)]
@@ -24815,11 +25939,11 @@ impl Error for ModifyCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyCurrentDBClusterCapacityError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>Capacity</code> isn't a valid Aurora Serverless DB cluster capacity. Valid capacity values are <code>2</code>, <code>4</code>, <code>8</code>, <code>16</code>, <code>32</code>, <code>64</code>, <code>128</code>, and <code>256</code>.</p>
-    InvalidDBClusterCapacityFault(String),
+    InvalidDBClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl ModifyCurrentDBClusterCapacityError {
@@ -24835,21 +25959,21 @@ impl ModifyCurrentDBClusterCapacityError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -24867,6 +25991,21 @@ impl ModifyCurrentDBClusterCapacityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyCurrentDBClusterCapacityError {
     #[allow(unused_variables)]
@@ -24889,29 +26028,29 @@ impl Error for ModifyCurrentDBClusterCapacityError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
     /// <p>The user already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing DB cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the DB security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterError {
@@ -24924,68 +26063,80 @@ impl ModifyDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(ModifyDBClusterError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBClusterError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -25002,6 +26153,26 @@ impl ModifyDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBClusterError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterError {
     #[allow(unused_variables)]
@@ -25033,15 +26204,15 @@ impl Error for ModifyDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterEndpointError {
     /// <p>The specified custom endpoint doesn't exist.</p>
-    DBClusterEndpointNotFoundFault(String),
+    DBClusterEndpointNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed on the endpoint while the endpoint is in this state.</p>
-    InvalidDBClusterEndpointStateFault(String),
+    InvalidDBClusterEndpointStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterEndpointError {
@@ -25055,35 +26226,35 @@ impl ModifyDBClusterEndpointError {
                     "DBClusterEndpointNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25101,6 +26272,25 @@ impl ModifyDBClusterEndpointError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterEndpointError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterEndpointError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterEndpointError {
     #[allow(unused_variables)]
@@ -25129,9 +26319,9 @@ impl Error for ModifyDBClusterEndpointError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterParameterGroupError {
@@ -25147,14 +26337,14 @@ impl ModifyDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25172,6 +26362,18 @@ impl ModifyDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -25191,11 +26393,11 @@ impl Error for ModifyDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with.</p>
-    SharedSnapshotQuotaExceededFault(String),
+    SharedSnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBClusterSnapshotAttributeError {
@@ -25211,19 +26413,19 @@ impl ModifyDBClusterSnapshotAttributeError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25241,6 +26443,21 @@ impl ModifyDBClusterSnapshotAttributeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -25263,43 +26480,42 @@ impl Error for ModifyDBClusterSnapshotAttributeError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
-
-    BackupPolicyNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
+    BackupPolicyNotFoundFault(XmlErrorMessage),
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
-    CertificateNotFoundFault(String),
+    CertificateNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB upgrade failed because a resource the DB depends on can't be modified.</p>
-    DBUpgradeDependencyFailureFault(String),
+    DBUpgradeDependencyFailureFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The state of the DB security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl ModifyDBInstanceError {
@@ -25312,116 +26528,132 @@ impl ModifyDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::BackupPolicyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "CertificateNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::CertificateNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            ModifyDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            ModifyDBInstanceError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            ModifyDBInstanceError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            ModifyDBInstanceError::StorageQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25439,6 +26671,37 @@ impl ModifyDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::BackupPolicyNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::CertificateNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBInstanceError::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBInstanceError {
     #[allow(unused_variables)]
@@ -25487,9 +26750,9 @@ impl Error for ModifyDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBParameterGroupError {
@@ -25503,14 +26766,14 @@ impl ModifyDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25528,6 +26791,18 @@ impl ModifyDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBParameterGroupError {
     #[allow(unused_variables)]
@@ -25547,11 +26822,11 @@ impl Error for ModifyDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBProxyError {
     /// <p>The specified proxy name must be unique for all proxies owned by your AWS account in the specified AWS Region.</p>
-    DBProxyAlreadyExistsFault(String),
+    DBProxyAlreadyExistsFault(XmlErrorMessage),
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBProxyError {
@@ -25564,17 +26839,17 @@ impl ModifyDBProxyError {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
                         return RusotoError::Service(ModifyDBProxyError::DBProxyAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(ModifyDBProxyError::DBProxyNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(ModifyDBProxyError::InvalidDBProxyStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -25591,6 +26866,15 @@ impl ModifyDBProxyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBProxyError::DBProxyAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyDBProxyError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBProxyError::InvalidDBProxyStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBProxyError {
     #[allow(unused_variables)]
@@ -25607,11 +26891,11 @@ impl Error for ModifyDBProxyError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBProxyTargetGroupError {
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl ModifyDBProxyTargetGroupError {
@@ -25625,21 +26909,21 @@ impl ModifyDBProxyTargetGroupError {
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBProxyTargetGroupError::DBProxyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(
                             ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25657,6 +26941,19 @@ impl ModifyDBProxyTargetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBProxyTargetGroupError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBProxyTargetGroupError {
     #[allow(unused_variables)]
@@ -25679,7 +26976,7 @@ impl Error for ModifyDBProxyTargetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSnapshotError {
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyDBSnapshotError {
@@ -25692,7 +26989,9 @@ impl ModifyDBSnapshotError {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
-                            ModifyDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
+                            ModifyDBSnapshotError::DBSnapshotNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -25709,6 +27008,13 @@ impl ModifyDBSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBSnapshotError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBSnapshotError {
     #[allow(unused_variables)]
@@ -25723,11 +27029,11 @@ impl Error for ModifyDBSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSnapshotAttributeError {
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with.</p>
-    SharedSnapshotQuotaExceededFault(String),
+    SharedSnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl ModifyDBSnapshotAttributeError {
@@ -25741,21 +27047,21 @@ impl ModifyDBSnapshotAttributeError {
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SharedSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25773,6 +27079,21 @@ impl ModifyDBSnapshotAttributeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyDBSnapshotAttributeError {
     #[allow(unused_variables)]
@@ -25795,15 +27116,15 @@ impl Error for ModifyDBSnapshotAttributeError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of subnets in a DB subnet groups.</p>
-    DBSubnetQuotaExceededFault(String),
+    DBSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet is already in use in the Availability Zone.</p>
-    SubnetAlreadyInUse(String),
+    SubnetAlreadyInUse(XmlErrorMessage),
 }
 
 impl ModifyDBSubnetGroupError {
@@ -25817,32 +27138,32 @@ impl ModifyDBSubnetGroupError {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetAlreadyInUse" => {
                         return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -25859,6 +27180,19 @@ impl ModifyDBSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyDBSubnetGroupError {
     #[allow(unused_variables)]
@@ -25883,17 +27217,17 @@ impl Error for ModifyDBSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
     /// <p>You have reached the maximum number of event subscriptions.</p>
-    EventSubscriptionQuotaExceededFault(String),
+    EventSubscriptionQuotaExceededFault(XmlErrorMessage),
     /// <p>SNS has responded that there is a problem with the SND topic specified.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>You do not have permission to publish to the SNS topic ARN.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>The SNS topic ARN does not exist.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The supplied category does not exist.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
     /// <p>The subscription name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyEventSubscriptionError {
@@ -25907,42 +27241,42 @@ impl ModifyEventSubscriptionError {
                     "EventSubscriptionQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -25960,6 +27294,24 @@ impl ModifyEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyEventSubscriptionError {
     #[allow(unused_variables)]
@@ -25989,9 +27341,9 @@ impl Error for ModifyEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyGlobalClusterError {
     /// <p><p/></p>
-    GlobalClusterNotFoundFault(String),
+    GlobalClusterNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    InvalidGlobalClusterStateFault(String),
+    InvalidGlobalClusterStateFault(XmlErrorMessage),
 }
 
 impl ModifyGlobalClusterError {
@@ -26005,14 +27357,14 @@ impl ModifyGlobalClusterError {
                     "GlobalClusterNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
                         return RusotoError::Service(
                             ModifyGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26030,6 +27382,16 @@ impl ModifyGlobalClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyGlobalClusterError::GlobalClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyGlobalClusterError::InvalidGlobalClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyGlobalClusterError {
     #[allow(unused_variables)]
@@ -26049,9 +27411,9 @@ impl Error for ModifyGlobalClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyOptionGroupError {
     /// <p> The option group isn't in the <i>available</i> state. </p>
-    InvalidOptionGroupStateFault(String),
+    InvalidOptionGroupStateFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyOptionGroupError {
@@ -26065,13 +27427,15 @@ impl ModifyOptionGroupError {
                     "InvalidOptionGroupStateFault" => {
                         return RusotoError::Service(
                             ModifyOptionGroupError::InvalidOptionGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                            ModifyOptionGroupError::OptionGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -26088,6 +27452,14 @@ impl ModifyOptionGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyOptionGroupError::InvalidOptionGroupStateFault(ref cause) => cause.request_id(),
+            ModifyOptionGroupError::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyOptionGroupError {
     #[allow(unused_variables)]
@@ -26105,9 +27477,9 @@ impl Error for ModifyOptionGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl PromoteReadReplicaError {
@@ -26120,13 +27492,15 @@ impl PromoteReadReplicaError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            PromoteReadReplicaError::DBInstanceNotFoundFault(parsed_error.message),
+                            PromoteReadReplicaError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             PromoteReadReplicaError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26144,6 +27518,14 @@ impl PromoteReadReplicaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PromoteReadReplicaError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            PromoteReadReplicaError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PromoteReadReplicaError {
     #[allow(unused_variables)]
@@ -26161,9 +27543,9 @@ impl Error for PromoteReadReplicaError {}
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl PromoteReadReplicaDBClusterError {
@@ -26179,14 +27561,14 @@ impl PromoteReadReplicaDBClusterError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26204,6 +27586,18 @@ impl PromoteReadReplicaDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PromoteReadReplicaDBClusterError {
     #[allow(unused_variables)]
@@ -26223,11 +27617,11 @@ impl Error for PromoteReadReplicaDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedDBInstancesOfferingError {
     /// <p>User already has a reservation with the given identifier.</p>
-    ReservedDBInstanceAlreadyExistsFault(String),
+    ReservedDBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p>Request would exceed the user's DB Instance quota.</p>
-    ReservedDBInstanceQuotaExceededFault(String),
+    ReservedDBInstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>Specified offering does not exist.</p>
-    ReservedDBInstancesOfferingNotFoundFault(String),
+    ReservedDBInstancesOfferingNotFoundFault(XmlErrorMessage),
 }
 
 impl PurchaseReservedDBInstancesOfferingError {
@@ -26240,7 +27634,7 @@ impl PurchaseReservedDBInstancesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(parsed_error.message)),"ReservedDBInstanceQuotaExceeded" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(parsed_error.message)),"ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedDBInstanceQuotaExceeded" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -26254,6 +27648,21 @@ impl PurchaseReservedDBInstancesOfferingError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PurchaseReservedDBInstancesOfferingError {
     #[allow(unused_variables)]
@@ -26276,9 +27685,9 @@ impl Error for PurchaseReservedDBInstancesOfferingError {}
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl RebootDBInstanceError {
@@ -26291,13 +27700,15 @@ impl RebootDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            RebootDBInstanceError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26315,6 +27726,14 @@ impl RebootDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RebootDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebootDBInstanceError {
     #[allow(unused_variables)]
@@ -26330,21 +27749,21 @@ impl Error for RebootDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum RegisterDBProxyTargetsError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The proxy is already associated with the specified RDS DB instance or Aurora DB cluster.</p>
-    DBProxyTargetAlreadyRegisteredFault(String),
+    DBProxyTargetAlreadyRegisteredFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the proxy is in this state.</p>
-    InvalidDBProxyStateFault(String),
+    InvalidDBProxyStateFault(XmlErrorMessage),
 }
 
 impl RegisterDBProxyTargetsError {
@@ -26358,54 +27777,56 @@ impl RegisterDBProxyTargetsError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
+                            RegisterDBProxyTargetsError::DBProxyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyTargetAlreadyRegisteredFault" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
                         return RusotoError::Service(
                             RegisterDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26423,6 +27844,28 @@ impl RegisterDBProxyTargetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RegisterDBProxyTargetsError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RegisterDBProxyTargetsError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RegisterDBProxyTargetsError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault(ref cause) => {
+                cause.request_id()
+            }
+            RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RegisterDBProxyTargetsError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RegisterDBProxyTargetsError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RegisterDBProxyTargetsError::InvalidDBProxyStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RegisterDBProxyTargetsError {
     #[allow(unused_variables)]
@@ -26458,11 +27901,11 @@ impl Error for RegisterDBProxyTargetsError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveFromGlobalClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    GlobalClusterNotFoundFault(String),
+    GlobalClusterNotFoundFault(XmlErrorMessage),
     /// <p><p/></p>
-    InvalidGlobalClusterStateFault(String),
+    InvalidGlobalClusterStateFault(XmlErrorMessage),
 }
 
 impl RemoveFromGlobalClusterError {
@@ -26476,21 +27919,21 @@ impl RemoveFromGlobalClusterError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveFromGlobalClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "GlobalClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveFromGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
                         return RusotoError::Service(
                             RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26508,6 +27951,19 @@ impl RemoveFromGlobalClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveFromGlobalClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveFromGlobalClusterError::GlobalClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveFromGlobalClusterError {
     #[allow(unused_variables)]
@@ -26530,11 +27986,11 @@ impl Error for RemoveFromGlobalClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified IAM role Amazon Resource Name (ARN) isn't associated with the specified DB cluster.</p>
-    DBClusterRoleNotFoundFault(String),
+    DBClusterRoleNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
 }
 
 impl RemoveRoleFromDBClusterError {
@@ -26548,21 +28004,21 @@ impl RemoveRoleFromDBClusterError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterRoleNotFound" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26580,6 +28036,19 @@ impl RemoveRoleFromDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveRoleFromDBClusterError {
     #[allow(unused_variables)]
@@ -26602,11 +28071,11 @@ impl Error for RemoveRoleFromDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified <code>RoleArn</code> value doesn't match the specified feature for the DB instance.</p>
-    DBInstanceRoleNotFoundFault(String),
+    DBInstanceRoleNotFoundFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl RemoveRoleFromDBInstanceError {
@@ -26620,21 +28089,21 @@ impl RemoveRoleFromDBInstanceError {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceRoleNotFound" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26652,6 +28121,19 @@ impl RemoveRoleFromDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveRoleFromDBInstanceError {
     #[allow(unused_variables)]
@@ -26674,9 +28156,9 @@ impl Error for RemoveRoleFromDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveSourceIdentifierFromSubscriptionError {
     /// <p>The requested source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The subscription name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveSourceIdentifierFromSubscriptionError {
@@ -26692,14 +28174,14 @@ impl RemoveSourceIdentifierFromSubscriptionError {
                     "SourceNotFound" => {
                         return RusotoError::Service(
                             RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26717,6 +28199,18 @@ impl RemoveSourceIdentifierFromSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RemoveSourceIdentifierFromSubscriptionError {
     #[allow(unused_variables)]
@@ -26736,15 +28230,15 @@ impl Error for RemoveSourceIdentifierFromSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The specified proxy name doesn't correspond to a proxy owned by your AWS accoutn in the specified AWS Region.</p>
-    DBProxyNotFoundFault(String),
+    DBProxyNotFoundFault(XmlErrorMessage),
     /// <p>The specified target group isn't available for a proxy owned by your AWS account in the specified AWS Region.</p>
-    DBProxyTargetGroupNotFoundFault(String),
+    DBProxyTargetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl RemoveTagsFromResourceError {
@@ -26758,33 +28252,35 @@ impl RemoveTagsFromResourceError {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
                         return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBProxyNotFoundFault(parsed_error.message),
+                            RemoveTagsFromResourceError::DBProxyNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26802,6 +28298,19 @@ impl RemoveTagsFromResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBProxyNotFoundFault(ref cause) => cause.request_id(),
+            RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemoveTagsFromResourceError {
     #[allow(unused_variables)]
@@ -26828,9 +28337,9 @@ impl Error for RemoveTagsFromResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetDBClusterParameterGroupError {
@@ -26846,14 +28355,14 @@ impl ResetDBClusterParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26871,6 +28380,18 @@ impl ResetDBClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetDBClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -26890,9 +28411,9 @@ impl Error for ResetDBClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetDBParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
-    InvalidDBParameterGroupStateFault(String),
+    InvalidDBParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetDBParameterGroupError {
@@ -26906,14 +28427,14 @@ impl ResetDBParameterGroupError {
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
                         return RusotoError::Service(
                             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -26931,6 +28452,18 @@ impl ResetDBParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetDBParameterGroupError {
     #[allow(unused_variables)]
@@ -26950,33 +28483,33 @@ impl Error for ResetDBParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromS3Error {
     /// <p>The user already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing DB cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The user attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB subnet group cannot be deleted because it's in use.</p>
-    InvalidDBSubnetGroupStateFault(String),
+    InvalidDBSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The specified Amazon S3 bucket name can't be found or Amazon RDS isn't authorized to access the specified Amazon S3 bucket. Verify the <b>SourceS3BucketName</b> and <b>S3IngestionRoleArn</b> values and try again.</p>
-    InvalidS3BucketFault(String),
+    InvalidS3BucketFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterFromS3Error {
@@ -26990,92 +28523,96 @@ impl RestoreDBClusterFromS3Error {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DomainNotFoundFault(parsed_error.message),
+                            RestoreDBClusterFromS3Error::DomainNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
                         return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InvalidS3BucketFault(parsed_error.message),
+                            RestoreDBClusterFromS3Error::InvalidS3BucketFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(RestoreDBClusterFromS3Error::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromS3Error::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -27093,6 +28630,42 @@ impl RestoreDBClusterFromS3Error {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::DomainNotFoundFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::InvalidS3BucketFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromS3Error::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromS3Error::StorageQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterFromS3Error {
     #[allow(unused_variables)]
@@ -27142,39 +28715,39 @@ impl Error for RestoreDBClusterFromS3Error {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
     /// <p>The user already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing DB cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The user attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster doesn't have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>Cannot restore from VPC backup to non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterFromSnapshotError {
@@ -27190,115 +28763,117 @@ impl RestoreDBClusterFromSnapshotError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::DomainNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -27316,6 +28891,57 @@ impl RestoreDBClusterFromSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::DomainNotFoundFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterFromSnapshotError {
     #[allow(unused_variables)]
@@ -27378,41 +29004,41 @@ impl Error for RestoreDBClusterFromSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
     /// <p>The user already has a DB cluster with the given identifier.</p>
-    DBClusterAlreadyExistsFault(String),
+    DBClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterParameterGroupName</code> doesn't refer to an existing DB cluster parameter group. </p>
-    DBClusterParameterGroupNotFoundFault(String),
+    DBClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The user attempted to create a new DB cluster and the user has already reached the maximum allowed DB cluster quota.</p>
-    DBClusterQuotaExceededFault(String),
+    DBClusterQuotaExceededFault(XmlErrorMessage),
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The DB cluster doesn't have enough capacity for the current operation.</p>
-    InsufficientDBClusterCapacityFault(String),
+    InsufficientDBClusterCapacityFault(XmlErrorMessage),
     /// <p>There is insufficient storage available for the current action. You might be able to resolve this error by updating your subnet group to use different Availability Zones that have more storage available.</p>
-    InsufficientStorageClusterCapacityFault(String),
+    InsufficientStorageClusterCapacityFault(XmlErrorMessage),
     /// <p>The supplied value isn't a valid DB cluster snapshot state.</p>
-    InvalidDBClusterSnapshotStateFault(String),
+    InvalidDBClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>Cannot restore from VPC backup to non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
 }
 
 impl RestoreDBClusterToPointInTimeError {
@@ -27428,120 +29054,122 @@ impl RestoreDBClusterToPointInTimeError {
                     "DBClusterAlreadyExistsFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => return RusotoError::Service(
                         RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "DBClusterQuotaExceededFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::DomainNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => return RusotoError::Service(
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -27559,6 +29187,64 @@ impl RestoreDBClusterToPointInTimeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::DomainNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBClusterToPointInTimeError {
     #[allow(unused_variables)]
@@ -27624,45 +29310,44 @@ impl Error for RestoreDBClusterToPointInTimeError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceFromDBSnapshotError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
-
-    BackupPolicyNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
+    BackupPolicyNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The state of the DB snapshot doesn't allow deletion.</p>
-    InvalidDBSnapshotStateFault(String),
+    InvalidDBSnapshotStateFault(XmlErrorMessage),
     /// <p>Cannot restore from VPC backup to non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl RestoreDBInstanceFromDBSnapshotError {
@@ -27678,134 +29363,134 @@ impl RestoreDBInstanceFromDBSnapshotError {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
                         RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => return RusotoError::Service(
                         RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBSnapshotState" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::InvalidSubnet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
                         RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -27823,6 +29508,70 @@ impl RestoreDBInstanceFromDBSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBInstanceFromDBSnapshotError {
     #[allow(unused_variables)]
@@ -27896,39 +29645,38 @@ impl Error for RestoreDBInstanceFromDBSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceFromS3Error {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
-
-    BackupPolicyNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
+    BackupPolicyNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The specified Amazon S3 bucket name can't be found or Amazon RDS isn't authorized to access the specified Amazon S3 bucket. Verify the <b>SourceS3BucketName</b> and <b>S3IngestionRoleArn</b> values and try again.</p>
-    InvalidS3BucketFault(String),
+    InvalidS3BucketFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl RestoreDBInstanceFromS3Error {
@@ -27942,117 +29690,117 @@ impl RestoreDBInstanceFromS3Error {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::InvalidS3BucketFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(RestoreDBInstanceFromS3Error::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -28070,6 +29818,55 @@ impl RestoreDBInstanceFromS3Error {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::InvalidS3BucketFault(ref cause) => cause.request_id(),
+            RestoreDBInstanceFromS3Error::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault(ref cause) => cause.request_id(),
+            RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBInstanceFromS3Error {
     #[allow(unused_variables)]
@@ -28130,49 +29927,48 @@ impl Error for RestoreDBInstanceFromS3Error {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceToPointInTimeError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
-
-    BackupPolicyNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
+    BackupPolicyNotFoundFault(XmlErrorMessage),
     /// <p>The user already has a DB instance with the given identifier.</p>
-    DBInstanceAlreadyExistsFault(String),
+    DBInstanceAlreadyExistsFault(XmlErrorMessage),
     /// <p>No automated backup for this DB instance was found.</p>
-    DBInstanceAutomatedBackupNotFoundFault(String),
+    DBInstanceAutomatedBackupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
-    DBParameterGroupNotFoundFault(String),
+    DBParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>Domain</code> doesn't refer to an existing Active Directory domain. </p>
-    DomainNotFoundFault(String),
+    DomainNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB instances.</p>
-    InstanceQuotaExceededFault(String),
+    InstanceQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>Cannot restore from VPC backup to non-VPC DB instance.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified option group could not be found.</p>
-    OptionGroupNotFoundFault(String),
+    OptionGroupNotFoundFault(XmlErrorMessage),
     /// <p> <code>SourceDBInstanceIdentifier</code> refers to a DB instance with <code>BackupRetentionPeriod</code> equal to 0. </p>
-    PointInTimeRestoreNotEnabledFault(String),
+    PointInTimeRestoreNotEnabledFault(XmlErrorMessage),
     /// <p>Provisioned IOPS not available in the specified Availability Zone.</p>
-    ProvisionedIopsNotAvailableInAZFault(String),
+    ProvisionedIopsNotAvailableInAZFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed amount of storage available across all DB instances.</p>
-    StorageQuotaExceededFault(String),
+    StorageQuotaExceededFault(XmlErrorMessage),
     /// <p>Storage of the <code>StorageType</code> specified can't be associated with the DB instance. </p>
-    StorageTypeNotSupportedFault(String),
+    StorageTypeNotSupportedFault(XmlErrorMessage),
 }
 
 impl RestoreDBInstanceToPointInTimeError {
@@ -28188,148 +29984,148 @@ impl RestoreDBInstanceToPointInTimeError {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(
                         RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::DomainNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => return RusotoError::Service(
                         RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::InvalidSubnet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PointInTimeRestoreNotEnabled" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
                         RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "StorageQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
                         return RusotoError::Service(
                             RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -28347,6 +30143,76 @@ impl RestoreDBInstanceToPointInTimeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::DomainNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::InvalidRestoreFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreDBInstanceToPointInTimeError {
     #[allow(unused_variables)]
@@ -28424,11 +30290,11 @@ impl Error for RestoreDBInstanceToPointInTimeError {}
 #[derive(Debug, PartialEq)]
 pub enum RevokeDBSecurityGroupIngressError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSecurityGroupName</code> doesn't refer to an existing DB security group. </p>
-    DBSecurityGroupNotFoundFault(String),
+    DBSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the DB security group doesn't allow deletion.</p>
-    InvalidDBSecurityGroupStateFault(String),
+    InvalidDBSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl RevokeDBSecurityGroupIngressError {
@@ -28444,21 +30310,21 @@ impl RevokeDBSecurityGroupIngressError {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
                         return RusotoError::Service(
                             RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -28476,6 +30342,21 @@ impl RevokeDBSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RevokeDBSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -28498,17 +30379,17 @@ impl Error for RevokeDBSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum StartActivityStreamError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl StartActivityStreamError {
@@ -28521,38 +30402,44 @@ impl StartActivityStreamError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            StartActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
+                            StartActivityStreamError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            StartActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
+                            StartActivityStreamError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             StartActivityStreamError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             StartActivityStreamError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
                             StartActivityStreamError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
-                            StartActivityStreamError::ResourceNotFoundFault(parsed_error.message),
+                            StartActivityStreamError::ResourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -28569,6 +30456,18 @@ impl StartActivityStreamError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartActivityStreamError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StartActivityStreamError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            StartActivityStreamError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StartActivityStreamError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            StartActivityStreamError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+            StartActivityStreamError::ResourceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartActivityStreamError {
     #[allow(unused_variables)]
@@ -28592,11 +30491,11 @@ impl Error for StartActivityStreamError {}
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StartDBClusterError {
@@ -28609,17 +30508,21 @@ impl StartDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StartDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -28636,6 +30539,15 @@ impl StartDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StartDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartDBClusterError {
     #[allow(unused_variables)]
@@ -28652,27 +30564,27 @@ impl Error for StartDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum StartDBInstanceError {
     /// <p>The specified CIDR IP range or Amazon EC2 security group might not be authorized for the specified DB security group.</p> <p>Or, RDS might not be authorized to perform necessary actions using IAM on your behalf.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
-    DBSubnetGroupDoesNotCoverEnoughAZs(String),
+    DBSubnetGroupDoesNotCoverEnoughAZs(XmlErrorMessage),
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
-    DBSubnetGroupNotFoundFault(String),
+    DBSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The specified DB instance class isn't available in the specified Availability Zone.</p>
-    InsufficientDBInstanceCapacityFault(String),
+    InsufficientDBInstanceCapacityFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The DB subnet group doesn't cover all Availability Zones after it's created because of users' change.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
 }
 
 impl StartDBInstanceError {
@@ -28685,61 +30597,73 @@ impl StartDBInstanceError {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            StartDBInstanceError::AuthorizationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StartDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(StartDBInstanceError::DBInstanceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
                         return RusotoError::Service(
                             StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            StartDBInstanceError::DBSubnetGroupNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
                         return RusotoError::Service(
                             StartDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            StartDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StartDBInstanceError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(StartDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            StartDBInstanceError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            StartDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            StartDBInstanceError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -28756,6 +30680,27 @@ impl StartDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartDBInstanceError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(ref cause) => {
+                cause.request_id()
+            }
+            StartDBInstanceError::DBSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::InsufficientDBInstanceCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            StartDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::InvalidSubnet(ref cause) => cause.request_id(),
+            StartDBInstanceError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            StartDBInstanceError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartDBInstanceError {
     #[allow(unused_variables)]
@@ -28784,23 +30729,23 @@ impl Error for StartDBInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum StartExportTaskError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
-    DBClusterSnapshotNotFoundFault(String),
+    DBClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
-    DBSnapshotNotFoundFault(String),
+    DBSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>You can't start an export task that's already running.</p>
-    ExportTaskAlreadyExistsFault(String),
+    ExportTaskAlreadyExistsFault(XmlErrorMessage),
     /// <p>The IAM role requires additional permissions to export to an Amazon S3 bucket.</p>
-    IamRoleMissingPermissionsFault(String),
+    IamRoleMissingPermissionsFault(XmlErrorMessage),
     /// <p>The IAM role is missing for exporting to an Amazon S3 bucket.</p>
-    IamRoleNotFoundFault(String),
+    IamRoleNotFoundFault(XmlErrorMessage),
     /// <p>The export is invalid for exporting to an Amazon S3 bucket.</p>
-    InvalidExportOnlyFault(String),
+    InvalidExportOnlyFault(XmlErrorMessage),
     /// <p>The state of the export snapshot is invalid for exporting to an Amazon S3 bucket.</p>
-    InvalidExportSourceStateFault(String),
+    InvalidExportSourceStateFault(XmlErrorMessage),
     /// <p>The specified Amazon S3 bucket name can't be found or Amazon RDS isn't authorized to access the specified Amazon S3 bucket. Verify the <b>SourceS3BucketName</b> and <b>S3IngestionRoleArn</b> values and try again.</p>
-    InvalidS3BucketFault(String),
+    InvalidS3BucketFault(XmlErrorMessage),
     /// <p>An error occurred accessing an AWS KMS key.</p>
-    KMSKeyNotAccessibleFault(String),
+    KMSKeyNotAccessibleFault(XmlErrorMessage),
 }
 
 impl StartExportTaskError {
@@ -28814,54 +30759,56 @@ impl StartExportTaskError {
                     "DBClusterSnapshotNotFoundFault" => {
                         return RusotoError::Service(
                             StartExportTaskError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
                         return RusotoError::Service(StartExportTaskError::DBSnapshotNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ExportTaskAlreadyExists" => {
                         return RusotoError::Service(
                             StartExportTaskError::ExportTaskAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IamRoleMissingPermissions" => {
                         return RusotoError::Service(
                             StartExportTaskError::IamRoleMissingPermissionsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IamRoleNotFound" => {
                         return RusotoError::Service(StartExportTaskError::IamRoleNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidExportOnly" => {
                         return RusotoError::Service(StartExportTaskError::InvalidExportOnlyFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidExportSourceState" => {
                         return RusotoError::Service(
                             StartExportTaskError::InvalidExportSourceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
                         return RusotoError::Service(StartExportTaskError::InvalidS3BucketFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSKeyNotAccessibleFault" => {
                         return RusotoError::Service(
-                            StartExportTaskError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            StartExportTaskError::KMSKeyNotAccessibleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -28878,6 +30825,21 @@ impl StartExportTaskError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StartExportTaskError::DBClusterSnapshotNotFoundFault(ref cause) => cause.request_id(),
+            StartExportTaskError::DBSnapshotNotFoundFault(ref cause) => cause.request_id(),
+            StartExportTaskError::ExportTaskAlreadyExistsFault(ref cause) => cause.request_id(),
+            StartExportTaskError::IamRoleMissingPermissionsFault(ref cause) => cause.request_id(),
+            StartExportTaskError::IamRoleNotFoundFault(ref cause) => cause.request_id(),
+            StartExportTaskError::InvalidExportOnlyFault(ref cause) => cause.request_id(),
+            StartExportTaskError::InvalidExportSourceStateFault(ref cause) => cause.request_id(),
+            StartExportTaskError::InvalidS3BucketFault(ref cause) => cause.request_id(),
+            StartExportTaskError::KMSKeyNotAccessibleFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StartExportTaskError {
     #[allow(unused_variables)]
@@ -28906,15 +30868,15 @@ impl Error for StartExportTaskError {}
 #[derive(Debug, PartialEq)]
 pub enum StopActivityStreamError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The specified resource ID was not found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl StopActivityStreamError {
@@ -28927,31 +30889,37 @@ impl StopActivityStreamError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(
-                            StopActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
+                            StopActivityStreamError::DBClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(
-                            StopActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
+                            StopActivityStreamError::DBInstanceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
                             StopActivityStreamError::InvalidDBClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
                             StopActivityStreamError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(
-                            StopActivityStreamError::ResourceNotFoundFault(parsed_error.message),
+                            StopActivityStreamError::ResourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -28968,6 +30936,17 @@ impl StopActivityStreamError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopActivityStreamError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StopActivityStreamError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            StopActivityStreamError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StopActivityStreamError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            StopActivityStreamError::ResourceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopActivityStreamError {
     #[allow(unused_variables)]
@@ -28990,11 +30969,11 @@ impl Error for StopActivityStreamError {}
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
-    DBClusterNotFoundFault(String),
+    DBClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
 }
 
 impl StopDBClusterError {
@@ -29007,17 +30986,21 @@ impl StopDBClusterError {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
                         return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StopDBClusterError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -29034,6 +31017,15 @@ impl StopDBClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StopDBClusterError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopDBClusterError {
     #[allow(unused_variables)]
@@ -29050,15 +31042,15 @@ impl Error for StopDBClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum StopDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
-    DBInstanceNotFoundFault(String),
+    DBInstanceNotFoundFault(XmlErrorMessage),
     /// <p> <code>DBSnapshotIdentifier</code> is already used by an existing snapshot. </p>
-    DBSnapshotAlreadyExistsFault(String),
+    DBSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The requested operation can't be performed while the cluster is in this state.</p>
-    InvalidDBClusterStateFault(String),
+    InvalidDBClusterStateFault(XmlErrorMessage),
     /// <p>The DB instance isn't in a valid state.</p>
-    InvalidDBInstanceStateFault(String),
+    InvalidDBInstanceStateFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of DB snapshots.</p>
-    SnapshotQuotaExceededFault(String),
+    SnapshotQuotaExceededFault(XmlErrorMessage),
 }
 
 impl StopDBInstanceError {
@@ -29071,27 +31063,35 @@ impl StopDBInstanceError {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
                         return RusotoError::Service(StopDBInstanceError::DBInstanceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "DBSnapshotAlreadyExists" => {
                         return RusotoError::Service(
-                            StopDBInstanceError::DBSnapshotAlreadyExistsFault(parsed_error.message),
+                            StopDBInstanceError::DBSnapshotAlreadyExistsFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
                         return RusotoError::Service(
-                            StopDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            StopDBInstanceError::InvalidDBClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDBInstanceState" => {
                         return RusotoError::Service(
-                            StopDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
+                            StopDBInstanceError::InvalidDBInstanceStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
                         return RusotoError::Service(
-                            StopDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            StopDBInstanceError::SnapshotQuotaExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -29108,6 +31108,17 @@ impl StopDBInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StopDBInstanceError::DBInstanceNotFoundFault(ref cause) => cause.request_id(),
+            StopDBInstanceError::DBSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            StopDBInstanceError::InvalidDBClusterStateFault(ref cause) => cause.request_id(),
+            StopDBInstanceError::InvalidDBInstanceStateFault(ref cause) => cause.request_id(),
+            StopDBInstanceError::SnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for StopDBInstanceError {
     #[allow(unused_variables)]
@@ -52,7 +52,7 @@ impl RedshiftClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -11795,19 +11795,19 @@ impl VpcSecurityGroupMembershipListDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AcceptReservedNodeExchangeError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
-    DependentServiceUnavailableFault(String),
+    DependentServiceUnavailableFault(XmlErrorMessage),
     /// <p>Indicates that the Reserved Node being exchanged is not in an active state.</p>
-    InvalidReservedNodeStateFault(String),
+    InvalidReservedNodeStateFault(XmlErrorMessage),
     /// <p>User already has a reservation with the given identifier.</p>
-    ReservedNodeAlreadyExistsFault(String),
+    ReservedNodeAlreadyExistsFault(XmlErrorMessage),
     /// <p>Indicates that the reserved node has already been exchanged.</p>
-    ReservedNodeAlreadyMigratedFault(String),
+    ReservedNodeAlreadyMigratedFault(XmlErrorMessage),
     /// <p>The specified reserved compute node not found.</p>
-    ReservedNodeNotFoundFault(String),
+    ReservedNodeNotFoundFault(XmlErrorMessage),
     /// <p>Specified offering does not exist.</p>
-    ReservedNodeOfferingNotFoundFault(String),
+    ReservedNodeOfferingNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl AcceptReservedNodeExchangeError {
@@ -11823,49 +11823,49 @@ impl AcceptReservedNodeExchangeError {
                     "DependentServiceUnavailableFault" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidReservedNodeState" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeAlreadyExists" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             AcceptReservedNodeExchangeError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11883,6 +11883,33 @@ impl AcceptReservedNodeExchangeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AcceptReservedNodeExchangeError::UnsupportedOperationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AcceptReservedNodeExchangeError {
     #[allow(unused_variables)]
@@ -11917,13 +11944,13 @@ impl Error for AcceptReservedNodeExchangeError {}
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeClusterSecurityGroupIngressError {
     /// <p>The specified CIDR block or EC2 security group is already authorized for the specified cluster security group.</p>
-    AuthorizationAlreadyExistsFault(String),
+    AuthorizationAlreadyExistsFault(XmlErrorMessage),
     /// <p>The authorization quota for the cluster security group has been reached.</p>
-    AuthorizationQuotaExceededFault(String),
+    AuthorizationQuotaExceededFault(XmlErrorMessage),
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
-    InvalidClusterSecurityGroupStateFault(String),
+    InvalidClusterSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl AuthorizeClusterSecurityGroupIngressError {
@@ -11936,7 +11963,7 @@ impl AuthorizeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"AuthorizationQuotaExceeded" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"AuthorizationQuotaExceeded" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ClusterSecurityGroupNotFound" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidClusterSecurityGroupState" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -11950,6 +11977,24 @@ impl AuthorizeClusterSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AuthorizeClusterSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -11975,17 +12020,17 @@ impl Error for AuthorizeClusterSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeSnapshotAccessError {
     /// <p>The specified CIDR block or EC2 security group is already authorized for the specified cluster security group.</p>
-    AuthorizationAlreadyExistsFault(String),
+    AuthorizationAlreadyExistsFault(XmlErrorMessage),
     /// <p>The authorization quota for the cluster security group has been reached.</p>
-    AuthorizationQuotaExceededFault(String),
+    AuthorizationQuotaExceededFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
 }
 
 impl AuthorizeSnapshotAccessError {
@@ -11999,41 +12044,43 @@ impl AuthorizeSnapshotAccessError {
                     "AuthorizationAlreadyExists" => {
                         return RusotoError::Service(
                             AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
                         return RusotoError::Service(
                             AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::LimitExceededFault(parsed_error.message),
+                            AuthorizeSnapshotAccessError::LimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12050,6 +12097,28 @@ impl AuthorizeSnapshotAccessError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            AuthorizeSnapshotAccessError::LimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AuthorizeSnapshotAccessError {
     #[allow(unused_variables)]
@@ -12079,7 +12148,7 @@ impl Error for AuthorizeSnapshotAccessError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteClusterSnapshotsError {
     /// <p>The maximum number for a batch delete of snapshots has been reached. The limit is 100. </p>
-    BatchDeleteRequestSizeExceededFault(String),
+    BatchDeleteRequestSizeExceededFault(XmlErrorMessage),
 }
 
 impl BatchDeleteClusterSnapshotsError {
@@ -12095,7 +12164,7 @@ impl BatchDeleteClusterSnapshotsError {
                     "BatchDeleteRequestSizeExceeded" => {
                         return RusotoError::Service(
                             BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12113,6 +12182,15 @@ impl BatchDeleteClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for BatchDeleteClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -12129,9 +12207,9 @@ impl Error for BatchDeleteClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchModifyClusterSnapshotsError {
     /// <p>The maximum number for snapshot identifiers has been reached. The limit is 100. </p>
-    BatchModifyClusterSnapshotsLimitExceededFault(String),
+    BatchModifyClusterSnapshotsLimitExceededFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
 }
 
 impl BatchModifyClusterSnapshotsError {
@@ -12144,7 +12222,7 @@ impl BatchModifyClusterSnapshotsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message)),"InvalidRetentionPeriodFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message)),_ => {}
+                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidRetentionPeriodFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -12158,6 +12236,18 @@ impl BatchModifyClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for BatchModifyClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -12177,13 +12267,13 @@ impl Error for BatchModifyClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum CancelResizeError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>A resize operation for the specified cluster is not found.</p>
-    ResizeNotFoundFault(String),
+    ResizeNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl CancelResizeError {
@@ -12196,22 +12286,22 @@ impl CancelResizeError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(CancelResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(CancelResizeError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResizeNotFound" => {
                         return RusotoError::Service(CancelResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(CancelResizeError::UnsupportedOperationFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12228,6 +12318,16 @@ impl CancelResizeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CancelResizeError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            CancelResizeError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            CancelResizeError::ResizeNotFoundFault(ref cause) => cause.request_id(),
+            CancelResizeError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CancelResizeError {
     #[allow(unused_variables)]
@@ -12245,15 +12345,15 @@ impl Error for CancelResizeError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyClusterSnapshotError {
     /// <p>The value specified as a snapshot identifier is already used by an existing snapshot.</p>
-    ClusterSnapshotAlreadyExistsFault(String),
+    ClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of cluster snapshots.</p>
-    ClusterSnapshotQuotaExceededFault(String),
+    ClusterSnapshotQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
 }
 
 impl CopyClusterSnapshotError {
@@ -12267,35 +12367,35 @@ impl CopyClusterSnapshotError {
                     "ClusterSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
                             CopyClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12313,6 +12413,23 @@ impl CopyClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(ref cause) => cause.request_id(),
+            CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CopyClusterSnapshotError::InvalidRetentionPeriodFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyClusterSnapshotError {
     #[allow(unused_variables)]
@@ -12341,49 +12458,49 @@ impl Error for CopyClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
     /// <p>The account already has a cluster with the given identifier.</p>
-    ClusterAlreadyExistsFault(String),
+    ClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would exceed the allowed number of cluster instances for this account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterQuotaExceededFault(String),
+    ClusterQuotaExceededFault(XmlErrorMessage),
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
-    ClusterSubnetGroupNotFoundFault(String),
+    ClusterSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateNotFoundFault(String),
+    HsmClientCertificateNotFoundFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationNotFoundFault(String),
+    HsmConfigurationNotFoundFault(XmlErrorMessage),
     /// <p>The number of nodes specified exceeds the allotted capacity of the cluster.</p>
-    InsufficientClusterCapacityFault(String),
+    InsufficientClusterCapacityFault(XmlErrorMessage),
     /// <p>The cluster subnet group cannot be deleted because it is in use.</p>
-    InvalidClusterSubnetGroupStateFault(String),
+    InvalidClusterSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The provided cluster track name is not valid.</p>
-    InvalidClusterTrackFault(String),
+    InvalidClusterTrackFault(XmlErrorMessage),
     /// <p>The Elastic IP (EIP) is invalid or cannot be found.</p>
-    InvalidElasticIpFault(String),
+    InvalidElasticIpFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or not all of the subnets are in the same VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The cluster subnet group does not cover all Availability Zones.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allowed for a cluster.</p>
-    NumberOfNodesPerClusterLimitExceededFault(String),
+    NumberOfNodesPerClusterLimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allotted to the account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    NumberOfNodesQuotaExceededFault(String),
+    NumberOfNodesQuotaExceededFault(XmlErrorMessage),
     /// <p>We could not find the specified snapshot schedule. </p>
-    SnapshotScheduleNotFoundFault(String),
+    SnapshotScheduleNotFoundFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl CreateClusterError {
@@ -12396,130 +12513,138 @@ impl CreateClusterError {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
                         return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             CreateClusterError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterQuotaExceeded" => {
                         return RusotoError::Service(CreateClusterError::ClusterQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             CreateClusterError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             CreateClusterError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             CreateClusterError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
                         return RusotoError::Service(
                             CreateClusterError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
                         return RusotoError::Service(
-                            CreateClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                            CreateClusterError::HsmConfigurationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientClusterCapacity" => {
                         return RusotoError::Service(
                             CreateClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             CreateClusterError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterTrack" => {
                         return RusotoError::Service(CreateClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidElasticIpFault" => {
                         return RusotoError::Service(CreateClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
-                            CreateClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            CreateClusterError::InvalidRetentionPeriodFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateClusterError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateClusterError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
-                            CreateClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            CreateClusterError::InvalidVPCNetworkStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(CreateClusterError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
                         return RusotoError::Service(
                             CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
                         return RusotoError::Service(
-                            CreateClusterError::SnapshotScheduleNotFoundFault(parsed_error.message),
+                            CreateClusterError::SnapshotScheduleNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(CreateClusterError::TagLimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(CreateClusterError::UnauthorizedOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -12536,6 +12661,40 @@ impl CreateClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateClusterError::ClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            CreateClusterError::ClusterParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::ClusterQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateClusterError::ClusterSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::ClusterSubnetGroupNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterError::HsmClientCertificateNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::HsmConfigurationNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::InsufficientClusterCapacityFault(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidClusterSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterError::InvalidClusterTrackFault(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidElasticIpFault(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidRetentionPeriodFault(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateClusterError::InvalidVPCNetworkStateFault(ref cause) => cause.request_id(),
+            CreateClusterError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterError::NumberOfNodesQuotaExceededFault(ref cause) => cause.request_id(),
+            CreateClusterError::SnapshotScheduleNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterError::TagLimitExceededFault(ref cause) => cause.request_id(),
+            CreateClusterError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateClusterError {
     #[allow(unused_variables)]
@@ -12589,13 +12748,13 @@ impl Error for CreateClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterParameterGroupError {
     /// <p>A cluster parameter group with the same name already exists.</p>
-    ClusterParameterGroupAlreadyExistsFault(String),
+    ClusterParameterGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of cluster parameter groups. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterParameterGroupQuotaExceededFault(String),
+    ClusterParameterGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateClusterParameterGroupError {
@@ -12610,23 +12769,25 @@ impl CreateClusterParameterGroupError {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupAlreadyExists" => return RusotoError::Service(
                         CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "ClusterParameterGroupQuotaExceeded" => return RusotoError::Service(
                         CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            CreateClusterParameterGroupError::InvalidTagFault(parsed_error.message),
+                            CreateClusterParameterGroupError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateClusterParameterGroupError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12644,6 +12805,22 @@ impl CreateClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            CreateClusterParameterGroupError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateClusterParameterGroupError::TagLimitExceededFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -12667,13 +12844,13 @@ impl Error for CreateClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSecurityGroupError {
     /// <p>A cluster security group with the same name already exists.</p>
-    ClusterSecurityGroupAlreadyExistsFault(String),
+    ClusterSecurityGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of cluster security groups. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterSecurityGroupQuotaExceededFault(String),
+    ClusterSecurityGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateClusterSecurityGroupError {
@@ -12689,26 +12866,28 @@ impl CreateClusterSecurityGroupError {
                     "ClusterSecurityGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "QuotaExceeded.ClusterSecurityGroup" => {
                         return RusotoError::Service(
                             CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            CreateClusterSecurityGroupError::InvalidTagFault(parsed_error.message),
+                            CreateClusterSecurityGroupError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateClusterSecurityGroupError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12726,6 +12905,20 @@ impl CreateClusterSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSecurityGroupError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateClusterSecurityGroupError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateClusterSecurityGroupError {
     #[allow(unused_variables)]
@@ -12749,19 +12942,19 @@ impl Error for CreateClusterSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSnapshotError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The value specified as a snapshot identifier is already used by an existing snapshot.</p>
-    ClusterSnapshotAlreadyExistsFault(String),
+    ClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of cluster snapshots.</p>
-    ClusterSnapshotQuotaExceededFault(String),
+    ClusterSnapshotQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateClusterSnapshotError {
@@ -12774,45 +12967,49 @@ impl CreateClusterSnapshotError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            CreateClusterSnapshotError::ClusterNotFoundFault(parsed_error.message),
+                            CreateClusterSnapshotError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ClusterSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             CreateClusterSnapshotError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
                             CreateClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateClusterSnapshotError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
-                            CreateClusterSnapshotError::TagLimitExceededFault(parsed_error.message),
+                            CreateClusterSnapshotError::TagLimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -12829,6 +13026,25 @@ impl CreateClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateClusterSnapshotError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSnapshotError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            CreateClusterSnapshotError::InvalidRetentionPeriodFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSnapshotError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateClusterSnapshotError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateClusterSnapshotError {
     #[allow(unused_variables)]
@@ -12857,21 +13073,21 @@ impl Error for CreateClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSubnetGroupError {
     /// <p>A <i>ClusterSubnetGroupName</i> is already used by an existing cluster subnet group. </p>
-    ClusterSubnetGroupAlreadyExistsFault(String),
+    ClusterSubnetGroupAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in user exceeding the allowed number of cluster subnet groups. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterSubnetGroupQuotaExceededFault(String),
+    ClusterSubnetGroupQuotaExceededFault(XmlErrorMessage),
     /// <p>The request would result in user exceeding the allowed number of subnets in a cluster subnet groups. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterSubnetQuotaExceededFault(String),
+    ClusterSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or not all of the subnets are in the same VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl CreateClusterSubnetGroupError {
@@ -12885,52 +13101,54 @@ impl CreateClusterSubnetGroupError {
                     "ClusterSubnetGroupAlreadyExists" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSubnetGroupQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(CreateClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            CreateClusterSubnetGroupError::InvalidTagFault(parsed_error.message),
+                            CreateClusterSubnetGroupError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
                             CreateClusterSubnetGroupError::UnauthorizedOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -12948,6 +13166,28 @@ impl CreateClusterSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateClusterSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            CreateClusterSubnetGroupError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateClusterSubnetGroupError::TagLimitExceededFault(ref cause) => cause.request_id(),
+            CreateClusterSubnetGroupError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateClusterSubnetGroupError {
     #[allow(unused_variables)]
@@ -12981,27 +13221,27 @@ impl Error for CreateClusterSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
     /// <p>The request would exceed the allowed number of event subscriptions for this account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    EventSubscriptionQuotaExceededFault(String),
+    EventSubscriptionQuotaExceededFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>Amazon SNS has responded that there is a problem with the specified Amazon SNS topic.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>You do not have permission to publish to the specified Amazon SNS topic.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>An Amazon SNS topic with the specified Amazon Resource Name (ARN) does not exist.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The specified Amazon Redshift event source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>There is already an existing event notification subscription with the specified name.</p>
-    SubscriptionAlreadyExistFault(String),
+    SubscriptionAlreadyExistFault(XmlErrorMessage),
     /// <p>The value specified for the event category was not one of the allowed values, or it specified a category that does not apply to the specified source type. The allowed values are Configuration, Management, Monitoring, and Security.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
     /// <p>An Amazon Redshift event with the specified event ID does not exist.</p>
-    SubscriptionEventIdNotFoundFault(String),
+    SubscriptionEventIdNotFoundFault(XmlErrorMessage),
     /// <p>The value specified for the event severity was not one of the allowed values, or it specified a severity that does not apply to the specified source type. The allowed values are ERROR and INFO.</p>
-    SubscriptionSeverityNotFoundFault(String),
+    SubscriptionSeverityNotFoundFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateEventSubscriptionError {
@@ -13015,73 +13255,75 @@ impl CreateEventSubscriptionError {
                     "EventSubscriptionQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateEventSubscriptionError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SourceNotFound" => {
                         return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            CreateEventSubscriptionError::SourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateEventSubscriptionError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13099,6 +13341,33 @@ impl CreateEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SourceNotFoundFault(ref cause) => cause.request_id(),
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateEventSubscriptionError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateEventSubscriptionError {
     #[allow(unused_variables)]
@@ -13139,13 +13408,13 @@ impl Error for CreateEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmClientCertificateError {
     /// <p>There is already an existing Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateAlreadyExistsFault(String),
+    HsmClientCertificateAlreadyExistsFault(XmlErrorMessage),
     /// <p>The quota for HSM client certificates has been reached. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    HsmClientCertificateQuotaExceededFault(String),
+    HsmClientCertificateQuotaExceededFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateHsmClientCertificateError {
@@ -13161,26 +13430,28 @@ impl CreateHsmClientCertificateError {
                     "HsmClientCertificateAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmClientCertificateQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            CreateHsmClientCertificateError::InvalidTagFault(parsed_error.message),
+                            CreateHsmClientCertificateError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateHsmClientCertificateError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13198,6 +13469,20 @@ impl CreateHsmClientCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateHsmClientCertificateError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateHsmClientCertificateError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateHsmClientCertificateError {
     #[allow(unused_variables)]
@@ -13221,13 +13506,13 @@ impl Error for CreateHsmClientCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmConfigurationError {
     /// <p>There is already an existing Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationAlreadyExistsFault(String),
+    HsmConfigurationAlreadyExistsFault(XmlErrorMessage),
     /// <p>The quota for HSM configurations has been reached. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    HsmConfigurationQuotaExceededFault(String),
+    HsmConfigurationQuotaExceededFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateHsmConfigurationError {
@@ -13241,26 +13526,26 @@ impl CreateHsmConfigurationError {
                     "HsmConfigurationAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmConfigurationQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateHsmConfigurationError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateHsmConfigurationError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13278,6 +13563,20 @@ impl CreateHsmConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateHsmConfigurationError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateHsmConfigurationError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateHsmConfigurationError {
     #[allow(unused_variables)]
@@ -13299,17 +13598,17 @@ impl Error for CreateHsmConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateScheduledActionError {
     /// <p>The schedule you submitted isn't valid.</p>
-    InvalidScheduleFault(String),
+    InvalidScheduleFault(XmlErrorMessage),
     /// <p>The scheduled action is not valid. </p>
-    InvalidScheduledActionFault(String),
+    InvalidScheduledActionFault(XmlErrorMessage),
     /// <p>The scheduled action already exists. </p>
-    ScheduledActionAlreadyExistsFault(String),
+    ScheduledActionAlreadyExistsFault(XmlErrorMessage),
     /// <p>The quota for scheduled actions exceeded. </p>
-    ScheduledActionQuotaExceededFault(String),
+    ScheduledActionQuotaExceededFault(XmlErrorMessage),
     /// <p>The action type specified for a scheduled action is not supported. </p>
-    ScheduledActionTypeUnsupportedFault(String),
+    ScheduledActionTypeUnsupportedFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl CreateScheduledActionError {
@@ -13322,40 +13621,44 @@ impl CreateScheduledActionError {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
                         return RusotoError::Service(
-                            CreateScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                            CreateScheduledActionError::InvalidScheduleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidScheduledAction" => {
                         return RusotoError::Service(
                             CreateScheduledActionError::InvalidScheduledActionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScheduledActionAlreadyExists" => {
                         return RusotoError::Service(
                             CreateScheduledActionError::ScheduledActionAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScheduledActionQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateScheduledActionError::ScheduledActionQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
                         return RusotoError::Service(
                             CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            CreateScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            CreateScheduledActionError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -13372,6 +13675,26 @@ impl CreateScheduledActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateScheduledActionError::InvalidScheduleFault(ref cause) => cause.request_id(),
+            CreateScheduledActionError::InvalidScheduledActionFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateScheduledActionError::ScheduledActionAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateScheduledActionError::ScheduledActionQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateScheduledActionError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateScheduledActionError {
     #[allow(unused_variables)]
@@ -13399,17 +13722,17 @@ impl Error for CreateScheduledActionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotCopyGrantError {
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The snapshot copy grant can't be created because a grant with the same name already exists.</p>
-    SnapshotCopyGrantAlreadyExistsFault(String),
+    SnapshotCopyGrantAlreadyExistsFault(XmlErrorMessage),
     /// <p>The AWS account has exceeded the maximum number of snapshot copy grants in this region.</p>
-    SnapshotCopyGrantQuotaExceededFault(String),
+    SnapshotCopyGrantQuotaExceededFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateSnapshotCopyGrantError {
@@ -13423,38 +13746,40 @@ impl CreateSnapshotCopyGrantError {
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateSnapshotCopyGrantError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::LimitExceededFault(parsed_error.message),
+                            CreateSnapshotCopyGrantError::LimitExceededFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotCopyGrantAlreadyExistsFault" => {
                         return RusotoError::Service(
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotCopyGrantQuotaExceededFault" => {
                         return RusotoError::Service(
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateSnapshotCopyGrantError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13472,6 +13797,24 @@ impl CreateSnapshotCopyGrantError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotCopyGrantError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateSnapshotCopyGrantError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotCopyGrantError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateSnapshotCopyGrantError {
     #[allow(unused_variables)]
@@ -13499,15 +13842,15 @@ impl Error for CreateSnapshotCopyGrantError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotScheduleError {
     /// <p>The schedule you submitted isn't valid.</p>
-    InvalidScheduleFault(String),
+    InvalidScheduleFault(XmlErrorMessage),
     /// <p>The definition you submitted is not supported.</p>
-    ScheduleDefinitionTypeUnsupportedFault(String),
+    ScheduleDefinitionTypeUnsupportedFault(XmlErrorMessage),
     /// <p>The specified snapshot schedule already exists. </p>
-    SnapshotScheduleAlreadyExistsFault(String),
+    SnapshotScheduleAlreadyExistsFault(XmlErrorMessage),
     /// <p>You have exceeded the quota of snapshot schedules. </p>
-    SnapshotScheduleQuotaExceededFault(String),
+    SnapshotScheduleQuotaExceededFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateSnapshotScheduleError {
@@ -13520,34 +13863,36 @@ impl CreateSnapshotScheduleError {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
                         return RusotoError::Service(
-                            CreateSnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                            CreateSnapshotScheduleError::InvalidScheduleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ScheduleDefinitionTypeUnsupported" => {
                         return RusotoError::Service(
                             CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleAlreadyExists" => {
                         return RusotoError::Service(
                             CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleQuotaExceeded" => {
                         return RusotoError::Service(
                             CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             CreateSnapshotScheduleError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13565,6 +13910,23 @@ impl CreateSnapshotScheduleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateSnapshotScheduleError::InvalidScheduleFault(ref cause) => cause.request_id(),
+            CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            CreateSnapshotScheduleError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateSnapshotScheduleError {
     #[allow(unused_variables)]
@@ -13589,11 +13951,11 @@ impl Error for CreateSnapshotScheduleError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The resource could not be found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
 }
 
 impl CreateTagsError {
@@ -13606,17 +13968,17 @@ impl CreateTagsError {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
                         return RusotoError::Service(CreateTagsError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(CreateTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(CreateTagsError::TagLimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -13633,6 +13995,15 @@ impl CreateTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTagsError::InvalidTagFault(ref cause) => cause.request_id(),
+            CreateTagsError::ResourceNotFoundFault(ref cause) => cause.request_id(),
+            CreateTagsError::TagLimitExceededFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTagsError {
     #[allow(unused_variables)]
@@ -13649,19 +14020,19 @@ impl Error for CreateTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateUsageLimitError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The usage limit is not valid.</p>
-    InvalidUsageLimitFault(String),
+    InvalidUsageLimitFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
     /// <p>The usage limit already exists. </p>
-    UsageLimitAlreadyExistsFault(String),
+    UsageLimitAlreadyExistsFault(XmlErrorMessage),
 }
 
 impl CreateUsageLimitError {
@@ -13674,38 +14045,42 @@ impl CreateUsageLimitError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(CreateUsageLimitError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
-                            CreateUsageLimitError::InvalidClusterStateFault(parsed_error.message),
+                            CreateUsageLimitError::InvalidClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidUsageLimit" => {
                         return RusotoError::Service(CreateUsageLimitError::InvalidUsageLimitFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(CreateUsageLimitError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(CreateUsageLimitError::TagLimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
-                            CreateUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                            CreateUsageLimitError::UnsupportedOperationFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UsageLimitAlreadyExists" => {
                         return RusotoError::Service(
                             CreateUsageLimitError::UsageLimitAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13723,6 +14098,19 @@ impl CreateUsageLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateUsageLimitError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::InvalidUsageLimitFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::LimitExceededFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::TagLimitExceededFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+            CreateUsageLimitError::UsageLimitAlreadyExistsFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateUsageLimitError {
     #[allow(unused_variables)]
@@ -13745,15 +14133,15 @@ impl Error for CreateUsageLimitError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The value specified as a snapshot identifier is already used by an existing snapshot.</p>
-    ClusterSnapshotAlreadyExistsFault(String),
+    ClusterSnapshotAlreadyExistsFault(XmlErrorMessage),
     /// <p>The request would result in the user exceeding the allowed number of cluster snapshots.</p>
-    ClusterSnapshotQuotaExceededFault(String),
+    ClusterSnapshotQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
 }
 
 impl DeleteClusterError {
@@ -13766,31 +14154,33 @@ impl DeleteClusterError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterSnapshotAlreadyExists" => {
                         return RusotoError::Service(
                             DeleteClusterError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
                         return RusotoError::Service(
                             DeleteClusterError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
-                            DeleteClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            DeleteClusterError::InvalidRetentionPeriodFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -13807,6 +14197,17 @@ impl DeleteClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DeleteClusterError::ClusterSnapshotAlreadyExistsFault(ref cause) => cause.request_id(),
+            DeleteClusterError::ClusterSnapshotQuotaExceededFault(ref cause) => cause.request_id(),
+            DeleteClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            DeleteClusterError::InvalidRetentionPeriodFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteClusterError {
     #[allow(unused_variables)]
@@ -13829,9 +14230,9 @@ impl Error for DeleteClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
-    InvalidClusterParameterGroupStateFault(String),
+    InvalidClusterParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteClusterParameterGroupError {
@@ -13847,13 +14248,13 @@ impl DeleteClusterParameterGroupError {
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterParameterGroupState" => return RusotoError::Service(
                         DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -13870,6 +14271,18 @@ impl DeleteClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -13889,9 +14302,9 @@ impl Error for DeleteClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSecurityGroupError {
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
-    InvalidClusterSecurityGroupStateFault(String),
+    InvalidClusterSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl DeleteClusterSecurityGroupError {
@@ -13907,14 +14320,14 @@ impl DeleteClusterSecurityGroupError {
                     "ClusterSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
                         return RusotoError::Service(
                             DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13932,6 +14345,18 @@ impl DeleteClusterSecurityGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteClusterSecurityGroupError {
     #[allow(unused_variables)]
@@ -13951,9 +14376,9 @@ impl Error for DeleteClusterSecurityGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSnapshotError {
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DeleteClusterSnapshotError {
@@ -13967,14 +14392,14 @@ impl DeleteClusterSnapshotError {
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -13992,6 +14417,18 @@ impl DeleteClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteClusterSnapshotError {
     #[allow(unused_variables)]
@@ -14011,11 +14448,11 @@ impl Error for DeleteClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSubnetGroupError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
-    ClusterSubnetGroupNotFoundFault(String),
+    ClusterSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster subnet group cannot be deleted because it is in use.</p>
-    InvalidClusterSubnetGroupStateFault(String),
+    InvalidClusterSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The state of the subnet is invalid.</p>
-    InvalidClusterSubnetStateFault(String),
+    InvalidClusterSubnetStateFault(XmlErrorMessage),
 }
 
 impl DeleteClusterSubnetGroupError {
@@ -14029,21 +14466,21 @@ impl DeleteClusterSubnetGroupError {
                     "ClusterSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSubnetStateFault" => {
                         return RusotoError::Service(
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14061,6 +14498,21 @@ impl DeleteClusterSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteClusterSubnetGroupError {
     #[allow(unused_variables)]
@@ -14083,9 +14535,9 @@ impl Error for DeleteClusterSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
     /// <p>The subscription request is invalid because it is a duplicate request. This subscription request is already in progress.</p>
-    InvalidSubscriptionStateFault(String),
+    InvalidSubscriptionStateFault(XmlErrorMessage),
     /// <p>An Amazon Redshift event notification subscription with the specified name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteEventSubscriptionError {
@@ -14099,14 +14551,14 @@ impl DeleteEventSubscriptionError {
                     "InvalidSubscriptionStateFault" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::InvalidSubscriptionStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14124,6 +14576,18 @@ impl DeleteEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteEventSubscriptionError::InvalidSubscriptionStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteEventSubscriptionError {
     #[allow(unused_variables)]
@@ -14143,9 +14607,9 @@ impl Error for DeleteEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmClientCertificateError {
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateNotFoundFault(String),
+    HsmClientCertificateNotFoundFault(XmlErrorMessage),
     /// <p>The specified HSM client certificate is not in the <code>available</code> state, or it is still in use by one or more Amazon Redshift clusters.</p>
-    InvalidHsmClientCertificateStateFault(String),
+    InvalidHsmClientCertificateStateFault(XmlErrorMessage),
 }
 
 impl DeleteHsmClientCertificateError {
@@ -14161,14 +14625,14 @@ impl DeleteHsmClientCertificateError {
                     "HsmClientCertificateNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidHsmClientCertificateStateFault" => {
                         return RusotoError::Service(
                             DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14186,6 +14650,18 @@ impl DeleteHsmClientCertificateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteHsmClientCertificateError {
     #[allow(unused_variables)]
@@ -14205,9 +14681,9 @@ impl Error for DeleteHsmClientCertificateError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmConfigurationError {
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationNotFoundFault(String),
+    HsmConfigurationNotFoundFault(XmlErrorMessage),
     /// <p>The specified HSM configuration is not in the <code>available</code> state, or it is still in use by one or more Amazon Redshift clusters.</p>
-    InvalidHsmConfigurationStateFault(String),
+    InvalidHsmConfigurationStateFault(XmlErrorMessage),
 }
 
 impl DeleteHsmConfigurationError {
@@ -14221,14 +14697,14 @@ impl DeleteHsmConfigurationError {
                     "HsmConfigurationNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidHsmConfigurationStateFault" => {
                         return RusotoError::Service(
                             DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14246,6 +14722,18 @@ impl DeleteHsmConfigurationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteHsmConfigurationError {
     #[allow(unused_variables)]
@@ -14265,9 +14753,9 @@ impl Error for DeleteHsmConfigurationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledActionError {
     /// <p>The scheduled action cannot be found. </p>
-    ScheduledActionNotFoundFault(String),
+    ScheduledActionNotFoundFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl DeleteScheduledActionError {
@@ -14281,13 +14769,15 @@ impl DeleteScheduledActionError {
                     "ScheduledActionNotFound" => {
                         return RusotoError::Service(
                             DeleteScheduledActionError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            DeleteScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            DeleteScheduledActionError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -14304,6 +14794,16 @@ impl DeleteScheduledActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteScheduledActionError::ScheduledActionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteScheduledActionError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteScheduledActionError {
     #[allow(unused_variables)]
@@ -14321,9 +14821,9 @@ impl Error for DeleteScheduledActionError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotCopyGrantError {
     /// <p>The snapshot copy grant can't be deleted because it is used by one or more clusters.</p>
-    InvalidSnapshotCopyGrantStateFault(String),
+    InvalidSnapshotCopyGrantStateFault(XmlErrorMessage),
     /// <p>The specified snapshot copy grant can't be found. Make sure that the name is typed correctly and that the grant exists in the destination region.</p>
-    SnapshotCopyGrantNotFoundFault(String),
+    SnapshotCopyGrantNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteSnapshotCopyGrantError {
@@ -14337,14 +14837,14 @@ impl DeleteSnapshotCopyGrantError {
                     "InvalidSnapshotCopyGrantStateFault" => {
                         return RusotoError::Service(
                             DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
                         return RusotoError::Service(
                             DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14362,6 +14862,18 @@ impl DeleteSnapshotCopyGrantError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteSnapshotCopyGrantError {
     #[allow(unused_variables)]
@@ -14381,9 +14893,9 @@ impl Error for DeleteSnapshotCopyGrantError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotScheduleError {
     /// <p>The cluster snapshot schedule state is not valid.</p>
-    InvalidClusterSnapshotScheduleStateFault(String),
+    InvalidClusterSnapshotScheduleStateFault(XmlErrorMessage),
     /// <p>We could not find the specified snapshot schedule. </p>
-    SnapshotScheduleNotFoundFault(String),
+    SnapshotScheduleNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteSnapshotScheduleError {
@@ -14397,14 +14909,14 @@ impl DeleteSnapshotScheduleError {
                     "InvalidClusterSnapshotScheduleState" => {
                         return RusotoError::Service(
                             DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
                         return RusotoError::Service(
                             DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14422,6 +14934,18 @@ impl DeleteSnapshotScheduleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(ref cause) => {
+                cause.request_id()
+            }
+            DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteSnapshotScheduleError {
     #[allow(unused_variables)]
@@ -14441,9 +14965,9 @@ impl Error for DeleteSnapshotScheduleError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The resource could not be found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteTagsError {
@@ -14456,12 +14980,12 @@ impl DeleteTagsError {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
                         return RusotoError::Service(DeleteTagsError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(DeleteTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14478,6 +15002,14 @@ impl DeleteTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTagsError::InvalidTagFault(ref cause) => cause.request_id(),
+            DeleteTagsError::ResourceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteTagsError {
     #[allow(unused_variables)]
@@ -14493,9 +15025,9 @@ impl Error for DeleteTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteUsageLimitError {
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
     /// <p>The usage limit identifier can't be found.</p>
-    UsageLimitNotFoundFault(String),
+    UsageLimitNotFoundFault(XmlErrorMessage),
 }
 
 impl DeleteUsageLimitError {
@@ -14508,12 +15040,16 @@ impl DeleteUsageLimitError {
                 match &parsed_error.code[..] {
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
-                            DeleteUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                            DeleteUsageLimitError::UnsupportedOperationFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UsageLimitNotFound" => {
                         return RusotoError::Service(
-                            DeleteUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
+                            DeleteUsageLimitError::UsageLimitNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -14530,6 +15066,14 @@ impl DeleteUsageLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteUsageLimitError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+            DeleteUsageLimitError::UsageLimitNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteUsageLimitError {
     #[allow(unused_variables)]
@@ -14567,6 +15111,11 @@ impl DescribeAccountAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
@@ -14579,9 +15128,9 @@ impl Error for DescribeAccountAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterDbRevisionsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl DescribeClusterDbRevisionsError {
@@ -14597,14 +15146,14 @@ impl DescribeClusterDbRevisionsError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterDbRevisionsError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             DescribeClusterDbRevisionsError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14622,6 +15171,16 @@ impl DescribeClusterDbRevisionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterDbRevisionsError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeClusterDbRevisionsError::InvalidClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeClusterDbRevisionsError {
     #[allow(unused_variables)]
@@ -14641,9 +15200,9 @@ impl Error for DescribeClusterDbRevisionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterParameterGroupsError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeClusterParameterGroupsError {
@@ -14659,14 +15218,14 @@ impl DescribeClusterParameterGroupsError {
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
                             DescribeClusterParameterGroupsError::InvalidTagFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14684,6 +15243,16 @@ impl DescribeClusterParameterGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeClusterParameterGroupsError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClusterParameterGroupsError {
     #[allow(unused_variables)]
@@ -14703,7 +15272,7 @@ impl Error for DescribeClusterParameterGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterParametersError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeClusterParametersError {
@@ -14717,7 +15286,7 @@ impl DescribeClusterParametersError {
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14735,6 +15304,15 @@ impl DescribeClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeClusterParametersError {
     #[allow(unused_variables)]
@@ -14751,9 +15329,9 @@ impl Error for DescribeClusterParametersError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSecurityGroupsError {
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeClusterSecurityGroupsError {
@@ -14769,14 +15347,14 @@ impl DescribeClusterSecurityGroupsError {
                     "ClusterSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
                             DescribeClusterSecurityGroupsError::InvalidTagFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -14794,6 +15372,16 @@ impl DescribeClusterSecurityGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeClusterSecurityGroupsError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClusterSecurityGroupsError {
     #[allow(unused_variables)]
@@ -14813,11 +15401,11 @@ impl Error for DescribeClusterSecurityGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSnapshotsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeClusterSnapshotsError {
@@ -14831,20 +15419,22 @@ impl DescribeClusterSnapshotsError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterSnapshotsError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            DescribeClusterSnapshotsError::InvalidTagFault(parsed_error.message),
+                            DescribeClusterSnapshotsError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -14861,6 +15451,17 @@ impl DescribeClusterSnapshotsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterSnapshotsError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeClusterSnapshotsError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClusterSnapshotsError {
     #[allow(unused_variables)]
@@ -14881,9 +15482,9 @@ impl Error for DescribeClusterSnapshotsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSubnetGroupsError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
-    ClusterSubnetGroupNotFoundFault(String),
+    ClusterSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeClusterSubnetGroupsError {
@@ -14899,13 +15500,15 @@ impl DescribeClusterSubnetGroupsError {
                     "ClusterSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            DescribeClusterSubnetGroupsError::InvalidTagFault(parsed_error.message),
+                            DescribeClusterSubnetGroupsError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -14922,6 +15525,16 @@ impl DescribeClusterSubnetGroupsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeClusterSubnetGroupsError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClusterSubnetGroupsError {
     #[allow(unused_variables)]
@@ -14939,9 +15552,9 @@ impl Error for DescribeClusterSubnetGroupsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterTracksError {
     /// <p>The provided cluster track name is not valid.</p>
-    InvalidClusterTrackFault(String),
+    InvalidClusterTrackFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl DescribeClusterTracksError {
@@ -14955,13 +15568,15 @@ impl DescribeClusterTracksError {
                     "InvalidClusterTrack" => {
                         return RusotoError::Service(
                             DescribeClusterTracksError::InvalidClusterTrackFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            DescribeClusterTracksError::UnauthorizedOperation(parsed_error.message),
+                            DescribeClusterTracksError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -14978,6 +15593,14 @@ impl DescribeClusterTracksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClusterTracksError::InvalidClusterTrackFault(ref cause) => cause.request_id(),
+            DescribeClusterTracksError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClusterTracksError {
     #[allow(unused_variables)]
@@ -15017,6 +15640,11 @@ impl DescribeClusterVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeClusterVersionsError {
     #[allow(unused_variables)]
@@ -15029,9 +15657,9 @@ impl Error for DescribeClusterVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeClustersError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeClustersError {
@@ -15044,12 +15672,12 @@ impl DescribeClustersError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(DescribeClustersError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15066,6 +15694,14 @@ impl DescribeClustersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeClustersError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeClustersError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeClustersError {
     #[allow(unused_variables)]
@@ -15105,6 +15741,11 @@ impl DescribeDefaultClusterParametersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeDefaultClusterParametersError {
     #[allow(unused_variables)]
@@ -15139,6 +15780,11 @@ impl DescribeEventCategoriesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
@@ -15151,9 +15797,9 @@ impl Error for DescribeEventCategoriesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>An Amazon Redshift event notification subscription with the specified name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -15168,13 +15814,15 @@ impl DescribeEventSubscriptionsError {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            DescribeEventSubscriptionsError::InvalidTagFault(parsed_error.message),
+                            DescribeEventSubscriptionsError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15192,6 +15840,16 @@ impl DescribeEventSubscriptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeEventSubscriptionsError::InvalidTagFault(ref cause) => cause.request_id(),
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeEventSubscriptionsError {
     #[allow(unused_variables)]
@@ -15231,6 +15889,11 @@ impl DescribeEventsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
@@ -15243,9 +15906,9 @@ impl Error for DescribeEventsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeHsmClientCertificatesError {
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateNotFoundFault(String),
+    HsmClientCertificateNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeHsmClientCertificatesError {
@@ -15261,14 +15924,14 @@ impl DescribeHsmClientCertificatesError {
                     "HsmClientCertificateNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
                             DescribeHsmClientCertificatesError::InvalidTagFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15286,6 +15949,16 @@ impl DescribeHsmClientCertificatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeHsmClientCertificatesError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeHsmClientCertificatesError {
     #[allow(unused_variables)]
@@ -15305,9 +15978,9 @@ impl Error for DescribeHsmClientCertificatesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeHsmConfigurationsError {
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationNotFoundFault(String),
+    HsmConfigurationNotFoundFault(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
 }
 
 impl DescribeHsmConfigurationsError {
@@ -15321,13 +15994,15 @@ impl DescribeHsmConfigurationsError {
                     "HsmConfigurationNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            DescribeHsmConfigurationsError::InvalidTagFault(parsed_error.message),
+                            DescribeHsmConfigurationsError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -15344,6 +16019,16 @@ impl DescribeHsmConfigurationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeHsmConfigurationsError::InvalidTagFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeHsmConfigurationsError {
     #[allow(unused_variables)]
@@ -15361,7 +16046,7 @@ impl Error for DescribeHsmConfigurationsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoggingStatusError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeLoggingStatusError {
@@ -15374,7 +16059,9 @@ impl DescribeLoggingStatusError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            DescribeLoggingStatusError::ClusterNotFoundFault(parsed_error.message),
+                            DescribeLoggingStatusError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -15391,6 +16078,13 @@ impl DescribeLoggingStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeLoggingStatusError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeLoggingStatusError {
     #[allow(unused_variables)]
@@ -15405,13 +16099,13 @@ impl Error for DescribeLoggingStatusError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeNodeConfigurationOptionsError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
-    AccessToSnapshotDeniedFault(String),
+    AccessToSnapshotDeniedFault(XmlErrorMessage),
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
 }
 
 impl DescribeNodeConfigurationOptionsError {
@@ -15427,28 +16121,28 @@ impl DescribeNodeConfigurationOptionsError {
                     "AccessToSnapshotDenied" => {
                         return RusotoError::Service(
                             DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15466,6 +16160,24 @@ impl DescribeNodeConfigurationOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeNodeConfigurationOptionsError {
     #[allow(unused_variables)]
@@ -15515,6 +16227,11 @@ impl DescribeOrderableClusterOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeOrderableClusterOptionsError {
     #[allow(unused_variables)]
@@ -15527,11 +16244,11 @@ impl Error for DescribeOrderableClusterOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedNodeOfferingsError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
-    DependentServiceUnavailableFault(String),
+    DependentServiceUnavailableFault(XmlErrorMessage),
     /// <p>Specified offering does not exist.</p>
-    ReservedNodeOfferingNotFoundFault(String),
+    ReservedNodeOfferingNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl DescribeReservedNodeOfferingsError {
@@ -15547,21 +16264,21 @@ impl DescribeReservedNodeOfferingsError {
                     "DependentServiceUnavailableFault" => {
                         return RusotoError::Service(
                             DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
                         return RusotoError::Service(
                             DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             DescribeReservedNodeOfferingsError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15579,6 +16296,21 @@ impl DescribeReservedNodeOfferingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedNodeOfferingsError::UnsupportedOperationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeReservedNodeOfferingsError {
     #[allow(unused_variables)]
@@ -15601,9 +16333,9 @@ impl Error for DescribeReservedNodeOfferingsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedNodesError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
-    DependentServiceUnavailableFault(String),
+    DependentServiceUnavailableFault(XmlErrorMessage),
     /// <p>The specified reserved compute node not found.</p>
-    ReservedNodeNotFoundFault(String),
+    ReservedNodeNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeReservedNodesError {
@@ -15617,14 +16349,14 @@ impl DescribeReservedNodesError {
                     "DependentServiceUnavailableFault" => {
                         return RusotoError::Service(
                             DescribeReservedNodesError::DependentServiceUnavailableFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
                         return RusotoError::Service(
                             DescribeReservedNodesError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15642,6 +16374,16 @@ impl DescribeReservedNodesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReservedNodesError::DependentServiceUnavailableFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeReservedNodesError::ReservedNodeNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeReservedNodesError {
     #[allow(unused_variables)]
@@ -15661,9 +16403,9 @@ impl Error for DescribeReservedNodesError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeResizeError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>A resize operation for the specified cluster is not found.</p>
-    ResizeNotFoundFault(String),
+    ResizeNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeResizeError {
@@ -15676,12 +16418,12 @@ impl DescribeResizeError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(DescribeResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResizeNotFound" => {
                         return RusotoError::Service(DescribeResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15698,6 +16440,14 @@ impl DescribeResizeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeResizeError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeResizeError::ResizeNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeResizeError {
     #[allow(unused_variables)]
@@ -15713,9 +16463,9 @@ impl Error for DescribeResizeError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledActionsError {
     /// <p>The scheduled action cannot be found. </p>
-    ScheduledActionNotFoundFault(String),
+    ScheduledActionNotFoundFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl DescribeScheduledActionsError {
@@ -15729,14 +16479,14 @@ impl DescribeScheduledActionsError {
                     "ScheduledActionNotFound" => {
                         return RusotoError::Service(
                             DescribeScheduledActionsError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
                             DescribeScheduledActionsError::UnauthorizedOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15754,6 +16504,16 @@ impl DescribeScheduledActionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeScheduledActionsError::ScheduledActionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            DescribeScheduledActionsError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeScheduledActionsError {
     #[allow(unused_variables)]
@@ -15773,9 +16533,9 @@ impl Error for DescribeScheduledActionsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotCopyGrantsError {
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The specified snapshot copy grant can't be found. Make sure that the name is typed correctly and that the grant exists in the destination region.</p>
-    SnapshotCopyGrantNotFoundFault(String),
+    SnapshotCopyGrantNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeSnapshotCopyGrantsError {
@@ -15790,13 +16550,15 @@ impl DescribeSnapshotCopyGrantsError {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            DescribeSnapshotCopyGrantsError::InvalidTagFault(parsed_error.message),
+                            DescribeSnapshotCopyGrantsError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15814,6 +16576,16 @@ impl DescribeSnapshotCopyGrantsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeSnapshotCopyGrantsError::InvalidTagFault(ref cause) => cause.request_id(),
+            DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeSnapshotCopyGrantsError {
     #[allow(unused_variables)]
@@ -15853,6 +16625,11 @@ impl DescribeSnapshotSchedulesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeSnapshotSchedulesError {
     #[allow(unused_variables)]
@@ -15887,6 +16664,11 @@ impl DescribeStorageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeStorageError {
     #[allow(unused_variables)]
@@ -15899,9 +16681,9 @@ impl Error for DescribeStorageError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTableRestoreStatusError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified <code>TableRestoreRequestId</code> value was not found.</p>
-    TableRestoreNotFoundFault(String),
+    TableRestoreNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeTableRestoreStatusError {
@@ -15917,14 +16699,14 @@ impl DescribeTableRestoreStatusError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             DescribeTableRestoreStatusError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TableRestoreNotFoundFault" => {
                         return RusotoError::Service(
                             DescribeTableRestoreStatusError::TableRestoreNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -15942,6 +16724,16 @@ impl DescribeTableRestoreStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTableRestoreStatusError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeTableRestoreStatusError::TableRestoreNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeTableRestoreStatusError {
     #[allow(unused_variables)]
@@ -15961,9 +16753,9 @@ impl Error for DescribeTableRestoreStatusError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The resource could not be found.</p>
-    ResourceNotFoundFault(String),
+    ResourceNotFoundFault(XmlErrorMessage),
 }
 
 impl DescribeTagsError {
@@ -15976,12 +16768,12 @@ impl DescribeTagsError {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
                         return RusotoError::Service(DescribeTagsError::InvalidTagFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFoundFault" => {
                         return RusotoError::Service(DescribeTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15998,6 +16790,14 @@ impl DescribeTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeTagsError::InvalidTagFault(ref cause) => cause.request_id(),
+            DescribeTagsError::ResourceNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeTagsError {
     #[allow(unused_variables)]
@@ -16013,9 +16813,9 @@ impl Error for DescribeTagsError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsageLimitsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl DescribeUsageLimitsError {
@@ -16028,13 +16828,15 @@ impl DescribeUsageLimitsError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            DescribeUsageLimitsError::ClusterNotFoundFault(parsed_error.message),
+                            DescribeUsageLimitsError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             DescribeUsageLimitsError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16052,6 +16854,14 @@ impl DescribeUsageLimitsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeUsageLimitsError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DescribeUsageLimitsError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeUsageLimitsError {
     #[allow(unused_variables)]
@@ -16069,7 +16879,7 @@ impl Error for DescribeUsageLimitsError {}
 #[derive(Debug, PartialEq)]
 pub enum DisableLoggingError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
 }
 
 impl DisableLoggingError {
@@ -16082,7 +16892,7 @@ impl DisableLoggingError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(DisableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16099,6 +16909,13 @@ impl DisableLoggingError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisableLoggingError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DisableLoggingError {
     #[allow(unused_variables)]
@@ -16113,13 +16930,13 @@ impl Error for DisableLoggingError {}
 #[derive(Debug, PartialEq)]
 pub enum DisableSnapshotCopyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The cluster already has cross-region snapshot copy disabled.</p>
-    SnapshotCopyAlreadyDisabledFault(String),
+    SnapshotCopyAlreadyDisabledFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl DisableSnapshotCopyError {
@@ -16132,26 +16949,30 @@ impl DisableSnapshotCopyError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            DisableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
+                            DisableSnapshotCopyError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             DisableSnapshotCopyError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotCopyAlreadyDisabledFault" => {
                         return RusotoError::Service(
                             DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            DisableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                            DisableSnapshotCopyError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -16168,6 +16989,18 @@ impl DisableSnapshotCopyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisableSnapshotCopyError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            DisableSnapshotCopyError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(ref cause) => {
+                cause.request_id()
+            }
+            DisableSnapshotCopyError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DisableSnapshotCopyError {
     #[allow(unused_variables)]
@@ -16187,17 +17020,17 @@ impl Error for DisableSnapshotCopyError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableLoggingError {
     /// <p>Could not find the specified S3 bucket.</p>
-    BucketNotFoundFault(String),
+    BucketNotFoundFault(XmlErrorMessage),
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster does not have read bucket or put object permissions on the S3 bucket specified when enabling logging.</p>
-    InsufficientS3BucketPolicyFault(String),
+    InsufficientS3BucketPolicyFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The S3 bucket name is invalid. For more information about naming rules, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/BucketRestrictions.html">Bucket Restrictions and Limitations</a> in the Amazon Simple Storage Service (S3) Developer Guide.</p>
-    InvalidS3BucketNameFault(String),
+    InvalidS3BucketNameFault(XmlErrorMessage),
     /// <p>The string specified for the logging S3 key prefix does not comply with the documented constraints.</p>
-    InvalidS3KeyPrefixFault(String),
+    InvalidS3KeyPrefixFault(XmlErrorMessage),
 }
 
 impl EnableLoggingError {
@@ -16210,34 +17043,34 @@ impl EnableLoggingError {
                 match &parsed_error.code[..] {
                     "BucketNotFoundFault" => {
                         return RusotoError::Service(EnableLoggingError::BucketNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterNotFound" => {
                         return RusotoError::Service(EnableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientS3BucketPolicyFault" => {
                         return RusotoError::Service(
                             EnableLoggingError::InsufficientS3BucketPolicyFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(EnableLoggingError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidS3BucketNameFault" => {
                         return RusotoError::Service(EnableLoggingError::InvalidS3BucketNameFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidS3KeyPrefixFault" => {
                         return RusotoError::Service(EnableLoggingError::InvalidS3KeyPrefixFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16254,6 +17087,18 @@ impl EnableLoggingError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableLoggingError::BucketNotFoundFault(ref cause) => cause.request_id(),
+            EnableLoggingError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            EnableLoggingError::InsufficientS3BucketPolicyFault(ref cause) => cause.request_id(),
+            EnableLoggingError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            EnableLoggingError::InvalidS3BucketNameFault(ref cause) => cause.request_id(),
+            EnableLoggingError::InvalidS3KeyPrefixFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for EnableLoggingError {
     #[allow(unused_variables)]
@@ -16275,27 +17120,27 @@ impl Error for EnableLoggingError {}
 #[derive(Debug, PartialEq)]
 pub enum EnableSnapshotCopyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>Cross-region snapshot copy was temporarily disabled. Try your request again.</p>
-    CopyToRegionDisabledFault(String),
+    CopyToRegionDisabledFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The specified options are incompatible.</p>
-    IncompatibleOrderableOptions(String),
+    IncompatibleOrderableOptions(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The cluster already has cross-region snapshot copy enabled.</p>
-    SnapshotCopyAlreadyEnabledFault(String),
+    SnapshotCopyAlreadyEnabledFault(XmlErrorMessage),
     /// <p>The specified snapshot copy grant can't be found. Make sure that the name is typed correctly and that the grant exists in the destination region.</p>
-    SnapshotCopyGrantNotFoundFault(String),
+    SnapshotCopyGrantNotFoundFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
     /// <p>The specified region is incorrect or does not exist.</p>
-    UnknownSnapshotCopyRegionFault(String),
+    UnknownSnapshotCopyRegionFault(XmlErrorMessage),
 }
 
 impl EnableSnapshotCopyError {
@@ -16308,70 +17153,74 @@ impl EnableSnapshotCopyError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(EnableSnapshotCopyError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "CopyToRegionDisabledFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::CopyToRegionDisabledFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IncompatibleOrderableOptions" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::IncompatibleOrderableOptions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
-                            EnableSnapshotCopyError::InvalidClusterStateFault(parsed_error.message),
+                            EnableSnapshotCopyError::InvalidClusterStateFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(EnableSnapshotCopyError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SnapshotCopyAlreadyEnabledFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            EnableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                            EnableSnapshotCopyError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UnknownSnapshotCopyRegionFault" => {
                         return RusotoError::Service(
                             EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16389,6 +17238,31 @@ impl EnableSnapshotCopyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            EnableSnapshotCopyError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::CopyToRegionDisabledFault(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            EnableSnapshotCopyError::IncompatibleOrderableOptions(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::InvalidRetentionPeriodFault(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::LimitExceededFault(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(ref cause) => {
+                cause.request_id()
+            }
+            EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            EnableSnapshotCopyError::UnauthorizedOperation(ref cause) => cause.request_id(),
+            EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for EnableSnapshotCopyError {
     #[allow(unused_variables)]
@@ -16425,9 +17299,9 @@ impl Error for EnableSnapshotCopyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetClusterCredentialsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl GetClusterCredentialsError {
@@ -16440,13 +17314,15 @@ impl GetClusterCredentialsError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            GetClusterCredentialsError::ClusterNotFoundFault(parsed_error.message),
+                            GetClusterCredentialsError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             GetClusterCredentialsError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16464,6 +17340,14 @@ impl GetClusterCredentialsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetClusterCredentialsError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            GetClusterCredentialsError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetClusterCredentialsError {
     #[allow(unused_variables)]
@@ -16481,17 +17365,17 @@ impl Error for GetClusterCredentialsError {}
 #[derive(Debug, PartialEq)]
 pub enum GetReservedNodeExchangeOfferingsError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
-    DependentServiceUnavailableFault(String),
+    DependentServiceUnavailableFault(XmlErrorMessage),
     /// <p>Indicates that the Reserved Node being exchanged is not in an active state.</p>
-    InvalidReservedNodeStateFault(String),
+    InvalidReservedNodeStateFault(XmlErrorMessage),
     /// <p>Indicates that the reserved node has already been exchanged.</p>
-    ReservedNodeAlreadyMigratedFault(String),
+    ReservedNodeAlreadyMigratedFault(XmlErrorMessage),
     /// <p>The specified reserved compute node not found.</p>
-    ReservedNodeNotFoundFault(String),
+    ReservedNodeNotFoundFault(XmlErrorMessage),
     /// <p>Specified offering does not exist.</p>
-    ReservedNodeOfferingNotFoundFault(String),
+    ReservedNodeOfferingNotFoundFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl GetReservedNodeExchangeOfferingsError {
@@ -16507,40 +17391,40 @@ impl GetReservedNodeExchangeOfferingsError {
                     "DependentServiceUnavailableFault" => {
                         return RusotoError::Service(
                             GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidReservedNodeState" => {
                         return RusotoError::Service(
                             GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
                         return RusotoError::Service(
                             GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
                         return RusotoError::Service(
                             GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => return RusotoError::Service(
                         GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16558,6 +17442,30 @@ impl GetReservedNodeExchangeOfferingsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(ref cause) => {
+                cause.request_id()
+            }
+            GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(ref cause) => {
+                cause.request_id()
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(ref cause) => {
+                cause.request_id()
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetReservedNodeExchangeOfferingsError {
     #[allow(unused_variables)]
@@ -16589,43 +17497,43 @@ impl Error for GetReservedNodeExchangeOfferingsError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterError {
     /// <p>The account already has a cluster with the given identifier.</p>
-    ClusterAlreadyExistsFault(String),
+    ClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateNotFoundFault(String),
+    HsmClientCertificateNotFoundFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationNotFoundFault(String),
+    HsmConfigurationNotFoundFault(XmlErrorMessage),
     /// <p>The number of nodes specified exceeds the allotted capacity of the cluster.</p>
-    InsufficientClusterCapacityFault(String),
+    InsufficientClusterCapacityFault(XmlErrorMessage),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
-    InvalidClusterSecurityGroupStateFault(String),
+    InvalidClusterSecurityGroupStateFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The provided cluster track name is not valid.</p>
-    InvalidClusterTrackFault(String),
+    InvalidClusterTrackFault(XmlErrorMessage),
     /// <p>The Elastic IP (EIP) is invalid or cannot be found.</p>
-    InvalidElasticIpFault(String),
+    InvalidElasticIpFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allowed for a cluster.</p>
-    NumberOfNodesPerClusterLimitExceededFault(String),
+    NumberOfNodesPerClusterLimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allotted to the account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    NumberOfNodesQuotaExceededFault(String),
+    NumberOfNodesQuotaExceededFault(XmlErrorMessage),
     /// <p>The number of tables in the cluster exceeds the limit for the requested new cluster node type. </p>
-    TableLimitExceededFault(String),
+    TableLimitExceededFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
     /// <p>A request option was specified that is not supported.</p>
-    UnsupportedOptionFault(String),
+    UnsupportedOptionFault(XmlErrorMessage),
 }
 
 impl ModifyClusterError {
@@ -16638,113 +17546,117 @@ impl ModifyClusterError {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
                         return RusotoError::Service(ModifyClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterNotFound" => {
                         return RusotoError::Service(ModifyClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             ModifyClusterError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyClusterError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
                         return RusotoError::Service(
-                            ModifyClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                            ModifyClusterError::HsmConfigurationNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InsufficientClusterCapacity" => {
                         return RusotoError::Service(
                             ModifyClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
                         return RusotoError::Service(
                             ModifyClusterError::InvalidClusterSecurityGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(ModifyClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterTrack" => {
                         return RusotoError::Service(ModifyClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidElasticIpFault" => {
                         return RusotoError::Service(ModifyClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
-                            ModifyClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            ModifyClusterError::InvalidRetentionPeriodFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(ModifyClusterError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
                         return RusotoError::Service(
                             ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
                         return RusotoError::Service(
                             ModifyClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TableLimitExceeded" => {
                         return RusotoError::Service(ModifyClusterError::TableLimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(ModifyClusterError::UnauthorizedOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOptionFault" => {
                         return RusotoError::Service(ModifyClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16761,6 +17673,37 @@ impl ModifyClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterError::ClusterAlreadyExistsFault(ref cause) => cause.request_id(),
+            ModifyClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterError::ClusterParameterGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterError::ClusterSecurityGroupNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterError::HsmClientCertificateNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterError::HsmConfigurationNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterError::InsufficientClusterCapacityFault(ref cause) => cause.request_id(),
+            ModifyClusterError::InvalidClusterSecurityGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            ModifyClusterError::InvalidClusterTrackFault(ref cause) => cause.request_id(),
+            ModifyClusterError::InvalidElasticIpFault(ref cause) => cause.request_id(),
+            ModifyClusterError::InvalidRetentionPeriodFault(ref cause) => cause.request_id(),
+            ModifyClusterError::LimitExceededFault(ref cause) => cause.request_id(),
+            ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterError::NumberOfNodesQuotaExceededFault(ref cause) => cause.request_id(),
+            ModifyClusterError::TableLimitExceededFault(ref cause) => cause.request_id(),
+            ModifyClusterError::UnauthorizedOperation(ref cause) => cause.request_id(),
+            ModifyClusterError::UnsupportedOptionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyClusterError {
     #[allow(unused_variables)]
@@ -16809,11 +17752,11 @@ impl Error for ModifyClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterDbRevisionError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>Cluster is already on the latest database revision.</p>
-    ClusterOnLatestRevisionFault(String),
+    ClusterOnLatestRevisionFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl ModifyClusterDbRevisionError {
@@ -16827,21 +17770,21 @@ impl ModifyClusterDbRevisionError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterDbRevisionError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterOnLatestRevision" => {
                         return RusotoError::Service(
                             ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             ModifyClusterDbRevisionError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16859,6 +17802,17 @@ impl ModifyClusterDbRevisionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterDbRevisionError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterDbRevisionError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyClusterDbRevisionError {
     #[allow(unused_variables)]
@@ -16879,9 +17833,9 @@ impl Error for ModifyClusterDbRevisionError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterIamRolesError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl ModifyClusterIamRolesError {
@@ -16894,13 +17848,15 @@ impl ModifyClusterIamRolesError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            ModifyClusterIamRolesError::ClusterNotFoundFault(parsed_error.message),
+                            ModifyClusterIamRolesError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             ModifyClusterIamRolesError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16918,6 +17874,14 @@ impl ModifyClusterIamRolesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterIamRolesError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterIamRolesError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyClusterIamRolesError {
     #[allow(unused_variables)]
@@ -16935,9 +17899,9 @@ impl Error for ModifyClusterIamRolesError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterMaintenanceError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl ModifyClusterMaintenanceError {
@@ -16951,14 +17915,14 @@ impl ModifyClusterMaintenanceError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterMaintenanceError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             ModifyClusterMaintenanceError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -16976,6 +17940,16 @@ impl ModifyClusterMaintenanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterMaintenanceError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ModifyClusterMaintenanceError::InvalidClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyClusterMaintenanceError {
     #[allow(unused_variables)]
@@ -16995,9 +17969,9 @@ impl Error for ModifyClusterMaintenanceError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
-    InvalidClusterParameterGroupStateFault(String),
+    InvalidClusterParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ModifyClusterParameterGroupError {
@@ -17013,13 +17987,13 @@ impl ModifyClusterParameterGroupError {
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterParameterGroupState" => return RusotoError::Service(
                         ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -17036,6 +18010,18 @@ impl ModifyClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -17055,11 +18041,11 @@ impl Error for ModifyClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSnapshotError {
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
 }
 
 impl ModifyClusterSnapshotError {
@@ -17073,21 +18059,21 @@ impl ModifyClusterSnapshotError {
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
                             ModifyClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17105,6 +18091,21 @@ impl ModifyClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSnapshotError::InvalidRetentionPeriodFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyClusterSnapshotError {
     #[allow(unused_variables)]
@@ -17127,11 +18128,11 @@ impl Error for ModifyClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSnapshotScheduleError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The cluster snapshot schedule state is not valid.</p>
-    InvalidClusterSnapshotScheduleStateFault(String),
+    InvalidClusterSnapshotScheduleStateFault(XmlErrorMessage),
     /// <p>We could not find the specified snapshot schedule. </p>
-    SnapshotScheduleNotFoundFault(String),
+    SnapshotScheduleNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyClusterSnapshotScheduleError {
@@ -17144,7 +18145,7 @@ impl ModifyClusterSnapshotScheduleError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message)),"InvalidClusterSnapshotScheduleState" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message)),"SnapshotScheduleNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidClusterSnapshotScheduleState" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"SnapshotScheduleNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -17158,6 +18159,21 @@ impl ModifyClusterSnapshotScheduleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(
+                ref cause,
+            ) => cause.request_id(),
+            ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyClusterSnapshotScheduleError {
     #[allow(unused_variables)]
@@ -17180,17 +18196,17 @@ impl Error for ModifyClusterSnapshotScheduleError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSubnetGroupError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
-    ClusterSubnetGroupNotFoundFault(String),
+    ClusterSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would result in user exceeding the allowed number of subnets in a cluster subnet groups. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterSubnetQuotaExceededFault(String),
+    ClusterSubnetQuotaExceededFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or not all of the subnets are in the same VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>A specified subnet is already in use by another cluster.</p>
-    SubnetAlreadyInUse(String),
+    SubnetAlreadyInUse(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl ModifyClusterSubnetGroupError {
@@ -17204,38 +18220,40 @@ impl ModifyClusterSubnetGroupError {
                     "ClusterSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
                         return RusotoError::Service(
                             ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(ModifyClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubnetAlreadyInUse" => {
                         return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                            ModifyClusterSubnetGroupError::SubnetAlreadyInUse(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
                             ModifyClusterSubnetGroupError::UnauthorizedOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17253,6 +18271,24 @@ impl ModifyClusterSubnetGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyClusterSubnetGroupError::InvalidSubnet(ref cause) => cause.request_id(),
+            ModifyClusterSubnetGroupError::SubnetAlreadyInUse(ref cause) => cause.request_id(),
+            ModifyClusterSubnetGroupError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyClusterSubnetGroupError {
     #[allow(unused_variables)]
@@ -17280,23 +18316,23 @@ impl Error for ModifyClusterSubnetGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
     /// <p>The subscription request is invalid because it is a duplicate request. This subscription request is already in progress.</p>
-    InvalidSubscriptionStateFault(String),
+    InvalidSubscriptionStateFault(XmlErrorMessage),
     /// <p>Amazon SNS has responded that there is a problem with the specified Amazon SNS topic.</p>
-    SNSInvalidTopicFault(String),
+    SNSInvalidTopicFault(XmlErrorMessage),
     /// <p>You do not have permission to publish to the specified Amazon SNS topic.</p>
-    SNSNoAuthorizationFault(String),
+    SNSNoAuthorizationFault(XmlErrorMessage),
     /// <p>An Amazon SNS topic with the specified Amazon Resource Name (ARN) does not exist.</p>
-    SNSTopicArnNotFoundFault(String),
+    SNSTopicArnNotFoundFault(XmlErrorMessage),
     /// <p>The specified Amazon Redshift event source could not be found.</p>
-    SourceNotFoundFault(String),
+    SourceNotFoundFault(XmlErrorMessage),
     /// <p>The value specified for the event category was not one of the allowed values, or it specified a category that does not apply to the specified source type. The allowed values are Configuration, Management, Monitoring, and Security.</p>
-    SubscriptionCategoryNotFoundFault(String),
+    SubscriptionCategoryNotFoundFault(XmlErrorMessage),
     /// <p>An Amazon Redshift event with the specified event ID does not exist.</p>
-    SubscriptionEventIdNotFoundFault(String),
+    SubscriptionEventIdNotFoundFault(XmlErrorMessage),
     /// <p>An Amazon Redshift event notification subscription with the specified name does not exist.</p>
-    SubscriptionNotFoundFault(String),
+    SubscriptionNotFoundFault(XmlErrorMessage),
     /// <p>The value specified for the event severity was not one of the allowed values, or it specified a severity that does not apply to the specified source type. The allowed values are ERROR and INFO.</p>
-    SubscriptionSeverityNotFoundFault(String),
+    SubscriptionSeverityNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyEventSubscriptionError {
@@ -17310,61 +18346,63 @@ impl ModifyEventSubscriptionError {
                     "InvalidSubscriptionStateFault" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::InvalidSubscriptionStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SourceNotFound" => {
                         return RusotoError::Service(
-                            ModifyEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            ModifyEventSubscriptionError::SourceNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
                         return RusotoError::Service(
                             ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17382,6 +18420,31 @@ impl ModifyEventSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyEventSubscriptionError::InvalidSubscriptionStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SourceNotFoundFault(ref cause) => cause.request_id(),
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifyEventSubscriptionError {
     #[allow(unused_variables)]
@@ -17418,15 +18481,15 @@ impl Error for ModifyEventSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyScheduledActionError {
     /// <p>The schedule you submitted isn't valid.</p>
-    InvalidScheduleFault(String),
+    InvalidScheduleFault(XmlErrorMessage),
     /// <p>The scheduled action is not valid. </p>
-    InvalidScheduledActionFault(String),
+    InvalidScheduledActionFault(XmlErrorMessage),
     /// <p>The scheduled action cannot be found. </p>
-    ScheduledActionNotFoundFault(String),
+    ScheduledActionNotFoundFault(XmlErrorMessage),
     /// <p>The action type specified for a scheduled action is not supported. </p>
-    ScheduledActionTypeUnsupportedFault(String),
+    ScheduledActionTypeUnsupportedFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl ModifyScheduledActionError {
@@ -17439,33 +18502,37 @@ impl ModifyScheduledActionError {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
                         return RusotoError::Service(
-                            ModifyScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                            ModifyScheduledActionError::InvalidScheduleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidScheduledAction" => {
                         return RusotoError::Service(
                             ModifyScheduledActionError::InvalidScheduledActionFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScheduledActionNotFound" => {
                         return RusotoError::Service(
                             ModifyScheduledActionError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
                         return RusotoError::Service(
                             ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
-                            ModifyScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            ModifyScheduledActionError::UnauthorizedOperation(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -17482,6 +18549,23 @@ impl ModifyScheduledActionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyScheduledActionError::InvalidScheduleFault(ref cause) => cause.request_id(),
+            ModifyScheduledActionError::InvalidScheduledActionFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyScheduledActionError::ScheduledActionNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifyScheduledActionError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyScheduledActionError {
     #[allow(unused_variables)]
@@ -17506,15 +18590,15 @@ impl Error for ModifyScheduledActionError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifySnapshotCopyRetentionPeriodError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
-    InvalidRetentionPeriodFault(String),
+    InvalidRetentionPeriodFault(XmlErrorMessage),
     /// <p>Cross-region snapshot copy was temporarily disabled. Try your request again.</p>
-    SnapshotCopyDisabledFault(String),
+    SnapshotCopyDisabledFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl ModifySnapshotCopyRetentionPeriodError {
@@ -17530,35 +18614,35 @@ impl ModifySnapshotCopyRetentionPeriodError {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
                             ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
                         return RusotoError::Service(
                             ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotCopyDisabledFault" => {
                         return RusotoError::Service(
                             ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
                             ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17576,6 +18660,27 @@ impl ModifySnapshotCopyRetentionPeriodError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifySnapshotCopyRetentionPeriodError {
     #[allow(unused_variables)]
@@ -17604,11 +18709,11 @@ impl Error for ModifySnapshotCopyRetentionPeriodError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifySnapshotScheduleError {
     /// <p>The schedule you submitted isn't valid.</p>
-    InvalidScheduleFault(String),
+    InvalidScheduleFault(XmlErrorMessage),
     /// <p>We could not find the specified snapshot schedule. </p>
-    SnapshotScheduleNotFoundFault(String),
+    SnapshotScheduleNotFoundFault(XmlErrorMessage),
     /// <p>The specified snapshot schedule is already being updated.</p>
-    SnapshotScheduleUpdateInProgressFault(String),
+    SnapshotScheduleUpdateInProgressFault(XmlErrorMessage),
 }
 
 impl ModifySnapshotScheduleError {
@@ -17621,20 +18726,22 @@ impl ModifySnapshotScheduleError {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
                         return RusotoError::Service(
-                            ModifySnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                            ModifySnapshotScheduleError::InvalidScheduleFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
                         return RusotoError::Service(
                             ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleUpdateInProgress" => {
                         return RusotoError::Service(
                             ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17652,6 +18759,19 @@ impl ModifySnapshotScheduleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifySnapshotScheduleError::InvalidScheduleFault(ref cause) => cause.request_id(),
+            ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ModifySnapshotScheduleError {
     #[allow(unused_variables)]
@@ -17672,11 +18792,11 @@ impl Error for ModifySnapshotScheduleError {}
 #[derive(Debug, PartialEq)]
 pub enum ModifyUsageLimitError {
     /// <p>The usage limit is not valid.</p>
-    InvalidUsageLimitFault(String),
+    InvalidUsageLimitFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
     /// <p>The usage limit identifier can't be found.</p>
-    UsageLimitNotFoundFault(String),
+    UsageLimitNotFoundFault(XmlErrorMessage),
 }
 
 impl ModifyUsageLimitError {
@@ -17689,17 +18809,21 @@ impl ModifyUsageLimitError {
                 match &parsed_error.code[..] {
                     "InvalidUsageLimit" => {
                         return RusotoError::Service(ModifyUsageLimitError::InvalidUsageLimitFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
-                            ModifyUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                            ModifyUsageLimitError::UnsupportedOperationFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "UsageLimitNotFound" => {
                         return RusotoError::Service(
-                            ModifyUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
+                            ModifyUsageLimitError::UsageLimitNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -17716,6 +18840,15 @@ impl ModifyUsageLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ModifyUsageLimitError::InvalidUsageLimitFault(ref cause) => cause.request_id(),
+            ModifyUsageLimitError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+            ModifyUsageLimitError::UsageLimitNotFoundFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ModifyUsageLimitError {
     #[allow(unused_variables)]
@@ -17732,9 +18865,9 @@ impl Error for ModifyUsageLimitError {}
 #[derive(Debug, PartialEq)]
 pub enum PauseClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl PauseClusterError {
@@ -17747,12 +18880,12 @@ impl PauseClusterError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(PauseClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(PauseClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17769,6 +18902,14 @@ impl PauseClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PauseClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            PauseClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PauseClusterError {
     #[allow(unused_variables)]
@@ -17784,13 +18925,13 @@ impl Error for PauseClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedNodeOfferingError {
     /// <p>User already has a reservation with the given identifier.</p>
-    ReservedNodeAlreadyExistsFault(String),
+    ReservedNodeAlreadyExistsFault(XmlErrorMessage),
     /// <p>Specified offering does not exist.</p>
-    ReservedNodeOfferingNotFoundFault(String),
+    ReservedNodeOfferingNotFoundFault(XmlErrorMessage),
     /// <p>Request would exceed the user's compute node quota. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ReservedNodeQuotaExceededFault(String),
+    ReservedNodeQuotaExceededFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl PurchaseReservedNodeOfferingError {
@@ -17806,28 +18947,28 @@ impl PurchaseReservedNodeOfferingError {
                     "ReservedNodeAlreadyExists" => {
                         return RusotoError::Service(
                             PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
                         return RusotoError::Service(
                             PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ReservedNodeQuotaExceeded" => {
                         return RusotoError::Service(
                             PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(
                             PurchaseReservedNodeOfferingError::UnsupportedOperationFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17845,6 +18986,24 @@ impl PurchaseReservedNodeOfferingError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            PurchaseReservedNodeOfferingError::UnsupportedOperationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PurchaseReservedNodeOfferingError {
     #[allow(unused_variables)]
@@ -17870,9 +19029,9 @@ impl Error for PurchaseReservedNodeOfferingError {}
 #[derive(Debug, PartialEq)]
 pub enum RebootClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl RebootClusterError {
@@ -17885,12 +19044,12 @@ impl RebootClusterError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(RebootClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(RebootClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -17907,6 +19066,14 @@ impl RebootClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RebootClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            RebootClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RebootClusterError {
     #[allow(unused_variables)]
@@ -17922,9 +19089,9 @@ impl Error for RebootClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum ResetClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
-    InvalidClusterParameterGroupStateFault(String),
+    InvalidClusterParameterGroupStateFault(XmlErrorMessage),
 }
 
 impl ResetClusterParameterGroupError {
@@ -17940,14 +19107,14 @@ impl ResetClusterParameterGroupError {
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterParameterGroupState" => {
                         return RusotoError::Service(
                             ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17965,6 +19132,18 @@ impl ResetClusterParameterGroupError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ResetClusterParameterGroupError {
     #[allow(unused_variables)]
@@ -17984,23 +19163,23 @@ impl Error for ResetClusterParameterGroupError {}
 #[derive(Debug, PartialEq)]
 pub enum ResizeClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The number of nodes specified exceeds the allotted capacity of the cluster.</p>
-    InsufficientClusterCapacityFault(String),
+    InsufficientClusterCapacityFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allowed for a cluster.</p>
-    NumberOfNodesPerClusterLimitExceededFault(String),
+    NumberOfNodesPerClusterLimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allotted to the account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    NumberOfNodesQuotaExceededFault(String),
+    NumberOfNodesQuotaExceededFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
     /// <p>A request option was specified that is not supported.</p>
-    UnsupportedOptionFault(String),
+    UnsupportedOptionFault(XmlErrorMessage),
 }
 
 impl ResizeClusterError {
@@ -18013,53 +19192,53 @@ impl ResizeClusterError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(ResizeClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InsufficientClusterCapacity" => {
                         return RusotoError::Service(
                             ResizeClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(ResizeClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(ResizeClusterError::LimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
                         return RusotoError::Service(
                             ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
                         return RusotoError::Service(
                             ResizeClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(ResizeClusterError::UnauthorizedOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOperation" => {
                         return RusotoError::Service(ResizeClusterError::UnsupportedOperationFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "UnsupportedOptionFault" => {
                         return RusotoError::Service(ResizeClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18076,6 +19255,23 @@ impl ResizeClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResizeClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ResizeClusterError::InsufficientClusterCapacityFault(ref cause) => cause.request_id(),
+            ResizeClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+            ResizeClusterError::LimitExceededFault(ref cause) => cause.request_id(),
+            ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            ResizeClusterError::NumberOfNodesQuotaExceededFault(ref cause) => cause.request_id(),
+            ResizeClusterError::UnauthorizedOperation(ref cause) => cause.request_id(),
+            ResizeClusterError::UnsupportedOperationFault(ref cause) => cause.request_id(),
+            ResizeClusterError::UnsupportedOptionFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResizeClusterError {
     #[allow(unused_variables)]
@@ -18104,55 +19300,55 @@ impl Error for ResizeClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreFromClusterSnapshotError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
-    AccessToSnapshotDeniedFault(String),
+    AccessToSnapshotDeniedFault(XmlErrorMessage),
     /// <p>The account already has a cluster with the given identifier.</p>
-    ClusterAlreadyExistsFault(String),
+    ClusterAlreadyExistsFault(XmlErrorMessage),
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
-    ClusterParameterGroupNotFoundFault(String),
+    ClusterParameterGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request would exceed the allowed number of cluster instances for this account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    ClusterQuotaExceededFault(String),
+    ClusterQuotaExceededFault(XmlErrorMessage),
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
-    ClusterSubnetGroupNotFoundFault(String),
+    ClusterSubnetGroupNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
-    HsmClientCertificateNotFoundFault(String),
+    HsmClientCertificateNotFoundFault(XmlErrorMessage),
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
-    HsmConfigurationNotFoundFault(String),
+    HsmConfigurationNotFoundFault(XmlErrorMessage),
     /// <p>The number of nodes specified exceeds the allotted capacity of the cluster.</p>
-    InsufficientClusterCapacityFault(String),
+    InsufficientClusterCapacityFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The cluster subnet group cannot be deleted because it is in use.</p>
-    InvalidClusterSubnetGroupStateFault(String),
+    InvalidClusterSubnetGroupStateFault(XmlErrorMessage),
     /// <p>The provided cluster track name is not valid.</p>
-    InvalidClusterTrackFault(String),
+    InvalidClusterTrackFault(XmlErrorMessage),
     /// <p>The Elastic IP (EIP) is invalid or cannot be found.</p>
-    InvalidElasticIpFault(String),
+    InvalidElasticIpFault(XmlErrorMessage),
     /// <p>The restore is invalid.</p>
-    InvalidRestoreFault(String),
+    InvalidRestoreFault(XmlErrorMessage),
     /// <p>The requested subnet is not valid, or not all of the subnets are in the same VPC.</p>
-    InvalidSubnet(String),
+    InvalidSubnet(XmlErrorMessage),
     /// <p>The tag is invalid.</p>
-    InvalidTagFault(String),
+    InvalidTagFault(XmlErrorMessage),
     /// <p>The cluster subnet group does not cover all Availability Zones.</p>
-    InvalidVPCNetworkStateFault(String),
+    InvalidVPCNetworkStateFault(XmlErrorMessage),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
-    LimitExceededFault(String),
+    LimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allowed for a cluster.</p>
-    NumberOfNodesPerClusterLimitExceededFault(String),
+    NumberOfNodesPerClusterLimitExceededFault(XmlErrorMessage),
     /// <p>The operation would exceed the number of nodes allotted to the account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    NumberOfNodesQuotaExceededFault(String),
+    NumberOfNodesQuotaExceededFault(XmlErrorMessage),
     /// <p>We could not find the specified snapshot schedule. </p>
-    SnapshotScheduleNotFoundFault(String),
+    SnapshotScheduleNotFoundFault(XmlErrorMessage),
     /// <p>You have exceeded the number of tags allowed.</p>
-    TagLimitExceededFault(String),
+    TagLimitExceededFault(XmlErrorMessage),
     /// <p>Your account is not authorized to perform the requested operation.</p>
-    UnauthorizedOperation(String),
+    UnauthorizedOperation(XmlErrorMessage),
 }
 
 impl RestoreFromClusterSnapshotError {
@@ -18168,169 +19364,173 @@ impl RestoreFromClusterSnapshotError {
                     "AccessToSnapshotDenied" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterAlreadyExists" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterParameterGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientClusterCapacity" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterTrack" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidClusterTrackFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidElasticIpFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidElasticIpFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidRestore" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSubnet" => {
                         return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidSubnet(parsed_error.message),
+                            RestoreFromClusterSnapshotError::InvalidSubnet(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidTagFault" => {
                         return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidTagFault(parsed_error.message),
+                            RestoreFromClusterSnapshotError::InvalidTagFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceededFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::LimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::Service(
                         RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "NumberOfNodesQuotaExceeded" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::TagLimitExceededFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
                         return RusotoError::Service(
                             RestoreFromClusterSnapshotError::UnauthorizedOperation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18348,6 +19548,73 @@ impl RestoreFromClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterTrackFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::InvalidElasticIpFault(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::InvalidRestoreFault(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::InvalidSubnet(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::InvalidTagFault(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::LimitExceededFault(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreFromClusterSnapshotError::TagLimitExceededFault(ref cause) => cause.request_id(),
+            RestoreFromClusterSnapshotError::UnauthorizedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RestoreFromClusterSnapshotError {
     #[allow(unused_variables)]
@@ -18432,19 +19699,19 @@ impl Error for RestoreFromClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreTableFromClusterSnapshotError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
     /// <p>You have exceeded the allowed number of table restore requests. Wait for your current table restore requests to complete before making a new request.</p>
-    InProgressTableRestoreQuotaExceededFault(String),
+    InProgressTableRestoreQuotaExceededFault(XmlErrorMessage),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
-    InvalidClusterSnapshotStateFault(String),
+    InvalidClusterSnapshotStateFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
     /// <p>The value specified for the <code>sourceDatabaseName</code>, <code>sourceSchemaName</code>, or <code>sourceTableName</code> parameter, or a combination of these, doesn't exist in the snapshot.</p>
-    InvalidTableRestoreArgumentFault(String),
+    InvalidTableRestoreArgumentFault(XmlErrorMessage),
     /// <p>The requested operation isn't supported.</p>
-    UnsupportedOperationFault(String),
+    UnsupportedOperationFault(XmlErrorMessage),
 }
 
 impl RestoreTableFromClusterSnapshotError {
@@ -18457,7 +19724,7 @@ impl RestoreTableFromClusterSnapshotError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message)),"ClusterSnapshotNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message)),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message)),"InvalidClusterSnapshotState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message)),"InvalidClusterState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message)),"InvalidTableRestoreArgument" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message)),"UnsupportedOperation" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ClusterSnapshotNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidClusterSnapshotState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidClusterState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTableRestoreArgument" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"UnsupportedOperation" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -18471,6 +19738,33 @@ impl RestoreTableFromClusterSnapshotError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(
+                ref cause,
+            ) => cause.request_id(),
+            RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(ref cause) => {
+                cause.request_id()
+            }
+            RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RestoreTableFromClusterSnapshotError {
     #[allow(unused_variables)]
@@ -18505,9 +19799,9 @@ impl Error for RestoreTableFromClusterSnapshotError {}
 #[derive(Debug, PartialEq)]
 pub enum ResumeClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl ResumeClusterError {
@@ -18520,12 +19814,12 @@ impl ResumeClusterError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(ResumeClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(ResumeClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -18542,6 +19836,14 @@ impl ResumeClusterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ResumeClusterError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            ResumeClusterError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ResumeClusterError {
     #[allow(unused_variables)]
@@ -18557,11 +19859,11 @@ impl Error for ResumeClusterError {}
 #[derive(Debug, PartialEq)]
 pub enum RevokeClusterSecurityGroupIngressError {
     /// <p>The specified CIDR IP range or EC2 security group is not authorized for the specified cluster security group.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
-    ClusterSecurityGroupNotFoundFault(String),
+    ClusterSecurityGroupNotFoundFault(XmlErrorMessage),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
-    InvalidClusterSecurityGroupStateFault(String),
+    InvalidClusterSecurityGroupStateFault(XmlErrorMessage),
 }
 
 impl RevokeClusterSecurityGroupIngressError {
@@ -18574,7 +19876,7 @@ impl RevokeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"ClusterSecurityGroupNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidClusterSecurityGroupState" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -18588,6 +19890,21 @@ impl RevokeClusterSecurityGroupIngressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+            RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(
+                ref cause,
+            ) => cause.request_id(),
+            RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RevokeClusterSecurityGroupIngressError {
     #[allow(unused_variables)]
@@ -18610,11 +19927,11 @@ impl Error for RevokeClusterSecurityGroupIngressError {}
 #[derive(Debug, PartialEq)]
 pub enum RevokeSnapshotAccessError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
-    AccessToSnapshotDeniedFault(String),
+    AccessToSnapshotDeniedFault(XmlErrorMessage),
     /// <p>The specified CIDR IP range or EC2 security group is not authorized for the specified cluster security group.</p>
-    AuthorizationNotFoundFault(String),
+    AuthorizationNotFoundFault(XmlErrorMessage),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
-    ClusterSnapshotNotFoundFault(String),
+    ClusterSnapshotNotFoundFault(XmlErrorMessage),
 }
 
 impl RevokeSnapshotAccessError {
@@ -18628,21 +19945,21 @@ impl RevokeSnapshotAccessError {
                     "AccessToSnapshotDenied" => {
                         return RusotoError::Service(
                             RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AuthorizationNotFound" => {
                         return RusotoError::Service(
                             RevokeSnapshotAccessError::AuthorizationNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
                         return RusotoError::Service(
                             RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18660,6 +19977,17 @@ impl RevokeSnapshotAccessError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(ref cause) => cause.request_id(),
+            RevokeSnapshotAccessError::AuthorizationNotFoundFault(ref cause) => cause.request_id(),
+            RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for RevokeSnapshotAccessError {
     #[allow(unused_variables)]
@@ -18682,11 +20010,11 @@ impl Error for RevokeSnapshotAccessError {}
 #[derive(Debug, PartialEq)]
 pub enum RotateEncryptionKeyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
-    ClusterNotFoundFault(String),
+    ClusterNotFoundFault(XmlErrorMessage),
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
-    DependentServiceRequestThrottlingFault(String),
+    DependentServiceRequestThrottlingFault(XmlErrorMessage),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
-    InvalidClusterStateFault(String),
+    InvalidClusterStateFault(XmlErrorMessage),
 }
 
 impl RotateEncryptionKeyError {
@@ -18699,20 +20027,22 @@ impl RotateEncryptionKeyError {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
                         return RusotoError::Service(
-                            RotateEncryptionKeyError::ClusterNotFoundFault(parsed_error.message),
+                            RotateEncryptionKeyError::ClusterNotFoundFault(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
                         return RusotoError::Service(
                             RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidClusterState" => {
                         return RusotoError::Service(
                             RotateEncryptionKeyError::InvalidClusterStateFault(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -18730,6 +20060,17 @@ impl RotateEncryptionKeyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RotateEncryptionKeyError::ClusterNotFoundFault(ref cause) => cause.request_id(),
+            RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(ref cause) => {
+                cause.request_id()
+            }
+            RotateEncryptionKeyError::InvalidClusterStateFault(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RotateEncryptionKeyError {
     #[allow(unused_variables)]
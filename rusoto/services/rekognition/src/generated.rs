@@ -41,7 +41,7 @@ impl RekognitionClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6786,7 +6786,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, CompareFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CompareFacesResponse, _>()
     }
 
@@ -6804,7 +6804,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, CreateCollectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCollectionResponse, _>()
     }
 
@@ -6822,7 +6822,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, CreateProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResponse, _>()
     }
 
@@ -6840,7 +6840,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, CreateProjectVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateProjectVersionResponse, _>()
     }
@@ -6859,7 +6859,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, CreateStreamProcessorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateStreamProcessorResponse, _>()
     }
@@ -6878,7 +6878,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DeleteCollectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteCollectionResponse, _>()
     }
 
@@ -6896,7 +6896,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DeleteFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFacesResponse, _>()
     }
 
@@ -6914,7 +6914,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DeleteProjectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectResponse, _>()
     }
 
@@ -6932,7 +6932,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DeleteProjectVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteProjectVersionResponse, _>()
     }
@@ -6951,7 +6951,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DeleteStreamProcessorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteStreamProcessorResponse, _>()
     }
@@ -6970,7 +6970,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DescribeCollectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCollectionResponse, _>()
     }
 
@@ -6988,7 +6988,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DescribeProjectVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProjectVersionsResponse, _>()
     }
@@ -7007,7 +7007,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DescribeProjectsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProjectsResponse, _>()
     }
 
@@ -7025,7 +7025,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DescribeStreamProcessorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeStreamProcessorResponse, _>()
     }
@@ -7044,7 +7044,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DetectCustomLabelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectCustomLabelsResponse, _>()
     }
 
@@ -7062,7 +7062,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DetectFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectFacesResponse, _>()
     }
 
@@ -7080,7 +7080,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DetectLabelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectLabelsResponse, _>()
     }
 
@@ -7098,7 +7098,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DetectModerationLabelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DetectModerationLabelsResponse, _>()
     }
@@ -7117,7 +7117,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, DetectTextError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectTextResponse, _>()
     }
 
@@ -7135,7 +7135,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetCelebrityInfoError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCelebrityInfoResponse, _>()
     }
 
@@ -7153,7 +7153,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetCelebrityRecognitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetCelebrityRecognitionResponse, _>()
     }
@@ -7172,7 +7172,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetContentModerationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetContentModerationResponse, _>()
     }
@@ -7191,7 +7191,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetFaceDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFaceDetectionResponse, _>()
     }
 
@@ -7209,7 +7209,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetFaceSearchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetFaceSearchResponse, _>()
     }
 
@@ -7227,7 +7227,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetLabelDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetLabelDetectionResponse, _>()
     }
 
@@ -7245,7 +7245,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetPersonTrackingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPersonTrackingResponse, _>()
     }
 
@@ -7263,7 +7263,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetSegmentDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSegmentDetectionResponse, _>()
     }
 
@@ -7281,7 +7281,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, GetTextDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTextDetectionResponse, _>()
     }
 
@@ -7299,7 +7299,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, IndexFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<IndexFacesResponse, _>()
     }
 
@@ -7317,7 +7317,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, ListCollectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCollectionsResponse, _>()
     }
 
@@ -7335,7 +7335,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, ListFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFacesResponse, _>()
     }
 
@@ -7353,7 +7353,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, ListStreamProcessorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListStreamProcessorsResponse, _>()
     }
@@ -7372,7 +7372,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, RecognizeCelebritiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RecognizeCelebritiesResponse, _>()
     }
@@ -7391,7 +7391,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, SearchFacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchFacesResponse, _>()
     }
 
@@ -7409,7 +7409,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, SearchFacesByImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchFacesByImageResponse, _>()
     }
 
@@ -7431,7 +7431,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartCelebrityRecognitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartCelebrityRecognitionResponse, _>()
     }
@@ -7450,7 +7450,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartContentModerationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartContentModerationResponse, _>()
     }
@@ -7469,7 +7469,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartFaceDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartFaceDetectionResponse, _>()
     }
 
@@ -7487,7 +7487,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartFaceSearchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartFaceSearchResponse, _>()
     }
 
@@ -7505,7 +7505,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartLabelDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartLabelDetectionResponse, _>()
     }
 
@@ -7523,7 +7523,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartPersonTrackingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartPersonTrackingResponse, _>()
     }
 
@@ -7541,7 +7541,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartProjectVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartProjectVersionResponse, _>()
     }
 
@@ -7559,7 +7559,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartSegmentDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartSegmentDetectionResponse, _>()
     }
@@ -7578,7 +7578,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartStreamProcessorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartStreamProcessorResponse, _>()
     }
@@ -7597,7 +7597,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StartTextDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartTextDetectionResponse, _>()
     }
 
@@ -7615,7 +7615,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StopProjectVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopProjectVersionResponse, _>()
     }
 
@@ -7633,7 +7633,7 @@ impl Rekognition for RekognitionClient {
             .sign_and_dispatch(request, StopStreamProcessorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopStreamProcessorResponse, _>()
     }
 }
@@ -1257,6 +1257,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1268,13 +1269,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateGroupError::from_response(response))
         }
     }
@@ -1289,6 +1290,7 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "resource-groups", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1297,13 +1299,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteGroupOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteGroupError::from_response(response))
         }
     }
@@ -1317,6 +1319,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1325,13 +1328,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetGroupOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupError::from_response(response))
         }
     }
@@ -1345,6 +1348,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1353,13 +1357,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetGroupQueryOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetGroupQueryError::from_response(response))
         }
     }
@@ -1373,6 +1377,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1381,13 +1386,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetTagsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetTagsError::from_response(response))
         }
     }
@@ -1404,6 +1409,7 @@ impl ResourceGroups for ResourceGroupsClient {
         );
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1424,13 +1430,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupResourcesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupResourcesError::from_response(response))
         }
     }
@@ -1444,6 +1450,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1464,13 +1471,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGroupsOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListGroupsError::from_response(response))
         }
     }
@@ -1484,6 +1491,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("SearchResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1495,13 +1503,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SearchResourcesOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SearchResourcesError::from_response(response))
         }
     }
@@ -1512,6 +1520,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Tag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1523,13 +1532,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<TagOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagError::from_response(response))
         }
     }
@@ -1541,6 +1550,7 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Untag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1552,13 +1562,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<UntagOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagError::from_response(response))
         }
     }
@@ -1572,6 +1582,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1583,13 +1594,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGroupOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGroupError::from_response(response))
         }
     }
@@ -1603,6 +1614,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1614,13 +1626,13 @@ impl ResourceGroups for ResourceGroupsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateGroupQueryOutput, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateGroupQueryError::from_response(response))
         }
     }
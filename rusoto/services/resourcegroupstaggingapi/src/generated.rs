@@ -41,7 +41,7 @@ impl ResourceGroupsTaggingApiClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -876,7 +876,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, DescribeReportCreationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeReportCreationOutput, _>()
     }
@@ -898,7 +898,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, GetComplianceSummaryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetComplianceSummaryOutput, _>()
     }
 
@@ -919,7 +919,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, GetResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourcesOutput, _>()
     }
 
@@ -940,7 +940,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, GetTagKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTagKeysOutput, _>()
     }
 
@@ -961,7 +961,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, GetTagValuesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTagValuesOutput, _>()
     }
 
@@ -982,7 +982,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, StartReportCreationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartReportCreationOutput, _>()
     }
 
@@ -1003,7 +1003,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, TagResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourcesOutput, _>()
     }
 
@@ -1024,7 +1024,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
             .sign_and_dispatch(request, UntagResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourcesOutput, _>()
     }
 }
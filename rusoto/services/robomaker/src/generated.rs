@@ -4968,6 +4968,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/batchDescribeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("BatchDescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4979,13 +4980,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchDescribeSimulationJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(BatchDescribeSimulationJobError::from_response(response))
         }
     }
@@ -4999,6 +5000,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5010,13 +5012,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelDeploymentJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelDeploymentJobError::from_response(response))
         }
     }
@@ -5030,6 +5032,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5041,13 +5044,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelSimulationJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelSimulationJobError::from_response(response))
         }
     }
@@ -5061,6 +5064,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5072,13 +5076,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelSimulationJobBatchResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CancelSimulationJobBatchError::from_response(response))
         }
     }
@@ -5092,6 +5096,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5103,13 +5108,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDeploymentJobError::from_response(response))
         }
     }
@@ -5123,6 +5128,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5134,13 +5140,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFleetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFleetError::from_response(response))
         }
     }
@@ -5154,6 +5160,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5165,13 +5172,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRobotError::from_response(response))
         }
     }
@@ -5185,6 +5192,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5196,13 +5204,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRobotApplicationError::from_response(response))
         }
     }
@@ -5219,6 +5227,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5230,13 +5239,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotApplicationVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRobotApplicationVersionError::from_response(response))
         }
     }
@@ -5251,6 +5260,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5262,13 +5272,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSimulationApplicationError::from_response(response))
         }
     }
@@ -5285,6 +5295,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5296,13 +5307,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationApplicationVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSimulationApplicationVersionError::from_response(
                 response,
             ))
@@ -5318,6 +5329,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5329,13 +5341,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSimulationJobError::from_response(response))
         }
     }
@@ -5349,6 +5361,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5360,13 +5373,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFleetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFleetError::from_response(response))
         }
     }
@@ -5380,6 +5393,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5391,13 +5405,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteRobotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRobotError::from_response(response))
         }
     }
@@ -5411,6 +5425,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5422,13 +5437,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteRobotApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRobotApplicationError::from_response(response))
         }
     }
@@ -5443,6 +5458,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5454,13 +5470,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSimulationApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSimulationApplicationError::from_response(response))
         }
     }
@@ -5474,6 +5490,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deregisterRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeregisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5485,13 +5502,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeregisterRobotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeregisterRobotError::from_response(response))
         }
     }
@@ -5505,6 +5522,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5516,13 +5534,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDeploymentJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDeploymentJobError::from_response(response))
         }
     }
@@ -5536,6 +5554,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5547,13 +5566,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFleetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFleetError::from_response(response))
         }
     }
@@ -5567,6 +5586,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5578,13 +5598,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRobotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRobotError::from_response(response))
         }
     }
@@ -5598,6 +5618,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5609,13 +5630,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRobotApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRobotApplicationError::from_response(response))
         }
     }
@@ -5632,6 +5653,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5643,13 +5665,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSimulationApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSimulationApplicationError::from_response(response))
         }
     }
@@ -5663,6 +5685,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5674,13 +5697,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSimulationJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSimulationJobError::from_response(response))
         }
     }
@@ -5695,6 +5718,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5706,13 +5730,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSimulationJobBatchResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSimulationJobBatchError::from_response(response))
         }
     }
@@ -5726,6 +5750,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listDeploymentJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListDeploymentJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5737,13 +5762,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeploymentJobsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDeploymentJobsError::from_response(response))
         }
     }
@@ -5757,6 +5782,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5768,13 +5794,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFleetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFleetsError::from_response(response))
         }
     }
@@ -5788,6 +5814,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobotApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobotApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5799,13 +5826,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRobotApplicationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRobotApplicationsError::from_response(response))
         }
     }
@@ -5819,6 +5846,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobots";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5830,13 +5858,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRobotsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRobotsError::from_response(response))
         }
     }
@@ -5851,6 +5879,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5862,13 +5891,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSimulationApplicationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSimulationApplicationsError::from_response(response))
         }
     }
@@ -5882,6 +5911,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobBatches";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobBatches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5893,13 +5923,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSimulationJobBatchesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSimulationJobBatchesError::from_response(response))
         }
     }
@@ -5913,6 +5943,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5924,13 +5955,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSimulationJobsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSimulationJobsError::from_response(response))
         }
     }
@@ -5944,6 +5975,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5952,13 +5984,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -5972,6 +6004,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/registerRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RegisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5983,13 +6016,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterRobotResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterRobotError::from_response(response))
         }
     }
@@ -6003,6 +6036,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/restartSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RestartSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6014,13 +6048,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RestartSimulationJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RestartSimulationJobError::from_response(response))
         }
     }
@@ -6034,6 +6068,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/startSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("StartSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6045,13 +6080,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartSimulationJobBatchResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartSimulationJobBatchError::from_response(response))
         }
     }
@@ -6065,6 +6100,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("SyncDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6076,13 +6112,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SyncDeploymentJobResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SyncDeploymentJobError::from_response(response))
         }
     }
@@ -6096,6 +6132,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6107,13 +6144,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -6127,6 +6164,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "robomaker", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6141,13 +6179,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -6161,6 +6199,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6172,13 +6211,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRobotApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRobotApplicationError::from_response(response))
         }
     }
@@ -6193,6 +6232,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6204,13 +6244,13 @@ impl Robomaker for RobomakerClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateSimulationApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateSimulationApplicationError::from_response(response))
         }
     }
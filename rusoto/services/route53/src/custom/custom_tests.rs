@@ -2,7 +2,7 @@ use crate::custom::util::quote_txt_record;
 use crate::generated::{
     ListResourceRecordSetsError, ListResourceRecordSetsRequest, Route53, Route53Client,
 };
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{Region, RusotoError, XmlErrorMessage};
 
 use rusoto_mock::*;
 
@@ -31,7 +31,8 @@ async fn test_parse_no_such_hosted_zone_error() {
     let err = result.err().unwrap();
     assert_eq!(
         RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
-            "No hosted zone found with ID: NO-SUCH-ZONE".to_owned()
+            XmlErrorMessage::new("No hosted zone found with ID: NO-SUCH-ZONE")
+                .with_request_id("20c2984f-279e-11e8-9a16-83e7725d8022")
         )),
         err
     );
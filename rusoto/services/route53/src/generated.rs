@@ -45,7 +45,7 @@ impl Route53Client {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7424,21 +7424,21 @@ impl VPCsDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AssociateVPCWithHostedZoneError {
     /// <p><p>The cause of this error depends on the operation that you&#39;re performing:</p> <ul> <li> <p> <b>Create a public hosted zone:</b> Two hosted zones that have the same name or that have a parent/child relationship (example.com and test.example.com) can&#39;t have any common name servers. You tried to create a hosted zone that has the same name as an existing hosted zone or that&#39;s the parent or child of an existing hosted zone, and you specified a delegation set that shares one or more name servers with the existing hosted zone. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateReusableDelegationSet.html">CreateReusableDelegationSet</a>.</p> </li> <li> <p> <b>Create a private hosted zone:</b> A hosted zone with the specified name already exists and is already associated with the Amazon VPC that you specified.</p> </li> <li> <p> <b>Associate VPCs with a private hosted zone:</b> The VPC that you specified is already associated with another hosted zone that has the same name.</p> </li> </ul></p>
-    ConflictingDomainExists(String),
+    ConflictingDomainExists(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The VPC ID that you specified either isn't a valid ID or the current account is not authorized to access this VPC.</p>
-    InvalidVPCId(String),
+    InvalidVPCId(XmlErrorMessage),
     /// <p>This operation can't be completed either because the current account has reached the limit on reusable delegation sets that it can create or because you've reached the limit on the number of Amazon VPCs that you can associate with a private hosted zone. To get the current limit on the number of reusable delegation sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. To get the current limit on the number of Amazon VPCs that you can associate with a private hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetHostedZoneLimit.html">GetHostedZoneLimit</a>. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    LimitsExceeded(String),
+    LimitsExceeded(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>Associating the specified VPC with the specified hosted zone has not been authorized.</p>
-    NotAuthorized(String),
+    NotAuthorized(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
     /// <p>You're trying to associate a VPC with a public hosted zone. Amazon Route 53 doesn't support associating a VPC with a public hosted zone.</p>
-    PublicZoneVPCAssociation(String),
+    PublicZoneVPCAssociation(XmlErrorMessage),
 }
 
 impl AssociateVPCWithHostedZoneError {
@@ -7454,46 +7454,52 @@ impl AssociateVPCWithHostedZoneError {
                     "ConflictingDomainExists" => {
                         return RusotoError::Service(
                             AssociateVPCWithHostedZoneError::ConflictingDomainExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCId" => {
                         return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitsExceeded" => {
                         return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::LimitsExceeded(parsed_error.message),
+                            AssociateVPCWithHostedZoneError::LimitsExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                            AssociateVPCWithHostedZoneError::NoSuchHostedZone(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NotAuthorizedException" => {
                         return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::NotAuthorized(parsed_error.message),
+                            AssociateVPCWithHostedZoneError::NotAuthorized(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             AssociateVPCWithHostedZoneError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PublicZoneVPCAssociation" => {
                         return RusotoError::Service(
                             AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7511,6 +7517,26 @@ impl AssociateVPCWithHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AssociateVPCWithHostedZoneError::ConflictingDomainExists(ref cause) => {
+                cause.request_id()
+            }
+            AssociateVPCWithHostedZoneError::InvalidInput(ref cause) => cause.request_id(),
+            AssociateVPCWithHostedZoneError::InvalidVPCId(ref cause) => cause.request_id(),
+            AssociateVPCWithHostedZoneError::LimitsExceeded(ref cause) => cause.request_id(),
+            AssociateVPCWithHostedZoneError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            AssociateVPCWithHostedZoneError::NotAuthorized(ref cause) => cause.request_id(),
+            AssociateVPCWithHostedZoneError::PriorRequestNotComplete(ref cause) => {
+                cause.request_id()
+            }
+            AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for AssociateVPCWithHostedZoneError {
     #[allow(unused_variables)]
@@ -7538,15 +7564,15 @@ impl Error for AssociateVPCWithHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum ChangeResourceRecordSetsError {
     /// <p>This exception contains a list of messages that might contain one or more error messages. Each error message indicates one error in the change batch.</p>
-    InvalidChangeBatch(String),
+    InvalidChangeBatch(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
 }
 
 impl ChangeResourceRecordSetsError {
@@ -7559,28 +7585,34 @@ impl ChangeResourceRecordSetsError {
                 match &parsed_error.code[..] {
                     "InvalidChangeBatch" => {
                         return RusotoError::Service(
-                            ChangeResourceRecordSetsError::InvalidChangeBatch(parsed_error.message),
+                            ChangeResourceRecordSetsError::InvalidChangeBatch(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(ChangeResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(
-                            ChangeResourceRecordSetsError::NoSuchHealthCheck(parsed_error.message),
+                            ChangeResourceRecordSetsError::NoSuchHealthCheck(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
-                            ChangeResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
+                            ChangeResourceRecordSetsError::NoSuchHostedZone(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             ChangeResourceRecordSetsError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7598,6 +7630,17 @@ impl ChangeResourceRecordSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ChangeResourceRecordSetsError::InvalidChangeBatch(ref cause) => cause.request_id(),
+            ChangeResourceRecordSetsError::InvalidInput(ref cause) => cause.request_id(),
+            ChangeResourceRecordSetsError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+            ChangeResourceRecordSetsError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            ChangeResourceRecordSetsError::PriorRequestNotComplete(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ChangeResourceRecordSetsError {
     #[allow(unused_variables)]
@@ -7618,15 +7661,15 @@ impl Error for ChangeResourceRecordSetsError {}
 #[derive(Debug, PartialEq)]
 pub enum ChangeTagsForResourceError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
     /// <p>The limit on the number of requests per second was exceeded.</p>
-    Throttling(String),
+    Throttling(XmlErrorMessage),
 }
 
 impl ChangeTagsForResourceError {
@@ -7639,29 +7682,29 @@ impl ChangeTagsForResourceError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ChangeTagsForResourceError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(ChangeTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(ChangeTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             ChangeTagsForResourceError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ThrottlingException" => {
                         return RusotoError::Service(ChangeTagsForResourceError::Throttling(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7678,6 +7721,17 @@ impl ChangeTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ChangeTagsForResourceError::InvalidInput(ref cause) => cause.request_id(),
+            ChangeTagsForResourceError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+            ChangeTagsForResourceError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            ChangeTagsForResourceError::PriorRequestNotComplete(ref cause) => cause.request_id(),
+            ChangeTagsForResourceError::Throttling(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ChangeTagsForResourceError {
     #[allow(unused_variables)]
@@ -7698,11 +7752,11 @@ impl Error for ChangeTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateHealthCheckError {
     /// <p><p> The health check you&#39;re attempting to create already exists. Amazon Route 53 returns this error when you submit a request that has the following values:</p> <ul> <li> <p>The same value for <code>CallerReference</code> as an existing health check, and one or more values that differ from the existing health check that has the same caller reference.</p> </li> <li> <p>The same value for <code>CallerReference</code> as a health check that you created and later deleted, regardless of the other settings in the request.</p> </li> </ul></p>
-    HealthCheckAlreadyExists(String),
+    HealthCheckAlreadyExists(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>This health check can't be created because the current account has reached the limit on the number of active health checks.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>For information about how to get the current limit for an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p> <p>You have reached the maximum number of active health checks for an AWS account. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    TooManyHealthChecks(String),
+    TooManyHealthChecks(XmlErrorMessage),
 }
 
 impl CreateHealthCheckError {
@@ -7715,17 +7769,19 @@ impl CreateHealthCheckError {
                 match &parsed_error.code[..] {
                     "HealthCheckAlreadyExists" => {
                         return RusotoError::Service(
-                            CreateHealthCheckError::HealthCheckAlreadyExists(parsed_error.message),
+                            CreateHealthCheckError::HealthCheckAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateHealthCheckError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyHealthChecks" => {
                         return RusotoError::Service(CreateHealthCheckError::TooManyHealthChecks(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7742,6 +7798,15 @@ impl CreateHealthCheckError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateHealthCheckError::HealthCheckAlreadyExists(ref cause) => cause.request_id(),
+            CreateHealthCheckError::InvalidInput(ref cause) => cause.request_id(),
+            CreateHealthCheckError::TooManyHealthChecks(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateHealthCheckError {
     #[allow(unused_variables)]
@@ -7758,23 +7823,23 @@ impl Error for CreateHealthCheckError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateHostedZoneError {
     /// <p><p>The cause of this error depends on the operation that you&#39;re performing:</p> <ul> <li> <p> <b>Create a public hosted zone:</b> Two hosted zones that have the same name or that have a parent/child relationship (example.com and test.example.com) can&#39;t have any common name servers. You tried to create a hosted zone that has the same name as an existing hosted zone or that&#39;s the parent or child of an existing hosted zone, and you specified a delegation set that shares one or more name servers with the existing hosted zone. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateReusableDelegationSet.html">CreateReusableDelegationSet</a>.</p> </li> <li> <p> <b>Create a private hosted zone:</b> A hosted zone with the specified name already exists and is already associated with the Amazon VPC that you specified.</p> </li> <li> <p> <b>Associate VPCs with a private hosted zone:</b> The VPC that you specified is already associated with another hosted zone that has the same name.</p> </li> </ul></p>
-    ConflictingDomainExists(String),
+    ConflictingDomainExists(XmlErrorMessage),
     /// <p>You can create a hosted zone that has the same name as an existing hosted zone (example.com is common), but there is a limit to the number of hosted zones that have the same name. If you get this error, Amazon Route 53 has reached that limit. If you own the domain name and Route 53 generates this error, contact Customer Support.</p>
-    DelegationSetNotAvailable(String),
+    DelegationSetNotAvailable(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    DelegationSetNotReusable(String),
+    DelegationSetNotReusable(XmlErrorMessage),
     /// <p>The hosted zone you're trying to create already exists. Amazon Route 53 returns this error when a hosted zone has already been created with the specified <code>CallerReference</code>.</p>
-    HostedZoneAlreadyExists(String),
+    HostedZoneAlreadyExists(XmlErrorMessage),
     /// <p>The specified domain name is not valid.</p>
-    InvalidDomainName(String),
+    InvalidDomainName(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The VPC ID that you specified either isn't a valid ID or the current account is not authorized to access this VPC.</p>
-    InvalidVPCId(String),
+    InvalidVPCId(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    NoSuchDelegationSet(String),
+    NoSuchDelegationSet(XmlErrorMessage),
     /// <p>This operation can't be completed either because the current account has reached the limit on the number of hosted zones or because you've reached the limit on the number of hosted zones that can be associated with a reusable delegation set.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>To get the current limit on hosted zones that can be created by an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>.</p> <p>To get the current limit on hosted zones that can be associated with a reusable delegation set, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSetLimit.html">GetReusableDelegationSetLimit</a>.</p> <p>To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    TooManyHostedZones(String),
+    TooManyHostedZones(XmlErrorMessage),
 }
 
 impl CreateHostedZoneError {
@@ -7787,47 +7852,55 @@ impl CreateHostedZoneError {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
                         return RusotoError::Service(
-                            CreateHostedZoneError::ConflictingDomainExists(parsed_error.message),
+                            CreateHostedZoneError::ConflictingDomainExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DelegationSetNotAvailable" => {
                         return RusotoError::Service(
-                            CreateHostedZoneError::DelegationSetNotAvailable(parsed_error.message),
+                            CreateHostedZoneError::DelegationSetNotAvailable(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "DelegationSetNotReusable" => {
                         return RusotoError::Service(
-                            CreateHostedZoneError::DelegationSetNotReusable(parsed_error.message),
+                            CreateHostedZoneError::DelegationSetNotReusable(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "HostedZoneAlreadyExists" => {
                         return RusotoError::Service(
-                            CreateHostedZoneError::HostedZoneAlreadyExists(parsed_error.message),
+                            CreateHostedZoneError::HostedZoneAlreadyExists(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidDomainName" => {
                         return RusotoError::Service(CreateHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateHostedZoneError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidVPCId" => {
                         return RusotoError::Service(CreateHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDelegationSet" => {
                         return RusotoError::Service(CreateHostedZoneError::NoSuchDelegationSet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyHostedZones" => {
                         return RusotoError::Service(CreateHostedZoneError::TooManyHostedZones(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7844,6 +7917,21 @@ impl CreateHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateHostedZoneError::ConflictingDomainExists(ref cause) => cause.request_id(),
+            CreateHostedZoneError::DelegationSetNotAvailable(ref cause) => cause.request_id(),
+            CreateHostedZoneError::DelegationSetNotReusable(ref cause) => cause.request_id(),
+            CreateHostedZoneError::HostedZoneAlreadyExists(ref cause) => cause.request_id(),
+            CreateHostedZoneError::InvalidDomainName(ref cause) => cause.request_id(),
+            CreateHostedZoneError::InvalidInput(ref cause) => cause.request_id(),
+            CreateHostedZoneError::InvalidVPCId(ref cause) => cause.request_id(),
+            CreateHostedZoneError::NoSuchDelegationSet(ref cause) => cause.request_id(),
+            CreateHostedZoneError::TooManyHostedZones(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateHostedZoneError {
     #[allow(unused_variables)]
@@ -7866,17 +7954,17 @@ impl Error for CreateHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateQueryLoggingConfigError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p><p>Amazon Route 53 doesn&#39;t have the permissions required to create log streams and send query logs to log streams. Possible causes include the following:</p> <ul> <li> <p>There is no resource policy that specifies the log group ARN in the value for <code>Resource</code>.</p> </li> <li> <p>The resource policy that includes the log group ARN in the value for <code>Resource</code> doesn&#39;t have the necessary permissions.</p> </li> <li> <p>The resource policy hasn&#39;t finished propagating yet.</p> </li> </ul></p>
-    InsufficientCloudWatchLogsResourcePolicy(String),
+    InsufficientCloudWatchLogsResourcePolicy(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>There is no CloudWatch Logs log group with the specified ARN.</p>
-    NoSuchCloudWatchLogsLogGroup(String),
+    NoSuchCloudWatchLogsLogGroup(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>You can create only one query logging configuration for a hosted zone, and a query logging configuration already exists for this hosted zone.</p>
-    QueryLoggingConfigAlreadyExists(String),
+    QueryLoggingConfigAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateQueryLoggingConfigError {
@@ -7890,38 +7978,40 @@ impl CreateQueryLoggingConfigError {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
                             CreateQueryLoggingConfigError::ConcurrentModification(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InsufficientCloudWatchLogsResourcePolicy" => {
                         return RusotoError::Service(
                             CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(CreateQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchCloudWatchLogsLogGroup" => {
                         return RusotoError::Service(
                             CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
-                            CreateQueryLoggingConfigError::NoSuchHostedZone(parsed_error.message),
+                            CreateQueryLoggingConfigError::NoSuchHostedZone(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "QueryLoggingConfigAlreadyExists" => {
                         return RusotoError::Service(
                             CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7939,6 +8029,24 @@ impl CreateQueryLoggingConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateQueryLoggingConfigError::ConcurrentModification(ref cause) => cause.request_id(),
+            CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(ref cause) => {
+                cause.request_id()
+            }
+            CreateQueryLoggingConfigError::InvalidInput(ref cause) => cause.request_id(),
+            CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(ref cause) => {
+                cause.request_id()
+            }
+            CreateQueryLoggingConfigError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateQueryLoggingConfigError {
     #[allow(unused_variables)]
@@ -7966,19 +8074,19 @@ impl Error for CreateQueryLoggingConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateReusableDelegationSetError {
     /// <p>A delegation set with the same owner and caller reference combination has already been created.</p>
-    DelegationSetAlreadyCreated(String),
+    DelegationSetAlreadyCreated(XmlErrorMessage),
     /// <p>The specified delegation set has already been marked as reusable.</p>
-    DelegationSetAlreadyReusable(String),
+    DelegationSetAlreadyReusable(XmlErrorMessage),
     /// <p>You can create a hosted zone that has the same name as an existing hosted zone (example.com is common), but there is a limit to the number of hosted zones that have the same name. If you get this error, Amazon Route 53 has reached that limit. If you own the domain name and Route 53 generates this error, contact Customer Support.</p>
-    DelegationSetNotAvailable(String),
+    DelegationSetNotAvailable(XmlErrorMessage),
     /// <p>The specified HostedZone can't be found.</p>
-    HostedZoneNotFound(String),
+    HostedZoneNotFound(XmlErrorMessage),
     /// <p>Parameter name is invalid.</p>
-    InvalidArgument(String),
+    InvalidArgument(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>This operation can't be completed either because the current account has reached the limit on reusable delegation sets that it can create or because you've reached the limit on the number of Amazon VPCs that you can associate with a private hosted zone. To get the current limit on the number of reusable delegation sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. To get the current limit on the number of Amazon VPCs that you can associate with a private hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetHostedZoneLimit.html">GetHostedZoneLimit</a>. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    LimitsExceeded(String),
+    LimitsExceeded(XmlErrorMessage),
 }
 
 impl CreateReusableDelegationSetError {
@@ -7994,44 +8102,50 @@ impl CreateReusableDelegationSetError {
                     "DelegationSetAlreadyCreated" => {
                         return RusotoError::Service(
                             CreateReusableDelegationSetError::DelegationSetAlreadyCreated(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DelegationSetAlreadyReusable" => {
                         return RusotoError::Service(
                             CreateReusableDelegationSetError::DelegationSetAlreadyReusable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DelegationSetNotAvailable" => {
                         return RusotoError::Service(
                             CreateReusableDelegationSetError::DelegationSetNotAvailable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "HostedZoneNotFound" => {
                         return RusotoError::Service(
                             CreateReusableDelegationSetError::HostedZoneNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidArgument" => {
                         return RusotoError::Service(
-                            CreateReusableDelegationSetError::InvalidArgument(parsed_error.message),
+                            CreateReusableDelegationSetError::InvalidArgument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            CreateReusableDelegationSetError::InvalidInput(parsed_error.message),
+                            CreateReusableDelegationSetError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LimitsExceeded" => {
                         return RusotoError::Service(
-                            CreateReusableDelegationSetError::LimitsExceeded(parsed_error.message),
+                            CreateReusableDelegationSetError::LimitsExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8048,6 +8162,25 @@ impl CreateReusableDelegationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateReusableDelegationSetError::DelegationSetAlreadyCreated(ref cause) => {
+                cause.request_id()
+            }
+            CreateReusableDelegationSetError::DelegationSetAlreadyReusable(ref cause) => {
+                cause.request_id()
+            }
+            CreateReusableDelegationSetError::DelegationSetNotAvailable(ref cause) => {
+                cause.request_id()
+            }
+            CreateReusableDelegationSetError::HostedZoneNotFound(ref cause) => cause.request_id(),
+            CreateReusableDelegationSetError::InvalidArgument(ref cause) => cause.request_id(),
+            CreateReusableDelegationSetError::InvalidInput(ref cause) => cause.request_id(),
+            CreateReusableDelegationSetError::LimitsExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateReusableDelegationSetError {
     #[allow(unused_variables)]
@@ -8076,13 +8209,13 @@ impl Error for CreateReusableDelegationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The format of the traffic policy document that you specified in the <code>Document</code> element is invalid.</p>
-    InvalidTrafficPolicyDocument(String),
+    InvalidTrafficPolicyDocument(XmlErrorMessage),
     /// <p>This traffic policy can't be created because the current account has reached the limit on the number of traffic policies.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>To get the current limit for an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. </p> <p>To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    TooManyTrafficPolicies(String),
+    TooManyTrafficPolicies(XmlErrorMessage),
     /// <p>A traffic policy that has the same value for <code>Name</code> already exists.</p>
-    TrafficPolicyAlreadyExists(String),
+    TrafficPolicyAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateTrafficPolicyError {
@@ -8095,25 +8228,27 @@ impl CreateTrafficPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(CreateTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTrafficPolicyDocument" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyError::InvalidTrafficPolicyDocument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTrafficPolicies" => {
                         return RusotoError::Service(
-                            CreateTrafficPolicyError::TooManyTrafficPolicies(parsed_error.message),
+                            CreateTrafficPolicyError::TooManyTrafficPolicies(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "TrafficPolicyAlreadyExists" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyError::TrafficPolicyAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8131,6 +8266,16 @@ impl CreateTrafficPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTrafficPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            CreateTrafficPolicyError::InvalidTrafficPolicyDocument(ref cause) => cause.request_id(),
+            CreateTrafficPolicyError::TooManyTrafficPolicies(ref cause) => cause.request_id(),
+            CreateTrafficPolicyError::TrafficPolicyAlreadyExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTrafficPolicyError {
     #[allow(unused_variables)]
@@ -8152,15 +8297,15 @@ impl Error for CreateTrafficPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
     /// <p>This traffic policy instance can't be created because the current account has reached the limit on the number of traffic policy instances.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>For information about how to get the current limit for an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>.</p> <p>To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
-    TooManyTrafficPolicyInstances(String),
+    TooManyTrafficPolicyInstances(XmlErrorMessage),
     /// <p>There is already a traffic policy instance with the specified ID.</p>
-    TrafficPolicyInstanceAlreadyExists(String),
+    TrafficPolicyInstanceAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateTrafficPolicyInstanceError {
@@ -8175,34 +8320,36 @@ impl CreateTrafficPolicyInstanceError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            CreateTrafficPolicyInstanceError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyInstanceError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyTrafficPolicyInstances" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TrafficPolicyInstanceAlreadyExists" => {
                         return RusotoError::Service(
                             CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8220,6 +8367,21 @@ impl CreateTrafficPolicyInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTrafficPolicyInstanceError::InvalidInput(ref cause) => cause.request_id(),
+            CreateTrafficPolicyInstanceError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+            CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(ref cause) => {
+                cause.request_id()
+            }
+            CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateTrafficPolicyInstanceError {
     #[allow(unused_variables)]
@@ -8244,15 +8406,15 @@ impl Error for CreateTrafficPolicyInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyVersionError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The format of the traffic policy document that you specified in the <code>Document</code> element is invalid.</p>
-    InvalidTrafficPolicyDocument(String),
+    InvalidTrafficPolicyDocument(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
     /// <p>This traffic policy version can't be created because you've reached the limit of 1000 on the number of versions that you can create for the current traffic policy.</p> <p>To create more traffic policy versions, you can use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetTrafficPolicy.html">GetTrafficPolicy</a> to get the traffic policy document for a specified traffic policy version, and then use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateTrafficPolicy.html">CreateTrafficPolicy</a> to create a new traffic policy using the traffic policy document.</p>
-    TooManyTrafficPolicyVersionsForCurrentPolicy(String),
+    TooManyTrafficPolicyVersionsForCurrentPolicy(XmlErrorMessage),
 }
 
 impl CreateTrafficPolicyVersionError {
@@ -8265,7 +8427,7 @@ impl CreateTrafficPolicyVersionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConcurrentModification" => return RusotoError::Service(CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message)),"InvalidInput" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message)),"InvalidTrafficPolicyDocument" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message)),"NoSuchTrafficPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message)),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message)),_ => {}
+                                    "ConcurrentModification" => return RusotoError::Service(CreateTrafficPolicyVersionError::ConcurrentModification(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidInput" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidInput(XmlErrorMessage::from_response(parsed_error.message, &res))),"InvalidTrafficPolicyDocument" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(XmlErrorMessage::from_response(parsed_error.message, &res))),"NoSuchTrafficPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(XmlErrorMessage::from_response(parsed_error.message, &res))),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8279,6 +8441,23 @@ impl CreateTrafficPolicyVersionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTrafficPolicyVersionError::ConcurrentModification(ref cause) => {
+                cause.request_id()
+            }
+            CreateTrafficPolicyVersionError::InvalidInput(ref cause) => cause.request_id(),
+            CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(ref cause) => {
+                cause.request_id()
+            }
+            CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+            CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTrafficPolicyVersionError {
     #[allow(unused_variables)]
@@ -8305,15 +8484,15 @@ impl Error for CreateTrafficPolicyVersionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateVPCAssociationAuthorizationError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The VPC ID that you specified either isn't a valid ID or the current account is not authorized to access this VPC.</p>
-    InvalidVPCId(String),
+    InvalidVPCId(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>You've created the maximum number of authorizations that can be created for the specified hosted zone. To authorize another VPC to be associated with the hosted zone, submit a <code>DeleteVPCAssociationAuthorization</code> request to remove an existing authorization. To get a list of existing authorizations, submit a <code>ListVPCAssociationAuthorizations</code> request.</p>
-    TooManyVPCAssociationAuthorizations(String),
+    TooManyVPCAssociationAuthorizations(XmlErrorMessage),
 }
 
 impl CreateVPCAssociationAuthorizationError {
@@ -8329,34 +8508,34 @@ impl CreateVPCAssociationAuthorizationError {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
                             CreateVPCAssociationAuthorizationError::ConcurrentModification(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
                             CreateVPCAssociationAuthorizationError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCId" => {
                         return RusotoError::Service(
                             CreateVPCAssociationAuthorizationError::InvalidVPCId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             CreateVPCAssociationAuthorizationError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TooManyVPCAssociationAuthorizations" => return RusotoError::Service(
                         CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -8373,6 +8552,23 @@ impl CreateVPCAssociationAuthorizationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateVPCAssociationAuthorizationError::ConcurrentModification(ref cause) => {
+                cause.request_id()
+            }
+            CreateVPCAssociationAuthorizationError::InvalidInput(ref cause) => cause.request_id(),
+            CreateVPCAssociationAuthorizationError::InvalidVPCId(ref cause) => cause.request_id(),
+            CreateVPCAssociationAuthorizationError::NoSuchHostedZone(ref cause) => {
+                cause.request_id()
+            }
+            CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateVPCAssociationAuthorizationError {
     #[allow(unused_variables)]
@@ -8401,11 +8597,11 @@ impl Error for CreateVPCAssociationAuthorizationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteHealthCheckError {
     /// <p>This error code is not in use.</p>
-    HealthCheckInUse(String),
+    HealthCheckInUse(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
 }
 
 impl DeleteHealthCheckError {
@@ -8418,17 +8614,17 @@ impl DeleteHealthCheckError {
                 match &parsed_error.code[..] {
                     "HealthCheckInUse" => {
                         return RusotoError::Service(DeleteHealthCheckError::HealthCheckInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeleteHealthCheckError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(DeleteHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8445,6 +8641,15 @@ impl DeleteHealthCheckError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteHealthCheckError::HealthCheckInUse(ref cause) => cause.request_id(),
+            DeleteHealthCheckError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteHealthCheckError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteHealthCheckError {
     #[allow(unused_variables)]
@@ -8461,15 +8666,15 @@ impl Error for DeleteHealthCheckError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteHostedZoneError {
     /// <p>The hosted zone contains resource records that are not SOA or NS records.</p>
-    HostedZoneNotEmpty(String),
+    HostedZoneNotEmpty(XmlErrorMessage),
     /// <p>The specified domain name is not valid.</p>
-    InvalidDomainName(String),
+    InvalidDomainName(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
 }
 
 impl DeleteHostedZoneError {
@@ -8482,27 +8687,29 @@ impl DeleteHostedZoneError {
                 match &parsed_error.code[..] {
                     "HostedZoneNotEmpty" => {
                         return RusotoError::Service(DeleteHostedZoneError::HostedZoneNotEmpty(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidDomainName" => {
                         return RusotoError::Service(DeleteHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeleteHostedZoneError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(DeleteHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
-                            DeleteHostedZoneError::PriorRequestNotComplete(parsed_error.message),
+                            DeleteHostedZoneError::PriorRequestNotComplete(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8519,6 +8726,17 @@ impl DeleteHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteHostedZoneError::HostedZoneNotEmpty(ref cause) => cause.request_id(),
+            DeleteHostedZoneError::InvalidDomainName(ref cause) => cause.request_id(),
+            DeleteHostedZoneError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteHostedZoneError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            DeleteHostedZoneError::PriorRequestNotComplete(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteHostedZoneError {
     #[allow(unused_variables)]
@@ -8537,11 +8755,11 @@ impl Error for DeleteHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueryLoggingConfigError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>There is no DNS query logging configuration with the specified ID.</p>
-    NoSuchQueryLoggingConfig(String),
+    NoSuchQueryLoggingConfig(XmlErrorMessage),
 }
 
 impl DeleteQueryLoggingConfigError {
@@ -8555,19 +8773,19 @@ impl DeleteQueryLoggingConfigError {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
                             DeleteQueryLoggingConfigError::ConcurrentModification(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeleteQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchQueryLoggingConfig" => {
                         return RusotoError::Service(
                             DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8585,6 +8803,17 @@ impl DeleteQueryLoggingConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteQueryLoggingConfigError::ConcurrentModification(ref cause) => cause.request_id(),
+            DeleteQueryLoggingConfigError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteQueryLoggingConfigError {
     #[allow(unused_variables)]
@@ -8605,13 +8834,13 @@ impl Error for DeleteQueryLoggingConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteReusableDelegationSetError {
     /// <p>The specified delegation contains associated hosted zones which must be deleted before the reusable delegation set can be deleted.</p>
-    DelegationSetInUse(String),
+    DelegationSetInUse(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    DelegationSetNotReusable(String),
+    DelegationSetNotReusable(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    NoSuchDelegationSet(String),
+    NoSuchDelegationSet(XmlErrorMessage),
 }
 
 impl DeleteReusableDelegationSetError {
@@ -8627,26 +8856,28 @@ impl DeleteReusableDelegationSetError {
                     "DelegationSetInUse" => {
                         return RusotoError::Service(
                             DeleteReusableDelegationSetError::DelegationSetInUse(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "DelegationSetNotReusable" => {
                         return RusotoError::Service(
                             DeleteReusableDelegationSetError::DelegationSetNotReusable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            DeleteReusableDelegationSetError::InvalidInput(parsed_error.message),
+                            DeleteReusableDelegationSetError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchDelegationSet" => {
                         return RusotoError::Service(
                             DeleteReusableDelegationSetError::NoSuchDelegationSet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8664,6 +8895,18 @@ impl DeleteReusableDelegationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteReusableDelegationSetError::DelegationSetInUse(ref cause) => cause.request_id(),
+            DeleteReusableDelegationSetError::DelegationSetNotReusable(ref cause) => {
+                cause.request_id()
+            }
+            DeleteReusableDelegationSetError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteReusableDelegationSetError::NoSuchDelegationSet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteReusableDelegationSetError {
     #[allow(unused_variables)]
@@ -8687,13 +8930,13 @@ impl Error for DeleteReusableDelegationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficPolicyError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
     /// <p>One or more traffic policy instances were created by using the specified traffic policy.</p>
-    TrafficPolicyInUse(String),
+    TrafficPolicyInUse(XmlErrorMessage),
 }
 
 impl DeleteTrafficPolicyError {
@@ -8706,22 +8949,24 @@ impl DeleteTrafficPolicyError {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
-                            DeleteTrafficPolicyError::ConcurrentModification(parsed_error.message),
+                            DeleteTrafficPolicyError::ConcurrentModification(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(DeleteTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(DeleteTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TrafficPolicyInUse" => {
                         return RusotoError::Service(DeleteTrafficPolicyError::TrafficPolicyInUse(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8738,6 +8983,16 @@ impl DeleteTrafficPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTrafficPolicyError::ConcurrentModification(ref cause) => cause.request_id(),
+            DeleteTrafficPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteTrafficPolicyError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+            DeleteTrafficPolicyError::TrafficPolicyInUse(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteTrafficPolicyError {
     #[allow(unused_variables)]
@@ -8755,11 +9010,11 @@ impl Error for DeleteTrafficPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
 }
 
 impl DeleteTrafficPolicyInstanceError {
@@ -8774,20 +9029,22 @@ impl DeleteTrafficPolicyInstanceError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            DeleteTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            DeleteTrafficPolicyInstanceError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
                         return RusotoError::Service(
                             DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8805,6 +9062,19 @@ impl DeleteTrafficPolicyInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTrafficPolicyInstanceError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+            DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteTrafficPolicyInstanceError {
     #[allow(unused_variables)]
@@ -8825,15 +9095,15 @@ impl Error for DeleteTrafficPolicyInstanceError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteVPCAssociationAuthorizationError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The VPC ID that you specified either isn't a valid ID or the current account is not authorized to access this VPC.</p>
-    InvalidVPCId(String),
+    InvalidVPCId(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>The VPC that you specified is not authorized to be associated with the hosted zone.</p>
-    VPCAssociationAuthorizationNotFound(String),
+    VPCAssociationAuthorizationNotFound(XmlErrorMessage),
 }
 
 impl DeleteVPCAssociationAuthorizationError {
@@ -8849,34 +9119,34 @@ impl DeleteVPCAssociationAuthorizationError {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
                             DeleteVPCAssociationAuthorizationError::ConcurrentModification(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
                             DeleteVPCAssociationAuthorizationError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidVPCId" => {
                         return RusotoError::Service(
                             DeleteVPCAssociationAuthorizationError::InvalidVPCId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "VPCAssociationAuthorizationNotFound" => return RusotoError::Service(
                         DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -8893,6 +9163,23 @@ impl DeleteVPCAssociationAuthorizationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteVPCAssociationAuthorizationError::ConcurrentModification(ref cause) => {
+                cause.request_id()
+            }
+            DeleteVPCAssociationAuthorizationError::InvalidInput(ref cause) => cause.request_id(),
+            DeleteVPCAssociationAuthorizationError::InvalidVPCId(ref cause) => cause.request_id(),
+            DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(ref cause) => {
+                cause.request_id()
+            }
+            DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteVPCAssociationAuthorizationError {
     #[allow(unused_variables)]
@@ -8921,15 +9208,15 @@ impl Error for DeleteVPCAssociationAuthorizationError {}
 #[derive(Debug, PartialEq)]
 pub enum DisassociateVPCFromHostedZoneError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The VPC ID that you specified either isn't a valid ID or the current account is not authorized to access this VPC.</p>
-    InvalidVPCId(String),
+    InvalidVPCId(XmlErrorMessage),
     /// <p>The VPC that you're trying to disassociate from the private hosted zone is the last VPC that is associated with the hosted zone. Amazon Route 53 doesn't support disassociating the last VPC from a hosted zone.</p>
-    LastVPCAssociation(String),
+    LastVPCAssociation(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>The specified VPC and hosted zone are not currently associated.</p>
-    VPCAssociationNotFound(String),
+    VPCAssociationNotFound(XmlErrorMessage),
 }
 
 impl DisassociateVPCFromHostedZoneError {
@@ -8944,32 +9231,36 @@ impl DisassociateVPCFromHostedZoneError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::InvalidInput(parsed_error.message),
+                            DisassociateVPCFromHostedZoneError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidVPCId" => {
                         return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::InvalidVPCId(parsed_error.message),
+                            DisassociateVPCFromHostedZoneError::InvalidVPCId(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "LastVPCAssociation" => {
                         return RusotoError::Service(
                             DisassociateVPCFromHostedZoneError::LastVPCAssociation(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             DisassociateVPCFromHostedZoneError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "VPCAssociationNotFound" => {
                         return RusotoError::Service(
                             DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8987,6 +9278,19 @@ impl DisassociateVPCFromHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DisassociateVPCFromHostedZoneError::InvalidInput(ref cause) => cause.request_id(),
+            DisassociateVPCFromHostedZoneError::InvalidVPCId(ref cause) => cause.request_id(),
+            DisassociateVPCFromHostedZoneError::LastVPCAssociation(ref cause) => cause.request_id(),
+            DisassociateVPCFromHostedZoneError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DisassociateVPCFromHostedZoneError {
     #[allow(unused_variables)]
@@ -9011,7 +9315,7 @@ impl Error for DisassociateVPCFromHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum GetAccountLimitError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl GetAccountLimitError {
@@ -9024,7 +9328,7 @@ impl GetAccountLimitError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetAccountLimitError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9041,6 +9345,13 @@ impl GetAccountLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetAccountLimitError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetAccountLimitError {
     #[allow(unused_variables)]
@@ -9055,9 +9366,9 @@ impl Error for GetAccountLimitError {}
 #[derive(Debug, PartialEq)]
 pub enum GetChangeError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>A change with the specified change ID does not exist.</p>
-    NoSuchChange(String),
+    NoSuchChange(XmlErrorMessage),
 }
 
 impl GetChangeError {
@@ -9070,12 +9381,12 @@ impl GetChangeError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetChangeError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchChange" => {
                         return RusotoError::Service(GetChangeError::NoSuchChange(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9092,6 +9403,14 @@ impl GetChangeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetChangeError::InvalidInput(ref cause) => cause.request_id(),
+            GetChangeError::NoSuchChange(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetChangeError {
     #[allow(unused_variables)]
@@ -9129,6 +9448,11 @@ impl GetCheckerIpRangesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetCheckerIpRangesError {
     #[allow(unused_variables)]
@@ -9141,9 +9465,9 @@ impl Error for GetCheckerIpRangesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetGeoLocationError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>Amazon Route 53 doesn't support the specified geographic location. For a list of supported geolocation codes, see the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GeoLocation.html">GeoLocation</a> data type.</p>
-    NoSuchGeoLocation(String),
+    NoSuchGeoLocation(XmlErrorMessage),
 }
 
 impl GetGeoLocationError {
@@ -9156,12 +9480,12 @@ impl GetGeoLocationError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetGeoLocationError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchGeoLocation" => {
                         return RusotoError::Service(GetGeoLocationError::NoSuchGeoLocation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9178,6 +9502,14 @@ impl GetGeoLocationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetGeoLocationError::InvalidInput(ref cause) => cause.request_id(),
+            GetGeoLocationError::NoSuchGeoLocation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetGeoLocationError {
     #[allow(unused_variables)]
@@ -9193,11 +9525,11 @@ impl Error for GetGeoLocationError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckError {
     /// <p>The resource you're trying to access is unsupported on this Amazon Route 53 endpoint.</p>
-    IncompatibleVersion(String),
+    IncompatibleVersion(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
 }
 
 impl GetHealthCheckError {
@@ -9210,17 +9542,17 @@ impl GetHealthCheckError {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
                         return RusotoError::Service(GetHealthCheckError::IncompatibleVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(GetHealthCheckError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(GetHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9237,6 +9569,15 @@ impl GetHealthCheckError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHealthCheckError::IncompatibleVersion(ref cause) => cause.request_id(),
+            GetHealthCheckError::InvalidInput(ref cause) => cause.request_id(),
+            GetHealthCheckError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetHealthCheckError {
     #[allow(unused_variables)]
@@ -9275,6 +9616,11 @@ impl GetHealthCheckCountError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetHealthCheckCountError {
     #[allow(unused_variables)]
@@ -9287,9 +9633,9 @@ impl Error for GetHealthCheckCountError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckLastFailureReasonError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
 }
 
 impl GetHealthCheckLastFailureReasonError {
@@ -9305,14 +9651,14 @@ impl GetHealthCheckLastFailureReasonError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             GetHealthCheckLastFailureReasonError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(
                             GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9330,6 +9676,16 @@ impl GetHealthCheckLastFailureReasonError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHealthCheckLastFailureReasonError::InvalidInput(ref cause) => cause.request_id(),
+            GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetHealthCheckLastFailureReasonError {
     #[allow(unused_variables)]
@@ -9347,9 +9703,9 @@ impl Error for GetHealthCheckLastFailureReasonError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckStatusError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
 }
 
 impl GetHealthCheckStatusError {
@@ -9362,12 +9718,12 @@ impl GetHealthCheckStatusError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetHealthCheckStatusError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(GetHealthCheckStatusError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9384,6 +9740,14 @@ impl GetHealthCheckStatusError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHealthCheckStatusError::InvalidInput(ref cause) => cause.request_id(),
+            GetHealthCheckStatusError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetHealthCheckStatusError {
     #[allow(unused_variables)]
@@ -9399,9 +9763,9 @@ impl Error for GetHealthCheckStatusError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl GetHostedZoneError {
@@ -9414,12 +9778,12 @@ impl GetHostedZoneError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetHostedZoneError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(GetHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9436,6 +9800,14 @@ impl GetHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHostedZoneError::InvalidInput(ref cause) => cause.request_id(),
+            GetHostedZoneError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetHostedZoneError {
     #[allow(unused_variables)]
@@ -9451,7 +9823,7 @@ impl Error for GetHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneCountError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl GetHostedZoneCountError {
@@ -9464,7 +9836,7 @@ impl GetHostedZoneCountError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetHostedZoneCountError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9481,6 +9853,13 @@ impl GetHostedZoneCountError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHostedZoneCountError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetHostedZoneCountError {
     #[allow(unused_variables)]
@@ -9495,11 +9874,11 @@ impl Error for GetHostedZoneCountError {}
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneLimitError {
     /// <p>The specified hosted zone is a public hosted zone, not a private hosted zone.</p>
-    HostedZoneNotPrivate(String),
+    HostedZoneNotPrivate(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl GetHostedZoneLimitError {
@@ -9512,17 +9891,17 @@ impl GetHostedZoneLimitError {
                 match &parsed_error.code[..] {
                     "HostedZoneNotPrivate" => {
                         return RusotoError::Service(GetHostedZoneLimitError::HostedZoneNotPrivate(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(GetHostedZoneLimitError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(GetHostedZoneLimitError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9539,6 +9918,15 @@ impl GetHostedZoneLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetHostedZoneLimitError::HostedZoneNotPrivate(ref cause) => cause.request_id(),
+            GetHostedZoneLimitError::InvalidInput(ref cause) => cause.request_id(),
+            GetHostedZoneLimitError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetHostedZoneLimitError {
     #[allow(unused_variables)]
@@ -9555,9 +9943,9 @@ impl Error for GetHostedZoneLimitError {}
 #[derive(Debug, PartialEq)]
 pub enum GetQueryLoggingConfigError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>There is no DNS query logging configuration with the specified ID.</p>
-    NoSuchQueryLoggingConfig(String),
+    NoSuchQueryLoggingConfig(XmlErrorMessage),
 }
 
 impl GetQueryLoggingConfigError {
@@ -9570,13 +9958,13 @@ impl GetQueryLoggingConfigError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchQueryLoggingConfig" => {
                         return RusotoError::Service(
                             GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9594,6 +9982,14 @@ impl GetQueryLoggingConfigError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetQueryLoggingConfigError::InvalidInput(ref cause) => cause.request_id(),
+            GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetQueryLoggingConfigError {
     #[allow(unused_variables)]
@@ -9611,11 +10007,11 @@ impl Error for GetQueryLoggingConfigError {}
 #[derive(Debug, PartialEq)]
 pub enum GetReusableDelegationSetError {
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    DelegationSetNotReusable(String),
+    DelegationSetNotReusable(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    NoSuchDelegationSet(String),
+    NoSuchDelegationSet(XmlErrorMessage),
 }
 
 impl GetReusableDelegationSetError {
@@ -9629,19 +10025,19 @@ impl GetReusableDelegationSetError {
                     "DelegationSetNotReusable" => {
                         return RusotoError::Service(
                             GetReusableDelegationSetError::DelegationSetNotReusable(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(GetReusableDelegationSetError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDelegationSet" => {
                         return RusotoError::Service(
                             GetReusableDelegationSetError::NoSuchDelegationSet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9659,6 +10055,17 @@ impl GetReusableDelegationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetReusableDelegationSetError::DelegationSetNotReusable(ref cause) => {
+                cause.request_id()
+            }
+            GetReusableDelegationSetError::InvalidInput(ref cause) => cause.request_id(),
+            GetReusableDelegationSetError::NoSuchDelegationSet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetReusableDelegationSetError {
     #[allow(unused_variables)]
@@ -9677,9 +10084,9 @@ impl Error for GetReusableDelegationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum GetReusableDelegationSetLimitError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    NoSuchDelegationSet(String),
+    NoSuchDelegationSet(XmlErrorMessage),
 }
 
 impl GetReusableDelegationSetLimitError {
@@ -9694,13 +10101,15 @@ impl GetReusableDelegationSetLimitError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            GetReusableDelegationSetLimitError::InvalidInput(parsed_error.message),
+                            GetReusableDelegationSetLimitError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchDelegationSet" => {
                         return RusotoError::Service(
                             GetReusableDelegationSetLimitError::NoSuchDelegationSet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9718,6 +10127,16 @@ impl GetReusableDelegationSetLimitError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetReusableDelegationSetLimitError::InvalidInput(ref cause) => cause.request_id(),
+            GetReusableDelegationSetLimitError::NoSuchDelegationSet(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetReusableDelegationSetLimitError {
     #[allow(unused_variables)]
@@ -9735,9 +10154,9 @@ impl Error for GetReusableDelegationSetLimitError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTrafficPolicyError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
 }
 
 impl GetTrafficPolicyError {
@@ -9750,12 +10169,12 @@ impl GetTrafficPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(GetTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9772,6 +10191,14 @@ impl GetTrafficPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTrafficPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            GetTrafficPolicyError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetTrafficPolicyError {
     #[allow(unused_variables)]
@@ -9787,9 +10214,9 @@ impl Error for GetTrafficPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
 }
 
 impl GetTrafficPolicyInstanceError {
@@ -9802,13 +10229,13 @@ impl GetTrafficPolicyInstanceError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(GetTrafficPolicyInstanceError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicyInstance" => {
                         return RusotoError::Service(
                             GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9826,6 +10253,16 @@ impl GetTrafficPolicyInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTrafficPolicyInstanceError::InvalidInput(ref cause) => cause.request_id(),
+            GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for GetTrafficPolicyInstanceError {
     #[allow(unused_variables)]
@@ -9867,6 +10304,11 @@ impl GetTrafficPolicyInstanceCountError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetTrafficPolicyInstanceCountError {
     #[allow(unused_variables)]
@@ -9879,7 +10321,7 @@ impl Error for GetTrafficPolicyInstanceCountError {}
 #[derive(Debug, PartialEq)]
 pub enum ListGeoLocationsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl ListGeoLocationsError {
@@ -9892,7 +10334,7 @@ impl ListGeoLocationsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListGeoLocationsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9909,6 +10351,13 @@ impl ListGeoLocationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListGeoLocationsError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListGeoLocationsError {
     #[allow(unused_variables)]
@@ -9923,9 +10372,9 @@ impl Error for ListGeoLocationsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListHealthChecksError {
     /// <p>The resource you're trying to access is unsupported on this Amazon Route 53 endpoint.</p>
-    IncompatibleVersion(String),
+    IncompatibleVersion(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl ListHealthChecksError {
@@ -9938,12 +10387,12 @@ impl ListHealthChecksError {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
                         return RusotoError::Service(ListHealthChecksError::IncompatibleVersion(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(ListHealthChecksError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9960,6 +10409,14 @@ impl ListHealthChecksError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListHealthChecksError::IncompatibleVersion(ref cause) => cause.request_id(),
+            ListHealthChecksError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListHealthChecksError {
     #[allow(unused_variables)]
@@ -9975,11 +10432,11 @@ impl Error for ListHealthChecksError {}
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesError {
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    DelegationSetNotReusable(String),
+    DelegationSetNotReusable(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
-    NoSuchDelegationSet(String),
+    NoSuchDelegationSet(XmlErrorMessage),
 }
 
 impl ListHostedZonesError {
@@ -9992,17 +10449,19 @@ impl ListHostedZonesError {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
                         return RusotoError::Service(
-                            ListHostedZonesError::DelegationSetNotReusable(parsed_error.message),
+                            ListHostedZonesError::DelegationSetNotReusable(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(ListHostedZonesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDelegationSet" => {
                         return RusotoError::Service(ListHostedZonesError::NoSuchDelegationSet(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10019,6 +10478,15 @@ impl ListHostedZonesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListHostedZonesError::DelegationSetNotReusable(ref cause) => cause.request_id(),
+            ListHostedZonesError::InvalidInput(ref cause) => cause.request_id(),
+            ListHostedZonesError::NoSuchDelegationSet(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListHostedZonesError {
     #[allow(unused_variables)]
@@ -10035,9 +10503,9 @@ impl Error for ListHostedZonesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesByNameError {
     /// <p>The specified domain name is not valid.</p>
-    InvalidDomainName(String),
+    InvalidDomainName(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl ListHostedZonesByNameError {
@@ -10050,12 +10518,12 @@ impl ListHostedZonesByNameError {
                 match &parsed_error.code[..] {
                     "InvalidDomainName" => {
                         return RusotoError::Service(ListHostedZonesByNameError::InvalidDomainName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(ListHostedZonesByNameError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10072,6 +10540,14 @@ impl ListHostedZonesByNameError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListHostedZonesByNameError::InvalidDomainName(ref cause) => cause.request_id(),
+            ListHostedZonesByNameError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListHostedZonesByNameError {
     #[allow(unused_variables)]
@@ -10087,9 +10563,9 @@ impl Error for ListHostedZonesByNameError {}
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesByVPCError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The value that you specified to get the second or subsequent page of results is invalid.</p>
-    InvalidPaginationToken(String),
+    InvalidPaginationToken(XmlErrorMessage),
 }
 
 impl ListHostedZonesByVPCError {
@@ -10102,12 +10578,14 @@ impl ListHostedZonesByVPCError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListHostedZonesByVPCError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidPaginationToken" => {
                         return RusotoError::Service(
-                            ListHostedZonesByVPCError::InvalidPaginationToken(parsed_error.message),
+                            ListHostedZonesByVPCError::InvalidPaginationToken(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10124,6 +10602,14 @@ impl ListHostedZonesByVPCError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListHostedZonesByVPCError::InvalidInput(ref cause) => cause.request_id(),
+            ListHostedZonesByVPCError::InvalidPaginationToken(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListHostedZonesByVPCError {
     #[allow(unused_variables)]
@@ -10139,11 +10625,11 @@ impl Error for ListHostedZonesByVPCError {}
 #[derive(Debug, PartialEq)]
 pub enum ListQueryLoggingConfigsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The value that you specified to get the second or subsequent page of results is invalid.</p>
-    InvalidPaginationToken(String),
+    InvalidPaginationToken(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl ListQueryLoggingConfigsError {
@@ -10156,19 +10642,21 @@ impl ListQueryLoggingConfigsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListQueryLoggingConfigsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidPaginationToken" => {
                         return RusotoError::Service(
                             ListQueryLoggingConfigsError::InvalidPaginationToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
-                            ListQueryLoggingConfigsError::NoSuchHostedZone(parsed_error.message),
+                            ListQueryLoggingConfigsError::NoSuchHostedZone(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10185,6 +10673,15 @@ impl ListQueryLoggingConfigsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListQueryLoggingConfigsError::InvalidInput(ref cause) => cause.request_id(),
+            ListQueryLoggingConfigsError::InvalidPaginationToken(ref cause) => cause.request_id(),
+            ListQueryLoggingConfigsError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListQueryLoggingConfigsError {
     #[allow(unused_variables)]
@@ -10203,9 +10700,9 @@ impl Error for ListQueryLoggingConfigsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListResourceRecordSetsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl ListResourceRecordSetsError {
@@ -10218,12 +10715,12 @@ impl ListResourceRecordSetsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10240,6 +10737,14 @@ impl ListResourceRecordSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListResourceRecordSetsError::InvalidInput(ref cause) => cause.request_id(),
+            ListResourceRecordSetsError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListResourceRecordSetsError {
     #[allow(unused_variables)]
@@ -10255,7 +10760,7 @@ impl Error for ListResourceRecordSetsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListReusableDelegationSetsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl ListReusableDelegationSetsError {
@@ -10270,7 +10775,7 @@ impl ListReusableDelegationSetsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListReusableDelegationSetsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10287,6 +10792,13 @@ impl ListReusableDelegationSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListReusableDelegationSetsError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListReusableDelegationSetsError {
     #[allow(unused_variables)]
@@ -10301,15 +10813,15 @@ impl Error for ListReusableDelegationSetsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
     /// <p>The limit on the number of requests per second was exceeded.</p>
-    Throttling(String),
+    Throttling(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -10322,27 +10834,29 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListTagsForResourceError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(ListTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(ListTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
-                            ListTagsForResourceError::PriorRequestNotComplete(parsed_error.message),
+                            ListTagsForResourceError::PriorRequestNotComplete(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ThrottlingException" => {
                         return RusotoError::Service(ListTagsForResourceError::Throttling(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10359,6 +10873,17 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::InvalidInput(ref cause) => cause.request_id(),
+            ListTagsForResourceError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+            ListTagsForResourceError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            ListTagsForResourceError::PriorRequestNotComplete(ref cause) => cause.request_id(),
+            ListTagsForResourceError::Throttling(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -10377,15 +10902,15 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourcesError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
     /// <p>The limit on the number of requests per second was exceeded.</p>
-    Throttling(String),
+    Throttling(XmlErrorMessage),
 }
 
 impl ListTagsForResourcesError {
@@ -10398,29 +10923,29 @@ impl ListTagsForResourcesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListTagsForResourcesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(ListTagsForResourcesError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(ListTagsForResourcesError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             ListTagsForResourcesError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ThrottlingException" => {
                         return RusotoError::Service(ListTagsForResourcesError::Throttling(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10437,6 +10962,17 @@ impl ListTagsForResourcesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourcesError::InvalidInput(ref cause) => cause.request_id(),
+            ListTagsForResourcesError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+            ListTagsForResourcesError::NoSuchHostedZone(ref cause) => cause.request_id(),
+            ListTagsForResourcesError::PriorRequestNotComplete(ref cause) => cause.request_id(),
+            ListTagsForResourcesError::Throttling(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourcesError {
     #[allow(unused_variables)]
@@ -10455,7 +10991,7 @@ impl Error for ListTagsForResourcesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPoliciesError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
 }
 
 impl ListTrafficPoliciesError {
@@ -10468,7 +11004,7 @@ impl ListTrafficPoliciesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListTrafficPoliciesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10485,6 +11021,13 @@ impl ListTrafficPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTrafficPoliciesError::InvalidInput(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTrafficPoliciesError {
     #[allow(unused_variables)]
@@ -10499,9 +11042,9 @@ impl Error for ListTrafficPoliciesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
 }
 
 impl ListTrafficPolicyInstancesError {
@@ -10516,13 +11059,13 @@ impl ListTrafficPolicyInstancesError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListTrafficPolicyInstancesError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicyInstance" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10540,6 +11083,16 @@ impl ListTrafficPolicyInstancesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTrafficPolicyInstancesError::InvalidInput(ref cause) => cause.request_id(),
+            ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListTrafficPolicyInstancesError {
     #[allow(unused_variables)]
@@ -10557,11 +11110,11 @@ impl Error for ListTrafficPolicyInstancesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesByHostedZoneError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
 }
 
 impl ListTrafficPolicyInstancesByHostedZoneError {
@@ -10577,20 +11130,20 @@ impl ListTrafficPolicyInstancesByHostedZoneError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => return RusotoError::Service(
                         ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -10607,6 +11160,21 @@ impl ListTrafficPolicyInstancesByHostedZoneError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(ref cause) => {
+                cause.request_id()
+            }
+            ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(ref cause) => {
+                cause.request_id()
+            }
+            ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListTrafficPolicyInstancesByHostedZoneError {
     #[allow(unused_variables)]
@@ -10629,11 +11197,11 @@ impl Error for ListTrafficPolicyInstancesByHostedZoneError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesByPolicyError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
 }
 
 impl ListTrafficPolicyInstancesByPolicyError {
@@ -10649,21 +11217,21 @@ impl ListTrafficPolicyInstancesByPolicyError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesByPolicyError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
                         return RusotoError::Service(
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10681,6 +11249,19 @@ impl ListTrafficPolicyInstancesByPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTrafficPolicyInstancesByPolicyError::InvalidInput(ref cause) => cause.request_id(),
+            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(ref cause) => {
+                cause.request_id()
+            }
+            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListTrafficPolicyInstancesByPolicyError {
     #[allow(unused_variables)]
@@ -10703,9 +11284,9 @@ impl Error for ListTrafficPolicyInstancesByPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyVersionsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
 }
 
 impl ListTrafficPolicyVersionsError {
@@ -10718,13 +11299,13 @@ impl ListTrafficPolicyVersionsError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(ListTrafficPolicyVersionsError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(
                             ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10742,6 +11323,14 @@ impl ListTrafficPolicyVersionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTrafficPolicyVersionsError::InvalidInput(ref cause) => cause.request_id(),
+            ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTrafficPolicyVersionsError {
     #[allow(unused_variables)]
@@ -10759,11 +11348,11 @@ impl Error for ListTrafficPolicyVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListVPCAssociationAuthorizationsError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>The value that you specified to get the second or subsequent page of results is invalid.</p>
-    InvalidPaginationToken(String),
+    InvalidPaginationToken(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl ListVPCAssociationAuthorizationsError {
@@ -10779,21 +11368,21 @@ impl ListVPCAssociationAuthorizationsError {
                     "InvalidInput" => {
                         return RusotoError::Service(
                             ListVPCAssociationAuthorizationsError::InvalidInput(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidPaginationToken" => {
                         return RusotoError::Service(
                             ListVPCAssociationAuthorizationsError::InvalidPaginationToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
                             ListVPCAssociationAuthorizationsError::NoSuchHostedZone(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10811,6 +11400,19 @@ impl ListVPCAssociationAuthorizationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListVPCAssociationAuthorizationsError::InvalidInput(ref cause) => cause.request_id(),
+            ListVPCAssociationAuthorizationsError::InvalidPaginationToken(ref cause) => {
+                cause.request_id()
+            }
+            ListVPCAssociationAuthorizationsError::NoSuchHostedZone(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ListVPCAssociationAuthorizationsError {
     #[allow(unused_variables)]
@@ -10833,9 +11435,9 @@ impl Error for ListVPCAssociationAuthorizationsError {}
 #[derive(Debug, PartialEq)]
 pub enum TestDNSAnswerError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl TestDNSAnswerError {
@@ -10848,12 +11450,12 @@ impl TestDNSAnswerError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(TestDNSAnswerError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(TestDNSAnswerError::NoSuchHostedZone(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10870,6 +11472,14 @@ impl TestDNSAnswerError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TestDNSAnswerError::InvalidInput(ref cause) => cause.request_id(),
+            TestDNSAnswerError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TestDNSAnswerError {
     #[allow(unused_variables)]
@@ -10885,11 +11495,11 @@ impl Error for TestDNSAnswerError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateHealthCheckError {
     /// <p>The value of <code>HealthCheckVersion</code> in the request doesn't match the value of <code>HealthCheckVersion</code> in the health check.</p>
-    HealthCheckVersionMismatch(String),
+    HealthCheckVersionMismatch(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No health check exists with the specified ID.</p>
-    NoSuchHealthCheck(String),
+    NoSuchHealthCheck(XmlErrorMessage),
 }
 
 impl UpdateHealthCheckError {
@@ -10903,18 +11513,18 @@ impl UpdateHealthCheckError {
                     "HealthCheckVersionMismatch" => {
                         return RusotoError::Service(
                             UpdateHealthCheckError::HealthCheckVersionMismatch(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(UpdateHealthCheckError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHealthCheck" => {
                         return RusotoError::Service(UpdateHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10931,6 +11541,15 @@ impl UpdateHealthCheckError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateHealthCheckError::HealthCheckVersionMismatch(ref cause) => cause.request_id(),
+            UpdateHealthCheckError::InvalidInput(ref cause) => cause.request_id(),
+            UpdateHealthCheckError::NoSuchHealthCheck(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateHealthCheckError {
     #[allow(unused_variables)]
@@ -10947,9 +11566,9 @@ impl Error for UpdateHealthCheckError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateHostedZoneCommentError {
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No hosted zone exists with the ID that you specified.</p>
-    NoSuchHostedZone(String),
+    NoSuchHostedZone(XmlErrorMessage),
 }
 
 impl UpdateHostedZoneCommentError {
@@ -10962,12 +11581,14 @@ impl UpdateHostedZoneCommentError {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
                         return RusotoError::Service(UpdateHostedZoneCommentError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchHostedZone" => {
                         return RusotoError::Service(
-                            UpdateHostedZoneCommentError::NoSuchHostedZone(parsed_error.message),
+                            UpdateHostedZoneCommentError::NoSuchHostedZone(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -10984,6 +11605,14 @@ impl UpdateHostedZoneCommentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateHostedZoneCommentError::InvalidInput(ref cause) => cause.request_id(),
+            UpdateHostedZoneCommentError::NoSuchHostedZone(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateHostedZoneCommentError {
     #[allow(unused_variables)]
@@ -10999,11 +11628,11 @@ impl Error for UpdateHostedZoneCommentError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrafficPolicyCommentError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
-    ConcurrentModification(String),
+    ConcurrentModification(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
 }
 
 impl UpdateTrafficPolicyCommentError {
@@ -11019,19 +11648,19 @@ impl UpdateTrafficPolicyCommentError {
                     "ConcurrentModification" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyCommentError::ConcurrentModification(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(UpdateTrafficPolicyCommentError::InvalidInput(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11049,6 +11678,17 @@ impl UpdateTrafficPolicyCommentError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateTrafficPolicyCommentError::ConcurrentModification(ref cause) => {
+                cause.request_id()
+            }
+            UpdateTrafficPolicyCommentError::InvalidInput(ref cause) => cause.request_id(),
+            UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateTrafficPolicyCommentError {
     #[allow(unused_variables)]
@@ -11069,15 +11709,15 @@ impl Error for UpdateTrafficPolicyCommentError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrafficPolicyInstanceError {
     /// <p>You tried to update a traffic policy instance by using a traffic policy version that has a different DNS type than the current type for the instance. You specified the type in the JSON document in the <code>CreateTrafficPolicy</code> or <code>CreateTrafficPolicyVersion</code>request. </p>
-    ConflictingTypes(String),
+    ConflictingTypes(XmlErrorMessage),
     /// <p>The input is not valid.</p>
-    InvalidInput(String),
+    InvalidInput(XmlErrorMessage),
     /// <p>No traffic policy exists with the specified ID.</p>
-    NoSuchTrafficPolicy(String),
+    NoSuchTrafficPolicy(XmlErrorMessage),
     /// <p>No traffic policy instance exists with the specified ID.</p>
-    NoSuchTrafficPolicyInstance(String),
+    NoSuchTrafficPolicyInstance(XmlErrorMessage),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
-    PriorRequestNotComplete(String),
+    PriorRequestNotComplete(XmlErrorMessage),
 }
 
 impl UpdateTrafficPolicyInstanceError {
@@ -11093,33 +11733,35 @@ impl UpdateTrafficPolicyInstanceError {
                     "ConflictingTypes" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyInstanceError::ConflictingTypes(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidInput" => {
                         return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            UpdateTrafficPolicyInstanceError::InvalidInput(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PriorRequestNotComplete" => {
                         return RusotoError::Service(
                             UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -11137,6 +11779,21 @@ impl UpdateTrafficPolicyInstanceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateTrafficPolicyInstanceError::ConflictingTypes(ref cause) => cause.request_id(),
+            UpdateTrafficPolicyInstanceError::InvalidInput(ref cause) => cause.request_id(),
+            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(ref cause) => cause.request_id(),
+            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(ref cause) => {
+                cause.request_id()
+            }
+            UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateTrafficPolicyInstanceError {
     #[allow(unused_variables)]
@@ -11586,6 +12243,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("AssociateVPCWithHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         AssociateVPCWithHostedZoneRequestSerializer::serialize(
@@ -11626,6 +12284,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeResourceRecordSets");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeResourceRecordSetsRequestSerializer::serialize(
@@ -11666,6 +12325,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeTagsForResource");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeTagsForResourceRequestSerializer::serialize(
@@ -11695,6 +12355,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHealthCheckRequestSerializer::serialize(
@@ -11729,6 +12390,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHostedZoneRequestSerializer::serialize(
@@ -11763,6 +12425,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateQueryLoggingConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateQueryLoggingConfigRequestSerializer::serialize(
@@ -11798,6 +12461,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateReusableDelegationSet");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateReusableDelegationSetRequestSerializer::serialize(
@@ -11832,6 +12496,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicy";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicy");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyRequestSerializer::serialize(
@@ -11867,6 +12532,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstance";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -11905,6 +12571,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyVersion");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyVersionRequestSerializer::serialize(
@@ -11948,6 +12615,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -11993,6 +12661,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHealthCheck");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHealthCheckError::from_response)
@@ -12016,6 +12685,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHostedZone");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHostedZoneError::from_response)
@@ -12043,6 +12713,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteQueryLoggingConfig");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteQueryLoggingConfigError::from_response)
@@ -12067,6 +12738,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteReusableDelegationSet");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteReusableDelegationSetError::from_response)
@@ -12094,6 +12766,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicy");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyError::from_response)
@@ -12118,6 +12791,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicyInstance");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyInstanceError::from_response)
@@ -12147,6 +12821,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -12188,6 +12863,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisassociateVPCFromHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         DisassociateVPCFromHostedZoneRequestSerializer::serialize(
@@ -12224,6 +12900,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetAccountLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetAccountLimitError::from_response)
@@ -12251,6 +12928,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetChange");
 
         let mut response = self
             .sign_and_dispatch(request, GetChangeError::from_response)
@@ -12275,6 +12953,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/checkeripranges";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetCheckerIpRanges");
 
         let mut response = self
             .sign_and_dispatch(request, GetCheckerIpRangesError::from_response)
@@ -12299,6 +12978,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocation";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetGeoLocation");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continent_code {
@@ -12341,6 +13021,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheck");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckError::from_response)
@@ -12365,6 +13046,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheckcount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckCountError::from_response)
@@ -12398,6 +13080,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckLastFailureReason");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckLastFailureReasonError::from_response)
@@ -12428,6 +13111,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckStatus");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckStatusError::from_response)
@@ -12455,6 +13139,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZone");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneError::from_response)
@@ -12479,6 +13164,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneCountError::from_response)
@@ -12503,6 +13189,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneLimitError::from_response)
@@ -12530,6 +13217,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetQueryLoggingConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetQueryLoggingConfigError::from_response)
@@ -12557,6 +13245,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSet");
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetError::from_response)
@@ -12584,6 +13273,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSetLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetLimitError::from_response)
@@ -12615,6 +13305,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicy");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyError::from_response)
@@ -12642,6 +13333,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstance");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceError::from_response)
@@ -12669,6 +13361,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstancecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstanceCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceCountError::from_response)
@@ -12693,6 +13386,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocations";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListGeoLocations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12732,6 +13426,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHealthChecks");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12765,6 +13460,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZones");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delegation_set_id {
@@ -12801,6 +13497,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyname";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByName");
 
         let mut params = Params::new();
         if let Some(ref x) = input.dns_name {
@@ -12837,6 +13534,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyvpc";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByVPC");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12872,6 +13570,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListQueryLoggingConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id {
@@ -12914,6 +13613,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListResourceRecordSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12954,6 +13654,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListReusableDelegationSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12994,6 +13695,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut response = self
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
@@ -13024,6 +13726,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResources");
 
         let mut writer = EventWriter::new(Vec::new());
         ListTagsForResourcesRequestSerializer::serialize(
@@ -13057,6 +13760,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicies";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -13091,6 +13795,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstances");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -13133,6 +13838,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByHostedZone");
 
         let mut params = Params::new();
         params.put("id", &input.hosted_zone_id);
@@ -13179,6 +13885,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByPolicy");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -13230,6 +13937,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -13272,6 +13980,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListVPCAssociationAuthorizations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -13311,6 +14020,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/testdnsanswer";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("TestDNSAnswer");
 
         let mut params = Params::new();
         if let Some(ref x) = input.edns0_client_subnet_ip {
@@ -13356,6 +14066,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHealthCheckRequestSerializer::serialize(
@@ -13392,6 +14103,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHostedZoneComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHostedZoneCommentRequestSerializer::serialize(
@@ -13433,6 +14145,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyCommentRequestSerializer::serialize(
@@ -13470,6 +14183,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -42,7 +42,7 @@ impl Route53DomainsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2605,7 +2605,7 @@ impl Route53Domains for Route53DomainsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AcceptDomainTransferFromAnotherAwsAccountResponse, _>()
     }
@@ -2633,7 +2633,7 @@ impl Route53Domains for Route53DomainsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CancelDomainTransferToAnotherAwsAccountResponse, _>()
     }
@@ -2655,7 +2655,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, CheckDomainAvailabilityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CheckDomainAvailabilityResponse, _>()
     }
@@ -2678,7 +2678,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, CheckDomainTransferabilityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CheckDomainTransferabilityResponse, _>()
     }
@@ -2700,7 +2700,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, DeleteTagsForDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsForDomainResponse, _>()
     }
 
@@ -2721,7 +2721,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, DisableDomainAutoRenewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisableDomainAutoRenewResponse, _>()
     }
@@ -2744,7 +2744,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, DisableDomainTransferLockError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisableDomainTransferLockResponse, _>()
     }
@@ -2766,7 +2766,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, EnableDomainAutoRenewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<EnableDomainAutoRenewResponse, _>()
     }
@@ -2788,7 +2788,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, EnableDomainTransferLockError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<EnableDomainTransferLockResponse, _>()
     }
@@ -2811,7 +2811,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, GetContactReachabilityStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetContactReachabilityStatusResponse, _>()
     }
@@ -2830,7 +2830,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, GetDomainDetailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDomainDetailResponse, _>()
     }
 
@@ -2851,7 +2851,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, GetDomainSuggestionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDomainSuggestionsResponse, _>()
     }
@@ -2873,7 +2873,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, GetOperationDetailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOperationDetailResponse, _>()
     }
 
@@ -2891,7 +2891,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, ListDomainsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDomainsResponse, _>()
     }
 
@@ -2909,7 +2909,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, ListOperationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListOperationsResponse, _>()
     }
 
@@ -2927,7 +2927,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, ListTagsForDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForDomainResponse, _>()
     }
 
@@ -2945,7 +2945,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, RegisterDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterDomainResponse, _>()
     }
 
@@ -2972,7 +2972,7 @@ impl Route53Domains for Route53DomainsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RejectDomainTransferFromAnotherAwsAccountResponse, _>()
     }
@@ -2991,7 +2991,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, RenewDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RenewDomainResponse, _>()
     }
 
@@ -3015,7 +3015,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, ResendContactReachabilityEmailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ResendContactReachabilityEmailResponse, _>()
     }
@@ -3037,7 +3037,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, RetrieveDomainAuthCodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RetrieveDomainAuthCodeResponse, _>()
     }
@@ -3056,7 +3056,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, TransferDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TransferDomainResponse, _>()
     }
 
@@ -3083,7 +3083,7 @@ impl Route53Domains for Route53DomainsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<TransferDomainToAnotherAwsAccountResponse, _>()
     }
@@ -3105,7 +3105,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, UpdateDomainContactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDomainContactResponse, _>()
     }
 
@@ -3127,7 +3127,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, UpdateDomainContactPrivacyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDomainContactPrivacyResponse, _>()
     }
@@ -3149,7 +3149,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, UpdateDomainNameserversError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDomainNameserversResponse, _>()
     }
@@ -3171,7 +3171,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, UpdateTagsForDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTagsForDomainResponse, _>()
     }
 
@@ -3189,7 +3189,7 @@ impl Route53Domains for Route53DomainsClient {
             .sign_and_dispatch(request, ViewBillingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ViewBillingResponse, _>()
     }
 }
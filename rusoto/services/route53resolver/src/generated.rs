@@ -42,7 +42,7 @@ impl Route53ResolverClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2461,7 +2461,7 @@ impl Route53Resolver for Route53ResolverClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateResolverEndpointIpAddressResponse, _>()
     }
@@ -2480,7 +2480,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, AssociateResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateResolverRuleResponse, _>()
     }
@@ -2499,7 +2499,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, CreateResolverEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateResolverEndpointResponse, _>()
     }
@@ -2518,7 +2518,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, CreateResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateResolverRuleResponse, _>()
     }
 
@@ -2536,7 +2536,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, DeleteResolverEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteResolverEndpointResponse, _>()
     }
@@ -2555,7 +2555,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, DeleteResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteResolverRuleResponse, _>()
     }
 
@@ -2582,7 +2582,7 @@ impl Route53Resolver for Route53ResolverClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateResolverEndpointIpAddressResponse, _>()
     }
@@ -2601,7 +2601,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, DisassociateResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateResolverRuleResponse, _>()
     }
@@ -2620,7 +2620,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, GetResolverEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResolverEndpointResponse, _>()
     }
 
@@ -2638,7 +2638,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, GetResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResolverRuleResponse, _>()
     }
 
@@ -2657,7 +2657,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, GetResolverRuleAssociationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetResolverRuleAssociationResponse, _>()
     }
@@ -2676,7 +2676,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, GetResolverRulePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetResolverRulePolicyResponse, _>()
     }
@@ -2701,7 +2701,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, ListResolverEndpointIpAddressesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResolverEndpointIpAddressesResponse, _>()
     }
@@ -2720,7 +2720,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, ListResolverEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResolverEndpointsResponse, _>()
     }
@@ -2743,7 +2743,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, ListResolverRuleAssociationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResolverRuleAssociationsResponse, _>()
     }
@@ -2762,7 +2762,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, ListResolverRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResolverRulesResponse, _>()
     }
 
@@ -2780,7 +2780,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2798,7 +2798,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, PutResolverRulePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutResolverRulePolicyResponse, _>()
     }
@@ -2817,7 +2817,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2835,7 +2835,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2853,7 +2853,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, UpdateResolverEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateResolverEndpointResponse, _>()
     }
@@ -2872,7 +2872,7 @@ impl Route53Resolver for Route53ResolverClient {
             .sign_and_dispatch(request, UpdateResolverRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResolverRuleResponse, _>()
     }
 }
@@ -0,0 +1,286 @@
+use std::str::FromStr;
+
+use rusoto_core::region::Region;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::EndpointVariants;
+
+/// The kinds of access point an ARN used as a bucket name can refer to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AccessPointKind {
+    /// `arn:<partition>:s3:<region>:<account>:accesspoint/<name>`
+    AccessPoint,
+    /// `arn:<partition>:s3-object-lambda:<region>:<account>:accesspoint/<name>`
+    ObjectLambda,
+    /// `arn:<partition>:s3::<account>:accesspoint/<alias>`
+    MultiRegion,
+}
+
+/// An access point ARN parsed from the path of a request.
+#[derive(Debug, PartialEq)]
+struct AccessPointArn<'a> {
+    kind: AccessPointKind,
+    partition: &'a str,
+    region: &'a str,
+    account: &'a str,
+    name: &'a str,
+}
+
+/// Sends `request` to the access point named by the ARN in its bucket position, signing it with
+/// the region and service of the ARN. Returns whether the bucket was an ARN.
+///
+/// Fails for ARNs that are malformed, in another partition than the client region, in another
+/// region if `allow_cross_region` is false, or that need a kind of endpoint the access point
+/// doesn't have.
+pub(crate) fn address_access_point(
+    request: &mut SignedRequest,
+    variants: EndpointVariants,
+    allow_cross_region: bool,
+) -> Result<bool, String> {
+    let (arn, rest) = match parse_arn_path(&request.path)? {
+        Some(parsed) => parsed,
+        None => return Ok(false),
+    };
+    if let Region::Custom { .. } = request.region {
+        return Err("access point ARNs can't be used with a custom endpoint".to_owned());
+    }
+    let client_partition = partition(&request.region);
+    if arn.partition != client_partition {
+        return Err(format!(
+            "access point ARN partition {} doesn't match the partition {} of region {}",
+            arn.partition,
+            client_partition,
+            request.region.name()
+        ));
+    }
+    if arn.kind == AccessPointKind::MultiRegion {
+        return Err(
+            "multi-region access point ARNs need SigV4A signing, which isn't supported".to_owned(),
+        );
+    }
+    if arn.region != request.region.name() && !allow_cross_region {
+        return Err(format!(
+            "access point ARN region {} differs from the client region {}, and cross-region \
+             ARNs are disabled",
+            arn.region,
+            request.region.name()
+        ));
+    }
+
+    let fips = if variants.fips { "-fips" } else { "" };
+    let (service, endpoint) = match arn.kind {
+        AccessPointKind::AccessPoint => {
+            let dualstack = if variants.dualstack { ".dualstack" } else { "" };
+            ("s3", format!("s3-accesspoint{}{}", fips, dualstack))
+        }
+        AccessPointKind::ObjectLambda => {
+            if variants.dualstack {
+                return Err(
+                    "Object Lambda access points don't have dual-stack endpoints".to_owned(),
+                );
+            }
+            ("s3-object-lambda", format!("s3-object-lambda{}", fips))
+        }
+        AccessPointKind::MultiRegion => unreachable!("rejected above"),
+    };
+    let suffix = if arn.partition == "aws-cn" {
+        "amazonaws.com.cn"
+    } else {
+        "amazonaws.com"
+    };
+    let hostname = format!(
+        "{}-{}.{}.{}.{}",
+        arn.name, arn.account, endpoint, arn.region, suffix
+    );
+    let region = Region::from_str(arn.region).unwrap_or_else(|_| Region::Custom {
+        name: arn.region.to_owned(),
+        endpoint: format!("https://{}", hostname),
+    });
+    let path = if rest.is_empty() { "/" } else { rest }.to_owned();
+
+    request.region = region;
+    request.service = service.to_owned();
+    request.hostname = Some(hostname);
+    request.path = path;
+    Ok(true)
+}
+
+/// Splits a request path of the form `/<arn>[/<key>]` into the parsed ARN and the rest of the
+/// path. Returns `None` if the path doesn't start with an ARN.
+fn parse_arn_path(path: &str) -> Result<Option<(AccessPointArn<'_>, &str)>, String> {
+    if !path.starts_with("/arn:") {
+        return Ok(None);
+    }
+    let invalid = || format!("invalid access point ARN in {:?}", path);
+    let parts: Vec<&str> = path[1..].splitn(6, ':').collect();
+    if parts.len() != 6 {
+        return Err(invalid());
+    }
+    let (partition, service, region, account, resource) =
+        (parts[1], parts[2], parts[3], parts[4], parts[5]);
+    let resource = if resource.starts_with("accesspoint/") || resource.starts_with("accesspoint:") {
+        &resource["accesspoint/".len()..]
+    } else {
+        return Err(format!(
+            "unsupported ARN resource in {:?}, only access points are supported",
+            path
+        ));
+    };
+    let (name, rest) = match resource.find('/') {
+        Some(pos) => (&resource[..pos], &resource[pos..]),
+        None => (resource, ""),
+    };
+    let kind = match (service, region) {
+        ("s3", "") => AccessPointKind::MultiRegion,
+        ("s3", _) => AccessPointKind::AccessPoint,
+        ("s3-object-lambda", r) if !r.is_empty() => AccessPointKind::ObjectLambda,
+        _ => return Err(invalid()),
+    };
+    let valid_name = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.');
+    let valid_account = account.len() == 12 && account.bytes().all(|b| b.is_ascii_digit());
+    if partition.is_empty() || !valid_name || !valid_account {
+        return Err(invalid());
+    }
+    let arn = AccessPointArn {
+        kind,
+        partition,
+        region,
+        account,
+        name,
+    };
+    Ok(Some((arn, rest)))
+}
+
+fn partition(region: &Region) -> &'static str {
+    match *region {
+        Region::CnNorth1 | Region::CnNorthwest1 => "aws-cn",
+        Region::UsGovEast1 | Region::UsGovWest1 => "aws-us-gov",
+        _ => "aws",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCESS_POINT: &str = "arn:aws:s3:us-west-2:123456789012:accesspoint/myap";
+
+    fn addressed(region: Region, path: &str, variants: EndpointVariants) -> Result<String, String> {
+        let mut request = SignedRequest::new("GET", "s3", &region, path);
+        assert!(address_access_point(&mut request, variants, true)?);
+        Ok(format!(
+            "{} {} {}{}",
+            request.region.name(),
+            request.service,
+            request.hostname(),
+            request.path
+        ))
+    }
+
+    #[test]
+    fn ignores_plain_buckets() {
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(!address_access_point(&mut request, Default::default(), true).unwrap());
+        assert_eq!(request.path, "/bucket/key");
+    }
+
+    #[test]
+    fn access_point_endpoints() {
+        assert_eq!(
+            addressed(
+                Region::UsWest2,
+                &format!("/{}/a/key", ACCESS_POINT),
+                Default::default()
+            )
+            .unwrap(),
+            "us-west-2 s3 myap-123456789012.s3-accesspoint.us-west-2.amazonaws.com/a/key"
+        );
+        let variants = EndpointVariants {
+            fips: true,
+            dualstack: true,
+        };
+        assert_eq!(
+            addressed(Region::UsWest2, &format!("/{}", ACCESS_POINT), variants).unwrap(),
+            "us-west-2 s3 myap-123456789012.s3-accesspoint-fips.dualstack.us-west-2.amazonaws.com/"
+        );
+        assert_eq!(
+            addressed(
+                Region::CnNorth1,
+                "/arn:aws-cn:s3:cn-north-1:123456789012:accesspoint:myap/key",
+                Default::default()
+            )
+            .unwrap(),
+            "cn-north-1 s3 myap-123456789012.s3-accesspoint.cn-north-1.amazonaws.com.cn/key"
+        );
+    }
+
+    #[test]
+    fn object_lambda_endpoints() {
+        assert_eq!(
+            addressed(
+                Region::UsEast1,
+                "/arn:aws:s3-object-lambda:us-east-1:123456789012:accesspoint/mylambda/key",
+                Default::default()
+            )
+            .unwrap(),
+            "us-east-1 s3-object-lambda \
+             mylambda-123456789012.s3-object-lambda.us-east-1.amazonaws.com/key"
+        );
+    }
+
+    #[test]
+    fn cross_region_arns_use_the_arn_region() {
+        assert!(addressed(
+            Region::EuWest1,
+            &format!("/{}/key", ACCESS_POINT),
+            Default::default()
+        )
+        .unwrap()
+        .starts_with("us-west-2 s3 "));
+
+        let path = format!("/{}/key", ACCESS_POINT);
+        let mut request = SignedRequest::new("GET", "s3", &Region::EuWest1, &path);
+        let err = address_access_point(&mut request, Default::default(), false).unwrap_err();
+        assert!(err.contains("cross-region ARNs are disabled"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_invalid_arns() {
+        let errors = [
+            (
+                Region::CnNorth1,
+                format!("/{}/key", ACCESS_POINT),
+                "doesn't match the partition aws-cn",
+            ),
+            (
+                Region::UsEast1,
+                "/arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key".to_owned(),
+                "SigV4A",
+            ),
+            (
+                Region::UsEast1,
+                "/arn:aws:s3:us-east-1:123456789012:bucket/name".to_owned(),
+                "only access points are supported",
+            ),
+            (
+                Region::UsEast1,
+                "/arn:aws:s3:us-east-1:1234:accesspoint/myap".to_owned(),
+                "invalid access point ARN",
+            ),
+            (
+                Region::Custom {
+                    name: "local".to_owned(),
+                    endpoint: "http://localhost:9000".to_owned(),
+                },
+                format!("/{}/key", ACCESS_POINT),
+                "custom endpoint",
+            ),
+        ];
+        for (region, path, message) in errors.iter() {
+            let err = addressed(region.clone(), path, Default::default()).unwrap_err();
+            assert!(err.contains(message), "{}: {}", path, err);
+        }
+    }
+}
@@ -0,0 +1,626 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::stream::{self, Stream, StreamExt};
+use rusoto_core::request::HttpDispatchError;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::ByteStream;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::generated::{GetObjectOutput, GetObjectRequest};
+
+/// The size of the chunks an `aws-chunked` body is sent in, but the last.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The checksum of a `GetObject` body isn't the one S3 reported for the object.
+///
+/// When a `GetObjectRequest` sets `checksum_mode` to `ENABLED` and requests the whole object,
+/// not a `range` or a `part_number`, the body of the response is checked against the
+/// strongest checksum S3 reports in its `checksum_*` fields. Reading the last of it then fails
+/// with an `io::Error` of kind `InvalidData` wrapping this error, instead of ending.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_s3::{ChecksumMismatch, GetObjectRequest, S3Client, S3};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let s3 = S3Client::new(Region::UsEast1);
+/// let request = GetObjectRequest {
+///     bucket: "bucket".to_owned(),
+///     key: "key".to_owned(),
+///     checksum_mode: Some("ENABLED".to_owned()),
+///     ..Default::default()
+/// };
+/// let body = s3.get_object(request).await?.body.expect("body");
+/// match body.map_ok(|chunk| chunk.to_vec()).try_concat().await {
+///     Ok(data) => println!("read {} bytes", data.len()),
+///     Err(err) => match err.get_ref().and_then(|err| err.downcast_ref::<ChecksumMismatch>()) {
+///         Some(mismatch) => println!("corrupt download: {}", mismatch),
+///         None => println!("failed to read the object: {}", err),
+///     },
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChecksumMismatch {
+    /// The checksum algorithm, e.g. `CRC32C`, or `MD5` when checking the ETag.
+    pub algorithm: &'static str,
+    /// The checksum S3 reported.
+    pub expected: String,
+    /// The checksum of the bytes received.
+    pub actual: String,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} checksum of the object is {}, expected {}",
+            self.algorithm, self.actual, self.expected
+        )
+    }
+}
+
+impl Error for ChecksumMismatch {}
+
+/// Computes the checksum S3 uses to check an object or part.
+#[derive(Clone)]
+pub(crate) enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Sha1(Sha1),
+    Sha256(Sha256),
+    Md5(md5::Context),
+}
+
+impl Hasher {
+    /// The hasher of the `x-amz-checksum-algorithm` `algorithm`, e.g. `CRC32C`.
+    fn new(algorithm: &str) -> Option<Hasher> {
+        match algorithm.to_ascii_uppercase().as_str() {
+            "CRC32" => Some(Hasher::Crc32(crc32fast::Hasher::new())),
+            "CRC32C" => Some(Hasher::Crc32c(0)),
+            "SHA1" => Some(Hasher::Sha1(Sha1::new())),
+            "SHA256" => Some(Hasher::Sha256(Sha256::new())),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match *self {
+            Hasher::Crc32(ref mut hasher) => hasher.update(data),
+            Hasher::Crc32c(ref mut crc) => *crc = crc32c(*crc, data),
+            Hasher::Sha1(ref mut hasher) => hasher.update(data),
+            Hasher::Sha256(ref mut hasher) => hasher.update(data),
+            Hasher::Md5(ref mut context) => context.consume(data),
+        }
+    }
+
+    /// Returns the checksum the way S3 reports it: base64 encoded, or hex for MD5 ETags.
+    fn finish(self) -> String {
+        match self {
+            Hasher::Crc32(hasher) => base64::encode(hasher.finalize().to_be_bytes()),
+            Hasher::Crc32c(crc) => base64::encode(crc.to_be_bytes()),
+            Hasher::Sha1(hasher) => base64::encode(hasher.finalize()),
+            Hasher::Sha256(hasher) => base64::encode(hasher.finalize()),
+            Hasher::Md5(context) => format!("{:x}", context.compute()),
+        }
+    }
+}
+
+/// A checksum S3 reported for an object, along with the one of the bytes received so far.
+pub(crate) struct Checksum {
+    algorithm: &'static str,
+    expected: String,
+    hasher: Hasher,
+}
+
+impl Checksum {
+    /// The strongest checksum of the whole object in `output`, if any.
+    pub(crate) fn reported(output: &GetObjectOutput) -> Option<Checksum> {
+        let reported = vec![
+            ("SHA256", &output.checksum_sha256),
+            ("SHA1", &output.checksum_sha1),
+            ("CRC32C", &output.checksum_crc32c),
+            ("CRC32", &output.checksum_crc32),
+        ];
+        // Checksums of multipart uploads are suffixed with the number of parts, e.g. `-3`.
+        reported.into_iter().find_map(|(algorithm, value)| {
+            value
+                .as_ref()
+                .filter(|value| !value.contains('-'))
+                .map(|value| Checksum {
+                    algorithm,
+                    expected: value.clone(),
+                    hasher: Hasher::new(algorithm).expect("supported algorithm"),
+                })
+        })
+    }
+
+    /// The MD5 digest of the object its ETag is, unless the object is encrypted with KMS or a
+    /// customer key, or was uploaded in parts.
+    pub(crate) fn e_tag(output: &GetObjectOutput) -> Option<Checksum> {
+        let encrypted = output.sse_customer_algorithm.is_some()
+            || output.server_side_encryption.as_deref() == Some("aws:kms");
+        let e_tag = output.e_tag.as_ref()?.trim_matches('"').to_lowercase();
+        if encrypted || e_tag.len() != 32 || !e_tag.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        Some(Checksum {
+            algorithm: "MD5",
+            expected: e_tag,
+            hasher: Hasher::Md5(md5::Context::new()),
+        })
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data)
+    }
+
+    /// Returns the error for a checksum of the bytes received that isn't the expected one.
+    pub(crate) fn mismatch(self) -> Option<ChecksumMismatch> {
+        let actual = self.hasher.finish();
+        if actual == self.expected {
+            return None;
+        }
+        Some(ChecksumMismatch {
+            algorithm: self.algorithm,
+            expected: self.expected,
+            actual,
+        })
+    }
+}
+
+/// Computes the checksum of the body of a `PutObject` or `UploadPart` request named by its
+/// `x-amz-sdk-checksum-algorithm` header, unless the request already sets it.
+///
+/// A streamed body is sent `aws-chunked` with the checksum in a trailer, computed as the
+/// chunks are sent, so it's read once, and its size must be known, from its size hint or the
+/// `Content-Length` header. Other bodies are sent with the checksum in a header.
+pub(crate) fn add_missing_checksum(request: &mut SignedRequest) -> Result<(), String> {
+    let algorithm = match header(request, "x-amz-sdk-checksum-algorithm") {
+        Some(algorithm) => algorithm.to_ascii_uppercase(),
+        None => return Ok(()),
+    };
+    let mut hasher = Hasher::new(&algorithm)
+        .ok_or_else(|| format!("Unsupported checksum algorithm {}", algorithm))?;
+    let name = format!("x-amz-checksum-{}", algorithm.to_ascii_lowercase());
+    if header(request, &name).is_some_and(|value| !value.is_empty()) {
+        return Ok(());
+    }
+    request.remove_header(&name);
+
+    let body = match request.payload.take() {
+        Some(SignedRequestPayload::Stream(body)) => body,
+        payload => {
+            if let Some(SignedRequestPayload::Buffer(ref bytes)) = payload {
+                hasher.update(bytes);
+            }
+            request.add_header(&name, &hasher.finish());
+            request.payload = payload;
+            return Ok(());
+        }
+    };
+    let len = body
+        .len_hint()
+        .or_else(|| header(request, "content-length")?.parse().ok())
+        .ok_or_else(|| {
+            format!(
+                "The {} checksum of a streamed body of unknown size can't be computed, set its content_length",
+                algorithm
+            )
+        })?;
+
+    let encoding = match header(request, "content-encoding") {
+        Some(encoding) if !encoding.is_empty() => format!("aws-chunked,{}", encoding),
+        _ => "aws-chunked".to_owned(),
+    };
+    request.remove_header("content-encoding");
+    request.add_header("content-encoding", &encoding);
+    request.remove_header("content-length");
+    request.add_header("x-amz-content-sha256", "STREAMING-UNSIGNED-PAYLOAD-TRAILER");
+    request.add_header("x-amz-decoded-content-length", &len.to_string());
+    request.add_header("x-amz-trailer", &name);
+
+    let encoded_len = aws_chunked_len(len, &name, hasher.clone().finish().len());
+    let stream = if body.is_replayable() {
+        ByteStream::new_replayable(move || {
+            let body = body.try_clone().expect("replayable body");
+            ByteStream::new_with_size(aws_chunked(body, hasher.clone(), name.clone()), encoded_len)
+        })
+    } else {
+        ByteStream::new_with_size(aws_chunked(body, hasher, name), encoded_len)
+    };
+    request.set_payload_stream(stream);
+    Ok(())
+}
+
+/// Sets the `Content-MD5` header of a `PutObject` request that doesn't set it, for
+/// `S3Config::put_object_content_md5`.
+pub(crate) async fn add_content_md5(request: &mut SignedRequest) -> Result<(), HttpDispatchError> {
+    if header(request, "content-md5").is_some_and(|value| !value.is_empty()) {
+        return Ok(());
+    }
+    let body = match request.payload.take() {
+        Some(SignedRequestPayload::Stream(body)) => body,
+        payload => {
+            request.payload = payload;
+            request.set_content_md5_header();
+            return Ok(());
+        }
+    };
+    let (digest, body) = md5_of(body).await.map_err(|err| {
+        HttpDispatchError::new(format!(
+            "Failed to read the body to compute its Content-MD5: {}",
+            err
+        ))
+    })?;
+    request.remove_header("content-md5");
+    request.add_header("Content-MD5", &base64::encode(*digest));
+    request.set_payload_stream(body);
+    Ok(())
+}
+
+/// Returns the MD5 digest of `body`, and the body to send in its place.
+async fn md5_of(body: ByteStream) -> io::Result<(md5::Digest, ByteStream)> {
+    let mut context = md5::Context::new();
+    if let Some(mut copy) = body.try_clone() {
+        while let Some(chunk) = copy.next().await {
+            context.consume(&chunk?);
+        }
+        return Ok((context.compute(), body));
+    }
+    let mut body = body;
+    let mut data = Vec::new();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        context.consume(&chunk);
+        data.extend_from_slice(&chunk);
+    }
+    Ok((context.compute(), ByteStream::from(data)))
+}
+
+fn header(request: &SignedRequest, name: &str) -> Option<String> {
+    let values = request.headers.get(name)?;
+    Some(String::from_utf8_lossy(values.first()?).into_owned())
+}
+
+/// The size of `len` bytes sent `aws-chunked`, followed by a `trailer` of `checksum_len`.
+fn aws_chunked_len(len: usize, trailer: &str, checksum_len: usize) -> usize {
+    let chunk_len = |size: usize| format!("{:x}", size).len() + size + 4;
+    let rest = len % CHUNK_SIZE;
+    let last = if rest > 0 { chunk_len(rest) } else { 0 };
+    // `0\r\n`, the trailer and its `\r\n`, and the final `\r\n`
+    len / CHUNK_SIZE * chunk_len(CHUNK_SIZE) + last + 3 + trailer.len() + 1 + checksum_len + 4
+}
+
+/// Encodes `body` `aws-chunked`, followed by a `trailer` with its checksum.
+fn aws_chunked(
+    body: ByteStream,
+    hasher: Hasher,
+    trailer: String,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Send + Sync {
+    let state = (body, BytesMut::new(), hasher, trailer);
+    stream::unfold(Some(state), |state| async move {
+        let (mut body, mut buffer, mut hasher, trailer) = state?;
+        while buffer.len() < CHUNK_SIZE {
+            match body.next().await {
+                Some(Ok(bytes)) => buffer.extend_from_slice(&bytes),
+                Some(Err(err)) => return Some((Err(err), None)),
+                None => break,
+            }
+        }
+        if buffer.is_empty() {
+            let last = format!("0\r\n{}:{}\r\n\r\n", trailer, hasher.finish());
+            return Some((Ok(Bytes::from(last)), None));
+        }
+        let data = buffer.split_to(buffer.len().min(CHUNK_SIZE));
+        hasher.update(&data);
+        let mut chunk = BytesMut::with_capacity(data.len() + 12);
+        chunk.put(format!("{:x}\r\n", data.len()).as_bytes());
+        chunk.put(data);
+        chunk.put(&b"\r\n"[..]);
+        Some((Ok(chunk.freeze()), Some((body, buffer, hasher, trailer))))
+    })
+}
+
+/// Checks the body of `output` against the checksum S3 reported, if `input` enabled them and
+/// asked for the whole object. See `ChecksumMismatch`.
+pub(crate) fn validate_checksum(input: &GetObjectRequest, output: &mut GetObjectOutput) {
+    let enabled = input
+        .checksum_mode
+        .as_deref()
+        .is_some_and(|mode| mode.eq_ignore_ascii_case("ENABLED"));
+    if !enabled || input.range.is_some() || input.part_number.is_some() {
+        return;
+    }
+    let checksum = match Checksum::reported(output) {
+        Some(checksum) => checksum,
+        None => return,
+    };
+    let size = output.content_length.map(|size| size.max(0) as usize);
+    output.body = output.body.take().map(|body| {
+        let len_hint = body.len_hint();
+        let validated = Validated {
+            body,
+            checksum: Some(checksum),
+            size,
+            received: 0,
+        };
+        match len_hint {
+            Some(len) => ByteStream::new_with_size(validated, len),
+            None => ByteStream::new(validated),
+        }
+    });
+}
+
+/// A body that fails with a `ChecksumMismatch` at its end if its checksum is wrong.
+struct Validated {
+    body: ByteStream,
+    checksum: Option<Checksum>,
+    // A body ending short of it isn't checked, it's truncated rather than corrupt.
+    size: Option<usize>,
+    received: usize,
+}
+
+impl Stream for Validated {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        match this.body.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                if let Some(ref mut checksum) = this.checksum {
+                    checksum.update(&chunk);
+                }
+                this.received += chunk.len();
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Poll::Ready(Some(Err(err))) => {
+                this.checksum = None;
+                Poll::Ready(Some(Err(err)))
+            }
+            Poll::Ready(None) => {
+                let complete = this.size.is_none_or(|size| size == this.received);
+                let mismatch = this.checksum.take().and_then(Checksum::mismatch);
+                match mismatch {
+                    Some(mismatch) if complete => Poll::Ready(Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        mismatch,
+                    )))),
+                    _ => Poll::Ready(None),
+                }
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Lookup table of the CRC-32C (Castagnoli) polynomial, reflected.
+const CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Continues the CRC-32C `crc` of the preceding bytes over `data`.
+fn crc32c(crc: u32, data: &[u8]) -> u32 {
+    !data.iter().fold(!crc, |crc, &byte| {
+        CRC32C_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{PutObjectRequest, S3Client, S3};
+    use futures::{FutureExt, TryStreamExt};
+    use rusoto_core::request::DispatchSignedRequestFuture;
+    use rusoto_core::{DispatchSignedRequest, Region};
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    type Sent = Arc<Mutex<Option<(BTreeMap<String, Vec<Vec<u8>>>, Vec<u8>)>>>;
+
+    /// Records the headers and the body of the request it answers.
+    #[derive(Clone, Default)]
+    struct Recorder(Sent);
+
+    impl DispatchSignedRequest for Recorder {
+        fn dispatch(
+            &self,
+            mut request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let sent = self.0.clone();
+            async move {
+                let body = match request.payload.take() {
+                    Some(SignedRequestPayload::Stream(stream)) => {
+                        stream.map_ok(|chunk| chunk.to_vec()).try_concat().await?
+                    }
+                    Some(SignedRequestPayload::Buffer(bytes)) => bytes.to_vec(),
+                    None => Vec::new(),
+                };
+                *sent.lock().unwrap() = Some((request.headers.clone(), body));
+                MockRequestDispatcher::default()
+                    .dispatch(request, None)
+                    .await
+            }
+            .boxed()
+        }
+    }
+
+    impl Recorder {
+        async fn put(&self, request: PutObjectRequest) -> (BTreeMap<String, String>, Vec<u8>) {
+            let client = S3Client::new_with(self.clone(), MockCredentialsProvider, Region::UsEast1);
+            client.put_object(request).await.unwrap();
+            let (headers, body) = self.0.lock().unwrap().take().unwrap();
+            let headers = headers
+                .into_iter()
+                .map(|(name, values)| (name, String::from_utf8(values[0].clone()).unwrap()))
+                .collect();
+            (headers, body)
+        }
+    }
+
+    fn object(body: ByteStream) -> PutObjectRequest {
+        PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            body: Some(body),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn streamed_bodies_are_sent_with_checksum_trailers() {
+        let data: Vec<u8> = (0..CHUNK_SIZE + 10).map(|i| i as u8).collect();
+        let pieces: Vec<Result<Bytes, io::Error>> = data
+            .chunks(1000)
+            .map(|piece| Ok(Bytes::copy_from_slice(piece)))
+            .collect();
+        let body = ByteStream::new_with_size(stream::iter(pieces), data.len());
+        let request = PutObjectRequest {
+            checksum_algorithm: Some("CRC32".to_owned()),
+            content_encoding: Some("gzip".to_owned()),
+            ..object(body)
+        };
+        let (headers, body) = Recorder::default().put(request).await;
+
+        assert_eq!(headers["content-encoding"], "aws-chunked,gzip");
+        assert_eq!(
+            headers["x-amz-content-sha256"],
+            "STREAMING-UNSIGNED-PAYLOAD-TRAILER"
+        );
+        assert_eq!(
+            headers["x-amz-decoded-content-length"],
+            data.len().to_string()
+        );
+        assert_eq!(headers["x-amz-trailer"], "x-amz-checksum-crc32");
+        assert_eq!(headers["content-length"], body.len().to_string());
+        assert!(!headers.contains_key("x-amz-checksum-crc32"));
+
+        let checksum = base64::encode(crc32fast::hash(&data).to_be_bytes());
+        let mut expected = b"10000\r\n".to_vec();
+        expected.extend_from_slice(&data[..CHUNK_SIZE]);
+        expected.extend_from_slice(b"\r\na\r\n");
+        expected.extend_from_slice(&data[CHUNK_SIZE..]);
+        expected.extend_from_slice(b"\r\n0\r\n");
+        expected.extend_from_slice(format!("x-amz-checksum-crc32:{}\r\n\r\n", checksum).as_bytes());
+        assert_eq!(body, expected);
+    }
+
+    #[tokio::test]
+    async fn given_checksums_are_sent_as_they_are() {
+        let request = PutObjectRequest {
+            checksum_algorithm: Some("SHA256".to_owned()),
+            checksum_sha256: Some("n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg=".to_owned()),
+            ..object(ByteStream::from(b"test".to_vec()))
+        };
+        let (headers, body) = Recorder::default().put(request).await;
+        assert_eq!(
+            headers["x-amz-checksum-sha256"],
+            "n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg="
+        );
+        assert_eq!(headers["x-amz-content-sha256"], "UNSIGNED-PAYLOAD");
+        assert!(!headers.contains_key("content-encoding"));
+        assert_eq!(body, b"test");
+    }
+
+    #[tokio::test]
+    async fn unknown_algorithms_and_sizes_are_rejected() {
+        let client = S3Client::new_with(
+            Recorder::default(),
+            MockCredentialsProvider,
+            Region::UsEast1,
+        );
+        let request = PutObjectRequest {
+            checksum_algorithm: Some("MD4".to_owned()),
+            ..object(ByteStream::from(b"test".to_vec()))
+        };
+        let err = client.put_object(request).await.unwrap_err();
+        assert_eq!(err.to_string(), "Unsupported checksum algorithm MD4");
+
+        let chunks = stream::iter(vec![Ok(Bytes::from_static(b"test"))]);
+        let request = PutObjectRequest {
+            checksum_algorithm: Some("CRC32C".to_owned()),
+            ..object(ByteStream::new(chunks))
+        };
+        let err = client.put_object(request).await.unwrap_err();
+        assert!(err.to_string().contains("unknown size"), "{}", err);
+    }
+
+    async fn read_object(checksum: &str, range: Option<&str>) -> Result<Vec<u8>, io::Error> {
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body("test")
+            .with_header("Content-Length", "4")
+            .with_header("x-amz-checksum-sha256", checksum);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            checksum_mode: Some("ENABLED".to_owned()),
+            range: range.map(str::to_owned),
+            ..Default::default()
+        };
+        let body = client.get_object(request).await.unwrap().body.unwrap();
+        body.map_ok(|chunk| chunk.to_vec()).try_concat().await
+    }
+
+    #[tokio::test]
+    async fn downloads_fail_when_checksums_mismatch() {
+        let correct = "n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg=";
+        assert_eq!(read_object(correct, None).await.unwrap(), b"test");
+
+        let wrong = "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=";
+        let err = read_object(wrong, None).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let mismatch = err.get_ref().unwrap().downcast_ref::<ChecksumMismatch>();
+        assert_eq!(
+            mismatch,
+            Some(&ChecksumMismatch {
+                algorithm: "SHA256",
+                expected: wrong.to_owned(),
+                actual: correct.to_owned(),
+            })
+        );
+
+        // The checksum is the one of the whole object, not of the range.
+        assert_eq!(
+            read_object(wrong, Some("bytes=0-3")).await.unwrap(),
+            b"test"
+        );
+    }
+
+    #[test]
+    fn crc32c_check_value() {
+        assert_eq!(crc32c(0, b"123456789"), 0xE306_9283);
+        assert_eq!(crc32c(crc32c(0, b"1234"), b"56789"), 0xE306_9283);
+    }
+}
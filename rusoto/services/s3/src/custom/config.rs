@@ -0,0 +1,430 @@
+use std::net::Ipv4Addr;
+
+use rusoto_core::region::Region;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Client, EndpointVariants, HttpClient};
+
+use super::access_point::address_access_point;
+use crate::generated::S3Client;
+
+/// How requests address their bucket.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AddressingStyle {
+    /// Use virtual-hosted addressing when the bucket name allows it, and path-style addressing
+    /// for `Region::Custom` endpoints and buckets whose names aren't valid host names or contain
+    /// dots (which the wildcard TLS certificate of S3 doesn't cover).
+    Auto,
+    /// Put the bucket in the path: `https://s3.us-east-1.amazonaws.com/bucket/key`. This is
+    /// what most S3 compatible servers, like MinIO, expect.
+    #[default]
+    Path,
+    /// Put the bucket in the host name: `https://bucket.s3.us-east-1.amazonaws.com/key`.
+    /// Requests for buckets whose names can't be used this way fail.
+    VirtualHosted,
+}
+
+/// Configuration of an `S3Client`, set with `S3Client::with_config`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3Config {
+    /// How requests address their bucket. Defaults to `AddressingStyle::Path`.
+    pub addressing_style: AddressingStyle,
+    /// Send object operations to the Transfer Acceleration endpoint,
+    /// `<bucket>.s3-accelerate.amazonaws.com` (or `s3-accelerate.dualstack` if dual-stack
+    /// endpoints are enabled on the client). Bucket operations, and all requests to a
+    /// `Region::Custom`, keep their endpoint. Requests for buckets whose names contain dots
+    /// fail, as acceleration doesn't support them.
+    pub use_accelerate_endpoint: bool,
+    /// Reject access point ARNs, used as bucket names, whose region differs from the region
+    /// of the client. By default requests for them are signed for, and sent to, the region of
+    /// the ARN. ARNs in another partition, e.g. `aws-cn` for a client in `us-east-1`, are
+    /// always rejected.
+    pub disable_cross_region_arns: bool,
+    /// Send the `Content-MD5` header with `PutObject` requests that don't set `content_md5`,
+    /// so S3 rejects bodies corrupted on their way. Operations that require the header always
+    /// send it. The body is read once to compute it before it's sent, so a body that isn't
+    /// replayable, like those of `ByteStream::from` and `ByteStream::from_path` are, is
+    /// buffered in memory.
+    pub put_object_content_md5: bool,
+}
+
+impl S3Config {
+    /// Rewrites `request`, whose endpoint has already been resolved for a client with
+    /// `variants` enabled, to use the endpoint and addressing this configuration asks for.
+    pub(crate) fn prepare_request(
+        &self,
+        request: &mut SignedRequest,
+        variants: EndpointVariants,
+    ) -> Result<(), String> {
+        if address_access_point(request, variants, !self.disable_cross_region_arns)? {
+            if self.use_accelerate_endpoint {
+                return Err("Transfer Acceleration can't be used with access point ARNs".to_owned());
+            }
+            return Ok(());
+        }
+        if self.use_accelerate_endpoint && accelerate(request, variants)? {
+            return Ok(());
+        }
+        address_bucket(self.addressing_style, request)
+    }
+}
+
+impl S3Client {
+    /// Creates a client that sends its requests without signing them, like the
+    /// `--no-sign-request` option of the AWS CLI, for buckets that allow anonymous access. No
+    /// credentials are read from the environment, so none need to be configured. The same
+    /// holds for clients created with `S3Client::new_with` and `StaticProvider::anonymous()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use rusoto_core::Region;
+    /// use rusoto_s3::{GetObjectRequest, S3Client, S3};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let s3 = S3Client::new_not_signing(Region::UsWest2);
+    /// let request = GetObjectRequest {
+    ///     bucket: "landsat-pds".to_owned(),
+    ///     key: "index.html".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let object = s3.get_object(request).await?;
+    /// let body = object.body.unwrap().map_ok(|chunk| chunk.to_vec()).try_concat().await?;
+    /// println!("{}", String::from_utf8_lossy(&body));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_not_signing(region: Region) -> S3Client {
+        let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
+        S3Client::new_with_client(Client::new_not_signing(dispatcher), region)
+    }
+
+    /// Sets the configuration this client uses for its requests.
+    pub fn with_config(mut self, config: S3Config) -> S3Client {
+        self.config = config;
+        self
+    }
+
+    /// Returns the configuration this client uses for its requests.
+    pub fn config(&self) -> &S3Config {
+        &self.config
+    }
+}
+
+/// Moves the bucket from the path of `request`, which is `/<bucket>` or `/<bucket>/<key>` for
+/// bucket operations, into its host name if `style` asks for virtual-hosted addressing.
+pub(crate) fn address_bucket(
+    style: AddressingStyle,
+    request: &mut SignedRequest,
+) -> Result<(), String> {
+    let (bucket, rest) = match split_bucket(&request.path) {
+        Some(parts) => parts,
+        None => return Ok(()),
+    };
+    let virtual_hosted = match style {
+        AddressingStyle::Path => false,
+        AddressingStyle::Auto => match request.region {
+            Region::Custom { .. } => false,
+            _ => is_dns_compatible(bucket) && !(bucket.contains('.') && is_tls(request)),
+        },
+        AddressingStyle::VirtualHosted => {
+            if !is_dns_compatible(bucket) {
+                return Err(format!(
+                    "bucket name {:?} can't be used with virtual-hosted addressing",
+                    bucket
+                ));
+            }
+            if bucket.contains('.') && is_tls(request) {
+                return Err(format!(
+                    "bucket name {:?} contains dots, which can't be used with virtual-hosted \
+                     addressing over HTTPS",
+                    bucket
+                ));
+            }
+            true
+        }
+    };
+    if virtual_hosted {
+        let hostname = format!("{}.{}", bucket, request.hostname());
+        let path = if rest.is_empty() { "/" } else { rest }.to_owned();
+        request.hostname = Some(hostname);
+        request.path = path;
+    }
+    Ok(())
+}
+
+/// Moves object operations of `request` to the Transfer Acceleration endpoint. Returns whether
+/// the request was moved.
+pub(crate) fn accelerate(
+    request: &mut SignedRequest,
+    variants: EndpointVariants,
+) -> Result<bool, String> {
+    let (bucket, key) = match split_bucket(&request.path) {
+        Some((bucket, key)) if key.len() > 1 => (bucket, key),
+        _ => return Ok(false),
+    };
+    match request.region {
+        Region::Custom { .. } => return Ok(false),
+        Region::CnNorth1 | Region::CnNorthwest1 => {
+            return Err(format!(
+                "Transfer Acceleration isn't available in {}",
+                request.region.name()
+            ))
+        }
+        _ => {}
+    }
+    if variants.fips {
+        return Err("Transfer Acceleration doesn't have FIPS endpoints".to_owned());
+    }
+    if bucket.contains('.') {
+        return Err(format!(
+            "bucket name {:?} contains dots, which Transfer Acceleration doesn't allow",
+            bucket
+        ));
+    }
+    if !is_dns_compatible(bucket) {
+        return Err(format!(
+            "bucket name {:?} can't be used with Transfer Acceleration",
+            bucket
+        ));
+    }
+    let dualstack = if variants.dualstack { ".dualstack" } else { "" };
+    let hostname = format!("{}.s3-accelerate{}.amazonaws.com", bucket, dualstack);
+    let key = key.to_owned();
+    request.scheme = Some("https".to_owned());
+    request.hostname = Some(hostname);
+    request.path = key;
+    Ok(true)
+}
+
+fn split_bucket(path: &str) -> Option<(&str, &str)> {
+    if !path.starts_with('/') {
+        return None;
+    }
+    let path = &path[1..];
+    let (bucket, rest) = match path.find('/') {
+        Some(pos) => (&path[..pos], &path[pos..]),
+        None => (path, ""),
+    };
+    if bucket.is_empty() {
+        None
+    } else {
+        Some((bucket, rest))
+    }
+}
+
+fn is_tls(request: &SignedRequest) -> bool {
+    request.scheme() == "https"
+}
+
+/// Whether `bucket` can be used as a host name label: 3 to 63 lowercase letters, digits, dots
+/// and hyphens, starting and ending with a letter or digit, without empty labels, and not
+/// formatted like an IP address.
+pub(crate) fn is_dns_compatible(bucket: &str) -> bool {
+    if bucket.len() < 3 || bucket.len() > 63 || bucket.parse::<Ipv4Addr>().is_ok() {
+        return false;
+    }
+    bucket.split('.').all(|label| {
+        let bytes = label.as_bytes();
+        !bytes.is_empty()
+            && bytes[0].is_ascii_alphanumeric()
+            && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+            && bytes
+                .iter()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addressed(style: AddressingStyle, region: Region, path: &str) -> Result<String, String> {
+        let mut request = SignedRequest::new("GET", "s3", &region, path);
+        address_bucket(style, &mut request)?;
+        Ok(format!(
+            "{}://{}{}",
+            request.scheme(),
+            request.hostname(),
+            request.path
+        ))
+    }
+
+    #[test]
+    fn dns_compatible_bucket_names() {
+        for bucket in &["abc", "my-bucket", "my.bucket.name", "0bucket1"] {
+            assert!(is_dns_compatible(bucket), "{}", bucket);
+        }
+        for bucket in &[
+            "ab",
+            "MyBucket",
+            "my_bucket",
+            "-bucket",
+            "bucket-",
+            "my..bucket",
+            "my.-bucket",
+            "192.168.1.1",
+            &"a".repeat(64),
+        ] {
+            assert!(!is_dns_compatible(bucket), "{}", bucket);
+        }
+    }
+
+    #[test]
+    fn path_style_leaves_requests_alone() {
+        assert_eq!(
+            addressed(AddressingStyle::Path, Region::UsEast1, "/bucket/key").unwrap(),
+            "https://s3.us-east-1.amazonaws.com/bucket/key"
+        );
+    }
+
+    #[test]
+    fn virtual_hosted_moves_bucket_into_host() {
+        assert_eq!(
+            addressed(
+                AddressingStyle::VirtualHosted,
+                Region::UsWest2,
+                "/bucket/a/key"
+            )
+            .unwrap(),
+            "https://bucket.s3.us-west-2.amazonaws.com/a/key"
+        );
+        assert_eq!(
+            addressed(AddressingStyle::Auto, Region::UsWest2, "/bucket").unwrap(),
+            "https://bucket.s3.us-west-2.amazonaws.com/"
+        );
+        assert_eq!(
+            addressed(AddressingStyle::Auto, Region::UsWest2, "/").unwrap(),
+            "https://s3.us-west-2.amazonaws.com/"
+        );
+    }
+
+    #[test]
+    fn auto_falls_back_to_path_style() {
+        assert_eq!(
+            addressed(AddressingStyle::Auto, Region::UsEast1, "/my.bucket/key").unwrap(),
+            "https://s3.us-east-1.amazonaws.com/my.bucket/key"
+        );
+        assert_eq!(
+            addressed(AddressingStyle::Auto, Region::UsEast1, "/MyBucket/key").unwrap(),
+            "https://s3.us-east-1.amazonaws.com/MyBucket/key"
+        );
+    }
+
+    #[test]
+    fn virtual_hosted_rejects_incompatible_buckets() {
+        let err = addressed(
+            AddressingStyle::VirtualHosted,
+            Region::UsEast1,
+            "/MyBucket/key",
+        )
+        .unwrap_err();
+        assert!(err.contains("\"MyBucket\""), "{}", err);
+        let err = addressed(
+            AddressingStyle::VirtualHosted,
+            Region::UsEast1,
+            "/my.bucket/key",
+        )
+        .unwrap_err();
+        assert!(err.contains("contains dots"), "{}", err);
+    }
+
+    #[test]
+    fn custom_region_endpoints() {
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        assert_eq!(
+            addressed(AddressingStyle::Auto, region.clone(), "/bucket/key").unwrap(),
+            "http://localhost:9000/bucket/key"
+        );
+        // Dotted names are fine without TLS.
+        assert_eq!(
+            addressed(AddressingStyle::VirtualHosted, region, "/my.bucket/key").unwrap(),
+            "http://my.bucket.localhost:9000/key"
+        );
+    }
+
+    fn accelerated(region: Region, path: &str, dualstack: bool) -> Result<String, String> {
+        let config = S3Config {
+            use_accelerate_endpoint: true,
+            ..Default::default()
+        };
+        let variants = EndpointVariants {
+            fips: false,
+            dualstack,
+        };
+        let mut request = SignedRequest::new("GET", "s3", &region, path);
+        config.prepare_request(&mut request, variants)?;
+        Ok(format!(
+            "{}://{}{}",
+            request.scheme(),
+            request.hostname(),
+            request.path
+        ))
+    }
+
+    #[test]
+    fn accelerate_object_operations() {
+        assert_eq!(
+            accelerated(Region::UsEast1, "/bucket/key", false).unwrap(),
+            "https://bucket.s3-accelerate.amazonaws.com/key"
+        );
+        assert_eq!(
+            accelerated(Region::EuWest1, "/bucket/a/key", true).unwrap(),
+            "https://bucket.s3-accelerate.dualstack.amazonaws.com/a/key"
+        );
+    }
+
+    #[test]
+    fn accelerate_keeps_bucket_operations_regional() {
+        assert_eq!(
+            accelerated(Region::UsEast1, "/", false).unwrap(),
+            "https://s3.us-east-1.amazonaws.com/"
+        );
+        assert_eq!(
+            accelerated(Region::UsEast1, "/bucket", false).unwrap(),
+            "https://s3.us-east-1.amazonaws.com/bucket"
+        );
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        assert_eq!(
+            accelerated(region, "/bucket/key", false).unwrap(),
+            "http://localhost:9000/bucket/key"
+        );
+    }
+
+    #[test]
+    fn accelerate_rejects_dotted_buckets() {
+        let err = accelerated(Region::UsEast1, "/my.bucket/key", false).unwrap_err();
+        assert_eq!(
+            err,
+            "bucket name \"my.bucket\" contains dots, which Transfer Acceleration doesn't allow"
+        );
+        assert!(accelerated(Region::CnNorth1, "/bucket/key", false).is_err());
+    }
+
+    #[test]
+    fn access_point_arns_skip_bucket_addressing() {
+        let path = "/arn:aws:s3:us-east-1:123456789012:accesspoint/myap/key";
+        let config = S3Config {
+            addressing_style: AddressingStyle::VirtualHosted,
+            ..Default::default()
+        };
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, path);
+        config
+            .prepare_request(&mut request, Default::default())
+            .unwrap();
+        assert_eq!(
+            request.hostname(),
+            "myap-123456789012.s3-accesspoint.us-east-1.amazonaws.com"
+        );
+        assert_eq!(request.path, "/key");
+
+        let err = accelerated(Region::UsEast1, path, false).unwrap_err();
+        assert!(err.contains("access point ARNs"), "{}", err);
+    }
+}
@@ -0,0 +1,903 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{FuturesUnordered, StreamExt, TryStreamExt};
+use rusoto_core::{ByteStream, RusotoError};
+use tokio::io::{AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+
+use super::checksum::{Checksum, ChecksumMismatch};
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, HeadObjectError, HeadObjectRequest,
+    S3Client, S3,
+};
+
+/// The range size used by `ParallelDownloader` unless configured otherwise (8 MiB).
+pub const DEFAULT_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+const DEFAULT_CONCURRENCY: usize = 4;
+const DEFAULT_RANGE_ATTEMPTS: usize = 3;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Errors returned by `ParallelDownloader` and `download_file`.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// Writing to the destination failed.
+    Io(io::Error),
+    /// Looking up the size and ETag of the object failed.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// Downloading a range failed.
+    GetObject {
+        /// The range that failed, as sent in the `Range` header.
+        range: String,
+        /// Error returned by the last attempt.
+        error: RusotoError<GetObjectError>,
+    },
+    /// Reading the body of a range failed on every attempt.
+    ReadBody {
+        /// The range that failed, as sent in the `Range` header.
+        range: String,
+        /// Error returned by the last attempt.
+        error: io::Error,
+    },
+    /// The object was replaced while it was being downloaded, so its ETag no longer matches.
+    ObjectChanged {
+        /// ETag of the object when the download started.
+        e_tag: String,
+    },
+    /// The number of bytes received doesn't match the `Content-Length` of the object, even
+    /// after resuming the download.
+    SizeMismatch {
+        /// The `Content-Length` of the object.
+        expected: u64,
+        /// The number of bytes received.
+        received: u64,
+    },
+    /// The checksum of the bytes received doesn't match the one S3 reported for the object.
+    ChecksumMismatch {
+        /// The checksum algorithm, e.g. `CRC32C`, or `MD5` when checking the ETag.
+        algorithm: &'static str,
+        /// The checksum S3 reported.
+        expected: String,
+        /// The checksum of the bytes received.
+        actual: String,
+    },
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DownloadError::Io(ref e) => write!(f, "failed to write download: {}", e),
+            DownloadError::HeadObject(ref e) => write!(f, "failed to look up object: {}", e),
+            DownloadError::GetObject {
+                ref range,
+                ref error,
+            } => write!(f, "failed to download {}: {}", range, error),
+            DownloadError::ReadBody {
+                ref range,
+                ref error,
+            } => write!(f, "failed to read {}: {}", range, error),
+            DownloadError::ObjectChanged { ref e_tag } => write!(
+                f,
+                "object changed during download, it no longer has ETag {}",
+                e_tag
+            ),
+            DownloadError::SizeMismatch { expected, received } => write!(
+                f,
+                "received {} bytes of an object of {} bytes",
+                received, expected
+            ),
+            DownloadError::ChecksumMismatch {
+                algorithm,
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "{} checksum of the download is {}, expected {}",
+                algorithm, actual, expected
+            ),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DownloadError::Io(ref e) => Some(e),
+            DownloadError::HeadObject(ref e) => Some(e),
+            DownloadError::GetObject { ref error, .. } => Some(error),
+            DownloadError::ReadBody { ref error, .. } => Some(error),
+            DownloadError::ObjectChanged { .. }
+            | DownloadError::SizeMismatch { .. }
+            | DownloadError::ChecksumMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<ChecksumMismatch> for DownloadError {
+    fn from(mismatch: ChecksumMismatch) -> DownloadError {
+        DownloadError::ChecksumMismatch {
+            algorithm: mismatch.algorithm,
+            expected: mismatch.expected,
+            actual: mismatch.actual,
+        }
+    }
+}
+
+/// Downloads an S3 object using concurrent ranged `GetObject` requests.
+///
+/// The size and ETag of the object are looked up with `HeadObject`, and every range is
+/// requested with `IfMatch` set to that ETag, so an object that is overwritten mid-download
+/// fails with `DownloadError::ObjectChanged` instead of producing a mix of both versions.
+/// Ranges that fail with a transient error are retried.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::ParallelDownloader;
+/// use rusoto_s3::S3Client;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let downloader =
+///     ParallelDownloader::builder(S3Client::new(Region::UsEast1), "my-bucket", "my-key")
+///         .chunk_size(64 * 1024 * 1024)
+///         .concurrency(16)
+///         .build();
+/// let size = downloader.download_file("large-file.bin").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ParallelDownloader {
+    client: S3Client,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    chunk_size: u64,
+    concurrency: usize,
+    range_attempts: usize,
+}
+
+impl fmt::Debug for ParallelDownloader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParallelDownloader")
+            .field("bucket", &self.bucket)
+            .field("key", &self.key)
+            .field("version_id", &self.version_id)
+            .field("chunk_size", &self.chunk_size)
+            .field("concurrency", &self.concurrency)
+            .field("range_attempts", &self.range_attempts)
+            .finish()
+    }
+}
+
+/// Builder for `ParallelDownloader`.
+#[derive(Clone, Debug)]
+pub struct ParallelDownloaderBuilder {
+    downloader: ParallelDownloader,
+}
+
+impl ParallelDownloaderBuilder {
+    /// Sets the size of each range, in bytes. Defaults to `DEFAULT_CHUNK_SIZE`.
+    pub fn chunk_size(mut self, chunk_size: u64) -> Self {
+        self.downloader.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of ranges downloaded at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.downloader.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets how many times each range is attempted before the download fails. Defaults to 3.
+    pub fn range_attempts(mut self, attempts: usize) -> Self {
+        self.downloader.range_attempts = attempts.max(1);
+        self
+    }
+
+    /// Downloads a specific version of the object.
+    pub fn version_id<V: Into<String>>(mut self, version_id: V) -> Self {
+        self.downloader.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Builds the `ParallelDownloader`.
+    pub fn build(self) -> ParallelDownloader {
+        self.downloader
+    }
+}
+
+impl ParallelDownloader {
+    /// Creates a builder for a downloader that downloads `key` from `bucket`.
+    pub fn builder<B, K>(client: S3Client, bucket: B, key: K) -> ParallelDownloaderBuilder
+    where
+        B: Into<String>,
+        K: Into<String>,
+    {
+        ParallelDownloaderBuilder {
+            downloader: ParallelDownloader {
+                client,
+                bucket: bucket.into(),
+                key: key.into(),
+                version_id: None,
+                chunk_size: DEFAULT_CHUNK_SIZE,
+                concurrency: DEFAULT_CONCURRENCY,
+                range_attempts: DEFAULT_RANGE_ATTEMPTS,
+            },
+        }
+    }
+
+    /// Downloads the object into the file at `path`, creating or truncating it.
+    ///
+    /// Returns the size of the object.
+    pub async fn download_file<P: AsRef<Path>>(&self, path: P) -> Result<u64, DownloadError> {
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(DownloadError::Io)?;
+        self.download_to(&mut file).await
+    }
+
+    /// Downloads the object into `writer`, writing each range at its offset from the start.
+    ///
+    /// Returns the size of the object.
+    pub async fn download_to<W>(&self, writer: &mut W) -> Result<u64, DownloadError>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let head = self
+            .client
+            .head_object(HeadObjectRequest {
+                bucket: self.bucket.clone(),
+                key: self.key.clone(),
+                version_id: self.version_id.clone(),
+                ..Default::default()
+            })
+            .await
+            .map_err(DownloadError::HeadObject)?;
+        let e_tag = head.e_tag.ok_or_else(|| {
+            DownloadError::HeadObject(RusotoError::ParseError(
+                "HeadObject response has no ETag".to_owned(),
+            ))
+        })?;
+        let size = head.content_length.ok_or_else(|| {
+            DownloadError::HeadObject(RusotoError::ParseError(
+                "HeadObject response has no Content-Length".to_owned(),
+            ))
+        })? as u64;
+
+        let chunk_size = self.chunk_size;
+        let mut ranges = (0..size)
+            .step_by(chunk_size as usize)
+            .map(|start| (start, (start + chunk_size).min(size) - 1));
+        let mut in_flight = FuturesUnordered::new();
+        loop {
+            while in_flight.len() < self.concurrency {
+                match ranges.next() {
+                    Some((start, end)) => in_flight.push(self.download_range(start, end, &e_tag)),
+                    None => break,
+                }
+            }
+            let (start, body) = match in_flight.next().await {
+                Some(result) => result?,
+                None => break,
+            };
+            writer
+                .seek(SeekFrom::Start(start))
+                .await
+                .map_err(DownloadError::Io)?;
+            writer.write_all(&body).await.map_err(DownloadError::Io)?;
+        }
+        writer.flush().await.map_err(DownloadError::Io)?;
+        Ok(size)
+    }
+
+    async fn download_range(
+        &self,
+        start: u64,
+        end: u64,
+        e_tag: &str,
+    ) -> Result<(u64, Bytes), DownloadError> {
+        let range = format!("bytes={}-{}", start, end);
+        let mut attempt = 1;
+        loop {
+            let request = GetObjectRequest {
+                bucket: self.bucket.clone(),
+                key: self.key.clone(),
+                version_id: self.version_id.clone(),
+                range: Some(range.clone()),
+                if_match: Some(e_tag.to_owned()),
+                ..Default::default()
+            };
+            let last_attempt = attempt >= self.range_attempts;
+            match self.client.get_object(request).await {
+                Ok(output) => match read_body(output.body, end - start + 1).await {
+                    Ok(body) => return Ok((start, body)),
+                    Err(error) if last_attempt => {
+                        return Err(DownloadError::ReadBody { range, error })
+                    }
+                    Err(_) => {}
+                },
+                Err(RusotoError::Unknown(ref response)) if response.status.as_u16() == 412 => {
+                    return Err(DownloadError::ObjectChanged {
+                        e_tag: e_tag.to_owned(),
+                    });
+                }
+                Err(error) if last_attempt || !is_transient(&error) => {
+                    return Err(DownloadError::GetObject { range, error });
+                }
+                Err(_) => {}
+            }
+            tokio::time::delay_for(RETRY_BASE_DELAY * 2u32.pow(attempt as u32 - 1)).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Downloads `key` from `bucket` into the file at `path`, which is only replaced once the
+/// download is complete and valid.
+///
+/// The object is streamed into a temporary file next to `path`. If the body fails or ends
+/// early, e.g. on a connection reset, the download resumes from the last byte received with a
+/// ranged `GetObject` requiring the ETag of the object, so an object overwritten in between
+/// fails with `DownloadError::ObjectChanged`. Transient errors are retried, 3 attempts in all.
+///
+/// The download is then validated: its size against the `Content-Length` of the object, and its
+/// checksum against the `x-amz-checksum-*` header S3 returns for objects uploaded with one or,
+/// failing that, against the ETag, which is the MD5 digest of objects uploaded in a single part
+/// without SSE-KMS or SSE-C. Composite checksums of multipart uploads, which aren't those of the
+/// whole object, aren't checked. Validation failures are `DownloadError::SizeMismatch` and
+/// `DownloadError::ChecksumMismatch`. On any error, the temporary file is removed and `path` is
+/// left untouched.
+///
+/// Returns the size of the object.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::download_file;
+/// use rusoto_s3::S3Client;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let size = download_file(&client, "my-bucket", "my-key", "my-file.bin").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_file<B, K, P>(
+    client: &S3Client,
+    bucket: B,
+    key: K,
+    path: P,
+) -> Result<u64, DownloadError>
+where
+    B: Into<String>,
+    K: Into<String>,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let temp_path = temp_path_for(path);
+    let result = match download_validated(client, bucket.into(), key.into(), &temp_path).await {
+        Ok(size) => tokio::fs::rename(&temp_path, path)
+            .await
+            .map(|_| size)
+            .map_err(DownloadError::Io),
+        Err(err) => Err(err),
+    };
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+    }
+    result
+}
+
+/// The temporary file a download into `path` is written to, in the same directory so that it
+/// can be renamed into place.
+fn temp_path_for(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.{}.download", name, std::process::id()))
+}
+
+/// The object being downloaded by `download_file`, as its first response described it.
+struct DownloadedObject {
+    e_tag: Option<String>,
+    size: u64,
+    checksum: Option<Checksum>,
+}
+
+async fn download_validated(
+    client: &S3Client,
+    bucket: String,
+    key: String,
+    temp_path: &Path,
+) -> Result<u64, DownloadError> {
+    let mut file = tokio::fs::File::create(temp_path)
+        .await
+        .map_err(DownloadError::Io)?;
+    let mut object: Option<DownloadedObject> = None;
+    let mut received = 0;
+    let mut attempt = 1;
+    loop {
+        let range = format!("bytes={}-", received);
+        let (if_match, resumed_range) = match object {
+            Some(ref object) => (object.e_tag.clone(), Some(range.clone())),
+            None => (None, None),
+        };
+        let request = GetObjectRequest {
+            bucket: bucket.clone(),
+            key: key.clone(),
+            checksum_mode: Some("ENABLED".to_owned()),
+            if_match,
+            range: resumed_range,
+            ..Default::default()
+        };
+        let last_attempt = attempt >= DEFAULT_RANGE_ATTEMPTS;
+        match client.get_object(request).await {
+            Ok(mut output) => {
+                let body = output.body.take();
+                let object = object.get_or_insert_with(|| DownloadedObject::of(&output));
+                let written =
+                    write_body(body, &range, &mut file, &mut object.checksum, &mut received).await;
+                match written {
+                    Ok(()) if received >= object.size => break,
+                    Ok(()) | Err(DownloadError::ReadBody { .. }) if !last_attempt => {}
+                    Ok(()) => {
+                        return Err(DownloadError::SizeMismatch {
+                            expected: object.size,
+                            received,
+                        })
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+            Err(RusotoError::Unknown(ref response)) if response.status.as_u16() == 412 => {
+                return Err(DownloadError::ObjectChanged {
+                    e_tag: object.and_then(|object| object.e_tag).unwrap_or_default(),
+                });
+            }
+            Err(error) if last_attempt || !is_transient(&error) => {
+                return Err(DownloadError::GetObject { range, error });
+            }
+            Err(_) => {}
+        }
+        tokio::time::delay_for(RETRY_BASE_DELAY * 2u32.pow(attempt as u32 - 1)).await;
+        attempt += 1;
+    }
+    file.flush().await.map_err(DownloadError::Io)?;
+    file.sync_all().await.map_err(DownloadError::Io)?;
+
+    let object = object.expect("a response was received");
+    if received != object.size {
+        return Err(DownloadError::SizeMismatch {
+            expected: object.size,
+            received,
+        });
+    }
+    if let Some(mismatch) = object.checksum.and_then(Checksum::mismatch) {
+        return Err(mismatch.into());
+    }
+    Ok(received)
+}
+
+impl DownloadedObject {
+    fn of(output: &GetObjectOutput) -> DownloadedObject {
+        DownloadedObject {
+            e_tag: output.e_tag.clone(),
+            size: output.content_length.unwrap_or_default().max(0) as u64,
+            checksum: Checksum::reported(output).or_else(|| Checksum::e_tag(output)),
+        }
+    }
+}
+
+/// Streams the body of the response to `range` into `file`, adding the bytes written to
+/// `received` and `checksum`.
+async fn write_body(
+    body: Option<ByteStream>,
+    range: &str,
+    file: &mut tokio::fs::File,
+    checksum: &mut Option<Checksum>,
+    received: &mut u64,
+) -> Result<(), DownloadError> {
+    let mut body = match body {
+        Some(body) => body,
+        None => return Ok(()),
+    };
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|error| {
+            // `GetObject` checked the checksum S3 reported itself.
+            match error
+                .get_ref()
+                .and_then(|e| e.downcast_ref::<ChecksumMismatch>())
+            {
+                Some(mismatch) => mismatch.clone().into(),
+                None => DownloadError::ReadBody {
+                    range: range.to_owned(),
+                    error,
+                },
+            }
+        })?;
+        file.write_all(&chunk).await.map_err(DownloadError::Io)?;
+        if let Some(ref mut checksum) = *checksum {
+            checksum.update(&chunk);
+        }
+        *received += chunk.len() as u64;
+    }
+    Ok(())
+}
+
+fn is_transient(error: &RusotoError<GetObjectError>) -> bool {
+    match *error {
+        RusotoError::HttpDispatch(_) | RusotoError::Timeout(_) => true,
+        RusotoError::Unknown(ref response) => {
+            response.status.is_server_error() || response.status.as_u16() == 429
+        }
+        _ => false,
+    }
+}
+
+/// Reads a range body, failing if it isn't exactly `expected` bytes long.
+async fn read_body(
+    body: Option<rusoto_core::ByteStream>,
+    expected: u64,
+) -> Result<Bytes, io::Error> {
+    let body = match body {
+        Some(body) => body,
+        None => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "empty body")),
+    };
+    let bytes = body
+        .try_fold(BytesMut::new(), |mut buf, chunk| async move {
+            buf.extend_from_slice(&chunk);
+            Ok(buf)
+        })
+        .await?;
+    if bytes.len() as u64 != expected {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("expected {} bytes, received {}", expected, bytes.len()),
+        ));
+    }
+    Ok(bytes.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::request::HttpDispatchError;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::{DispatchSignedRequest, Region};
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use std::sync::{Arc, Mutex};
+
+    const OBJECT: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+    /// Serves `OBJECT` for HEAD and ranged GET requests and records the ranges requested.
+    #[derive(Clone)]
+    struct RangeDispatcher {
+        e_tag: Arc<Mutex<String>>,
+        // Number of times a range starting at the given offset fails before it succeeds.
+        failures: Arc<Mutex<HashMap<u64, usize>>>,
+        ranges: Arc<Mutex<Vec<String>>>,
+        // ETag the object is given right after it has been looked up, simulating an overwrite.
+        replacement_e_tag: Arc<Mutex<Option<String>>>,
+        body: &'static str,
+    }
+
+    impl RangeDispatcher {
+        fn new(body: &'static str) -> Self {
+            RangeDispatcher {
+                e_tag: Arc::new(Mutex::new("\"v1\"".to_owned())),
+                failures: Default::default(),
+                ranges: Default::default(),
+                replacement_e_tag: Default::default(),
+                body,
+            }
+        }
+
+        fn downloader(&self) -> ParallelDownloaderBuilder {
+            let client = S3Client::new_with(self.clone(), MockCredentialsProvider, Region::UsEast1);
+            ParallelDownloader::builder(client, "bucket", "key")
+        }
+    }
+
+    impl DispatchSignedRequest for RangeDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> rusoto_core::request::DispatchSignedRequestFuture {
+            let header = |name: &str| {
+                request
+                    .headers
+                    .get(name)
+                    .map(|values| String::from_utf8(values[0].clone()).unwrap())
+            };
+            let e_tag = self.e_tag.lock().unwrap().clone();
+            let mock = match request.method() {
+                "HEAD" => {
+                    if let Some(replacement) = self.replacement_e_tag.lock().unwrap().take() {
+                        *self.e_tag.lock().unwrap() = replacement;
+                    }
+                    MockRequestDispatcher::with_status(200)
+                        .with_header("Content-Length", &self.body.len().to_string())
+                        .with_header("ETag", &e_tag)
+                }
+                "GET" if header("if-match") != Some(e_tag) => {
+                    MockRequestDispatcher::with_status(412)
+                }
+                "GET" => {
+                    let range = header("range").unwrap();
+                    self.ranges.lock().unwrap().push(range.clone());
+                    let mut bounds = range.trim_start_matches("bytes=").split('-');
+                    let start: usize = bounds.next().unwrap().parse().unwrap();
+                    let end: usize = bounds.next().unwrap().parse().unwrap();
+                    let mut failures = self.failures.lock().unwrap();
+                    match failures.get_mut(&(start as u64)) {
+                        Some(remaining) if *remaining > 0 => {
+                            *remaining -= 1;
+                            MockRequestDispatcher::with_status(503)
+                        }
+                        _ => MockRequestDispatcher::with_status(206)
+                            .with_body(&self.body[start..=end]),
+                    }
+                }
+                method => MockRequestDispatcher::with_dispatch_error(HttpDispatchError::new(
+                    format!("unexpected {} request", method),
+                )),
+            };
+            mock.dispatch(request, timeout)
+        }
+    }
+
+    #[tokio::test]
+    async fn downloads_ranges_into_writer() {
+        let dispatcher = RangeDispatcher::new(OBJECT);
+        let downloader = dispatcher
+            .downloader()
+            .chunk_size(10)
+            .concurrency(3)
+            .build();
+
+        let mut writer = Cursor::new(Vec::new());
+        let size = downloader.download_to(&mut writer).await.unwrap();
+
+        assert_eq!(size, OBJECT.len() as u64);
+        assert_eq!(writer.into_inner(), OBJECT.as_bytes());
+        let mut ranges = dispatcher.ranges.lock().unwrap().clone();
+        ranges.sort();
+        assert_eq!(
+            ranges,
+            vec!["bytes=0-9", "bytes=10-19", "bytes=20-29", "bytes=30-35"]
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_transient_range_errors() {
+        let dispatcher = RangeDispatcher::new(OBJECT);
+        dispatcher.failures.lock().unwrap().insert(20, 1);
+        let downloader = dispatcher.downloader().chunk_size(20).build();
+
+        let mut writer = Cursor::new(Vec::new());
+        downloader.download_to(&mut writer).await.unwrap();
+
+        assert_eq!(writer.into_inner(), OBJECT.as_bytes());
+        assert_eq!(dispatcher.ranges.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_range_attempts() {
+        let dispatcher = RangeDispatcher::new(OBJECT);
+        dispatcher.failures.lock().unwrap().insert(0, 2);
+        let downloader = dispatcher.downloader().range_attempts(2).build();
+
+        let err = downloader
+            .download_to(&mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        match err {
+            DownloadError::GetObject { range, .. } => assert_eq!(range, "bytes=0-35"),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_when_object_changes() {
+        let dispatcher = RangeDispatcher::new(OBJECT);
+        *dispatcher.replacement_e_tag.lock().unwrap() = Some("\"v2\"".to_owned());
+        let downloader = dispatcher.downloader().chunk_size(10).build();
+
+        let err = downloader
+            .download_to(&mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        match err {
+            DownloadError::ObjectChanged { e_tag } => assert_eq!(e_tag, "\"v1\""),
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(dispatcher.ranges.lock().unwrap().is_empty());
+    }
+
+    // The Range and If-Match headers of a request.
+    type Conditions = (Option<String>, Option<String>);
+
+    /// Serves `OBJECT` for `GetObject`, cutting the first bodies short, and records the `Range`
+    /// and `If-Match` headers of the requests.
+    #[derive(Clone, Default)]
+    struct ObjectDispatcher {
+        headers: Vec<(&'static str, &'static str)>,
+        // Lengths of the first bodies served, shorter than their Content-Length.
+        truncated: Arc<Mutex<Vec<usize>>>,
+        // Whether the object is replaced after its first response, failing resumed requests.
+        replaced: bool,
+        requests: Arc<Mutex<Vec<Conditions>>>,
+    }
+
+    impl ObjectDispatcher {
+        fn with_headers(headers: Vec<(&'static str, &'static str)>) -> Self {
+            ObjectDispatcher {
+                headers,
+                ..Default::default()
+            }
+        }
+
+        async fn download(&self, path: &Path) -> Result<u64, DownloadError> {
+            let client = S3Client::new_with(self.clone(), MockCredentialsProvider, Region::UsEast1);
+            download_file(&client, "bucket", "key", path).await
+        }
+    }
+
+    impl DispatchSignedRequest for ObjectDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> rusoto_core::request::DispatchSignedRequestFuture {
+            let header = |name: &str| {
+                request
+                    .headers
+                    .get(name)
+                    .map(|values| String::from_utf8(values[0].clone()).unwrap())
+            };
+            assert_eq!(header("x-amz-checksum-mode").as_deref(), Some("ENABLED"));
+            let (range, if_match) = (header("range"), header("if-match"));
+            self.requests
+                .lock()
+                .unwrap()
+                .push((range.clone(), if_match.clone()));
+            if self.replaced && if_match.is_some() {
+                return MockRequestDispatcher::with_status(412).dispatch(request, timeout);
+            }
+
+            let start: usize = range
+                .map(|range| {
+                    range["bytes=".len()..]
+                        .trim_end_matches('-')
+                        .parse()
+                        .unwrap()
+                })
+                .unwrap_or(0);
+            let mut body = &OBJECT[start..];
+            let mut truncated = self.truncated.lock().unwrap();
+            if !truncated.is_empty() {
+                body = &body[..truncated.remove(0)];
+            }
+            let mut mock = MockRequestDispatcher::with_status(if start > 0 { 206 } else { 200 })
+                .with_body(body)
+                .with_header("Content-Length", &(OBJECT.len() - start).to_string());
+            for (name, value) in &self.headers {
+                mock = mock.with_header(name, value);
+            }
+            mock.dispatch(request, timeout)
+        }
+    }
+
+    fn destination(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rusoto-download-{}-{}", std::process::id(), name))
+    }
+
+    #[tokio::test]
+    async fn download_file_resumes_truncated_bodies() {
+        let dispatcher =
+            ObjectDispatcher::with_headers(vec![("ETag", "\"e9b1713db620f1e3a14b6812de523f4b\"")]);
+        dispatcher.truncated.lock().unwrap().extend(vec![10, 5]);
+        let path = destination("resumed");
+
+        assert_eq!(dispatcher.download(&path).await.unwrap(), 36);
+        assert_eq!(std::fs::read(&path).unwrap(), OBJECT.as_bytes());
+        assert!(!temp_path_for(&path).exists());
+        std::fs::remove_file(&path).unwrap();
+        let e_tag = Some("\"e9b1713db620f1e3a14b6812de523f4b\"".to_owned());
+        assert_eq!(
+            *dispatcher.requests.lock().unwrap(),
+            vec![
+                (None, None),
+                (Some("bytes=10-".to_owned()), e_tag.clone()),
+                (Some("bytes=15-".to_owned()), e_tag),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn download_file_validates_checksums() {
+        // The checksum header is preferred over a multipart ETag, which is no MD5 digest.
+        let dispatcher = ObjectDispatcher::with_headers(vec![
+            ("ETag", "\"d41d8cd98f00b204e9800998ecf8427e-2\""),
+            ("x-amz-checksum-crc32c", "sOQphg=="),
+        ]);
+        let path = destination("checksum");
+        dispatcher.download(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let dispatcher = ObjectDispatcher::with_headers(vec![
+            ("ETag", "\"e9b1713db620f1e3a14b6812de523f4b\""),
+            ("x-amz-checksum-crc32", "/bVTfQ=="),
+            (
+                "x-amz-checksum-sha256",
+                "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=",
+            ),
+        ]);
+        match dispatcher.download(&path).await.unwrap_err() {
+            DownloadError::ChecksumMismatch {
+                algorithm,
+                expected,
+                actual,
+            } => {
+                assert_eq!(algorithm, "SHA256");
+                assert_eq!(expected, "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=");
+                assert_eq!(actual, "dOflu50i1tsmv3aUbUD/8+qfA0a4hP0GlJIPzPrRXjM=");
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(!path.exists());
+        assert!(!temp_path_for(&path).exists());
+
+        let dispatcher =
+            ObjectDispatcher::with_headers(vec![("ETag", "\"00000000000000000000000000000000\"")]);
+        match dispatcher.download(&path).await.unwrap_err() {
+            DownloadError::ChecksumMismatch { algorithm, .. } => assert_eq!(algorithm, "MD5"),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn download_file_fails_when_object_changes_or_stays_truncated() {
+        let mut dispatcher = ObjectDispatcher::with_headers(vec![("ETag", "\"v1\"")]);
+        dispatcher.truncated.lock().unwrap().push(10);
+        dispatcher.replaced = true;
+        let path = destination("changed");
+        match dispatcher.download(&path).await.unwrap_err() {
+            DownloadError::ObjectChanged { e_tag } => assert_eq!(e_tag, "\"v1\""),
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(!path.exists());
+
+        let dispatcher = ObjectDispatcher::with_headers(vec![("ETag", "\"v1\"")]);
+        dispatcher.truncated.lock().unwrap().extend(vec![10, 0, 0]);
+        match dispatcher.download(&path).await.unwrap_err() {
+            DownloadError::SizeMismatch { expected, received } => {
+                assert_eq!((expected, received), (36, 10))
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(!path.exists());
+        assert!(!temp_path_for(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn empty_object_needs_no_ranges() {
+        let dispatcher = RangeDispatcher::new("");
+        let downloader = dispatcher.downloader().build();
+
+        let mut writer = Cursor::new(Vec::new());
+        assert_eq!(downloader.download_to(&mut writer).await.unwrap(), 0);
+        assert!(dispatcher.ranges.lock().unwrap().is_empty());
+    }
+}
@@ -1,5 +1,17 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+mod access_point;
+mod checksum;
+mod config;
+mod download;
+mod paginate;
+mod upload;
+mod waiter;
+
+pub use self::checksum::ChecksumMismatch;
+pub(crate) use self::checksum::{add_content_md5, add_missing_checksum, validate_checksum};
+pub use self::config::{AddressingStyle, S3Config};
+
 #[cfg(test)]
 mod custom_tests;
@@ -0,0 +1,148 @@
+use futures::Stream;
+use rusoto_core::pagination::{flatten_pages, paginate};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    ListObjectsV2Error, ListObjectsV2Output, ListObjectsV2Request, Object, S3Client, S3,
+};
+
+impl S3Client {
+    /// Streams the pages of `list_objects_v2`, starting with the response to `request` and
+    /// following `next_continuation_token` while the listing is truncated.
+    ///
+    /// Pages are only requested while the stream is polled, dropping it cancels the request
+    /// in flight. The stream ends after the first error.
+    pub fn list_objects_v2_pages(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>>> + '_ {
+        paginate(
+            request,
+            move |request| self.list_objects_v2(request),
+            |request, page: &ListObjectsV2Output| match page.next_continuation_token {
+                Some(ref token) if page.is_truncated == Some(true) => Some(ListObjectsV2Request {
+                    continuation_token: Some(token.clone()),
+                    ..request
+                }),
+                _ => None,
+            },
+        )
+    }
+
+    /// Streams the objects of all pages of `list_objects_v2`, see `list_objects_v2_pages`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use rusoto_core::Region;
+    /// use rusoto_s3::{ListObjectsV2Request, S3Client};
+    ///
+    /// # async fn example() {
+    /// let s3 = S3Client::new(Region::UsEast1);
+    /// let request = ListObjectsV2Request {
+    ///     bucket: "my-bucket".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let objects = s3.list_objects_v2_stream(request);
+    /// futures::pin_mut!(objects);
+    /// while let Some(object) = objects.try_next().await.unwrap() {
+    ///     println!("{:?}", object.key);
+    /// }
+    /// # }
+    /// ```
+    pub fn list_objects_v2_stream(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<Object, RusotoError<ListObjectsV2Error>>> + '_ {
+        flatten_pages(self.list_objects_v2_pages(request), |page| {
+            page.contents.unwrap_or_default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{StreamExt, TryStreamExt};
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn page(keys: &[&str], next_token: Option<&str>) -> String {
+        let contents: String = keys
+            .iter()
+            .map(|key| format!("<Contents><Key>{}</Key></Contents>", key))
+            .collect();
+        let next = next_token
+            .map(|token| format!("<NextContinuationToken>{}</NextContinuationToken>", token))
+            .unwrap_or_default();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>bucket</Name>
+  <IsTruncated>{}</IsTruncated>
+  {}{}
+</ListBucketResult>"#,
+            next_token.is_some(),
+            next,
+            contents
+        )
+    }
+
+    fn expecting_token(token: Option<&'static str>, body: &str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(body)
+            .with_request_checker(move |request| {
+                let sent = request
+                    .params
+                    .get("continuation-token")
+                    .and_then(|value| value.as_ref().map(String::as_str));
+                assert_eq!(sent, token);
+            })
+    }
+
+    fn request() -> ListObjectsV2Request {
+        ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_objects_across_pages() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            expecting_token(None, &page(&["a", "b"], Some("token-1"))),
+            expecting_token(Some("token-1"), &page(&["c"], None)),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let keys: Vec<_> = client
+            .list_objects_v2_stream(request())
+            .map_ok(|object| object.key.unwrap())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(keys, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_stream_stops_requesting_pages() {
+        // A second request would panic, since the dispatcher only has one response.
+        let mock = MultipleMockRequestDispatcher::new(vec![expecting_token(
+            None,
+            &page(&["a"], Some("token-1")),
+        )]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let pages = client.list_objects_v2_pages(request());
+        fn is_send<T: Send>(_: &T) {}
+        is_send(&pages);
+        futures::pin_mut!(pages);
+        let first = pages.next().await.unwrap().unwrap();
+
+        assert_eq!(first.next_continuation_token.as_deref(), Some("token-1"));
+    }
+}
@@ -0,0 +1,634 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use rusoto_core::{ByteStream, RusotoError};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::generated::{
+    AbortMultipartUploadRequest, CompleteMultipartUploadError, CompleteMultipartUploadOutput,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart,
+    CreateMultipartUploadError, CreateMultipartUploadRequest, S3Client, UploadPartError,
+    UploadPartRequest, S3,
+};
+
+/// The smallest part size S3 accepts for any part but the last one (5 MiB).
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+/// The part size used by `MultipartUploader` unless configured otherwise (8 MiB).
+pub const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+/// The largest number of parts a single multipart upload may have.
+pub const MAX_PARTS: i64 = 10_000;
+
+const DEFAULT_CONCURRENCY: usize = 4;
+const DEFAULT_PART_ATTEMPTS: usize = 3;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+type ProgressCallback = Arc<dyn Fn(&UploadProgress) + Send + Sync>;
+
+/// Progress of a multipart upload, reported after each part has been uploaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadProgress {
+    /// Number of the part that was just uploaded.
+    pub part_number: i64,
+    /// Size of the part that was just uploaded, in bytes.
+    pub part_size: usize,
+    /// Number of parts uploaded so far.
+    pub parts_uploaded: usize,
+    /// Number of bytes uploaded so far.
+    pub bytes_uploaded: u64,
+}
+
+/// Errors returned by `MultipartUploader`.
+///
+/// Except for `CreateMultipartUpload`, the multipart upload has been aborted by the time one of
+/// these errors is returned.
+#[derive(Debug)]
+pub enum MultipartUploadError {
+    /// Reading the body failed.
+    Io(io::Error),
+    /// The body needs more than `MAX_PARTS` parts at the configured part size.
+    TooManyParts,
+    /// Starting the multipart upload failed.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// Uploading a part failed on every attempt.
+    UploadPart {
+        /// Number of the part that failed.
+        part_number: i64,
+        /// Error returned by the last attempt.
+        error: RusotoError<UploadPartError>,
+    },
+    /// Completing the multipart upload failed.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+}
+
+impl fmt::Display for MultipartUploadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MultipartUploadError::Io(ref e) => write!(f, "failed to read upload body: {}", e),
+            MultipartUploadError::TooManyParts => write!(
+                f,
+                "upload body needs more than {} parts; increase the part size",
+                MAX_PARTS
+            ),
+            MultipartUploadError::CreateMultipartUpload(ref e) => {
+                write!(f, "failed to create multipart upload: {}", e)
+            }
+            MultipartUploadError::UploadPart {
+                part_number,
+                ref error,
+            } => write!(f, "failed to upload part {}: {}", part_number, error),
+            MultipartUploadError::CompleteMultipartUpload(ref e) => {
+                write!(f, "failed to complete multipart upload: {}", e)
+            }
+        }
+    }
+}
+
+impl Error for MultipartUploadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            MultipartUploadError::Io(ref e) => Some(e),
+            MultipartUploadError::TooManyParts => None,
+            MultipartUploadError::CreateMultipartUpload(ref e) => Some(e),
+            MultipartUploadError::UploadPart { ref error, .. } => Some(error),
+            MultipartUploadError::CompleteMultipartUpload(ref e) => Some(e),
+        }
+    }
+}
+
+/// Uploads an object of any size to S3 using the multipart upload API.
+///
+/// The body is split into parts of `part_size` bytes which are uploaded concurrently. Failed
+/// parts are retried, and if the upload fails (or the upload future is dropped before it
+/// finishes) the multipart upload is aborted so the uploaded parts don't keep using storage.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::MultipartUploader;
+/// use rusoto_s3::S3Client;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let uploader = MultipartUploader::builder(S3Client::new(Region::UsEast1), "my-bucket", "my-key")
+///     .part_size(16 * 1024 * 1024)
+///     .concurrency(8)
+///     .on_progress(|progress| println!("{} bytes uploaded", progress.bytes_uploaded))
+///     .build();
+/// uploader.upload_file("large-file.bin").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MultipartUploader {
+    client: S3Client,
+    request: CreateMultipartUploadRequest,
+    part_size: usize,
+    concurrency: usize,
+    part_attempts: usize,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl fmt::Debug for MultipartUploader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MultipartUploader")
+            .field("bucket", &self.request.bucket)
+            .field("key", &self.request.key)
+            .field("part_size", &self.part_size)
+            .field("concurrency", &self.concurrency)
+            .field("part_attempts", &self.part_attempts)
+            .finish()
+    }
+}
+
+/// Builder for `MultipartUploader`.
+#[derive(Clone, Debug)]
+pub struct MultipartUploaderBuilder {
+    uploader: MultipartUploader,
+}
+
+impl MultipartUploaderBuilder {
+    /// Sets the size of each part, in bytes. Values below `MIN_PART_SIZE` are raised to it.
+    /// Defaults to `DEFAULT_PART_SIZE`.
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.uploader.part_size = part_size.max(MIN_PART_SIZE);
+        self
+    }
+
+    /// Sets the maximum number of parts uploaded at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.uploader.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets how many times each part is attempted before the upload fails. Defaults to 3.
+    pub fn part_attempts(mut self, attempts: usize) -> Self {
+        self.uploader.part_attempts = attempts.max(1);
+        self
+    }
+
+    /// Sets the request used to create the multipart upload, e.g. to set the content type,
+    /// metadata or server side encryption. Its `bucket` and `key` are replaced with the ones
+    /// given to `MultipartUploader::builder`.
+    pub fn create_request(mut self, request: CreateMultipartUploadRequest) -> Self {
+        let bucket = std::mem::take(&mut self.uploader.request.bucket);
+        let key = std::mem::take(&mut self.uploader.request.key);
+        self.uploader.request = CreateMultipartUploadRequest {
+            bucket,
+            key,
+            ..request
+        };
+        self
+    }
+
+    /// Sets a callback that is called after each part has been uploaded.
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&UploadProgress) + Send + Sync + 'static,
+    {
+        self.uploader.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Builds the `MultipartUploader`.
+    pub fn build(self) -> MultipartUploader {
+        self.uploader
+    }
+}
+
+type Body = Pin<Box<dyn AsyncRead + Send>>;
+
+impl MultipartUploader {
+    /// Creates a builder for an uploader that uploads to `key` in `bucket`.
+    pub fn builder<B, K>(client: S3Client, bucket: B, key: K) -> MultipartUploaderBuilder
+    where
+        B: Into<String>,
+        K: Into<String>,
+    {
+        MultipartUploaderBuilder {
+            uploader: MultipartUploader {
+                client,
+                request: CreateMultipartUploadRequest {
+                    bucket: bucket.into(),
+                    key: key.into(),
+                    ..Default::default()
+                },
+                part_size: DEFAULT_PART_SIZE,
+                concurrency: DEFAULT_CONCURRENCY,
+                part_attempts: DEFAULT_PART_ATTEMPTS,
+                on_progress: None,
+            },
+        }
+    }
+
+    /// Uploads the contents of a `ByteStream`.
+    pub async fn upload(
+        &self,
+        body: ByteStream,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError> {
+        self.upload_body(Box::pin(body.into_async_read())).await
+    }
+
+    /// Uploads everything read from `reader`.
+    pub async fn upload_reader<R>(
+        &self,
+        reader: R,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.upload_body(Box::pin(reader)).await
+    }
+
+    /// Uploads the contents of the file at `path`.
+    pub async fn upload_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError> {
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(MultipartUploadError::Io)?;
+        self.upload_body(Box::pin(file)).await
+    }
+
+    async fn upload_body(
+        &self,
+        mut body: Body,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError> {
+        let upload_id = self
+            .client
+            .create_multipart_upload(self.request.clone())
+            .await
+            .map_err(MultipartUploadError::CreateMultipartUpload)?
+            .upload_id
+            .ok_or_else(|| {
+                MultipartUploadError::CreateMultipartUpload(RusotoError::ParseError(
+                    "CreateMultipartUpload response has no UploadId".to_owned(),
+                ))
+            })?;
+        let mut guard = AbortOnDrop {
+            client: self.client.clone(),
+            request: Some(self.abort_request(&upload_id)),
+        };
+
+        let result = match self.upload_parts(&upload_id, &mut body).await {
+            Ok(parts) => self
+                .client
+                .complete_multipart_upload(CompleteMultipartUploadRequest {
+                    bucket: self.request.bucket.clone(),
+                    key: self.request.key.clone(),
+                    upload_id: upload_id.clone(),
+                    multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+                    request_payer: self.request.request_payer.clone(),
+                })
+                .await
+                .map_err(MultipartUploadError::CompleteMultipartUpload),
+            Err(e) => Err(e),
+        };
+
+        if result.is_err() {
+            // An abort failure leaves nothing more to do here, so report the original error.
+            let _ = self
+                .client
+                .abort_multipart_upload(self.abort_request(&upload_id))
+                .await;
+        }
+        guard.request = None;
+        result
+    }
+
+    async fn upload_parts(
+        &self,
+        upload_id: &str,
+        body: &mut Body,
+    ) -> Result<Vec<CompletedPart>, MultipartUploadError> {
+        let mut in_flight = FuturesUnordered::new();
+        let mut parts = Vec::new();
+        let mut part_number = 0;
+        let mut bytes_uploaded = 0;
+        let mut finished_reading = false;
+
+        loop {
+            while !finished_reading && in_flight.len() < self.concurrency {
+                let part = read_part(body, self.part_size)
+                    .await
+                    .map_err(MultipartUploadError::Io)?;
+                // An empty body is still uploaded as a single empty part.
+                if part.is_empty() && part_number > 0 {
+                    finished_reading = true;
+                    break;
+                }
+                finished_reading = part.len() < self.part_size;
+                part_number += 1;
+                if part_number > MAX_PARTS {
+                    return Err(MultipartUploadError::TooManyParts);
+                }
+                in_flight.push(self.upload_part(upload_id, part_number, part));
+            }
+
+            let (part, part_size) = match in_flight.next().await {
+                Some(result) => result?,
+                None => break,
+            };
+            bytes_uploaded += part_size as u64;
+            parts.push(part);
+            if let Some(ref on_progress) = self.on_progress {
+                on_progress(&UploadProgress {
+                    part_number: parts.last().and_then(|p| p.part_number).unwrap_or_default(),
+                    part_size,
+                    parts_uploaded: parts.len(),
+                    bytes_uploaded,
+                });
+            }
+        }
+
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+
+    async fn upload_part(
+        &self,
+        upload_id: &str,
+        part_number: i64,
+        part: Bytes,
+    ) -> Result<(CompletedPart, usize), MultipartUploadError> {
+        let part_size = part.len();
+        let mut attempt = 1;
+        loop {
+            let request = UploadPartRequest {
+                body: Some(ByteStream::new_with_size(
+                    stream::once(futures::future::ready(Ok(part.clone()))),
+                    part_size,
+                )),
+                bucket: self.request.bucket.clone(),
+                content_length: Some(part_size as i64),
+                key: self.request.key.clone(),
+                part_number,
+                request_payer: self.request.request_payer.clone(),
+                sse_customer_algorithm: self.request.sse_customer_algorithm.clone(),
+                sse_customer_key: self.request.sse_customer_key.clone(),
+                sse_customer_key_md5: self.request.sse_customer_key_md5.clone(),
+                upload_id: upload_id.to_owned(),
+                ..Default::default()
+            };
+            match self.client.upload_part(request).await {
+                Ok(output) => {
+                    let completed = CompletedPart {
+                        e_tag: output.e_tag,
+                        part_number: Some(part_number),
+                    };
+                    return Ok((completed, part_size));
+                }
+                Err(error) if attempt >= self.part_attempts => {
+                    return Err(MultipartUploadError::UploadPart { part_number, error });
+                }
+                Err(_) => {
+                    tokio::time::delay_for(RETRY_BASE_DELAY * 2u32.pow(attempt as u32 - 1)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn abort_request(&self, upload_id: &str) -> AbortMultipartUploadRequest {
+        AbortMultipartUploadRequest {
+            bucket: self.request.bucket.clone(),
+            key: self.request.key.clone(),
+            upload_id: upload_id.to_owned(),
+            request_payer: self.request.request_payer.clone(),
+        }
+    }
+}
+
+/// Reads up to `part_size` bytes, stopping early only at the end of the body.
+async fn read_part(body: &mut Body, part_size: usize) -> io::Result<Bytes> {
+    let mut part = BytesMut::with_capacity(part_size);
+    part.resize(part_size, 0);
+    let mut filled = 0;
+    while filled < part_size {
+        match body.read(&mut part[filled..]).await? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    part.truncate(filled);
+    Ok(part.freeze())
+}
+
+/// Aborts the multipart upload in the background if the upload future is dropped early.
+struct AbortOnDrop {
+    client: S3Client,
+    request: Option<AbortMultipartUploadRequest>,
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        if let Some(request) = self.request.take() {
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                let client = self.client.clone();
+                handle.spawn(async move {
+                    let _ = client.abort_multipart_upload(request).await;
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::request::HttpDispatchError;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::{DispatchSignedRequest, Region};
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Answers multipart upload requests and records what was sent.
+    #[derive(Clone, Default)]
+    struct MultipartDispatcher {
+        // Number of times each part number fails before it succeeds.
+        failures: Arc<Mutex<HashMap<i64, usize>>>,
+        part_sizes: Arc<Mutex<Vec<(i64, usize)>>>,
+        completed_body: Arc<Mutex<Option<String>>>,
+        aborted: Arc<Mutex<bool>>,
+    }
+
+    impl MultipartDispatcher {
+        fn failing(part_number: i64, times: usize) -> Self {
+            let dispatcher = MultipartDispatcher::default();
+            dispatcher
+                .failures
+                .lock()
+                .unwrap()
+                .insert(part_number, times);
+            dispatcher
+        }
+
+        fn uploader(&self) -> MultipartUploaderBuilder {
+            let client = S3Client::new_with(self.clone(), MockCredentialsProvider, Region::UsEast1);
+            MultipartUploader::builder(client, "bucket", "key").part_size(MIN_PART_SIZE)
+        }
+    }
+
+    impl DispatchSignedRequest for MultipartDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> rusoto_core::request::DispatchSignedRequestFuture {
+            let param = |name: &str| request.params.get(name).cloned().flatten();
+            let mock =
+                match (request.method(), param("partNumber")) {
+                    ("POST", None) if request.params.contains_key("uploads") => {
+                        MockRequestDispatcher::with_status(200).with_body(
+                            "<InitiateMultipartUploadResult><Bucket>bucket</Bucket><Key>key</Key>\
+                         <UploadId>upload-id</UploadId></InitiateMultipartUploadResult>",
+                        )
+                    }
+                    ("PUT", Some(part_number)) => {
+                        let part_number: i64 = part_number.parse().unwrap();
+                        let mut failures = self.failures.lock().unwrap();
+                        match failures.get_mut(&part_number) {
+                            Some(remaining) if *remaining > 0 => {
+                                *remaining -= 1;
+                                MockRequestDispatcher::with_status(500)
+                            }
+                            _ => {
+                                let size = request.headers["content-length"][0].clone();
+                                let size = String::from_utf8(size).unwrap().parse().unwrap();
+                                self.part_sizes.lock().unwrap().push((part_number, size));
+                                MockRequestDispatcher::with_status(200)
+                                    .with_header("ETag", &format!("\"etag-{}\"", part_number))
+                            }
+                        }
+                    }
+                    ("POST", None) => {
+                        if let Some(SignedRequestPayload::Buffer(ref body)) = request.payload {
+                            *self.completed_body.lock().unwrap() =
+                                Some(String::from_utf8(body.to_vec()).unwrap());
+                        }
+                        MockRequestDispatcher::with_status(200).with_body(
+                            "<CompleteMultipartUploadResult><Bucket>bucket</Bucket><Key>key</Key>\
+                         <ETag>\"etag\"</ETag></CompleteMultipartUploadResult>",
+                        )
+                    }
+                    ("DELETE", None) => {
+                        *self.aborted.lock().unwrap() = true;
+                        MockRequestDispatcher::with_status(204)
+                    }
+                    _ => MockRequestDispatcher::with_dispatch_error(HttpDispatchError::new(
+                        format!("unexpected request {} {}", request.method(), request.path()),
+                    )),
+                };
+            mock.dispatch(request, timeout)
+        }
+    }
+
+    #[tokio::test]
+    async fn uploads_parts_and_completes() {
+        let dispatcher = MultipartDispatcher::default();
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        let uploader = dispatcher
+            .uploader()
+            .concurrency(2)
+            .on_progress(move |p| recorded.lock().unwrap().push(p.clone()))
+            .build();
+
+        let body = vec![7u8; 2 * MIN_PART_SIZE + 5];
+        let output = uploader.upload(ByteStream::from(body)).await.unwrap();
+        assert_eq!(output.e_tag.as_deref(), Some("\"etag\""));
+
+        let mut part_sizes = dispatcher.part_sizes.lock().unwrap().clone();
+        part_sizes.sort();
+        assert_eq!(
+            part_sizes,
+            vec![(1, MIN_PART_SIZE), (2, MIN_PART_SIZE), (3, 5)]
+        );
+        let completed = dispatcher.completed_body.lock().unwrap().clone().unwrap();
+        let positions: Vec<usize> = (1..=3)
+            .map(|n| {
+                completed
+                    .find(&format!("<PartNumber>{}</PartNumber>", n))
+                    .unwrap()
+            })
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", completed);
+        assert!(
+            completed.contains("<ETag>\"etag-2\"</ETag>"),
+            "{}",
+            completed
+        );
+
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 3);
+        let last = progress.last().unwrap();
+        assert_eq!(last.parts_uploaded, 3);
+        assert_eq!(last.bytes_uploaded, 2 * MIN_PART_SIZE as u64 + 5);
+        assert!(!*dispatcher.aborted.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn retries_failed_parts() {
+        let dispatcher = MultipartDispatcher::failing(2, 1);
+        let uploader = dispatcher.uploader().build();
+
+        let body = vec![0u8; MIN_PART_SIZE + 1];
+        uploader
+            .upload_reader(std::io::Cursor::new(body))
+            .await
+            .unwrap();
+
+        let mut part_sizes = dispatcher.part_sizes.lock().unwrap().clone();
+        part_sizes.sort();
+        assert_eq!(part_sizes, vec![(1, MIN_PART_SIZE), (2, 1)]);
+        assert!(!*dispatcher.aborted.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn aborts_when_a_part_keeps_failing() {
+        let dispatcher = MultipartDispatcher::failing(1, 2);
+        let uploader = dispatcher.uploader().part_attempts(2).build();
+
+        let err = uploader
+            .upload(ByteStream::from(vec![0u8; 10]))
+            .await
+            .unwrap_err();
+        match err {
+            MultipartUploadError::UploadPart { part_number, .. } => assert_eq!(part_number, 1),
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(*dispatcher.aborted.lock().unwrap());
+        assert!(dispatcher.completed_body.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn uploads_empty_body_as_one_part() {
+        let dispatcher = MultipartDispatcher::default();
+        let uploader = dispatcher.uploader().build();
+
+        uploader.upload(ByteStream::from(Vec::new())).await.unwrap();
+
+        assert_eq!(*dispatcher.part_sizes.lock().unwrap(), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn builder_enforces_minimums() {
+        let uploader = MultipartDispatcher::default()
+            .uploader()
+            .part_size(1024)
+            .concurrency(0)
+            .part_attempts(0)
+            .build();
+        assert_eq!(uploader.part_size, MIN_PART_SIZE);
+        assert_eq!(uploader.concurrency, 1);
+        assert_eq!(uploader.part_attempts, 1);
+    }
+}
@@ -1,3 +1,5 @@
+use super::access_point::address_access_point;
+use super::config::{accelerate, address_bucket, AddressingStyle};
 use crate::generated::{
     DeleteObjectRequest, GetObjectRequest, PutObjectRequest, UploadPartRequest,
 };
@@ -6,7 +8,17 @@ use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::region::Region;
 use rusoto_core::signature;
 use rusoto_core::signature::SignedRequest;
+use std::collections::BTreeMap;
 use std::time::Duration;
+
+pub use super::download::{
+    download_file, DownloadError, ParallelDownloader, ParallelDownloaderBuilder, DEFAULT_CHUNK_SIZE,
+};
+pub use super::upload::{
+    MultipartUploadError, MultipartUploader, MultipartUploaderBuilder, UploadProgress,
+    DEFAULT_PART_SIZE, MAX_PARTS, MIN_PART_SIZE,
+};
+
 /// URL encodes an S3 object key. This is necessary for `copy_object` and `upload_part_copy`,
 /// which require the `copy_source` field to be URL encoded.
 ///
@@ -62,13 +74,50 @@ macro_rules! add_params {
 
 pub struct PreSignedRequestOption {
     pub expires_in: Duration,
+    /// Additional query parameters to include in the URL, e.g. `response-content-disposition`.
+    pub extra_params: BTreeMap<String, String>,
+    /// Additional headers to include in the signature. Clients using the URL must send them
+    /// with the same values, e.g. the `x-amz-server-side-encryption-customer-*` headers for SSE-C.
+    pub signed_headers: BTreeMap<String, String>,
+    /// How the URL addresses the bucket. Should match the `S3Config` of the clients the URL
+    /// is shared with. Buckets that can't be virtual-hosted fall back to path-style URLs.
+    /// Access point ARNs used as bucket names always get URLs for the access point.
+    pub addressing_style: AddressingStyle,
+    /// Use the Transfer Acceleration endpoint, as with `S3Config::use_accelerate_endpoint`.
+    /// Buckets that can't be accelerated fall back to `addressing_style`.
+    pub use_accelerate_endpoint: bool,
 }
 
 impl Default for PreSignedRequestOption {
     fn default() -> Self {
         Self {
             expires_in: Duration::from_secs(3600),
+            extra_params: BTreeMap::new(),
+            signed_headers: BTreeMap::new(),
+            addressing_style: AddressingStyle::default(),
+            use_accelerate_endpoint: false,
+        }
+    }
+}
+
+impl PreSignedRequestOption {
+    fn presign(&self, request: &mut SignedRequest, credentials: &AwsCredentials) -> String {
+        for (key, value) in &self.extra_params {
+            request.add_param(key.as_str(), value.as_str());
+        }
+        for (name, value) in &self.signed_headers {
+            request.remove_header(name);
+            request.add_header(name, value);
+        }
+        let access_point = address_access_point(request, Default::default(), true).unwrap_or(false);
+        let accelerated = !access_point
+            && self.use_accelerate_endpoint
+            && accelerate(request, Default::default()).unwrap_or(false);
+        if !access_point && !accelerated && address_bucket(self.addressing_style, request).is_err()
+        {
+            address_bucket(AddressingStyle::Path, request).expect("path-style never fails");
         }
+        request.generate_presigned_url(credentials, &self.expires_in, false)
     }
 }
 
@@ -119,7 +168,7 @@ impl PreSignedRequest for GetObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        option.presign(&mut request, credentials)
     }
 }
 
@@ -171,7 +220,7 @@ impl PreSignedRequest for PutObjectRequest {
             }
         }
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        option.presign(&mut request, credentials)
     }
 }
 
@@ -198,7 +247,7 @@ impl PreSignedRequest for DeleteObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        option.presign(&mut request, credentials)
     }
 }
 
@@ -226,6 +275,6 @@ impl PreSignedRequest for UploadPartRequest {
             request_payer, "x-amz-request-payer";
         );
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        option.presign(&mut request, credentials)
     }
 }
@@ -0,0 +1,222 @@
+use std::time::Duration;
+
+use rusoto_core::waiter::{error_status, wait, WaiterConfig, WaiterError, WaiterState};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    HeadBucketError, HeadBucketRequest, HeadObjectError, HeadObjectOutput, HeadObjectRequest,
+    S3Client, S3,
+};
+
+/// The delay and attempts of the S3 waiters unless they're given a config.
+fn default_config() -> WaiterConfig {
+    WaiterConfig::new(Duration::from_secs(5), 20)
+}
+
+/// Whether `err` says the bucket or object doesn't exist.
+fn is_not_found<E>(err: &RusotoError<E>) -> bool {
+    matches!(error_status(err), Some(status) if status.as_u16() == 404)
+}
+
+// Matchers hand back the `RusotoError`s they don't expect.
+#[allow(clippy::result_large_err)]
+impl S3Client {
+    /// Polls `head_object` until the object exists, and returns its metadata.
+    ///
+    /// Without a `config` this polls every 5 seconds, up to 20 times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_s3::{HeadObjectRequest, S3Client};
+    ///
+    /// # async fn example() {
+    /// let s3 = S3Client::new(Region::UsEast1);
+    /// let request = HeadObjectRequest {
+    ///     bucket: "my-bucket".to_owned(),
+    ///     key: "my-key".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let object = s3.wait_until_object_exists(request, None).await.unwrap();
+    /// println!("{:?}", object.content_length);
+    /// # }
+    /// ```
+    pub async fn wait_until_object_exists(
+        &self,
+        request: HeadObjectRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<HeadObjectOutput, WaiterError<HeadObjectError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.head_object(request.clone()),
+            |result| match result {
+                Ok(output) => Ok(WaiterState::Success(output)),
+                Err(RusotoError::Service(HeadObjectError::NoSuchKey(_))) => Ok(WaiterState::Retry),
+                Err(ref err) if is_not_found(err) => Ok(WaiterState::Retry),
+                Err(err) => Err(err),
+            },
+        )
+        .await
+    }
+
+    /// Polls `head_object` until the object doesn't exist, see `wait_until_object_exists`.
+    pub async fn wait_until_object_not_exists(
+        &self,
+        request: HeadObjectRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<(), WaiterError<HeadObjectError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.head_object(request.clone()),
+            |result| match result {
+                Ok(_) => Ok(WaiterState::Retry),
+                Err(RusotoError::Service(HeadObjectError::NoSuchKey(_))) => {
+                    Ok(WaiterState::Success(()))
+                }
+                Err(ref err) if is_not_found(err) => Ok(WaiterState::Success(())),
+                Err(err) => Err(err),
+            },
+        )
+        .await
+    }
+
+    /// Polls `head_bucket` until the bucket exists, see `wait_until_object_exists`.
+    ///
+    /// A bucket in another region (HTTP 301) or owned by someone else (HTTP 403) exists too.
+    pub async fn wait_until_bucket_exists(
+        &self,
+        request: HeadBucketRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<(), WaiterError<HeadBucketError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.head_bucket(request.clone()),
+            |result| match result {
+                Ok(()) => Ok(WaiterState::Success(())),
+                Err(RusotoError::Service(HeadBucketError::NoSuchBucket(_))) => {
+                    Ok(WaiterState::Retry)
+                }
+                Err(ref err) if is_not_found(err) => Ok(WaiterState::Retry),
+                Err(err) => match error_status(&err).map(|status| status.as_u16()) {
+                    Some(301) | Some(403) => Ok(WaiterState::Success(())),
+                    _ => Err(err),
+                },
+            },
+        )
+        .await
+    }
+
+    /// Polls `head_bucket` until the bucket doesn't exist, see `wait_until_object_exists`.
+    pub async fn wait_until_bucket_not_exists(
+        &self,
+        request: HeadBucketRequest,
+        config: Option<WaiterConfig>,
+    ) -> Result<(), WaiterError<HeadBucketError>> {
+        wait(
+            &config.unwrap_or_else(default_config),
+            || self.head_bucket(request.clone()),
+            |result| match result {
+                Ok(()) => Ok(WaiterState::Retry),
+                Err(RusotoError::Service(HeadBucketError::NoSuchBucket(_))) => {
+                    Ok(WaiterState::Success(()))
+                }
+                Err(ref err) if is_not_found(err) => Ok(WaiterState::Success(())),
+                Err(err) => Err(err),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn quick(max_attempts: u32) -> Option<WaiterConfig> {
+        Some(WaiterConfig::new(Duration::from_millis(1), max_attempts))
+    }
+
+    fn request() -> HeadObjectRequest {
+        HeadObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn object_exists_retries_not_found() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(404),
+            MockRequestDispatcher::with_status(200).with_header("Content-Length", "5"),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        let object = client
+            .wait_until_object_exists(request(), quick(3))
+            .await
+            .unwrap();
+
+        assert_eq!(object.content_length, Some(5));
+    }
+
+    #[tokio::test]
+    async fn object_exists_times_out() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(404),
+            MockRequestDispatcher::with_status(404),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        match client.wait_until_object_exists(request(), quick(2)).await {
+            Err(WaiterError::Timeout { attempts, .. }) => assert_eq!(attempts, 2),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn object_exists_fails_on_unexpected_errors() {
+        let mock = MockRequestDispatcher::with_status(500);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        match client.wait_until_object_exists(request(), quick(3)).await {
+            Err(WaiterError::Request(RusotoError::Unknown(response))) => {
+                assert_eq!(response.status.as_u16(), 500)
+            }
+            other => panic!("expected a request error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn object_not_exists_succeeds_on_not_found() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200),
+            MockRequestDispatcher::with_status(404),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+        client
+            .wait_until_object_not_exists(request(), quick(3))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn bucket_exists_accepts_forbidden() {
+        let mock = MockRequestDispatcher::with_status(403);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let request = HeadBucketRequest {
+            bucket: "bucket".to_owned(),
+        };
+
+        client
+            .wait_until_bucket_exists(request, quick(1))
+            .await
+            .unwrap();
+    }
+}
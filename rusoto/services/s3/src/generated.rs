@@ -40,12 +40,18 @@ use xml::EventWriter;
 impl S3Client {
     async fn sign_and_dispatch<E>(
         &self,
-        request: SignedRequest,
+        mut request: SignedRequest,
         from_response: fn(BufferedHttpResponse) -> RusotoError<E>,
     ) -> Result<HttpResponse, RusotoError<E>> {
-        let mut response = self.client.sign_and_dispatch(request).await?;
+        self.client
+            .resolve_endpoint(&mut request)
+            .map_err(|err| RusotoError::Validation(err.to_string()))?;
+        self.config
+            .prepare_request(&mut request, self.client.endpoint_variants())
+            .map_err(RusotoError::Validation)?;
+        let mut response = self.client.sign_and_dispatch_resolved(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4705,6 +4711,14 @@ pub struct GetObjectOutput {
     pub body: Option<StreamingBody>,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
     pub cache_control: Option<String>,
+    /// <p>The base64-encoded, 32-bit CRC32 checksum of the object. This will only be present if it was uploaded with the object. With multipart uploads, this may not be a checksum value of the object. For more information about how checksums are calculated with multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html#large-object-checksums"> Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32: Option<String>,
+    /// <p>The base64-encoded, 32-bit CRC32C checksum of the object. This will only be present if it was uploaded with the object. With multipart uploads, this may not be a checksum value of the object. For more information about how checksums are calculated with multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html#large-object-checksums"> Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32c: Option<String>,
+    /// <p>The base64-encoded, 160-bit SHA-1 digest of the object. This will only be present if it was uploaded with the object. With multipart uploads, this may not be a checksum value of the object. For more information about how checksums are calculated with multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html#large-object-checksums"> Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha1: Option<String>,
+    /// <p>The base64-encoded, 256-bit SHA-256 digest of the object. This will only be present if it was uploaded with the object. With multipart uploads, this may not be a checksum value of the object. For more information about how checksums are calculated with multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html#large-object-checksums"> Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha256: Option<String>,
     /// <p>Specifies presentational information for the object.</p>
     pub content_disposition: Option<String>,
     /// <p>Specifies what content encodings have been applied to the object and thus what decoding mechanisms must be applied to obtain the media-type referenced by the Content-Type header field.</p>
@@ -4767,6 +4781,8 @@ pub struct GetObjectOutput {
 pub struct GetObjectRequest {
     /// <p>The bucket name containing the object. </p> <p>When using this API with an access point, you must direct requests to the access point hostname. The access point hostname takes the form <i>AccessPointName</i>-<i>AccountId</i>.s3-accesspoint.<i>Region</i>.amazonaws.com. When using this operation using an access point through the AWS SDKs, you provide the access point ARN in place of the bucket name. For more information about access point ARNs, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-access-points.html">Using Access Points</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub bucket: String,
+    /// <p>To retrieve the checksum, this mode must be enabled.</p>
+    pub checksum_mode: Option<String>,
     /// <p>Return the object only if its entity tag (ETag) is the same as the one specified, otherwise return a 412 (precondition failed).</p>
     pub if_match: Option<String>,
     /// <p>Return the object only if it has been modified since the specified time, otherwise return a 304 (not modified).</p>
@@ -10301,6 +10317,14 @@ pub struct PutObjectLockConfigurationRequest {
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct PutObjectOutput {
+    /// <p>The base64-encoded, 32-bit CRC32 checksum of the object. This will only be present if it was uploaded with the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32: Option<String>,
+    /// <p>The base64-encoded, 32-bit CRC32C checksum of the object. This will only be present if it was uploaded with the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32c: Option<String>,
+    /// <p>The base64-encoded, 160-bit SHA-1 digest of the object. This will only be present if it was uploaded with the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha1: Option<String>,
+    /// <p>The base64-encoded, 256-bit SHA-256 digest of the object. This will only be present if it was uploaded with the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha256: Option<String>,
     /// <p>Entity tag for the uploaded object.</p>
     pub e_tag: Option<String>,
     /// <p> If the expiration is configured for the object (see <a>PutBucketLifecycleConfiguration</a>), the response includes this header. It includes the expiry-date and rule-id key-value pairs that provide information about object expiration. The value of the rule-id is URL encoded.</p>
@@ -10347,6 +10371,16 @@ pub struct PutObjectRequest {
     pub bucket: String,
     /// <p> Can be used to specify caching behavior along the request/reply chain. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.9">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.9</a>.</p>
     pub cache_control: Option<String>,
+    /// <p>Indicates the algorithm used to create the checksum for the object, e.g. <code>CRC32C</code>. If the matching <code>checksum_*</code> field is left empty, the checksum is computed over the body and sent with it. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_algorithm: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 32-bit CRC32 checksum of the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 32-bit CRC32C checksum of the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32c: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 160-bit SHA-1 digest of the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha1: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 256-bit SHA-256 digest of the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha256: Option<String>,
     /// <p>Specifies presentational information for the object. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec19.html#sec19.5.1">http://www.w3.org/Protocols/rfc2616/rfc2616-sec19.html#sec19.5.1</a>.</p>
     pub content_disposition: Option<String>,
     /// <p>Specifies what content encodings have been applied to the object and thus what decoding mechanisms must be applied to obtain the media-type referenced by the Content-Type header field. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.11">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.11</a>.</p>
@@ -12314,8 +12348,29 @@ pub enum SelectObjectContentEventStreamItem {
 }
 
 impl DeserializeEvent for SelectObjectContentEventStreamItem {
-    fn deserialize_event(_event_type: &str, _data: &[u8]) -> Result<Self, RusotoError<()>> {
-        unimplemented!()
+    fn deserialize_event(event_type: &str, data: &[u8]) -> Result<Self, RusotoError<()>> {
+        let deserialized = match event_type {
+            "Cont" => SelectObjectContentEventStreamItem::Cont(ContinuationEvent::default()),
+            "End" => SelectObjectContentEventStreamItem::End(EndEvent::default()),
+            "Progress" => SelectObjectContentEventStreamItem::Progress(ProgressEvent {
+                details: Some(xml_util::parse_payload(data, |tag_name, stack| {
+                    ProgressDeserializer::deserialize(tag_name, stack)
+                })?),
+            }),
+            "Records" => SelectObjectContentEventStreamItem::Records(RecordsEvent {
+                payload: Some(bytes::Bytes::copy_from_slice(data)),
+            }),
+            "Stats" => SelectObjectContentEventStreamItem::Stats(StatsEvent {
+                details: Some(xml_util::parse_payload(data, |tag_name, stack| {
+                    StatsDeserializer::deserialize(tag_name, stack)
+                })?),
+            }),
+            _ => Err(RusotoError::ParseError(format!(
+                "Invalid event type: {}",
+                event_type
+            )))?,
+        };
+        Ok(deserialized)
     }
 }
 
@@ -13939,6 +13994,14 @@ pub struct UploadPartCopyRequest {
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serialize_structs", derive(Serialize))]
 pub struct UploadPartOutput {
+    /// <p>The base64-encoded, 32-bit CRC32 checksum of the part. This will only be present if it was uploaded with the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32: Option<String>,
+    /// <p>The base64-encoded, 32-bit CRC32C checksum of the part. This will only be present if it was uploaded with the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32c: Option<String>,
+    /// <p>The base64-encoded, 160-bit SHA-1 digest of the part. This will only be present if it was uploaded with the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha1: Option<String>,
+    /// <p>The base64-encoded, 256-bit SHA-256 digest of the part. This will only be present if it was uploaded with the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha256: Option<String>,
     /// <p>Entity tag for the uploaded object.</p>
     pub e_tag: Option<String>,
     pub request_charged: Option<String>,
@@ -13975,6 +14038,16 @@ pub struct UploadPartRequest {
     pub body: Option<StreamingBody>,
     /// <p>Name of the bucket to which the multipart upload was initiated.</p>
     pub bucket: String,
+    /// <p>Indicates the algorithm used to create the checksum for the part, e.g. <code>CRC32C</code>. If the matching <code>checksum_*</code> field is left empty, the checksum is computed over the body and sent with it. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_algorithm: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 32-bit CRC32 checksum of the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 32-bit CRC32C checksum of the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_crc32c: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 160-bit SHA-1 digest of the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha1: Option<String>,
+    /// <p>This header can be used as a data integrity check to verify that the data received is the same data that was originally sent. This header specifies the base64-encoded, 256-bit SHA-256 digest of the part. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html">Checking object integrity</a> in the <i>Amazon S3 User Guide</i>.</p>
+    pub checksum_sha256: Option<String>,
     /// <p>Size of the body in bytes. This parameter is useful when the size of the body cannot be determined automatically.</p>
     pub content_length: Option<i64>,
     /// <p>The base64-encoded 128-bit MD5 digest of the part data. This parameter is auto-populated when using the command from the CLI. This parameter is required if object lock parameters are specified.</p>
@@ -14168,7 +14241,7 @@ impl YearsSerializer {
 #[derive(Debug, PartialEq)]
 pub enum AbortMultipartUploadError {
     /// <p>The specified multipart upload does not exist.</p>
-    NoSuchUpload(String),
+    NoSuchUpload(XmlErrorMessage),
 }
 
 impl AbortMultipartUploadError {
@@ -14181,7 +14254,7 @@ impl AbortMultipartUploadError {
                 match &parsed_error.code[..] {
                     "NoSuchUpload" => {
                         return RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14197,6 +14270,13 @@ impl AbortMultipartUploadError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AbortMultipartUploadError::NoSuchUpload(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AbortMultipartUploadError {
     #[allow(unused_variables)]
@@ -14232,6 +14312,11 @@ impl CompleteMultipartUploadError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CompleteMultipartUploadError {
     #[allow(unused_variables)]
@@ -14244,7 +14329,7 @@ impl Error for CompleteMultipartUploadError {}
 #[derive(Debug, PartialEq)]
 pub enum CopyObjectError {
     /// <p>The source object of the COPY operation is not in the active tier and is only stored in Amazon S3 Glacier.</p>
-    ObjectNotInActiveTierError(String),
+    ObjectNotInActiveTierError(XmlErrorMessage),
 }
 
 impl CopyObjectError {
@@ -14257,7 +14342,7 @@ impl CopyObjectError {
                 match &parsed_error.code[..] {
                     "ObjectNotInActiveTierError" => {
                         return RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14273,6 +14358,13 @@ impl CopyObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CopyObjectError::ObjectNotInActiveTierError(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CopyObjectError {
     #[allow(unused_variables)]
@@ -14287,9 +14379,9 @@ impl Error for CopyObjectError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateBucketError {
     /// <p>The requested bucket name is not available. The bucket namespace is shared by all users of the system. Please select a different name and try again.</p>
-    BucketAlreadyExists(String),
+    BucketAlreadyExists(XmlErrorMessage),
     /// <p>The bucket you tried to create already exists, and you own it. Amazon S3 returns this error in all AWS Regions except in the North Virginia Region. For legacy compatibility, if you re-create an existing bucket that you already own in the North Virginia Region, Amazon S3 returns 200 OK and resets the bucket access control lists (ACLs).</p>
-    BucketAlreadyOwnedByYou(String),
+    BucketAlreadyOwnedByYou(XmlErrorMessage),
 }
 
 impl CreateBucketError {
@@ -14302,12 +14394,12 @@ impl CreateBucketError {
                 match &parsed_error.code[..] {
                     "BucketAlreadyExists" => {
                         return RusotoError::Service(CreateBucketError::BucketAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "BucketAlreadyOwnedByYou" => {
                         return RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -14323,6 +14415,14 @@ impl CreateBucketError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateBucketError::BucketAlreadyExists(ref cause) => cause.request_id(),
+            CreateBucketError::BucketAlreadyOwnedByYou(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateBucketError {
     #[allow(unused_variables)]
@@ -14359,6 +14459,11 @@ impl CreateMultipartUploadError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for CreateMultipartUploadError {
     #[allow(unused_variables)]
@@ -14392,6 +14497,11 @@ impl DeleteBucketError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketError {
     #[allow(unused_variables)]
@@ -14427,6 +14537,11 @@ impl DeleteBucketAnalyticsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -14460,6 +14575,11 @@ impl DeleteBucketCorsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketCorsError {
     #[allow(unused_variables)]
@@ -14493,6 +14613,11 @@ impl DeleteBucketEncryptionError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketEncryptionError {
     #[allow(unused_variables)]
@@ -14528,6 +14653,11 @@ impl DeleteBucketInventoryConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -14561,6 +14691,11 @@ impl DeleteBucketLifecycleError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketLifecycleError {
     #[allow(unused_variables)]
@@ -14596,6 +14731,11 @@ impl DeleteBucketMetricsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -14629,6 +14769,11 @@ impl DeleteBucketPolicyError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketPolicyError {
     #[allow(unused_variables)]
@@ -14662,6 +14807,11 @@ impl DeleteBucketReplicationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketReplicationError {
     #[allow(unused_variables)]
@@ -14695,6 +14845,11 @@ impl DeleteBucketTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketTaggingError {
     #[allow(unused_variables)]
@@ -14728,6 +14883,11 @@ impl DeleteBucketWebsiteError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteBucketWebsiteError {
     #[allow(unused_variables)]
@@ -14761,6 +14921,11 @@ impl DeleteObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteObjectError {
     #[allow(unused_variables)]
@@ -14794,6 +14959,11 @@ impl DeleteObjectTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteObjectTaggingError {
     #[allow(unused_variables)]
@@ -14827,6 +14997,11 @@ impl DeleteObjectsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteObjectsError {
     #[allow(unused_variables)]
@@ -14860,6 +15035,11 @@ impl DeletePublicAccessBlockError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeletePublicAccessBlockError {
     #[allow(unused_variables)]
@@ -14895,6 +15075,11 @@ impl GetBucketAccelerateConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
@@ -14928,6 +15113,11 @@ impl GetBucketAclError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketAclError {
     #[allow(unused_variables)]
@@ -14963,6 +15153,11 @@ impl GetBucketAnalyticsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -14996,6 +15191,11 @@ impl GetBucketCorsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketCorsError {
     #[allow(unused_variables)]
@@ -15029,6 +15229,11 @@ impl GetBucketEncryptionError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketEncryptionError {
     #[allow(unused_variables)]
@@ -15064,6 +15269,11 @@ impl GetBucketInventoryConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -15097,6 +15307,11 @@ impl GetBucketLifecycleError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketLifecycleError {
     #[allow(unused_variables)]
@@ -15132,6 +15347,11 @@ impl GetBucketLifecycleConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
@@ -15165,6 +15385,11 @@ impl GetBucketLocationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketLocationError {
     #[allow(unused_variables)]
@@ -15198,6 +15423,11 @@ impl GetBucketLoggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketLoggingError {
     #[allow(unused_variables)]
@@ -15233,6 +15463,11 @@ impl GetBucketMetricsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -15266,6 +15501,11 @@ impl GetBucketNotificationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketNotificationError {
     #[allow(unused_variables)]
@@ -15301,6 +15541,11 @@ impl GetBucketNotificationConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -15334,6 +15579,11 @@ impl GetBucketPolicyError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketPolicyError {
     #[allow(unused_variables)]
@@ -15367,6 +15617,11 @@ impl GetBucketPolicyStatusError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketPolicyStatusError {
     #[allow(unused_variables)]
@@ -15400,6 +15655,11 @@ impl GetBucketReplicationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketReplicationError {
     #[allow(unused_variables)]
@@ -15433,6 +15693,11 @@ impl GetBucketRequestPaymentError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketRequestPaymentError {
     #[allow(unused_variables)]
@@ -15466,6 +15731,11 @@ impl GetBucketTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketTaggingError {
     #[allow(unused_variables)]
@@ -15499,6 +15769,11 @@ impl GetBucketVersioningError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketVersioningError {
     #[allow(unused_variables)]
@@ -15532,6 +15807,11 @@ impl GetBucketWebsiteError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetBucketWebsiteError {
     #[allow(unused_variables)]
@@ -15544,7 +15824,7 @@ impl Error for GetBucketWebsiteError {}
 #[derive(Debug, PartialEq)]
 pub enum GetObjectError {
     /// <p>The specified key does not exist.</p>
-    NoSuchKey(String),
+    NoSuchKey(XmlErrorMessage),
 }
 
 impl GetObjectError {
@@ -15557,7 +15837,7 @@ impl GetObjectError {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
                         return RusotoError::Service(GetObjectError::NoSuchKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15573,6 +15853,13 @@ impl GetObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetObjectError::NoSuchKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetObjectError {
     #[allow(unused_variables)]
@@ -15587,7 +15874,7 @@ impl Error for GetObjectError {}
 #[derive(Debug, PartialEq)]
 pub enum GetObjectAclError {
     /// <p>The specified key does not exist.</p>
-    NoSuchKey(String),
+    NoSuchKey(XmlErrorMessage),
 }
 
 impl GetObjectAclError {
@@ -15600,7 +15887,7 @@ impl GetObjectAclError {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
                         return RusotoError::Service(GetObjectAclError::NoSuchKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15616,6 +15903,13 @@ impl GetObjectAclError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetObjectAclError::NoSuchKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetObjectAclError {
     #[allow(unused_variables)]
@@ -15651,6 +15945,11 @@ impl GetObjectLegalHoldError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetObjectLegalHoldError {
     #[allow(unused_variables)]
@@ -15686,6 +15985,11 @@ impl GetObjectLockConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetObjectLockConfigurationError {
     #[allow(unused_variables)]
@@ -15719,6 +16023,11 @@ impl GetObjectRetentionError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetObjectRetentionError {
     #[allow(unused_variables)]
@@ -15752,6 +16061,11 @@ impl GetObjectTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetObjectTaggingError {
     #[allow(unused_variables)]
@@ -15785,6 +16099,11 @@ impl GetObjectTorrentError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetObjectTorrentError {
     #[allow(unused_variables)]
@@ -15818,6 +16137,11 @@ impl GetPublicAccessBlockError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetPublicAccessBlockError {
     #[allow(unused_variables)]
@@ -15830,7 +16154,7 @@ impl Error for GetPublicAccessBlockError {}
 #[derive(Debug, PartialEq)]
 pub enum HeadBucketError {
     /// <p>The specified bucket does not exist.</p>
-    NoSuchBucket(String),
+    NoSuchBucket(XmlErrorMessage),
 }
 
 impl HeadBucketError {
@@ -15843,7 +16167,7 @@ impl HeadBucketError {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
                         return RusotoError::Service(HeadBucketError::NoSuchBucket(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15859,6 +16183,13 @@ impl HeadBucketError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            HeadBucketError::NoSuchBucket(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for HeadBucketError {
     #[allow(unused_variables)]
@@ -15873,7 +16204,7 @@ impl Error for HeadBucketError {}
 #[derive(Debug, PartialEq)]
 pub enum HeadObjectError {
     /// <p>The specified key does not exist.</p>
-    NoSuchKey(String),
+    NoSuchKey(XmlErrorMessage),
 }
 
 impl HeadObjectError {
@@ -15886,7 +16217,7 @@ impl HeadObjectError {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
                         return RusotoError::Service(HeadObjectError::NoSuchKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -15902,6 +16233,13 @@ impl HeadObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            HeadObjectError::NoSuchKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for HeadObjectError {
     #[allow(unused_variables)]
@@ -15939,6 +16277,11 @@ impl ListBucketAnalyticsConfigurationsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListBucketAnalyticsConfigurationsError {
     #[allow(unused_variables)]
@@ -15974,6 +16317,11 @@ impl ListBucketInventoryConfigurationsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListBucketInventoryConfigurationsError {
     #[allow(unused_variables)]
@@ -16009,6 +16357,11 @@ impl ListBucketMetricsConfigurationsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListBucketMetricsConfigurationsError {
     #[allow(unused_variables)]
@@ -16042,6 +16395,11 @@ impl ListBucketsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListBucketsError {
     #[allow(unused_variables)]
@@ -16075,6 +16433,11 @@ impl ListMultipartUploadsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListMultipartUploadsError {
     #[allow(unused_variables)]
@@ -16108,6 +16471,11 @@ impl ListObjectVersionsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListObjectVersionsError {
     #[allow(unused_variables)]
@@ -16120,7 +16488,7 @@ impl Error for ListObjectVersionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListObjectsError {
     /// <p>The specified bucket does not exist.</p>
-    NoSuchBucket(String),
+    NoSuchBucket(XmlErrorMessage),
 }
 
 impl ListObjectsError {
@@ -16133,7 +16501,7 @@ impl ListObjectsError {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
                         return RusotoError::Service(ListObjectsError::NoSuchBucket(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16149,6 +16517,13 @@ impl ListObjectsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListObjectsError::NoSuchBucket(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListObjectsError {
     #[allow(unused_variables)]
@@ -16163,7 +16538,7 @@ impl Error for ListObjectsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListObjectsV2Error {
     /// <p>The specified bucket does not exist.</p>
-    NoSuchBucket(String),
+    NoSuchBucket(XmlErrorMessage),
 }
 
 impl ListObjectsV2Error {
@@ -16176,7 +16551,7 @@ impl ListObjectsV2Error {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
                         return RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16192,6 +16567,13 @@ impl ListObjectsV2Error {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListObjectsV2Error::NoSuchBucket(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListObjectsV2Error {
     #[allow(unused_variables)]
@@ -16227,6 +16609,11 @@ impl ListPartsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListPartsError {
     #[allow(unused_variables)]
@@ -16262,6 +16649,11 @@ impl PutBucketAccelerateConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
@@ -16295,6 +16687,11 @@ impl PutBucketAclError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketAclError {
     #[allow(unused_variables)]
@@ -16330,6 +16727,11 @@ impl PutBucketAnalyticsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -16363,6 +16765,11 @@ impl PutBucketCorsError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketCorsError {
     #[allow(unused_variables)]
@@ -16396,6 +16803,11 @@ impl PutBucketEncryptionError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketEncryptionError {
     #[allow(unused_variables)]
@@ -16431,6 +16843,11 @@ impl PutBucketInventoryConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -16464,6 +16881,11 @@ impl PutBucketLifecycleError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketLifecycleError {
     #[allow(unused_variables)]
@@ -16499,6 +16921,11 @@ impl PutBucketLifecycleConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
@@ -16532,6 +16959,11 @@ impl PutBucketLoggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketLoggingError {
     #[allow(unused_variables)]
@@ -16567,6 +16999,11 @@ impl PutBucketMetricsConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -16600,6 +17037,11 @@ impl PutBucketNotificationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketNotificationError {
     #[allow(unused_variables)]
@@ -16635,6 +17077,11 @@ impl PutBucketNotificationConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -16668,6 +17115,11 @@ impl PutBucketPolicyError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketPolicyError {
     #[allow(unused_variables)]
@@ -16701,6 +17153,11 @@ impl PutBucketReplicationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketReplicationError {
     #[allow(unused_variables)]
@@ -16734,6 +17191,11 @@ impl PutBucketRequestPaymentError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketRequestPaymentError {
     #[allow(unused_variables)]
@@ -16767,6 +17229,11 @@ impl PutBucketTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketTaggingError {
     #[allow(unused_variables)]
@@ -16800,6 +17267,11 @@ impl PutBucketVersioningError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketVersioningError {
     #[allow(unused_variables)]
@@ -16833,6 +17305,11 @@ impl PutBucketWebsiteError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutBucketWebsiteError {
     #[allow(unused_variables)]
@@ -16866,6 +17343,11 @@ impl PutObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutObjectError {
     #[allow(unused_variables)]
@@ -16878,7 +17360,7 @@ impl Error for PutObjectError {}
 #[derive(Debug, PartialEq)]
 pub enum PutObjectAclError {
     /// <p>The specified key does not exist.</p>
-    NoSuchKey(String),
+    NoSuchKey(XmlErrorMessage),
 }
 
 impl PutObjectAclError {
@@ -16891,7 +17373,7 @@ impl PutObjectAclError {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
                         return RusotoError::Service(PutObjectAclError::NoSuchKey(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -16907,6 +17389,13 @@ impl PutObjectAclError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutObjectAclError::NoSuchKey(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutObjectAclError {
     #[allow(unused_variables)]
@@ -16942,6 +17431,11 @@ impl PutObjectLegalHoldError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutObjectLegalHoldError {
     #[allow(unused_variables)]
@@ -16977,6 +17471,11 @@ impl PutObjectLockConfigurationError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutObjectLockConfigurationError {
     #[allow(unused_variables)]
@@ -17010,6 +17509,11 @@ impl PutObjectRetentionError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutObjectRetentionError {
     #[allow(unused_variables)]
@@ -17043,6 +17547,11 @@ impl PutObjectTaggingError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutObjectTaggingError {
     #[allow(unused_variables)]
@@ -17076,6 +17585,11 @@ impl PutPublicAccessBlockError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for PutPublicAccessBlockError {
     #[allow(unused_variables)]
@@ -17088,7 +17602,7 @@ impl Error for PutPublicAccessBlockError {}
 #[derive(Debug, PartialEq)]
 pub enum RestoreObjectError {
     /// <p>This operation is not allowed against this storage tier.</p>
-    ObjectAlreadyInActiveTierError(String),
+    ObjectAlreadyInActiveTierError(XmlErrorMessage),
 }
 
 impl RestoreObjectError {
@@ -17102,7 +17616,7 @@ impl RestoreObjectError {
                     "ObjectAlreadyInActiveTierError" => {
                         return RusotoError::Service(
                             RestoreObjectError::ObjectAlreadyInActiveTierError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -17119,6 +17633,13 @@ impl RestoreObjectError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RestoreObjectError::ObjectAlreadyInActiveTierError(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RestoreObjectError {
     #[allow(unused_variables)]
@@ -17154,6 +17675,11 @@ impl SelectObjectContentError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SelectObjectContentError {
     #[allow(unused_variables)]
@@ -17187,6 +17713,11 @@ impl UploadPartError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UploadPartError {
     #[allow(unused_variables)]
@@ -17220,6 +17751,11 @@ impl UploadPartCopyError {
     {
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UploadPartCopyError {
     #[allow(unused_variables)]
@@ -17782,6 +18318,7 @@ pub trait S3 {
 pub struct S3Client {
     client: Client,
     region: region::Region,
+    pub(crate) config: crate::custom::S3Config,
 }
 
 impl S3Client {
@@ -17792,6 +18329,7 @@ impl S3Client {
         S3Client {
             client: Client::shared(),
             region,
+            config: Default::default(),
         }
     }
 
@@ -17807,11 +18345,16 @@ impl S3Client {
         S3Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
             region,
+            config: Default::default(),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> S3Client {
-        S3Client { client, region }
+        S3Client {
+            client,
+            region,
+            config: Default::default(),
+        }
     }
 }
 
@@ -17826,6 +18369,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -17851,6 +18395,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -17897,6 +18442,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CopyObject");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header("Cache-Control", input.cache_control.as_ref());
@@ -18033,6 +18579,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CreateBucket");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -18079,6 +18626,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CreateMultipartUpload");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header("Cache-Control", input.cache_control.as_ref());
@@ -18183,6 +18731,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucket");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketError::from_response)
@@ -18201,6 +18750,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18227,6 +18777,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
@@ -18249,6 +18800,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
@@ -18271,6 +18823,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18297,6 +18850,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -18319,6 +18873,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18345,6 +18900,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketPolicy");
 
         let mut params = Params::new();
         params.put_key("policy");
@@ -18367,6 +18923,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketReplication");
 
         let mut params = Params::new();
         params.put_key("replication");
@@ -18389,6 +18946,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
@@ -18411,6 +18969,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
@@ -18433,6 +18992,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -18470,6 +19030,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -18497,6 +19058,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjects");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -18535,6 +19097,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeletePublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
@@ -18560,6 +19123,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAccelerateConfiguration");
 
         let mut params = Params::new();
         params.put_key("accelerate");
@@ -18591,6 +19155,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAcl");
 
         let mut params = Params::new();
         params.put_key("acl");
@@ -18622,6 +19187,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18651,6 +19217,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
@@ -18679,6 +19246,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
@@ -18710,6 +19278,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18739,6 +19308,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -18770,6 +19340,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycleConfiguration");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -18798,6 +19369,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLocation");
 
         let mut params = Params::new();
         params.put_key("location");
@@ -18826,6 +19398,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLogging");
 
         let mut params = Params::new();
         params.put_key("logging");
@@ -18855,6 +19428,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -18884,6 +19458,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotification");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -18913,6 +19488,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotificationConfiguration");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -18944,6 +19520,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicy");
 
         let mut params = Params::new();
         params.put_key("policy");
@@ -18954,7 +19531,7 @@ impl S3 for S3Client {
             .await?;
 
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         let mut result = GetBucketPolicyOutput::default();
         result.policy = Some(String::from_utf8_lossy(response.body.as_ref()).into());
 
@@ -18970,6 +19547,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicyStatus");
 
         let mut params = Params::new();
         params.put_key("policyStatus");
@@ -18998,6 +19576,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketReplication");
 
         let mut params = Params::new();
         params.put_key("replication");
@@ -19026,6 +19605,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketRequestPayment");
 
         let mut params = Params::new();
         params.put_key("requestPayment");
@@ -19054,6 +19634,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
@@ -19082,6 +19663,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketVersioning");
 
         let mut params = Params::new();
         params.put_key("versioning");
@@ -19110,6 +19692,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
@@ -19138,7 +19721,9 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObject");
 
+        request.add_optional_header("x-amz-checksum-mode", input.checksum_mode.as_ref());
         request.add_optional_header("If-Match", input.if_match.as_ref());
         request.add_optional_header("If-Modified-Since", input.if_modified_since.as_ref());
         request.add_optional_header("If-None-Match", input.if_none_match.as_ref());
@@ -19192,6 +19777,10 @@ impl S3 for S3Client {
         result.body = Some(response.body);
         result.accept_ranges = response.headers.remove("accept-ranges");
         result.cache_control = response.headers.remove("Cache-Control");
+        result.checksum_crc32 = response.headers.remove("x-amz-checksum-crc32");
+        result.checksum_crc32c = response.headers.remove("x-amz-checksum-crc32c");
+        result.checksum_sha1 = response.headers.remove("x-amz-checksum-sha1");
+        result.checksum_sha256 = response.headers.remove("x-amz-checksum-sha256");
         result.content_disposition = response.headers.remove("Content-Disposition");
         result.content_encoding = response.headers.remove("Content-Encoding");
         result.content_language = response.headers.remove("Content-Language");
@@ -19254,6 +19843,7 @@ impl S3 for S3Client {
         result.version_id = response.headers.remove("x-amz-version-id");
         result.website_redirect_location =
             response.headers.remove("x-amz-website-redirect-location");
+        crate::custom::validate_checksum(&input, &mut result);
         Ok(result)
     }
 
@@ -19266,6 +19856,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectAcl");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19298,6 +19889,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLegalHold");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19331,6 +19923,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLockConfiguration");
 
         let mut params = Params::new();
         params.put_key("object-lock");
@@ -19359,6 +19952,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectRetention");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19391,6 +19985,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -19422,6 +20017,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTorrent");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19447,6 +20043,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetPublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
@@ -19475,6 +20072,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadBucket");
 
         let mut response = self
             .sign_and_dispatch(request, HeadBucketError::from_response)
@@ -19493,6 +20091,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadObject");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
         request.add_optional_header("If-Modified-Since", input.if_modified_since.as_ref());
@@ -19601,6 +20200,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketAnalyticsConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -19638,6 +20238,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketInventoryConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -19675,6 +20276,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketMetricsConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -19703,6 +20305,7 @@ impl S3 for S3Client {
         let request_uri = "/";
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBuckets");
 
         let mut response = self
             .sign_and_dispatch(request, ListBucketsError::from_response)
@@ -19727,6 +20330,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
@@ -19773,6 +20377,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
@@ -19819,6 +20424,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjects");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19862,6 +20468,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectsV2");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19912,6 +20519,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListParts");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -19949,6 +20557,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAccelerateConfiguration");
 
         let mut params = Params::new();
         params.put_key("accelerate");
@@ -19981,6 +20590,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAcl");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -20024,6 +20634,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -20054,6 +20665,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
@@ -20084,6 +20696,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
@@ -20114,6 +20727,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -20144,6 +20758,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -20178,6 +20793,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycleConfiguration");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -20212,6 +20828,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLogging");
 
         let mut params = Params::new();
         params.put_key("logging");
@@ -20242,6 +20859,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -20272,6 +20890,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotification");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -20302,6 +20921,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotificationConfiguration");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -20334,6 +20954,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketPolicy");
 
         request.add_optional_header(
             "x-amz-confirm-remove-self-bucket-access",
@@ -20362,6 +20983,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketReplication");
 
         request.add_optional_header("x-amz-bucket-object-lock-token", input.token.as_ref());
         let mut params = Params::new();
@@ -20393,6 +21015,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketRequestPayment");
 
         let mut params = Params::new();
         params.put_key("requestPayment");
@@ -20423,6 +21046,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
@@ -20449,6 +21073,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketVersioning");
 
         request.add_optional_header("x-amz-mfa", input.mfa.as_ref());
         let mut params = Params::new();
@@ -20480,6 +21105,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
@@ -20510,9 +21136,18 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObject");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header("Cache-Control", input.cache_control.as_ref());
+        request.add_optional_header(
+            "x-amz-sdk-checksum-algorithm",
+            input.checksum_algorithm.as_ref(),
+        );
+        request.add_optional_header("x-amz-checksum-crc32", input.checksum_crc32.as_ref());
+        request.add_optional_header("x-amz-checksum-crc32c", input.checksum_crc32c.as_ref());
+        request.add_optional_header("x-amz-checksum-sha1", input.checksum_sha1.as_ref());
+        request.add_optional_header("x-amz-checksum-sha256", input.checksum_sha256.as_ref());
         request.add_optional_header("Content-Disposition", input.content_disposition.as_ref());
         request.add_optional_header("Content-Encoding", input.content_encoding.as_ref());
         request.add_optional_header("Content-Language", input.content_language.as_ref());
@@ -20578,6 +21213,12 @@ impl S3 for S3Client {
         if let Some(__body) = input.body {
             request.set_payload_stream(__body);
         }
+        if self.config.put_object_content_md5 {
+            crate::custom::add_content_md5(&mut request)
+                .await
+                .map_err(RusotoError::HttpDispatch)?;
+        }
+        crate::custom::add_missing_checksum(&mut request).map_err(RusotoError::Validation)?;
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectError::from_response)
@@ -20585,6 +21226,10 @@ impl S3 for S3Client {
 
         let result = PutObjectOutput::default();
         let mut result = result;
+        result.checksum_crc32 = response.headers.remove("x-amz-checksum-crc32");
+        result.checksum_crc32c = response.headers.remove("x-amz-checksum-crc32c");
+        result.checksum_sha1 = response.headers.remove("x-amz-checksum-sha1");
+        result.checksum_sha256 = response.headers.remove("x-amz-checksum-sha256");
         result.e_tag = response.headers.remove("ETag");
         result.expiration = response.headers.remove("x-amz-expiration");
         result.request_charged = response.headers.remove("x-amz-request-charged");
@@ -20614,6 +21259,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectAcl");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -20663,6 +21309,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLegalHold");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -20704,6 +21351,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLockConfiguration");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         request.add_optional_header("x-amz-bucket-object-lock-token", input.token.as_ref());
@@ -20742,6 +21390,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectRetention");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -20786,6 +21435,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -20817,6 +21467,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutPublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
@@ -20847,6 +21498,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("RestoreObject");
 
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -20887,6 +21539,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("SelectObjectContent");
 
         request.add_optional_header(
             "x-amz-server-side-encryption-customer-algorithm",
@@ -20917,7 +21570,9 @@ impl S3 for S3Client {
             .sign_and_dispatch(request, SelectObjectContentError::from_response)
             .await?;
 
-        unimplemented!()
+        Ok(SelectObjectContentOutput {
+            payload: Some(EventStream::new(response)),
+        })
     }
 
     /// <p><p>Uploads a part in a multipart upload.</p> <note> <p>In this operation, you provide part data in your request. However, you have an option to specify your existing Amazon S3 object as a data source for the part you are uploading. To upload a part from an existing object, you use the <a>UploadPartCopy</a> operation. </p> </note> <p>You must initiate a multipart upload (see <a>CreateMultipartUpload</a>) before you can upload any part. In response to your initiate request, Amazon S3 returns an upload ID, a unique identifier, that you must include in your upload part request.</p> <p>Part numbers can be any number from 1 to 10,000, inclusive. A part number uniquely identifies a part and also defines its position within the object being created. If you upload a new part using the same part number that was used with a previous part, the previously uploaded part is overwritten. Each part must be at least 5 MB in size, except the last part. There is no size limit on the last part of your multipart upload.</p> <p>To ensure that data is not corrupted when traversing the network, specify the <code>Content-MD5</code> header in the upload part request. Amazon S3 checks the part data against the provided MD5 value. If they do not match, Amazon S3 returns an error. </p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p> <p>For more information on multipart uploads, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html">Multipart Upload Overview</a> in the <i>Amazon Simple Storage Service Developer Guide </i>.</p> <p>For information on the permissions required to use the multipart upload API, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>You can optionally request server-side encryption where Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it for you when you access it. You have the option of providing your own encryption key, or you can use the AWS managed encryption keys. If you choose to provide your own encryption key, the request headers you provide in the request must match the headers you used in the request to initiate the upload by using <a>CreateMultipartUpload</a>. For more information, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingServerSideEncryption.html">Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>Server-side encryption is supported by the S3 Multipart Upload actions. Unless you are using a customer-provided encryption key, you don&#39;t need to specify the encryption parameters in each UploadPart request. Instead, you only need to specify the server-side encryption parameters in the initial Initiate Multipart request. For more information, see <a>CreateMultipartUpload</a>.</p> <p>If you requested server-side encryption using a customer-provided encryption key in your initiate multipart upload request, you must provide identical encryption information in each part upload using the following headers.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: NoSuchUpload</i> </p> </li> <li> <p> <i>Cause: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</i> </p> </li> <li> <p> <i> HTTP Status Code: 404 Not Found </i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: Client</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
@@ -20929,7 +21584,16 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPart");
 
+        request.add_optional_header(
+            "x-amz-sdk-checksum-algorithm",
+            input.checksum_algorithm.as_ref(),
+        );
+        request.add_optional_header("x-amz-checksum-crc32", input.checksum_crc32.as_ref());
+        request.add_optional_header("x-amz-checksum-crc32c", input.checksum_crc32c.as_ref());
+        request.add_optional_header("x-amz-checksum-sha1", input.checksum_sha1.as_ref());
+        request.add_optional_header("x-amz-checksum-sha256", input.checksum_sha256.as_ref());
         request.add_optional_header("Content-Length", input.content_length.as_ref());
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
@@ -20952,6 +21616,7 @@ impl S3 for S3Client {
         if let Some(__body) = input.body {
             request.set_payload_stream(__body);
         }
+        crate::custom::add_missing_checksum(&mut request).map_err(RusotoError::Validation)?;
 
         let mut response = self
             .sign_and_dispatch(request, UploadPartError::from_response)
@@ -20959,6 +21624,10 @@ impl S3 for S3Client {
 
         let result = UploadPartOutput::default();
         let mut result = result;
+        result.checksum_crc32 = response.headers.remove("x-amz-checksum-crc32");
+        result.checksum_crc32c = response.headers.remove("x-amz-checksum-crc32c");
+        result.checksum_sha1 = response.headers.remove("x-amz-checksum-sha1");
+        result.checksum_sha256 = response.headers.remove("x-amz-checksum-sha256");
         result.e_tag = response.headers.remove("ETag");
         result.request_charged = response.headers.remove("x-amz-request-charged");
         result.sse_customer_algorithm = response
@@ -20983,6 +21652,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPartCopy");
 
         request.add_header("x-amz-copy-source", &input.copy_source.to_string());
         request.add_optional_header(
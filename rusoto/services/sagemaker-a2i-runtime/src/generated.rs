@@ -510,6 +510,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DeleteHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -520,13 +521,13 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteHumanLoopResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteHumanLoopError::from_response(response))
         }
     }
@@ -543,6 +544,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DescribeHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -553,13 +555,13 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeHumanLoopResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeHumanLoopError::from_response(response))
         }
     }
@@ -573,6 +575,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("ListHumanLoops");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -602,13 +605,13 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListHumanLoopsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListHumanLoopsError::from_response(response))
         }
     }
@@ -622,6 +625,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StartHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -634,13 +638,13 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartHumanLoopResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartHumanLoopError::from_response(response))
         }
     }
@@ -654,6 +658,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops/stop";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StopHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -666,13 +671,13 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopHumanLoopResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopHumanLoopError::from_response(response))
         }
     }
@@ -180,6 +180,7 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("InvokeEndpoint");
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -205,7 +206,7 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = InvokeEndpointOutput::default();
             result.body = response.body;
@@ -219,7 +220,7 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InvokeEndpointError::from_response(response))
         }
     }
@@ -42,7 +42,7 @@ impl SageMakerClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -13633,7 +13633,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, AddTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
     }
 
@@ -13651,7 +13651,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, AssociateTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateTrialComponentResponse, _>()
     }
@@ -13670,7 +13670,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateAlgorithmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAlgorithmOutput, _>()
     }
 
@@ -13688,7 +13688,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResponse, _>()
     }
 
@@ -13706,7 +13706,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateAutoMLJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAutoMLJobResponse, _>()
     }
 
@@ -13724,7 +13724,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateCodeRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCodeRepositoryOutput, _>()
     }
 
@@ -13742,7 +13742,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateCompilationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCompilationJobResponse, _>()
     }
@@ -13761,7 +13761,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainResponse, _>()
     }
 
@@ -13779,7 +13779,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointOutput, _>()
     }
 
@@ -13797,7 +13797,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateEndpointConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointConfigOutput, _>()
     }
 
@@ -13815,7 +13815,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateExperimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateExperimentResponse, _>()
     }
 
@@ -13833,7 +13833,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateFlowDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateFlowDefinitionResponse, _>()
     }
@@ -13852,7 +13852,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateHumanTaskUiError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHumanTaskUiResponse, _>()
     }
 
@@ -13873,7 +13873,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateHyperParameterTuningJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateHyperParameterTuningJobResponse, _>()
     }
@@ -13892,7 +13892,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateLabelingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateLabelingJobResponse, _>()
     }
 
@@ -13910,7 +13910,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateModelOutput, _>()
     }
 
@@ -13928,7 +13928,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateModelPackageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateModelPackageOutput, _>()
     }
 
@@ -13946,7 +13946,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateMonitoringScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateMonitoringScheduleResponse, _>()
     }
@@ -13965,7 +13965,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateNotebookInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateNotebookInstanceOutput, _>()
     }
@@ -13993,7 +13993,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateNotebookInstanceLifecycleConfigOutput, _>()
     }
@@ -14012,7 +14012,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreatePresignedDomainUrlError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePresignedDomainUrlResponse, _>()
     }
@@ -14040,7 +14040,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePresignedNotebookInstanceUrlOutput, _>()
     }
@@ -14059,7 +14059,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateProcessingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProcessingJobResponse, _>()
     }
 
@@ -14077,7 +14077,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateTrainingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTrainingJobResponse, _>()
     }
 
@@ -14095,7 +14095,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateTransformJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTransformJobResponse, _>()
     }
 
@@ -14113,7 +14113,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateTrialError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTrialResponse, _>()
     }
 
@@ -14131,7 +14131,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateTrialComponentResponse, _>()
     }
@@ -14150,7 +14150,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserProfileResponse, _>()
     }
 
@@ -14168,7 +14168,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, CreateWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkteamResponse, _>()
     }
 
@@ -14285,7 +14285,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteExperimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteExperimentResponse, _>()
     }
 
@@ -14303,7 +14303,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteFlowDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteFlowDefinitionResponse, _>()
     }
@@ -14322,7 +14322,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteHumanTaskUiError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteHumanTaskUiResponse, _>()
     }
 
@@ -14431,7 +14431,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsOutput, _>()
     }
 
@@ -14449,7 +14449,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteTrialError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrialResponse, _>()
     }
 
@@ -14467,7 +14467,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteTrialComponentResponse, _>()
     }
@@ -14503,7 +14503,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DeleteWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkteamResponse, _>()
     }
 
@@ -14521,7 +14521,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeAlgorithmError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAlgorithmOutput, _>()
     }
 
@@ -14539,7 +14539,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAppResponse, _>()
     }
 
@@ -14557,7 +14557,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeAutoMLJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAutoMLJobResponse, _>()
     }
 
@@ -14575,7 +14575,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeCodeRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCodeRepositoryOutput, _>()
     }
@@ -14594,7 +14594,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeCompilationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCompilationJobResponse, _>()
     }
@@ -14613,7 +14613,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDomainResponse, _>()
     }
 
@@ -14631,7 +14631,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeEndpointOutput, _>()
     }
 
@@ -14649,7 +14649,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeEndpointConfigError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEndpointConfigOutput, _>()
     }
@@ -14668,7 +14668,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeExperimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeExperimentResponse, _>()
     }
 
@@ -14686,7 +14686,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeFlowDefinitionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeFlowDefinitionResponse, _>()
     }
@@ -14705,7 +14705,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeHumanTaskUiError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeHumanTaskUiResponse, _>()
     }
 
@@ -14726,7 +14726,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeHyperParameterTuningJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeHyperParameterTuningJobResponse, _>()
     }
@@ -14745,7 +14745,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeLabelingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeLabelingJobResponse, _>()
     }
 
@@ -14763,7 +14763,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeModelError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeModelOutput, _>()
     }
 
@@ -14781,7 +14781,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeModelPackageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeModelPackageOutput, _>()
     }
 
@@ -14800,7 +14800,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeMonitoringScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMonitoringScheduleResponse, _>()
     }
@@ -14819,7 +14819,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeNotebookInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeNotebookInstanceOutput, _>()
     }
@@ -14847,7 +14847,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeNotebookInstanceLifecycleConfigOutput, _>()
     }
@@ -14866,7 +14866,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeProcessingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProcessingJobResponse, _>()
     }
@@ -14886,7 +14886,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeSubscribedWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSubscribedWorkteamResponse, _>()
     }
@@ -14905,7 +14905,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeTrainingJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrainingJobResponse, _>()
     }
 
@@ -14923,7 +14923,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeTransformJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTransformJobResponse, _>()
     }
@@ -14942,7 +14942,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeTrialError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrialResponse, _>()
     }
 
@@ -14960,7 +14960,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTrialComponentResponse, _>()
     }
@@ -14979,7 +14979,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserProfileResponse, _>()
     }
 
@@ -14997,7 +14997,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeWorkforceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeWorkforceResponse, _>()
     }
 
@@ -15015,7 +15015,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DescribeWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeWorkteamResponse, _>()
     }
 
@@ -15034,7 +15034,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, DisassociateTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateTrialComponentResponse, _>()
     }
@@ -15053,7 +15053,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, GetSearchSuggestionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSearchSuggestionsResponse, _>()
     }
@@ -15072,7 +15072,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListAlgorithmsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAlgorithmsOutput, _>()
     }
 
@@ -15090,7 +15090,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListAppsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResponse, _>()
     }
 
@@ -15108,7 +15108,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListAutoMLJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAutoMLJobsResponse, _>()
     }
 
@@ -15127,7 +15127,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListCandidatesForAutoMLJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCandidatesForAutoMLJobResponse, _>()
     }
@@ -15146,7 +15146,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListCodeRepositoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCodeRepositoriesOutput, _>()
     }
 
@@ -15164,7 +15164,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListCompilationJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCompilationJobsResponse, _>()
     }
 
@@ -15182,7 +15182,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListDomainsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDomainsResponse, _>()
     }
 
@@ -15200,7 +15200,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListEndpointConfigsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointConfigsOutput, _>()
     }
 
@@ -15218,7 +15218,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListEndpointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointsOutput, _>()
     }
 
@@ -15236,7 +15236,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListExperimentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListExperimentsResponse, _>()
     }
 
@@ -15254,7 +15254,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListFlowDefinitionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFlowDefinitionsResponse, _>()
     }
 
@@ -15272,7 +15272,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListHumanTaskUisError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListHumanTaskUisResponse, _>()
     }
 
@@ -15291,7 +15291,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListHyperParameterTuningJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListHyperParameterTuningJobsResponse, _>()
     }
@@ -15310,7 +15310,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListLabelingJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLabelingJobsResponse, _>()
     }
 
@@ -15329,7 +15329,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListLabelingJobsForWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListLabelingJobsForWorkteamResponse, _>()
     }
@@ -15348,7 +15348,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListModelPackagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListModelPackagesOutput, _>()
     }
 
@@ -15366,7 +15366,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListModelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListModelsOutput, _>()
     }
 
@@ -15384,7 +15384,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListMonitoringExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListMonitoringExecutionsResponse, _>()
     }
@@ -15403,7 +15403,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListMonitoringSchedulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListMonitoringSchedulesResponse, _>()
     }
@@ -15431,7 +15431,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListNotebookInstanceLifecycleConfigsOutput, _>()
     }
@@ -15450,7 +15450,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListNotebookInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListNotebookInstancesOutput, _>()
     }
 
@@ -15468,7 +15468,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListProcessingJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProcessingJobsResponse, _>()
     }
 
@@ -15486,7 +15486,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListSubscribedWorkteamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSubscribedWorkteamsResponse, _>()
     }
@@ -15505,7 +15505,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()
     }
 
@@ -15523,7 +15523,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListTrainingJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTrainingJobsResponse, _>()
     }
 
@@ -15550,7 +15550,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTrainingJobsForHyperParameterTuningJobResponse, _>()
     }
@@ -15569,7 +15569,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListTransformJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTransformJobsResponse, _>()
     }
 
@@ -15587,7 +15587,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListTrialComponentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTrialComponentsResponse, _>()
     }
 
@@ -15605,7 +15605,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListTrialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTrialsResponse, _>()
     }
 
@@ -15623,7 +15623,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListUserProfilesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUserProfilesResponse, _>()
     }
 
@@ -15641,7 +15641,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, ListWorkteamsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWorkteamsResponse, _>()
     }
 
@@ -15659,7 +15659,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, RenderUiTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RenderUiTemplateResponse, _>()
     }
 
@@ -15677,7 +15677,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, SearchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()
     }
 
@@ -15882,7 +15882,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateCodeRepositoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCodeRepositoryOutput, _>()
     }
 
@@ -15900,7 +15900,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDomainResponse, _>()
     }
 
@@ -15918,7 +15918,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateEndpointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointOutput, _>()
     }
 
@@ -15945,7 +15945,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateEndpointWeightsAndCapacitiesOutput, _>()
     }
@@ -15964,7 +15964,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateExperimentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateExperimentResponse, _>()
     }
 
@@ -15982,7 +15982,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateMonitoringScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMonitoringScheduleResponse, _>()
     }
@@ -16001,7 +16001,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateNotebookInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateNotebookInstanceOutput, _>()
     }
@@ -16029,7 +16029,7 @@ impl SageMaker for SageMakerClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateNotebookInstanceLifecycleConfigOutput, _>()
     }
@@ -16048,7 +16048,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateTrialError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrialResponse, _>()
     }
 
@@ -16066,7 +16066,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateTrialComponentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateTrialComponentResponse, _>()
     }
@@ -16085,7 +16085,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateUserProfileError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserProfileResponse, _>()
     }
 
@@ -16103,7 +16103,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateWorkforceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkforceResponse, _>()
     }
 
@@ -16121,7 +16121,7 @@ impl SageMaker for SageMakerClient {
             .sign_and_dispatch(request, UpdateWorkteamError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkteamResponse, _>()
     }
 }
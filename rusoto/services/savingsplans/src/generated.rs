@@ -1040,6 +1040,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/CreateSavingsPlan";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("CreateSavingsPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1051,13 +1052,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSavingsPlanResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSavingsPlanError::from_response(response))
         }
     }
@@ -1071,6 +1072,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlanRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlanRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1082,13 +1084,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlanRatesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSavingsPlanRatesError::from_response(response))
         }
     }
@@ -1102,6 +1104,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlans";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1113,13 +1116,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSavingsPlansError::from_response(response))
         }
     }
@@ -1136,6 +1139,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferingRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferingRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1147,13 +1151,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansOfferingRatesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSavingsPlansOfferingRatesError::from_response(
                 response,
             ))
@@ -1172,6 +1176,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferings";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1183,13 +1188,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansOfferingsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSavingsPlansOfferingsError::from_response(response))
         }
     }
@@ -1203,6 +1208,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1214,13 +1220,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -1234,6 +1240,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1245,13 +1252,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -1265,6 +1272,7 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1276,13 +1284,13 @@ impl SavingsPlans for SavingsPlansClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -2986,6 +2986,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2997,13 +2998,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDiscovererResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateDiscovererError::from_response(response))
         }
     }
@@ -3020,6 +3021,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3031,13 +3033,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRegistryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateRegistryError::from_response(response))
         }
     }
@@ -3055,6 +3057,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3066,13 +3069,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSchemaResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateSchemaError::from_response(response))
         }
     }
@@ -3089,6 +3092,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3097,12 +3101,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDiscovererError::from_response(response))
         }
     }
@@ -3119,6 +3123,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3127,12 +3132,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteRegistryError::from_response(response))
         }
     }
@@ -3146,6 +3151,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteResourcePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3160,12 +3166,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteResourcePolicyError::from_response(response))
         }
     }
@@ -3183,6 +3189,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3191,12 +3198,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSchemaError::from_response(response))
         }
     }
@@ -3210,6 +3217,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/version/{schema_version}", registry_name = input.registry_name, schema_name = input.schema_name, schema_version = input.schema_version);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3218,12 +3226,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteSchemaVersionError::from_response(response))
         }
     }
@@ -3242,6 +3250,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3256,13 +3265,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeCodeBindingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeCodeBindingError::from_response(response))
         }
     }
@@ -3279,6 +3288,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3287,13 +3297,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDiscovererResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDiscovererError::from_response(response))
         }
     }
@@ -3310,6 +3320,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3318,13 +3329,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRegistryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRegistryError::from_response(response))
         }
     }
@@ -3342,6 +3353,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3356,13 +3368,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSchemaResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeSchemaError::from_response(response))
         }
     }
@@ -3376,6 +3388,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}/source", language = input.language, registry_name = input.registry_name, schema_name = input.schema_name);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("GetCodeBindingSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3390,14 +3403,14 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetCodeBindingSourceResponse::default();
             result.body = Some(response.body);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCodeBindingSourceError::from_response(response))
         }
     }
@@ -3411,6 +3424,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discover";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("GetDiscoveredSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3422,13 +3436,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDiscoveredSchemaResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDiscoveredSchemaError::from_response(response))
         }
     }
@@ -3442,6 +3456,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("GetResourcePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3456,13 +3471,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetResourcePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourcePolicyError::from_response(response))
         }
     }
@@ -3476,6 +3491,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListDiscoverers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3499,13 +3515,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDiscoverersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDiscoverersError::from_response(response))
         }
     }
@@ -3519,6 +3535,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/registries";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListRegistries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3542,13 +3559,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRegistriesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListRegistriesError::from_response(response))
         }
     }
@@ -3566,6 +3583,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemaVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3583,13 +3601,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSchemaVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSchemaVersionsError::from_response(response))
         }
     }
@@ -3606,6 +3624,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3626,13 +3645,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSchemasResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListSchemasError::from_response(response))
         }
     }
@@ -3646,6 +3665,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3654,13 +3674,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3679,6 +3699,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("PutCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3693,13 +3714,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 202 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutCodeBindingResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutCodeBindingError::from_response(response))
         }
     }
@@ -3713,6 +3734,7 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("PutResourcePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3730,13 +3752,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutResourcePolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutResourcePolicyError::from_response(response))
         }
     }
@@ -3753,6 +3775,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("SearchSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3771,13 +3794,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SearchSchemasResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SearchSchemasError::from_response(response))
         }
     }
@@ -3794,6 +3817,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StartDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3802,13 +3826,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartDiscovererResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartDiscovererError::from_response(response))
         }
     }
@@ -3825,6 +3849,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StopDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3833,13 +3858,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StopDiscovererResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StopDiscovererError::from_response(response))
         }
     }
@@ -3853,6 +3878,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3864,12 +3890,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -3883,6 +3909,7 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3897,12 +3924,12 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -3919,6 +3946,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3930,13 +3958,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDiscovererResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDiscovererError::from_response(response))
         }
     }
@@ -3953,6 +3981,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3964,13 +3993,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateRegistryResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateRegistryError::from_response(response))
         }
     }
@@ -3988,6 +4017,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3999,13 +4029,13 @@ impl Schemas for SchemasClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateSchemaResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateSchemaError::from_response(response))
         }
     }
@@ -52,7 +52,7 @@ impl SimpleDbClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -935,6 +935,11 @@ impl BatchDeleteAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for BatchDeleteAttributesError {
     #[allow(unused_variables)]
@@ -947,23 +952,23 @@ impl Error for BatchDeleteAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum BatchPutAttributesError {
     /// <p>The item name was specified more than once. </p>
-    DuplicateItemName(String),
+    DuplicateItemName(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
     /// <p>Too many attributes in this domain.</p>
-    NumberDomainAttributesExceeded(String),
+    NumberDomainAttributesExceeded(XmlErrorMessage),
     /// <p>Too many bytes in this domain.</p>
-    NumberDomainBytesExceeded(String),
+    NumberDomainBytesExceeded(XmlErrorMessage),
     /// <p>Too many attributes in this item.</p>
-    NumberItemAttributesExceeded(String),
+    NumberItemAttributesExceeded(XmlErrorMessage),
     /// <p>Too many attributes exist in a single call.</p>
-    NumberSubmittedAttributesExceeded(String),
+    NumberSubmittedAttributesExceeded(XmlErrorMessage),
     /// <p>Too many items exist in a single call.</p>
-    NumberSubmittedItemsExceeded(String),
+    NumberSubmittedItemsExceeded(XmlErrorMessage),
 }
 
 impl BatchPutAttributesError {
@@ -976,56 +981,58 @@ impl BatchPutAttributesError {
                 match &parsed_error.code[..] {
                     "DuplicateItemName" => {
                         return RusotoError::Service(BatchPutAttributesError::DuplicateItemName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(
-                            BatchPutAttributesError::InvalidParameterValue(parsed_error.message),
+                            BatchPutAttributesError::InvalidParameterValue(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(BatchPutAttributesError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(BatchPutAttributesError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberDomainAttributesExceeded" => {
                         return RusotoError::Service(
                             BatchPutAttributesError::NumberDomainAttributesExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberDomainBytesExceeded" => {
                         return RusotoError::Service(
                             BatchPutAttributesError::NumberDomainBytesExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberItemAttributesExceeded" => {
                         return RusotoError::Service(
                             BatchPutAttributesError::NumberItemAttributesExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberSubmittedAttributesExceeded" => {
                         return RusotoError::Service(
                             BatchPutAttributesError::NumberSubmittedAttributesExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberSubmittedItemsExceeded" => {
                         return RusotoError::Service(
                             BatchPutAttributesError::NumberSubmittedItemsExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -1043,6 +1050,25 @@ impl BatchPutAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            BatchPutAttributesError::DuplicateItemName(ref cause) => cause.request_id(),
+            BatchPutAttributesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            BatchPutAttributesError::MissingParameter(ref cause) => cause.request_id(),
+            BatchPutAttributesError::NoSuchDomain(ref cause) => cause.request_id(),
+            BatchPutAttributesError::NumberDomainAttributesExceeded(ref cause) => {
+                cause.request_id()
+            }
+            BatchPutAttributesError::NumberDomainBytesExceeded(ref cause) => cause.request_id(),
+            BatchPutAttributesError::NumberItemAttributesExceeded(ref cause) => cause.request_id(),
+            BatchPutAttributesError::NumberSubmittedAttributesExceeded(ref cause) => {
+                cause.request_id()
+            }
+            BatchPutAttributesError::NumberSubmittedItemsExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for BatchPutAttributesError {
     #[allow(unused_variables)]
@@ -1073,11 +1099,11 @@ impl Error for BatchPutAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>Too many domains exist per this account.</p>
-    NumberDomainsExceeded(String),
+    NumberDomainsExceeded(XmlErrorMessage),
 }
 
 impl CreateDomainError {
@@ -1090,17 +1116,17 @@ impl CreateDomainError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(CreateDomainError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(CreateDomainError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberDomainsExceeded" => {
                         return RusotoError::Service(CreateDomainError::NumberDomainsExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1117,6 +1143,15 @@ impl CreateDomainError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateDomainError::InvalidParameterValue(ref cause) => cause.request_id(),
+            CreateDomainError::MissingParameter(ref cause) => cause.request_id(),
+            CreateDomainError::NumberDomainsExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateDomainError {
     #[allow(unused_variables)]
@@ -1133,13 +1168,13 @@ impl Error for CreateDomainError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteAttributesError {
     /// <p>The specified attribute does not exist.</p>
-    AttributeDoesNotExist(String),
+    AttributeDoesNotExist(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
 }
 
 impl DeleteAttributesError {
@@ -1152,22 +1187,22 @@ impl DeleteAttributesError {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
                         return RusotoError::Service(DeleteAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(DeleteAttributesError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(DeleteAttributesError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(DeleteAttributesError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1184,6 +1219,16 @@ impl DeleteAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteAttributesError::AttributeDoesNotExist(ref cause) => cause.request_id(),
+            DeleteAttributesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            DeleteAttributesError::MissingParameter(ref cause) => cause.request_id(),
+            DeleteAttributesError::NoSuchDomain(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteAttributesError {
     #[allow(unused_variables)]
@@ -1201,7 +1246,7 @@ impl Error for DeleteAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
 }
 
 impl DeleteDomainError {
@@ -1214,7 +1259,7 @@ impl DeleteDomainError {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
                         return RusotoError::Service(DeleteDomainError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1231,6 +1276,13 @@ impl DeleteDomainError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteDomainError::MissingParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteDomainError {
     #[allow(unused_variables)]
@@ -1245,9 +1297,9 @@ impl Error for DeleteDomainError {}
 #[derive(Debug, PartialEq)]
 pub enum DomainMetadataError {
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
 }
 
 impl DomainMetadataError {
@@ -1260,12 +1312,12 @@ impl DomainMetadataError {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
                         return RusotoError::Service(DomainMetadataError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(DomainMetadataError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1282,6 +1334,14 @@ impl DomainMetadataError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DomainMetadataError::MissingParameter(ref cause) => cause.request_id(),
+            DomainMetadataError::NoSuchDomain(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DomainMetadataError {
     #[allow(unused_variables)]
@@ -1297,11 +1357,11 @@ impl Error for DomainMetadataError {}
 #[derive(Debug, PartialEq)]
 pub enum GetAttributesError {
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
 }
 
 impl GetAttributesError {
@@ -1314,17 +1374,17 @@ impl GetAttributesError {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
                         return RusotoError::Service(GetAttributesError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(GetAttributesError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(GetAttributesError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1341,6 +1401,15 @@ impl GetAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetAttributesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            GetAttributesError::MissingParameter(ref cause) => cause.request_id(),
+            GetAttributesError::NoSuchDomain(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetAttributesError {
     #[allow(unused_variables)]
@@ -1357,9 +1426,9 @@ impl Error for GetAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {
     /// <p>The specified NextToken is not valid. </p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
 }
 
 impl ListDomainsError {
@@ -1372,12 +1441,12 @@ impl ListDomainsError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(ListDomainsError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(ListDomainsError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1394,6 +1463,14 @@ impl ListDomainsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDomainsError::InvalidNextToken(ref cause) => cause.request_id(),
+            ListDomainsError::InvalidParameterValue(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDomainsError {
     #[allow(unused_variables)]
@@ -1409,19 +1486,19 @@ impl Error for ListDomainsError {}
 #[derive(Debug, PartialEq)]
 pub enum PutAttributesError {
     /// <p>The specified attribute does not exist.</p>
-    AttributeDoesNotExist(String),
+    AttributeDoesNotExist(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
     /// <p>Too many attributes in this domain.</p>
-    NumberDomainAttributesExceeded(String),
+    NumberDomainAttributesExceeded(XmlErrorMessage),
     /// <p>Too many bytes in this domain.</p>
-    NumberDomainBytesExceeded(String),
+    NumberDomainBytesExceeded(XmlErrorMessage),
     /// <p>Too many attributes in this item.</p>
-    NumberItemAttributesExceeded(String),
+    NumberItemAttributesExceeded(XmlErrorMessage),
 }
 
 impl PutAttributesError {
@@ -1434,39 +1511,41 @@ impl PutAttributesError {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
                         return RusotoError::Service(PutAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(PutAttributesError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(PutAttributesError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(PutAttributesError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberDomainAttributesExceeded" => {
                         return RusotoError::Service(
                             PutAttributesError::NumberDomainAttributesExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NumberDomainBytesExceeded" => {
                         return RusotoError::Service(PutAttributesError::NumberDomainBytesExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NumberItemAttributesExceeded" => {
                         return RusotoError::Service(
-                            PutAttributesError::NumberItemAttributesExceeded(parsed_error.message),
+                            PutAttributesError::NumberItemAttributesExceeded(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -1483,6 +1562,19 @@ impl PutAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutAttributesError::AttributeDoesNotExist(ref cause) => cause.request_id(),
+            PutAttributesError::InvalidParameterValue(ref cause) => cause.request_id(),
+            PutAttributesError::MissingParameter(ref cause) => cause.request_id(),
+            PutAttributesError::NoSuchDomain(ref cause) => cause.request_id(),
+            PutAttributesError::NumberDomainAttributesExceeded(ref cause) => cause.request_id(),
+            PutAttributesError::NumberDomainBytesExceeded(ref cause) => cause.request_id(),
+            PutAttributesError::NumberItemAttributesExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutAttributesError {
     #[allow(unused_variables)]
@@ -1503,23 +1595,23 @@ impl Error for PutAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SelectError {
     /// <p>The specified NextToken is not valid. </p>
-    InvalidNextToken(String),
+    InvalidNextToken(XmlErrorMessage),
     /// <p>Too many predicates exist in the query expression.</p>
-    InvalidNumberPredicates(String),
+    InvalidNumberPredicates(XmlErrorMessage),
     /// <p>Too many predicates exist in the query expression.</p>
-    InvalidNumberValueTests(String),
+    InvalidNumberValueTests(XmlErrorMessage),
     /// <p>The value for a parameter is invalid.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The specified query expression syntax is not valid.</p>
-    InvalidQueryExpression(String),
+    InvalidQueryExpression(XmlErrorMessage),
     /// <p>The request must contain the specified missing parameter.</p>
-    MissingParameter(String),
+    MissingParameter(XmlErrorMessage),
     /// <p>The specified domain does not exist.</p>
-    NoSuchDomain(String),
+    NoSuchDomain(XmlErrorMessage),
     /// <p>A timeout occurred when attempting to query the specified domain with specified query expression.</p>
-    RequestTimeout(String),
+    RequestTimeout(XmlErrorMessage),
     /// <p>Too many attributes requested.</p>
-    TooManyRequestedAttributes(String),
+    TooManyRequestedAttributes(XmlErrorMessage),
 }
 
 impl SelectError {
@@ -1532,47 +1624,47 @@ impl SelectError {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
                         return RusotoError::Service(SelectError::InvalidNextToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidNumberPredicates" => {
                         return RusotoError::Service(SelectError::InvalidNumberPredicates(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidNumberValueTests" => {
                         return RusotoError::Service(SelectError::InvalidNumberValueTests(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameterValue" => {
                         return RusotoError::Service(SelectError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidQueryExpression" => {
                         return RusotoError::Service(SelectError::InvalidQueryExpression(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MissingParameter" => {
                         return RusotoError::Service(SelectError::MissingParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NoSuchDomain" => {
                         return RusotoError::Service(SelectError::NoSuchDomain(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RequestTimeout" => {
                         return RusotoError::Service(SelectError::RequestTimeout(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TooManyRequestedAttributes" => {
                         return RusotoError::Service(SelectError::TooManyRequestedAttributes(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1589,6 +1681,21 @@ impl SelectError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SelectError::InvalidNextToken(ref cause) => cause.request_id(),
+            SelectError::InvalidNumberPredicates(ref cause) => cause.request_id(),
+            SelectError::InvalidNumberValueTests(ref cause) => cause.request_id(),
+            SelectError::InvalidParameterValue(ref cause) => cause.request_id(),
+            SelectError::InvalidQueryExpression(ref cause) => cause.request_id(),
+            SelectError::MissingParameter(ref cause) => cause.request_id(),
+            SelectError::NoSuchDomain(ref cause) => cause.request_id(),
+            SelectError::RequestTimeout(ref cause) => cause.request_id(),
+            SelectError::TooManyRequestedAttributes(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SelectError {
     #[allow(unused_variables)]
@@ -42,7 +42,7 @@ impl SecretsManagerClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2057,7 +2057,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, CancelRotateSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelRotateSecretResponse, _>()
     }
 
@@ -2075,7 +2075,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, CreateSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSecretResponse, _>()
     }
 
@@ -2093,7 +2093,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, DeleteResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteResourcePolicyResponse, _>()
     }
@@ -2112,7 +2112,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, DeleteSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSecretResponse, _>()
     }
 
@@ -2130,7 +2130,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, DescribeSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSecretResponse, _>()
     }
 
@@ -2148,7 +2148,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, GetRandomPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRandomPasswordResponse, _>()
     }
 
@@ -2166,7 +2166,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, GetResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourcePolicyResponse, _>()
     }
 
@@ -2184,7 +2184,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, GetSecretValueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSecretValueResponse, _>()
     }
 
@@ -2202,7 +2202,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, ListSecretVersionIdsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSecretVersionIdsResponse, _>()
     }
@@ -2221,7 +2221,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, ListSecretsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListSecretsResponse, _>()
     }
 
@@ -2239,7 +2239,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, PutResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourcePolicyResponse, _>()
     }
 
@@ -2257,7 +2257,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, PutSecretValueError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutSecretValueResponse, _>()
     }
 
@@ -2275,7 +2275,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, RestoreSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RestoreSecretResponse, _>()
     }
 
@@ -2293,7 +2293,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, RotateSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RotateSecretResponse, _>()
     }
 
@@ -2345,7 +2345,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, UpdateSecretError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSecretResponse, _>()
     }
 
@@ -2363,7 +2363,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, UpdateSecretVersionStageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSecretVersionStageResponse, _>()
     }
@@ -2382,7 +2382,7 @@ impl SecretsManager for SecretsManagerClient {
             .sign_and_dispatch(request, ValidateResourcePolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ValidateResourcePolicyResponse, _>()
     }
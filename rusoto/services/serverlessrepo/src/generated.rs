@@ -2011,6 +2011,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2022,13 +2023,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApplicationError::from_response(response))
         }
     }
@@ -2046,6 +2047,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2057,13 +2059,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateApplicationVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateApplicationVersionError::from_response(response))
         }
     }
@@ -2083,6 +2085,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2094,13 +2097,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCloudFormationChangeSetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCloudFormationChangeSetError::from_response(response))
         }
     }
@@ -2118,6 +2121,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2129,13 +2133,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCloudFormationTemplateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCloudFormationTemplateError::from_response(response))
         }
     }
@@ -2153,6 +2157,7 @@ impl ServerlessRepo for ServerlessRepoClient {
 
         let mut request =
             SignedRequest::new("DELETE", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2161,12 +2166,12 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteApplicationError::from_response(response))
         }
     }
@@ -2183,6 +2188,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2197,13 +2203,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApplicationError::from_response(response))
         }
     }
@@ -2220,6 +2226,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2228,13 +2235,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetApplicationPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetApplicationPolicyError::from_response(response))
         }
     }
@@ -2253,6 +2260,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2261,13 +2269,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCloudFormationTemplateResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCloudFormationTemplateError::from_response(response))
         }
     }
@@ -2285,6 +2293,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationDependencies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2305,13 +2314,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListApplicationDependenciesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListApplicationDependenciesError::from_response(response))
         }
     }
@@ -2328,6 +2337,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2345,13 +2355,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListApplicationVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListApplicationVersionsError::from_response(response))
         }
     }
@@ -2365,6 +2375,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2382,13 +2393,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListApplicationsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListApplicationsError::from_response(response))
         }
     }
@@ -2408,6 +2419,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("PutApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2419,13 +2431,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutApplicationPolicyResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(PutApplicationPolicyError::from_response(response))
         }
     }
@@ -2442,6 +2454,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("UnshareApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2453,12 +2466,12 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UnshareApplicationError::from_response(response))
         }
     }
@@ -2475,6 +2488,7 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2486,13 +2500,13 @@ impl ServerlessRepo for ServerlessRepoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateApplicationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateApplicationError::from_response(response))
         }
     }
@@ -42,7 +42,7 @@ impl ServiceQuotasClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2250,7 +2250,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, AssociateServiceQuotaTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateServiceQuotaTemplateResponse, _>()
     }
@@ -2278,7 +2278,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteServiceQuotaIncreaseRequestFromTemplateResponse, _>()
     }
@@ -2304,7 +2304,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateServiceQuotaTemplateResponse, _>()
     }
@@ -2327,7 +2327,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, GetAWSDefaultServiceQuotaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAWSDefaultServiceQuotaResponse, _>()
     }
@@ -2353,7 +2353,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAssociationForServiceQuotaTemplateResponse, _>()
     }
@@ -2378,7 +2378,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, GetRequestedServiceQuotaChangeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRequestedServiceQuotaChangeResponse, _>()
     }
@@ -2397,7 +2397,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, GetServiceQuotaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetServiceQuotaResponse, _>()
     }
 
@@ -2424,7 +2424,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetServiceQuotaIncreaseRequestFromTemplateResponse, _>()
     }
@@ -2447,7 +2447,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, ListAWSDefaultServiceQuotasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAWSDefaultServiceQuotasResponse, _>()
     }
@@ -2475,7 +2475,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRequestedServiceQuotaChangeHistoryResponse, _>()
     }
@@ -2503,7 +2503,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRequestedServiceQuotaChangeHistoryByQuotaResponse, _>()
     }
@@ -2531,7 +2531,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListServiceQuotaIncreaseRequestsInTemplateResponse, _>()
     }
@@ -2550,7 +2550,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, ListServiceQuotasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServiceQuotasResponse, _>()
     }
 
@@ -2568,7 +2568,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, ListServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
     }
 
@@ -2595,7 +2595,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutServiceQuotaIncreaseRequestIntoTemplateResponse, _>()
     }
@@ -2618,7 +2618,7 @@ impl ServiceQuotas for ServiceQuotasClient {
             .sign_and_dispatch(request, RequestServiceQuotaIncreaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RequestServiceQuotaIncreaseResponse, _>()
     }
@@ -42,7 +42,7 @@ impl ServiceCatalogClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -8033,7 +8033,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, AcceptPortfolioShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AcceptPortfolioShareOutput, _>()
     }
 
@@ -8055,7 +8055,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, AssociateBudgetWithResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateBudgetWithResourceOutput, _>()
     }
@@ -8080,7 +8080,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, AssociatePrincipalWithPortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociatePrincipalWithPortfolioOutput, _>()
     }
@@ -8103,7 +8103,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, AssociateProductWithPortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateProductWithPortfolioOutput, _>()
     }
@@ -8131,7 +8131,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateServiceActionWithProvisioningArtifactOutput, _>()
     }
@@ -8156,7 +8156,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, AssociateTagOptionWithResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateTagOptionWithResourceOutput, _>()
     }
@@ -8184,7 +8184,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchAssociateServiceActionWithProvisioningArtifactOutput, _>()
     }
@@ -8212,7 +8212,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<BatchDisassociateServiceActionFromProvisioningArtifactOutput, _>()
     }
@@ -8231,7 +8231,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CopyProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CopyProductOutput, _>()
     }
 
@@ -8252,7 +8252,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateConstraintError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateConstraintOutput, _>()
     }
 
@@ -8273,7 +8273,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreatePortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePortfolioOutput, _>()
     }
 
@@ -8294,7 +8294,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreatePortfolioShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePortfolioShareOutput, _>()
     }
 
@@ -8312,7 +8312,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProductOutput, _>()
     }
 
@@ -8334,7 +8334,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateProvisionedProductPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateProvisionedProductPlanOutput, _>()
     }
@@ -8357,7 +8357,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateProvisioningArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateProvisioningArtifactOutput, _>()
     }
@@ -8379,7 +8379,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateServiceActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateServiceActionOutput, _>()
     }
 
@@ -8400,7 +8400,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, CreateTagOptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTagOptionOutput, _>()
     }
 
@@ -8421,7 +8421,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteConstraintError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteConstraintOutput, _>()
     }
 
@@ -8442,7 +8442,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeletePortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeletePortfolioOutput, _>()
     }
 
@@ -8463,7 +8463,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeletePortfolioShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeletePortfolioShareOutput, _>()
     }
 
@@ -8481,7 +8481,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProductOutput, _>()
     }
 
@@ -8503,7 +8503,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteProvisionedProductPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteProvisionedProductPlanOutput, _>()
     }
@@ -8526,7 +8526,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteProvisioningArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteProvisioningArtifactOutput, _>()
     }
@@ -8548,7 +8548,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteServiceActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteServiceActionOutput, _>()
     }
 
@@ -8569,7 +8569,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DeleteTagOptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagOptionOutput, _>()
     }
 
@@ -8590,7 +8590,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeConstraintError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeConstraintOutput, _>()
     }
 
@@ -8611,7 +8611,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeCopyProductStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCopyProductStatusOutput, _>()
     }
@@ -8633,7 +8633,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribePortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePortfolioOutput, _>()
     }
 
@@ -8655,7 +8655,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribePortfolioShareStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePortfolioShareStatusOutput, _>()
     }
@@ -8677,7 +8677,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProductOutput, _>()
     }
 
@@ -8698,7 +8698,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProductAsAdminError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProductAsAdminOutput, _>()
     }
@@ -8720,7 +8720,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProductViewError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProductViewOutput, _>()
     }
 
@@ -8742,7 +8742,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProvisionedProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProvisionedProductOutput, _>()
     }
@@ -8767,7 +8767,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProvisionedProductPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProvisionedProductPlanOutput, _>()
     }
@@ -8790,7 +8790,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProvisioningArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProvisioningArtifactOutput, _>()
     }
@@ -8815,7 +8815,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeProvisioningParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeProvisioningParametersOutput, _>()
     }
@@ -8834,7 +8834,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeRecordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeRecordOutput, _>()
     }
 
@@ -8855,7 +8855,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeServiceActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServiceActionOutput, _>()
     }
 
@@ -8882,7 +8882,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeServiceActionExecutionParametersOutput, _>()
     }
@@ -8904,7 +8904,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DescribeTagOptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagOptionOutput, _>()
     }
 
@@ -8924,7 +8924,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DisableAWSOrganizationsAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisableAWSOrganizationsAccessOutput, _>()
     }
@@ -8949,7 +8949,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, DisassociateBudgetFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateBudgetFromResourceOutput, _>()
     }
@@ -8977,7 +8977,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociatePrincipalFromPortfolioOutput, _>()
     }
@@ -9005,7 +9005,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateProductFromPortfolioOutput, _>()
     }
@@ -9033,7 +9033,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateServiceActionFromProvisioningArtifactOutput, _>()
     }
@@ -9061,7 +9061,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateTagOptionFromResourceOutput, _>()
     }
@@ -9082,7 +9082,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, EnableAWSOrganizationsAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<EnableAWSOrganizationsAccessOutput, _>()
     }
@@ -9105,7 +9105,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ExecuteProvisionedProductPlanError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExecuteProvisionedProductPlanOutput, _>()
     }
@@ -9133,7 +9133,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ExecuteProvisionedProductServiceActionOutput, _>()
     }
@@ -9156,7 +9156,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, GetAWSOrganizationsAccessStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAWSOrganizationsAccessStatusOutput, _>()
     }
@@ -9179,7 +9179,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListAcceptedPortfolioSharesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAcceptedPortfolioSharesOutput, _>()
     }
@@ -9201,7 +9201,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListBudgetsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListBudgetsForResourceOutput, _>()
     }
@@ -9224,7 +9224,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListConstraintsForPortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListConstraintsForPortfolioOutput, _>()
     }
@@ -9246,7 +9246,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListLaunchPathsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLaunchPathsOutput, _>()
     }
 
@@ -9270,7 +9270,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListOrganizationPortfolioAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListOrganizationPortfolioAccessOutput, _>()
     }
@@ -9292,7 +9292,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListPortfolioAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPortfolioAccessOutput, _>()
     }
 
@@ -9310,7 +9310,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListPortfoliosError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListPortfoliosOutput, _>()
     }
 
@@ -9331,7 +9331,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListPortfoliosForProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPortfoliosForProductOutput, _>()
     }
@@ -9354,7 +9354,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListPrincipalsForPortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListPrincipalsForPortfolioOutput, _>()
     }
@@ -9377,7 +9377,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListProvisionedProductPlansError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListProvisionedProductPlansOutput, _>()
     }
@@ -9399,7 +9399,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListProvisioningArtifactsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListProvisioningArtifactsOutput, _>()
     }
@@ -9427,7 +9427,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListProvisioningArtifactsForServiceActionOutput, _>()
     }
@@ -9449,7 +9449,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListRecordHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRecordHistoryOutput, _>()
     }
 
@@ -9470,7 +9470,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListResourcesForTagOptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResourcesForTagOptionOutput, _>()
     }
@@ -9492,7 +9492,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListServiceActionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServiceActionsOutput, _>()
     }
 
@@ -9519,7 +9519,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListServiceActionsForProvisioningArtifactOutput, _>()
     }
@@ -9547,7 +9547,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListStackInstancesForProvisionedProductOutput, _>()
     }
@@ -9566,7 +9566,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ListTagOptionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagOptionsOutput, _>()
     }
 
@@ -9587,7 +9587,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ProvisionProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ProvisionProductOutput, _>()
     }
 
@@ -9608,7 +9608,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, RejectPortfolioShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RejectPortfolioShareOutput, _>()
     }
 
@@ -9629,7 +9629,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, ScanProvisionedProductsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ScanProvisionedProductsOutput, _>()
     }
@@ -9648,7 +9648,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, SearchProductsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchProductsOutput, _>()
     }
 
@@ -9669,7 +9669,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, SearchProductsAsAdminError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SearchProductsAsAdminOutput, _>()
     }
 
@@ -9690,7 +9690,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, SearchProvisionedProductsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SearchProvisionedProductsOutput, _>()
     }
@@ -9713,7 +9713,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, TerminateProvisionedProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<TerminateProvisionedProductOutput, _>()
     }
@@ -9735,7 +9735,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateConstraintError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateConstraintOutput, _>()
     }
 
@@ -9756,7 +9756,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdatePortfolioError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePortfolioOutput, _>()
     }
 
@@ -9774,7 +9774,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateProductOutput, _>()
     }
 
@@ -9795,7 +9795,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateProvisionedProductError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateProvisionedProductOutput, _>()
     }
@@ -9823,7 +9823,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateProvisionedProductPropertiesOutput, _>()
     }
@@ -9846,7 +9846,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateProvisioningArtifactError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateProvisioningArtifactOutput, _>()
     }
@@ -9868,7 +9868,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateServiceActionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceActionOutput, _>()
     }
 
@@ -9889,7 +9889,7 @@ impl ServiceCatalog for ServiceCatalogClient {
             .sign_and_dispatch(request, UpdateTagOptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTagOptionOutput, _>()
     }
 }
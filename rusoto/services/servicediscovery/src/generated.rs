@@ -42,7 +42,7 @@ impl ServiceDiscoveryClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2288,7 +2288,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, CreateHttpNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateHttpNamespaceResponse, _>()
     }
 
@@ -2310,7 +2310,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, CreatePrivateDnsNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePrivateDnsNamespaceResponse, _>()
     }
@@ -2332,7 +2332,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, CreatePublicDnsNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreatePublicDnsNamespaceResponse, _>()
     }
@@ -2351,7 +2351,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, CreateServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateServiceResponse, _>()
     }
 
@@ -2372,7 +2372,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, DeleteNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamespaceResponse, _>()
     }
 
@@ -2390,7 +2390,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, DeleteServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteServiceResponse, _>()
     }
 
@@ -2411,7 +2411,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, DeregisterInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeregisterInstanceResponse, _>()
     }
 
@@ -2432,7 +2432,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, DiscoverInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DiscoverInstancesResponse, _>()
     }
 
@@ -2450,7 +2450,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, GetInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceResponse, _>()
     }
 
@@ -2471,7 +2471,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, GetInstancesHealthStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetInstancesHealthStatusResponse, _>()
     }
@@ -2490,7 +2490,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, GetNamespaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetNamespaceResponse, _>()
     }
 
@@ -2508,7 +2508,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, GetOperationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOperationResponse, _>()
     }
 
@@ -2526,7 +2526,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, GetServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetServiceResponse, _>()
     }
 
@@ -2544,7 +2544,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, ListInstancesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInstancesResponse, _>()
     }
 
@@ -2562,7 +2562,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, ListNamespacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListNamespacesResponse, _>()
     }
 
@@ -2580,7 +2580,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, ListOperationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListOperationsResponse, _>()
     }
 
@@ -2598,7 +2598,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, ListServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
     }
 
@@ -2619,7 +2619,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2640,7 +2640,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, RegisterInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterInstanceResponse, _>()
     }
 
@@ -2658,7 +2658,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -2676,7 +2676,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -2717,7 +2717,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
             .sign_and_dispatch(request, UpdateServiceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
     }
 }
@@ -52,7 +52,7 @@ impl SesClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7085,11 +7085,11 @@ impl WorkmailActionSerializer {
 #[derive(Debug, PartialEq)]
 pub enum CloneReceiptRuleSetError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl CloneReceiptRuleSetError {
@@ -7102,17 +7102,17 @@ impl CloneReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CloneReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CloneReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(CloneReceiptRuleSetError::RuleSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7129,6 +7129,15 @@ impl CloneReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CloneReceiptRuleSetError::AlreadyExists(ref cause) => cause.request_id(),
+            CloneReceiptRuleSetError::LimitExceeded(ref cause) => cause.request_id(),
+            CloneReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CloneReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -7145,11 +7154,11 @@ impl Error for CloneReceiptRuleSetError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetError {
     /// <p>Indicates that the configuration set could not be created because of a naming conflict.</p>
-    ConfigurationSetAlreadyExists(String),
+    ConfigurationSetAlreadyExists(XmlErrorMessage),
     /// <p>Indicates that the configuration set is invalid. See the error message for details.</p>
-    InvalidConfigurationSet(String),
+    InvalidConfigurationSet(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateConfigurationSetError {
@@ -7163,20 +7172,20 @@ impl CreateConfigurationSetError {
                     "ConfigurationSetAlreadyExists" => {
                         return RusotoError::Service(
                             CreateConfigurationSetError::ConfigurationSetAlreadyExists(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidConfigurationSet" => {
                         return RusotoError::Service(
                             CreateConfigurationSetError::InvalidConfigurationSet(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7193,6 +7202,17 @@ impl CreateConfigurationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateConfigurationSetError::ConfigurationSetAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationSetError::InvalidConfigurationSet(ref cause) => cause.request_id(),
+            CreateConfigurationSetError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateConfigurationSetError {
     #[allow(unused_variables)]
@@ -7213,17 +7233,17 @@ impl Error for CreateConfigurationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the event destination could not be created because of a naming conflict.</p>
-    EventDestinationAlreadyExists(String),
+    EventDestinationAlreadyExists(XmlErrorMessage),
     /// <p>Indicates that the Amazon CloudWatch destination is invalid. See the error message for details.</p>
-    InvalidCloudWatchDestination(String),
+    InvalidCloudWatchDestination(XmlErrorMessage),
     /// <p>Indicates that the Amazon Kinesis Firehose destination is invalid. See the error message for details.</p>
-    InvalidFirehoseDestination(String),
+    InvalidFirehoseDestination(XmlErrorMessage),
     /// <p>Indicates that the Amazon Simple Notification Service (Amazon SNS) destination is invalid. See the error message for details.</p>
-    InvalidSNSDestination(String),
+    InvalidSNSDestination(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateConfigurationSetEventDestinationError {
@@ -7238,37 +7258,37 @@ impl CreateConfigurationSetEventDestinationError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "EventDestinationAlreadyExists" => return RusotoError::Service(
                         CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidCloudWatchDestination" => return RusotoError::Service(
                         CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidFirehoseDestination" => {
                         return RusotoError::Service(
                             CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSNSDestination" => {
                         return RusotoError::Service(
                             CreateConfigurationSetEventDestinationError::InvalidSNSDestination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(
                             CreateConfigurationSetEventDestinationError::LimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7286,6 +7306,30 @@ impl CreateConfigurationSetEventDestinationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+            CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(
+                ref cause,
+            ) => cause.request_id(),
+            CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
+                ref cause,
+            ) => cause.request_id(),
+            CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationSetEventDestinationError::InvalidSNSDestination(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationSetEventDestinationError::LimitExceeded(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateConfigurationSetEventDestinationError {
     #[allow(unused_variables)]
@@ -7317,11 +7361,11 @@ impl Error for CreateConfigurationSetEventDestinationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p><p>Indicates that the custom domain to be used for open and click tracking redirects is invalid. This error appears most often in the following situations:</p> <ul> <li> <p>When the tracking domain you specified is not verified in Amazon SES.</p> </li> <li> <p>When the tracking domain you specified is not a valid domain or subdomain.</p> </li> </ul></p>
-    InvalidTrackingOptions(String),
+    InvalidTrackingOptions(XmlErrorMessage),
     /// <p>Indicates that the configuration set you specified already contains a TrackingOptions object.</p>
-    TrackingOptionsAlreadyExists(String),
+    TrackingOptionsAlreadyExists(XmlErrorMessage),
 }
 
 impl CreateConfigurationSetTrackingOptionsError {
@@ -7336,19 +7380,19 @@ impl CreateConfigurationSetTrackingOptionsError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidTrackingOptions" => {
                         return RusotoError::Service(
                             CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TrackingOptionsAlreadyExistsException" => return RusotoError::Service(
                         CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -7365,6 +7409,21 @@ impl CreateConfigurationSetTrackingOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(ref cause) => {
+                cause.request_id()
+            }
+            CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for CreateConfigurationSetTrackingOptionsError {
     #[allow(unused_variables)]
@@ -7387,13 +7446,13 @@ impl Error for CreateConfigurationSetTrackingOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomVerificationEmailTemplateError {
     /// <p>Indicates that custom verification email template provided content is invalid.</p>
-    CustomVerificationEmailInvalidContent(String),
+    CustomVerificationEmailInvalidContent(XmlErrorMessage),
     /// <p>Indicates that a custom verification email template with the name you specified already exists.</p>
-    CustomVerificationEmailTemplateAlreadyExists(String),
+    CustomVerificationEmailTemplateAlreadyExists(XmlErrorMessage),
     /// <p>Indicates that the sender address specified for a custom verification email is not verified, and is therefore not eligible to send the custom verification email. </p>
-    FromEmailAddressNotVerified(String),
+    FromEmailAddressNotVerified(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateCustomVerificationEmailTemplateError {
@@ -7406,7 +7465,7 @@ impl CreateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),"LimitExceeded" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(XmlErrorMessage::from_response(parsed_error.message, &res))),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(XmlErrorMessage::from_response(parsed_error.message, &res))),"FromEmailAddressNotVerified" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(XmlErrorMessage::from_response(parsed_error.message, &res))),"LimitExceeded" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::LimitExceeded(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -7420,6 +7479,16 @@ impl CreateCustomVerificationEmailTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(ref cause) => cause.request_id(),
+            CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(ref cause) => cause.request_id(),
+            CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref cause) => cause.request_id(),
+            CreateCustomVerificationEmailTemplateError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
@@ -7437,9 +7506,9 @@ impl Error for CreateCustomVerificationEmailTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptFilterError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateReceiptFilterError {
@@ -7452,12 +7521,12 @@ impl CreateReceiptFilterError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CreateReceiptFilterError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateReceiptFilterError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7474,6 +7543,14 @@ impl CreateReceiptFilterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateReceiptFilterError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateReceiptFilterError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateReceiptFilterError {
     #[allow(unused_variables)]
@@ -7489,19 +7566,19 @@ impl Error for CreateReceiptFilterError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptRuleError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>Indicates that the provided AWS Lambda function is invalid, or that Amazon SES could not execute the provided function, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidLambdaFunction(String),
+    InvalidLambdaFunction(XmlErrorMessage),
     /// <p>Indicates that the provided Amazon S3 bucket or AWS KMS encryption key is invalid, or that Amazon SES could not publish to the bucket, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidS3Configuration(String),
+    InvalidS3Configuration(XmlErrorMessage),
     /// <p>Indicates that the provided Amazon SNS topic is invalid, or that Amazon SES could not publish to the topic, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidSnsTopic(String),
+    InvalidSnsTopic(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule does not exist.</p>
-    RuleDoesNotExist(String),
+    RuleDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl CreateReceiptRuleError {
@@ -7514,37 +7591,39 @@ impl CreateReceiptRuleError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CreateReceiptRuleError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidLambdaFunction" => {
                         return RusotoError::Service(CreateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidS3Configuration" => {
                         return RusotoError::Service(
-                            CreateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                            CreateReceiptRuleError::InvalidS3Configuration(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSnsTopic" => {
                         return RusotoError::Service(CreateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleDoesNotExist" => {
                         return RusotoError::Service(CreateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(CreateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7561,6 +7640,19 @@ impl CreateReceiptRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateReceiptRuleError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::InvalidLambdaFunction(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::InvalidS3Configuration(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::InvalidSnsTopic(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::LimitExceeded(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::RuleDoesNotExist(ref cause) => cause.request_id(),
+            CreateReceiptRuleError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateReceiptRuleError {
     #[allow(unused_variables)]
@@ -7581,9 +7673,9 @@ impl Error for CreateReceiptRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptRuleSetError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateReceiptRuleSetError {
@@ -7596,12 +7688,12 @@ impl CreateReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CreateReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7618,6 +7710,14 @@ impl CreateReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateReceiptRuleSetError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateReceiptRuleSetError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -7633,11 +7733,11 @@ impl Error for CreateReceiptRuleSetError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTemplateError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
-    AlreadyExists(String),
+    AlreadyExists(XmlErrorMessage),
     /// <p>Indicates that the template that you specified could not be rendered. This issue may occur when a template refers to a partial that does not exist.</p>
-    InvalidTemplate(String),
+    InvalidTemplate(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
 }
 
 impl CreateTemplateError {
@@ -7650,17 +7750,17 @@ impl CreateTemplateError {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
                         return RusotoError::Service(CreateTemplateError::AlreadyExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidTemplate" => {
                         return RusotoError::Service(CreateTemplateError::InvalidTemplate(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(CreateTemplateError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -7677,6 +7777,15 @@ impl CreateTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTemplateError::AlreadyExists(ref cause) => cause.request_id(),
+            CreateTemplateError::InvalidTemplate(ref cause) => cause.request_id(),
+            CreateTemplateError::LimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTemplateError {
     #[allow(unused_variables)]
@@ -7693,7 +7802,7 @@ impl Error for CreateTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
 }
 
 impl DeleteConfigurationSetError {
@@ -7707,7 +7816,7 @@ impl DeleteConfigurationSetError {
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             DeleteConfigurationSetError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7725,6 +7834,15 @@ impl DeleteConfigurationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteConfigurationSetError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteConfigurationSetError {
     #[allow(unused_variables)]
@@ -7741,9 +7859,9 @@ impl Error for DeleteConfigurationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the event destination does not exist.</p>
-    EventDestinationDoesNotExist(String),
+    EventDestinationDoesNotExist(XmlErrorMessage),
 }
 
 impl DeleteConfigurationSetEventDestinationError {
@@ -7758,12 +7876,12 @@ impl DeleteConfigurationSetEventDestinationError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "EventDestinationDoesNotExist" => return RusotoError::Service(
                         DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     _ => {}
@@ -7780,6 +7898,18 @@ impl DeleteConfigurationSetEventDestinationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+            DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteConfigurationSetEventDestinationError {
     #[allow(unused_variables)]
@@ -7799,9 +7929,9 @@ impl Error for DeleteConfigurationSetEventDestinationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the TrackingOptions object you specified does not exist.</p>
-    TrackingOptionsDoesNotExist(String),
+    TrackingOptionsDoesNotExist(XmlErrorMessage),
 }
 
 impl DeleteConfigurationSetTrackingOptionsError {
@@ -7816,13 +7946,13 @@ impl DeleteConfigurationSetTrackingOptionsError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "TrackingOptionsDoesNotExistException" => {
                         return RusotoError::Service(
                             DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -7840,6 +7970,18 @@ impl DeleteConfigurationSetTrackingOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DeleteConfigurationSetTrackingOptionsError {
     #[allow(unused_variables)]
@@ -7883,6 +8025,11 @@ impl DeleteCustomVerificationEmailTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
@@ -7917,6 +8064,11 @@ impl DeleteIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteIdentityError {
     #[allow(unused_variables)]
@@ -7951,6 +8103,11 @@ impl DeleteIdentityPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteIdentityPolicyError {
     #[allow(unused_variables)]
@@ -7985,6 +8142,11 @@ impl DeleteReceiptFilterError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteReceiptFilterError {
     #[allow(unused_variables)]
@@ -7997,7 +8159,7 @@ impl Error for DeleteReceiptFilterError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteReceiptRuleError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl DeleteReceiptRuleError {
@@ -8010,7 +8172,7 @@ impl DeleteReceiptRuleError {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(DeleteReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8027,6 +8189,13 @@ impl DeleteReceiptRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteReceiptRuleError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteReceiptRuleError {
     #[allow(unused_variables)]
@@ -8041,7 +8210,7 @@ impl Error for DeleteReceiptRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteReceiptRuleSetError {
     /// <p>Indicates that the delete operation could not be completed.</p>
-    CannotDelete(String),
+    CannotDelete(XmlErrorMessage),
 }
 
 impl DeleteReceiptRuleSetError {
@@ -8054,7 +8223,7 @@ impl DeleteReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "CannotDelete" => {
                         return RusotoError::Service(DeleteReceiptRuleSetError::CannotDelete(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8071,6 +8240,13 @@ impl DeleteReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteReceiptRuleSetError::CannotDelete(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -8107,6 +8283,11 @@ impl DeleteTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteTemplateError {
     #[allow(unused_variables)]
@@ -8143,6 +8324,11 @@ impl DeleteVerifiedEmailAddressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteVerifiedEmailAddressError {
     #[allow(unused_variables)]
@@ -8179,6 +8365,11 @@ impl DescribeActiveReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DescribeActiveReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -8191,7 +8382,7 @@ impl Error for DescribeActiveReceiptRuleSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationSetError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
 }
 
 impl DescribeConfigurationSetError {
@@ -8205,7 +8396,7 @@ impl DescribeConfigurationSetError {
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             DescribeConfigurationSetError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -8223,6 +8414,15 @@ impl DescribeConfigurationSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeConfigurationSetError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DescribeConfigurationSetError {
     #[allow(unused_variables)]
@@ -8239,9 +8439,9 @@ impl Error for DescribeConfigurationSetError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReceiptRuleError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
-    RuleDoesNotExist(String),
+    RuleDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl DescribeReceiptRuleError {
@@ -8254,12 +8454,12 @@ impl DescribeReceiptRuleError {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
                         return RusotoError::Service(DescribeReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(DescribeReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8276,6 +8476,14 @@ impl DescribeReceiptRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReceiptRuleError::RuleDoesNotExist(ref cause) => cause.request_id(),
+            DescribeReceiptRuleError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeReceiptRuleError {
     #[allow(unused_variables)]
@@ -8291,7 +8499,7 @@ impl Error for DescribeReceiptRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum DescribeReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl DescribeReceiptRuleSetError {
@@ -8304,7 +8512,9 @@ impl DescribeReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(
-                            DescribeReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            DescribeReceiptRuleSetError::RuleSetDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -8321,6 +8531,13 @@ impl DescribeReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DescribeReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DescribeReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -8357,6 +8574,11 @@ impl GetAccountSendingEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetAccountSendingEnabledError {
     #[allow(unused_variables)]
@@ -8369,7 +8591,7 @@ impl Error for GetAccountSendingEnabledError {}
 #[derive(Debug, PartialEq)]
 pub enum GetCustomVerificationEmailTemplateError {
     /// <p>Indicates that a custom verification email template with the name you specified does not exist.</p>
-    CustomVerificationEmailTemplateDoesNotExist(String),
+    CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl GetCustomVerificationEmailTemplateError {
@@ -8382,7 +8604,7 @@ impl GetCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -8396,6 +8618,13 @@ impl GetCustomVerificationEmailTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
@@ -8432,6 +8661,11 @@ impl GetIdentityDkimAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetIdentityDkimAttributesError {
     #[allow(unused_variables)]
@@ -8468,6 +8702,11 @@ impl GetIdentityMailFromDomainAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetIdentityMailFromDomainAttributesError {
     #[allow(unused_variables)]
@@ -8504,6 +8743,11 @@ impl GetIdentityNotificationAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetIdentityNotificationAttributesError {
     #[allow(unused_variables)]
@@ -8538,6 +8782,11 @@ impl GetIdentityPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetIdentityPoliciesError {
     #[allow(unused_variables)]
@@ -8574,6 +8823,11 @@ impl GetIdentityVerificationAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetIdentityVerificationAttributesError {
     #[allow(unused_variables)]
@@ -8608,6 +8862,11 @@ impl GetSendQuotaError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetSendQuotaError {
     #[allow(unused_variables)]
@@ -8642,6 +8901,11 @@ impl GetSendStatisticsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetSendStatisticsError {
     #[allow(unused_variables)]
@@ -8654,7 +8918,7 @@ impl Error for GetSendStatisticsError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateError {
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
-    TemplateDoesNotExist(String),
+    TemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl GetTemplateError {
@@ -8667,7 +8931,7 @@ impl GetTemplateError {
                 match &parsed_error.code[..] {
                     "TemplateDoesNotExist" => {
                         return RusotoError::Service(GetTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -8684,6 +8948,13 @@ impl GetTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTemplateError::TemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetTemplateError {
     #[allow(unused_variables)]
@@ -8720,6 +8991,11 @@ impl ListConfigurationSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListConfigurationSetsError {
     #[allow(unused_variables)]
@@ -8756,6 +9032,11 @@ impl ListCustomVerificationEmailTemplatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListCustomVerificationEmailTemplatesError {
     #[allow(unused_variables)]
@@ -8790,6 +9071,11 @@ impl ListIdentitiesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListIdentitiesError {
     #[allow(unused_variables)]
@@ -8824,6 +9110,11 @@ impl ListIdentityPoliciesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListIdentityPoliciesError {
     #[allow(unused_variables)]
@@ -8858,6 +9149,11 @@ impl ListReceiptFiltersError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListReceiptFiltersError {
     #[allow(unused_variables)]
@@ -8892,6 +9188,11 @@ impl ListReceiptRuleSetsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListReceiptRuleSetsError {
     #[allow(unused_variables)]
@@ -8926,6 +9227,11 @@ impl ListTemplatesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListTemplatesError {
     #[allow(unused_variables)]
@@ -8962,6 +9268,11 @@ impl ListVerifiedEmailAddressesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListVerifiedEmailAddressesError {
     #[allow(unused_variables)]
@@ -8974,9 +9285,9 @@ impl Error for ListVerifiedEmailAddressesError {}
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetDeliveryOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that provided delivery option is invalid.</p>
-    InvalidDeliveryOptions(String),
+    InvalidDeliveryOptions(XmlErrorMessage),
 }
 
 impl PutConfigurationSetDeliveryOptionsError {
@@ -8992,14 +9303,14 @@ impl PutConfigurationSetDeliveryOptionsError {
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidDeliveryOptions" => {
                         return RusotoError::Service(
                             PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -9017,6 +9328,18 @@ impl PutConfigurationSetDeliveryOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
     #[allow(unused_variables)]
@@ -9036,7 +9359,7 @@ impl Error for PutConfigurationSetDeliveryOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum PutIdentityPolicyError {
     /// <p>Indicates that the provided policy is invalid. Check the error stack for more information about what caused the error.</p>
-    InvalidPolicy(String),
+    InvalidPolicy(XmlErrorMessage),
 }
 
 impl PutIdentityPolicyError {
@@ -9049,7 +9372,7 @@ impl PutIdentityPolicyError {
                 match &parsed_error.code[..] {
                     "InvalidPolicy" => {
                         return RusotoError::Service(PutIdentityPolicyError::InvalidPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9066,6 +9389,13 @@ impl PutIdentityPolicyError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PutIdentityPolicyError::InvalidPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PutIdentityPolicyError {
     #[allow(unused_variables)]
@@ -9080,9 +9410,9 @@ impl Error for PutIdentityPolicyError {}
 #[derive(Debug, PartialEq)]
 pub enum ReorderReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
-    RuleDoesNotExist(String),
+    RuleDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl ReorderReceiptRuleSetError {
@@ -9095,12 +9425,14 @@ impl ReorderReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
                         return RusotoError::Service(ReorderReceiptRuleSetError::RuleDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(
-                            ReorderReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            ReorderReceiptRuleSetError::RuleSetDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9117,6 +9449,14 @@ impl ReorderReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ReorderReceiptRuleSetError::RuleDoesNotExist(ref cause) => cause.request_id(),
+            ReorderReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ReorderReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -9132,7 +9472,7 @@ impl Error for ReorderReceiptRuleSetError {}
 #[derive(Debug, PartialEq)]
 pub enum SendBounceError {
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
 }
 
 impl SendBounceError {
@@ -9145,7 +9485,7 @@ impl SendBounceError {
                 match &parsed_error.code[..] {
                     "MessageRejected" => {
                         return RusotoError::Service(SendBounceError::MessageRejected(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9162,6 +9502,13 @@ impl SendBounceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendBounceError::MessageRejected(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendBounceError {
     #[allow(unused_variables)]
@@ -9176,17 +9523,17 @@ impl Error for SendBounceError {}
 #[derive(Debug, PartialEq)]
 pub enum SendBulkTemplatedEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
-    AccountSendingPaused(String),
+    AccountSendingPaused(XmlErrorMessage),
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that email sending is disabled for the configuration set.</p> <p>You can enable or disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
-    ConfigurationSetSendingPaused(String),
+    ConfigurationSetSendingPaused(XmlErrorMessage),
     /// <p> Indicates that the message could not be sent because Amazon SES could not read the MX record required to use the specified MAIL FROM domain. For information about editing the custom MAIL FROM domain settings for an identity, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-edit.html">Amazon SES Developer Guide</a>.</p>
-    MailFromDomainNotVerified(String),
+    MailFromDomainNotVerified(XmlErrorMessage),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
-    TemplateDoesNotExist(String),
+    TemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl SendBulkTemplatedEmailError {
@@ -9199,38 +9546,42 @@ impl SendBulkTemplatedEmailError {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
                         return RusotoError::Service(
-                            SendBulkTemplatedEmailError::AccountSendingPaused(parsed_error.message),
+                            SendBulkTemplatedEmailError::AccountSendingPaused(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
                         return RusotoError::Service(
                             SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
                         return RusotoError::Service(
                             SendBulkTemplatedEmailError::MailFromDomainNotVerified(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MessageRejected" => {
                         return RusotoError::Service(SendBulkTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TemplateDoesNotExist" => {
                         return RusotoError::Service(
-                            SendBulkTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
+                            SendBulkTemplatedEmailError::TemplateDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9247,6 +9598,22 @@ impl SendBulkTemplatedEmailError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendBulkTemplatedEmailError::AccountSendingPaused(ref cause) => cause.request_id(),
+            SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(ref cause) => {
+                cause.request_id()
+            }
+            SendBulkTemplatedEmailError::MailFromDomainNotVerified(ref cause) => cause.request_id(),
+            SendBulkTemplatedEmailError::MessageRejected(ref cause) => cause.request_id(),
+            SendBulkTemplatedEmailError::TemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendBulkTemplatedEmailError {
     #[allow(unused_variables)]
@@ -9272,15 +9639,15 @@ impl Error for SendBulkTemplatedEmailError {}
 #[derive(Debug, PartialEq)]
 pub enum SendCustomVerificationEmailError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that a custom verification email template with the name you specified does not exist.</p>
-    CustomVerificationEmailTemplateDoesNotExist(String),
+    CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the sender address specified for a custom verification email is not verified, and is therefore not eligible to send the custom verification email. </p>
-    FromEmailAddressNotVerified(String),
+    FromEmailAddressNotVerified(XmlErrorMessage),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
     /// <p>Indicates that the account has not been granted production access.</p>
-    ProductionAccessNotGranted(String),
+    ProductionAccessNotGranted(XmlErrorMessage),
 }
 
 impl SendCustomVerificationEmailError {
@@ -9293,7 +9660,7 @@ impl SendCustomVerificationEmailError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message)),"MessageRejected" => return RusotoError::Service(SendCustomVerificationEmailError::MessageRejected(parsed_error.message)),"ProductionAccessNotGranted" => return RusotoError::Service(SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),"FromEmailAddressNotVerified" => return RusotoError::Service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(XmlErrorMessage::from_response(parsed_error.message, &res))),"MessageRejected" => return RusotoError::Service(SendCustomVerificationEmailError::MessageRejected(XmlErrorMessage::from_response(parsed_error.message, &res))),"ProductionAccessNotGranted" => return RusotoError::Service(SendCustomVerificationEmailError::ProductionAccessNotGranted(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -9307,6 +9674,25 @@ impl SendCustomVerificationEmailError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+            SendCustomVerificationEmailError::FromEmailAddressNotVerified(ref cause) => {
+                cause.request_id()
+            }
+            SendCustomVerificationEmailError::MessageRejected(ref cause) => cause.request_id(),
+            SendCustomVerificationEmailError::ProductionAccessNotGranted(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for SendCustomVerificationEmailError {
     #[allow(unused_variables)]
@@ -9333,15 +9719,15 @@ impl Error for SendCustomVerificationEmailError {}
 #[derive(Debug, PartialEq)]
 pub enum SendEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
-    AccountSendingPaused(String),
+    AccountSendingPaused(XmlErrorMessage),
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that email sending is disabled for the configuration set.</p> <p>You can enable or disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
-    ConfigurationSetSendingPaused(String),
+    ConfigurationSetSendingPaused(XmlErrorMessage),
     /// <p> Indicates that the message could not be sent because Amazon SES could not read the MX record required to use the specified MAIL FROM domain. For information about editing the custom MAIL FROM domain settings for an identity, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-edit.html">Amazon SES Developer Guide</a>.</p>
-    MailFromDomainNotVerified(String),
+    MailFromDomainNotVerified(XmlErrorMessage),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
 }
 
 impl SendEmailError {
@@ -9354,27 +9740,27 @@ impl SendEmailError {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
                         return RusotoError::Service(SendEmailError::AccountSendingPaused(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(SendEmailError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConfigurationSetSendingPausedException" => {
                         return RusotoError::Service(SendEmailError::ConfigurationSetSendingPaused(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MailFromDomainNotVerifiedException" => {
                         return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MessageRejected" => {
                         return RusotoError::Service(SendEmailError::MessageRejected(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9391,6 +9777,17 @@ impl SendEmailError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendEmailError::AccountSendingPaused(ref cause) => cause.request_id(),
+            SendEmailError::ConfigurationSetDoesNotExist(ref cause) => cause.request_id(),
+            SendEmailError::ConfigurationSetSendingPaused(ref cause) => cause.request_id(),
+            SendEmailError::MailFromDomainNotVerified(ref cause) => cause.request_id(),
+            SendEmailError::MessageRejected(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendEmailError {
     #[allow(unused_variables)]
@@ -9409,15 +9806,15 @@ impl Error for SendEmailError {}
 #[derive(Debug, PartialEq)]
 pub enum SendRawEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
-    AccountSendingPaused(String),
+    AccountSendingPaused(XmlErrorMessage),
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that email sending is disabled for the configuration set.</p> <p>You can enable or disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
-    ConfigurationSetSendingPaused(String),
+    ConfigurationSetSendingPaused(XmlErrorMessage),
     /// <p> Indicates that the message could not be sent because Amazon SES could not read the MX record required to use the specified MAIL FROM domain. For information about editing the custom MAIL FROM domain settings for an identity, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-edit.html">Amazon SES Developer Guide</a>.</p>
-    MailFromDomainNotVerified(String),
+    MailFromDomainNotVerified(XmlErrorMessage),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
 }
 
 impl SendRawEmailError {
@@ -9430,27 +9827,31 @@ impl SendRawEmailError {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
                         return RusotoError::Service(SendRawEmailError::AccountSendingPaused(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
-                            SendRawEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
+                            SendRawEmailError::ConfigurationSetDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
                         return RusotoError::Service(
-                            SendRawEmailError::ConfigurationSetSendingPaused(parsed_error.message),
+                            SendRawEmailError::ConfigurationSetSendingPaused(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
                         return RusotoError::Service(SendRawEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MessageRejected" => {
                         return RusotoError::Service(SendRawEmailError::MessageRejected(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9467,6 +9868,17 @@ impl SendRawEmailError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendRawEmailError::AccountSendingPaused(ref cause) => cause.request_id(),
+            SendRawEmailError::ConfigurationSetDoesNotExist(ref cause) => cause.request_id(),
+            SendRawEmailError::ConfigurationSetSendingPaused(ref cause) => cause.request_id(),
+            SendRawEmailError::MailFromDomainNotVerified(ref cause) => cause.request_id(),
+            SendRawEmailError::MessageRejected(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendRawEmailError {
     #[allow(unused_variables)]
@@ -9485,17 +9897,17 @@ impl Error for SendRawEmailError {}
 #[derive(Debug, PartialEq)]
 pub enum SendTemplatedEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
-    AccountSendingPaused(String),
+    AccountSendingPaused(XmlErrorMessage),
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that email sending is disabled for the configuration set.</p> <p>You can enable or disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
-    ConfigurationSetSendingPaused(String),
+    ConfigurationSetSendingPaused(XmlErrorMessage),
     /// <p> Indicates that the message could not be sent because Amazon SES could not read the MX record required to use the specified MAIL FROM domain. For information about editing the custom MAIL FROM domain settings for an identity, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-edit.html">Amazon SES Developer Guide</a>.</p>
-    MailFromDomainNotVerified(String),
+    MailFromDomainNotVerified(XmlErrorMessage),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
-    MessageRejected(String),
+    MessageRejected(XmlErrorMessage),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
-    TemplateDoesNotExist(String),
+    TemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl SendTemplatedEmailError {
@@ -9508,38 +9920,38 @@ impl SendTemplatedEmailError {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
                         return RusotoError::Service(SendTemplatedEmailError::AccountSendingPaused(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             SendTemplatedEmailError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
                         return RusotoError::Service(
                             SendTemplatedEmailError::ConfigurationSetSendingPaused(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
                         return RusotoError::Service(
                             SendTemplatedEmailError::MailFromDomainNotVerified(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MessageRejected" => {
                         return RusotoError::Service(SendTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TemplateDoesNotExist" => {
                         return RusotoError::Service(SendTemplatedEmailError::TemplateDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9556,6 +9968,18 @@ impl SendTemplatedEmailError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendTemplatedEmailError::AccountSendingPaused(ref cause) => cause.request_id(),
+            SendTemplatedEmailError::ConfigurationSetDoesNotExist(ref cause) => cause.request_id(),
+            SendTemplatedEmailError::ConfigurationSetSendingPaused(ref cause) => cause.request_id(),
+            SendTemplatedEmailError::MailFromDomainNotVerified(ref cause) => cause.request_id(),
+            SendTemplatedEmailError::MessageRejected(ref cause) => cause.request_id(),
+            SendTemplatedEmailError::TemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendTemplatedEmailError {
     #[allow(unused_variables)]
@@ -9579,7 +10003,7 @@ impl Error for SendTemplatedEmailError {}
 #[derive(Debug, PartialEq)]
 pub enum SetActiveReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl SetActiveReceiptRuleSetError {
@@ -9592,7 +10016,9 @@ impl SetActiveReceiptRuleSetError {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(
-                            SetActiveReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            SetActiveReceiptRuleSetError::RuleSetDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9609,6 +10035,13 @@ impl SetActiveReceiptRuleSetError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetActiveReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetActiveReceiptRuleSetError {
     #[allow(unused_variables)]
@@ -9645,6 +10078,11 @@ impl SetIdentityDkimEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetIdentityDkimEnabledError {
     #[allow(unused_variables)]
@@ -9681,6 +10119,11 @@ impl SetIdentityFeedbackForwardingEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetIdentityFeedbackForwardingEnabledError {
     #[allow(unused_variables)]
@@ -9717,6 +10160,11 @@ impl SetIdentityHeadersInNotificationsEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetIdentityHeadersInNotificationsEnabledError {
     #[allow(unused_variables)]
@@ -9751,6 +10199,11 @@ impl SetIdentityMailFromDomainError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetIdentityMailFromDomainError {
     #[allow(unused_variables)]
@@ -9787,6 +10240,11 @@ impl SetIdentityNotificationTopicError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for SetIdentityNotificationTopicError {
     #[allow(unused_variables)]
@@ -9799,9 +10257,9 @@ impl Error for SetIdentityNotificationTopicError {}
 #[derive(Debug, PartialEq)]
 pub enum SetReceiptRulePositionError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
-    RuleDoesNotExist(String),
+    RuleDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl SetReceiptRulePositionError {
@@ -9814,12 +10272,14 @@ impl SetReceiptRulePositionError {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
                         return RusotoError::Service(SetReceiptRulePositionError::RuleDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(
-                            SetReceiptRulePositionError::RuleSetDoesNotExist(parsed_error.message),
+                            SetReceiptRulePositionError::RuleSetDoesNotExist(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -9836,6 +10296,14 @@ impl SetReceiptRulePositionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetReceiptRulePositionError::RuleDoesNotExist(ref cause) => cause.request_id(),
+            SetReceiptRulePositionError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetReceiptRulePositionError {
     #[allow(unused_variables)]
@@ -9851,11 +10319,11 @@ impl Error for SetReceiptRulePositionError {}
 #[derive(Debug, PartialEq)]
 pub enum TestRenderTemplateError {
     /// <p>Indicates that one or more of the replacement values you provided is invalid. This error may occur when the TemplateData object contains invalid JSON.</p>
-    InvalidRenderingParameter(String),
+    InvalidRenderingParameter(XmlErrorMessage),
     /// <p>Indicates that one or more of the replacement values for the specified template was not specified. Ensure that the TemplateData object contains references to all of the replacement tags in the specified template.</p>
-    MissingRenderingAttribute(String),
+    MissingRenderingAttribute(XmlErrorMessage),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
-    TemplateDoesNotExist(String),
+    TemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl TestRenderTemplateError {
@@ -9869,20 +10337,20 @@ impl TestRenderTemplateError {
                     "InvalidRenderingParameter" => {
                         return RusotoError::Service(
                             TestRenderTemplateError::InvalidRenderingParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MissingRenderingAttribute" => {
                         return RusotoError::Service(
                             TestRenderTemplateError::MissingRenderingAttribute(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TemplateDoesNotExist" => {
                         return RusotoError::Service(TestRenderTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -9899,6 +10367,15 @@ impl TestRenderTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TestRenderTemplateError::InvalidRenderingParameter(ref cause) => cause.request_id(),
+            TestRenderTemplateError::MissingRenderingAttribute(ref cause) => cause.request_id(),
+            TestRenderTemplateError::TemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TestRenderTemplateError {
     #[allow(unused_variables)]
@@ -9939,6 +10416,11 @@ impl UpdateAccountSendingEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UpdateAccountSendingEnabledError {
     #[allow(unused_variables)]
@@ -9951,15 +10433,15 @@ impl Error for UpdateAccountSendingEnabledError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the event destination does not exist.</p>
-    EventDestinationDoesNotExist(String),
+    EventDestinationDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the Amazon CloudWatch destination is invalid. See the error message for details.</p>
-    InvalidCloudWatchDestination(String),
+    InvalidCloudWatchDestination(XmlErrorMessage),
     /// <p>Indicates that the Amazon Kinesis Firehose destination is invalid. See the error message for details.</p>
-    InvalidFirehoseDestination(String),
+    InvalidFirehoseDestination(XmlErrorMessage),
     /// <p>Indicates that the Amazon Simple Notification Service (Amazon SNS) destination is invalid. See the error message for details.</p>
-    InvalidSNSDestination(String),
+    InvalidSNSDestination(XmlErrorMessage),
 }
 
 impl UpdateConfigurationSetEventDestinationError {
@@ -9974,30 +10456,30 @@ impl UpdateConfigurationSetEventDestinationError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "EventDestinationDoesNotExist" => return RusotoError::Service(
                         UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidCloudWatchDestination" => return RusotoError::Service(
                         UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidFirehoseDestination" => {
                         return RusotoError::Service(
                             UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidSNSDestination" => {
                         return RusotoError::Service(
                             UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10015,6 +10497,27 @@ impl UpdateConfigurationSetEventDestinationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+            UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+            UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
+                ref cause,
+            ) => cause.request_id(),
+            UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(ref cause) => {
+                cause.request_id()
+            }
+            UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateConfigurationSetEventDestinationError {
     #[allow(unused_variables)]
@@ -10043,7 +10546,7 @@ impl Error for UpdateConfigurationSetEventDestinationError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetReputationMetricsEnabledError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateConfigurationSetReputationMetricsEnabledError {
@@ -10056,7 +10559,7 @@ impl UpdateConfigurationSetReputationMetricsEnabledError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -10070,6 +10573,15 @@ impl UpdateConfigurationSetReputationMetricsEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(
+                ref cause,
+            ) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateConfigurationSetReputationMetricsEnabledError {
     #[allow(unused_variables)]
@@ -10086,7 +10598,7 @@ impl Error for UpdateConfigurationSetReputationMetricsEnabledError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetSendingEnabledError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateConfigurationSetSendingEnabledError {
@@ -10102,7 +10614,7 @@ impl UpdateConfigurationSetSendingEnabledError {
                     "ConfigurationSetDoesNotExist" => {
                         return RusotoError::Service(
                             UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10120,6 +10632,15 @@ impl UpdateConfigurationSetSendingEnabledError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateConfigurationSetSendingEnabledError {
     #[allow(unused_variables)]
@@ -10136,11 +10657,11 @@ impl Error for UpdateConfigurationSetSendingEnabledError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
-    ConfigurationSetDoesNotExist(String),
+    ConfigurationSetDoesNotExist(XmlErrorMessage),
     /// <p><p>Indicates that the custom domain to be used for open and click tracking redirects is invalid. This error appears most often in the following situations:</p> <ul> <li> <p>When the tracking domain you specified is not verified in Amazon SES.</p> </li> <li> <p>When the tracking domain you specified is not a valid domain or subdomain.</p> </li> </ul></p>
-    InvalidTrackingOptions(String),
+    InvalidTrackingOptions(XmlErrorMessage),
     /// <p>Indicates that the TrackingOptions object you specified does not exist.</p>
-    TrackingOptionsDoesNotExist(String),
+    TrackingOptionsDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateConfigurationSetTrackingOptionsError {
@@ -10155,20 +10676,20 @@ impl UpdateConfigurationSetTrackingOptionsError {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => return RusotoError::Service(
                         UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ),
                     ),
                     "InvalidTrackingOptions" => {
                         return RusotoError::Service(
                             UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "TrackingOptionsDoesNotExistException" => {
                         return RusotoError::Service(
                             UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -10186,6 +10707,21 @@ impl UpdateConfigurationSetTrackingOptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+            UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(ref cause) => {
+                cause.request_id()
+            }
+            UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for UpdateConfigurationSetTrackingOptionsError {
     #[allow(unused_variables)]
@@ -10208,11 +10744,11 @@ impl Error for UpdateConfigurationSetTrackingOptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomVerificationEmailTemplateError {
     /// <p>Indicates that custom verification email template provided content is invalid.</p>
-    CustomVerificationEmailInvalidContent(String),
+    CustomVerificationEmailInvalidContent(XmlErrorMessage),
     /// <p>Indicates that a custom verification email template with the name you specified does not exist.</p>
-    CustomVerificationEmailTemplateDoesNotExist(String),
+    CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the sender address specified for a custom verification email is not verified, and is therefore not eligible to send the custom verification email. </p>
-    FromEmailAddressNotVerified(String),
+    FromEmailAddressNotVerified(XmlErrorMessage),
 }
 
 impl UpdateCustomVerificationEmailTemplateError {
@@ -10225,7 +10761,7 @@ impl UpdateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(XmlErrorMessage::from_response(parsed_error.message, &res))),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(XmlErrorMessage::from_response(parsed_error.message, &res))),"FromEmailAddressNotVerified" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(XmlErrorMessage::from_response(parsed_error.message, &res))),_ => {}
                                 }
             }
         }
@@ -10239,6 +10775,15 @@ impl UpdateCustomVerificationEmailTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(ref cause) => cause.request_id(),
+            UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(ref cause) => cause.request_id(),
+            UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
@@ -10255,17 +10800,17 @@ impl Error for UpdateCustomVerificationEmailTemplateError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateReceiptRuleError {
     /// <p>Indicates that the provided AWS Lambda function is invalid, or that Amazon SES could not execute the provided function, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidLambdaFunction(String),
+    InvalidLambdaFunction(XmlErrorMessage),
     /// <p>Indicates that the provided Amazon S3 bucket or AWS KMS encryption key is invalid, or that Amazon SES could not publish to the bucket, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidS3Configuration(String),
+    InvalidS3Configuration(XmlErrorMessage),
     /// <p>Indicates that the provided Amazon SNS topic is invalid, or that Amazon SES could not publish to the topic, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
-    InvalidSnsTopic(String),
+    InvalidSnsTopic(XmlErrorMessage),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
-    LimitExceeded(String),
+    LimitExceeded(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule does not exist.</p>
-    RuleDoesNotExist(String),
+    RuleDoesNotExist(XmlErrorMessage),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
-    RuleSetDoesNotExist(String),
+    RuleSetDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateReceiptRuleError {
@@ -10278,32 +10823,34 @@ impl UpdateReceiptRuleError {
                 match &parsed_error.code[..] {
                     "InvalidLambdaFunction" => {
                         return RusotoError::Service(UpdateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidS3Configuration" => {
                         return RusotoError::Service(
-                            UpdateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                            UpdateReceiptRuleError::InvalidS3Configuration(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidSnsTopic" => {
                         return RusotoError::Service(UpdateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "LimitExceeded" => {
                         return RusotoError::Service(UpdateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleDoesNotExist" => {
                         return RusotoError::Service(UpdateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RuleSetDoesNotExist" => {
                         return RusotoError::Service(UpdateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10320,6 +10867,18 @@ impl UpdateReceiptRuleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateReceiptRuleError::InvalidLambdaFunction(ref cause) => cause.request_id(),
+            UpdateReceiptRuleError::InvalidS3Configuration(ref cause) => cause.request_id(),
+            UpdateReceiptRuleError::InvalidSnsTopic(ref cause) => cause.request_id(),
+            UpdateReceiptRuleError::LimitExceeded(ref cause) => cause.request_id(),
+            UpdateReceiptRuleError::RuleDoesNotExist(ref cause) => cause.request_id(),
+            UpdateReceiptRuleError::RuleSetDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateReceiptRuleError {
     #[allow(unused_variables)]
@@ -10339,9 +10898,9 @@ impl Error for UpdateReceiptRuleError {}
 #[derive(Debug, PartialEq)]
 pub enum UpdateTemplateError {
     /// <p>Indicates that the template that you specified could not be rendered. This issue may occur when a template refers to a partial that does not exist.</p>
-    InvalidTemplate(String),
+    InvalidTemplate(XmlErrorMessage),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
-    TemplateDoesNotExist(String),
+    TemplateDoesNotExist(XmlErrorMessage),
 }
 
 impl UpdateTemplateError {
@@ -10354,12 +10913,12 @@ impl UpdateTemplateError {
                 match &parsed_error.code[..] {
                     "InvalidTemplate" => {
                         return RusotoError::Service(UpdateTemplateError::InvalidTemplate(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TemplateDoesNotExist" => {
                         return RusotoError::Service(UpdateTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -10376,6 +10935,14 @@ impl UpdateTemplateError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UpdateTemplateError::InvalidTemplate(ref cause) => cause.request_id(),
+            UpdateTemplateError::TemplateDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UpdateTemplateError {
     #[allow(unused_variables)]
@@ -10413,6 +10980,11 @@ impl VerifyDomainDkimError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for VerifyDomainDkimError {
     #[allow(unused_variables)]
@@ -10447,6 +11019,11 @@ impl VerifyDomainIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for VerifyDomainIdentityError {
     #[allow(unused_variables)]
@@ -10481,6 +11058,11 @@ impl VerifyEmailAddressError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for VerifyEmailAddressError {
     #[allow(unused_variables)]
@@ -10515,6 +11097,11 @@ impl VerifyEmailIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for VerifyEmailIdentityError {
     #[allow(unused_variables)]
@@ -41,7 +41,7 @@ impl ShieldClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2085,7 +2085,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, AssociateDRTLogBucketError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateDRTLogBucketResponse, _>()
     }
@@ -2104,7 +2104,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, AssociateDRTRoleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateDRTRoleResponse, _>()
     }
 
@@ -2122,7 +2122,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, AssociateHealthCheckError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateHealthCheckResponse, _>()
     }
@@ -2150,7 +2150,7 @@ impl Shield for ShieldClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateProactiveEngagementDetailsResponse, _>()
     }
@@ -2169,7 +2169,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, CreateProtectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateProtectionResponse, _>()
     }
 
@@ -2185,7 +2185,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, CreateSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSubscriptionResponse, _>()
     }
 
@@ -2203,7 +2203,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DeleteProtectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteProtectionResponse, _>()
     }
 
@@ -2219,7 +2219,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DeleteSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteSubscriptionResponse, _>()
     }
 
@@ -2237,7 +2237,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DescribeAttackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAttackResponse, _>()
     }
 
@@ -2253,7 +2253,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DescribeDRTAccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDRTAccessResponse, _>()
     }
 
@@ -2278,7 +2278,7 @@ impl Shield for ShieldClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEmergencyContactSettingsResponse, _>()
     }
@@ -2297,7 +2297,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DescribeProtectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeProtectionResponse, _>()
     }
 
@@ -2313,7 +2313,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DescribeSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSubscriptionResponse, _>()
     }
@@ -2334,7 +2334,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DisableProactiveEngagementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisableProactiveEngagementResponse, _>()
     }
@@ -2356,7 +2356,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DisassociateDRTLogBucketError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateDRTLogBucketResponse, _>()
     }
@@ -2373,7 +2373,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DisassociateDRTRoleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisassociateDRTRoleResponse, _>()
     }
 
@@ -2391,7 +2391,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, DisassociateHealthCheckError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateHealthCheckResponse, _>()
     }
@@ -2412,7 +2412,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, EnableProactiveEngagementError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<EnableProactiveEngagementResponse, _>()
     }
@@ -2429,7 +2429,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, GetSubscriptionStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSubscriptionStateResponse, _>()
     }
@@ -2448,7 +2448,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, ListAttacksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAttacksResponse, _>()
     }
 
@@ -2466,7 +2466,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, ListProtectionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListProtectionsResponse, _>()
     }
 
@@ -2490,7 +2490,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, UpdateEmergencyContactSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateEmergencyContactSettingsResponse, _>()
     }
@@ -2509,7 +2509,7 @@ impl Shield for ShieldClient {
             .sign_and_dispatch(request, UpdateSubscriptionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriptionResponse, _>()
     }
 }
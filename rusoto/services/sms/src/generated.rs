@@ -41,7 +41,7 @@ impl ServerMigrationServiceClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3170,7 +3170,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, CreateAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResponse, _>()
     }
 
@@ -3191,7 +3191,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, CreateReplicationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateReplicationJobResponse, _>()
     }
@@ -3213,7 +3213,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, DeleteAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAppResponse, _>()
     }
 
@@ -3235,7 +3235,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, DeleteAppLaunchConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteAppLaunchConfigurationResponse, _>()
     }
@@ -3263,7 +3263,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteAppReplicationConfigurationResponse, _>()
     }
@@ -3285,7 +3285,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, DeleteReplicationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteReplicationJobResponse, _>()
     }
@@ -3305,7 +3305,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, DeleteServerCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteServerCatalogResponse, _>()
     }
 
@@ -3326,7 +3326,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, DisassociateConnectorError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateConnectorResponse, _>()
     }
@@ -3348,7 +3348,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GenerateChangeSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateChangeSetResponse, _>()
     }
 
@@ -3369,7 +3369,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GenerateTemplateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GenerateTemplateResponse, _>()
     }
 
@@ -3390,7 +3390,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetAppResponse, _>()
     }
 
@@ -3412,7 +3412,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetAppLaunchConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAppLaunchConfigurationResponse, _>()
     }
@@ -3437,7 +3437,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetAppReplicationConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAppReplicationConfigurationResponse, _>()
     }
@@ -3459,7 +3459,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetConnectorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConnectorsResponse, _>()
     }
 
@@ -3480,7 +3480,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetReplicationJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetReplicationJobsResponse, _>()
     }
 
@@ -3501,7 +3501,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetReplicationRunsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetReplicationRunsResponse, _>()
     }
 
@@ -3522,7 +3522,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, GetServersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetServersResponse, _>()
     }
 
@@ -3541,7 +3541,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, ImportServerCatalogError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportServerCatalogResponse, _>()
     }
 
@@ -3562,7 +3562,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, LaunchAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<LaunchAppResponse, _>()
     }
 
@@ -3583,7 +3583,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, ListAppsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResponse, _>()
     }
 
@@ -3605,7 +3605,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, PutAppLaunchConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutAppLaunchConfigurationResponse, _>()
     }
@@ -3630,7 +3630,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, PutAppReplicationConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutAppReplicationConfigurationResponse, _>()
     }
@@ -3652,7 +3652,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, StartAppReplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartAppReplicationResponse, _>()
     }
 
@@ -3674,7 +3674,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, StartOnDemandReplicationRunError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartOnDemandReplicationRunResponse, _>()
     }
@@ -3696,7 +3696,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, StopAppReplicationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopAppReplicationResponse, _>()
     }
 
@@ -3717,7 +3717,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, TerminateAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TerminateAppResponse, _>()
     }
 
@@ -3738,7 +3738,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, UpdateAppError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAppResponse, _>()
     }
 
@@ -3759,7 +3759,7 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .sign_and_dispatch(request, UpdateReplicationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateReplicationJobResponse, _>()
     }
@@ -41,7 +41,7 @@ impl SnowballClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1943,7 +1943,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, CancelClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelClusterResult, _>()
     }
 
@@ -1964,7 +1964,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, CancelJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelJobResult, _>()
     }
 
@@ -1985,7 +1985,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, CreateAddressError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAddressResult, _>()
     }
 
@@ -2006,7 +2006,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, CreateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResult, _>()
     }
 
@@ -2027,7 +2027,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, CreateJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResult, _>()
     }
 
@@ -2048,7 +2048,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, DescribeAddressError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAddressResult, _>()
     }
 
@@ -2069,7 +2069,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, DescribeAddressesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAddressesResult, _>()
     }
 
@@ -2090,7 +2090,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, DescribeClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeClusterResult, _>()
     }
 
@@ -2111,7 +2111,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, DescribeJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeJobResult, _>()
     }
 
@@ -2132,7 +2132,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, GetJobManifestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobManifestResult, _>()
     }
 
@@ -2153,7 +2153,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, GetJobUnlockCodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetJobUnlockCodeResult, _>()
     }
 
@@ -2172,7 +2172,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, GetSnowballUsageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSnowballUsageResult, _>()
     }
 
@@ -2193,7 +2193,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, GetSoftwareUpdatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSoftwareUpdatesResult, _>()
     }
 
@@ -2214,7 +2214,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, ListClusterJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListClusterJobsResult, _>()
     }
 
@@ -2235,7 +2235,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, ListClustersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListClustersResult, _>()
     }
 
@@ -2256,7 +2256,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, ListCompatibleImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCompatibleImagesResult, _>()
     }
 
@@ -2274,7 +2274,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, ListJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
     }
 
@@ -2295,7 +2295,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, UpdateClusterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateClusterResult, _>()
     }
 
@@ -2316,7 +2316,7 @@ impl Snowball for SnowballClient {
             .sign_and_dispatch(request, UpdateJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResult, _>()
     }
 }
@@ -1,2 +1,10 @@
+#[cfg(feature = "message-validation")]
+mod validator;
+#[cfg(feature = "message-validation")]
+pub use self::validator::{
+    FetchCertificate, HttpCertificateFetcher, MessageValidationError, MessageValidator,
+    Notification, SnsMessage, SubscriptionConfirmation,
+};
+
 #[cfg(test)]
 mod custom_tests;
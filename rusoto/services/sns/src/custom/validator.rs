@@ -0,0 +1,610 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use openssl::hash::MessageDigest;
+use openssl::pkey::{PKey, Public};
+use openssl::sign::Verifier;
+use openssl::x509::X509;
+use rusoto_core::request::{DispatchSignedRequest, HttpClient, TlsError};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+use serde_json::{Map, Value};
+
+/// How long fetching a signing certificate may take with the default fetcher.
+const CERTIFICATE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A message SNS delivered to an HTTP/S endpoint, whose signature was verified.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnsMessage {
+    /// A message published to the topic.
+    Notification(Notification),
+    /// SNS asks to confirm the subscription of the endpoint, by visiting `subscribe_url`.
+    SubscriptionConfirmation(SubscriptionConfirmation),
+    /// The endpoint was unsubscribed from the topic, visiting `subscribe_url` subscribes it again.
+    UnsubscribeConfirmation(SubscriptionConfirmation),
+}
+
+/// A message published to a topic, see `SnsMessage::Notification`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Notification {
+    /// The unique identifier of the message.
+    pub message_id: String,
+    /// The ARN of the topic the message was published to.
+    pub topic_arn: String,
+    /// The subject of the message, if it was published with one.
+    pub subject: Option<String>,
+    /// The message itself.
+    pub message: String,
+    /// When the message was published, in ISO 8601 format.
+    pub timestamp: String,
+    /// The URL to unsubscribe the endpoint from the topic.
+    pub unsubscribe_url: String,
+}
+
+/// A (un)subscription confirmation, see `SnsMessage::SubscriptionConfirmation` and
+/// `SnsMessage::UnsubscribeConfirmation`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubscriptionConfirmation {
+    /// The unique identifier of the message.
+    pub message_id: String,
+    /// The ARN of the topic the endpoint is (un)subscribed from.
+    pub topic_arn: String,
+    /// A description of the message.
+    pub message: String,
+    /// When the message was sent, in ISO 8601 format.
+    pub timestamp: String,
+    /// The token to confirm the subscription with `ConfirmSubscription`.
+    pub token: String,
+    /// The URL to visit to confirm the subscription.
+    pub subscribe_url: String,
+}
+
+/// Why `MessageValidator::validate` rejected a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageValidationError {
+    /// The body isn't a JSON object.
+    InvalidJson(String),
+    /// A field the message type requires is missing or not a string.
+    MissingField(&'static str),
+    /// The `Type` of the message is none of the known ones.
+    UnsupportedType(String),
+    /// The `SignatureVersion` is neither 1 (SHA1) nor 2 (SHA256).
+    UnsupportedSignatureVersion(String),
+    /// The `SigningCertURL` isn't an HTTPS URL of an SNS host.
+    InvalidCertificateUrl(String),
+    /// The signing certificate couldn't be fetched.
+    CertificateFetch(String),
+    /// The signing certificate couldn't be parsed.
+    InvalidCertificate(String),
+    /// The `Signature` doesn't match the message.
+    InvalidSignature,
+}
+
+impl fmt::Display for MessageValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MessageValidationError::InvalidJson(ref reason) => {
+                write!(f, "SNS message is not a JSON object: {}", reason)
+            }
+            MessageValidationError::MissingField(field) => {
+                write!(f, "SNS message has no string field '{}'", field)
+            }
+            MessageValidationError::UnsupportedType(ref message_type) => {
+                write!(f, "Unsupported SNS message type '{}'", message_type)
+            }
+            MessageValidationError::UnsupportedSignatureVersion(ref version) => {
+                write!(f, "Unsupported SNS signature version '{}'", version)
+            }
+            MessageValidationError::InvalidCertificateUrl(ref url) => write!(
+                f,
+                "Signing certificate URL '{}' is not an HTTPS URL of an SNS host",
+                url
+            ),
+            MessageValidationError::CertificateFetch(ref reason) => {
+                write!(f, "Couldn't fetch the signing certificate: {}", reason)
+            }
+            MessageValidationError::InvalidCertificate(ref reason) => {
+                write!(f, "Invalid signing certificate: {}", reason)
+            }
+            MessageValidationError::InvalidSignature => {
+                f.write_str("SNS message signature doesn't match the message")
+            }
+        }
+    }
+}
+
+impl Error for MessageValidationError {}
+
+/// Fetches the PEM encoded signing certificates of SNS messages.
+#[async_trait]
+pub trait FetchCertificate {
+    /// Fetches the certificate at `url`, which `MessageValidator` checked is an HTTPS URL of
+    /// an SNS host.
+    async fn fetch_certificate(&self, url: &str) -> Result<Vec<u8>, String>;
+}
+
+/// Fetches signing certificates with a `rusoto_core` `HttpClient`.
+pub struct HttpCertificateFetcher {
+    client: HttpClient,
+}
+
+impl HttpCertificateFetcher {
+    /// Creates a fetcher with a new `HttpClient`.
+    pub fn new() -> Result<Self, TlsError> {
+        Ok(HttpCertificateFetcher {
+            client: HttpClient::new()?,
+        })
+    }
+}
+
+#[async_trait]
+impl FetchCertificate for HttpCertificateFetcher {
+    async fn fetch_certificate(&self, url: &str) -> Result<Vec<u8>, String> {
+        let (host, path) = split_certificate_url(url).ok_or_else(|| url.to_owned())?;
+        let mut request = SignedRequest::new("GET", "sns", &Region::default(), path);
+        request.set_hostname(Some(host.to_owned()));
+        let mut response = self
+            .client
+            .dispatch(request, Some(CERTIFICATE_FETCH_TIMEOUT))
+            .await
+            .map_err(|err| err.to_string())?;
+        let response = response.buffer().await.map_err(|err| err.to_string())?;
+        if !response.status.is_success() {
+            return Err(format!("HTTP {} from {}", response.status, url));
+        }
+        Ok(response.body.to_vec())
+    }
+}
+
+/// Verifies the signatures of messages SNS delivers to HTTP/S endpoints.
+///
+/// The signing certificate is only fetched from HTTPS URLs of `sns.<region>.amazonaws.com`
+/// hosts, and kept for later messages signed with it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_sns::{MessageValidator, SnsMessage};
+///
+/// # async fn handle(body: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let validator = MessageValidator::new()?;
+/// match validator.validate(body).await? {
+///     SnsMessage::Notification(notification) => println!("{}", notification.message),
+///     SnsMessage::SubscriptionConfirmation(confirmation) => {
+///         println!("confirm at {}", confirmation.subscribe_url)
+///     }
+///     SnsMessage::UnsubscribeConfirmation(_) => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct MessageValidator {
+    fetcher: Arc<dyn FetchCertificate + Send + Sync>,
+    certificates: Mutex<HashMap<String, PKey<Public>>>,
+}
+
+impl fmt::Debug for MessageValidator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MessageValidator").finish()
+    }
+}
+
+impl MessageValidator {
+    /// Creates a validator fetching certificates with an `HttpCertificateFetcher`.
+    pub fn new() -> Result<Self, TlsError> {
+        Ok(Self::with_fetcher(HttpCertificateFetcher::new()?))
+    }
+
+    /// Creates a validator fetching certificates with `fetcher`.
+    pub fn with_fetcher<F>(fetcher: F) -> Self
+    where
+        F: FetchCertificate + Send + Sync + 'static,
+    {
+        MessageValidator {
+            fetcher: Arc::new(fetcher),
+            certificates: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Parses the JSON `body` of a message SNS delivered and verifies its signature.
+    pub async fn validate(&self, body: &str) -> Result<SnsMessage, MessageValidationError> {
+        let fields = match serde_json::from_str(body) {
+            Ok(Value::Object(fields)) => fields,
+            Ok(_) => {
+                return Err(MessageValidationError::InvalidJson(
+                    "expected an object".to_owned(),
+                ))
+            }
+            Err(err) => return Err(MessageValidationError::InvalidJson(err.to_string())),
+        };
+        let message = parse_message(&fields)?;
+        let digest = match field(&fields, "SignatureVersion")? {
+            "1" => MessageDigest::sha1(),
+            "2" => MessageDigest::sha256(),
+            version => {
+                return Err(MessageValidationError::UnsupportedSignatureVersion(
+                    version.to_owned(),
+                ))
+            }
+        };
+        let signature = base64::decode(field(&fields, "Signature")?)
+            .map_err(|_| MessageValidationError::InvalidSignature)?;
+        let key = self.signing_key(field(&fields, "SigningCertURL")?).await?;
+
+        let verified = Verifier::new(digest, &key)
+            .and_then(|mut verifier| {
+                verifier.update(string_to_sign(&message).as_bytes())?;
+                verifier.verify(&signature)
+            })
+            .unwrap_or(false);
+        if verified {
+            Ok(message)
+        } else {
+            Err(MessageValidationError::InvalidSignature)
+        }
+    }
+
+    /// Returns the public key of the certificate at `url`, fetching it if it isn't cached.
+    async fn signing_key(&self, url: &str) -> Result<PKey<Public>, MessageValidationError> {
+        if split_certificate_url(url).is_none() {
+            return Err(MessageValidationError::InvalidCertificateUrl(
+                url.to_owned(),
+            ));
+        }
+        if let Some(key) = self.lock_certificates().get(url) {
+            return Ok(key.clone());
+        }
+        let pem = self
+            .fetcher
+            .fetch_certificate(url)
+            .await
+            .map_err(MessageValidationError::CertificateFetch)?;
+        let key = X509::from_pem(&pem)
+            .and_then(|certificate| certificate.public_key())
+            .map_err(|err| MessageValidationError::InvalidCertificate(err.to_string()))?;
+        self.lock_certificates().insert(url.to_owned(), key.clone());
+        Ok(key)
+    }
+
+    fn lock_certificates(&self) -> std::sync::MutexGuard<'_, HashMap<String, PKey<Public>>> {
+        match self.certificates.lock() {
+            Ok(certificates) => certificates,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+fn field<'a>(
+    fields: &'a Map<String, Value>,
+    name: &'static str,
+) -> Result<&'a str, MessageValidationError> {
+    fields
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or(MessageValidationError::MissingField(name))
+}
+
+fn parse_message(fields: &Map<String, Value>) -> Result<SnsMessage, MessageValidationError> {
+    let confirmation = || -> Result<SubscriptionConfirmation, MessageValidationError> {
+        Ok(SubscriptionConfirmation {
+            message_id: field(fields, "MessageId")?.to_owned(),
+            topic_arn: field(fields, "TopicArn")?.to_owned(),
+            message: field(fields, "Message")?.to_owned(),
+            timestamp: field(fields, "Timestamp")?.to_owned(),
+            token: field(fields, "Token")?.to_owned(),
+            subscribe_url: field(fields, "SubscribeURL")?.to_owned(),
+        })
+    };
+    match field(fields, "Type")? {
+        "Notification" => Ok(SnsMessage::Notification(Notification {
+            message_id: field(fields, "MessageId")?.to_owned(),
+            topic_arn: field(fields, "TopicArn")?.to_owned(),
+            subject: fields
+                .get("Subject")
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+            message: field(fields, "Message")?.to_owned(),
+            timestamp: field(fields, "Timestamp")?.to_owned(),
+            unsubscribe_url: field(fields, "UnsubscribeURL")?.to_owned(),
+        })),
+        "SubscriptionConfirmation" => Ok(SnsMessage::SubscriptionConfirmation(confirmation()?)),
+        "UnsubscribeConfirmation" => Ok(SnsMessage::UnsubscribeConfirmation(confirmation()?)),
+        message_type => Err(MessageValidationError::UnsupportedType(
+            message_type.to_owned(),
+        )),
+    }
+}
+
+/// Builds the string SNS signed: the signed fields in byte order, each as its name and its
+/// value on their own line.
+fn string_to_sign(message: &SnsMessage) -> String {
+    let fields = match *message {
+        SnsMessage::Notification(ref notification) => {
+            let mut fields = vec![
+                ("Message", notification.message.as_str()),
+                ("MessageId", notification.message_id.as_str()),
+            ];
+            if let Some(ref subject) = notification.subject {
+                fields.push(("Subject", subject));
+            }
+            fields.extend_from_slice(&[
+                ("Timestamp", notification.timestamp.as_str()),
+                ("TopicArn", notification.topic_arn.as_str()),
+                ("Type", "Notification"),
+            ]);
+            fields
+        }
+        SnsMessage::SubscriptionConfirmation(ref confirmation) => {
+            confirmation_fields(confirmation, "SubscriptionConfirmation")
+        }
+        SnsMessage::UnsubscribeConfirmation(ref confirmation) => {
+            confirmation_fields(confirmation, "UnsubscribeConfirmation")
+        }
+    };
+    fields
+        .into_iter()
+        .map(|(name, value)| format!("{}\n{}\n", name, value))
+        .collect()
+}
+
+fn confirmation_fields<'a>(
+    confirmation: &'a SubscriptionConfirmation,
+    message_type: &'static str,
+) -> Vec<(&'static str, &'a str)> {
+    vec![
+        ("Message", &confirmation.message),
+        ("MessageId", &confirmation.message_id),
+        ("SubscribeURL", &confirmation.subscribe_url),
+        ("Timestamp", &confirmation.timestamp),
+        ("Token", &confirmation.token),
+        ("TopicArn", &confirmation.topic_arn),
+        ("Type", message_type),
+    ]
+}
+
+/// Splits an HTTPS URL of an SNS host, like
+/// `https://sns.us-east-1.amazonaws.com/SimpleNotificationService-abc.pem`, into host and path.
+/// Returns `None` for any other URL.
+fn split_certificate_url(url: &str) -> Option<(&str, &str)> {
+    let rest = url.strip_prefix("https://")?;
+    let path_start = rest.find('/')?;
+    let (host, path) = rest.split_at(path_start);
+    let region = host
+        .strip_prefix("sns.")?
+        .strip_suffix(".amazonaws.com")
+        .or_else(|| host.strip_prefix("sns.")?.strip_suffix(".amazonaws.com.cn"))?;
+    let is_region = !region.is_empty()
+        && region
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    let is_pem_path = path.ends_with(".pem") && !path.contains(&['?', '#'][..]);
+    if is_region && is_pem_path {
+        Some((host, path))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::asn1::Asn1Time;
+    use openssl::pkey::Private;
+    use openssl::rsa::Rsa;
+    use openssl::sign::Signer;
+    use openssl::x509::X509NameBuilder;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CERT_URL: &str = "https://sns.us-east-1.amazonaws.com/SimpleNotificationService-1.pem";
+
+    struct FakeFetcher {
+        pem: Vec<u8>,
+        fetches: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl FetchCertificate for FakeFetcher {
+        async fn fetch_certificate(&self, url: &str) -> Result<Vec<u8>, String> {
+            assert_eq!(url, CERT_URL);
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(self.pem.clone())
+        }
+    }
+
+    fn certificate() -> (PKey<Private>, Vec<u8>) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "sns.amazonaws.com")
+            .unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        (key, builder.build().to_pem().unwrap())
+    }
+
+    fn validator(pem: Vec<u8>) -> (MessageValidator, Arc<AtomicUsize>) {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let validator = MessageValidator::with_fetcher(FakeFetcher {
+            pem,
+            fetches: fetches.clone(),
+        });
+        (validator, fetches)
+    }
+
+    /// Signs `message` with `key` as SNS would, returning the JSON body delivered.
+    fn signed(message: &SnsMessage, key: &PKey<Private>, version: &str) -> Value {
+        let digest = match version {
+            "1" => MessageDigest::sha1(),
+            _ => MessageDigest::sha256(),
+        };
+        let mut signer = Signer::new(digest, key).unwrap();
+        signer.update(string_to_sign(message).as_bytes()).unwrap();
+        let signature = base64::encode(signer.sign_to_vec().unwrap());
+        let mut body = match *message {
+            SnsMessage::Notification(ref notification) => json!({
+                "Type": "Notification",
+                "MessageId": notification.message_id,
+                "TopicArn": notification.topic_arn,
+                "Message": notification.message,
+                "Timestamp": notification.timestamp,
+                "UnsubscribeURL": notification.unsubscribe_url,
+            }),
+            SnsMessage::SubscriptionConfirmation(ref confirmation)
+            | SnsMessage::UnsubscribeConfirmation(ref confirmation) => json!({
+                "Type": match *message {
+                    SnsMessage::SubscriptionConfirmation(_) => "SubscriptionConfirmation",
+                    _ => "UnsubscribeConfirmation",
+                },
+                "MessageId": confirmation.message_id,
+                "TopicArn": confirmation.topic_arn,
+                "Message": confirmation.message,
+                "Timestamp": confirmation.timestamp,
+                "Token": confirmation.token,
+                "SubscribeURL": confirmation.subscribe_url,
+            }),
+        };
+        if let SnsMessage::Notification(Notification {
+            subject: Some(ref subject),
+            ..
+        }) = *message
+        {
+            body["Subject"] = json!(subject);
+        }
+        body["SignatureVersion"] = json!(version);
+        body["Signature"] = json!(signature);
+        body["SigningCertURL"] = json!(CERT_URL);
+        body
+    }
+
+    fn notification(subject: Option<&str>) -> SnsMessage {
+        SnsMessage::Notification(Notification {
+            message_id: "22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324".to_owned(),
+            topic_arn: "arn:aws:sns:us-east-1:123456789012:MyTopic".to_owned(),
+            subject: subject.map(str::to_owned),
+            message: "Hello\nworld".to_owned(),
+            timestamp: "2012-05-02T00:54:06.655Z".to_owned(),
+            unsubscribe_url: "https://sns.us-east-1.amazonaws.com/?Action=Unsubscribe".to_owned(),
+        })
+    }
+
+    #[tokio::test]
+    async fn validates_signed_messages_and_caches_the_certificate() {
+        let (key, pem) = certificate();
+        let (validator, fetches) = validator(pem);
+        let confirmation = SnsMessage::SubscriptionConfirmation(SubscriptionConfirmation {
+            message_id: "165545c9-2a5c-472c-8df2-7ff2be2b3b1b".to_owned(),
+            topic_arn: "arn:aws:sns:us-east-1:123456789012:MyTopic".to_owned(),
+            message: "You have chosen to subscribe to the topic".to_owned(),
+            timestamp: "2012-04-26T20:45:04.751Z".to_owned(),
+            token: "2336412f37".to_owned(),
+            subscribe_url: "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription"
+                .to_owned(),
+        });
+
+        for (message, version) in [
+            (notification(Some("Greeting")), "1"),
+            (notification(None), "2"),
+            (confirmation, "2"),
+        ] {
+            let body = signed(&message, &key, version).to_string();
+            assert_eq!(validator.validate(&body).await, Ok(message));
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_messages() {
+        let (key, pem) = certificate();
+        let (validator, _) = validator(pem);
+        let mut body = signed(&notification(Some("Greeting")), &key, "1");
+        body["Message"] = json!("Goodbye");
+
+        assert_eq!(
+            validator.validate(&body.to_string()).await,
+            Err(MessageValidationError::InvalidSignature)
+        );
+
+        let (other_key, _) = certificate();
+        let body = signed(&notification(None), &other_key, "2");
+        assert_eq!(
+            validator.validate(&body.to_string()).await,
+            Err(MessageValidationError::InvalidSignature)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_certificates_from_other_hosts() {
+        let (key, pem) = certificate();
+        let (validator, fetches) = validator(pem);
+
+        for url in &[
+            "http://sns.us-east-1.amazonaws.com/SimpleNotificationService-1.pem",
+            "https://sns.us-east-1.amazonaws.com.evil.com/SimpleNotificationService-1.pem",
+            "https://evil.com/sns.us-east-1.amazonaws.com/cert.pem",
+            "https://sns.us-east-1.amazonaws.com@evil.com/cert.pem",
+            "https://sns.us-east-1.amazonaws.com/cert.pem?redirect=evil.com",
+        ] {
+            let mut body = signed(&notification(None), &key, "1");
+            body["SigningCertURL"] = json!(url);
+            assert_eq!(
+                validator.validate(&body.to_string()).await,
+                Err(MessageValidationError::InvalidCertificateUrl(
+                    url.to_string()
+                ))
+            );
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            split_certificate_url("https://sns.cn-north-1.amazonaws.com.cn/cert.pem"),
+            Some(("sns.cn-north-1.amazonaws.com.cn", "/cert.pem"))
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_messages() {
+        let (key, pem) = certificate();
+        let (validator, _) = validator(pem);
+        let validate = |body: Value| {
+            let body = body.to_string();
+            let validator = &validator;
+            async move { validator.validate(&body).await.unwrap_err() }
+        };
+
+        assert!(matches!(
+            validator.validate("not json").await,
+            Err(MessageValidationError::InvalidJson(_))
+        ));
+        let mut body = signed(&notification(None), &key, "1");
+        body["Type"] = json!("Broadcast");
+        assert_eq!(
+            validate(body).await,
+            MessageValidationError::UnsupportedType("Broadcast".to_owned())
+        );
+        let mut body = signed(&notification(None), &key, "1");
+        body.as_object_mut().unwrap().remove("TopicArn");
+        assert_eq!(
+            validate(body).await,
+            MessageValidationError::MissingField("TopicArn")
+        );
+        let mut body = signed(&notification(None), &key, "1");
+        body["SignatureVersion"] = json!("3");
+        assert_eq!(
+            validate(body).await,
+            MessageValidationError::UnsupportedSignatureVersion("3".to_owned())
+        );
+    }
+}
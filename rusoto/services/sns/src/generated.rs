@@ -52,7 +52,7 @@ impl SnsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2320,13 +2320,13 @@ impl UntagResourceResponseDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AddPermissionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl AddPermissionError {
@@ -2339,22 +2339,22 @@ impl AddPermissionError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(AddPermissionError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(AddPermissionError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(AddPermissionError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(AddPermissionError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2371,6 +2371,16 @@ impl AddPermissionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddPermissionError::AuthorizationError(ref cause) => cause.request_id(),
+            AddPermissionError::InternalError(ref cause) => cause.request_id(),
+            AddPermissionError::InvalidParameter(ref cause) => cause.request_id(),
+            AddPermissionError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddPermissionError {
     #[allow(unused_variables)]
@@ -2388,13 +2398,13 @@ impl Error for AddPermissionError {}
 #[derive(Debug, PartialEq)]
 pub enum CheckIfPhoneNumberIsOptedOutError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
-    Throttled(String),
+    Throttled(XmlErrorMessage),
 }
 
 impl CheckIfPhoneNumberIsOptedOutError {
@@ -2410,25 +2420,27 @@ impl CheckIfPhoneNumberIsOptedOutError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             CheckIfPhoneNumberIsOptedOutError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(
-                            CheckIfPhoneNumberIsOptedOutError::InternalError(parsed_error.message),
+                            CheckIfPhoneNumberIsOptedOutError::InternalError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
                             CheckIfPhoneNumberIsOptedOutError::InvalidParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "Throttled" => {
                         return RusotoError::Service(CheckIfPhoneNumberIsOptedOutError::Throttled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2445,6 +2457,16 @@ impl CheckIfPhoneNumberIsOptedOutError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CheckIfPhoneNumberIsOptedOutError::AuthorizationError(ref cause) => cause.request_id(),
+            CheckIfPhoneNumberIsOptedOutError::InternalError(ref cause) => cause.request_id(),
+            CheckIfPhoneNumberIsOptedOutError::InvalidParameter(ref cause) => cause.request_id(),
+            CheckIfPhoneNumberIsOptedOutError::Throttled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CheckIfPhoneNumberIsOptedOutError {
     #[allow(unused_variables)]
@@ -2466,17 +2488,17 @@ impl Error for CheckIfPhoneNumberIsOptedOutError {}
 #[derive(Debug, PartialEq)]
 pub enum ConfirmSubscriptionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates that the number of filter polices in your AWS account exceeds the limit. To add more filter polices, submit an SNS Limit Increase case in the AWS Support Center.</p>
-    FilterPolicyLimitExceeded(String),
+    FilterPolicyLimitExceeded(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
     /// <p>Indicates that the customer already owns the maximum allowed number of subscriptions.</p>
-    SubscriptionLimitExceeded(String),
+    SubscriptionLimitExceeded(XmlErrorMessage),
 }
 
 impl ConfirmSubscriptionError {
@@ -2489,35 +2511,35 @@ impl ConfirmSubscriptionError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(ConfirmSubscriptionError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "FilterPolicyLimitExceeded" => {
                         return RusotoError::Service(
                             ConfirmSubscriptionError::FilterPolicyLimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(ConfirmSubscriptionError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(ConfirmSubscriptionError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(ConfirmSubscriptionError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "SubscriptionLimitExceeded" => {
                         return RusotoError::Service(
                             ConfirmSubscriptionError::SubscriptionLimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -2535,6 +2557,18 @@ impl ConfirmSubscriptionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ConfirmSubscriptionError::AuthorizationError(ref cause) => cause.request_id(),
+            ConfirmSubscriptionError::FilterPolicyLimitExceeded(ref cause) => cause.request_id(),
+            ConfirmSubscriptionError::InternalError(ref cause) => cause.request_id(),
+            ConfirmSubscriptionError::InvalidParameter(ref cause) => cause.request_id(),
+            ConfirmSubscriptionError::NotFound(ref cause) => cause.request_id(),
+            ConfirmSubscriptionError::SubscriptionLimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ConfirmSubscriptionError {
     #[allow(unused_variables)]
@@ -2558,11 +2592,11 @@ impl Error for ConfirmSubscriptionError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl CreatePlatformApplicationError {
@@ -2576,18 +2610,20 @@ impl CreatePlatformApplicationError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             CreatePlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(CreatePlatformApplicationError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            CreatePlatformApplicationError::InvalidParameter(parsed_error.message),
+                            CreatePlatformApplicationError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -2604,6 +2640,15 @@ impl CreatePlatformApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePlatformApplicationError::AuthorizationError(ref cause) => cause.request_id(),
+            CreatePlatformApplicationError::InternalError(ref cause) => cause.request_id(),
+            CreatePlatformApplicationError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePlatformApplicationError {
     #[allow(unused_variables)]
@@ -2620,13 +2665,13 @@ impl Error for CreatePlatformApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformEndpointError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl CreatePlatformEndpointError {
@@ -2639,22 +2684,24 @@ impl CreatePlatformEndpointError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            CreatePlatformEndpointError::AuthorizationError(parsed_error.message),
+                            CreatePlatformEndpointError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(CreatePlatformEndpointError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(CreatePlatformEndpointError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(CreatePlatformEndpointError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2671,6 +2718,16 @@ impl CreatePlatformEndpointError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreatePlatformEndpointError::AuthorizationError(ref cause) => cause.request_id(),
+            CreatePlatformEndpointError::InternalError(ref cause) => cause.request_id(),
+            CreatePlatformEndpointError::InvalidParameter(ref cause) => cause.request_id(),
+            CreatePlatformEndpointError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreatePlatformEndpointError {
     #[allow(unused_variables)]
@@ -2688,23 +2745,23 @@ impl Error for CreatePlatformEndpointError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Can't perform multiple operations on a tag simultaneously. Perform the operations sequentially.</p>
-    ConcurrentAccess(String),
+    ConcurrentAccess(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>A tag has been added to a resource with the same ARN as a deleted resource. Wait a short while and then retry the operation.</p>
-    StaleTag(String),
+    StaleTag(XmlErrorMessage),
     /// <p>Can't add more than 50 tags to a topic.</p>
-    TagLimitExceeded(String),
+    TagLimitExceeded(XmlErrorMessage),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
-    TagPolicy(String),
+    TagPolicy(XmlErrorMessage),
     /// <p>Indicates that the customer already owns the maximum allowed number of topics.</p>
-    TopicLimitExceeded(String),
+    TopicLimitExceeded(XmlErrorMessage),
 }
 
 impl CreateTopicError {
@@ -2717,47 +2774,47 @@ impl CreateTopicError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(CreateTopicError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConcurrentAccess" => {
                         return RusotoError::Service(CreateTopicError::ConcurrentAccess(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(CreateTopicError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(CreateTopicError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(CreateTopicError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleTag" => {
                         return RusotoError::Service(CreateTopicError::StaleTag(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceeded" => {
                         return RusotoError::Service(CreateTopicError::TagLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagPolicy" => {
                         return RusotoError::Service(CreateTopicError::TagPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TopicLimitExceeded" => {
                         return RusotoError::Service(CreateTopicError::TopicLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2774,6 +2831,21 @@ impl CreateTopicError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateTopicError::AuthorizationError(ref cause) => cause.request_id(),
+            CreateTopicError::ConcurrentAccess(ref cause) => cause.request_id(),
+            CreateTopicError::InternalError(ref cause) => cause.request_id(),
+            CreateTopicError::InvalidParameter(ref cause) => cause.request_id(),
+            CreateTopicError::InvalidSecurity(ref cause) => cause.request_id(),
+            CreateTopicError::StaleTag(ref cause) => cause.request_id(),
+            CreateTopicError::TagLimitExceeded(ref cause) => cause.request_id(),
+            CreateTopicError::TagPolicy(ref cause) => cause.request_id(),
+            CreateTopicError::TopicLimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateTopicError {
     #[allow(unused_variables)]
@@ -2796,11 +2868,11 @@ impl Error for CreateTopicError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl DeleteEndpointError {
@@ -2813,17 +2885,17 @@ impl DeleteEndpointError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(DeleteEndpointError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(DeleteEndpointError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(DeleteEndpointError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2840,6 +2912,15 @@ impl DeleteEndpointError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteEndpointError::AuthorizationError(ref cause) => cause.request_id(),
+            DeleteEndpointError::InternalError(ref cause) => cause.request_id(),
+            DeleteEndpointError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteEndpointError {
     #[allow(unused_variables)]
@@ -2856,11 +2937,11 @@ impl Error for DeleteEndpointError {}
 #[derive(Debug, PartialEq)]
 pub enum DeletePlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl DeletePlatformApplicationError {
@@ -2874,18 +2955,20 @@ impl DeletePlatformApplicationError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             DeletePlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(DeletePlatformApplicationError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            DeletePlatformApplicationError::InvalidParameter(parsed_error.message),
+                            DeletePlatformApplicationError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -2902,6 +2985,15 @@ impl DeletePlatformApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeletePlatformApplicationError::AuthorizationError(ref cause) => cause.request_id(),
+            DeletePlatformApplicationError::InternalError(ref cause) => cause.request_id(),
+            DeletePlatformApplicationError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeletePlatformApplicationError {
     #[allow(unused_variables)]
@@ -2918,19 +3010,19 @@ impl Error for DeletePlatformApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Can't perform multiple operations on a tag simultaneously. Perform the operations sequentially.</p>
-    ConcurrentAccess(String),
+    ConcurrentAccess(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
     /// <p>A tag has been added to a resource with the same ARN as a deleted resource. Wait a short while and then retry the operation.</p>
-    StaleTag(String),
+    StaleTag(XmlErrorMessage),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
-    TagPolicy(String),
+    TagPolicy(XmlErrorMessage),
 }
 
 impl DeleteTopicError {
@@ -2943,37 +3035,37 @@ impl DeleteTopicError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(DeleteTopicError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConcurrentAccess" => {
                         return RusotoError::Service(DeleteTopicError::ConcurrentAccess(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(DeleteTopicError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(DeleteTopicError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(DeleteTopicError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleTag" => {
                         return RusotoError::Service(DeleteTopicError::StaleTag(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagPolicy" => {
                         return RusotoError::Service(DeleteTopicError::TagPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2990,6 +3082,19 @@ impl DeleteTopicError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteTopicError::AuthorizationError(ref cause) => cause.request_id(),
+            DeleteTopicError::ConcurrentAccess(ref cause) => cause.request_id(),
+            DeleteTopicError::InternalError(ref cause) => cause.request_id(),
+            DeleteTopicError::InvalidParameter(ref cause) => cause.request_id(),
+            DeleteTopicError::NotFound(ref cause) => cause.request_id(),
+            DeleteTopicError::StaleTag(ref cause) => cause.request_id(),
+            DeleteTopicError::TagPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteTopicError {
     #[allow(unused_variables)]
@@ -3010,13 +3115,13 @@ impl Error for DeleteTopicError {}
 #[derive(Debug, PartialEq)]
 pub enum GetEndpointAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl GetEndpointAttributesError {
@@ -3029,22 +3134,24 @@ impl GetEndpointAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            GetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                            GetEndpointAttributesError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(GetEndpointAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(GetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(GetEndpointAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3061,6 +3168,16 @@ impl GetEndpointAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetEndpointAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            GetEndpointAttributesError::InternalError(ref cause) => cause.request_id(),
+            GetEndpointAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            GetEndpointAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetEndpointAttributesError {
     #[allow(unused_variables)]
@@ -3078,13 +3195,13 @@ impl Error for GetEndpointAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetPlatformApplicationAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl GetPlatformApplicationAttributesError {
@@ -3100,27 +3217,29 @@ impl GetPlatformApplicationAttributesError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             GetPlatformApplicationAttributesError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(
                             GetPlatformApplicationAttributesError::InternalError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
                             GetPlatformApplicationAttributesError::InvalidParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(
-                            GetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                            GetPlatformApplicationAttributesError::NotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -3137,6 +3256,20 @@ impl GetPlatformApplicationAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetPlatformApplicationAttributesError::AuthorizationError(ref cause) => {
+                cause.request_id()
+            }
+            GetPlatformApplicationAttributesError::InternalError(ref cause) => cause.request_id(),
+            GetPlatformApplicationAttributesError::InvalidParameter(ref cause) => {
+                cause.request_id()
+            }
+            GetPlatformApplicationAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetPlatformApplicationAttributesError {
     #[allow(unused_variables)]
@@ -3160,13 +3293,13 @@ impl Error for GetPlatformApplicationAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetSMSAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
-    Throttled(String),
+    Throttled(XmlErrorMessage),
 }
 
 impl GetSMSAttributesError {
@@ -3179,22 +3312,22 @@ impl GetSMSAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(GetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(GetSMSAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(GetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "Throttled" => {
                         return RusotoError::Service(GetSMSAttributesError::Throttled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3211,6 +3344,16 @@ impl GetSMSAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetSMSAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            GetSMSAttributesError::InternalError(ref cause) => cause.request_id(),
+            GetSMSAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            GetSMSAttributesError::Throttled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetSMSAttributesError {
     #[allow(unused_variables)]
@@ -3228,13 +3371,13 @@ impl Error for GetSMSAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetSubscriptionAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl GetSubscriptionAttributesError {
@@ -3248,23 +3391,25 @@ impl GetSubscriptionAttributesError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             GetSubscriptionAttributesError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(GetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            GetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                            GetSubscriptionAttributesError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(GetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3281,6 +3426,16 @@ impl GetSubscriptionAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetSubscriptionAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            GetSubscriptionAttributesError::InternalError(ref cause) => cause.request_id(),
+            GetSubscriptionAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            GetSubscriptionAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetSubscriptionAttributesError {
     #[allow(unused_variables)]
@@ -3298,15 +3453,15 @@ impl Error for GetSubscriptionAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetTopicAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl GetTopicAttributesError {
@@ -3319,27 +3474,27 @@ impl GetTopicAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(GetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(GetTopicAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(GetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(GetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(GetTopicAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3356,6 +3511,17 @@ impl GetTopicAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetTopicAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            GetTopicAttributesError::InternalError(ref cause) => cause.request_id(),
+            GetTopicAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            GetTopicAttributesError::InvalidSecurity(ref cause) => cause.request_id(),
+            GetTopicAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetTopicAttributesError {
     #[allow(unused_variables)]
@@ -3374,13 +3540,13 @@ impl Error for GetTopicAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointsByPlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl ListEndpointsByPlatformApplicationError {
@@ -3396,27 +3562,29 @@ impl ListEndpointsByPlatformApplicationError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             ListEndpointsByPlatformApplicationError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(
                             ListEndpointsByPlatformApplicationError::InternalError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
                             ListEndpointsByPlatformApplicationError::InvalidParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(
-                            ListEndpointsByPlatformApplicationError::NotFound(parsed_error.message),
+                            ListEndpointsByPlatformApplicationError::NotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -3433,6 +3601,20 @@ impl ListEndpointsByPlatformApplicationError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListEndpointsByPlatformApplicationError::AuthorizationError(ref cause) => {
+                cause.request_id()
+            }
+            ListEndpointsByPlatformApplicationError::InternalError(ref cause) => cause.request_id(),
+            ListEndpointsByPlatformApplicationError::InvalidParameter(ref cause) => {
+                cause.request_id()
+            }
+            ListEndpointsByPlatformApplicationError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListEndpointsByPlatformApplicationError {
     #[allow(unused_variables)]
@@ -3456,13 +3638,13 @@ impl Error for ListEndpointsByPlatformApplicationError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPhoneNumbersOptedOutError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
-    Throttled(String),
+    Throttled(XmlErrorMessage),
 }
 
 impl ListPhoneNumbersOptedOutError {
@@ -3475,22 +3657,26 @@ impl ListPhoneNumbersOptedOutError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            ListPhoneNumbersOptedOutError::AuthorizationError(parsed_error.message),
+                            ListPhoneNumbersOptedOutError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(ListPhoneNumbersOptedOutError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            ListPhoneNumbersOptedOutError::InvalidParameter(parsed_error.message),
+                            ListPhoneNumbersOptedOutError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "Throttled" => {
                         return RusotoError::Service(ListPhoneNumbersOptedOutError::Throttled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3507,6 +3693,16 @@ impl ListPhoneNumbersOptedOutError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPhoneNumbersOptedOutError::AuthorizationError(ref cause) => cause.request_id(),
+            ListPhoneNumbersOptedOutError::InternalError(ref cause) => cause.request_id(),
+            ListPhoneNumbersOptedOutError::InvalidParameter(ref cause) => cause.request_id(),
+            ListPhoneNumbersOptedOutError::Throttled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPhoneNumbersOptedOutError {
     #[allow(unused_variables)]
@@ -3524,11 +3720,11 @@ impl Error for ListPhoneNumbersOptedOutError {}
 #[derive(Debug, PartialEq)]
 pub enum ListPlatformApplicationsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl ListPlatformApplicationsError {
@@ -3541,17 +3737,21 @@ impl ListPlatformApplicationsError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            ListPlatformApplicationsError::AuthorizationError(parsed_error.message),
+                            ListPlatformApplicationsError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(ListPlatformApplicationsError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            ListPlatformApplicationsError::InvalidParameter(parsed_error.message),
+                            ListPlatformApplicationsError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -3568,6 +3768,15 @@ impl ListPlatformApplicationsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListPlatformApplicationsError::AuthorizationError(ref cause) => cause.request_id(),
+            ListPlatformApplicationsError::InternalError(ref cause) => cause.request_id(),
+            ListPlatformApplicationsError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListPlatformApplicationsError {
     #[allow(unused_variables)]
@@ -3584,11 +3793,11 @@ impl Error for ListPlatformApplicationsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl ListSubscriptionsError {
@@ -3601,17 +3810,17 @@ impl ListSubscriptionsError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(ListSubscriptionsError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(ListSubscriptionsError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(ListSubscriptionsError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3628,6 +3837,15 @@ impl ListSubscriptionsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListSubscriptionsError::AuthorizationError(ref cause) => cause.request_id(),
+            ListSubscriptionsError::InternalError(ref cause) => cause.request_id(),
+            ListSubscriptionsError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListSubscriptionsError {
     #[allow(unused_variables)]
@@ -3644,13 +3862,13 @@ impl Error for ListSubscriptionsError {}
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionsByTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl ListSubscriptionsByTopicError {
@@ -3663,22 +3881,26 @@ impl ListSubscriptionsByTopicError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            ListSubscriptionsByTopicError::AuthorizationError(parsed_error.message),
+                            ListSubscriptionsByTopicError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(ListSubscriptionsByTopicError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            ListSubscriptionsByTopicError::InvalidParameter(parsed_error.message),
+                            ListSubscriptionsByTopicError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(ListSubscriptionsByTopicError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3695,6 +3917,16 @@ impl ListSubscriptionsByTopicError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListSubscriptionsByTopicError::AuthorizationError(ref cause) => cause.request_id(),
+            ListSubscriptionsByTopicError::InternalError(ref cause) => cause.request_id(),
+            ListSubscriptionsByTopicError::InvalidParameter(ref cause) => cause.request_id(),
+            ListSubscriptionsByTopicError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListSubscriptionsByTopicError {
     #[allow(unused_variables)]
@@ -3712,15 +3944,15 @@ impl Error for ListSubscriptionsByTopicError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Can't perform multiple operations on a tag simultaneously. Perform the operations sequentially.</p>
-    ConcurrentAccess(String),
+    ConcurrentAccess(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Can't tag resource. Verify that the topic exists.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
-    TagPolicy(String),
+    TagPolicy(XmlErrorMessage),
 }
 
 impl ListTagsForResourceError {
@@ -3733,27 +3965,27 @@ impl ListTagsForResourceError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(ListTagsForResourceError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConcurrentAccess" => {
                         return RusotoError::Service(ListTagsForResourceError::ConcurrentAccess(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagPolicy" => {
                         return RusotoError::Service(ListTagsForResourceError::TagPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3770,6 +4002,17 @@ impl ListTagsForResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTagsForResourceError::AuthorizationError(ref cause) => cause.request_id(),
+            ListTagsForResourceError::ConcurrentAccess(ref cause) => cause.request_id(),
+            ListTagsForResourceError::InvalidParameter(ref cause) => cause.request_id(),
+            ListTagsForResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+            ListTagsForResourceError::TagPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
@@ -3788,11 +4031,11 @@ impl Error for ListTagsForResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum ListTopicsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
 }
 
 impl ListTopicsError {
@@ -3805,17 +4048,17 @@ impl ListTopicsError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(ListTopicsError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(ListTopicsError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(ListTopicsError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3832,6 +4075,15 @@ impl ListTopicsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListTopicsError::AuthorizationError(ref cause) => cause.request_id(),
+            ListTopicsError::InternalError(ref cause) => cause.request_id(),
+            ListTopicsError::InvalidParameter(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListTopicsError {
     #[allow(unused_variables)]
@@ -3848,13 +4100,13 @@ impl Error for ListTopicsError {}
 #[derive(Debug, PartialEq)]
 pub enum OptInPhoneNumberError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
-    Throttled(String),
+    Throttled(XmlErrorMessage),
 }
 
 impl OptInPhoneNumberError {
@@ -3867,22 +4119,22 @@ impl OptInPhoneNumberError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(OptInPhoneNumberError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(OptInPhoneNumberError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(OptInPhoneNumberError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "Throttled" => {
                         return RusotoError::Service(OptInPhoneNumberError::Throttled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3899,6 +4151,16 @@ impl OptInPhoneNumberError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            OptInPhoneNumberError::AuthorizationError(ref cause) => cause.request_id(),
+            OptInPhoneNumberError::InternalError(ref cause) => cause.request_id(),
+            OptInPhoneNumberError::InvalidParameter(ref cause) => cause.request_id(),
+            OptInPhoneNumberError::Throttled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for OptInPhoneNumberError {
     #[allow(unused_variables)]
@@ -3916,33 +4178,33 @@ impl Error for OptInPhoneNumberError {}
 #[derive(Debug, PartialEq)]
 pub enum PublishError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Exception error indicating endpoint disabled.</p>
-    EndpointDisabled(String),
+    EndpointDisabled(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameterValue(String),
+    InvalidParameterValue(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>The ciphertext references a key that doesn't exist or that you don't have access to.</p>
-    KMSAccessDenied(String),
+    KMSAccessDenied(XmlErrorMessage),
     /// <p>The request was rejected because the specified customer master key (CMK) isn't enabled.</p>
-    KMSDisabled(String),
+    KMSDisabled(XmlErrorMessage),
     /// <p>The request was rejected because the state of the specified resource isn't valid for this request. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    KMSInvalidState(String),
+    KMSInvalidState(XmlErrorMessage),
     /// <p>The request was rejected because the specified entity or resource can't be found.</p>
-    KMSNotFound(String),
+    KMSNotFound(XmlErrorMessage),
     /// <p>The AWS access key ID needs a subscription for the service.</p>
-    KMSOptInRequired(String),
+    KMSOptInRequired(XmlErrorMessage),
     /// <p>The request was denied due to request throttling. For more information about throttling, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#requests-per-second">Limits</a> in the <i>AWS Key Management Service Developer Guide.</i> </p>
-    KMSThrottling(String),
+    KMSThrottling(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
     /// <p>Exception error indicating platform application disabled.</p>
-    PlatformApplicationDisabled(String),
+    PlatformApplicationDisabled(XmlErrorMessage),
 }
 
 impl PublishError {
@@ -3955,70 +4217,72 @@ impl PublishError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(PublishError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "EndpointDisabled" => {
                         return RusotoError::Service(PublishError::EndpointDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(PublishError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(PublishError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ParameterValueInvalid" => {
                         return RusotoError::Service(PublishError::InvalidParameterValue(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(PublishError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSAccessDenied" => {
                         return RusotoError::Service(PublishError::KMSAccessDenied(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSDisabled" => {
                         return RusotoError::Service(PublishError::KMSDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSInvalidState" => {
                         return RusotoError::Service(PublishError::KMSInvalidState(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSNotFound" => {
                         return RusotoError::Service(PublishError::KMSNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSOptInRequired" => {
                         return RusotoError::Service(PublishError::KMSOptInRequired(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "KMSThrottling" => {
                         return RusotoError::Service(PublishError::KMSThrottling(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
-                        return RusotoError::Service(PublishError::NotFound(parsed_error.message))
+                        return RusotoError::Service(PublishError::NotFound(
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
+                        ))
                     }
                     "PlatformApplicationDisabled" => {
                         return RusotoError::Service(PublishError::PlatformApplicationDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4035,6 +4299,26 @@ impl PublishError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PublishError::AuthorizationError(ref cause) => cause.request_id(),
+            PublishError::EndpointDisabled(ref cause) => cause.request_id(),
+            PublishError::InternalError(ref cause) => cause.request_id(),
+            PublishError::InvalidParameter(ref cause) => cause.request_id(),
+            PublishError::InvalidParameterValue(ref cause) => cause.request_id(),
+            PublishError::InvalidSecurity(ref cause) => cause.request_id(),
+            PublishError::KMSAccessDenied(ref cause) => cause.request_id(),
+            PublishError::KMSDisabled(ref cause) => cause.request_id(),
+            PublishError::KMSInvalidState(ref cause) => cause.request_id(),
+            PublishError::KMSNotFound(ref cause) => cause.request_id(),
+            PublishError::KMSOptInRequired(ref cause) => cause.request_id(),
+            PublishError::KMSThrottling(ref cause) => cause.request_id(),
+            PublishError::NotFound(ref cause) => cause.request_id(),
+            PublishError::PlatformApplicationDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PublishError {
     #[allow(unused_variables)]
@@ -4062,13 +4346,13 @@ impl Error for PublishError {}
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl RemovePermissionError {
@@ -4081,22 +4365,22 @@ impl RemovePermissionError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(RemovePermissionError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(RemovePermissionError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(RemovePermissionError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(RemovePermissionError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4113,6 +4397,16 @@ impl RemovePermissionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RemovePermissionError::AuthorizationError(ref cause) => cause.request_id(),
+            RemovePermissionError::InternalError(ref cause) => cause.request_id(),
+            RemovePermissionError::InvalidParameter(ref cause) => cause.request_id(),
+            RemovePermissionError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for RemovePermissionError {
     #[allow(unused_variables)]
@@ -4130,13 +4424,13 @@ impl Error for RemovePermissionError {}
 #[derive(Debug, PartialEq)]
 pub enum SetEndpointAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl SetEndpointAttributesError {
@@ -4149,22 +4443,24 @@ impl SetEndpointAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(
-                            SetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                            SetEndpointAttributesError::AuthorizationError(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(SetEndpointAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(SetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(SetEndpointAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4181,6 +4477,16 @@ impl SetEndpointAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetEndpointAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            SetEndpointAttributesError::InternalError(ref cause) => cause.request_id(),
+            SetEndpointAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            SetEndpointAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetEndpointAttributesError {
     #[allow(unused_variables)]
@@ -4198,13 +4504,13 @@ impl Error for SetEndpointAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetPlatformApplicationAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl SetPlatformApplicationAttributesError {
@@ -4220,27 +4526,29 @@ impl SetPlatformApplicationAttributesError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             SetPlatformApplicationAttributesError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(
                             SetPlatformApplicationAttributesError::InternalError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
                             SetPlatformApplicationAttributesError::InvalidParameter(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(
-                            SetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                            SetPlatformApplicationAttributesError::NotFound(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -4257,6 +4565,20 @@ impl SetPlatformApplicationAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetPlatformApplicationAttributesError::AuthorizationError(ref cause) => {
+                cause.request_id()
+            }
+            SetPlatformApplicationAttributesError::InternalError(ref cause) => cause.request_id(),
+            SetPlatformApplicationAttributesError::InvalidParameter(ref cause) => {
+                cause.request_id()
+            }
+            SetPlatformApplicationAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetPlatformApplicationAttributesError {
     #[allow(unused_variables)]
@@ -4280,13 +4602,13 @@ impl Error for SetPlatformApplicationAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetSMSAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
-    Throttled(String),
+    Throttled(XmlErrorMessage),
 }
 
 impl SetSMSAttributesError {
@@ -4299,22 +4621,22 @@ impl SetSMSAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(SetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(SetSMSAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(SetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "Throttled" => {
                         return RusotoError::Service(SetSMSAttributesError::Throttled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4331,6 +4653,16 @@ impl SetSMSAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetSMSAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            SetSMSAttributesError::InternalError(ref cause) => cause.request_id(),
+            SetSMSAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            SetSMSAttributesError::Throttled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetSMSAttributesError {
     #[allow(unused_variables)]
@@ -4348,15 +4680,15 @@ impl Error for SetSMSAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetSubscriptionAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates that the number of filter polices in your AWS account exceeds the limit. To add more filter polices, submit an SNS Limit Increase case in the AWS Support Center.</p>
-    FilterPolicyLimitExceeded(String),
+    FilterPolicyLimitExceeded(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl SetSubscriptionAttributesError {
@@ -4370,30 +4702,32 @@ impl SetSubscriptionAttributesError {
                     "AuthorizationError" => {
                         return RusotoError::Service(
                             SetSubscriptionAttributesError::AuthorizationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "FilterPolicyLimitExceeded" => {
                         return RusotoError::Service(
                             SetSubscriptionAttributesError::FilterPolicyLimitExceeded(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "InternalError" => {
                         return RusotoError::Service(SetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(
-                            SetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                            SetSubscriptionAttributesError::InvalidParameter(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "NotFound" => {
                         return RusotoError::Service(SetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4410,6 +4744,19 @@ impl SetSubscriptionAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetSubscriptionAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            SetSubscriptionAttributesError::FilterPolicyLimitExceeded(ref cause) => {
+                cause.request_id()
+            }
+            SetSubscriptionAttributesError::InternalError(ref cause) => cause.request_id(),
+            SetSubscriptionAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            SetSubscriptionAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetSubscriptionAttributesError {
     #[allow(unused_variables)]
@@ -4430,15 +4777,15 @@ impl Error for SetSubscriptionAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SetTopicAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl SetTopicAttributesError {
@@ -4451,27 +4798,27 @@ impl SetTopicAttributesError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(SetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(SetTopicAttributesError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(SetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(SetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(SetTopicAttributesError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4488,6 +4835,17 @@ impl SetTopicAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetTopicAttributesError::AuthorizationError(ref cause) => cause.request_id(),
+            SetTopicAttributesError::InternalError(ref cause) => cause.request_id(),
+            SetTopicAttributesError::InvalidParameter(ref cause) => cause.request_id(),
+            SetTopicAttributesError::InvalidSecurity(ref cause) => cause.request_id(),
+            SetTopicAttributesError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetTopicAttributesError {
     #[allow(unused_variables)]
@@ -4506,19 +4864,19 @@ impl Error for SetTopicAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum SubscribeError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates that the number of filter polices in your AWS account exceeds the limit. To add more filter polices, submit an SNS Limit Increase case in the AWS Support Center.</p>
-    FilterPolicyLimitExceeded(String),
+    FilterPolicyLimitExceeded(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
     /// <p>Indicates that the customer already owns the maximum allowed number of subscriptions.</p>
-    SubscriptionLimitExceeded(String),
+    SubscriptionLimitExceeded(XmlErrorMessage),
 }
 
 impl SubscribeError {
@@ -4531,35 +4889,37 @@ impl SubscribeError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(SubscribeError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "FilterPolicyLimitExceeded" => {
                         return RusotoError::Service(SubscribeError::FilterPolicyLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(SubscribeError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(SubscribeError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(SubscribeError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SubscribeError::NotFound(parsed_error.message))
+                        return RusotoError::Service(SubscribeError::NotFound(
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
+                        ))
                     }
                     "SubscriptionLimitExceeded" => {
                         return RusotoError::Service(SubscribeError::SubscriptionLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4576,6 +4936,19 @@ impl SubscribeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SubscribeError::AuthorizationError(ref cause) => cause.request_id(),
+            SubscribeError::FilterPolicyLimitExceeded(ref cause) => cause.request_id(),
+            SubscribeError::InternalError(ref cause) => cause.request_id(),
+            SubscribeError::InvalidParameter(ref cause) => cause.request_id(),
+            SubscribeError::InvalidSecurity(ref cause) => cause.request_id(),
+            SubscribeError::NotFound(ref cause) => cause.request_id(),
+            SubscribeError::SubscriptionLimitExceeded(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SubscribeError {
     #[allow(unused_variables)]
@@ -4596,19 +4969,19 @@ impl Error for SubscribeError {}
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Can't perform multiple operations on a tag simultaneously. Perform the operations sequentially.</p>
-    ConcurrentAccess(String),
+    ConcurrentAccess(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Can't tag resource. Verify that the topic exists.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>A tag has been added to a resource with the same ARN as a deleted resource. Wait a short while and then retry the operation.</p>
-    StaleTag(String),
+    StaleTag(XmlErrorMessage),
     /// <p>Can't add more than 50 tags to a topic.</p>
-    TagLimitExceeded(String),
+    TagLimitExceeded(XmlErrorMessage),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
-    TagPolicy(String),
+    TagPolicy(XmlErrorMessage),
 }
 
 impl TagResourceError {
@@ -4621,37 +4994,37 @@ impl TagResourceError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(TagResourceError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConcurrentAccess" => {
                         return RusotoError::Service(TagResourceError::ConcurrentAccess(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(TagResourceError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleTag" => {
                         return RusotoError::Service(TagResourceError::StaleTag(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceeded" => {
                         return RusotoError::Service(TagResourceError::TagLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagPolicy" => {
                         return RusotoError::Service(TagResourceError::TagPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4668,6 +5041,19 @@ impl TagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            TagResourceError::AuthorizationError(ref cause) => cause.request_id(),
+            TagResourceError::ConcurrentAccess(ref cause) => cause.request_id(),
+            TagResourceError::InvalidParameter(ref cause) => cause.request_id(),
+            TagResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+            TagResourceError::StaleTag(ref cause) => cause.request_id(),
+            TagResourceError::TagLimitExceeded(ref cause) => cause.request_id(),
+            TagResourceError::TagPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for TagResourceError {
     #[allow(unused_variables)]
@@ -4688,15 +5074,15 @@ impl Error for TagResourceError {}
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Indicates an internal service error.</p>
-    InternalError(String),
+    InternalError(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>The credential signature isn't valid. You must use an HTTPS endpoint and sign your request using Signature Version 4.</p>
-    InvalidSecurity(String),
+    InvalidSecurity(XmlErrorMessage),
     /// <p>Indicates that the requested resource does not exist.</p>
-    NotFound(String),
+    NotFound(XmlErrorMessage),
 }
 
 impl UnsubscribeError {
@@ -4709,27 +5095,27 @@ impl UnsubscribeError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(UnsubscribeError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InternalError" => {
                         return RusotoError::Service(UnsubscribeError::InternalError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(UnsubscribeError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidSecurity" => {
                         return RusotoError::Service(UnsubscribeError::InvalidSecurity(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "NotFound" => {
                         return RusotoError::Service(UnsubscribeError::NotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4746,6 +5132,17 @@ impl UnsubscribeError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UnsubscribeError::AuthorizationError(ref cause) => cause.request_id(),
+            UnsubscribeError::InternalError(ref cause) => cause.request_id(),
+            UnsubscribeError::InvalidParameter(ref cause) => cause.request_id(),
+            UnsubscribeError::InvalidSecurity(ref cause) => cause.request_id(),
+            UnsubscribeError::NotFound(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UnsubscribeError {
     #[allow(unused_variables)]
@@ -4764,19 +5161,19 @@ impl Error for UnsubscribeError {}
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
-    AuthorizationError(String),
+    AuthorizationError(XmlErrorMessage),
     /// <p>Can't perform multiple operations on a tag simultaneously. Perform the operations sequentially.</p>
-    ConcurrentAccess(String),
+    ConcurrentAccess(XmlErrorMessage),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
-    InvalidParameter(String),
+    InvalidParameter(XmlErrorMessage),
     /// <p>Can't tag resource. Verify that the topic exists.</p>
-    ResourceNotFound(String),
+    ResourceNotFound(XmlErrorMessage),
     /// <p>A tag has been added to a resource with the same ARN as a deleted resource. Wait a short while and then retry the operation.</p>
-    StaleTag(String),
+    StaleTag(XmlErrorMessage),
     /// <p>Can't add more than 50 tags to a topic.</p>
-    TagLimitExceeded(String),
+    TagLimitExceeded(XmlErrorMessage),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
-    TagPolicy(String),
+    TagPolicy(XmlErrorMessage),
 }
 
 impl UntagResourceError {
@@ -4789,37 +5186,37 @@ impl UntagResourceError {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
                         return RusotoError::Service(UntagResourceError::AuthorizationError(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ConcurrentAccess" => {
                         return RusotoError::Service(UntagResourceError::ConcurrentAccess(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidParameter" => {
                         return RusotoError::Service(UntagResourceError::InvalidParameter(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ResourceNotFound" => {
                         return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "StaleTag" => {
                         return RusotoError::Service(UntagResourceError::StaleTag(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagLimitExceeded" => {
                         return RusotoError::Service(UntagResourceError::TagLimitExceeded(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "TagPolicy" => {
                         return RusotoError::Service(UntagResourceError::TagPolicy(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -4836,6 +5233,19 @@ impl UntagResourceError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            UntagResourceError::AuthorizationError(ref cause) => cause.request_id(),
+            UntagResourceError::ConcurrentAccess(ref cause) => cause.request_id(),
+            UntagResourceError::InvalidParameter(ref cause) => cause.request_id(),
+            UntagResourceError::ResourceNotFound(ref cause) => cause.request_id(),
+            UntagResourceError::StaleTag(ref cause) => cause.request_id(),
+            UntagResourceError::TagLimitExceeded(ref cause) => cause.request_id(),
+            UntagResourceError::TagPolicy(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for UntagResourceError {
     #[allow(unused_variables)]
@@ -0,0 +1,458 @@
+use std::fmt;
+
+use rusoto_core::retry::{is_throttling_error, RetryPolicy};
+use rusoto_core::RusotoError;
+use tokio::time;
+
+use crate::generated::{
+    BatchResultErrorEntry, SendMessageBatchError, SendMessageBatchRequest,
+    SendMessageBatchRequestEntry, SendMessageBatchResultEntry, Sqs, SqsClient,
+};
+
+/// The most entries `send_message_batch` accepts at once.
+const MAX_BATCH_ENTRIES: usize = 10;
+/// The most bytes of messages `send_message_batch` accepts at once, which is also the
+/// maximum size of a message.
+const MAX_BATCH_BYTES: usize = 256 * 1024;
+
+/// Why `SqsClient::send_messages` couldn't send a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SendMessageFailure {
+    /// The message is larger than the 256 KiB SQS accepts, and wasn't sent.
+    TooLarge(usize),
+    /// SQS failed the entry of the message, because of the message itself if `sender_fault`
+    /// is set, or still after the last retry otherwise.
+    Entry(BatchResultErrorEntry),
+    /// The `send_message_batch` call sending the message failed.
+    Request(String),
+    /// SQS accepted the message, but the MD5 digest of the body it returned doesn't match the
+    /// body sent, so it may have received it corrupted.
+    Md5Mismatch {
+        /// The entry SQS returned for the message.
+        entry: Box<SendMessageBatchResultEntry>,
+        /// The MD5 digest of the body sent, in hex.
+        expected: String,
+    },
+}
+
+impl fmt::Display for SendMessageFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SendMessageFailure::TooLarge(size) => write!(
+                f,
+                "message of {} bytes is larger than the {} bytes SQS accepts",
+                size, MAX_BATCH_BYTES
+            ),
+            SendMessageFailure::Entry(ref entry) => match entry.message {
+                Some(ref message) => write!(f, "{}: {}", entry.code, message),
+                None => f.write_str(&entry.code),
+            },
+            SendMessageFailure::Request(ref message) => f.write_str(message),
+            SendMessageFailure::Md5Mismatch {
+                ref entry,
+                ref expected,
+            } => write!(
+                f,
+                "MD5 of message body {} returned by SQS doesn't match the body sent, {}",
+                entry.md5_of_message_body, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SendMessageFailure {}
+
+impl SqsClient {
+    /// Sends any number of messages to the queue at `queue_url` with `send_message_batch`,
+    /// and returns the result of every message, in the order of `messages`.
+    ///
+    /// Messages are sent in batches of up to 10 messages and 256 KiB, counting the body and
+    /// the names, types and values of the message attributes. The `id` of each entry is only
+    /// used to fill in the `id` of its result, so it needn't be unique.
+    ///
+    /// Entries SQS failed without it being the sender's fault, and batches that were throttled
+    /// or couldn't be sent, are retried after the delays of `policy`, until they're sent or
+    /// were sent `policy.max_attempts()` times. The MD5 digests of the bodies SQS returns are
+    /// checked, mismatches fail with `SendMessageFailure::Md5Mismatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::retry::RetryPolicy;
+    /// use rusoto_core::Region;
+    /// use rusoto_sqs::{SendMessageBatchRequestEntry, SqsClient};
+    ///
+    /// # async fn example() {
+    /// let sqs = SqsClient::new(Region::UsEast1);
+    /// let messages = (0..100).map(|n| SendMessageBatchRequestEntry {
+    ///     id: n.to_string(),
+    ///     message_body: format!("message {}", n),
+    ///     ..Default::default()
+    /// });
+    /// let results = sqs
+    ///     .send_messages("https://queue.url", messages, RetryPolicy::new())
+    ///     .await;
+    /// for result in results {
+    ///     if let Err(failure) = result {
+    ///         eprintln!("failed to send a message: {}", failure);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn send_messages<I>(
+        &self,
+        queue_url: &str,
+        messages: I,
+        policy: RetryPolicy,
+    ) -> Vec<Result<SendMessageBatchResultEntry, SendMessageFailure>>
+    where
+        I: IntoIterator<Item = SendMessageBatchRequestEntry>,
+    {
+        let messages: Vec<_> = messages.into_iter().collect();
+        let sizes: Vec<_> = messages.iter().map(message_size).collect();
+        let mut results: Vec<Option<Result<_, _>>> = sizes
+            .iter()
+            .map(|&size| {
+                if size > MAX_BATCH_BYTES {
+                    Some(Err(SendMessageFailure::TooLarge(size)))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        for batch in batches(&sizes) {
+            let mut pending = batch;
+            let mut attempt = 1;
+            loop {
+                let entries = pending
+                    .iter()
+                    .map(|&index| SendMessageBatchRequestEntry {
+                        id: index.to_string(),
+                        ..messages[index].clone()
+                    })
+                    .collect();
+                let request = SendMessageBatchRequest {
+                    entries,
+                    queue_url: queue_url.to_owned(),
+                };
+                let retry = match self.send_message_batch(request).await {
+                    Ok(result) => {
+                        for mut entry in result.successful {
+                            if let Some(index) = batch_index(&entry.id, &pending) {
+                                entry.id = messages[index].id.clone();
+                                let expected = md5_hex(&messages[index].message_body);
+                                let result =
+                                    if entry.md5_of_message_body.eq_ignore_ascii_case(&expected) {
+                                        Ok(entry)
+                                    } else {
+                                        Err(SendMessageFailure::Md5Mismatch {
+                                            entry: Box::new(entry),
+                                            expected,
+                                        })
+                                    };
+                                results[index] = Some(result);
+                            }
+                        }
+                        let mut retry = Vec::new();
+                        for mut entry in result.failed {
+                            if let Some(index) = batch_index(&entry.id, &pending) {
+                                if !entry.sender_fault {
+                                    retry.push(index);
+                                }
+                                entry.id = messages[index].id.clone();
+                                results[index] = Some(Err(SendMessageFailure::Entry(entry)));
+                            }
+                        }
+                        retry
+                    }
+                    Err(err) => {
+                        let failure = SendMessageFailure::Request(err.to_string());
+                        for &index in &pending {
+                            results[index] = Some(Err(failure.clone()));
+                        }
+                        if is_retryable(&err) {
+                            pending.clone()
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                };
+                if retry.is_empty() || attempt >= policy.max_attempts() {
+                    break;
+                }
+                time::delay_for(policy.delay(attempt)).await;
+                attempt += 1;
+                pending = retry;
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    Err(SendMessageFailure::Request(
+                        "SQS returned no result for the message".to_owned(),
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Whether a failed `send_message_batch` call is sent again.
+fn is_retryable(err: &RusotoError<SendMessageBatchError>) -> bool {
+    match *err {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(ref response) => response.status.is_server_error(),
+        ref err => is_throttling_error(err),
+    }
+}
+
+/// The index of the message an entry of a batch was sent for, if it's one of `pending`.
+fn batch_index(id: &str, pending: &[usize]) -> Option<usize> {
+    id.parse().ok().filter(|index| pending.contains(index))
+}
+
+/// The size SQS counts for a message: its body, and the name, type and value of each of its
+/// message attributes.
+fn message_size(entry: &SendMessageBatchRequestEntry) -> usize {
+    let attributes = entry.message_attributes.iter().flatten();
+    entry.message_body.len()
+        + attributes
+            .map(|(name, value)| {
+                name.len()
+                    + value.data_type.len()
+                    + value.string_value.as_ref().map_or(0, String::len)
+                    + value.binary_value.as_ref().map_or(0, |value| value.len())
+                    + value
+                        .string_list_values
+                        .iter()
+                        .flatten()
+                        .map(String::len)
+                        .sum::<usize>()
+                    + value
+                        .binary_list_values
+                        .iter()
+                        .flatten()
+                        .map(|value| value.len())
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+}
+
+/// Splits the messages of `sizes` that aren't too large into batches of indices, in order.
+fn batches(sizes: &[usize]) -> Vec<Vec<usize>> {
+    let mut batches = Vec::new();
+    let mut batch = Vec::new();
+    let mut bytes = 0;
+    for (index, &size) in sizes.iter().enumerate() {
+        if size > MAX_BATCH_BYTES {
+            continue;
+        }
+        if batch.len() == MAX_BATCH_ENTRIES || bytes + size > MAX_BATCH_BYTES {
+            batches.push(std::mem::take(&mut batch));
+            bytes = 0;
+        }
+        batch.push(index);
+        bytes += size;
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+fn md5_hex(body: &str) -> String {
+    format!("{:x}", md5::compute(body.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::MessageAttributeValue;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use std::collections::HashMap;
+
+    fn message(body: &str) -> SendMessageBatchRequestEntry {
+        SendMessageBatchRequestEntry {
+            id: format!("id-{}", body),
+            message_body: body.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// The bodies of the entries of a `send_message_batch` request, by entry ID.
+    fn sent_bodies(request: &SignedRequest) -> Vec<(String, String)> {
+        let params: HashMap<String, String> = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => {
+                serde_urlencoded::from_bytes(body).unwrap()
+            }
+            _ => panic!("expected a buffered request body"),
+        };
+        let mut entries: Vec<_> = (1..)
+            .map(|n| format!("SendMessageBatchRequestEntry.{}.", n))
+            .take_while(|prefix| params.contains_key(&format!("{}Id", prefix)))
+            .map(|prefix| {
+                (
+                    params[&format!("{}Id", prefix)].clone(),
+                    params[&format!("{}MessageBody", prefix)].clone(),
+                )
+            })
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    fn sent(id: usize, body: &str) -> String {
+        format!(
+            "<SendMessageBatchResultEntry><Id>{}</Id><MessageId>m{}</MessageId>\
+             <MD5OfMessageBody>{}</MD5OfMessageBody></SendMessageBatchResultEntry>",
+            id,
+            id,
+            md5_hex(body)
+        )
+    }
+
+    fn failed(id: usize, sender_fault: bool) -> String {
+        format!(
+            "<BatchResultErrorEntry><Id>{}</Id><Code>InternalError</Code>\
+             <SenderFault>{}</SenderFault></BatchResultErrorEntry>",
+            id, sender_fault
+        )
+    }
+
+    fn responding(expected: Vec<(usize, &str)>, entries: &[String]) -> MockRequestDispatcher {
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(id, body)| (id.to_string(), body.to_owned()))
+            .collect::<Vec<_>>();
+        let body = format!(
+            "<SendMessageBatchResponse><SendMessageBatchResult>{}</SendMessageBatchResult>\
+             <ResponseMetadata><RequestId>id</RequestId></ResponseMetadata>\
+             </SendMessageBatchResponse>",
+            entries.concat()
+        );
+        MockRequestDispatcher::with_status(200)
+            .with_body(&body)
+            .with_request_checker(move |request| {
+                let mut expected = expected.clone();
+                expected.sort();
+                assert_eq!(sent_bodies(request), expected);
+            })
+    }
+
+    #[test]
+    fn message_sizes_count_attributes() {
+        let mut attributes = HashMap::new();
+        attributes.insert(
+            "name".to_owned(),
+            MessageAttributeValue {
+                data_type: "String".to_owned(),
+                string_value: Some("value".to_owned()),
+                ..Default::default()
+            },
+        );
+        attributes.insert(
+            "blob".to_owned(),
+            MessageAttributeValue {
+                data_type: "Binary".to_owned(),
+                binary_value: Some(vec![0; 3].into()),
+                ..Default::default()
+            },
+        );
+        let entry = SendMessageBatchRequestEntry {
+            message_attributes: Some(attributes),
+            ..message("body")
+        };
+
+        assert_eq!(message_size(&entry), 4 + (4 + 6 + 5) + (4 + 6 + 3));
+    }
+
+    #[test]
+    fn batches_respect_entry_and_byte_limits() {
+        assert_eq!(
+            batches(&[1; 12]),
+            vec![(0..10).collect::<Vec<_>>(), vec![10, 11]]
+        );
+        let kib = 1024;
+        assert_eq!(
+            batches(&[100 * kib, 100 * kib, 100 * kib, 300 * kib, 56 * kib]),
+            vec![vec![0, 1], vec![2, 4]]
+        );
+    }
+
+    #[tokio::test]
+    async fn send_messages_retries_failed_entries() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            responding(
+                vec![(0, "a"), (1, "b"), (2, "c"), (3, "d")],
+                &[
+                    failed(0, false),
+                    sent(1, "b"),
+                    failed(2, true),
+                    sent(3, "tampered"),
+                ],
+            ),
+            responding(vec![(0, "a")], &[failed(0, false)]),
+            responding(vec![(0, "a")], &[sent(0, "a")]),
+        ]);
+        let client = SqsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let messages = vec![message("a"), message("b"), message("c"), message("d")];
+
+        let results = client
+            .send_messages(
+                "https://queue.url",
+                messages,
+                RetryPolicy::new()
+                    .with_max_attempts(3)
+                    .with_base_delay(std::time::Duration::from_millis(1)),
+            )
+            .await;
+
+        assert_eq!(results.len(), 4);
+        let sent = results[0].as_ref().unwrap();
+        assert_eq!((sent.id.as_str(), sent.message_id.as_str()), ("id-a", "m0"));
+        assert_eq!(results[1].as_ref().unwrap().message_id, "m1");
+        match results[2] {
+            Err(SendMessageFailure::Entry(ref entry)) => {
+                assert_eq!(entry.id, "id-c");
+                assert!(entry.sender_fault);
+            }
+            ref other => panic!("expected a failed entry, got {:?}", other),
+        }
+        match results[3] {
+            Err(SendMessageFailure::Md5Mismatch { ref expected, .. }) => {
+                assert_eq!(*expected, md5_hex("d"))
+            }
+            ref other => panic!("expected an MD5 mismatch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_messages_skips_messages_too_large() {
+        let body = "x".repeat(MAX_BATCH_BYTES + 1);
+        let client = SqsClient::new_with(
+            MockRequestDispatcher::with_status(500),
+            MockCredentialsProvider,
+            Region::UsEast1,
+        );
+
+        let results = client
+            .send_messages(
+                "https://queue.url",
+                vec![message(&body)],
+                RetryPolicy::never(),
+            )
+            .await;
+
+        assert_eq!(
+            results,
+            vec![Err(SendMessageFailure::TooLarge(MAX_BATCH_BYTES + 1))]
+        );
+    }
+}
@@ -0,0 +1,665 @@
+//! A consumer of the messages of a queue, see `SqsConsumer`.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::future::{self, Either};
+use futures::stream::{self, Stream};
+use rusoto_core::retry::RetryPolicy;
+use rusoto_core::RusotoError;
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time;
+
+use crate::generated::{
+    ChangeMessageVisibilityRequest, DeleteMessageBatchRequest, DeleteMessageBatchRequestEntry,
+    Message, ReceiveMessageError, ReceiveMessageRequest, Sqs,
+};
+
+/// The most messages `receive_message` returns, and `delete_message_batch` deletes, at once.
+const MAX_BATCH_SIZE: usize = 10;
+
+type SharedSqs = Arc<dyn Sqs + Send + Sync>;
+
+/// Receives the messages of a queue with long polling, and deletes them in batches once
+/// they're acknowledged.
+///
+/// `messages` streams the messages received, while at most `max_in_flight` of them are
+/// checked out: received, but neither acknowledged with `ReceivedMessage::ack` nor dropped.
+/// With a visibility timeout set, the visibility of checked out messages is extended before
+/// it runs out, so slow messages aren't received again meanwhile.
+///
+/// Acknowledged messages are deleted by a task running in the background, in batches of up
+/// to 10 messages. Deletes failing with a server error are retried with the backoff of the
+/// consumer's retry policy; those that still fail, or fail with a client error like an
+/// expired receipt handle, are returned by `take_failed_deletes` and `shutdown`.
+///
+/// A consumer must be created within a Tokio runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_sqs::{SqsClient, SqsConsumer};
+///
+/// # async fn example() {
+/// let consumer = SqsConsumer::new(SqsClient::new(Region::UsEast1), "https://queue.url")
+///     .with_max_in_flight(20)
+///     .with_visibility_timeout(Duration::from_secs(60));
+/// {
+///     let messages = consumer.messages();
+///     futures::pin_mut!(messages);
+///     while let Some(message) = messages.try_next().await.unwrap() {
+///         println!("{:?}", message.body);
+///         message.ack();
+///     }
+/// }
+/// let failed = consumer.shutdown().await;
+/// assert!(failed.is_empty(), "{:?}", failed);
+/// # }
+/// ```
+pub struct SqsConsumer {
+    sqs: SharedSqs,
+    queue_url: String,
+    max_in_flight: usize,
+    wait_time: Duration,
+    visibility_timeout: Option<Duration>,
+    in_flight: Arc<Semaphore>,
+    acks: mpsc::UnboundedSender<Ack>,
+    acker: JoinHandle<()>,
+    failed_deletes: Arc<Mutex<Vec<FailedDelete>>>,
+}
+
+/// A message acknowledged for deletion.
+struct Ack {
+    message_id: Option<String>,
+    receipt_handle: String,
+}
+
+impl SqsConsumer {
+    /// Creates a consumer of the queue at `queue_url`, with at most 10 messages in flight,
+    /// long polling for 20 seconds, the queue's visibility timeout and `RetryPolicy::new()`
+    /// retrying deletes.
+    pub fn new<C, S>(sqs: C, queue_url: S) -> SqsConsumer
+    where
+        C: Sqs + Send + Sync + 'static,
+        S: Into<String>,
+    {
+        SqsConsumer::with_retry_policy(sqs, queue_url, RetryPolicy::new())
+    }
+
+    /// Creates a consumer like `new`, retrying failed deletes with `policy`. Only its maximum
+    /// number of attempts and its delays are used.
+    pub fn with_retry_policy<C, S>(sqs: C, queue_url: S, policy: RetryPolicy) -> SqsConsumer
+    where
+        C: Sqs + Send + Sync + 'static,
+        S: Into<String>,
+    {
+        let sqs: SharedSqs = Arc::new(sqs);
+        let queue_url = queue_url.into();
+        let failed_deletes = Arc::new(Mutex::new(Vec::new()));
+        let (acks, received) = mpsc::unbounded_channel();
+        let acker = tokio::spawn(delete_acked(
+            sqs.clone(),
+            queue_url.clone(),
+            received,
+            policy,
+            failed_deletes.clone(),
+        ));
+        SqsConsumer {
+            sqs,
+            queue_url,
+            max_in_flight: MAX_BATCH_SIZE,
+            wait_time: Duration::from_secs(20),
+            visibility_timeout: None,
+            in_flight: Arc::new(Semaphore::new(MAX_BATCH_SIZE)),
+            acks,
+            acker,
+            failed_deletes,
+        }
+    }
+
+    /// Sets the maximum number of messages checked out at once, at least 1.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self.in_flight = Arc::new(Semaphore::new(self.max_in_flight));
+        self
+    }
+
+    /// Sets how long a `receive_message` call waits for messages to arrive, at most 20
+    /// seconds.
+    pub fn with_wait_time(mut self, wait_time: Duration) -> Self {
+        self.wait_time = wait_time.min(Duration::from_secs(20));
+        self
+    }
+
+    /// Receives messages with a visibility timeout of `timeout`, whole seconds of at least
+    /// one, and extends it by as much every half of it while a message is checked out.
+    pub fn with_visibility_timeout(mut self, timeout: Duration) -> Self {
+        self.visibility_timeout = Some(timeout.max(Duration::from_secs(1)));
+        self
+    }
+
+    /// Streams the messages of the queue, receiving more while fewer than `max_in_flight` are
+    /// checked out.
+    ///
+    /// Errors of `receive_message` are streamed too, and polling the stream again receives
+    /// again.
+    pub fn messages(
+        &self,
+    ) -> impl Stream<Item = Result<ReceivedMessage, RusotoError<ReceiveMessageError>>> + '_ {
+        stream::unfold(VecDeque::new(), move |mut received| async move {
+            loop {
+                let permit = self.in_flight.clone().acquire_owned().await;
+                if let Some(message) = received.pop_front() {
+                    return Some((Ok(self.check_out(message, permit)), received));
+                }
+                let available = 1 + self.in_flight.available_permits();
+                match self.receive(available.min(MAX_BATCH_SIZE)).await {
+                    Ok(messages) => received.extend(messages),
+                    Err(err) => return Some((Err(err), received)),
+                }
+            }
+        })
+    }
+
+    /// Returns the deletes that failed since this was last called, without waiting for those
+    /// in progress.
+    pub fn take_failed_deletes(&self) -> Vec<FailedDelete> {
+        take_failed(&self.failed_deletes)
+    }
+
+    /// Waits for all messages to be acknowledged or dropped and their deletes to end, and
+    /// returns the deletes that failed and weren't taken with `take_failed_deletes`.
+    pub async fn shutdown(self) -> Vec<FailedDelete> {
+        let SqsConsumer {
+            acks,
+            acker,
+            failed_deletes,
+            ..
+        } = self;
+        drop(acks);
+        // The task only ends early if it panicked, leaving its failures reported so far.
+        let _ = acker.await;
+        take_failed(&failed_deletes)
+    }
+
+    async fn receive(
+        &self,
+        max_messages: usize,
+    ) -> Result<Vec<Message>, RusotoError<ReceiveMessageError>> {
+        let request = ReceiveMessageRequest {
+            queue_url: self.queue_url.clone(),
+            max_number_of_messages: Some(max_messages as i64),
+            wait_time_seconds: Some(self.wait_time.as_secs() as i64),
+            visibility_timeout: self.visibility_timeout.map(|t| t.as_secs() as i64),
+            ..Default::default()
+        };
+        let result = self.sqs.receive_message(request).await?;
+        Ok(result.messages.unwrap_or_default())
+    }
+
+    fn check_out(&self, message: Message, permit: OwnedSemaphorePermit) -> ReceivedMessage {
+        let extension = match (self.visibility_timeout, &message.receipt_handle) {
+            (Some(timeout), Some(receipt_handle)) => {
+                let (stop, stopped) = oneshot::channel();
+                tokio::spawn(extend_visibility(
+                    self.sqs.clone(),
+                    ChangeMessageVisibilityRequest {
+                        queue_url: self.queue_url.clone(),
+                        receipt_handle: receipt_handle.clone(),
+                        visibility_timeout: timeout.as_secs() as i64,
+                    },
+                    stopped,
+                ));
+                Some(stop)
+            }
+            _ => None,
+        };
+        ReceivedMessage {
+            message,
+            acks: self.acks.clone(),
+            _extension: extension,
+            _permit: permit,
+        }
+    }
+}
+
+impl fmt::Debug for SqsConsumer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SqsConsumer")
+            .field("queue_url", &self.queue_url)
+            .field("max_in_flight", &self.max_in_flight)
+            .field("wait_time", &self.wait_time)
+            .field("visibility_timeout", &self.visibility_timeout)
+            .finish()
+    }
+}
+
+/// A message checked out of an `SqsConsumer`, which dereferences to the `Message`.
+///
+/// Acknowledging it with `ack` deletes it from the queue. Dropping it without acknowledging
+/// it stops extending its visibility, so it's received again once its visibility timeout
+/// runs out.
+pub struct ReceivedMessage {
+    message: Message,
+    acks: mpsc::UnboundedSender<Ack>,
+    /// Extends the visibility of the message until dropped.
+    _extension: Option<oneshot::Sender<()>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl ReceivedMessage {
+    /// Acknowledges the message, which the consumer then deletes with its next batch.
+    pub fn ack(self) {
+        if let Some(ref receipt_handle) = self.message.receipt_handle {
+            // The consumer outlives its messages' acks, so sending can't fail.
+            let _ = self.acks.send(Ack {
+                message_id: self.message.message_id.clone(),
+                receipt_handle: receipt_handle.clone(),
+            });
+        }
+    }
+
+    /// Returns the message, without acknowledging it.
+    pub fn into_inner(self) -> Message {
+        self.message
+    }
+}
+
+impl std::ops::Deref for ReceivedMessage {
+    type Target = Message;
+
+    fn deref(&self) -> &Message {
+        &self.message
+    }
+}
+
+impl fmt::Debug for ReceivedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ReceivedMessage")
+            .field(&self.message)
+            .finish()
+    }
+}
+
+/// A message an `SqsConsumer` couldn't delete.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedDelete {
+    /// The ID of the message.
+    pub message_id: Option<String>,
+    /// The receipt handle the message was received with.
+    pub receipt_handle: String,
+    /// Why the delete failed: the error code and message of the batch entry, or the error of
+    /// the `delete_message_batch` call.
+    pub reason: String,
+}
+
+/// Extends the visibility of a message every half of its timeout, until `stopped` ends or
+/// an extension fails.
+async fn extend_visibility(
+    sqs: SharedSqs,
+    request: ChangeMessageVisibilityRequest,
+    mut stopped: oneshot::Receiver<()>,
+) {
+    let period = Duration::from_secs(request.visibility_timeout as u64) / 2;
+    loop {
+        let delay = time::delay_for(period);
+        match future::select(delay, stopped).await {
+            Either::Left((_, still_running)) => stopped = still_running,
+            Either::Right(_) => return,
+        }
+        if sqs
+            .change_message_visibility(request.clone())
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Deletes the messages acknowledged through `acks` in batches, until all senders are gone.
+async fn delete_acked(
+    sqs: SharedSqs,
+    queue_url: String,
+    mut acks: mpsc::UnboundedReceiver<Ack>,
+    policy: RetryPolicy,
+    failed_deletes: Arc<Mutex<Vec<FailedDelete>>>,
+) {
+    while let Some(ack) = acks.recv().await {
+        let mut batch = vec![ack];
+        while batch.len() < MAX_BATCH_SIZE {
+            match acks.try_recv() {
+                Ok(ack) => batch.push(ack),
+                Err(_) => break,
+            }
+        }
+        let failed = delete_batch(&*sqs, &queue_url, batch, &policy).await;
+        if !failed.is_empty() {
+            match failed_deletes.lock() {
+                Ok(mut failed_deletes) => failed_deletes.extend(failed),
+                Err(poisoned) => poisoned.into_inner().extend(failed),
+            }
+        }
+    }
+}
+
+/// Deletes `batch`, retrying the entries that failed with a server error, and returns those
+/// that couldn't be deleted.
+async fn delete_batch(
+    sqs: &(dyn Sqs + Send + Sync),
+    queue_url: &str,
+    batch: Vec<Ack>,
+    policy: &RetryPolicy,
+) -> Vec<FailedDelete> {
+    let mut failed = Vec::new();
+    let mut pending = batch;
+    let mut attempt = 1;
+    loop {
+        let entries = pending
+            .iter()
+            .enumerate()
+            .map(|(id, ack)| DeleteMessageBatchRequestEntry {
+                id: id.to_string(),
+                receipt_handle: ack.receipt_handle.clone(),
+            })
+            .collect();
+        let request = DeleteMessageBatchRequest {
+            entries,
+            queue_url: queue_url.to_owned(),
+        };
+        let mut retry = Vec::new();
+        match sqs.delete_message_batch(request).await {
+            Ok(result) => {
+                let mut acks: Vec<_> = pending.into_iter().map(Some).collect();
+                for entry in result.failed {
+                    let ack = match entry.id.parse().ok().and_then(|id: usize| acks.get_mut(id)) {
+                        Some(ack) => ack.take(),
+                        None => None,
+                    };
+                    let ack = match ack {
+                        Some(ack) => ack,
+                        None => continue,
+                    };
+                    let reason = match entry.message {
+                        Some(message) => format!("{}: {}", entry.code, message),
+                        None => entry.code,
+                    };
+                    if entry.sender_fault {
+                        failed.push(failed_delete(ack, reason));
+                    } else {
+                        retry.push((ack, reason));
+                    }
+                }
+            }
+            Err(err) => {
+                let reason = err.to_string();
+                retry.extend(pending.into_iter().map(|ack| (ack, reason.clone())));
+            }
+        }
+
+        if retry.is_empty() {
+            return failed;
+        }
+        if attempt >= policy.max_attempts() {
+            failed.extend(
+                retry
+                    .into_iter()
+                    .map(|(ack, reason)| failed_delete(ack, reason)),
+            );
+            return failed;
+        }
+        time::delay_for(policy.delay(attempt)).await;
+        attempt += 1;
+        pending = retry.into_iter().map(|(ack, _)| ack).collect();
+    }
+}
+
+fn take_failed(failed_deletes: &Mutex<Vec<FailedDelete>>) -> Vec<FailedDelete> {
+    match failed_deletes.lock() {
+        Ok(mut failed) => mem::take(&mut *failed),
+        Err(poisoned) => mem::take(&mut *poisoned.into_inner()),
+    }
+}
+
+fn failed_delete(ack: Ack, reason: String) -> FailedDelete {
+    FailedDelete {
+        message_id: ack.message_id,
+        receipt_handle: ack.receipt_handle,
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SqsClient;
+    use futures::{StreamExt, TryStreamExt};
+    use rusoto_core::request::{DispatchSignedRequest, DispatchSignedRequestFuture};
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::collections::HashMap;
+
+    /// A queue of messages `m<n>` with the receipt handles `r<n>`, answering deletes with the
+    /// responses given, or deleting all entries once they ran out.
+    #[derive(Clone, Default)]
+    struct FakeQueue(Arc<Mutex<FakeState>>);
+
+    #[derive(Default)]
+    struct FakeState {
+        messages: VecDeque<u32>,
+        delete_responses: VecDeque<String>,
+        requests: Vec<HashMap<String, String>>,
+    }
+
+    impl FakeQueue {
+        fn new(messages: u32, delete_responses: Vec<String>) -> FakeQueue {
+            let queue = FakeQueue::default();
+            {
+                let mut state = queue.0.lock().unwrap();
+                state.messages = (1..=messages).collect();
+                state.delete_responses = delete_responses.into();
+            }
+            queue
+        }
+
+        /// The parameters of the requests sent with `action`.
+        fn requests(&self, action: &str) -> Vec<HashMap<String, String>> {
+            let state = self.0.lock().unwrap();
+            state
+                .requests
+                .iter()
+                .filter(|params| params["Action"] == action)
+                .cloned()
+                .collect()
+        }
+    }
+
+    impl DispatchSignedRequest for FakeQueue {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let params: HashMap<String, String> = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref body)) => {
+                    serde_urlencoded::from_bytes(body).unwrap()
+                }
+                _ => panic!("expected a buffered request body"),
+            };
+            let mut state = self.0.lock().unwrap();
+            state.requests.push(params.clone());
+            let body = match params["Action"].as_str() {
+                "ReceiveMessage" => {
+                    let max: usize = params["MaxNumberOfMessages"].parse().unwrap();
+                    let count = max.min(state.messages.len());
+                    let messages: String = state
+                        .messages
+                        .drain(..count)
+                        .map(|n| {
+                            format!(
+                                "<Message><MessageId>m{n}</MessageId>\
+                                 <ReceiptHandle>r{n}</ReceiptHandle><Body>{n}</Body></Message>",
+                                n = n
+                            )
+                        })
+                        .collect();
+                    response("ReceiveMessage", &messages)
+                }
+                "DeleteMessageBatch" => state
+                    .delete_responses
+                    .pop_front()
+                    .unwrap_or_else(|| response("DeleteMessageBatch", "")),
+                action => response(action, ""),
+            };
+            MockRequestDispatcher::with_status(200)
+                .with_body(&body)
+                .dispatch(request, timeout)
+        }
+    }
+
+    fn response(action: &str, result: &str) -> String {
+        format!(
+            "<{action}Response><{action}Result>{result}</{action}Result>\
+             <ResponseMetadata><RequestId>id</RequestId></ResponseMetadata></{action}Response>",
+            action = action,
+            result = result
+        )
+    }
+
+    fn failed_entry(id: u32, code: &str, sender_fault: bool) -> String {
+        format!(
+            "<BatchResultErrorEntry><Id>{}</Id><Code>{}</Code><Message>failed</Message>\
+             <SenderFault>{}</SenderFault></BatchResultErrorEntry>",
+            id, code, sender_fault
+        )
+    }
+
+    fn consumer(queue: &FakeQueue) -> SqsConsumer {
+        let client = SqsClient::new_with(queue.clone(), MockCredentialsProvider, Region::UsEast1);
+        SqsConsumer::with_retry_policy(
+            client,
+            "https://queue.url",
+            RetryPolicy::new()
+                .with_max_attempts(2)
+                .with_base_delay(Duration::from_millis(1)),
+        )
+    }
+
+    fn receipt_handles(request: &HashMap<String, String>) -> Vec<String> {
+        let mut handles: Vec<_> = request
+            .iter()
+            .filter(|(name, _)| name.ends_with(".ReceiptHandle"))
+            .map(|(_, handle)| handle.clone())
+            .collect();
+        handles.sort();
+        handles
+    }
+
+    #[tokio::test]
+    async fn acked_messages_are_deleted_in_batches() {
+        let queue = FakeQueue::new(
+            3,
+            vec![response(
+                "DeleteMessageBatch",
+                &failed_entry(1, "InternalError", false),
+            )],
+        );
+        let consumer = consumer(&queue);
+
+        let messages: Vec<_> = consumer.messages().take(3).try_collect().await.unwrap();
+        let bodies: Vec<_> = messages.iter().map(|m| m.body.clone().unwrap()).collect();
+        assert_eq!(bodies, vec!["1", "2", "3"]);
+        for message in messages {
+            message.ack();
+        }
+
+        assert_eq!(consumer.shutdown().await, vec![]);
+        let deletes = queue.requests("DeleteMessageBatch");
+        assert_eq!(receipt_handles(&deletes[0]), vec!["r1", "r2", "r3"]);
+        assert_eq!(receipt_handles(&deletes[1]), vec!["r2"]);
+        assert_eq!(deletes.len(), 2);
+
+        let receives = queue.requests("ReceiveMessage");
+        assert_eq!(receives[0]["MaxNumberOfMessages"], "10");
+        assert_eq!(receives[0]["WaitTimeSeconds"], "20");
+    }
+
+    #[tokio::test]
+    async fn failed_deletes_are_surfaced() {
+        let queue = FakeQueue::new(
+            2,
+            vec![
+                response(
+                    "DeleteMessageBatch",
+                    &(failed_entry(0, "ReceiptHandleIsInvalid", true)
+                        + &failed_entry(1, "InternalError", false)),
+                ),
+                response(
+                    "DeleteMessageBatch",
+                    &failed_entry(0, "InternalError", false),
+                ),
+            ],
+        );
+        let consumer = consumer(&queue);
+
+        let messages: Vec<_> = consumer.messages().take(2).try_collect().await.unwrap();
+        for message in messages {
+            message.ack();
+        }
+
+        let mut failed = consumer.shutdown().await;
+        failed.sort_by(|a, b| a.receipt_handle.cmp(&b.receipt_handle));
+        assert_eq!(
+            failed,
+            vec![
+                FailedDelete {
+                    message_id: Some("m1".to_owned()),
+                    receipt_handle: "r1".to_owned(),
+                    reason: "ReceiptHandleIsInvalid: failed".to_owned(),
+                },
+                FailedDelete {
+                    message_id: Some("m2".to_owned()),
+                    receipt_handle: "r2".to_owned(),
+                    reason: "InternalError: failed".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn in_flight_messages_are_limited_and_extended() {
+        let queue = FakeQueue::new(2, Vec::new());
+        let consumer = consumer(&queue)
+            .with_max_in_flight(1)
+            .with_visibility_timeout(Duration::from_secs(1));
+
+        let messages = consumer.messages();
+        futures::pin_mut!(messages);
+        let first = messages.try_next().await.unwrap().unwrap();
+        time::delay_for(Duration::from_millis(700)).await;
+        let extensions = queue.requests("ChangeMessageVisibility");
+        assert_eq!(extensions.len(), 1);
+        assert_eq!(extensions[0]["ReceiptHandle"], "r1");
+        assert_eq!(extensions[0]["VisibilityTimeout"], "1");
+
+        let second = messages.next();
+        futures::pin_mut!(second);
+        assert!(futures::poll!(second.as_mut()).is_pending());
+        drop(first);
+        let second = second.await.unwrap().unwrap();
+        assert_eq!(second.receipt_handle.as_deref(), Some("r2"));
+
+        let receives = queue.requests("ReceiveMessage");
+        assert!(receives.iter().all(|r| r["MaxNumberOfMessages"] == "1"));
+        assert_eq!(receives[0]["VisibilityTimeout"], "1");
+    }
+}
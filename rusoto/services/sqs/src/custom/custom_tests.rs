@@ -10,7 +10,7 @@ use self::rusoto_mock::*;
 use rusoto_core::param::Params;
 use rusoto_core::signature::SignedRequest;
 use rusoto_core::signature::SignedRequestPayload;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{Region, RusotoError, XmlErrorMessage};
 use serde_urlencoded;
 
 #[tokio::test]
@@ -174,7 +174,8 @@ async fn test_parse_queue_does_not_exist_error() {
     let err = result.err().unwrap();
     assert_eq!(
         RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-            "The specified queue does not exist for this wsdl version.".to_owned()
+            XmlErrorMessage::new("The specified queue does not exist for this wsdl version.")
+                .with_request_id("8f8f9957-c0d9-536a-9ca6-ca7483be06ad")
         )),
         err
     );
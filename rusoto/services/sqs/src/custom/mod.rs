@@ -1,2 +1,8 @@
+mod batch;
+mod consumer;
+
+pub use self::batch::SendMessageFailure;
+pub use self::consumer::{FailedDelete, ReceivedMessage, SqsConsumer};
+
 #[cfg(test)]
 mod custom_tests;
@@ -52,7 +52,7 @@ impl SqsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2143,7 +2143,7 @@ impl UntagQueueRequestSerializer {
 #[derive(Debug, PartialEq)]
 pub enum AddPermissionError {
     /// <p>The specified action violates a limit. For example, <code>ReceiveMessage</code> returns this error if the maximum number of inflight messages is reached and <code>AddPermission</code> returns this error if the maximum number of permissions for the queue is reached.</p>
-    OverLimit(String),
+    OverLimit(XmlErrorMessage),
 }
 
 impl AddPermissionError {
@@ -2156,7 +2156,7 @@ impl AddPermissionError {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
                         return RusotoError::Service(AddPermissionError::OverLimit(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2173,6 +2173,13 @@ impl AddPermissionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AddPermissionError::OverLimit(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AddPermissionError {
     #[allow(unused_variables)]
@@ -2187,9 +2194,9 @@ impl Error for AddPermissionError {}
 #[derive(Debug, PartialEq)]
 pub enum ChangeMessageVisibilityError {
     /// <p>The specified message isn't in flight.</p>
-    MessageNotInflight(String),
+    MessageNotInflight(XmlErrorMessage),
     /// <p>The specified receipt handle isn't valid.</p>
-    ReceiptHandleIsInvalid(String),
+    ReceiptHandleIsInvalid(XmlErrorMessage),
 }
 
 impl ChangeMessageVisibilityError {
@@ -2202,13 +2209,15 @@ impl ChangeMessageVisibilityError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.MessageNotInflight" => {
                         return RusotoError::Service(
-                            ChangeMessageVisibilityError::MessageNotInflight(parsed_error.message),
+                            ChangeMessageVisibilityError::MessageNotInflight(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "ReceiptHandleIsInvalid" => {
                         return RusotoError::Service(
                             ChangeMessageVisibilityError::ReceiptHandleIsInvalid(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -2226,6 +2235,14 @@ impl ChangeMessageVisibilityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ChangeMessageVisibilityError::MessageNotInflight(ref cause) => cause.request_id(),
+            ChangeMessageVisibilityError::ReceiptHandleIsInvalid(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ChangeMessageVisibilityError {
     #[allow(unused_variables)]
@@ -2243,13 +2260,13 @@ impl Error for ChangeMessageVisibilityError {}
 #[derive(Debug, PartialEq)]
 pub enum ChangeMessageVisibilityBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
-    BatchEntryIdsNotDistinct(String),
+    BatchEntryIdsNotDistinct(XmlErrorMessage),
     /// <p>The batch request doesn't contain any entries.</p>
-    EmptyBatchRequest(String),
+    EmptyBatchRequest(XmlErrorMessage),
     /// <p>The <code>Id</code> of a batch entry in a batch request doesn't abide by the specification.</p>
-    InvalidBatchEntryId(String),
+    InvalidBatchEntryId(XmlErrorMessage),
     /// <p>The batch request contains more entries than permissible.</p>
-    TooManyEntriesInBatchRequest(String),
+    TooManyEntriesInBatchRequest(XmlErrorMessage),
 }
 
 impl ChangeMessageVisibilityBatchError {
@@ -2265,28 +2282,28 @@ impl ChangeMessageVisibilityBatchError {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
                         return RusotoError::Service(
                             ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
                         return RusotoError::Service(
                             ChangeMessageVisibilityBatchError::EmptyBatchRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
                         return RusotoError::Service(
                             ChangeMessageVisibilityBatchError::InvalidBatchEntryId(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
                         return RusotoError::Service(
                             ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -2304,6 +2321,20 @@ impl ChangeMessageVisibilityBatchError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(ref cause) => {
+                cause.request_id()
+            }
+            ChangeMessageVisibilityBatchError::EmptyBatchRequest(ref cause) => cause.request_id(),
+            ChangeMessageVisibilityBatchError::InvalidBatchEntryId(ref cause) => cause.request_id(),
+            ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for ChangeMessageVisibilityBatchError {
     #[allow(unused_variables)]
@@ -2329,9 +2360,9 @@ impl Error for ChangeMessageVisibilityBatchError {}
 #[derive(Debug, PartialEq)]
 pub enum CreateQueueError {
     /// <p>You must wait 60 seconds after deleting a queue before you can create another queue with the same name.</p>
-    QueueDeletedRecently(String),
+    QueueDeletedRecently(XmlErrorMessage),
     /// <p>A queue with this name already exists. Amazon SQS returns this error only if the request includes attributes whose values differ from those of the existing queue.</p>
-    QueueNameExists(String),
+    QueueNameExists(XmlErrorMessage),
 }
 
 impl CreateQueueError {
@@ -2344,12 +2375,12 @@ impl CreateQueueError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.QueueDeletedRecently" => {
                         return RusotoError::Service(CreateQueueError::QueueDeletedRecently(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "QueueAlreadyExists" => {
                         return RusotoError::Service(CreateQueueError::QueueNameExists(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2366,6 +2397,14 @@ impl CreateQueueError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            CreateQueueError::QueueDeletedRecently(ref cause) => cause.request_id(),
+            CreateQueueError::QueueNameExists(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for CreateQueueError {
     #[allow(unused_variables)]
@@ -2381,9 +2420,9 @@ impl Error for CreateQueueError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteMessageError {
     /// <p>The specified receipt handle isn't valid for the current version.</p>
-    InvalidIdFormat(String),
+    InvalidIdFormat(XmlErrorMessage),
     /// <p>The specified receipt handle isn't valid.</p>
-    ReceiptHandleIsInvalid(String),
+    ReceiptHandleIsInvalid(XmlErrorMessage),
 }
 
 impl DeleteMessageError {
@@ -2396,12 +2435,12 @@ impl DeleteMessageError {
                 match &parsed_error.code[..] {
                     "InvalidIdFormat" => {
                         return RusotoError::Service(DeleteMessageError::InvalidIdFormat(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "ReceiptHandleIsInvalid" => {
                         return RusotoError::Service(DeleteMessageError::ReceiptHandleIsInvalid(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2418,6 +2457,14 @@ impl DeleteMessageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteMessageError::InvalidIdFormat(ref cause) => cause.request_id(),
+            DeleteMessageError::ReceiptHandleIsInvalid(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteMessageError {
     #[allow(unused_variables)]
@@ -2433,13 +2480,13 @@ impl Error for DeleteMessageError {}
 #[derive(Debug, PartialEq)]
 pub enum DeleteMessageBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
-    BatchEntryIdsNotDistinct(String),
+    BatchEntryIdsNotDistinct(XmlErrorMessage),
     /// <p>The batch request doesn't contain any entries.</p>
-    EmptyBatchRequest(String),
+    EmptyBatchRequest(XmlErrorMessage),
     /// <p>The <code>Id</code> of a batch entry in a batch request doesn't abide by the specification.</p>
-    InvalidBatchEntryId(String),
+    InvalidBatchEntryId(XmlErrorMessage),
     /// <p>The batch request contains more entries than permissible.</p>
-    TooManyEntriesInBatchRequest(String),
+    TooManyEntriesInBatchRequest(XmlErrorMessage),
 }
 
 impl DeleteMessageBatchError {
@@ -2452,23 +2499,25 @@ impl DeleteMessageBatchError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
                         return RusotoError::Service(
-                            DeleteMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                            DeleteMessageBatchError::BatchEntryIdsNotDistinct(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
                         return RusotoError::Service(DeleteMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
                         return RusotoError::Service(DeleteMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
                         return RusotoError::Service(
                             DeleteMessageBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -2486,6 +2535,16 @@ impl DeleteMessageBatchError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DeleteMessageBatchError::BatchEntryIdsNotDistinct(ref cause) => cause.request_id(),
+            DeleteMessageBatchError::EmptyBatchRequest(ref cause) => cause.request_id(),
+            DeleteMessageBatchError::InvalidBatchEntryId(ref cause) => cause.request_id(),
+            DeleteMessageBatchError::TooManyEntriesInBatchRequest(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for DeleteMessageBatchError {
     #[allow(unused_variables)]
@@ -2527,6 +2586,11 @@ impl DeleteQueueError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for DeleteQueueError {
     #[allow(unused_variables)]
@@ -2539,7 +2603,7 @@ impl Error for DeleteQueueError {}
 #[derive(Debug, PartialEq)]
 pub enum GetQueueAttributesError {
     /// <p>The specified attribute doesn't exist.</p>
-    InvalidAttributeName(String),
+    InvalidAttributeName(XmlErrorMessage),
 }
 
 impl GetQueueAttributesError {
@@ -2552,7 +2616,7 @@ impl GetQueueAttributesError {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
                         return RusotoError::Service(GetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2569,6 +2633,13 @@ impl GetQueueAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetQueueAttributesError::InvalidAttributeName(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetQueueAttributesError {
     #[allow(unused_variables)]
@@ -2583,7 +2654,7 @@ impl Error for GetQueueAttributesError {}
 #[derive(Debug, PartialEq)]
 pub enum GetQueueUrlError {
     /// <p>The specified queue doesn't exist.</p>
-    QueueDoesNotExist(String),
+    QueueDoesNotExist(XmlErrorMessage),
 }
 
 impl GetQueueUrlError {
@@ -2596,7 +2667,7 @@ impl GetQueueUrlError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
                         return RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2613,6 +2684,13 @@ impl GetQueueUrlError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetQueueUrlError::QueueDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetQueueUrlError {
     #[allow(unused_variables)]
@@ -2627,7 +2705,7 @@ impl Error for GetQueueUrlError {}
 #[derive(Debug, PartialEq)]
 pub enum ListDeadLetterSourceQueuesError {
     /// <p>The specified queue doesn't exist.</p>
-    QueueDoesNotExist(String),
+    QueueDoesNotExist(XmlErrorMessage),
 }
 
 impl ListDeadLetterSourceQueuesError {
@@ -2643,7 +2721,7 @@ impl ListDeadLetterSourceQueuesError {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
                         return RusotoError::Service(
                             ListDeadLetterSourceQueuesError::QueueDoesNotExist(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -2661,6 +2739,13 @@ impl ListDeadLetterSourceQueuesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ListDeadLetterSourceQueuesError::QueueDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ListDeadLetterSourceQueuesError {
     #[allow(unused_variables)]
@@ -2697,6 +2782,11 @@ impl ListQueueTagsError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListQueueTagsError {
     #[allow(unused_variables)]
@@ -2731,6 +2821,11 @@ impl ListQueuesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for ListQueuesError {
     #[allow(unused_variables)]
@@ -2743,9 +2838,9 @@ impl Error for ListQueuesError {}
 #[derive(Debug, PartialEq)]
 pub enum PurgeQueueError {
     /// <p>Indicates that the specified queue previously received a <code>PurgeQueue</code> request within the last 60 seconds (the time it can take to delete the messages in the queue).</p>
-    PurgeQueueInProgress(String),
+    PurgeQueueInProgress(XmlErrorMessage),
     /// <p>The specified queue doesn't exist.</p>
-    QueueDoesNotExist(String),
+    QueueDoesNotExist(XmlErrorMessage),
 }
 
 impl PurgeQueueError {
@@ -2758,12 +2853,12 @@ impl PurgeQueueError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.PurgeQueueInProgress" => {
                         return RusotoError::Service(PurgeQueueError::PurgeQueueInProgress(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.NonExistentQueue" => {
                         return RusotoError::Service(PurgeQueueError::QueueDoesNotExist(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2780,6 +2875,14 @@ impl PurgeQueueError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            PurgeQueueError::PurgeQueueInProgress(ref cause) => cause.request_id(),
+            PurgeQueueError::QueueDoesNotExist(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for PurgeQueueError {
     #[allow(unused_variables)]
@@ -2795,7 +2898,7 @@ impl Error for PurgeQueueError {}
 #[derive(Debug, PartialEq)]
 pub enum ReceiveMessageError {
     /// <p>The specified action violates a limit. For example, <code>ReceiveMessage</code> returns this error if the maximum number of inflight messages is reached and <code>AddPermission</code> returns this error if the maximum number of permissions for the queue is reached.</p>
-    OverLimit(String),
+    OverLimit(XmlErrorMessage),
 }
 
 impl ReceiveMessageError {
@@ -2808,7 +2911,7 @@ impl ReceiveMessageError {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
                         return RusotoError::Service(ReceiveMessageError::OverLimit(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2825,6 +2928,13 @@ impl ReceiveMessageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            ReceiveMessageError::OverLimit(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for ReceiveMessageError {
     #[allow(unused_variables)]
@@ -2861,6 +2971,11 @@ impl RemovePermissionError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for RemovePermissionError {
     #[allow(unused_variables)]
@@ -2873,9 +2988,9 @@ impl Error for RemovePermissionError {}
 #[derive(Debug, PartialEq)]
 pub enum SendMessageError {
     /// <p>The message contains characters outside the allowed set.</p>
-    InvalidMessageContents(String),
+    InvalidMessageContents(XmlErrorMessage),
     /// <p>Error code 400. Unsupported operation.</p>
-    UnsupportedOperation(String),
+    UnsupportedOperation(XmlErrorMessage),
 }
 
 impl SendMessageError {
@@ -2888,12 +3003,12 @@ impl SendMessageError {
                 match &parsed_error.code[..] {
                     "InvalidMessageContents" => {
                         return RusotoError::Service(SendMessageError::InvalidMessageContents(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
                         return RusotoError::Service(SendMessageError::UnsupportedOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2910,6 +3025,14 @@ impl SendMessageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendMessageError::InvalidMessageContents(ref cause) => cause.request_id(),
+            SendMessageError::UnsupportedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendMessageError {
     #[allow(unused_variables)]
@@ -2925,17 +3048,17 @@ impl Error for SendMessageError {}
 #[derive(Debug, PartialEq)]
 pub enum SendMessageBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
-    BatchEntryIdsNotDistinct(String),
+    BatchEntryIdsNotDistinct(XmlErrorMessage),
     /// <p>The length of all the messages put together is more than the limit.</p>
-    BatchRequestTooLong(String),
+    BatchRequestTooLong(XmlErrorMessage),
     /// <p>The batch request doesn't contain any entries.</p>
-    EmptyBatchRequest(String),
+    EmptyBatchRequest(XmlErrorMessage),
     /// <p>The <code>Id</code> of a batch entry in a batch request doesn't abide by the specification.</p>
-    InvalidBatchEntryId(String),
+    InvalidBatchEntryId(XmlErrorMessage),
     /// <p>The batch request contains more entries than permissible.</p>
-    TooManyEntriesInBatchRequest(String),
+    TooManyEntriesInBatchRequest(XmlErrorMessage),
     /// <p>Error code 400. Unsupported operation.</p>
-    UnsupportedOperation(String),
+    UnsupportedOperation(XmlErrorMessage),
 }
 
 impl SendMessageBatchError {
@@ -2948,34 +3071,36 @@ impl SendMessageBatchError {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
                         return RusotoError::Service(
-                            SendMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                            SendMessageBatchError::BatchEntryIdsNotDistinct(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "AWS.SimpleQueueService.BatchRequestTooLong" => {
                         return RusotoError::Service(SendMessageBatchError::BatchRequestTooLong(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
                         return RusotoError::Service(SendMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
                         return RusotoError::Service(SendMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
                         return RusotoError::Service(
                             SendMessageBatchError::TooManyEntriesInBatchRequest(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
                         return RusotoError::Service(SendMessageBatchError::UnsupportedOperation(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -2992,6 +3117,18 @@ impl SendMessageBatchError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SendMessageBatchError::BatchEntryIdsNotDistinct(ref cause) => cause.request_id(),
+            SendMessageBatchError::BatchRequestTooLong(ref cause) => cause.request_id(),
+            SendMessageBatchError::EmptyBatchRequest(ref cause) => cause.request_id(),
+            SendMessageBatchError::InvalidBatchEntryId(ref cause) => cause.request_id(),
+            SendMessageBatchError::TooManyEntriesInBatchRequest(ref cause) => cause.request_id(),
+            SendMessageBatchError::UnsupportedOperation(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SendMessageBatchError {
     #[allow(unused_variables)]
@@ -3013,7 +3150,7 @@ impl Error for SendMessageBatchError {}
 #[derive(Debug, PartialEq)]
 pub enum SetQueueAttributesError {
     /// <p>The specified attribute doesn't exist.</p>
-    InvalidAttributeName(String),
+    InvalidAttributeName(XmlErrorMessage),
 }
 
 impl SetQueueAttributesError {
@@ -3026,7 +3163,7 @@ impl SetQueueAttributesError {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
                         return RusotoError::Service(SetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -3043,6 +3180,13 @@ impl SetQueueAttributesError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            SetQueueAttributesError::InvalidAttributeName(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for SetQueueAttributesError {
     #[allow(unused_variables)]
@@ -3079,6 +3223,11 @@ impl TagQueueError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for TagQueueError {
     #[allow(unused_variables)]
@@ -3113,6 +3262,11 @@ impl UntagQueueError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for UntagQueueError {
     #[allow(unused_variables)]
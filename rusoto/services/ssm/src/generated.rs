@@ -41,7 +41,7 @@ impl SsmClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -15544,7 +15544,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, AddTagsToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResult, _>()
     }
 
@@ -15562,7 +15562,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CancelCommandError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelCommandResult, _>()
     }
 
@@ -15586,7 +15586,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CancelMaintenanceWindowExecutionResult, _>()
     }
@@ -15605,7 +15605,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateActivationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateActivationResult, _>()
     }
 
@@ -15623,7 +15623,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateAssociationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAssociationResult, _>()
     }
 
@@ -15641,7 +15641,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateAssociationBatchError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateAssociationBatchResult, _>()
     }
@@ -15660,7 +15660,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateDocumentResult, _>()
     }
 
@@ -15678,7 +15678,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateMaintenanceWindowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateMaintenanceWindowResult, _>()
     }
@@ -15697,7 +15697,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateOpsItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateOpsItemResponse, _>()
     }
 
@@ -15715,7 +15715,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreatePatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreatePatchBaselineResult, _>()
     }
 
@@ -15733,7 +15733,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, CreateResourceDataSyncError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateResourceDataSyncResult, _>()
     }
@@ -15752,7 +15752,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteActivationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteActivationResult, _>()
     }
 
@@ -15770,7 +15770,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteAssociationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAssociationResult, _>()
     }
 
@@ -15788,7 +15788,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteDocumentResult, _>()
     }
 
@@ -15806,7 +15806,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteInventoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteInventoryResult, _>()
     }
 
@@ -15824,7 +15824,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteMaintenanceWindowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteMaintenanceWindowResult, _>()
     }
@@ -15843,7 +15843,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteParameterResult, _>()
     }
 
@@ -15861,7 +15861,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteParametersResult, _>()
     }
 
@@ -15879,7 +15879,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeletePatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeletePatchBaselineResult, _>()
     }
 
@@ -15897,7 +15897,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeleteResourceDataSyncError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteResourceDataSyncResult, _>()
     }
@@ -15916,7 +15916,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DeregisterManagedInstanceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterManagedInstanceResult, _>()
     }
@@ -15944,7 +15944,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterPatchBaselineForPatchGroupResult, _>()
     }
@@ -15972,7 +15972,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterTargetFromMaintenanceWindowResult, _>()
     }
@@ -16000,7 +16000,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterTaskFromMaintenanceWindowResult, _>()
     }
@@ -16019,7 +16019,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeActivationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeActivationsResult, _>()
     }
 
@@ -16037,7 +16037,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeAssociationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAssociationResult, _>()
     }
 
@@ -16064,7 +16064,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAssociationExecutionTargetsResult, _>()
     }
@@ -16084,7 +16084,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeAssociationExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAssociationExecutionsResult, _>()
     }
@@ -16104,7 +16104,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeAutomationExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAutomationExecutionsResult, _>()
     }
@@ -16129,7 +16129,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAutomationStepExecutionsResult, _>()
     }
@@ -16148,7 +16148,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeAvailablePatchesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAvailablePatchesResult, _>()
     }
@@ -16167,7 +16167,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeDocumentResult, _>()
     }
 
@@ -16186,7 +16186,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeDocumentPermissionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeDocumentPermissionResponse, _>()
     }
@@ -16214,7 +16214,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEffectiveInstanceAssociationsResult, _>()
     }
@@ -16242,7 +16242,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeEffectivePatchesForPatchBaselineResult, _>()
     }
@@ -16270,7 +16270,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInstanceAssociationsStatusResult, _>()
     }
@@ -16290,7 +16290,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeInstanceInformationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInstanceInformationResult, _>()
     }
@@ -16310,7 +16310,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeInstancePatchStatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInstancePatchStatesResult, _>()
     }
@@ -16338,7 +16338,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInstancePatchStatesForPatchGroupResult, _>()
     }
@@ -16357,7 +16357,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeInstancePatchesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInstancePatchesResult, _>()
     }
@@ -16377,7 +16377,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeInventoryDeletionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeInventoryDeletionsResult, _>()
     }
@@ -16405,7 +16405,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowExecutionTaskInvocationsResult, _>()
     }
@@ -16433,7 +16433,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowExecutionTasksResult, _>()
     }
@@ -16461,7 +16461,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowExecutionsResult, _>()
     }
@@ -16489,7 +16489,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowScheduleResult, _>()
     }
@@ -16514,7 +16514,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowTargetsResult, _>()
     }
@@ -16536,7 +16536,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeMaintenanceWindowTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowTasksResult, _>()
     }
@@ -16556,7 +16556,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeMaintenanceWindowsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowsResult, _>()
     }
@@ -16584,7 +16584,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceWindowsForTargetResult, _>()
     }
@@ -16603,7 +16603,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeOpsItemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeOpsItemsResponse, _>()
     }
 
@@ -16621,7 +16621,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeParametersResult, _>()
     }
 
@@ -16639,7 +16639,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribePatchBaselinesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePatchBaselinesResult, _>()
     }
@@ -16658,7 +16658,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribePatchGroupStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePatchGroupStateResult, _>()
     }
@@ -16677,7 +16677,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribePatchGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribePatchGroupsResult, _>()
     }
 
@@ -16695,7 +16695,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribePatchPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribePatchPropertiesResult, _>()
     }
@@ -16714,7 +16714,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, DescribeSessionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSessionsResponse, _>()
     }
 
@@ -16732,7 +16732,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetAutomationExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAutomationExecutionResult, _>()
     }
@@ -16751,7 +16751,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetCalendarStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCalendarStateResponse, _>()
     }
 
@@ -16769,7 +16769,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetCommandInvocationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetCommandInvocationResult, _>()
     }
 
@@ -16787,7 +16787,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetConnectionStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionStatusResponse, _>()
     }
 
@@ -16805,7 +16805,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetDefaultPatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDefaultPatchBaselineResult, _>()
     }
@@ -16833,7 +16833,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDeployablePatchSnapshotForInstanceResult, _>()
     }
@@ -16852,7 +16852,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDocumentResult, _>()
     }
 
@@ -16870,7 +16870,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetInventoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInventoryResult, _>()
     }
 
@@ -16888,7 +16888,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetInventorySchemaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetInventorySchemaResult, _>()
     }
 
@@ -16906,7 +16906,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetMaintenanceWindowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMaintenanceWindowResult, _>()
     }
 
@@ -16925,7 +16925,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetMaintenanceWindowExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMaintenanceWindowExecutionResult, _>()
     }
@@ -16953,7 +16953,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMaintenanceWindowExecutionTaskResult, _>()
     }
@@ -16981,7 +16981,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMaintenanceWindowExecutionTaskInvocationResult, _>()
     }
@@ -17000,7 +17000,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetMaintenanceWindowTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMaintenanceWindowTaskResult, _>()
     }
@@ -17019,7 +17019,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetOpsItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOpsItemResponse, _>()
     }
 
@@ -17037,7 +17037,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetOpsSummaryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetOpsSummaryResult, _>()
     }
 
@@ -17055,7 +17055,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetParameterResult, _>()
     }
 
@@ -17073,7 +17073,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetParameterHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetParameterHistoryResult, _>()
     }
 
@@ -17091,7 +17091,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetParametersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetParametersResult, _>()
     }
 
@@ -17109,7 +17109,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetParametersByPathError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetParametersByPathResult, _>()
     }
 
@@ -17127,7 +17127,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetPatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPatchBaselineResult, _>()
     }
 
@@ -17146,7 +17146,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetPatchBaselineForPatchGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetPatchBaselineForPatchGroupResult, _>()
     }
@@ -17165,7 +17165,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, GetServiceSettingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetServiceSettingResult, _>()
     }
 
@@ -17183,7 +17183,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, LabelParameterVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<LabelParameterVersionResult, _>()
     }
 
@@ -17201,7 +17201,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListAssociationVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAssociationVersionsResult, _>()
     }
@@ -17220,7 +17220,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListAssociationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAssociationsResult, _>()
     }
 
@@ -17238,7 +17238,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListCommandInvocationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListCommandInvocationsResult, _>()
     }
@@ -17257,7 +17257,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListCommandsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListCommandsResult, _>()
     }
 
@@ -17275,7 +17275,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListComplianceItemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListComplianceItemsResult, _>()
     }
 
@@ -17293,7 +17293,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListComplianceSummariesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListComplianceSummariesResult, _>()
     }
@@ -17312,7 +17312,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListDocumentVersionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDocumentVersionsResult, _>()
     }
 
@@ -17330,7 +17330,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListDocumentsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListDocumentsResult, _>()
     }
 
@@ -17348,7 +17348,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListInventoryEntriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListInventoryEntriesResult, _>()
     }
 
@@ -17369,7 +17369,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListResourceComplianceSummariesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResourceComplianceSummariesResult, _>()
     }
@@ -17388,7 +17388,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListResourceDataSyncError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourceDataSyncResult, _>()
     }
 
@@ -17406,7 +17406,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResult, _>()
     }
 
@@ -17424,7 +17424,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ModifyDocumentPermissionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyDocumentPermissionResponse, _>()
     }
@@ -17443,7 +17443,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, PutComplianceItemsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutComplianceItemsResult, _>()
     }
 
@@ -17461,7 +17461,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, PutInventoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutInventoryResult, _>()
     }
 
@@ -17479,7 +17479,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, PutParameterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutParameterResult, _>()
     }
 
@@ -17498,7 +17498,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, RegisterDefaultPatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterDefaultPatchBaselineResult, _>()
     }
@@ -17526,7 +17526,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterPatchBaselineForPatchGroupResult, _>()
     }
@@ -17554,7 +17554,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterTargetWithMaintenanceWindowResult, _>()
     }
@@ -17582,7 +17582,7 @@ impl Ssm for SsmClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterTaskWithMaintenanceWindowResult, _>()
     }
@@ -17601,7 +17601,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, RemoveTagsFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveTagsFromResourceResult, _>()
     }
@@ -17620,7 +17620,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ResetServiceSettingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResetServiceSettingResult, _>()
     }
 
@@ -17638,7 +17638,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, ResumeSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResumeSessionResponse, _>()
     }
 
@@ -17656,7 +17656,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, SendAutomationSignalError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendAutomationSignalResult, _>()
     }
 
@@ -17674,7 +17674,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, SendCommandError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendCommandResult, _>()
     }
 
@@ -17692,7 +17692,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, StartAssociationsOnceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartAssociationsOnceResult, _>()
     }
 
@@ -17710,7 +17710,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, StartAutomationExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartAutomationExecutionResult, _>()
     }
@@ -17729,7 +17729,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, StartSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartSessionResponse, _>()
     }
 
@@ -17747,7 +17747,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, StopAutomationExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopAutomationExecutionResult, _>()
     }
@@ -17766,7 +17766,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, TerminateSessionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TerminateSessionResponse, _>()
     }
 
@@ -17784,7 +17784,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateAssociationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateAssociationResult, _>()
     }
 
@@ -17802,7 +17802,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateAssociationStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateAssociationStatusResult, _>()
     }
@@ -17821,7 +17821,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDocumentResult, _>()
     }
 
@@ -17840,7 +17840,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateDocumentDefaultVersionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateDocumentDefaultVersionResult, _>()
     }
@@ -17859,7 +17859,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateMaintenanceWindowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMaintenanceWindowResult, _>()
     }
@@ -17879,7 +17879,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateMaintenanceWindowTargetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMaintenanceWindowTargetResult, _>()
     }
@@ -17899,7 +17899,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateMaintenanceWindowTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMaintenanceWindowTaskResult, _>()
     }
@@ -17918,7 +17918,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateManagedInstanceRoleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateManagedInstanceRoleResult, _>()
     }
@@ -17937,7 +17937,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateOpsItemError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateOpsItemResponse, _>()
     }
 
@@ -17955,7 +17955,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdatePatchBaselineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePatchBaselineResult, _>()
     }
 
@@ -17973,7 +17973,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateResourceDataSyncError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateResourceDataSyncResult, _>()
     }
@@ -17992,7 +17992,7 @@ impl Ssm for SsmClient {
             .sign_and_dispatch(request, UpdateServiceSettingError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceSettingResult, _>()
     }
 }
@@ -441,6 +441,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/token";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("CreateToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -453,13 +454,13 @@ impl SsoOidc for SsoOidcClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateTokenResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateTokenError::from_response(response))
         }
     }
@@ -473,6 +474,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/client/register";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("RegisterClient");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -485,13 +487,13 @@ impl SsoOidc for SsoOidcClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterClientResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RegisterClientError::from_response(response))
         }
     }
@@ -505,6 +507,7 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/device_authorization";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("StartDeviceAuthorization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -517,13 +520,13 @@ impl SsoOidc for SsoOidcClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartDeviceAuthorizationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(StartDeviceAuthorizationError::from_response(response))
         }
     }
@@ -429,6 +429,7 @@ impl Sso for SsoClient {
         let request_uri = "/federation/credentials";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("GetRoleCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -445,13 +446,13 @@ impl Sso for SsoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRoleCredentialsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRoleCredentialsError::from_response(response))
         }
     }
@@ -465,6 +466,7 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/roles";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccountRoles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -486,13 +488,13 @@ impl Sso for SsoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAccountRolesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAccountRolesError::from_response(response))
         }
     }
@@ -506,6 +508,7 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/accounts";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -526,13 +529,13 @@ impl Sso for SsoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListAccountsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListAccountsError::from_response(response))
         }
     }
@@ -543,6 +546,7 @@ impl Sso for SsoClient {
         let request_uri = "/logout";
 
         let mut request = SignedRequest::new("POST", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("Logout");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -555,12 +559,12 @@ impl Sso for SsoClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(LogoutError::from_response(response))
         }
     }
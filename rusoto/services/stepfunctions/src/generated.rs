@@ -41,7 +41,7 @@ impl StepFunctionsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2422,7 +2422,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, CreateActivityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateActivityOutput, _>()
     }
 
@@ -2440,7 +2440,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, CreateStateMachineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateStateMachineOutput, _>()
     }
 
@@ -2458,7 +2458,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, DeleteActivityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteActivityOutput, _>()
     }
 
@@ -2476,7 +2476,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, DeleteStateMachineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteStateMachineOutput, _>()
     }
 
@@ -2494,7 +2494,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, DescribeActivityError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeActivityOutput, _>()
     }
 
@@ -2512,7 +2512,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, DescribeExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeExecutionOutput, _>()
     }
 
@@ -2530,7 +2530,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, DescribeStateMachineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeStateMachineOutput, _>()
     }
 
@@ -2557,7 +2557,7 @@ impl StepFunctions for StepFunctionsClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeStateMachineForExecutionOutput, _>()
     }
@@ -2576,7 +2576,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, GetActivityTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetActivityTaskOutput, _>()
     }
 
@@ -2594,7 +2594,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, GetExecutionHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetExecutionHistoryOutput, _>()
     }
 
@@ -2612,7 +2612,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, ListActivitiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListActivitiesOutput, _>()
     }
 
@@ -2630,7 +2630,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, ListExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListExecutionsOutput, _>()
     }
 
@@ -2648,7 +2648,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, ListStateMachinesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListStateMachinesOutput, _>()
     }
 
@@ -2666,7 +2666,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -2684,7 +2684,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, SendTaskFailureError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendTaskFailureOutput, _>()
     }
 
@@ -2702,7 +2702,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, SendTaskHeartbeatError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendTaskHeartbeatOutput, _>()
     }
 
@@ -2720,7 +2720,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, SendTaskSuccessError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SendTaskSuccessOutput, _>()
     }
 
@@ -2738,7 +2738,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, StartExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartExecutionOutput, _>()
     }
 
@@ -2756,7 +2756,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, StopExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopExecutionOutput, _>()
     }
 
@@ -2774,7 +2774,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
     }
 
@@ -2792,7 +2792,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 
@@ -2810,7 +2810,7 @@ impl StepFunctions for StepFunctionsClient {
             .sign_and_dispatch(request, UpdateStateMachineError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStateMachineOutput, _>()
     }
 }
@@ -42,7 +42,7 @@ impl StorageGatewayClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -6817,7 +6817,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ActivateGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivateGatewayOutput, _>()
     }
 
@@ -6835,7 +6835,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AddCacheError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddCacheOutput, _>()
     }
 
@@ -6853,7 +6853,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AddTagsToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceOutput, _>()
     }
 
@@ -6871,7 +6871,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AddUploadBufferError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddUploadBufferOutput, _>()
     }
 
@@ -6889,7 +6889,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AddWorkingStorageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddWorkingStorageOutput, _>()
     }
 
@@ -6907,7 +6907,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AssignTapePoolError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssignTapePoolOutput, _>()
     }
 
@@ -6925,7 +6925,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, AttachVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AttachVolumeOutput, _>()
     }
 
@@ -6943,7 +6943,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CancelArchivalError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelArchivalOutput, _>()
     }
 
@@ -6961,7 +6961,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CancelRetrievalError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CancelRetrievalOutput, _>()
     }
 
@@ -6982,7 +6982,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateCachediSCSIVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateCachediSCSIVolumeOutput, _>()
     }
@@ -7001,7 +7001,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateNFSFileShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateNFSFileShareOutput, _>()
     }
 
@@ -7019,7 +7019,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateSMBFileShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSMBFileShareOutput, _>()
     }
 
@@ -7037,7 +7037,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateSnapshotError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateSnapshotOutput, _>()
     }
 
@@ -7064,7 +7064,7 @@ impl StorageGateway for StorageGatewayClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSnapshotFromVolumeRecoveryPointOutput, _>()
     }
@@ -7086,7 +7086,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateStorediSCSIVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateStorediSCSIVolumeOutput, _>()
     }
@@ -7108,7 +7108,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateTapeWithBarcodeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTapeWithBarcodeOutput, _>()
     }
 
@@ -7126,7 +7126,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, CreateTapesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTapesOutput, _>()
     }
 
@@ -7153,7 +7153,7 @@ impl StorageGateway for StorageGatewayClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteAutomaticTapeCreationPolicyOutput, _>()
     }
@@ -7175,7 +7175,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteBandwidthRateLimitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteBandwidthRateLimitOutput, _>()
     }
@@ -7197,7 +7197,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteChapCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteChapCredentialsOutput, _>()
     }
 
@@ -7215,7 +7215,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteFileShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteFileShareOutput, _>()
     }
 
@@ -7233,7 +7233,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGatewayOutput, _>()
     }
 
@@ -7254,7 +7254,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteSnapshotScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSnapshotScheduleOutput, _>()
     }
@@ -7273,7 +7273,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteTapeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTapeOutput, _>()
     }
 
@@ -7291,7 +7291,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteTapeArchiveError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTapeArchiveOutput, _>()
     }
 
@@ -7309,7 +7309,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DeleteVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteVolumeOutput, _>()
     }
 
@@ -7333,7 +7333,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeAvailabilityMonitorTestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAvailabilityMonitorTestOutput, _>()
     }
@@ -7356,7 +7356,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeBandwidthRateLimitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeBandwidthRateLimitOutput, _>()
     }
@@ -7375,7 +7375,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeCacheError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCacheOutput, _>()
     }
 
@@ -7397,7 +7397,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeCachediSCSIVolumesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCachediSCSIVolumesOutput, _>()
     }
@@ -7419,7 +7419,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeChapCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeChapCredentialsOutput, _>()
     }
@@ -7442,7 +7442,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeGatewayInformationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeGatewayInformationOutput, _>()
     }
@@ -7465,7 +7465,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeMaintenanceStartTimeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeMaintenanceStartTimeOutput, _>()
     }
@@ -7487,7 +7487,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeNFSFileSharesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeNFSFileSharesOutput, _>()
     }
 
@@ -7508,7 +7508,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeSMBFileSharesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSMBFileSharesOutput, _>()
     }
 
@@ -7529,7 +7529,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeSMBSettingsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeSMBSettingsOutput, _>()
     }
 
@@ -7550,7 +7550,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeSnapshotScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSnapshotScheduleOutput, _>()
     }
@@ -7573,7 +7573,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeStorediSCSIVolumesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeStorediSCSIVolumesOutput, _>()
     }
@@ -7595,7 +7595,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeTapeArchivesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTapeArchivesOutput, _>()
     }
 
@@ -7617,7 +7617,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeTapeRecoveryPointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTapeRecoveryPointsOutput, _>()
     }
@@ -7636,7 +7636,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeTapesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTapesOutput, _>()
     }
 
@@ -7657,7 +7657,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeUploadBufferError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUploadBufferOutput, _>()
     }
 
@@ -7675,7 +7675,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeVTLDevicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeVTLDevicesOutput, _>()
     }
 
@@ -7696,7 +7696,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DescribeWorkingStorageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkingStorageOutput, _>()
     }
@@ -7715,7 +7715,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DetachVolumeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetachVolumeOutput, _>()
     }
 
@@ -7733,7 +7733,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, DisableGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisableGatewayOutput, _>()
     }
 
@@ -7751,7 +7751,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, JoinDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<JoinDomainOutput, _>()
     }
 
@@ -7778,7 +7778,7 @@ impl StorageGateway for StorageGatewayClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAutomaticTapeCreationPoliciesOutput, _>()
     }
@@ -7797,7 +7797,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListFileSharesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListFileSharesOutput, _>()
     }
 
@@ -7815,7 +7815,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListGatewaysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGatewaysOutput, _>()
     }
 
@@ -7833,7 +7833,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListLocalDisksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListLocalDisksOutput, _>()
     }
 
@@ -7854,7 +7854,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -7872,7 +7872,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListTapesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTapesOutput, _>()
     }
 
@@ -7893,7 +7893,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListVolumeInitiatorsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListVolumeInitiatorsOutput, _>()
     }
 
@@ -7914,7 +7914,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListVolumeRecoveryPointsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListVolumeRecoveryPointsOutput, _>()
     }
@@ -7933,7 +7933,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ListVolumesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListVolumesOutput, _>()
     }
 
@@ -7951,7 +7951,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, NotifyWhenUploadedError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<NotifyWhenUploadedOutput, _>()
     }
 
@@ -7969,7 +7969,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, RefreshCacheError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RefreshCacheOutput, _>()
     }
 
@@ -7990,7 +7990,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, RemoveTagsFromResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RemoveTagsFromResourceOutput, _>()
     }
@@ -8009,7 +8009,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ResetCacheError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResetCacheOutput, _>()
     }
 
@@ -8030,7 +8030,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, RetrieveTapeArchiveError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RetrieveTapeArchiveOutput, _>()
     }
 
@@ -8051,7 +8051,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, RetrieveTapeRecoveryPointError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RetrieveTapeRecoveryPointOutput, _>()
     }
@@ -8073,7 +8073,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, SetLocalConsolePasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<SetLocalConsolePasswordOutput, _>()
     }
@@ -8095,7 +8095,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, SetSMBGuestPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<SetSMBGuestPasswordOutput, _>()
     }
 
@@ -8113,7 +8113,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, ShutdownGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ShutdownGatewayOutput, _>()
     }
 
@@ -8135,7 +8135,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, StartAvailabilityMonitorTestError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartAvailabilityMonitorTestOutput, _>()
     }
@@ -8154,7 +8154,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, StartGatewayError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartGatewayOutput, _>()
     }
 
@@ -8181,7 +8181,7 @@ impl StorageGateway for StorageGatewayClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateAutomaticTapeCreationPolicyOutput, _>()
     }
@@ -8203,7 +8203,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateBandwidthRateLimitError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateBandwidthRateLimitOutput, _>()
     }
@@ -8225,7 +8225,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateChapCredentialsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateChapCredentialsOutput, _>()
     }
 
@@ -8246,7 +8246,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateGatewayInformationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateGatewayInformationOutput, _>()
     }
@@ -8268,7 +8268,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateGatewaySoftwareNowError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateGatewaySoftwareNowOutput, _>()
     }
@@ -8291,7 +8291,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateMaintenanceStartTimeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMaintenanceStartTimeOutput, _>()
     }
@@ -8310,7 +8310,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateNFSFileShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateNFSFileShareOutput, _>()
     }
 
@@ -8328,7 +8328,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateSMBFileShareError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSMBFileShareOutput, _>()
     }
 
@@ -8349,7 +8349,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateSMBSecurityStrategyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSMBSecurityStrategyOutput, _>()
     }
@@ -8371,7 +8371,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateSnapshotScheduleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSnapshotScheduleOutput, _>()
     }
@@ -8393,7 +8393,7 @@ impl StorageGateway for StorageGatewayClient {
             .sign_and_dispatch(request, UpdateVTLDeviceTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVTLDeviceTypeOutput, _>()
     }
 }
@@ -0,0 +1,301 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::{self, Either};
+use rusoto_core::credential::{
+    AwsCredentials, Clock, CredentialsError, ProvideAwsCredentials, SystemClock,
+};
+use tokio::sync::{oneshot, watch};
+use tokio::time;
+
+/// The shortest time between refreshes, so that credentials issued for less than the refresh
+/// buffer aren't refreshed in a loop.
+const MIN_REFRESH_DELAY: Duration = Duration::from_secs(1);
+
+/// The result of the latest fetch, `None` until the first one completes.
+type Latest = Option<Result<AwsCredentials, CredentialsError>>;
+
+/// When a `StsBackgroundRefreshProvider` refreshes its credentials.
+#[derive(Clone, Debug)]
+pub struct BackgroundRefreshOptions {
+    refresh_buffer: Duration,
+    retry_delay: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl BackgroundRefreshOptions {
+    /// Options refreshing credentials 5 minutes before they expire, retrying failed
+    /// refreshes every 10 seconds, with the time of `SystemClock`.
+    pub fn new() -> Self {
+        BackgroundRefreshOptions {
+            refresh_buffer: Duration::from_secs(5 * 60),
+            retry_delay: Duration::from_secs(10),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Refreshes credentials `refresh_buffer` before they expire.
+    pub fn with_refresh_buffer(mut self, refresh_buffer: Duration) -> Self {
+        self.refresh_buffer = refresh_buffer;
+        self
+    }
+
+    /// Waits `retry_delay` after a failed refresh before trying again.
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Computes the time until a refresh, and whether credentials have expired, with the time
+    /// of `clock`.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+}
+
+impl Default for BackgroundRefreshOptions {
+    fn default() -> Self {
+        BackgroundRefreshOptions::new()
+    }
+}
+
+/// Wraps a provider, e.g. a `StsAssumeRoleSessionCredentialsProvider`, fetching its
+/// credentials in a background task ahead of their expiry, so that getting credentials never
+/// waits for STS once the first ones are fetched.
+///
+/// `credentials` returns the credentials fetched last, waiting only for the first fetch. When
+/// a refresh fails, the previous credentials are kept while they are valid and the refresh
+/// is retried after the retry delay; once they expire, `credentials` returns the error until
+/// a refresh succeeds. Credentials without an expiry are never refreshed.
+///
+/// The task runs until `shutdown` is called on the provider or one of its clones, or until
+/// they are all dropped. A provider must be created within a Tokio runtime.
+#[derive(Clone)]
+pub struct StsBackgroundRefreshProvider {
+    latest: watch::Receiver<Latest>,
+    shutdown: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+}
+
+impl fmt::Debug for StsBackgroundRefreshProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let running = match self.shutdown.lock() {
+            Ok(shutdown) => shutdown.is_some(),
+            Err(poisoned) => poisoned.into_inner().is_some(),
+        };
+        f.debug_struct("StsBackgroundRefreshProvider")
+            .field("running", &running)
+            .finish()
+    }
+}
+
+impl StsBackgroundRefreshProvider {
+    /// Spawns the task fetching credentials from `provider`, starting right away.
+    pub fn new<P>(provider: P, options: BackgroundRefreshOptions) -> StsBackgroundRefreshProvider
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        let (sender, latest) = watch::channel(None);
+        let (shutdown, stopped) = oneshot::channel();
+        tokio::spawn(refresh(provider, options, sender, stopped));
+        StsBackgroundRefreshProvider {
+            latest,
+            shutdown: Arc::new(Mutex::new(Some(shutdown))),
+        }
+    }
+
+    /// Stops the background task, for this provider and all its clones. The credentials
+    /// fetched last are still returned by `credentials`, but no longer refreshed.
+    pub fn shutdown(&self) {
+        let shutdown = match self.shutdown.lock() {
+            Ok(mut shutdown) => shutdown.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        if let Some(shutdown) = shutdown {
+            // Fails if the task already ended, which is what was asked for.
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for StsBackgroundRefreshProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut latest = self.latest.clone();
+        loop {
+            let fetched = latest.borrow().clone();
+            if let Some(result) = fetched {
+                return result;
+            }
+            if latest.recv().await.is_none() {
+                return Err(CredentialsError::new(
+                    "The background refresh stopped before fetching credentials",
+                ));
+            }
+        }
+    }
+}
+
+/// Fetches credentials from `provider` until `stopped` completes, publishing each result.
+async fn refresh<P>(
+    provider: P,
+    options: BackgroundRefreshOptions,
+    latest: watch::Sender<Latest>,
+    mut stopped: oneshot::Receiver<()>,
+) where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+{
+    let mut current: Option<AwsCredentials> = None;
+    loop {
+        let fetch = provider.credentials();
+        futures::pin_mut!(fetch);
+        let fetched = match future::select(fetch, &mut stopped).await {
+            Either::Left((fetched, _)) => fetched,
+            Either::Right(_) => return,
+        };
+        let delay = match fetched {
+            Ok(credentials) => {
+                let delay = refresh_delay(&credentials, &options);
+                current = Some(credentials.clone());
+                let _ = latest.broadcast(Some(Ok(credentials)));
+                delay
+            }
+            Err(err) => {
+                let now = options.clock.now();
+                let valid = current
+                    .as_ref()
+                    .is_some_and(|current| current.expires_at().is_none_or(|at| at > now));
+                if !valid {
+                    let _ = latest.broadcast(Some(Err(err)));
+                }
+                Some(options.retry_delay)
+            }
+        };
+        match delay {
+            Some(delay) => {
+                if let Either::Right(_) = future::select(time::delay_for(delay), &mut stopped).await
+                {
+                    return;
+                }
+            }
+            None => {
+                let _ = (&mut stopped).await;
+                return;
+            }
+        }
+    }
+}
+
+/// The time until `credentials` are refreshed, or `None` if they don't expire.
+fn refresh_delay(
+    credentials: &AwsCredentials,
+    options: &BackgroundRefreshOptions,
+) -> Option<Duration> {
+    let expires_at = (*credentials.expires_at())?;
+    let until_expiry = (expires_at - options.clock.now())
+        .to_std()
+        .unwrap_or_default();
+    Some(
+        until_expiry
+            .checked_sub(options.refresh_buffer)
+            .unwrap_or_default()
+            .max(MIN_REFRESH_DELAY),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration as ChronoDuration, Utc};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone)]
+    struct FakeClock(DateTime<Utc>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    /// Returns credentials numbered by fetch, the first expiring 61 seconds after `start` and
+    /// the others an hour after it. Fetches after the second take `slow` to complete.
+    struct Numbered {
+        fetches: Arc<AtomicUsize>,
+        start: DateTime<Utc>,
+        slow: Duration,
+    }
+
+    #[async_trait]
+    impl ProvideAwsCredentials for Numbered {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            let fetch = self.fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            if fetch > 2 {
+                time::delay_for(self.slow).await;
+            }
+            let lifetime = if fetch == 1 { 61 } else { 3600 };
+            Ok(AwsCredentials::new(
+                format!("key-{}", fetch),
+                "secret",
+                None,
+                Some(self.start + ChronoDuration::seconds(lifetime)),
+            ))
+        }
+    }
+
+    async fn key(provider: &StsBackgroundRefreshProvider) -> String {
+        let credentials = time::timeout(Duration::from_millis(50), provider.credentials())
+            .await
+            .expect("credentials without waiting")
+            .expect("credentials");
+        credentials.aws_access_key_id().to_owned()
+    }
+
+    #[tokio::test]
+    async fn refreshes_before_expiry_without_blocking_credentials() {
+        let start = Utc::now();
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let provider = StsBackgroundRefreshProvider::new(
+            Numbered {
+                fetches: fetches.clone(),
+                start,
+                slow: Duration::from_secs(30),
+            },
+            BackgroundRefreshOptions::new()
+                .with_refresh_buffer(Duration::from_secs(60))
+                .with_clock(FakeClock(start)),
+        );
+
+        assert_eq!(key(&provider).await, "key-1");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // The first credentials are refreshed a second in, 60 seconds before they expire.
+        time::delay_for(Duration::from_millis(1200)).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        assert_eq!(key(&provider).await, "key-2");
+
+        provider.shutdown();
+        assert_eq!(key(&provider).await, "key-2");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    /// Fails every fetch.
+    struct Failing;
+
+    #[async_trait]
+    impl ProvideAwsCredentials for Failing {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            Err(CredentialsError::new("no credentials"))
+        }
+    }
+
+    #[tokio::test]
+    async fn first_fetch_errors_are_returned() {
+        let provider = StsBackgroundRefreshProvider::new(Failing, BackgroundRefreshOptions::new());
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(err.message, "no credentials");
+        provider.shutdown();
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use rusoto_core::Region;
+use serde::{Deserialize, Serialize};
+
+/// The settings of a `StsAssumeRoleSessionCredentialsProvider`, to be loaded from a
+/// configuration file and passed to `StsAssumeRoleSessionCredentialsProvider::from_config`.
+///
+/// Secrets aren't part of the config: MFA codes are set on the provider with
+/// `set_mfa_code` or `with_mfa_callback`, and the base credentials come from the provider
+/// passed to `from_config`.
+///
+/// The region is written as its name, e.g. `"us-west-2"`, or like `Region` serializes itself,
+/// as a `[name, endpoint]` pair for custom regions.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_sts::AssumeRoleConfig;
+///
+/// let config: AssumeRoleConfig = serde_json::from_str(r#"{
+///     "role_arn": "arn:aws:iam::123456789012:role/deploy",
+///     "session_name": "deploy",
+///     "duration_seconds": 3600,
+///     "tags": {"team": "platform"},
+///     "region": "us-west-2"
+/// }"#).unwrap();
+/// assert_eq!(config.tags["team"], "platform");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssumeRoleConfig {
+    /// The ARN of the role to assume.
+    pub role_arn: String,
+    /// The role session name.
+    pub session_name: String,
+    /// The external ID the role's trust policy may require.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The duration of the role session in seconds, an hour by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u64>,
+    /// An inline session policy, as JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_down_policy: Option<String>,
+    /// The serial number or ARN of the MFA device the role requires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mfa_serial: Option<String>,
+    /// Session tags to pass.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+    /// The region of the STS endpoint, the default region if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "region_name")]
+    pub region: Option<Region>,
+}
+
+/// (De)serializes regions other than custom ones as their name.
+mod region_name {
+    use rusoto_core::Region;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RegionValue {
+        Name(String),
+        Region(Region),
+    }
+
+    pub fn serialize<S: Serializer>(
+        region: &Option<Region>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match *region {
+            Some(Region::Custom { .. }) | None => region.serialize(serializer),
+            Some(ref region) => serializer.serialize_some(region.name()),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Region>, D::Error> {
+        match Option::<RegionValue>::deserialize(deserializer)? {
+            Some(RegionValue::Name(name)) => name.parse().map(Some).map_err(D::Error::custom),
+            Some(RegionValue::Region(region)) => Ok(Some(region)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AssumeRoleConfig {
+        let mut tags = BTreeMap::new();
+        tags.insert("team".to_owned(), "platform".to_owned());
+        AssumeRoleConfig {
+            role_arn: "arn:aws:iam::123456789012:role/deploy".to_owned(),
+            session_name: "deploy".to_owned(),
+            external_id: Some("external".to_owned()),
+            duration_seconds: Some(3600),
+            scope_down_policy: Some(r#"{"Version":"2012-10-17"}"#.to_owned()),
+            mfa_serial: Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+            tags,
+            region: Some(Region::UsWest2),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let config = config();
+
+        let json = serde_json::to_value(&config).unwrap();
+
+        assert_eq!(json["region"], "us-west-2");
+        assert_eq!(json["tags"]["team"], "platform");
+        assert_eq!(
+            serde_json::from_value::<AssumeRoleConfig>(json).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn round_trips_custom_regions() {
+        let config = AssumeRoleConfig {
+            region: Some(Region::Custom {
+                name: "local".to_owned(),
+                endpoint: "http://localhost:4566".to_owned(),
+            }),
+            ..config()
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<AssumeRoleConfig>(&json).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn optional_settings_can_be_left_out() {
+        let config: AssumeRoleConfig = serde_json::from_str(
+            r#"{"role_arn": "arn:aws:iam::123456789012:role/deploy", "session_name": "deploy"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.duration_seconds, None);
+        assert!(config.tags.is_empty());
+        assert_eq!(config.region, None);
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"role_arn":"arn:aws:iam::123456789012:role/deploy","session_name":"deploy"}"#
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_region_names() {
+        let result = serde_json::from_str::<AssumeRoleConfig>(
+            r#"{"role_arn": "arn", "session_name": "deploy", "region": "moon-1"}"#,
+        );
+
+        assert!(result.is_err());
+    }
+}
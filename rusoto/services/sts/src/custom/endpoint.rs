@@ -0,0 +1,99 @@
+use rusoto_core::{Endpoint, Region};
+
+/// The STS endpoint to call, pinned regardless of the `AWS_STS_REGIONAL_ENDPOINTS` setting.
+///
+/// `StsEndpoint::region_for` turns a region into the `Region::Custom` to pass to the
+/// region-based constructors, like `new_with_base_credentials`, or to set as the `region` of
+/// an `AssumeRoleConfig`, so that the provider calls this endpoint and signs the calls for the
+/// region. Without it, the client calls the regional endpoint of the region, unless
+/// `rusoto_core::StsRegionalEndpoints::Legacy` is configured.
+///
+/// The global endpoint `sts.amazonaws.com` is served from `us-east-1`, so calls from other
+/// regions cross regions and fail if it's unavailable. Regional endpoints,
+/// `sts.<region>.amazonaws.com`, keep the calls, and the data they carry, in the region.
+/// Session tokens from regional endpoints are valid in all regions, while those from the
+/// global endpoint are only valid in the regions enabled by default, unless the account's IAM
+/// settings make it issue tokens valid in all regions.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::Region;
+/// use rusoto_sts::StsEndpoint;
+///
+/// let endpoint = "https://vpce-1a2b3c4d.sts.eu-west-1.vpce.amazonaws.com";
+/// let region = StsEndpoint::Custom(endpoint.to_owned()).region_for(Region::EuWest1);
+/// assert_eq!(region.name(), "eu-west-1");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StsEndpoint {
+    /// The global endpoint, `sts.amazonaws.com`, signed for `us-east-1`.
+    Global,
+    /// The endpoint of the region, e.g. `sts.eu-west-1.amazonaws.com`.
+    Regional,
+    /// The endpoint at a URL, e.g. a VPC interface endpoint, signed for the region.
+    Custom(String),
+}
+
+impl StsEndpoint {
+    /// Returns the region to build an `StsClient` for to call this endpoint from `region`.
+    pub fn region_for(&self, region: Region) -> Region {
+        match *self {
+            StsEndpoint::Global => Region::Custom {
+                name: Region::UsEast1.name().to_owned(),
+                endpoint: "https://sts.amazonaws.com".to_owned(),
+            },
+            StsEndpoint::Regional => match region {
+                Region::Custom { .. } => region,
+                _ => {
+                    let endpoint = Endpoint::for_region("sts", &region);
+                    Region::Custom {
+                        name: region.name().to_owned(),
+                        endpoint: format!("{}://{}", endpoint.scheme, endpoint.hostname),
+                    }
+                }
+            },
+            StsEndpoint::Custom(ref url) => Region::Custom {
+                name: region.name().to_owned(),
+                endpoint: url.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_for_endpoints() {
+        assert_eq!(
+            StsEndpoint::Global.region_for(Region::EuWest1),
+            Region::Custom {
+                name: "us-east-1".to_owned(),
+                endpoint: "https://sts.amazonaws.com".to_owned(),
+            }
+        );
+        assert_eq!(
+            StsEndpoint::Regional.region_for(Region::EuWest1),
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "https://sts.eu-west-1.amazonaws.com".to_owned(),
+            }
+        );
+        assert_eq!(
+            StsEndpoint::Regional.region_for(Region::CnNorth1),
+            Region::Custom {
+                name: "cn-north-1".to_owned(),
+                endpoint: "https://sts.cn-north-1.amazonaws.com.cn".to_owned(),
+            }
+        );
+        assert_eq!(
+            StsEndpoint::Custom("http://localhost:4566".to_owned()).region_for(Region::UsWest2),
+            Region::Custom {
+                name: "us-west-2".to_owned(),
+                endpoint: "http://localhost:4566".to_owned(),
+            }
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::error::Error;
+use std::fmt;
+
+use rusoto_core::credential::CredentialsError;
+use rusoto_core::{RusotoError, XmlErrorMessage};
+
+use crate::generated::{
+    AssumeRoleError, AssumeRoleWithSAMLError, AssumeRoleWithWebIdentityError,
+    DecodeAuthorizationMessageError, GetAccessKeyInfoError, GetCallerIdentityError,
+    GetFederationTokenError, GetSessionTokenError,
+};
+
+/// Why an STS credentials provider failed to get credentials.
+///
+/// Providers still fail with a `CredentialsError`, which keeps the `StsProviderError` as its
+/// source; `StsProviderError::find` gets it back. Failures for other reasons, like the request
+/// timing out or not reaching STS, have no `StsProviderError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::credential::CredentialsError;
+/// use rusoto_sts::{StsProviderError, StsProviderErrorKind};
+///
+/// let err = CredentialsError::from(StsProviderError::NoCredentials {
+///     operation: "AssumeRole".to_owned(),
+/// });
+/// assert_eq!(
+///     StsProviderErrorKind::of(&err),
+///     StsProviderErrorKind::NoCredentials
+/// );
+/// assert_eq!(
+///     StsProviderErrorKind::of(&CredentialsError::new("timed out")),
+///     StsProviderErrorKind::Other
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum StsProviderError {
+    /// STS answered without credentials.
+    NoCredentials {
+        /// The STS operation that answered, e.g. `AssumeRole`.
+        operation: String,
+    },
+    /// The expiration of the credentials STS returned couldn't be parsed.
+    InvalidExpiration {
+        /// The expiration as returned.
+        expiration: String,
+        /// Why it couldn't be parsed.
+        reason: String,
+    },
+    /// An MFA serial is set, but neither an MFA code nor an MFA callback.
+    MfaRequired {
+        /// The serial number or ARN of the MFA device.
+        mfa_serial: String,
+    },
+    /// STS failed the call with an error response.
+    Service {
+        /// The message of the error, or the status and body of the response for errors
+        /// without a dedicated type, like `AccessDenied`.
+        message: String,
+        /// The ID STS gave the request, to quote in support cases, if the error response had
+        /// one.
+        request_id: Option<String>,
+    },
+}
+
+impl StsProviderError {
+    /// The error for a response of `operation` without credentials.
+    pub(crate) fn no_credentials(operation: &str) -> StsProviderError {
+        StsProviderError::NoCredentials {
+            operation: operation.to_owned(),
+        }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> StsProviderErrorKind {
+        match *self {
+            StsProviderError::NoCredentials { .. } => StsProviderErrorKind::NoCredentials,
+            StsProviderError::InvalidExpiration { .. } => StsProviderErrorKind::InvalidExpiration,
+            StsProviderError::MfaRequired { .. } => StsProviderErrorKind::MfaRequired,
+            StsProviderError::Service { .. } => StsProviderErrorKind::Service,
+        }
+    }
+
+    /// Returns the ID of the request STS failed, if known. See `StsProviderError::Service`.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            StsProviderError::Service {
+                request_id: Some(ref request_id),
+                ..
+            } => Some(request_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the `StsProviderError` among the sources of `err`, if any.
+    pub fn find(err: &CredentialsError) -> Option<&StsProviderError> {
+        let mut source = err.source();
+        while let Some(err) = source {
+            if let Some(err) = err.downcast_ref::<StsProviderError>() {
+                return Some(err);
+            }
+            source = err.source();
+        }
+        None
+    }
+}
+
+impl fmt::Display for StsProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StsProviderError::NoCredentials { ref operation } => {
+                write!(f, "No credentials in STS {} response", operation)
+            }
+            StsProviderError::InvalidExpiration {
+                ref expiration,
+                ref reason,
+            } => write!(
+                f,
+                "Invalid expiration '{}' in STS credentials: {}",
+                expiration, reason
+            ),
+            StsProviderError::MfaRequired { ref mfa_serial } => write!(
+                f,
+                "MFA serial '{}' is set but neither an MFA code nor an MFA callback is configured",
+                mfa_serial
+            ),
+            StsProviderError::Service {
+                ref message,
+                request_id: Some(ref request_id),
+            } => write!(f, "STS error: {} (request ID: {})", message, request_id),
+            StsProviderError::Service { ref message, .. } => write!(f, "STS error: {}", message),
+        }
+    }
+}
+
+impl Error for StsProviderError {}
+
+impl From<StsProviderError> for CredentialsError {
+    fn from(err: StsProviderError) -> CredentialsError {
+        CredentialsError::new(&err).with_source(err)
+    }
+}
+
+impl<E> From<StsProviderError> for RusotoError<E> {
+    fn from(err: StsProviderError) -> RusotoError<E> {
+        RusotoError::Credentials(err.into())
+    }
+}
+
+/// The kind of a `StsProviderError`, or `Other` for failures without one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StsProviderErrorKind {
+    /// See `StsProviderError::NoCredentials`.
+    NoCredentials,
+    /// See `StsProviderError::InvalidExpiration`.
+    InvalidExpiration,
+    /// See `StsProviderError::MfaRequired`.
+    MfaRequired,
+    /// See `StsProviderError::Service`.
+    Service,
+    /// The provider failed for another reason.
+    Other,
+}
+
+impl StsProviderErrorKind {
+    /// Returns the kind of the `StsProviderError` `err` was caused by, `Other` if none.
+    pub fn of(err: &CredentialsError) -> StsProviderErrorKind {
+        StsProviderError::find(err)
+            .map(StsProviderError::kind)
+            .unwrap_or(StsProviderErrorKind::Other)
+    }
+}
+
+/// An error of an STS operation, keeping the ID of the request STS failed.
+pub(crate) trait StsOperationError: Error + 'static {
+    fn request_id(&self) -> Option<&str>;
+}
+
+macro_rules! sts_operation_errors {
+    ($($error:ident),*) => {
+        $(
+            impl StsOperationError for $error {
+                fn request_id(&self) -> Option<&str> {
+                    $error::request_id(self)
+                }
+            }
+        )*
+    };
+}
+
+sts_operation_errors!(
+    AssumeRoleError,
+    AssumeRoleWithSAMLError,
+    AssumeRoleWithWebIdentityError,
+    DecodeAuthorizationMessageError,
+    GetAccessKeyInfoError,
+    GetCallerIdentityError,
+    GetFederationTokenError,
+    GetSessionTokenError
+);
+
+/// Converts the error of an STS call into a `CredentialsError` with `message`, keeping what
+/// caused it as its source.
+pub(crate) fn sts_credentials_error<E>(message: String, err: RusotoError<E>) -> CredentialsError
+where
+    E: StsOperationError,
+{
+    let credentials_error = CredentialsError::new(message);
+    match err {
+        RusotoError::Credentials(err) => credentials_error.with_source(err),
+        RusotoError::Service(err) => credentials_error.with_source(StsProviderError::Service {
+            message: err.to_string(),
+            request_id: err.request_id().map(str::to_owned),
+        }),
+        RusotoError::Unknown(ref response) => {
+            let message = XmlErrorMessage::from_response(
+                format!("HTTP {}: {}", response.status, response.body_as_str()),
+                response,
+            );
+            credentials_error.with_source(StsProviderError::Service {
+                request_id: message.request_id().map(str::to_owned),
+                message: message.into(),
+            })
+        }
+        _ => credentials_error,
+    }
+}
+
+/// Whether STS denied the call with `AccessDenied`, as for an MFA code of another MFA device.
+pub(crate) fn is_access_denied<E>(err: &RusotoError<E>) -> bool {
+    match *err {
+        RusotoError::Unknown(ref response) => {
+            response.status.as_u16() == 403
+                && response.body_as_str().contains("<Code>AccessDenied</Code>")
+        }
+        _ => false,
+    }
+}
+
+/// Whether STS rejected the credentials the call was signed with as expired.
+pub(crate) fn is_expired_token<E>(err: &RusotoError<E>) -> bool {
+    match *err {
+        RusotoError::Unknown(ref response) => {
+            response.body_as_str().contains("<Code>ExpiredToken</Code>")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::request::BufferedHttpResponse;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn sources_survive_wrapping() {
+        let err: CredentialsError = StsProviderError::MfaRequired {
+            mfa_serial: "arn:aws:iam::123456789012:mfa/user".to_owned(),
+        }
+        .into();
+        let wrapped = sts_credentials_error(
+            "StsProvider error".to_owned(),
+            RusotoError::<AssumeRoleError>::Credentials(err),
+        );
+
+        assert_eq!(
+            StsProviderErrorKind::of(&wrapped),
+            StsProviderErrorKind::MfaRequired
+        );
+    }
+
+    #[test]
+    fn service_errors_are_classified() {
+        let err = sts_credentials_error(
+            "StsProvider error".to_owned(),
+            RusotoError::Service(AssumeRoleError::RegionDisabled("disabled".into())),
+        );
+        assert_eq!(
+            StsProviderError::find(&err),
+            Some(&StsProviderError::Service {
+                message: "disabled".to_owned(),
+                request_id: None,
+            })
+        );
+
+        let err = sts_credentials_error(
+            "StsProvider error".to_owned(),
+            RusotoError::<AssumeRoleError>::Unknown(BufferedHttpResponse {
+                status: TryFrom::try_from(403).unwrap(),
+                body: "denied".into(),
+                headers: Default::default(),
+            }),
+        );
+        assert_eq!(
+            StsProviderError::find(&err),
+            Some(&StsProviderError::Service {
+                message: "HTTP 403 Forbidden: denied".to_owned(),
+                request_id: None,
+            })
+        );
+
+        let err = sts_credentials_error(
+            "StsProvider error".to_owned(),
+            RusotoError::<AssumeRoleError>::Validation("bad".to_owned()),
+        );
+        assert_eq!(StsProviderErrorKind::of(&err), StsProviderErrorKind::Other);
+    }
+
+    #[test]
+    fn request_ids_are_kept() {
+        let body = "<ErrorResponse><Error><Code>ExpiredToken</Code></Error>\
+                    <RequestId>4e5c1f6a-2d3b-4c8e-9f0a-1b2c3d4e5f60</RequestId></ErrorResponse>";
+        let unknown = |request_id: Option<&str>| {
+            let mut response = BufferedHttpResponse {
+                status: TryFrom::try_from(400).unwrap(),
+                body: body.into(),
+                headers: Default::default(),
+            };
+            if let Some(request_id) = request_id {
+                response
+                    .headers
+                    .insert("x-amzn-requestid", request_id.to_owned());
+            }
+            sts_credentials_error(
+                "StsProvider error".to_owned(),
+                RusotoError::<AssumeRoleError>::Unknown(response),
+            )
+        };
+
+        let err = unknown(None);
+        let source = StsProviderError::find(&err).unwrap();
+        assert_eq!(
+            source.request_id(),
+            Some("4e5c1f6a-2d3b-4c8e-9f0a-1b2c3d4e5f60")
+        );
+        assert!(source
+            .to_string()
+            .ends_with("(request ID: 4e5c1f6a-2d3b-4c8e-9f0a-1b2c3d4e5f60)"));
+
+        let err = unknown(Some("c6104cbe-af31-11e0-8154-cbc7ccf896c7"));
+        assert_eq!(
+            StsProviderError::find(&err).and_then(StsProviderError::request_id),
+            Some("c6104cbe-af31-11e0-8154-cbc7ccf896c7")
+        );
+
+        let err = sts_credentials_error(
+            "StsProvider error".to_owned(),
+            RusotoError::Service(AssumeRoleError::RegionDisabled(
+                XmlErrorMessage::new("disabled")
+                    .with_request_id("c6104cbe-af31-11e0-8154-cbc7ccf896c7"),
+            )),
+        );
+        assert_eq!(
+            StsProviderError::find(&err),
+            Some(&StsProviderError::Service {
+                message: "disabled".to_owned(),
+                request_id: Some("c6104cbe-af31-11e0-8154-cbc7ccf896c7".to_owned()),
+            })
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rusoto_core::credential::{AwsCredentials, CredentialsError};
+
+use crate::custom::error::StsProviderErrorKind;
+
+/// How a credentials fetch ended, see `MetricsRecorder::record_fetch`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FetchOutcome {
+    /// STS returned credentials.
+    Success,
+    /// The fetch failed, with the kind of error it failed with.
+    Failure(StsProviderErrorKind),
+}
+
+/// Records the credentials fetches of STS credentials providers, e.g. to export them as
+/// metrics and alert on STS error rates.
+///
+/// Set with `with_metrics_recorder` on a provider. Providers without one record nothing.
+/// The recorder is called on the task fetching the credentials, so it should return quickly.
+pub trait MetricsRecorder: Send + Sync {
+    /// Called after each call to STS for credentials, with the STS operation, e.g.
+    /// `AssumeRole`, how it ended and how long it took. Credentials returned from a cache
+    /// aren't recorded.
+    fn record_fetch(&self, operation: &'static str, outcome: FetchOutcome, latency: Duration);
+}
+
+impl<R: MetricsRecorder + ?Sized> MetricsRecorder for Arc<R> {
+    fn record_fetch(&self, operation: &'static str, outcome: FetchOutcome, latency: Duration) {
+        (**self).record_fetch(operation, outcome, latency)
+    }
+}
+
+/// A `MetricsRecorder` that records nothing, used by providers without a recorder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetricsRecorder;
+
+impl MetricsRecorder for NoopMetricsRecorder {
+    fn record_fetch(&self, _operation: &'static str, _outcome: FetchOutcome, _latency: Duration) {}
+}
+
+/// A fetch recorded by an `InMemoryMetricsRecorder`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchEvent {
+    /// The STS operation called.
+    pub operation: &'static str,
+    /// How the fetch ended.
+    pub outcome: FetchOutcome,
+    /// How long the fetch took.
+    pub latency: Duration,
+}
+
+/// A `MetricsRecorder` keeping every fetch in memory, e.g. for tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use rusoto_core::Region;
+/// use rusoto_sts::{InMemoryMetricsRecorder, StsClient, StsSessionCredentialsProvider};
+///
+/// let recorder = Arc::new(InMemoryMetricsRecorder::new());
+/// let provider = StsSessionCredentialsProvider::new(StsClient::new(Region::UsEast1), None, None)
+///     .with_metrics_recorder(recorder.clone());
+/// assert!(recorder.events().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryMetricsRecorder {
+    events: Mutex<Vec<FetchEvent>>,
+}
+
+impl InMemoryMetricsRecorder {
+    /// Creates a recorder without any events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the fetches recorded so far, oldest first.
+    pub fn events(&self) -> Vec<FetchEvent> {
+        match self.events.lock() {
+            Ok(events) => events.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+}
+
+impl MetricsRecorder for InMemoryMetricsRecorder {
+    fn record_fetch(&self, operation: &'static str, outcome: FetchOutcome, latency: Duration) {
+        let event = FetchEvent {
+            operation,
+            outcome,
+            latency,
+        };
+        match self.events.lock() {
+            Ok(mut events) => events.push(event),
+            Err(poisoned) => poisoned.into_inner().push(event),
+        }
+    }
+}
+
+/// The `MetricsRecorder` a provider was given, `NoopMetricsRecorder` by default.
+#[derive(Clone)]
+pub(crate) struct SharedRecorder(Arc<dyn MetricsRecorder>);
+
+impl SharedRecorder {
+    pub(crate) fn new<R: MetricsRecorder + 'static>(recorder: R) -> Self {
+        SharedRecorder(Arc::new(recorder))
+    }
+
+    /// Awaits `fetch`, a call to `operation`, and records how it ended.
+    pub(crate) async fn recorded<F>(
+        &self,
+        operation: &'static str,
+        fetch: F,
+    ) -> Result<AwsCredentials, CredentialsError>
+    where
+        F: Future<Output = Result<AwsCredentials, CredentialsError>>,
+    {
+        let started = Instant::now();
+        let result = fetch.await;
+        let outcome = match result {
+            Ok(_) => FetchOutcome::Success,
+            Err(ref err) => FetchOutcome::Failure(StsProviderErrorKind::of(err)),
+        };
+        self.0.record_fetch(operation, outcome, started.elapsed());
+        result
+    }
+}
+
+impl Default for SharedRecorder {
+    fn default() -> Self {
+        SharedRecorder::new(NoopMetricsRecorder)
+    }
+}
+
+impl fmt::Debug for SharedRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsRecorder")
+    }
+}
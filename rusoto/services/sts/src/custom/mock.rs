@@ -0,0 +1,250 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use rusoto_core::RusotoError;
+
+use crate::{
+    AssumeRoleError, AssumeRoleRequest, AssumeRoleResponse, AssumeRoleWithSAMLError,
+    AssumeRoleWithSAMLRequest, AssumeRoleWithSAMLResponse, AssumeRoleWithWebIdentityError,
+    AssumeRoleWithWebIdentityRequest, AssumeRoleWithWebIdentityResponse, Credentials,
+    DecodeAuthorizationMessageError, DecodeAuthorizationMessageRequest,
+    DecodeAuthorizationMessageResponse, GetAccessKeyInfoError, GetAccessKeyInfoRequest,
+    GetAccessKeyInfoResponse, GetCallerIdentityError, GetCallerIdentityRequest,
+    GetCallerIdentityResponse, GetFederationTokenError, GetFederationTokenRequest,
+    GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
+    GetSessionTokenResponse, Sts,
+};
+
+/// The queued results of one operation and the requests it received.
+struct Operation<Req, Resp, Err> {
+    results: VecDeque<Result<Resp, RusotoError<Err>>>,
+    requests: Vec<Req>,
+}
+
+impl<Req, Resp, Err> Default for Operation<Req, Resp, Err> {
+    fn default() -> Self {
+        Operation {
+            results: VecDeque::new(),
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl<Req, Resp, Err> Operation<Req, Resp, Err> {
+    // The error type is that of the `Sts` trait.
+    #[allow(clippy::result_large_err)]
+    fn call(&mut self, name: &str, request: Req) -> Result<Resp, RusotoError<Err>> {
+        self.requests.push(request);
+        self.results.pop_front().unwrap_or_else(|| {
+            Err(RusotoError::Validation(format!(
+                "MockSts has no queued result for {}",
+                name
+            )))
+        })
+    }
+}
+
+macro_rules! mock_operations {
+    ($(
+        $op:ident, $name:expr, $push:ident, $requests:ident,
+        $req:ty, $resp:ty, $err:ty, $push_doc:expr, $requests_doc:expr;
+    )*) => {
+        #[derive(Default)]
+        struct State {
+            $($op: Operation<$req, $resp, $err>,)*
+        }
+
+        impl MockSts {
+            $(
+                #[doc = $push_doc]
+                pub fn $push(&self, result: Result<$resp, RusotoError<$err>>) -> &Self {
+                    self.state.lock().unwrap().$op.results.push_back(result);
+                    self
+                }
+
+                #[doc = $requests_doc]
+                pub fn $requests(&self) -> Vec<$req> {
+                    self.state.lock().unwrap().$op.requests.clone()
+                }
+            )*
+        }
+
+        #[async_trait]
+        impl Sts for MockSts {
+            $(
+                async fn $op(&self, input: $req) -> Result<$resp, RusotoError<$err>> {
+                    self.state.lock().unwrap().$op.call($name, input)
+                }
+            )*
+        }
+    };
+}
+
+/// An `Sts` implementation answering with queued results instead of calling AWS, for testing
+/// code that uses the STS credentials providers. Available with the `test-util` feature.
+///
+/// Results are queued per operation with the `push_*` methods and returned in order. Calls
+/// to an operation without queued results fail with a `RusotoError::Validation`. The
+/// requests received are recorded and can be read with the `*_requests` methods.
+///
+/// Clones share their queues and recorded requests, so a clone can be given to a provider
+/// while the test keeps the original to queue results and inspect requests.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use rusoto_core::credential::ProvideAwsCredentials;
+/// use rusoto_sts::{AssumeRoleResponse, MockSts, StsAssumeRoleSessionCredentialsProvider};
+///
+/// let sts = MockSts::new();
+/// sts.push_assume_role(Ok(AssumeRoleResponse {
+///     credentials: Some(MockSts::credentials(Utc::now() + Duration::hours(1))),
+///     ..Default::default()
+/// }));
+///
+/// let provider = StsAssumeRoleSessionCredentialsProvider::new(
+///     sts.clone(),
+///     "arn:aws:iam::123456789012:role/test".to_owned(),
+///     "session".to_owned(),
+///     None,
+///     None,
+///     None,
+///     None,
+/// );
+/// let credentials = futures::executor::block_on(provider.credentials()).unwrap();
+///
+/// assert_eq!(credentials.aws_access_key_id(), "ASIAMOCKACCESSKEY");
+/// assert_eq!(sts.assume_role_requests()[0].role_session_name, "session");
+/// ```
+#[derive(Clone, Default)]
+pub struct MockSts {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockSts {
+    /// Creates a mock without queued results.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns STS credentials with fixed, fake keys, expiring at `expiration`.
+    pub fn credentials(expiration: DateTime<Utc>) -> Credentials {
+        Credentials {
+            access_key_id: "ASIAMOCKACCESSKEY".to_owned(),
+            secret_access_key: "mock-secret-access-key".to_owned(),
+            session_token: "mock-session-token".to_owned(),
+            expiration: expiration.to_rfc3339(),
+        }
+    }
+}
+
+mock_operations! {
+    assume_role, "AssumeRole", push_assume_role, assume_role_requests,
+    AssumeRoleRequest, AssumeRoleResponse, AssumeRoleError,
+    "Queues the result of the next `assume_role` call.",
+    "Returns the requests `assume_role` received.";
+
+    assume_role_with_saml, "AssumeRoleWithSAML", push_assume_role_with_saml,
+    assume_role_with_saml_requests,
+    AssumeRoleWithSAMLRequest, AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError,
+    "Queues the result of the next `assume_role_with_saml` call.",
+    "Returns the requests `assume_role_with_saml` received.";
+
+    assume_role_with_web_identity, "AssumeRoleWithWebIdentity",
+    push_assume_role_with_web_identity, assume_role_with_web_identity_requests,
+    AssumeRoleWithWebIdentityRequest, AssumeRoleWithWebIdentityResponse,
+    AssumeRoleWithWebIdentityError,
+    "Queues the result of the next `assume_role_with_web_identity` call.",
+    "Returns the requests `assume_role_with_web_identity` received.";
+
+    decode_authorization_message, "DecodeAuthorizationMessage",
+    push_decode_authorization_message, decode_authorization_message_requests,
+    DecodeAuthorizationMessageRequest, DecodeAuthorizationMessageResponse,
+    DecodeAuthorizationMessageError,
+    "Queues the result of the next `decode_authorization_message` call.",
+    "Returns the requests `decode_authorization_message` received.";
+
+    get_access_key_info, "GetAccessKeyInfo", push_get_access_key_info,
+    get_access_key_info_requests,
+    GetAccessKeyInfoRequest, GetAccessKeyInfoResponse, GetAccessKeyInfoError,
+    "Queues the result of the next `get_access_key_info` call.",
+    "Returns the requests `get_access_key_info` received.";
+
+    get_caller_identity, "GetCallerIdentity", push_get_caller_identity,
+    get_caller_identity_requests,
+    GetCallerIdentityRequest, GetCallerIdentityResponse, GetCallerIdentityError,
+    "Queues the result of the next `get_caller_identity` call.",
+    "Returns the requests `get_caller_identity` received.";
+
+    get_federation_token, "GetFederationToken", push_get_federation_token,
+    get_federation_token_requests,
+    GetFederationTokenRequest, GetFederationTokenResponse, GetFederationTokenError,
+    "Queues the result of the next `get_federation_token` call.",
+    "Returns the requests `get_federation_token` received.";
+
+    get_session_token, "GetSessionToken", push_get_session_token, get_session_token_requests,
+    GetSessionTokenRequest, GetSessionTokenResponse, GetSessionTokenError,
+    "Queues the result of the next `get_session_token` call.",
+    "Returns the requests `get_session_token` received.";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider};
+    use chrono::Duration;
+    use rusoto_core::credential::ProvideAwsCredentials;
+
+    #[tokio::test]
+    async fn assume_role_provider_against_mock() {
+        let sts = MockSts::new();
+        let expiration = Utc::now() + Duration::hours(1);
+        sts.push_assume_role(Err(RusotoError::Service(AssumeRoleError::RegionDisabled(
+            "region disabled".into(),
+        ))))
+        .push_assume_role(Ok(AssumeRoleResponse {
+            credentials: Some(MockSts::credentials(expiration)),
+            ..Default::default()
+        }));
+        let provider = StsAssumeRoleSessionCredentialsProvider::new(
+            sts.clone(),
+            "arn:aws:iam::123456789012:role/test".to_owned(),
+            "session".to_owned(),
+            Some("external".to_owned()),
+            None,
+            None,
+            None,
+        );
+
+        let err = provider.credentials().await.unwrap_err();
+        assert!(err.to_string().contains("region disabled"), "{}", err);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "ASIAMOCKACCESSKEY");
+        assert_eq!(credentials.token().as_deref(), Some("mock-session-token"));
+
+        let requests = sts.assume_role_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].role_arn, "arn:aws:iam::123456789012:role/test");
+        assert_eq!(requests[0].external_id.as_deref(), Some("external"));
+    }
+
+    #[tokio::test]
+    async fn fails_without_queued_results() {
+        let sts = MockSts::new();
+        let provider = StsSessionCredentialsProvider::new(sts.clone(), None, None);
+
+        let err = provider.credentials().await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("MockSts has no queued result for GetSessionToken"),
+            "{}",
+            err
+        );
+        assert_eq!(sts.get_session_token_requests().len(), 1);
+        assert!(sts.assume_role_requests().is_empty());
+    }
+}
@@ -1,9 +1,30 @@
+mod access_key;
+mod background;
+mod config;
 mod credential;
+mod endpoint;
+mod error;
+mod metrics;
+#[cfg(any(test, feature = "test-util"))]
+mod mock;
+mod policy;
+mod trace;
 
+pub use self::background::{BackgroundRefreshOptions, StsBackgroundRefreshProvider};
+pub use self::config::AssumeRoleConfig;
 pub use self::credential::{
-    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
+    DurationLimitMode, NewAwsCredsForStsCreds, SessionDurationMetadata, SessionTokenCaller,
+    StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
 };
+pub use self::endpoint::StsEndpoint;
+pub use self::error::{StsProviderError, StsProviderErrorKind};
+pub use self::metrics::{
+    FetchEvent, FetchOutcome, InMemoryMetricsRecorder, MetricsRecorder, NoopMetricsRecorder,
+};
+#[cfg(any(test, feature = "test-util"))]
+pub use self::mock::MockSts;
+pub use self::policy::{PolicyEffect, PolicyStatement, SessionPolicy, MAX_SESSION_POLICY_LEN};
 
 mod web_identity;
 pub use self::web_identity::*;
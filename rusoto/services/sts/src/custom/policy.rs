@@ -0,0 +1,226 @@
+use std::fmt::Write;
+
+use rusoto_core::credential::CredentialsError;
+
+/// The maximum length of an inline session policy accepted by STS.
+pub const MAX_SESSION_POLICY_LEN: usize = 2048;
+
+const POLICY_VERSION: &str = "2012-10-17";
+
+/// Whether a policy statement allows or denies its actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyEffect {
+    /// The statement allows the actions.
+    Allow,
+    /// The statement denies the actions.
+    Deny,
+}
+
+impl PolicyEffect {
+    fn as_str(self) -> &'static str {
+        match self {
+            PolicyEffect::Allow => "Allow",
+            PolicyEffect::Deny => "Deny",
+        }
+    }
+}
+
+/// A statement of a `SessionPolicy`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolicyStatement {
+    /// Optional identifier of the statement.
+    pub sid: Option<String>,
+    /// Whether the statement allows or denies `actions`.
+    pub effect: PolicyEffect,
+    /// Actions the statement applies to, e.g. `s3:GetObject`.
+    pub actions: Vec<String>,
+    /// ARNs of the resources the statement applies to.
+    pub resources: Vec<String>,
+}
+
+/// Builder for an inline session policy, which further restricts the permissions of the
+/// credentials returned by STS.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_sts::SessionPolicy;
+///
+/// let policy = SessionPolicy::new()
+///     .allow(&["s3:GetObject"], &["arn:aws:s3:::my-bucket/*"])
+///     .deny(&["s3:DeleteObject"], &["*"])
+///     .to_json()
+///     .unwrap();
+/// assert!(policy.starts_with(r#"{"Version":"2012-10-17","Statement":["#));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionPolicy {
+    statements: Vec<PolicyStatement>,
+}
+
+impl SessionPolicy {
+    /// Creates a policy without statements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a statement allowing `actions` on `resources`.
+    pub fn allow<A, R>(self, actions: &[A], resources: &[R]) -> Self
+    where
+        A: AsRef<str>,
+        R: AsRef<str>,
+    {
+        self.with_effect(PolicyEffect::Allow, actions, resources)
+    }
+
+    /// Adds a statement denying `actions` on `resources`.
+    pub fn deny<A, R>(self, actions: &[A], resources: &[R]) -> Self
+    where
+        A: AsRef<str>,
+        R: AsRef<str>,
+    {
+        self.with_effect(PolicyEffect::Deny, actions, resources)
+    }
+
+    /// Adds a statement.
+    pub fn statement(mut self, statement: PolicyStatement) -> Self {
+        self.statements.push(statement);
+        self
+    }
+
+    fn with_effect<A, R>(self, effect: PolicyEffect, actions: &[A], resources: &[R]) -> Self
+    where
+        A: AsRef<str>,
+        R: AsRef<str>,
+    {
+        self.statement(PolicyStatement {
+            sid: None,
+            effect,
+            actions: actions.iter().map(|a| a.as_ref().to_owned()).collect(),
+            resources: resources.iter().map(|r| r.as_ref().to_owned()).collect(),
+        })
+    }
+
+    /// Serializes the policy to the IAM policy JSON expected by STS. Returns an error if the
+    /// policy has no statements or is longer than `MAX_SESSION_POLICY_LEN`.
+    pub fn to_json(&self) -> Result<String, CredentialsError> {
+        if self.statements.is_empty() {
+            return Err(CredentialsError::new(
+                "session policy must have at least one statement",
+            ));
+        }
+        let mut out = String::new();
+        out.push_str("{\"Version\":");
+        write_json_string(&mut out, POLICY_VERSION);
+        out.push_str(",\"Statement\":[");
+        for (i, statement) in self.statements.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push('{');
+            if let Some(ref sid) = statement.sid {
+                out.push_str("\"Sid\":");
+                write_json_string(&mut out, sid);
+                out.push(',');
+            }
+            out.push_str("\"Effect\":");
+            write_json_string(&mut out, statement.effect.as_str());
+            out.push_str(",\"Action\":");
+            write_json_array(&mut out, &statement.actions);
+            out.push_str(",\"Resource\":");
+            write_json_array(&mut out, &statement.resources);
+            out.push('}');
+        }
+        out.push_str("]}");
+
+        let len = out.chars().count();
+        if len > MAX_SESSION_POLICY_LEN {
+            return Err(CredentialsError::new(format!(
+                "session policy is {} characters long, the maximum is {}",
+                len, MAX_SESSION_POLICY_LEN
+            )));
+        }
+        Ok(out)
+    }
+}
+
+fn write_json_array(out: &mut String, values: &[String]) {
+    out.push('[');
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_json_string(out, value);
+    }
+    out.push(']');
+}
+
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_known_good_policy() {
+        let policy = SessionPolicy::new()
+            .allow(
+                &["s3:GetObject", "s3:ListBucket"],
+                &["arn:aws:s3:::my-bucket", "arn:aws:s3:::my-bucket/*"],
+            )
+            .statement(PolicyStatement {
+                sid: Some("NoDeletes".to_owned()),
+                effect: PolicyEffect::Deny,
+                actions: vec!["s3:DeleteObject".to_owned()],
+                resources: vec!["*".to_owned()],
+            });
+
+        assert_eq!(
+            policy.to_json().unwrap(),
+            concat!(
+                r#"{"Version":"2012-10-17","Statement":["#,
+                r#"{"Effect":"Allow","Action":["s3:GetObject","s3:ListBucket"],"#,
+                r#""Resource":["arn:aws:s3:::my-bucket","arn:aws:s3:::my-bucket/*"]},"#,
+                r#"{"Sid":"NoDeletes","Effect":"Deny","Action":["s3:DeleteObject"],"#,
+                r#""Resource":["*"]}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let policy = SessionPolicy::new()
+            .allow(&["s3:GetObject"], &["arn:aws:s3:::bucket/\"quoted\"\\key"])
+            .to_json()
+            .unwrap();
+        assert!(policy.contains(r#"["arn:aws:s3:::bucket/\"quoted\"\\key"]"#));
+    }
+
+    #[test]
+    fn rejects_empty_and_oversized_policies() {
+        assert!(SessionPolicy::new().to_json().is_err());
+
+        let resource = format!("arn:aws:s3:::{}", "b".repeat(MAX_SESSION_POLICY_LEN));
+        let err = SessionPolicy::new()
+            .allow(&["s3:GetObject"], &[resource])
+            .to_json()
+            .unwrap_err();
+        assert!(err.to_string().contains("the maximum is 2048"), "{}", err);
+    }
+}
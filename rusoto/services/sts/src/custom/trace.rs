@@ -0,0 +1,195 @@
+use std::future::Future;
+
+use rusoto_core::credential::AwsCredentials;
+use rusoto_core::RusotoError;
+
+/// Awaits `fetch`, an STS call returning credentials. With the `tracing` feature the call runs
+/// in an `aws.credentials` span whose `otel.name` names the operation, e.g.
+/// `aws.credentials.assume_role`, and an event records its expiration or the kind of error.
+/// The credentials themselves are never recorded.
+pub(crate) async fn traced<E, F>(
+    operation: &'static str,
+    role_arn: Option<&str>,
+    fetch: F,
+) -> Result<AwsCredentials, RusotoError<E>>
+where
+    F: Future<Output = Result<AwsCredentials, RusotoError<E>>>,
+{
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!(
+            "aws.credentials",
+            otel.name = %format_args!("aws.credentials.{}", snake_case(operation)),
+            operation,
+            role_arn
+        );
+        let result = fetch.instrument(span.clone()).await;
+        span.in_scope(|| match result {
+            Ok(ref creds) => match creds.expires_at() {
+                Some(expiration) => tracing::info!(
+                    expiration = %expiration.to_rfc3339(),
+                    "fetched STS credentials"
+                ),
+                None => tracing::info!("fetched STS credentials"),
+            },
+            Err(ref err) => tracing::warn!(
+                error_kind = error_kind(err),
+                "failed to fetch STS credentials"
+            ),
+        });
+        result
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (operation, role_arn);
+        fetch.await
+    }
+}
+
+/// Converts an operation name like `AssumeRole` to `assume_role`.
+#[cfg(feature = "tracing")]
+fn snake_case(operation: &str) -> String {
+    let mut name = String::with_capacity(operation.len() + 4);
+    for (i, c) in operation.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            name.push('_');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}
+
+#[cfg(feature = "tracing")]
+fn error_kind<E>(err: &RusotoError<E>) -> &'static str {
+    match *err {
+        RusotoError::Service(_) => "service",
+        RusotoError::HttpDispatch(_) => "http_dispatch",
+        RusotoError::Credentials(_) => "credentials",
+        RusotoError::Validation(_) => "validation",
+        RusotoError::ParseError(_) => "parse",
+        RusotoError::Unknown(_) => "unknown",
+        RusotoError::Blocking => "blocking",
+        RusotoError::Timeout(_) => "timeout",
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use rusoto_core::credential::CredentialsError;
+    use std::fmt::{self, Write};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records span names and event fields as `name=value` strings.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    struct Fields<'a>(&'a mut String);
+
+    impl<'a> Visit for Fields<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut line = format!("span {}", span.metadata().name());
+            span.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut line = format!("event {}", event.metadata().level());
+            event.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn capture<F>(fetch: F) -> Vec<String>
+    where
+        F: Future<Output = Result<AwsCredentials, RusotoError<()>>>,
+    {
+        let capture = Capture::default();
+        let lines = capture.0.clone();
+        tracing::subscriber::with_default(capture, || {
+            let _ = futures::executor::block_on(traced(
+                "AssumeRole",
+                Some("arn:aws:iam::123456789012:role/test"),
+                fetch,
+            ));
+        });
+        let lines = lines.lock().unwrap().clone();
+        lines
+    }
+
+    #[test]
+    fn records_expiration_without_secrets() {
+        let expiration = "2030-01-01T00:00:00Z".parse().unwrap();
+        let creds = AwsCredentials::new(
+            "AKIDSECRETKEY",
+            "very-secret",
+            Some("session-token".to_owned()),
+            Some(expiration),
+        );
+        let lines = capture(async move { Ok(creds) });
+
+        assert_eq!(lines.len(), 2, "{:?}", lines);
+        assert!(lines[0].starts_with("span aws.credentials"), "{:?}", lines);
+        assert!(
+            lines[0].contains("otel.name=aws.credentials.assume_role"),
+            "{:?}",
+            lines
+        );
+        assert!(lines[0].contains("operation=\"AssumeRole\""), "{:?}", lines);
+        assert!(lines[0].contains("role/test"), "{:?}", lines);
+        assert!(
+            lines[1].contains("expiration=2030-01-01T00:00:00+00:00"),
+            "{:?}",
+            lines
+        );
+        for secret in &["AKIDSECRETKEY", "very-secret", "session-token"] {
+            assert!(
+                lines.iter().all(|line| !line.contains(secret)),
+                "{:?}",
+                lines
+            );
+        }
+    }
+
+    #[test]
+    fn records_error_kind() {
+        let lines = capture(async {
+            Err(RusotoError::Credentials(CredentialsError::new(
+                "secret details",
+            )))
+        });
+
+        assert_eq!(lines.len(), 2, "{:?}", lines);
+        assert!(lines[1].starts_with("event WARN"), "{:?}", lines);
+        assert!(
+            lines[1].contains("error_kind=\"credentials\""),
+            "{:?}",
+            lines
+        );
+        assert!(!lines[1].contains("secret details"), "{:?}", lines);
+    }
+}
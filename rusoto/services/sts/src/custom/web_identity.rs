@@ -1,10 +1,24 @@
-use crate::custom::credential::NewAwsCredsForStsCreds;
-use crate::{AssumeRoleWithWebIdentityRequest, Sts, StsClient, PolicyDescriptorType};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::custom::credential::{
+    policy_descriptors, validate_provider_id, validate_session_name, with_fetch_timeout,
+    NewAwsCredsForStsCreds,
+};
+use crate::custom::error::{sts_credentials_error, StsProviderError, StsProviderErrorKind};
+use crate::custom::metrics::{MetricsRecorder, SharedRecorder};
+use crate::custom::trace::traced;
+use crate::{
+    AssumeRoleWithWebIdentityError, AssumeRoleWithWebIdentityRequest, PolicyDescriptorType, Sts,
+    StsClient,
+};
 use rusoto_core::credential::{
     AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
+    DEFAULT_REFRESH_BUFFER,
 };
 use rusoto_core::request::HttpClient;
-use rusoto_core::{Client, Region};
+use rusoto_core::{Client, Region, RusotoError};
 
 use async_trait::async_trait;
 
@@ -16,6 +30,11 @@ const AWS_ROLE_SESSION_NAME: &str = "AWS_ROLE_SESSION_NAME";
 
 /// WebIdentityProvider using OpenID Connect bearer token to retrieve AWS IAM credentials.
 ///
+/// The credentials are kept and returned again until they expire within the refresh buffer,
+/// after which the token is read again and the role assumed again. If STS rejects the token as
+/// expired or invalid, it's read and sent once more, since the token may have been renewed
+/// since it was read, e.g. by Kubernetes rotating a projected service account token.
+///
 /// See https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRoleWithWebIdentity.html for
 /// more details.
 #[derive(Debug, Clone)]
@@ -39,10 +58,42 @@ pub struct WebIdentityProvider {
     pub policy: Option<String>,
     /// The Amazon Resource Names (ARNs) of the IAM managed policies that you want to use as managed session policies.
     pub policy_arns: Option<Vec<PolicyDescriptorType>>,
+    /// The fully qualified host component of the domain name of the identity provider, only
+    /// given for OAuth 2.0 access tokens, e.g. `www.amazon.com` or `graph.facebook.com`. Leave
+    /// it out for OpenID Connect ID tokens. Fetching credentials fails if it has a URL scheme,
+    /// port or path.
+    pub provider_id: Option<String>,
+    /// The maximum time a credentials fetch may take, after which it fails. By default fetches
+    /// take as long as the HTTP client allows.
+    pub fetch_timeout: Option<Duration>,
+    /// Whether the token, as given or read from its file, is base64 encoded and must be decoded
+    /// before sending it to STS. Fetching credentials fails if it isn't valid base64 of UTF-8
+    /// text. By default the token is sent as it is.
+    pub base64_token: bool,
+    refresh_buffer: Duration,
+    sts: Option<SharedSts>,
+    metrics: SharedRecorder,
+    /// The last credentials fetched, shared between clones.
+    cached: Arc<Mutex<Option<AwsCredentials>>>,
+    /// The kind of error the last fetch failed with, shared between clones.
+    last_error_kind: Arc<Mutex<Option<StsProviderErrorKind>>>,
+}
+
+/// The `Sts` client a `WebIdentityProvider` was given.
+#[derive(Clone)]
+struct SharedSts(Arc<dyn Sts + Send + Sync>);
+
+impl fmt::Debug for SharedSts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sts")
+    }
 }
 
 impl WebIdentityProvider {
     /// Create new WebIdentityProvider by explicitly passing its configuration.
+    ///
+    /// The session name isn't checked until credentials are fetched, prefer `try_new` for a
+    /// fixed session name, which checks it right away.
     pub fn new<A, B, C>(web_identity_token: A, role_arn: B, role_session_name: Option<C>) -> Self
     where
         A: Into<Variable<Secret, CredentialsError>>,
@@ -55,10 +106,90 @@ impl WebIdentityProvider {
             role_session_name: role_session_name.map(|v| v.into()),
             duration_seconds: None,
             policy: None,
-            policy_arns: None
+            policy_arns: None,
+            provider_id: None,
+            fetch_timeout: None,
+            base64_token: false,
+            refresh_buffer: DEFAULT_REFRESH_BUFFER,
+            sts: None,
+            metrics: SharedRecorder::default(),
+            cached: Arc::new(Mutex::new(None)),
+            last_error_kind: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Create new WebIdentityProvider like `new` with a fixed session name, returning an
+    /// error if `role_session_name` isn't 2 to 64 characters matching `[\w+=,.@-]*`.
+    pub fn try_new<A, B, S>(
+        web_identity_token: A,
+        role_arn: B,
+        role_session_name: Option<S>,
+    ) -> Result<Self, CredentialsError>
+    where
+        A: Into<Variable<Secret, CredentialsError>>,
+        B: Into<Variable<String, CredentialsError>>,
+        S: Into<String>,
+    {
+        let role_session_name = role_session_name.map(Into::into);
+        if let Some(ref session_name) = role_session_name {
+            validate_session_name(session_name)?;
+        }
+        Ok(Self::new(
+            web_identity_token,
+            role_arn,
+            role_session_name.map(Some),
+        ))
+    }
+
+    /// Set how long before their expiry the credentials are fetched again. Defaults to
+    /// `DEFAULT_REFRESH_BUFFER`.
+    pub fn with_refresh_buffer(mut self, buffer: Duration) -> Self {
+        self.refresh_buffer = buffer;
+        self
+    }
+
+    /// Sets the ARNs of IAM managed policies to use as managed session policies.
+    /// They may be combined with the inline `policy`.
+    pub fn with_policy_arns<I, S>(mut self, policy_arns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.policy_arns = Some(policy_descriptors(policy_arns));
+        self
+    }
+
+    /// Sets the identity provider of OAuth 2.0 access tokens, see `provider_id`.
+    pub fn with_provider_id<S: Into<String>>(mut self, provider_id: S) -> Self {
+        self.provider_id = Some(provider_id.into());
+        self
+    }
+
+    /// Sets whether the token is base64 encoded, see `base64_token`.
+    pub fn with_base64_token(mut self, base64_token: bool) -> Self {
+        self.base64_token = base64_token;
+        self
+    }
+
+    /// Sets the `MetricsRecorder` every call to `AssumeRoleWithWebIdentity` is recorded with.
+    pub fn with_metrics_recorder<R>(mut self, recorder: R) -> Self
+    where
+        R: MetricsRecorder + 'static,
+    {
+        self.metrics = SharedRecorder::new(recorder);
+        self
+    }
+
+    /// Call `AssumeRoleWithWebIdentity` with `sts` instead of an unsigned `StsClient` in the
+    /// default region.
+    pub fn with_sts_client<C>(mut self, sts: C) -> Self
+    where
+        C: Sts + Send + Sync + 'static,
+    {
+        self.sts = Some(SharedSts(Arc::new(sts)));
+        self
+    }
+
     /// Creat a WebIdentityProvider from the following environment variables:
     ///
     /// - `AWS_WEB_IDENTITY_TOKEN_FILE` path to the web identity token file.
@@ -103,24 +234,28 @@ impl WebIdentityProvider {
         // better correlation analysis in CloudTrail.
         "WebIdentitySession".to_string()
     }
-}
 
-#[async_trait]
-impl ProvideAwsCredentials for WebIdentityProvider {
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let http_client = match HttpClient::new() {
-            Ok(c) => c,
-            Err(e) => return Err(CredentialsError::new(e)),
-        };
-        let client = Client::new_not_signing(http_client);
-        let sts = StsClient::new_with_client(client, Region::default());
+    /// Reads the token and assumes the role with it.
+    async fn assume_role(
+        &self,
+        sts: &(dyn Sts + Send + Sync),
+    ) -> Result<AwsCredentials, RusotoError<AssumeRoleWithWebIdentityError>> {
         let mut req = AssumeRoleWithWebIdentityRequest::default();
 
         req.role_arn = self.role_arn.resolve()?;
-        req.web_identity_token = self.web_identity_token.resolve()?.as_ref().to_string();
+        let token = self.web_identity_token.resolve()?;
+        req.web_identity_token = if self.base64_token {
+            decode_base64_token(token.as_ref())?
+        } else {
+            token.as_ref().to_string()
+        };
         req.policy = self.policy.to_owned();
         req.duration_seconds = self.duration_seconds.to_owned();
         req.policy_arns = self.policy_arns.to_owned();
+        if let Some(ref provider_id) = self.provider_id {
+            validate_provider_id(provider_id)?;
+        }
+        req.provider_id = self.provider_id.to_owned();
         req.role_session_name = match self.role_session_name {
             Some(ref role_session_name) => match role_session_name.resolve()? {
                 Some(session_name) => session_name,
@@ -128,24 +263,137 @@ impl ProvideAwsCredentials for WebIdentityProvider {
             },
             None => Self::create_session_name(),
         };
+        validate_session_name(&req.role_session_name)?;
+
+        let role_arn = req.role_arn.clone();
+        let fetch = with_fetch_timeout(self.fetch_timeout, sts.assume_role_with_web_identity(req));
+        traced("AssumeRoleWithWebIdentity", Some(&role_arn), async {
+            let role = fetch.await?;
+            match role.credentials {
+                None => Err(StsProviderError::no_credentials("AssumeRoleWithWebIdentity").into()),
+                Some(c) => Ok(AwsCredentials::new_for_credentials(c)?),
+            }
+        })
+        .await
+    }
+
+    fn cached(&self) -> Option<AwsCredentials> {
+        match self.cached.lock() {
+            Ok(cached) => cached.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Returns the kind of error the last credentials fetch failed with, or `None` if it
+    /// succeeded or no credentials were fetched yet. Credentials returned from the cache
+    /// don't change it.
+    pub fn last_error_kind(&self) -> Option<StsProviderErrorKind> {
+        match self.last_error_kind.lock() {
+            Ok(kind) => *kind,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    /// Checks that the provider can get credentials, e.g. for a readiness probe, by calling
+    /// `credentials` and discarding them. Fails with the error of the fetch.
+    ///
+    /// `check` makes a network call to STS, unless it finds cached credentials that don't
+    /// need to be refreshed yet.
+    pub async fn check(&self) -> Result<(), CredentialsError> {
+        self.credentials().await.map(|_| ())
+    }
+
+    /// Creates an STS client if none was given, and assumes the role, again if the token was
+    /// rejected.
+    async fn fetch(&self) -> Result<AwsCredentials, CredentialsError> {
+        let sts = match self.sts {
+            Some(SharedSts(ref sts)) => sts.clone(),
+            None => {
+                let http_client = match HttpClient::new() {
+                    Ok(c) => c,
+                    Err(e) => return Err(CredentialsError::new(e)),
+                };
+                let client = Client::new_not_signing(http_client);
+                Arc::new(StsClient::new_with_client(client, Region::default()))
+            }
+        };
 
-        let assume_role = sts.assume_role_with_web_identity(req).await;
-        match assume_role {
-            Err(e) => Err(CredentialsError::new(e)),
-            Ok(role) => match role.credentials {
-                None => Err(CredentialsError::new(format!(
-                    "No credentials found in AssumeRoleWithWebIdentityResponse: {:?}",
-                    role
-                ))),
-                Some(c) => AwsCredentials::new_for_credentials(c),
+        match self.assume_role(&*sts).await {
+            Err(ref e) if is_token_rejected(e) => match self.assume_role(&*sts).await {
+                Err(e) if is_token_rejected(&e) => Err(sts_credentials_error(
+                    format!(
+                        "The web identity token was rejected again after reading it anew: {}",
+                        e
+                    ),
+                    e,
+                )),
+                result => result.map_err(into_credentials_error),
             },
+            result => result.map_err(into_credentials_error),
+        }
+    }
+}
+
+/// Decodes a base64 encoded web identity token, ignoring whitespace around it.
+fn decode_base64_token(token: &str) -> Result<String, CredentialsError> {
+    let decoded = base64::decode(token.trim()).map_err(|err| {
+        CredentialsError::new(format!(
+            "The web identity token is not valid base64: {}",
+            err
+        ))
+    })?;
+    String::from_utf8(decoded).map_err(|_| {
+        CredentialsError::new("The base64 decoded web identity token is not valid UTF-8")
+    })
+}
+
+/// Whether STS rejected the web identity token, which may succeed once it's read again.
+fn is_token_rejected(err: &RusotoError<AssumeRoleWithWebIdentityError>) -> bool {
+    matches!(
+        err,
+        RusotoError::Service(AssumeRoleWithWebIdentityError::ExpiredToken(_))
+            | RusotoError::Service(AssumeRoleWithWebIdentityError::InvalidIdentityToken(_))
+    )
+}
+
+fn into_credentials_error(err: RusotoError<AssumeRoleWithWebIdentityError>) -> CredentialsError {
+    match err {
+        RusotoError::Credentials(e) => e,
+        e => sts_credentials_error(e.to_string(), e),
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for WebIdentityProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        if let Some(credentials) = self.cached() {
+            if !credentials.needs_refresh(self.refresh_buffer) {
+                return Ok(credentials);
+            }
+        }
+        let result = self
+            .metrics
+            .recorded("AssumeRoleWithWebIdentity", self.fetch())
+            .await;
+        let kind = result.as_ref().err().map(StsProviderErrorKind::of);
+        match self.last_error_kind.lock() {
+            Ok(mut last_error_kind) => *last_error_kind = kind,
+            Err(poisoned) => *poisoned.into_inner() = kind,
         }
+        let credentials = result?;
+        match self.cached.lock() {
+            Ok(mut cached) => *cached = Some(credentials.clone()),
+            Err(poisoned) => *poisoned.into_inner() = Some(credentials.clone()),
+        }
+        Ok(credentials)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{AssumeRoleWithWebIdentityResponse, MockSts};
+    use chrono::{Duration as ChronoDuration, Utc};
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -154,6 +402,49 @@ mod tests {
         WebIdentityProvider::new(Secret::from("".to_string()), "", Some(Some("".to_string())));
     }
 
+    #[test]
+    fn try_new_validates_session_name() {
+        let try_new = |session_name: &str| {
+            WebIdentityProvider::try_new(
+                Secret::from("token".to_string()),
+                "role",
+                Some(session_name),
+            )
+            .map(|_| ())
+            .map_err(|err| err.message)
+        };
+
+        assert_eq!(try_new("pod-1@namespace"), Ok(()));
+        assert!(WebIdentityProvider::try_new(
+            Secret::from("token".to_string()),
+            "role",
+            None::<String>
+        )
+        .is_ok());
+        assert_eq!(
+            try_new("a"),
+            Err("session name must be between 2 and 64 characters long, got 1".to_owned())
+        );
+        assert!(try_new(&"a".repeat(65)).unwrap_err().contains("got 65"));
+        assert_eq!(
+            try_new("pod/1"),
+            Err("session name contains invalid character '/'".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_session_names_read_when_fetching() {
+        let provider = WebIdentityProvider::new(
+            Secret::from("token".to_string()),
+            "role",
+            Some(Variable::with_value(Some("a b".to_string()))),
+        )
+        .with_sts_client(MockSts::default());
+
+        let err = provider.credentials().await.unwrap_err();
+        assert!(err.message.contains("invalid character ' '"), "{}", err);
+    }
+
     #[test]
     fn from_k8s_env() -> Result<(), CredentialsError> {
         const TOKEN_VALUE: &str = "secret";
@@ -172,4 +463,249 @@ mod tests {
         assert_eq!(token.as_ref(), TOKEN_VALUE);
         Ok(())
     }
+
+    fn token_sequence(tokens: &'static [&'static str]) -> Variable<Secret, CredentialsError> {
+        let reads = Arc::new(Mutex::new(0));
+        Variable::dynamic(move || {
+            let mut reads = reads.lock().unwrap();
+            *reads += 1;
+            Ok(Secret::from(
+                tokens[(*reads - 1).min(tokens.len() - 1)].to_owned(),
+            ))
+        })
+    }
+
+    fn provider(sts: &MockSts, tokens: &'static [&'static str]) -> WebIdentityProvider {
+        WebIdentityProvider::new(
+            token_sequence(tokens),
+            "arn:aws:iam::123456789012:role/web",
+            Some(Some("session".to_owned())),
+        )
+        .with_sts_client(sts.clone())
+    }
+
+    fn assumed(expires_in: ChronoDuration) -> AssumeRoleWithWebIdentityResponse {
+        AssumeRoleWithWebIdentityResponse {
+            credentials: Some(MockSts::credentials(Utc::now() + expires_in)),
+            ..Default::default()
+        }
+    }
+
+    fn sent_tokens(sts: &MockSts) -> Vec<String> {
+        sts.assume_role_with_web_identity_requests()
+            .into_iter()
+            .map(|request| request.web_identity_token)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn forwards_policy_arns_and_provider_id() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::hours(1))));
+        let provider = provider(&sts, &["token"])
+            .with_policy_arns(vec!["arn:aws:iam::aws:policy/ReadOnlyAccess"])
+            .with_provider_id("graph.facebook.com");
+
+        provider.credentials().await.unwrap();
+
+        let request = sts.assume_role_with_web_identity_requests().remove(0);
+        assert_eq!(
+            request.policy_arns,
+            Some(vec![PolicyDescriptorType {
+                arn: Some("arn:aws:iam::aws:policy/ReadOnlyAccess".to_owned()),
+            }])
+        );
+        assert_eq!(request.provider_id.as_deref(), Some("graph.facebook.com"));
+    }
+
+    #[tokio::test]
+    async fn rejects_provider_ids_with_a_scheme() {
+        let sts = MockSts::new();
+        let provider = provider(&sts, &["token"]).with_provider_id("https://graph.facebook.com");
+
+        let err = provider.credentials().await.unwrap_err();
+
+        assert!(err.message.contains("without a URL scheme"), "{}", err);
+        assert!(sent_tokens(&sts).is_empty());
+    }
+
+    #[tokio::test]
+    async fn sends_tokens_verbatim_or_base64_decoded() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::seconds(10))))
+            .push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::seconds(10))));
+
+        provider(&sts, &["ZXlKaGJHY2lPaUpTVXpJMU5pSjk="])
+            .credentials()
+            .await
+            .unwrap();
+        provider(&sts, &["ZXlKaGJHY2lPaUpTVXpJMU5pSjk=\n"])
+            .with_base64_token(true)
+            .credentials()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sent_tokens(&sts),
+            vec!["ZXlKaGJHY2lPaUpTVXpJMU5pSjk=", "eyJhbGciOiJSUzI1NiJ9"]
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_tokens_that_are_not_base64() {
+        let sts = MockSts::new();
+        let provider = provider(&sts, &["eyJhbGciOiJSUzI1NiJ9.e30.sig"]).with_base64_token(true);
+
+        let err = provider.credentials().await.unwrap_err();
+
+        assert!(err.message.contains("not valid base64"), "{}", err);
+        assert!(sent_tokens(&sts).is_empty());
+    }
+
+    #[tokio::test]
+    async fn reuses_credentials_until_the_refresh_buffer() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::seconds(10))))
+            .push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::hours(1))));
+        let provider = provider(&sts, &["token-1", "token-2"]);
+
+        // The first credentials expire within the default buffer of 20 seconds.
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+        provider.clone().credentials().await.unwrap();
+
+        assert_eq!(sent_tokens(&sts), vec!["token-1", "token-2"]);
+    }
+
+    #[tokio::test]
+    async fn rereads_an_expired_token() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Err(RusotoError::Service(
+            AssumeRoleWithWebIdentityError::ExpiredToken("token expired".into()),
+        )))
+        .push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::hours(1))));
+        let provider = provider(&sts, &["stale", "renewed"]);
+
+        let credentials = provider.credentials().await.unwrap();
+
+        assert_eq!(credentials.aws_access_key_id(), "ASIAMOCKACCESSKEY");
+        assert_eq!(sent_tokens(&sts), vec!["stale", "renewed"]);
+    }
+
+    #[tokio::test]
+    async fn fails_if_the_reread_token_is_rejected() {
+        let sts = MockSts::new();
+        for _ in 0..2 {
+            sts.push_assume_role_with_web_identity(Err(RusotoError::Service(
+                AssumeRoleWithWebIdentityError::InvalidIdentityToken("bad token".into()),
+            )));
+        }
+        let provider = provider(&sts, &["bad"]);
+
+        let err = provider.credentials().await.unwrap_err();
+
+        assert!(err.message.contains("rejected again"), "{}", err);
+        assert_eq!(sent_tokens(&sts).len(), 2);
+        assert_eq!(
+            StsProviderError::find(&err),
+            Some(&StsProviderError::Service {
+                message: "bad token".to_owned(),
+                request_id: None,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_the_request_id_of_rejected_tokens() {
+        use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+        let body = r#"<ErrorResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <Error>
+    <Type>Sender</Type>
+    <Code>ExpiredTokenException</Code>
+    <Message>Token expired: current date/time 1700000000 must be before the expiration date/time 1690000000</Message>
+  </Error>
+  <RequestId>4fa5fc25-2a56-4a2b-8f6b-0123456789ab</RequestId>
+</ErrorResponse>"#;
+        let dispatcher = MockRequestDispatcher::with_status(403)
+            .with_body(body)
+            .with_header("x-amzn-RequestId", "4fa5fc25-2a56-4a2b-8f6b-0123456789ab");
+        let client = StsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let provider = WebIdentityProvider::new(
+            Secret::from("token".to_owned()),
+            "arn:aws:iam::123456789012:role/web",
+            Some(Some("session".to_owned())),
+        )
+        .with_sts_client(client);
+
+        let err = provider.credentials().await.unwrap_err();
+
+        assert!(err.message.contains("rejected again"), "{}", err);
+        assert_eq!(
+            StsProviderError::find(&err).and_then(StsProviderError::request_id),
+            Some("4fa5fc25-2a56-4a2b-8f6b-0123456789ab")
+        );
+    }
+
+    #[tokio::test]
+    async fn records_fetches_but_not_cached_credentials() {
+        use crate::custom::metrics::{FetchOutcome, InMemoryMetricsRecorder};
+
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Ok(AssumeRoleWithWebIdentityResponse::default()))
+            .push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::hours(1))));
+        let recorder = Arc::new(InMemoryMetricsRecorder::new());
+        let provider = provider(&sts, &["token"]).with_metrics_recorder(recorder.clone());
+
+        provider.credentials().await.unwrap_err();
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+
+        let events = recorder.events();
+        assert_eq!(events.len(), 2, "{:?}", events);
+        assert!(events
+            .iter()
+            .all(|event| event.operation == "AssumeRoleWithWebIdentity"));
+        assert_eq!(
+            events[0].outcome,
+            FetchOutcome::Failure(StsProviderErrorKind::NoCredentials)
+        );
+        assert_eq!(events[1].outcome, FetchOutcome::Success);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_kind_of_the_last_error() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Ok(AssumeRoleWithWebIdentityResponse::default()))
+            .push_assume_role_with_web_identity(Ok(assumed(ChronoDuration::hours(1))));
+        let provider = provider(&sts, &["token-1", "token-2"]);
+        assert_eq!(provider.last_error_kind(), None);
+
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(
+            err.message,
+            "No credentials in STS AssumeRoleWithWebIdentity response"
+        );
+        assert_eq!(
+            provider.last_error_kind(),
+            Some(StsProviderErrorKind::NoCredentials)
+        );
+
+        provider.credentials().await.unwrap();
+        assert_eq!(provider.clone().last_error_kind(), None);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_errors() {
+        let sts = MockSts::new();
+        sts.push_assume_role_with_web_identity(Err(RusotoError::Service(
+            AssumeRoleWithWebIdentityError::RegionDisabled("disabled".into()),
+        )));
+        let provider = provider(&sts, &["token"]);
+
+        let err = provider.credentials().await.unwrap_err();
+
+        assert!(err.message.contains("disabled"), "{}", err);
+        assert!(!err.message.contains("rejected again"), "{}", err);
+        assert_eq!(sent_tokens(&sts).len(), 1);
+    }
 }
@@ -52,7 +52,7 @@ impl StsClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -109,6 +109,8 @@ pub struct AssumeRoleRequest {
     pub role_session_name: String,
     /// <p>The identification number of the MFA device that is associated with the user who is making the <code>AssumeRole</code> call. Specify this value if the trust policy of the role being assumed includes a condition that requires MFA authentication. The value is either the serial number for a hardware device (such as <code>GAHT12345678</code>) or an Amazon Resource Name (ARN) for a virtual device (such as <code>arn:aws:iam::123456789012:mfa/user</code>).</p> <p>The regex used to validate this parameter is a string of characters consisting of upper- and lower-case alphanumeric characters with no spaces. You can also include underscores or any of the following characters: =,.@-</p>
     pub serial_number: Option<String>,
+    /// <p>The source identity specified by the principal that is calling the <code>AssumeRole</code> operation.</p> <p>You can require users to specify a source identity when they assume a role. You do this by using the <code>sts:SourceIdentity</code> condition key in a role trust policy. You can use source identity information in CloudTrail logs to determine who took actions with a role. You can use the <code>aws:SourceIdentity</code> condition key to further control access to Amazon Web Services resources based on the value of source identity. For more information about using source identity, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_control-access_monitor.html">Monitor and control actions taken with assumed roles</a> in the <i>IAM User Guide</i>.</p> <p>The regex used to validate this parameter is a string of characters consisting of upper- and lower-case alphanumeric characters with no spaces. You can also include underscores or any of the following characters: =,.@-. You cannot use a value that begins with the text <code>aws:</code>. This prefix is reserved for Amazon Web Services internal use.</p>
+    pub source_identity: Option<String>,
     /// <p>A list of session tags that you want to pass. Each session tag consists of a key name and an associated value. For more information about session tags, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Tagging AWS STS Sessions</a> in the <i>IAM User Guide</i>.</p> <p>This parameter is optional. You can pass up to 50 session tags. The plain text session tag keys can’t exceed 128 characters, and the values can’t exceed 256 characters. For these and additional limits, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-limits.html#reference_iam-limits-entity-length">IAM and STS Character Limits</a> in the <i>IAM User Guide</i>.</p> <note> <p>An AWS conversion compresses the passed session policies and session tags into a packed binary format that has a separate limit. Your request can fail for this limit even if your plain text meets the other requirements. The <code>PackedPolicySize</code> response element indicates by percentage how close the policies and tags for your request are to the upper size limit. </p> </note> <p>You can pass a session tag with the same key as a tag that is already attached to the role. When you do, session tags override a role tag with the same key. </p> <p>Tag key–value pairs are not case sensitive, but case is preserved. This means that you cannot have separate <code>Department</code> and <code>department</code> tag keys. Assume that the role has the <code>Department</code>=<code>Marketing</code> tag and you pass the <code>department</code>=<code>engineering</code> session tag. <code>Department</code> and <code>department</code> are not saved as separate tags, and the session tag passed in the request takes precedence over the role tag.</p> <p>Additionally, if you used temporary credentials to perform this operation, the new session inherits any transitive session tags from the calling session. If you pass a session tag with the same key as an inherited tag, the operation fails. To view the inherited tags for a session, see the AWS CloudTrail logs. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/session-tags.html#id_session-tags_ctlogs">Viewing Session Tags in CloudTrail</a> in the <i>IAM User Guide</i>.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The value provided by the MFA device, if the trust policy of the role being assumed requires MFA (that is, if the policy includes a condition that tests for MFA). If the role being assumed requires MFA and if the <code>TokenCode</code> value is missing or expired, the <code>AssumeRole</code> call returns an "access denied" error.</p> <p>The format for this parameter, as described by its regex pattern, is a sequence of six numeric digits.</p>
@@ -150,6 +152,9 @@ impl AssumeRoleRequestSerializer {
         if let Some(ref field_value) = obj.serial_number {
             params.put(&format!("{}{}", prefix, "SerialNumber"), &field_value);
         }
+        if let Some(ref field_value) = obj.source_identity {
+            params.put(&format!("{}{}", prefix, "SourceIdentity"), &field_value);
+        }
         if let Some(ref field_value) = obj.tags {
             TagListTypeSerializer::serialize(params, &format!("{}{}", prefix, "Tags"), field_value);
         }
@@ -176,6 +181,8 @@ pub struct AssumeRoleResponse {
     pub credentials: Option<Credentials>,
     /// <p>A percentage value that indicates the packed size of the session policies and session tags combined passed in the request. The request fails if the packed size is greater than 100 percent, which means the policies and tags exceeded the allowed space.</p>
     pub packed_policy_size: Option<i64>,
+    /// <p>The source identity specified by the principal that is calling the <code>AssumeRole</code> operation.</p>
+    pub source_identity: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -204,6 +211,12 @@ impl AssumeRoleResponseDeserializer {
                         stack,
                     )?);
                 }
+                "SourceIdentity" => {
+                    obj.source_identity = Some(SourceIdentityTypeDeserializer::deserialize(
+                        "SourceIdentity",
+                        stack,
+                    )?);
+                }
                 _ => skip_tree(stack),
             }
             Ok(())
@@ -989,6 +1002,14 @@ impl PolicyDescriptorTypeSerializer {
     }
 }
 
+#[allow(dead_code)]
+struct SourceIdentityTypeDeserializer;
+impl SourceIdentityTypeDeserializer {
+    #[allow(dead_code, unused_variables)]
+    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+        xml_util::deserialize_primitive(tag_name, stack, Ok)
+    }
+}
 #[allow(dead_code)]
 struct SubjectDeserializer;
 impl SubjectDeserializer {
@@ -1079,11 +1100,11 @@ impl WebIdentitySubjectTypeDeserializer {
 #[derive(Debug, PartialEq)]
 pub enum AssumeRoleError {
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because the total packed size of the session policies and session tags combined was too large. An AWS conversion compresses the session policy document, session policy ARNs, and session tags into a packed binary format that has a separate limit. The error message indicates by percentage how close the policies and tags are to the upper size limit. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Passing Session Tags in STS</a> in the <i>IAM User Guide</i>.</p> <p>You could receive this error even though you meet other defined session policy and session tag limits. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">IAM and STS Entity Character Limits</a> in the <i>IAM User Guide</i>.</p>
-    PackedPolicyTooLarge(String),
+    PackedPolicyTooLarge(XmlErrorMessage),
     /// <p>STS is not activated in the requested region for the account that is being asked to generate credentials. The account administrator must use the IAM console to activate STS in that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    RegionDisabled(String),
+    RegionDisabled(XmlErrorMessage),
 }
 
 impl AssumeRoleError {
@@ -1096,17 +1117,17 @@ impl AssumeRoleError {
                 match &parsed_error.code[..] {
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(AssumeRoleError::MalformedPolicyDocument(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "PackedPolicyTooLarge" => {
                         return RusotoError::Service(AssumeRoleError::PackedPolicyTooLarge(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RegionDisabledException" => {
                         return RusotoError::Service(AssumeRoleError::RegionDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1123,6 +1144,15 @@ impl AssumeRoleError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AssumeRoleError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            AssumeRoleError::PackedPolicyTooLarge(ref cause) => cause.request_id(),
+            AssumeRoleError::RegionDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AssumeRoleError {
     #[allow(unused_variables)]
@@ -1139,17 +1169,17 @@ impl Error for AssumeRoleError {}
 #[derive(Debug, PartialEq)]
 pub enum AssumeRoleWithSAMLError {
     /// <p>The web identity token that was passed is expired or is not valid. Get a new identity token from the identity provider and then retry the request.</p>
-    ExpiredToken(String),
+    ExpiredToken(XmlErrorMessage),
     /// <p>The identity provider (IdP) reported that authentication failed. This might be because the claim is invalid.</p> <p>If this error is returned for the <code>AssumeRoleWithWebIdentity</code> operation, it can also mean that the claim has expired or has been explicitly revoked. </p>
-    IDPRejectedClaim(String),
+    IDPRejectedClaim(XmlErrorMessage),
     /// <p>The web identity token that was passed could not be validated by AWS. Get a new identity token from the identity provider and then retry the request.</p>
-    InvalidIdentityToken(String),
+    InvalidIdentityToken(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because the total packed size of the session policies and session tags combined was too large. An AWS conversion compresses the session policy document, session policy ARNs, and session tags into a packed binary format that has a separate limit. The error message indicates by percentage how close the policies and tags are to the upper size limit. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Passing Session Tags in STS</a> in the <i>IAM User Guide</i>.</p> <p>You could receive this error even though you meet other defined session policy and session tag limits. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">IAM and STS Entity Character Limits</a> in the <i>IAM User Guide</i>.</p>
-    PackedPolicyTooLarge(String),
+    PackedPolicyTooLarge(XmlErrorMessage),
     /// <p>STS is not activated in the requested region for the account that is being asked to generate credentials. The account administrator must use the IAM console to activate STS in that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    RegionDisabled(String),
+    RegionDisabled(XmlErrorMessage),
 }
 
 impl AssumeRoleWithSAMLError {
@@ -1162,32 +1192,34 @@ impl AssumeRoleWithSAMLError {
                 match &parsed_error.code[..] {
                     "ExpiredTokenException" => {
                         return RusotoError::Service(AssumeRoleWithSAMLError::ExpiredToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "IDPRejectedClaim" => {
                         return RusotoError::Service(AssumeRoleWithSAMLError::IDPRejectedClaim(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "InvalidIdentityToken" => {
                         return RusotoError::Service(AssumeRoleWithSAMLError::InvalidIdentityToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
-                            AssumeRoleWithSAMLError::MalformedPolicyDocument(parsed_error.message),
+                            AssumeRoleWithSAMLError::MalformedPolicyDocument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "PackedPolicyTooLarge" => {
                         return RusotoError::Service(AssumeRoleWithSAMLError::PackedPolicyTooLarge(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RegionDisabledException" => {
                         return RusotoError::Service(AssumeRoleWithSAMLError::RegionDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1204,6 +1236,18 @@ impl AssumeRoleWithSAMLError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AssumeRoleWithSAMLError::ExpiredToken(ref cause) => cause.request_id(),
+            AssumeRoleWithSAMLError::IDPRejectedClaim(ref cause) => cause.request_id(),
+            AssumeRoleWithSAMLError::InvalidIdentityToken(ref cause) => cause.request_id(),
+            AssumeRoleWithSAMLError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            AssumeRoleWithSAMLError::PackedPolicyTooLarge(ref cause) => cause.request_id(),
+            AssumeRoleWithSAMLError::RegionDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AssumeRoleWithSAMLError {
     #[allow(unused_variables)]
@@ -1223,19 +1267,19 @@ impl Error for AssumeRoleWithSAMLError {}
 #[derive(Debug, PartialEq)]
 pub enum AssumeRoleWithWebIdentityError {
     /// <p>The web identity token that was passed is expired or is not valid. Get a new identity token from the identity provider and then retry the request.</p>
-    ExpiredToken(String),
+    ExpiredToken(XmlErrorMessage),
     /// <p>The request could not be fulfilled because the identity provider (IDP) that was asked to verify the incoming identity token could not be reached. This is often a transient error caused by network conditions. Retry the request a limited number of times so that you don't exceed the request rate. If the error persists, the identity provider might be down or not responding.</p>
-    IDPCommunicationError(String),
+    IDPCommunicationError(XmlErrorMessage),
     /// <p>The identity provider (IdP) reported that authentication failed. This might be because the claim is invalid.</p> <p>If this error is returned for the <code>AssumeRoleWithWebIdentity</code> operation, it can also mean that the claim has expired or has been explicitly revoked. </p>
-    IDPRejectedClaim(String),
+    IDPRejectedClaim(XmlErrorMessage),
     /// <p>The web identity token that was passed could not be validated by AWS. Get a new identity token from the identity provider and then retry the request.</p>
-    InvalidIdentityToken(String),
+    InvalidIdentityToken(XmlErrorMessage),
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because the total packed size of the session policies and session tags combined was too large. An AWS conversion compresses the session policy document, session policy ARNs, and session tags into a packed binary format that has a separate limit. The error message indicates by percentage how close the policies and tags are to the upper size limit. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Passing Session Tags in STS</a> in the <i>IAM User Guide</i>.</p> <p>You could receive this error even though you meet other defined session policy and session tag limits. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">IAM and STS Entity Character Limits</a> in the <i>IAM User Guide</i>.</p>
-    PackedPolicyTooLarge(String),
+    PackedPolicyTooLarge(XmlErrorMessage),
     /// <p>STS is not activated in the requested region for the account that is being asked to generate credentials. The account administrator must use the IAM console to activate STS in that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    RegionDisabled(String),
+    RegionDisabled(XmlErrorMessage),
 }
 
 impl AssumeRoleWithWebIdentityError {
@@ -1248,45 +1292,49 @@ impl AssumeRoleWithWebIdentityError {
                 match &parsed_error.code[..] {
                     "ExpiredTokenException" => {
                         return RusotoError::Service(AssumeRoleWithWebIdentityError::ExpiredToken(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "IDPCommunicationError" => {
                         return RusotoError::Service(
                             AssumeRoleWithWebIdentityError::IDPCommunicationError(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "IDPRejectedClaim" => {
                         return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::IDPRejectedClaim(parsed_error.message),
+                            AssumeRoleWithWebIdentityError::IDPRejectedClaim(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "InvalidIdentityToken" => {
                         return RusotoError::Service(
                             AssumeRoleWithWebIdentityError::InvalidIdentityToken(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
                             AssumeRoleWithWebIdentityError::MalformedPolicyDocument(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "PackedPolicyTooLarge" => {
                         return RusotoError::Service(
                             AssumeRoleWithWebIdentityError::PackedPolicyTooLarge(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
                     "RegionDisabledException" => {
                         return RusotoError::Service(
-                            AssumeRoleWithWebIdentityError::RegionDisabled(parsed_error.message),
+                            AssumeRoleWithWebIdentityError::RegionDisabled(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     _ => {}
@@ -1303,6 +1351,21 @@ impl AssumeRoleWithWebIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            AssumeRoleWithWebIdentityError::ExpiredToken(ref cause) => cause.request_id(),
+            AssumeRoleWithWebIdentityError::IDPCommunicationError(ref cause) => cause.request_id(),
+            AssumeRoleWithWebIdentityError::IDPRejectedClaim(ref cause) => cause.request_id(),
+            AssumeRoleWithWebIdentityError::InvalidIdentityToken(ref cause) => cause.request_id(),
+            AssumeRoleWithWebIdentityError::MalformedPolicyDocument(ref cause) => {
+                cause.request_id()
+            }
+            AssumeRoleWithWebIdentityError::PackedPolicyTooLarge(ref cause) => cause.request_id(),
+            AssumeRoleWithWebIdentityError::RegionDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for AssumeRoleWithWebIdentityError {
     #[allow(unused_variables)]
@@ -1331,7 +1394,7 @@ impl Error for AssumeRoleWithWebIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum DecodeAuthorizationMessageError {
     /// <p>The error returned if the message passed to <code>DecodeAuthorizationMessage</code> was invalid. This can happen if the token contains invalid characters, such as linebreaks. </p>
-    InvalidAuthorizationMessage(String),
+    InvalidAuthorizationMessage(XmlErrorMessage),
 }
 
 impl DecodeAuthorizationMessageError {
@@ -1347,7 +1410,7 @@ impl DecodeAuthorizationMessageError {
                     "InvalidAuthorizationMessageException" => {
                         return RusotoError::Service(
                             DecodeAuthorizationMessageError::InvalidAuthorizationMessage(
-                                parsed_error.message,
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
                             ),
                         )
                     }
@@ -1365,6 +1428,15 @@ impl DecodeAuthorizationMessageError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            DecodeAuthorizationMessageError::InvalidAuthorizationMessage(ref cause) => {
+                cause.request_id()
+            }
+        }
+    }
 }
 impl fmt::Display for DecodeAuthorizationMessageError {
     #[allow(unused_variables)]
@@ -1403,6 +1475,11 @@ impl GetAccessKeyInfoError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetAccessKeyInfoError {
     #[allow(unused_variables)]
@@ -1437,6 +1514,11 @@ impl GetCallerIdentityError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {}
+    }
 }
 impl fmt::Display for GetCallerIdentityError {
     #[allow(unused_variables)]
@@ -1449,11 +1531,11 @@ impl Error for GetCallerIdentityError {}
 #[derive(Debug, PartialEq)]
 pub enum GetFederationTokenError {
     /// <p>The request was rejected because the policy document was malformed. The error message describes the specific error.</p>
-    MalformedPolicyDocument(String),
+    MalformedPolicyDocument(XmlErrorMessage),
     /// <p>The request was rejected because the total packed size of the session policies and session tags combined was too large. An AWS conversion compresses the session policy document, session policy ARNs, and session tags into a packed binary format that has a separate limit. The error message indicates by percentage how close the policies and tags are to the upper size limit. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Passing Session Tags in STS</a> in the <i>IAM User Guide</i>.</p> <p>You could receive this error even though you meet other defined session policy and session tag limits. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">IAM and STS Entity Character Limits</a> in the <i>IAM User Guide</i>.</p>
-    PackedPolicyTooLarge(String),
+    PackedPolicyTooLarge(XmlErrorMessage),
     /// <p>STS is not activated in the requested region for the account that is being asked to generate credentials. The account administrator must use the IAM console to activate STS in that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    RegionDisabled(String),
+    RegionDisabled(XmlErrorMessage),
 }
 
 impl GetFederationTokenError {
@@ -1466,17 +1548,19 @@ impl GetFederationTokenError {
                 match &parsed_error.code[..] {
                     "MalformedPolicyDocument" => {
                         return RusotoError::Service(
-                            GetFederationTokenError::MalformedPolicyDocument(parsed_error.message),
+                            GetFederationTokenError::MalformedPolicyDocument(
+                                XmlErrorMessage::from_response(parsed_error.message, &res),
+                            ),
                         )
                     }
                     "PackedPolicyTooLarge" => {
                         return RusotoError::Service(GetFederationTokenError::PackedPolicyTooLarge(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     "RegionDisabledException" => {
                         return RusotoError::Service(GetFederationTokenError::RegionDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1493,6 +1577,15 @@ impl GetFederationTokenError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetFederationTokenError::MalformedPolicyDocument(ref cause) => cause.request_id(),
+            GetFederationTokenError::PackedPolicyTooLarge(ref cause) => cause.request_id(),
+            GetFederationTokenError::RegionDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetFederationTokenError {
     #[allow(unused_variables)]
@@ -1509,7 +1602,7 @@ impl Error for GetFederationTokenError {}
 #[derive(Debug, PartialEq)]
 pub enum GetSessionTokenError {
     /// <p>STS is not activated in the requested region for the account that is being asked to generate credentials. The account administrator must use the IAM console to activate STS in that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    RegionDisabled(String),
+    RegionDisabled(XmlErrorMessage),
 }
 
 impl GetSessionTokenError {
@@ -1522,7 +1615,7 @@ impl GetSessionTokenError {
                 match &parsed_error.code[..] {
                     "RegionDisabledException" => {
                         return RusotoError::Service(GetSessionTokenError::RegionDisabled(
-                            parsed_error.message,
+                            XmlErrorMessage::from_response(parsed_error.message, &res),
                         ))
                     }
                     _ => {}
@@ -1539,6 +1632,13 @@ impl GetSessionTokenError {
         xml_util::start_element("ErrorResponse", stack)?;
         XmlErrorDeserializer::deserialize("Error", stack)
     }
+
+    /// Returns the ID of the failed request, if the error response had one.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            GetSessionTokenError::RegionDisabled(ref cause) => cause.request_id(),
+        }
+    }
 }
 impl fmt::Display for GetSessionTokenError {
     #[allow(unused_variables)]
@@ -41,7 +41,7 @@ impl AWSSupportClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1473,7 +1473,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, AddAttachmentsToSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AddAttachmentsToSetResponse, _>()
     }
 
@@ -1491,7 +1491,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, AddCommunicationToCaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AddCommunicationToCaseResponse, _>()
     }
@@ -1510,7 +1510,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, CreateCaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateCaseResponse, _>()
     }
 
@@ -1528,7 +1528,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeAttachmentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAttachmentResponse, _>()
     }
 
@@ -1546,7 +1546,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeCasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeCasesResponse, _>()
     }
 
@@ -1564,7 +1564,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeCommunicationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeCommunicationsResponse, _>()
     }
@@ -1583,7 +1583,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeServicesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServicesResponse, _>()
     }
 
@@ -1601,7 +1601,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeSeverityLevelsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeSeverityLevelsResponse, _>()
     }
@@ -1629,7 +1629,7 @@ impl AWSSupport for AWSSupportClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTrustedAdvisorCheckRefreshStatusesResponse, _>()
     }
@@ -1657,7 +1657,7 @@ impl AWSSupport for AWSSupportClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTrustedAdvisorCheckResultResponse, _>()
     }
@@ -1685,7 +1685,7 @@ impl AWSSupport for AWSSupportClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTrustedAdvisorCheckSummariesResponse, _>()
     }
@@ -1708,7 +1708,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, DescribeTrustedAdvisorChecksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTrustedAdvisorChecksResponse, _>()
     }
@@ -1731,7 +1731,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, RefreshTrustedAdvisorCheckError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RefreshTrustedAdvisorCheckResponse, _>()
     }
@@ -1750,7 +1750,7 @@ impl AWSSupport for AWSSupportClient {
             .sign_and_dispatch(request, ResolveCaseError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCaseResponse, _>()
     }
 }
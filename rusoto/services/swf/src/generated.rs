@@ -41,7 +41,7 @@ impl SwfClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -5008,7 +5008,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, CountClosedWorkflowExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionCount, _>()
     }
 
@@ -5029,7 +5029,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, CountOpenWorkflowExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionCount, _>()
     }
 
@@ -5050,7 +5050,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, CountPendingActivityTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PendingTaskCount, _>()
     }
 
@@ -5071,7 +5071,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, CountPendingDecisionTasksError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PendingTaskCount, _>()
     }
 
@@ -5146,7 +5146,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, DescribeActivityTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivityTypeDetail, _>()
     }
 
@@ -5164,7 +5164,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, DescribeDomainError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DomainDetail, _>()
     }
 
@@ -5185,7 +5185,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, DescribeWorkflowExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionDetail, _>()
     }
 
@@ -5203,7 +5203,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, DescribeWorkflowTypeError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowTypeDetail, _>()
     }
 
@@ -5224,7 +5224,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, GetWorkflowExecutionHistoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<History, _>()
     }
 
@@ -5242,7 +5242,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListActivityTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivityTypeInfos, _>()
     }
 
@@ -5263,7 +5263,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListClosedWorkflowExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionInfos, _>()
     }
 
@@ -5281,7 +5281,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListDomainsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DomainInfos, _>()
     }
 
@@ -5302,7 +5302,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListOpenWorkflowExecutionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionInfos, _>()
     }
 
@@ -5320,7 +5320,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceOutput, _>()
     }
 
@@ -5338,7 +5338,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, ListWorkflowTypesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<WorkflowTypeInfos, _>()
     }
 
@@ -5356,7 +5356,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, PollForActivityTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivityTask, _>()
     }
 
@@ -5374,7 +5374,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, PollForDecisionTaskError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DecisionTask, _>()
     }
 
@@ -5395,7 +5395,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, RecordActivityTaskHeartbeatError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ActivityTaskStatus, _>()
     }
 
@@ -5587,7 +5587,7 @@ impl Swf for SwfClient {
             .sign_and_dispatch(request, StartWorkflowExecutionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<Run, _>()
     }
 
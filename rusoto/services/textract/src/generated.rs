@@ -41,7 +41,7 @@ impl TextractClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1158,7 +1158,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, AnalyzeDocumentError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AnalyzeDocumentResponse, _>()
     }
 
@@ -1176,7 +1176,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, DetectDocumentTextError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DetectDocumentTextResponse, _>()
     }
 
@@ -1194,7 +1194,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, GetDocumentAnalysisError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetDocumentAnalysisResponse, _>()
     }
 
@@ -1212,7 +1212,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, GetDocumentTextDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDocumentTextDetectionResponse, _>()
     }
@@ -1231,7 +1231,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, StartDocumentAnalysisError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDocumentAnalysisResponse, _>()
     }
@@ -1251,7 +1251,7 @@ impl Textract for TextractClient {
             .sign_and_dispatch(request, StartDocumentTextDetectionError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartDocumentTextDetectionResponse, _>()
     }
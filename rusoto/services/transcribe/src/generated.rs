@@ -41,7 +41,7 @@ impl TranscribeClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -2454,7 +2454,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, CreateMedicalVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateMedicalVocabularyResponse, _>()
     }
@@ -2473,7 +2473,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, CreateVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateVocabularyResponse, _>()
     }
 
@@ -2491,7 +2491,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, CreateVocabularyFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateVocabularyFilterResponse, _>()
     }
@@ -2596,7 +2596,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, GetMedicalTranscriptionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMedicalTranscriptionJobResponse, _>()
     }
@@ -2615,7 +2615,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, GetMedicalVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetMedicalVocabularyResponse, _>()
     }
@@ -2634,7 +2634,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, GetTranscriptionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTranscriptionJobResponse, _>()
     }
 
@@ -2652,7 +2652,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, GetVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetVocabularyResponse, _>()
     }
 
@@ -2670,7 +2670,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, GetVocabularyFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetVocabularyFilterResponse, _>()
     }
 
@@ -2689,7 +2689,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, ListMedicalTranscriptionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListMedicalTranscriptionJobsResponse, _>()
     }
@@ -2708,7 +2708,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, ListMedicalVocabulariesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListMedicalVocabulariesResponse, _>()
     }
@@ -2727,7 +2727,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, ListTranscriptionJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTranscriptionJobsResponse, _>()
     }
@@ -2746,7 +2746,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, ListVocabulariesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListVocabulariesResponse, _>()
     }
 
@@ -2764,7 +2764,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, ListVocabularyFiltersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListVocabularyFiltersResponse, _>()
     }
@@ -2784,7 +2784,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, StartMedicalTranscriptionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartMedicalTranscriptionJobResponse, _>()
     }
@@ -2803,7 +2803,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, StartTranscriptionJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartTranscriptionJobResponse, _>()
     }
@@ -2822,7 +2822,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, UpdateMedicalVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateMedicalVocabularyResponse, _>()
     }
@@ -2841,7 +2841,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, UpdateVocabularyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVocabularyResponse, _>()
     }
 
@@ -2859,7 +2859,7 @@ impl Transcribe for TranscribeClient {
             .sign_and_dispatch(request, UpdateVocabularyFilterError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateVocabularyFilterResponse, _>()
     }
@@ -41,7 +41,7 @@ impl TransferClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1874,7 +1874,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, CreateServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateServerResponse, _>()
     }
 
@@ -1892,7 +1892,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, CreateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResponse, _>()
     }
 
@@ -1961,7 +1961,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, DescribeServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeServerResponse, _>()
     }
 
@@ -1979,7 +1979,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, DescribeUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserResponse, _>()
     }
 
@@ -1997,7 +1997,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, ImportSshPublicKeyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportSshPublicKeyResponse, _>()
     }
 
@@ -2015,7 +2015,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, ListServersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListServersResponse, _>()
     }
 
@@ -2033,7 +2033,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -2051,7 +2051,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, ListUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUsersResponse, _>()
     }
 
@@ -2120,7 +2120,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, TestIdentityProviderError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<TestIdentityProviderResponse, _>()
     }
@@ -2156,7 +2156,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, UpdateServerError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServerResponse, _>()
     }
 
@@ -2174,7 +2174,7 @@ impl Transfer for TransferClient {
             .sign_and_dispatch(request, UpdateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserResponse, _>()
     }
 }
@@ -41,7 +41,7 @@ impl TranslateClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -1135,7 +1135,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, DescribeTextTranslationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeTextTranslationJobResponse, _>()
     }
@@ -1157,7 +1157,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, GetTerminologyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetTerminologyResponse, _>()
     }
 
@@ -1178,7 +1178,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, ImportTerminologyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportTerminologyResponse, _>()
     }
 
@@ -1199,7 +1199,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, ListTerminologiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTerminologiesResponse, _>()
     }
 
@@ -1220,7 +1220,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, ListTextTranslationJobsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListTextTranslationJobsResponse, _>()
     }
@@ -1242,7 +1242,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, StartTextTranslationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StartTextTranslationJobResponse, _>()
     }
@@ -1264,7 +1264,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, StopTextTranslationJobError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<StopTextTranslationJobResponse, _>()
     }
@@ -1286,7 +1286,7 @@ impl Translate for TranslateClient {
             .sign_and_dispatch(request, TranslateTextError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TranslateTextResponse, _>()
     }
 }
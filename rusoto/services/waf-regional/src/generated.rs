@@ -42,7 +42,7 @@ impl WAFRegionalClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7938,7 +7938,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, AssociateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateWebACLResponse, _>()
     }
 
@@ -7959,7 +7959,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateByteMatchSetResponse, _>()
     }
 
@@ -7977,7 +7977,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGeoMatchSetResponse, _>()
     }
 
@@ -7995,7 +7995,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateIPSetResponse, _>()
     }
 
@@ -8016,7 +8016,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRateBasedRuleResponse, _>()
     }
 
@@ -8037,7 +8037,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRegexMatchSetResponse, _>()
     }
 
@@ -8058,7 +8058,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRegexPatternSetResponse, _>()
     }
@@ -8077,7 +8077,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleResponse, _>()
     }
 
@@ -8095,7 +8095,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleGroupResponse, _>()
     }
 
@@ -8116,7 +8116,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSizeConstraintSetResponse, _>()
     }
@@ -8139,7 +8139,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSqlInjectionMatchSetResponse, _>()
     }
@@ -8158,7 +8158,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWebACLResponse, _>()
     }
 
@@ -8180,7 +8180,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateWebACLMigrationStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateWebACLMigrationStackResponse, _>()
     }
@@ -8199,7 +8199,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, CreateXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateXssMatchSetResponse, _>()
     }
 
@@ -8220,7 +8220,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteByteMatchSetResponse, _>()
     }
 
@@ -8238,7 +8238,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGeoMatchSetResponse, _>()
     }
 
@@ -8256,7 +8256,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteIPSetResponse, _>()
     }
 
@@ -8278,7 +8278,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteLoggingConfigurationResponse, _>()
     }
@@ -8300,7 +8300,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeletePermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeletePermissionPolicyResponse, _>()
     }
@@ -8322,7 +8322,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRateBasedRuleResponse, _>()
     }
 
@@ -8343,7 +8343,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRegexMatchSetResponse, _>()
     }
 
@@ -8364,7 +8364,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRegexPatternSetResponse, _>()
     }
@@ -8383,7 +8383,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleResponse, _>()
     }
 
@@ -8401,7 +8401,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleGroupResponse, _>()
     }
 
@@ -8422,7 +8422,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSizeConstraintSetResponse, _>()
     }
@@ -8445,7 +8445,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSqlInjectionMatchSetResponse, _>()
     }
@@ -8464,7 +8464,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebACLResponse, _>()
     }
 
@@ -8482,7 +8482,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DeleteXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteXssMatchSetResponse, _>()
     }
 
@@ -8503,7 +8503,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, DisassociateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisassociateWebACLResponse, _>()
     }
 
@@ -8521,7 +8521,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetByteMatchSetResponse, _>()
     }
 
@@ -8537,7 +8537,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetChangeTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetChangeTokenResponse, _>()
     }
 
@@ -8558,7 +8558,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetChangeTokenStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetChangeTokenStatusResponse, _>()
     }
@@ -8577,7 +8577,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGeoMatchSetResponse, _>()
     }
 
@@ -8595,7 +8595,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetIPSetResponse, _>()
     }
 
@@ -8616,7 +8616,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLoggingConfigurationResponse, _>()
     }
@@ -8638,7 +8638,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetPermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPermissionPolicyResponse, _>()
     }
 
@@ -8656,7 +8656,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRateBasedRuleResponse, _>()
     }
 
@@ -8678,7 +8678,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRateBasedRuleManagedKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRateBasedRuleManagedKeysResponse, _>()
     }
@@ -8697,7 +8697,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRegexMatchSetResponse, _>()
     }
 
@@ -8718,7 +8718,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRegexPatternSetResponse, _>()
     }
 
@@ -8736,7 +8736,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRuleResponse, _>()
     }
 
@@ -8754,7 +8754,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRuleGroupResponse, _>()
     }
 
@@ -8775,7 +8775,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetSampledRequestsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSampledRequestsResponse, _>()
     }
 
@@ -8796,7 +8796,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSizeConstraintSetResponse, _>()
     }
@@ -8818,7 +8818,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSqlInjectionMatchSetResponse, _>()
     }
@@ -8837,7 +8837,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWebACLResponse, _>()
     }
 
@@ -8858,7 +8858,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetWebACLForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetWebACLForResourceResponse, _>()
     }
@@ -8877,7 +8877,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, GetXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetXssMatchSetResponse, _>()
     }
 
@@ -8901,7 +8901,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListActivatedRulesInRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListActivatedRulesInRuleGroupResponse, _>()
     }
@@ -8920,7 +8920,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListByteMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListByteMatchSetsResponse, _>()
     }
 
@@ -8938,7 +8938,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListGeoMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGeoMatchSetsResponse, _>()
     }
 
@@ -8956,7 +8956,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListIPSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIPSetsResponse, _>()
     }
 
@@ -8978,7 +8978,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListLoggingConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListLoggingConfigurationsResponse, _>()
     }
@@ -9000,7 +9000,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListRateBasedRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRateBasedRulesResponse, _>()
     }
 
@@ -9021,7 +9021,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListRegexMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRegexMatchSetsResponse, _>()
     }
 
@@ -9042,7 +9042,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListRegexPatternSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRegexPatternSetsResponse, _>()
     }
@@ -9064,7 +9064,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListResourcesForWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResourcesForWebACLResponse, _>()
     }
@@ -9083,7 +9083,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListRuleGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRuleGroupsResponse, _>()
     }
 
@@ -9101,7 +9101,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRulesResponse, _>()
     }
 
@@ -9122,7 +9122,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListSizeConstraintSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSizeConstraintSetsResponse, _>()
     }
@@ -9145,7 +9145,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListSqlInjectionMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSqlInjectionMatchSetsResponse, _>()
     }
@@ -9167,7 +9167,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListSubscribedRuleGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSubscribedRuleGroupsResponse, _>()
     }
@@ -9189,7 +9189,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -9207,7 +9207,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListWebACLsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWebACLsResponse, _>()
     }
 
@@ -9225,7 +9225,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, ListXssMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListXssMatchSetsResponse, _>()
     }
 
@@ -9246,7 +9246,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, PutLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutLoggingConfigurationResponse, _>()
     }
@@ -9268,7 +9268,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, PutPermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutPermissionPolicyResponse, _>()
     }
 
@@ -9286,7 +9286,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -9304,7 +9304,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -9325,7 +9325,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateByteMatchSetResponse, _>()
     }
 
@@ -9343,7 +9343,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGeoMatchSetResponse, _>()
     }
 
@@ -9361,7 +9361,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateIPSetResponse, _>()
     }
 
@@ -9382,7 +9382,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRateBasedRuleResponse, _>()
     }
 
@@ -9403,7 +9403,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRegexMatchSetResponse, _>()
     }
 
@@ -9424,7 +9424,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateRegexPatternSetResponse, _>()
     }
@@ -9443,7 +9443,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleResponse, _>()
     }
 
@@ -9461,7 +9461,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleGroupResponse, _>()
     }
 
@@ -9482,7 +9482,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSizeConstraintSetResponse, _>()
     }
@@ -9505,7 +9505,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSqlInjectionMatchSetResponse, _>()
     }
@@ -9524,7 +9524,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebACLResponse, _>()
     }
 
@@ -9542,7 +9542,7 @@ impl WAFRegional for WAFRegionalClient {
             .sign_and_dispatch(request, UpdateXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateXssMatchSetResponse, _>()
     }
 }
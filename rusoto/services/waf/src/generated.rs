@@ -41,7 +41,7 @@ impl WafClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -7618,7 +7618,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateByteMatchSetResponse, _>()
     }
 
@@ -7636,7 +7636,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGeoMatchSetResponse, _>()
     }
 
@@ -7654,7 +7654,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateIPSetResponse, _>()
     }
 
@@ -7672,7 +7672,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRateBasedRuleResponse, _>()
     }
 
@@ -7690,7 +7690,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRegexMatchSetResponse, _>()
     }
 
@@ -7708,7 +7708,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateRegexPatternSetResponse, _>()
     }
@@ -7727,7 +7727,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleResponse, _>()
     }
 
@@ -7745,7 +7745,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleGroupResponse, _>()
     }
 
@@ -7763,7 +7763,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSizeConstraintSetResponse, _>()
     }
@@ -7783,7 +7783,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateSqlInjectionMatchSetResponse, _>()
     }
@@ -7802,7 +7802,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWebACLResponse, _>()
     }
 
@@ -7821,7 +7821,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateWebACLMigrationStackError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<CreateWebACLMigrationStackResponse, _>()
     }
@@ -7840,7 +7840,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, CreateXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateXssMatchSetResponse, _>()
     }
 
@@ -7858,7 +7858,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteByteMatchSetResponse, _>()
     }
 
@@ -7876,7 +7876,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGeoMatchSetResponse, _>()
     }
 
@@ -7894,7 +7894,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteIPSetResponse, _>()
     }
 
@@ -7913,7 +7913,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteLoggingConfigurationResponse, _>()
     }
@@ -7932,7 +7932,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeletePermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeletePermissionPolicyResponse, _>()
     }
@@ -7951,7 +7951,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRateBasedRuleResponse, _>()
     }
 
@@ -7969,7 +7969,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRegexMatchSetResponse, _>()
     }
 
@@ -7987,7 +7987,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRegexPatternSetResponse, _>()
     }
@@ -8006,7 +8006,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleResponse, _>()
     }
 
@@ -8024,7 +8024,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleGroupResponse, _>()
     }
 
@@ -8042,7 +8042,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSizeConstraintSetResponse, _>()
     }
@@ -8062,7 +8062,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteSqlInjectionMatchSetResponse, _>()
     }
@@ -8081,7 +8081,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebACLResponse, _>()
     }
 
@@ -8099,7 +8099,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, DeleteXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteXssMatchSetResponse, _>()
     }
 
@@ -8117,7 +8117,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetByteMatchSetResponse, _>()
     }
 
@@ -8133,7 +8133,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetChangeTokenError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetChangeTokenResponse, _>()
     }
 
@@ -8151,7 +8151,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetChangeTokenStatusError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetChangeTokenStatusResponse, _>()
     }
@@ -8170,7 +8170,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetGeoMatchSetResponse, _>()
     }
 
@@ -8188,7 +8188,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetIPSetResponse, _>()
     }
 
@@ -8206,7 +8206,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetLoggingConfigurationResponse, _>()
     }
@@ -8225,7 +8225,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetPermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetPermissionPolicyResponse, _>()
     }
 
@@ -8243,7 +8243,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRateBasedRuleResponse, _>()
     }
 
@@ -8265,7 +8265,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRateBasedRuleManagedKeysError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetRateBasedRuleManagedKeysResponse, _>()
     }
@@ -8284,7 +8284,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRegexMatchSetResponse, _>()
     }
 
@@ -8302,7 +8302,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRegexPatternSetResponse, _>()
     }
 
@@ -8320,7 +8320,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRuleResponse, _>()
     }
 
@@ -8338,7 +8338,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetRuleGroupResponse, _>()
     }
 
@@ -8356,7 +8356,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetSampledRequestsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetSampledRequestsResponse, _>()
     }
 
@@ -8374,7 +8374,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSizeConstraintSetResponse, _>()
     }
@@ -8393,7 +8393,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetSqlInjectionMatchSetResponse, _>()
     }
@@ -8412,7 +8412,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetWebACLResponse, _>()
     }
 
@@ -8430,7 +8430,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, GetXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetXssMatchSetResponse, _>()
     }
 
@@ -8454,7 +8454,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListActivatedRulesInRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListActivatedRulesInRuleGroupResponse, _>()
     }
@@ -8473,7 +8473,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListByteMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListByteMatchSetsResponse, _>()
     }
 
@@ -8491,7 +8491,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListGeoMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGeoMatchSetsResponse, _>()
     }
 
@@ -8509,7 +8509,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListIPSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListIPSetsResponse, _>()
     }
 
@@ -8528,7 +8528,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListLoggingConfigurationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListLoggingConfigurationsResponse, _>()
     }
@@ -8547,7 +8547,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListRateBasedRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRateBasedRulesResponse, _>()
     }
 
@@ -8565,7 +8565,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListRegexMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRegexMatchSetsResponse, _>()
     }
 
@@ -8583,7 +8583,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListRegexPatternSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListRegexPatternSetsResponse, _>()
     }
@@ -8602,7 +8602,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListRuleGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRuleGroupsResponse, _>()
     }
 
@@ -8620,7 +8620,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListRulesResponse, _>()
     }
 
@@ -8638,7 +8638,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListSizeConstraintSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSizeConstraintSetsResponse, _>()
     }
@@ -8658,7 +8658,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListSqlInjectionMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSqlInjectionMatchSetsResponse, _>()
     }
@@ -8677,7 +8677,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListSubscribedRuleGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListSubscribedRuleGroupsResponse, _>()
     }
@@ -8696,7 +8696,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -8714,7 +8714,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListWebACLsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListWebACLsResponse, _>()
     }
 
@@ -8732,7 +8732,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, ListXssMatchSetsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListXssMatchSetsResponse, _>()
     }
 
@@ -8750,7 +8750,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, PutLoggingConfigurationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutLoggingConfigurationResponse, _>()
     }
@@ -8769,7 +8769,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, PutPermissionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutPermissionPolicyResponse, _>()
     }
 
@@ -8787,7 +8787,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -8805,7 +8805,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -8823,7 +8823,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateByteMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateByteMatchSetResponse, _>()
     }
 
@@ -8841,7 +8841,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateGeoMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateGeoMatchSetResponse, _>()
     }
 
@@ -8859,7 +8859,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateIPSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateIPSetResponse, _>()
     }
 
@@ -8877,7 +8877,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateRateBasedRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRateBasedRuleResponse, _>()
     }
 
@@ -8895,7 +8895,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateRegexMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRegexMatchSetResponse, _>()
     }
 
@@ -8913,7 +8913,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateRegexPatternSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateRegexPatternSetResponse, _>()
     }
@@ -8932,7 +8932,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleResponse, _>()
     }
 
@@ -8950,7 +8950,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateRuleGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleGroupResponse, _>()
     }
 
@@ -8968,7 +8968,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateSizeConstraintSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSizeConstraintSetResponse, _>()
     }
@@ -8988,7 +8988,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateSqlInjectionMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdateSqlInjectionMatchSetResponse, _>()
     }
@@ -9007,7 +9007,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateWebACLError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebACLResponse, _>()
     }
 
@@ -9025,7 +9025,7 @@ impl Waf for WafClient {
             .sign_and_dispatch(request, UpdateXssMatchSetError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateXssMatchSetResponse, _>()
     }
 }
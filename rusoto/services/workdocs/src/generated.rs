@@ -4955,6 +4955,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("AbortDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -4965,12 +4966,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AbortDocumentVersionUploadError::from_response(response))
         }
     }
@@ -4987,6 +4988,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("ActivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -4997,13 +4999,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ActivateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ActivateUserError::from_response(response))
         }
     }
@@ -5020,6 +5022,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("AddResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5032,13 +5035,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AddResourcePermissionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AddResourcePermissionsError::from_response(response))
         }
     }
@@ -5056,6 +5059,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5068,13 +5072,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCommentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCommentError::from_response(response))
         }
     }
@@ -5091,6 +5095,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5108,13 +5113,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCustomMetadataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateCustomMetadataError::from_response(response))
         }
     }
@@ -5128,6 +5133,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/folders";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5140,13 +5146,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFolderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFolderError::from_response(response))
         }
     }
@@ -5163,6 +5169,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5175,13 +5182,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateLabelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateLabelsError::from_response(response))
         }
     }
@@ -5201,6 +5208,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5212,13 +5220,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateNotificationSubscriptionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateNotificationSubscriptionError::from_response(response))
         }
     }
@@ -5232,6 +5240,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5244,13 +5253,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateUserError::from_response(response))
         }
     }
@@ -5267,6 +5276,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeactivateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5277,12 +5287,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeactivateUserError::from_response(response))
         }
     }
@@ -5301,6 +5311,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteComment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5311,12 +5322,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteCommentError::from_response(response))
         }
     }
@@ -5333,6 +5344,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteCustomMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5356,13 +5368,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteCustomMetadataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteCustomMetadataError::from_response(response))
         }
     }
@@ -5379,6 +5391,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5389,12 +5402,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteDocumentError::from_response(response))
         }
     }
@@ -5408,6 +5421,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5418,12 +5432,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFolderError::from_response(response))
         }
     }
@@ -5440,6 +5454,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5450,12 +5465,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFolderContentsError::from_response(response))
         }
     }
@@ -5472,6 +5487,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteLabels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5492,13 +5508,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteLabelsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteLabelsError::from_response(response))
         }
     }
@@ -5516,6 +5532,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationSubscription");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5524,12 +5541,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteNotificationSubscriptionError::from_response(response))
         }
     }
@@ -5543,6 +5560,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5553,12 +5571,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteUserError::from_response(response))
         }
     }
@@ -5572,6 +5590,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/activities";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeActivities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5611,13 +5630,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeActivitiesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeActivitiesError::from_response(response))
         }
     }
@@ -5635,6 +5654,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeComments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5653,13 +5673,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeCommentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeCommentsError::from_response(response))
         }
     }
@@ -5676,6 +5696,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeDocumentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5700,13 +5721,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDocumentVersionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDocumentVersionsError::from_response(response))
         }
     }
@@ -5723,6 +5744,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeFolderContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5753,13 +5775,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFolderContentsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFolderContentsError::from_response(response))
         }
     }
@@ -5773,6 +5795,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/groups";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5795,13 +5818,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeGroupsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeGroupsError::from_response(response))
         }
     }
@@ -5821,6 +5844,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationSubscriptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5838,13 +5862,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeNotificationSubscriptionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeNotificationSubscriptionsError::from_response(
                 response,
             ))
@@ -5864,6 +5888,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5885,13 +5910,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeResourcePermissionsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeResourcePermissionsError::from_response(response))
         }
     }
@@ -5905,6 +5930,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me/root";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeRootFolders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -5923,13 +5949,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRootFoldersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeRootFoldersError::from_response(response))
         }
     }
@@ -5943,6 +5969,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5982,13 +6009,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeUsersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeUsersError::from_response(response))
         }
     }
@@ -6002,6 +6029,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetCurrentUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -6012,13 +6040,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetCurrentUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetCurrentUserError::from_response(response))
         }
     }
@@ -6035,6 +6063,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6050,13 +6079,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDocumentResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentError::from_response(response))
         }
     }
@@ -6073,6 +6102,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6094,13 +6124,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDocumentPathResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentPathError::from_response(response))
         }
     }
@@ -6118,6 +6148,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6136,13 +6167,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDocumentVersionResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetDocumentVersionError::from_response(response))
         }
     }
@@ -6156,6 +6187,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6171,13 +6203,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFolderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFolderError::from_response(response))
         }
     }
@@ -6194,6 +6226,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolderPath");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6215,13 +6248,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFolderPathResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetFolderPathError::from_response(response))
         }
     }
@@ -6235,6 +6268,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/resources";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6259,13 +6293,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetResourcesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetResourcesError::from_response(response))
         }
     }
@@ -6282,6 +6316,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/documents";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("InitiateDocumentVersionUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6294,13 +6329,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 201 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateDocumentVersionUploadResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(InitiateDocumentVersionUploadError::from_response(response))
         }
     }
@@ -6317,6 +6352,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveAllResourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6327,12 +6363,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveAllResourcePermissionsError::from_response(response))
         }
     }
@@ -6350,6 +6386,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveResourcePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6365,12 +6402,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 204 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RemoveResourcePermissionError::from_response(response))
         }
     }
@@ -6387,6 +6424,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6399,12 +6437,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDocumentError::from_response(response))
         }
     }
@@ -6422,6 +6460,7 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6434,12 +6473,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDocumentVersionError::from_response(response))
         }
     }
@@ -6453,6 +6492,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateFolder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6465,12 +6505,12 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = ::std::mem::drop(response);
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFolderError::from_response(response))
         }
     }
@@ -6484,6 +6524,7 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6496,13 +6537,13 @@ impl Workdocs for WorkdocsClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.as_u16() == 200 {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateUserError::from_response(response))
         }
     }
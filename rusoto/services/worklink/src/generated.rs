@@ -3268,6 +3268,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3279,13 +3280,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateDomainError::from_response(response))
         }
     }
@@ -3302,6 +3303,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3313,13 +3315,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateWebsiteAuthorizationProviderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateWebsiteAuthorizationProviderError::from_response(
                 response,
             ))
@@ -3338,6 +3340,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3349,13 +3352,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateWebsiteCertificateAuthorityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(AssociateWebsiteCertificateAuthorityError::from_response(
                 response,
             ))
@@ -3371,6 +3374,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3382,13 +3386,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFleetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(CreateFleetError::from_response(response))
         }
     }
@@ -3402,6 +3406,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3413,13 +3418,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFleetResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DeleteFleetError::from_response(response))
         }
     }
@@ -3436,6 +3441,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3447,13 +3453,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeAuditStreamConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeAuditStreamConfigurationError::from_response(
                 response,
             ))
@@ -3472,6 +3478,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3483,13 +3490,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeCompanyNetworkConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeCompanyNetworkConfigurationError::from_response(
                 response,
             ))
@@ -3505,6 +3512,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevice";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3516,13 +3524,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDeviceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDeviceError::from_response(response))
         }
     }
@@ -3539,6 +3547,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3550,13 +3559,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDevicePolicyConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDevicePolicyConfigurationError::from_response(
                 response,
             ))
@@ -3572,6 +3581,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3583,13 +3593,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeDomainError::from_response(response))
         }
     }
@@ -3603,6 +3613,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3614,13 +3625,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFleetMetadataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeFleetMetadataError::from_response(response))
         }
     }
@@ -3637,6 +3648,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3648,13 +3660,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeIdentityProviderConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeIdentityProviderConfigurationError::from_response(
                 response,
             ))
@@ -3673,6 +3685,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3684,13 +3697,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeWebsiteCertificateAuthorityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DescribeWebsiteCertificateAuthorityError::from_response(
                 response,
             ))
@@ -3706,6 +3719,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateDomain");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3717,13 +3731,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateDomainResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateDomainError::from_response(response))
         }
     }
@@ -3740,6 +3754,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteAuthorizationProvider");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3751,13 +3766,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateWebsiteAuthorizationProviderResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateWebsiteAuthorizationProviderError::from_response(response))
         }
     }
@@ -3774,6 +3789,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteCertificateAuthority");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3785,13 +3801,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateWebsiteCertificateAuthorityResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(DisassociateWebsiteCertificateAuthorityError::from_response(
                 response,
             ))
@@ -3807,6 +3823,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDevices";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3818,13 +3835,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDevicesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDevicesError::from_response(response))
         }
     }
@@ -3838,6 +3855,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDomains";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDomains");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3849,13 +3867,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDomainsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListDomainsError::from_response(response))
         }
     }
@@ -3869,6 +3887,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3880,13 +3899,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFleetsResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListFleetsError::from_response(response))
         }
     }
@@ -3900,6 +3919,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "worklink", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/json".to_owned());
 
         let mut response = self
@@ -3908,13 +3928,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
@@ -3931,6 +3951,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteAuthorizationProviders";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteAuthorizationProviders");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3942,13 +3963,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListWebsiteAuthorizationProvidersResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListWebsiteAuthorizationProvidersError::from_response(
                 response,
             ))
@@ -3967,6 +3988,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteCertificateAuthorities";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteCertificateAuthorities");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3978,13 +4000,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListWebsiteCertificateAuthoritiesResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(ListWebsiteCertificateAuthoritiesError::from_response(
                 response,
             ))
@@ -4000,6 +4022,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/restoreDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RestoreDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4011,13 +4034,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RestoreDomainAccessResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RestoreDomainAccessError::from_response(response))
         }
     }
@@ -4031,6 +4054,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/revokeDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RevokeDomainAccess");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4042,13 +4066,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RevokeDomainAccessResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(RevokeDomainAccessError::from_response(response))
         }
     }
@@ -4062,6 +4086,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/signOutUser";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("SignOutUser");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4073,13 +4098,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SignOutUserResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(SignOutUserError::from_response(response))
         }
     }
@@ -4093,6 +4118,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4104,13 +4130,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(TagResourceError::from_response(response))
         }
     }
@@ -4124,6 +4150,7 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "worklink", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/json".to_owned());
 
         let mut params = Params::new();
@@ -4138,13 +4165,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UntagResourceError::from_response(response))
         }
     }
@@ -4161,6 +4188,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateAuditStreamConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4172,13 +4200,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateAuditStreamConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateAuditStreamConfigurationError::from_response(response))
         }
     }
@@ -4195,6 +4223,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateCompanyNetworkConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4206,13 +4235,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateCompanyNetworkConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateCompanyNetworkConfigurationError::from_response(
                 response,
             ))
@@ -4231,6 +4260,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDevicePolicyConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4242,13 +4272,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDevicePolicyConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDevicePolicyConfigurationError::from_response(
                 response,
             ))
@@ -4264,6 +4294,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDomainMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDomainMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4275,13 +4306,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDomainMetadataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateDomainMetadataError::from_response(response))
         }
     }
@@ -4295,6 +4326,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/UpdateFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateFleetMetadata");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4306,13 +4338,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateFleetMetadataResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateFleetMetadataError::from_response(response))
         }
     }
@@ -4329,6 +4361,7 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateIdentityProviderConfiguration");
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4340,13 +4373,13 @@ impl Worklink for WorklinkClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateIdentityProviderConfigurationResponse, _>()?;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(UpdateIdentityProviderConfigurationError::from_response(
                 response,
             ))
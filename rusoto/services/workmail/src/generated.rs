@@ -41,7 +41,7 @@ impl WorkmailClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -4293,7 +4293,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, AssociateDelegateToResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateDelegateToResourceResponse, _>()
     }
@@ -4312,7 +4312,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, AssociateMemberToGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<AssociateMemberToGroupResponse, _>()
     }
@@ -4331,7 +4331,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, CreateAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateAliasResponse, _>()
     }
 
@@ -4349,7 +4349,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, CreateGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateGroupResponse, _>()
     }
 
@@ -4367,7 +4367,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, CreateResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateResourceResponse, _>()
     }
 
@@ -4385,7 +4385,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, CreateUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResponse, _>()
     }
 
@@ -4403,7 +4403,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteAccessControlRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteAccessControlRuleResponse, _>()
     }
@@ -4422,7 +4422,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteAliasError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteAliasResponse, _>()
     }
 
@@ -4440,7 +4440,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteGroupResponse, _>()
     }
 
@@ -4458,7 +4458,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteMailboxPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteMailboxPermissionsResponse, _>()
     }
@@ -4477,7 +4477,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteResourceResponse, _>()
     }
 
@@ -4495,7 +4495,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteRetentionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeleteRetentionPolicyResponse, _>()
     }
@@ -4514,7 +4514,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeleteUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserResponse, _>()
     }
 
@@ -4532,7 +4532,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DeregisterFromWorkMailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterFromWorkMailResponse, _>()
     }
@@ -4551,7 +4551,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DescribeGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeGroupResponse, _>()
     }
 
@@ -4569,7 +4569,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DescribeOrganizationError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeOrganizationResponse, _>()
     }
@@ -4588,7 +4588,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DescribeResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeResourceResponse, _>()
     }
 
@@ -4606,7 +4606,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DescribeUserError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserResponse, _>()
     }
 
@@ -4633,7 +4633,7 @@ impl Workmail for WorkmailClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateDelegateFromResourceResponse, _>()
     }
@@ -4656,7 +4656,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, DisassociateMemberFromGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DisassociateMemberFromGroupResponse, _>()
     }
@@ -4675,7 +4675,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, GetAccessControlEffectError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetAccessControlEffectResponse, _>()
     }
@@ -4695,7 +4695,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, GetDefaultRetentionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<GetDefaultRetentionPolicyResponse, _>()
     }
@@ -4714,7 +4714,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, GetMailboxDetailsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<GetMailboxDetailsResponse, _>()
     }
 
@@ -4732,7 +4732,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListAccessControlRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAccessControlRulesResponse, _>()
     }
@@ -4751,7 +4751,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListAliasesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListAliasesResponse, _>()
     }
 
@@ -4769,7 +4769,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListGroupMembersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGroupMembersResponse, _>()
     }
 
@@ -4787,7 +4787,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListGroupsResponse, _>()
     }
 
@@ -4805,7 +4805,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListMailboxPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListMailboxPermissionsResponse, _>()
     }
@@ -4824,7 +4824,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListOrganizationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListOrganizationsResponse, _>()
     }
 
@@ -4842,7 +4842,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListResourceDelegatesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListResourceDelegatesResponse, _>()
     }
@@ -4861,7 +4861,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListResourcesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListResourcesResponse, _>()
     }
 
@@ -4879,7 +4879,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForResourceResponse, _>()
     }
 
@@ -4897,7 +4897,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ListUsersError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ListUsersResponse, _>()
     }
 
@@ -4915,7 +4915,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, PutAccessControlRuleError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutAccessControlRuleResponse, _>()
     }
@@ -4934,7 +4934,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, PutMailboxPermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<PutMailboxPermissionsResponse, _>()
     }
@@ -4953,7 +4953,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, PutRetentionPolicyError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<PutRetentionPolicyResponse, _>()
     }
 
@@ -4971,7 +4971,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, RegisterToWorkMailError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RegisterToWorkMailResponse, _>()
     }
 
@@ -4989,7 +4989,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, ResetPasswordError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ResetPasswordResponse, _>()
     }
 
@@ -5007,7 +5007,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, TagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
     }
 
@@ -5025,7 +5025,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, UntagResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 
@@ -5043,7 +5043,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, UpdateMailboxQuotaError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateMailboxQuotaResponse, _>()
     }
 
@@ -5062,7 +5062,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, UpdatePrimaryEmailAddressError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<UpdatePrimaryEmailAddressResponse, _>()
     }
@@ -5081,7 +5081,7 @@ impl Workmail for WorkmailClient {
             .sign_and_dispatch(request, UpdateResourceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
@@ -128,6 +128,7 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
 
         let mut request =
             SignedRequest::new("GET", "workmailmessageflow", &self.region, &request_uri);
+        request.set_operation("GetRawMessageContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -136,14 +137,14 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
             .await
             .map_err(RusotoError::from)?;
         if response.status.is_success() {
-            let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let mut response = response.buffer().await?;
 
             let mut result = GetRawMessageContentResponse::default();
             result.message_content = response.body;
 
             Ok(result)
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             Err(GetRawMessageContentError::from_response(response))
         }
     }
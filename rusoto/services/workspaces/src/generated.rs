@@ -41,7 +41,7 @@ impl WorkspacesClient {
     ) -> Result<HttpResponse, RusotoError<E>> {
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response.buffer().await?;
             return Err(from_response(response));
         }
 
@@ -3808,7 +3808,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, AssociateIpGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AssociateIpGroupsResult, _>()
     }
 
@@ -3826,7 +3826,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, AuthorizeIpRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<AuthorizeIpRulesResult, _>()
     }
 
@@ -3844,7 +3844,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, CopyWorkspaceImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CopyWorkspaceImageResult, _>()
     }
 
@@ -3862,7 +3862,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, CreateIpGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateIpGroupResult, _>()
     }
 
@@ -3880,7 +3880,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, CreateTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateTagsResult, _>()
     }
 
@@ -3898,7 +3898,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, CreateWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkspacesResult, _>()
     }
 
@@ -3916,7 +3916,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DeleteIpGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteIpGroupResult, _>()
     }
 
@@ -3934,7 +3934,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DeleteTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsResult, _>()
     }
 
@@ -3952,7 +3952,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DeleteWorkspaceImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkspaceImageResult, _>()
     }
 
@@ -3974,7 +3974,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DeregisterWorkspaceDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DeregisterWorkspaceDirectoryResult, _>()
     }
@@ -3991,7 +3991,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeAccountResult, _>()
     }
 
@@ -4013,7 +4013,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeAccountModificationsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeAccountModificationsResult, _>()
     }
@@ -4032,7 +4032,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeClientPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeClientPropertiesResult, _>()
     }
@@ -4051,7 +4051,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeIpGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeIpGroupsResult, _>()
     }
 
@@ -4069,7 +4069,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeTagsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsResult, _>()
     }
 
@@ -4087,7 +4087,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeWorkspaceBundlesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkspaceBundlesResult, _>()
     }
@@ -4110,7 +4110,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeWorkspaceDirectoriesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkspaceDirectoriesResult, _>()
     }
@@ -4129,7 +4129,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeWorkspaceImagesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkspaceImagesResult, _>()
     }
@@ -4152,7 +4152,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeWorkspaceSnapshotsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkspaceSnapshotsResult, _>()
     }
@@ -4171,7 +4171,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DescribeWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DescribeWorkspacesResult, _>()
     }
 
@@ -4198,7 +4198,7 @@ impl Workspaces for WorkspacesClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<DescribeWorkspacesConnectionStatusResult, _>()
     }
@@ -4217,7 +4217,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, DisassociateIpGroupsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<DisassociateIpGroupsResult, _>()
     }
 
@@ -4235,7 +4235,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ImportWorkspaceImageError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ImportWorkspaceImageResult, _>()
     }
 
@@ -4262,7 +4262,7 @@ impl Workspaces for WorkspacesClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ListAvailableManagementCidrRangesResult, _>()
     }
@@ -4281,7 +4281,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, MigrateWorkspaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<MigrateWorkspaceResult, _>()
     }
 
@@ -4299,7 +4299,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifyAccountError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyAccountResult, _>()
     }
 
@@ -4317,7 +4317,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifyClientPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyClientPropertiesResult, _>()
     }
@@ -4340,7 +4340,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifySelfservicePermissionsError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifySelfservicePermissionsResult, _>()
     }
@@ -4365,7 +4365,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifyWorkspaceAccessPropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyWorkspaceAccessPropertiesResult, _>()
     }
@@ -4393,7 +4393,7 @@ impl Workspaces for WorkspacesClient {
             )
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyWorkspaceCreationPropertiesResult, _>()
     }
@@ -4415,7 +4415,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifyWorkspacePropertiesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<ModifyWorkspacePropertiesResult, _>()
     }
@@ -4434,7 +4434,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, ModifyWorkspaceStateError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<ModifyWorkspaceStateResult, _>()
     }
 
@@ -4452,7 +4452,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, RebootWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RebootWorkspacesResult, _>()
     }
 
@@ -4470,7 +4470,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, RebuildWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RebuildWorkspacesResult, _>()
     }
 
@@ -4492,7 +4492,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, RegisterWorkspaceDirectoryError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response)
             .deserialize::<RegisterWorkspaceDirectoryResult, _>()
     }
@@ -4511,7 +4511,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, RestoreWorkspaceError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RestoreWorkspaceResult, _>()
     }
 
@@ -4529,7 +4529,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, RevokeIpRulesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<RevokeIpRulesResult, _>()
     }
 
@@ -4547,7 +4547,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, StartWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StartWorkspacesResult, _>()
     }
 
@@ -4565,7 +4565,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, StopWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<StopWorkspacesResult, _>()
     }
 
@@ -4583,7 +4583,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, TerminateWorkspacesError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<TerminateWorkspacesResult, _>()
     }
 
@@ -4601,7 +4601,7 @@ impl Workspaces for WorkspacesClient {
             .sign_and_dispatch(request, UpdateRulesOfIpGroupError::from_response)
             .await?;
         let mut response = response;
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response.buffer().await?;
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRulesOfIpGroupResult, _>()
     }
 }
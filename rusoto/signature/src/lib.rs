@@ -11,9 +11,11 @@
 #![cfg_attr(not(feature = "unstable"), deny(warnings))]
 #![cfg_attr(not(feature = "unstable"), allow(clippy::type_complexity))]
 pub extern crate rusoto_credential as credential;
+pub mod presigned_post;
 pub mod region;
 pub mod signature;
 pub mod stream;
+pub use presigned_post::{PostPolicyCondition, PresignedPost, PresignedPostBuilder};
 pub use region::Region;
 pub use signature::{SignedRequest, SignedRequestPayload};
 pub use stream::ByteStream;
@@ -4,7 +4,7 @@
 //!
 //! For example: `UsEast1` to "us-east-1"
 
-use crate::credential::ProfileProvider;
+use crate::credential::{InstanceMetadataProvider, ProfileProvider};
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std;
@@ -21,6 +21,12 @@ use std::str::FromStr;
 /// If it is not present it will fallback on the value associated with the current profile in `~/.aws/config` or the file
 /// specified by the `AWS_CONFIG_FILE` environment variable. If that is malformed of absent it will fall back on `Region::UsEast1`
 ///
+/// # Resolution
+///
+/// `Region::resolve` is the recommended way to pick the region. It also asks the instance
+/// metadata service for the region of the EC2 instance, like the AWS CLI, and returns an error
+/// instead of falling back to `Region::UsEast1`.
+///
 /// # AWS-compatible services
 ///
 /// `Region::Custom` can be used to connect to AWS-compatible services such as DynamoDB Local or Ceph.
@@ -173,6 +179,77 @@ pub struct ParseRegionError {
     message: String,
 }
 
+/// An error produced when `Region::resolve` can't determine the region.
+#[derive(Debug, PartialEq)]
+pub struct ResolveRegionError {
+    message: String,
+}
+
+impl Error for ResolveRegionError {}
+
+impl Display for ResolveRegionError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Region {
+    /// Resolves the region like the AWS CLI does, checking in order:
+    ///
+    /// 1. the `AWS_DEFAULT_REGION` and `AWS_REGION` environment variables,
+    /// 2. the `region` of the profile selected by `AWS_PROFILE` in `~/.aws/config` or the file
+    ///    specified by the `AWS_CONFIG_FILE` environment variable,
+    /// 3. the region of the EC2 instance, from the instance metadata service (IMDSv2, falling
+    ///    back to IMDSv1).
+    ///
+    /// Unlike `Region::default()`, a malformed region is an error, and so is finding none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use rusoto_signature::Region;
+    ///
+    /// let region = Region::resolve().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve() -> Result<Region, ResolveRegionError> {
+        Region::resolve_with(&InstanceMetadataProvider::new()).await
+    }
+
+    /// Resolves the region like `Region::resolve`, asking `instance_metadata` for the region of
+    /// the EC2 instance, e.g. to change its timeout.
+    pub async fn resolve_with(
+        instance_metadata: &InstanceMetadataProvider,
+    ) -> Result<Region, ResolveRegionError> {
+        for var in &["AWS_DEFAULT_REGION", "AWS_REGION"] {
+            if let Some(region) = std::env::var(var).ok().filter(|v| !v.is_empty()) {
+                return parse_resolved(&region, var);
+            }
+        }
+        if let Ok(Some(region)) = ProfileProvider::region() {
+            return parse_resolved(&region, "the config file");
+        }
+        match instance_metadata.region().await {
+            Ok(region) => parse_resolved(&region, "instance metadata"),
+            Err(err) => Err(ResolveRegionError {
+                message: format!(
+                    "No region in AWS_DEFAULT_REGION, AWS_REGION or the config file, \
+                     and none from instance metadata: {}",
+                    err
+                ),
+            }),
+        }
+    }
+}
+
+fn parse_resolved(region: &str, source: &str) -> Result<Region, ResolveRegionError> {
+    Region::from_str(region).map_err(|err| ResolveRegionError {
+        message: format!("{} (from {})", err, source),
+    })
+}
+
 // Manually created for lack of a way to flatten the `Region::Custom` variant
 // Related: https://github.com/serde-rs/serde/issues/119
 impl Serialize for Region {
@@ -301,6 +378,55 @@ mod tests {
     extern crate serde_test;
     use self::serde_test::{assert_tokens, Token};
     use super::*;
+    use std::env;
+
+    #[tokio::test]
+    async fn resolve_regions() {
+        // bind and immediately release a port so nothing is listening on it
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .unwrap();
+        let mut instance_metadata = InstanceMetadataProvider::new().with_max_retries(0);
+        instance_metadata.set_ip_addr_with_port(&addr.ip().to_string(), &addr.port().to_string());
+        let config_file = env::temp_dir().join(format!("rusoto-region-{}", std::process::id()));
+        std::fs::write(
+            &config_file,
+            "[default]\nregion = eu-west-2\n[profile bad]\nregion = moon-1\n",
+        )
+        .unwrap();
+        env::remove_var("AWS_DEFAULT_REGION");
+        env::remove_var("AWS_PROFILE");
+        env::set_var("AWS_CONFIG_FILE", &config_file);
+
+        env::set_var("AWS_REGION", "ap-south-1");
+        assert_eq!(
+            Region::resolve_with(&instance_metadata).await,
+            Ok(Region::ApSouth1)
+        );
+        env::set_var("AWS_REGION", "moon-1");
+        let err = Region::resolve_with(&instance_metadata).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not a valid AWS region: moon-1 (from AWS_REGION)"
+        );
+
+        env::remove_var("AWS_REGION");
+        assert_eq!(
+            Region::resolve_with(&instance_metadata).await,
+            Ok(Region::EuWest2)
+        );
+        env::set_var("AWS_PROFILE", "bad");
+        let err = Region::resolve_with(&instance_metadata).await.unwrap_err();
+        assert!(err.to_string().contains("from the config file"), "{}", err);
+
+        env::set_var("AWS_CONFIG_FILE", config_file.with_extension("missing"));
+        let err = Region::resolve_with(&instance_metadata).await.unwrap_err();
+        assert!(err.to_string().contains("instance metadata"), "{}", err);
+
+        env::remove_var("AWS_PROFILE");
+        env::remove_var("AWS_CONFIG_FILE");
+        std::fs::remove_file(config_file).unwrap();
+    }
 
     #[test]
     fn from_str() {
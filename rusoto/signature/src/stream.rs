@@ -1,17 +1,28 @@
 use std::fmt;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use bytes::{BufMut, Bytes, BytesMut};
 use futures::{future, stream, Stream, StreamExt};
 use pin_project::pin_project;
-use tokio::io::AsyncRead;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+type Replay = Arc<dyn Fn() -> ByteStream + Send + Sync>;
 
 /// Stream of bytes.
+///
+/// A stream created with `from_bytes`, `from_path`, `new_replayable` or from a `Vec<u8>` is
+/// replayable: `try_clone` can create a fresh copy of it, so a request using it as its body
+/// can be sent again.
 #[pin_project]
 pub struct ByteStream {
     size_hint: Option<usize>,
+    replay: Option<Replay>,
     #[pin]
     inner: Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static>>,
 }
@@ -24,6 +35,7 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: None,
+            replay: None,
             inner: Box::pin(stream),
         }
     }
@@ -36,14 +48,66 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: Some(size_hint),
+            replay: None,
             inner: Box::pin(stream),
         }
     }
 
+    /// Creates a new replayable `ByteStream`. `factory` is called to create the stream, and
+    /// again each time it is replayed, so it must produce the same bytes every time.
+    pub fn new_replayable<F>(factory: F) -> ByteStream
+    where
+        F: Fn() -> ByteStream + Send + Sync + 'static,
+    {
+        let mut stream = factory();
+        stream.replay = Some(Arc::new(factory));
+        stream
+    }
+
+    /// Creates a new replayable `ByteStream` containing `bytes`.
+    pub fn from_bytes(bytes: Bytes) -> ByteStream {
+        ByteStream::new_replayable(move || {
+            let len = bytes.len();
+            ByteStream::new_with_size(stream::once(future::ready(Ok(bytes.clone()))), len)
+        })
+    }
+
+    /// Creates a new replayable `ByteStream` that reads the file at `path`. The size hint is
+    /// set to the size of the file, so requests using it as their body send a `Content-Length`
+    /// header. The file is opened again each time the stream is replayed.
+    pub async fn from_path<P: AsRef<Path>>(path: P) -> io::Result<ByteStream> {
+        let path = path.as_ref().to_owned();
+        let size = tokio::fs::metadata(&path).await?.len() as usize;
+        Ok(ByteStream::new_replayable(move || {
+            ByteStream::new_with_size(file_chunks(path.clone()), size)
+        }))
+    }
+
+    /// Returns true if `try_clone` can create a fresh copy of this stream.
+    pub fn is_replayable(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Creates a fresh copy of this stream, starting from the beginning, or returns `None` if
+    /// the stream isn't replayable.
+    pub fn try_clone(&self) -> Option<ByteStream> {
+        self.replay.as_ref().map(|replay| {
+            let mut stream = replay();
+            stream.replay = Some(replay.clone());
+            stream
+        })
+    }
+
     pub(crate) fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
 
+    /// Returns the number of bytes the stream was created to produce, if known, e.g. by
+    /// `new_with_size`.
+    pub fn len_hint(&self) -> Option<usize> {
+        self.size_hint
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send + Sync {
         ImplAsyncRead::new(self.inner)
@@ -57,16 +121,48 @@ impl ByteStream {
 
 impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
-        ByteStream {
-            size_hint: Some(buf.len()),
-            inner: Box::pin(stream::once(async move { Ok(Bytes::from(buf)) })),
-        }
+        ByteStream::from_bytes(Bytes::from(buf))
     }
 }
 
+/// Reads the file at `path` in chunks, opening it when the stream is first polled.
+fn file_chunks(path: PathBuf) -> impl Stream<Item = Result<Bytes, io::Error>> + Send + Sync {
+    enum State {
+        Closed(PathBuf),
+        Open(tokio::fs::File),
+        Done,
+    }
+
+    stream::unfold(State::Closed(path), |state| async move {
+        let mut file = match state {
+            State::Closed(path) => match tokio::fs::File::open(path).await {
+                Ok(file) => file,
+                Err(e) => return Some((Err(e), State::Done)),
+            },
+            State::Open(file) => file,
+            State::Done => return None,
+        };
+        let mut buf = BytesMut::new();
+        buf.resize(FILE_CHUNK_SIZE, 0);
+        match file.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(buf.freeze()), State::Open(file)))
+            }
+            Err(e) => Some((Err(e), State::Done)),
+        }
+    })
+}
+
 impl fmt::Debug for ByteStream {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "<ByteStream size_hint={:?}>", self.size_hint)
+        write!(
+            f,
+            "<ByteStream size_hint={:?} replayable={}>",
+            self.size_hint,
+            self.is_replayable()
+        )
     }
 }
 
@@ -83,7 +179,8 @@ impl Stream for ByteStream {
 struct ImplAsyncRead {
     buffer: BytesMut,
     #[pin]
-    stream: futures::stream::Fuse<Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync>>>,
+    stream:
+        futures::stream::Fuse<Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync>>>,
 }
 
 impl ImplAsyncRead {
@@ -216,3 +313,64 @@ async fn test_new_with_size_read() {
     assert_eq!(&buf[..1], b"8");
     assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
 }
+
+#[tokio::test]
+async fn test_from_path() {
+    use std::io::Write;
+    use tokio::io::AsyncReadExt;
+
+    let contents: Vec<u8> = (0..FILE_CHUNK_SIZE * 2 + 10).map(|i| i as u8).collect();
+    let path = std::env::temp_dir().join(format!("rusoto-bytestream-{}", std::process::id()));
+    std::fs::File::create(&path)
+        .unwrap()
+        .write_all(&contents)
+        .unwrap();
+
+    let stream = ByteStream::from_path(&path).await.unwrap();
+    assert_eq!(stream.size_hint, Some(contents.len()));
+
+    assert!(stream.is_replayable());
+    let replayed = stream.try_clone().unwrap();
+
+    let mut read = Vec::new();
+    stream
+        .into_async_read()
+        .read_to_end(&mut read)
+        .await
+        .unwrap();
+    assert_eq!(read, contents);
+
+    let mut read = Vec::new();
+    replayed
+        .into_async_read()
+        .read_to_end(&mut read)
+        .await
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read, contents);
+}
+
+#[tokio::test]
+async fn test_from_path_missing_file() {
+    let path = std::env::temp_dir().join("rusoto-bytestream-does-not-exist");
+    let err = ByteStream::from_path(&path).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[tokio::test]
+async fn test_replayable_streams() {
+    let stream = ByteStream::from(b"1234".to_vec());
+    assert!(stream.is_replayable());
+    let replayed = stream.try_clone().unwrap();
+    assert_eq!(replayed.size_hint, Some(4));
+    assert!(replayed.is_replayable());
+
+    let first: Vec<_> = stream.map(Result::unwrap).collect().await;
+    let second: Vec<_> = replayed.map(Result::unwrap).collect().await;
+    assert_eq!(first, vec![Bytes::from_static(b"1234")]);
+    assert_eq!(first, second);
+
+    let stream = ByteStream::new(stream::iter(vec![Ok(Bytes::from_static(b"1234"))]));
+    assert!(!stream.is_replayable());
+    assert!(stream.try_clone().is_none());
+}
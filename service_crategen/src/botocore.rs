@@ -8,12 +8,16 @@ use std::path::Path;
 
 use serde::de::{Error as SerdeError, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
-use serde_json;
+use serde_json::{self, Value as JsonValue};
 
 use crate::util;
 
 const BOTOCORE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/botocore/botocore/data/");
 
+/// Partial service definitions merged into the botocore ones, for model updates not yet in the
+/// botocore version in use.
+const PATCHES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/patches/");
+
 #[derive(Debug, Deserialize)]
 pub struct ServiceDefinition {
     pub documentation: Option<String>,
@@ -30,9 +34,27 @@ impl ServiceDefinition {
         let input_path =
             Path::new(BOTOCORE_DIR).join(format!("{}/{}/service-2.json", name, protocol_version));
 
-        let input_file = BufReader::new(File::open(&input_path)?);
+        Self::load_patched(&input_path, name, protocol_version)
+    }
+
+    /// Loads the service definition at `path`, merging the patch for `name` and
+    /// `protocol_version` into it if there is one.
+    fn load_patched(
+        path: &Path,
+        name: &str,
+        protocol_version: &str,
+    ) -> Result<Self, Box<dyn error::Error>> {
+        let input_file = BufReader::new(File::open(path)?);
+        let mut definition: JsonValue = serde_json::from_reader(input_file)?;
+
+        let patch_path =
+            Path::new(PATCHES_DIR).join(format!("{}/{}/service-2.json", name, protocol_version));
+        if patch_path.exists() {
+            let patch_file = BufReader::new(File::open(&patch_path)?);
+            merge_patch(&mut definition, serde_json::from_reader(patch_file)?);
+        }
 
-        let service: ServiceDefinition = serde_json::from_reader(input_file)?;
+        let service: ServiceDefinition = serde_json::from_value(definition)?;
 
         Ok(service)
     }
@@ -66,10 +88,9 @@ impl ServiceDefinition {
                 })
             })
             .map(|(service_name, path)| {
-                let input_file =
-                    BufReader::new(File::open(&format!("{}/service-2.json", path.display()))?);
-
-                let service: ServiceDefinition = serde_json::from_reader(input_file)?;
+                let version = path.file_name().unwrap().to_string_lossy().into_owned();
+                let service =
+                    Self::load_patched(&path.join("service-2.json"), &service_name, &version)?;
 
                 Ok((service_name, service))
             })
@@ -77,6 +98,30 @@ impl ServiceDefinition {
     }
 }
 
+/// Merges `patch` into `definition`: objects are merged key by key, anything else is replaced.
+fn merge_patch(definition: &mut JsonValue, patch: JsonValue) {
+    match (definition, patch) {
+        (JsonValue::Object(definition), JsonValue::Object(patch)) => {
+            for (key, value) in patch {
+                merge_patch(definition.entry(key).or_insert(JsonValue::Null), value);
+            }
+        }
+        (definition, patch) => *definition = patch,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HttpChecksum {
+    #[serde(rename = "requestAlgorithmMember")]
+    pub request_algorithm_member: Option<String>,
+    #[serde(rename = "requestChecksumRequired")]
+    pub request_checksum_required: Option<bool>,
+    #[serde(rename = "requestValidationModeMember")]
+    pub request_validation_mode_member: Option<String>,
+    #[serde(rename = "responseAlgorithms")]
+    pub response_algorithms: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct HttpRequest {
     pub method: String,
@@ -128,6 +173,7 @@ pub struct HttpError {
 pub struct Member {
     pub deprecated: Option<bool>,
     pub documentation: Option<String>,
+    pub eventpayload: Option<bool>,
     pub flattened: Option<bool>,
     pub location: Option<String>,
     #[serde(rename = "locationName")]
@@ -149,6 +195,10 @@ impl Member {
     pub fn streaming(&self) -> bool {
         self.streaming.unwrap_or(false)
     }
+
+    pub fn eventpayload(&self) -> bool {
+        self.eventpayload.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -314,11 +364,19 @@ pub struct Operation {
     pub documentation_url: Option<String>,
     pub errors: Option<BTreeSet<Error>>,
     pub http: HttpRequest,
+    #[serde(rename = "httpChecksum")]
+    pub http_checksum: Option<HttpChecksum>,
     #[serde(rename = "httpChecksumRequired")]
     pub http_checksum_required: Option<bool>,
     pub input: Option<Input>,
     pub name: String,
     pub output: Option<Output>,
+    pub requestcompression: Option<RequestCompression>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequestCompression {
+    pub encodings: Vec<String>,
 }
 
 impl<'a> Operation {
@@ -344,6 +402,34 @@ impl<'a> Operation {
         }
     }
 
+    /// The input member naming the algorithm of the request checksum, if the operation
+    /// accepts flexible checksums.
+    pub fn request_algorithm_member(&'a self) -> Option<&'a str> {
+        self.http_checksum
+            .as_ref()?
+            .request_algorithm_member
+            .as_ref()
+            .map(String::as_ref)
+    }
+
+    /// Whether the operation accepts request bodies compressed with gzip.
+    pub fn accepts_gzip_requests(&self) -> bool {
+        match self.requestcompression {
+            Some(ref compression) => compression.encodings.iter().any(|e| e == "gzip"),
+            None => false,
+        }
+    }
+
+    /// The input member enabling the validation of response checksums, if the operation
+    /// returns flexible checksums.
+    pub fn request_validation_mode_member(&'a self) -> Option<&'a str> {
+        self.http_checksum
+            .as_ref()?
+            .request_validation_mode_member
+            .as_ref()
+            .map(String::as_ref)
+    }
+
     // botocore duplicates errors in a few places
     // return a unique set
     pub fn errors(&'a self) -> &BTreeSet<Error> {
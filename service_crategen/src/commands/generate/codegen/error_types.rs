@@ -93,13 +93,14 @@ pub trait GenerateErrorTypes {
                     || service.service_id() == Some("CloudSearch")
                 {
                     enum_types.push(format!(
-                        "\n{}\n{}(String)",
+                        "\n{}\n{}({})",
                         crate::doco::Item(
                             error_documentation
                                 .get(&error.shape)
                                 .unwrap_or(&&String::from(""))
                         ),
-                        error.idiomatic_error_name()
+                        error.idiomatic_error_name(),
+                        self.error_message_type()
                     ));
                 }
             }
@@ -108,6 +109,11 @@ pub trait GenerateErrorTypes {
         Some(enum_types.join(","))
     }
 
+    /// the type of the message each error variant holds
+    fn error_message_type(&self) -> &'static str {
+        "String"
+    }
+
     /// generate the matcher arms for an error type's implementation of Error's Display
     fn generate_error_display_matchers(
         &self,
@@ -176,10 +182,22 @@ impl GenerateErrorTypes for XmlErrorTypes {
                     fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError> where T: Peek + Next {{
                         {error_deserializer}
                     }}
+
+                    /// Returns the ID of the failed request, if the error response had one.
+                    pub fn request_id(&self) -> Option<&str> {{
+                        match *self {{
+                            {request_id_matchers}
+                        }}
+                    }}
                 }}",
                 type_name = error_type_name(service, operation_name),
                 error_deserializer = self.generate_error_deserializer(service),
-                type_matchers = self.generate_error_type_matchers(operation_name, operation, service))
+                type_matchers = self.generate_error_type_matchers(operation_name, operation, service),
+                request_id_matchers = self.generate_request_id_matchers(operation_name, operation, service))
+    }
+
+    fn error_message_type(&self) -> &'static str {
+        "XmlErrorMessage"
     }
 }
 
@@ -224,7 +242,7 @@ impl XmlErrorTypes {
                     .as_ref()
                     .and_then(|http_error| http_error.code.as_ref())
                     .unwrap_or(&error.shape);
-                type_matchers.push(format!("\"{error_code}\" => return RusotoError::Service({error_type}::{error_name}(parsed_error.message))",
+                type_matchers.push(format!("\"{error_code}\" => return RusotoError::Service({error_type}::{error_name}(XmlErrorMessage::from_response(parsed_error.message, &res)))",
                     error_code = error_code,
                     error_type = error_type,
                     error_name = error.idiomatic_error_name()))
@@ -234,6 +252,33 @@ impl XmlErrorTypes {
         type_matchers.push("_ => {}".to_string());
         type_matchers.join(",")
     }
+
+    /// generate the arms for a match expression that returns the request ID kept by each of
+    /// an error type's variants, filtered like the variants themselves
+    fn generate_request_id_matchers(
+        &self,
+        operation_name: &str,
+        operation: &Operation,
+        service: &Service<'_>,
+    ) -> String {
+        let mut request_id_matchers: Vec<String> = Vec::new();
+
+        if operation.errors.is_some() {
+            for error in operation.errors() {
+                if error.idiomatic_error_name() != "Validation"
+                    || service.service_id() == Some("CloudSearch")
+                {
+                    request_id_matchers.push(format!(
+                        "{error_type}::{error_shape}(ref cause) => cause.request_id()",
+                        error_type = error_type_name(service, operation_name),
+                        error_shape = error.idiomatic_error_name()
+                    ))
+                }
+            }
+        }
+
+        request_id_matchers.join(",\n")
+    }
 }
 
 impl GenerateErrorTypes for JsonErrorTypes {
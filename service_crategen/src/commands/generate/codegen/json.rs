@@ -98,7 +98,7 @@ impl GenerateProtocol for JsonGenerator {
                 ) -> Result<HttpResponse, RusotoError<E>> {{
                     let mut response = self.client.sign_and_dispatch(request).await?;
                     if !response.status.is_success() {{
-                        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                        let response = response.buffer().await?;
                         return Err(from_response(response));
                     }}
 
@@ -270,7 +270,7 @@ fn generate_ok_response(service: &Service<'_>, operation: &Operation, output_typ
         } else {
             format!(
                 "let mut response = response;
-                let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                let response = response.buffer().await?;
                 proto::json::ResponsePayload::new(&response).deserialize::<{}, _>()",
                 output_type = output_type,
             )
@@ -193,12 +193,23 @@ where
 
     writeln!(writer, "}}")?;
 
+    // S3 clients carry an `S3Config`, defined in the crate's custom module.
+    let (config_field, config_init) = if service.service_type_name() == "S3" {
+        (
+            "pub(crate) config: crate::custom::S3Config,",
+            "config: Default::default(),",
+        )
+    } else {
+        ("", "")
+    };
+
     writeln!(writer,
         "/// A client for the {service_name} API.
         #[derive(Clone)]
         pub struct {type_name} {{
             client: Client,
             region: region::Region,
+            {config_field}
         }}
 
         impl {type_name} {{
@@ -208,7 +219,8 @@ where
             pub fn new(region: region::Region) -> {type_name} {{
                 {type_name} {{
                     client: Client::shared(),
-                    region
+                    region,
+                    {config_init}
                 }}
             }}
 
@@ -218,7 +230,8 @@ where
             {{
                 {type_name} {{
                     client: Client::new_with(credentials_provider, request_dispatcher),
-                    region
+                    region,
+                    {config_init}
                 }}
             }}
 
@@ -226,7 +239,8 @@ where
             {{
                 {type_name} {{
                     client,
-                    region
+                    region,
+                    {config_init}
                 }}
             }}
         }}
@@ -237,6 +251,8 @@ where
         service_name = service.name(),
         type_name = service.client_type_name(),
         trait_name = service.service_type_name(),
+        config_field = config_field,
+        config_init = config_init,
     )?;
     protocol_generator.generate_method_impls(writer, service)?;
     writeln!(writer, "}}")
@@ -101,7 +101,7 @@ impl GenerateProtocol for QueryGenerator {
                 ) -> Result<HttpResponse, RusotoError<E>> {{
                     let mut response = self.client.sign_and_dispatch(request).await?;
                     if !response.status.is_success() {{
-                        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                        let response = response.buffer().await?;
                         return Err(from_response(response));
                     }}
 
@@ -174,9 +174,14 @@ fn generate_set_input_params(operation: &Operation) -> String {
     if operation.http.method != "POST" {
         panic!("query protocol supports only POST method: {:?}", operation);
     }
-    "request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-    request.set_content_type(\"application/x-www-form-urlencoded\".to_owned());"
-        .to_owned()
+    let mut set_input_params =
+        "request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type(\"application/x-www-form-urlencoded\".to_owned());"
+            .to_owned();
+    if operation.accepts_gzip_requests() {
+        set_input_params.push_str("\nrequest.set_request_compression(true);");
+    }
+    set_input_params
 }
 
 fn generate_serializer_body(service: &Service<'_>, shape: &Shape) -> String {
@@ -530,3 +535,45 @@ fn generate_method_signature(
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gzip_request_compression_is_enabled_for_operations_accepting_it() {
+        let operation_json = "{\
+            \"name\": \"PutMetricData\",
+            \"http\":{
+                \"method\":\"POST\",
+                \"requestUri\":\"/\"
+            },
+            \"requestcompression\":{
+                \"encodings\":[\"gzip\"]
+            }\
+        }";
+        let operation =
+            serde_json::from_str(operation_json).expect("failed to parse operation json");
+
+        let set_input_params = generate_set_input_params(&operation);
+
+        assert!(set_input_params.ends_with("\nrequest.set_request_compression(true);"));
+    }
+
+    #[test]
+    fn request_compression_is_left_off_by_default() {
+        let operation_json = "{\
+            \"name\": \"ListMetrics\",
+            \"http\":{
+                \"method\":\"POST\",
+                \"requestUri\":\"/\"
+            }\
+        }";
+        let operation =
+            serde_json::from_str(operation_json).expect("failed to parse operation json");
+
+        let set_input_params = generate_set_input_params(&operation);
+
+        assert!(!set_input_params.contains("set_request_compression"));
+    }
+}
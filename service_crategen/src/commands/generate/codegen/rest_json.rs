@@ -61,6 +61,7 @@ impl GenerateProtocol for RestJsonGenerator {
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                    request.set_operation(\"{operation_name}\");
                     {default_headers}
                     {set_headers}
                     {modify_endpoint_prefix}
@@ -70,13 +71,13 @@ impl GenerateProtocol for RestJsonGenerator {
 
                     let mut response = self.client.sign_and_dispatch(request).await.map_err(RusotoError::from)?;
                     if {status_check} {{
-                        let mut response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                        let mut response = response.buffer().await?;
                         {parse_body}
                         {parse_headers}
                         {parse_status_code}
                         Ok(result)
                     }} else {{
-                        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                        let response = response.buffer().await?;
                         Err({error_type}::from_response(response))
                     }}
                 }}
@@ -86,6 +87,7 @@ impl GenerateProtocol for RestJsonGenerator {
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
                 http_method = operation.http.method,
+                operation_name = &operation.name,
                 error_type = error_type_name(service, operation_name),
                 status_check = http_code_expected(operation.http.response_code),
                 parse_body = generate_body_parser(operation, service),
@@ -36,9 +36,16 @@ impl GenerateProtocol for RestXmlGenerator {
         for (operation_name, operation) in service.operations().iter() {
             let (request_uri, _) =
                 rest_request_generator::parse_query_string(&operation.http.request_uri);
-            let parse_non_payload =
+            let mut parse_non_payload =
                 rest_response_parser::generate_response_headers_parser(service, operation)
                     .unwrap_or_else(|| "".to_owned());
+            // S3 checks the body of responses against their checksum once validation is enabled.
+            if service.service_type_name() == "S3"
+                && operation.request_validation_mode_member().is_some()
+            {
+                parse_non_payload
+                    .push_str("\ncrate::custom::validate_checksum(&input, &mut result);");
+            }
             writeln!(writer,
                      "{documentation}
                     #[allow(unused_variables, warnings)]
@@ -46,6 +53,7 @@ impl GenerateProtocol for RestXmlGenerator {
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                        request.set_operation(\"{operation_name}\");
 
                         {set_headers}
                         {set_parameters}
@@ -61,6 +69,7 @@ impl GenerateProtocol for RestXmlGenerator {
                      endpoint_prefix = service.endpoint_prefix(),
                      method_signature = generate_method_signature(operation_name, operation, service),
                      error_type = error_type_name(service, operation_name),
+                     operation_name = &operation.name,
                      build_payload = generate_payload_serialization(service, operation)
                          .unwrap_or_else(|| "".to_string()),
                      modify_uri = rest_request_generator::generate_uri_formatter(&request_uri,
@@ -100,18 +109,36 @@ impl GenerateProtocol for RestXmlGenerator {
 
         writeln!(writer, "{}", imports)?;
 
+        // S3 rewrites requests according to the client's `S3Config` once their endpoint is known.
+        let dispatch = if service.service_type_name() == "S3" {
+            "self.client
+                .resolve_endpoint(&mut request)
+                .map_err(|err| RusotoError::Validation(err.to_string()))?;
+            self.config
+                .prepare_request(&mut request, self.client.endpoint_variants())
+                .map_err(RusotoError::Validation)?;
+            let mut response = self.client.sign_and_dispatch_resolved(request).await?;"
+        } else {
+            "let mut response = self.client.sign_and_dispatch(request).await?;"
+        };
+        let request_binding = if service.service_type_name() == "S3" {
+            "mut request"
+        } else {
+            "request"
+        };
+
         writeln!(
             writer,
             "
             impl {type_name} {{
                 async fn sign_and_dispatch<E>(
                     &self,
-                    request: SignedRequest,
+                    {request_binding}: SignedRequest,
                     from_response: fn (BufferedHttpResponse) -> RusotoError<E>,
                 ) -> Result<HttpResponse, RusotoError<E>> {{
-                    let mut response = self.client.sign_and_dispatch(request).await?;
+                    {dispatch}
                     if !response.status.is_success() {{
-                        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                        let response = response.buffer().await?;
                         return Err(from_response(response));
                     }}
 
@@ -119,6 +146,8 @@ impl GenerateProtocol for RestXmlGenerator {
                 }}
             }}",
             type_name = service.client_type_name(),
+            request_binding = request_binding,
+            dispatch = dispatch,
         )?;
 
         if service.has_event_streams() {
@@ -186,6 +215,93 @@ impl GenerateProtocol for RestXmlGenerator {
     fn timestamp_type(&self) -> &'static str {
         "String"
     }
+
+    fn generate_event_enum_deserialize_impl(
+        &self,
+        service: &Service<'_>,
+        name: &str,
+        shape: &Shape,
+    ) -> String {
+        let match_arms = shape
+            .members
+            .as_ref()
+            .unwrap()
+            .iter()
+            .filter(|(_, member)| !member.deprecated())
+            .map(|(member_name, member)| {
+                format!(
+                    "\"{member_name}\" => {name}::{member_name}({event}),",
+                    name = name,
+                    member_name = member_name,
+                    event = generate_event_deserializer(service, member),
+                )
+            })
+            .chain(std::iter::once(format!(
+                "_ => Err(RusotoError::ParseError({err_fmt}))?",
+                err_fmt = "format!(\"Invalid event type: {}\", event_type)",
+            )))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            "impl DeserializeEvent for {name} {{
+                fn deserialize_event(
+                    event_type: &str,
+                    data: &[u8],
+                ) -> Result<Self, RusotoError<()>> {{
+                    let deserialized = match event_type {{
+                        {match_arms}
+                    }};
+                    Ok(deserialized)
+                }}
+            }}
+            ",
+            name = name,
+            match_arms = match_arms,
+        )
+    }
+}
+
+/// Generates the expression deserializing the event of `member` of an event stream from its
+/// message payload `data`: a blob event payload is taken as is, a structure is parsed as XML,
+/// and events without a payload member carry no data.
+fn generate_event_deserializer(service: &Service<'_>, member: &Member) -> String {
+    let event_type = mutate_type_name(service, &member.shape);
+    let event_shape = service.shape_for_member(member).unwrap();
+    let payload = event_shape
+        .members
+        .iter()
+        .flatten()
+        .find(|(_, event_member)| event_member.eventpayload());
+    let (payload_name, payload_member) = match payload {
+        Some(payload) => payload,
+        None => return format!("{}::default()", event_type),
+    };
+
+    let payload_shape = service.shape_for_member(payload_member).unwrap();
+    let mut value = match payload_shape.shape_type {
+        ShapeType::Blob => "bytes::Bytes::copy_from_slice(data)".to_owned(),
+        ShapeType::String => "String::from_utf8_lossy(data).into_owned()".to_owned(),
+        _ => format!(
+            "xml_util::parse_payload(data, |tag_name, stack| {}Deserializer::deserialize(tag_name, stack))?",
+            mutate_type_name(service, &payload_member.shape)
+        ),
+    };
+    if !event_shape.required(payload_name) {
+        value = format!("Some({})", value);
+    }
+    let other_members = if event_shape.members.as_ref().map_or(0, |m| m.len()) > 1 {
+        ", ..Default::default()"
+    } else {
+        ""
+    };
+    format!(
+        "{event_type} {{ {field}: {value}{other_members} }}",
+        event_type = event_type,
+        field = generate_field_name(payload_name),
+        value = value,
+        other_members = other_members,
+    )
 }
 
 fn generate_documentation(operation: &Operation, service: &Service<'_>) -> String {
@@ -234,6 +350,26 @@ fn generate_payload_serialization(service: &Service<'_>, operation: &Operation)
         parts.push("request.set_content_md5_header();".to_owned());
     }
 
+    // S3 sends the Content-MD5 of PutObject bodies if the client's `S3Config` asks for it.
+    if service.service_type_name() == "S3" && operation.name == "PutObject" {
+        parts.push(
+            "if self.config.put_object_content_md5 {
+                crate::custom::add_content_md5(&mut request)
+                    .await
+                    .map_err(RusotoError::HttpDispatch)?;
+            }"
+            .to_owned(),
+        );
+    }
+
+    // S3 computes the checksum for the requested algorithm when none was given.
+    if service.service_type_name() == "S3" && operation.request_algorithm_member().is_some() {
+        parts.push(
+            "crate::custom::add_missing_checksum(&mut request).map_err(RusotoError::Validation)?;"
+                .to_owned(),
+        );
+    }
+
     Some(parts.join("\n"))
 }
 
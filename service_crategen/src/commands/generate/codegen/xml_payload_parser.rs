@@ -119,7 +119,7 @@ fn payload_body_parser(
         ShapeType::Blob if !streaming => {
             format!("
                 let mut response = response;
-                let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                let response = response.buffer().await?;
                 let mut result = {output_shape}::default();
                 result.{payload_member} = Some(response.body);
                 {parse_non_payload}
@@ -143,7 +143,7 @@ fn payload_body_parser(
         _ => {
             format!("
                 let mut response = response;
-                let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+                let response = response.buffer().await?;
                 let mut result = {output_shape}::default();
                 result.{payload_member} = Some(String::from_utf8_lossy(response.body.as_ref()).into());
                 {parse_non_payload}
@@ -164,8 +164,23 @@ fn xml_body_parser(
     mutable_result: bool,
     parse_non_payload: &str,
 ) -> String {
-    if let Some(_eventstream_field) = eventstream_field_name(service, shape) {
-        return "unimplemented!()".to_string();
+    if let Some(eventstream_field) = eventstream_field_name(service, shape) {
+        let required = shape
+            .members
+            .iter()
+            .flatten()
+            .any(|(name, _)| generate_field_name(name) == eventstream_field && shape.required(name));
+        let event_stream = if required {
+            "EventStream::new(response)"
+        } else {
+            "Some(EventStream::new(response))"
+        };
+        return format!(
+            "Ok({output_shape} {{ {eventstream_field}: {event_stream} }})",
+            output_shape = output_shape,
+            eventstream_field = eventstream_field,
+            event_stream = event_stream,
+        );
     }
 
     let xml_deserialize = if needs_xml_deserializer(shape) {